@@ -16,7 +16,7 @@ mkdir ./build_docs
 mkdir ./build_docs/language
 
 echo "create instruction reference markdown file"
-(cd doc/insref && cargo update && cargo run -- x64 > ../instructionref_x64.md && cargo run -- aarch64 > ../instructionref_aarch64.md && cargo run -- riscv > ../instructionref_riscv.md)
+(cd doc/insref && cargo update && cargo run -- x64 > ../instructionref_x64.md && cargo run -- aarch64 > ../instructionref_aarch64.md && cargo run -- riscv > ../instructionref_riscv.md && cargo run -- loongarch64 > ../instructionref_loongarch64.md && cargo run -- arm > ../instructionref_arm.md && cargo run -- thumb2 > ../instructionref_thumb2.md && cargo run -- ebpf > ../instructionref_ebpf.md && cargo run -- ppc64le > ../instructionref_ppc64le.md)
 
 echo "build plugin docs"
 for f in ./doc/*.md; do
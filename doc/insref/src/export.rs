@@ -8,6 +8,11 @@ fn main() {
         "x64" => dynasm::dynasm_extract!(x64),
         "aarch64" => dynasm::dynasm_extract!(aarch64),
         "riscv" => dynasm::dynasm_extract!(riscv),
+        "loongarch64" => dynasm::dynasm_extract!(loongarch64),
+        "arm" => dynasm::dynasm_extract!(arm),
+        "thumb2" => dynasm::dynasm_extract!(thumb2),
+        "ebpf" => dynasm::dynasm_extract!(ebpf),
+        "ppc64le" => dynasm::dynasm_extract!(ppc64le),
         x => panic!("Unknown opmap format '{}'", x)
     };
 
@@ -8,6 +8,11 @@ fn main() {
         "x64" => dynasm::dynasm_opmap!(x64),
         "aarch64" => dynasm::dynasm_opmap!(aarch64),
         "riscv" => dynasm::dynasm_opmap!(riscv),
+        "loongarch64" => dynasm::dynasm_opmap!(loongarch64),
+        "arm" => dynasm::dynasm_opmap!(arm),
+        "thumb2" => dynasm::dynasm_opmap!(thumb2),
+        "ebpf" => dynasm::dynasm_opmap!(ebpf),
+        "ppc64le" => dynasm::dynasm_opmap!(ppc64le),
         x => panic!("Unknown opmap format '{}'", x)
     };
 
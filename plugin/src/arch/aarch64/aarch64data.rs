@@ -1,9 +1,12 @@
 use crate::common::Size;
 use super::ast::Modifier;
 
+use bitflags::bitflags;
 use lazy_static::lazy_static;
 use std::collections::{HashMap, hash_map};
 
+use std::fmt;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Matcher {
     // a literal "."
@@ -61,6 +64,48 @@ pub enum Matcher {
     // register list with element specifier. It has .0 items with a size of .1
     RegListElement(u8, Size),
 
+    // scalable vector regs
+    /// scalable vector register with elements of the specified size
+    Z(Size),
+    /// scalable vector register without element size specifier
+    ZBare,
+    /// scalable vector register with element specifier, with elements of the specified size
+    ZElement(Size),
+
+    // scalable vector register list with .0 items, with the elements of size .1
+    ZRegList(u8, Size),
+
+    // predicate regs
+    /// predicate register with elements of the specified size
+    P(Size),
+    /// predicate register without element size specifier or qualifier
+    PBare,
+    /// governing predicate register with the zeroing (/Z) qualifier
+    PZero,
+    /// governing predicate register with the merging (/M) qualifier
+    PMerge,
+
+    /// a 64-bit general purpose register that gets written back (Xn!)
+    XWriteback,
+
+    // ZA matrix registers
+    /// the complete ZA array, without any encoding
+    ZaArray,
+    /// an array vector of the ZA array, `za[Wv, offset]`
+    ZaVector,
+    /// a ZA tile with the specified element size
+    ZaTile(Size),
+    /// a horizontal ZA tile slice with the specified element size, `za0h.s[Wv, offset]`
+    ZaSliceH(Size),
+    /// a vertical ZA tile slice with the specified element size, `za0v.s[Wv, offset]`
+    ZaSliceV(Size),
+    /// a list of ZA tiles, encoded as the mask of 64-bit tiles it covers
+    ZaTileList,
+    /// a list containing a single horizontal ZA tile slice with the specified element size
+    ZaSliceListH(Size),
+    /// a list containing a single vertical ZA tile slice with the specified element size
+    ZaSliceListV(Size),
+
     // jump offsets
     Offset,
 
@@ -69,6 +114,12 @@ pub enum Matcher {
     RefOffset,
     RefPre,
     RefIndex,
+    // [Xn|SP {, #imm, MUL VL}]
+    RefMulVl,
+    // [Xn|SP, Xm {, LSL #.0}], with the shift amount fixed to .0
+    RefIndexLsl(u8),
+    // [Xn]!, a base register that gets written back, as used by the MOPS instructions
+    RefWriteback,
 
     // a single modifier
     LitMod(Modifier),
@@ -87,7 +138,11 @@ pub enum Command {
     REven(u8), // same as R, but requires that the register is even.
     RNoZr(u8), // same as R, but does not allow register 31.
     R4(u8), // encode a register in the range 0-15 into a 4-bit bitfield
+    R3(u8), // encode a register in the range 0-7 into a 3-bit bitfield
     RNext, // encode that this register should be the previous register, plus one
+    RTied, // encode that this register should be the same register as the first argument
+    RTile(u8, u8), // encode a ZA tile number into a bitfield of the given length. A length of 0 only allows tile 0
+    RSelect(u8), // encode a W12-W15 slice index register into a 2-bit bitfield
 
     // unsigned immediate encodings
 
@@ -154,6 +209,9 @@ pub enum Command {
     /// format: `(min, max)`
     /// `immediate - min` is in the range `0 ..= max - min`.
     CUrange(u8, u8),
+    /// format: `(back)`
+    /// `immediate` is equal to the immediate `back` arguments before it.
+    CUeq(u8),
 
     // bit slice encodings. These encode part of a value
 
@@ -235,6 +293,106 @@ impl Relocation {
 }
 
 
+bitflags! {
+    /// Flags specifying what ISA extensions are required for an instruction
+    // debug.rs opmap generation relies on the Ex_ prefixes being fixed,
+    // so change that if editing this.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ExtensionFlags: u64 {
+        /// base: the ARMv8.0-A base instruction set, including floating point and advanced SIMD
+        const Ex_BASE = 0x0000_0000_0000_0001;
+        /// aes: AES instructions
+        const Ex_AES = 0x0000_0000_0000_0002;
+        /// bti: branch target identification
+        const Ex_BTI = 0x0000_0000_0000_0004;
+        /// crc: CRC32 checksum instructions
+        const Ex_CRC = 0x0000_0000_0000_0008;
+        /// dotprod: advanced SIMD dot product instructions
+        const Ex_DOTPROD = 0x0000_0000_0000_0010;
+        /// fcma: floating point complex number instructions
+        const Ex_FCMA = 0x0000_0000_0000_0020;
+        /// flagm: condition flag manipulation
+        const Ex_FLAGM = 0x0000_0000_0000_0040;
+        /// fp16: half precision floating point data processing
+        const Ex_FP16 = 0x0000_0000_0000_0080;
+        /// fp16fml: half precision floating point multiply-add long
+        const Ex_FP16FML = 0x0000_0000_0000_0100;
+        /// jscvt: javascript floating point conversion
+        const Ex_JSCVT = 0x0000_0000_0000_0200;
+        /// lor: limited ordering regions
+        const Ex_LOR = 0x0000_0000_0000_0400;
+        /// lse: large system extensions (atomic memory operations)
+        const Ex_LSE = 0x0000_0000_0000_0800;
+        /// mops: standardized memory copy and set instructions
+        const Ex_MOPS = 0x0000_0000_0000_1000;
+        /// mte: memory tagging extension
+        const Ex_MTE = 0x0000_0000_0000_2000;
+        /// pauth: pointer authentication
+        const Ex_PAUTH = 0x0000_0000_0000_4000;
+        /// predres: execution and data prediction restriction instructions
+        const Ex_PREDRES = 0x0000_0000_0000_8000;
+        /// ras: reliability, availability and serviceability extension
+        const Ex_RAS = 0x0000_0000_0001_0000;
+        /// rcpc: load-acquire RCpc instructions
+        const Ex_RCPC = 0x0000_0000_0002_0000;
+        /// rcpc2: load-acquire RCpc instructions with immediate offsets
+        const Ex_RCPC2 = 0x0000_0000_0004_0000;
+        /// rdm: advanced SIMD rounding double multiply accumulate
+        const Ex_RDM = 0x0000_0000_0008_0000;
+        /// sb: speculation barrier
+        const Ex_SB = 0x0000_0000_0010_0000;
+        /// sha2: SHA1 and SHA256 instructions
+        const Ex_SHA2 = 0x0000_0000_0020_0000;
+        /// sha3: SHA3 and SHA512 instructions
+        const Ex_SHA3 = 0x0000_0000_0040_0000;
+        /// sm4: SM3 and SM4 instructions
+        const Ex_SM4 = 0x0000_0000_0080_0000;
+        /// sme: scalable matrix extension
+        const Ex_SME = 0x0000_0000_0100_0000;
+        /// sme_f64f64: scalable matrix extension double precision floating point outer products
+        const Ex_SME_F64F64 = 0x0000_0000_0200_0000;
+        /// sme_i16i64: scalable matrix extension 16-bit integer outer products with 64-bit accumulation
+        const Ex_SME_I16I64 = 0x0000_0000_0400_0000;
+        /// spe: statistical profiling extension
+        const Ex_SPE = 0x0000_0000_0800_0000;
+        /// sve: scalable vector extension
+        const Ex_SVE = 0x0000_0000_1000_0000;
+        /// sve2: scalable vector extension version 2
+        const Ex_SVE2 = 0x0000_0000_2000_0000;
+        /// trf: self-hosted trace extension
+        const Ex_TRF = 0x0000_0000_4000_0000;
+    }
+}
+
+impl ExtensionFlags {
+    const fn make(bits: u64) -> ExtensionFlags {
+        ExtensionFlags::from_bits_truncate(bits)
+    }
+}
+
+impl fmt::Display for ExtensionFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut first = true;
+
+        // print the lowercase names as used by the .feature directive, joined by +
+        for (flag, _) in self.iter_names() {
+            if !first {
+                write!(f, "+")?;
+            }
+            first = false;
+
+            write!(f, "{}", flag[3..].to_ascii_lowercase())?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for ExtensionFlags {
+    fn default() -> ExtensionFlags {
+        ExtensionFlags::Ex_BASE
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Opdata {
     /// The base template for the encoding.
@@ -242,11 +400,13 @@ pub struct Opdata {
     /// A set of matchers capable of matching the instruction encoding that this instruction represents.
     pub matchers: &'static [Matcher],
     /// A sequence of encoder commands that check the matched instruction on validity and whose output gets orred together with the original template at runtime.
-    pub commands: &'static [Command]
+    pub commands: &'static [Command],
+    /// What (combination) of extensions is required for this instruction
+    pub ext_flags: &'static [ExtensionFlags],
 }
 
 macro_rules! SingleOp {
-    ( $base:expr, [ $( $matcher:expr ),* ], [ $( $command:expr ),* ] ) => {
+    ( $base:expr, [ $( $matcher:expr ),* ], [ $( $command:expr ),* ], [ $( $extension:expr ),* ] ) => {
         {
             const MATCHERS: &'static [Matcher] = {
                 #[allow(unused_imports)]
@@ -262,22 +422,28 @@ macro_rules! SingleOp {
                     $command
                 ),* ]
             };
+            const EXTENSIONS: &'static [ExtensionFlags] = {
+                &[ $(
+                    ExtensionFlags::make($extension)
+                ),* ]
+            };
             Opdata {
                 base: $base,
                 matchers: MATCHERS,
                 commands: COMMANDS,
+                ext_flags: EXTENSIONS,
             }
         }
     }
 }
 
 macro_rules! Ops {
-    ( $( $name:tt = [ $( $base:tt = [ $( $matcher:expr ),* ] => [ $( $command:expr ),* ] ; )+ ] )* ) => {
+    ( $( $name:tt = [ $( $base:tt = [ $( $matcher:expr ),* ] => [ $( $command:expr ),* ] , [ $( $extension:expr ),* ] ; )+ ] )* ) => {
         [ $(
             (
                 $name,
                 &[ $(
-                    SingleOp!( $base, [ $( $matcher ),* ], [ $( $command ),* ] )
+                    SingleOp!( $base, [ $( $matcher ),* ], [ $( $command ),* ], [ $( $extension ),* ] )
                 ),+ ] as &[_]
             )
         ),* ]
@@ -295,6 +461,8 @@ pub fn mnemnonics() -> hash_map::Keys<'static, &'static str, &'static [Opdata]>
 
 lazy_static! {
     static ref OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        #![allow(non_upper_case_globals)]
+
         use super::ast::Modifier::*;
         use crate::common::Size::*;
         use self::SpecialComm::*;
@@ -306,6 +474,39 @@ lazy_static! {
         const SHIFTS: &[super::ast::Modifier] = &[LSL, LSR, ASR];
         const ROTATES: &[super::ast::Modifier] = &[LSL, LSR, ASR, ROR];
 
+        // we need to reimport these as we can't use bitflags in const
+        const Ex_BASE: u64 = ExtensionFlags::Ex_BASE.bits();
+        const Ex_AES: u64 = ExtensionFlags::Ex_AES.bits();
+        const Ex_BTI: u64 = ExtensionFlags::Ex_BTI.bits();
+        const Ex_CRC: u64 = ExtensionFlags::Ex_CRC.bits();
+        const Ex_DOTPROD: u64 = ExtensionFlags::Ex_DOTPROD.bits();
+        const Ex_FCMA: u64 = ExtensionFlags::Ex_FCMA.bits();
+        const Ex_FLAGM: u64 = ExtensionFlags::Ex_FLAGM.bits();
+        const Ex_FP16: u64 = ExtensionFlags::Ex_FP16.bits();
+        const Ex_FP16FML: u64 = ExtensionFlags::Ex_FP16FML.bits();
+        const Ex_JSCVT: u64 = ExtensionFlags::Ex_JSCVT.bits();
+        const Ex_LOR: u64 = ExtensionFlags::Ex_LOR.bits();
+        const Ex_LSE: u64 = ExtensionFlags::Ex_LSE.bits();
+        const Ex_MOPS: u64 = ExtensionFlags::Ex_MOPS.bits();
+        const Ex_MTE: u64 = ExtensionFlags::Ex_MTE.bits();
+        const Ex_PAUTH: u64 = ExtensionFlags::Ex_PAUTH.bits();
+        const Ex_PREDRES: u64 = ExtensionFlags::Ex_PREDRES.bits();
+        const Ex_RAS: u64 = ExtensionFlags::Ex_RAS.bits();
+        const Ex_RCPC: u64 = ExtensionFlags::Ex_RCPC.bits();
+        const Ex_RCPC2: u64 = ExtensionFlags::Ex_RCPC2.bits();
+        const Ex_RDM: u64 = ExtensionFlags::Ex_RDM.bits();
+        const Ex_SB: u64 = ExtensionFlags::Ex_SB.bits();
+        const Ex_SHA2: u64 = ExtensionFlags::Ex_SHA2.bits();
+        const Ex_SHA3: u64 = ExtensionFlags::Ex_SHA3.bits();
+        const Ex_SM4: u64 = ExtensionFlags::Ex_SM4.bits();
+        const Ex_SME: u64 = ExtensionFlags::Ex_SME.bits();
+        const Ex_SME_F64F64: u64 = ExtensionFlags::Ex_SME_F64F64.bits();
+        const Ex_SME_I16I64: u64 = ExtensionFlags::Ex_SME_I16I64.bits();
+        const Ex_SPE: u64 = ExtensionFlags::Ex_SPE.bits();
+        const Ex_SVE: u64 = ExtensionFlags::Ex_SVE.bits();
+        const Ex_SVE2: u64 = ExtensionFlags::Ex_SVE2.bits();
+        const Ex_TRF: u64 = ExtensionFlags::Ex_TRF.bits();
+
         static MAP: &[(&str, &[Opdata])] = &include!("opmap.rs");
         MAP.iter().cloned().collect()
     };
@@ -395,6 +596,28 @@ lazy_static! {
             ];
             MAP.iter().cloned().collect()
         });
+        mapmap.insert("SVE_PATTERNS", {
+            static MAP: &[(&str, u32)] = &[
+                ("pow2",  0b00000),
+                ("vl1",   0b00001),
+                ("vl2",   0b00010),
+                ("vl3",   0b00011),
+                ("vl4",   0b00100),
+                ("vl5",   0b00101),
+                ("vl6",   0b00110),
+                ("vl7",   0b00111),
+                ("vl8",   0b01000),
+                ("vl16",  0b01001),
+                ("vl32",  0b01010),
+                ("vl64",  0b01011),
+                ("vl128", 0b01100),
+                ("vl256", 0b01101),
+                ("mul4",  0b11101),
+                ("mul3",  0b11110),
+                ("all",   0b11111),
+            ];
+            MAP.iter().cloned().collect()
+        });
         mapmap.insert("MSR_IMM_OPS", {
             static MAP: &[(&str, u32)] = &[
                 ("spsel",   0b00_0010_0000_0101),
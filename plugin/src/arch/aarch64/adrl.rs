@@ -0,0 +1,103 @@
+//! Implementation of the `adrl Xd, label` and `ldr Rt, [label]` pseudo-instructions, which address a label
+//! up to 4GiB away using an `adrp` instruction followed by an `add` or load that supplies the low 12 bits.
+//! Both instructions are patched by a single relocation, which also allows `extern` targets.
+
+use proc_macro_error2::emit_error;
+use quote::quote_spanned;
+
+use super::Context;
+use super::ast::{Instruction, RawArg, Register, RegKind, RegId, RegFamily};
+use crate::common::{Size, Stmt, JumpKind, delimited};
+
+// the relocation id of an adrp pair relocation whose second instruction scales its offset by 1 << scale.
+// extern targets use a different id, as they are relative to an absolute address
+fn relocation_id(scale: u8, bare: bool) -> u8 {
+    16 + if bare { 8 } else { 0 } + scale
+}
+
+// integer loads that can be used with a label reference. other instructions get an error while matching
+const LOADS: [&str; 6] = ["ldr", "ldrb", "ldrh", "ldrsb", "ldrsh", "ldrsw"];
+
+/// Returns true if this instruction is the `adrl Xd, label` pseudo-instruction, or a load from a label
+pub(super) fn is_adrp_pair(instruction: &Instruction, args: &[RawArg]) -> bool {
+    instruction.ident == "adrl" || (
+        LOADS.iter().any(|&load| instruction.ident == load) &&
+        matches!(args, [_, RawArg::LabelReference { .. }])
+    )
+}
+
+/// Compiles `adrl Xd, label` into `adrp Xd, label` + `add Xd, Xd, #lo12(label)`, and `ldr Rt, [label]`
+/// (and the other unsigned offset integer loads) into `adrp Xt, label` + `ldr Rt, [Xt, #lo12(label)]`.
+pub(super) fn compile_adrp_pair(ctx: &mut Context, instruction: &Instruction, args: Vec<RawArg>) -> Result<(), Option<String>> {
+    let span = instruction.span;
+    let mnemonic = instruction.ident.to_string();
+    let mut args = args.into_iter();
+
+    let (reg_span, reg, jump) = match (args.next(), args.next(), args.next()) {
+        (Some(RawArg::Direct { span, reg: Register::Scalar(reg) }), Some(RawArg::JumpTarget { jump }), None) if mnemonic == "adrl" => (span, reg, jump),
+        (Some(RawArg::Direct { span, reg: Register::Scalar(reg) }), Some(RawArg::LabelReference { jump, .. }), None) if mnemonic != "adrl" => (span, reg, jump),
+        _ if mnemonic == "adrl" => return Err(Some("adrl expects an X register and a label".into())),
+        _ => return Err(Some(format!("{} from a label expects a W or X register", mnemonic)))
+    };
+
+    if reg.kind.family() != RegFamily::INTEGER {
+        emit_error!(reg_span, "{} from a label can only target W or X registers", mnemonic);
+        return Err(None);
+    }
+
+    // the register is also used as the base address, so it cannot be the zero register
+    if let RegKind::Static(RegId::XZR) = reg.kind {
+        emit_error!(reg_span, "{} from a label cannot target the zero register", mnemonic);
+        return Err(None);
+    }
+
+    // unsigned offset forms of the second instruction, with the scale of their offsets
+    let (template, scale) = match (mnemonic.as_str(), reg.size) {
+        ("adrl", Size::B_8) => (0x9100_0000u32, 0),
+        ("ldrb", Size::B_4) => (0x3940_0000, 0),
+        ("ldrsb", Size::B_4) => (0x39C0_0000, 0),
+        ("ldrsb", Size::B_8) => (0x3980_0000, 0),
+        ("ldrh", Size::B_4) => (0x7940_0000, 1),
+        ("ldrsh", Size::B_4) => (0x79C0_0000, 1),
+        ("ldrsh", Size::B_8) => (0x7980_0000, 1),
+        ("ldr", Size::B_4) => (0xB940_0000, 2),
+        ("ldrsw", Size::B_8) => (0xB980_0000, 2),
+        ("ldr", Size::B_8) => (0xF940_0000, 3),
+        ("adrl", _) => {
+            emit_error!(reg_span, "adrl can only target X registers");
+            return Err(None);
+        },
+        _ => return Err(Some(format!("{} with this register cannot be used to load from a label", mnemonic)))
+    };
+
+    let bare = matches!(jump.kind, JumpKind::Bare(_));
+
+    match reg.kind {
+        RegKind::Static(id) => {
+            let code = u32::from(id.code());
+            ctx.state.stmts.push(Stmt::Const(u64::from(0x9000_0000 | code), Size::B_4));
+            ctx.state.stmts.push(Stmt::Const(u64::from(template | code << 5 | code), Size::B_4));
+        },
+        RegKind::Dynamic(_, expr) => {
+            let expr = delimited(expr);
+            ctx.state.stmts.push(Stmt::ExprUnsigned(delimited(quote_spanned!{ span=>
+                0x9000_0000u32 | {
+                    let _dyn_reg: u8 = #expr.into();
+                    u32::from(_dyn_reg & 0x1F)
+                }
+            }), Size::B_4));
+            ctx.state.stmts.push(Stmt::ExprUnsigned(delimited(quote_spanned!{ span=>
+                #template | {
+                    let _dyn_reg: u8 = #expr.into();
+                    let _dyn_reg = u32::from(_dyn_reg & 0x1F);
+                    _dyn_reg << 5 | _dyn_reg
+                }
+            }), Size::B_4));
+        }
+    }
+
+    // a single relocation covering both instructions, calculated relative to the page of the adrp
+    ctx.state.stmts.push(jump.encode(8, 8, &[relocation_id(scale, bare)]));
+
+    Ok(())
+}
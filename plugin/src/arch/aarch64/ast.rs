@@ -7,7 +7,10 @@ use crate::common::{Size, Jump};
 #[derive(Debug, Clone)]
 pub enum Register {
     Scalar(RegScalar),
-    Vector(RegVector)
+    Vector(RegVector),
+    Scalable(RegScalable),
+    Predicate(RegPredicate),
+    Matrix(RegMatrix)
 }
 
 /// A vcalar register. Can be either of the integer or simd families. 
@@ -26,6 +29,46 @@ pub struct RegVector {
     pub element: Option<syn::Expr>
 }
 
+/// A scalable vector register. Can only be of the sve family
+#[derive(Debug, Clone)]
+pub struct RegScalable {
+    pub kind: RegKind,
+    pub element_size: Option<Size>,
+    pub element: Option<syn::Expr>
+}
+
+/// A predicate register. Can only be of the predicate family
+#[derive(Debug, Clone)]
+pub struct RegPredicate {
+    pub kind: RegKind,
+    pub element_size: Option<Size>,
+    pub qualifier: Option<PredicateQualifier>
+}
+
+/// A ZA matrix register access. Either the whole ZA array (za), a tile (za0.s),
+/// a horizontal or vertical tile slice (za0h.s[w12, 0]) or an array vector (za[w12, 0])
+#[derive(Debug, Clone)]
+pub struct RegMatrix {
+    pub kind: RegKind,
+    pub element_size: Option<Size>,
+    pub direction: Option<SliceDirection>,
+    pub slice: Option<Box<(RegKind, syn::Expr)>>
+}
+
+/// The direction of a ZA tile slice
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceDirection {
+    Horizontal,
+    Vertical
+}
+
+/// The /z or /m qualifier of a governing predicate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PredicateQualifier {
+    Zeroing,
+    Merging
+}
+
 // Register id without indication of its usage. Either a static Regid or a family identifier + expression to choose the register
 #[derive(Debug, Clone)]
 pub enum RegKind {
@@ -61,15 +104,44 @@ pub enum RegId {
     V16= 0x50, V17= 0x51, V18= 0x52, V19= 0x53,
     V20= 0x54, V21= 0x55, V22= 0x56, V23= 0x57,
     V24= 0x58, V25= 0x59, V26= 0x5A, V27= 0x5B,
-    V28= 0x5C, V29= 0x5D, V30= 0x5E, V31= 0x5F
+    V28= 0x5C, V29= 0x5D, V30= 0x5E, V31= 0x5F,
+
+    // scalable vector registers. Their element size is determined by their usage.
+    Z0 = 0x60, Z1 = 0x61, Z2 = 0x62, Z3 = 0x63,
+    Z4 = 0x64, Z5 = 0x65, Z6 = 0x66, Z7 = 0x67,
+    Z8 = 0x68, Z9 = 0x69, Z10= 0x6A, Z11= 0x6B,
+    Z12= 0x6C, Z13= 0x6D, Z14= 0x6E, Z15= 0x6F,
+    Z16= 0x70, Z17= 0x71, Z18= 0x72, Z19= 0x73,
+    Z20= 0x74, Z21= 0x75, Z22= 0x76, Z23= 0x77,
+    Z24= 0x78, Z25= 0x79, Z26= 0x7A, Z27= 0x7B,
+    Z28= 0x7C, Z29= 0x7D, Z30= 0x7E, Z31= 0x7F,
+
+    // predicate registers.
+    P0 = 0x80, P1 = 0x81, P2 = 0x82, P3 = 0x83,
+    P4 = 0x84, P5 = 0x85, P6 = 0x86, P7 = 0x87,
+    P8 = 0x88, P9 = 0x89, P10= 0x8A, P11= 0x8B,
+    P12= 0x8C, P13= 0x8D, P14= 0x8E, P15= 0x8F,
+
+    // ZA tiles. The amount of tiles available depends on the element size they are used with.
+    ZA0 = 0xA0, ZA1 = 0xA1, ZA2 = 0xA2, ZA3 = 0xA3,
+    ZA4 = 0xA4, ZA5 = 0xA5, ZA6 = 0xA6, ZA7 = 0xA7,
+    ZA8 = 0xA8, ZA9 = 0xA9, ZA10= 0xAA, ZA11= 0xAB,
+    ZA12= 0xAC, ZA13= 0xAD, ZA14= 0xAE, ZA15= 0xAF,
+
+    // the complete ZA array.
+    ZA = 0xBF
 }
 
 // register family. INTEGER = Xn/Wn including XZR/WZR. INTEGERSP is just SP or XSP. SIMD = Bn/Hn/Sn/Dn/Qn
+// SVE = Zn, PREDICATE = Pn, MATRIX = ZA and its tiles
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum RegFamily {
     INTEGER   = 0,
     INTEGERSP = 1,
     SIMD      = 2,
+    SVE       = 3,
+    PREDICATE = 4,
+    MATRIX    = 5,
 }
 
 impl RegId {
@@ -84,6 +156,9 @@ impl RegId {
             0 => RegFamily::INTEGER,
             1 => RegFamily::INTEGERSP,
             2 => RegFamily::SIMD,
+            3 => RegFamily::SVE,
+            4 => RegFamily::PREDICATE,
+            5 => RegFamily::MATRIX,
             _ => unreachable!()
         }
     }
@@ -163,25 +238,43 @@ impl RegVector {
     }
 }
 
+impl RegMatrix {
+    /// Returns true if this refers to the complete ZA array instead of one of its tiles
+    pub fn is_array(&self) -> bool {
+        matches!(self.kind, RegKind::Static(RegId::ZA))
+    }
+}
+
 impl Register {
-    pub fn size(&self) -> Size {
+    /// Returns the size of this register, or of its elements for vector registers.
+    /// Scalable vector and predicate registers without an element size specifier return None
+    pub fn size(&self) -> Option<Size> {
         match self {
-            Register::Scalar(s) => s.size(),
-            Register::Vector(v) => v.element_size()
+            Register::Scalar(s) => Some(s.size()),
+            Register::Vector(v) => Some(v.element_size()),
+            Register::Scalable(z) => z.element_size,
+            Register::Predicate(p) => p.element_size,
+            Register::Matrix(m) => m.element_size,
         }
     }
 
     pub fn kind(&self) -> &RegKind {
         match self {
             Register::Scalar(s) => &s.kind,
-            Register::Vector(v) => &v.kind
+            Register::Vector(v) => &v.kind,
+            Register::Scalable(z) => &z.kind,
+            Register::Predicate(p) => &p.kind,
+            Register::Matrix(m) => &m.kind,
         }
     }
 
     pub fn kind_owned(self) -> RegKind {
         match self {
             Register::Scalar(s) => s.kind,
-            Register::Vector(v) => v.kind
+            Register::Vector(v) => v.kind,
+            Register::Scalable(z) => z.kind,
+            Register::Predicate(p) => p.kind,
+            Register::Matrix(m) => m.kind,
         }
     }
 
@@ -189,13 +282,16 @@ impl Register {
         match self {
             Register::Scalar(s) => s.kind.family(),
             Register::Vector(_) => RegFamily::SIMD,
+            Register::Scalable(_) => RegFamily::SVE,
+            Register::Predicate(_) => RegFamily::PREDICATE,
+            Register::Matrix(_) => RegFamily::MATRIX,
         }
     }
 
     pub fn assume_vector(&self) -> &RegVector {
         match self {
-            Register::Scalar(_) => panic!("That wasn't a vector register"),
-            Register::Vector(v) => v
+            Register::Vector(v) => v,
+            _ => panic!("That wasn't a vector register")
         }
     }
 }
@@ -219,6 +315,8 @@ pub enum Modifier {
     UXTH,
     UXTB,
     MSL,
+    MUL,
+    MULVL,
 }
 
 impl Modifier {
@@ -237,6 +335,8 @@ impl Modifier {
             Modifier::UXTH => "UXTH",
             Modifier::UXTB => "UXTB",
             Modifier::MSL => "MSL",
+            Modifier::MUL => "MUL",
+            Modifier::MULVL => "MUL VL",
         }
     }
 
@@ -246,7 +346,8 @@ impl Modifier {
             | Modifier::LSR
             | Modifier::ASR
             | Modifier::ROR
-            | Modifier::MSL => true,
+            | Modifier::MSL
+            | Modifier::MUL => true,
             Modifier::SXTX
             | Modifier::SXTW
             | Modifier::SXTH
@@ -254,7 +355,8 @@ impl Modifier {
             | Modifier::UXTX
             | Modifier::UXTW
             | Modifier::UXTH
-            | Modifier::UXTB => false,
+            | Modifier::UXTB
+            | Modifier::MULVL => false,
         }
     }
 }
@@ -324,10 +426,25 @@ pub enum RawArg {
         span: Span,
         reg: Register
     },
+    // register with writeback, as used by the MOPS instructions (Xn!)
+    Writeback {
+        span: Span,
+        reg: Register
+    },
     // jump target. Also used by PC-rel loads etc
     JumpTarget {
         jump: Jump
     },
+    // a memory reference to a label ([label]), loaded from using an adrp pair
+    LabelReference {
+        span: Span,
+        jump: Jump
+    },
+    // a value or label address to be placed in the literal pool (=expr or =label)
+    LitPool {
+        span: Span,
+        target: LitPoolTarget
+    },
     // just an arbitrary expression
     Immediate {
         prefixed: bool,
@@ -346,6 +463,13 @@ pub enum RawArg {
     }
 }
 
+// The contents of a literal pool entry
+#[derive(Debug)]
+pub enum LitPoolTarget {
+    Value(syn::Expr),
+    Label(Jump)
+}
+
 // Contains the actual instruction mnemnonic.
 #[derive(Debug)]
 pub struct Instruction {
@@ -357,8 +481,11 @@ pub struct Instruction {
 pub enum RefKind {
     Base,
     Offset(syn::Expr),
-    Indexed(Register, Option<ModifyExpr>),
+    OffsetMulVl(syn::Expr),
+    // the index register is boxed to keep references small
+    Indexed(Box<Register>, Option<ModifyExpr>),
     PreIndexed(syn::Expr),
+    Writeback,
 }
 
 // sanitized parse results
@@ -379,6 +506,20 @@ pub enum CleanArg {
         span: Span,
         reg: Register
     },
+    Writeback {
+        span: Span,
+        reg: Register
+    },
+    // a list of ZA tiles, canonicalized to the mask of 64-bit tiles it covers
+    TileList {
+        span: Span,
+        mask: u8
+    },
+    // a list containing a single ZA tile slice
+    SliceList {
+        span: Span,
+        slice: RegMatrix
+    },
     JumpTarget {
         jump: Jump
     },
@@ -69,6 +69,43 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                     }
                     statics.push((offset, u32::from(id.code())));
                 },
+                Command::R3(offset) => {
+                    if id.code() >= 8 {
+                        emit_error!(span, "Field only supports register numbers 0-7");
+                        return Err(None);
+                    }
+                    statics.push((offset, u32::from(id.code())));
+                },
+                Command::RTile(offset, bitlen) => {
+                    if id.code() >= (1 << bitlen) {
+                        emit_error!(span, "Field only supports ZA tiles 0-{}", (1 << bitlen) - 1);
+                        return Err(None);
+                    }
+                    statics.push((offset, u32::from(id.code())));
+                },
+                Command::RSelect(offset) => {
+                    if !(12 ..= 15).contains(&id.code()) {
+                        emit_error!(span, "Field only supports slice index registers W12-W15");
+                        return Err(None);
+                    }
+                    statics.push((offset, u32::from(id.code() - 12)));
+                },
+                Command::RTied => {
+                    if let Some(FlatArg::Direct { span: _firstspan, reg: ref firstreg } ) = data.args.first() {
+                        match firstreg {
+                            RegKind::Static(firstid) => if id.code() != firstid.code() {
+                                emit_error!(span, "Invalid register. This register has to be the same register as the first argument.");
+                                return Err(None);
+                            },
+                            RegKind::Dynamic(_, _) => {
+                                emit_error!(span, "Invalid register. This register has to be the same register as the first argument, which is dynamic.");
+                                return Err(None);
+                            }
+                        }
+                    } else {
+                        panic!("RTied command without the first command being a register encoder");
+                    }
+                },
                 Command::RNext => {
                     if let Some(FlatArg::Direct { span: _prevspan, reg: ref prevreg } ) = data.args.get(cursor - 1) {
                         match prevreg {
@@ -116,6 +153,43 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                         }
                     }));
                 },
+                Command::R3(offset) => {
+                    let expr = delimited(expr);
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        {
+                            let _dyn_reg: u8 = #expr.into();
+                            _dyn_reg & 0x7
+                        }
+                    }));
+                },
+                Command::RTile(offset, bitlen) => {
+                    let expr = delimited(expr);
+                    let mask = (1u8 << bitlen) - 1;
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        {
+                            let _dyn_reg: u8 = #expr.into();
+                            _dyn_reg & #mask
+                        }
+                    }));
+                },
+                Command::RSelect(offset) => {
+                    // W12-W15 only differ in their bottom two bits
+                    let expr = delimited(expr);
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        {
+                            let _dyn_reg: u8 = #expr.into();
+                            _dyn_reg & 0x3
+                        }
+                    }));
+                },
+                Command::RTied => {
+                    // the tied register is encoded by the first argument. As we cannot check dynamic
+                    // registers at compile time, we only require the first argument to be dynamic as well.
+                    if let Some(FlatArg::Direct { span: _firstspan, reg: RegKind::Static(_) } ) = data.args.first() {
+                        emit_error!(span, "Invalid register. This register has to be the same register as the first argument, which is static.");
+                        return Err(None);
+                    }
+                },
                 Command::RNext => {
                     emit_error!(span, "This register is constrained to be the register after the previous argument's register. As such, it does not support dynamic registers. Please substitute it with XZR to indicate this");
                     return Err(None);
@@ -231,6 +305,18 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                             emit_error!(value, "Invalid immediate '{}'", number);
                             return Err(None);
                         }
+                    } else if let syn::Expr::Const(_) = value {
+                        // constant blocks, like the shift of a type mapped index, are checked at compile time
+                        let checks = options.iter().enumerate().rev().map(|(i, &n)| {
+                            let (i, n) = (i as u32, u32::from(n));
+                            quote! { if _imm == #n { #i } else }
+                        });
+                        dynamics.push((offset, quote_spanned!{ value.span()=>
+                            const {
+                                let _imm: u32 = #value;
+                                #(#checks)* { ::std::panic!("Cannot assemble this Aarch64 instruction. Constant immediate is out of range.") }
+                            }
+                        }));
                     } else {
                         dynamics.push((offset, quote_spanned!{ value.span()=>
                             {
@@ -450,6 +536,27 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                         }));
                     }
                 },
+                Command::CUeq(back) => {
+                    let prev_value = if let Some(FlatArg::Immediate {value: prev_value } ) = data.args.get(cursor - usize::from(back)) {
+                        prev_value
+                    } else {
+                        panic!("Bad encoding data, tied argument was not an immediate");
+                    };
+
+                    match (as_unsigned_number(value), as_unsigned_number(prev_value)) {
+                        (Some(number), Some(prev_number)) => if number != prev_number {
+                            emit_error!(value, "Immediate has to be equal to {}", prev_number);
+                            return Err(None);
+                        },
+                        _ => dynamics.push((0, quote_spanned! { value.span()=>
+                            {
+                                let _dyn_imm: u32 = #value;
+                                if _dyn_imm != #prev_value { ::dynasmrt::aarch64::immediate_out_of_range_unsigned_32(_dyn_imm); }
+                                0
+                            }
+                        }))
+                    }
+                },
                 Command::CSscaled(bitlen, shift) => {
                     let mask = bitmask(bitlen);
                     let half = -1i32 << (bitlen - 1);
@@ -600,6 +707,30 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                 Command::Sscaled(_, _, _) |
                 Command::Sslice(_, _, _) => (),
 
+                // tied immediates have to be zero as well
+                Command::CUeq(back) => {
+                    let prev_value = if let Some(FlatArg::Immediate {value: prev_value } ) = data.args.get(cursor - usize::from(back)) {
+                        prev_value
+                    } else {
+                        panic!("Bad encoding data, tied argument was not an immediate");
+                    };
+
+                    match as_unsigned_number(prev_value) {
+                        Some(0) => (),
+                        Some(_) => {
+                            emit_error!(prev_value, "Immediate has to be 0 as the tied immediate was omitted");
+                            return Err(None);
+                        },
+                        None => dynamics.push((0, quote_spanned! { prev_value.span()=>
+                            {
+                                let _dyn_imm: u32 = #prev_value;
+                                if _dyn_imm != 0 { ::dynasmrt::aarch64::immediate_out_of_range_unsigned_32(_dyn_imm); }
+                                0
+                            }
+                        }))
+                    }
+                },
+
                 // integer checks don't have anything to check
                 Command::CUbits(_) |
                 Command::CSscaled(_, _) => (),
@@ -649,7 +780,8 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
             Command::CUbits(_) |
             Command::CUsum(_) |
             Command::CSscaled(_, _) |
-            Command::CUrange(_, _) => (),
+            Command::CUrange(_, _) |
+            Command::CUeq(_) => (),
             _ => cursor += 1
         }
     }
@@ -1,5 +1,5 @@
 use super::ast::Modifier;
-use super::aarch64data::{Opdata, Matcher, Command, Relocation, SpecialComm};
+use super::aarch64data::{Opdata, Matcher, Command, Relocation, SpecialComm, ExtensionFlags};
 use crate::common::Size;
 
 use std::fmt::Write;
@@ -139,11 +139,32 @@ pub fn format_opdata(name: &str, data: &Opdata) -> Vec<String> {
                 },
                 Matcher::RegListStatic(a, s, c) => write!(buf, "{{V{}.{}{} * {}}}", arg_names[0], size_to_string(*s), c, a).unwrap(),
                 Matcher::RegListElement(a, s) =>   write!(buf, "{{V{}.{} * {}}}[{}]", arg_names[0], size_to_string(*s), a, arg_names[1]).unwrap(),
+                Matcher::Z(s) => write!(buf, "Z{}.{}", arg_names[0], size_to_string(*s)).unwrap(),
+                Matcher::ZBare => write!(buf, "Z{}", arg_names[0]).unwrap(),
+                Matcher::ZElement(s) => write!(buf, "Z{}.{}[{}]", arg_names[0], size_to_string(*s), arg_names[1]).unwrap(),
+                Matcher::ZRegList(a, s) => write!(buf, "{{Z{}.{} * {}}}", arg_names[0], size_to_string(*s), a).unwrap(),
+                Matcher::P(s) => write!(buf, "P{}.{}", arg_names[0], size_to_string(*s)).unwrap(),
+                Matcher::PBare => write!(buf, "P{}", arg_names[0]).unwrap(),
+                Matcher::PZero => write!(buf, "P{}/Z", arg_names[0]).unwrap(),
+                Matcher::PMerge => write!(buf, "P{}/M", arg_names[0]).unwrap(),
+                Matcher::XWriteback => write!(buf, "X{}!", arg_names[0]).unwrap(),
+                Matcher::ZaArray => buf.push_str("ZA"),
+                Matcher::ZaVector => write!(buf, "ZA[W{}, {}]", arg_names[0], arg_names[1]).unwrap(),
+                Matcher::ZaTile(s) => write!(buf, "ZA{}.{}", arg_names[0], size_to_string(*s)).unwrap(),
+                Matcher::ZaSliceH(s) => write!(buf, "ZA{}H.{}[W{}, {}]", arg_names[0], size_to_string(*s), arg_names[1], arg_names[2]).unwrap(),
+                Matcher::ZaSliceV(s) => write!(buf, "ZA{}V.{}[W{}, {}]", arg_names[0], size_to_string(*s), arg_names[1], arg_names[2]).unwrap(),
+                Matcher::ZaTileList => buf.push_str("{ZA tiles}"),
+                Matcher::ZaSliceListH(s) => write!(buf, "{{ZA{}H.{}[W{}, {}]}}", arg_names[0], size_to_string(*s), arg_names[1], arg_names[2]).unwrap(),
+                Matcher::ZaSliceListV(s) => write!(buf, "{{ZA{}V.{}[W{}, {}]}}", arg_names[0], size_to_string(*s), arg_names[1], arg_names[2]).unwrap(),
                 Matcher::Offset => buf.push_str(&arg_names[0]),
                 Matcher::RefBase =>   write!(buf, "[X{}|SP]", arg_names[0]).unwrap(),
                 Matcher::RefOffset => write!(buf, "[X{}|SP {{, #{} }} ]", arg_names[0], arg_names[1]).unwrap(),
                 Matcher::RefPre =>    write!(buf, "[X{}|SP, #{}]!", arg_names[0], arg_names[1]).unwrap(),
                 Matcher::RefIndex =>  write!(buf, "[X{}|SP, W{}|X{} {{ , UXTW|LSL|SXTW|SXTX {{ #{} }} }} ]", arg_names[0], arg_names[1], arg_names[1], arg_names[3]).unwrap(),
+                Matcher::RefMulVl =>  write!(buf, "[X{}|SP {{, #{}, MUL VL }} ]", arg_names[0], arg_names[1]).unwrap(),
+                Matcher::RefIndexLsl(0) => write!(buf, "[X{}|SP, X{}]", arg_names[0], arg_names[1]).unwrap(),
+                Matcher::RefIndexLsl(shift) => write!(buf, "[X{}|SP, X{}, LSL #{}]", arg_names[0], arg_names[1], shift).unwrap(),
+                Matcher::RefWriteback => write!(buf, "[X{}]!", arg_names[0]).unwrap(),
                 Matcher::LitMod(m) => {
                     buf.push_str(m.as_str());
                     if !m.expr_required() {
@@ -160,7 +181,7 @@ pub fn format_opdata(name: &str, data: &Opdata) -> Vec<String> {
                     for m in *mods {
                         required = required || m.expr_required();
                         match m {
-                            Modifier::LSL | Modifier::LSR | Modifier::ASR | Modifier::ROR | Modifier::MSL => rest.push(m.as_str()),
+                            Modifier::LSL | Modifier::LSR | Modifier::ASR | Modifier::ROR | Modifier::MSL | Modifier::MUL | Modifier::MULVL => rest.push(m.as_str()),
                             Modifier::SXTX | Modifier::SXTW | Modifier::SXTH | Modifier::SXTB => signed_extends.push(m.as_str().chars().nth(3).unwrap()),
                             Modifier::UXTX | Modifier::UXTW | Modifier::UXTH | Modifier::UXTB => unsigned_extends.push(m.as_str().chars().nth(3).unwrap()),
                         }
@@ -198,7 +219,12 @@ pub fn format_opdata(name: &str, data: &Opdata) -> Vec<String> {
             buf.push_str(" }");
         }
 
-        if let Some(c) = constraints {
+        let annotations = match (constraints, format_features(data)) {
+            (Some(c), Some(f)) => Some(format!("{} {}", c, f)),
+            (c, f) => c.or(f)
+        };
+
+        if let Some(c) = annotations {
             let mut len = c.len() + buf.len();
             while len < 100 {
                 buf.push(' ');
@@ -213,6 +239,15 @@ pub fn format_opdata(name: &str, data: &Opdata) -> Vec<String> {
     forms
 }
 
+fn format_features(data: &Opdata) -> Option<String> {
+    if data.ext_flags.contains(&ExtensionFlags::Ex_BASE) {
+        return None;
+    }
+
+    let items = data.ext_flags.iter().map(|f| f.to_string()).collect::<Vec<_>>();
+    Some(format!("(requires {})", items.join(" or ")))
+}
+
 pub fn size_to_string(size: Size) -> &'static str {
     match size {
         Size::BYTE => "B",
@@ -309,8 +344,36 @@ fn flatten_matchers(matchers: &[Matcher]) -> Vec<(FlatArgTy, bool)> {
                 args.push((FlatArgTy::Direct, default));
                 args.push((FlatArgTy::Immediate, default));
             },
+            Matcher::Z(_)
+            | Matcher::ZBare
+            | Matcher::ZRegList(_, _)
+            | Matcher::P(_)
+            | Matcher::PBare
+            | Matcher::PZero
+            | Matcher::PMerge
+            | Matcher::XWriteback => args.push((FlatArgTy::Direct, default)),
+            Matcher::ZaArray => (),
+            Matcher::ZaVector => {
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Immediate, default));
+            },
+            Matcher::ZaTile(_) => args.push((FlatArgTy::Direct, default)),
+            Matcher::ZaTileList => args.push((FlatArgTy::Immediate, default)),
+            Matcher::ZaSliceH(_)
+            | Matcher::ZaSliceV(_)
+            | Matcher::ZaSliceListH(_)
+            | Matcher::ZaSliceListV(_) => {
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Immediate, default));
+            },
+            Matcher::ZElement(_) => {
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Immediate, default));
+            },
             Matcher::Offset => args.push((FlatArgTy::JumpTarget, default)),
-            Matcher::RefBase => args.push((FlatArgTy::Direct, default)),
+            Matcher::RefBase
+            | Matcher::RefWriteback => args.push((FlatArgTy::Direct, default)),
             Matcher::RefOffset => {
                 args.push((FlatArgTy::Direct, default));
                 args.push((FlatArgTy::Immediate, true));
@@ -325,6 +388,14 @@ fn flatten_matchers(matchers: &[Matcher]) -> Vec<(FlatArgTy, bool)> {
                 args.push((FlatArgTy::Modifier, true));
                 args.push((FlatArgTy::Immediate, true));
             },
+            Matcher::RefMulVl => {
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Immediate, true));
+            },
+            Matcher::RefIndexLsl(_) => {
+                args.push((FlatArgTy::Direct, default));
+                args.push((FlatArgTy::Direct, default));
+            },
             Matcher::LitMod(_) => {
                 args.push((FlatArgTy::Immediate, true));
             },
@@ -364,8 +435,12 @@ fn group_commands(commands: &[Command]) -> (usize, Vec<(Command, usize)>) {
             Command::R(_)
             | Command::REven(_)
             | Command::R4(_)
+            | Command::R3(_)
             | Command::RNoZr(_)
             | Command::RNext
+            | Command::RTied
+            | Command::RTile(_, _)
+            | Command::RSelect(_)
             | Command::Ubits(_, _)
             | Command::Uscaled(_, _, _)
             | Command::Ulist(_, _)
@@ -404,8 +479,12 @@ fn check_command_sanity(args: &[ArgWithCommands]) -> Result<(), &'static str> {
                 Command::R(_)
                 | Command::REven(_)
                 | Command::R4(_)
+                | Command::R3(_)
                 | Command::RNoZr(_)
-                | Command::RNext => arg.arg == FlatArgTy::Direct,
+                | Command::RNext
+                | Command::RTied
+                | Command::RTile(_, _)
+                | Command::RSelect(_) => arg.arg == FlatArgTy::Direct,
                 Command::Ubits(_, _)
                 | Command::Uscaled(_, _, _)
                 | Command::Ulist(_, _)
@@ -421,6 +500,7 @@ fn check_command_sanity(args: &[ArgWithCommands]) -> Result<(), &'static str> {
                 | Command::CUsum(_)
                 | Command::CSscaled(_, _)
                 | Command::CUrange(_, _)
+                | Command::CUeq(_)
                 | Command::Uslice(_, _, _)
                 | Command::Sslice(_, _, _)
                 | Command::Special(_, _) => arg.arg == FlatArgTy::Immediate,
@@ -454,13 +534,18 @@ fn check_command_sanity(args: &[ArgWithCommands]) -> Result<(), &'static str> {
                 | Command::CUbits(_)
                 | Command::CUsum(_)
                 | Command::CSscaled(_, _)
+                | Command::CUeq(_)
                 | Command::Rotates(_)
                 | Command::ExtendsW(_)
                 | Command::ExtendsX(_) => true,
                 Command::R4(_)
+                | Command::R3(_)
                 | Command::RNoZr(_)
                 | Command::REven(_)
                 | Command::RNext
+                | Command::RTied
+                | Command::RTile(_, _)
+                | Command::RSelect(_)
                 | Command::Usubone(_, _)
                 | Command::Usubzero(_, _)
                 | Command::Usubmod(_, _)
@@ -488,7 +573,7 @@ fn check_command_sanity(args: &[ArgWithCommands]) -> Result<(), &'static str> {
 /// assign names to the args being used
 fn name_args(args: &mut [ArgWithCommands]) {
     // iirc no op uses more than 4 unconstrained literals / immediates
-    let reg_name_list = ["n", "m", "a", "b"];
+    let reg_name_list = ["n", "m", "a", "b", "c", "d"];
     let mut reg_name_idx = 0;
     let imm_name_list = ["", "1", "2", "3"];
     let mut imm_name_idx = 0;
@@ -500,13 +585,19 @@ fn name_args(args: &mut [ArgWithCommands]) {
                     Command::R(_)
                     | Command::REven(_)
                     | Command::RNoZr(_)
-                    | Command::R4(_) => {
+                    | Command::R4(_)
+                    | Command::R3(_)
+                    | Command::RTile(_, _)
+                    | Command::RSelect(_) => {
                         arg.name = Some(reg_name_list[reg_name_idx].to_string());
                         reg_name_idx += 1;
                     },
                     Command::RNext => {
                         arg.name = Some(format!("{}+1", reg_name_list[reg_name_idx - 1]));
                     },
+                    Command::RTied => {
+                        arg.name = Some(reg_name_list[0].to_string());
+                    },
                     _ => unreachable!()
                 }
             },
@@ -542,6 +633,10 @@ fn name_args(args: &mut [ArgWithCommands]) {
                         arg.name = Some(format!("imm{}", imm_name_list[imm_name_idx]));
                         imm_name_idx += 1;
                     },
+                    // a tied immediate has the same name as the immediate it is tied to
+                    Command::CUeq(_) => {
+                        arg.name = Some(format!("uimm{}", imm_name_list[imm_name_idx - 1]));
+                    },
                     _ => unreachable!()
                 }
             },
@@ -583,6 +678,10 @@ fn emit_constraints(name: &str, prevname: &str, commands: &[Command], buf: &mut
     for command in commands {
         match command {
             Command::R4(_) => write!(buf, "{} is 0-15", name),
+            Command::R3(_) => write!(buf, "{} is 0-7", name),
+            Command::RTile(_, 0) => write!(buf, "{} is 0", name),
+            Command::RTile(_, bits) => write!(buf, "{} is 0-{}", name, (1u32 << bits) - 1),
+            Command::RSelect(_) => write!(buf, "{} is 12-15", name),
             Command::RNoZr(_) => write!(buf, "{} is 0-30", name),
             Command::REven(_) => write!(buf, "{} is even", name),
             Command::Ubits(_, bits)
@@ -701,10 +800,31 @@ pub fn extract_opdata(name: &str, data: &Opdata) -> Vec<String> {
                 },
                 Matcher::RegListStatic(a, s, c) => write!(buf, "{{<V,{}>.{}{} * {}}}", arg_idx, size_to_string(*s), c, a).unwrap(),
                 Matcher::RegListElement(a, s) =>   write!(buf, "{{<V,{}>.{} * {}}}[<Imm,{}>]", arg_idx, size_to_string(*s), a, arg_idx + 1).unwrap(),
+                Matcher::Z(s) => write!(buf, "<Z,{}>.{}", arg_idx, size_to_string(*s)).unwrap(),
+                Matcher::ZBare => write!(buf, "<Z,{}>", arg_idx).unwrap(),
+                Matcher::ZElement(s) => write!(buf, "<Z,{}>.{}[<Imm,{}>]", arg_idx, size_to_string(*s), arg_idx + 1).unwrap(),
+                Matcher::ZRegList(a, s) => write!(buf, "{{<Z,{}>.{} * {}}}", arg_idx, size_to_string(*s), a).unwrap(),
+                Matcher::P(s) => write!(buf, "<P,{}>.{}", arg_idx, size_to_string(*s)).unwrap(),
+                Matcher::PBare => write!(buf, "<P,{}>", arg_idx).unwrap(),
+                Matcher::PZero => write!(buf, "<P,{}>/Z", arg_idx).unwrap(),
+                Matcher::PMerge => write!(buf, "<P,{}>/M", arg_idx).unwrap(),
+                Matcher::XWriteback => write!(buf, "<X,{}>!", arg_idx).unwrap(),
+                Matcher::ZaArray => buf.push_str("ZA"),
+                Matcher::ZaVector => write!(buf, "ZA[<W,{}>, <Imm,{}>]", arg_idx, arg_idx + 1).unwrap(),
+                Matcher::ZaTile(s) => write!(buf, "<ZA,{}>.{}", arg_idx, size_to_string(*s)).unwrap(),
+                Matcher::ZaSliceH(s) => write!(buf, "<ZA,{}>H.{}[<W,{}>, <Imm,{}>]", arg_idx, size_to_string(*s), arg_idx + 1, arg_idx + 2).unwrap(),
+                Matcher::ZaSliceV(s) => write!(buf, "<ZA,{}>V.{}[<W,{}>, <Imm,{}>]", arg_idx, size_to_string(*s), arg_idx + 1, arg_idx + 2).unwrap(),
+                Matcher::ZaTileList => write!(buf, "{{<ZaTiles,{}>}}", arg_idx).unwrap(),
+                Matcher::ZaSliceListH(s) => write!(buf, "{{<ZA,{}>H.{}[<W,{}>, <Imm,{}>]}}", arg_idx, size_to_string(*s), arg_idx + 1, arg_idx + 2).unwrap(),
+                Matcher::ZaSliceListV(s) => write!(buf, "{{<ZA,{}>V.{}[<W,{}>, <Imm,{}>]}}", arg_idx, size_to_string(*s), arg_idx + 1, arg_idx + 2).unwrap(),
                 Matcher::Offset => write!(buf, "<Off,{}>", arg_idx).unwrap(),
                 Matcher::RefBase =>   write!(buf, "[<XSP,{}>]", arg_idx).unwrap(),
                 Matcher::RefOffset => write!(buf, "[<XSP,{}> <, <Imm,{}> > ]", arg_idx, arg_idx + 1).unwrap(),
                 Matcher::RefPre =>    write!(buf, "[<XSP,{}>, <Imm,{}>]!", arg_idx, arg_idx + 1).unwrap(),
+                Matcher::RefMulVl => write!(buf, "[<XSP,{}> <, <Imm,{}>, MUL VL > ]", arg_idx, arg_idx + 1).unwrap(),
+                Matcher::RefIndexLsl(0) => write!(buf, "[<XSP,{}>, <X,{}>]", arg_idx, arg_idx + 1).unwrap(),
+                Matcher::RefIndexLsl(shift) => write!(buf, "[<XSP,{}>, <X,{}>, LSL {}]", arg_idx, arg_idx + 1, shift).unwrap(),
+                Matcher::RefWriteback => write!(buf, "[<X,{}>]!", arg_idx).unwrap(),
                 Matcher::RefIndex => {
                     constraints.push(format!("{}: ModWX()", arg_idx + 2));
                     write!(buf, "[<XSP,{}>, <WX,{}> < , <Mod,{}> < <Imm,{}> > > ]", arg_idx, arg_idx + 1, arg_idx + 2, arg_idx + 3).unwrap();
@@ -763,6 +883,9 @@ fn extract_constraints(args: &[ArgWithCommands]) -> Vec<String> {
                 Command::REven(_) => format!("R(32, 2)"),
                 Command::RNoZr(_) => format!("R(31)"),
                 Command::R4(_) => format!("R(16)"),
+                Command::R3(_) => format!("R(8)"),
+                Command::RTile(_, bits) => format!("R({})", 1u32 << bits),
+                Command::RSelect(_) => format!("Range(12, 16, 1)"),
                 Command::RNext => format!("RNext()"),
                 Command::Ubits(_, bits)
                 | Command::CUbits(bits) => format!("Range(0, {}, 1)", 1u32 << bits),
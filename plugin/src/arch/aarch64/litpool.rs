@@ -0,0 +1,120 @@
+//! Implementation of the `ldr Rt, =value` pseudo-instruction, which loads a value or the address of a label
+//! from a literal pool that is managed by the assembler. The pool is emitted by the `.ltorg` directive, or
+//! automatically (with a branch around it) when its first reference would otherwise get out of range, at
+//! labels and alignment directives once half of its range is used up, or when the assembler commits.
+
+use proc_macro2::{Literal, TokenTree};
+use quote::quote_spanned;
+use proc_macro_error2::emit_error;
+
+use super::Context;
+use super::ast::{Instruction, RawArg, Register, RegKind, RegFamily, LitPoolTarget};
+use super::aarch64data::Relocation;
+use crate::common::{Size, Stmt, JumpKind, delimited};
+use crate::serialize;
+
+// ldr (literal) can reach 1MiB forwards
+const LITERAL_RANGE: usize = 0xF_FFFC;
+// an ldr instruction, a new 8-byte entry and its alignment padding
+const LITERAL_RESERVE: usize = 16;
+
+/// Returns true if this instruction is the `ldr Rt, =value` pseudo-instruction
+pub(super) fn is_literal_load(instruction: &Instruction, args: &[RawArg]) -> bool {
+    instruction.ident == "ldr" && matches!(args, [_, RawArg::LitPool { .. }])
+}
+
+/// Compiles `ldr Wt, =value`, `ldr Xt, =value` and the same forms with a global or dynamic label instead of a value.
+pub(super) fn compile_literal_load(ctx: &mut Context, instruction: &Instruction, args: Vec<RawArg>) -> Result<(), Option<String>> {
+    let span = instruction.span;
+    let mut args = args.into_iter();
+
+    let (reg_span, reg) = match args.next() {
+        Some(RawArg::Direct { span, reg: Register::Scalar(reg) }) => (span, reg),
+        _ => return Err(Some("ldr from a literal pool expects a W or X register".into()))
+    };
+    let target = match args.next() {
+        Some(RawArg::LitPool { target, .. }) => target,
+        _ => unreachable!()
+    };
+
+    if reg.kind.family() != RegFamily::INTEGER {
+        emit_error!(reg_span, "ldr from a literal pool can only target W or X registers");
+        return Err(None);
+    }
+
+    let (template, size) = match reg.size {
+        Size::B_8 => (0x5800_0000u32, Size::B_8),
+        Size::B_4 => (0x1800_0000u32, Size::B_4),
+        _ => unreachable!()
+    };
+
+    // validate the pool entry before emitting anything
+    let pool_size = match size {
+        Size::B_8 => quote_spanned!{ span=> ::dynasmrt::relocations::RelocationSize::QWord },
+        _ => quote_spanned!{ span=> ::dynasmrt::relocations::RelocationSize::DWord },
+    };
+    let kind = serialize::expr_tuple_of_u8s(span, &[Relocation::BCOND.to_id()]);
+    let assembler = ctx.state.target;
+
+    let reference = match target {
+        LitPoolTarget::Value(value) => {
+            let value = delimited(value);
+            if size == Size::B_8 {
+                quote_spanned!{ span=> #assembler.pool_u64((#value) as u64, 4u8, 4u8, #kind) }
+            } else {
+                quote_spanned!{ span=> #assembler.pool_u32((#value) as u32, 4u8, 4u8, #kind) }
+            }
+        },
+        LitPoolTarget::Label(jump) => {
+            let jump_span = jump.span();
+            let target_offset = match jump.offset {
+                Some(offset) => delimited(offset),
+                None => TokenTree::Literal(Literal::isize_suffixed(0))
+            };
+
+            match jump.kind {
+                JumpKind::Global(ident) => {
+                    let name = serialize::expr_string_from_ident(&ident);
+                    quote_spanned!{ span=> #assembler.pool_global_reloc(#name, #target_offset, #pool_size, 4u8, 4u8, #kind) }
+                },
+                JumpKind::Dynamic(expr) => {
+                    let expr = delimited(expr);
+                    quote_spanned!{ span=> #assembler.pool_dynamic_reloc(#expr, #target_offset, #pool_size, 4u8, 4u8, #kind) }
+                },
+                JumpKind::Forward(_)
+                | JumpKind::Backward(_) => {
+                    emit_error!(jump_span, "Local labels cannot be placed in a literal pool, as they would be resolved from the location of the pool.");
+                    return Err(None);
+                },
+                JumpKind::Bare(_) => {
+                    emit_error!(jump_span, "Extern targets cannot be placed in a literal pool.");
+                    return Err(None);
+                }
+            }
+        }
+    };
+
+    // emit the pending pool first if it would otherwise get out of range, jumping over it
+    ctx.state.stmts.push(Stmt::Stmt(quote_spanned!{ span=>
+        #assembler.pool_check(#LITERAL_RANGE, #LITERAL_RESERVE, |_distance| 0x1400_0000 | (_distance as u32 >> 2))
+    }));
+
+    // the ldr (literal) instruction itself
+    match reg.kind {
+        RegKind::Static(id) => ctx.state.stmts.push(Stmt::Const(u64::from(template | u32::from(id.code())), Size::B_4)),
+        RegKind::Dynamic(_, expr) => {
+            let expr = delimited(expr);
+            ctx.state.stmts.push(Stmt::ExprUnsigned(delimited(quote_spanned!{ span=>
+                #template | {
+                    let _dyn_reg: u8 = #expr.into();
+                    u32::from(_dyn_reg & 0x1F)
+                }
+            }), Size::B_4));
+        }
+    }
+
+    // and the reference to the pool entry
+    ctx.state.stmts.push(Stmt::Stmt(reference));
+
+    Ok(())
+}
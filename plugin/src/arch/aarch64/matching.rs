@@ -2,7 +2,7 @@ use proc_macro_error2::emit_error;
 use proc_macro2::Span;
 
 use super::Context;
-use super::ast::{Instruction, RawArg, CleanArg, FlatArg, RefItem, Register, RegFamily, RefKind, Modifier};
+use super::ast::{Instruction, RawArg, CleanArg, FlatArg, RefItem, Register, RegFamily, RegKind, RegId, RegMatrix, RefKind, Modifier, PredicateQualifier, SliceDirection};
 use super::aarch64data::{Opdata, Matcher, COND_MAP, get_mnemonic_data};
 use super::debug::format_opdata_list;
 
@@ -10,7 +10,7 @@ use crate::common::{Size, JumpKind};
 use crate::parse_helpers::{as_ident, as_unsigned_number, as_float};
 
 /// Try finding an appropriate instruction definition that matches the given instruction / arguments.
-pub(super) fn match_instruction(_ctx: &mut Context, instruction: &Instruction, args: Vec<RawArg>) -> Result<MatchData, Option<String>> {
+pub(super) fn match_instruction(ctx: &mut Context, instruction: &Instruction, args: Vec<RawArg>) -> Result<MatchData, Option<String>> {
     // sanitize our arg list to remove any structures that cannot be matched on
     let args = sanitize_args(args)?;
 
@@ -22,8 +22,18 @@ pub(super) fn match_instruction(_ctx: &mut Context, instruction: &Instruction, a
         return Err(Some(format!("Unknown instruction mnemonic '{}'", name)));
     };
 
+    // the extensions required by the first format that would have matched if its extensions were enabled
+    let mut rejected_because_features = None;
+
     // matching loop
     for data in opdata {
+        if !data.ext_flags.iter().any(|f| ctx.features.contains(*f)) {
+            if rejected_because_features.is_none() && match_args(&args, data).is_some() {
+                rejected_because_features = Some(data.ext_flags);
+            }
+            continue;
+        }
+
         if let Some(mut ctx) = match_args(&args, data) {
 
             // flatten the arg list for the encoding vm
@@ -33,13 +43,20 @@ pub(super) fn match_instruction(_ctx: &mut Context, instruction: &Instruction, a
         }
     }
 
+    if let Some(ext_flags) = rejected_because_features {
+        let required = ext_flags.iter().map(|f| format!("'{}'", f)).collect::<Vec<_>>();
+        return Err(Some(
+            format!("'{}': this instruction format requires extension {}, which is not enabled by .feature", &name, required.join(" or "))
+        ));
+    }
+
     Err(Some(
         format!("'{}': instruction format mismatch, expected one of the following forms:\n{}", &name, format_opdata_list(&name, opdata))
     ))
 }
 
 /// Sanitizes arguments, ensuring that:
-/// Register lists contain only vector registers without element specifiers
+/// Register lists contain only vector or scalable vector registers without element specifiers
 /// Vector register size specifications are possible (1B 2B 4B 8B 16B 1H 2H 4H 8H 1S 2S 4S 1D 2D)
 /// References obey the allowed formats and use only normal registers
 /// Reference modifiers are in the allowed set of modifiers
@@ -53,6 +70,10 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
                 sanitize_register(span, &reg)?;
                 res.push(CleanArg::Direct { span, reg });
             },
+            RawArg::Writeback { span, reg } => {
+                sanitize_register(span, &reg)?;
+                res.push(CleanArg::Writeback { span, reg });
+            },
             // offsets: validate that only relative jumps are allowed (no extern relocations)
             RawArg::JumpTarget { jump } => {
                 if let JumpKind::Bare(_) = jump.kind {
@@ -61,10 +82,20 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
                 }
                 res.push(CleanArg::JumpTarget { jump });
             },
-            // modifier: LSL LSR ASR ROR and MSL require an immediate.
+            // literal pool entries are handled by the ldr pseudo-instruction
+            RawArg::LitPool { span, .. } => {
+                emit_error!(span, "Literal pool entries can only be loaded using ldr.");
+                return Err(None);
+            },
+            // label references are handled by the adrp pair pseudo-instructions
+            RawArg::LabelReference { span, .. } => {
+                emit_error!(span, "Memory references to labels can only be used by integer loads.");
+                return Err(None);
+            },
+            // modifier: LSL LSR ASR ROR and MSL require an immediate. MUL requires a multiplier immediate
             RawArg::Modifier { span, modifier } => {
                 if modifier.expr.is_none() && modifier.op.expr_required() {
-                    emit_error!(span, "LSL, LSR, ASR, ROR and MSL modifiers require a shift immediate.");
+                    emit_error!(span, "LSL, LSR, ASR, ROR, MSL and MUL modifiers require an immediate.");
                     return Err(None);
                 }
 
@@ -82,7 +113,7 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
             RawArg::Immediate { value, prefixed } => {
                 res.push(CleanArg::Immediate { value, prefixed })
             },
-            // reference: first, assert the used indexing mode (base, offset, pre-indexed, or register-indexed)
+            // reference: first, assert the used indexing mode (base, offset, vector length scaled offset, pre-indexed, or register-indexed)
             // then, verify that the base register is always an XSP register
             // for the register-indexed mode, additionally verify that the index register is either an W or an X register
             // and that the appropriate extend mode is used (UXTW/SXTW for W, LSL/SXTX for X)
@@ -104,7 +135,7 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
                 // second item is either a register or an offset
                 match items.next() {
                     Some(RefItem::Direct { reg, ..}) => {
-                        kind = RefKind::Indexed(reg, None);
+                        kind = RefKind::Indexed(Box::new(reg), None);
                     },
                     Some(RefItem::Immediate { value }) => {
                         kind = RefKind::Offset(value);
@@ -130,6 +161,22 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
                     }
                 }
 
+                // if the second item was an offset, it could be scaled by the vector length
+                if let RefKind::Offset(_) = kind {
+                    match items.next() {
+                        Some(RefItem::Modifier { modifier: m, ..}) if m.op == Modifier::MULVL => {
+                            if let RefKind::Offset(offset) = kind {
+                                kind = RefKind::OffsetMulVl(offset);
+                            }
+                        },
+                        Some(_) => {
+                            emit_error!(span, "Too many items in reference list");
+                            return Err(None);
+                        },
+                        None => hit_end = true
+                    }
+                }
+
                 // there should not be any more items in the reference
                 if !hit_end && items.next().is_some() {
                     emit_error!(span, "Too many items in reference list");
@@ -140,6 +187,8 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
                 if bang {
                     if let RefKind::Offset(offset) = kind {
                         kind = RefKind::PreIndexed(offset);
+                    } else if let RefKind::Base = kind {
+                        kind = RefKind::Writeback;
                     } else {
                         emit_error!(span, "Cannot use pre-indexed addressing without an immediate offset.");
                         return Err(None);
@@ -148,7 +197,7 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
 
                 // sanitizaiton
                 // base can only be a Xn|SP reg
-                if !(base.size() == Size::B_8 && (base.family() == RegFamily::INTEGERSP || (base.family() == RegFamily::INTEGER && !base.kind().is_zero_reg()))) {
+                if !(base.size() == Some(Size::B_8) && (base.family() == RegFamily::INTEGERSP || (base.family() == RegFamily::INTEGER && !base.kind().is_zero_reg()))) {
                     emit_error!(span, "Base register can only be a Xn|SP register");
                     return Err(None);
                 }
@@ -162,7 +211,7 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
 
                     // limited set of allowed modifiers.
                     if let Some(ref m) = modifier {
-                        if if index.size() == Size::B_8 {m.op != Modifier::LSL && m.op != Modifier::SXTX} else {m.op != Modifier::SXTW && m.op != Modifier::UXTW} {
+                        if if index.size() == Some(Size::B_8) {m.op != Modifier::LSL && m.op != Modifier::SXTX} else {m.op != Modifier::SXTW && m.op != Modifier::UXTW} {
                             emit_error!(span, "Invalid modifier for the selected base register type");
                             return Err(None);
                         }
@@ -190,10 +239,10 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
 
                 let first_code = first.kind().encode();
                 let last_code = last.kind().encode();
-                let amount = if last_code <= first_code {
-                    last_code + 32 - first_code
+                let amount = if last_code < first_code {
+                    last_code + 33 - first_code
                 } else {
-                    last_code - first_code
+                    last_code + 1 - first_code
                 };
 
                 res.push(CleanArg::RegList {
@@ -207,6 +256,12 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
             // registerlist in comma notation: verify that all used registers have the same element size / lane count.
             // then, canonicalize it to first register / count and confirm it is a valid bare vector register
             RawArg::CommaList { span, items, element } => {
+                // lists of ZA tiles or tile slices are handled separately
+                if let Some(Register::Matrix(_)) = items.first() {
+                    res.push(sanitize_matrix_list(span, items, element)?);
+                    continue;
+                }
+
                 if items.len() > 32 {
                     emit_error!(span, "Too many registers in register list.");
                     return Err(None);
@@ -246,8 +301,17 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
                         emit_error!(span, "Cannot use element specifiers inside of register lists.");
                         return Err(None);
                     }
+                } else if let Register::Scalable(z) = &first {
+                    if z.element.is_some() {
+                        emit_error!(span, "Cannot use element specifiers inside of register lists.");
+                        return Err(None);
+                    }
+                    if z.element_size.is_none() {
+                        emit_error!(span, "Scalable vector registers in register lists require an element size.");
+                        return Err(None);
+                    }
                 } else {
-                    emit_error!(span, "Can only use vector registers in register lists.");
+                    emit_error!(span, "Can only use vector or scalable vector registers in register lists.");
                     return Err(None);
                 }
 
@@ -277,6 +341,7 @@ fn sanitize_args(args: Vec<RawArg>) -> Result<Vec<CleanArg>, Option<String>> {
 }
 
 struct ListSanitizer {
+    pub family: Option<RegFamily>,
     pub element_size: Option<Size>,
     pub lanes: Option<Option<u8>>
 }
@@ -284,6 +349,7 @@ struct ListSanitizer {
 impl ListSanitizer {
     fn new() -> ListSanitizer {
         ListSanitizer {
+            family: None,
             element_size: None,
             lanes: None
         }
@@ -292,7 +358,39 @@ impl ListSanitizer {
     // check if this register spec is valid in a register list
     fn sanitize(&mut self, span: Span, register: &Register) -> Result<(), Option<String>> {
         sanitize_register(span, register)?;
-        if let Register::Vector(v) = register {
+
+        let family = register.family();
+        if let Some(f) = self.family {
+            if f != family {
+                emit_error!(span, "Cannot mix vector and scalable vector registers in register lists.");
+                return Err(None);
+            }
+        } else {
+            self.family = Some(family);
+        }
+
+        if let Register::Scalable(z) = register {
+            if z.element.is_some() {
+                emit_error!(span, "Cannot use element specifiers inside of register lists.");
+                return Err(None);
+            }
+
+            if z.kind.is_dynamic() {
+                emit_error!(span, "Cannot use dynamic registers inside of a comma/dash register list.");
+                return Err(None);
+            }
+
+            if z.element_size.is_none() {
+                emit_error!(span, "Scalable vector registers in register lists require an element size.");
+                return Err(None);
+            }
+
+            if self.element_size.is_some() && self.element_size != z.element_size {
+                emit_error!(span, "Inconsistent element sizes.");
+                return Err(None);
+            }
+            self.element_size = z.element_size;
+        } else if let Register::Vector(v) = register {
             if v.element.is_some() {
                 emit_error!(span, "Cannot use element specifiers inside of register lists.");
                 return Err(None);
@@ -321,13 +419,88 @@ impl ListSanitizer {
                 self.lanes = Some(v.lanes);
             }
         } else {
-            emit_error!(span, "Can only use vector registers in register lists.");
+            emit_error!(span, "Can only use vector or scalable vector registers in register lists.");
             return Err(None);
         }
         Ok(())
     }
 }
 
+// check that a list of ZA tiles or a single ZA tile slice is valid, and canonicalize tile lists
+// to the mask of 64-bit tiles that they cover
+fn sanitize_matrix_list(span: Span, items: Vec<Register>, element: Option<syn::Expr>) -> Result<CleanArg, Option<String>> {
+    if element.is_some() {
+        emit_error!(span, "Cannot use element specifiers on ZA tile lists.");
+        return Err(None);
+    }
+
+    let mut tiles = Vec::new();
+    for item in items {
+        sanitize_register(span, &item)?;
+        if let Register::Matrix(m) = item {
+            tiles.push(m);
+        } else {
+            emit_error!(span, "Cannot mix ZA tiles with other registers in register lists.");
+            return Err(None);
+        }
+    }
+
+    // a single tile slice, as used by the SME loads and stores
+    if tiles[0].slice.is_some() {
+        if tiles.len() != 1 {
+            emit_error!(span, "ZA tile slice lists can only contain a single tile slice.");
+            return Err(None);
+        }
+        return Ok(CleanArg::SliceList {
+            span,
+            slice: tiles.pop().unwrap()
+        });
+    }
+
+    let mut mask = 0u8;
+    for tile in tiles {
+        let id = match tile.kind {
+            RegKind::Static(id) if tile.slice.is_none() => id,
+            RegKind::Static(_) => {
+                emit_error!(span, "Cannot mix ZA tiles and tile slices in register lists.");
+                return Err(None);
+            },
+            RegKind::Dynamic(_, _) => {
+                emit_error!(span, "Cannot use dynamic registers inside of a ZA tile list.");
+                return Err(None);
+            }
+        };
+
+        if id == RegId::ZA {
+            mask |= 0xFF;
+            continue;
+        }
+
+        // the amount of tiles of this size, and the 64-bit tiles covered by the first of them
+        let (amount, covered) = match tile.element_size {
+            Some(Size::BYTE) => (1, 0xFFu8),
+            Some(Size::B_2) => (2, 0x55),
+            Some(Size::B_4) => (4, 0x11),
+            Some(Size::B_8) => (8, 0x01),
+            _ => {
+                emit_error!(span, "ZA tile lists can only contain tiles with B, H, S or D element sizes.");
+                return Err(None);
+            }
+        };
+
+        if id.code() >= amount {
+            emit_error!(span, "ZA tile number out of range for its element size.");
+            return Err(None);
+        }
+        mask |= covered << id.code();
+    }
+
+    Ok(CleanArg::TileList {
+        span,
+        mask
+    })
+}
+
 // check that the register spec is possible
 fn sanitize_register(span: Span, register: &Register) -> Result<(), Option<String>> {
     if let Register::Vector(v) = register {
@@ -337,6 +510,24 @@ fn sanitize_register(span: Span, register: &Register) -> Result<(), Option<Strin
                 return Err(None)
             }
         }
+    } else if let Register::Predicate(p) = register {
+        if p.element_size.is_some() && p.qualifier.is_some() {
+            emit_error!(span, "Predicate registers cannot have both an element size and a qualifier.");
+            return Err(None)
+        }
+    } else if let Register::Matrix(m) = register {
+        if m.is_array() {
+            if m.element_size.is_some() {
+                emit_error!(span, "The ZA array cannot have an element size.");
+                return Err(None)
+            }
+        } else if m.element_size.is_none() {
+            emit_error!(span, "ZA tiles require an element size.");
+            return Err(None)
+        } else if m.direction.is_some() != m.slice.is_some() {
+            emit_error!(span, "ZA tile slices require both a slice direction (h or v) and a slice index.");
+            return Err(None)
+        }
     }
     Ok(())
 }
@@ -367,13 +558,36 @@ impl Matcher {
         match arg {
             CleanArg::Reference { kind, .. } => {
                 match kind {
-                    RefKind::Base => *self == Matcher::RefBase || *self == Matcher::RefOffset,
+                    RefKind::Base => *self == Matcher::RefBase || *self == Matcher::RefOffset || *self == Matcher::RefMulVl,
                     RefKind::Offset(_) => *self == Matcher::RefOffset,
+                    RefKind::OffsetMulVl(_) => *self == Matcher::RefMulVl,
                     RefKind::PreIndexed(_) => *self == Matcher::RefPre,
-                    RefKind::Indexed(_, _) => *self == Matcher::RefIndex,
+                    RefKind::Writeback => *self == Matcher::RefWriteback,
+                    RefKind::Indexed(index, modifier) => match self {
+                        Matcher::RefIndex => true,
+                        Matcher::RefIndexLsl(shift) => {
+                            if index.size() != Some(Size::B_8) {
+                                return false;
+                            }
+                            match modifier {
+                                None => *shift == 0,
+                                Some(m) => m.op == Modifier::LSL && *shift != 0
+                                    && m.expr.as_ref().and_then(as_unsigned_number) == Some(u64::from(*shift))
+                            }
+                        },
+                        _ => false
+                    },
                 }
             },
             CleanArg::RegList { amount, element, first, .. } => {
+                if let Register::Scalable(z) = first {
+                    return match self {
+                        Matcher::ZRegList(m_amount, element_size) =>
+                            m_amount == amount && z.element_size == Some(*element_size) && element.is_none(),
+                        _ => false
+                    };
+                }
+
                 let first = first.assume_vector();
                 match self {
                     Matcher::RegList(m_amount, element_size) => {
@@ -450,9 +664,40 @@ impl Matcher {
                         Matcher::D => s.size() == Size::B_8 && s.kind.family() == RegFamily::SIMD,
                         Matcher::Q => s.size() == Size::B_16 && s.kind.family() == RegFamily::SIMD,
                         _ => false
+                    },
+                    Register::Scalable(ref z) => match self {
+                        Matcher::Z(size) => z.element_size == Some(*size) && z.element.is_none(),
+                        Matcher::ZBare => z.element_size.is_none(),
+                        Matcher::ZElement(size) => z.element_size == Some(*size) && z.element.is_some(),
+                        _ => false
+                    },
+                    Register::Predicate(ref p) => match self {
+                        Matcher::P(size) => p.element_size == Some(*size),
+                        Matcher::PBare => p.element_size.is_none() && p.qualifier.is_none(),
+                        Matcher::PZero => p.qualifier == Some(PredicateQualifier::Zeroing),
+                        Matcher::PMerge => p.qualifier == Some(PredicateQualifier::Merging),
+                        _ => false
+                    },
+                    Register::Matrix(ref m) => match self {
+                        Matcher::ZaArray => m.is_array() && m.slice.is_none(),
+                        Matcher::ZaVector => m.is_array() && m.slice.is_some(),
+                        Matcher::ZaTile(size) => !m.is_array() && m.element_size == Some(*size) && m.slice.is_none(),
+                        Matcher::ZaSliceH(size) => m.element_size == Some(*size) && m.direction == Some(SliceDirection::Horizontal),
+                        Matcher::ZaSliceV(size) => m.element_size == Some(*size) && m.direction == Some(SliceDirection::Vertical),
+                        _ => false
                     }
                 }
             },
+            CleanArg::TileList { .. } => *self == Matcher::ZaTileList,
+            CleanArg::SliceList { slice, .. } => match self {
+                Matcher::ZaSliceListH(size) => slice.element_size == Some(*size) && slice.direction == Some(SliceDirection::Horizontal),
+                Matcher::ZaSliceListV(size) => slice.element_size == Some(*size) && slice.direction == Some(SliceDirection::Vertical),
+                _ => false
+            },
+            CleanArg::Writeback { reg, .. } => match reg {
+                Register::Scalar(ref s) => *self == Matcher::XWriteback && s.size() == Size::B_8 && s.kind.family() == RegFamily::INTEGER,
+                _ => false
+            },
             CleanArg::JumpTarget { .. } => *self == Matcher::Offset,
             CleanArg::Immediate { prefixed: true, value } => match self {
                 Matcher::Imm
@@ -524,11 +769,31 @@ impl Matcher {
             Matcher::RegList(_, _) |
             Matcher::RegListStatic(_, _, _) => 1,
             Matcher::RegListElement(_, _) => 2,
+            Matcher::Z(_) |
+            Matcher::ZBare => 1,
+            Matcher::ZElement(_) => 2,
+            Matcher::ZRegList(_, _) => 1,
+            Matcher::P(_) |
+            Matcher::PBare |
+            Matcher::PZero |
+            Matcher::PMerge => 1,
+            Matcher::ZaArray => 0,
+            Matcher::ZaVector => 2,
+            Matcher::ZaTile(_) => 1,
+            Matcher::ZaSliceH(_) |
+            Matcher::ZaSliceV(_) => 3,
+            Matcher::ZaTileList => 1,
+            Matcher::ZaSliceListH(_) |
+            Matcher::ZaSliceListV(_) => 3,
             Matcher::Offset => 1,
             Matcher::RefBase => 1,
             Matcher::RefOffset => 2,
             Matcher::RefPre => 2,
+            Matcher::RefWriteback => 1,
+            Matcher::XWriteback => 1,
             Matcher::RefIndex => 4,
+            Matcher::RefMulVl => 2,
+            Matcher::RefIndexLsl(_) => 2,
             Matcher::Mod(_) => 2,
             Matcher::LitMod(_) => 1,
 
@@ -584,14 +849,19 @@ fn flatten_args(args: Vec<CleanArg>, data: &Opdata, ctx: &mut MatchData) {
                 CleanArg::Reference { span, base, kind} => {
                     new_args.push(FlatArg::Direct { span, reg: base.kind_owned() } );
                     match kind {
-                        RefKind::Base => (),
+                        RefKind::Base |
+                        RefKind::Writeback => (),
                         RefKind::Offset(value) =>
                             new_args.push(FlatArg::Immediate { value } ),
+                        RefKind::OffsetMulVl(value) =>
+                            new_args.push(FlatArg::Immediate { value } ),
                         RefKind::PreIndexed(value) =>
                             new_args.push(FlatArg::Immediate { value } ),
                         RefKind::Indexed(index, modifier) => {
                             new_args.push(FlatArg::Direct { span, reg: index.kind_owned() } );
-                            if let Some(modifier) = modifier {
+                            if let Matcher::RefIndexLsl(_) = matcher {
+                                // the shift amount has already been checked during matching
+                            } else if let Some(modifier) = modifier {
                                 new_args.push(FlatArg::Modifier { modifier: modifier.op } );
                                 if let Some(expr) = modifier.expr {
                                     new_args.push(FlatArg::Immediate { value: expr } );
@@ -616,9 +886,30 @@ fn flatten_args(args: Vec<CleanArg>, data: &Opdata, ctx: &mut MatchData) {
                             if let Some(element) = v.element {
                                 new_args.push(FlatArg::Immediate { value: element });
                             }
-                        }
+                        },
+                        Register::Scalable(z) => {
+                            new_args.push(FlatArg::Direct { span, reg: z.kind });
+                            if let Some(element) = z.element {
+                                new_args.push(FlatArg::Immediate { value: element });
+                            }
+                        },
+                        Register::Predicate(p) => {
+                            new_args.push(FlatArg::Direct { span, reg: p.kind });
+                        },
+                        Register::Matrix(m) => flatten_matrix(span, m, &mut new_args)
                     }
                 },
+                CleanArg::TileList { span, mask } => {
+                    let value = syn::Expr::Lit(syn::ExprLit {
+                        attrs: Vec::new(),
+                        lit: syn::Lit::Int(syn::LitInt::new(&mask.to_string(), span))
+                    });
+                    new_args.push(FlatArg::Immediate { value });
+                },
+                CleanArg::SliceList { span, slice } => flatten_matrix(span, slice, &mut new_args),
+                CleanArg::Writeback { span, reg } => {
+                    new_args.push(FlatArg::Direct { span, reg: reg.kind_owned() });
+                },
                 CleanArg::JumpTarget { jump } => {
                     new_args.push(FlatArg::JumpTarget { jump } );
                 },
@@ -645,3 +936,15 @@ fn flatten_args(args: Vec<CleanArg>, data: &Opdata, ctx: &mut MatchData) {
         ctx.args.extend(new_args.drain(..))
     }
 }
+
+/// flattens a ZA array access, tile or tile slice into the tile number, the slice index register and the slice offset
+fn flatten_matrix(span: Span, matrix: RegMatrix, new_args: &mut Vec<FlatArg>) {
+    if !matrix.is_array() {
+        new_args.push(FlatArg::Direct { span, reg: matrix.kind });
+    }
+    if let Some(slice) = matrix.slice {
+        let (index, offset) = *slice;
+        new_args.push(FlatArg::Direct { span, reg: index });
+        new_args.push(FlatArg::Immediate { value: offset });
+    }
+}
@@ -7,6 +7,9 @@ mod matching;
 mod compiler;
 mod aarch64data;
 mod encoding_helpers;
+mod movimm;
+mod litpool;
+mod adrl;
 mod debug;
 
 use crate::State;
@@ -20,19 +23,24 @@ pub use debug::create_opmap;
 pub use debug::extract_opmap;
 
 struct Context<'a, 'b: 'a> {
-    pub state: &'a mut State<'b>
+    pub state: &'a mut State<'b>,
+    pub features: aarch64data::ExtensionFlags
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct ArchAarch64 {
+    features: aarch64data::ExtensionFlags
+}
 
+impl Default for ArchAarch64 {
+    fn default() -> ArchAarch64 {
+        ArchAarch64 { features: aarch64data::ExtensionFlags::all() }
+    }
 }
 
 impl Arch for ArchAarch64 {
     fn set_features(&mut self, features: &[syn::Ident]) {
-        if let Some(feature) = features.first() {
-            emit_error!(feature, "Arch aarch64 has no known features");
-        }
+        self.features = parse_features(features);
     }
 
     fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
@@ -59,12 +67,34 @@ impl Arch for ArchAarch64 {
 
     fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
         let mut ctx = Context {
-            state
+            state,
+            features: self.features
         };
 
         let (instruction, args) = parser::parse_instruction(&mut ctx, input)?;
         let span = instruction.span;
 
+        if movimm::is_mov_immediate(&instruction, &args) {
+            if let Err(Some(e)) = movimm::compile_mov_immediate(&mut ctx, &instruction, args) {
+                emit_error!(span, e);
+            }
+            return Ok(());
+        }
+
+        if litpool::is_literal_load(&instruction, &args) {
+            if let Err(Some(e)) = litpool::compile_literal_load(&mut ctx, &instruction, args) {
+                emit_error!(span, e);
+            }
+            return Ok(());
+        }
+
+        if adrl::is_adrp_pair(&instruction, &args) {
+            if let Err(Some(e)) = adrl::compile_adrp_pair(&mut ctx, &instruction, args) {
+                emit_error!(span, e);
+            }
+            return Ok(());
+        }
+
         let match_data = match matching::match_instruction(&mut ctx, &instruction, args) {
             Err(None) => return Ok(()),
             Err(Some(e)) => {
@@ -86,3 +116,54 @@ impl Arch for ArchAarch64 {
         Ok(())
     }
 }
+
+fn parse_features(features: &[syn::Ident]) -> aarch64data::ExtensionFlags {
+    // always enable the base instruction set
+    let mut extension_flags = aarch64data::ExtensionFlags::default();
+
+    for feature in features {
+        let mut s = feature.to_string();
+        s.make_ascii_lowercase();
+
+        let flag = match s.as_str() {
+            "aes" => aarch64data::ExtensionFlags::Ex_AES,
+            "bti" => aarch64data::ExtensionFlags::Ex_BTI,
+            "crc" => aarch64data::ExtensionFlags::Ex_CRC,
+            "dotprod" => aarch64data::ExtensionFlags::Ex_DOTPROD,
+            "fcma" => aarch64data::ExtensionFlags::Ex_FCMA,
+            "flagm" => aarch64data::ExtensionFlags::Ex_FLAGM,
+            "fp16" => aarch64data::ExtensionFlags::Ex_FP16,
+            "fp16fml" => aarch64data::ExtensionFlags::Ex_FP16FML | aarch64data::ExtensionFlags::Ex_FP16,
+            "jscvt" => aarch64data::ExtensionFlags::Ex_JSCVT,
+            "lor" => aarch64data::ExtensionFlags::Ex_LOR,
+            "lse" => aarch64data::ExtensionFlags::Ex_LSE,
+            "mops" => aarch64data::ExtensionFlags::Ex_MOPS,
+            "mte" => aarch64data::ExtensionFlags::Ex_MTE,
+            "pauth" => aarch64data::ExtensionFlags::Ex_PAUTH,
+            "predres" => aarch64data::ExtensionFlags::Ex_PREDRES,
+            "ras" => aarch64data::ExtensionFlags::Ex_RAS,
+            "rcpc" => aarch64data::ExtensionFlags::Ex_RCPC,
+            "rcpc2" => aarch64data::ExtensionFlags::Ex_RCPC2 | aarch64data::ExtensionFlags::Ex_RCPC,
+            "rdm" => aarch64data::ExtensionFlags::Ex_RDM,
+            "sb" => aarch64data::ExtensionFlags::Ex_SB,
+            "sha2" => aarch64data::ExtensionFlags::Ex_SHA2,
+            "sha3" => aarch64data::ExtensionFlags::Ex_SHA3 | aarch64data::ExtensionFlags::Ex_SHA2,
+            "sm4" => aarch64data::ExtensionFlags::Ex_SM4,
+            "sme" => aarch64data::ExtensionFlags::Ex_SME,
+            "sme_f64f64" => aarch64data::ExtensionFlags::Ex_SME_F64F64 | aarch64data::ExtensionFlags::Ex_SME,
+            "sme_i16i64" => aarch64data::ExtensionFlags::Ex_SME_I16I64 | aarch64data::ExtensionFlags::Ex_SME,
+            "spe" => aarch64data::ExtensionFlags::Ex_SPE,
+            "sve" => aarch64data::ExtensionFlags::Ex_SVE | aarch64data::ExtensionFlags::Ex_FP16,
+            "sve2" => aarch64data::ExtensionFlags::Ex_SVE2 | aarch64data::ExtensionFlags::Ex_SVE | aarch64data::ExtensionFlags::Ex_FP16,
+            "trf" => aarch64data::ExtensionFlags::Ex_TRF,
+            x => {
+                emit_error!(feature, "Unknown aarch64 extension '{}'", x);
+                continue;
+            }
+        };
+
+        extension_flags |= flag;
+    }
+
+    extension_flags
+}
@@ -0,0 +1,247 @@
+//! Implementation of the `mov.imm` pseudo-instruction, which loads an arbitrary 32 or 64-bit
+//! immediate into a general purpose register using a sequence of `movz`, `movn`, `movk` and `orr`
+//! instructions.
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use proc_macro_error2::emit_error;
+
+use super::Context;
+use super::ast::{Instruction, RawArg, Register, RegScalar, RegKind, RegId, RegFamily, ModifyExpr, Modifier};
+use super::{matching, compiler};
+use super::encoding_helpers::{encode_logical_immediate_32bit, encode_logical_immediate_64bit};
+use crate::common::{Size, Stmt, delimited};
+use crate::parse_helpers::{as_unsigned_number, as_signed_number};
+use crate::serialize;
+
+
+/// A single instruction in a sequence loading an immediate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MovStep {
+    Movz(u16, u8),
+    Movn(u16, u8),
+    Movk(u16, u8),
+    Orr(u64)
+}
+
+/// Returns true if this instruction is the `mov.imm` pseudo-instruction
+pub(super) fn is_mov_immediate(instruction: &Instruction, args: &[RawArg]) -> bool {
+    instruction.ident == "mov" && matches!(args, [RawArg::Dot {}, RawArg::Lit { ident }, ..] if ident == "imm")
+}
+
+/// Compiles `mov.imm Rd, imm` and `mov.imm.short Rd, imm`. Constant immediates always result in the
+/// shortest possible sequence. Dynamic immediates result in a fixed-length `movz`/`movk` sequence,
+/// unless the `short` variant was used, in which case the sequence is picked at runtime.
+pub(super) fn compile_mov_immediate(ctx: &mut Context, instruction: &Instruction, args: Vec<RawArg>) -> Result<(), Option<String>> {
+    let span = instruction.span;
+    let mut args = args.into_iter().skip(2).peekable();
+
+    let mut short = false;
+    if let Some(RawArg::Dot {}) = args.peek() {
+        args.next();
+        match args.next() {
+            Some(RawArg::Lit { ident }) if ident == "short" => short = true,
+            Some(RawArg::Lit { ident }) => return Err(Some(format!("Unknown mov.imm variant '{}'", ident))),
+            _ => return Err(Some("Expected a mov.imm variant".into()))
+        }
+    }
+
+    let (reg_span, reg, value) = match (args.next(), args.next(), args.next()) {
+        (Some(RawArg::Direct { span, reg: Register::Scalar(reg) }), Some(RawArg::Immediate { value, .. }), None) => (span, reg, value),
+        _ => return Err(Some("mov.imm expects a general purpose register and an immediate".into()))
+    };
+
+    if reg.kind.family() != RegFamily::INTEGER {
+        emit_error!(reg_span, "mov.imm can only target W or X registers");
+        return Err(None);
+    }
+
+    let wide = match reg.size {
+        Size::B_8 => true,
+        Size::B_4 => false,
+        _ => unreachable!()
+    };
+
+    // constant immediates: pick the best sequence now
+    let number = match (as_unsigned_number(&value), as_signed_number(&value)) {
+        (Some(number), _) if wide || number <= u64::from(u32::MAX) => Some(number),
+        (None, Some(number)) if wide || number >= i64::from(i32::MIN) => Some(number as u64 & reg_mask(wide)),
+        (None, None) => None,
+        _ => {
+            emit_error!(value, "Immediate does not fit in a 32-bit register");
+            return Err(None);
+        }
+    };
+
+    if let Some(number) = number {
+        // orr cannot target the zero register, and does not accept dynamic registers
+        let allow_orr = matches!(reg.kind, RegKind::Static(id) if id != RegId::XZR);
+
+        for step in mov_immediate_sequence(number, wide, allow_orr) {
+            let (mnemonic, args) = match step {
+                MovStep::Movz(imm, shift) => ("movz", wide_immediate_args(span, &reg, imm, shift)),
+                MovStep::Movn(imm, shift) => ("movn", wide_immediate_args(span, &reg, imm, shift)),
+                MovStep::Movk(imm, shift) => ("movk", wide_immediate_args(span, &reg, imm, shift)),
+                MovStep::Orr(imm) => ("orr", vec![
+                    direct(span, &reg),
+                    direct(span, &RegScalar { kind: RegKind::Static(RegId::XZR), size: reg.size }),
+                    immediate(span, imm)
+                ])
+            };
+            emit_instruction(ctx, span, mnemonic, args)?;
+        }
+
+        return Ok(());
+    }
+
+    // dynamic immediates, picking the sequence at runtime.
+    if short {
+        let value = delimited(value);
+        let rd = match reg.kind {
+            RegKind::Static(id) => {
+                let code = id.code();
+                quote_spanned!{ span=> #code }
+            },
+            RegKind::Dynamic(_, ref expr) => {
+                let expr = delimited(expr);
+                quote_spanned!{ span=>
+                    {
+                        let _dyn_reg: u8 = #expr.into();
+                        _dyn_reg & 0x1F
+                    }
+                }
+            }
+        };
+
+        let sequence = if wide {
+            quote_spanned!{ span=> ::dynasmrt::aarch64::encode_mov_immediate_64bit(#rd, (#value) as u64) }
+        } else {
+            quote_spanned!{ span=> ::dynasmrt::aarch64::encode_mov_immediate_32bit(#rd, (#value) as u32) }
+        };
+        ctx.state.stmts.push(Stmt::ExprExtend(delimited(sequence)));
+        return Ok(());
+    }
+
+    // dynamic immediates, using a fixed-length sequence
+    let value = delimited(value);
+    let chunks = if wide { 4 } else { 2 };
+    for i in 0 .. chunks {
+        let shift = 16 * i;
+        let chunk = quote_spanned!{ span=> ((((#value) as u64) >> #shift) & 0xFFFF) as u32 };
+        let chunk = serialize::reparse(&delimited(chunk)).expect("Invalid expression generated internally");
+
+        let mnemonic = if i == 0 { "movz" } else { "movk" };
+        let mut args = vec![
+            direct(span, &reg),
+            RawArg::Immediate { prefixed: false, value: chunk }
+        ];
+        if shift != 0 {
+            args.push(lsl(span, shift as u8));
+        }
+        emit_instruction(ctx, span, mnemonic, args)?;
+    }
+
+    Ok(())
+}
+
+/// Determines the shortest sequence of instructions needed to load `value` into a register.
+/// If `wide` is false, only the lower 32 bits of value are considered. `allow_orr` has to be
+/// false when the target is the zero register, as `orr` would target the stack pointer.
+fn mov_immediate_sequence(value: u64, wide: bool, allow_orr: bool) -> Vec<MovStep> {
+    let count = if wide { 4 } else { 2 };
+    let chunks: Vec<u16> = (0 .. count).map(|i| (value >> (16 * i)) as u16).collect();
+
+    let zeros = chunks.iter().filter(|&&c| c == 0).count();
+    let ones = chunks.iter().filter(|&&c| c == 0xFFFF).count();
+
+    // sequences of more than one mov instruction might be replacable by a single orr
+    if allow_orr && zeros.max(ones) + 1 < count {
+        let encodable = if wide {
+            encode_logical_immediate_64bit(value).is_some()
+        } else {
+            encode_logical_immediate_32bit(value as u32).is_some()
+        };
+
+        if encodable {
+            return vec![MovStep::Orr(value)];
+        }
+    }
+
+    // start with either movn or movz, depending on which saves the most movk's
+    let (inverted, skip) = if ones > zeros { (true, 0xFFFF) } else { (false, 0) };
+
+    let mut steps = Vec::new();
+    for (i, &chunk) in chunks.iter().enumerate() {
+        if chunk == skip {
+            continue;
+        }
+
+        let shift = 16 * i as u8;
+        steps.push(match (steps.is_empty(), inverted) {
+            (true, true) => MovStep::Movn(!chunk, shift),
+            (true, false) => MovStep::Movz(chunk, shift),
+            (false, _) => MovStep::Movk(chunk, shift),
+        });
+    }
+
+    // the value consists entirely of zeros or ones
+    if steps.is_empty() {
+        steps.push(if inverted { MovStep::Movn(0, 0) } else { MovStep::Movz(0, 0) });
+    }
+
+    steps
+}
+
+fn reg_mask(wide: bool) -> u64 {
+    if wide { u64::MAX } else { u64::from(u32::MAX) }
+}
+
+/// Matches and compiles a single generated instruction
+fn emit_instruction(ctx: &mut Context, span: Span, mnemonic: &str, args: Vec<RawArg>) -> Result<(), Option<String>> {
+    let instruction = Instruction {
+        span,
+        ident: syn::Ident::new(mnemonic, span)
+    };
+
+    let match_data = matching::match_instruction(ctx, &instruction, args)?;
+    compiler::compile_instruction(ctx, match_data)
+}
+
+fn wide_immediate_args(span: Span, reg: &RegScalar, imm: u16, shift: u8) -> Vec<RawArg> {
+    let mut args = vec![
+        direct(span, reg),
+        immediate(span, u64::from(imm))
+    ];
+    if shift != 0 {
+        args.push(lsl(span, shift));
+    }
+    args
+}
+
+fn direct(span: Span, reg: &RegScalar) -> RawArg {
+    RawArg::Direct {
+        span,
+        reg: Register::Scalar(reg.clone())
+    }
+}
+
+fn immediate(span: Span, value: u64) -> RawArg {
+    RawArg::Immediate {
+        prefixed: false,
+        value: literal(span, value)
+    }
+}
+
+fn lsl(span: Span, shift: u8) -> RawArg {
+    RawArg::Modifier {
+        span,
+        modifier: ModifyExpr::new(Modifier::LSL, Some(literal(span, u64::from(shift))))
+    }
+}
+
+fn literal(span: Span, value: u64) -> syn::Expr {
+    syn::Expr::Lit(syn::ExprLit {
+        attrs: Vec::new(),
+        lit: syn::Lit::Int(syn::LitInt::new(&value.to_string(), span))
+    })
+}
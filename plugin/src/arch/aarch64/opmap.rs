@@ -2,4432 +2,7516 @@
 Ops!(
 
 "abs" = [
-    0b01011110_11100000_10111000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_00100000_10111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100000_10111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100000_10111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100000_10111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_11100000_10111000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_00100000_10111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_10111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_10111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_10111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    // ABS (predicated)
+    0b00000100_00010110_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // ABS (predicated)
+    0b00000100_01010110_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // ABS (predicated)
+    0b00000100_10010110_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // ABS (predicated)
+    0b00000100_11010110_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "adc" = [
-    0b00011010_00000000_00000000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b10011010_00000000_00000000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00011010_00000000_00000000_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b10011010_00000000_00000000_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "adcs" = [
-    0b00111010_00000000_00000000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b10111010_00000000_00000000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00111010_00000000_00000000_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b10111010_00000000_00000000_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "add" = [
     // ADD (shifted register)
-    0b00001011_00000000_00000000_00000000 = [W, W, W, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b10001011_00000000_00000000_00000000 = [X, X, X, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b00001011_00000000_00000000_00000000 = [W, W, W, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b10001011_00000000_00000000_00000000 = [X, X, X, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
     // ADD (extended register)
-    0b00001011_00100000_00000000_00000000 = [WSP, WSP, W, End, Mod(EXTENDS)] => [R(0), R(5), R(16), ExtendsW(13), Urange(10, 0, 4)];
-    0b10001011_00100000_00000000_00000000 = [XSP, XSP, W, End, Mod(EXTENDS_W)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
-    0b10001011_00100000_00000000_00000000 = [XSP, XSP, X, End, Mod(EXTENDS_X)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    0b00001011_00100000_00000000_00000000 = [WSP, WSP, W, End, Mod(EXTENDS)] => [R(0), R(5), R(16), ExtendsW(13), Urange(10, 0, 4)], [Ex_BASE];
+    0b10001011_00100000_00000000_00000000 = [XSP, XSP, W, End, Mod(EXTENDS_W)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)], [Ex_BASE];
+    0b10001011_00100000_00000000_00000000 = [XSP, XSP, X, End, Mod(EXTENDS_X)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)], [Ex_BASE];
     // ADD (immediate)
-    0b00010001_00000000_00000000_00000000 = [WSP, WSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
-    0b10010001_00000000_00000000_00000000 = [XSP, XSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+    0b00010001_00000000_00000000_00000000 = [WSP, WSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])], [Ex_BASE];
+    0b10010001_00000000_00000000_00000000 = [XSP, XSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])], [Ex_BASE];
     // ADD (vector)
-    0b01011110_11100000_10000100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_00100000_10000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_10000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_10000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_10000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_11100000_10000100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_00100000_10000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_10000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_10000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_10000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // ADD (vectors, predicated)
+    0b00000100_00000000_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // ADD (vectors, predicated)
+    0b00000100_01000000_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // ADD (vectors, predicated)
+    0b00000100_10000000_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // ADD (vectors, predicated)
+    0b00000100_11000000_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // ADD (vectors, unpredicated)
+    0b00000100_00100000_00000000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // ADD (vectors, unpredicated)
+    0b00000100_01100000_00000000_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // ADD (vectors, unpredicated)
+    0b00000100_10100000_00000000_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // ADD (vectors, unpredicated)
+    0b00000100_11100000_00000000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // ADD (immediate)
+    0b00100101_00100000_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
+    0b00100101_01100000_11000000_00000000 = [Z(B_2), Z(B_2), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_10100000_11000000_00000000 = [Z(B_4), Z(B_4), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_11100000_11000000_00000000 = [Z(B_8), Z(B_8), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+]
+"addg" = [
+    0b10010001_10000000_00000000_00000000 = [XSP, XSP, Imm, Imm] => [R(0), R(5), Uscaled(16, 6, 4), Ubits(10, 4)], [Ex_MTE];
+]
+"addha" = [
+    0b11000000_10010000_00000000_00000000 = [ZaTile(B_4), PMerge, PMerge, Z(B_4)] => [RTile(0, 2), R3(10), R3(13), R(5)], [Ex_SME];
+    0b11000000_11010000_00000000_00000000 = [ZaTile(B_8), PMerge, PMerge, Z(B_8)] => [RTile(0, 3), R3(10), R3(13), R(5)], [Ex_SME_I16I64];
 ]
 "addhn" = [
-    0b00001110_00100000_01000000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b00001110_01100000_01000000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
-    0b00001110_10100000_01000000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)];
+    0b00001110_00100000_01000000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_01000000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_10100000_01000000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "addhn2" = [
-    0b01001110_00100000_01000000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01001110_01100000_01000000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_01000000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)];
+    0b01001110_00100000_01000000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_01100000_01000000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_10100000_01000000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"addhnb" = [
+    0b01000101_01100000_01100000_00000000 = [Z(BYTE), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10100000_01100000_00000000 = [Z(B_2), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11100000_01100000_00000000 = [Z(B_4), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"addhnt" = [
+    0b01000101_01100000_01100100_00000000 = [Z(BYTE), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10100000_01100100_00000000 = [Z(B_2), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11100000_01100100_00000000 = [Z(B_4), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "addp" = [
     // ADDP (scalar)
-    0b01011110_11110001_10111000_00000000 = [D, VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01011110_11110001_10111000_00000000 = [D, VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
     // ADDP (vector)
-    0b00001110_00100000_10111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_10111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_10111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_10111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00100000_10111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_10111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_10111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_10111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // ADDP (vectors, predicated)
+    0b01000100_00010001_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // ADDP (vectors, predicated)
+    0b01000100_01010001_10100000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // ADDP (vectors, predicated)
+    0b01000100_10010001_10100000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // ADDP (vectors, predicated)
+    0b01000100_11010001_10100000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+]
+"addpl" = [
+    0b00000100_01100000_01010000_00000000 = [XSP, XSP, Imm] => [R(0), R(16), Sbits(5, 6)], [Ex_SVE];
 ]
 "adds" = [
     // ADDS (shifted register)
-    0b00101011_00000000_00000000_00000000 = [W, W, W, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b10101011_00000000_00000000_00000000 = [X, X, X, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b00101011_00000000_00000000_00000000 = [W, W, W, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b10101011_00000000_00000000_00000000 = [X, X, X, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
     // ADDS (extended register)
-    0b00101011_00100000_00000000_00000000 = [W, WSP, W, End, Mod(EXTENDS)] => [R(0), R(5), R(16), ExtendsW(13), Urange(10, 0, 4)];
-    0b10101011_00100000_00000000_00000000 = [X, XSP, W, End, Mod(EXTENDS_W)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
-    0b10101011_00100000_00000000_00000000 = [X, XSP, X, End, Mod(EXTENDS_X)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    0b00101011_00100000_00000000_00000000 = [W, WSP, W, End, Mod(EXTENDS)] => [R(0), R(5), R(16), ExtendsW(13), Urange(10, 0, 4)], [Ex_BASE];
+    0b10101011_00100000_00000000_00000000 = [X, XSP, W, End, Mod(EXTENDS_W)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)], [Ex_BASE];
+    0b10101011_00100000_00000000_00000000 = [X, XSP, X, End, Mod(EXTENDS_X)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)], [Ex_BASE];
     // ADDS (immediate)
-    0b00110001_00000000_00000000_00000000 = [W, WSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
-    0b10110001_00000000_00000000_00000000 = [X, XSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+    0b00110001_00000000_00000000_00000000 = [W, WSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])], [Ex_BASE];
+    0b10110001_00000000_00000000_00000000 = [X, XSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])], [Ex_BASE];
+]
+"addspl" = [
+    0b00000100_01100000_01011000_00000000 = [XSP, XSP, Imm] => [R(0), R(16), Sbits(5, 6)], [Ex_SME];
+]
+"addsvl" = [
+    0b00000100_00100000_01011000_00000000 = [XSP, XSP, Imm] => [R(0), R(16), Sbits(5, 6)], [Ex_SME];
 ]
 "addv" = [
-    0b00001110_00110001_10111000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01110001_10111000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10110001_10111000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_00110001_10111000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01110001_10111000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_10110001_10111000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+]
+"addva" = [
+    0b11000000_10010001_00000000_00000000 = [ZaTile(B_4), PMerge, PMerge, Z(B_4)] => [RTile(0, 2), R3(10), R3(13), R(5)], [Ex_SME];
+    0b11000000_11010001_00000000_00000000 = [ZaTile(B_8), PMerge, PMerge, Z(B_8)] => [RTile(0, 3), R3(10), R3(13), R(5)], [Ex_SME_I16I64];
+]
+"addvl" = [
+    0b00000100_00100000_01010000_00000000 = [XSP, XSP, Imm] => [R(0), R(16), Sbits(5, 6)], [Ex_SVE];
 ]
 "adr" = [
-    0b00010000_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(ADR)];
+    0b00010000_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(ADR)], [Ex_BASE];
 ]
 "adrp" = [
-    0b10010000_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(ADRP)];
+    0b10010000_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(ADRP)], [Ex_BASE];
 ]
 "aesd" = [
-    0b01001110_00101000_01011000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)];
+    0b01001110_00101000_01011000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)], [Ex_AES];
 ]
 "aese" = [
-    0b01001110_00101000_01001000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)];
+    0b01001110_00101000_01001000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)], [Ex_AES];
 ]
 "aesimc" = [
-    0b01001110_00101000_01111000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)];
+    0b01001110_00101000_01111000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)], [Ex_AES];
 ]
 "aesmc" = [
-    0b01001110_00101000_01101000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)];
+    0b01001110_00101000_01101000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)], [Ex_AES];
 ]
 "and" = [
     // AND (vector)
-    0b00001110_00100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // AND (immediate)
-    0b00010010_00000000_00000000_00000000 = [WSP, W, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_W)];
-    0b10010010_00000000_00000000_00000000 = [XSP, X, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_X)];
+    0b00010010_00000000_00000000_00000000 = [WSP, W, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_W)], [Ex_BASE];
+    0b10010010_00000000_00000000_00000000 = [XSP, X, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_X)], [Ex_BASE];
     // AND (shifted register)
-    0b00001010_00000000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b10001010_00000000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b00001010_00000000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b10001010_00000000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
+    // AND (vectors, predicated)
+    0b00000100_00011010_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // AND (vectors, predicated)
+    0b00000100_01011010_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // AND (vectors, predicated)
+    0b00000100_10011010_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // AND (vectors, predicated)
+    0b00000100_11011010_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // AND (vectors, unpredicated)
+    0b00000100_00100000_00110000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // AND (predicates)
+    0b00100101_00000000_01000000_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
 ]
 "ands" = [
     // ANDS (immediate)
-    0b01110010_00000000_00000000_00000000 = [W, W, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_W)];
-    0b11110010_00000000_00000000_00000000 = [X, X, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_X)];
+    0b01110010_00000000_00000000_00000000 = [W, W, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_W)], [Ex_BASE];
+    0b11110010_00000000_00000000_00000000 = [X, X, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_X)], [Ex_BASE];
     // ANDS (shifted register)
-    0b01101010_00000000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b11101010_00000000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b01101010_00000000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b11101010_00000000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
+    // ANDS (predicates)
+    0b00100101_01000000_01000000_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
+]
+"andv" = [
+    0b00000100_00011010_00100000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_01011010_00100000_00000000 = [H, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_10011010_00100000_00000000 = [S, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_11011010_00100000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "asr" = [
     // ASR (register)
-    0b00011010_11000000_00101000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b10011010_11000000_00101000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00011010_11000000_00101000_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b10011010_11000000_00101000_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
     // ASR (immediate)
-    0b00010011_00000000_01111100_00000000 = [W, W, Imm] => [R(0), R(5), Ubits(16, 5)];
-    0b10010011_01000000_11111100_00000000 = [X, X, Imm] => [R(0), R(5), Ubits(16, 6)];
+    0b00010011_00000000_01111100_00000000 = [W, W, Imm] => [R(0), R(5), Ubits(16, 5)], [Ex_BASE];
+    0b10010011_01000000_11111100_00000000 = [X, X, Imm] => [R(0), R(5), Ubits(16, 6)], [Ex_BASE];
+    // ASR (vectors, predicated)
+    0b00000100_00010000_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // ASR (vectors, predicated)
+    0b00000100_01010000_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // ASR (vectors, predicated)
+    0b00000100_10010000_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // ASR (vectors, predicated)
+    0b00000100_11010000_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+]
+"asrr" = [
+    // ASRR (vectors, predicated)
+    0b00000100_00010100_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // ASRR (vectors, predicated)
+    0b00000100_01010100_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // ASRR (vectors, predicated)
+    0b00000100_10010100_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // ASRR (vectors, predicated)
+    0b00000100_11010100_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "asrv" = [
-    0b00011010_11000000_00101000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b10011010_11000000_00101000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00011010_11000000_00101000_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b10011010_11000000_00101000_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "at" = [
-    0b11010101_00001000_01111000_00000000 = [Ident, X] => [LitList(5, "AT_OPS"), R(0)];
+    0b11010101_00001000_01111000_00000000 = [Ident, X] => [LitList(5, "AT_OPS"), R(0)], [Ex_BASE];
 ]
 "autda" = [
-    0b11011010_11000001_00011000_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00011000_00000000 = [X, XSP] => [R(0), R(5)], [Ex_PAUTH];
 ]
 "autdb" = [
-    0b11011010_11000001_00011100_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00011100_00000000 = [X, XSP] => [R(0), R(5)], [Ex_PAUTH];
 ]
 "autdza" = [
-    0b11011010_11000001_00111011_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00111011_11100000 = [X] => [R(0)], [Ex_PAUTH];
 ]
 "autdzb" = [
-    0b11011010_11000001_00111111_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00111111_11100000 = [X] => [R(0)], [Ex_PAUTH];
 ]
 "autia" = [
-    0b11011010_11000001_00010000_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00010000_00000000 = [X, XSP] => [R(0), R(5)], [Ex_PAUTH];
 ]
 "autia1716" = [
-    0b11010101_00000011_00100001_10011111 = [] => [];
+    0b11010101_00000011_00100001_10011111 = [] => [], [Ex_PAUTH];
 ]
 "autiasp" = [
-    0b11010101_00000011_00100011_10111111 = [] => [];
+    0b11010101_00000011_00100011_10111111 = [] => [], [Ex_PAUTH];
 ]
 "autiaz" = [
-    0b11010101_00000011_00100011_10011111 = [] => [];
+    0b11010101_00000011_00100011_10011111 = [] => [], [Ex_PAUTH];
 ]
 "autib" = [
-    0b11011010_11000001_00010100_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00010100_00000000 = [X, XSP] => [R(0), R(5)], [Ex_PAUTH];
 ]
 "autib1716" = [
-    0b11010101_00000011_00100001_11011111 = [] => [];
+    0b11010101_00000011_00100001_11011111 = [] => [], [Ex_PAUTH];
 ]
 "autibsp" = [
-    0b11010101_00000011_00100011_11111111 = [] => [];
+    0b11010101_00000011_00100011_11111111 = [] => [], [Ex_PAUTH];
 ]
 "autibz" = [
-    0b11010101_00000011_00100011_11011111 = [] => [];
+    0b11010101_00000011_00100011_11011111 = [] => [], [Ex_PAUTH];
 ]
 "autiza" = [
-    0b11011010_11000001_00110011_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00110011_11100000 = [X] => [R(0)], [Ex_PAUTH];
 ]
 "autizb" = [
-    0b11011010_11000001_00110111_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00110111_11100000 = [X] => [R(0)], [Ex_PAUTH];
 ]
 "b" = [
     // B.cond
-    0b01010100_00000000_00000000_00000000 = [Dot, Cond, Offset] => [Cond(0), Offset(BCOND)];
+    0b01010100_00000000_00000000_00000000 = [Dot, Cond, Offset] => [Cond(0), Offset(BCOND)], [Ex_BASE];
     // B
-    0b00010100_00000000_00000000_00000000 = [Offset] => [Offset(B)];
+    0b00010100_00000000_00000000_00000000 = [Offset] => [Offset(B)], [Ex_BASE];
 ]
 "bcax" = [
-    0b11001110_00100000_00000000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16), R(10)];
+    0b11001110_00100000_00000000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16), R(10)], [Ex_SHA3];
+    0b00000100_01100000_00111000_00000000 = [Z(B_8), Z(B_8), Z(B_8), Z(B_8)] => [R(0), RTied, R(16), R(5)], [Ex_SVE2];
 ]
 "bfc" = [
-    0b00110011_00000000_00000011_11100000 = [W, Imm, Imm] => [R(0), Usubmod(16, 5), CUsum(5), Urange(10, 1, 32)];
-    0b10110011_01000000_00000011_11100000 = [X, Imm, Imm] => [R(0), Usubmod(16, 6), CUsum(6), Urange(10, 1, 64)];
+    0b00110011_00000000_00000011_11100000 = [W, Imm, Imm] => [R(0), Usubmod(16, 5), CUsum(5), Urange(10, 1, 32)], [Ex_BASE];
+    0b10110011_01000000_00000011_11100000 = [X, Imm, Imm] => [R(0), Usubmod(16, 6), CUsum(6), Urange(10, 1, 64)], [Ex_BASE];
 ]
 "bfi" = [
-    0b00110011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Usubmod(16, 5), CUsum(5), Urange(10, 1, 32)];
-    0b10110011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Usubmod(16, 6), CUsum(6), Urange(10, 1, 64)];
+    0b00110011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Usubmod(16, 5), CUsum(5), Urange(10, 1, 32)], [Ex_BASE];
+    0b10110011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Usubmod(16, 6), CUsum(6), Urange(10, 1, 64)], [Ex_BASE];
 ]
 "bfm" = [
-    0b00110011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Ubits(10, 5)];
-    0b10110011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), CUsum(6), Ubits(10, 6)];
+    0b00110011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Ubits(10, 5)], [Ex_BASE];
+    0b10110011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), CUsum(6), Ubits(10, 6)], [Ex_BASE];
+]
+"bfmopa" = [
+    0b10000001_10000000_00000000_00000000 = [ZaTile(B_4), PMerge, PMerge, Z(B_2), Z(B_2)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], [Ex_SME];
+]
+"bfmops" = [
+    0b10000001_10000000_00000000_00010000 = [ZaTile(B_4), PMerge, PMerge, Z(B_2), Z(B_2)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], [Ex_SME];
 ]
 "bfxil" = [
-    0b00110011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Usum(10, 5)];
-    0b10110011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), Usum(10, 6)];
+    0b00110011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Usum(10, 5)], [Ex_BASE];
+    0b10110011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), Usum(10, 6)], [Ex_BASE];
 ]
 "bic" = [
     // BIC (vector, immediate)
-    0b00101111_00000000_10010100_00000000 = [V(B_2), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8]), Rwidth(30)];
-    0b00101111_00000000_00010100_00000000 = [V(B_4), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8, 16, 24]), Rwidth(30)];
+    0b00101111_00000000_10010100_00000000 = [V(B_2), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8]), Rwidth(30)], [Ex_BASE];
+    0b00101111_00000000_00010100_00000000 = [V(B_4), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8, 16, 24]), Rwidth(30)], [Ex_BASE];
     // BIC (vector, register)
-    0b00001110_01100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // BIC (shifted register)
-    0b00001010_00100000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b10001010_00100000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b00001010_00100000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b10001010_00100000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
+    // BIC (vectors, predicated)
+    0b00000100_00011011_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // BIC (vectors, predicated)
+    0b00000100_01011011_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // BIC (vectors, predicated)
+    0b00000100_10011011_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // BIC (vectors, predicated)
+    0b00000100_11011011_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // BIC (vectors, unpredicated)
+    0b00000100_11100000_00110000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // BIC (predicates)
+    0b00100101_00000000_01000000_00010000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
 ]
 "bics" = [
-    0b01101010_00100000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b11101010_00100000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b01101010_00100000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b11101010_00100000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
+    // BICS (predicates)
+    0b00100101_01000000_01000000_00010000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
 ]
 "bif" = [
-    0b00101110_11100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
 ]
 "bit" = [
-    0b00101110_10100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_10100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
 ]
 "bl" = [
-    0b10010100_00000000_00000000_00000000 = [Offset] => [Offset(B)];
+    0b10010100_00000000_00000000_00000000 = [Offset] => [Offset(B)], [Ex_BASE];
 ]
 "blr" = [
-    0b11010110_00111111_00000000_00000000 = [X] => [R(5)];
+    0b11010110_00111111_00000000_00000000 = [X] => [R(5)], [Ex_BASE];
 ]
 "blraa" = [
-    0b11010111_00111111_00001000_00000000 = [X, XSP] => [R(5), R(0)];
+    0b11010111_00111111_00001000_00000000 = [X, XSP] => [R(5), R(0)], [Ex_PAUTH];
 ]
 "blraaz" = [
-    0b11010110_00111111_00001000_00011111 = [X] => [R(5)];
+    0b11010110_00111111_00001000_00011111 = [X] => [R(5)], [Ex_PAUTH];
 ]
 "blrab" = [
-    0b11010111_00111111_00001100_00000000 = [X, XSP] => [R(5), R(0)];
+    0b11010111_00111111_00001100_00000000 = [X, XSP] => [R(5), R(0)], [Ex_PAUTH];
 ]
 "blrabz" = [
-    0b11010110_00111111_00001100_00011111 = [X] => [R(5)];
+    0b11010110_00111111_00001100_00011111 = [X] => [R(5)], [Ex_PAUTH];
 ]
 "br" = [
-    0b11010110_00011111_00000000_00000000 = [X] => [R(5)];
+    0b11010110_00011111_00000000_00000000 = [X] => [R(5)], [Ex_BASE];
 ]
 "braa" = [
-    0b11010111_00011111_00001000_00000000 = [X, XSP] => [R(5), R(0)];
+    0b11010111_00011111_00001000_00000000 = [X, XSP] => [R(5), R(0)], [Ex_PAUTH];
 ]
 "braaz" = [
-    0b11010110_00011111_00001000_00011111 = [X] => [R(5)];
+    0b11010110_00011111_00001000_00011111 = [X] => [R(5)], [Ex_PAUTH];
 ]
 "brab" = [
-    0b11010111_00011111_00001100_00000000 = [X, XSP] => [R(5), R(0)];
+    0b11010111_00011111_00001100_00000000 = [X, XSP] => [R(5), R(0)], [Ex_PAUTH];
 ]
 "brabz" = [
-    0b11010110_00011111_00001100_00011111 = [X] => [R(5)];
+    0b11010110_00011111_00001100_00011111 = [X] => [R(5)], [Ex_PAUTH];
 ]
 "brk" = [
-    0b11010100_00100000_00000000_00000000 = [Imm] => [Ubits(5, 16)];
+    0b11010100_00100000_00000000_00000000 = [Imm] => [Ubits(5, 16)], [Ex_BASE];
+]
+"brka" = [
+    0b00100101_00010000_01000000_00000000 = [P(BYTE), PZero, P(BYTE)] => [R4(0), R4(10), R4(5)], [Ex_SVE];
+    0b00100101_00010000_01000000_00010000 = [P(BYTE), PMerge, P(BYTE)] => [R4(0), R4(10), R4(5)], [Ex_SVE];
+]
+"brkas" = [
+    0b00100101_01010000_01000000_00000000 = [P(BYTE), PZero, P(BYTE)] => [R4(0), R4(10), R4(5)], [Ex_SVE];
+]
+"brkb" = [
+    0b00100101_10010000_01000000_00000000 = [P(BYTE), PZero, P(BYTE)] => [R4(0), R4(10), R4(5)], [Ex_SVE];
+    0b00100101_10010000_01000000_00010000 = [P(BYTE), PMerge, P(BYTE)] => [R4(0), R4(10), R4(5)], [Ex_SVE];
+]
+"brkbs" = [
+    0b00100101_11010000_01000000_00000000 = [P(BYTE), PZero, P(BYTE)] => [R4(0), R4(10), R4(5)], [Ex_SVE];
 ]
 "bsl" = [
-    0b00101110_01100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00000100_00100000_00111100_00000000 = [Z(B_8), Z(B_8), Z(B_8), Z(B_8)] => [R(0), RTied, R(16), R(5)], [Ex_SVE2];
+]
+"bsl1n" = [
+    0b00000100_01100000_00111100_00000000 = [Z(B_8), Z(B_8), Z(B_8), Z(B_8)] => [R(0), RTied, R(16), R(5)], [Ex_SVE2];
+]
+"bsl2n" = [
+    0b00000100_10100000_00111100_00000000 = [Z(B_8), Z(B_8), Z(B_8), Z(B_8)] => [R(0), RTied, R(16), R(5)], [Ex_SVE2];
+]
+"bti" = [
+    0b11010101_00000011_00100100_00011111 = [] => [], [Ex_BTI];
+    0b11010101_00000011_00100100_01011111 = [Lit("c")] => [], [Ex_BTI];
+    0b11010101_00000011_00100100_10011111 = [Lit("j")] => [], [Ex_BTI];
+    0b11010101_00000011_00100100_11011111 = [Lit("jc")] => [], [Ex_BTI];
 ]
 "cas" = [
-    0b10001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11001000_10100000_01111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11001000_10100000_01111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "casa" = [
-    0b10001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11001000_11100000_01111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11001000_11100000_01111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "casab" = [
-    0b00001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "casah" = [
-    0b01001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "casal" = [
-    0b10001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11001000_11100000_11111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11001000_11100000_11111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "casalb" = [
-    0b00001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "casalh" = [
-    0b01001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "casb" = [
-    0b00001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "cash" = [
-    0b01001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "casl" = [
-    0b10001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11001000_10100000_11111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11001000_10100000_11111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "caslb" = [
-    0b00001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "caslh" = [
-    0b01001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "casp" = [
-    0b00001000_00100000_01111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
-    0b01001000_00100000_01111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
+    0b00001000_00100000_01111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], [Ex_LSE];
+    0b01001000_00100000_01111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], [Ex_LSE];
 ]
 "caspa" = [
-    0b00001000_01100000_01111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
-    0b01001000_01100000_01111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
+    0b00001000_01100000_01111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], [Ex_LSE];
+    0b01001000_01100000_01111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], [Ex_LSE];
 ]
 "caspal" = [
-    0b00001000_01100000_11111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
-    0b01001000_01100000_11111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
+    0b00001000_01100000_11111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], [Ex_LSE];
+    0b01001000_01100000_11111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], [Ex_LSE];
 ]
 "caspl" = [
-    0b00001000_00100000_11111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
-    0b01001000_00100000_11111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
+    0b00001000_00100000_11111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], [Ex_LSE];
+    0b01001000_00100000_11111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], [Ex_LSE];
 ]
 "cbnz" = [
-    0b00110101_00000000_00000000_00000000 = [W, Offset] => [R(0), Offset(BCOND)];
-    0b10110101_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(BCOND)];
+    0b00110101_00000000_00000000_00000000 = [W, Offset] => [R(0), Offset(BCOND)], [Ex_BASE];
+    0b10110101_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(BCOND)], [Ex_BASE];
 ]
 "cbz" = [
-    0b00110100_00000000_00000000_00000000 = [W, Offset] => [R(0), Offset(BCOND)];
-    0b10110100_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(BCOND)];
+    0b00110100_00000000_00000000_00000000 = [W, Offset] => [R(0), Offset(BCOND)], [Ex_BASE];
+    0b10110100_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(BCOND)], [Ex_BASE];
 ]
 "ccmn" = [
     // CCMN (immediate)
-    0b00111010_01000000_00001000_00000000 = [W, Imm, Imm, Cond] => [R(5), Ubits(16, 5), Ubits(0, 4), Cond(12)];
-    0b10111010_01000000_00001000_00000000 = [X, Imm, Imm, Cond] => [R(5), Ubits(16, 5), Ubits(0, 4), Cond(12)];
+    0b00111010_01000000_00001000_00000000 = [W, Imm, Imm, Cond] => [R(5), Ubits(16, 5), Ubits(0, 4), Cond(12)], [Ex_BASE];
+    0b10111010_01000000_00001000_00000000 = [X, Imm, Imm, Cond] => [R(5), Ubits(16, 5), Ubits(0, 4), Cond(12)], [Ex_BASE];
     // CCMN (register)
-    0b00111010_01000000_00000000_00000000 = [W, W, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
-    0b10111010_01000000_00000000_00000000 = [X, X, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
+    0b00111010_01000000_00000000_00000000 = [W, W, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)], [Ex_BASE];
+    0b10111010_01000000_00000000_00000000 = [X, X, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)], [Ex_BASE];
 ]
 "ccmp" = [
     // CCMP (immediate)
-    0b01111010_01000000_00001000_00000000 = [W, Imm, Imm, Cond] => [R(5), Ubits(16, 5), Ubits(0, 4), Cond(12)];
-    0b11111010_01000000_00001000_00000000 = [X, Imm, Imm, Cond] => [R(5), Ubits(16, 5), Ubits(0, 4), Cond(12)];
+    0b01111010_01000000_00001000_00000000 = [W, Imm, Imm, Cond] => [R(5), Ubits(16, 5), Ubits(0, 4), Cond(12)], [Ex_BASE];
+    0b11111010_01000000_00001000_00000000 = [X, Imm, Imm, Cond] => [R(5), Ubits(16, 5), Ubits(0, 4), Cond(12)], [Ex_BASE];
     // CCMP (register)
-    0b01111010_01000000_00000000_00000000 = [W, W, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
-    0b11111010_01000000_00000000_00000000 = [X, X, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
+    0b01111010_01000000_00000000_00000000 = [W, W, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)], [Ex_BASE];
+    0b11111010_01000000_00000000_00000000 = [X, X, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)], [Ex_BASE];
 ]
 "cfinv" = [
-    0b11010101_00000000_01000000_00011111 = [] => [];
+    0b11010101_00000000_01000000_00011111 = [] => [], [Ex_FLAGM];
 ]
 "cfp" = [
-    0b11010101_00001011_01110011_10000000 = [Lit("rctx"), X] => [R(0)];
+    0b11010101_00001011_01110011_10000000 = [Lit("rctx"), X] => [R(0)], [Ex_PREDRES];
 ]
 "cinc" = [
-    0b00011010_10000000_00000100_00000000 = [W, W, Cond] => [R(0), R(5), C, R(16), CondInv(12)];
-    0b10011010_10000000_00000100_00000000 = [X, X, Cond] => [R(0), R(5), C, R(16), CondInv(12)];
+    0b00011010_10000000_00000100_00000000 = [W, W, Cond] => [R(0), R(5), C, R(16), CondInv(12)], [Ex_BASE];
+    0b10011010_10000000_00000100_00000000 = [X, X, Cond] => [R(0), R(5), C, R(16), CondInv(12)], [Ex_BASE];
 ]
 "cinv" = [
-    0b01011010_10000000_00000000_00000000 = [W, W, Cond] => [R(0), R(5), C, R(16), CondInv(12)];
-    0b11011010_10000000_00000000_00000000 = [X, X, Cond] => [R(0), R(5), C, R(16), CondInv(12)];
+    0b01011010_10000000_00000000_00000000 = [W, W, Cond] => [R(0), R(5), C, R(16), CondInv(12)], [Ex_BASE];
+    0b11011010_10000000_00000000_00000000 = [X, X, Cond] => [R(0), R(5), C, R(16), CondInv(12)], [Ex_BASE];
 ]
 "clrex" = [
-    0b11010101_00000011_00110000_01011111 = [Imm] => [Ubits(8, 4)];
-    0b11010101_00000011_00111111_01011111 = [] => [];
+    0b11010101_00000011_00110000_01011111 = [Imm] => [Ubits(8, 4)], [Ex_BASE];
+    0b11010101_00000011_00111111_01011111 = [] => [], [Ex_BASE];
 ]
 "cls" = [
     // CLS (vector)
-    0b00001110_00100000_01001000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100000_01001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100000_01001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_00100000_01001000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_01001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_01001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // CLS
-    0b01011010_11000000_00010100_00000000 = [W, W] => [R(0), R(5)];
-    0b11011010_11000000_00010100_00000000 = [X, X] => [R(0), R(5)];
+    0b01011010_11000000_00010100_00000000 = [W, W] => [R(0), R(5)], [Ex_BASE];
+    0b11011010_11000000_00010100_00000000 = [X, X] => [R(0), R(5)], [Ex_BASE];
+    // CLS (predicated)
+    0b00000100_00011000_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // CLS (predicated)
+    0b00000100_01011000_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // CLS (predicated)
+    0b00000100_10011000_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // CLS (predicated)
+    0b00000100_11011000_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "clz" = [
     // CLZ (vector)
-    0b00101110_00100000_01001000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100000_01001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100000_01001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_00100000_01001000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_01001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_01001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // CLZ
-    0b01011010_11000000_00010000_00000000 = [W, W] => [R(0), R(5)];
-    0b11011010_11000000_00010000_00000000 = [X, X] => [R(0), R(5)];
+    0b01011010_11000000_00010000_00000000 = [W, W] => [R(0), R(5)], [Ex_BASE];
+    0b11011010_11000000_00010000_00000000 = [X, X] => [R(0), R(5)], [Ex_BASE];
+    // CLZ (predicated)
+    0b00000100_00011001_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // CLZ (predicated)
+    0b00000100_01011001_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // CLZ (predicated)
+    0b00000100_10011001_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // CLZ (predicated)
+    0b00000100_11011001_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "cmeq" = [
     // CMEQ (register)
-    0b01111110_11100000_10001100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_00100000_10001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_10001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_10001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_10001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_11100000_10001100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_00100000_10001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_10001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_10001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_10001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // CMEQ (zero)
-    0b01011110_11100000_10011000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)];
-    0b00001110_00100000_10011000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100000_10011000_00000000 = [V(B_2), V(B_2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100000_10011000_00000000 = [V(B_4), V(B_4), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100000_10011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_11100000_10011000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_00100000_10011000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_10011000_00000000 = [V(B_2), V(B_2), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_10011000_00000000 = [V(B_4), V(B_4), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_10011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
 ]
 "cmge" = [
     // CMGE (register)
-    0b01011110_11100000_00111100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_00100000_00111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_00111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_00111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_00111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_11100000_00111100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_00100000_00111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_00111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_00111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_00111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // CMGE (zero)
-    0b01111110_11100000_10001000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)];
-    0b00101110_00100000_10001000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100000_10001000_00000000 = [V(B_2), V(B_2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100000_10001000_00000000 = [V(B_4), V(B_4), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_11100000_10001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b01111110_11100000_10001000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_00100000_10001000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_10001000_00000000 = [V(B_2), V(B_2), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_10001000_00000000 = [V(B_4), V(B_4), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_10001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
 ]
 "cmgt" = [
     // CMGT (register)
-    0b01011110_11100000_00110100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_00100000_00110100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_00110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_00110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_00110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_11100000_00110100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_00100000_00110100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_00110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_00110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_00110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // CMGT (zero)
-    0b01011110_11100000_10001000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)];
-    0b00001110_00100000_10001000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100000_10001000_00000000 = [V(B_2), V(B_2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100000_10001000_00000000 = [V(B_4), V(B_4), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100000_10001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_11100000_10001000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_00100000_10001000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_10001000_00000000 = [V(B_2), V(B_2), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_10001000_00000000 = [V(B_4), V(B_4), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_10001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
 ]
 "cmhi" = [
-    0b01111110_11100000_00110100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_00100000_00110100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_00110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_00110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_00110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_11100000_00110100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_00100000_00110100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_00110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_00110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_00110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
 ]
 "cmhs" = [
-    0b01111110_11100000_00111100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_00100000_00111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_00111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_00111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_00111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_11100000_00111100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_00100000_00111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_00111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_00111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_00111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
 ]
 "cmle" = [
-    0b01111110_11100000_10011000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)];
-    0b00101110_00100000_10011000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100000_10011000_00000000 = [V(B_2), V(B_2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100000_10011000_00000000 = [V(B_4), V(B_4), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_11100000_10011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b01111110_11100000_10011000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_00100000_10011000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_10011000_00000000 = [V(B_2), V(B_2), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_10011000_00000000 = [V(B_4), V(B_4), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_10011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
 ]
 "cmlt" = [
-    0b01011110_11100000_10101000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)];
-    0b00001110_00100000_10101000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100000_10101000_00000000 = [V(B_2), V(B_2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100000_10101000_00000000 = [V(B_4), V(B_4), LitInt(0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100000_10101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_11100000_10101000_00000000 = [D, D, LitInt(0)] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_00100000_10101000_00000000 = [V(BYTE), V(BYTE), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_10101000_00000000 = [V(B_2), V(B_2), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_10101000_00000000 = [V(B_4), V(B_4), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_10101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitInt(0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
 ]
 "cmn" = [
     // CMN (shifted register)
-    0b00101011_00000000_00000000_00011111 = [W, W, End, Mod(SHIFTS)] => [R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b10101011_00000000_00000000_00011111 = [X, X, End, Mod(SHIFTS)] => [R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b00101011_00000000_00000000_00011111 = [W, W, End, Mod(SHIFTS)] => [R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b10101011_00000000_00000000_00011111 = [X, X, End, Mod(SHIFTS)] => [R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
     // CMN (extended register)
-    0b00101011_00100000_00000000_00011111 = [WSP, W, End, Mod(EXTENDS)] => [R(5), R(16), ExtendsW(13), Urange(10, 0, 4)];
-    0b10101011_00100000_00000000_00011111 = [XSP, W, Mod(EXTENDS_W)] => [R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
-    0b10101011_00100000_00000000_00011111 = [XSP, X, End, Mod(EXTENDS_X)] => [R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    0b00101011_00100000_00000000_00011111 = [WSP, W, End, Mod(EXTENDS)] => [R(5), R(16), ExtendsW(13), Urange(10, 0, 4)], [Ex_BASE];
+    0b10101011_00100000_00000000_00011111 = [XSP, W, Mod(EXTENDS_W)] => [R(5), R(16), ExtendsX(13), Urange(10, 0, 4)], [Ex_BASE];
+    0b10101011_00100000_00000000_00011111 = [XSP, X, End, Mod(EXTENDS_X)] => [R(5), R(16), ExtendsX(13), Urange(10, 0, 4)], [Ex_BASE];
     // CMN (immediate)
-    0b00110001_00000000_00000000_00011111 = [WSP, Imm, End, LitMod(LSL)] => [R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
-    0b10110001_00000000_00000000_00011111 = [XSP, Imm, End, LitMod(LSL)] => [R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+    0b00110001_00000000_00000000_00011111 = [WSP, Imm, End, LitMod(LSL)] => [R(5), Ubits(10, 12), Ulist(22, &[0, 12])], [Ex_BASE];
+    0b10110001_00000000_00000000_00011111 = [XSP, Imm, End, LitMod(LSL)] => [R(5), Ubits(10, 12), Ulist(22, &[0, 12])], [Ex_BASE];
 ]
 "cmp" = [
     // CMP (shifted register)
-    0b01101011_00000000_00000000_00011111 = [W, W, End, Mod(SHIFTS)] => [R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b11101011_00000000_00000000_00011111 = [X, X, End, Mod(SHIFTS)] => [R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b01101011_00000000_00000000_00011111 = [W, W, End, Mod(SHIFTS)] => [R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b11101011_00000000_00000000_00011111 = [X, X, End, Mod(SHIFTS)] => [R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
     // CMP (extended register)
-    0b01101011_00100000_00000000_00011111 = [WSP, W, End, Mod(EXTENDS)] => [R(5), R(16), ExtendsW(13), Urange(10, 0, 4)];
-    0b11101011_00100000_00000000_00011111 = [XSP, W, Mod(EXTENDS_W)] => [R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
-    0b11101011_00100000_00000000_00011111 = [XSP, X, End, Mod(EXTENDS_X)] => [R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    0b01101011_00100000_00000000_00011111 = [WSP, W, End, Mod(EXTENDS)] => [R(5), R(16), ExtendsW(13), Urange(10, 0, 4)], [Ex_BASE];
+    0b11101011_00100000_00000000_00011111 = [XSP, W, Mod(EXTENDS_W)] => [R(5), R(16), ExtendsX(13), Urange(10, 0, 4)], [Ex_BASE];
+    0b11101011_00100000_00000000_00011111 = [XSP, X, End, Mod(EXTENDS_X)] => [R(5), R(16), ExtendsX(13), Urange(10, 0, 4)], [Ex_BASE];
     // CMP (immediate)
-    0b01110001_00000000_00000000_00011111 = [WSP, Imm, End, LitMod(LSL)] => [R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
-    0b11110001_00000000_00000000_00011111 = [XSP, Imm, End, LitMod(LSL)] => [R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+    0b01110001_00000000_00000000_00011111 = [WSP, Imm, End, LitMod(LSL)] => [R(5), Ubits(10, 12), Ulist(22, &[0, 12])], [Ex_BASE];
+    0b11110001_00000000_00000000_00011111 = [XSP, Imm, End, LitMod(LSL)] => [R(5), Ubits(10, 12), Ulist(22, &[0, 12])], [Ex_BASE];
+]
+"cmpeq" = [
+    // CMPEQ (vectors)
+    0b00100100_00000000_10100000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_01000000_10100000_00000000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_10000000_10100000_00000000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_11000000_10100000_00000000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // CMPEQ (immediate)
+    0b00100101_00000000_10000000_00000000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_01000000_10000000_00000000 = [P(B_2), PZero, Z(B_2), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_10000000_10000000_00000000 = [P(B_4), PZero, Z(B_4), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_11000000_10000000_00000000 = [P(B_8), PZero, Z(B_8), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+]
+"cmpge" = [
+    // CMPGE (vectors)
+    0b00100100_00000000_10000000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_01000000_10000000_00000000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_10000000_10000000_00000000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_11000000_10000000_00000000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // CMPGE (immediate)
+    0b00100101_00000000_00000000_00000000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_01000000_00000000_00000000 = [P(B_2), PZero, Z(B_2), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_10000000_00000000_00000000 = [P(B_4), PZero, Z(B_4), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_11000000_00000000_00000000 = [P(B_8), PZero, Z(B_8), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+]
+"cmpgt" = [
+    // CMPGT (vectors)
+    0b00100100_00000000_10000000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_01000000_10000000_00010000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_10000000_10000000_00010000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_11000000_10000000_00010000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // CMPGT (immediate)
+    0b00100101_00000000_00000000_00010000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_01000000_00000000_00010000 = [P(B_2), PZero, Z(B_2), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_10000000_00000000_00010000 = [P(B_4), PZero, Z(B_4), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_11000000_00000000_00010000 = [P(B_8), PZero, Z(B_8), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+]
+"cmphi" = [
+    // CMPHI (vectors)
+    0b00100100_00000000_00000000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_01000000_00000000_00010000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_10000000_00000000_00010000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_11000000_00000000_00010000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // CMPHI (immediate)
+    0b00100100_00100000_00000000_00010000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+    0b00100100_01100000_00000000_00010000 = [P(B_2), PZero, Z(B_2), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+    0b00100100_10100000_00000000_00010000 = [P(B_4), PZero, Z(B_4), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+    0b00100100_11100000_00000000_00010000 = [P(B_8), PZero, Z(B_8), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+]
+"cmphs" = [
+    // CMPHS (vectors)
+    0b00100100_00000000_00000000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_01000000_00000000_00000000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_10000000_00000000_00000000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_11000000_00000000_00000000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // CMPHS (immediate)
+    0b00100100_00100000_00000000_00000000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+    0b00100100_01100000_00000000_00000000 = [P(B_2), PZero, Z(B_2), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+    0b00100100_10100000_00000000_00000000 = [P(B_4), PZero, Z(B_4), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+    0b00100100_11100000_00000000_00000000 = [P(B_8), PZero, Z(B_8), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+]
+"cmple" = [
+    // CMPLE (vectors)
+    0b00100100_00000000_10000000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b00100100_01000000_10000000_00000000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b00100100_10000000_10000000_00000000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b00100100_11000000_10000000_00000000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    // CMPLE (immediate)
+    0b00100101_00000000_00100000_00010000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_01000000_00100000_00010000 = [P(B_2), PZero, Z(B_2), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_10000000_00100000_00010000 = [P(B_4), PZero, Z(B_4), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_11000000_00100000_00010000 = [P(B_8), PZero, Z(B_8), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+]
+"cmplo" = [
+    // CMPLO (vectors)
+    0b00100100_00000000_00000000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b00100100_01000000_00000000_00010000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b00100100_10000000_00000000_00010000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b00100100_11000000_00000000_00010000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    // CMPLO (immediate)
+    0b00100100_00100000_00100000_00000000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+    0b00100100_01100000_00100000_00000000 = [P(B_2), PZero, Z(B_2), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+    0b00100100_10100000_00100000_00000000 = [P(B_4), PZero, Z(B_4), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+    0b00100100_11100000_00100000_00000000 = [P(B_8), PZero, Z(B_8), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+]
+"cmpls" = [
+    // CMPLS (vectors)
+    0b00100100_00000000_00000000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b00100100_01000000_00000000_00000000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b00100100_10000000_00000000_00000000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b00100100_11000000_00000000_00000000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    // CMPLS (immediate)
+    0b00100100_00100000_00100000_00010000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+    0b00100100_01100000_00100000_00010000 = [P(B_2), PZero, Z(B_2), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+    0b00100100_10100000_00100000_00010000 = [P(B_4), PZero, Z(B_4), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+    0b00100100_11100000_00100000_00010000 = [P(B_8), PZero, Z(B_8), Imm] => [R4(0), R3(10), R(5), Ubits(14, 7)], [Ex_SVE];
+]
+"cmplt" = [
+    // CMPLT (vectors)
+    0b00100100_00000000_10000000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b00100100_01000000_10000000_00010000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b00100100_10000000_10000000_00010000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b00100100_11000000_10000000_00010000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    // CMPLT (immediate)
+    0b00100101_00000000_00100000_00000000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_01000000_00100000_00000000 = [P(B_2), PZero, Z(B_2), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_10000000_00100000_00000000 = [P(B_4), PZero, Z(B_4), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_11000000_00100000_00000000 = [P(B_8), PZero, Z(B_8), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+]
+"cmpne" = [
+    // CMPNE (vectors)
+    0b00100100_00000000_10100000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_01000000_10100000_00010000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_10000000_10100000_00010000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b00100100_11000000_10100000_00010000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // CMPNE (immediate)
+    0b00100101_00000000_10000000_00010000 = [P(BYTE), PZero, Z(BYTE), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_01000000_10000000_00010000 = [P(B_2), PZero, Z(B_2), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_10000000_10000000_00010000 = [P(B_4), PZero, Z(B_4), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00100101_11000000_10000000_00010000 = [P(B_8), PZero, Z(B_8), Imm] => [R4(0), R3(10), R(5), Sbits(16, 5)], [Ex_SVE];
+]
+"cmpp" = [
+    0b10111010_11000000_00000000_00011111 = [XSP, XSP] => [R(5), R(16)], [Ex_MTE];
 ]
 "cmtst" = [
-    0b01011110_11100000_10001100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_00100000_10001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_10001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_10001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_10001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_11100000_10001100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_00100000_10001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_10001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_10001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_10001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
 ]
 "cneg" = [
-    0b01011010_10000000_00000100_00000000 = [W, W, Cond] => [R(0), R(5), C, R(16), CondInv(12)];
-    0b11011010_10000000_00000100_00000000 = [X, X, Cond] => [R(0), R(5), C, R(16), CondInv(12)];
+    0b01011010_10000000_00000100_00000000 = [W, W, Cond] => [R(0), R(5), C, R(16), CondInv(12)], [Ex_BASE];
+    0b11011010_10000000_00000100_00000000 = [X, X, Cond] => [R(0), R(5), C, R(16), CondInv(12)], [Ex_BASE];
+]
+"cnot" = [
+    // CNOT (predicated)
+    0b00000100_00011011_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // CNOT (predicated)
+    0b00000100_01011011_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // CNOT (predicated)
+    0b00000100_10011011_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // CNOT (predicated)
+    0b00000100_11011011_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "cnt" = [
-    0b00001110_00100000_01011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_00100000_01011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    // CNT (predicated)
+    0b00000100_00011010_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // CNT (predicated)
+    0b00000100_01011010_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // CNT (predicated)
+    0b00000100_10011010_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // CNT (predicated)
+    0b00000100_11011010_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+]
+"cntb" = [
+    0b00000100_00100000_11100011_11100000 = [X] => [R(0)], [Ex_SVE];
+    0b00000100_00100000_11100000_00000000 = [X, Ident] => [R(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00000100_00100000_11100000_00000000 = [X, Ident, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], [Ex_SVE];
+]
+"cntd" = [
+    0b00000100_11100000_11100011_11100000 = [X] => [R(0)], [Ex_SVE];
+    0b00000100_11100000_11100000_00000000 = [X, Ident] => [R(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00000100_11100000_11100000_00000000 = [X, Ident, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], [Ex_SVE];
+]
+"cnth" = [
+    0b00000100_01100000_11100011_11100000 = [X] => [R(0)], [Ex_SVE];
+    0b00000100_01100000_11100000_00000000 = [X, Ident] => [R(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00000100_01100000_11100000_00000000 = [X, Ident, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], [Ex_SVE];
+]
+"cntp" = [
+    0b00100101_00100000_10000000_00000000 = [X, PBare, P(BYTE)] => [R(0), R4(10), R4(5)], [Ex_SVE];
+    0b00100101_01100000_10000000_00000000 = [X, PBare, P(B_2)] => [R(0), R4(10), R4(5)], [Ex_SVE];
+    0b00100101_10100000_10000000_00000000 = [X, PBare, P(B_4)] => [R(0), R4(10), R4(5)], [Ex_SVE];
+    0b00100101_11100000_10000000_00000000 = [X, PBare, P(B_8)] => [R(0), R4(10), R4(5)], [Ex_SVE];
+]
+"cntw" = [
+    0b00000100_10100000_11100011_11100000 = [X] => [R(0)], [Ex_SVE];
+    0b00000100_10100000_11100000_00000000 = [X, Ident] => [R(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00000100_10100000_11100000_00000000 = [X, Ident, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], [Ex_SVE];
+]
+"compact" = [
+    0b00000101_10100001_10000000_00000000 = [Z(B_4), PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_11100001_10000000_00000000 = [Z(B_8), PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "cpp" = [
-    0b11010101_00001011_01110011_11100000 = [Lit("rctx"), X] => [R(0)];
+    0b11010101_00001011_01110011_11100000 = [Lit("rctx"), X] => [R(0)], [Ex_PREDRES];
+]
+"cpy" = [
+    // CPY (scalar, predicated)
+    0b00000101_00101000_10100000_00000000 = [Z(BYTE), PMerge, WSP] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_01101000_10100000_00000000 = [Z(B_2), PMerge, WSP] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_10101000_10100000_00000000 = [Z(B_4), PMerge, WSP] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_11101000_10100000_00000000 = [Z(B_8), PMerge, XSP] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // CPY (SIMD&FP scalar, predicated)
+    0b00000101_00100000_10000000_00000000 = [Z(BYTE), PMerge, B] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_01100000_10000000_00000000 = [Z(B_2), PMerge, H] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_10100000_10000000_00000000 = [Z(B_4), PMerge, S] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_11100000_10000000_00000000 = [Z(B_8), PMerge, D] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // CPY (immediate, zeroing)
+    0b00000101_00010000_00000000_00000000 = [Z(BYTE), PZero, Imm] => [R(0), R4(16), Sbits(5, 8)], [Ex_SVE];
+    0b00000101_01010000_00000000_00000000 = [Z(B_2), PZero, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00000101_10010000_00000000_00000000 = [Z(B_4), PZero, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00000101_11010000_00000000_00000000 = [Z(B_8), PZero, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    // CPY (immediate, merging)
+    0b00000101_00010000_01000000_00000000 = [Z(BYTE), PMerge, Imm] => [R(0), R4(16), Sbits(5, 8)], [Ex_SVE];
+    0b00000101_01010000_01000000_00000000 = [Z(B_2), PMerge, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00000101_10010000_01000000_00000000 = [Z(B_4), PMerge, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00000101_11010000_01000000_00000000 = [Z(B_8), PMerge, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+]
+"cpye" = [
+    0b00011101_10000000_00000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyen" = [
+    0b00011101_10000000_11000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyern" = [
+    0b00011101_10000000_10000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyert" = [
+    0b00011101_10000000_00100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyertn" = [
+    0b00011101_10000000_11100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyertrn" = [
+    0b00011101_10000000_10100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyertwn" = [
+    0b00011101_10000000_01100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyet" = [
+    0b00011101_10000000_00110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyetn" = [
+    0b00011101_10000000_11110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyetrn" = [
+    0b00011101_10000000_10110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyetwn" = [
+    0b00011101_10000000_01110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyewn" = [
+    0b00011101_10000000_01000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyewt" = [
+    0b00011101_10000000_00010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyewtn" = [
+    0b00011101_10000000_11010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyewtrn" = [
+    0b00011101_10000000_10010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyewtwn" = [
+    0b00011101_10000000_01010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfe" = [
+    0b00011001_10000000_00000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfen" = [
+    0b00011001_10000000_11000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfern" = [
+    0b00011001_10000000_10000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfert" = [
+    0b00011001_10000000_00100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfertn" = [
+    0b00011001_10000000_11100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfertrn" = [
+    0b00011001_10000000_10100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfertwn" = [
+    0b00011001_10000000_01100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfet" = [
+    0b00011001_10000000_00110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfetn" = [
+    0b00011001_10000000_11110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfetrn" = [
+    0b00011001_10000000_10110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfetwn" = [
+    0b00011001_10000000_01110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfewn" = [
+    0b00011001_10000000_01000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfewt" = [
+    0b00011001_10000000_00010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfewtn" = [
+    0b00011001_10000000_11010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfewtrn" = [
+    0b00011001_10000000_10010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfewtwn" = [
+    0b00011001_10000000_01010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfm" = [
+    0b00011001_01000000_00000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmn" = [
+    0b00011001_01000000_11000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmrn" = [
+    0b00011001_01000000_10000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmrt" = [
+    0b00011001_01000000_00100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmrtn" = [
+    0b00011001_01000000_11100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmrtrn" = [
+    0b00011001_01000000_10100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmrtwn" = [
+    0b00011001_01000000_01100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmt" = [
+    0b00011001_01000000_00110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmtn" = [
+    0b00011001_01000000_11110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmtrn" = [
+    0b00011001_01000000_10110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmtwn" = [
+    0b00011001_01000000_01110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmwn" = [
+    0b00011001_01000000_01000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmwt" = [
+    0b00011001_01000000_00010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmwtn" = [
+    0b00011001_01000000_11010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmwtrn" = [
+    0b00011001_01000000_10010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfmwtwn" = [
+    0b00011001_01000000_01010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfp" = [
+    0b00011001_00000000_00000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfpn" = [
+    0b00011001_00000000_11000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfprn" = [
+    0b00011001_00000000_10000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfprt" = [
+    0b00011001_00000000_00100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfprtn" = [
+    0b00011001_00000000_11100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfprtrn" = [
+    0b00011001_00000000_10100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfprtwn" = [
+    0b00011001_00000000_01100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfpt" = [
+    0b00011001_00000000_00110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfptn" = [
+    0b00011001_00000000_11110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfptrn" = [
+    0b00011001_00000000_10110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfptwn" = [
+    0b00011001_00000000_01110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfpwn" = [
+    0b00011001_00000000_01000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfpwt" = [
+    0b00011001_00000000_00010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfpwtn" = [
+    0b00011001_00000000_11010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfpwtrn" = [
+    0b00011001_00000000_10010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyfpwtwn" = [
+    0b00011001_00000000_01010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpym" = [
+    0b00011101_01000000_00000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymn" = [
+    0b00011101_01000000_11000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymrn" = [
+    0b00011101_01000000_10000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymrt" = [
+    0b00011101_01000000_00100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymrtn" = [
+    0b00011101_01000000_11100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymrtrn" = [
+    0b00011101_01000000_10100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymrtwn" = [
+    0b00011101_01000000_01100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymt" = [
+    0b00011101_01000000_00110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymtn" = [
+    0b00011101_01000000_11110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymtrn" = [
+    0b00011101_01000000_10110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymtwn" = [
+    0b00011101_01000000_01110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymwn" = [
+    0b00011101_01000000_01000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymwt" = [
+    0b00011101_01000000_00010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymwtn" = [
+    0b00011101_01000000_11010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymwtrn" = [
+    0b00011101_01000000_10010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpymwtwn" = [
+    0b00011101_01000000_01010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyp" = [
+    0b00011101_00000000_00000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpypn" = [
+    0b00011101_00000000_11000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyprn" = [
+    0b00011101_00000000_10000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyprt" = [
+    0b00011101_00000000_00100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyprtn" = [
+    0b00011101_00000000_11100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyprtrn" = [
+    0b00011101_00000000_10100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyprtwn" = [
+    0b00011101_00000000_01100100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpypt" = [
+    0b00011101_00000000_00110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyptn" = [
+    0b00011101_00000000_11110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyptrn" = [
+    0b00011101_00000000_10110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpyptwn" = [
+    0b00011101_00000000_01110100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpypwn" = [
+    0b00011101_00000000_01000100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpypwt" = [
+    0b00011101_00000000_00010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpypwtn" = [
+    0b00011101_00000000_11010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpypwtrn" = [
+    0b00011101_00000000_10010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
+]
+"cpypwtwn" = [
+    0b00011101_00000000_01010100_00000000 = [RefWriteback, RefWriteback, XWriteback] => [RNoZr(0), RNoZr(16), RNoZr(5)], [Ex_MOPS];
 ]
 "crc32b" = [
-    0b00011010_11000000_01000000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b00011010_11000000_01000000_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_CRC];
 ]
 "crc32cb" = [
-    0b00011010_11000000_01010000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b00011010_11000000_01010000_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_CRC];
 ]
 "crc32ch" = [
-    0b00011010_11000000_01010100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b00011010_11000000_01010100_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_CRC];
 ]
 "crc32cw" = [
-    0b00011010_11000000_01011000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b00011010_11000000_01011000_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_CRC];
 ]
 "crc32cx" = [
-    0b10011010_11000000_01011100_00000000 = [W, W, X] => [R(0), R(5), R(16)];
+    0b10011010_11000000_01011100_00000000 = [W, W, X] => [R(0), R(5), R(16)], [Ex_CRC];
 ]
 "crc32h" = [
-    0b00011010_11000000_01000100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b00011010_11000000_01000100_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_CRC];
 ]
 "crc32w" = [
-    0b00011010_11000000_01001000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b00011010_11000000_01001000_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_CRC];
 ]
 "crc32x" = [
-    0b10011010_11000000_01001100_00000000 = [W, W, X] => [R(0), R(5), R(16)];
+    0b10011010_11000000_01001100_00000000 = [W, W, X] => [R(0), R(5), R(16)], [Ex_CRC];
 ]
 "csdb" = [
-    0b11010101_00000011_00100010_10011111 = [] => [];
+    0b11010101_00000011_00100010_10011111 = [] => [], [Ex_BASE];
 ]
 "csel" = [
-    0b00011010_10000000_00000000_00000000 = [W, W, W, Cond] => [R(0), R(5), R(16), Cond(12)];
-    0b10011010_10000000_00000000_00000000 = [X, X, X, Cond] => [R(0), R(5), R(16), Cond(12)];
+    0b00011010_10000000_00000000_00000000 = [W, W, W, Cond] => [R(0), R(5), R(16), Cond(12)], [Ex_BASE];
+    0b10011010_10000000_00000000_00000000 = [X, X, X, Cond] => [R(0), R(5), R(16), Cond(12)], [Ex_BASE];
 ]
 "cset" = [
-    0b00011010_10011111_00000111_11100000 = [W, Cond] => [R(0), CondInv(12)];
-    0b10011010_10011111_00000111_11100000 = [X, Cond] => [R(0), CondInv(12)];
+    0b00011010_10011111_00000111_11100000 = [W, Cond] => [R(0), CondInv(12)], [Ex_BASE];
+    0b10011010_10011111_00000111_11100000 = [X, Cond] => [R(0), CondInv(12)], [Ex_BASE];
 ]
 "csetm" = [
-    0b01011010_10011111_00000011_11100000 = [W, Cond] => [R(0), CondInv(12)];
-    0b11011010_10011111_00000011_11100000 = [X, Cond] => [R(0), CondInv(12)];
+    0b01011010_10011111_00000011_11100000 = [W, Cond] => [R(0), CondInv(12)], [Ex_BASE];
+    0b11011010_10011111_00000011_11100000 = [X, Cond] => [R(0), CondInv(12)], [Ex_BASE];
 ]
 "csinc" = [
-    0b00011010_10000000_00000100_00000000 = [W, W, W, Cond] => [R(0), R(5), R(16), Cond(12)];
-    0b10011010_10000000_00000100_00000000 = [X, X, X, Cond] => [R(0), R(5), R(16), Cond(12)];
+    0b00011010_10000000_00000100_00000000 = [W, W, W, Cond] => [R(0), R(5), R(16), Cond(12)], [Ex_BASE];
+    0b10011010_10000000_00000100_00000000 = [X, X, X, Cond] => [R(0), R(5), R(16), Cond(12)], [Ex_BASE];
 ]
 "csinv" = [
-    0b01011010_10000000_00000000_00000000 = [W, W, W, Cond] => [R(0), R(5), R(16), Cond(12)];
-    0b11011010_10000000_00000000_00000000 = [X, X, X, Cond] => [R(0), R(5), R(16), Cond(12)];
+    0b01011010_10000000_00000000_00000000 = [W, W, W, Cond] => [R(0), R(5), R(16), Cond(12)], [Ex_BASE];
+    0b11011010_10000000_00000000_00000000 = [X, X, X, Cond] => [R(0), R(5), R(16), Cond(12)], [Ex_BASE];
 ]
 "csneg" = [
-    0b01011010_10000000_00000100_00000000 = [W, W, W, Cond] => [R(0), R(5), R(16), Cond(12)];
-    0b11011010_10000000_00000100_00000000 = [X, X, X, Cond] => [R(0), R(5), R(16), Cond(12)];
+    0b01011010_10000000_00000100_00000000 = [W, W, W, Cond] => [R(0), R(5), R(16), Cond(12)], [Ex_BASE];
+    0b11011010_10000000_00000100_00000000 = [X, X, X, Cond] => [R(0), R(5), R(16), Cond(12)], [Ex_BASE];
 ]
 "dc" = [
-    0b11010101_00001000_01110000_00000000 = [Ident, X] => [LitList(5, "DC_OPS"), R(0)];
+    0b11010101_00001000_01110000_00000000 = [Ident, X] => [LitList(5, "DC_OPS"), R(0)], [Ex_BASE];
 ]
 "dcps1" = [
-    0b11010100_10100000_00000000_00000001 = [End, Imm] => [Ubits(5, 16)];
+    0b11010100_10100000_00000000_00000001 = [End, Imm] => [Ubits(5, 16)], [Ex_BASE];
 ]
 "dcps2" = [
-    0b11010100_10100000_00000000_00000010 = [End, Imm] => [Ubits(5, 16)];
+    0b11010100_10100000_00000000_00000010 = [End, Imm] => [Ubits(5, 16)], [Ex_BASE];
 ]
 "dcps3" = [
-    0b11010100_10100000_00000000_00000011 = [End, Imm] => [Ubits(5, 16)];
+    0b11010100_10100000_00000000_00000011 = [End, Imm] => [Ubits(5, 16)], [Ex_BASE];
+]
+"decb" = [
+    0b00000100_00110000_11100111_11100000 = [X] => [R(0)], [Ex_SVE];
+    0b00000100_00110000_11100100_00000000 = [X, Ident] => [R(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00000100_00110000_11100100_00000000 = [X, Ident, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], [Ex_SVE];
+]
+"decd" = [
+    0b00000100_11110000_11100111_11100000 = [X] => [R(0)], [Ex_SVE];
+    0b00000100_11110000_11100100_00000000 = [X, Ident] => [R(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00000100_11110000_11100100_00000000 = [X, Ident, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], [Ex_SVE];
+]
+"dech" = [
+    0b00000100_01110000_11100111_11100000 = [X] => [R(0)], [Ex_SVE];
+    0b00000100_01110000_11100100_00000000 = [X, Ident] => [R(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00000100_01110000_11100100_00000000 = [X, Ident, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], [Ex_SVE];
+]
+"decp" = [
+    // DECP (scalar)
+    0b00100101_00101101_10001000_00000000 = [X, P(BYTE)] => [R(0), R4(5)], [Ex_SVE];
+    0b00100101_01101101_10001000_00000000 = [X, P(B_2)] => [R(0), R4(5)], [Ex_SVE];
+    0b00100101_10101101_10001000_00000000 = [X, P(B_4)] => [R(0), R4(5)], [Ex_SVE];
+    0b00100101_11101101_10001000_00000000 = [X, P(B_8)] => [R(0), R4(5)], [Ex_SVE];
+]
+"decw" = [
+    0b00000100_10110000_11100111_11100000 = [X] => [R(0)], [Ex_SVE];
+    0b00000100_10110000_11100100_00000000 = [X, Ident] => [R(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00000100_10110000_11100100_00000000 = [X, Ident, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], [Ex_SVE];
 ]
 "dmb" = [
-    0b11010101_00000011_00110000_10111111 = [Ident] => [LitList(8, "BARRIER_OPS")];
-    0b11010101_00000011_00110000_10111111 = [Imm] => [Ubits(8, 4)];
+    0b11010101_00000011_00110000_10111111 = [Ident] => [LitList(8, "BARRIER_OPS")], [Ex_BASE];
+    0b11010101_00000011_00110000_10111111 = [Imm] => [Ubits(8, 4)], [Ex_BASE];
 ]
 "drps" = [
-    0b11010110_10111111_00000011_11100000 = [] => [];
+    0b11010110_10111111_00000011_11100000 = [] => [], [Ex_BASE];
 ]
 "dsb" = [
-    0b11010101_00000011_00110000_10011111 = [Ident] => [LitList(8, "BARRIER_OPS")];
-    0b11010101_00000011_00110000_10011111 = [Imm] => [Ubits(8, 4)];
+    0b11010101_00000011_00110000_10011111 = [Ident] => [LitList(8, "BARRIER_OPS")], [Ex_BASE];
+    0b11010101_00000011_00110000_10011111 = [Imm] => [Ubits(8, 4)], [Ex_BASE];
 ]
 "dup" = [
     // DUP (element)
-    0b01011110_00000001_00000100_00000000 = [B, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)];
-    0b01011110_00000010_00000100_00000000 = [H, VElement(B_2)] => [R(0), R(5), Ubits(18, 3)];
-    0b01011110_00000100_00000100_00000000 = [S, VElement(B_4)] => [R(0), R(5), Ubits(19, 2)];
-    0b01011110_00001000_00000100_00000000 = [D, VElement(B_8)] => [R(0), R(5), Ubits(20, 1)];
-    0b00001110_00000001_00000100_00000000 = [V(BYTE), VElement(BYTE)] => [R(0), R(5), Ubits(17, 4), Rwidth(30)];
-    0b00001110_00000010_00000100_00000000 = [V(B_2), VElement(B_2)] => [R(0), R(5), Ubits(18, 3), Rwidth(30)];
-    0b00001110_00000100_00000100_00000000 = [V(B_4), VElement(B_4)] => [R(0), R(5), Ubits(19, 2), Rwidth(30)];
-    0b00001110_00001000_00000100_00000000 = [VStatic(B_8, 2), VElement(B_8)] => [R(0), R(5), Ubits(20, 1), Rwidth(30)];
+    0b01011110_00000001_00000100_00000000 = [B, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)], [Ex_BASE];
+    0b01011110_00000010_00000100_00000000 = [H, VElement(B_2)] => [R(0), R(5), Ubits(18, 3)], [Ex_BASE];
+    0b01011110_00000100_00000100_00000000 = [S, VElement(B_4)] => [R(0), R(5), Ubits(19, 2)], [Ex_BASE];
+    0b01011110_00001000_00000100_00000000 = [D, VElement(B_8)] => [R(0), R(5), Ubits(20, 1)], [Ex_BASE];
+    0b00001110_00000001_00000100_00000000 = [V(BYTE), VElement(BYTE)] => [R(0), R(5), Ubits(17, 4), Rwidth(30)], [Ex_BASE];
+    0b00001110_00000010_00000100_00000000 = [V(B_2), VElement(B_2)] => [R(0), R(5), Ubits(18, 3), Rwidth(30)], [Ex_BASE];
+    0b00001110_00000100_00000100_00000000 = [V(B_4), VElement(B_4)] => [R(0), R(5), Ubits(19, 2), Rwidth(30)], [Ex_BASE];
+    0b00001110_00001000_00000100_00000000 = [VStatic(B_8, 2), VElement(B_8)] => [R(0), R(5), Ubits(20, 1), Rwidth(30)], [Ex_BASE];
     // DUP (general)
-    0b00001110_00000001_00001100_00000000 = [V(BYTE), W] => [R(0), Rwidth(30), R(5)];
-    0b00001110_00000010_00001100_00000000 = [V(B_2), W] => [R(0), Rwidth(30), R(5)];
-    0b00001110_00000100_00001100_00000000 = [V(B_4), W] => [R(0), Rwidth(30), R(5)];
-    0b00001110_00001000_00001100_00000000 = [VStatic(B_8, 2), X] => [R(0), Rwidth(30), R(5)];
+    0b00001110_00000001_00001100_00000000 = [V(BYTE), W] => [R(0), Rwidth(30), R(5)], [Ex_BASE];
+    0b00001110_00000010_00001100_00000000 = [V(B_2), W] => [R(0), Rwidth(30), R(5)], [Ex_BASE];
+    0b00001110_00000100_00001100_00000000 = [V(B_4), W] => [R(0), Rwidth(30), R(5)], [Ex_BASE];
+    0b00001110_00001000_00001100_00000000 = [VStatic(B_8, 2), X] => [R(0), Rwidth(30), R(5)], [Ex_BASE];
+    // DUP (scalar)
+    0b00000101_00100000_00111000_00000000 = [Z(BYTE), WSP] => [R(0), R(5)], [Ex_SVE];
+    0b00000101_01100000_00111000_00000000 = [Z(B_2), WSP] => [R(0), R(5)], [Ex_SVE];
+    0b00000101_10100000_00111000_00000000 = [Z(B_4), WSP] => [R(0), R(5)], [Ex_SVE];
+    0b00000101_11100000_00111000_00000000 = [Z(B_8), XSP] => [R(0), R(5)], [Ex_SVE];
+    // DUP (immediate)
+    0b00100101_00111000_11000000_00000000 = [Z(BYTE), Imm] => [R(0), Sbits(5, 8)], [Ex_SVE];
+    0b00100101_01111000_11000000_00000000 = [Z(B_2), Imm, End, LitMod(LSL)] => [R(0), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_10111000_11000000_00000000 = [Z(B_4), Imm, End, LitMod(LSL)] => [R(0), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_11111000_11000000_00000000 = [Z(B_8), Imm, End, LitMod(LSL)] => [R(0), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    // DUP (indexed)
+    0b00000101_00100001_00100000_00000000 = [Z(BYTE), ZElement(BYTE)] => [R(0), R(5), Ufields(&[23, 22, 20, 19, 18, 17])], [Ex_SVE];
+    0b00000101_00100010_00100000_00000000 = [Z(B_2), ZElement(B_2)] => [R(0), R(5), Ufields(&[23, 22, 20, 19, 18])], [Ex_SVE];
+    0b00000101_00100100_00100000_00000000 = [Z(B_4), ZElement(B_4)] => [R(0), R(5), Ufields(&[23, 22, 20, 19])], [Ex_SVE];
+    0b00000101_00101000_00100000_00000000 = [Z(B_8), ZElement(B_8)] => [R(0), R(5), Ufields(&[23, 22, 20])], [Ex_SVE];
+    0b00000101_00110000_00100000_00000000 = [Z(B_16), ZElement(B_16)] => [R(0), R(5), Ufields(&[23, 22])], [Ex_SVE];
 ]
 "dvp" = [
-    0b11010101_00001011_01110011_10100000 = [Lit("rctx"), X] => [R(0)];
+    0b11010101_00001011_01110011_10100000 = [Lit("rctx"), X] => [R(0)], [Ex_PREDRES];
 ]
 "eon" = [
-    0b01001010_00100000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b11001010_00100000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b01001010_00100000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b11001010_00100000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
 ]
 "eor" = [
     // EOR (vector)
-    0b00101110_00100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_00100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // EOR (immediate)
-    0b01010010_00000000_00000000_00000000 = [WSP, W, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_W)];
-    0b11010010_00000000_00000000_00000000 = [XSP, X, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_X)];
+    0b01010010_00000000_00000000_00000000 = [WSP, W, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_W)], [Ex_BASE];
+    0b11010010_00000000_00000000_00000000 = [XSP, X, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_X)], [Ex_BASE];
     // EOR (shifted register)
-    0b01001010_00000000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b11001010_00000000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b01001010_00000000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b11001010_00000000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
+    // EOR (vectors, predicated)
+    0b00000100_00011001_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // EOR (vectors, predicated)
+    0b00000100_01011001_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // EOR (vectors, predicated)
+    0b00000100_10011001_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // EOR (vectors, predicated)
+    0b00000100_11011001_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // EOR (vectors, unpredicated)
+    0b00000100_10100000_00110000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // EOR (predicates)
+    0b00100101_00000000_01000010_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
 ]
 "eor3" = [
-    0b11001110_00000000_00000000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16), R(10)];
+    0b11001110_00000000_00000000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16), R(10)], [Ex_SHA3];
+    0b00000100_00100000_00111000_00000000 = [Z(B_8), Z(B_8), Z(B_8), Z(B_8)] => [R(0), RTied, R(16), R(5)], [Ex_SVE2];
+]
+"eors" = [
+    // EORS (predicates)
+    0b00100101_01000000_01000010_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
+]
+"eorv" = [
+    0b00000100_00011001_00100000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_01011001_00100000_00000000 = [H, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_10011001_00100000_00000000 = [S, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_11011001_00100000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "eret" = [
-    0b11010110_10011111_00000011_11100000 = [] => [];
+    0b11010110_10011111_00000011_11100000 = [] => [], [Ex_BASE];
 ]
 "eretaa" = [
-    0b11010110_10011111_00001011_11111111 = [] => [];
+    0b11010110_10011111_00001011_11111111 = [] => [], [Ex_PAUTH];
 ]
 "eretab" = [
-    0b11010110_10011111_00001111_11111111 = [] => [];
+    0b11010110_10011111_00001111_11111111 = [] => [], [Ex_PAUTH];
 ]
 "esb" = [
-    0b11010101_00000011_00100010_00011111 = [] => [];
+    0b11010101_00000011_00100010_00011111 = [] => [], [Ex_RAS];
 ]
 "ext" = [
-    0b00101110_00000000_00000000_00000000 = [VStatic(BYTE, 8), VStatic(BYTE, 8), VStatic(BYTE, 8), Imm] => [R(0), R(5), R(16), Ubits(11, 3)];
-    0b01101110_00000000_00000000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16), Imm] => [R(0), R(5), R(16), Ubits(11, 4)];
+    0b00101110_00000000_00000000_00000000 = [VStatic(BYTE, 8), VStatic(BYTE, 8), VStatic(BYTE, 8), Imm] => [R(0), R(5), R(16), Ubits(11, 3)], [Ex_BASE];
+    0b01101110_00000000_00000000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16), Imm] => [R(0), R(5), R(16), Ubits(11, 4)], [Ex_BASE];
+    0b00000101_00100000_00000000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, R(5), CUbits(8), Uslice(10, 3, 0), Uslice(16, 5, 3), A], [Ex_SVE];
 ]
 "extr" = [
-    0b00010011_10000000_00000000_00000000 = [W, W, W, Imm] => [R(0), R(5), R(16), Ubits(10, 5)];
-    0b10010011_11000000_00000000_00000000 = [X, X, X, Imm] => [R(0), R(5), R(16), Ubits(10, 6)];
+    0b00010011_10000000_00000000_00000000 = [W, W, W, Imm] => [R(0), R(5), R(16), Ubits(10, 5)], [Ex_BASE];
+    0b10010011_11000000_00000000_00000000 = [X, X, X, Imm] => [R(0), R(5), R(16), Ubits(10, 6)], [Ex_BASE];
 ]
 "fabd" = [
-    0b01111110_11000000_00010100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01111110_10100000_11010100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01111110_11100000_11010100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_11000000_00010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_11010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_11010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_11000000_00010100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b01111110_10100000_11010100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_11100000_11010100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_11000000_00010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00101110_10100000_11010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_11010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // FABD (vectors, predicated)
+    0b01100101_01001000_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FABD (vectors, predicated)
+    0b01100101_10001000_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FABD (vectors, predicated)
+    0b01100101_11001000_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "fabs" = [
     // FABS (vector)
-    0b00001110_11111000_11111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100000_11111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100000_11111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11111000_11111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_10100000_11111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_11111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FABS (scalar)
-    0b00011110_11100000_11000000_00000000 = [H, H] => [R(0), R(5)];
-    0b00011110_00100000_11000000_00000000 = [S, S] => [R(0), R(5)];
-    0b00011110_01100000_11000000_00000000 = [D, D] => [R(0), R(5)];
+    0b00011110_11100000_11000000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100000_11000000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100000_11000000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    // FABS (predicated)
+    0b00000100_01011100_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FABS (predicated)
+    0b00000100_10011100_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FABS (predicated)
+    0b00000100_11011100_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "facge" = [
-    0b01111110_01000000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01111110_00100000_11101100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01111110_01100000_11101100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_01000000_00101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_00100000_11101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_11101100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_01000000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b01111110_00100000_11101100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_01100000_11101100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_01000000_00101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00101110_00100000_11101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_11101100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // FACGE (vectors)
+    0b01100101_01000000_11000000_00010000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b01100101_10000000_11000000_00010000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b01100101_11000000_11000000_00010000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
 ]
 "facgt" = [
-    0b01111110_11000000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01111110_10100000_11101100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01111110_11100000_11101100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_11000000_00101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_11101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_11101100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_11000000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b01111110_10100000_11101100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_11100000_11101100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_11000000_00101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00101110_10100000_11101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_11101100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // FACGT (vectors)
+    0b01100101_01000000_11100000_00010000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b01100101_10000000_11100000_00010000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b01100101_11000000_11100000_00010000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+]
+"facle" = [
+    // FACLE (vectors)
+    0b01100101_01000000_11000000_00010000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b01100101_10000000_11000000_00010000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b01100101_11000000_11000000_00010000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+]
+"faclt" = [
+    // FACLT (vectors)
+    0b01100101_01000000_11100000_00010000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b01100101_10000000_11100000_00010000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b01100101_11000000_11100000_00010000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
 ]
 "fadd" = [
     // FADD (vector)
-    0b00001110_01000000_00010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_00100000_11010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_11010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_00010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00001110_00100000_11010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_11010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // FADD (scalar)
-    0b00011110_11100000_00101000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b00011110_00100000_00101000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00011110_01100000_00101000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00011110_11100000_00101000_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b00011110_00100000_00101000_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00011110_01100000_00101000_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    // FADD (vectors, predicated)
+    0b01100101_01000000_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FADD (vectors, predicated)
+    0b01100101_10000000_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FADD (vectors, predicated)
+    0b01100101_11000000_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FADD (vectors, unpredicated)
+    0b01100101_01000000_00000000_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // FADD (vectors, unpredicated)
+    0b01100101_10000000_00000000_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // FADD (vectors, unpredicated)
+    0b01100101_11000000_00000000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+]
+"fadda" = [
+    0b01100101_01011000_00100000_00000000 = [H, PBare, H, Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    0b01100101_10011000_00100000_00000000 = [S, PBare, S, Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    0b01100101_11011000_00100000_00000000 = [D, PBare, D, Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "faddp" = [
     // FADDP (scalar)
-    0b01011110_00110000_11011000_00000000 = [H, VStatic(B_2, 2)] => [R(0), R(5)];
-    0b01111110_00110000_11011000_00000000 = [S, VStatic(B_4, 2)] => [R(0), R(5)];
-    0b01111110_01110000_11011000_00000000 = [D, VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01011110_00110000_11011000_00000000 = [H, VStatic(B_2, 2)] => [R(0), R(5)], [Ex_FP16];
+    0b01111110_00110000_11011000_00000000 = [S, VStatic(B_4, 2)] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_01110000_11011000_00000000 = [D, VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
     // FADDP (vector)
-    0b00101110_01000000_00010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_00100000_11010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_11010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01000000_00010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00101110_00100000_11010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_11010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // FADDP (vectors, predicated)
+    0b01100100_01010000_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // FADDP (vectors, predicated)
+    0b01100100_10010000_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // FADDP (vectors, predicated)
+    0b01100100_11010000_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+]
+"faddv" = [
+    0b01100101_01000000_00100000_00000000 = [H, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_10000000_00100000_00000000 = [S, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_11000000_00100000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fcadd" = [
-    0b00101110_01000000_11100100_00000000 = [V(B_2), V(B_2), V(B_2), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)];
-    0b00101110_10000000_11100100_00000000 = [V(B_4), V(B_4), V(B_4), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)];
-    0b00101110_11000000_11100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)];
+    0b00101110_01000000_11100100_00000000 = [V(B_2), V(B_2), V(B_2), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)], [Ex_FCMA | Ex_FP16];
+    0b00101110_10000000_11100100_00000000 = [V(B_4), V(B_4), V(B_4), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)], [Ex_FCMA];
+    0b00101110_11000000_11100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)], [Ex_FCMA];
 ]
 "fccmp" = [
-    0b00011110_11100000_00000100_00000000 = [H, H, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
-    0b00011110_00100000_00000100_00000000 = [S, S, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
-    0b00011110_01100000_00000100_00000000 = [D, D, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
+    0b00011110_11100000_00000100_00000000 = [H, H, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)], [Ex_FP16];
+    0b00011110_00100000_00000100_00000000 = [S, S, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)], [Ex_BASE];
+    0b00011110_01100000_00000100_00000000 = [D, D, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)], [Ex_BASE];
 ]
 "fccmpe" = [
-    0b00011110_11100000_00000100_00010000 = [H, H, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
-    0b00011110_00100000_00000100_00010000 = [S, S, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
-    0b00011110_01100000_00000100_00010000 = [D, D, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
+    0b00011110_11100000_00000100_00010000 = [H, H, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)], [Ex_FP16];
+    0b00011110_00100000_00000100_00010000 = [S, S, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)], [Ex_BASE];
+    0b00011110_01100000_00000100_00010000 = [D, D, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)], [Ex_BASE];
 ]
 "fcmeq" = [
     // FCMEQ (register)
-    0b01011110_01000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01011110_00100000_11100100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01011110_01100000_11100100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_01000000_00100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_00100000_11100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_11100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_01000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b01011110_00100000_11100100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_01100000_11100100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01000000_00100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00001110_00100000_11100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_11100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // FCMEQ (zero)
-    0b01011110_11111000_11011000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)];
-    0b01011110_10100000_11011000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
-    0b01011110_11100000_11011000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
-    0b00001110_11111000_11011000_00000000 = [V(B_2), V(B_2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100000_11011000_00000000 = [V(B_4), V(B_4), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100000_11011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_11111000_11011000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], [Ex_FP16];
+    0b01011110_10100000_11011000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_11100000_11011000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_11111000_11011000_00000000 = [V(B_2), V(B_2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_10100000_11011000_00000000 = [V(B_4), V(B_4), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_11011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    // FCMEQ (vectors)
+    0b01100101_01000000_01100000_00000000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b01100101_10000000_01100000_00000000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b01100101_11000000_01100000_00000000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // FCMEQ (zero)
+    0b01100101_01010010_00100000_00000000 = [P(B_2), PZero, Z(B_2), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_10010010_00100000_00000000 = [P(B_4), PZero, Z(B_4), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_11010010_00100000_00000000 = [P(B_8), PZero, Z(B_8), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fcmge" = [
     // FCMGE (register)
-    0b01111110_01000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01111110_00100000_11100100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01111110_01100000_11100100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_01000000_00100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_00100000_11100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_11100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_01000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b01111110_00100000_11100100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_01100000_11100100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_01000000_00100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00101110_00100000_11100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_11100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // FCMGE (zero)
+    0b01111110_11111000_11001000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], [Ex_FP16];
+    0b01111110_10100000_11001000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_11100000_11001000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_11111000_11001000_00000000 = [V(B_2), V(B_2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_10100000_11001000_00000000 = [V(B_4), V(B_4), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_11001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    // FCMGE (vectors)
+    0b01100101_01000000_01000000_00000000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b01100101_10000000_01000000_00000000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b01100101_11000000_01000000_00000000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
     // FCMGE (zero)
-    0b01111110_11111000_11001000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)];
-    0b01111110_10100000_11001000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
-    0b01111110_11100000_11001000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
-    0b00101110_11111000_11001000_00000000 = [V(B_2), V(B_2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100000_11001000_00000000 = [V(B_4), V(B_4), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_11100000_11001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b01100101_01010000_00100000_00000000 = [P(B_2), PZero, Z(B_2), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_10010000_00100000_00000000 = [P(B_4), PZero, Z(B_4), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_11010000_00100000_00000000 = [P(B_8), PZero, Z(B_8), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fcmgt" = [
     // FCMGT (register)
-    0b01111110_11000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01111110_10100000_11100100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01111110_11100000_11100100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_11000000_00100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_11100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_11100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_11000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b01111110_10100000_11100100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_11100000_11100100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_11000000_00100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00101110_10100000_11100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_11100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // FCMGT (zero)
-    0b01011110_11111000_11001000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)];
-    0b01011110_10100000_11001000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
-    0b01011110_11100000_11001000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
-    0b00001110_11111000_11001000_00000000 = [V(B_2), V(B_2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100000_11001000_00000000 = [V(B_4), V(B_4), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100000_11001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_11111000_11001000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], [Ex_FP16];
+    0b01011110_10100000_11001000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_11100000_11001000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_11111000_11001000_00000000 = [V(B_2), V(B_2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_10100000_11001000_00000000 = [V(B_4), V(B_4), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_11001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    // FCMGT (vectors)
+    0b01100101_01000000_01000000_00010000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b01100101_10000000_01000000_00010000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b01100101_11000000_01000000_00010000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // FCMGT (zero)
+    0b01100101_01010000_00100000_00010000 = [P(B_2), PZero, Z(B_2), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_10010000_00100000_00010000 = [P(B_4), PZero, Z(B_4), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_11010000_00100000_00010000 = [P(B_8), PZero, Z(B_8), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fcmla" = [
     // FCMLA (by element)
-    0b00101111_01000000_00010000_00000000 = [VStatic(B_2, 4), VStatic(B_2, 4), VElement(B_2), Imm] => [R(0), R(5), R(16), Ufields(&[21]), Ulist(13, &[0, 90, 180, 270])];
-    0b01101111_01000000_00010000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VElement(B_2), Imm] => [R(0), R(5), R(16), Ufields(&[11, 21]), Ulist(13, &[0, 90, 180, 270])];
-    0b00101111_10000000_00010000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VElement(B_4), Imm] => [R(0), R(5), R(16), Ufields(&[11]), Ulist(13, &[0, 90, 180, 270]), Rwidth(30)];
+    0b00101111_01000000_00010000_00000000 = [VStatic(B_2, 4), VStatic(B_2, 4), VElement(B_2), Imm] => [R(0), R(5), R(16), Ufields(&[21]), Ulist(13, &[0, 90, 180, 270])], [Ex_FCMA | Ex_FP16];
+    0b01101111_01000000_00010000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VElement(B_2), Imm] => [R(0), R(5), R(16), Ufields(&[11, 21]), Ulist(13, &[0, 90, 180, 270])], [Ex_FCMA | Ex_FP16];
+    0b00101111_10000000_00010000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VElement(B_4), Imm] => [R(0), R(5), R(16), Ufields(&[11]), Ulist(13, &[0, 90, 180, 270]), Rwidth(30)], [Ex_FCMA];
     // FCMLA
-    0b00101110_01000000_11000100_00000000 = [V(B_2), V(B_2), V(B_2), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)];
-    0b00101110_10000000_11000100_00000000 = [V(B_4), V(B_4), V(B_4), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)];
-    0b00101110_11000000_11000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)];
+    0b00101110_01000000_11000100_00000000 = [V(B_2), V(B_2), V(B_2), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)], [Ex_FCMA | Ex_FP16];
+    0b00101110_10000000_11000100_00000000 = [V(B_4), V(B_4), V(B_4), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)], [Ex_FCMA];
+    0b00101110_11000000_11000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)], [Ex_FCMA];
 ]
 "fcmle" = [
-    0b01111110_11111000_11011000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)];
-    0b01111110_10100000_11011000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
-    0b01111110_11100000_11011000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
-    0b00101110_11111000_11011000_00000000 = [V(B_2), V(B_2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100000_11011000_00000000 = [V(B_4), V(B_4), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_11100000_11011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b01111110_11111000_11011000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], [Ex_FP16];
+    0b01111110_10100000_11011000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_11100000_11011000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_11111000_11011000_00000000 = [V(B_2), V(B_2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_10100000_11011000_00000000 = [V(B_4), V(B_4), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_11011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    // FCMLE (vectors)
+    0b01100101_01000000_01000000_00000000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b01100101_10000000_01000000_00000000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b01100101_11000000_01000000_00000000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    // FCMLE (zero)
+    0b01100101_01010001_00100000_00010000 = [P(B_2), PZero, Z(B_2), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_10010001_00100000_00010000 = [P(B_4), PZero, Z(B_4), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_11010001_00100000_00010000 = [P(B_8), PZero, Z(B_8), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fcmlt" = [
-    0b01011110_11111000_11101000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)];
-    0b01011110_10100000_11101000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
-    0b01011110_11100000_11101000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
-    0b00001110_11111000_11101000_00000000 = [V(B_2), V(B_2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100000_11101000_00000000 = [V(B_4), V(B_4), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100000_11101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_11111000_11101000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], [Ex_FP16];
+    0b01011110_10100000_11101000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_11100000_11101000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_11111000_11101000_00000000 = [V(B_2), V(B_2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_10100000_11101000_00000000 = [V(B_4), V(B_4), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_11101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    // FCMLT (vectors)
+    0b01100101_01000000_01000000_00010000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b01100101_10000000_01000000_00010000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    0b01100101_11000000_01000000_00010000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(16), R(5)], [Ex_SVE];
+    // FCMLT (zero)
+    0b01100101_01010001_00100000_00000000 = [P(B_2), PZero, Z(B_2), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_10010001_00100000_00000000 = [P(B_4), PZero, Z(B_4), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_11010001_00100000_00000000 = [P(B_8), PZero, Z(B_8), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
+]
+"fcmne" = [
+    // FCMNE (vectors)
+    0b01100101_01000000_01100000_00010000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b01100101_10000000_01100000_00010000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b01100101_11000000_01100000_00010000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // FCMNE (zero)
+    0b01100101_01010011_00100000_00000000 = [P(B_2), PZero, Z(B_2), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_10010011_00100000_00000000 = [P(B_4), PZero, Z(B_4), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_11010011_00100000_00000000 = [P(B_8), PZero, Z(B_8), LitFloat(0.0)] => [R4(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fcmp" = [
-    0b00011110_11100000_00100000_00000000 = [H, H] => [R(5), R(16)];
-    0b00011110_11100000_00100000_00001000 = [H, LitFloat(0.0)] => [R(5)];
-    0b00011110_00100000_00100000_00000000 = [S, S] => [R(5), R(16)];
-    0b00011110_00100000_00100000_00001000 = [S, LitFloat(0.0)] => [R(5)];
-    0b00011110_01100000_00100000_00000000 = [D, D] => [R(5), R(16)];
-    0b00011110_01100000_00100000_00001000 = [D, LitFloat(0.0)] => [R(5)];
+    0b00011110_11100000_00100000_00000000 = [H, H] => [R(5), R(16)], [Ex_FP16];
+    0b00011110_11100000_00100000_00001000 = [H, LitFloat(0.0)] => [R(5)], [Ex_FP16];
+    0b00011110_00100000_00100000_00000000 = [S, S] => [R(5), R(16)], [Ex_BASE];
+    0b00011110_00100000_00100000_00001000 = [S, LitFloat(0.0)] => [R(5)], [Ex_BASE];
+    0b00011110_01100000_00100000_00000000 = [D, D] => [R(5), R(16)], [Ex_BASE];
+    0b00011110_01100000_00100000_00001000 = [D, LitFloat(0.0)] => [R(5)], [Ex_BASE];
 ]
 "fcmpe" = [
-    0b00011110_11100000_00100000_00010000 = [H, H] => [R(5), R(16)];
-    0b00011110_11100000_00100000_00011000 = [H, LitFloat(0.0)] => [R(5)];
-    0b00011110_00100000_00100000_00010000 = [S, S] => [R(5), R(16)];
-    0b00011110_00100000_00100000_00011000 = [S, LitFloat(0.0)] => [R(5)];
-    0b00011110_01100000_00100000_00010000 = [D, D] => [R(5), R(16)];
-    0b00011110_01100000_00100000_00011000 = [D, LitFloat(0.0)] => [R(5)];
+    0b00011110_11100000_00100000_00010000 = [H, H] => [R(5), R(16)], [Ex_FP16];
+    0b00011110_11100000_00100000_00011000 = [H, LitFloat(0.0)] => [R(5)], [Ex_FP16];
+    0b00011110_00100000_00100000_00010000 = [S, S] => [R(5), R(16)], [Ex_BASE];
+    0b00011110_00100000_00100000_00011000 = [S, LitFloat(0.0)] => [R(5)], [Ex_BASE];
+    0b00011110_01100000_00100000_00010000 = [D, D] => [R(5), R(16)], [Ex_BASE];
+    0b00011110_01100000_00100000_00011000 = [D, LitFloat(0.0)] => [R(5)], [Ex_BASE];
+]
+"fcmuo" = [
+    // FCMUO (vectors)
+    0b01100101_01000000_11000000_00000000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b01100101_10000000_11000000_00000000 = [P(B_4), PZero, Z(B_4), Z(B_4)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
+    0b01100101_11000000_11000000_00000000 = [P(B_8), PZero, Z(B_8), Z(B_8)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE];
 ]
 "fcsel" = [
-    0b00011110_11100000_00001100_00000000 = [H, H, H, Cond] => [R(0), R(5), R(16), Cond(12)];
-    0b00011110_00100000_00001100_00000000 = [S, S, S, Cond] => [R(0), R(5), R(16), Cond(12)];
-    0b00011110_01100000_00001100_00000000 = [D, D, D, Cond] => [R(0), R(5), R(16), Cond(12)];
+    0b00011110_11100000_00001100_00000000 = [H, H, H, Cond] => [R(0), R(5), R(16), Cond(12)], [Ex_FP16];
+    0b00011110_00100000_00001100_00000000 = [S, S, S, Cond] => [R(0), R(5), R(16), Cond(12)], [Ex_BASE];
+    0b00011110_01100000_00001100_00000000 = [D, D, D, Cond] => [R(0), R(5), R(16), Cond(12)], [Ex_BASE];
 ]
 "fcvt" = [
-    0b00011110_11100010_01000000_00000000 = [S, H] => [R(0), R(5)];
-    0b00011110_11100010_11000000_00000000 = [D, H] => [R(0), R(5)];
-    0b00011110_00100011_11000000_00000000 = [H, S] => [R(0), R(5)];
-    0b00011110_00100010_11000000_00000000 = [D, S] => [R(0), R(5)];
-    0b00011110_01100011_11000000_00000000 = [H, D] => [R(0), R(5)];
-    0b00011110_01100010_01000000_00000000 = [S, D] => [R(0), R(5)];
+    0b00011110_11100010_01000000_00000000 = [S, H] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_11100010_11000000_00000000 = [D, H] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_00100011_11000000_00000000 = [H, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_00100010_11000000_00000000 = [D, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100011_11000000_00000000 = [H, D] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100010_01000000_00000000 = [S, D] => [R(0), R(5)], [Ex_BASE];
+    // FCVT (predicated)
+    0b01100101_10001000_10100000_00000000 = [Z(B_2), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FCVT (predicated)
+    0b01100101_10001001_10100000_00000000 = [Z(B_4), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FCVT (predicated)
+    0b01100101_11001000_10100000_00000000 = [Z(B_2), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FCVT (predicated)
+    0b01100101_11001001_10100000_00000000 = [Z(B_8), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FCVT (predicated)
+    0b01100101_11001010_10100000_00000000 = [Z(B_4), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FCVT (predicated)
+    0b01100101_11001011_10100000_00000000 = [Z(B_8), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fcvtas" = [
     // FCVTAS (vector)
-    0b01011110_01111001_11001000_00000000 = [H, H] => [R(0), R(5)];
-    0b01011110_00100001_11001000_00000000 = [S, S] => [R(0), R(5)];
-    0b01011110_01100001_11001000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_01111001_11001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_00100001_11001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100001_11001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_01111001_11001000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01011110_00100001_11001000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_01100001_11001000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_01111001_11001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_00100001_11001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100001_11001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FCVTAS (scalar)
-    0b00011110_11100100_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11100100_00000000_00000000 = [X, H] => [R(0), R(5)];
-    0b00011110_00100100_00000000_00000000 = [W, S] => [R(0), R(5)];
-    0b10011110_00100100_00000000_00000000 = [X, S] => [R(0), R(5)];
-    0b00011110_01100100_00000000_00000000 = [W, D] => [R(0), R(5)];
-    0b10011110_01100100_00000000_00000000 = [X, D] => [R(0), R(5)];
+    0b00011110_11100100_00000000_00000000 = [W, H] => [R(0), R(5)], [Ex_FP16];
+    0b10011110_11100100_00000000_00000000 = [X, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100100_00000000_00000000 = [W, S] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_00100100_00000000_00000000 = [X, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100100_00000000_00000000 = [W, D] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_01100100_00000000_00000000 = [X, D] => [R(0), R(5)], [Ex_BASE];
 ]
 "fcvtau" = [
     // FCVTAU (vector)
-    0b01111110_01111001_11001000_00000000 = [H, H] => [R(0), R(5)];
-    0b01111110_00100001_11001000_00000000 = [S, S] => [R(0), R(5)];
-    0b01111110_01100001_11001000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_01111001_11001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_00100001_11001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100001_11001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01111110_01111001_11001000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01111110_00100001_11001000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_01100001_11001000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_01111001_11001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_00100001_11001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100001_11001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FCVTAU (scalar)
-    0b00011110_11100101_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11100101_00000000_00000000 = [X, H] => [R(0), R(5)];
-    0b00011110_00100101_00000000_00000000 = [W, S] => [R(0), R(5)];
-    0b10011110_00100101_00000000_00000000 = [X, S] => [R(0), R(5)];
-    0b00011110_01100101_00000000_00000000 = [W, D] => [R(0), R(5)];
-    0b10011110_01100101_00000000_00000000 = [X, D] => [R(0), R(5)];
+    0b00011110_11100101_00000000_00000000 = [W, H] => [R(0), R(5)], [Ex_FP16];
+    0b10011110_11100101_00000000_00000000 = [X, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100101_00000000_00000000 = [W, S] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_00100101_00000000_00000000 = [X, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100101_00000000_00000000 = [W, D] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_01100101_00000000_00000000 = [X, D] => [R(0), R(5)], [Ex_BASE];
 ]
 "fcvtl" = [
-    0b00001110_00100001_01111000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4)] => [R(0), R(5)];
-    0b00001110_01100001_01111000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2)] => [R(0), R(5)];
+    0b00001110_00100001_01111000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4)] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_01100001_01111000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2)] => [R(0), R(5)], [Ex_BASE];
 ]
 "fcvtl2" = [
-    0b01001110_00100001_01111000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8)] => [R(0), R(5)];
-    0b01001110_01100001_01111000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4)] => [R(0), R(5)];
+    0b01001110_00100001_01111000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8)] => [R(0), R(5)], [Ex_BASE];
+    0b01001110_01100001_01111000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4)] => [R(0), R(5)], [Ex_BASE];
 ]
 "fcvtms" = [
     // FCVTMS (vector)
-    0b01011110_01111001_10111000_00000000 = [H, H] => [R(0), R(5)];
-    0b01011110_00100001_10111000_00000000 = [S, S] => [R(0), R(5)];
-    0b01011110_01100001_10111000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_01111001_10111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_00100001_10111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100001_10111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_01111001_10111000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01011110_00100001_10111000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_01100001_10111000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_01111001_10111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_00100001_10111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100001_10111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FCVTMS (scalar)
-    0b00011110_11110000_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11110000_00000000_00000000 = [X, H] => [R(0), R(5)];
-    0b00011110_00110000_00000000_00000000 = [W, S] => [R(0), R(5)];
-    0b10011110_00110000_00000000_00000000 = [X, S] => [R(0), R(5)];
-    0b00011110_01110000_00000000_00000000 = [W, D] => [R(0), R(5)];
-    0b10011110_01110000_00000000_00000000 = [X, D] => [R(0), R(5)];
+    0b00011110_11110000_00000000_00000000 = [W, H] => [R(0), R(5)], [Ex_FP16];
+    0b10011110_11110000_00000000_00000000 = [X, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00110000_00000000_00000000 = [W, S] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_00110000_00000000_00000000 = [X, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01110000_00000000_00000000 = [W, D] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_01110000_00000000_00000000 = [X, D] => [R(0), R(5)], [Ex_BASE];
 ]
 "fcvtmu" = [
     // FCVTMU (vector)
-    0b01111110_01111001_10111000_00000000 = [H, H] => [R(0), R(5)];
-    0b01111110_00100001_10111000_00000000 = [S, S] => [R(0), R(5)];
-    0b01111110_01100001_10111000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_01111001_10111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_00100001_10111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100001_10111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01111110_01111001_10111000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01111110_00100001_10111000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_01100001_10111000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_01111001_10111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_00100001_10111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100001_10111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FCVTMU (scalar)
-    0b00011110_11110001_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11110001_00000000_00000000 = [X, H] => [R(0), R(5)];
-    0b00011110_00110001_00000000_00000000 = [W, S] => [R(0), R(5)];
-    0b10011110_00110001_00000000_00000000 = [X, S] => [R(0), R(5)];
-    0b00011110_01110001_00000000_00000000 = [W, D] => [R(0), R(5)];
-    0b10011110_01110001_00000000_00000000 = [X, D] => [R(0), R(5)];
+    0b00011110_11110001_00000000_00000000 = [W, H] => [R(0), R(5)], [Ex_FP16];
+    0b10011110_11110001_00000000_00000000 = [X, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00110001_00000000_00000000 = [W, S] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_00110001_00000000_00000000 = [X, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01110001_00000000_00000000 = [W, D] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_01110001_00000000_00000000 = [X, D] => [R(0), R(5)], [Ex_BASE];
 ]
 "fcvtn" = [
-    0b00001110_01100001_01101000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2)] => [R(0), R(5)];
+    0b00001110_01100001_01101000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
 ]
 "fcvtn2" = [
-    0b01001110_01100001_01101000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01001110_01100001_01101000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
 ]
 "fcvtns" = [
     // FCVTNS (vector)
-    0b01011110_01111001_10101000_00000000 = [H, H] => [R(0), R(5)];
-    0b01011110_00100001_10101000_00000000 = [S, S] => [R(0), R(5)];
-    0b01011110_01100001_10101000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_01111001_10101000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_00100001_10101000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100001_10101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_01111001_10101000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01011110_00100001_10101000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_01100001_10101000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_01111001_10101000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_00100001_10101000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100001_10101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FCVTNS (scalar)
-    0b00011110_11100000_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11100000_00000000_00000000 = [X, H] => [R(0), R(5)];
-    0b00011110_00100000_00000000_00000000 = [W, S] => [R(0), R(5)];
-    0b10011110_00100000_00000000_00000000 = [X, S] => [R(0), R(5)];
-    0b00011110_01100000_00000000_00000000 = [W, D] => [R(0), R(5)];
-    0b10011110_01100000_00000000_00000000 = [X, D] => [R(0), R(5)];
+    0b00011110_11100000_00000000_00000000 = [W, H] => [R(0), R(5)], [Ex_FP16];
+    0b10011110_11100000_00000000_00000000 = [X, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100000_00000000_00000000 = [W, S] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_00100000_00000000_00000000 = [X, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100000_00000000_00000000 = [W, D] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_01100000_00000000_00000000 = [X, D] => [R(0), R(5)], [Ex_BASE];
 ]
 "fcvtnu" = [
     // FCVTNU (vector)
-    0b01111110_01111001_10101000_00000000 = [H, H] => [R(0), R(5)];
-    0b01111110_00100001_10101000_00000000 = [S, S] => [R(0), R(5)];
-    0b01111110_01100001_10101000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_01111001_10101000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_00100001_10101000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100001_10101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01111110_01111001_10101000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01111110_00100001_10101000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_01100001_10101000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_01111001_10101000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_00100001_10101000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100001_10101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FCVTNU (scalar)
-    0b00011110_11100001_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11100001_00000000_00000000 = [X, H] => [R(0), R(5)];
-    0b00011110_00100001_00000000_00000000 = [W, S] => [R(0), R(5)];
-    0b10011110_00100001_00000000_00000000 = [X, S] => [R(0), R(5)];
-    0b00011110_01100001_00000000_00000000 = [W, D] => [R(0), R(5)];
-    0b10011110_01100001_00000000_00000000 = [X, D] => [R(0), R(5)];
+    0b00011110_11100001_00000000_00000000 = [W, H] => [R(0), R(5)], [Ex_FP16];
+    0b10011110_11100001_00000000_00000000 = [X, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100001_00000000_00000000 = [W, S] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_00100001_00000000_00000000 = [X, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100001_00000000_00000000 = [W, D] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_01100001_00000000_00000000 = [X, D] => [R(0), R(5)], [Ex_BASE];
 ]
 "fcvtps" = [
     // FCVTPS (vector)
-    0b01011110_11111001_10101000_00000000 = [H, H] => [R(0), R(5)];
-    0b01011110_10100001_10101000_00000000 = [S, S] => [R(0), R(5)];
-    0b01011110_11100001_10101000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_11111001_10101000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100001_10101000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100001_10101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_11111001_10101000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01011110_10100001_10101000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_11100001_10101000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_11111001_10101000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_10100001_10101000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100001_10101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FCVTPS (scalar)
-    0b00011110_11101000_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11101000_00000000_00000000 = [X, H] => [R(0), R(5)];
-    0b00011110_00101000_00000000_00000000 = [W, S] => [R(0), R(5)];
-    0b10011110_00101000_00000000_00000000 = [X, S] => [R(0), R(5)];
-    0b00011110_01101000_00000000_00000000 = [W, D] => [R(0), R(5)];
-    0b10011110_01101000_00000000_00000000 = [X, D] => [R(0), R(5)];
+    0b00011110_11101000_00000000_00000000 = [W, H] => [R(0), R(5)], [Ex_FP16];
+    0b10011110_11101000_00000000_00000000 = [X, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00101000_00000000_00000000 = [W, S] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_00101000_00000000_00000000 = [X, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01101000_00000000_00000000 = [W, D] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_01101000_00000000_00000000 = [X, D] => [R(0), R(5)], [Ex_BASE];
 ]
 "fcvtpu" = [
     // FCVTPU (vector)
-    0b01111110_11111001_10101000_00000000 = [H, H] => [R(0), R(5)];
-    0b01111110_10100001_10101000_00000000 = [S, S] => [R(0), R(5)];
-    0b01111110_11100001_10101000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_11111001_10101000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100001_10101000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_11100001_10101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01111110_11111001_10101000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01111110_10100001_10101000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_11100001_10101000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_11111001_10101000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_10100001_10101000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100001_10101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FCVTPU (scalar)
-    0b00011110_11101001_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11101001_00000000_00000000 = [X, H] => [R(0), R(5)];
-    0b00011110_00101001_00000000_00000000 = [W, S] => [R(0), R(5)];
-    0b10011110_00101001_00000000_00000000 = [X, S] => [R(0), R(5)];
-    0b00011110_01101001_00000000_00000000 = [W, D] => [R(0), R(5)];
-    0b10011110_01101001_00000000_00000000 = [X, D] => [R(0), R(5)];
+    0b00011110_11101001_00000000_00000000 = [W, H] => [R(0), R(5)], [Ex_FP16];
+    0b10011110_11101001_00000000_00000000 = [X, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00101001_00000000_00000000 = [W, S] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_00101001_00000000_00000000 = [X, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01101001_00000000_00000000 = [W, D] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_01101001_00000000_00000000 = [X, D] => [R(0), R(5)], [Ex_BASE];
 ]
 "fcvtxn" = [
-    0b01111110_01100001_01101000_00000000 = [S, D] => [R(0), R(5)];
-    0b00101110_01100001_01101000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01111110_01100001_01101000_00000000 = [S, D] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_01100001_01101000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
 ]
 "fcvtxn2" = [
-    0b01101110_01100001_01101000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01101110_01100001_01101000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
 ]
 "fcvtzs" = [
     // FCVTZS (vector, fixed-point)
-    0b01011111_00000000_11111100_00000000 = [H, H, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)];
-    0b01011111_00000000_11111100_00000000 = [S, S, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)];
-    0b01011111_00000000_11111100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)];
-    0b00001111_00010000_11111100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)];
-    0b00001111_00100000_11111100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)];
-    0b00001111_01000000_11111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)];
+    0b01011111_00000000_11111100_00000000 = [H, H, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)], [Ex_FP16];
+    0b01011111_00000000_11111100_00000000 = [S, S, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)], [Ex_BASE];
+    0b01011111_00000000_11111100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)], [Ex_BASE];
+    0b00001111_00010000_11111100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)], [Ex_FP16];
+    0b00001111_00100000_11111100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00001111_01000000_11111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)], [Ex_BASE];
     // FCVTZS (vector, integer)
-    0b01011110_11111001_10111000_00000000 = [H, H] => [R(0), R(5)];
-    0b01011110_10100001_10111000_00000000 = [S, S] => [R(0), R(5)];
-    0b01011110_11100001_10111000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_11111001_10111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100001_10111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100001_10111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_11111001_10111000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01011110_10100001_10111000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_11100001_10111000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_11111001_10111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_10100001_10111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100001_10111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FCVTZS (scalar, fixed-point)
-    0b00011110_11011000_00000000_00000000 = [W, H, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)];
-    0b10011110_11011000_00000000_00000000 = [X, H, Imm] => [R(0), R(5), Usubone(10, 6)];
-    0b00011110_00011000_00000000_00000000 = [W, S, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)];
-    0b10011110_00011000_00000000_00000000 = [X, S, Imm] => [R(0), R(5), Usubone(10, 6)];
-    0b00011110_01011000_00000000_00000000 = [W, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)];
-    0b10011110_01011000_00000000_00000000 = [X, D, Imm] => [R(0), R(5), Usubone(10, 6)];
+    0b00011110_11011000_00000000_00000000 = [W, H, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)], [Ex_FP16];
+    0b10011110_11011000_00000000_00000000 = [X, H, Imm] => [R(0), R(5), Usubone(10, 6)], [Ex_FP16];
+    0b00011110_00011000_00000000_00000000 = [W, S, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)], [Ex_BASE];
+    0b10011110_00011000_00000000_00000000 = [X, S, Imm] => [R(0), R(5), Usubone(10, 6)], [Ex_BASE];
+    0b00011110_01011000_00000000_00000000 = [W, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)], [Ex_BASE];
+    0b10011110_01011000_00000000_00000000 = [X, D, Imm] => [R(0), R(5), Usubone(10, 6)], [Ex_BASE];
     // FCVTZS (scalar, integer)
-    0b00011110_11111000_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11111000_00000000_00000000 = [X, H] => [R(0), R(5)];
-    0b00011110_00111000_00000000_00000000 = [W, S] => [R(0), R(5)];
-    0b10011110_00111000_00000000_00000000 = [X, S] => [R(0), R(5)];
-    0b00011110_01111000_00000000_00000000 = [W, D] => [R(0), R(5)];
-    0b10011110_01111000_00000000_00000000 = [X, D] => [R(0), R(5)];
+    0b00011110_11111000_00000000_00000000 = [W, H] => [R(0), R(5)], [Ex_FP16];
+    0b10011110_11111000_00000000_00000000 = [X, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00111000_00000000_00000000 = [W, S] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_00111000_00000000_00000000 = [X, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01111000_00000000_00000000 = [W, D] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_01111000_00000000_00000000 = [X, D] => [R(0), R(5)], [Ex_BASE];
+    // FCVTZS (predicated)
+    0b01100101_01011010_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FCVTZS (predicated)
+    0b01100101_10011100_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FCVTZS (predicated)
+    0b01100101_11011110_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FCVTZS (predicated)
+    0b01100101_11011000_10100000_00000000 = [Z(B_4), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FCVTZS (predicated)
+    0b01100101_11011100_10100000_00000000 = [Z(B_8), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fcvtzu" = [
     // FCVTZU (vector, fixed-point)
-    0b01111111_00000000_11111100_00000000 = [H, H, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)];
-    0b01111111_00000000_11111100_00000000 = [S, S, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)];
-    0b01111111_00000000_11111100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)];
-    0b00101111_00010000_11111100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)];
-    0b00101111_00100000_11111100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)];
-    0b00101111_01000000_11111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)];
+    0b01111111_00000000_11111100_00000000 = [H, H, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)], [Ex_FP16];
+    0b01111111_00000000_11111100_00000000 = [S, S, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)], [Ex_BASE];
+    0b01111111_00000000_11111100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)], [Ex_BASE];
+    0b00101111_00010000_11111100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)], [Ex_FP16];
+    0b00101111_00100000_11111100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00101111_01000000_11111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)], [Ex_BASE];
     // FCVTZU (vector, integer)
-    0b01111110_11111001_10111000_00000000 = [H, H] => [R(0), R(5)];
-    0b01111110_10100001_10111000_00000000 = [S, S] => [R(0), R(5)];
-    0b01111110_11100001_10111000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_11111001_10111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100001_10111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_11100001_10111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01111110_11111001_10111000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01111110_10100001_10111000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_11100001_10111000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_11111001_10111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_10100001_10111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100001_10111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FCVTZU (scalar, fixed-point)
-    0b00011110_11011001_00000000_00000000 = [W, H, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)];
-    0b10011110_11011001_00000000_00000000 = [X, H, Imm] => [R(0), R(5), Usubone(10, 6)];
-    0b00011110_00011001_00000000_00000000 = [W, S, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)];
-    0b10011110_00011001_00000000_00000000 = [X, S, Imm] => [R(0), R(5), Usubone(10, 6)];
-    0b00011110_01011001_00000000_00000000 = [W, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)];
-    0b10011110_01011001_00000000_00000000 = [X, D, Imm] => [R(0), R(5), Usubone(10, 6)];
+    0b00011110_11011001_00000000_00000000 = [W, H, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)], [Ex_FP16];
+    0b10011110_11011001_00000000_00000000 = [X, H, Imm] => [R(0), R(5), Usubone(10, 6)], [Ex_FP16];
+    0b00011110_00011001_00000000_00000000 = [W, S, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)], [Ex_BASE];
+    0b10011110_00011001_00000000_00000000 = [X, S, Imm] => [R(0), R(5), Usubone(10, 6)], [Ex_BASE];
+    0b00011110_01011001_00000000_00000000 = [W, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)], [Ex_BASE];
+    0b10011110_01011001_00000000_00000000 = [X, D, Imm] => [R(0), R(5), Usubone(10, 6)], [Ex_BASE];
     // FCVTZU (scalar, integer)
-    0b00011110_11111001_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11111001_00000000_00000000 = [X, H] => [R(0), R(5)];
-    0b00011110_00111001_00000000_00000000 = [W, S] => [R(0), R(5)];
-    0b10011110_00111001_00000000_00000000 = [X, S] => [R(0), R(5)];
-    0b00011110_01111001_00000000_00000000 = [W, D] => [R(0), R(5)];
-    0b10011110_01111001_00000000_00000000 = [X, D] => [R(0), R(5)];
+    0b00011110_11111001_00000000_00000000 = [W, H] => [R(0), R(5)], [Ex_FP16];
+    0b10011110_11111001_00000000_00000000 = [X, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00111001_00000000_00000000 = [W, S] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_00111001_00000000_00000000 = [X, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01111001_00000000_00000000 = [W, D] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_01111001_00000000_00000000 = [X, D] => [R(0), R(5)], [Ex_BASE];
+    // FCVTZU (predicated)
+    0b01100101_01011011_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FCVTZU (predicated)
+    0b01100101_10011101_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FCVTZU (predicated)
+    0b01100101_11011111_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FCVTZU (predicated)
+    0b01100101_11011001_10100000_00000000 = [Z(B_4), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FCVTZU (predicated)
+    0b01100101_11011101_10100000_00000000 = [Z(B_8), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fdiv" = [
     // FDIV (vector)
-    0b00101110_01000000_00111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_00100000_11111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_11111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01000000_00111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00101110_00100000_11111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_11111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // FDIV (scalar)
-    0b00011110_11100000_00011000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b00011110_00100000_00011000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00011110_01100000_00011000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00011110_11100000_00011000_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b00011110_00100000_00011000_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00011110_01100000_00011000_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    // FDIV (vectors, predicated)
+    0b01100101_01001101_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FDIV (vectors, predicated)
+    0b01100101_10001101_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FDIV (vectors, predicated)
+    0b01100101_11001101_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+]
+"fdivr" = [
+    // FDIVR (vectors, predicated)
+    0b01100101_01001100_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FDIVR (vectors, predicated)
+    0b01100101_10001100_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FDIVR (vectors, predicated)
+    0b01100101_11001100_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+]
+"fdup" = [
+    0b00100101_01111001_11000000_00000000 = [Z(B_2), Imm] => [R(0), Special(5, FLOAT_IMMEDIATE)], [Ex_SVE];
+    0b00100101_10111001_11000000_00000000 = [Z(B_4), Imm] => [R(0), Special(5, FLOAT_IMMEDIATE)], [Ex_SVE];
+    0b00100101_11111001_11000000_00000000 = [Z(B_8), Imm] => [R(0), Special(5, FLOAT_IMMEDIATE)], [Ex_SVE];
 ]
 "fjcvtzs" = [
-    0b00011110_01111110_00000000_00000000 = [W, D] => [R(0), R(5)];
+    0b00011110_01111110_00000000_00000000 = [W, D] => [R(0), R(5)], [Ex_JSCVT];
 ]
 "fmadd" = [
-    0b00011111_11000000_00000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)];
-    0b00011111_00000000_00000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)];
-    0b00011111_01000000_00000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)];
+    0b00011111_11000000_00000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)], [Ex_FP16];
+    0b00011111_00000000_00000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
+    0b00011111_01000000_00000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
 ]
 "fmax" = [
     // FMAX (vector)
-    0b00001110_01000000_00110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_00100000_11110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_11110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_00110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00001110_00100000_11110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_11110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // FMAX (scalar)
-    0b00011110_11100000_01001000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b00011110_00100000_01001000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00011110_01100000_01001000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00011110_11100000_01001000_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b00011110_00100000_01001000_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00011110_01100000_01001000_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    // FMAX (vectors, predicated)
+    0b01100101_01000110_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FMAX (vectors, predicated)
+    0b01100101_10000110_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FMAX (vectors, predicated)
+    0b01100101_11000110_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "fmaxnm" = [
     // FMAXNM (vector)
-    0b00001110_01000000_00000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_00100000_11000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_11000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_00000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00001110_00100000_11000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_11000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // FMAXNM (scalar)
-    0b00011110_11100000_01101000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b00011110_00100000_01101000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00011110_01100000_01101000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00011110_11100000_01101000_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b00011110_00100000_01101000_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00011110_01100000_01101000_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    // FMAXNM (vectors, predicated)
+    0b01100101_01000100_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FMAXNM (vectors, predicated)
+    0b01100101_10000100_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FMAXNM (vectors, predicated)
+    0b01100101_11000100_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "fmaxnmp" = [
     // FMAXNMP (scalar)
-    0b01011110_00110000_11001000_00000000 = [H, VStatic(B_2, 2)] => [R(0), R(5)];
-    0b01111110_00110000_11001000_00000000 = [S, VStatic(B_4, 2)] => [R(0), R(5)];
-    0b01111110_01110000_11001000_00000000 = [D, VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01011110_00110000_11001000_00000000 = [H, VStatic(B_2, 2)] => [R(0), R(5)], [Ex_FP16];
+    0b01111110_00110000_11001000_00000000 = [S, VStatic(B_4, 2)] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_01110000_11001000_00000000 = [D, VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
     // FMAXNMP (vector)
-    0b00101110_01000000_00000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_00100000_11000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_11000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01000000_00000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00101110_00100000_11000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_11000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // FMAXNMP (vectors, predicated)
+    0b01100100_01010100_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // FMAXNMP (vectors, predicated)
+    0b01100100_10010100_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // FMAXNMP (vectors, predicated)
+    0b01100100_11010100_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "fmaxnmv" = [
-    0b00001110_00110000_11001000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_00110000_11001000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_00110000_11001000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_00110000_11001000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b01100101_01000100_00100000_00000000 = [H, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_10000100_00100000_00000000 = [S, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_11000100_00100000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fmaxp" = [
     // FMAXP (scalar)
-    0b01011110_00110000_11111000_00000000 = [H, VStatic(B_2, 2)] => [R(0), R(5)];
-    0b01111110_00110000_11111000_00000000 = [S, VStatic(B_4, 2)] => [R(0), R(5)];
-    0b01111110_01110000_11111000_00000000 = [D, VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01011110_00110000_11111000_00000000 = [H, VStatic(B_2, 2)] => [R(0), R(5)], [Ex_FP16];
+    0b01111110_00110000_11111000_00000000 = [S, VStatic(B_4, 2)] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_01110000_11111000_00000000 = [D, VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
     // FMAXP (vector)
-    0b00101110_01000000_00110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_00100000_11110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_11110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01000000_00110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00101110_00100000_11110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_11110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // FMAXP (vectors, predicated)
+    0b01100100_01010110_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // FMAXP (vectors, predicated)
+    0b01100100_10010110_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // FMAXP (vectors, predicated)
+    0b01100100_11010110_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "fmaxv" = [
-    0b00001110_00110000_11111000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_00110000_11111000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_00110000_11111000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_00110000_11111000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b01100101_01000110_00100000_00000000 = [H, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_10000110_00100000_00000000 = [S, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_11000110_00100000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fmin" = [
     // FMIN (vector)
-    0b00001110_11000000_00110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_11110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_11110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_00110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00001110_10100000_11110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_11110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // FMIN (scalar)
-    0b00011110_11100000_01011000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b00011110_00100000_01011000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00011110_01100000_01011000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00011110_11100000_01011000_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b00011110_00100000_01011000_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00011110_01100000_01011000_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    // FMIN (vectors, predicated)
+    0b01100101_01000111_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FMIN (vectors, predicated)
+    0b01100101_10000111_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FMIN (vectors, predicated)
+    0b01100101_11000111_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "fminnm" = [
     // FMINNM (vector)
-    0b00001110_11000000_00000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_11000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_11000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_00000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00001110_10100000_11000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_11000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // FMINNM (scalar)
-    0b00011110_11100000_01111000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b00011110_00100000_01111000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00011110_01100000_01111000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00011110_11100000_01111000_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b00011110_00100000_01111000_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00011110_01100000_01111000_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    // FMINNM (vectors, predicated)
+    0b01100101_01000101_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FMINNM (vectors, predicated)
+    0b01100101_10000101_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FMINNM (vectors, predicated)
+    0b01100101_11000101_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "fminnmp" = [
     // FMINNMP (scalar)
-    0b01011110_10110000_11001000_00000000 = [H, VStatic(B_2, 2)] => [R(0), R(5)];
-    0b01111110_10110000_11001000_00000000 = [S, VStatic(B_4, 2)] => [R(0), R(5)];
-    0b01111110_11110000_11001000_00000000 = [D, VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01011110_10110000_11001000_00000000 = [H, VStatic(B_2, 2)] => [R(0), R(5)], [Ex_FP16];
+    0b01111110_10110000_11001000_00000000 = [S, VStatic(B_4, 2)] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_11110000_11001000_00000000 = [D, VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
     // FMINNMP (vector)
-    0b00101110_11000000_00000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_11000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_11000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11000000_00000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00101110_10100000_11000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_11000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // FMINNMP (vectors, predicated)
+    0b01100100_01010101_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // FMINNMP (vectors, predicated)
+    0b01100100_10010101_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // FMINNMP (vectors, predicated)
+    0b01100100_11010101_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "fminnmv" = [
-    0b00001110_10110000_11001000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10110000_11001000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10110000_11001000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_10110000_11001000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b01100101_01000101_00100000_00000000 = [H, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_10000101_00100000_00000000 = [S, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_11000101_00100000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fminp" = [
     // FMINP (scalar)
-    0b01011110_10110000_11111000_00000000 = [H, VStatic(B_2, 2)] => [R(0), R(5)];
-    0b01111110_10110000_11111000_00000000 = [S, VStatic(B_4, 2)] => [R(0), R(5)];
-    0b01111110_11110000_11111000_00000000 = [D, VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01011110_10110000_11111000_00000000 = [H, VStatic(B_2, 2)] => [R(0), R(5)], [Ex_FP16];
+    0b01111110_10110000_11111000_00000000 = [S, VStatic(B_4, 2)] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_11110000_11111000_00000000 = [D, VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
     // FMINP (vector)
-    0b00101110_11000000_00110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_11110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_11110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11000000_00110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00101110_10100000_11110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_11110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // FMINP (vectors, predicated)
+    0b01100100_01010111_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // FMINP (vectors, predicated)
+    0b01100100_10010111_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // FMINP (vectors, predicated)
+    0b01100100_11010111_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "fminv" = [
-    0b00001110_10110000_11111000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10110000_11111000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10110000_11111000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_10110000_11111000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b01100101_01000111_00100000_00000000 = [H, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_10000111_00100000_00000000 = [S, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b01100101_11000111_00100000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fmla" = [
     // FMLA (by element)
-    0b01011111_00000000_00010000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01011111_10000000_00010000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b01011111_11000000_00010000_00000000 = [D, D, VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11])];
-    0b00001111_00000000_00010000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
-    0b00001111_10000000_00010000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
-    0b00001111_11000000_00010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)];
+    0b01011111_00000000_00010000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_FP16];
+    0b01011111_10000000_00010000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
+    0b01011111_11000000_00010000_00000000 = [D, D, VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11])], [Ex_BASE];
+    0b00001111_00000000_00010000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], [Ex_FP16];
+    0b00001111_10000000_00010000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], [Ex_BASE];
+    0b00001111_11000000_00010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)], [Ex_BASE];
     // FMLA (vector)
-    0b00001110_01000000_00001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_00100000_11001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_11001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_00001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00001110_00100000_11001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_11001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // FMLA (vectors, predicated)
+    0b01100101_01100000_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // FMLA (vectors, predicated)
+    0b01100101_10100000_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // FMLA (vectors, predicated)
+    0b01100101_11100000_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // FMLA (indexed)
+    0b01100100_00100000_00000000_00000000 = [Z(B_2), Z(B_2), ZElement(B_2)] => [R(0), R(5), R3(16), Ufields(&[22, 20, 19])], [Ex_SVE];
+    0b01100100_10100000_00000000_00000000 = [Z(B_4), Z(B_4), ZElement(B_4)] => [R(0), R(5), R3(16), Ubits(19, 2)], [Ex_SVE];
+    0b01100100_11100000_00000000_00000000 = [Z(B_8), Z(B_8), ZElement(B_8)] => [R(0), R(5), R4(16), Ubits(20, 1)], [Ex_SVE];
 ]
 "fmlal" = [
     // FMLAL, FMLAL2 (by element)
-    0b00001111_10000000_00000000_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01001111_10000000_00000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00001111_10000000_00000000_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_FP16FML];
+    0b01001111_10000000_00000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_FP16FML];
     // FMLAL, FMLAL2 (vector)
-    0b00001110_00100000_11101100_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VStatic(B_2, 2)] => [R(0), R(5), R(16)];
-    0b01001110_00100000_11101100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
+    0b00001110_00100000_11101100_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VStatic(B_2, 2)] => [R(0), R(5), R(16)], [Ex_FP16FML];
+    0b01001110_00100000_11101100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_FP16FML];
 ]
 "fmlal2" = [
     // FMLAL, FMLAL2 (by element)
-    0b00101111_10000000_10000000_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01101111_10000000_10000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00101111_10000000_10000000_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_FP16FML];
+    0b01101111_10000000_10000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_FP16FML];
     // FMLAL, FMLAL2 (vector)
-    0b00101110_00100000_11001100_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VStatic(B_2, 2)] => [R(0), R(5), R(16)];
-    0b01101110_00100000_11001100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
+    0b00101110_00100000_11001100_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VStatic(B_2, 2)] => [R(0), R(5), R(16)], [Ex_FP16FML];
+    0b01101110_00100000_11001100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_FP16FML];
 ]
 "fmls" = [
     // FMLS (by element)
-    0b01011111_00000000_01010000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01011111_10000000_01010000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b01011111_11000000_01010000_00000000 = [D, D, VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11])];
-    0b00001111_00000000_01010000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
-    0b00001111_10000000_01010000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
-    0b00001111_11000000_01010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)];
+    0b01011111_00000000_01010000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_FP16];
+    0b01011111_10000000_01010000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
+    0b01011111_11000000_01010000_00000000 = [D, D, VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11])], [Ex_BASE];
+    0b00001111_00000000_01010000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], [Ex_FP16];
+    0b00001111_10000000_01010000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], [Ex_BASE];
+    0b00001111_11000000_01010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)], [Ex_BASE];
     // FMLS (vector)
-    0b00001110_11000000_00001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_11001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_11001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_00001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00001110_10100000_11001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_11001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // FMLS (vectors, predicated)
+    0b01100101_01100000_00100000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // FMLS (vectors, predicated)
+    0b01100101_10100000_00100000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // FMLS (vectors, predicated)
+    0b01100101_11100000_00100000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // FMLS (indexed)
+    0b01100100_00100000_00000100_00000000 = [Z(B_2), Z(B_2), ZElement(B_2)] => [R(0), R(5), R3(16), Ufields(&[22, 20, 19])], [Ex_SVE];
+    0b01100100_10100000_00000100_00000000 = [Z(B_4), Z(B_4), ZElement(B_4)] => [R(0), R(5), R3(16), Ubits(19, 2)], [Ex_SVE];
+    0b01100100_11100000_00000100_00000000 = [Z(B_8), Z(B_8), ZElement(B_8)] => [R(0), R(5), R4(16), Ubits(20, 1)], [Ex_SVE];
 ]
 "fmlsl" = [
     // FMLSL, FMLSL2 (by element)
-    0b00001111_10000000_01000000_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01001111_10000000_01000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00001111_10000000_01000000_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_FP16FML];
+    0b01001111_10000000_01000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_FP16FML];
     // FMLSL, FMLSL2 (vector)
-    0b00001110_10100000_11101100_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VStatic(B_2, 2)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_11101100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_11101100_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VStatic(B_2, 2)] => [R(0), R(5), R(16)], [Ex_FP16FML];
+    0b01001110_10100000_11101100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_FP16FML];
 ]
 "fmlsl2" = [
     // FMLSL, FMLSL2 (by element)
-    0b00101111_10000000_11000000_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01101111_10000000_11000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00101111_10000000_11000000_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_FP16FML];
+    0b01101111_10000000_11000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_FP16FML];
     // FMLSL, FMLSL2 (vector)
-    0b00101110_10100000_11001100_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VStatic(B_2, 2)] => [R(0), R(5), R(16)];
-    0b01101110_10100000_11001100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
+    0b00101110_10100000_11001100_00000000 = [VStatic(B_4, 2), VStatic(B_2, 2), VStatic(B_2, 2)] => [R(0), R(5), R(16)], [Ex_FP16FML];
+    0b01101110_10100000_11001100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_FP16FML];
+]
+"fmopa" = [
+    // FMOPA (widening)
+    0b10000001_10100000_00000000_00000000 = [ZaTile(B_4), PMerge, PMerge, Z(B_2), Z(B_2)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], [Ex_SME];
+    // FMOPA (non-widening)
+    0b10000000_10000000_00000000_00000000 = [ZaTile(B_4), PMerge, PMerge, Z(B_4), Z(B_4)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], [Ex_SME];
+    0b10000000_11000000_00000000_00000000 = [ZaTile(B_8), PMerge, PMerge, Z(B_8), Z(B_8)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], [Ex_SME_F64F64];
+]
+"fmops" = [
+    // FMOPS (widening)
+    0b10000001_10100000_00000000_00010000 = [ZaTile(B_4), PMerge, PMerge, Z(B_2), Z(B_2)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], [Ex_SME];
+    // FMOPS (non-widening)
+    0b10000000_10000000_00000000_00010000 = [ZaTile(B_4), PMerge, PMerge, Z(B_4), Z(B_4)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], [Ex_SME];
+    0b10000000_11000000_00000000_00010000 = [ZaTile(B_8), PMerge, PMerge, Z(B_8), Z(B_8)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], [Ex_SME_F64F64];
 ]
 "fmov" = [
     // FMOV (vector, immediate)
-    0b00001111_00000000_11111100_00000000 = [V(B_2), Imm] => [R(0), Special(5, SPLIT_FLOAT_IMMEDIATE), Rwidth(30)];
-    0b00001111_00000000_11110100_00000000 = [V(B_4), Imm] => [R(0), Special(5, SPLIT_FLOAT_IMMEDIATE), Rwidth(30)];
-    0b01101111_00000000_11110100_00000000 = [VStatic(B_8, 2), Imm] => [R(0), Special(5, SPLIT_FLOAT_IMMEDIATE)];
+    0b00001111_00000000_11111100_00000000 = [V(B_2), Imm] => [R(0), Special(5, SPLIT_FLOAT_IMMEDIATE), Rwidth(30)], [Ex_FP16];
+    0b00001111_00000000_11110100_00000000 = [V(B_4), Imm] => [R(0), Special(5, SPLIT_FLOAT_IMMEDIATE), Rwidth(30)], [Ex_BASE];
+    0b01101111_00000000_11110100_00000000 = [VStatic(B_8, 2), Imm] => [R(0), Special(5, SPLIT_FLOAT_IMMEDIATE)], [Ex_BASE];
     // FMOV (register)
-    0b00011110_11100000_01000000_00000000 = [H, H] => [R(0), R(5)];
-    0b00011110_00100000_01000000_00000000 = [S, S] => [R(0), R(5)];
-    0b00011110_01100000_01000000_00000000 = [D, D] => [R(0), R(5)];
+    0b00011110_11100000_01000000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100000_01000000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100000_01000000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
     // FMOV (general)
-    0b00011110_11100110_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11100110_00000000_00000000 = [X, H] => [R(0), R(5)];
-    0b00011110_11100111_00000000_00000000 = [H, W] => [R(0), R(5)];
-    0b00011110_00100111_00000000_00000000 = [S, W] => [R(0), R(5)];
-    0b00011110_00100110_00000000_00000000 = [W, S] => [R(0), R(5)];
-    0b10011110_11100111_00000000_00000000 = [H, X] => [R(0), R(5)];
-    0b10011110_01100111_00000000_00000000 = [D, X] => [R(0), R(5)];
-    0b10011110_10101111_00000000_00000000 = [VElementStatic(B_8, 1), X] => [R(0), R(5)];
-    0b10011110_01100110_00000000_00000000 = [X, D] => [R(0), R(5)];
-    0b10011110_10101110_00000000_00000000 = [X, VElementStatic(B_8, 1)] => [R(0), R(5)];
+    0b00011110_11100110_00000000_00000000 = [W, H] => [R(0), R(5)], [Ex_FP16];
+    0b10011110_11100110_00000000_00000000 = [X, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_11100111_00000000_00000000 = [H, W] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100111_00000000_00000000 = [S, W] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_00100110_00000000_00000000 = [W, S] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_11100111_00000000_00000000 = [H, X] => [R(0), R(5)], [Ex_FP16];
+    0b10011110_01100111_00000000_00000000 = [D, X] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_10101111_00000000_00000000 = [VElementStatic(B_8, 1), X] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_01100110_00000000_00000000 = [X, D] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_10101110_00000000_00000000 = [X, VElementStatic(B_8, 1)] => [R(0), R(5)], [Ex_BASE];
     // FMOV (scalar, immediate)
-    0b00011110_11100000_00010000_00000000 = [H, Imm] => [R(0), Special(13, FLOAT_IMMEDIATE)];
-    0b00011110_00100000_00010000_00000000 = [S, Imm] => [R(0), Special(13, FLOAT_IMMEDIATE)];
-    0b00011110_01100000_00010000_00000000 = [D, Imm] => [R(0), Special(13, FLOAT_IMMEDIATE)];
+    0b00011110_11100000_00010000_00000000 = [H, Imm] => [R(0), Special(13, FLOAT_IMMEDIATE)], [Ex_FP16];
+    0b00011110_00100000_00010000_00000000 = [S, Imm] => [R(0), Special(13, FLOAT_IMMEDIATE)], [Ex_BASE];
+    0b00011110_01100000_00010000_00000000 = [D, Imm] => [R(0), Special(13, FLOAT_IMMEDIATE)], [Ex_BASE];
+    0b00100101_01111001_11000000_00000000 = [Z(B_2), Imm] => [R(0), Special(5, FLOAT_IMMEDIATE)], [Ex_SVE];
+    0b00100101_10111001_11000000_00000000 = [Z(B_4), Imm] => [R(0), Special(5, FLOAT_IMMEDIATE)], [Ex_SVE];
+    0b00100101_11111001_11000000_00000000 = [Z(B_8), Imm] => [R(0), Special(5, FLOAT_IMMEDIATE)], [Ex_SVE];
 ]
 "fmsub" = [
-    0b00011111_11000000_10000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)];
-    0b00011111_00000000_10000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)];
-    0b00011111_01000000_10000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)];
+    0b00011111_11000000_10000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)], [Ex_FP16];
+    0b00011111_00000000_10000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
+    0b00011111_01000000_10000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
 ]
 "fmul" = [
     // FMUL (by element)
-    0b01011111_00000000_10010000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01011111_10000000_10010000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b01011111_11000000_10010000_00000000 = [D, D, VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11])];
-    0b00001111_00000000_10010000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
-    0b00001111_10000000_10010000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
-    0b00001111_11000000_10010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)];
+    0b01011111_00000000_10010000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_FP16];
+    0b01011111_10000000_10010000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
+    0b01011111_11000000_10010000_00000000 = [D, D, VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11])], [Ex_BASE];
+    0b00001111_00000000_10010000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], [Ex_FP16];
+    0b00001111_10000000_10010000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], [Ex_BASE];
+    0b00001111_11000000_10010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)], [Ex_BASE];
     // FMUL (vector)
-    0b00101110_01000000_00011100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_00100000_11011100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_11011100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01000000_00011100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00101110_00100000_11011100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_11011100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // FMUL (scalar)
-    0b00011110_11100000_00001000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b00011110_00100000_00001000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00011110_01100000_00001000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00011110_11100000_00001000_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b00011110_00100000_00001000_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00011110_01100000_00001000_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    // FMUL (vectors, predicated)
+    0b01100101_01000010_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FMUL (vectors, predicated)
+    0b01100101_10000010_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FMUL (vectors, predicated)
+    0b01100101_11000010_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FMUL (vectors, unpredicated)
+    0b01100101_01000000_00001000_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // FMUL (vectors, unpredicated)
+    0b01100101_10000000_00001000_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // FMUL (vectors, unpredicated)
+    0b01100101_11000000_00001000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // FMUL (indexed)
+    0b01100100_00100000_00100000_00000000 = [Z(B_2), Z(B_2), ZElement(B_2)] => [R(0), R(5), R3(16), Ufields(&[22, 20, 19])], [Ex_SVE];
+    0b01100100_10100000_00100000_00000000 = [Z(B_4), Z(B_4), ZElement(B_4)] => [R(0), R(5), R3(16), Ubits(19, 2)], [Ex_SVE];
+    0b01100100_11100000_00100000_00000000 = [Z(B_8), Z(B_8), ZElement(B_8)] => [R(0), R(5), R4(16), Ubits(20, 1)], [Ex_SVE];
 ]
 "fmulx" = [
     // FMULX (by element)
-    0b01111111_00000000_10010000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01111111_10000000_10010000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b01111111_11000000_10010000_00000000 = [D, D, VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11])];
-    0b00101111_00000000_10010000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
-    0b00101111_10000000_10010000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
-    0b00101111_11000000_10010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)];
+    0b01111111_00000000_10010000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_FP16];
+    0b01111111_10000000_10010000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
+    0b01111111_11000000_10010000_00000000 = [D, D, VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11])], [Ex_BASE];
+    0b00101111_00000000_10010000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], [Ex_FP16];
+    0b00101111_10000000_10010000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], [Ex_BASE];
+    0b00101111_11000000_10010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VElement(B_8)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)], [Ex_BASE];
     // FMULX
-    0b01011110_01000000_00011100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01011110_00100000_11011100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01011110_01100000_11011100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_01000000_00011100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_00100000_11011100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_11011100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_01000000_00011100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b01011110_00100000_11011100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_01100000_11011100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01000000_00011100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00001110_00100000_11011100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_11011100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // FMULX (vectors, predicated)
+    0b01100101_01001010_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FMULX (vectors, predicated)
+    0b01100101_10001010_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FMULX (vectors, predicated)
+    0b01100101_11001010_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "fneg" = [
     // FNEG (vector)
-    0b00101110_11111000_11111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100000_11111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_11100000_11111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11111000_11111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_10100000_11111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_11111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FNEG (scalar)
-    0b00011110_11100001_01000000_00000000 = [H, H] => [R(0), R(5)];
-    0b00011110_00100001_01000000_00000000 = [S, S] => [R(0), R(5)];
-    0b00011110_01100001_01000000_00000000 = [D, D] => [R(0), R(5)];
+    0b00011110_11100001_01000000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100001_01000000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100001_01000000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    // FNEG (predicated)
+    0b00000100_01011101_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FNEG (predicated)
+    0b00000100_10011101_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FNEG (predicated)
+    0b00000100_11011101_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fnmadd" = [
-    0b00011111_11100000_00000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)];
-    0b00011111_00100000_00000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)];
-    0b00011111_01100000_00000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)];
+    0b00011111_11100000_00000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)], [Ex_FP16];
+    0b00011111_00100000_00000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
+    0b00011111_01100000_00000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
+]
+"fnmla" = [
+    // FNMLA (vectors, predicated)
+    0b01100101_01100000_01000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // FNMLA (vectors, predicated)
+    0b01100101_10100000_01000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // FNMLA (vectors, predicated)
+    0b01100101_11100000_01000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+]
+"fnmls" = [
+    // FNMLS (vectors, predicated)
+    0b01100101_01100000_01100000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // FNMLS (vectors, predicated)
+    0b01100101_10100000_01100000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // FNMLS (vectors, predicated)
+    0b01100101_11100000_01100000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
 ]
 "fnmsub" = [
-    0b00011111_11100000_10000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)];
-    0b00011111_00100000_10000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)];
-    0b00011111_01100000_10000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)];
+    0b00011111_11100000_10000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)], [Ex_FP16];
+    0b00011111_00100000_10000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
+    0b00011111_01100000_10000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
 ]
 "fnmul" = [
-    0b00011110_11100000_10001000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b00011110_00100000_10001000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00011110_01100000_10001000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00011110_11100000_10001000_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b00011110_00100000_10001000_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00011110_01100000_10001000_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "frecpe" = [
-    0b01011110_11111001_11011000_00000000 = [H, H] => [R(0), R(5)];
-    0b01011110_10100001_11011000_00000000 = [S, S] => [R(0), R(5)];
-    0b01011110_11100001_11011000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_11111001_11011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100001_11011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100001_11011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_11111001_11011000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01011110_10100001_11011000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_11100001_11011000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_11111001_11011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_10100001_11011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100001_11011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
 ]
 "frecps" = [
-    0b01011110_01000000_00111100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01011110_00100000_11111100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01011110_01100000_11111100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_01000000_00111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_00100000_11111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_11111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_01000000_00111100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b01011110_00100000_11111100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_01100000_11111100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01000000_00111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00001110_00100000_11111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_11111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // FRECPS (vectors, unpredicated)
+    0b01100101_01000000_00011000_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // FRECPS (vectors, unpredicated)
+    0b01100101_10000000_00011000_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // FRECPS (vectors, unpredicated)
+    0b01100101_11000000_00011000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
 ]
 "frecpx" = [
-    0b01011110_11111001_11111000_00000000 = [H, H] => [R(0), R(5)];
-    0b01011110_10100001_11111000_00000000 = [S, S] => [R(0), R(5)];
-    0b01011110_11100001_11111000_00000000 = [D, D] => [R(0), R(5)];
+    0b01011110_11111001_11111000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01011110_10100001_11111000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_11100001_11111000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    // FRECPX (predicated)
+    0b01100101_01001100_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRECPX (predicated)
+    0b01100101_10001100_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRECPX (predicated)
+    0b01100101_11001100_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "frinta" = [
     // FRINTA (vector)
-    0b00101110_01111001_10001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_00100001_10001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100001_10001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01111001_10001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_00100001_10001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100001_10001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FRINTA (scalar)
-    0b00011110_11100110_01000000_00000000 = [H, H] => [R(0), R(5)];
-    0b00011110_00100110_01000000_00000000 = [S, S] => [R(0), R(5)];
-    0b00011110_01100110_01000000_00000000 = [D, D] => [R(0), R(5)];
+    0b00011110_11100110_01000000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100110_01000000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100110_01000000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    // FRINTA (predicated)
+    0b01100101_01000100_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRINTA (predicated)
+    0b01100101_10000100_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRINTA (predicated)
+    0b01100101_11000100_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "frinti" = [
     // FRINTI (vector)
-    0b00101110_11111001_10011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100001_10011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_11100001_10011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11111001_10011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_10100001_10011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100001_10011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FRINTI (scalar)
-    0b00011110_11100111_11000000_00000000 = [H, H] => [R(0), R(5)];
-    0b00011110_00100111_11000000_00000000 = [S, S] => [R(0), R(5)];
-    0b00011110_01100111_11000000_00000000 = [D, D] => [R(0), R(5)];
+    0b00011110_11100111_11000000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100111_11000000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100111_11000000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    // FRINTI (predicated)
+    0b01100101_01000111_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRINTI (predicated)
+    0b01100101_10000111_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRINTI (predicated)
+    0b01100101_11000111_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "frintm" = [
     // FRINTM (vector)
-    0b00001110_01111001_10011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_00100001_10011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100001_10011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01111001_10011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_00100001_10011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100001_10011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FRINTM (scalar)
-    0b00011110_11100101_01000000_00000000 = [H, H] => [R(0), R(5)];
-    0b00011110_00100101_01000000_00000000 = [S, S] => [R(0), R(5)];
-    0b00011110_01100101_01000000_00000000 = [D, D] => [R(0), R(5)];
+    0b00011110_11100101_01000000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100101_01000000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100101_01000000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    // FRINTM (predicated)
+    0b01100101_01000010_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRINTM (predicated)
+    0b01100101_10000010_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRINTM (predicated)
+    0b01100101_11000010_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "frintn" = [
     // FRINTN (vector)
-    0b00001110_01111001_10001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_00100001_10001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100001_10001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01111001_10001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_00100001_10001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100001_10001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FRINTN (scalar)
-    0b00011110_11100100_01000000_00000000 = [H, H] => [R(0), R(5)];
-    0b00011110_00100100_01000000_00000000 = [S, S] => [R(0), R(5)];
-    0b00011110_01100100_01000000_00000000 = [D, D] => [R(0), R(5)];
+    0b00011110_11100100_01000000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100100_01000000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100100_01000000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    // FRINTN (predicated)
+    0b01100101_01000000_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRINTN (predicated)
+    0b01100101_10000000_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRINTN (predicated)
+    0b01100101_11000000_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "frintp" = [
     // FRINTP (vector)
-    0b00001110_11111001_10001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100001_10001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100001_10001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11111001_10001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_10100001_10001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100001_10001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FRINTP (scalar)
-    0b00011110_11100100_11000000_00000000 = [H, H] => [R(0), R(5)];
-    0b00011110_00100100_11000000_00000000 = [S, S] => [R(0), R(5)];
-    0b00011110_01100100_11000000_00000000 = [D, D] => [R(0), R(5)];
+    0b00011110_11100100_11000000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100100_11000000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100100_11000000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    // FRINTP (predicated)
+    0b01100101_01000001_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRINTP (predicated)
+    0b01100101_10000001_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRINTP (predicated)
+    0b01100101_11000001_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "frintx" = [
     // FRINTX (vector)
-    0b00101110_01111001_10011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_00100001_10011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100001_10011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01111001_10011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_00100001_10011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100001_10011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FRINTX (scalar)
-    0b00011110_11100111_01000000_00000000 = [H, H] => [R(0), R(5)];
-    0b00011110_00100111_01000000_00000000 = [S, S] => [R(0), R(5)];
-    0b00011110_01100111_01000000_00000000 = [D, D] => [R(0), R(5)];
+    0b00011110_11100111_01000000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100111_01000000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100111_01000000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    // FRINTX (predicated)
+    0b01100101_01000110_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRINTX (predicated)
+    0b01100101_10000110_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRINTX (predicated)
+    0b01100101_11000110_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "frintz" = [
     // FRINTZ (vector)
-    0b00001110_11111001_10011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100001_10011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100001_10011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11111001_10011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_10100001_10011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100001_10011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FRINTZ (scalar)
-    0b00011110_11100101_11000000_00000000 = [H, H] => [R(0), R(5)];
-    0b00011110_00100101_11000000_00000000 = [S, S] => [R(0), R(5)];
-    0b00011110_01100101_11000000_00000000 = [D, D] => [R(0), R(5)];
+    0b00011110_11100101_11000000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100101_11000000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100101_11000000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    // FRINTZ (predicated)
+    0b01100101_01000011_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRINTZ (predicated)
+    0b01100101_10000011_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FRINTZ (predicated)
+    0b01100101_11000011_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "frsqrte" = [
-    0b01111110_11111001_11011000_00000000 = [H, H] => [R(0), R(5)];
-    0b01111110_10100001_11011000_00000000 = [S, S] => [R(0), R(5)];
-    0b01111110_11100001_11011000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_11111001_11011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100001_11011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_11100001_11011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01111110_11111001_11011000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01111110_10100001_11011000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_11100001_11011000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_11111001_11011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_10100001_11011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100001_11011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
 ]
 "frsqrts" = [
-    0b01011110_11000000_00111100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01011110_10100000_11111100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01011110_11100000_11111100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_11000000_00111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_11111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_11111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_11000000_00111100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b01011110_10100000_11111100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_11100000_11111100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_11000000_00111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00001110_10100000_11111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_11111100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // FRSQRTS (vectors, unpredicated)
+    0b01100101_01000000_00011100_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // FRSQRTS (vectors, unpredicated)
+    0b01100101_10000000_00011100_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // FRSQRTS (vectors, unpredicated)
+    0b01100101_11000000_00011100_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+]
+"fscale" = [
+    // FSCALE (vectors, predicated)
+    0b01100101_01001001_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FSCALE (vectors, predicated)
+    0b01100101_10001001_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FSCALE (vectors, predicated)
+    0b01100101_11001001_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "fsqrt" = [
     // FSQRT (vector)
-    0b00101110_11111001_11111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100001_11111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_11100001_11111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11111001_11111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_10100001_11111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100001_11111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // FSQRT (scalar)
-    0b00011110_11100001_11000000_00000000 = [H, H] => [R(0), R(5)];
-    0b00011110_00100001_11000000_00000000 = [S, S] => [R(0), R(5)];
-    0b00011110_01100001_11000000_00000000 = [D, D] => [R(0), R(5)];
+    0b00011110_11100001_11000000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100001_11000000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100001_11000000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    // FSQRT (predicated)
+    0b01100101_01001101_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FSQRT (predicated)
+    0b01100101_10001101_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // FSQRT (predicated)
+    0b01100101_11001101_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "fsub" = [
     // FSUB (vector)
-    0b00001110_11000000_00010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_11010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_11010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_00010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_FP16];
+    0b00001110_10100000_11010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_11010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // FSUB (scalar)
-    0b00011110_11100000_00111000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b00011110_00100000_00111000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00011110_01100000_00111000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
+    0b00011110_11100000_00111000_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_FP16];
+    0b00011110_00100000_00111000_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00011110_01100000_00111000_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    // FSUB (vectors, predicated)
+    0b01100101_01000001_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FSUB (vectors, predicated)
+    0b01100101_10000001_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FSUB (vectors, predicated)
+    0b01100101_11000001_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FSUB (vectors, unpredicated)
+    0b01100101_01000000_00000100_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // FSUB (vectors, unpredicated)
+    0b01100101_10000000_00000100_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // FSUB (vectors, unpredicated)
+    0b01100101_11000000_00000100_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+]
+"fsubr" = [
+    // FSUBR (vectors, predicated)
+    0b01100101_01000011_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FSUBR (vectors, predicated)
+    0b01100101_10000011_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // FSUBR (vectors, predicated)
+    0b01100101_11000011_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+]
+"gmi" = [
+    0b10011010_11000000_00010100_00000000 = [X, XSP, X] => [R(0), R(5), R(16)], [Ex_MTE];
 ]
 "hint" = [
-    0b11010101_00000011_00100000_00011111 = [Imm] => [Ubits(5, 7)];
+    0b11010101_00000011_00100000_00011111 = [Imm] => [Ubits(5, 7)], [Ex_BASE];
+]
+"histcnt" = [
+    0b01000101_10100000_11000000_00000000 = [Z(B_4), PZero, Z(B_4), Z(B_4)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11100000_11000000_00000000 = [Z(B_8), PZero, Z(B_8), Z(B_8)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE2];
+]
+"histseg" = [
+    // HISTSEG (vectors, unpredicated)
+    0b01000101_00100000_10100000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "hlt" = [
-    0b11010100_01000000_00000000_00000000 = [Imm] => [Ubits(5, 16)];
+    0b11010100_01000000_00000000_00000000 = [Imm] => [Ubits(5, 16)], [Ex_BASE];
 ]
 "hvc" = [
-    0b11010100_00000000_00000000_00000010 = [Imm] => [Ubits(5, 16)];
+    0b11010100_00000000_00000000_00000010 = [Imm] => [Ubits(5, 16)], [Ex_BASE];
 ]
 "ic" = [
-    0b11010101_00001011_01110101_00100000 = [Lit("ivau"), X] => [R(0)];
-    0b11010101_00001000_01110000_00011111 = [Ident] => [LitList(5, "IC_OPS")];
+    0b11010101_00001011_01110101_00100000 = [Lit("ivau"), X] => [R(0)], [Ex_BASE];
+    0b11010101_00001000_01110000_00011111 = [Ident] => [LitList(5, "IC_OPS")], [Ex_BASE];
+]
+"incb" = [
+    0b00000100_00110000_11100011_11100000 = [X] => [R(0)], [Ex_SVE];
+    0b00000100_00110000_11100000_00000000 = [X, Ident] => [R(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00000100_00110000_11100000_00000000 = [X, Ident, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], [Ex_SVE];
+]
+"incd" = [
+    0b00000100_11110000_11100011_11100000 = [X] => [R(0)], [Ex_SVE];
+    0b00000100_11110000_11100000_00000000 = [X, Ident] => [R(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00000100_11110000_11100000_00000000 = [X, Ident, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], [Ex_SVE];
+]
+"inch" = [
+    0b00000100_01110000_11100011_11100000 = [X] => [R(0)], [Ex_SVE];
+    0b00000100_01110000_11100000_00000000 = [X, Ident] => [R(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00000100_01110000_11100000_00000000 = [X, Ident, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], [Ex_SVE];
+]
+"incp" = [
+    // INCP (scalar)
+    0b00100101_00101100_10001000_00000000 = [X, P(BYTE)] => [R(0), R4(5)], [Ex_SVE];
+    0b00100101_01101100_10001000_00000000 = [X, P(B_2)] => [R(0), R4(5)], [Ex_SVE];
+    0b00100101_10101100_10001000_00000000 = [X, P(B_4)] => [R(0), R4(5)], [Ex_SVE];
+    0b00100101_11101100_10001000_00000000 = [X, P(B_8)] => [R(0), R4(5)], [Ex_SVE];
+]
+"incw" = [
+    0b00000100_10110000_11100011_11100000 = [X] => [R(0)], [Ex_SVE];
+    0b00000100_10110000_11100000_00000000 = [X, Ident] => [R(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00000100_10110000_11100000_00000000 = [X, Ident, LitMod(MUL)] => [R(0), LitList(5, "SVE_PATTERNS"), Urange(16, 1, 16)], [Ex_SVE];
+]
+"index" = [
+    0b00000100_00100000_01000000_00000000 = [Z(BYTE), Imm, Imm] => [R(0), Sbits(5, 5), Sbits(16, 5)], [Ex_SVE];
+    0b00000100_00100000_01001000_00000000 = [Z(BYTE), Imm, W] => [R(0), Sbits(5, 5), R(16)], [Ex_SVE];
+    0b00000100_00100000_01000100_00000000 = [Z(BYTE), W, Imm] => [R(0), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00000100_00100000_01001100_00000000 = [Z(BYTE), W, W] => [R(0), R(5), R(16)], [Ex_SVE];
+    0b00000100_01100000_01000000_00000000 = [Z(B_2), Imm, Imm] => [R(0), Sbits(5, 5), Sbits(16, 5)], [Ex_SVE];
+    0b00000100_01100000_01001000_00000000 = [Z(B_2), Imm, W] => [R(0), Sbits(5, 5), R(16)], [Ex_SVE];
+    0b00000100_01100000_01000100_00000000 = [Z(B_2), W, Imm] => [R(0), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00000100_01100000_01001100_00000000 = [Z(B_2), W, W] => [R(0), R(5), R(16)], [Ex_SVE];
+    0b00000100_10100000_01000000_00000000 = [Z(B_4), Imm, Imm] => [R(0), Sbits(5, 5), Sbits(16, 5)], [Ex_SVE];
+    0b00000100_10100000_01001000_00000000 = [Z(B_4), Imm, W] => [R(0), Sbits(5, 5), R(16)], [Ex_SVE];
+    0b00000100_10100000_01000100_00000000 = [Z(B_4), W, Imm] => [R(0), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00000100_10100000_01001100_00000000 = [Z(B_4), W, W] => [R(0), R(5), R(16)], [Ex_SVE];
+    0b00000100_11100000_01000000_00000000 = [Z(B_8), Imm, Imm] => [R(0), Sbits(5, 5), Sbits(16, 5)], [Ex_SVE];
+    0b00000100_11100000_01001000_00000000 = [Z(B_8), Imm, X] => [R(0), Sbits(5, 5), R(16)], [Ex_SVE];
+    0b00000100_11100000_01000100_00000000 = [Z(B_8), X, Imm] => [R(0), R(5), Sbits(16, 5)], [Ex_SVE];
+    0b00000100_11100000_01001100_00000000 = [Z(B_8), X, X] => [R(0), R(5), R(16)], [Ex_SVE];
 ]
 "ins" = [
     // INS (element)
-    0b01101110_00000001_00000100_00000000 = [VElement(BYTE), VElement(BYTE)] => [R(0), Ubits(17, 4), R(5), Ubits(11, 4)];
-    0b01101110_00000010_00000100_00000000 = [VElement(B_2), VElement(B_2)] => [R(0), Ubits(18, 3), R(5), Ubits(12, 3)];
-    0b01101110_00000100_00000100_00000000 = [VElement(B_4), VElement(B_4)] => [R(0), Ubits(19, 2), R(5), Ubits(13, 2)];
-    0b01101110_00001000_00000100_00000000 = [VElement(B_8), VElement(B_8)] => [R(0), Ubits(20, 1), R(5), Ubits(14, 1)];
+    0b01101110_00000001_00000100_00000000 = [VElement(BYTE), VElement(BYTE)] => [R(0), Ubits(17, 4), R(5), Ubits(11, 4)], [Ex_BASE];
+    0b01101110_00000010_00000100_00000000 = [VElement(B_2), VElement(B_2)] => [R(0), Ubits(18, 3), R(5), Ubits(12, 3)], [Ex_BASE];
+    0b01101110_00000100_00000100_00000000 = [VElement(B_4), VElement(B_4)] => [R(0), Ubits(19, 2), R(5), Ubits(13, 2)], [Ex_BASE];
+    0b01101110_00001000_00000100_00000000 = [VElement(B_8), VElement(B_8)] => [R(0), Ubits(20, 1), R(5), Ubits(14, 1)], [Ex_BASE];
     // INS (general)
-    0b01001110_00000001_00011100_00000000 = [VElement(BYTE), W] => [R(0), Ubits(17, 4), R(5)];
-    0b01001110_00000010_00011100_00000000 = [VElement(B_2), W] => [R(0), Ubits(18, 3), R(5)];
-    0b01001110_00000100_00011100_00000000 = [VElement(B_4), W] => [R(0), Ubits(19, 2), R(5)];
-    0b01001110_00001000_00011100_00000000 = [VElement(B_8), X] => [R(0), Ubits(20, 1), R(5)];
+    0b01001110_00000001_00011100_00000000 = [VElement(BYTE), W] => [R(0), Ubits(17, 4), R(5)], [Ex_BASE];
+    0b01001110_00000010_00011100_00000000 = [VElement(B_2), W] => [R(0), Ubits(18, 3), R(5)], [Ex_BASE];
+    0b01001110_00000100_00011100_00000000 = [VElement(B_4), W] => [R(0), Ubits(19, 2), R(5)], [Ex_BASE];
+    0b01001110_00001000_00011100_00000000 = [VElement(B_8), X] => [R(0), Ubits(20, 1), R(5)], [Ex_BASE];
+]
+"irg" = [
+    0b10011010_11000000_00010000_00000000 = [XSP, XSP, End, X] => [R(0), R(5), R(16)], [Ex_MTE];
 ]
 "isb" = [
-    0b11010101_00000011_00111111_11011111 = [Lit("sy")] => [];
-    0b11010101_00000011_00110000_11011111 = [Imm] => [Ubits(8, 4)];
-    0b11010101_00000011_00111111_11011111 = [] => [];
+    0b11010101_00000011_00111111_11011111 = [Lit("sy")] => [], [Ex_BASE];
+    0b11010101_00000011_00110000_11011111 = [Imm] => [Ubits(8, 4)], [Ex_BASE];
+    0b11010101_00000011_00111111_11011111 = [] => [], [Ex_BASE];
+]
+"lasta" = [
+    // LASTA (scalar)
+    0b00000101_00100000_10100000_00000000 = [W, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_01100000_10100000_00000000 = [W, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_10100000_10100000_00000000 = [W, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_11100000_10100000_00000000 = [X, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // LASTA (SIMD&FP scalar)
+    0b00000101_00100010_10000000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_01100010_10000000_00000000 = [H, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_10100010_10000000_00000000 = [S, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_11100010_10000000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+]
+"lastb" = [
+    // LASTB (scalar)
+    0b00000101_00100001_10100000_00000000 = [W, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_01100001_10100000_00000000 = [W, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_10100001_10100000_00000000 = [W, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_11100001_10100000_00000000 = [X, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // LASTB (SIMD&FP scalar)
+    0b00000101_00100011_10000000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_01100011_10000000_00000000 = [H, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_10100011_10000000_00000000 = [S, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_11100011_10000000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "ld1" = [
     // LD1 (multiple structures)
-    0b00001100_01000000_01110000_00000000 = [RegList(1, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_01110100_00000000 = [RegList(1, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_01111000_00000000 = [RegList(1, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_01111100_00000000 = [RegList(1, B_8), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_10100000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_10100100_00000000 = [RegList(2, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_10101000_00000000 = [RegList(2, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_10101100_00000000 = [RegList(2, B_8), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_01100000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_01100100_00000000 = [RegList(3, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_01101000_00000000 = [RegList(3, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_01101100_00000000 = [RegList(3, B_8), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_00100000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_00100100_00000000 = [RegList(4, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_00101000_00000000 = [RegList(4, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_00101100_00000000 = [RegList(4, B_8), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_11011111_01110000_00000000 = [RegListStatic(1, BYTE, 8), RefBase, LitInt(8)] => [R(0), R(5)];
-    0b00001100_11011111_01110100_00000000 = [RegListStatic(1, B_2, 4), RefBase, LitInt(8)] => [R(0), R(5)];
-    0b00001100_11011111_01111000_00000000 = [RegListStatic(1, B_4, 2), RefBase, LitInt(8)] => [R(0), R(5)];
-    0b00001100_11011111_01111100_00000000 = [RegListStatic(1, B_8, 1), RefBase, LitInt(8)] => [R(0), R(5)];
-    0b01001100_11011111_01110000_00000000 = [RegListStatic(1, BYTE, 16), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b01001100_11011111_01110100_00000000 = [RegListStatic(1, B_2, 8), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b01001100_11011111_01111000_00000000 = [RegListStatic(1, B_4, 4), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b01001100_11011111_01111100_00000000 = [RegListStatic(1, B_8, 2), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b00001100_11000000_01110000_00000000 = [RegList(1, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_01110100_00000000 = [RegList(1, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_01111000_00000000 = [RegList(1, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_01111100_00000000 = [RegList(1, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11011111_10100000_00000000 = [RegListStatic(2, BYTE, 8), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b00001100_11011111_10100100_00000000 = [RegListStatic(2, B_2, 4), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b00001100_11011111_10101000_00000000 = [RegListStatic(2, B_4, 2), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b00001100_11011111_10101100_00000000 = [RegListStatic(2, B_8, 1), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b01001100_11011111_10100000_00000000 = [RegListStatic(2, BYTE, 16), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_11011111_10100100_00000000 = [RegListStatic(2, B_2, 8), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_11011111_10101000_00000000 = [RegListStatic(2, B_4, 4), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_11011111_10101100_00000000 = [RegListStatic(2, B_8, 2), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b00001100_11000000_10100000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_10100100_00000000 = [RegList(2, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_10101000_00000000 = [RegList(2, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_10101100_00000000 = [RegList(2, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11011111_01100000_00000000 = [RegListStatic(3, BYTE, 8), RefBase, LitInt(24)] => [R(0), R(5)];
-    0b00001100_11011111_01100100_00000000 = [RegListStatic(3, B_2, 4), RefBase, LitInt(24)] => [R(0), R(5)];
-    0b00001100_11011111_01101000_00000000 = [RegListStatic(3, B_4, 2), RefBase, LitInt(24)] => [R(0), R(5)];
-    0b00001100_11011111_01101100_00000000 = [RegListStatic(3, B_8, 1), RefBase, LitInt(24)] => [R(0), R(5)];
-    0b01001100_11011111_01100000_00000000 = [RegListStatic(3, BYTE, 16), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b01001100_11011111_01100100_00000000 = [RegListStatic(3, B_2, 8), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b01001100_11011111_01101000_00000000 = [RegListStatic(3, B_4, 4), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b01001100_11011111_01101100_00000000 = [RegListStatic(3, B_8, 2), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b00001100_11000000_01100000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_01100100_00000000 = [RegList(3, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_01101000_00000000 = [RegList(3, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_01101100_00000000 = [RegList(3, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11011111_00100000_00000000 = [RegListStatic(4, BYTE, 8), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b00001100_11011111_00100100_00000000 = [RegListStatic(4, B_2, 4), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b00001100_11011111_00101000_00000000 = [RegListStatic(4, B_4, 2), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b00001100_11011111_00101100_00000000 = [RegListStatic(4, B_8, 1), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_11011111_00100000_00000000 = [RegListStatic(4, BYTE, 16), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b01001100_11011111_00100100_00000000 = [RegListStatic(4, B_2, 8), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b01001100_11011111_00101000_00000000 = [RegListStatic(4, B_4, 4), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b01001100_11011111_00101100_00000000 = [RegListStatic(4, B_8, 2), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b00001100_11000000_00100000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_00100100_00000000 = [RegList(4, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_00101000_00000000 = [RegList(4, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_00101100_00000000 = [RegList(4, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_01000000_01110000_00000000 = [RegList(1, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_01110100_00000000 = [RegList(1, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_01111000_00000000 = [RegList(1, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_01111100_00000000 = [RegList(1, B_8), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_10100000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_10100100_00000000 = [RegList(2, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_10101000_00000000 = [RegList(2, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_10101100_00000000 = [RegList(2, B_8), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_01100000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_01100100_00000000 = [RegList(3, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_01101000_00000000 = [RegList(3, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_01101100_00000000 = [RegList(3, B_8), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_00100000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_00100100_00000000 = [RegList(4, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_00101000_00000000 = [RegList(4, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_00101100_00000000 = [RegList(4, B_8), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_11011111_01110000_00000000 = [RegListStatic(1, BYTE, 8), RefBase, LitInt(8)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_01110100_00000000 = [RegListStatic(1, B_2, 4), RefBase, LitInt(8)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_01111000_00000000 = [RegListStatic(1, B_4, 2), RefBase, LitInt(8)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_01111100_00000000 = [RegListStatic(1, B_8, 1), RefBase, LitInt(8)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_01110000_00000000 = [RegListStatic(1, BYTE, 16), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_01110100_00000000 = [RegListStatic(1, B_2, 8), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_01111000_00000000 = [RegListStatic(1, B_4, 4), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_01111100_00000000 = [RegListStatic(1, B_8, 2), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11000000_01110000_00000000 = [RegList(1, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_01110100_00000000 = [RegList(1, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_01111000_00000000 = [RegList(1, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_01111100_00000000 = [RegList(1, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11011111_10100000_00000000 = [RegListStatic(2, BYTE, 8), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_10100100_00000000 = [RegListStatic(2, B_2, 4), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_10101000_00000000 = [RegListStatic(2, B_4, 2), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_10101100_00000000 = [RegListStatic(2, B_8, 1), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_10100000_00000000 = [RegListStatic(2, BYTE, 16), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_10100100_00000000 = [RegListStatic(2, B_2, 8), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_10101000_00000000 = [RegListStatic(2, B_4, 4), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_10101100_00000000 = [RegListStatic(2, B_8, 2), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11000000_10100000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_10100100_00000000 = [RegList(2, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_10101000_00000000 = [RegList(2, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_10101100_00000000 = [RegList(2, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11011111_01100000_00000000 = [RegListStatic(3, BYTE, 8), RefBase, LitInt(24)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_01100100_00000000 = [RegListStatic(3, B_2, 4), RefBase, LitInt(24)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_01101000_00000000 = [RegListStatic(3, B_4, 2), RefBase, LitInt(24)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_01101100_00000000 = [RegListStatic(3, B_8, 1), RefBase, LitInt(24)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_01100000_00000000 = [RegListStatic(3, BYTE, 16), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_01100100_00000000 = [RegListStatic(3, B_2, 8), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_01101000_00000000 = [RegListStatic(3, B_4, 4), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_01101100_00000000 = [RegListStatic(3, B_8, 2), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11000000_01100000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_01100100_00000000 = [RegList(3, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_01101000_00000000 = [RegList(3, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_01101100_00000000 = [RegList(3, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11011111_00100000_00000000 = [RegListStatic(4, BYTE, 8), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_00100100_00000000 = [RegListStatic(4, B_2, 4), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_00101000_00000000 = [RegListStatic(4, B_4, 2), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_00101100_00000000 = [RegListStatic(4, B_8, 1), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_00100000_00000000 = [RegListStatic(4, BYTE, 16), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_00100100_00000000 = [RegListStatic(4, B_2, 8), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_00101000_00000000 = [RegListStatic(4, B_4, 4), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_00101100_00000000 = [RegListStatic(4, B_8, 2), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11000000_00100000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_00100100_00000000 = [RegList(4, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_00101000_00000000 = [RegList(4, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_00101100_00000000 = [RegList(4, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
     // LD1 (single structure)
-    0b00001101_01000000_00000000_00000000 = [RegListElement(1, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_01000000_01000000_00000000 = [RegListElement(1, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_01000000_10000000_00000000 = [RegListElement(1, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_01000000_10000100_00000000 = [RegListElement(1, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_11011111_00000000_00000000 = [RegListElement(1, BYTE), RefBase, LitInt(1)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_11000000_00000000_00000000 = [RegListElement(1, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
-    0b00001101_11011111_01000000_00000000 = [RegListElement(1, B_2), RefBase, LitInt(2)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_11000000_01000000_00000000 = [RegListElement(1, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
-    0b00001101_11011111_10000000_00000000 = [RegListElement(1, B_4), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_11000000_10000000_00000000 = [RegListElement(1, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
-    0b00001101_11011111_10000100_00000000 = [RegListElement(1, B_8), RefBase, LitInt(8)] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_11000000_10000100_00000000 = [RegListElement(1, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+    0b00001101_01000000_00000000_00000000 = [RegListElement(1, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_01000000_01000000_00000000 = [RegListElement(1, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_01000000_10000000_00000000 = [RegListElement(1, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_01000000_10000100_00000000 = [RegListElement(1, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_11011111_00000000_00000000 = [RegListElement(1, BYTE), RefBase, LitInt(1)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_11000000_00000000_00000000 = [RegListElement(1, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_11011111_01000000_00000000 = [RegListElement(1, B_2), RefBase, LitInt(2)] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_11000000_01000000_00000000 = [RegListElement(1, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_11011111_10000000_00000000 = [RegListElement(1, B_4), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_11000000_10000000_00000000 = [RegListElement(1, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_11011111_10000100_00000000 = [RegListElement(1, B_8), RefBase, LitInt(8)] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_11000000_10000100_00000000 = [RegListElement(1, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)], [Ex_BASE];
+]
+"ld1b" = [
+    // LD1B (scalar plus immediate)
+    0b10100100_00000000_10100000_00000000 = [ZRegList(1, BYTE), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100100_00100000_10100000_00000000 = [ZRegList(1, B_2), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100100_01000000_10100000_00000000 = [ZRegList(1, B_4), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100100_01100000_10100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // LD1B (scalar plus scalar)
+    0b10100100_00000000_01000000_00000000 = [ZRegList(1, BYTE), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b10100100_00100000_01000000_00000000 = [ZRegList(1, B_2), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b10100100_01000000_01000000_00000000 = [ZRegList(1, B_4), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b10100100_01100000_01000000_00000000 = [ZRegList(1, B_8), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    // LD1B (scalar plus scalar, tile slice)
+    0b11100000_00000000_00000000_00000000 = [ZaSliceListH(BYTE), PZero, RefIndexLsl(0)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_00011111_00000000_00000000 = [ZaSliceListH(BYTE), PZero, RefBase] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], [Ex_SME];
+    0b11100000_00000000_10000000_00000000 = [ZaSliceListV(BYTE), PZero, RefIndexLsl(0)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_00011111_10000000_00000000 = [ZaSliceListV(BYTE), PZero, RefBase] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], [Ex_SME];
+]
+"ld1d" = [
+    // LD1D (scalar plus immediate)
+    0b10100101_11100000_10100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // LD1D (scalar plus scalar)
+    0b10100101_11100000_01000000_00000000 = [ZRegList(1, B_8), PZero, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    // LD1D (scalar plus scalar, tile slice)
+    0b11100000_11000000_00000000_00000000 = [ZaSliceListH(B_8), PZero, RefIndexLsl(3)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_11011111_00000000_00000000 = [ZaSliceListH(B_8), PZero, RefBase] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], [Ex_SME];
+    0b11100000_11000000_10000000_00000000 = [ZaSliceListV(B_8), PZero, RefIndexLsl(3)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_11011111_10000000_00000000 = [ZaSliceListV(B_8), PZero, RefBase] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], [Ex_SME];
+]
+"ld1h" = [
+    // LD1H (scalar plus immediate)
+    0b10100100_10100000_10100000_00000000 = [ZRegList(1, B_2), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100100_11000000_10100000_00000000 = [ZRegList(1, B_4), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100100_11100000_10100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // LD1H (scalar plus scalar)
+    0b10100100_10100000_01000000_00000000 = [ZRegList(1, B_2), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b10100100_11000000_01000000_00000000 = [ZRegList(1, B_4), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b10100100_11100000_01000000_00000000 = [ZRegList(1, B_8), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    // LD1H (scalar plus scalar, tile slice)
+    0b11100000_01000000_00000000_00000000 = [ZaSliceListH(B_2), PZero, RefIndexLsl(1)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_01011111_00000000_00000000 = [ZaSliceListH(B_2), PZero, RefBase] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], [Ex_SME];
+    0b11100000_01000000_10000000_00000000 = [ZaSliceListV(B_2), PZero, RefIndexLsl(1)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_01011111_10000000_00000000 = [ZaSliceListV(B_2), PZero, RefBase] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], [Ex_SME];
+]
+"ld1q" = [
+    0b11100001_11000000_00000000_00000000 = [ZaSliceListH(B_16), PZero, RefIndexLsl(4)] => [RTile(0, 4), RSelect(13), Ubits(0, 0), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100001_11011111_00000000_00000000 = [ZaSliceListH(B_16), PZero, RefBase] => [RTile(0, 4), RSelect(13), Ubits(0, 0), R3(10), R(5)], [Ex_SME];
+    0b11100001_11000000_10000000_00000000 = [ZaSliceListV(B_16), PZero, RefIndexLsl(4)] => [RTile(0, 4), RSelect(13), Ubits(0, 0), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100001_11011111_10000000_00000000 = [ZaSliceListV(B_16), PZero, RefBase] => [RTile(0, 4), RSelect(13), Ubits(0, 0), R3(10), R(5)], [Ex_SME];
 ]
 "ld1r" = [
-    0b00001101_01000000_11000000_00000000 = [RegList(1, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_01000000_11000100_00000000 = [RegList(1, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_01000000_11001000_00000000 = [RegList(1, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_01000000_11001100_00000000 = [RegList(1, B_8), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11011111_11000000_00000000 = [RegList(1, BYTE), RefBase, LitInt(1)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11011111_11000100_00000000 = [RegList(1, B_2), RefBase, LitInt(2)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11011111_11001000_00000000 = [RegList(1, B_4), RefBase, LitInt(4)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11011111_11001100_00000000 = [RegList(1, B_8), RefBase, LitInt(8)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11000000_11000000_00000000 = [RegList(1, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001101_11000000_11000100_00000000 = [RegList(1, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001101_11000000_11001000_00000000 = [RegList(1, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001101_11000000_11001100_00000000 = [RegList(1, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_01000000_11000000_00000000 = [RegList(1, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_01000000_11000100_00000000 = [RegList(1, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_01000000_11001000_00000000 = [RegList(1, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_01000000_11001100_00000000 = [RegList(1, B_8), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11011111_11000000_00000000 = [RegList(1, BYTE), RefBase, LitInt(1)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11011111_11000100_00000000 = [RegList(1, B_2), RefBase, LitInt(2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11011111_11001000_00000000 = [RegList(1, B_4), RefBase, LitInt(4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11011111_11001100_00000000 = [RegList(1, B_8), RefBase, LitInt(8)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11000000_11000000_00000000 = [RegList(1, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001101_11000000_11000100_00000000 = [RegList(1, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001101_11000000_11001000_00000000 = [RegList(1, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001101_11000000_11001100_00000000 = [RegList(1, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+]
+"ld1rb" = [
+    0b10000100_01000000_10000000_00000000 = [ZRegList(1, BYTE), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 0)], [Ex_SVE];
+    0b10000100_01000000_10100000_00000000 = [ZRegList(1, B_2), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 0)], [Ex_SVE];
+    0b10000100_01000000_11000000_00000000 = [ZRegList(1, B_4), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 0)], [Ex_SVE];
+    0b10000100_01000000_11100000_00000000 = [ZRegList(1, B_8), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 0)], [Ex_SVE];
+]
+"ld1rd" = [
+    0b10000101_11000000_11100000_00000000 = [ZRegList(1, B_8), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 3)], [Ex_SVE];
+]
+"ld1rh" = [
+    0b10000100_11000000_10100000_00000000 = [ZRegList(1, B_2), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 1)], [Ex_SVE];
+    0b10000100_11000000_11000000_00000000 = [ZRegList(1, B_4), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 1)], [Ex_SVE];
+    0b10000100_11000000_11100000_00000000 = [ZRegList(1, B_8), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 1)], [Ex_SVE];
+]
+"ld1rqb" = [
+    // LD1RQB (scalar plus immediate)
+    0b10100100_00000000_00100000_00000000 = [ZRegList(1, BYTE), PZero, RefOffset] => [R(0), R3(10), R(5), Sscaled(16, 4, 4)], [Ex_SVE];
+    // LD1RQB (scalar plus scalar)
+    0b10100100_00000000_00000000_00000000 = [ZRegList(1, BYTE), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ld1rqd" = [
+    // LD1RQD (scalar plus immediate)
+    0b10100101_10000000_00100000_00000000 = [ZRegList(1, B_8), PZero, RefOffset] => [R(0), R3(10), R(5), Sscaled(16, 4, 4)], [Ex_SVE];
+    // LD1RQD (scalar plus scalar)
+    0b10100101_10000000_00000000_00000000 = [ZRegList(1, B_8), PZero, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ld1rqh" = [
+    // LD1RQH (scalar plus immediate)
+    0b10100100_10000000_00100000_00000000 = [ZRegList(1, B_2), PZero, RefOffset] => [R(0), R3(10), R(5), Sscaled(16, 4, 4)], [Ex_SVE];
+    // LD1RQH (scalar plus scalar)
+    0b10100100_10000000_00000000_00000000 = [ZRegList(1, B_2), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ld1rqw" = [
+    // LD1RQW (scalar plus immediate)
+    0b10100101_00000000_00100000_00000000 = [ZRegList(1, B_4), PZero, RefOffset] => [R(0), R3(10), R(5), Sscaled(16, 4, 4)], [Ex_SVE];
+    // LD1RQW (scalar plus scalar)
+    0b10100101_00000000_00000000_00000000 = [ZRegList(1, B_4), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ld1rsb" = [
+    0b10000101_11000000_11000000_00000000 = [ZRegList(1, B_2), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 0)], [Ex_SVE];
+    0b10000101_11000000_10100000_00000000 = [ZRegList(1, B_4), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 0)], [Ex_SVE];
+    0b10000101_11000000_10000000_00000000 = [ZRegList(1, B_8), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 0)], [Ex_SVE];
+]
+"ld1rsh" = [
+    0b10000101_01000000_10100000_00000000 = [ZRegList(1, B_4), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 1)], [Ex_SVE];
+    0b10000101_01000000_10000000_00000000 = [ZRegList(1, B_8), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 1)], [Ex_SVE];
+]
+"ld1rsw" = [
+    0b10000100_11000000_10000000_00000000 = [ZRegList(1, B_8), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 2)], [Ex_SVE];
+]
+"ld1rw" = [
+    0b10000101_01000000_11000000_00000000 = [ZRegList(1, B_4), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 2)], [Ex_SVE];
+    0b10000101_01000000_11100000_00000000 = [ZRegList(1, B_8), PZero, RefOffset] => [R(0), R3(10), R(5), Uscaled(16, 6, 2)], [Ex_SVE];
+]
+"ld1sb" = [
+    // LD1SB (scalar plus immediate)
+    0b10100101_11000000_10100000_00000000 = [ZRegList(1, B_2), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100101_10100000_10100000_00000000 = [ZRegList(1, B_4), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100101_10000000_10100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // LD1SB (scalar plus scalar)
+    0b10100101_11000000_01000000_00000000 = [ZRegList(1, B_2), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b10100101_10100000_01000000_00000000 = [ZRegList(1, B_4), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b10100101_10000000_01000000_00000000 = [ZRegList(1, B_8), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ld1sh" = [
+    // LD1SH (scalar plus immediate)
+    0b10100101_00100000_10100000_00000000 = [ZRegList(1, B_4), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100101_00000000_10100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // LD1SH (scalar plus scalar)
+    0b10100101_00100000_01000000_00000000 = [ZRegList(1, B_4), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b10100101_00000000_01000000_00000000 = [ZRegList(1, B_8), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ld1sw" = [
+    // LD1SW (scalar plus immediate)
+    0b10100100_10000000_10100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // LD1SW (scalar plus scalar)
+    0b10100100_10000000_01000000_00000000 = [ZRegList(1, B_8), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ld1w" = [
+    // LD1W (scalar plus immediate)
+    0b10100101_01000000_10100000_00000000 = [ZRegList(1, B_4), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100101_01100000_10100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // LD1W (scalar plus scalar)
+    0b10100101_01000000_01000000_00000000 = [ZRegList(1, B_4), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b10100101_01100000_01000000_00000000 = [ZRegList(1, B_8), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    // LD1W (scalar plus scalar, tile slice)
+    0b11100000_10000000_00000000_00000000 = [ZaSliceListH(B_4), PZero, RefIndexLsl(2)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_10011111_00000000_00000000 = [ZaSliceListH(B_4), PZero, RefBase] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], [Ex_SME];
+    0b11100000_10000000_10000000_00000000 = [ZaSliceListV(B_4), PZero, RefIndexLsl(2)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_10011111_10000000_00000000 = [ZaSliceListV(B_4), PZero, RefBase] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], [Ex_SME];
 ]
 "ld2" = [
     // LD2 (multiple structures)
-    0b00001100_01000000_10000000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_10000100_00000000 = [RegList(2, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_10001000_00000000 = [RegList(2, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_10001100_00000000 = [RegListStatic(2, B_8, 2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_11011111_10000000_00000000 = [RegListStatic(2, BYTE, 8), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b00001100_11011111_10000100_00000000 = [RegListStatic(2, B_2, 4), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b00001100_11011111_10001000_00000000 = [RegListStatic(2, B_4, 2), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b01001100_11011111_10000000_00000000 = [RegListStatic(2, BYTE, 16), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_11011111_10000100_00000000 = [RegListStatic(2, B_2, 8), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_11011111_10001000_00000000 = [RegListStatic(2, B_4, 4), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_11011111_10001100_00000000 = [RegListStatic(2, B_8, 2), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b00001100_11000000_10000000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_10000100_00000000 = [RegList(2, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_10001000_00000000 = [RegList(2, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_10001100_00000000 = [RegListStatic(2, B_8, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_01000000_10000000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_10000100_00000000 = [RegList(2, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_10001000_00000000 = [RegList(2, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_10001100_00000000 = [RegListStatic(2, B_8, 2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_11011111_10000000_00000000 = [RegListStatic(2, BYTE, 8), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_10000100_00000000 = [RegListStatic(2, B_2, 4), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_10001000_00000000 = [RegListStatic(2, B_4, 2), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_10000000_00000000 = [RegListStatic(2, BYTE, 16), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_10000100_00000000 = [RegListStatic(2, B_2, 8), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_10001000_00000000 = [RegListStatic(2, B_4, 4), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_10001100_00000000 = [RegListStatic(2, B_8, 2), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11000000_10000000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_10000100_00000000 = [RegList(2, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_10001000_00000000 = [RegList(2, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_10001100_00000000 = [RegListStatic(2, B_8, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
     // LD2 (single structure)
-    0b00001101_01100000_00000000_00000000 = [RegListElement(2, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_01100000_01000000_00000000 = [RegListElement(2, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_01100000_10000000_00000000 = [RegListElement(2, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_01100000_10000100_00000000 = [RegListElement(2, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_11111111_00000000_00000000 = [RegListElement(2, BYTE), RefBase, LitInt(2)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_11100000_00000000_00000000 = [RegListElement(2, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
-    0b00001101_11111111_01000000_00000000 = [RegListElement(2, B_2), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_11100000_01000000_00000000 = [RegListElement(2, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
-    0b00001101_11111111_10000000_00000000 = [RegListElement(2, B_4), RefBase, LitInt(8)] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_11100000_10000000_00000000 = [RegListElement(2, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
-    0b00001101_11111111_10000100_00000000 = [RegListElement(2, B_8), RefBase, LitInt(16)] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_11100000_10000100_00000000 = [RegListElement(2, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+    0b00001101_01100000_00000000_00000000 = [RegListElement(2, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_01100000_01000000_00000000 = [RegListElement(2, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_01100000_10000000_00000000 = [RegListElement(2, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_01100000_10000100_00000000 = [RegListElement(2, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_11111111_00000000_00000000 = [RegListElement(2, BYTE), RefBase, LitInt(2)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_11100000_00000000_00000000 = [RegListElement(2, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_11111111_01000000_00000000 = [RegListElement(2, B_2), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_11100000_01000000_00000000 = [RegListElement(2, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_11111111_10000000_00000000 = [RegListElement(2, B_4), RefBase, LitInt(8)] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_11100000_10000000_00000000 = [RegListElement(2, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_11111111_10000100_00000000 = [RegListElement(2, B_8), RefBase, LitInt(16)] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_11100000_10000100_00000000 = [RegListElement(2, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)], [Ex_BASE];
+]
+"ld2b" = [
+    // LD2B (scalar plus immediate)
+    0b10100100_00100000_11100000_00000000 = [ZRegList(2, BYTE), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], [Ex_SVE];
+    // LD2B (scalar plus scalar)
+    0b10100100_00100000_11000000_00000000 = [ZRegList(2, BYTE), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ld2d" = [
+    // LD2D (scalar plus immediate)
+    0b10100101_10100000_11100000_00000000 = [ZRegList(2, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], [Ex_SVE];
+    // LD2D (scalar plus scalar)
+    0b10100101_10100000_11000000_00000000 = [ZRegList(2, B_8), PZero, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ld2h" = [
+    // LD2H (scalar plus immediate)
+    0b10100100_10100000_11100000_00000000 = [ZRegList(2, B_2), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], [Ex_SVE];
+    // LD2H (scalar plus scalar)
+    0b10100100_10100000_11000000_00000000 = [ZRegList(2, B_2), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
 ]
 "ld2r" = [
-    0b00001101_01100000_11000000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_01100000_11000100_00000000 = [RegList(2, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_01100000_11001000_00000000 = [RegList(2, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_01100000_11001100_00000000 = [RegList(2, B_8), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11111111_11000000_00000000 = [RegList(2, BYTE), RefBase, LitInt(2)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11111111_11000100_00000000 = [RegList(2, B_2), RefBase, LitInt(4)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11111111_11001000_00000000 = [RegList(2, B_4), RefBase, LitInt(8)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11111111_11001100_00000000 = [RegList(2, B_8), RefBase, LitInt(16)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11100000_11000000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001101_11100000_11000100_00000000 = [RegList(2, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001101_11100000_11001000_00000000 = [RegList(2, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001101_11100000_11001100_00000000 = [RegList(2, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_01100000_11000000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_01100000_11000100_00000000 = [RegList(2, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_01100000_11001000_00000000 = [RegList(2, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_01100000_11001100_00000000 = [RegList(2, B_8), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11111111_11000000_00000000 = [RegList(2, BYTE), RefBase, LitInt(2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11111111_11000100_00000000 = [RegList(2, B_2), RefBase, LitInt(4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11111111_11001000_00000000 = [RegList(2, B_4), RefBase, LitInt(8)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11111111_11001100_00000000 = [RegList(2, B_8), RefBase, LitInt(16)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11100000_11000000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001101_11100000_11000100_00000000 = [RegList(2, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001101_11100000_11001000_00000000 = [RegList(2, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001101_11100000_11001100_00000000 = [RegList(2, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+]
+"ld2w" = [
+    // LD2W (scalar plus immediate)
+    0b10100101_00100000_11100000_00000000 = [ZRegList(2, B_4), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], [Ex_SVE];
+    // LD2W (scalar plus scalar)
+    0b10100101_00100000_11000000_00000000 = [ZRegList(2, B_4), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
 ]
 "ld3" = [
     // LD3 (multiple structures)
-    0b00001100_01000000_01000000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_01000100_00000000 = [RegList(3, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_01001000_00000000 = [RegList(3, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_01001100_00000000 = [RegListStatic(3, B_8, 2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_11011111_01000000_00000000 = [RegListStatic(3, BYTE, 8), RefBase, LitInt(24)] => [R(0), R(5)];
-    0b00001100_11011111_01000100_00000000 = [RegListStatic(3, B_2, 4), RefBase, LitInt(24)] => [R(0), R(5)];
-    0b00001100_11011111_01001000_00000000 = [RegListStatic(3, B_4, 2), RefBase, LitInt(24)] => [R(0), R(5)];
-    0b01001100_11011111_01000000_00000000 = [RegListStatic(3, BYTE, 16), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b01001100_11011111_01000100_00000000 = [RegListStatic(3, B_2, 8), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b01001100_11011111_01001000_00000000 = [RegListStatic(3, B_4, 4), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b01001100_11011111_01001100_00000000 = [RegListStatic(3, B_8, 2), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b00001100_11000000_01000000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_01000100_00000000 = [RegList(3, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_01001000_00000000 = [RegList(3, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_01001100_00000000 = [RegListStatic(3, B_8, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_01000000_01000000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_01000100_00000000 = [RegList(3, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_01001000_00000000 = [RegList(3, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_01001100_00000000 = [RegListStatic(3, B_8, 2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_11011111_01000000_00000000 = [RegListStatic(3, BYTE, 8), RefBase, LitInt(24)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_01000100_00000000 = [RegListStatic(3, B_2, 4), RefBase, LitInt(24)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_01001000_00000000 = [RegListStatic(3, B_4, 2), RefBase, LitInt(24)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_01000000_00000000 = [RegListStatic(3, BYTE, 16), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_01000100_00000000 = [RegListStatic(3, B_2, 8), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_01001000_00000000 = [RegListStatic(3, B_4, 4), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_01001100_00000000 = [RegListStatic(3, B_8, 2), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11000000_01000000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_01000100_00000000 = [RegList(3, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_01001000_00000000 = [RegList(3, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_01001100_00000000 = [RegListStatic(3, B_8, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
     // LD3 (single structure)
-    0b00001101_01000000_00100000_00000000 = [RegListElement(3, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_01000000_01100000_00000000 = [RegListElement(3, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_01000000_10100000_00000000 = [RegListElement(3, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_01000000_10100100_00000000 = [RegListElement(3, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_11011111_00100000_00000000 = [RegListElement(3, BYTE), RefBase, LitInt(3)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_11000000_00100000_00000000 = [RegListElement(3, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
-    0b00001101_11011111_01100000_00000000 = [RegListElement(3, B_2), RefBase, LitInt(6)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_11000000_01100000_00000000 = [RegListElement(3, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
-    0b00001101_11011111_10100000_00000000 = [RegListElement(3, B_4), RefBase, LitInt(12)] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_11000000_10100000_00000000 = [RegListElement(3, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
-    0b00001101_11011111_10100100_00000000 = [RegListElement(3, B_8), RefBase, LitInt(24)] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_11000000_10100100_00000000 = [RegListElement(3, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+    0b00001101_01000000_00100000_00000000 = [RegListElement(3, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_01000000_01100000_00000000 = [RegListElement(3, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_01000000_10100000_00000000 = [RegListElement(3, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_01000000_10100100_00000000 = [RegListElement(3, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_11011111_00100000_00000000 = [RegListElement(3, BYTE), RefBase, LitInt(3)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_11000000_00100000_00000000 = [RegListElement(3, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_11011111_01100000_00000000 = [RegListElement(3, B_2), RefBase, LitInt(6)] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_11000000_01100000_00000000 = [RegListElement(3, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_11011111_10100000_00000000 = [RegListElement(3, B_4), RefBase, LitInt(12)] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_11000000_10100000_00000000 = [RegListElement(3, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_11011111_10100100_00000000 = [RegListElement(3, B_8), RefBase, LitInt(24)] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_11000000_10100100_00000000 = [RegListElement(3, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)], [Ex_BASE];
+]
+"ld3b" = [
+    0b10100100_01000000_11000000_00000000 = [ZRegList(3, BYTE), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ld3d" = [
+    0b10100101_11000000_11000000_00000000 = [ZRegList(3, B_8), PZero, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ld3h" = [
+    0b10100100_11000000_11000000_00000000 = [ZRegList(3, B_2), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
 ]
 "ld3r" = [
-    0b00001101_01000000_11100000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_01000000_11100100_00000000 = [RegList(3, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_01000000_11101000_00000000 = [RegList(3, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_01000000_11101100_00000000 = [RegList(3, B_8), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11011111_11100000_00000000 = [RegList(3, BYTE), RefBase, LitInt(3)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11011111_11100100_00000000 = [RegList(3, B_2), RefBase, LitInt(6)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11011111_11101000_00000000 = [RegList(3, B_4), RefBase, LitInt(12)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11011111_11101100_00000000 = [RegList(3, B_8), RefBase, LitInt(24)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11000000_11100000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001101_11000000_11100100_00000000 = [RegList(3, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001101_11000000_11101000_00000000 = [RegList(3, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001101_11000000_11101100_00000000 = [RegList(3, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_01000000_11100000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_01000000_11100100_00000000 = [RegList(3, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_01000000_11101000_00000000 = [RegList(3, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_01000000_11101100_00000000 = [RegList(3, B_8), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11011111_11100000_00000000 = [RegList(3, BYTE), RefBase, LitInt(3)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11011111_11100100_00000000 = [RegList(3, B_2), RefBase, LitInt(6)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11011111_11101000_00000000 = [RegList(3, B_4), RefBase, LitInt(12)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11011111_11101100_00000000 = [RegList(3, B_8), RefBase, LitInt(24)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11000000_11100000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001101_11000000_11100100_00000000 = [RegList(3, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001101_11000000_11101000_00000000 = [RegList(3, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001101_11000000_11101100_00000000 = [RegList(3, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+]
+"ld3w" = [
+    0b10100101_01000000_11000000_00000000 = [ZRegList(3, B_4), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
 ]
 "ld4" = [
     // LD4 (multiple structures)
-    0b00001100_01000000_00000000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_00000100_00000000 = [RegList(4, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_00001000_00000000 = [RegList(4, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_01000000_00001100_00000000 = [RegListStatic(4, B_8, 2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_11011111_00000000_00000000 = [RegListStatic(4, BYTE, 8), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b00001100_11011111_00000100_00000000 = [RegListStatic(4, B_2, 4), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b00001100_11011111_00001000_00000000 = [RegListStatic(4, B_4, 2), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_11011111_00000000_00000000 = [RegListStatic(4, BYTE, 16), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b01001100_11011111_00000100_00000000 = [RegListStatic(4, B_2, 8), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b01001100_11011111_00001000_00000000 = [RegListStatic(4, B_4, 4), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b01001100_11011111_00001100_00000000 = [RegListStatic(4, B_8, 2), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b00001100_11000000_00000000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_00000100_00000000 = [RegList(4, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_00001000_00000000 = [RegList(4, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_11000000_00001100_00000000 = [RegListStatic(4, B_8, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_01000000_00000000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_00000100_00000000 = [RegList(4, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_00001000_00000000 = [RegList(4, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_01000000_00001100_00000000 = [RegListStatic(4, B_8, 2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_11011111_00000000_00000000 = [RegListStatic(4, BYTE, 8), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_00000100_00000000 = [RegListStatic(4, B_2, 4), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11011111_00001000_00000000 = [RegListStatic(4, B_4, 2), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_00000000_00000000 = [RegListStatic(4, BYTE, 16), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_00000100_00000000 = [RegListStatic(4, B_2, 8), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_00001000_00000000 = [RegListStatic(4, B_4, 4), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_11011111_00001100_00000000 = [RegListStatic(4, B_8, 2), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_11000000_00000000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_00000100_00000000 = [RegList(4, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_00001000_00000000 = [RegList(4, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_11000000_00001100_00000000 = [RegListStatic(4, B_8, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
     // LD4 (single structure)
-    0b00001101_01100000_00100000_00000000 = [RegListElement(4, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_01100000_01100000_00000000 = [RegListElement(4, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_01100000_10100000_00000000 = [RegListElement(4, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_01100000_10100100_00000000 = [RegListElement(4, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_11111111_00100000_00000000 = [RegListElement(4, BYTE), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_11100000_00100000_00000000 = [RegListElement(4, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
-    0b00001101_11111111_01100000_00000000 = [RegListElement(4, B_2), RefBase, LitInt(8)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_11100000_01100000_00000000 = [RegListElement(4, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
-    0b00001101_11111111_10100000_00000000 = [RegListElement(4, B_4), RefBase, LitInt(16)] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_11100000_10100000_00000000 = [RegListElement(4, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
-    0b00001101_11111111_10100100_00000000 = [RegListElement(4, B_8), RefBase, LitInt(32)] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_11100000_10100100_00000000 = [RegListElement(4, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+    0b00001101_01100000_00100000_00000000 = [RegListElement(4, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_01100000_01100000_00000000 = [RegListElement(4, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_01100000_10100000_00000000 = [RegListElement(4, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_01100000_10100100_00000000 = [RegListElement(4, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_11111111_00100000_00000000 = [RegListElement(4, BYTE), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_11100000_00100000_00000000 = [RegListElement(4, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_11111111_01100000_00000000 = [RegListElement(4, B_2), RefBase, LitInt(8)] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_11100000_01100000_00000000 = [RegListElement(4, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_11111111_10100000_00000000 = [RegListElement(4, B_4), RefBase, LitInt(16)] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_11100000_10100000_00000000 = [RegListElement(4, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_11111111_10100100_00000000 = [RegListElement(4, B_8), RefBase, LitInt(32)] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_11100000_10100100_00000000 = [RegListElement(4, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)], [Ex_BASE];
+]
+"ld4b" = [
+    // LD4B (scalar plus immediate)
+    0b10100100_01100000_11100000_00000000 = [ZRegList(4, BYTE), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], [Ex_SVE];
+    // LD4B (scalar plus scalar)
+    0b10100100_01100000_11000000_00000000 = [ZRegList(4, BYTE), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ld4d" = [
+    // LD4D (scalar plus immediate)
+    0b10100101_11100000_11100000_00000000 = [ZRegList(4, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], [Ex_SVE];
+    // LD4D (scalar plus scalar)
+    0b10100101_11100000_11000000_00000000 = [ZRegList(4, B_8), PZero, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ld4h" = [
+    // LD4H (scalar plus immediate)
+    0b10100100_11100000_11100000_00000000 = [ZRegList(4, B_2), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], [Ex_SVE];
+    // LD4H (scalar plus scalar)
+    0b10100100_11100000_11000000_00000000 = [ZRegList(4, B_2), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
 ]
 "ld4r" = [
-    0b00001101_01100000_11100000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_01100000_11100100_00000000 = [RegList(4, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_01100000_11101000_00000000 = [RegList(4, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_01100000_11101100_00000000 = [RegList(4, B_8), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11111111_11100000_00000000 = [RegList(4, BYTE), RefBase, LitInt(4)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11111111_11100100_00000000 = [RegList(4, B_2), RefBase, LitInt(8)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11111111_11101000_00000000 = [RegList(4, B_4), RefBase, LitInt(16)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11111111_11101100_00000000 = [RegList(4, B_8), RefBase, LitInt(32)] => [R(0), R(5), Rwidth(30)];
-    0b00001101_11100000_11100000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001101_11100000_11100100_00000000 = [RegList(4, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001101_11100000_11101000_00000000 = [RegList(4, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001101_11100000_11101100_00000000 = [RegList(4, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001101_01100000_11100000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_01100000_11100100_00000000 = [RegList(4, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_01100000_11101000_00000000 = [RegList(4, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_01100000_11101100_00000000 = [RegList(4, B_8), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11111111_11100000_00000000 = [RegList(4, BYTE), RefBase, LitInt(4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11111111_11100100_00000000 = [RegList(4, B_2), RefBase, LitInt(8)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11111111_11101000_00000000 = [RegList(4, B_4), RefBase, LitInt(16)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11111111_11101100_00000000 = [RegList(4, B_8), RefBase, LitInt(32)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001101_11100000_11100000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001101_11100000_11100100_00000000 = [RegList(4, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001101_11100000_11101000_00000000 = [RegList(4, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001101_11100000_11101100_00000000 = [RegList(4, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+]
+"ld4w" = [
+    // LD4W (scalar plus immediate)
+    0b10100101_01100000_11100000_00000000 = [ZRegList(4, B_4), PZero, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], [Ex_SVE];
+    // LD4W (scalar plus scalar)
+    0b10100101_01100000_11000000_00000000 = [ZRegList(4, B_4), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
 ]
 "ldadd" = [
-    0b10111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_00100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldadda" = [
-    0b10111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_10100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldaddab" = [
-    0b00111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldaddah" = [
-    0b01111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldaddal" = [
-    0b10111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_11100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldaddalb" = [
-    0b00111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldaddalh" = [
-    0b01111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldaddb" = [
-    0b00111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldaddh" = [
-    0b01111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldaddl" = [
-    0b10111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_01100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldaddlb" = [
-    0b00111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldaddlh" = [
-    0b01111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldapr" = [
-    0b10111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)];
-    0b11111000_10111111_11000000_00000000 = [X, RefBase] => [R(0), R(5)];
+    0b10111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_RCPC];
+    0b11111000_10111111_11000000_00000000 = [X, RefBase] => [R(0), R(5)], [Ex_RCPC];
 ]
 "ldaprb" = [
-    0b00111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b00111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_RCPC];
 ]
 "ldaprh" = [
-    0b01111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b01111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_RCPC];
 ]
 "ldapur" = [
-    0b10011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b11011001_01000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b10011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_RCPC2];
+    0b11011001_01000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_RCPC2];
 ]
 "ldapurb" = [
-    0b00011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_RCPC2];
 ]
 "ldapurh" = [
-    0b01011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_RCPC2];
 ]
 "ldapursb" = [
-    0b00011001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b00011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00011001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_RCPC2];
+    0b00011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_RCPC2];
 ]
 "ldapursh" = [
-    0b01011001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b01011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01011001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_RCPC2];
+    0b01011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_RCPC2];
 ]
 "ldapursw" = [
-    0b10011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b10011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_RCPC2];
 ]
 "ldar" = [
-    0b10001000_11011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
-    0b11001000_11011111_11111100_00000000 = [X, RefBase] => [R(0), R(5)];
+    0b10001000_11011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_BASE];
+    0b11001000_11011111_11111100_00000000 = [X, RefBase] => [R(0), R(5)], [Ex_BASE];
 ]
 "ldarb" = [
-    0b00001000_11011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b00001000_11011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_BASE];
 ]
 "ldarh" = [
-    0b01001000_11011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b01001000_11011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_BASE];
 ]
 "ldaxp" = [
-    0b10001000_01111111_10000000_00000000 = [W, W, RefBase] => [R(0), R(10), R(5)];
-    0b11001000_01111111_10000000_00000000 = [X, X, RefBase] => [R(0), R(10), R(5)];
+    0b10001000_01111111_10000000_00000000 = [W, W, RefBase] => [R(0), R(10), R(5)], [Ex_BASE];
+    0b11001000_01111111_10000000_00000000 = [X, X, RefBase] => [R(0), R(10), R(5)], [Ex_BASE];
 ]
 "ldaxr" = [
-    0b10001000_01011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
-    0b11001000_01011111_11111100_00000000 = [X, RefBase] => [R(0), R(5)];
+    0b10001000_01011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_BASE];
+    0b11001000_01011111_11111100_00000000 = [X, RefBase] => [R(0), R(5)], [Ex_BASE];
 ]
 "ldaxrb" = [
-    0b00001000_01011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b00001000_01011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_BASE];
 ]
 "ldaxrh" = [
-    0b01001000_01011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b01001000_01011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_BASE];
 ]
 "ldclr" = [
-    0b10111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_00100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldclra" = [
-    0b10111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_10100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldclrab" = [
-    0b00111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldclrah" = [
-    0b01111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldclral" = [
-    0b10111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_11100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldclralb" = [
-    0b00111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldclralh" = [
-    0b01111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldclrb" = [
-    0b00111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldclrh" = [
-    0b01111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldclrl" = [
-    0b10111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_01100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldclrlb" = [
-    0b00111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldclrlh" = [
-    0b01111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldeor" = [
-    0b10111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_00100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldeora" = [
-    0b10111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_10100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldeorab" = [
-    0b00111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldeorah" = [
-    0b01111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldeoral" = [
-    0b10111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_11100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldeoralb" = [
-    0b00111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldeoralh" = [
-    0b01111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldeorb" = [
-    0b00111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldeorh" = [
-    0b01111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldeorl" = [
-    0b10111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_01100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldeorlb" = [
-    0b00111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldeorlh" = [
-    0b01111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+]
+"ldg" = [
+    0b11011001_01100000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sscaled(12, 9, 4)], [Ex_MTE];
+]
+"ldgm" = [
+    0b11011001_11100000_00000000_00000000 = [X, RefBase] => [R(0), R(5)], [Ex_MTE];
 ]
 "ldlar" = [
-    0b10001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
-    0b11001000_11011111_01111100_00000000 = [X, RefBase] => [R(0), R(5)];
+    0b10001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_LOR];
+    0b11001000_11011111_01111100_00000000 = [X, RefBase] => [R(0), R(5)], [Ex_LOR];
 ]
 "ldlarb" = [
-    0b00001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b00001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_LOR];
 ]
 "ldlarh" = [
-    0b01001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b01001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_LOR];
+]
+"ldnf1b" = [
+    0b10100100_00010000_10100000_00000000 = [ZRegList(1, BYTE), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100100_00110000_10100000_00000000 = [ZRegList(1, B_2), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100100_01010000_10100000_00000000 = [ZRegList(1, B_4), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100100_01110000_10100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+]
+"ldnf1d" = [
+    0b10100101_11110000_10100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+]
+"ldnf1h" = [
+    0b10100100_10110000_10100000_00000000 = [ZRegList(1, B_2), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100100_11010000_10100000_00000000 = [ZRegList(1, B_4), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100100_11110000_10100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+]
+"ldnf1sb" = [
+    0b10100101_11010000_10100000_00000000 = [ZRegList(1, B_2), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100101_10110000_10100000_00000000 = [ZRegList(1, B_4), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100101_10010000_10100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+]
+"ldnf1sh" = [
+    0b10100101_00110000_10100000_00000000 = [ZRegList(1, B_4), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100101_00010000_10100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+]
+"ldnf1sw" = [
+    0b10100100_10010000_10100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+]
+"ldnf1w" = [
+    0b10100101_01010000_10100000_00000000 = [ZRegList(1, B_4), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b10100101_01110000_10100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
 ]
 "ldnp" = [
     // LDNP (SIMD&FP)
-    0b00101100_01000000_00000000_00000000 = [S, S, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b01101100_01000000_00000000_00000000 = [D, D, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
-    0b10101100_01000000_00000000_00000000 = [Q, Q, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
+    0b00101100_01000000_00000000_00000000 = [S, S, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b01101100_01000000_00000000_00000000 = [D, D, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
+    0b10101100_01000000_00000000_00000000 = [Q, Q, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 4)], [Ex_BASE];
     // LDNP
-    0b00101000_01000000_00000000_00000000 = [W, W, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b10101000_01000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b00101000_01000000_00000000_00000000 = [W, W, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b10101000_01000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
+]
+"ldnt1b" = [
+    // LDNT1B (scalar plus immediate)
+    0b10100100_00000000_11100000_00000000 = [ZRegList(1, BYTE), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // LDNT1B (scalar plus scalar)
+    0b10100100_00000000_11000000_00000000 = [ZRegList(1, BYTE), PZero, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ldnt1d" = [
+    // LDNT1D (scalar plus immediate)
+    0b10100101_10000000_11100000_00000000 = [ZRegList(1, B_8), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // LDNT1D (scalar plus scalar)
+    0b10100101_10000000_11000000_00000000 = [ZRegList(1, B_8), PZero, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ldnt1h" = [
+    // LDNT1H (scalar plus immediate)
+    0b10100100_10000000_11100000_00000000 = [ZRegList(1, B_2), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // LDNT1H (scalar plus scalar)
+    0b10100100_10000000_11000000_00000000 = [ZRegList(1, B_2), PZero, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"ldnt1w" = [
+    // LDNT1W (scalar plus immediate)
+    0b10100101_00000000_11100000_00000000 = [ZRegList(1, B_4), PZero, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // LDNT1W (scalar plus scalar)
+    0b10100101_00000000_11000000_00000000 = [ZRegList(1, B_4), PZero, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
 ]
 "ldp" = [
     // LDP (SIMD&FP)
-    0b00101100_11000000_00000000_00000000 = [S, S, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b01101100_11000000_00000000_00000000 = [D, D, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
-    0b10101100_11000000_00000000_00000000 = [Q, Q, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
-    0b00101101_11000000_00000000_00000000 = [S, S, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b01101101_11000000_00000000_00000000 = [D, D, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
-    0b10101101_11000000_00000000_00000000 = [Q, Q, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
-    0b00101101_01000000_00000000_00000000 = [S, S, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b01101101_01000000_00000000_00000000 = [D, D, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
-    0b10101101_01000000_00000000_00000000 = [Q, Q, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
+    0b00101100_11000000_00000000_00000000 = [S, S, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b01101100_11000000_00000000_00000000 = [D, D, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
+    0b10101100_11000000_00000000_00000000 = [Q, Q, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 4)], [Ex_BASE];
+    0b00101101_11000000_00000000_00000000 = [S, S, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b01101101_11000000_00000000_00000000 = [D, D, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
+    0b10101101_11000000_00000000_00000000 = [Q, Q, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 4)], [Ex_BASE];
+    0b00101101_01000000_00000000_00000000 = [S, S, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b01101101_01000000_00000000_00000000 = [D, D, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
+    0b10101101_01000000_00000000_00000000 = [Q, Q, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 4)], [Ex_BASE];
     // LDP
-    0b00101000_11000000_00000000_00000000 = [W, W, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b10101000_11000000_00000000_00000000 = [X, X, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
-    0b00101001_11000000_00000000_00000000 = [W, W, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b10101001_11000000_00000000_00000000 = [X, X, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
-    0b00101001_01000000_00000000_00000000 = [W, W, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b10101001_01000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b00101000_11000000_00000000_00000000 = [W, W, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b10101000_11000000_00000000_00000000 = [X, X, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
+    0b00101001_11000000_00000000_00000000 = [W, W, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b10101001_11000000_00000000_00000000 = [X, X, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
+    0b00101001_01000000_00000000_00000000 = [W, W, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b10101001_01000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
 ]
 "ldpsw" = [
-    0b01101000_11000000_00000000_00000000 = [X, X, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b01101001_11000000_00000000_00000000 = [X, X, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b01101001_01000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
+    0b01101000_11000000_00000000_00000000 = [X, X, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b01101001_11000000_00000000_00000000 = [X, X, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b01101001_01000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
 ]
 "ldr" = [
     // LDR (immediate, SIMD&FP)
-    0b00111100_01000000_00000100_00000000 = [B, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b01111100_01000000_00000100_00000000 = [H, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b10111100_01000000_00000100_00000000 = [S, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b11111100_01000000_00000100_00000000 = [D, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b00111100_11000000_00000100_00000000 = [Q, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b00111100_01000000_00001100_00000000 = [B, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b01111100_01000000_00001100_00000000 = [H, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b10111100_01000000_00001100_00000000 = [S, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b11111100_01000000_00001100_00000000 = [D, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b00111100_11000000_00001100_00000000 = [Q, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b00111101_01000000_00000000_00000000 = [B, RefOffset] => [R(0), R(5), Ubits(10, 12)];
-    0b01111101_01000000_00000000_00000000 = [H, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)];
-    0b10111101_01000000_00000000_00000000 = [S, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)];
-    0b11111101_01000000_00000000_00000000 = [D, RefOffset] => [R(0), R(5), Uscaled(10, 12, 3)];
-    0b00111101_11000000_00000000_00000000 = [Q, RefOffset] => [R(0), R(5), Uscaled(10, 12, 4)];
+    0b00111100_01000000_00000100_00000000 = [B, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111100_01000000_00000100_00000000 = [H, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b10111100_01000000_00000100_00000000 = [S, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b11111100_01000000_00000100_00000000 = [D, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111100_11000000_00000100_00000000 = [Q, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111100_01000000_00001100_00000000 = [B, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111100_01000000_00001100_00000000 = [H, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b10111100_01000000_00001100_00000000 = [S, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b11111100_01000000_00001100_00000000 = [D, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111100_11000000_00001100_00000000 = [Q, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111101_01000000_00000000_00000000 = [B, RefOffset] => [R(0), R(5), Ubits(10, 12)], [Ex_BASE];
+    0b01111101_01000000_00000000_00000000 = [H, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)], [Ex_BASE];
+    0b10111101_01000000_00000000_00000000 = [S, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)], [Ex_BASE];
+    0b11111101_01000000_00000000_00000000 = [D, RefOffset] => [R(0), R(5), Uscaled(10, 12, 3)], [Ex_BASE];
+    0b00111101_11000000_00000000_00000000 = [Q, RefOffset] => [R(0), R(5), Uscaled(10, 12, 4)], [Ex_BASE];
     // LDR (immediate)
-    0b10111000_01000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b11111000_01000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b10111000_01000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b11111000_01000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b10111001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)];
-    0b11111001_01000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Uscaled(10, 12, 3)];
+    0b10111000_01000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b11111000_01000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b10111000_01000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b11111000_01000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b10111001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)], [Ex_BASE];
+    0b11111001_01000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Uscaled(10, 12, 3)], [Ex_BASE];
     // LDR (literal, SIMD&FP)
-    0b00011100_00000000_00000000_00000000 = [S, Offset] => [R(0), Offset(BCOND)];
-    0b01011100_00000000_00000000_00000000 = [D, Offset] => [R(0), Offset(BCOND)];
-    0b10011100_00000000_00000000_00000000 = [Q, Offset] => [R(0), Offset(BCOND)];
+    0b00011100_00000000_00000000_00000000 = [S, Offset] => [R(0), Offset(BCOND)], [Ex_BASE];
+    0b01011100_00000000_00000000_00000000 = [D, Offset] => [R(0), Offset(BCOND)], [Ex_BASE];
+    0b10011100_00000000_00000000_00000000 = [Q, Offset] => [R(0), Offset(BCOND)], [Ex_BASE];
     // LDR (literal)
-    0b00011000_00000000_00000000_00000000 = [W, Offset] => [R(0), Offset(BCOND)];
-    0b01011000_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(BCOND)];
+    0b00011000_00000000_00000000_00000000 = [W, Offset] => [R(0), Offset(BCOND)], [Ex_BASE];
+    0b01011000_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(BCOND)], [Ex_BASE];
     // LDR (register, SIMD&FP)
-    0b00111100_01100000_00001000_00000000 = [B, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])];
-    0b01111100_01100000_00001000_00000000 = [H, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])];
-    0b10111100_01100000_00001000_00000000 = [S, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])];
-    0b11111100_01100000_00001000_00000000 = [D, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])];
-    0b00111100_11100000_00001000_00000000 = [Q, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 4])];
+    0b00111100_01100000_00001000_00000000 = [B, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])], [Ex_BASE];
+    0b01111100_01100000_00001000_00000000 = [H, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])], [Ex_BASE];
+    0b10111100_01100000_00001000_00000000 = [S, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])], [Ex_BASE];
+    0b11111100_01100000_00001000_00000000 = [D, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])], [Ex_BASE];
+    0b00111100_11100000_00001000_00000000 = [Q, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 4])], [Ex_BASE];
     // LDR (register)
-    0b10111000_01100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])];
-    0b11111000_01100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])];
+    0b10111000_01100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])], [Ex_BASE];
+    0b11111000_01100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])], [Ex_BASE];
+    // LDR (vector)
+    0b10000101_10000000_01000000_00000000 = [ZBare, RefMulVl] => [R(0), R(5), CSscaled(9, 0), Sslice(10, 3, 0), Sslice(16, 6, 3), A], [Ex_SVE];
+    // LDR (predicate)
+    0b10000101_10000000_00000000_00000000 = [PBare, RefMulVl] => [R4(0), R(5), CSscaled(9, 0), Sslice(10, 3, 0), Sslice(16, 6, 3), A], [Ex_SVE];
+    // LDR (array vector)
+    0b11100001_00000000_00000000_00000000 = [ZaVector, RefMulVl] => [RSelect(13), Ubits(0, 4), R(5), CUeq(2), A], [Ex_SME];
 ]
 "ldraa" = [
-    0b11111000_00100000_00000100_00000000 = [X, RefOffset] => [R(0), R(5), CSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A];
-    0b11111000_00100000_00001100_00000000 = [X, RefPre] => [R(0), R(5), CSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A];
+    0b11111000_00100000_00000100_00000000 = [X, RefOffset] => [R(0), R(5), CSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A], [Ex_PAUTH];
+    0b11111000_00100000_00001100_00000000 = [X, RefPre] => [R(0), R(5), CSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A], [Ex_PAUTH];
 ]
 "ldrab" = [
-    0b11111000_10100000_00000100_00000000 = [X, RefOffset] => [R(0), R(5), CSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A];
-    0b11111000_10100000_00001100_00000000 = [X, RefPre] => [R(0), R(5), CSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A];
+    0b11111000_10100000_00000100_00000000 = [X, RefOffset] => [R(0), R(5), CSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A], [Ex_PAUTH];
+    0b11111000_10100000_00001100_00000000 = [X, RefPre] => [R(0), R(5), CSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A], [Ex_PAUTH];
 ]
 "ldrb" = [
     // LDRB (immediate)
-    0b00111000_01000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b00111000_01000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b00111001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Ubits(10, 12)];
+    0b00111000_01000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111000_01000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Ubits(10, 12)], [Ex_BASE];
     // LDRB (register)
-    0b00111000_01100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])];
+    0b00111000_01100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])], [Ex_BASE];
 ]
 "ldrh" = [
     // LDRH (immediate)
-    0b01111000_01000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b01111000_01000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b01111001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)];
+    0b01111000_01000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111000_01000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)], [Ex_BASE];
     // LDRH (register)
-    0b01111000_01100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])];
+    0b01111000_01100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])], [Ex_BASE];
 ]
 "ldrsb" = [
     // LDRSB (immediate)
-    0b00111000_11000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b00111000_10000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b00111000_11000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b00111000_10000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b00111001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Ubits(10, 12)];
-    0b00111001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Ubits(10, 12)];
+    0b00111000_11000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111000_10000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111000_11000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111000_10000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Ubits(10, 12)], [Ex_BASE];
+    0b00111001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Ubits(10, 12)], [Ex_BASE];
     // LDRSB (register)
-    0b00111000_11100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])];
-    0b00111000_10100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])];
+    0b00111000_11100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])], [Ex_BASE];
+    0b00111000_10100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])], [Ex_BASE];
 ]
 "ldrsh" = [
     // LDRSH (immediate)
-    0b01111000_11000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b01111000_10000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b01111000_11000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b01111000_10000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b01111001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)];
-    0b01111001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)];
+    0b01111000_11000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111000_10000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111000_11000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111000_10000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)], [Ex_BASE];
+    0b01111001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)], [Ex_BASE];
     // LDRSH (register)
-    0b01111000_11100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])];
-    0b01111000_10100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])];
+    0b01111000_11100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])], [Ex_BASE];
+    0b01111000_10100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])], [Ex_BASE];
 ]
 "ldrsw" = [
     // LDRSW (immediate)
-    0b10111000_10000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b10111000_10000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b10111001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)];
+    0b10111000_10000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b10111000_10000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b10111001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)], [Ex_BASE];
     // LDRSW (literal)
-    0b10011000_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(BCOND)];
+    0b10011000_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(BCOND)], [Ex_BASE];
     // LDRSW (register)
-    0b10111000_10100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])];
+    0b10111000_10100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])], [Ex_BASE];
 ]
 "ldset" = [
-    0b10111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_00100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldseta" = [
-    0b10111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_10100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsetab" = [
-    0b00111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsetah" = [
-    0b01111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsetal" = [
-    0b10111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_11100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsetalb" = [
-    0b00111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsetalh" = [
-    0b01111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsetb" = [
-    0b00111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldseth" = [
-    0b01111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsetl" = [
-    0b10111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_01100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsetlb" = [
-    0b00111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsetlh" = [
-    0b01111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsmax" = [
-    0b10111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_00100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsmaxa" = [
-    0b10111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_10100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsmaxab" = [
-    0b00111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsmaxah" = [
-    0b01111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsmaxal" = [
-    0b10111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_11100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsmaxalb" = [
-    0b00111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsmaxalh" = [
-    0b01111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsmaxb" = [
-    0b00111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsmaxh" = [
-    0b01111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsmaxl" = [
-    0b10111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_01100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsmaxlb" = [
-    0b00111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsmaxlh" = [
-    0b01111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsmin" = [
-    0b10111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_00100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsmina" = [
-    0b10111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_10100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsminab" = [
-    0b00111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsminah" = [
-    0b01111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsminal" = [
-    0b10111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_11100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsminalb" = [
-    0b00111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsminalh" = [
-    0b01111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsminb" = [
-    0b00111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsminh" = [
-    0b01111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsminl" = [
-    0b10111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_01100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsminlb" = [
-    0b00111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldsminlh" = [
-    0b01111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldtr" = [
-    0b10111000_01000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b11111000_01000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b10111000_01000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b11111000_01000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "ldtrb" = [
-    0b00111000_01000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00111000_01000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "ldtrh" = [
-    0b01111000_01000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01111000_01000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "ldtrsb" = [
-    0b00111000_11000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b00111000_10000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00111000_11000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111000_10000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "ldtrsh" = [
-    0b01111000_11000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b01111000_10000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01111000_11000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111000_10000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "ldtrsw" = [
-    0b10111000_10000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b10111000_10000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "ldumax" = [
-    0b10111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_00100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldumaxa" = [
-    0b10111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_10100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldumaxab" = [
-    0b00111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldumaxah" = [
-    0b01111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldumaxal" = [
-    0b10111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_11100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldumaxalb" = [
-    0b00111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldumaxalh" = [
-    0b01111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldumaxb" = [
-    0b00111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldumaxh" = [
-    0b01111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldumaxl" = [
-    0b10111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_01100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldumaxlb" = [
-    0b00111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldumaxlh" = [
-    0b01111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldumin" = [
-    0b10111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_00100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldumina" = [
-    0b10111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_10100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "lduminab" = [
-    0b00111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "lduminah" = [
-    0b01111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "lduminal" = [
-    0b10111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_11100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "lduminalb" = [
-    0b00111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "lduminalh" = [
-    0b01111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "lduminb" = [
-    0b00111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "lduminh" = [
-    0b01111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "lduminl" = [
-    0b10111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_01100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "lduminlb" = [
-    0b00111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "lduminlh" = [
-    0b01111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "ldur" = [
     // LDUR (SIMD&FP)
-    0b00111100_01000000_00000000_00000000 = [B, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b01111100_01000000_00000000_00000000 = [H, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b10111100_01000000_00000000_00000000 = [S, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b11111100_01000000_00000000_00000000 = [D, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b00111100_11000000_00000000_00000000 = [Q, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00111100_01000000_00000000_00000000 = [B, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111100_01000000_00000000_00000000 = [H, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b10111100_01000000_00000000_00000000 = [S, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b11111100_01000000_00000000_00000000 = [D, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111100_11000000_00000000_00000000 = [Q, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
     // LDUR
-    0b10111000_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b11111000_01000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b10111000_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b11111000_01000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "ldurb" = [
-    0b00111000_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00111000_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "ldurh" = [
-    0b01111000_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01111000_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "ldursb" = [
-    0b00111000_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b00111000_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00111000_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111000_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "ldursh" = [
-    0b01111000_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b01111000_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01111000_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111000_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "ldursw" = [
-    0b10111000_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b10111000_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "ldxp" = [
-    0b10001000_01111111_00000000_00000000 = [W, W, RefBase] => [R(0), R(10), R(5)];
-    0b11001000_01111111_00000000_00000000 = [X, X, RefBase] => [R(0), R(10), R(5)];
+    0b10001000_01111111_00000000_00000000 = [W, W, RefBase] => [R(0), R(10), R(5)], [Ex_BASE];
+    0b11001000_01111111_00000000_00000000 = [X, X, RefBase] => [R(0), R(10), R(5)], [Ex_BASE];
 ]
 "ldxr" = [
-    0b10001000_01011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
-    0b11001000_01011111_01111100_00000000 = [X, RefBase] => [R(0), R(5)];
+    0b10001000_01011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_BASE];
+    0b11001000_01011111_01111100_00000000 = [X, RefBase] => [R(0), R(5)], [Ex_BASE];
 ]
 "ldxrb" = [
-    0b00001000_01011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b00001000_01011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_BASE];
 ]
 "ldxrh" = [
-    0b01001000_01011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b01001000_01011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_BASE];
 ]
 "lsl" = [
     // LSL (register)
-    0b00011010_11000000_00100000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b10011010_11000000_00100000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00011010_11000000_00100000_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b10011010_11000000_00100000_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
     // LSL (immediate)
-    0b01010011_00000000_00000000_00000000 = [W, W, Imm] => [R(0), R(5), Usubmod(16, 5), C, Usubzero(10, 5)];
-    0b11010011_01000000_00000000_00000000 = [X, X, Imm] => [R(0), R(5), Usubmod(16, 6), C, Usubzero(10, 6)];
+    0b01010011_00000000_00000000_00000000 = [W, W, Imm] => [R(0), R(5), Usubmod(16, 5), C, Usubzero(10, 5)], [Ex_BASE];
+    0b11010011_01000000_00000000_00000000 = [X, X, Imm] => [R(0), R(5), Usubmod(16, 6), C, Usubzero(10, 6)], [Ex_BASE];
+    // LSL (vectors, predicated)
+    0b00000100_00010011_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // LSL (vectors, predicated)
+    0b00000100_01010011_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // LSL (vectors, predicated)
+    0b00000100_10010011_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // LSL (vectors, predicated)
+    0b00000100_11010011_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+]
+"lslr" = [
+    // LSLR (vectors, predicated)
+    0b00000100_00010111_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // LSLR (vectors, predicated)
+    0b00000100_01010111_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // LSLR (vectors, predicated)
+    0b00000100_10010111_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // LSLR (vectors, predicated)
+    0b00000100_11010111_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "lslv" = [
-    0b00011010_11000000_00100000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b10011010_11000000_00100000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00011010_11000000_00100000_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b10011010_11000000_00100000_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "lsr" = [
     // LSR (register)
-    0b00011010_11000000_00100100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b10011010_11000000_00100100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00011010_11000000_00100100_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b10011010_11000000_00100100_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
     // LSR (immediate)
-    0b01010011_00000000_01111100_00000000 = [W, W, Imm] => [R(0), R(5), Ubits(16, 5)];
-    0b11010011_01000000_11111100_00000000 = [X, X, Imm] => [R(0), R(5), Ubits(16, 6)];
+    0b01010011_00000000_01111100_00000000 = [W, W, Imm] => [R(0), R(5), Ubits(16, 5)], [Ex_BASE];
+    0b11010011_01000000_11111100_00000000 = [X, X, Imm] => [R(0), R(5), Ubits(16, 6)], [Ex_BASE];
+    // LSR (vectors, predicated)
+    0b00000100_00010001_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // LSR (vectors, predicated)
+    0b00000100_01010001_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // LSR (vectors, predicated)
+    0b00000100_10010001_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // LSR (vectors, predicated)
+    0b00000100_11010001_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+]
+"lsrr" = [
+    // LSRR (vectors, predicated)
+    0b00000100_00010101_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // LSRR (vectors, predicated)
+    0b00000100_01010101_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // LSRR (vectors, predicated)
+    0b00000100_10010101_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // LSRR (vectors, predicated)
+    0b00000100_11010101_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "lsrv" = [
-    0b00011010_11000000_00100100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b10011010_11000000_00100100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00011010_11000000_00100100_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b10011010_11000000_00100100_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "madd" = [
-    0b00011011_00000000_00000000_00000000 = [W, W, W, W] => [R(0), R(5), R(16), R(10)];
-    0b10011011_00000000_00000000_00000000 = [X, X, X, X] => [R(0), R(5), R(16), R(10)];
+    0b00011011_00000000_00000000_00000000 = [W, W, W, W] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
+    0b10011011_00000000_00000000_00000000 = [X, X, X, X] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
+]
+"match" = [
+    0b01000101_00100000_10000000_00000000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE2];
+    0b01000101_01100000_10000000_00000000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE2];
 ]
 "mla" = [
     // MLA (by element)
-    0b00101111_01000000_00000000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
-    0b00101111_10000000_00000000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    0b00101111_01000000_00000000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], [Ex_BASE];
+    0b00101111_10000000_00000000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], [Ex_BASE];
     // MLA (vector)
-    0b00001110_00100000_10010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_10010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_10010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00100000_10010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_10010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_10010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // MLA (vectors, predicated)
+    0b00000100_00000000_01000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // MLA (vectors, predicated)
+    0b00000100_01000000_01000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // MLA (vectors, predicated)
+    0b00000100_10000000_01000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // MLA (vectors, predicated)
+    0b00000100_11000000_01000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
 ]
 "mls" = [
     // MLS (by element)
-    0b00101111_01000000_01000000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
-    0b00101111_10000000_01000000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    0b00101111_01000000_01000000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], [Ex_BASE];
+    0b00101111_10000000_01000000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], [Ex_BASE];
     // MLS (vector)
-    0b00101110_00100000_10010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_10010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_10010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_00100000_10010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_10010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_10010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // MLS (vectors, predicated)
+    0b00000100_00000000_01100000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // MLS (vectors, predicated)
+    0b00000100_01000000_01100000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // MLS (vectors, predicated)
+    0b00000100_10000000_01100000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
+    // MLS (vectors, predicated)
+    0b00000100_11000000_01100000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), R(5), R(16)], [Ex_SVE];
 ]
 "mneg" = [
-    0b00011011_00000000_11111100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b10011011_00000000_11111100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00011011_00000000_11111100_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b10011011_00000000_11111100_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "mov" = [
     // MOV (register)
-    0b00101010_00000000_00000011_11100000 = [W, W] => [R(0), R(16)];
-    0b10101010_00000000_00000011_11100000 = [X, X] => [R(0), R(16)];
+    0b00101010_00000000_00000011_11100000 = [W, W] => [R(0), R(16)], [Ex_BASE];
+    0b10101010_00000000_00000011_11100000 = [X, X] => [R(0), R(16)], [Ex_BASE];
     // MOV (to/from SP)
-    0b00010001_00000000_00000000_00000000 = [WSP, WSP] => [R(0), R(5)];
-    0b10010001_00000000_00000000_00000000 = [XSP, XSP] => [R(0), R(5)];
+    0b00010001_00000000_00000000_00000000 = [WSP, WSP] => [R(0), R(5)], [Ex_BASE];
+    0b10010001_00000000_00000000_00000000 = [XSP, XSP] => [R(0), R(5)], [Ex_BASE];
     // MOV (scalar)
-    0b01011110_00000001_00000100_00000000 = [B, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)];
-    0b01011110_00000010_00000100_00000000 = [H, VElement(B_2)] => [R(0), R(5), Ubits(18, 3)];
-    0b01011110_00000100_00000100_00000000 = [S, VElement(B_4)] => [R(0), R(5), Ubits(19, 2)];
-    0b01011110_00001000_00000100_00000000 = [D, VElement(B_8)] => [R(0), R(5), Ubits(20, 1)];
+    0b01011110_00000001_00000100_00000000 = [B, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)], [Ex_BASE];
+    0b01011110_00000010_00000100_00000000 = [H, VElement(B_2)] => [R(0), R(5), Ubits(18, 3)], [Ex_BASE];
+    0b01011110_00000100_00000100_00000000 = [S, VElement(B_4)] => [R(0), R(5), Ubits(19, 2)], [Ex_BASE];
+    0b01011110_00001000_00000100_00000000 = [D, VElement(B_8)] => [R(0), R(5), Ubits(20, 1)], [Ex_BASE];
     // MOV (element)
-    0b01101110_00000001_00000100_00000000 = [VElement(BYTE), VElement(BYTE)] => [R(0), Ubits(17, 4), R(5), Ubits(11, 4)];
-    0b01101110_00000010_00000100_00000000 = [VElement(B_2), VElement(B_2)] => [R(0), Ubits(18, 3), R(5), Ubits(12, 3)];
-    0b01101110_00000100_00000100_00000000 = [VElement(B_4), VElement(B_4)] => [R(0), Ubits(19, 2), R(5), Ubits(13, 2)];
-    0b01101110_00001000_00000100_00000000 = [VElement(B_8), VElement(B_8)] => [R(0), Ubits(20, 1), R(5), Ubits(14, 1)];
+    0b01101110_00000001_00000100_00000000 = [VElement(BYTE), VElement(BYTE)] => [R(0), Ubits(17, 4), R(5), Ubits(11, 4)], [Ex_BASE];
+    0b01101110_00000010_00000100_00000000 = [VElement(B_2), VElement(B_2)] => [R(0), Ubits(18, 3), R(5), Ubits(12, 3)], [Ex_BASE];
+    0b01101110_00000100_00000100_00000000 = [VElement(B_4), VElement(B_4)] => [R(0), Ubits(19, 2), R(5), Ubits(13, 2)], [Ex_BASE];
+    0b01101110_00001000_00000100_00000000 = [VElement(B_8), VElement(B_8)] => [R(0), Ubits(20, 1), R(5), Ubits(14, 1)], [Ex_BASE];
     // MOV (from general)
-    0b01001110_00000001_00011100_00000000 = [VElement(BYTE), W] => [R(0), Ubits(17, 4), R(5)];
-    0b01001110_00000010_00011100_00000000 = [VElement(B_2), W] => [R(0), Ubits(18, 3), R(5)];
-    0b01001110_00000100_00011100_00000000 = [VElement(B_4), W] => [R(0), Ubits(19, 2), R(5)];
-    0b01001110_00001000_00011100_00000000 = [VElement(B_8), X] => [R(0), Ubits(20, 1), R(5)];
+    0b01001110_00000001_00011100_00000000 = [VElement(BYTE), W] => [R(0), Ubits(17, 4), R(5)], [Ex_BASE];
+    0b01001110_00000010_00011100_00000000 = [VElement(B_2), W] => [R(0), Ubits(18, 3), R(5)], [Ex_BASE];
+    0b01001110_00000100_00011100_00000000 = [VElement(B_4), W] => [R(0), Ubits(19, 2), R(5)], [Ex_BASE];
+    0b01001110_00001000_00011100_00000000 = [VElement(B_8), X] => [R(0), Ubits(20, 1), R(5)], [Ex_BASE];
     // MOV (inverted wide immediate)
-    0b00010010_10000000_00000000_00000000 = [Dot, Lit("inverted"), W, Imm] => [R(0), Special(5, INVERTED_WIDE_IMMEDIATE_W)];
-    0b10010010_10000000_00000000_00000000 = [Dot, Lit("inverted"), X, Imm] => [R(0), Special(5, INVERTED_WIDE_IMMEDIATE_X)];
+    0b00010010_10000000_00000000_00000000 = [Dot, Lit("inverted"), W, Imm] => [R(0), Special(5, INVERTED_WIDE_IMMEDIATE_W)], [Ex_BASE];
+    0b10010010_10000000_00000000_00000000 = [Dot, Lit("inverted"), X, Imm] => [R(0), Special(5, INVERTED_WIDE_IMMEDIATE_X)], [Ex_BASE];
     // MOV (wide immediate)
-    0b01010010_10000000_00000000_00000000 = [W, Imm] => [R(0), Special(5, WIDE_IMMEDIATE_W)];
-    0b11010010_10000000_00000000_00000000 = [X, Imm] => [R(0), Special(5, WIDE_IMMEDIATE_X)];
+    0b01010010_10000000_00000000_00000000 = [W, Imm] => [R(0), Special(5, WIDE_IMMEDIATE_W)], [Ex_BASE];
+    0b11010010_10000000_00000000_00000000 = [X, Imm] => [R(0), Special(5, WIDE_IMMEDIATE_X)], [Ex_BASE];
     // MOV (vector)
-    0b00001110_10100000_00011100_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), C, R(16), Rwidth(30)];
+    0b00001110_10100000_00011100_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), C, R(16), Rwidth(30)], [Ex_BASE];
     // MOV (bitmask immediate)
-    0b00110010_00000000_00000011_11100000 = [Dot, Lit("logical"), WSP, Imm] => [R(0), Special(10, LOGICAL_IMMEDIATE_W)];
-    0b10110010_00000000_00000011_11100000 = [Dot, Lit("logical"), XSP, Imm] => [R(0), Special(10, LOGICAL_IMMEDIATE_X)];
+    0b00110010_00000000_00000011_11100000 = [Dot, Lit("logical"), WSP, Imm] => [R(0), Special(10, LOGICAL_IMMEDIATE_W)], [Ex_BASE];
+    0b10110010_00000000_00000011_11100000 = [Dot, Lit("logical"), XSP, Imm] => [R(0), Special(10, LOGICAL_IMMEDIATE_X)], [Ex_BASE];
     // MOV (to general)
-    0b00001110_00000100_00111100_00000000 = [W, VElement(B_4)] => [R(0), R(5), Ubits(19, 2)];
-    0b01001110_00001000_00111100_00000000 = [X, VElement(B_8)] => [R(0), R(5), Ubits(20, 1)];
+    0b00001110_00000100_00111100_00000000 = [W, VElement(B_4)] => [R(0), R(5), Ubits(19, 2)], [Ex_BASE];
+    0b01001110_00001000_00111100_00000000 = [X, VElement(B_8)] => [R(0), R(5), Ubits(20, 1)], [Ex_BASE];
+    // MOV (vector, unpredicated)
+    0b00000100_01100000_00110000_00000000 = [Z(B_8), Z(B_8)] => [R(0), R(5), C, R(16)], [Ex_SVE];
+    // MOV (scalar)
+    0b00000101_00100000_00111000_00000000 = [Z(BYTE), WSP] => [R(0), R(5)], [Ex_SVE];
+    0b00000101_01100000_00111000_00000000 = [Z(B_2), WSP] => [R(0), R(5)], [Ex_SVE];
+    0b00000101_10100000_00111000_00000000 = [Z(B_4), WSP] => [R(0), R(5)], [Ex_SVE];
+    0b00000101_11100000_00111000_00000000 = [Z(B_8), XSP] => [R(0), R(5)], [Ex_SVE];
+    // MOV (immediate)
+    0b00100101_00111000_11000000_00000000 = [Z(BYTE), Imm] => [R(0), Sbits(5, 8)], [Ex_SVE];
+    0b00100101_01111000_11000000_00000000 = [Z(B_2), Imm, End, LitMod(LSL)] => [R(0), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_10111000_11000000_00000000 = [Z(B_4), Imm, End, LitMod(LSL)] => [R(0), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_11111000_11000000_00000000 = [Z(B_8), Imm, End, LitMod(LSL)] => [R(0), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    // MOV (indexed)
+    0b00000101_00100001_00100000_00000000 = [Z(BYTE), ZElement(BYTE)] => [R(0), R(5), Ufields(&[23, 22, 20, 19, 18, 17])], [Ex_SVE];
+    0b00000101_00100010_00100000_00000000 = [Z(B_2), ZElement(B_2)] => [R(0), R(5), Ufields(&[23, 22, 20, 19, 18])], [Ex_SVE];
+    0b00000101_00100100_00100000_00000000 = [Z(B_4), ZElement(B_4)] => [R(0), R(5), Ufields(&[23, 22, 20, 19])], [Ex_SVE];
+    0b00000101_00101000_00100000_00000000 = [Z(B_8), ZElement(B_8)] => [R(0), R(5), Ufields(&[23, 22, 20])], [Ex_SVE];
+    0b00000101_00110000_00100000_00000000 = [Z(B_16), ZElement(B_16)] => [R(0), R(5), Ufields(&[23, 22])], [Ex_SVE];
+    // MOV (scalar, predicated)
+    0b00000101_00101000_10100000_00000000 = [Z(BYTE), PMerge, WSP] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_01101000_10100000_00000000 = [Z(B_2), PMerge, WSP] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_10101000_10100000_00000000 = [Z(B_4), PMerge, WSP] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_11101000_10100000_00000000 = [Z(B_8), PMerge, XSP] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // MOV (SIMD&FP scalar, predicated)
+    0b00000101_00100000_10000000_00000000 = [Z(BYTE), PMerge, B] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_01100000_10000000_00000000 = [Z(B_2), PMerge, H] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_10100000_10000000_00000000 = [Z(B_4), PMerge, S] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000101_11100000_10000000_00000000 = [Z(B_8), PMerge, D] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // MOV (immediate, zeroing)
+    0b00000101_00010000_00000000_00000000 = [Z(BYTE), PZero, Imm] => [R(0), R4(16), Sbits(5, 8)], [Ex_SVE];
+    0b00000101_01010000_00000000_00000000 = [Z(B_2), PZero, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00000101_10010000_00000000_00000000 = [Z(B_4), PZero, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00000101_11010000_00000000_00000000 = [Z(B_8), PZero, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    // MOV (immediate, merging)
+    0b00000101_00010000_01000000_00000000 = [Z(BYTE), PMerge, Imm] => [R(0), R4(16), Sbits(5, 8)], [Ex_SVE];
+    0b00000101_01010000_01000000_00000000 = [Z(B_2), PMerge, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00000101_10010000_01000000_00000000 = [Z(B_4), PMerge, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00000101_11010000_01000000_00000000 = [Z(B_8), PMerge, Imm, End, LitMod(LSL)] => [R(0), R4(16), Sbits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    // MOV (vector, predicated)
+    0b00000101_00100000_11000000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), C, R(16), R4(10), R(5)], [Ex_SVE];
+    0b00000101_01100000_11000000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), C, R(16), R4(10), R(5)], [Ex_SVE];
+    0b00000101_10100000_11000000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), C, R(16), R4(10), R(5)], [Ex_SVE];
+    0b00000101_11100000_11000000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), C, R(16), R4(10), R(5)], [Ex_SVE];
+    // MOV (predicate, unpredicated)
+    0b00100101_10000000_01000000_00000000 = [P(BYTE), P(BYTE)] => [R4(0), R4(5), C, R4(10), C, R4(16)], [Ex_SVE];
+    // MOV (predicate, predicated, zeroing)
+    0b00100101_00000000_01000000_00000000 = [P(BYTE), PZero, P(BYTE)] => [R4(0), R4(10), R4(5), C, R4(16)], [Ex_SVE];
+    // MOV (tile to vector)
+    0b11000000_00000010_00000000_00000000 = [Z(BYTE), PMerge, ZaSliceH(BYTE)] => [R(0), R3(10), RTile(9, 0), RSelect(13), Ubits(5, 4)], [Ex_SME];
+    0b11000000_00000010_10000000_00000000 = [Z(BYTE), PMerge, ZaSliceV(BYTE)] => [R(0), R3(10), RTile(9, 0), RSelect(13), Ubits(5, 4)], [Ex_SME];
+    0b11000000_01000010_00000000_00000000 = [Z(B_2), PMerge, ZaSliceH(B_2)] => [R(0), R3(10), RTile(8, 1), RSelect(13), Ubits(5, 3)], [Ex_SME];
+    0b11000000_01000010_10000000_00000000 = [Z(B_2), PMerge, ZaSliceV(B_2)] => [R(0), R3(10), RTile(8, 1), RSelect(13), Ubits(5, 3)], [Ex_SME];
+    0b11000000_10000010_00000000_00000000 = [Z(B_4), PMerge, ZaSliceH(B_4)] => [R(0), R3(10), RTile(7, 2), RSelect(13), Ubits(5, 2)], [Ex_SME];
+    0b11000000_10000010_10000000_00000000 = [Z(B_4), PMerge, ZaSliceV(B_4)] => [R(0), R3(10), RTile(7, 2), RSelect(13), Ubits(5, 2)], [Ex_SME];
+    0b11000000_11000010_00000000_00000000 = [Z(B_8), PMerge, ZaSliceH(B_8)] => [R(0), R3(10), RTile(6, 3), RSelect(13), Ubits(5, 1)], [Ex_SME];
+    0b11000000_11000010_10000000_00000000 = [Z(B_8), PMerge, ZaSliceV(B_8)] => [R(0), R3(10), RTile(6, 3), RSelect(13), Ubits(5, 1)], [Ex_SME];
+    0b11000000_11000011_00000000_00000000 = [Z(B_16), PMerge, ZaSliceH(B_16)] => [R(0), R3(10), RTile(5, 4), RSelect(13), Ubits(5, 0)], [Ex_SME];
+    0b11000000_11000011_10000000_00000000 = [Z(B_16), PMerge, ZaSliceV(B_16)] => [R(0), R3(10), RTile(5, 4), RSelect(13), Ubits(5, 0)], [Ex_SME];
+    // MOV (vector to tile)
+    0b11000000_00000000_00000000_00000000 = [ZaSliceH(BYTE), PMerge, Z(BYTE)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], [Ex_SME];
+    0b11000000_00000000_10000000_00000000 = [ZaSliceV(BYTE), PMerge, Z(BYTE)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], [Ex_SME];
+    0b11000000_01000000_00000000_00000000 = [ZaSliceH(B_2), PMerge, Z(B_2)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], [Ex_SME];
+    0b11000000_01000000_10000000_00000000 = [ZaSliceV(B_2), PMerge, Z(B_2)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], [Ex_SME];
+    0b11000000_10000000_00000000_00000000 = [ZaSliceH(B_4), PMerge, Z(B_4)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], [Ex_SME];
+    0b11000000_10000000_10000000_00000000 = [ZaSliceV(B_4), PMerge, Z(B_4)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], [Ex_SME];
+    0b11000000_11000000_00000000_00000000 = [ZaSliceH(B_8), PMerge, Z(B_8)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], [Ex_SME];
+    0b11000000_11000000_10000000_00000000 = [ZaSliceV(B_8), PMerge, Z(B_8)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], [Ex_SME];
+    0b11000000_11000001_00000000_00000000 = [ZaSliceH(B_16), PMerge, Z(B_16)] => [RTile(0, 4), RSelect(13), Ubits(0, 0), R3(10), R(5)], [Ex_SME];
+    0b11000000_11000001_10000000_00000000 = [ZaSliceV(B_16), PMerge, Z(B_16)] => [RTile(0, 4), RSelect(13), Ubits(0, 0), R3(10), R(5)], [Ex_SME];
+]
+"mova" = [
+    // MOVA (tile to vector)
+    0b11000000_00000010_00000000_00000000 = [Z(BYTE), PMerge, ZaSliceH(BYTE)] => [R(0), R3(10), RTile(9, 0), RSelect(13), Ubits(5, 4)], [Ex_SME];
+    0b11000000_00000010_10000000_00000000 = [Z(BYTE), PMerge, ZaSliceV(BYTE)] => [R(0), R3(10), RTile(9, 0), RSelect(13), Ubits(5, 4)], [Ex_SME];
+    0b11000000_01000010_00000000_00000000 = [Z(B_2), PMerge, ZaSliceH(B_2)] => [R(0), R3(10), RTile(8, 1), RSelect(13), Ubits(5, 3)], [Ex_SME];
+    0b11000000_01000010_10000000_00000000 = [Z(B_2), PMerge, ZaSliceV(B_2)] => [R(0), R3(10), RTile(8, 1), RSelect(13), Ubits(5, 3)], [Ex_SME];
+    0b11000000_10000010_00000000_00000000 = [Z(B_4), PMerge, ZaSliceH(B_4)] => [R(0), R3(10), RTile(7, 2), RSelect(13), Ubits(5, 2)], [Ex_SME];
+    0b11000000_10000010_10000000_00000000 = [Z(B_4), PMerge, ZaSliceV(B_4)] => [R(0), R3(10), RTile(7, 2), RSelect(13), Ubits(5, 2)], [Ex_SME];
+    0b11000000_11000010_00000000_00000000 = [Z(B_8), PMerge, ZaSliceH(B_8)] => [R(0), R3(10), RTile(6, 3), RSelect(13), Ubits(5, 1)], [Ex_SME];
+    0b11000000_11000010_10000000_00000000 = [Z(B_8), PMerge, ZaSliceV(B_8)] => [R(0), R3(10), RTile(6, 3), RSelect(13), Ubits(5, 1)], [Ex_SME];
+    0b11000000_11000011_00000000_00000000 = [Z(B_16), PMerge, ZaSliceH(B_16)] => [R(0), R3(10), RTile(5, 4), RSelect(13), Ubits(5, 0)], [Ex_SME];
+    0b11000000_11000011_10000000_00000000 = [Z(B_16), PMerge, ZaSliceV(B_16)] => [R(0), R3(10), RTile(5, 4), RSelect(13), Ubits(5, 0)], [Ex_SME];
+    // MOVA (vector to tile)
+    0b11000000_00000000_00000000_00000000 = [ZaSliceH(BYTE), PMerge, Z(BYTE)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], [Ex_SME];
+    0b11000000_00000000_10000000_00000000 = [ZaSliceV(BYTE), PMerge, Z(BYTE)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], [Ex_SME];
+    0b11000000_01000000_00000000_00000000 = [ZaSliceH(B_2), PMerge, Z(B_2)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], [Ex_SME];
+    0b11000000_01000000_10000000_00000000 = [ZaSliceV(B_2), PMerge, Z(B_2)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], [Ex_SME];
+    0b11000000_10000000_00000000_00000000 = [ZaSliceH(B_4), PMerge, Z(B_4)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], [Ex_SME];
+    0b11000000_10000000_10000000_00000000 = [ZaSliceV(B_4), PMerge, Z(B_4)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], [Ex_SME];
+    0b11000000_11000000_00000000_00000000 = [ZaSliceH(B_8), PMerge, Z(B_8)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], [Ex_SME];
+    0b11000000_11000000_10000000_00000000 = [ZaSliceV(B_8), PMerge, Z(B_8)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], [Ex_SME];
+    0b11000000_11000001_00000000_00000000 = [ZaSliceH(B_16), PMerge, Z(B_16)] => [RTile(0, 4), RSelect(13), Ubits(0, 0), R3(10), R(5)], [Ex_SME];
+    0b11000000_11000001_10000000_00000000 = [ZaSliceV(B_16), PMerge, Z(B_16)] => [RTile(0, 4), RSelect(13), Ubits(0, 0), R3(10), R(5)], [Ex_SME];
 ]
 "movi" = [
-    0b00001111_00000000_11100100_00000000 = [V(BYTE), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, CUbits(0), A, Rwidth(30)];
-    0b00001111_00000000_10000100_00000000 = [V(B_2), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8]), Rwidth(30)];
-    0b00001111_00000000_00000100_00000000 = [V(B_4), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8, 16, 24]), Rwidth(30)];
-    0b00001111_00000000_11000100_00000000 = [V(B_4), Imm, LitMod(MSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(12, &[8, 16]), Rwidth(30)];
-    0b00101111_00000000_11100100_00000000 = [D, Imm] => [R(0), Special(5, STRETCHED_IMMEDIATE)];
-    0b01101111_00000000_11100100_00000000 = [VStatic(B_8, 2), Imm] => [R(0), Special(5, STRETCHED_IMMEDIATE)];
+    0b00001111_00000000_11100100_00000000 = [V(BYTE), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, CUbits(0), A, Rwidth(30)], [Ex_BASE];
+    0b00001111_00000000_10000100_00000000 = [V(B_2), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8]), Rwidth(30)], [Ex_BASE];
+    0b00001111_00000000_00000100_00000000 = [V(B_4), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8, 16, 24]), Rwidth(30)], [Ex_BASE];
+    0b00001111_00000000_11000100_00000000 = [V(B_4), Imm, LitMod(MSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(12, &[8, 16]), Rwidth(30)], [Ex_BASE];
+    0b00101111_00000000_11100100_00000000 = [D, Imm] => [R(0), Special(5, STRETCHED_IMMEDIATE)], [Ex_BASE];
+    0b01101111_00000000_11100100_00000000 = [VStatic(B_8, 2), Imm] => [R(0), Special(5, STRETCHED_IMMEDIATE)], [Ex_BASE];
 ]
 "movk" = [
-    0b01110010_10000000_00000000_00000000 = [W, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16])];
-    0b11110010_10000000_00000000_00000000 = [X, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16, 32, 48])];
+    0b01110010_10000000_00000000_00000000 = [W, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16])], [Ex_BASE];
+    0b11110010_10000000_00000000_00000000 = [X, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16, 32, 48])], [Ex_BASE];
 ]
 "movn" = [
-    0b00010010_10000000_00000000_00000000 = [W, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16])];
-    0b10010010_10000000_00000000_00000000 = [X, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16, 32, 48])];
+    0b00010010_10000000_00000000_00000000 = [W, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16])], [Ex_BASE];
+    0b10010010_10000000_00000000_00000000 = [X, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16, 32, 48])], [Ex_BASE];
+]
+"movprfx" = [
+    // MOVPRFX (unpredicated)
+    0b00000100_00100000_10111100_00000000 = [ZBare, ZBare] => [R(0), R(5)], [Ex_SVE];
+    // MOVPRFX (predicated)
+    0b00000100_00010000_00100000_00000000 = [Z(BYTE), PZero, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_00010001_00100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_01010000_00100000_00000000 = [Z(B_2), PZero, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_01010001_00100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_10010000_00100000_00000000 = [Z(B_4), PZero, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_10010001_00100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_11010000_00100000_00000000 = [Z(B_8), PZero, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_11010001_00100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "movz" = [
-    0b01010010_10000000_00000000_00000000 = [W, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16])];
-    0b11010010_10000000_00000000_00000000 = [X, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16, 32, 48])];
+    0b01010010_10000000_00000000_00000000 = [W, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16])], [Ex_BASE];
+    0b11010010_10000000_00000000_00000000 = [X, Imm, End, LitMod(LSL)] => [R(0), Ubits(5, 16), Ulist(21, &[0, 16, 32, 48])], [Ex_BASE];
 ]
 "mrs" = [
-    0b11010101_00110000_00000000_00000000 = [X, Imm] => [R(0), Ubits(5, 15)];
+    0b11010101_00110000_00000000_00000000 = [X, Imm] => [R(0), Ubits(5, 15)], [Ex_BASE];
 ]
 "msr" = [
     // MSR (immediate)
-    0b11010101_00000000_01000000_00011111 = [Ident, Imm] => [LitList(5, "MSR_IMM_OPS"), Ubits(8, 4)];
+    0b11010101_00000000_01000000_00011111 = [Ident, Imm] => [LitList(5, "MSR_IMM_OPS"), Ubits(8, 4)], [Ex_BASE];
     // MSR (register)
-    0b11010101_00010000_00000000_00000000 = [Imm, X] => [Ubits(5, 15), R(0)];
+    0b11010101_00010000_00000000_00000000 = [Imm, X] => [Ubits(5, 15), R(0)], [Ex_BASE];
 ]
 "msub" = [
-    0b00011011_00000000_10000000_00000000 = [W, W, W, W] => [R(0), R(5), R(16), R(10)];
-    0b10011011_00000000_10000000_00000000 = [X, X, X, X] => [R(0), R(5), R(16), R(10)];
+    0b00011011_00000000_10000000_00000000 = [W, W, W, W] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
+    0b10011011_00000000_10000000_00000000 = [X, X, X, X] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
 ]
 "mul" = [
     // MUL (by element)
-    0b00001111_01000000_10000000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
-    0b00001111_10000000_10000000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    0b00001111_01000000_10000000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], [Ex_BASE];
+    0b00001111_10000000_10000000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], [Ex_BASE];
     // MUL (vector)
-    0b00001110_00100000_10011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_10011100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_10011100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00100000_10011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_10011100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_10011100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // MUL
-    0b00011011_00000000_01111100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b10011011_00000000_01111100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00011011_00000000_01111100_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b10011011_00000000_01111100_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
+    // MUL (vectors, predicated)
+    0b00000100_00010000_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // MUL (vectors, predicated)
+    0b00000100_01010000_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // MUL (vectors, predicated)
+    0b00000100_10010000_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // MUL (vectors, predicated)
+    0b00000100_11010000_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // MUL (vectors, unpredicated)
+    0b00000100_00100000_01100000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // MUL (vectors, unpredicated)
+    0b00000100_01100000_01100000_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // MUL (vectors, unpredicated)
+    0b00000100_10100000_01100000_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // MUL (vectors, unpredicated)
+    0b00000100_11100000_01100000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // MUL (immediate)
+    0b00100101_00110000_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Sbits(5, 8)], [Ex_SVE];
+    0b00100101_01110000_11000000_00000000 = [Z(B_2), Z(B_2), Imm] => [R(0), RTied, Sbits(5, 8)], [Ex_SVE];
+    0b00100101_10110000_11000000_00000000 = [Z(B_4), Z(B_4), Imm] => [R(0), RTied, Sbits(5, 8)], [Ex_SVE];
+    0b00100101_11110000_11000000_00000000 = [Z(B_8), Z(B_8), Imm] => [R(0), RTied, Sbits(5, 8)], [Ex_SVE];
 ]
 "mvn" = [
-    0b00101010_00100000_00000011_11100000 = [W, W, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 5)];
-    0b10101010_00100000_00000011_11100000 = [X, X, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 6)];
-    0b00101110_00100000_01011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00101010_00100000_00000011_11100000 = [W, W, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b10101010_00100000_00000011_11100000 = [X, X, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
+    0b00101110_00100000_01011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
 ]
 "mvni" = [
-    0b00101111_00000000_10000100_00000000 = [V(B_2), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8]), Rwidth(30)];
-    0b00101111_00000000_00000100_00000000 = [V(B_4), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8, 16, 24]), Rwidth(30)];
-    0b00101111_00000000_11000100_00000000 = [V(B_4), Imm, LitMod(MSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(12, &[8, 16]), Rwidth(30)];
+    0b00101111_00000000_10000100_00000000 = [V(B_2), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8]), Rwidth(30)], [Ex_BASE];
+    0b00101111_00000000_00000100_00000000 = [V(B_4), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8, 16, 24]), Rwidth(30)], [Ex_BASE];
+    0b00101111_00000000_11000100_00000000 = [V(B_4), Imm, LitMod(MSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(12, &[8, 16]), Rwidth(30)], [Ex_BASE];
+]
+"nand" = [
+    // NAND (predicates)
+    0b00100101_10000000_01000010_00010000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
+]
+"nands" = [
+    // NANDS (predicates)
+    0b00100101_11000000_01000010_00010000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
+]
+"nbsl" = [
+    0b00000100_11100000_00111100_00000000 = [Z(B_8), Z(B_8), Z(B_8), Z(B_8)] => [R(0), RTied, R(16), R(5)], [Ex_SVE2];
 ]
 "neg" = [
     // NEG (shifted register)
-    0b01001011_00000000_00000011_11100000 = [W, W, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 5)];
-    0b11001011_00000000_00000011_11100000 = [X, X, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 6)];
+    0b01001011_00000000_00000011_11100000 = [W, W, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b11001011_00000000_00000011_11100000 = [X, X, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
     // NEG (vector)
-    0b01111110_11100000_10111000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_00100000_10111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100000_10111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100000_10111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_11100000_10111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01111110_11100000_10111000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_00100000_10111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_10111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_10111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_10111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    // NEG (predicated)
+    0b00000100_00010111_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // NEG (predicated)
+    0b00000100_01010111_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // NEG (predicated)
+    0b00000100_10010111_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // NEG (predicated)
+    0b00000100_11010111_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "negs" = [
-    0b01101011_00000000_00000011_11100000 = [W, W, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 5)];
-    0b11101011_00000000_00000011_11100000 = [X, X, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 6)];
+    0b01101011_00000000_00000011_11100000 = [W, W, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b11101011_00000000_00000011_11100000 = [X, X, End, Mod(SHIFTS)] => [R(0), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
 ]
 "ngc" = [
-    0b01011010_00000000_00000011_11100000 = [W, W] => [R(0), R(16)];
-    0b11011010_00000000_00000011_11100000 = [X, X] => [R(0), R(16)];
+    0b01011010_00000000_00000011_11100000 = [W, W] => [R(0), R(16)], [Ex_BASE];
+    0b11011010_00000000_00000011_11100000 = [X, X] => [R(0), R(16)], [Ex_BASE];
 ]
 "ngcs" = [
-    0b01111010_00000000_00000011_11100000 = [W, W] => [R(0), R(16)];
-    0b11111010_00000000_00000011_11100000 = [X, X] => [R(0), R(16)];
+    0b01111010_00000000_00000011_11100000 = [W, W] => [R(0), R(16)], [Ex_BASE];
+    0b11111010_00000000_00000011_11100000 = [X, X] => [R(0), R(16)], [Ex_BASE];
+]
+"nmatch" = [
+    0b01000101_00100000_10000000_00010000 = [P(BYTE), PZero, Z(BYTE), Z(BYTE)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE2];
+    0b01000101_01100000_10000000_00010000 = [P(B_2), PZero, Z(B_2), Z(B_2)] => [R4(0), R3(10), R(5), R(16)], [Ex_SVE2];
 ]
 "nop" = [
-    0b11010101_00000011_00100000_00011111 = [] => [];
+    0b11010101_00000011_00100000_00011111 = [] => [], [Ex_BASE];
+]
+"nor" = [
+    // NOR (predicates)
+    0b00100101_10000000_01000010_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
+]
+"nors" = [
+    // NORS (predicates)
+    0b00100101_11000000_01000010_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
 ]
 "not" = [
-    0b00101110_00100000_01011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_00100000_01011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    // NOT (predicated)
+    0b00000100_00011110_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // NOT (predicated)
+    0b00000100_01011110_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // NOT (predicated)
+    0b00000100_10011110_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // NOT (predicated)
+    0b00000100_11011110_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // NOT (predicate)
+    0b00100101_00000000_01000010_00000000 = [P(BYTE), PZero, P(BYTE)] => [R4(0), R4(10), C, R4(16), R4(5)], [Ex_SVE];
 ]
 "orn" = [
     // ORN (vector)
-    0b00001110_11100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // ORN (shifted register)
-    0b00101010_00100000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b10101010_00100000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b00101010_00100000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b10101010_00100000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
+    // ORN (predicates)
+    0b00100101_10000000_01000000_00010000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
+]
+"orns" = [
+    // ORNS (predicates)
+    0b00100101_11000000_01000000_00010000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
 ]
 "orr" = [
     // ORR (vector, immediate)
-    0b00001111_00000000_10010100_00000000 = [V(B_2), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8]), Rwidth(30)];
-    0b00001111_00000000_00010100_00000000 = [V(B_4), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8, 16, 24]), Rwidth(30)];
+    0b00001111_00000000_10010100_00000000 = [V(B_2), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8]), Rwidth(30)], [Ex_BASE];
+    0b00001111_00000000_00010100_00000000 = [V(B_4), Imm, End, LitMod(LSL)] => [R(0), CUbits(8), Uslice(5, 5, 0), Uslice(16, 3, 5), A, Ulist(13, &[0, 8, 16, 24]), Rwidth(30)], [Ex_BASE];
     // ORR (vector, register)
-    0b00001110_10100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_10100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
     // ORR (immediate)
-    0b00110010_00000000_00000000_00000000 = [WSP, W, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_W)];
-    0b10110010_00000000_00000000_00000000 = [XSP, X, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_X)];
+    0b00110010_00000000_00000000_00000000 = [WSP, W, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_W)], [Ex_BASE];
+    0b10110010_00000000_00000000_00000000 = [XSP, X, Imm] => [R(0), R(5), Special(10, LOGICAL_IMMEDIATE_X)], [Ex_BASE];
     // ORR (shifted register)
-    0b00101010_00000000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b10101010_00000000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b00101010_00000000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b10101010_00000000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
+    // ORR (vectors, predicated)
+    0b00000100_00011000_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // ORR (vectors, predicated)
+    0b00000100_01011000_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // ORR (vectors, predicated)
+    0b00000100_10011000_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // ORR (vectors, predicated)
+    0b00000100_11011000_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // ORR (vectors, unpredicated)
+    0b00000100_01100000_00110000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // ORR (predicates)
+    0b00100101_10000000_01000000_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
+]
+"orrs" = [
+    // ORRS (predicates)
+    0b00100101_11000000_01000000_00000000 = [P(BYTE), PZero, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
+]
+"orv" = [
+    0b00000100_00011000_00100000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_01011000_00100000_00000000 = [H, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_10011000_00100000_00000000 = [S, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_11011000_00100000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "pacda" = [
-    0b11011010_11000001_00001000_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00001000_00000000 = [X, XSP] => [R(0), R(5)], [Ex_PAUTH];
 ]
 "pacdb" = [
-    0b11011010_11000001_00001100_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00001100_00000000 = [X, XSP] => [R(0), R(5)], [Ex_PAUTH];
 ]
 "pacdza" = [
-    0b11011010_11000001_00101011_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00101011_11100000 = [X] => [R(0)], [Ex_PAUTH];
 ]
 "pacdzb" = [
-    0b11011010_11000001_00101111_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00101111_11100000 = [X] => [R(0)], [Ex_PAUTH];
 ]
 "pacga" = [
-    0b10011010_11000000_00110000_00000000 = [X, X, XSP] => [R(0), R(5), R(16)];
+    0b10011010_11000000_00110000_00000000 = [X, X, XSP] => [R(0), R(5), R(16)], [Ex_PAUTH];
 ]
 "pacia" = [
-    0b11011010_11000001_00000000_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00000000_00000000 = [X, XSP] => [R(0), R(5)], [Ex_PAUTH];
 ]
 "pacia1716" = [
-    0b11010101_00000011_00100001_00011111 = [] => [];
+    0b11010101_00000011_00100001_00011111 = [] => [], [Ex_PAUTH];
 ]
 "paciasp" = [
-    0b11010101_00000011_00100011_00111111 = [] => [];
+    0b11010101_00000011_00100011_00111111 = [] => [], [Ex_PAUTH];
 ]
 "paciaz" = [
-    0b11010101_00000011_00100011_00011111 = [] => [];
+    0b11010101_00000011_00100011_00011111 = [] => [], [Ex_PAUTH];
 ]
 "pacib" = [
-    0b11011010_11000001_00000100_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00000100_00000000 = [X, XSP] => [R(0), R(5)], [Ex_PAUTH];
 ]
 "pacib1716" = [
-    0b11010101_00000011_00100001_01011111 = [] => [];
+    0b11010101_00000011_00100001_01011111 = [] => [], [Ex_PAUTH];
 ]
 "pacibsp" = [
-    0b11010101_00000011_00100011_01111111 = [] => [];
+    0b11010101_00000011_00100011_01111111 = [] => [], [Ex_PAUTH];
 ]
 "pacibz" = [
-    0b11010101_00000011_00100011_01011111 = [] => [];
+    0b11010101_00000011_00100011_01011111 = [] => [], [Ex_PAUTH];
 ]
 "paciza" = [
-    0b11011010_11000001_00100011_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00100011_11100000 = [X] => [R(0)], [Ex_PAUTH];
 ]
 "pacizb" = [
-    0b11011010_11000001_00100111_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00100111_11100000 = [X] => [R(0)], [Ex_PAUTH];
+]
+"pfalse" = [
+    0b00100101_00011000_11100100_00000000 = [P(BYTE)] => [R4(0)], [Ex_SVE];
+]
+"pfirst" = [
+    0b00100101_01011000_11000000_00000000 = [P(BYTE), PBare, P(BYTE)] => [R4(0), R4(5), RTied], [Ex_SVE];
 ]
 "pmul" = [
-    0b00101110_00100000_10011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_00100000_10011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // PMUL (vectors, unpredicated)
+    0b00000100_00100000_01100100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "pmull" = [
-    0b00001110_00100000_11100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00001110_11100000_11100000_00000000 = [VStatic(B_16, 1), VStatic(B_8, 1), VStatic(B_8, 1)] => [R(0), R(5), R(16)];
+    0b00001110_00100000_11100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_11100000_11100000_00000000 = [VStatic(B_16, 1), VStatic(B_8, 1), VStatic(B_8, 1)] => [R(0), R(5), R(16)], [Ex_AES];
 ]
 "pmull2" = [
-    0b01001110_00100000_11100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01001110_11100000_11100000_00000000 = [VStatic(B_16, 1), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)];
+    0b01001110_00100000_11100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_11100000_11100000_00000000 = [VStatic(B_16, 1), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)], [Ex_AES];
+]
+"pnext" = [
+    0b00100101_00011001_11000100_00000000 = [P(BYTE), PBare, P(BYTE)] => [R4(0), R4(5), RTied], [Ex_SVE];
+    0b00100101_01011001_11000100_00000000 = [P(B_2), PBare, P(B_2)] => [R4(0), R4(5), RTied], [Ex_SVE];
+    0b00100101_10011001_11000100_00000000 = [P(B_4), PBare, P(B_4)] => [R4(0), R4(5), RTied], [Ex_SVE];
+    0b00100101_11011001_11000100_00000000 = [P(B_8), PBare, P(B_8)] => [R4(0), R4(5), RTied], [Ex_SVE];
 ]
 "prfm" = [
     // PRFM (literal)
-    0b11011000_00000000_00000000_00000000 = [Imm, Offset] => [Ubits(0, 5), Offset(BCOND)];
+    0b11011000_00000000_00000000_00000000 = [Imm, Offset] => [Ubits(0, 5), Offset(BCOND)], [Ex_BASE];
     // PRFM (register)
-    0b11111000_10100000_00001000_00000000 = [Imm, RefIndex] => [Ubits(0, 5), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])];
+    0b11111000_10100000_00001000_00000000 = [Imm, RefIndex] => [Ubits(0, 5), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])], [Ex_BASE];
 ]
 "prfum" = [
-    0b11111000_10000000_00000000_00000000 = [Imm, RefOffset] => [Ubits(0, 5), R(5), Sbits(12, 9)];
+    0b11111000_10000000_00000000_00000000 = [Imm, RefOffset] => [Ubits(0, 5), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "psb" = [
-    0b11010101_00000011_00100010_00111111 = [Lit("csync")] => [];
+    0b11010101_00000011_00100010_00111111 = [Lit("csync")] => [], [Ex_SPE];
 ]
 "pssbb" = [
-    0b11010101_00000011_00110100_10011111 = [] => [];
+    0b11010101_00000011_00110100_10011111 = [] => [], [Ex_BASE];
+]
+"ptest" = [
+    0b00100101_01010000_11000000_00000000 = [PBare, P(BYTE)] => [R4(10), R4(5)], [Ex_SVE];
+]
+"ptrue" = [
+    0b00100101_00011000_11100011_11100000 = [P(BYTE)] => [R4(0)], [Ex_SVE];
+    0b00100101_00011000_11100000_00000000 = [P(BYTE), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00100101_01011000_11100011_11100000 = [P(B_2)] => [R4(0)], [Ex_SVE];
+    0b00100101_01011000_11100000_00000000 = [P(B_2), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00100101_10011000_11100011_11100000 = [P(B_4)] => [R4(0)], [Ex_SVE];
+    0b00100101_10011000_11100000_00000000 = [P(B_4), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00100101_11011000_11100011_11100000 = [P(B_8)] => [R4(0)], [Ex_SVE];
+    0b00100101_11011000_11100000_00000000 = [P(B_8), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+]
+"ptrues" = [
+    0b00100101_00011001_11100011_11100000 = [P(BYTE)] => [R4(0)], [Ex_SVE];
+    0b00100101_00011001_11100000_00000000 = [P(BYTE), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00100101_01011001_11100011_11100000 = [P(B_2)] => [R4(0)], [Ex_SVE];
+    0b00100101_01011001_11100000_00000000 = [P(B_2), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00100101_10011001_11100011_11100000 = [P(B_4)] => [R4(0)], [Ex_SVE];
+    0b00100101_10011001_11100000_00000000 = [P(B_4), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+    0b00100101_11011001_11100011_11100000 = [P(B_8)] => [R4(0)], [Ex_SVE];
+    0b00100101_11011001_11100000_00000000 = [P(B_8), Ident] => [R4(0), LitList(5, "SVE_PATTERNS")], [Ex_SVE];
+]
+"punpkhi" = [
+    0b00000101_00110001_01000000_00000000 = [P(B_2), P(BYTE)] => [R4(0), R4(5)], [Ex_SVE];
+]
+"punpklo" = [
+    0b00000101_00110000_01000000_00000000 = [P(B_2), P(BYTE)] => [R4(0), R4(5)], [Ex_SVE];
 ]
 "raddhn" = [
-    0b00101110_00100000_01000000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b00101110_01100000_01000000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
-    0b00101110_10100000_01000000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)];
+    0b00101110_00100000_01000000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_01100000_01000000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_10100000_01000000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "raddhn2" = [
-    0b01101110_00100000_01000000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01101110_01100000_01000000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
-    0b01101110_10100000_01000000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)];
+    0b01101110_00100000_01000000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_01100000_01000000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_10100000_01000000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"raddhnb" = [
+    0b01000101_01100000_01101000_00000000 = [Z(BYTE), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10100000_01101000_00000000 = [Z(B_2), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11100000_01101000_00000000 = [Z(B_4), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"raddhnt" = [
+    0b01000101_01100000_01101100_00000000 = [Z(BYTE), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10100000_01101100_00000000 = [Z(B_2), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11100000_01101100_00000000 = [Z(B_4), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "rax1" = [
-    0b11001110_01100000_10001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)];
+    0b11001110_01100000_10001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)], [Ex_SHA3];
 ]
 "rbit" = [
     // RBIT (vector)
-    0b00101110_01100000_01011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01100000_01011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // RBIT
-    0b01011010_11000000_00000000_00000000 = [W, W] => [R(0), R(5)];
-    0b11011010_11000000_00000000_00000000 = [X, X] => [R(0), R(5)];
+    0b01011010_11000000_00000000_00000000 = [W, W] => [R(0), R(5)], [Ex_BASE];
+    0b11011010_11000000_00000000_00000000 = [X, X] => [R(0), R(5)], [Ex_BASE];
+    // RBIT (predicated)
+    0b00000101_00100111_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // RBIT (predicated)
+    0b00000101_01100111_10000000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // RBIT (predicated)
+    0b00000101_10100111_10000000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // RBIT (predicated)
+    0b00000101_11100111_10000000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+]
+"rdsvl" = [
+    0b00000100_10111111_01011000_00000000 = [X, Imm] => [R(0), Sbits(5, 6)], [Ex_SME];
+]
+"rdvl" = [
+    0b00000100_10111111_01010000_00000000 = [X, Imm] => [R(0), Sbits(5, 6)], [Ex_SVE];
 ]
 "ret" = [
-    0b11010110_01011111_00000000_00000000 = [X] => [R(5)];
-    0b11010110_01011111_00000011_11000000 = [] => [];
+    0b11010110_01011111_00000000_00000000 = [X] => [R(5)], [Ex_BASE];
+    0b11010110_01011111_00000011_11000000 = [] => [], [Ex_BASE];
 ]
 "retaa" = [
-    0b11010110_01011111_00001011_11111111 = [] => [];
+    0b11010110_01011111_00001011_11111111 = [] => [], [Ex_PAUTH];
 ]
 "retab" = [
-    0b11010110_01011111_00001111_11111111 = [] => [];
+    0b11010110_01011111_00001111_11111111 = [] => [], [Ex_PAUTH];
 ]
 "rev" = [
-    0b01011010_11000000_00001000_00000000 = [W, W] => [R(0), R(5)];
-    0b11011010_11000000_00001100_00000000 = [X, X] => [R(0), R(5)];
+    0b01011010_11000000_00001000_00000000 = [W, W] => [R(0), R(5)], [Ex_BASE];
+    0b11011010_11000000_00001100_00000000 = [X, X] => [R(0), R(5)], [Ex_BASE];
+    // REV (vector)
+    0b00000101_00111000_00111000_00000000 = [Z(BYTE), Z(BYTE)] => [R(0), R(5)], [Ex_SVE];
+    // REV (vector)
+    0b00000101_01111000_00111000_00000000 = [Z(B_2), Z(B_2)] => [R(0), R(5)], [Ex_SVE];
+    // REV (vector)
+    0b00000101_10111000_00111000_00000000 = [Z(B_4), Z(B_4)] => [R(0), R(5)], [Ex_SVE];
+    // REV (vector)
+    0b00000101_11111000_00111000_00000000 = [Z(B_8), Z(B_8)] => [R(0), R(5)], [Ex_SVE];
 ]
 "rev16" = [
     // REV16 (vector)
-    0b00001110_00100000_00011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_00100000_00011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // REV16
-    0b01011010_11000000_00000100_00000000 = [W, W] => [R(0), R(5)];
-    0b11011010_11000000_00000100_00000000 = [X, X] => [R(0), R(5)];
+    0b01011010_11000000_00000100_00000000 = [W, W] => [R(0), R(5)], [Ex_BASE];
+    0b11011010_11000000_00000100_00000000 = [X, X] => [R(0), R(5)], [Ex_BASE];
 ]
 "rev32" = [
     // REV32 (vector)
-    0b00101110_00100000_00001000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100000_00001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_00100000_00001000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_00001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // REV32
-    0b11011010_11000000_00001000_00000000 = [X, X] => [R(0), R(5)];
+    0b11011010_11000000_00001000_00000000 = [X, X] => [R(0), R(5)], [Ex_BASE];
 ]
 "rev64" = [
-    0b00001110_00100000_00001000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100000_00001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100000_00001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b11011010_11000000_00001100_00000000 = [X, X] => [R(0), R(5)];
+    0b00001110_00100000_00001000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_00001000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_00001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b11011010_11000000_00001100_00000000 = [X, X] => [R(0), R(5)], [Ex_BASE];
+]
+"revb" = [
+    // REVB (predicated)
+    0b00000101_01100100_10000000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // REVB (predicated)
+    0b00000101_10100100_10000000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // REVB (predicated)
+    0b00000101_11100100_10000000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+]
+"revd" = [
+    0b00000101_00101110_10000000_00000000 = [Z(B_16), PMerge, Z(B_16)] => [R(0), R3(10), R(5)], [Ex_SME];
+]
+"revh" = [
+    // REVH (predicated)
+    0b00000101_10100101_10000000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // REVH (predicated)
+    0b00000101_11100101_10000000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+]
+"revw" = [
+    // REVW (predicated)
+    0b00000101_11100110_10000000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "rmif" = [
-    0b10111010_00000000_00000100_00000000 = [X, Imm, Imm] => [R(5), Ubits(15, 6), Ubits(0, 4)];
+    0b10111010_00000000_00000100_00000000 = [X, Imm, Imm] => [R(5), Ubits(15, 6), Ubits(0, 4)], [Ex_FLAGM];
 ]
 "ror" = [
     // ROR (immediate)
-    0b00010011_10000000_00000000_00000000 = [W, W, Imm] => [R(0), R(5), C, R(16), Ubits(10, 5)];
-    0b10010011_11000000_00000000_00000000 = [X, X, Imm] => [R(0), R(5), C, R(16), Ubits(10, 6)];
+    0b00010011_10000000_00000000_00000000 = [W, W, Imm] => [R(0), R(5), C, R(16), Ubits(10, 5)], [Ex_BASE];
+    0b10010011_11000000_00000000_00000000 = [X, X, Imm] => [R(0), R(5), C, R(16), Ubits(10, 6)], [Ex_BASE];
     // ROR (register)
-    0b00011010_11000000_00101100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b10011010_11000000_00101100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00011010_11000000_00101100_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b10011010_11000000_00101100_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "rorv" = [
-    0b00011010_11000000_00101100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b10011010_11000000_00101100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00011010_11000000_00101100_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b10011010_11000000_00101100_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "rshrn" = [
-    0b00001111_00001000_10001100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b00001111_00010000_10001100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b00001111_00100000_10001100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b00001111_00001000_10001100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b00001111_00010000_10001100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b00001111_00100000_10001100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "rshrn2" = [
-    0b01001111_00001000_10001100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b01001111_00010000_10001100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b01001111_00100000_10001100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b01001111_00001000_10001100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b01001111_00010000_10001100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b01001111_00100000_10001100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "rsubhn" = [
-    0b00101110_00100000_01100000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b00101110_01100000_01100000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
-    0b00101110_10100000_01100000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)];
+    0b00101110_00100000_01100000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_01100000_01100000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_10100000_01100000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "rsubhn2" = [
-    0b01101110_00100000_01100000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01101110_01100000_01100000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
-    0b01101110_10100000_01100000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)];
+    0b01101110_00100000_01100000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_01100000_01100000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_10100000_01100000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"rsubhnb" = [
+    0b01000101_01100000_01111000_00000000 = [Z(BYTE), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10100000_01111000_00000000 = [Z(B_2), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11100000_01111000_00000000 = [Z(B_4), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"rsubhnt" = [
+    0b01000101_01100000_01111100_00000000 = [Z(BYTE), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10100000_01111100_00000000 = [Z(B_2), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11100000_01111100_00000000 = [Z(B_4), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "saba" = [
-    0b00001110_00100000_01111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_01111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_01111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00100000_01111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_01111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_01111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SABA (vectors, unpredicated)
+    0b01000101_00000000_11111000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // SABA (vectors, unpredicated)
+    0b01000101_01000000_11111000_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // SABA (vectors, unpredicated)
+    0b01000101_10000000_11111000_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // SABA (vectors, unpredicated)
+    0b01000101_11000000_11111000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "sabal" = [
-    0b00001110_00100000_01010000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00001110_01100000_01010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00001110_10100000_01010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00001110_00100000_01010000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_01010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_10100000_01010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "sabal2" = [
-    0b01001110_00100000_01010000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01001110_01100000_01010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_01010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01001110_00100000_01010000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_01100000_01010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_10100000_01010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "sabd" = [
-    0b00001110_00100000_01110100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_01110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_01110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00100000_01110100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_01110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_01110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SABD (vectors, predicated)
+    0b00000100_00001100_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SABD (vectors, predicated)
+    0b00000100_01001100_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SABD (vectors, predicated)
+    0b00000100_10001100_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SABD (vectors, predicated)
+    0b00000100_11001100_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "sabdl" = [
-    0b00001110_00100000_01110000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00001110_01100000_01110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00001110_10100000_01110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00001110_00100000_01110000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_01110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_10100000_01110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "sabdl2" = [
-    0b01001110_00100000_01110000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01001110_01100000_01110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_01110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01001110_00100000_01110000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_01100000_01110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_10100000_01110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"sabdlb" = [
+    0b01000101_01000000_00110000_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_00110000_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_00110000_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"sabdlt" = [
+    0b01000101_01000000_00110100_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_00110100_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_00110100_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "sadalp" = [
-    0b00001110_00100000_01101000_00000000 = [V(B_2), V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100000_01101000_00000000 = [V(B_4), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100000_01101000_00000000 = [V(B_8), V(B_4)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_00100000_01101000_00000000 = [V(B_2), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_01101000_00000000 = [V(B_4), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_01101000_00000000 = [V(B_8), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
 ]
 "saddl" = [
-    0b00001110_00100000_00000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00001110_01100000_00000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00001110_10100000_00000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00001110_00100000_00000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_00000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_10100000_00000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "saddl2" = [
-    0b01001110_00100000_00000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01001110_01100000_00000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_00000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01001110_00100000_00000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_01100000_00000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_10100000_00000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"saddlb" = [
+    0b01000101_01000000_00000000_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_00000000_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_00000000_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "saddlp" = [
-    0b00001110_00100000_00101000_00000000 = [V(B_2), V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100000_00101000_00000000 = [V(B_4), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100000_00101000_00000000 = [V(B_8), V(B_4)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_00100000_00101000_00000000 = [V(B_2), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_00101000_00000000 = [V(B_4), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_00101000_00000000 = [V(B_8), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+]
+"saddlt" = [
+    0b01000101_01000000_00000100_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_00000100_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_00000100_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "saddlv" = [
-    0b00001110_00110000_00111000_00000000 = [H, V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01110000_00111000_00000000 = [S, V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10110000_00111000_00000000 = [D, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_00110000_00111000_00000000 = [H, V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01110000_00111000_00000000 = [S, V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_10110000_00111000_00000000 = [D, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+]
+"saddv" = [
+    0b00000100_00000000_00100000_00000000 = [D, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_01000000_00100000_00000000 = [D, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_10000000_00100000_00000000 = [D, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "saddw" = [
-    0b00001110_00100000_00010000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00001110_01100000_00010000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00001110_10100000_00010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00001110_00100000_00010000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_00010000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_10100000_00010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "saddw2" = [
-    0b01001110_00100000_00010000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01001110_01100000_00010000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_00010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01001110_00100000_00010000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_01100000_00010000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_10100000_00010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"saddwb" = [
+    0b01000101_01000000_01000000_00000000 = [Z(B_2), Z(B_2), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_01000000_00000000 = [Z(B_4), Z(B_4), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_01000000_00000000 = [Z(B_8), Z(B_8), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"saddwt" = [
+    0b01000101_01000000_01000100_00000000 = [Z(B_2), Z(B_2), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_01000100_00000000 = [Z(B_4), Z(B_4), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_01000100_00000000 = [Z(B_8), Z(B_8), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "sb" = [
-    0b11010101_00000011_00110000_11111111 = [] => [];
+    0b11010101_00000011_00110000_11111111 = [] => [], [Ex_SB];
 ]
 "sbc" = [
-    0b01011010_00000000_00000000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b11011010_00000000_00000000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b01011010_00000000_00000000_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b11011010_00000000_00000000_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "sbcs" = [
-    0b01111010_00000000_00000000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b11111010_00000000_00000000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b01111010_00000000_00000000_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b11111010_00000000_00000000_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "sbfiz" = [
-    0b00010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Usubmod(16, 5), CUsum(5), Urange(10, 1, 32)];
-    0b10010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Usubmod(16, 6), CUsum(6), Urange(10, 1, 64)];
+    0b00010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Usubmod(16, 5), CUsum(5), Urange(10, 1, 32)], [Ex_BASE];
+    0b10010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Usubmod(16, 6), CUsum(6), Urange(10, 1, 64)], [Ex_BASE];
 ]
 "sbfm" = [
-    0b00010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Ubits(10, 5)];
-    0b10010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), CUsum(6), Ubits(10, 6)];
+    0b00010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Ubits(10, 5)], [Ex_BASE];
+    0b10010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), CUsum(6), Ubits(10, 6)], [Ex_BASE];
 ]
 "sbfx" = [
-    0b00010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Usum(10, 5)];
-    0b10010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), Usum(10, 6)];
+    0b00010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Usum(10, 5)], [Ex_BASE];
+    0b10010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), Usum(10, 6)], [Ex_BASE];
+]
+"sclamp" = [
+    0b01000100_00000000_11000000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SME];
+    0b01000100_01000000_11000000_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SME];
+    0b01000100_10000000_11000000_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SME];
+    0b01000100_11000000_11000000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SME];
 ]
 "scvtf" = [
     // SCVTF (vector, fixed-point)
-    0b01011111_00000000_11100100_00000000 = [H, H, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)];
-    0b01011111_00000000_11100100_00000000 = [S, S, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)];
-    0b01011111_00000000_11100100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)];
-    0b00001111_00010000_11100100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)];
-    0b00001111_00100000_11100100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)];
-    0b00001111_01000000_11100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)];
+    0b01011111_00000000_11100100_00000000 = [H, H, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)], [Ex_FP16];
+    0b01011111_00000000_11100100_00000000 = [S, S, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)], [Ex_BASE];
+    0b01011111_00000000_11100100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)], [Ex_BASE];
+    0b00001111_00010000_11100100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)], [Ex_FP16];
+    0b00001111_00100000_11100100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00001111_01000000_11100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)], [Ex_BASE];
     // SCVTF (vector, integer)
-    0b01011110_01111001_11011000_00000000 = [H, H] => [R(0), R(5)];
-    0b01011110_00100001_11011000_00000000 = [S, S] => [R(0), R(5)];
-    0b01011110_01100001_11011000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_01111001_11011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_00100001_11011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100001_11011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_01111001_11011000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01011110_00100001_11011000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_01100001_11011000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_01111001_11011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00001110_00100001_11011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100001_11011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // SCVTF (scalar, fixed-point)
-    0b00011110_11000010_00000000_00000000 = [H, W, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)];
-    0b00011110_00000010_00000000_00000000 = [S, W, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)];
-    0b00011110_01000010_00000000_00000000 = [D, W, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)];
-    0b10011110_11000010_00000000_00000000 = [H, X, Imm] => [R(0), R(5), Usubone(10, 6)];
-    0b10011110_00000010_00000000_00000000 = [S, X, Imm] => [R(0), R(5), Usubone(10, 6)];
-    0b10011110_01000010_00000000_00000000 = [D, X, Imm] => [R(0), R(5), Usubone(10, 6)];
+    0b00011110_11000010_00000000_00000000 = [H, W, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)], [Ex_FP16];
+    0b00011110_00000010_00000000_00000000 = [S, W, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)], [Ex_BASE];
+    0b00011110_01000010_00000000_00000000 = [D, W, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)], [Ex_BASE];
+    0b10011110_11000010_00000000_00000000 = [H, X, Imm] => [R(0), R(5), Usubone(10, 6)], [Ex_FP16];
+    0b10011110_00000010_00000000_00000000 = [S, X, Imm] => [R(0), R(5), Usubone(10, 6)], [Ex_BASE];
+    0b10011110_01000010_00000000_00000000 = [D, X, Imm] => [R(0), R(5), Usubone(10, 6)], [Ex_BASE];
     // SCVTF (scalar, integer)
-    0b00011110_11100010_00000000_00000000 = [H, W] => [R(0), R(5)];
-    0b00011110_00100010_00000000_00000000 = [S, W] => [R(0), R(5)];
-    0b00011110_01100010_00000000_00000000 = [D, W] => [R(0), R(5)];
-    0b10011110_11100010_00000000_00000000 = [H, X] => [R(0), R(5)];
-    0b10011110_00100010_00000000_00000000 = [S, X] => [R(0), R(5)];
-    0b10011110_01100010_00000000_00000000 = [D, X] => [R(0), R(5)];
+    0b00011110_11100010_00000000_00000000 = [H, W] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100010_00000000_00000000 = [S, W] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100010_00000000_00000000 = [D, W] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_11100010_00000000_00000000 = [H, X] => [R(0), R(5)], [Ex_FP16];
+    0b10011110_00100010_00000000_00000000 = [S, X] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_01100010_00000000_00000000 = [D, X] => [R(0), R(5)], [Ex_BASE];
+    // SCVTF (predicated)
+    0b01100101_01010010_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // SCVTF (predicated)
+    0b01100101_10010100_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // SCVTF (predicated)
+    0b01100101_11010110_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // SCVTF (predicated)
+    0b01100101_11010100_10100000_00000000 = [Z(B_4), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // SCVTF (predicated)
+    0b01100101_11010000_10100000_00000000 = [Z(B_8), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "sdiv" = [
-    0b00011010_11000000_00001100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b10011010_11000000_00001100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00011010_11000000_00001100_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b10011010_11000000_00001100_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
+    // SDIV (vectors, predicated)
+    0b00000100_10010100_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SDIV (vectors, predicated)
+    0b00000100_11010100_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+]
+"sdivr" = [
+    // SDIVR (vectors, predicated)
+    0b00000100_10010110_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SDIVR (vectors, predicated)
+    0b00000100_11010110_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "sdot" = [
     // SDOT (by element)
-    0b00001111_10000000_11100000_00000000 = [VStatic(B_4, 2), VStatic(BYTE, 8), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b01001111_10000000_11100000_00000000 = [VStatic(B_4, 4), VStatic(BYTE, 16), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00001111_10000000_11100000_00000000 = [VStatic(B_4, 2), VStatic(BYTE, 8), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_DOTPROD];
+    0b01001111_10000000_11100000_00000000 = [VStatic(B_4, 4), VStatic(BYTE, 16), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_DOTPROD];
     // SDOT (vector)
-    0b00001110_10000000_10010100_00000000 = [VStatic(B_4, 2), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b01001110_10000000_10010100_00000000 = [VStatic(B_4, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b00001110_10000000_10010100_00000000 = [VStatic(B_4, 2), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_DOTPROD];
+    0b01001110_10000000_10010100_00000000 = [VStatic(B_4, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_DOTPROD];
+    // SDOT (vectors)
+    0b01000100_10000000_00000000_00000000 = [Z(B_4), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    0b01000100_11000000_00000000_00000000 = [Z(B_8), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // SDOT (indexed)
+    0b01000100_10100000_00000000_00000000 = [Z(B_4), Z(BYTE), ZElement(BYTE)] => [R(0), R(5), R3(16), Ubits(19, 2)], [Ex_SVE];
+    0b01000100_11100000_00000000_00000000 = [Z(B_8), Z(B_2), ZElement(B_2)] => [R(0), R(5), R4(16), Ubits(20, 1)], [Ex_SVE];
+]
+"sel" = [
+    // SEL (vectors)
+    0b00000101_00100000_11000000_00000000 = [Z(BYTE), PBare, Z(BYTE), Z(BYTE)] => [R(0), R4(10), R(5), R(16)], [Ex_SVE];
+    0b00000101_01100000_11000000_00000000 = [Z(B_2), PBare, Z(B_2), Z(B_2)] => [R(0), R4(10), R(5), R(16)], [Ex_SVE];
+    0b00000101_10100000_11000000_00000000 = [Z(B_4), PBare, Z(B_4), Z(B_4)] => [R(0), R4(10), R(5), R(16)], [Ex_SVE];
+    0b00000101_11100000_11000000_00000000 = [Z(B_8), PBare, Z(B_8), Z(B_8)] => [R(0), R4(10), R(5), R(16)], [Ex_SVE];
+    // SEL (predicates)
+    0b00100101_00000000_01000010_00010000 = [P(BYTE), PBare, P(BYTE), P(BYTE)] => [R4(0), R4(10), R4(5), R4(16)], [Ex_SVE];
+]
+"sete" = [
+    0b00011001_11000000_10000100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS];
+]
+"seten" = [
+    0b00011001_11000000_10100100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS];
+]
+"setet" = [
+    0b00011001_11000000_10010100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS];
+]
+"setetn" = [
+    0b00011001_11000000_10110100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS];
 ]
 "setf16" = [
-    0b00111010_00000000_01001000_00001101 = [W] => [R(5)];
+    0b00111010_00000000_01001000_00001101 = [W] => [R(5)], [Ex_FLAGM];
 ]
 "setf8" = [
-    0b00111010_00000000_00001000_00001101 = [W] => [R(5)];
+    0b00111010_00000000_00001000_00001101 = [W] => [R(5)], [Ex_FLAGM];
+]
+"setge" = [
+    0b00011101_11000000_10000100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS | Ex_MTE];
+]
+"setgen" = [
+    0b00011101_11000000_10100100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS | Ex_MTE];
+]
+"setget" = [
+    0b00011101_11000000_10010100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS | Ex_MTE];
+]
+"setgetn" = [
+    0b00011101_11000000_10110100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS | Ex_MTE];
+]
+"setgm" = [
+    0b00011101_11000000_01000100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS | Ex_MTE];
+]
+"setgmn" = [
+    0b00011101_11000000_01100100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS | Ex_MTE];
+]
+"setgmt" = [
+    0b00011101_11000000_01010100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS | Ex_MTE];
+]
+"setgmtn" = [
+    0b00011101_11000000_01110100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS | Ex_MTE];
+]
+"setgp" = [
+    0b00011101_11000000_00000100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS | Ex_MTE];
+]
+"setgpn" = [
+    0b00011101_11000000_00100100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS | Ex_MTE];
+]
+"setgpt" = [
+    0b00011101_11000000_00010100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS | Ex_MTE];
+]
+"setgptn" = [
+    0b00011101_11000000_00110100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS | Ex_MTE];
+]
+"setm" = [
+    0b00011001_11000000_01000100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS];
+]
+"setmn" = [
+    0b00011001_11000000_01100100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS];
+]
+"setmt" = [
+    0b00011001_11000000_01010100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS];
+]
+"setmtn" = [
+    0b00011001_11000000_01110100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS];
+]
+"setp" = [
+    0b00011001_11000000_00000100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS];
+]
+"setpn" = [
+    0b00011001_11000000_00100100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS];
+]
+"setpt" = [
+    0b00011001_11000000_00010100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS];
+]
+"setptn" = [
+    0b00011001_11000000_00110100_00000000 = [RefWriteback, XWriteback, X] => [RNoZr(0), RNoZr(5), R(16)], [Ex_MOPS];
 ]
 "sev" = [
-    0b11010101_00000011_00100000_10011111 = [] => [];
+    0b11010101_00000011_00100000_10011111 = [] => [], [Ex_BASE];
 ]
 "sevl" = [
-    0b11010101_00000011_00100000_10111111 = [] => [];
+    0b11010101_00000011_00100000_10111111 = [] => [], [Ex_BASE];
 ]
 "sha1c" = [
-    0b01011110_00000000_00000000_00000000 = [Q, S, VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01011110_00000000_00000000_00000000 = [Q, S, VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_SHA2];
 ]
 "sha1h" = [
-    0b01011110_00101000_00001000_00000000 = [S, S] => [R(0), R(5)];
+    0b01011110_00101000_00001000_00000000 = [S, S] => [R(0), R(5)], [Ex_SHA2];
 ]
 "sha1m" = [
-    0b01011110_00000000_00100000_00000000 = [Q, S, VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01011110_00000000_00100000_00000000 = [Q, S, VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_SHA2];
 ]
 "sha1p" = [
-    0b01011110_00000000_00010000_00000000 = [Q, S, VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01011110_00000000_00010000_00000000 = [Q, S, VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_SHA2];
 ]
 "sha1su0" = [
-    0b01011110_00000000_00110000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01011110_00000000_00110000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_SHA2];
 ]
 "sha1su1" = [
-    0b01011110_00101000_00011000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5)];
+    0b01011110_00101000_00011000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5)], [Ex_SHA2];
 ]
 "sha256h" = [
-    0b01011110_00000000_01000000_00000000 = [Q, Q, VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01011110_00000000_01000000_00000000 = [Q, Q, VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_SHA2];
 ]
 "sha256h2" = [
-    0b01011110_00000000_01010000_00000000 = [Q, Q, VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01011110_00000000_01010000_00000000 = [Q, Q, VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_SHA2];
 ]
 "sha256su0" = [
-    0b01011110_00101000_00101000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5)];
+    0b01011110_00101000_00101000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5)], [Ex_SHA2];
 ]
 "sha256su1" = [
-    0b01011110_00000000_01100000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01011110_00000000_01100000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_SHA2];
 ]
 "sha512h" = [
-    0b11001110_01100000_10000000_00000000 = [Q, Q, VStatic(B_8, 2)] => [R(0), R(5), R(16)];
+    0b11001110_01100000_10000000_00000000 = [Q, Q, VStatic(B_8, 2)] => [R(0), R(5), R(16)], [Ex_SHA3];
 ]
 "sha512h2" = [
-    0b11001110_01100000_10000100_00000000 = [Q, Q, VStatic(B_8, 2)] => [R(0), R(5), R(16)];
+    0b11001110_01100000_10000100_00000000 = [Q, Q, VStatic(B_8, 2)] => [R(0), R(5), R(16)], [Ex_SHA3];
 ]
 "sha512su0" = [
-    0b11001110_11000000_10000000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5)];
+    0b11001110_11000000_10000000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5)], [Ex_SHA3];
 ]
 "sha512su1" = [
-    0b11001110_01100000_10001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)];
+    0b11001110_01100000_10001000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)], [Ex_SHA3];
 ]
 "shadd" = [
-    0b00001110_00100000_00000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_00000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_00000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00100000_00000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_00000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_00000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SHADD (vectors, predicated)
+    0b01000100_00010000_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SHADD (vectors, predicated)
+    0b01000100_01010000_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SHADD (vectors, predicated)
+    0b01000100_10010000_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SHADD (vectors, predicated)
+    0b01000100_11010000_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "shl" = [
-    0b01011111_01000000_01010100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)];
-    0b00001111_00001000_01010100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)];
-    0b00001111_00010000_01010100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)];
-    0b00001111_00100000_01010100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)];
-    0b00001111_01000000_01010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)];
+    0b01011111_01000000_01010100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)], [Ex_BASE];
+    0b00001111_00001000_01010100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)], [Ex_BASE];
+    0b00001111_00010000_01010100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)], [Ex_BASE];
+    0b00001111_00100000_01010100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00001111_01000000_01010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)], [Ex_BASE];
 ]
 "shll" = [
-    0b00101110_00100001_00111000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), LitInt(8)] => [R(0), R(5)];
-    0b00101110_01100001_00111000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), LitInt(16)] => [R(0), R(5)];
-    0b00101110_10100001_00111000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), LitInt(32)] => [R(0), R(5)];
+    0b00101110_00100001_00111000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), LitInt(8)] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_01100001_00111000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_10100001_00111000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), LitInt(32)] => [R(0), R(5)], [Ex_BASE];
 ]
 "shll2" = [
-    0b01101110_00100001_00111000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), LitInt(8)] => [R(0), R(5)];
-    0b01101110_01100001_00111000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), LitInt(16)] => [R(0), R(5)];
-    0b01101110_10100001_00111000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), LitInt(32)] => [R(0), R(5)];
+    0b01101110_00100001_00111000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), LitInt(8)] => [R(0), R(5)], [Ex_BASE];
+    0b01101110_01100001_00111000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b01101110_10100001_00111000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), LitInt(32)] => [R(0), R(5)], [Ex_BASE];
 ]
 "shrn" = [
-    0b00001111_00001000_10000100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b00001111_00010000_10000100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b00001111_00100000_10000100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b00001111_00001000_10000100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b00001111_00010000_10000100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b00001111_00100000_10000100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "shrn2" = [
-    0b01001111_00001000_10000100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b01001111_00010000_10000100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b01001111_00100000_10000100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b01001111_00001000_10000100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b01001111_00010000_10000100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b01001111_00100000_10000100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "shsub" = [
-    0b00001110_00100000_00100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_00100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_00100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00100000_00100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_00100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_00100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SHSUB (vectors, predicated)
+    0b01000100_00010010_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SHSUB (vectors, predicated)
+    0b01000100_01010010_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SHSUB (vectors, predicated)
+    0b01000100_10010010_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SHSUB (vectors, predicated)
+    0b01000100_11010010_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "sli" = [
-    0b01111111_01000000_01010100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)];
-    0b00101111_00001000_01010100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)];
-    0b00101111_00010000_01010100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)];
-    0b00101111_00100000_01010100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)];
-    0b00101111_01000000_01010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)];
+    0b01111111_01000000_01010100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)], [Ex_BASE];
+    0b00101111_00001000_01010100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)], [Ex_BASE];
+    0b00101111_00010000_01010100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)], [Ex_BASE];
+    0b00101111_00100000_01010100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00101111_01000000_01010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)], [Ex_BASE];
 ]
 "sm3partw1" = [
-    0b11001110_01100000_11000000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b11001110_01100000_11000000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_SM4];
 ]
 "sm3partw2" = [
-    0b11001110_01100000_11000100_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b11001110_01100000_11000100_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_SM4];
 ]
 "sm3ss1" = [
-    0b11001110_01000000_00000000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16), R(10)];
+    0b11001110_01000000_00000000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16), R(10)], [Ex_SM4];
 ]
 "sm3tt1a" = [
-    0b11001110_01000000_10000000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ubits(12, 2)];
+    0b11001110_01000000_10000000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ubits(12, 2)], [Ex_SM4];
 ]
 "sm3tt1b" = [
-    0b11001110_01000000_10000100_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ubits(12, 2)];
+    0b11001110_01000000_10000100_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ubits(12, 2)], [Ex_SM4];
 ]
 "sm3tt2a" = [
-    0b11001110_01000000_10001000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ubits(12, 2)];
+    0b11001110_01000000_10001000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ubits(12, 2)], [Ex_SM4];
 ]
 "sm3tt2b" = [
-    0b11001110_01000000_10001100_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ubits(12, 2)];
+    0b11001110_01000000_10001100_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ubits(12, 2)], [Ex_SM4];
 ]
 "sm4e" = [
-    0b11001110_11000000_10000100_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5)];
+    0b11001110_11000000_10000100_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5)], [Ex_SM4];
 ]
 "sm4ekey" = [
-    0b11001110_01100000_11001000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b11001110_01100000_11001000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_SM4];
 ]
 "smaddl" = [
-    0b10011011_00100000_00000000_00000000 = [X, W, W, X] => [R(0), R(5), R(16), R(10)];
+    0b10011011_00100000_00000000_00000000 = [X, W, W, X] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
 ]
 "smax" = [
-    0b00001110_00100000_01100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_01100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_01100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00100000_01100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_01100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_01100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SMAX (vectors, predicated)
+    0b00000100_00001000_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SMAX (vectors, predicated)
+    0b00000100_01001000_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SMAX (vectors, predicated)
+    0b00000100_10001000_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SMAX (vectors, predicated)
+    0b00000100_11001000_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SMAX (immediate)
+    0b00100101_00101000_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Sbits(5, 8)], [Ex_SVE];
+    0b00100101_01101000_11000000_00000000 = [Z(B_2), Z(B_2), Imm] => [R(0), RTied, Sbits(5, 8)], [Ex_SVE];
+    0b00100101_10101000_11000000_00000000 = [Z(B_4), Z(B_4), Imm] => [R(0), RTied, Sbits(5, 8)], [Ex_SVE];
+    0b00100101_11101000_11000000_00000000 = [Z(B_8), Z(B_8), Imm] => [R(0), RTied, Sbits(5, 8)], [Ex_SVE];
 ]
 "smaxp" = [
-    0b00001110_00100000_10100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_10100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_10100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00100000_10100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_10100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_10100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SMAXP (vectors, predicated)
+    0b01000100_00010100_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SMAXP (vectors, predicated)
+    0b01000100_01010100_10100000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SMAXP (vectors, predicated)
+    0b01000100_10010100_10100000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SMAXP (vectors, predicated)
+    0b01000100_11010100_10100000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "smaxv" = [
-    0b00001110_00110000_10101000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01110000_10101000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10110000_10101000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_00110000_10101000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01110000_10101000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_10110000_10101000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00000100_00001000_00100000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_01001000_00100000_00000000 = [H, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_10001000_00100000_00000000 = [S, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_11001000_00100000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "smc" = [
-    0b11010100_00000000_00000000_00000011 = [Imm] => [Ubits(5, 16)];
+    0b11010100_00000000_00000000_00000011 = [Imm] => [Ubits(5, 16)], [Ex_BASE];
 ]
 "smin" = [
-    0b00001110_00100000_01101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_01101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_01101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00100000_01101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_01101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_01101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SMIN (vectors, predicated)
+    0b00000100_00001010_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SMIN (vectors, predicated)
+    0b00000100_01001010_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SMIN (vectors, predicated)
+    0b00000100_10001010_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SMIN (vectors, predicated)
+    0b00000100_11001010_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SMIN (immediate)
+    0b00100101_00101010_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Sbits(5, 8)], [Ex_SVE];
+    0b00100101_01101010_11000000_00000000 = [Z(B_2), Z(B_2), Imm] => [R(0), RTied, Sbits(5, 8)], [Ex_SVE];
+    0b00100101_10101010_11000000_00000000 = [Z(B_4), Z(B_4), Imm] => [R(0), RTied, Sbits(5, 8)], [Ex_SVE];
+    0b00100101_11101010_11000000_00000000 = [Z(B_8), Z(B_8), Imm] => [R(0), RTied, Sbits(5, 8)], [Ex_SVE];
 ]
 "sminp" = [
-    0b00001110_00100000_10101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_10101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_10101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00100000_10101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_10101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_10101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SMINP (vectors, predicated)
+    0b01000100_00010110_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SMINP (vectors, predicated)
+    0b01000100_01010110_10100000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SMINP (vectors, predicated)
+    0b01000100_10010110_10100000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SMINP (vectors, predicated)
+    0b01000100_11010110_10100000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "sminv" = [
-    0b00001110_00110001_10101000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01110001_10101000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10110001_10101000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_00110001_10101000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01110001_10101000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_10110001_10101000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00000100_00001010_00100000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_01001010_00100000_00000000 = [H, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_10001010_00100000_00000000 = [S, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_11001010_00100000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "smlal" = [
     // SMLAL, SMLAL2 (by element)
-    0b00001111_01000000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b00001111_10000000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00001111_01000000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b00001111_10000000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // SMLAL, SMLAL2 (vector)
-    0b00001110_00100000_10000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00001110_01100000_10000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00001110_10100000_10000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00001110_00100000_10000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_10000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_10100000_10000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "smlal2" = [
     // SMLAL, SMLAL2 (by element)
-    0b01001111_01000000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01001111_10000000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01001111_01000000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b01001111_10000000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // SMLAL, SMLAL2 (vector)
-    0b01001110_00100000_10000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01001110_01100000_10000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_10000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01001110_00100000_10000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_01100000_10000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_10100000_10000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"smlalb" = [
+    0b01000100_01000000_01000000_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_10000000_01000000_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_11000000_01000000_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"smlalt" = [
+    0b01000100_01000000_01000100_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_10000000_01000100_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_11000000_01000100_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "smlsl" = [
     // SMLSL, SMLSL2 (by element)
-    0b00001111_01000000_01100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b00001111_10000000_01100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00001111_01000000_01100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b00001111_10000000_01100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // SMLSL, SMLSL2 (vector)
-    0b00001110_00100000_10100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00001110_01100000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00001110_10100000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00001110_00100000_10100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_10100000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "smlsl2" = [
     // SMLSL, SMLSL2 (by element)
-    0b01001111_01000000_01100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01001111_10000000_01100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01001111_01000000_01100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b01001111_10000000_01100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // SMLSL, SMLSL2 (vector)
-    0b01001110_00100000_10100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01001110_01100000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01001110_00100000_10100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_01100000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_10100000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"smlslb" = [
+    0b01000100_01000000_01010000_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_10000000_01010000_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_11000000_01010000_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"smlslt" = [
+    0b01000100_01000000_01010100_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_10000000_01010100_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_11000000_01010100_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "smnegl" = [
-    0b10011011_00100000_11111100_00000000 = [X, W, W] => [R(0), R(5), R(16)];
+    0b10011011_00100000_11111100_00000000 = [X, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"smopa" = [
+    0b10100000_10000000_00000000_00000000 = [ZaTile(B_4), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], [Ex_SME];
+    0b10100000_11000000_00000000_00000000 = [ZaTile(B_8), PMerge, PMerge, Z(B_2), Z(B_2)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], [Ex_SME_I16I64];
+]
+"smops" = [
+    0b10100000_10000000_00000000_00010000 = [ZaTile(B_4), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], [Ex_SME];
+    0b10100000_11000000_00000000_00010000 = [ZaTile(B_8), PMerge, PMerge, Z(B_2), Z(B_2)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], [Ex_SME_I16I64];
 ]
 "smov" = [
-    0b00001110_00000001_00101100_00000000 = [W, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)];
-    0b00001110_00000010_00101100_00000000 = [W, VElement(B_2)] => [R(0), R(5), Ubits(18, 3)];
-    0b01001110_00000001_00101100_00000000 = [X, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)];
-    0b01001110_00000010_00101100_00000000 = [X, VElement(B_2)] => [R(0), R(5), Ubits(18, 3)];
-    0b01001110_00000100_00101100_00000000 = [X, VElement(B_4)] => [R(0), R(5), Ubits(19, 2)];
+    0b00001110_00000001_00101100_00000000 = [W, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)], [Ex_BASE];
+    0b00001110_00000010_00101100_00000000 = [W, VElement(B_2)] => [R(0), R(5), Ubits(18, 3)], [Ex_BASE];
+    0b01001110_00000001_00101100_00000000 = [X, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)], [Ex_BASE];
+    0b01001110_00000010_00101100_00000000 = [X, VElement(B_2)] => [R(0), R(5), Ubits(18, 3)], [Ex_BASE];
+    0b01001110_00000100_00101100_00000000 = [X, VElement(B_4)] => [R(0), R(5), Ubits(19, 2)], [Ex_BASE];
+]
+"smstart" = [
+    0b11010101_00000011_01000111_01111111 = [] => [], [Ex_SME];
+    0b11010101_00000011_01000011_01111111 = [Lit("sm")] => [], [Ex_SME];
+    0b11010101_00000011_01000101_01111111 = [ZaArray] => [], [Ex_SME];
+]
+"smstop" = [
+    0b11010101_00000011_01000110_01111111 = [] => [], [Ex_SME];
+    0b11010101_00000011_01000010_01111111 = [Lit("sm")] => [], [Ex_SME];
+    0b11010101_00000011_01000100_01111111 = [ZaArray] => [], [Ex_SME];
 ]
 "smsubl" = [
-    0b10011011_00100000_10000000_00000000 = [X, W, W, X] => [R(0), R(5), R(16), R(10)];
+    0b10011011_00100000_10000000_00000000 = [X, W, W, X] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
 ]
 "smulh" = [
-    0b10011011_01000000_01111100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b10011011_01000000_01111100_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
+    // SMULH (vectors, predicated)
+    0b00000100_00010010_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SMULH (vectors, predicated)
+    0b00000100_01010010_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SMULH (vectors, predicated)
+    0b00000100_10010010_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SMULH (vectors, predicated)
+    0b00000100_11010010_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SMULH (vectors, unpredicated)
+    0b00000100_00100000_01101000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // SMULH (vectors, unpredicated)
+    0b00000100_01100000_01101000_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // SMULH (vectors, unpredicated)
+    0b00000100_10100000_01101000_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // SMULH (vectors, unpredicated)
+    0b00000100_11100000_01101000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "smull" = [
     // SMULL, SMULL2 (by element)
-    0b00001111_01000000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b00001111_10000000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00001111_01000000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b00001111_10000000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // SMULL, SMULL2 (vector)
-    0b00001110_00100000_11000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00001110_01100000_11000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00001110_10100000_11000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00001110_00100000_11000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_11000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_10100000_11000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
     // SMULL
-    0b10011011_00100000_01111100_00000000 = [X, W, W] => [R(0), R(5), R(16)];
+    0b10011011_00100000_01111100_00000000 = [X, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "smull2" = [
     // SMULL, SMULL2 (by element)
-    0b01001111_01000000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01001111_10000000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01001111_01000000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b01001111_10000000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // SMULL, SMULL2 (vector)
-    0b01001110_00100000_11000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01001110_01100000_11000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_11000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01001110_00100000_11000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_01100000_11000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_10100000_11000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"smullb" = [
+    0b01000101_01000000_01110000_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_01110000_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_01110000_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"smullt" = [
+    0b01000101_01000000_01110100_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_01110100_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_01110100_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"splice" = [
+    0b00000101_00101100_10000000_00000000 = [Z(BYTE), PBare, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    0b00000101_01101100_10000000_00000000 = [Z(B_2), PBare, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    0b00000101_10101100_10000000_00000000 = [Z(B_4), PBare, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    0b00000101_11101100_10000000_00000000 = [Z(B_8), PBare, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "sqabs" = [
-    0b01011110_00100000_01111000_00000000 = [B, B] => [R(0), R(5)];
-    0b01011110_01100000_01111000_00000000 = [H, H] => [R(0), R(5)];
-    0b01011110_10100000_01111000_00000000 = [S, S] => [R(0), R(5)];
-    0b01011110_11100000_01111000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_00100000_01111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100000_01111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100000_01111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100000_01111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_00100000_01111000_00000000 = [B, B] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_01100000_01111000_00000000 = [H, H] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_10100000_01111000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_11100000_01111000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_00100000_01111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_01111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_01111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_01111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    // SQABS (predicated)
+    0b01000100_00001000_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE2];
+    // SQABS (predicated)
+    0b01000100_01001000_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE2];
+    // SQABS (predicated)
+    0b01000100_10001000_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE2];
+    // SQABS (predicated)
+    0b01000100_11001000_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE2];
 ]
 "sqadd" = [
-    0b01011110_00100000_00001100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
-    0b01011110_01100000_00001100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01011110_10100000_00001100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01011110_11100000_00001100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_00100000_00001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_00001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_00001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_00001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_00100000_00001100_00000000 = [B, B, B] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_01100000_00001100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_10100000_00001100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_11100000_00001100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_00100000_00001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_00001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_00001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_00001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SQADD (vectors, predicated)
+    0b01000100_00011000_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SQADD (vectors, predicated)
+    0b01000100_01011000_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SQADD (vectors, predicated)
+    0b01000100_10011000_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SQADD (vectors, predicated)
+    0b01000100_11011000_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SQADD (vectors, unpredicated)
+    0b00000100_00100000_00010000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // SQADD (vectors, unpredicated)
+    0b00000100_01100000_00010000_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // SQADD (vectors, unpredicated)
+    0b00000100_10100000_00010000_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // SQADD (vectors, unpredicated)
+    0b00000100_11100000_00010000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // SQADD (immediate)
+    0b00100101_00100100_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
+    0b00100101_01100100_11000000_00000000 = [Z(B_2), Z(B_2), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_10100100_11000000_00000000 = [Z(B_4), Z(B_4), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_11100100_11000000_00000000 = [Z(B_8), Z(B_8), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
 ]
 "sqdmlal" = [
     // SQDMLAL, SQDMLAL2 (by element)
-    0b01011111_01000000_00110000_00000000 = [S, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01011111_10000000_00110000_00000000 = [D, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b00001111_01000000_00110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b00001111_10000000_00110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01011111_01000000_00110000_00000000 = [S, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b01011111_10000000_00110000_00000000 = [D, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
+    0b00001111_01000000_00110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b00001111_10000000_00110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // SQDMLAL, SQDMLAL2 (vector)
-    0b01011110_01100000_10010000_00000000 = [S, H, H] => [R(0), R(5), R(16)];
-    0b01011110_10100000_10010000_00000000 = [D, S, S] => [R(0), R(5), R(16)];
-    0b00001110_01100000_10010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00001110_10100000_10010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b01011110_01100000_10010000_00000000 = [S, H, H] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_10100000_10010000_00000000 = [D, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_10010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_10100000_10010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "sqdmlal2" = [
     // SQDMLAL, SQDMLAL2 (by element)
-    0b01001111_01000000_00110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01001111_10000000_00110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01001111_01000000_00110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b01001111_10000000_00110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // SQDMLAL, SQDMLAL2 (vector)
-    0b01001110_01100000_10010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_10010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01001110_01100000_10010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_10100000_10010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "sqdmlsl" = [
     // SQDMLSL, SQDMLSL2 (by element)
-    0b01011111_01000000_01110000_00000000 = [S, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01011111_10000000_01110000_00000000 = [D, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b00001111_01000000_01110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b00001111_10000000_01110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01011111_01000000_01110000_00000000 = [S, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b01011111_10000000_01110000_00000000 = [D, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
+    0b00001111_01000000_01110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b00001111_10000000_01110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // SQDMLSL, SQDMLSL2 (vector)
-    0b01011110_01100000_10110000_00000000 = [S, H, H] => [R(0), R(5), R(16)];
-    0b01011110_10100000_10110000_00000000 = [D, S, S] => [R(0), R(5), R(16)];
-    0b00001110_01100000_10110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00001110_10100000_10110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b01011110_01100000_10110000_00000000 = [S, H, H] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_10100000_10110000_00000000 = [D, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_10110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_10100000_10110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "sqdmlsl2" = [
     // SQDMLSL, SQDMLSL2 (by element)
-    0b01001111_01000000_01110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01001111_10000000_01110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01001111_01000000_01110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b01001111_10000000_01110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // SQDMLSL, SQDMLSL2 (vector)
-    0b01001110_01100000_10110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_10110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01001110_01100000_10110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_10100000_10110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "sqdmulh" = [
     // SQDMULH (by element)
-    0b01011111_01000000_11000000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01011111_10000000_11000000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b00001111_01000000_11000000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
-    0b00001111_10000000_11000000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    0b01011111_01000000_11000000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b01011111_10000000_11000000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
+    0b00001111_01000000_11000000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], [Ex_BASE];
+    0b00001111_10000000_11000000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], [Ex_BASE];
     // SQDMULH (vector)
-    0b01011110_01100000_10110100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01011110_10100000_10110100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00001110_01100000_10110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_10110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_01100000_10110100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_10100000_10110100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_10110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_10110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SQDMULH (vectors, unpredicated)
+    0b00000100_00100000_01110000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // SQDMULH (vectors, unpredicated)
+    0b00000100_01100000_01110000_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // SQDMULH (vectors, unpredicated)
+    0b00000100_10100000_01110000_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // SQDMULH (vectors, unpredicated)
+    0b00000100_11100000_01110000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "sqdmull" = [
     // SQDMULL, SQDMULL2 (by element)
-    0b01011111_01000000_10110000_00000000 = [S, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01011111_10000000_10110000_00000000 = [D, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b00001111_01000000_10110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b00001111_10000000_10110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01011111_01000000_10110000_00000000 = [S, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b01011111_10000000_10110000_00000000 = [D, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
+    0b00001111_01000000_10110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b00001111_10000000_10110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // SQDMULL, SQDMULL2 (vector)
-    0b01011110_01100000_11010000_00000000 = [S, H, H] => [R(0), R(5), R(16)];
-    0b01011110_10100000_11010000_00000000 = [D, S, S] => [R(0), R(5), R(16)];
-    0b00001110_01100000_11010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00001110_10100000_11010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b01011110_01100000_11010000_00000000 = [S, H, H] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_10100000_11010000_00000000 = [D, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_11010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_10100000_11010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "sqdmull2" = [
     // SQDMULL, SQDMULL2 (by element)
-    0b01001111_01000000_10110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01001111_10000000_10110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01001111_01000000_10110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b01001111_10000000_10110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // SQDMULL, SQDMULL2 (vector)
-    0b01001110_01100000_11010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_11010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01001110_01100000_11010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_10100000_11010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "sqneg" = [
-    0b01111110_00100000_01111000_00000000 = [B, B] => [R(0), R(5)];
-    0b01111110_01100000_01111000_00000000 = [H, H] => [R(0), R(5)];
-    0b01111110_10100000_01111000_00000000 = [S, S] => [R(0), R(5)];
-    0b01111110_11100000_01111000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_00100000_01111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100000_01111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100000_01111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_11100000_01111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01111110_00100000_01111000_00000000 = [B, B] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_01100000_01111000_00000000 = [H, H] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_10100000_01111000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_11100000_01111000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_00100000_01111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_01111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_01111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_01111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    // SQNEG (predicated)
+    0b01000100_00001001_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE2];
+    // SQNEG (predicated)
+    0b01000100_01001001_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE2];
+    // SQNEG (predicated)
+    0b01000100_10001001_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE2];
+    // SQNEG (predicated)
+    0b01000100_11001001_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE2];
 ]
 "sqrdmlah" = [
     // SQRDMLAH (by element)
-    0b01111111_01000000_11010000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01111111_10000000_11010000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b00101111_01000000_11010000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
-    0b00101111_10000000_11010000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    0b01111111_01000000_11010000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_RDM];
+    0b01111111_10000000_11010000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_RDM];
+    0b00101111_01000000_11010000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], [Ex_RDM];
+    0b00101111_10000000_11010000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], [Ex_RDM];
     // SQRDMLAH (vector)
-    0b01111110_01000000_10000100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01111110_10000000_10000100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00101110_01000000_10000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10000000_10000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_01000000_10000100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_RDM];
+    0b01111110_10000000_10000100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_RDM];
+    0b00101110_01000000_10000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_RDM];
+    0b00101110_10000000_10000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_RDM];
 ]
 "sqrdmlsh" = [
     // SQRDMLSH (by element)
-    0b01111111_01000000_11110000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01111111_10000000_11110000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b00101111_01000000_11110000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
-    0b00101111_10000000_11110000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    0b01111111_01000000_11110000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_RDM];
+    0b01111111_10000000_11110000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_RDM];
+    0b00101111_01000000_11110000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], [Ex_RDM];
+    0b00101111_10000000_11110000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], [Ex_RDM];
     // SQRDMLSH (vector)
-    0b01111110_01000000_10001100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01111110_10000000_10001100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00101110_01000000_10001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10000000_10001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_01000000_10001100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_RDM];
+    0b01111110_10000000_10001100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_RDM];
+    0b00101110_01000000_10001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_RDM];
+    0b00101110_10000000_10001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_RDM];
 ]
 "sqrdmulh" = [
     // SQRDMULH (by element)
-    0b01011111_01000000_11010000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01011111_10000000_11010000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b00001111_01000000_11010000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
-    0b00001111_10000000_11010000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    0b01011111_01000000_11010000_00000000 = [H, H, VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b01011111_10000000_11010000_00000000 = [S, S, VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
+    0b00001111_01000000_11010000_00000000 = [V(B_2), V(B_2), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], [Ex_BASE];
+    0b00001111_10000000_11010000_00000000 = [V(B_4), V(B_4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], [Ex_BASE];
     // SQRDMULH (vector)
-    0b01111110_01100000_10110100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01111110_10100000_10110100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00101110_01100000_10110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_10110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_01100000_10110100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_10100000_10110100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_01100000_10110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_10110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SQRDMULH (vectors, unpredicated)
+    0b00000100_00100000_01110100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // SQRDMULH (vectors, unpredicated)
+    0b00000100_01100000_01110100_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // SQRDMULH (vectors, unpredicated)
+    0b00000100_10100000_01110100_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // SQRDMULH (vectors, unpredicated)
+    0b00000100_11100000_01110100_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "sqrshl" = [
-    0b01011110_00100000_01011100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
-    0b01011110_01100000_01011100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01011110_10100000_01011100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01011110_11100000_01011100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_00100000_01011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_01011100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_01011100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_01011100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_00100000_01011100_00000000 = [B, B, B] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_01100000_01011100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_10100000_01011100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_11100000_01011100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_00100000_01011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_01011100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_01011100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_01011100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SQRSHL (vectors, predicated)
+    0b01000100_00001010_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SQRSHL (vectors, predicated)
+    0b01000100_01001010_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SQRSHL (vectors, predicated)
+    0b01000100_10001010_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SQRSHL (vectors, predicated)
+    0b01000100_11001010_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "sqrshrn" = [
-    0b01011111_00000000_10011100_00000000 = [B, H, Imm] => [R(0), R(5), CUrange(1, 8), Usubone(16, 4)];
-    0b01011111_00000000_10011100_00000000 = [H, S, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)];
-    0b01011111_00000000_10011100_00000000 = [S, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)];
-    0b00001111_00001000_10011100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b00001111_00010000_10011100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b00001111_00100000_10011100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b01011111_00000000_10011100_00000000 = [B, H, Imm] => [R(0), R(5), CUrange(1, 8), Usubone(16, 4)], [Ex_BASE];
+    0b01011111_00000000_10011100_00000000 = [H, S, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)], [Ex_BASE];
+    0b01011111_00000000_10011100_00000000 = [S, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)], [Ex_BASE];
+    0b00001111_00001000_10011100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b00001111_00010000_10011100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b00001111_00100000_10011100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "sqrshrn2" = [
-    0b01001111_00001000_10011100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b01001111_00010000_10011100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b01001111_00100000_10011100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b01001111_00001000_10011100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b01001111_00010000_10011100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b01001111_00100000_10011100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "sqrshrun" = [
-    0b01111111_00000000_10001100_00000000 = [B, H, Imm] => [R(0), R(5), CUrange(1, 8), Usubone(16, 4)];
-    0b01111111_00000000_10001100_00000000 = [H, S, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)];
-    0b01111111_00000000_10001100_00000000 = [S, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)];
-    0b00101111_00001000_10001100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b00101111_00010000_10001100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b00101111_00100000_10001100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b01111111_00000000_10001100_00000000 = [B, H, Imm] => [R(0), R(5), CUrange(1, 8), Usubone(16, 4)], [Ex_BASE];
+    0b01111111_00000000_10001100_00000000 = [H, S, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)], [Ex_BASE];
+    0b01111111_00000000_10001100_00000000 = [S, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)], [Ex_BASE];
+    0b00101111_00001000_10001100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b00101111_00010000_10001100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b00101111_00100000_10001100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "sqrshrun2" = [
-    0b01101111_00001000_10001100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b01101111_00010000_10001100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b01101111_00100000_10001100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b01101111_00001000_10001100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b01101111_00010000_10001100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b01101111_00100000_10001100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "sqshl" = [
     // SQSHL (immediate)
-    0b01011111_00001000_01110100_00000000 = [B, B, Imm] => [R(0), R(5), Ubits(16, 3)];
-    0b01011111_00010000_01110100_00000000 = [H, H, Imm] => [R(0), R(5), Ubits(16, 4)];
-    0b01011111_00100000_01110100_00000000 = [S, S, Imm] => [R(0), R(5), Ubits(16, 5)];
-    0b01011111_01000000_01110100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)];
-    0b00001111_00001000_01110100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)];
-    0b00001111_00010000_01110100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)];
-    0b00001111_00100000_01110100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)];
-    0b00001111_01000000_01110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)];
+    0b01011111_00001000_01110100_00000000 = [B, B, Imm] => [R(0), R(5), Ubits(16, 3)], [Ex_BASE];
+    0b01011111_00010000_01110100_00000000 = [H, H, Imm] => [R(0), R(5), Ubits(16, 4)], [Ex_BASE];
+    0b01011111_00100000_01110100_00000000 = [S, S, Imm] => [R(0), R(5), Ubits(16, 5)], [Ex_BASE];
+    0b01011111_01000000_01110100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)], [Ex_BASE];
+    0b00001111_00001000_01110100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)], [Ex_BASE];
+    0b00001111_00010000_01110100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)], [Ex_BASE];
+    0b00001111_00100000_01110100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00001111_01000000_01110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)], [Ex_BASE];
     // SQSHL (register)
-    0b01011110_00100000_01001100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
-    0b01011110_01100000_01001100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01011110_10100000_01001100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01011110_11100000_01001100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_00100000_01001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_01001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_01001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_01001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_00100000_01001100_00000000 = [B, B, B] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_01100000_01001100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_10100000_01001100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_11100000_01001100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_00100000_01001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_01001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_01001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_01001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SQSHL (vectors, predicated)
+    0b01000100_00001000_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SQSHL (vectors, predicated)
+    0b01000100_01001000_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SQSHL (vectors, predicated)
+    0b01000100_10001000_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SQSHL (vectors, predicated)
+    0b01000100_11001000_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "sqshlu" = [
-    0b01111111_00001000_01100100_00000000 = [B, B, Imm] => [R(0), R(5), Ubits(16, 3)];
-    0b01111111_00010000_01100100_00000000 = [H, H, Imm] => [R(0), R(5), Ubits(16, 4)];
-    0b01111111_00100000_01100100_00000000 = [S, S, Imm] => [R(0), R(5), Ubits(16, 5)];
-    0b01111111_01000000_01100100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)];
-    0b00101111_00001000_01100100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)];
-    0b00101111_00010000_01100100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)];
-    0b00101111_00100000_01100100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)];
-    0b00101111_01000000_01100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)];
+    0b01111111_00001000_01100100_00000000 = [B, B, Imm] => [R(0), R(5), Ubits(16, 3)], [Ex_BASE];
+    0b01111111_00010000_01100100_00000000 = [H, H, Imm] => [R(0), R(5), Ubits(16, 4)], [Ex_BASE];
+    0b01111111_00100000_01100100_00000000 = [S, S, Imm] => [R(0), R(5), Ubits(16, 5)], [Ex_BASE];
+    0b01111111_01000000_01100100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)], [Ex_BASE];
+    0b00101111_00001000_01100100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)], [Ex_BASE];
+    0b00101111_00010000_01100100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)], [Ex_BASE];
+    0b00101111_00100000_01100100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00101111_01000000_01100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)], [Ex_BASE];
 ]
 "sqshrn" = [
-    0b01011111_00000000_10010100_00000000 = [B, H, Imm] => [R(0), R(5), CUrange(1, 8), Usubone(16, 4)];
-    0b01011111_00000000_10010100_00000000 = [H, S, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)];
-    0b01011111_00000000_10010100_00000000 = [S, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)];
-    0b00001111_00001000_10010100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b00001111_00010000_10010100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b00001111_00100000_10010100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b01011111_00000000_10010100_00000000 = [B, H, Imm] => [R(0), R(5), CUrange(1, 8), Usubone(16, 4)], [Ex_BASE];
+    0b01011111_00000000_10010100_00000000 = [H, S, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)], [Ex_BASE];
+    0b01011111_00000000_10010100_00000000 = [S, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)], [Ex_BASE];
+    0b00001111_00001000_10010100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b00001111_00010000_10010100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b00001111_00100000_10010100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "sqshrn2" = [
-    0b01001111_00001000_10010100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b01001111_00010000_10010100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b01001111_00100000_10010100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b01001111_00001000_10010100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b01001111_00010000_10010100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b01001111_00100000_10010100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "sqshrun" = [
-    0b01111111_00000000_10000100_00000000 = [B, H, Imm] => [R(0), R(5), CUrange(1, 8), Usubone(16, 4)];
-    0b01111111_00000000_10000100_00000000 = [H, S, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)];
-    0b01111111_00000000_10000100_00000000 = [S, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)];
-    0b00101111_00001000_10000100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b00101111_00010000_10000100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b00101111_00100000_10000100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b01111111_00000000_10000100_00000000 = [B, H, Imm] => [R(0), R(5), CUrange(1, 8), Usubone(16, 4)], [Ex_BASE];
+    0b01111111_00000000_10000100_00000000 = [H, S, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)], [Ex_BASE];
+    0b01111111_00000000_10000100_00000000 = [S, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)], [Ex_BASE];
+    0b00101111_00001000_10000100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b00101111_00010000_10000100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b00101111_00100000_10000100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "sqshrun2" = [
-    0b01101111_00001000_10000100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b01101111_00010000_10000100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b01101111_00100000_10000100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b01101111_00001000_10000100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b01101111_00010000_10000100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b01101111_00100000_10000100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "sqsub" = [
-    0b01011110_00100000_00101100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
-    0b01011110_01100000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01011110_10100000_00101100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01011110_11100000_00101100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_00100000_00101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_00101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_00101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_00101100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_00100000_00101100_00000000 = [B, B, B] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_01100000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_10100000_00101100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01011110_11100000_00101100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_00100000_00101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_00101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_00101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_00101100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SQSUB (vectors, predicated)
+    0b01000100_00011010_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SQSUB (vectors, predicated)
+    0b01000100_01011010_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SQSUB (vectors, predicated)
+    0b01000100_10011010_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SQSUB (vectors, predicated)
+    0b01000100_11011010_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SQSUB (vectors, unpredicated)
+    0b00000100_00100000_00011000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // SQSUB (vectors, unpredicated)
+    0b00000100_01100000_00011000_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // SQSUB (vectors, unpredicated)
+    0b00000100_10100000_00011000_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // SQSUB (vectors, unpredicated)
+    0b00000100_11100000_00011000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // SQSUB (immediate)
+    0b00100101_00100110_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
+    0b00100101_01100110_11000000_00000000 = [Z(B_2), Z(B_2), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_10100110_11000000_00000000 = [Z(B_4), Z(B_4), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_11100110_11000000_00000000 = [Z(B_8), Z(B_8), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
 ]
 "sqxtn" = [
-    0b01011110_00100001_01001000_00000000 = [B, H] => [R(0), R(5)];
-    0b01011110_01100001_01001000_00000000 = [H, S] => [R(0), R(5)];
-    0b01011110_10100001_01001000_00000000 = [S, D] => [R(0), R(5)];
-    0b00001110_00100001_01001000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8)] => [R(0), R(5)];
-    0b00001110_01100001_01001000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4)] => [R(0), R(5)];
-    0b00001110_10100001_01001000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01011110_00100001_01001000_00000000 = [B, H] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_01100001_01001000_00000000 = [H, S] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_10100001_01001000_00000000 = [S, D] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_00100001_01001000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8)] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_01100001_01001000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4)] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_10100001_01001000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
 ]
 "sqxtn2" = [
-    0b01001110_00100001_01001000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8)] => [R(0), R(5)];
-    0b01001110_01100001_01001000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4)] => [R(0), R(5)];
-    0b01001110_10100001_01001000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01001110_00100001_01001000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8)] => [R(0), R(5)], [Ex_BASE];
+    0b01001110_01100001_01001000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4)] => [R(0), R(5)], [Ex_BASE];
+    0b01001110_10100001_01001000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
+]
+"sqxtnb" = [
+    0b01000101_00101000_01000000_00000000 = [Z(BYTE), Z(B_2)] => [R(0), R(5)], [Ex_SVE2];
+    0b01000101_00110000_01000000_00000000 = [Z(B_2), Z(B_4)] => [R(0), R(5)], [Ex_SVE2];
+    0b01000101_01100000_01000000_00000000 = [Z(B_4), Z(B_8)] => [R(0), R(5)], [Ex_SVE2];
+]
+"sqxtnt" = [
+    0b01000101_00101000_01000100_00000000 = [Z(BYTE), Z(B_2)] => [R(0), R(5)], [Ex_SVE2];
+    0b01000101_00110000_01000100_00000000 = [Z(B_2), Z(B_4)] => [R(0), R(5)], [Ex_SVE2];
+    0b01000101_01100000_01000100_00000000 = [Z(B_4), Z(B_8)] => [R(0), R(5)], [Ex_SVE2];
 ]
 "sqxtun" = [
-    0b01111110_00100001_00101000_00000000 = [B, H] => [R(0), R(5)];
-    0b01111110_01100001_00101000_00000000 = [H, S] => [R(0), R(5)];
-    0b01111110_10100001_00101000_00000000 = [S, D] => [R(0), R(5)];
-    0b00101110_00100001_00101000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8)] => [R(0), R(5)];
-    0b00101110_01100001_00101000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4)] => [R(0), R(5)];
-    0b00101110_10100001_00101000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01111110_00100001_00101000_00000000 = [B, H] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_01100001_00101000_00000000 = [H, S] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_10100001_00101000_00000000 = [S, D] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_00100001_00101000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8)] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_01100001_00101000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4)] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_10100001_00101000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
 ]
 "sqxtun2" = [
-    0b01101110_00100001_00101000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8)] => [R(0), R(5)];
-    0b01101110_01100001_00101000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4)] => [R(0), R(5)];
-    0b01101110_10100001_00101000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01101110_00100001_00101000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8)] => [R(0), R(5)], [Ex_BASE];
+    0b01101110_01100001_00101000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4)] => [R(0), R(5)], [Ex_BASE];
+    0b01101110_10100001_00101000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
+]
+"sqxtunb" = [
+    0b01000101_00101000_01010000_00000000 = [Z(BYTE), Z(B_2)] => [R(0), R(5)], [Ex_SVE2];
+    0b01000101_00110000_01010000_00000000 = [Z(B_2), Z(B_4)] => [R(0), R(5)], [Ex_SVE2];
+    0b01000101_01100000_01010000_00000000 = [Z(B_4), Z(B_8)] => [R(0), R(5)], [Ex_SVE2];
+]
+"sqxtunt" = [
+    0b01000101_00101000_01010100_00000000 = [Z(BYTE), Z(B_2)] => [R(0), R(5)], [Ex_SVE2];
+    0b01000101_00110000_01010100_00000000 = [Z(B_2), Z(B_4)] => [R(0), R(5)], [Ex_SVE2];
+    0b01000101_01100000_01010100_00000000 = [Z(B_4), Z(B_8)] => [R(0), R(5)], [Ex_SVE2];
 ]
 "srhadd" = [
-    0b00001110_00100000_00010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_00010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_00010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00100000_00010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_00010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_00010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SRHADD (vectors, predicated)
+    0b01000100_00010100_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SRHADD (vectors, predicated)
+    0b01000100_01010100_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SRHADD (vectors, predicated)
+    0b01000100_10010100_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SRHADD (vectors, predicated)
+    0b01000100_11010100_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "sri" = [
-    0b01111111_00000000_01000100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)];
-    0b00101111_00001000_01000100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)];
-    0b00101111_00010000_01000100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)];
-    0b00101111_00100000_01000100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)];
-    0b00101111_01000000_01000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)];
+    0b01111111_00000000_01000100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)], [Ex_BASE];
+    0b00101111_00001000_01000100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)], [Ex_BASE];
+    0b00101111_00010000_01000100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)], [Ex_BASE];
+    0b00101111_00100000_01000100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00101111_01000000_01000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)], [Ex_BASE];
 ]
 "srshl" = [
-    0b01011110_11100000_01010100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_00100000_01010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_01010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_01010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_01010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_11100000_01010100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_00100000_01010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_01010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_01010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_01010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SRSHL (vectors, predicated)
+    0b01000100_00000010_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SRSHL (vectors, predicated)
+    0b01000100_01000010_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SRSHL (vectors, predicated)
+    0b01000100_10000010_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SRSHL (vectors, predicated)
+    0b01000100_11000010_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "srshr" = [
-    0b01011111_00000000_00100100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)];
-    0b00001111_00001000_00100100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)];
-    0b00001111_00010000_00100100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)];
-    0b00001111_00100000_00100100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)];
-    0b00001111_01000000_00100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)];
+    0b01011111_00000000_00100100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)], [Ex_BASE];
+    0b00001111_00001000_00100100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)], [Ex_BASE];
+    0b00001111_00010000_00100100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)], [Ex_BASE];
+    0b00001111_00100000_00100100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00001111_01000000_00100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)], [Ex_BASE];
 ]
 "srsra" = [
-    0b01011111_00000000_00110100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)];
-    0b00001111_00001000_00110100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)];
-    0b00001111_00010000_00110100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)];
-    0b00001111_00100000_00110100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)];
-    0b00001111_01000000_00110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)];
+    0b01011111_00000000_00110100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)], [Ex_BASE];
+    0b00001111_00001000_00110100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)], [Ex_BASE];
+    0b00001111_00010000_00110100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)], [Ex_BASE];
+    0b00001111_00100000_00110100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00001111_01000000_00110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)], [Ex_BASE];
 ]
 "ssbb" = [
-    0b11010101_00000011_00110000_10011111 = [] => [];
+    0b11010101_00000011_00110000_10011111 = [] => [], [Ex_BASE];
 ]
 "sshl" = [
-    0b01011110_11100000_01000100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_00100000_01000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01100000_01000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10100000_01000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11100000_01000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01011110_11100000_01000100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_00100000_01000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_01000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_01000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_01000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
 ]
 "sshll" = [
-    0b00001111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), Imm] => [R(0), R(5), Ubits(16, 3)];
-    0b00001111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), Imm] => [R(0), R(5), Ubits(16, 4)];
-    0b00001111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), Imm] => [R(0), R(5), Ubits(16, 5)];
+    0b00001111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), Imm] => [R(0), R(5), Ubits(16, 3)], [Ex_BASE];
+    0b00001111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), Imm] => [R(0), R(5), Ubits(16, 4)], [Ex_BASE];
+    0b00001111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), Imm] => [R(0), R(5), Ubits(16, 5)], [Ex_BASE];
 ]
 "sshll2" = [
-    0b01001111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), Imm] => [R(0), R(5), Ubits(16, 3)];
-    0b01001111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), Imm] => [R(0), R(5), Ubits(16, 4)];
-    0b01001111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), Imm] => [R(0), R(5), Ubits(16, 5)];
+    0b01001111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), Imm] => [R(0), R(5), Ubits(16, 3)], [Ex_BASE];
+    0b01001111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), Imm] => [R(0), R(5), Ubits(16, 4)], [Ex_BASE];
+    0b01001111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), Imm] => [R(0), R(5), Ubits(16, 5)], [Ex_BASE];
 ]
 "sshr" = [
-    0b01011111_00000000_00000100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)];
-    0b00001111_00001000_00000100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)];
-    0b00001111_00010000_00000100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)];
-    0b00001111_00100000_00000100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)];
-    0b00001111_01000000_00000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)];
+    0b01011111_00000000_00000100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)], [Ex_BASE];
+    0b00001111_00001000_00000100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)], [Ex_BASE];
+    0b00001111_00010000_00000100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)], [Ex_BASE];
+    0b00001111_00100000_00000100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00001111_01000000_00000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)], [Ex_BASE];
 ]
 "ssra" = [
-    0b01011111_00000000_00010100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)];
-    0b00001111_00001000_00010100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)];
-    0b00001111_00010000_00010100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)];
-    0b00001111_00100000_00010100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)];
-    0b00001111_01000000_00010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)];
+    0b01011111_00000000_00010100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)], [Ex_BASE];
+    0b00001111_00001000_00010100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)], [Ex_BASE];
+    0b00001111_00010000_00010100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)], [Ex_BASE];
+    0b00001111_00100000_00010100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00001111_01000000_00010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)], [Ex_BASE];
 ]
 "ssubl" = [
-    0b00001110_00100000_00100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00001110_01100000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00001110_10100000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00001110_00100000_00100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_10100000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "ssubl2" = [
-    0b01001110_00100000_00100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01001110_01100000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01001110_00100000_00100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_01100000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_10100000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"ssublb" = [
+    0b01000101_01000000_00010000_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_00010000_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_00010000_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"ssublt" = [
+    0b01000101_01000000_00010100_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_00010100_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_00010100_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "ssubw" = [
-    0b00001110_00100000_00110000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00001110_01100000_00110000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00001110_10100000_00110000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00001110_00100000_00110000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_00110000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_10100000_00110000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "ssubw2" = [
-    0b01001110_00100000_00110000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01001110_01100000_00110000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_00110000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01001110_00100000_00110000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_01100000_00110000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_10100000_00110000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"ssubwb" = [
+    0b01000101_01000000_01010000_00000000 = [Z(B_2), Z(B_2), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_01010000_00000000 = [Z(B_4), Z(B_4), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_01010000_00000000 = [Z(B_8), Z(B_8), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"ssubwt" = [
+    0b01000101_01000000_01010100_00000000 = [Z(B_2), Z(B_2), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_01010100_00000000 = [Z(B_4), Z(B_4), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_01010100_00000000 = [Z(B_8), Z(B_8), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "st1" = [
     // ST1 (multiple structures)
-    0b00001100_00000000_01110000_00000000 = [RegList(1, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_01110100_00000000 = [RegList(1, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_01111000_00000000 = [RegList(1, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_01111100_00000000 = [RegList(1, B_8), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_10100000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_10100100_00000000 = [RegList(2, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_10101000_00000000 = [RegList(2, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_10101100_00000000 = [RegList(2, B_8), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_01100000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_01100100_00000000 = [RegList(3, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_01101000_00000000 = [RegList(3, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_01101100_00000000 = [RegList(3, B_8), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_00100000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_00100100_00000000 = [RegList(4, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_00101000_00000000 = [RegList(4, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_00101100_00000000 = [RegList(4, B_8), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_10011111_01110000_00000000 = [RegListStatic(1, BYTE, 8), RefBase, LitInt(8)] => [R(0), R(5)];
-    0b00001100_10011111_01110100_00000000 = [RegListStatic(1, B_2, 4), RefBase, LitInt(8)] => [R(0), R(5)];
-    0b00001100_10011111_01111000_00000000 = [RegListStatic(1, B_4, 2), RefBase, LitInt(8)] => [R(0), R(5)];
-    0b00001100_10011111_01111100_00000000 = [RegListStatic(1, B_8, 1), RefBase, LitInt(8)] => [R(0), R(5)];
-    0b01001100_10011111_01110000_00000000 = [RegListStatic(1, BYTE, 16), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b01001100_10011111_01110100_00000000 = [RegListStatic(1, B_2, 8), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b01001100_10011111_01111000_00000000 = [RegListStatic(1, B_4, 4), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b01001100_10011111_01111100_00000000 = [RegListStatic(1, B_8, 2), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b00001100_10000000_01110000_00000000 = [RegList(1, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_01110100_00000000 = [RegList(1, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_01111000_00000000 = [RegList(1, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_01111100_00000000 = [RegList(1, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10011111_10100000_00000000 = [RegListStatic(2, BYTE, 8), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b00001100_10011111_10100100_00000000 = [RegListStatic(2, B_2, 4), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b00001100_10011111_10101000_00000000 = [RegListStatic(2, B_4, 2), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b00001100_10011111_10101100_00000000 = [RegListStatic(2, B_8, 1), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b01001100_10011111_10100000_00000000 = [RegListStatic(2, BYTE, 16), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_10011111_10100100_00000000 = [RegListStatic(2, B_2, 8), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_10011111_10101000_00000000 = [RegListStatic(2, B_4, 4), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_10011111_10101100_00000000 = [RegListStatic(2, B_8, 2), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b00001100_10000000_10100000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_10100100_00000000 = [RegList(2, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_10101000_00000000 = [RegList(2, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_10101100_00000000 = [RegList(2, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10011111_01100000_00000000 = [RegListStatic(3, BYTE, 8), RefBase, LitInt(24)] => [R(0), R(5)];
-    0b00001100_10011111_01100100_00000000 = [RegListStatic(3, B_2, 4), RefBase, LitInt(24)] => [R(0), R(5)];
-    0b00001100_10011111_01101000_00000000 = [RegListStatic(3, B_4, 2), RefBase, LitInt(24)] => [R(0), R(5)];
-    0b00001100_10011111_01101100_00000000 = [RegListStatic(3, B_8, 1), RefBase, LitInt(24)] => [R(0), R(5)];
-    0b01001100_10011111_01100000_00000000 = [RegListStatic(3, BYTE, 16), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b01001100_10011111_01100100_00000000 = [RegListStatic(3, B_2, 8), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b01001100_10011111_01101000_00000000 = [RegListStatic(3, B_4, 4), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b01001100_10011111_01101100_00000000 = [RegListStatic(3, B_8, 2), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b00001100_10000000_01100000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_01100100_00000000 = [RegList(3, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_01101000_00000000 = [RegList(3, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_01101100_00000000 = [RegList(3, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10011111_00100000_00000000 = [RegListStatic(4, BYTE, 8), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b00001100_10011111_00100100_00000000 = [RegListStatic(4, B_2, 4), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b00001100_10011111_00101000_00000000 = [RegListStatic(4, B_4, 2), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b00001100_10011111_00101100_00000000 = [RegListStatic(4, B_8, 1), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_10011111_00100000_00000000 = [RegListStatic(4, BYTE, 16), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b01001100_10011111_00100100_00000000 = [RegListStatic(4, B_2, 8), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b01001100_10011111_00101000_00000000 = [RegListStatic(4, B_4, 4), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b01001100_10011111_00101100_00000000 = [RegListStatic(4, B_8, 2), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b00001100_10000000_00100000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_00100100_00000000 = [RegList(4, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_00101000_00000000 = [RegList(4, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_00101100_00000000 = [RegList(4, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_00000000_01110000_00000000 = [RegList(1, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_01110100_00000000 = [RegList(1, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_01111000_00000000 = [RegList(1, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_01111100_00000000 = [RegList(1, B_8), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_10100000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_10100100_00000000 = [RegList(2, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_10101000_00000000 = [RegList(2, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_10101100_00000000 = [RegList(2, B_8), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_01100000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_01100100_00000000 = [RegList(3, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_01101000_00000000 = [RegList(3, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_01101100_00000000 = [RegList(3, B_8), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_00100000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_00100100_00000000 = [RegList(4, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_00101000_00000000 = [RegList(4, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_00101100_00000000 = [RegList(4, B_8), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_10011111_01110000_00000000 = [RegListStatic(1, BYTE, 8), RefBase, LitInt(8)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_01110100_00000000 = [RegListStatic(1, B_2, 4), RefBase, LitInt(8)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_01111000_00000000 = [RegListStatic(1, B_4, 2), RefBase, LitInt(8)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_01111100_00000000 = [RegListStatic(1, B_8, 1), RefBase, LitInt(8)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_01110000_00000000 = [RegListStatic(1, BYTE, 16), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_01110100_00000000 = [RegListStatic(1, B_2, 8), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_01111000_00000000 = [RegListStatic(1, B_4, 4), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_01111100_00000000 = [RegListStatic(1, B_8, 2), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10000000_01110000_00000000 = [RegList(1, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_01110100_00000000 = [RegList(1, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_01111000_00000000 = [RegList(1, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_01111100_00000000 = [RegList(1, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10011111_10100000_00000000 = [RegListStatic(2, BYTE, 8), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_10100100_00000000 = [RegListStatic(2, B_2, 4), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_10101000_00000000 = [RegListStatic(2, B_4, 2), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_10101100_00000000 = [RegListStatic(2, B_8, 1), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_10100000_00000000 = [RegListStatic(2, BYTE, 16), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_10100100_00000000 = [RegListStatic(2, B_2, 8), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_10101000_00000000 = [RegListStatic(2, B_4, 4), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_10101100_00000000 = [RegListStatic(2, B_8, 2), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10000000_10100000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_10100100_00000000 = [RegList(2, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_10101000_00000000 = [RegList(2, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_10101100_00000000 = [RegList(2, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10011111_01100000_00000000 = [RegListStatic(3, BYTE, 8), RefBase, LitInt(24)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_01100100_00000000 = [RegListStatic(3, B_2, 4), RefBase, LitInt(24)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_01101000_00000000 = [RegListStatic(3, B_4, 2), RefBase, LitInt(24)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_01101100_00000000 = [RegListStatic(3, B_8, 1), RefBase, LitInt(24)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_01100000_00000000 = [RegListStatic(3, BYTE, 16), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_01100100_00000000 = [RegListStatic(3, B_2, 8), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_01101000_00000000 = [RegListStatic(3, B_4, 4), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_01101100_00000000 = [RegListStatic(3, B_8, 2), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10000000_01100000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_01100100_00000000 = [RegList(3, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_01101000_00000000 = [RegList(3, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_01101100_00000000 = [RegList(3, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10011111_00100000_00000000 = [RegListStatic(4, BYTE, 8), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_00100100_00000000 = [RegListStatic(4, B_2, 4), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_00101000_00000000 = [RegListStatic(4, B_4, 2), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_00101100_00000000 = [RegListStatic(4, B_8, 1), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_00100000_00000000 = [RegListStatic(4, BYTE, 16), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_00100100_00000000 = [RegListStatic(4, B_2, 8), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_00101000_00000000 = [RegListStatic(4, B_4, 4), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_00101100_00000000 = [RegListStatic(4, B_8, 2), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10000000_00100000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_00100100_00000000 = [RegList(4, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_00101000_00000000 = [RegList(4, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_00101100_00000000 = [RegList(4, B_8), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
     // ST1 (single structure)
-    0b00001101_00000000_00000000_00000000 = [RegListElement(1, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_00000000_01000000_00000000 = [RegListElement(1, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_00000000_10000000_00000000 = [RegListElement(1, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_00000000_10000100_00000000 = [RegListElement(1, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_10011111_00000000_00000000 = [RegListElement(1, BYTE), RefBase, LitInt(1)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_10000000_00000000_00000000 = [RegListElement(1, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
-    0b00001101_10011111_01000000_00000000 = [RegListElement(1, B_2), RefBase, LitInt(2)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_10000000_01000000_00000000 = [RegListElement(1, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
-    0b00001101_10011111_10000000_00000000 = [RegListElement(1, B_4), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_10000000_10000000_00000000 = [RegListElement(1, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
-    0b00001101_10011111_10000100_00000000 = [RegListElement(1, B_8), RefBase, LitInt(8)] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_10000000_10000100_00000000 = [RegListElement(1, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+    0b00001101_00000000_00000000_00000000 = [RegListElement(1, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_00000000_01000000_00000000 = [RegListElement(1, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_00000000_10000000_00000000 = [RegListElement(1, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_00000000_10000100_00000000 = [RegListElement(1, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_10011111_00000000_00000000 = [RegListElement(1, BYTE), RefBase, LitInt(1)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_10000000_00000000_00000000 = [RegListElement(1, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_10011111_01000000_00000000 = [RegListElement(1, B_2), RefBase, LitInt(2)] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_10000000_01000000_00000000 = [RegListElement(1, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_10011111_10000000_00000000 = [RegListElement(1, B_4), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_10000000_10000000_00000000 = [RegListElement(1, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_10011111_10000100_00000000 = [RegListElement(1, B_8), RefBase, LitInt(8)] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_10000000_10000100_00000000 = [RegListElement(1, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)], [Ex_BASE];
+]
+"st1b" = [
+    // ST1B (scalar plus immediate)
+    0b11100100_00000000_11100000_00000000 = [ZRegList(1, BYTE), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b11100100_00100000_11100000_00000000 = [ZRegList(1, B_2), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b11100100_01000000_11100000_00000000 = [ZRegList(1, B_4), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b11100100_01100000_11100000_00000000 = [ZRegList(1, B_8), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // ST1B (scalar plus scalar)
+    0b11100100_00000000_01000000_00000000 = [ZRegList(1, BYTE), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b11100100_00100000_01000000_00000000 = [ZRegList(1, B_2), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b11100100_01000000_01000000_00000000 = [ZRegList(1, B_4), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b11100100_01100000_01000000_00000000 = [ZRegList(1, B_8), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    // ST1B (scalar plus scalar, tile slice)
+    0b11100000_00100000_00000000_00000000 = [ZaSliceListH(BYTE), PBare, RefIndexLsl(0)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_00111111_00000000_00000000 = [ZaSliceListH(BYTE), PBare, RefBase] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], [Ex_SME];
+    0b11100000_00100000_10000000_00000000 = [ZaSliceListV(BYTE), PBare, RefIndexLsl(0)] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_00111111_10000000_00000000 = [ZaSliceListV(BYTE), PBare, RefBase] => [RTile(4, 0), RSelect(13), Ubits(0, 4), R3(10), R(5)], [Ex_SME];
+]
+"st1d" = [
+    // ST1D (scalar plus immediate)
+    0b11100101_11100000_11100000_00000000 = [ZRegList(1, B_8), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // ST1D (scalar plus scalar)
+    0b11100101_11100000_01000000_00000000 = [ZRegList(1, B_8), PBare, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    // ST1D (scalar plus scalar, tile slice)
+    0b11100000_11100000_00000000_00000000 = [ZaSliceListH(B_8), PBare, RefIndexLsl(3)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_11111111_00000000_00000000 = [ZaSliceListH(B_8), PBare, RefBase] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], [Ex_SME];
+    0b11100000_11100000_10000000_00000000 = [ZaSliceListV(B_8), PBare, RefIndexLsl(3)] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_11111111_10000000_00000000 = [ZaSliceListV(B_8), PBare, RefBase] => [RTile(1, 3), RSelect(13), Ubits(0, 1), R3(10), R(5)], [Ex_SME];
+]
+"st1h" = [
+    // ST1H (scalar plus immediate)
+    0b11100100_10100000_11100000_00000000 = [ZRegList(1, B_2), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b11100100_11000000_11100000_00000000 = [ZRegList(1, B_4), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b11100100_11100000_11100000_00000000 = [ZRegList(1, B_8), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // ST1H (scalar plus scalar)
+    0b11100100_10100000_01000000_00000000 = [ZRegList(1, B_2), PBare, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b11100100_11000000_01000000_00000000 = [ZRegList(1, B_4), PBare, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b11100100_11100000_01000000_00000000 = [ZRegList(1, B_8), PBare, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    // ST1H (scalar plus scalar, tile slice)
+    0b11100000_01100000_00000000_00000000 = [ZaSliceListH(B_2), PBare, RefIndexLsl(1)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_01111111_00000000_00000000 = [ZaSliceListH(B_2), PBare, RefBase] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], [Ex_SME];
+    0b11100000_01100000_10000000_00000000 = [ZaSliceListV(B_2), PBare, RefIndexLsl(1)] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_01111111_10000000_00000000 = [ZaSliceListV(B_2), PBare, RefBase] => [RTile(3, 1), RSelect(13), Ubits(0, 3), R3(10), R(5)], [Ex_SME];
+]
+"st1q" = [
+    0b11100001_11100000_00000000_00000000 = [ZaSliceListH(B_16), PBare, RefIndexLsl(4)] => [RTile(0, 4), RSelect(13), Ubits(0, 0), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100001_11111111_00000000_00000000 = [ZaSliceListH(B_16), PBare, RefBase] => [RTile(0, 4), RSelect(13), Ubits(0, 0), R3(10), R(5)], [Ex_SME];
+    0b11100001_11100000_10000000_00000000 = [ZaSliceListV(B_16), PBare, RefIndexLsl(4)] => [RTile(0, 4), RSelect(13), Ubits(0, 0), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100001_11111111_10000000_00000000 = [ZaSliceListV(B_16), PBare, RefBase] => [RTile(0, 4), RSelect(13), Ubits(0, 0), R3(10), R(5)], [Ex_SME];
+]
+"st1w" = [
+    // ST1W (scalar plus immediate)
+    0b11100101_01000000_11100000_00000000 = [ZRegList(1, B_4), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    0b11100101_01100000_11100000_00000000 = [ZRegList(1, B_8), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // ST1W (scalar plus scalar)
+    0b11100101_01000000_01000000_00000000 = [ZRegList(1, B_4), PBare, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    0b11100101_01100000_01000000_00000000 = [ZRegList(1, B_8), PBare, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+    // ST1W (scalar plus scalar, tile slice)
+    0b11100000_10100000_00000000_00000000 = [ZaSliceListH(B_4), PBare, RefIndexLsl(2)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_10111111_00000000_00000000 = [ZaSliceListH(B_4), PBare, RefBase] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], [Ex_SME];
+    0b11100000_10100000_10000000_00000000 = [ZaSliceListV(B_4), PBare, RefIndexLsl(2)] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5), RNoZr(16)], [Ex_SME];
+    0b11100000_10111111_10000000_00000000 = [ZaSliceListV(B_4), PBare, RefBase] => [RTile(2, 2), RSelect(13), Ubits(0, 2), R3(10), R(5)], [Ex_SME];
 ]
 "st2" = [
     // ST2 (multiple structures)
-    0b00001100_00000000_10000000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_10000100_00000000 = [RegList(2, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_10001000_00000000 = [RegList(2, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_10001100_00000000 = [RegListStatic(2, B_8, 2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_10011111_10000000_00000000 = [RegListStatic(2, BYTE, 8), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b00001100_10011111_10000100_00000000 = [RegListStatic(2, B_2, 4), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b00001100_10011111_10001000_00000000 = [RegListStatic(2, B_4, 2), RefBase, LitInt(16)] => [R(0), R(5)];
-    0b01001100_10011111_10000000_00000000 = [RegListStatic(2, BYTE, 16), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_10011111_10000100_00000000 = [RegListStatic(2, B_2, 8), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_10011111_10001000_00000000 = [RegListStatic(2, B_4, 4), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_10011111_10001100_00000000 = [RegListStatic(2, B_8, 2), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b00001100_10000000_10000000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_10000100_00000000 = [RegList(2, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_10001000_00000000 = [RegList(2, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_10001100_00000000 = [RegListStatic(2, B_8, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_00000000_10000000_00000000 = [RegList(2, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_10000100_00000000 = [RegList(2, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_10001000_00000000 = [RegList(2, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_10001100_00000000 = [RegListStatic(2, B_8, 2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_10011111_10000000_00000000 = [RegListStatic(2, BYTE, 8), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_10000100_00000000 = [RegListStatic(2, B_2, 4), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_10001000_00000000 = [RegListStatic(2, B_4, 2), RefBase, LitInt(16)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_10000000_00000000 = [RegListStatic(2, BYTE, 16), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_10000100_00000000 = [RegListStatic(2, B_2, 8), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_10001000_00000000 = [RegListStatic(2, B_4, 4), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_10001100_00000000 = [RegListStatic(2, B_8, 2), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10000000_10000000_00000000 = [RegList(2, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_10000100_00000000 = [RegList(2, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_10001000_00000000 = [RegList(2, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_10001100_00000000 = [RegListStatic(2, B_8, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
     // ST2 (single structure)
-    0b00001101_00100000_00000000_00000000 = [RegListElement(2, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_00100000_01000000_00000000 = [RegListElement(2, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_00100000_10000000_00000000 = [RegListElement(2, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_00100000_10000100_00000000 = [RegListElement(2, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_10111111_00000000_00000000 = [RegListElement(2, BYTE), RefBase, LitInt(2)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_10100000_00000000_00000000 = [RegListElement(2, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
-    0b00001101_10111111_01000000_00000000 = [RegListElement(2, B_2), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_10100000_01000000_00000000 = [RegListElement(2, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
-    0b00001101_10111111_10000000_00000000 = [RegListElement(2, B_4), RefBase, LitInt(8)] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_10100000_10000000_00000000 = [RegListElement(2, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
-    0b00001101_10111111_10000100_00000000 = [RegListElement(2, B_8), RefBase, LitInt(16)] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_10100000_10000100_00000000 = [RegListElement(2, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+    0b00001101_00100000_00000000_00000000 = [RegListElement(2, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_00100000_01000000_00000000 = [RegListElement(2, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_00100000_10000000_00000000 = [RegListElement(2, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_00100000_10000100_00000000 = [RegListElement(2, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_10111111_00000000_00000000 = [RegListElement(2, BYTE), RefBase, LitInt(2)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_10100000_00000000_00000000 = [RegListElement(2, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_10111111_01000000_00000000 = [RegListElement(2, B_2), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_10100000_01000000_00000000 = [RegListElement(2, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_10111111_10000000_00000000 = [RegListElement(2, B_4), RefBase, LitInt(8)] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_10100000_10000000_00000000 = [RegListElement(2, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_10111111_10000100_00000000 = [RegListElement(2, B_8), RefBase, LitInt(16)] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_10100000_10000100_00000000 = [RegListElement(2, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)], [Ex_BASE];
+]
+"st2b" = [
+    // ST2B (scalar plus immediate)
+    0b11100100_00110000_11100000_00000000 = [ZRegList(2, BYTE), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], [Ex_SVE];
+    // ST2B (scalar plus scalar)
+    0b11100100_00100000_01100000_00000000 = [ZRegList(2, BYTE), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"st2d" = [
+    // ST2D (scalar plus immediate)
+    0b11100101_10110000_11100000_00000000 = [ZRegList(2, B_8), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], [Ex_SVE];
+    // ST2D (scalar plus scalar)
+    0b11100101_10100000_01100000_00000000 = [ZRegList(2, B_8), PBare, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"st2g" = [
+    0b11011001_10100000_00000100_00000000 = [XSP, RefBase, Imm] => [R(0), R(5), Sscaled(12, 9, 4)], [Ex_MTE];
+    0b11011001_10100000_00001100_00000000 = [XSP, RefPre] => [R(0), R(5), Sscaled(12, 9, 4)], [Ex_MTE];
+    0b11011001_10100000_00001000_00000000 = [XSP, RefOffset] => [R(0), R(5), Sscaled(12, 9, 4)], [Ex_MTE];
+]
+"st2h" = [
+    // ST2H (scalar plus immediate)
+    0b11100100_10110000_11100000_00000000 = [ZRegList(2, B_2), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], [Ex_SVE];
+    // ST2H (scalar plus scalar)
+    0b11100100_10100000_01100000_00000000 = [ZRegList(2, B_2), PBare, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"st2w" = [
+    // ST2W (scalar plus immediate)
+    0b11100101_00110000_11100000_00000000 = [ZRegList(2, B_4), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 1)], [Ex_SVE];
+    // ST2W (scalar plus scalar)
+    0b11100101_00100000_01100000_00000000 = [ZRegList(2, B_4), PBare, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
 ]
 "st3" = [
     // ST3 (multiple structures)
-    0b00001100_00000000_01000000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_01000100_00000000 = [RegList(3, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_01001000_00000000 = [RegList(3, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_01001100_00000000 = [RegListStatic(3, B_8, 2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_10011111_01000000_00000000 = [RegListStatic(3, BYTE, 8), RefBase, LitInt(24)] => [R(0), R(5)];
-    0b00001100_10011111_01000100_00000000 = [RegListStatic(3, B_2, 4), RefBase, LitInt(24)] => [R(0), R(5)];
-    0b00001100_10011111_01001000_00000000 = [RegListStatic(3, B_4, 2), RefBase, LitInt(24)] => [R(0), R(5)];
-    0b01001100_10011111_01000000_00000000 = [RegListStatic(3, BYTE, 16), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b01001100_10011111_01000100_00000000 = [RegListStatic(3, B_2, 8), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b01001100_10011111_01001000_00000000 = [RegListStatic(3, B_4, 4), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b01001100_10011111_01001100_00000000 = [RegListStatic(3, B_8, 2), RefBase, LitInt(48)] => [R(0), R(5)];
-    0b00001100_10000000_01000000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_01000100_00000000 = [RegList(3, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_01001000_00000000 = [RegList(3, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_01001100_00000000 = [RegListStatic(3, B_8, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_00000000_01000000_00000000 = [RegList(3, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_01000100_00000000 = [RegList(3, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_01001000_00000000 = [RegList(3, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_01001100_00000000 = [RegListStatic(3, B_8, 2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_10011111_01000000_00000000 = [RegListStatic(3, BYTE, 8), RefBase, LitInt(24)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_01000100_00000000 = [RegListStatic(3, B_2, 4), RefBase, LitInt(24)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_01001000_00000000 = [RegListStatic(3, B_4, 2), RefBase, LitInt(24)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_01000000_00000000 = [RegListStatic(3, BYTE, 16), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_01000100_00000000 = [RegListStatic(3, B_2, 8), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_01001000_00000000 = [RegListStatic(3, B_4, 4), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_01001100_00000000 = [RegListStatic(3, B_8, 2), RefBase, LitInt(48)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10000000_01000000_00000000 = [RegList(3, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_01000100_00000000 = [RegList(3, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_01001000_00000000 = [RegList(3, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_01001100_00000000 = [RegListStatic(3, B_8, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
     // ST3 (single structure)
-    0b00001101_00000000_00100000_00000000 = [RegListElement(3, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_00000000_01100000_00000000 = [RegListElement(3, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_00000000_10100000_00000000 = [RegListElement(3, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_00000000_10100100_00000000 = [RegListElement(3, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_10011111_00100000_00000000 = [RegListElement(3, BYTE), RefBase, LitInt(3)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_10000000_00100000_00000000 = [RegListElement(3, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
-    0b00001101_10011111_01100000_00000000 = [RegListElement(3, B_2), RefBase, LitInt(6)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_10000000_01100000_00000000 = [RegListElement(3, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
-    0b00001101_10011111_10100000_00000000 = [RegListElement(3, B_4), RefBase, LitInt(12)] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_10000000_10100000_00000000 = [RegListElement(3, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
-    0b00001101_10011111_10100100_00000000 = [RegListElement(3, B_8), RefBase, LitInt(24)] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_10000000_10100100_00000000 = [RegListElement(3, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+    0b00001101_00000000_00100000_00000000 = [RegListElement(3, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_00000000_01100000_00000000 = [RegListElement(3, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_00000000_10100000_00000000 = [RegListElement(3, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_00000000_10100100_00000000 = [RegListElement(3, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_10011111_00100000_00000000 = [RegListElement(3, BYTE), RefBase, LitInt(3)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_10000000_00100000_00000000 = [RegListElement(3, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_10011111_01100000_00000000 = [RegListElement(3, B_2), RefBase, LitInt(6)] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_10000000_01100000_00000000 = [RegListElement(3, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_10011111_10100000_00000000 = [RegListElement(3, B_4), RefBase, LitInt(12)] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_10000000_10100000_00000000 = [RegListElement(3, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_10011111_10100100_00000000 = [RegListElement(3, B_8), RefBase, LitInt(24)] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_10000000_10100100_00000000 = [RegListElement(3, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)], [Ex_BASE];
+]
+"st3b" = [
+    0b11100100_01000000_01100000_00000000 = [ZRegList(3, BYTE), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"st3d" = [
+    0b11100101_11000000_01100000_00000000 = [ZRegList(3, B_8), PBare, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"st3h" = [
+    0b11100100_11000000_01100000_00000000 = [ZRegList(3, B_2), PBare, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"st3w" = [
+    0b11100101_01000000_01100000_00000000 = [ZRegList(3, B_4), PBare, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
 ]
 "st4" = [
     // ST4 (multiple structures)
-    0b00001100_00000000_00000000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_00000100_00000000 = [RegList(4, B_2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_00001000_00000000 = [RegList(4, B_4), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_00000000_00001100_00000000 = [RegListStatic(4, B_8, 2), RefBase] => [R(0), R(5), Rwidth(30)];
-    0b00001100_10011111_00000000_00000000 = [RegListStatic(4, BYTE, 8), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b00001100_10011111_00000100_00000000 = [RegListStatic(4, B_2, 4), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b00001100_10011111_00001000_00000000 = [RegListStatic(4, B_4, 2), RefBase, LitInt(32)] => [R(0), R(5)];
-    0b01001100_10011111_00000000_00000000 = [RegListStatic(4, BYTE, 16), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b01001100_10011111_00000100_00000000 = [RegListStatic(4, B_2, 8), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b01001100_10011111_00001000_00000000 = [RegListStatic(4, B_4, 4), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b01001100_10011111_00001100_00000000 = [RegListStatic(4, B_8, 2), RefBase, LitInt(64)] => [R(0), R(5)];
-    0b00001100_10000000_00000000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_00000100_00000000 = [RegList(4, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_00001000_00000000 = [RegList(4, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
-    0b00001100_10000000_00001100_00000000 = [RegListStatic(4, B_8, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
+    0b00001100_00000000_00000000_00000000 = [RegList(4, BYTE), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_00000100_00000000 = [RegList(4, B_2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_00001000_00000000 = [RegList(4, B_4), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_00000000_00001100_00000000 = [RegListStatic(4, B_8, 2), RefBase] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001100_10011111_00000000_00000000 = [RegListStatic(4, BYTE, 8), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_00000100_00000000 = [RegListStatic(4, B_2, 4), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10011111_00001000_00000000 = [RegListStatic(4, B_4, 2), RefBase, LitInt(32)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_00000000_00000000 = [RegListStatic(4, BYTE, 16), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_00000100_00000000 = [RegListStatic(4, B_2, 8), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_00001000_00000000 = [RegListStatic(4, B_4, 4), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b01001100_10011111_00001100_00000000 = [RegListStatic(4, B_8, 2), RefBase, LitInt(64)] => [R(0), R(5)], [Ex_BASE];
+    0b00001100_10000000_00000000_00000000 = [RegList(4, BYTE), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_00000100_00000000 = [RegList(4, B_2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_00001000_00000000 = [RegList(4, B_4), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
+    0b00001100_10000000_00001100_00000000 = [RegListStatic(4, B_8, 2), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)], [Ex_BASE];
     // ST4 (single structure)
-    0b00001101_00100000_00100000_00000000 = [RegListElement(4, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_00100000_01100000_00000000 = [RegListElement(4, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_00100000_10100000_00000000 = [RegListElement(4, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_00100000_10100100_00000000 = [RegListElement(4, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_10111111_00100000_00000000 = [RegListElement(4, BYTE), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)];
-    0b00001101_10100000_00100000_00000000 = [RegListElement(4, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)];
-    0b00001101_10111111_01100000_00000000 = [RegListElement(4, B_2), RefBase, LitInt(8)] => [R(0), Ufields(&[30, 12, 11]), R(5)];
-    0b00001101_10100000_01100000_00000000 = [RegListElement(4, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)];
-    0b00001101_10111111_10100000_00000000 = [RegListElement(4, B_4), RefBase, LitInt(16)] => [R(0), Ufields(&[30, 12]), R(5)];
-    0b00001101_10100000_10100000_00000000 = [RegListElement(4, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)];
-    0b00001101_10111111_10100100_00000000 = [RegListElement(4, B_8), RefBase, LitInt(32)] => [R(0), Ufields(&[30]), R(5)];
-    0b00001101_10100000_10100100_00000000 = [RegListElement(4, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
+    0b00001101_00100000_00100000_00000000 = [RegListElement(4, BYTE), RefBase] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_00100000_01100000_00000000 = [RegListElement(4, B_2), RefBase] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_00100000_10100000_00000000 = [RegListElement(4, B_4), RefBase] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_00100000_10100100_00000000 = [RegListElement(4, B_8), RefBase] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_10111111_00100000_00000000 = [RegListElement(4, BYTE), RefBase, LitInt(4)] => [R(0), Ufields(&[30, 12, 11, 10]), R(5)], [Ex_BASE];
+    0b00001101_10100000_00100000_00000000 = [RegListElement(4, BYTE), RefBase, X] => [R(0), Ufields(&[30, 12, 11, 10]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_10111111_01100000_00000000 = [RegListElement(4, B_2), RefBase, LitInt(8)] => [R(0), Ufields(&[30, 12, 11]), R(5)], [Ex_BASE];
+    0b00001101_10100000_01100000_00000000 = [RegListElement(4, B_2), RefBase, X] => [R(0), Ufields(&[30, 12, 11]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_10111111_10100000_00000000 = [RegListElement(4, B_4), RefBase, LitInt(16)] => [R(0), Ufields(&[30, 12]), R(5)], [Ex_BASE];
+    0b00001101_10100000_10100000_00000000 = [RegListElement(4, B_4), RefBase, X] => [R(0), Ufields(&[30, 12]), R(5), RNoZr(16)], [Ex_BASE];
+    0b00001101_10111111_10100100_00000000 = [RegListElement(4, B_8), RefBase, LitInt(32)] => [R(0), Ufields(&[30]), R(5)], [Ex_BASE];
+    0b00001101_10100000_10100100_00000000 = [RegListElement(4, B_8), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)], [Ex_BASE];
+]
+"st4b" = [
+    // ST4B (scalar plus immediate)
+    0b11100100_01110000_11100000_00000000 = [ZRegList(4, BYTE), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], [Ex_SVE];
+    // ST4B (scalar plus scalar)
+    0b11100100_01100000_01100000_00000000 = [ZRegList(4, BYTE), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"st4d" = [
+    // ST4D (scalar plus immediate)
+    0b11100101_11110000_11100000_00000000 = [ZRegList(4, B_8), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], [Ex_SVE];
+    // ST4D (scalar plus scalar)
+    0b11100101_11100000_01100000_00000000 = [ZRegList(4, B_8), PBare, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"st4h" = [
+    // ST4H (scalar plus immediate)
+    0b11100100_11110000_11100000_00000000 = [ZRegList(4, B_2), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], [Ex_SVE];
+    // ST4H (scalar plus scalar)
+    0b11100100_11100000_01100000_00000000 = [ZRegList(4, B_2), PBare, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"st4w" = [
+    // ST4W (scalar plus immediate)
+    0b11100101_01110000_11100000_00000000 = [ZRegList(4, B_4), PBare, RefMulVl] => [R(0), R3(10), R(5), Sscaled(16, 4, 2)], [Ex_SVE];
+    // ST4W (scalar plus scalar)
+    0b11100101_01100000_01100000_00000000 = [ZRegList(4, B_4), PBare, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
 ]
 "stadd" = [
-    0b10111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_00000000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_00100000_00000000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "staddb" = [
-    0b00111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "staddh" = [
-    0b01111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "staddl" = [
-    0b10111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_00000000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_01100000_00000000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "staddlb" = [
-    0b00111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "staddlh" = [
-    0b01111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stclr" = [
-    0b10111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_00010000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_00100000_00010000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stclrb" = [
-    0b00111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stclrh" = [
-    0b01111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stclrl" = [
-    0b10111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_00010000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_01100000_00010000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stclrlb" = [
-    0b00111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stclrlh" = [
-    0b01111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "steor" = [
-    0b10111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_00100000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_00100000_00100000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "steorb" = [
-    0b00111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "steorh" = [
-    0b01111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "steorl" = [
-    0b10111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_00100000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_01100000_00100000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "steorlb" = [
-    0b00111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "steorlh" = [
-    0b01111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+]
+"stg" = [
+    0b11011001_00100000_00000100_00000000 = [XSP, RefBase, Imm] => [R(0), R(5), Sscaled(12, 9, 4)], [Ex_MTE];
+    0b11011001_00100000_00001100_00000000 = [XSP, RefPre] => [R(0), R(5), Sscaled(12, 9, 4)], [Ex_MTE];
+    0b11011001_00100000_00001000_00000000 = [XSP, RefOffset] => [R(0), R(5), Sscaled(12, 9, 4)], [Ex_MTE];
+]
+"stgm" = [
+    0b11011001_10100000_00000000_00000000 = [X, RefBase] => [R(0), R(5)], [Ex_MTE];
+]
+"stgp" = [
+    0b01101000_10000000_00000000_00000000 = [X, X, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 4)], [Ex_MTE];
+    0b01101001_10000000_00000000_00000000 = [X, X, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 4)], [Ex_MTE];
+    0b01101001_00000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 4)], [Ex_MTE];
 ]
 "stllr" = [
-    0b10001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
-    0b11001000_10011111_01111100_00000000 = [X, RefBase] => [R(0), R(5)];
+    0b10001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_LOR];
+    0b11001000_10011111_01111100_00000000 = [X, RefBase] => [R(0), R(5)], [Ex_LOR];
 ]
 "stllrb" = [
-    0b00001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b00001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_LOR];
 ]
 "stllrh" = [
-    0b01001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b01001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_LOR];
 ]
 "stlr" = [
-    0b10001000_10011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
-    0b11001000_10011111_11111100_00000000 = [X, RefBase] => [R(0), R(5)];
+    0b10001000_10011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_BASE];
+    0b11001000_10011111_11111100_00000000 = [X, RefBase] => [R(0), R(5)], [Ex_BASE];
 ]
 "stlrb" = [
-    0b00001000_10011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b00001000_10011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_BASE];
 ]
 "stlrh" = [
-    0b01001000_10011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b01001000_10011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)], [Ex_BASE];
 ]
 "stlur" = [
-    0b10011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b11011001_00000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b10011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_RCPC2];
+    0b11011001_00000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_RCPC2];
 ]
 "stlurb" = [
-    0b00011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_RCPC2];
 ]
 "stlurh" = [
-    0b01011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_RCPC2];
 ]
 "stlxp" = [
-    0b10001000_00100000_10000000_00000000 = [W, W, W, RefBase] => [R(16), R(0), R(10), R(5)];
-    0b11001000_00100000_10000000_00000000 = [W, X, X, RefBase] => [R(16), R(0), R(10), R(5)];
+    0b10001000_00100000_10000000_00000000 = [W, W, W, RefBase] => [R(16), R(0), R(10), R(5)], [Ex_BASE];
+    0b11001000_00100000_10000000_00000000 = [W, X, X, RefBase] => [R(16), R(0), R(10), R(5)], [Ex_BASE];
 ]
 "stlxr" = [
-    0b10001000_00000000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11001000_00000000_11111100_00000000 = [W, X, RefBase] => [R(16), R(0), R(5)];
+    0b10001000_00000000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_BASE];
+    0b11001000_00000000_11111100_00000000 = [W, X, RefBase] => [R(16), R(0), R(5)], [Ex_BASE];
 ]
 "stlxrb" = [
-    0b00001000_00000000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00001000_00000000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_BASE];
 ]
 "stlxrh" = [
-    0b01001000_00000000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01001000_00000000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_BASE];
 ]
 "stnp" = [
     // STNP (SIMD&FP)
-    0b00101100_00000000_00000000_00000000 = [S, S, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b01101100_00000000_00000000_00000000 = [D, D, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
-    0b10101100_00000000_00000000_00000000 = [Q, Q, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
+    0b00101100_00000000_00000000_00000000 = [S, S, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b01101100_00000000_00000000_00000000 = [D, D, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
+    0b10101100_00000000_00000000_00000000 = [Q, Q, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 4)], [Ex_BASE];
     // STNP
-    0b00101000_00000000_00000000_00000000 = [W, W, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b10101000_00000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b00101000_00000000_00000000_00000000 = [W, W, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b10101000_00000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
+]
+"stnt1b" = [
+    // STNT1B (scalar plus immediate)
+    0b11100100_00010000_11100000_00000000 = [ZRegList(1, BYTE), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // STNT1B (scalar plus scalar)
+    0b11100100_00000000_01100000_00000000 = [ZRegList(1, BYTE), PBare, RefIndexLsl(0)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"stnt1d" = [
+    // STNT1D (scalar plus immediate)
+    0b11100101_10010000_11100000_00000000 = [ZRegList(1, B_8), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // STNT1D (scalar plus scalar)
+    0b11100101_10000000_01100000_00000000 = [ZRegList(1, B_8), PBare, RefIndexLsl(3)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"stnt1h" = [
+    // STNT1H (scalar plus immediate)
+    0b11100100_10010000_11100000_00000000 = [ZRegList(1, B_2), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // STNT1H (scalar plus scalar)
+    0b11100100_10000000_01100000_00000000 = [ZRegList(1, B_2), PBare, RefIndexLsl(1)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
+]
+"stnt1w" = [
+    // STNT1W (scalar plus immediate)
+    0b11100101_00010000_11100000_00000000 = [ZRegList(1, B_4), PBare, RefMulVl] => [R(0), R3(10), R(5), Sbits(16, 4)], [Ex_SVE];
+    // STNT1W (scalar plus scalar)
+    0b11100101_00000000_01100000_00000000 = [ZRegList(1, B_4), PBare, RefIndexLsl(2)] => [R(0), R3(10), R(5), RNoZr(16)], [Ex_SVE];
 ]
 "stp" = [
     // STP (SIMD&FP)
-    0b00101100_10000000_00000000_00000000 = [S, S, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b01101100_10000000_00000000_00000000 = [D, D, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
-    0b10101100_10000000_00000000_00000000 = [Q, Q, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
-    0b00101101_10000000_00000000_00000000 = [S, S, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b01101101_10000000_00000000_00000000 = [D, D, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
-    0b10101101_10000000_00000000_00000000 = [Q, Q, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
-    0b00101101_00000000_00000000_00000000 = [S, S, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b01101101_00000000_00000000_00000000 = [D, D, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
-    0b10101101_00000000_00000000_00000000 = [Q, Q, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 4)];
+    0b00101100_10000000_00000000_00000000 = [S, S, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b01101100_10000000_00000000_00000000 = [D, D, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
+    0b10101100_10000000_00000000_00000000 = [Q, Q, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 4)], [Ex_BASE];
+    0b00101101_10000000_00000000_00000000 = [S, S, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b01101101_10000000_00000000_00000000 = [D, D, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
+    0b10101101_10000000_00000000_00000000 = [Q, Q, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 4)], [Ex_BASE];
+    0b00101101_00000000_00000000_00000000 = [S, S, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b01101101_00000000_00000000_00000000 = [D, D, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
+    0b10101101_00000000_00000000_00000000 = [Q, Q, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 4)], [Ex_BASE];
     // STP
-    0b00101000_10000000_00000000_00000000 = [W, W, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b10101000_10000000_00000000_00000000 = [X, X, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
-    0b00101001_10000000_00000000_00000000 = [W, W, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b10101001_10000000_00000000_00000000 = [X, X, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
-    0b00101001_00000000_00000000_00000000 = [W, W, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)];
-    0b10101001_00000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)];
+    0b00101000_10000000_00000000_00000000 = [W, W, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b10101000_10000000_00000000_00000000 = [X, X, RefBase, Imm] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
+    0b00101001_10000000_00000000_00000000 = [W, W, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b10101001_10000000_00000000_00000000 = [X, X, RefPre] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
+    0b00101001_00000000_00000000_00000000 = [W, W, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 2)], [Ex_BASE];
+    0b10101001_00000000_00000000_00000000 = [X, X, RefOffset] => [R(0), R(10), R(5), Sscaled(15, 7, 3)], [Ex_BASE];
 ]
 "str" = [
     // STR (immediate, SIMD&FP)
-    0b00111100_00000000_00000100_00000000 = [B, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b01111100_00000000_00000100_00000000 = [H, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b10111100_00000000_00000100_00000000 = [S, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b11111100_00000000_00000100_00000000 = [D, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b00111100_10000000_00000100_00000000 = [Q, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b00111100_00000000_00001100_00000000 = [B, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b01111100_00000000_00001100_00000000 = [H, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b10111100_00000000_00001100_00000000 = [S, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b11111100_00000000_00001100_00000000 = [D, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b00111100_10000000_00001100_00000000 = [Q, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b00111101_00000000_00000000_00000000 = [B, RefOffset] => [R(0), R(5), Ubits(10, 12)];
-    0b01111101_00000000_00000000_00000000 = [H, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)];
-    0b10111101_00000000_00000000_00000000 = [S, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)];
-    0b11111101_00000000_00000000_00000000 = [D, RefOffset] => [R(0), R(5), Uscaled(10, 12, 3)];
-    0b00111101_10000000_00000000_00000000 = [Q, RefOffset] => [R(0), R(5), Uscaled(10, 12, 4)];
+    0b00111100_00000000_00000100_00000000 = [B, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111100_00000000_00000100_00000000 = [H, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b10111100_00000000_00000100_00000000 = [S, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b11111100_00000000_00000100_00000000 = [D, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111100_10000000_00000100_00000000 = [Q, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111100_00000000_00001100_00000000 = [B, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111100_00000000_00001100_00000000 = [H, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b10111100_00000000_00001100_00000000 = [S, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b11111100_00000000_00001100_00000000 = [D, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111100_10000000_00001100_00000000 = [Q, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111101_00000000_00000000_00000000 = [B, RefOffset] => [R(0), R(5), Ubits(10, 12)], [Ex_BASE];
+    0b01111101_00000000_00000000_00000000 = [H, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)], [Ex_BASE];
+    0b10111101_00000000_00000000_00000000 = [S, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)], [Ex_BASE];
+    0b11111101_00000000_00000000_00000000 = [D, RefOffset] => [R(0), R(5), Uscaled(10, 12, 3)], [Ex_BASE];
+    0b00111101_10000000_00000000_00000000 = [Q, RefOffset] => [R(0), R(5), Uscaled(10, 12, 4)], [Ex_BASE];
     // STR (immediate)
-    0b10111000_00000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b11111000_00000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b10111000_00000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b11111000_00000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b10111001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)];
-    0b11111001_00000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Uscaled(10, 12, 3)];
+    0b10111000_00000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b11111000_00000000_00000100_00000000 = [X, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b10111000_00000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b11111000_00000000_00001100_00000000 = [X, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b10111001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 2)], [Ex_BASE];
+    0b11111001_00000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Uscaled(10, 12, 3)], [Ex_BASE];
     // STR (register, SIMD&FP)
-    0b00111100_00100000_00001000_00000000 = [B, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])];
-    0b01111100_00100000_00001000_00000000 = [H, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])];
-    0b10111100_00100000_00001000_00000000 = [S, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])];
-    0b11111100_00100000_00001000_00000000 = [D, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])];
-    0b00111100_10100000_00001000_00000000 = [Q, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 4])];
+    0b00111100_00100000_00001000_00000000 = [B, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])], [Ex_BASE];
+    0b01111100_00100000_00001000_00000000 = [H, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])], [Ex_BASE];
+    0b10111100_00100000_00001000_00000000 = [S, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])], [Ex_BASE];
+    0b11111100_00100000_00001000_00000000 = [D, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])], [Ex_BASE];
+    0b00111100_10100000_00001000_00000000 = [Q, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 4])], [Ex_BASE];
     // STR (register)
-    0b10111000_00100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])];
-    0b11111000_00100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])];
+    0b10111000_00100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])], [Ex_BASE];
+    0b11111000_00100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])], [Ex_BASE];
+    // STR (vector)
+    0b11100101_10000000_01000000_00000000 = [ZBare, RefMulVl] => [R(0), R(5), CSscaled(9, 0), Sslice(10, 3, 0), Sslice(16, 6, 3), A], [Ex_SVE];
+    // STR (predicate)
+    0b11100101_10000000_00000000_00000000 = [PBare, RefMulVl] => [R4(0), R(5), CSscaled(9, 0), Sslice(10, 3, 0), Sslice(16, 6, 3), A], [Ex_SVE];
+    // STR (array vector)
+    0b11100001_00100000_00000000_00000000 = [ZaVector, RefMulVl] => [RSelect(13), Ubits(0, 4), R(5), CUeq(2), A], [Ex_SME];
 ]
 "strb" = [
     // STRB (immediate)
-    0b00111000_00000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b00111000_00000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b00111001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Ubits(10, 12)];
+    0b00111000_00000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111000_00000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Ubits(10, 12)], [Ex_BASE];
     // STRB (register)
-    0b00111000_00100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])];
+    0b00111000_00100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 0])], [Ex_BASE];
 ]
 "strh" = [
     // STRH (immediate)
-    0b01111000_00000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)];
-    0b01111000_00000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)];
-    0b01111001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)];
+    0b01111000_00000000_00000100_00000000 = [W, RefBase, Imm] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111000_00000000_00001100_00000000 = [W, RefPre] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Uscaled(10, 12, 1)], [Ex_BASE];
     // STRH (register)
-    0b01111000_00100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])];
+    0b01111000_00100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])], [Ex_BASE];
 ]
 "stset" = [
-    0b10111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_00110000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_00100000_00110000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsetb" = [
-    0b00111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stseth" = [
-    0b01111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsetl" = [
-    0b10111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_00110000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_01100000_00110000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsetlb" = [
-    0b00111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsetlh" = [
-    0b01111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsmax" = [
-    0b10111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_01000000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_00100000_01000000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsmaxb" = [
-    0b00111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsmaxh" = [
-    0b01111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsmaxl" = [
-    0b10111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_01000000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_01100000_01000000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsmaxlb" = [
-    0b00111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsmaxlh" = [
-    0b01111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsmin" = [
-    0b10111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_01010000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_00100000_01010000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsminb" = [
-    0b00111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsminh" = [
-    0b01111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsminl" = [
-    0b10111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_01010000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_01100000_01010000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsminlb" = [
-    0b00111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stsminlh" = [
-    0b01111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "sttr" = [
-    0b10111000_00000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b11111000_00000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b10111000_00000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b11111000_00000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "sttrb" = [
-    0b00111000_00000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00111000_00000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "sttrh" = [
-    0b01111000_00000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01111000_00000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "stumax" = [
-    0b10111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_01100000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_00100000_01100000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stumaxb" = [
-    0b00111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stumaxh" = [
-    0b01111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stumaxl" = [
-    0b10111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_01100000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_01100000_01100000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stumaxlb" = [
-    0b00111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stumaxlh" = [
-    0b01111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stumin" = [
-    0b10111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_01110000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_00100000_01110000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stuminb" = [
-    0b00111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stuminh" = [
-    0b01111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stuminl" = [
-    0b10111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_01110000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
+    0b11111000_01100000_01110000_00011111 = [X, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stuminlb" = [
-    0b00111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stuminlh" = [
-    0b01111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], [Ex_LSE];
 ]
 "stur" = [
     // STUR (SIMD&FP)
-    0b00111100_00000000_00000000_00000000 = [B, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b01111100_00000000_00000000_00000000 = [H, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b10111100_00000000_00000000_00000000 = [S, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b11111100_00000000_00000000_00000000 = [D, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b00111100_10000000_00000000_00000000 = [Q, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00111100_00000000_00000000_00000000 = [B, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b01111100_00000000_00000000_00000000 = [H, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b10111100_00000000_00000000_00000000 = [S, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b11111100_00000000_00000000_00000000 = [D, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b00111100_10000000_00000000_00000000 = [Q, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
     // STUR
-    0b10111000_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b11111000_00000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b10111000_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
+    0b11111000_00000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "sturb" = [
-    0b00111000_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00111000_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "sturh" = [
-    0b01111000_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01111000_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], [Ex_BASE];
 ]
 "stxp" = [
-    0b10001000_00100000_00000000_00000000 = [W, W, W, RefBase] => [R(16), R(0), R(10), R(5)];
-    0b11001000_00100000_00000000_00000000 = [W, X, X, RefBase] => [R(16), R(0), R(10), R(5)];
+    0b10001000_00100000_00000000_00000000 = [W, W, W, RefBase] => [R(16), R(0), R(10), R(5)], [Ex_BASE];
+    0b11001000_00100000_00000000_00000000 = [W, X, X, RefBase] => [R(16), R(0), R(10), R(5)], [Ex_BASE];
 ]
 "stxr" = [
-    0b10001000_00000000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11001000_00000000_01111100_00000000 = [W, X, RefBase] => [R(16), R(0), R(5)];
+    0b10001000_00000000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_BASE];
+    0b11001000_00000000_01111100_00000000 = [W, X, RefBase] => [R(16), R(0), R(5)], [Ex_BASE];
 ]
 "stxrb" = [
-    0b00001000_00000000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00001000_00000000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_BASE];
 ]
 "stxrh" = [
-    0b01001000_00000000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01001000_00000000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_BASE];
+]
+"stz2g" = [
+    0b11011001_11100000_00000100_00000000 = [XSP, RefBase, Imm] => [R(0), R(5), Sscaled(12, 9, 4)], [Ex_MTE];
+    0b11011001_11100000_00001100_00000000 = [XSP, RefPre] => [R(0), R(5), Sscaled(12, 9, 4)], [Ex_MTE];
+    0b11011001_11100000_00001000_00000000 = [XSP, RefOffset] => [R(0), R(5), Sscaled(12, 9, 4)], [Ex_MTE];
+]
+"stzg" = [
+    0b11011001_01100000_00000100_00000000 = [XSP, RefBase, Imm] => [R(0), R(5), Sscaled(12, 9, 4)], [Ex_MTE];
+    0b11011001_01100000_00001100_00000000 = [XSP, RefPre] => [R(0), R(5), Sscaled(12, 9, 4)], [Ex_MTE];
+    0b11011001_01100000_00001000_00000000 = [XSP, RefOffset] => [R(0), R(5), Sscaled(12, 9, 4)], [Ex_MTE];
+]
+"stzgm" = [
+    0b11011001_00100000_00000000_00000000 = [X, RefBase] => [R(0), R(5)], [Ex_MTE];
 ]
 "sub" = [
     // SUB (shifted register)
-    0b01001011_00000000_00000000_00000000 = [W, W, W, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b11001011_00000000_00000000_00000000 = [X, X, X, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b01001011_00000000_00000000_00000000 = [W, W, W, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b11001011_00000000_00000000_00000000 = [X, X, X, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
     // SUB (extended register)
-    0b01001011_00100000_00000000_00000000 = [WSP, WSP, W, End, Mod(EXTENDS)] => [R(0), R(5), R(16), ExtendsW(13), Urange(10, 0, 4)];
-    0b11001011_00100000_00000000_00000000 = [XSP, XSP, W, End, Mod(EXTENDS_W)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
-    0b11001011_00100000_00000000_00000000 = [XSP, XSP, X, End, Mod(EXTENDS_X)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    0b01001011_00100000_00000000_00000000 = [WSP, WSP, W, End, Mod(EXTENDS)] => [R(0), R(5), R(16), ExtendsW(13), Urange(10, 0, 4)], [Ex_BASE];
+    0b11001011_00100000_00000000_00000000 = [XSP, XSP, W, End, Mod(EXTENDS_W)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)], [Ex_BASE];
+    0b11001011_00100000_00000000_00000000 = [XSP, XSP, X, End, Mod(EXTENDS_X)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)], [Ex_BASE];
     // SUB (immediate)
-    0b01010001_00000000_00000000_00000000 = [WSP, WSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
-    0b11010001_00000000_00000000_00000000 = [XSP, XSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+    0b01010001_00000000_00000000_00000000 = [WSP, WSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])], [Ex_BASE];
+    0b11010001_00000000_00000000_00000000 = [XSP, XSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])], [Ex_BASE];
     // SUB (vector)
-    0b01111110_11100000_10000100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_00100000_10000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_10000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_10000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_10000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_11100000_10000100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_00100000_10000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_10000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_10000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_10000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // SUB (vectors, predicated)
+    0b00000100_00000001_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SUB (vectors, predicated)
+    0b00000100_01000001_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SUB (vectors, predicated)
+    0b00000100_10000001_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SUB (vectors, predicated)
+    0b00000100_11000001_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SUB (vectors, unpredicated)
+    0b00000100_00100000_00000100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // SUB (vectors, unpredicated)
+    0b00000100_01100000_00000100_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // SUB (vectors, unpredicated)
+    0b00000100_10100000_00000100_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // SUB (vectors, unpredicated)
+    0b00000100_11100000_00000100_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // SUB (immediate)
+    0b00100101_00100001_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
+    0b00100101_01100001_11000000_00000000 = [Z(B_2), Z(B_2), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_10100001_11000000_00000000 = [Z(B_4), Z(B_4), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_11100001_11000000_00000000 = [Z(B_8), Z(B_8), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+]
+"subg" = [
+    0b11010001_10000000_00000000_00000000 = [XSP, XSP, Imm, Imm] => [R(0), R(5), Uscaled(16, 6, 4), Ubits(10, 4)], [Ex_MTE];
 ]
 "subhn" = [
-    0b00001110_00100000_01100000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b00001110_01100000_01100000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
-    0b00001110_10100000_01100000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)];
+    0b00001110_00100000_01100000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_01100000_01100000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00001110_10100000_01100000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "subhn2" = [
-    0b01001110_00100000_01100000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01001110_01100000_01100000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_01100000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)];
+    0b01001110_00100000_01100000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_01100000_01100000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01001110_10100000_01100000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"subhnb" = [
+    0b01000101_01100000_01110000_00000000 = [Z(BYTE), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10100000_01110000_00000000 = [Z(B_2), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11100000_01110000_00000000 = [Z(B_4), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"subhnt" = [
+    0b01000101_01100000_01110100_00000000 = [Z(BYTE), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10100000_01110100_00000000 = [Z(B_2), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11100000_01110100_00000000 = [Z(B_4), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"subp" = [
+    0b10011010_11000000_00000000_00000000 = [X, XSP, XSP] => [R(0), R(5), R(16)], [Ex_MTE];
+]
+"subps" = [
+    0b10111010_11000000_00000000_00000000 = [X, XSP, XSP] => [R(0), R(5), R(16)], [Ex_MTE];
+]
+"subr" = [
+    // SUBR (vectors, predicated)
+    0b00000100_00000011_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SUBR (vectors, predicated)
+    0b00000100_01000011_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SUBR (vectors, predicated)
+    0b00000100_10000011_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SUBR (vectors, predicated)
+    0b00000100_11000011_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // SUBR (immediate)
+    0b00100101_00100011_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
+    0b00100101_01100011_11000000_00000000 = [Z(B_2), Z(B_2), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_10100011_11000000_00000000 = [Z(B_4), Z(B_4), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_11100011_11000000_00000000 = [Z(B_8), Z(B_8), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
 ]
 "subs" = [
     // SUBS (shifted register)
-    0b01101011_00000000_00000000_00000000 = [W, W, W, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b11101011_00000000_00000000_00000000 = [X, X, X, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b01101011_00000000_00000000_00000000 = [W, W, W, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b11101011_00000000_00000000_00000000 = [X, X, X, End, Mod(SHIFTS)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
     // SUBS (extended register)
-    0b01101011_00100000_00000000_00000000 = [W, WSP, W, End, Mod(EXTENDS)] => [R(0), R(5), R(16), ExtendsW(13), Urange(10, 0, 4)];
-    0b11101011_00100000_00000000_00000000 = [X, XSP, W, End, Mod(EXTENDS_W)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
-    0b11101011_00100000_00000000_00000000 = [X, XSP, X, End, Mod(EXTENDS_X)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)];
+    0b01101011_00100000_00000000_00000000 = [W, WSP, W, End, Mod(EXTENDS)] => [R(0), R(5), R(16), ExtendsW(13), Urange(10, 0, 4)], [Ex_BASE];
+    0b11101011_00100000_00000000_00000000 = [X, XSP, W, End, Mod(EXTENDS_W)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)], [Ex_BASE];
+    0b11101011_00100000_00000000_00000000 = [X, XSP, X, End, Mod(EXTENDS_X)] => [R(0), R(5), R(16), ExtendsX(13), Urange(10, 0, 4)], [Ex_BASE];
     // SUBS (immediate)
-    0b01110001_00000000_00000000_00000000 = [W, WSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
-    0b11110001_00000000_00000000_00000000 = [X, XSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])];
+    0b01110001_00000000_00000000_00000000 = [W, WSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])], [Ex_BASE];
+    0b11110001_00000000_00000000_00000000 = [X, XSP, Imm, End, LitMod(LSL)] => [R(0), R(5), Ubits(10, 12), Ulist(22, &[0, 12])], [Ex_BASE];
+]
+"sumopa" = [
+    0b10100000_10100000_00000000_00000000 = [ZaTile(B_4), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], [Ex_SME];
+    0b10100000_11100000_00000000_00000000 = [ZaTile(B_8), PMerge, PMerge, Z(B_2), Z(B_2)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], [Ex_SME_I16I64];
+]
+"sumops" = [
+    0b10100000_10100000_00000000_00010000 = [ZaTile(B_4), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], [Ex_SME];
+    0b10100000_11100000_00000000_00010000 = [ZaTile(B_8), PMerge, PMerge, Z(B_2), Z(B_2)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], [Ex_SME_I16I64];
+]
+"sunpkhi" = [
+    0b00000101_01110001_00111000_00000000 = [Z(B_2), Z(BYTE)] => [R(0), R(5)], [Ex_SVE];
+    0b00000101_10110001_00111000_00000000 = [Z(B_4), Z(B_2)] => [R(0), R(5)], [Ex_SVE];
+    0b00000101_11110001_00111000_00000000 = [Z(B_8), Z(B_4)] => [R(0), R(5)], [Ex_SVE];
+]
+"sunpklo" = [
+    0b00000101_01110000_00111000_00000000 = [Z(B_2), Z(BYTE)] => [R(0), R(5)], [Ex_SVE];
+    0b00000101_10110000_00111000_00000000 = [Z(B_4), Z(B_2)] => [R(0), R(5)], [Ex_SVE];
+    0b00000101_11110000_00111000_00000000 = [Z(B_8), Z(B_4)] => [R(0), R(5)], [Ex_SVE];
 ]
 "suqadd" = [
-    0b01011110_00100000_00111000_00000000 = [B, B] => [R(0), R(5)];
-    0b01011110_01100000_00111000_00000000 = [H, H] => [R(0), R(5)];
-    0b01011110_10100000_00111000_00000000 = [S, S] => [R(0), R(5)];
-    0b01011110_11100000_00111000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_00100000_00111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_01100000_00111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_10100000_00111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00001110_11100000_00111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01011110_00100000_00111000_00000000 = [B, B] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_01100000_00111000_00000000 = [H, H] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_10100000_00111000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01011110_11100000_00111000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_00100000_00111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_01100000_00111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_10100000_00111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00001110_11100000_00111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    // SUQADD (vectors, predicated)
+    0b01000100_00011100_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SUQADD (vectors, predicated)
+    0b01000100_01011100_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SUQADD (vectors, predicated)
+    0b01000100_10011100_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // SUQADD (vectors, predicated)
+    0b01000100_11011100_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "svc" = [
-    0b11010100_00000000_00000000_00000001 = [Imm] => [Ubits(5, 16)];
+    0b11010100_00000000_00000000_00000001 = [Imm] => [Ubits(5, 16)], [Ex_BASE];
 ]
 "swp" = [
-    0b10111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_00100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "swpa" = [
-    0b10111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_10100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "swpab" = [
-    0b00111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "swpah" = [
-    0b01111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "swpal" = [
-    0b10111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_11100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "swpalb" = [
-    0b00111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "swpalh" = [
-    0b01111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "swpb" = [
-    0b00111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "swph" = [
-    0b01111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "swpl" = [
-    0b10111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
+    0b11111000_01100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "swplb" = [
-    0b00111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "swplh" = [
-    0b01111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], [Ex_LSE];
 ]
 "sxtb" = [
-    0b00010011_00000000_00011100_00000000 = [W, W] => [R(0), R(5)];
-    0b10010011_01000000_00011100_00000000 = [X, W] => [R(0), R(5)];
+    0b00010011_00000000_00011100_00000000 = [W, W] => [R(0), R(5)], [Ex_BASE];
+    0b10010011_01000000_00011100_00000000 = [X, W] => [R(0), R(5)], [Ex_BASE];
+    // SXTB (predicated)
+    0b00000100_01010000_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // SXTB (predicated)
+    0b00000100_10010000_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // SXTB (predicated)
+    0b00000100_11010000_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "sxth" = [
-    0b00010011_00000000_00111100_00000000 = [W, W] => [R(0), R(5)];
-    0b10010011_01000000_00111100_00000000 = [X, W] => [R(0), R(5)];
+    0b00010011_00000000_00111100_00000000 = [W, W] => [R(0), R(5)], [Ex_BASE];
+    0b10010011_01000000_00111100_00000000 = [X, W] => [R(0), R(5)], [Ex_BASE];
+    // SXTH (predicated)
+    0b00000100_10010010_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // SXTH (predicated)
+    0b00000100_11010010_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "sxtl" = [
-    0b00001111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8)] => [R(0), R(5)];
-    0b00001111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4)] => [R(0), R(5)];
-    0b00001111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2)] => [R(0), R(5)];
+    0b00001111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8)] => [R(0), R(5)], [Ex_BASE];
+    0b00001111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4)] => [R(0), R(5)], [Ex_BASE];
+    0b00001111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2)] => [R(0), R(5)], [Ex_BASE];
 ]
 "sxtl2" = [
-    0b01001111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16)] => [R(0), R(5)];
-    0b01001111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8)] => [R(0), R(5)];
-    0b01001111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4)] => [R(0), R(5)];
+    0b01001111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16)] => [R(0), R(5)], [Ex_BASE];
+    0b01001111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8)] => [R(0), R(5)], [Ex_BASE];
+    0b01001111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4)] => [R(0), R(5)], [Ex_BASE];
 ]
 "sxtw" = [
-    0b10010011_01000000_01111100_00000000 = [X, W] => [R(0), R(5)];
+    0b10010011_01000000_01111100_00000000 = [X, W] => [R(0), R(5)], [Ex_BASE];
+    // SXTW (predicated)
+    0b00000100_11010100_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "sys" = [
-    0b11010101_00001000_00000000_00000000 = [Imm, Ident, Ident, Imm, End, X] => [Ubits(16, 3), LitList(12, "CONTROL_REGS"), LitList(8, "CONTROL_REGS"), Ubits(5, 3), R(0)];
+    0b11010101_00001000_00000000_00000000 = [Imm, Ident, Ident, Imm, End, X] => [Ubits(16, 3), LitList(12, "CONTROL_REGS"), LitList(8, "CONTROL_REGS"), Ubits(5, 3), R(0)], [Ex_BASE];
 ]
 "sysl" = [
-    0b11010101_00101000_00000000_00000000 = [X, Imm, Ident, Ident, Imm] => [R(0), Ubits(16, 3), LitList(12, "CONTROL_REGS"), LitList(8, "CONTROL_REGS"), Ubits(5, 3)];
+    0b11010101_00101000_00000000_00000000 = [X, Imm, Ident, Ident, Imm] => [R(0), Ubits(16, 3), LitList(12, "CONTROL_REGS"), LitList(8, "CONTROL_REGS"), Ubits(5, 3)], [Ex_BASE];
 ]
 "tbl" = [
-    0b00001110_00000000_00100000_00000000 = [V(BYTE), RegListStatic(2, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_00000000_01000000_00000000 = [V(BYTE), RegListStatic(3, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_00000000_01100000_00000000 = [V(BYTE), RegListStatic(4, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_00000000_00000000_00000000 = [V(BYTE), RegListStatic(1, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00000000_00100000_00000000 = [V(BYTE), RegListStatic(2, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_00000000_01000000_00000000 = [V(BYTE), RegListStatic(3, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_00000000_01100000_00000000 = [V(BYTE), RegListStatic(4, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_00000000_00000000_00000000 = [V(BYTE), RegListStatic(1, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00000101_00100000_00110000_00000000 = [Z(BYTE), ZRegList(1, BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    0b00000101_01100000_00110000_00000000 = [Z(B_2), ZRegList(1, B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    0b00000101_10100000_00110000_00000000 = [Z(B_4), ZRegList(1, B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    0b00000101_11100000_00110000_00000000 = [Z(B_8), ZRegList(1, B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
 ]
 "tbnz" = [
-    0b00110111_00000000_00000000_00000000 = [W, Imm, Offset] => [R(0), Ubits(19, 5), Offset(TBZ)];
-    0b00110111_00000000_00000000_00000000 = [X, Imm, Offset] => [R(0), CUbits(6), Uslice(19, 5, 0), Uslice(31, 1, 5), A, Offset(TBZ)];
+    0b00110111_00000000_00000000_00000000 = [W, Imm, Offset] => [R(0), Ubits(19, 5), Offset(TBZ)], [Ex_BASE];
+    0b00110111_00000000_00000000_00000000 = [X, Imm, Offset] => [R(0), CUbits(6), Uslice(19, 5, 0), Uslice(31, 1, 5), A, Offset(TBZ)], [Ex_BASE];
 ]
 "tbx" = [
-    0b00001110_00000000_00110000_00000000 = [V(BYTE), RegListStatic(2, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_00000000_01010000_00000000 = [V(BYTE), RegListStatic(3, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_00000000_01110000_00000000 = [V(BYTE), RegListStatic(4, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_00000000_00010000_00000000 = [V(BYTE), RegListStatic(1, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00000000_00110000_00000000 = [V(BYTE), RegListStatic(2, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_00000000_01010000_00000000 = [V(BYTE), RegListStatic(3, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_00000000_01110000_00000000 = [V(BYTE), RegListStatic(4, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_00000000_00010000_00000000 = [V(BYTE), RegListStatic(1, BYTE, 16), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // TBX (vectors, unpredicated)
+    0b00000101_00100000_00101100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // TBX (vectors, unpredicated)
+    0b00000101_01100000_00101100_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // TBX (vectors, unpredicated)
+    0b00000101_10100000_00101100_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // TBX (vectors, unpredicated)
+    0b00000101_11100000_00101100_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "tbz" = [
-    0b00110110_00000000_00000000_00000000 = [W, Imm, Offset] => [R(0), Ubits(19, 5), Offset(TBZ)];
-    0b00110110_00000000_00000000_00000000 = [X, Imm, Offset] => [R(0), CUbits(6), Uslice(19, 5, 0), Uslice(31, 1, 5), A, Offset(TBZ)];
+    0b00110110_00000000_00000000_00000000 = [W, Imm, Offset] => [R(0), Ubits(19, 5), Offset(TBZ)], [Ex_BASE];
+    0b00110110_00000000_00000000_00000000 = [X, Imm, Offset] => [R(0), CUbits(6), Uslice(19, 5, 0), Uslice(31, 1, 5), A, Offset(TBZ)], [Ex_BASE];
 ]
 "tlbi" = [
-    0b11010101_00001000_10000000_00000000 = [Ident, End, X] => [LitList(5, "TLBI_OPS"), R(0)];
+    0b11010101_00001000_10000000_00000000 = [Ident, End, X] => [LitList(5, "TLBI_OPS"), R(0)], [Ex_BASE];
 ]
 "trn1" = [
-    0b00001110_00000000_00101000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01000000_00101000_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10000000_00101000_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11000000_00101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00000000_00101000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01000000_00101000_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10000000_00101000_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11000000_00101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // TRN1 (vectors, unpredicated)
+    0b00000101_00100000_01110000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // TRN1 (vectors, unpredicated)
+    0b00000101_01100000_01110000_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // TRN1 (vectors, unpredicated)
+    0b00000101_10100000_01110000_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // TRN1 (vectors, unpredicated)
+    0b00000101_11100000_01110000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // TRN1 (predicates)
+    0b00000101_00100000_01010000_00000000 = [P(BYTE), P(BYTE), P(BYTE)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_01100000_01010000_00000000 = [P(B_2), P(B_2), P(B_2)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_10100000_01010000_00000000 = [P(B_4), P(B_4), P(B_4)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_11100000_01010000_00000000 = [P(B_8), P(B_8), P(B_8)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
 ]
 "trn2" = [
-    0b00001110_00000000_01101000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01000000_01101000_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10000000_01101000_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11000000_01101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00000000_01101000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01000000_01101000_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10000000_01101000_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11000000_01101000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // TRN2 (vectors, unpredicated)
+    0b00000101_00100000_01110100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // TRN2 (vectors, unpredicated)
+    0b00000101_01100000_01110100_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // TRN2 (vectors, unpredicated)
+    0b00000101_10100000_01110100_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // TRN2 (vectors, unpredicated)
+    0b00000101_11100000_01110100_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // TRN2 (predicates)
+    0b00000101_00100000_01010100_00000000 = [P(BYTE), P(BYTE), P(BYTE)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_01100000_01010100_00000000 = [P(B_2), P(B_2), P(B_2)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_10100000_01010100_00000000 = [P(B_4), P(B_4), P(B_4)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_11100000_01010100_00000000 = [P(B_8), P(B_8), P(B_8)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
 ]
 "tsb" = [
-    0b11010101_00000011_00100010_01011111 = [Lit("csync")] => [];
+    0b11010101_00000011_00100010_01011111 = [Lit("csync")] => [], [Ex_TRF];
 ]
 "tst" = [
     // TST (immediate)
-    0b01110010_00000000_00000000_00011111 = [W, Imm] => [R(5), Special(10, LOGICAL_IMMEDIATE_W)];
-    0b11110010_00000000_00000000_00011111 = [X, Imm] => [R(5), Special(10, LOGICAL_IMMEDIATE_X)];
+    0b01110010_00000000_00000000_00011111 = [W, Imm] => [R(5), Special(10, LOGICAL_IMMEDIATE_W)], [Ex_BASE];
+    0b11110010_00000000_00000000_00011111 = [X, Imm] => [R(5), Special(10, LOGICAL_IMMEDIATE_X)], [Ex_BASE];
     // TST (shifted register)
-    0b01101010_00000000_00000000_00011111 = [W, W, End, Mod(ROTATES)] => [R(5), R(16), Rotates(22), Ubits(10, 5)];
-    0b11101010_00000000_00000000_00011111 = [X, X, End, Mod(ROTATES)] => [R(5), R(16), Rotates(22), Ubits(10, 6)];
+    0b01101010_00000000_00000000_00011111 = [W, W, End, Mod(ROTATES)] => [R(5), R(16), Rotates(22), Ubits(10, 5)], [Ex_BASE];
+    0b11101010_00000000_00000000_00011111 = [X, X, End, Mod(ROTATES)] => [R(5), R(16), Rotates(22), Ubits(10, 6)], [Ex_BASE];
 ]
 "uaba" = [
-    0b00101110_00100000_01111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_01111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_01111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_00100000_01111100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_01111100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_01111100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // UABA (vectors, unpredicated)
+    0b01000101_00000000_11111100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // UABA (vectors, unpredicated)
+    0b01000101_01000000_11111100_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // UABA (vectors, unpredicated)
+    0b01000101_10000000_11111100_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // UABA (vectors, unpredicated)
+    0b01000101_11000000_11111100_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "uabal" = [
-    0b00101110_00100000_01010000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00101110_01100000_01010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00101110_10100000_01010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00101110_00100000_01010000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_01100000_01010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_10100000_01010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "uabal2" = [
-    0b01101110_00100000_01010000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01101110_01100000_01010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01101110_10100000_01010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01101110_00100000_01010000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_01100000_01010000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_10100000_01010000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "uabd" = [
-    0b00101110_00100000_01110100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_01110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_01110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_00100000_01110100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_01110100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_01110100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // UABD (vectors, predicated)
+    0b00000100_00001101_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UABD (vectors, predicated)
+    0b00000100_01001101_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UABD (vectors, predicated)
+    0b00000100_10001101_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UABD (vectors, predicated)
+    0b00000100_11001101_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "uabdl" = [
-    0b00101110_00100000_01110000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00101110_01100000_01110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00101110_10100000_01110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00101110_00100000_01110000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_01100000_01110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_10100000_01110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "uabdl2" = [
-    0b01101110_00100000_01110000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01101110_01100000_01110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01101110_10100000_01110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01101110_00100000_01110000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_01100000_01110000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_10100000_01110000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"uabdlb" = [
+    0b01000101_01000000_00111000_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_00111000_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_00111000_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"uabdlt" = [
+    0b01000101_01000000_00111100_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_00111100_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_00111100_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "uadalp" = [
-    0b00101110_00100000_01101000_00000000 = [V(B_2), V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100000_01101000_00000000 = [V(B_4), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100000_01101000_00000000 = [V(B_8), V(B_4)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_00100000_01101000_00000000 = [V(B_2), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_01101000_00000000 = [V(B_4), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_01101000_00000000 = [V(B_8), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
 ]
 "uaddl" = [
-    0b00101110_00100000_00000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00101110_01100000_00000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00101110_10100000_00000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00101110_00100000_00000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_01100000_00000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_10100000_00000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "uaddl2" = [
-    0b01101110_00100000_00000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01101110_01100000_00000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01101110_10100000_00000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01101110_00100000_00000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_01100000_00000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_10100000_00000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"uaddlb" = [
+    0b01000101_01000000_00001000_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_00001000_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_00001000_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "uaddlp" = [
-    0b00101110_00100000_00101000_00000000 = [V(B_2), V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100000_00101000_00000000 = [V(B_4), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100000_00101000_00000000 = [V(B_8), V(B_4)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_00100000_00101000_00000000 = [V(B_2), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_00101000_00000000 = [V(B_4), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_00101000_00000000 = [V(B_8), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+]
+"uaddlt" = [
+    0b01000101_01000000_00001100_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_00001100_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_00001100_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "uaddlv" = [
-    0b00101110_00110000_00111000_00000000 = [H, V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01110000_00111000_00000000 = [S, V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10110000_00111000_00000000 = [D, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_00110000_00111000_00000000 = [H, V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01110000_00111000_00000000 = [S, V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_10110000_00111000_00000000 = [D, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+]
+"uaddv" = [
+    0b00000100_00000001_00100000_00000000 = [D, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_01000001_00100000_00000000 = [D, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_10000001_00100000_00000000 = [D, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_11000001_00100000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "uaddw" = [
-    0b00101110_00100000_00010000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00101110_01100000_00010000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00101110_10100000_00010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00101110_00100000_00010000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_01100000_00010000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_10100000_00010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "uaddw2" = [
-    0b01101110_00100000_00010000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01101110_01100000_00010000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01101110_10100000_00010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01101110_00100000_00010000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_01100000_00010000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_10100000_00010000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"uaddwb" = [
+    0b01000101_01000000_01001000_00000000 = [Z(B_2), Z(B_2), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_01001000_00000000 = [Z(B_4), Z(B_4), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_01001000_00000000 = [Z(B_8), Z(B_8), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"uaddwt" = [
+    0b01000101_01000000_01001100_00000000 = [Z(B_2), Z(B_2), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_01001100_00000000 = [Z(B_4), Z(B_4), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_01001100_00000000 = [Z(B_8), Z(B_8), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "ubfiz" = [
-    0b01010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Usubmod(16, 5), CUsum(5), Urange(10, 1, 32)];
-    0b11010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Usubmod(16, 6), CUsum(6), Urange(10, 1, 64)];
+    0b01010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Usubmod(16, 5), CUsum(5), Urange(10, 1, 32)], [Ex_BASE];
+    0b11010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Usubmod(16, 6), CUsum(6), Urange(10, 1, 64)], [Ex_BASE];
 ]
 "ubfm" = [
-    0b01010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Ubits(10, 5)];
-    0b11010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), CUsum(6), Ubits(10, 6)]; // TODO: check
+    0b01010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Ubits(10, 5)], [Ex_BASE];
+    0b11010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), CUsum(6), Ubits(10, 6)], [Ex_BASE]; // TODO: check
 ]
 "ubfx" = [
-    0b01010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Usum(10, 5)];
-    0b11010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), Usum(10, 6)];
+    0b01010011_00000000_00000000_00000000 = [W, W, Imm, Imm] => [R(0), R(5), Ubits(16, 5), Usum(10, 5)], [Ex_BASE];
+    0b11010011_01000000_00000000_00000000 = [X, X, Imm, Imm] => [R(0), R(5), Ubits(16, 6), Usum(10, 6)], [Ex_BASE];
+]
+"uclamp" = [
+    0b01000100_00000000_11000100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SME];
+    0b01000100_01000000_11000100_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SME];
+    0b01000100_10000000_11000100_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SME];
+    0b01000100_11000000_11000100_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SME];
 ]
 "ucvtf" = [
     // UCVTF (vector, fixed-point)
-    0b01111111_00000000_11100100_00000000 = [H, H, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)];
-    0b01111111_00000000_11100100_00000000 = [S, S, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)];
-    0b01111111_00000000_11100100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)];
-    0b00101111_00010000_11100100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)];
-    0b00101111_00100000_11100100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)];
-    0b00101111_01000000_11100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)];
+    0b01111111_00000000_11100100_00000000 = [H, H, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)], [Ex_FP16];
+    0b01111111_00000000_11100100_00000000 = [S, S, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)], [Ex_BASE];
+    0b01111111_00000000_11100100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)], [Ex_BASE];
+    0b00101111_00010000_11100100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)], [Ex_FP16];
+    0b00101111_00100000_11100100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00101111_01000000_11100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)], [Ex_BASE];
     // UCVTF (vector, integer)
-    0b01111110_01111001_11011000_00000000 = [H, H] => [R(0), R(5)];
-    0b01111110_00100001_11011000_00000000 = [S, S] => [R(0), R(5)];
-    0b01111110_01100001_11011000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_01111001_11011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_00100001_11011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100001_11011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01111110_01111001_11011000_00000000 = [H, H] => [R(0), R(5)], [Ex_FP16];
+    0b01111110_00100001_11011000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_01100001_11011000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_01111001_11011000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_FP16];
+    0b00101110_00100001_11011000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100001_11011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
     // UCVTF (scalar, fixed-point)
-    0b00011110_11000011_00000000_00000000 = [H, W, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)];
-    0b00011110_00000011_00000000_00000000 = [S, W, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)];
-    0b00011110_01000011_00000000_00000000 = [D, W, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)];
-    0b10011110_11000011_00000000_00000000 = [H, X, Imm] => [R(0), R(5), Usubone(10, 6)];
-    0b10011110_00000011_00000000_00000000 = [S, X, Imm] => [R(0), R(5), Usubone(10, 6)];
-    0b10011110_01000011_00000000_00000000 = [D, X, Imm] => [R(0), R(5), Usubone(10, 6)];
+    0b00011110_11000011_00000000_00000000 = [H, W, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)], [Ex_FP16];
+    0b00011110_00000011_00000000_00000000 = [S, W, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)], [Ex_BASE];
+    0b00011110_01000011_00000000_00000000 = [D, W, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(10, 6)], [Ex_BASE];
+    0b10011110_11000011_00000000_00000000 = [H, X, Imm] => [R(0), R(5), Usubone(10, 6)], [Ex_FP16];
+    0b10011110_00000011_00000000_00000000 = [S, X, Imm] => [R(0), R(5), Usubone(10, 6)], [Ex_BASE];
+    0b10011110_01000011_00000000_00000000 = [D, X, Imm] => [R(0), R(5), Usubone(10, 6)], [Ex_BASE];
     // UCVTF (scalar, integer)
-    0b00011110_11100011_00000000_00000000 = [H, W] => [R(0), R(5)];
-    0b00011110_00100011_00000000_00000000 = [S, W] => [R(0), R(5)];
-    0b00011110_01100011_00000000_00000000 = [D, W] => [R(0), R(5)];
-    0b10011110_11100011_00000000_00000000 = [H, X] => [R(0), R(5)];
-    0b10011110_00100011_00000000_00000000 = [S, X] => [R(0), R(5)];
-    0b10011110_01100011_00000000_00000000 = [D, X] => [R(0), R(5)];
+    0b00011110_11100011_00000000_00000000 = [H, W] => [R(0), R(5)], [Ex_FP16];
+    0b00011110_00100011_00000000_00000000 = [S, W] => [R(0), R(5)], [Ex_BASE];
+    0b00011110_01100011_00000000_00000000 = [D, W] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_11100011_00000000_00000000 = [H, X] => [R(0), R(5)], [Ex_FP16];
+    0b10011110_00100011_00000000_00000000 = [S, X] => [R(0), R(5)], [Ex_BASE];
+    0b10011110_01100011_00000000_00000000 = [D, X] => [R(0), R(5)], [Ex_BASE];
+    // UCVTF (predicated)
+    0b01100101_01010011_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // UCVTF (predicated)
+    0b01100101_10010101_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // UCVTF (predicated)
+    0b01100101_11010111_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // UCVTF (predicated)
+    0b01100101_11010101_10100000_00000000 = [Z(B_4), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // UCVTF (predicated)
+    0b01100101_11010001_10100000_00000000 = [Z(B_8), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "udf" = [
-    0b00000000_00000000_00000000_00000000 = [Imm] => [Ubits(0, 16)];
+    0b00000000_00000000_00000000_00000000 = [Imm] => [Ubits(0, 16)], [Ex_BASE];
 ]
 "udiv" = [
-    0b00011010_11000000_00001000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
-    0b10011010_11000000_00001000_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b00011010_11000000_00001000_00000000 = [W, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b10011010_11000000_00001000_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
+    // UDIV (vectors, predicated)
+    0b00000100_10010101_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UDIV (vectors, predicated)
+    0b00000100_11010101_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+]
+"udivr" = [
+    // UDIVR (vectors, predicated)
+    0b00000100_10010111_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UDIVR (vectors, predicated)
+    0b00000100_11010111_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
 ]
 "udot" = [
     // UDOT (by element)
-    0b00101111_10000000_11100000_00000000 = [VStatic(B_4, 2), VStatic(BYTE, 8), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b01101111_10000000_11100000_00000000 = [VStatic(B_4, 4), VStatic(BYTE, 16), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00101111_10000000_11100000_00000000 = [VStatic(B_4, 2), VStatic(BYTE, 8), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_DOTPROD];
+    0b01101111_10000000_11100000_00000000 = [VStatic(B_4, 4), VStatic(BYTE, 16), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_DOTPROD];
     // UDOT (vector)
-    0b00101110_10000000_10010100_00000000 = [VStatic(B_4, 2), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b01101110_10000000_10010100_00000000 = [VStatic(B_4, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b00101110_10000000_10010100_00000000 = [VStatic(B_4, 2), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_DOTPROD];
+    0b01101110_10000000_10010100_00000000 = [VStatic(B_4, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_DOTPROD];
+    // UDOT (vectors)
+    0b01000100_10000000_00000100_00000000 = [Z(B_4), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    0b01000100_11000000_00000100_00000000 = [Z(B_8), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UDOT (indexed)
+    0b01000100_10100000_00000100_00000000 = [Z(B_4), Z(BYTE), ZElement(BYTE)] => [R(0), R(5), R3(16), Ubits(19, 2)], [Ex_SVE];
+    0b01000100_11100000_00000100_00000000 = [Z(B_8), Z(B_2), ZElement(B_2)] => [R(0), R(5), R4(16), Ubits(20, 1)], [Ex_SVE];
 ]
 "uhadd" = [
-    0b00101110_00100000_00000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_00000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_00000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_00100000_00000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_00000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_00000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // UHADD (vectors, predicated)
+    0b01000100_00010001_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UHADD (vectors, predicated)
+    0b01000100_01010001_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UHADD (vectors, predicated)
+    0b01000100_10010001_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UHADD (vectors, predicated)
+    0b01000100_11010001_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "uhsub" = [
-    0b00101110_00100000_00100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_00100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_00100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_00100000_00100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_00100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_00100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // UHSUB (vectors, predicated)
+    0b01000100_00010011_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UHSUB (vectors, predicated)
+    0b01000100_01010011_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UHSUB (vectors, predicated)
+    0b01000100_10010011_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UHSUB (vectors, predicated)
+    0b01000100_11010011_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "umaddl" = [
-    0b10011011_10100000_00000000_00000000 = [X, W, W, X] => [R(0), R(5), R(16), R(10)];
+    0b10011011_10100000_00000000_00000000 = [X, W, W, X] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
 ]
 "umax" = [
-    0b00101110_00100000_01100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_01100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_01100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_00100000_01100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_01100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_01100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // UMAX (vectors, predicated)
+    0b00000100_00001001_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UMAX (vectors, predicated)
+    0b00000100_01001001_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UMAX (vectors, predicated)
+    0b00000100_10001001_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UMAX (vectors, predicated)
+    0b00000100_11001001_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UMAX (immediate)
+    0b00100101_00101001_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
+    0b00100101_01101001_11000000_00000000 = [Z(B_2), Z(B_2), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
+    0b00100101_10101001_11000000_00000000 = [Z(B_4), Z(B_4), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
+    0b00100101_11101001_11000000_00000000 = [Z(B_8), Z(B_8), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
 ]
 "umaxp" = [
-    0b00101110_00100000_10100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_10100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_10100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_00100000_10100100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_10100100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_10100100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // UMAXP (vectors, predicated)
+    0b01000100_00010101_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UMAXP (vectors, predicated)
+    0b01000100_01010101_10100000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UMAXP (vectors, predicated)
+    0b01000100_10010101_10100000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UMAXP (vectors, predicated)
+    0b01000100_11010101_10100000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "umaxv" = [
-    0b00101110_00110000_10101000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01110000_10101000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10110000_10101000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_00110000_10101000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01110000_10101000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_10110000_10101000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00000100_00001001_00100000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_01001001_00100000_00000000 = [H, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_10001001_00100000_00000000 = [S, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_11001001_00100000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "umin" = [
-    0b00101110_00100000_01101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_01101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_01101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_00100000_01101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_01101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_01101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // UMIN (vectors, predicated)
+    0b00000100_00001011_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UMIN (vectors, predicated)
+    0b00000100_01001011_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UMIN (vectors, predicated)
+    0b00000100_10001011_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UMIN (vectors, predicated)
+    0b00000100_11001011_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UMIN (immediate)
+    0b00100101_00101011_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
+    0b00100101_01101011_11000000_00000000 = [Z(B_2), Z(B_2), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
+    0b00100101_10101011_11000000_00000000 = [Z(B_4), Z(B_4), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
+    0b00100101_11101011_11000000_00000000 = [Z(B_8), Z(B_8), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
 ]
 "uminp" = [
-    0b00101110_00100000_10101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_10101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_10101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_00100000_10101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_10101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_10101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // UMINP (vectors, predicated)
+    0b01000100_00010111_10100000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UMINP (vectors, predicated)
+    0b01000100_01010111_10100000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UMINP (vectors, predicated)
+    0b01000100_10010111_10100000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UMINP (vectors, predicated)
+    0b01000100_11010111_10100000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "uminv" = [
-    0b00101110_00110001_10101000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01110001_10101000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10110001_10101000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_00110001_10101000_00000000 = [B, V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01110001_10101000_00000000 = [H, V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_10110001_10101000_00000000 = [S, VStatic(B_4, 4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00000100_00001011_00100000_00000000 = [B, PBare, Z(BYTE)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_01001011_00100000_00000000 = [H, PBare, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_10001011_00100000_00000000 = [S, PBare, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    0b00000100_11001011_00100000_00000000 = [D, PBare, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "umlal" = [
     // UMLAL, UMLAL2 (by element)
-    0b00101111_01000000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b00101111_10000000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00101111_01000000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b00101111_10000000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // UMLAL, UMLAL2 (vector)
-    0b00101110_00100000_10000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00101110_01100000_10000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00101110_10100000_10000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00101110_00100000_10000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_01100000_10000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_10100000_10000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "umlal2" = [
     // UMLAL, UMLAL2 (by element)
-    0b01101111_01000000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01101111_10000000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01101111_01000000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b01101111_10000000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // UMLAL, UMLAL2 (vector)
-    0b01101110_00100000_10000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01101110_01100000_10000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01101110_10100000_10000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01101110_00100000_10000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_01100000_10000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_10100000_10000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"umlalb" = [
+    0b01000100_01000000_01001000_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_10000000_01001000_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_11000000_01001000_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"umlalt" = [
+    0b01000100_01000000_01001100_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_10000000_01001100_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_11000000_01001100_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "umlsl" = [
     // UMLSL, UMLSL2 (by element)
-    0b00101111_01000000_01100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b00101111_10000000_01100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00101111_01000000_01100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b00101111_10000000_01100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // UMLSL, UMLSL2 (vector)
-    0b00101110_00100000_10100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00101110_01100000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00101110_10100000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00101110_00100000_10100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_01100000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_10100000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "umlsl2" = [
     // UMLSL, UMLSL2 (by element)
-    0b01101111_01000000_01100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01101111_10000000_01100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01101111_01000000_01100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b01101111_10000000_01100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // UMLSL, UMLSL2 (vector)
-    0b01101110_00100000_10100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01101110_01100000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01101110_10100000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01101110_00100000_10100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_01100000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_10100000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"umlslb" = [
+    0b01000100_01000000_01011000_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_10000000_01011000_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_11000000_01011000_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"umlslt" = [
+    0b01000100_01000000_01011100_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_10000000_01011100_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000100_11000000_01011100_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "umnegl" = [
-    0b10011011_10100000_11111100_00000000 = [X, W, W] => [R(0), R(5), R(16)];
+    0b10011011_10100000_11111100_00000000 = [X, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"umopa" = [
+    0b10100001_10100000_00000000_00000000 = [ZaTile(B_4), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], [Ex_SME];
+    0b10100001_11100000_00000000_00000000 = [ZaTile(B_8), PMerge, PMerge, Z(B_2), Z(B_2)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], [Ex_SME_I16I64];
+]
+"umops" = [
+    0b10100001_10100000_00000000_00010000 = [ZaTile(B_4), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], [Ex_SME];
+    0b10100001_11100000_00000000_00010000 = [ZaTile(B_8), PMerge, PMerge, Z(B_2), Z(B_2)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], [Ex_SME_I16I64];
 ]
 "umov" = [
-    0b00001110_00000001_00111100_00000000 = [W, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)];
-    0b00001110_00000010_00111100_00000000 = [W, VElement(B_2)] => [R(0), R(5), Ubits(18, 3)];
-    0b00001110_00000100_00111100_00000000 = [W, VElement(B_4)] => [R(0), R(5), Ubits(19, 2)];
-    0b01001110_00001000_00111100_00000000 = [X, VElement(B_8)] => [R(0), R(5), Ubits(20, 1)];
+    0b00001110_00000001_00111100_00000000 = [W, VElement(BYTE)] => [R(0), R(5), Ubits(17, 4)], [Ex_BASE];
+    0b00001110_00000010_00111100_00000000 = [W, VElement(B_2)] => [R(0), R(5), Ubits(18, 3)], [Ex_BASE];
+    0b00001110_00000100_00111100_00000000 = [W, VElement(B_4)] => [R(0), R(5), Ubits(19, 2)], [Ex_BASE];
+    0b01001110_00001000_00111100_00000000 = [X, VElement(B_8)] => [R(0), R(5), Ubits(20, 1)], [Ex_BASE];
 ]
 "umsubl" = [
-    0b10011011_10100000_10000000_00000000 = [X, W, W, X] => [R(0), R(5), R(16), R(10)];
+    0b10011011_10100000_10000000_00000000 = [X, W, W, X] => [R(0), R(5), R(16), R(10)], [Ex_BASE];
 ]
 "umulh" = [
-    0b10011011_11000000_01111100_00000000 = [X, X, X] => [R(0), R(5), R(16)];
+    0b10011011_11000000_01111100_00000000 = [X, X, X] => [R(0), R(5), R(16)], [Ex_BASE];
+    // UMULH (vectors, predicated)
+    0b00000100_00010011_00000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UMULH (vectors, predicated)
+    0b00000100_01010011_00000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UMULH (vectors, predicated)
+    0b00000100_10010011_00000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UMULH (vectors, predicated)
+    0b00000100_11010011_00000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE];
+    // UMULH (vectors, unpredicated)
+    0b00000100_00100000_01101100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // UMULH (vectors, unpredicated)
+    0b00000100_01100000_01101100_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // UMULH (vectors, unpredicated)
+    0b00000100_10100000_01101100_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    // UMULH (vectors, unpredicated)
+    0b00000100_11100000_01101100_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "umull" = [
     // UMULL, UMULL2 (by element)
-    0b00101111_01000000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b00101111_10000000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00101111_01000000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b00101111_10000000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // UMULL, UMULL2 (vector)
-    0b00101110_00100000_11000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00101110_01100000_11000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00101110_10100000_11000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00101110_00100000_11000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_01100000_11000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_10100000_11000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
     // UMULL
-    0b10011011_10100000_01111100_00000000 = [X, W, W] => [R(0), R(5), R(16)];
+    0b10011011_10100000_01111100_00000000 = [X, W, W] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "umull2" = [
     // UMULL, UMULL2 (by element)
-    0b01101111_01000000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01101111_10000000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b01101111_01000000_10100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VElement(B_2)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], [Ex_BASE];
+    0b01101111_10000000_10100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VElement(B_4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], [Ex_BASE];
     // UMULL, UMULL2 (vector)
-    0b01101110_00100000_11000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01101110_01100000_11000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01101110_10100000_11000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01101110_00100000_11000000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_01100000_11000000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_10100000_11000000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"umullb" = [
+    0b01000101_01000000_01111000_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_01111000_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_01111000_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"umullt" = [
+    0b01000101_01000000_01111100_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_01111100_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_01111100_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "uqadd" = [
-    0b01111110_00100000_00001100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
-    0b01111110_01100000_00001100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01111110_10100000_00001100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01111110_11100000_00001100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_00100000_00001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_00001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_00001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_00001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_00100000_00001100_00000000 = [B, B, B] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_01100000_00001100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_10100000_00001100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_11100000_00001100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_00100000_00001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_00001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_00001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_00001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // UQADD (vectors, predicated)
+    0b01000100_00011001_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UQADD (vectors, predicated)
+    0b01000100_01011001_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UQADD (vectors, predicated)
+    0b01000100_10011001_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UQADD (vectors, predicated)
+    0b01000100_11011001_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UQADD (vectors, unpredicated)
+    0b00000100_00100000_00010100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UQADD (vectors, unpredicated)
+    0b00000100_01100000_00010100_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UQADD (vectors, unpredicated)
+    0b00000100_10100000_00010100_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UQADD (vectors, unpredicated)
+    0b00000100_11100000_00010100_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UQADD (immediate)
+    0b00100101_00100101_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
+    0b00100101_01100101_11000000_00000000 = [Z(B_2), Z(B_2), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_10100101_11000000_00000000 = [Z(B_4), Z(B_4), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_11100101_11000000_00000000 = [Z(B_8), Z(B_8), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
 ]
 "uqrshl" = [
-    0b01111110_00100000_01011100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
-    0b01111110_01100000_01011100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01111110_10100000_01011100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01111110_11100000_01011100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_00100000_01011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_01011100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_01011100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_01011100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_00100000_01011100_00000000 = [B, B, B] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_01100000_01011100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_10100000_01011100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_11100000_01011100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_00100000_01011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_01011100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_01011100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_01011100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // UQRSHL (vectors, predicated)
+    0b01000100_00001011_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UQRSHL (vectors, predicated)
+    0b01000100_01001011_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UQRSHL (vectors, predicated)
+    0b01000100_10001011_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UQRSHL (vectors, predicated)
+    0b01000100_11001011_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "uqrshrn" = [
-    0b01111111_00000000_10011100_00000000 = [B, H, Imm] => [R(0), R(5), CUrange(1, 8), Usubone(16, 4)];
-    0b01111111_00000000_10011100_00000000 = [H, S, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)];
-    0b01111111_00000000_10011100_00000000 = [S, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)];
-    0b00101111_00001000_10011100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b00101111_00010000_10011100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b00101111_00100000_10011100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b01111111_00000000_10011100_00000000 = [B, H, Imm] => [R(0), R(5), CUrange(1, 8), Usubone(16, 4)], [Ex_BASE];
+    0b01111111_00000000_10011100_00000000 = [H, S, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)], [Ex_BASE];
+    0b01111111_00000000_10011100_00000000 = [S, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)], [Ex_BASE];
+    0b00101111_00001000_10011100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b00101111_00010000_10011100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b00101111_00100000_10011100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "uqrshrn2" = [
-    0b01101111_00001000_10011100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b01101111_00010000_10011100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b01101111_00100000_10011100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b01101111_00001000_10011100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b01101111_00010000_10011100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b01101111_00100000_10011100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "uqshl" = [
     // UQSHL (immediate)
-    0b01111111_00001000_01110100_00000000 = [B, B, Imm] => [R(0), R(5), Ubits(16, 3)];
-    0b01111111_00010000_01110100_00000000 = [H, H, Imm] => [R(0), R(5), Ubits(16, 4)];
-    0b01111111_00100000_01110100_00000000 = [S, S, Imm] => [R(0), R(5), Ubits(16, 5)];
-    0b01111111_01000000_01110100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)];
-    0b00101111_00001000_01110100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)];
-    0b00101111_00010000_01110100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)];
-    0b00101111_00100000_01110100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)];
-    0b00101111_01000000_01110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)];
+    0b01111111_00001000_01110100_00000000 = [B, B, Imm] => [R(0), R(5), Ubits(16, 3)], [Ex_BASE];
+    0b01111111_00010000_01110100_00000000 = [H, H, Imm] => [R(0), R(5), Ubits(16, 4)], [Ex_BASE];
+    0b01111111_00100000_01110100_00000000 = [S, S, Imm] => [R(0), R(5), Ubits(16, 5)], [Ex_BASE];
+    0b01111111_01000000_01110100_00000000 = [D, D, Imm] => [R(0), R(5), Ubits(16, 6)], [Ex_BASE];
+    0b00101111_00001000_01110100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Ubits(16, 3), Rwidth(30)], [Ex_BASE];
+    0b00101111_00010000_01110100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Ubits(16, 4), Rwidth(30)], [Ex_BASE];
+    0b00101111_00100000_01110100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Ubits(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00101111_01000000_01110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)], [Ex_BASE];
     // UQSHL (register)
-    0b01111110_00100000_01001100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
-    0b01111110_01100000_01001100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01111110_10100000_01001100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01111110_11100000_01001100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_00100000_01001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_01001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_01001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_01001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_00100000_01001100_00000000 = [B, B, B] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_01100000_01001100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_10100000_01001100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_11100000_01001100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_00100000_01001100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_01001100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_01001100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_01001100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // UQSHL (vectors, predicated)
+    0b01000100_00001001_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UQSHL (vectors, predicated)
+    0b01000100_01001001_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UQSHL (vectors, predicated)
+    0b01000100_10001001_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UQSHL (vectors, predicated)
+    0b01000100_11001001_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "uqshrn" = [
-    0b01111111_00000000_10010100_00000000 = [B, H, Imm] => [R(0), R(5), CUrange(1, 8), Usubone(16, 4)];
-    0b01111111_00000000_10010100_00000000 = [H, S, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)];
-    0b01111111_00000000_10010100_00000000 = [S, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)];
-    0b00101111_00001000_10010100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b00101111_00010000_10010100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b00101111_00100000_10010100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b01111111_00000000_10010100_00000000 = [B, H, Imm] => [R(0), R(5), CUrange(1, 8), Usubone(16, 4)], [Ex_BASE];
+    0b01111111_00000000_10010100_00000000 = [H, S, Imm] => [R(0), R(5), CUrange(1, 16), Usubone(16, 5)], [Ex_BASE];
+    0b01111111_00000000_10010100_00000000 = [S, D, Imm] => [R(0), R(5), CUrange(1, 32), Usubone(16, 6)], [Ex_BASE];
+    0b00101111_00001000_10010100_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b00101111_00010000_10010100_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b00101111_00100000_10010100_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "uqshrn2" = [
-    0b01101111_00001000_10010100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)];
-    0b01101111_00010000_10010100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)];
-    0b01101111_00100000_10010100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)];
+    0b01101111_00001000_10010100_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8), Imm] => [R(0), R(5), Usubone(16, 3)], [Ex_BASE];
+    0b01101111_00010000_10010100_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4), Imm] => [R(0), R(5), Usubone(16, 4)], [Ex_BASE];
+    0b01101111_00100000_10010100_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 5)], [Ex_BASE];
 ]
 "uqsub" = [
-    0b01111110_00100000_00101100_00000000 = [B, B, B] => [R(0), R(5), R(16)];
-    0b01111110_01100000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01111110_10100000_00101100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b01111110_11100000_00101100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_00100000_00101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_00101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_00101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_00101100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_00100000_00101100_00000000 = [B, B, B] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_01100000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_10100000_00101100_00000000 = [S, S, S] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01111110_11100000_00101100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_00100000_00101100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_00101100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_00101100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_00101100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // UQSUB (vectors, predicated)
+    0b01000100_00011011_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UQSUB (vectors, predicated)
+    0b01000100_01011011_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UQSUB (vectors, predicated)
+    0b01000100_10011011_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UQSUB (vectors, predicated)
+    0b01000100_11011011_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // UQSUB (vectors, unpredicated)
+    0b00000100_00100000_00011100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UQSUB (vectors, unpredicated)
+    0b00000100_01100000_00011100_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UQSUB (vectors, unpredicated)
+    0b00000100_10100000_00011100_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UQSUB (vectors, unpredicated)
+    0b00000100_11100000_00011100_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UQSUB (immediate)
+    0b00100101_00100111_11000000_00000000 = [Z(BYTE), Z(BYTE), Imm] => [R(0), RTied, Ubits(5, 8)], [Ex_SVE];
+    0b00100101_01100111_11000000_00000000 = [Z(B_2), Z(B_2), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_10100111_11000000_00000000 = [Z(B_4), Z(B_4), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
+    0b00100101_11100111_11000000_00000000 = [Z(B_8), Z(B_8), Imm, End, LitMod(LSL)] => [R(0), RTied, Ubits(5, 8), Ulist(13, &[0, 8])], [Ex_SVE];
 ]
 "uqxtn" = [
-    0b01111110_00100001_01001000_00000000 = [B, H] => [R(0), R(5)];
-    0b01111110_01100001_01001000_00000000 = [H, S] => [R(0), R(5)];
-    0b01111110_10100001_01001000_00000000 = [S, D] => [R(0), R(5)];
-    0b00101110_00100001_01001000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8)] => [R(0), R(5)];
-    0b00101110_01100001_01001000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4)] => [R(0), R(5)];
-    0b00101110_10100001_01001000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01111110_00100001_01001000_00000000 = [B, H] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_01100001_01001000_00000000 = [H, S] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_10100001_01001000_00000000 = [S, D] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_00100001_01001000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8)] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_01100001_01001000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4)] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_10100001_01001000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
 ]
 "uqxtn2" = [
-    0b01101110_00100001_01001000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8)] => [R(0), R(5)];
-    0b01101110_01100001_01001000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4)] => [R(0), R(5)];
-    0b01101110_10100001_01001000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01101110_00100001_01001000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8)] => [R(0), R(5)], [Ex_BASE];
+    0b01101110_01100001_01001000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4)] => [R(0), R(5)], [Ex_BASE];
+    0b01101110_10100001_01001000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
+]
+"uqxtnb" = [
+    0b01000101_00101000_01001000_00000000 = [Z(BYTE), Z(B_2)] => [R(0), R(5)], [Ex_SVE2];
+    0b01000101_00110000_01001000_00000000 = [Z(B_2), Z(B_4)] => [R(0), R(5)], [Ex_SVE2];
+    0b01000101_01100000_01001000_00000000 = [Z(B_4), Z(B_8)] => [R(0), R(5)], [Ex_SVE2];
+]
+"uqxtnt" = [
+    0b01000101_00101000_01001100_00000000 = [Z(BYTE), Z(B_2)] => [R(0), R(5)], [Ex_SVE2];
+    0b01000101_00110000_01001100_00000000 = [Z(B_2), Z(B_4)] => [R(0), R(5)], [Ex_SVE2];
+    0b01000101_01100000_01001100_00000000 = [Z(B_4), Z(B_8)] => [R(0), R(5)], [Ex_SVE2];
 ]
 "urecpe" = [
-    0b00001110_10100001_11001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10100001_11001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
 ]
 "urhadd" = [
-    0b00101110_00100000_00010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_00010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_00010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_00100000_00010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_00010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_00010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // URHADD (vectors, predicated)
+    0b01000100_00010101_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // URHADD (vectors, predicated)
+    0b01000100_01010101_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // URHADD (vectors, predicated)
+    0b01000100_10010101_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // URHADD (vectors, predicated)
+    0b01000100_11010101_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "urshl" = [
-    0b01111110_11100000_01010100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_00100000_01010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_01010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_01010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_01010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_11100000_01010100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_00100000_01010100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_01010100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_01010100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_01010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // URSHL (vectors, predicated)
+    0b01000100_00000011_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // URSHL (vectors, predicated)
+    0b01000100_01000011_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // URSHL (vectors, predicated)
+    0b01000100_10000011_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // URSHL (vectors, predicated)
+    0b01000100_11000011_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "urshr" = [
-    0b01111111_00000000_00100100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)];
-    0b00101111_00001000_00100100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)];
-    0b00101111_00010000_00100100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)];
-    0b00101111_00100000_00100100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)];
-    0b00101111_01000000_00100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)];
+    0b01111111_00000000_00100100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)], [Ex_BASE];
+    0b00101111_00001000_00100100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)], [Ex_BASE];
+    0b00101111_00010000_00100100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)], [Ex_BASE];
+    0b00101111_00100000_00100100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00101111_01000000_00100100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)], [Ex_BASE];
 ]
 "ursqrte" = [
-    0b00101110_10100001_11001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_10100001_11001000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
 ]
 "ursra" = [
-    0b01111111_00000000_00110100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)];
-    0b00101111_00001000_00110100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)];
-    0b00101111_00010000_00110100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)];
-    0b00101111_00100000_00110100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)];
-    0b00101111_01000000_00110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)];
+    0b01111111_00000000_00110100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)], [Ex_BASE];
+    0b00101111_00001000_00110100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)], [Ex_BASE];
+    0b00101111_00010000_00110100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)], [Ex_BASE];
+    0b00101111_00100000_00110100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00101111_01000000_00110100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)], [Ex_BASE];
 ]
 "ushl" = [
-    0b01111110_11100000_01000100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_00100000_01000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_01100000_01000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10100000_01000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_11100000_01000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_11100000_01000100_00000000 = [D, D, D] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_00100000_01000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_01000100_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_01000100_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_01000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
 ]
 "ushll" = [
-    0b00101111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), Imm] => [R(0), R(5), Ubits(16, 3)];
-    0b00101111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), Imm] => [R(0), R(5), Ubits(16, 4)];
-    0b00101111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), Imm] => [R(0), R(5), Ubits(16, 5)];
+    0b00101111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), Imm] => [R(0), R(5), Ubits(16, 3)], [Ex_BASE];
+    0b00101111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), Imm] => [R(0), R(5), Ubits(16, 4)], [Ex_BASE];
+    0b00101111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), Imm] => [R(0), R(5), Ubits(16, 5)], [Ex_BASE];
 ]
 "ushll2" = [
-    0b01101111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), Imm] => [R(0), R(5), Ubits(16, 3)];
-    0b01101111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), Imm] => [R(0), R(5), Ubits(16, 4)];
-    0b01101111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), Imm] => [R(0), R(5), Ubits(16, 5)];
+    0b01101111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), Imm] => [R(0), R(5), Ubits(16, 3)], [Ex_BASE];
+    0b01101111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), Imm] => [R(0), R(5), Ubits(16, 4)], [Ex_BASE];
+    0b01101111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), Imm] => [R(0), R(5), Ubits(16, 5)], [Ex_BASE];
 ]
 "ushr" = [
-    0b01111111_00000000_00000100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)];
-    0b00101111_00001000_00000100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)];
-    0b00101111_00010000_00000100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)];
-    0b00101111_00100000_00000100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)];
-    0b00101111_01000000_00000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)];
+    0b01111111_00000000_00000100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)], [Ex_BASE];
+    0b00101111_00001000_00000100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)], [Ex_BASE];
+    0b00101111_00010000_00000100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)], [Ex_BASE];
+    0b00101111_00100000_00000100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00101111_01000000_00000100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)], [Ex_BASE];
+]
+"usmopa" = [
+    0b10100001_10000000_00000000_00000000 = [ZaTile(B_4), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], [Ex_SME];
+    0b10100001_11000000_00000000_00000000 = [ZaTile(B_8), PMerge, PMerge, Z(B_2), Z(B_2)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], [Ex_SME_I16I64];
+]
+"usmops" = [
+    0b10100001_10000000_00000000_00010000 = [ZaTile(B_4), PMerge, PMerge, Z(BYTE), Z(BYTE)] => [RTile(0, 2), R3(10), R3(13), R(5), R(16)], [Ex_SME];
+    0b10100001_11000000_00000000_00010000 = [ZaTile(B_8), PMerge, PMerge, Z(B_2), Z(B_2)] => [RTile(0, 3), R3(10), R3(13), R(5), R(16)], [Ex_SME_I16I64];
 ]
 "usqadd" = [
-    0b01111110_00100000_00111000_00000000 = [B, B] => [R(0), R(5)];
-    0b01111110_01100000_00111000_00000000 = [H, H] => [R(0), R(5)];
-    0b01111110_10100000_00111000_00000000 = [S, S] => [R(0), R(5)];
-    0b01111110_11100000_00111000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_00100000_00111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_01100000_00111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_10100000_00111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)];
-    0b00101110_11100000_00111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)];
+    0b01111110_00100000_00111000_00000000 = [B, B] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_01100000_00111000_00000000 = [H, H] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_10100000_00111000_00000000 = [S, S] => [R(0), R(5)], [Ex_BASE];
+    0b01111110_11100000_00111000_00000000 = [D, D] => [R(0), R(5)], [Ex_BASE];
+    0b00101110_00100000_00111000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_01100000_00111000_00000000 = [V(B_2), V(B_2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_10100000_00111000_00000000 = [V(B_4), V(B_4)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    0b00101110_11100000_00111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), Rwidth(30)], [Ex_BASE];
+    // USQADD (vectors, predicated)
+    0b01000100_00011101_10000000_00000000 = [Z(BYTE), PMerge, Z(BYTE), Z(BYTE)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // USQADD (vectors, predicated)
+    0b01000100_01011101_10000000_00000000 = [Z(B_2), PMerge, Z(B_2), Z(B_2)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // USQADD (vectors, predicated)
+    0b01000100_10011101_10000000_00000000 = [Z(B_4), PMerge, Z(B_4), Z(B_4)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
+    // USQADD (vectors, predicated)
+    0b01000100_11011101_10000000_00000000 = [Z(B_8), PMerge, Z(B_8), Z(B_8)] => [R(0), R3(10), RTied, R(5)], [Ex_SVE2];
 ]
 "usra" = [
-    0b01111111_00000000_00010100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)];
-    0b00101111_00001000_00010100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)];
-    0b00101111_00010000_00010100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)];
-    0b00101111_00100000_00010100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)];
-    0b00101111_01000000_00010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)];
+    0b01111111_00000000_00010100_00000000 = [D, D, Imm] => [R(0), R(5), CUrange(1, 64), Usubone(16, 7)], [Ex_BASE];
+    0b00101111_00001000_00010100_00000000 = [V(BYTE), V(BYTE), Imm] => [R(0), R(5), Usubone(16, 3), Rwidth(30)], [Ex_BASE];
+    0b00101111_00010000_00010100_00000000 = [V(B_2), V(B_2), Imm] => [R(0), R(5), Usubone(16, 4), Rwidth(30)], [Ex_BASE];
+    0b00101111_00100000_00010100_00000000 = [V(B_4), V(B_4), Imm] => [R(0), R(5), Usubone(16, 5), Rwidth(30)], [Ex_BASE];
+    0b00101111_01000000_00010100_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), Usubone(16, 6), Rwidth(30)], [Ex_BASE];
 ]
 "usubl" = [
-    0b00101110_00100000_00100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00101110_01100000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00101110_10100000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00101110_00100000_00100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_01100000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_10100000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "usubl2" = [
-    0b01101110_00100000_00100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01101110_01100000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01101110_10100000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01101110_00100000_00100000_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_01100000_00100000_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_10100000_00100000_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"usublb" = [
+    0b01000101_01000000_00011000_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_00011000_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_00011000_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"usublt" = [
+    0b01000101_01000000_00011100_00000000 = [Z(B_2), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_00011100_00000000 = [Z(B_4), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_00011100_00000000 = [Z(B_8), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
 ]
 "usubw" = [
-    0b00101110_00100000_00110000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00101110_01100000_00110000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)];
-    0b00101110_10100000_00110000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)];
+    0b00101110_00100000_00110000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_01100000_00110000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b00101110_10100000_00110000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 2)] => [R(0), R(5), R(16)], [Ex_BASE];
 ]
 "usubw2" = [
-    0b01101110_00100000_00110000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01101110_01100000_00110000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 8)] => [R(0), R(5), R(16)];
-    0b01101110_10100000_00110000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 4)] => [R(0), R(5), R(16)];
+    0b01101110_00100000_00110000_00000000 = [VStatic(B_2, 8), VStatic(B_2, 8), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_01100000_00110000_00000000 = [VStatic(B_4, 4), VStatic(B_4, 4), VStatic(B_2, 8)] => [R(0), R(5), R(16)], [Ex_BASE];
+    0b01101110_10100000_00110000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_4, 4)] => [R(0), R(5), R(16)], [Ex_BASE];
+]
+"usubwb" = [
+    0b01000101_01000000_01011000_00000000 = [Z(B_2), Z(B_2), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_01011000_00000000 = [Z(B_4), Z(B_4), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_01011000_00000000 = [Z(B_8), Z(B_8), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"usubwt" = [
+    0b01000101_01000000_01011100_00000000 = [Z(B_2), Z(B_2), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_10000000_01011100_00000000 = [Z(B_4), Z(B_4), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE2];
+    0b01000101_11000000_01011100_00000000 = [Z(B_8), Z(B_8), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE2];
+]
+"uunpkhi" = [
+    0b00000101_01110011_00111000_00000000 = [Z(B_2), Z(BYTE)] => [R(0), R(5)], [Ex_SVE];
+    0b00000101_10110011_00111000_00000000 = [Z(B_4), Z(B_2)] => [R(0), R(5)], [Ex_SVE];
+    0b00000101_11110011_00111000_00000000 = [Z(B_8), Z(B_4)] => [R(0), R(5)], [Ex_SVE];
+]
+"uunpklo" = [
+    0b00000101_01110010_00111000_00000000 = [Z(B_2), Z(BYTE)] => [R(0), R(5)], [Ex_SVE];
+    0b00000101_10110010_00111000_00000000 = [Z(B_4), Z(B_2)] => [R(0), R(5)], [Ex_SVE];
+    0b00000101_11110010_00111000_00000000 = [Z(B_8), Z(B_4)] => [R(0), R(5)], [Ex_SVE];
 ]
 "uxtb" = [
-    0b01010011_00000000_00011100_00000000 = [W, W] => [R(0), R(5)];
+    0b01010011_00000000_00011100_00000000 = [W, W] => [R(0), R(5)], [Ex_BASE];
+    // UXTB (predicated)
+    0b00000100_01010001_10100000_00000000 = [Z(B_2), PMerge, Z(B_2)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // UXTB (predicated)
+    0b00000100_10010001_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // UXTB (predicated)
+    0b00000100_11010001_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "uxth" = [
-    0b01010011_00000000_00111100_00000000 = [W, W] => [R(0), R(5)];
+    0b01010011_00000000_00111100_00000000 = [W, W] => [R(0), R(5)], [Ex_BASE];
+    // UXTH (predicated)
+    0b00000100_10010011_10100000_00000000 = [Z(B_4), PMerge, Z(B_4)] => [R(0), R3(10), R(5)], [Ex_SVE];
+    // UXTH (predicated)
+    0b00000100_11010011_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "uxtl" = [
-    0b00101111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8)] => [R(0), R(5)];
-    0b00101111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4)] => [R(0), R(5)];
-    0b00101111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2)] => [R(0), R(5)];
+    0b00101111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 8)] => [R(0), R(5)], [Ex_BASE];
+    0b00101111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 4)] => [R(0), R(5)], [Ex_BASE];
+    0b00101111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 2)] => [R(0), R(5)], [Ex_BASE];
 ]
 "uxtl2" = [
-    0b01101111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16)] => [R(0), R(5)];
-    0b01101111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8)] => [R(0), R(5)];
-    0b01101111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4)] => [R(0), R(5)];
+    0b01101111_00001000_10100100_00000000 = [VStatic(B_2, 8), VStatic(BYTE, 16)] => [R(0), R(5)], [Ex_BASE];
+    0b01101111_00010000_10100100_00000000 = [VStatic(B_4, 4), VStatic(B_2, 8)] => [R(0), R(5)], [Ex_BASE];
+    0b01101111_00100000_10100100_00000000 = [VStatic(B_8, 2), VStatic(B_4, 4)] => [R(0), R(5)], [Ex_BASE];
+]
+"uxtw" = [
+    // UXTW (predicated)
+    0b00000100_11010101_10100000_00000000 = [Z(B_8), PMerge, Z(B_8)] => [R(0), R3(10), R(5)], [Ex_SVE];
 ]
 "uzp1" = [
-    0b00001110_00000000_00011000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01000000_00011000_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10000000_00011000_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11000000_00011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00000000_00011000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01000000_00011000_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10000000_00011000_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11000000_00011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // UZP1 (vectors, unpredicated)
+    0b00000101_00100000_01101000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UZP1 (vectors, unpredicated)
+    0b00000101_01100000_01101000_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UZP1 (vectors, unpredicated)
+    0b00000101_10100000_01101000_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UZP1 (vectors, unpredicated)
+    0b00000101_11100000_01101000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UZP1 (predicates)
+    0b00000101_00100000_01001000_00000000 = [P(BYTE), P(BYTE), P(BYTE)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_01100000_01001000_00000000 = [P(B_2), P(B_2), P(B_2)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_10100000_01001000_00000000 = [P(B_4), P(B_4), P(B_4)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_11100000_01001000_00000000 = [P(B_8), P(B_8), P(B_8)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
 ]
 "uzp2" = [
-    0b00001110_00000000_01011000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01000000_01011000_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10000000_01011000_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11000000_01011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00000000_01011000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01000000_01011000_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10000000_01011000_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11000000_01011000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // UZP2 (vectors, unpredicated)
+    0b00000101_00100000_01101100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UZP2 (vectors, unpredicated)
+    0b00000101_01100000_01101100_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UZP2 (vectors, unpredicated)
+    0b00000101_10100000_01101100_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UZP2 (vectors, unpredicated)
+    0b00000101_11100000_01101100_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // UZP2 (predicates)
+    0b00000101_00100000_01001100_00000000 = [P(BYTE), P(BYTE), P(BYTE)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_01100000_01001100_00000000 = [P(B_2), P(B_2), P(B_2)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_10100000_01001100_00000000 = [P(B_4), P(B_4), P(B_4)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_11100000_01001100_00000000 = [P(B_8), P(B_8), P(B_8)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
 ]
 "wfe" = [
-    0b11010101_00000011_00100000_01011111 = [] => [];
+    0b11010101_00000011_00100000_01011111 = [] => [], [Ex_BASE];
 ]
 "wfi" = [
-    0b11010101_00000011_00100000_01111111 = [] => [];
+    0b11010101_00000011_00100000_01111111 = [] => [], [Ex_BASE];
+]
+"whilege" = [
+    0b00100101_00100000_00000000_00000000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_01100000_00000000_00000000 = [P(B_2), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_10100000_00000000_00000000 = [P(B_4), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_11100000_00000000_00000000 = [P(B_8), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_00100000_00010000_00000000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_01100000_00010000_00000000 = [P(B_2), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_10100000_00010000_00000000 = [P(B_4), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_11100000_00010000_00000000 = [P(B_8), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+]
+"whilegt" = [
+    0b00100101_00100000_00000000_00010000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_01100000_00000000_00010000 = [P(B_2), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_10100000_00000000_00010000 = [P(B_4), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_11100000_00000000_00010000 = [P(B_8), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_00100000_00010000_00010000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_01100000_00010000_00010000 = [P(B_2), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_10100000_00010000_00010000 = [P(B_4), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_11100000_00010000_00010000 = [P(B_8), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+]
+"whilehi" = [
+    0b00100101_00100000_00001000_00010000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_01100000_00001000_00010000 = [P(B_2), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_10100000_00001000_00010000 = [P(B_4), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_11100000_00001000_00010000 = [P(B_8), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_00100000_00011000_00010000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_01100000_00011000_00010000 = [P(B_2), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_10100000_00011000_00010000 = [P(B_4), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_11100000_00011000_00010000 = [P(B_8), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+]
+"whilehs" = [
+    0b00100101_00100000_00001000_00000000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_01100000_00001000_00000000 = [P(B_2), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_10100000_00001000_00000000 = [P(B_4), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_11100000_00001000_00000000 = [P(B_8), W, W] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_00100000_00011000_00000000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_01100000_00011000_00000000 = [P(B_2), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_10100000_00011000_00000000 = [P(B_4), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_11100000_00011000_00000000 = [P(B_8), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+]
+"whilele" = [
+    0b00100101_00100000_00000100_00010000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_01100000_00000100_00010000 = [P(B_2), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_10100000_00000100_00010000 = [P(B_4), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_11100000_00000100_00010000 = [P(B_8), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_00100000_00010100_00010000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_01100000_00010100_00010000 = [P(B_2), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_10100000_00010100_00010000 = [P(B_4), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_11100000_00010100_00010000 = [P(B_8), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+]
+"whilelo" = [
+    0b00100101_00100000_00001100_00000000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_01100000_00001100_00000000 = [P(B_2), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_10100000_00001100_00000000 = [P(B_4), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_11100000_00001100_00000000 = [P(B_8), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_00100000_00011100_00000000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_01100000_00011100_00000000 = [P(B_2), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_10100000_00011100_00000000 = [P(B_4), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_11100000_00011100_00000000 = [P(B_8), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+]
+"whilels" = [
+    0b00100101_00100000_00001100_00010000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_01100000_00001100_00010000 = [P(B_2), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_10100000_00001100_00010000 = [P(B_4), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_11100000_00001100_00010000 = [P(B_8), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_00100000_00011100_00010000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_01100000_00011100_00010000 = [P(B_2), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_10100000_00011100_00010000 = [P(B_4), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_11100000_00011100_00010000 = [P(B_8), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+]
+"whilelt" = [
+    0b00100101_00100000_00000100_00000000 = [P(BYTE), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_01100000_00000100_00000000 = [P(B_2), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_10100000_00000100_00000000 = [P(B_4), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_11100000_00000100_00000000 = [P(B_8), W, W] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_00100000_00010100_00000000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_01100000_00010100_00000000 = [P(B_2), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_10100000_00010100_00000000 = [P(B_4), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+    0b00100101_11100000_00010100_00000000 = [P(B_8), X, X] => [R4(0), R(5), R(16)], [Ex_SVE];
+]
+"whilerw" = [
+    0b00100101_00100000_00110000_00010000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_01100000_00110000_00010000 = [P(B_2), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_10100000_00110000_00010000 = [P(B_4), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_11100000_00110000_00010000 = [P(B_8), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+]
+"whilewr" = [
+    0b00100101_00100000_00110000_00000000 = [P(BYTE), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_01100000_00110000_00000000 = [P(B_2), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_10100000_00110000_00000000 = [P(B_4), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
+    0b00100101_11100000_00110000_00000000 = [P(B_8), X, X] => [R4(0), R(5), R(16)], [Ex_SVE2];
 ]
 "xar" = [
-    0b11001110_10000000_00000000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), R(16), Ubits(10, 6)];
+    0b11001110_10000000_00000000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2), Imm] => [R(0), R(5), R(16), Ubits(10, 6)], [Ex_SHA3];
 ]
 "xpacd" = [
-    0b11011010_11000001_01000111_11100000 = [X] => [R(0)];
+    0b11011010_11000001_01000111_11100000 = [X] => [R(0)], [Ex_PAUTH];
 ]
 "xpaci" = [
-    0b11011010_11000001_01000011_11100000 = [X] => [R(0)];
+    0b11011010_11000001_01000011_11100000 = [X] => [R(0)], [Ex_PAUTH];
 ]
 "xpaclri" = [
-    0b11010101_00000011_00100000_11111111 = [] => [];
+    0b11010101_00000011_00100000_11111111 = [] => [], [Ex_PAUTH];
 ]
 "xtn" = [
-    0b00001110_00100001_00101000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8)] => [R(0), R(5)];
-    0b00001110_01100001_00101000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4)] => [R(0), R(5)];
-    0b00001110_10100001_00101000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2)] => [R(0), R(5)];
+    0b00001110_00100001_00101000_00000000 = [VStatic(BYTE, 8), VStatic(B_2, 8)] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_01100001_00101000_00000000 = [VStatic(B_2, 4), VStatic(B_4, 4)] => [R(0), R(5)], [Ex_BASE];
+    0b00001110_10100001_00101000_00000000 = [VStatic(B_4, 2), VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
 ]
 "xtn2" = [
-    0b01001110_00100001_00101000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8)] => [R(0), R(5)];
-    0b01001110_01100001_00101000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4)] => [R(0), R(5)];
-    0b01001110_10100001_00101000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2)] => [R(0), R(5)];
+    0b01001110_00100001_00101000_00000000 = [VStatic(BYTE, 16), VStatic(B_2, 8)] => [R(0), R(5)], [Ex_BASE];
+    0b01001110_01100001_00101000_00000000 = [VStatic(B_2, 8), VStatic(B_4, 4)] => [R(0), R(5)], [Ex_BASE];
+    0b01001110_10100001_00101000_00000000 = [VStatic(B_4, 4), VStatic(B_8, 2)] => [R(0), R(5)], [Ex_BASE];
 ]
 "yield" = [
-    0b11010101_00000011_00100000_00111111 = [] => [];
+    0b11010101_00000011_00100000_00111111 = [] => [], [Ex_BASE];
+]
+"zero" = [
+    0b11000000_00001000_00000000_00000000 = [ZaTileList] => [Ubits(0, 8)], [Ex_SME];
 ]
 "zip1" = [
-    0b00001110_00000000_00111000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01000000_00111000_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10000000_00111000_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11000000_00111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00000000_00111000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01000000_00111000_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10000000_00111000_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11000000_00111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // ZIP1 (vectors, unpredicated)
+    0b00000101_00100000_01100000_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // ZIP1 (vectors, unpredicated)
+    0b00000101_01100000_01100000_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // ZIP1 (vectors, unpredicated)
+    0b00000101_10100000_01100000_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // ZIP1 (vectors, unpredicated)
+    0b00000101_11100000_01100000_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // ZIP1 (predicates)
+    0b00000101_00100000_01000000_00000000 = [P(BYTE), P(BYTE), P(BYTE)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_01100000_01000000_00000000 = [P(B_2), P(B_2), P(B_2)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_10100000_01000000_00000000 = [P(B_4), P(B_4), P(B_4)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_11100000_01000000_00000000 = [P(B_8), P(B_8), P(B_8)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
 ]
 "zip2" = [
-    0b00001110_00000000_01111000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_01000000_01111000_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_10000000_01111000_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00001110_11000000_01111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_00000000_01111000_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_01000000_01111000_00000000 = [V(B_2), V(B_2), V(B_2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_10000000_01111000_00000000 = [V(B_4), V(B_4), V(B_4)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    0b00001110_11000000_01111000_00000000 = [VStatic(B_8, 2), VStatic(B_8, 2), VStatic(B_8, 2)] => [R(0), R(5), R(16), Rwidth(30)], [Ex_BASE];
+    // ZIP2 (vectors, unpredicated)
+    0b00000101_00100000_01100100_00000000 = [Z(BYTE), Z(BYTE), Z(BYTE)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // ZIP2 (vectors, unpredicated)
+    0b00000101_01100000_01100100_00000000 = [Z(B_2), Z(B_2), Z(B_2)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // ZIP2 (vectors, unpredicated)
+    0b00000101_10100000_01100100_00000000 = [Z(B_4), Z(B_4), Z(B_4)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // ZIP2 (vectors, unpredicated)
+    0b00000101_11100000_01100100_00000000 = [Z(B_8), Z(B_8), Z(B_8)] => [R(0), R(5), R(16)], [Ex_SVE];
+    // ZIP2 (predicates)
+    0b00000101_00100000_01000100_00000000 = [P(BYTE), P(BYTE), P(BYTE)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_01100000_01000100_00000000 = [P(B_2), P(B_2), P(B_2)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_10100000_01000100_00000000 = [P(B_4), P(B_4), P(B_4)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
+    0b00000101_11100000_01000100_00000000 = [P(B_8), P(B_8), P(B_8)] => [R4(0), R4(5), R4(16)], [Ex_SVE];
 ]
 
 )
@@ -1,12 +1,15 @@
 use syn::{parse, Token};
+use proc_macro2::Span;
+use quote::quote_spanned;
 
 use lazy_static::lazy_static;
 
 use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOpt, ParseOptExt};
 use crate::common::Size;
+use crate::serialize;
 
 use super::Context;
-use super::ast::{Instruction, RawArg, Register, RegId, RegKind, RegScalar, RegVector, RegFamily, RefItem, Modifier, ModifyExpr};
+use super::ast::{Instruction, RawArg, Register, RegId, RegKind, RegScalar, RegVector, RegScalable, RegPredicate, RegMatrix, PredicateQualifier, SliceDirection, RegFamily, RefItem, Modifier, ModifyExpr, LitPoolTarget};
 
 use std::collections::HashMap;
 
@@ -55,6 +58,23 @@ pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) ->
 fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawArg> {
     let _start = input.cursor().span(); // FIXME can't join spans yet
 
+    // a literal pool entry. Note that =>label is a dynamic label, while ==>label is a literal pool entry
+    if input.peek(Token![=]) && !input.peek(Token![=>]) {
+        let span = input.cursor().span();
+        let _: Token![=] = input.parse()?;
+
+        let target = if let Some(jump) = input.parse_opt()? {
+            LitPoolTarget::Label(jump)
+        } else {
+            LitPoolTarget::Value(input.parse()?)
+        };
+
+        return Ok(RawArg::LitPool {
+            span,
+            target
+        });
+    }
+
     // a label
     if let Some(jump) = input.parse_opt()? {
         return Ok(RawArg::JumpTarget {
@@ -69,6 +89,14 @@ fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawA
         let _ = syn::bracketed!(inner in input);
         let inner = &inner;
 
+        // a label reference, as used by the ldr Rt, [label] pseudo-instruction
+        if let Some(jump) = inner.parse_opt()? {
+            return Ok(RawArg::LabelReference {
+                span,
+                jump
+            });
+        }
+
         // parse comma-separated inner items
         let mut items = Vec::new();
         items.push(parse_refitem(ctx, inner)?);
@@ -185,6 +213,20 @@ fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawA
 
     // register
     if let Some(reg) = parse_reg(ctx, input)? {
+        // register with writeback
+        if input.peek(Token![!]) {
+            let _: Token![!] = input.parse()?;
+            return Ok(RawArg::Writeback {
+                reg,
+                span: _start
+            })
+        }
+
+        // type mapped reference
+        if input.peek(Token![=>]) {
+            return parse_typemap(ctx, input, reg, _start);
+        }
+
         return Ok(RawArg::Direct {
             reg,
             span: _start
@@ -199,6 +241,71 @@ fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawA
     })
 }
 
+/// Parses the remainder of a type mapped reference, `base => Type ("[" index "]")? ("." attr)?`
+/// and lowers it to a normal memory reference. An immediate index and the attribute are combined
+/// into the offset, while an index register is scaled by the size of the type, which then has
+/// to match the size of the access.
+fn parse_typemap(ctx: &mut Context, input: parse::ParseStream, base: Register, span: Span) -> parse::Result<RawArg> {
+    let _: Token![=>] = input.parse()?;
+
+    let ty: syn::Path = input.parse()?;
+
+    let mut index_reg = None;
+    let mut index = None;
+    if input.peek(syn::token::Bracket) {
+        let inner;
+        let _ = syn::bracketed!(inner in input);
+        let inner = &inner;
+
+        if let Some(reg) = parse_reg(ctx, inner)? {
+            if !inner.is_empty() {
+                return Err(inner.error("Cannot combine an index register with a displacement"));
+            }
+            index_reg = Some(reg);
+        } else {
+            index = Some(inner.parse::<syn::Expr>()?);
+        }
+    }
+
+    let attr: Option<syn::Ident> = if input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        Some(input.parse()?)
+    } else {
+        None
+    };
+
+    let mut items = vec![RefItem::Direct { reg: base }];
+
+    if let Some(index) = index_reg {
+        if let Some(attr) = attr {
+            return Err(parse::Error::new(attr.span(), "Cannot combine an index register with an attribute offset"));
+        }
+
+        // the shift amount is a constant block, so both the type size and the resulting shift
+        // can be checked at compile time.
+        let shift = quote_spanned! { span=>
+            const {
+                let _size = ::std::mem::size_of::<#ty>();
+                ::std::assert!(_size.is_power_of_two(), "The size of a type indexed by a register has to be a power of two");
+                _size.trailing_zeros()
+            }
+        };
+        let op = if index.size() == Some(Size::B_4) { Modifier::SXTW } else { Modifier::LSL };
+
+        items.push(RefItem::Direct { reg: index });
+        items.push(RefItem::Modifier { modifier: ModifyExpr::new(op, Some(syn::parse2(shift)?)) });
+
+    } else if let Some(disp) = serialize::expr_typemap_disp(span, &ty, index.as_ref(), attr.as_ref()) {
+        items.push(RefItem::Immediate { value: serialize::reparse(&disp)? });
+    }
+
+    Ok(RawArg::Reference {
+        span,
+        items,
+        bang: false
+    })
+}
+
 fn parse_refitem(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RefItem> {
     let _start = input.cursor().span(); // FIXME can't join spans yet
 
@@ -233,12 +340,17 @@ fn parse_refitem(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<
 }
 
 fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<Register>> {
-    let name = match input.step(|cursor| {
+    let (name, direction) = match input.step(|cursor| {
         if let Some((ident, rest)) = cursor.ident() {
             let mut ident = ident.to_string();
 
             if AARCH64_FAMILIES.contains_key(&*ident) {
-                return Ok((ident, rest));
+                return Ok(((ident, None), rest));
+            }
+
+            // ZA tile slices carry their direction as a suffix of the tile name
+            if let Some((tile, direction)) = split_slice_direction(&ident) {
+                return Ok(((tile.to_string(), Some(direction)), rest));
             }
 
             if let Some(repl) = ctx.state.invocation_context.aliases.get(&ident) {
@@ -246,7 +358,7 @@ fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Opti
             }
 
             if AARCH64_REGISTERS.contains_key(&*ident) {
-                return Ok((ident, rest));
+                return Ok(((ident, None), rest));
             }
         }
         Err(cursor.error("expected identifier"))
@@ -282,6 +394,96 @@ fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Opti
             kind,
             size
         })))
+    } else if kind.family() == RegFamily::SVE {
+        // parse possible scalable vector trailers
+
+        // parse the optional element size specifier ("." [BHSDQ])
+        let element_size = if input.peek(Token![.]) {
+            let _: Token![.] = input.parse()?;
+            Some(parse_element_size(input, true)?)
+        } else {
+            None
+        };
+
+        // parse the element specifier
+        let element = if element_size.is_some() && input.peek(syn::token::Bracket) {
+            let inner;
+            let _ = syn::bracketed!(inner in input);
+            let inner = &inner;
+
+            let expr: syn::Expr = inner.parse()?;
+            Some(expr)
+        } else {
+            None
+        };
+
+        Ok(Some(Register::Scalable(RegScalable {
+            kind,
+            element_size,
+            element
+        })))
+    } else if kind.family() == RegFamily::PREDICATE {
+        // parse possible predicate trailers. Either an element size specifier ("." [BHSD])
+        // or a zeroing/merging qualifier ("/" [ZM])
+        let mut element_size = None;
+        let mut qualifier = None;
+
+        if input.peek(Token![.]) {
+            let _: Token![.] = input.parse()?;
+            element_size = Some(parse_element_size(input, false)?);
+
+        } else if input.peek(Token![/]) {
+            let _: Token![/] = input.parse()?;
+            qualifier = Some(input.step(|cursor| {
+                if let Some((ident, rest)) = cursor.ident() {
+                    match &*ident.to_string() {
+                        "Z" | "z" => return Ok((PredicateQualifier::Zeroing, rest)),
+                        "M" | "m" => return Ok((PredicateQualifier::Merging, rest)),
+                        _ => ()
+                    }
+                }
+                Err(cursor.error("Expected a predicate qualifier (/z or /m)"))
+            })?);
+        }
+
+        Ok(Some(Register::Predicate(RegPredicate {
+            kind,
+            element_size,
+            qualifier
+        })))
+    } else if kind.family() == RegFamily::MATRIX {
+        // parse possible ZA trailers. Tiles and tile slices take an element size specifier ("." [BHSDQ]),
+        // tile slices and array vectors are followed by a slice index ("[" Wv "," expr "]")
+        let element_size = if input.peek(Token![.]) {
+            let _: Token![.] = input.parse()?;
+            Some(parse_element_size(input, true)?)
+        } else {
+            None
+        };
+
+        let slice = if input.peek(syn::token::Bracket) {
+            let inner;
+            let _ = syn::bracketed!(inner in input);
+            let inner = &inner;
+
+            let index = match parse_reg(ctx, inner)? {
+                Some(Register::Scalar(s)) if s.size == Size::B_4 && s.kind.family() == RegFamily::INTEGER => s.kind,
+                _ => return Err(inner.error("Expected a W12-W15 slice index register"))
+            };
+
+            let _: Token![,] = inner.parse()?;
+            let offset: syn::Expr = inner.parse()?;
+            Some(Box::new((index, offset)))
+        } else {
+            None
+        };
+
+        Ok(Some(Register::Matrix(RegMatrix {
+            kind,
+            element_size,
+            direction,
+            slice
+        })))
     } else {
         // parse possible vector trailers
 
@@ -346,6 +548,41 @@ fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Opti
     }
 }
 
+/// splits the name of a ZA tile slice (like za0h or ZAV) into the name of the tile and the slice direction
+fn split_slice_direction(name: &str) -> Option<(&str, SliceDirection)> {
+    let direction = match name.as_bytes().last() {
+        Some(b'h') | Some(b'H') => SliceDirection::Horizontal,
+        Some(b'v') | Some(b'V') => SliceDirection::Vertical,
+        _ => return None
+    };
+
+    let tile = &name[.. name.len() - 1];
+    match (AARCH64_REGISTERS.get(tile), AARCH64_FAMILIES.get(tile)) {
+        (Some(&(id, _)), _) if id.family() == RegFamily::MATRIX && id != RegId::ZA => Some((tile, direction)),
+        (_, Some(&(RegFamily::MATRIX, _))) => Some((tile, direction)),
+        _ => None
+    }
+}
+
+/// parses the element size specifier of a scalable vector or predicate register ([BHSD], or Q if allowed)
+fn parse_element_size(input: parse::ParseStream, allow_q: bool) -> parse::Result<Size> {
+    input.step(|cursor| {
+        if let Some((ident, rest)) = cursor.ident() {
+            let element_size = match &*ident.to_string() {
+                "B" | "b" => Size::BYTE,
+                "H" | "h" => Size::B_2,
+                "S" | "s" => Size::B_4,
+                "D" | "d" => Size::B_8,
+                "Q" | "q" if allow_q => Size::B_16,
+                _ => return Err(cursor.error("Invalid element size specifier"))
+            };
+
+            return Ok((element_size, rest));
+        }
+        Err(cursor.error("Expected an element size specifier"))
+    })
+}
+
 impl ParseOpt for ModifyExpr {
     fn parse(input: parse::ParseStream) -> parse::Result<Option<Self>> {
         let modifier: Modifier = match input.parse() {
@@ -386,6 +623,15 @@ impl parse::Parse for Modifier {
                     "UXTH" | "uxth" => Modifier::UXTH,
                     "UXTB" | "uxtb" => Modifier::UXTB,
                     "MSL"  | "msl"  => Modifier::MSL,
+                    "MUL"  | "mul"  => {
+                        // MUL VL is used as a two-word modifier
+                        if let Some((next, next_rest)) = rest.ident() {
+                            if next == "VL" || next == "vl" {
+                                return Ok((Modifier::MULVL, next_rest));
+                            }
+                        }
+                        Modifier::MUL
+                    },
                     _ => return Err(cursor.error("Unknown modifier"))
                 };
 
@@ -670,6 +916,74 @@ lazy_static!{
             ("v29", (V29, None)),
             ("v30", (V30, None)),
             ("v31", (V31, None)),
+
+            ("z0" , (Z0 , None)),
+            ("z1" , (Z1 , None)),
+            ("z2" , (Z2 , None)),
+            ("z3" , (Z3 , None)),
+            ("z4" , (Z4 , None)),
+            ("z5" , (Z5 , None)),
+            ("z6" , (Z6 , None)),
+            ("z7" , (Z7 , None)),
+            ("z8" , (Z8 , None)),
+            ("z9" , (Z9 , None)),
+            ("z10", (Z10, None)),
+            ("z11", (Z11, None)),
+            ("z12", (Z12, None)),
+            ("z13", (Z13, None)),
+            ("z14", (Z14, None)),
+            ("z15", (Z15, None)),
+            ("z16", (Z16, None)),
+            ("z17", (Z17, None)),
+            ("z18", (Z18, None)),
+            ("z19", (Z19, None)),
+            ("z20", (Z20, None)),
+            ("z21", (Z21, None)),
+            ("z22", (Z22, None)),
+            ("z23", (Z23, None)),
+            ("z24", (Z24, None)),
+            ("z25", (Z25, None)),
+            ("z26", (Z26, None)),
+            ("z27", (Z27, None)),
+            ("z28", (Z28, None)),
+            ("z29", (Z29, None)),
+            ("z30", (Z30, None)),
+            ("z31", (Z31, None)),
+
+            ("p0" , (P0 , None)),
+            ("p1" , (P1 , None)),
+            ("p2" , (P2 , None)),
+            ("p3" , (P3 , None)),
+            ("p4" , (P4 , None)),
+            ("p5" , (P5 , None)),
+            ("p6" , (P6 , None)),
+            ("p7" , (P7 , None)),
+            ("p8" , (P8 , None)),
+            ("p9" , (P9 , None)),
+            ("p10", (P10, None)),
+            ("p11", (P11, None)),
+            ("p12", (P12, None)),
+            ("p13", (P13, None)),
+            ("p14", (P14, None)),
+            ("p15", (P15, None)),
+
+            ("za"  , (ZA  , None)),
+            ("za0" , (ZA0 , None)),
+            ("za1" , (ZA1 , None)),
+            ("za2" , (ZA2 , None)),
+            ("za3" , (ZA3 , None)),
+            ("za4" , (ZA4 , None)),
+            ("za5" , (ZA5 , None)),
+            ("za6" , (ZA6 , None)),
+            ("za7" , (ZA7 , None)),
+            ("za8" , (ZA8 , None)),
+            ("za9" , (ZA9 , None)),
+            ("za10", (ZA10, None)),
+            ("za11", (ZA11, None)),
+            ("za12", (ZA12, None)),
+            ("za13", (ZA13, None)),
+            ("za14", (ZA14, None)),
+            ("za15", (ZA15, None)),
         ];
         MAP.iter().cloned().collect()
     };
@@ -688,6 +1002,10 @@ lazy_static!{
             ("Q", (RegFamily::SIMD, Some(Size::B_16))),
 
             ("V", (RegFamily::SIMD, None)),
+
+            ("Z", (RegFamily::SVE, None)),
+            ("P", (RegFamily::PREDICATE, None)),
+            ("ZA", (RegFamily::MATRIX, None)),
         ];
         MAP.iter().cloned().collect()
     };
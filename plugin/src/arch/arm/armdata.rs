@@ -0,0 +1,462 @@
+//! this file contains the datastructure specification for the ARM and Thumb-2 encoding data
+use bitflags::bitflags;
+use lazy_static::lazy_static;
+
+use std::collections::{HashMap, hash_map};
+
+
+/// A template contains the information for the static parts of an instruction encoding, as well
+/// as its length
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Template {
+    /// A 32-bit ARM instruction
+    A32(u32),
+    /// A 16-bit Thumb instruction
+    T16(u16),
+    /// A 32-bit Thumb instruction. The first halfword is stored in the upper 16 bits
+    T32(u32),
+}
+
+impl Template {
+    /// The size of the encoded instruction in bytes
+    pub fn size(self) -> u8 {
+        match self {
+            Template::T16(_) => 2,
+            Template::A32(_)
+            | Template::T32(_) => 4
+        }
+    }
+
+    /// The base bits of the instruction
+    pub fn bits(self) -> u32 {
+        match self {
+            Template::A32(bits)
+            | Template::T32(bits) => bits,
+            Template::T16(bits) => u32::from(bits)
+        }
+    }
+
+    /// The bit offset of the condition field of a conditionally executed instruction
+    pub fn cond_offset(self) -> u8 {
+        match self {
+            Template::A32(_) => 28,
+            Template::T16(_) => 8,
+            Template::T32(_) => 22
+        }
+    }
+}
+
+
+bitflags! {
+    /// Flags specifying how an instruction interacts with conditional execution and the
+    /// instruction width selection.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Flags: u8 {
+        /// In ARM code, the instruction can be conditionally executed by a condition suffix,
+        /// which is encoded in bits 28-31. In Thumb code, the instruction is a conditional branch
+        /// that requires a condition suffix, encoded in bits 8-11 (16-bit) or bits 22-25 (32-bit).
+        const COND = 0x01;
+        /// Thumb: this encoding can only be used outside of an IT block (16-bit flag-setting forms)
+        const OUTSIDE_IT = 0x02;
+        /// Thumb: this encoding can only be used inside of an IT block (16-bit non-flag-setting forms)
+        const INSIDE_IT = 0x04;
+        /// Thumb: if used in an IT block, this instruction has to be the last instruction in it
+        const LAST_IN_IT = 0x08;
+        /// Thumb: this instruction cannot be used in an IT block
+        const NO_IT = 0x10;
+        /// Thumb: this 16-bit encoding is only used when explicitly requested with a `.n` suffix,
+        /// as its range is too limited to be a sensible default.
+        const EXPLICIT_N = 0x20;
+    }
+}
+
+
+/// Matchers. These are used to check if a certain argument is compatible with the instruction
+/// being assembled. Multiple matchers can match a single argument
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Matcher {
+    /// A general purpose register
+    R,
+
+    /// A general purpose register with writeback, `rn!`
+    Writeback,
+
+    /// A general purpose register used as post-indexed offset, which can be negated as `-rm`
+    Index,
+
+    /// An immediate
+    Imm,
+
+    /// A jump offset
+    Offset,
+
+    /// A specific literal (basically just an ident)
+    Lit(&'static str),
+
+    /// A random ident
+    Ident,
+
+    /// A shift by an immediate, `lsl/lsr/asr/ror #imm` or `rrx`
+    Shift,
+
+    /// A shift by a register, `lsl/lsr/asr/ror rs`
+    RegShift,
+
+    /// A rotation used by extension instructions, `ror #imm`
+    Ror,
+
+    /// A register list, `{r0, r4-r7, lr}`
+    RegList,
+
+    /// An indirect reference with optional offset, `[rn]` or `[rn, #imm]`. Expands args to R, Imm
+    RefOffset,
+
+    /// A pre-indexed reference, `[rn, #imm]!`. Expands args to R, Imm
+    RefPre,
+
+    /// A plain indirect reference, `[rn]`. Used for post-indexed addressing
+    RefBase,
+
+    /// An indirect reference with index register, `[rn, +/-rm {, shift}]`. Expands args to R, Index, Shift
+    RefIndex,
+
+    /// A pre-indexed reference with index register, `[rn, +/-rm {, shift}]!`. Expands args to R, Index, Shift
+    RefIndexPre,
+
+    /// Any following matchers are optional. Omitted args are flattened as defaults
+    End,
+}
+
+
+/// Modified immediate transformations. Some instructions have a complement that allows encoding
+/// values that cannot be encoded directly, e.g. `add r0, r0, #-1` can be assembled as `sub r0, r0, #1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transform {
+    /// Encode the value directly
+    Plain,
+    /// Encode the negated value
+    Neg,
+    /// Encode the inverted value
+    Inv,
+}
+
+
+/// Encoding commands. They specify how arguments should be checked / encoded.
+///
+/// Unless otherwise stated, the first argument indicates the bottom-most bit of the affected bitfield
+#[derive(Debug, Clone)]
+pub enum Command {
+    // Meta commands
+
+    /// Repeat the same argument again, as it needs to be encoded twice
+    Repeat,
+
+    /// go to the next argument, if not done implicitly
+    Next,
+
+    // register fields
+
+    /// A normal 4-bit register encoding
+    R(u8),
+
+    /// A 3-bit low register (r0-r7) encoding
+    R3(u8),
+
+    /// A 4-bit register encoding, split into 3 bits at .0 and the top bit at .1
+    RSplit(u8, u8),
+
+    /// A 4-bit register encoding that has to be an even register
+    REven(u8),
+
+    /// The register has to be the register after the previous argument. Not encoded
+    RNext,
+
+    /// The register has to be the same as argument .0. Not encoded
+    Same(u8),
+
+    /// The register has to be register .0. Not encoded
+    RFixed(u8),
+
+    /// An index register encoded at .0. If it is added the bit at .1 is set. If .1 is 0, the
+    /// register cannot be subtracted.
+    RIndex(u8, u8),
+
+    // register lists
+
+    /// A 16-bit register list
+    RList(u8),
+
+    /// A list of low registers encoded at bit 0-7, that can also contain register .0 which is encoded in bit 8.
+    /// If .0 is 0, only low registers are allowed
+    RListLo(u8),
+
+    /// A list containing a single register, encoded as a normal 4-bit register at .0
+    RListSingle(u8),
+
+    // immediate handling, validation fields
+
+    /// validate that the current arg is an unsigned value that fits in .0 bits, and that the
+    /// lower .1 bits are 0
+    UImm(u8, u8),
+
+    /// validate that the current arg is an unsigned value between .0 and .1, inclusive
+    URange(u8, u8),
+
+    /// validate that the magnitude of the current arg fits in .1 bits, and that the lower .2
+    /// bits are 0. If the value is positive, the bit at .0 is set. Any following BitRange encodes
+    /// the magnitude of the value.
+    SignMag(u8, u8, u8),
+
+    // immediate handling, encoding fields.
+
+    /// Encode a slice of bits from a value .0 = offset, .1 = amount of bits, .2 = offset in value
+    BitRange(u8, u8, u8),
+
+    // special immediate fields
+
+    /// An A32 modified immediate, encoded as a rotation and an 8-bit value in bits 0-11
+    ArmImm(Transform),
+
+    /// A T32 modified immediate, encoded in the i:imm3:imm8 fields
+    ThumbImm(Transform),
+
+    /// A T32 load/store offset. Either encoded as a positive 12-bit offset, or as a negative 8-bit offset
+    ThumbOff12,
+
+    /// The width of a bitfield, encoded as width - 1 at .0. The previous arg is the lsb
+    Width(u8),
+
+    /// The width of a bitfield, encoded as the most significant bit at .0. The previous arg is the lsb
+    Msb(u8),
+
+    // shifts
+
+    /// An A32 shift by an immediate, or rrx
+    ArmShift,
+
+    /// An A32 shift by a register
+    ArmRegShift,
+
+    /// A T32 shift by an immediate, or rrx
+    ThumbShift,
+
+    /// A left shift by at most .1, encoded at .0
+    IndexShift(u8, u8),
+
+    /// The shift has to be a left shift by .0. Not encoded
+    LslExact(u8),
+
+    /// A rotation by a multiple of 8, encoded in 2 bits at .0
+    Rotation(u8),
+
+    // others
+
+    /// A specific ident from a named list, encoded at .0
+    LitList(u8, &'static str),
+
+    /// some kind of offset for a jump or literal load
+    Offset(Relocation),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relocation {
+    // A32 b, bl: 26 bits, word aligned
+    B24 = 0,
+    // A32 ldr, ldrb, str, strb (literal): sign and 12-bit magnitude
+    LDR12 = 1,
+    // A32 ldrh, ldrsb, ldrsh, ldrd (literal): sign and 8-bit magnitude
+    LDR8 = 2,
+    // A32 adr: add or sub from pc with a modified immediate
+    ADR12 = 3,
+    // T16 b: 12 bits, halfword aligned
+    TB11 = 4,
+    // T16 b<cond>: 9 bits, halfword aligned
+    TB8 = 5,
+    // T16 cbz, cbnz: 7 bits unsigned, halfword aligned
+    TCB6 = 6,
+    // T32 b, bl: 25 bits, halfword aligned
+    TB24 = 7,
+    // T32 b<cond>: 21 bits, halfword aligned
+    TB20 = 8,
+    // T16 ldr (literal), adr: 10 bits unsigned, word aligned, relative to the aligned pc
+    TLDR8 = 9,
+    // T32 ldr (literal) and friends: sign and 12-bit magnitude, relative to the aligned pc
+    TLDR12 = 10,
+    // T32 adr: addw or subw from the aligned pc
+    TADR12 = 11,
+    // Anything in directives
+    LITERAL8 = 17,
+    LITERAL16 = 18,
+    LITERAL32 = 20,
+    LITERAL64 = 24,
+}
+
+impl Relocation {
+    pub fn to_id(self) -> u8 {
+        self as u8
+    }
+}
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct Opdata {
+    /// The base template for the encoding.
+    pub template: Template,
+    /// Flags affecting conditional execution and width selection
+    pub flags: Flags,
+    /// A set of matchers capable of matching the instruction encoding that this instruction represents.
+    pub matchers: &'static [Matcher],
+    /// A set of commands used to process the matched arguments
+    pub commands: &'static [Command],
+}
+
+macro_rules! SingleOp {
+    ( $template:expr, [ $( $matcher:expr ),* ], [ $( $command:expr ),* ], [ $( $flag:ident ),* ] ) => {
+        {
+            const MATCHERS: &'static [Matcher] = {
+                #[allow(unused_imports)]
+                use self::Matcher::*;
+                &[ $(
+                    $matcher
+                ),* ]
+            };
+            const COMMANDS: &'static [Command] = {
+                #[allow(unused_imports)]
+                use self::Command::*;
+                #[allow(unused_imports)]
+                use self::Relocation::*;
+                #[allow(unused_imports)]
+                use self::Transform::*;
+                &[ $(
+                    $command
+                ),* ]
+            };
+
+            use self::Template::*;
+            Opdata {
+                template: $template,
+                flags: Flags::empty() $( .union(Flags::$flag) )*,
+                matchers: MATCHERS,
+                commands: COMMANDS,
+            }
+        }
+    }
+}
+
+macro_rules! Ops {
+    ( $( $name:tt = [ $( $template:expr , [ $( $matcher:expr ),* ] => [ $( $command:expr ),* ] $( , $flag:ident )* ; )+ ] , )* ) => {
+        [ $(
+            (
+                $name,
+                &[ $(
+                    SingleOp!( $template, [ $( $matcher ),* ], [ $( $command ),* ], [ $( $flag ),* ] )
+                ),+ ] as &[_]
+            )
+        ),* ]
+    }
+}
+
+/// Look up the encodings of a mnemonic, either in the ARM or the Thumb instruction set
+pub fn get_mnemonic_data(thumb: bool, name: &str) -> Option<&'static [Opdata]> {
+    if thumb {
+        THUMB_OPMAP.get(&name).cloned()
+    } else {
+        ARM_OPMAP.get(&name).cloned()
+    }
+}
+
+#[allow(dead_code)]
+pub fn mnemonics(thumb: bool) -> hash_map::Keys<'static, &'static str, &'static [Opdata]> {
+    if thumb {
+        THUMB_OPMAP.keys()
+    } else {
+        ARM_OPMAP.keys()
+    }
+}
+
+
+lazy_static!{
+    static ref ARM_OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        static MAP: &[(&str, &[Opdata])] = &include!("opmap_arm.rs");
+        MAP.iter().cloned().collect()
+    };
+
+    static ref THUMB_OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        static MAP: &[(&str, &[Opdata])] = &include!("opmap_thumb.rs");
+        MAP.iter().cloned().collect()
+    };
+
+    /// A map of existing condition codes and their normal encoding
+    pub static ref COND_MAP: HashMap<&'static str, u8> = {
+        static MAP: &[(&str, u8)] = &[
+            ("eq", 0),
+            ("ne", 1),
+            ("cs", 2),
+            ("hs", 2),
+            ("cc", 3),
+            ("lo", 3),
+            ("mi", 4),
+            ("pl", 5),
+            ("vs", 6),
+            ("vc", 7),
+            ("hi", 8),
+            ("ls", 9),
+            ("ge", 10),
+            ("lt", 11),
+            ("gt", 12),
+            ("le", 13),
+            ("al", 14),
+        ];
+        MAP.iter().cloned().collect()
+    };
+
+    // special ident maps
+    pub static ref SPECIAL_IDENT_MAP: HashMap<&'static str, HashMap<&'static str, u32>> = {
+        let mut mapmap = HashMap::new();
+        mapmap.insert("BARRIER_OPS", {
+            static MAP: &[(&str, u32)] = &[
+                ("sy",    0b1111),
+                ("st",    0b1110),
+                ("ld",    0b1101),
+                ("ish",   0b1011),
+                ("ishst", 0b1010),
+                ("ishld", 0b1001),
+                ("nsh",   0b0111),
+                ("nshst", 0b0110),
+                ("nshld", 0b0101),
+                ("osh",   0b0011),
+                ("oshst", 0b0010),
+                ("oshld", 0b0001),
+            ];
+            MAP.iter().cloned().collect()
+        });
+        mapmap.insert("ISB_OPS", {
+            static MAP: &[(&str, u32)] = &[
+                ("sy",    0b1111),
+            ];
+            MAP.iter().cloned().collect()
+        });
+        mapmap.insert("SPECIAL_REGS", {
+            // the M-profile special registers, as used by thumb mrs and msr
+            static MAP: &[(&str, u32)] = &[
+                ("apsr",        0),
+                ("apsr_nzcvq",  0),
+                ("iapsr",       1),
+                ("eapsr",       2),
+                ("xpsr",        3),
+                ("ipsr",        5),
+                ("epsr",        6),
+                ("iepsr",       7),
+                ("msp",         8),
+                ("psp",         9),
+                ("primask",     16),
+                ("basepri",     17),
+                ("basepri_max", 18),
+                ("faultmask",   19),
+                ("control",     20),
+            ];
+            MAP.iter().cloned().collect()
+        });
+        mapmap
+    };
+}
@@ -0,0 +1,215 @@
+//! ARM registers are simple. There is a single family of 16 general purpose registers, denoted as
+//! r0-r15 or by their ABI names. r13, r14 and r15 double as the stack pointer, link register and
+//! program counter (sp, lr and pc). Only the integer instruction set is supported, so there are no
+//! floating point or vector register families.
+use proc_macro2::Span;
+use crate::common::Jump;
+use super::armdata::Opdata;
+
+use std::fmt;
+
+
+/// A generic register reference. Can be either a static RegId or a dynamic register
+#[derive(Debug, Clone)]
+pub enum Register {
+    Static(RegId),
+    Dynamic(syn::Expr)
+}
+
+/// Unique identifiers for a specific register
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegId {
+    R0 = 0x00, R1 = 0x01, R2 = 0x02, R3 = 0x03, // a1, a2, a3, a4
+    R4 = 0x04, R5 = 0x05, R6 = 0x06, R7 = 0x07, // v1, v2, v3, v4
+    R8 = 0x08, R9 = 0x09, R10= 0x0A, R11= 0x0B, // v5, sb, sl, fp
+    R12= 0x0C, R13= 0x0D, R14= 0x0E, R15= 0x0F, // ip, sp, lr, pc
+}
+
+impl RegId {
+    /// Encode this RegId in a 4-bit value
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+}
+
+impl fmt::Display for RegId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegId::R13 => write!(f, "sp"),
+            RegId::R14 => write!(f, "lr"),
+            RegId::R15 => write!(f, "pc"),
+            _ => write!(f, "r{}", self.code())
+        }
+    }
+}
+
+
+/// The shift operations that can be applied to a register operand. These are named after
+/// their mnemonics, like the aarch64 modifiers are.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShiftOp {
+    LSL,
+    LSR,
+    ASR,
+    ROR,
+    RRX,
+}
+
+impl ShiftOp {
+    /// The 2-bit encoding of the shift type
+    pub fn code(self) -> u8 {
+        match self {
+            ShiftOp::LSL => 0,
+            ShiftOp::LSR => 1,
+            ShiftOp::ASR => 2,
+            ShiftOp::ROR
+            | ShiftOp::RRX => 3,
+        }
+    }
+}
+
+/// The amount a register operand is shifted by
+#[derive(Debug, Clone)]
+pub enum ShiftAmount {
+    Immediate(syn::Expr),
+    Register(Register),
+    // rrx has no shift amount
+    None
+}
+
+/// A shift applied to a register operand, like `lsl #2`
+#[derive(Debug, Clone)]
+pub struct ShiftExpr {
+    pub span: Span,
+    pub op: ShiftOp,
+    pub amount: ShiftAmount,
+}
+
+
+/// The offset part of a memory reference
+#[derive(Debug, Clone)]
+pub enum RefOffset {
+    // [rn]
+    None,
+    // [rn, #imm]
+    Immediate(syn::Expr),
+    // [rn, +/-rm {, shift}]
+    Index {
+        reg: Register,
+        subtract: bool,
+        shift: Option<ShiftExpr>
+    }
+}
+
+
+/// The instruction width requested by a `.n` or `.w` suffix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Width {
+    Any,
+    Narrow,
+    Wide
+}
+
+
+/// An ARM parsed instruction.
+/// These are fairly simple. the format is "op" [ "." width ] [ arg [ , arg ]* ]
+/// where arg is
+/// * an immediate (arbitrary expression, optionally prefixed by #)
+/// * a label (in normal dynasm-rs style)
+/// * a register (one of the above), optionally followed by ! to indicate writeback
+/// * a shift `lsl #2` or `asr r3`
+/// * a register list `{ reg [ - reg ] [ , reg [ - reg ] ]* }`
+/// * a memory reference `[ reg [ , expr | [ - ] reg [ , shift ] ] ] [ ! ]`
+#[derive(Debug, Clone)]
+pub enum RawArg {
+    // An immediate
+    Immediate {
+        value: syn::Expr
+    },
+    // A label
+    JumpTarget {
+        jump: Jump
+    },
+    // A register
+    Register {
+        span: Span,
+        reg: Register
+    },
+    // A register with writeback
+    Writeback {
+        span: Span,
+        reg: Register
+    },
+    // A subtracted register, used for post-indexed offsets
+    NegRegister {
+        span: Span,
+        reg: Register
+    },
+    // A shift
+    Shift {
+        shift: ShiftExpr
+    },
+    // A register list
+    RegList {
+        span: Span,
+        regs: Vec<Register>
+    },
+    // A memory reference
+    Reference {
+        span: Span,
+        base: Register,
+        offset: RefOffset,
+        bang: bool
+    },
+}
+
+/// The result of parsing a single instruction
+#[derive(Debug)]
+pub struct ParsedInstruction {
+    pub name: String,
+    pub width: Width,
+    pub span: Span,
+    pub args: Vec<RawArg>
+}
+
+#[derive(Debug, Clone)]
+pub enum FlatArg {
+    Immediate {
+        value: syn::Expr
+    },
+    JumpTarget {
+        jump: Jump
+    },
+    Register {
+        span: Span,
+        reg: Register
+    },
+    Index {
+        span: Span,
+        reg: Register,
+        subtract: bool
+    },
+    Shift {
+        shift: ShiftExpr
+    },
+    RegList {
+        span: Span,
+        regs: Vec<Register>
+    },
+    Ident {
+        ident: syn::Ident
+    },
+    Default
+}
+
+/// The result of finding a match for an instruction
+#[derive(Debug)]
+pub struct MatchData {
+    pub data: &'static Opdata,
+    pub args: Vec<FlatArg>,
+    /// The condition code the instruction is executed under, if any
+    pub cond: Option<u8>,
+    /// If the 16-bit encoding was explicitly requested using `.n`
+    pub narrow: bool,
+}
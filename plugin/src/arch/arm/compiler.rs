@@ -0,0 +1,970 @@
+use super::Context;
+use super::armdata::{Template, Command, Relocation, Transform, Flags, SPECIAL_IDENT_MAP};
+use super::ast::{MatchData, FlatArg, Register, ShiftExpr, ShiftOp, ShiftAmount};
+
+use syn::spanned::Spanned;
+use quote::quote;
+use proc_macro2::{TokenStream, Span};
+use proc_macro_error2::emit_error;
+
+use crate::parse_helpers::as_signed_number;
+use crate::common::{Stmt, Size, delimited, bitmask};
+
+
+/// An error that occurred while encoding an instruction. If it has a span, it is specific to
+/// that part of the instruction, otherwise it applies to the whole instruction.
+#[derive(Debug)]
+pub(super) struct EncodingError {
+    pub span: Option<Span>,
+    pub message: String
+}
+
+fn error<T>(span: Span, message: impl Into<String>) -> Result<T, EncodingError> {
+    Err(EncodingError {
+        span: Some(span),
+        message: message.into()
+    })
+}
+
+fn rejected<T>(message: impl Into<String>) -> Result<T, EncodingError> {
+    Err(EncodingError {
+        span: None,
+        message: message.into()
+    })
+}
+
+
+/// Compile a single instruction. Input is taken from `data`, containing both the arguments
+/// and the encoding template and commands.
+/// Output is written to ctx.state
+/// Errors can be emitted to either the whole instruction span (by returning Err(Some(errormsg)))
+/// or emited specifically using emit_error! and returning Err(None)
+pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<(), Option<String>> {
+    match encode_instruction(&data, false) {
+        Ok(stmts) => {
+            ctx.state.stmts.extend(stmts);
+            Ok(())
+        },
+        Err(EncodingError { span: Some(span), message }) => {
+            emit_error!(span, message);
+            Err(None)
+        },
+        Err(EncodingError { span: None, message }) => Err(Some(message))
+    }
+}
+
+
+/// Encodes a single instruction into a list of statements.
+///
+/// In `strict` mode, 16-bit Thumb encodings are rejected if they would need a runtime check to
+/// ensure a dynamic argument fits, unless the 16-bit encoding was explicitly requested. This is used
+/// during matching so that a 32-bit encoding is picked instead.
+pub(super) fn encode_instruction(data: &MatchData, strict: bool) -> Result<Vec<Stmt>, EncodingError> {
+    let template = data.data.template;
+    let commands = data.data.commands;
+
+    let mut encoder = Encoder {
+        restricted: strict && matches!(template, Template::T16(_)) && !data.narrow,
+        statics: Vec::new(),
+        dynamics: Vec::new(),
+        checks: Vec::new(),
+        relocations: Vec::new(),
+    };
+
+    if data.data.flags.contains(Flags::COND) {
+        encoder.statics.push((template.cond_offset(), u32::from(data.cond.unwrap_or(14))));
+    }
+
+    // argument cursor
+    let mut cursor = 0usize;
+
+    for (i, command) in commands.iter().enumerate() {
+        // meta commands
+        match *command {
+            Command::Repeat => {
+                cursor -= 1;
+                continue;
+            },
+            Command::Next => {
+                cursor += 1;
+                continue;
+            },
+            _ => ()
+        }
+
+        let arg = data.args.get(cursor).expect("Invalid encoding data, tried to process more arguments than given");
+
+        match *command {
+            // register fields
+            Command::R(offset) => {
+                let (_, reg) = register_arg(arg)?;
+                encoder.encode_register(reg, offset, 0xF);
+            },
+            Command::R3(offset) => {
+                let (span, reg) = register_arg(arg)?;
+                match reg {
+                    Register::Static(id) if id.code() > 7 => return error(span, "Only r0-r7 can be used here"),
+                    Register::Dynamic(_) if encoder.restricted => return rejected("Dynamic register in a 16-bit encoding"),
+                    Register::Dynamic(_) => encoder.check_register(reg, quote!{ _dyn_reg > 7 }),
+                    Register::Static(_) => ()
+                }
+                encoder.encode_register(reg, offset, 0x7);
+            },
+            Command::RSplit(low, high) => {
+                let (_, reg) = register_arg(arg)?;
+                match reg {
+                    Register::Static(id) => {
+                        encoder.statics.push((low, u32::from(id.code() & 0x7)));
+                        encoder.statics.push((high, u32::from(id.code() >> 3)));
+                    },
+                    Register::Dynamic(_) => {
+                        encoder.encode_register(reg, low, 0x7);
+                        let expr = register_expr(reg);
+                        encoder.dynamics.push((high, quote!{
+                            ((#expr >> 3) & 1) as u32
+                        }));
+                    }
+                }
+            },
+            Command::REven(offset) => {
+                let (span, reg) = register_arg(arg)?;
+                match reg {
+                    Register::Static(id) if id.code() & 1 != 0 || id.code() == 14 => return error(span, "Expected an even register below r14"),
+                    Register::Dynamic(_) => encoder.check_register(reg, quote!{ _dyn_reg & 1 != 0 || _dyn_reg >= 14 }),
+                    Register::Static(_) => ()
+                }
+                encoder.encode_register(reg, offset, 0xF);
+            },
+            Command::RNext => {
+                let (span, reg) = register_arg(arg)?;
+                let (_, prev) = register_arg(&data.args[cursor - 1])?;
+                if let (Register::Static(id), Register::Static(prev)) = (reg, prev) {
+                    if id.code() != prev.code() + 1 {
+                        return error(span, format!("Expected {} to be the register after {}", id, prev));
+                    }
+                }
+            },
+            Command::Same(index) => {
+                let (span, reg) = register_arg(arg)?;
+                let (_, other) = register_arg(&data.args[usize::from(index)])?;
+                match (reg, other) {
+                    (Register::Static(id), Register::Static(other)) => if id != other {
+                        return error(span, format!("Expected register {} here", other));
+                    },
+                    // prefer encodings that can be verified statically
+                    _ if strict => return rejected("Dynamic register has to match another register"),
+                    _ => {
+                        let other = register_expr(other);
+                        encoder.check_register(reg, quote!{ _dyn_reg != #other });
+                    }
+                }
+            },
+            Command::RFixed(code) => {
+                let (span, reg) = register_arg(arg)?;
+                match reg {
+                    Register::Static(id) => if id.code() != code {
+                        return error(span, format!("Only {} can be used here", RegisterName(code)));
+                    },
+                    // prefer encodings that can be verified statically
+                    Register::Dynamic(_) if strict => return rejected("Dynamic register has to be a specific register"),
+                    Register::Dynamic(_) => encoder.check_register(reg, quote!{ _dyn_reg != #code }),
+                }
+            },
+            Command::RIndex(offset, ubit) => {
+                let (span, reg, subtract) = match arg {
+                    FlatArg::Index { span, reg, subtract } => (*span, reg, *subtract),
+                    FlatArg::Register { span, reg } => (*span, reg, false),
+                    _ => panic!("Invalid argument processor")
+                };
+
+                if subtract && ubit == 0 {
+                    return error(span, "This instruction cannot subtract the index register");
+                } else if !subtract && ubit != 0 {
+                    encoder.statics.push((ubit, 1));
+                }
+
+                encoder.encode_register(reg, offset, 0xF);
+            },
+
+            // register lists
+            Command::RList(offset) => {
+                let (span, regs) = register_list_arg(arg)?;
+                if matches!(template, Template::T32(_)) && regs.len() < 2 {
+                    return error(span, "Thumb-2 register lists have to contain at least two registers");
+                }
+                let mut mask = 0u32;
+                for reg in regs {
+                    match reg {
+                        Register::Static(id) => mask |= 1 << id.code(),
+                        Register::Dynamic(_) => {
+                            let expr = register_expr(reg);
+                            encoder.dynamics.push((offset, quote!{
+                                (1u32 << (#expr & 0xF))
+                            }));
+                        }
+                    }
+                }
+                encoder.statics.push((offset, mask));
+            },
+            Command::RListLo(extra) => {
+                let (span, regs) = register_list_arg(arg)?;
+                let mut mask = 0u32;
+                for reg in regs {
+                    match reg {
+                        Register::Static(id) if id.code() < 8 => mask |= 1 << id.code(),
+                        Register::Static(id) if extra != 0 && id.code() == extra => mask |= 0x100,
+                        Register::Static(id) => return error(span, format!("Register {} cannot be used in this register list", id)),
+                        Register::Dynamic(_) if encoder.restricted => return rejected("Dynamic register in a 16-bit encoding"),
+                        Register::Dynamic(_) => {
+                            let expr = register_expr(reg);
+                            let extra = if extra == 0 {
+                                quote!{}
+                            } else {
+                                quote!{ #extra => 0x100u32, }
+                            };
+                            encoder.dynamics.push((0, quote!{
+                                {
+                                    let _dyn_reg: u8 = #expr;
+                                    match _dyn_reg {
+                                        0 ..= 7 => 1u32 << _dyn_reg,
+                                        #extra
+                                        _ => ::dynasmrt::arm::invalid_register(_dyn_reg)
+                                    }
+                                }
+                            }));
+                        }
+                    }
+                }
+                encoder.statics.push((0, mask));
+            },
+            Command::RListSingle(offset) => {
+                let (span, regs) = register_list_arg(arg)?;
+                match regs {
+                    [reg] => encoder.encode_register(reg, offset, 0xF),
+                    _ => return error(span, "Expected a single register")
+                }
+            },
+
+            // immediate validation
+            Command::UImm(_, _)
+            | Command::URange(_, _)
+            | Command::SignMag(_, _, _) => {
+                let fields = gather_fields(commands, i + 1);
+                match arg {
+                    FlatArg::Immediate { value } => encoder.encode_immediate(value, command, &fields)?,
+                    FlatArg::Default => if let Command::SignMag(ubit, _, _) = *command {
+                        // no offset counts as a positive offset
+                        encoder.statics.push((ubit, 1));
+                    },
+                    _ => panic!("Invalid argument processor")
+                }
+            },
+            Command::BitRange(_, _, _) => (),
+
+            // special immediates
+            Command::ArmImm(transform) => {
+                let value = immediate_arg(arg);
+                let encoded = encoder.encode_modified_immediate(value, transform, false)?;
+                encoder.statics.push((0, encoded));
+            },
+            Command::ThumbImm(transform) => {
+                let value = immediate_arg(arg);
+                let encoded = encoder.encode_modified_immediate(value, transform, true)?;
+                encoder.statics.push((0, spread_thumb_immediate(encoded)));
+            },
+            Command::ThumbOff12 => match arg {
+                FlatArg::Default => encoder.statics.push((0, 0x0080_0000)),
+                FlatArg::Immediate { value } => {
+                    let span = value.span();
+                    match static_value(value) {
+                        Some(v @ 0 ..= 4095) => encoder.statics.push((0, 0x0080_0000 | v as u32)),
+                        Some(v @ -255 ..= -1) => encoder.statics.push((0, 0xC00 | (-v) as u32)),
+                        Some(_) => return error(span, "Immediate out of range, expected a value between -255 and 4095"),
+                        None if encoder.restricted => return rejected("Dynamic immediate in a 16-bit encoding"),
+                        None => encoder.dynamics.push((0, quote!{
+                            {
+                                let _dyn_imm: i32 = #value;
+                                match _dyn_imm {
+                                    0 ..= 4095 => 0x0080_0000u32 | _dyn_imm as u32,
+                                    -255 ..= -1 => 0xC00u32 | _dyn_imm.unsigned_abs(),
+                                    _ => ::dynasmrt::arm::immediate_out_of_range_signed_32(_dyn_imm)
+                                }
+                            }
+                        }))
+                    }
+                },
+                _ => panic!("Invalid argument processor")
+            },
+            Command::Width(offset)
+            | Command::Msb(offset) => {
+                let value = immediate_arg(arg);
+                let lsb = immediate_arg(&data.args[cursor - 1]);
+                let span = value.span();
+                let is_msb = matches!(*command, Command::Msb(_));
+
+                match (static_value(value), static_value(lsb)) {
+                    (Some(width), Some(lsb)) => {
+                        if width < 1 || width > 32 - lsb {
+                            return error(span, format!("Bitfield width out of range, expected a value between 1 and {}", 32 - lsb));
+                        }
+                        let encoded = if is_msb { lsb + width - 1 } else { width - 1 };
+                        encoder.statics.push((offset, encoded as u32));
+                    },
+                    _ if encoder.restricted => return rejected("Dynamic immediate in a 16-bit encoding"),
+                    _ => {
+                        let encoded = if is_msb {
+                            quote!{ _dyn_lsb + _dyn_imm - 1 }
+                        } else {
+                            quote!{ _dyn_imm - 1 }
+                        };
+                        encoder.dynamics.push((offset, quote!{
+                            {
+                                let _dyn_lsb: u32 = #lsb;
+                                let _dyn_imm: u32 = #value;
+                                if _dyn_imm == 0 || _dyn_imm > 32u32.wrapping_sub(_dyn_lsb) {
+                                    ::dynasmrt::arm::immediate_out_of_range_unsigned_32(_dyn_imm);
+                                }
+                                #encoded
+                            }
+                        }));
+                    }
+                }
+            },
+
+            // shifts
+            Command::ArmShift
+            | Command::ThumbShift => {
+                let shift = match arg {
+                    FlatArg::Default => {
+                        cursor += 1;
+                        continue;
+                    },
+                    FlatArg::Shift { shift } => shift,
+                    _ => panic!("Invalid argument processor")
+                };
+
+                let thumb = matches!(*command, Command::ThumbShift);
+                let type_offset = if thumb { 4 } else { 5 };
+                encoder.statics.push((type_offset, u32::from(shift.op.code())));
+
+                let (min, max): (u32, u32) = match shift.op {
+                    ShiftOp::LSL => (0, 31),
+                    ShiftOp::LSR
+                    | ShiftOp::ASR => (1, 32),
+                    ShiftOp::ROR => (1, 31),
+                    ShiftOp::RRX => {
+                        cursor += 1;
+                        continue;
+                    }
+                };
+
+                let value = match shift.amount {
+                    ShiftAmount::Immediate(ref value) => value,
+                    _ => panic!("Invalid argument processor")
+                };
+                let span = value.span();
+
+                match static_value(value) {
+                    Some(amount) => {
+                        if amount < i64::from(min) || amount > i64::from(max) {
+                            return error(span, format!("Shift amount out of range, expected a value between {} and {}", min, max));
+                        }
+                        let amount = amount as u32 & 0x1F;
+                        if thumb {
+                            encoder.statics.push((0, ((amount >> 2) << 12) | ((amount & 3) << 6)));
+                        } else {
+                            encoder.statics.push((7, amount));
+                        }
+                    },
+                    None => {
+                        let encoded = if thumb {
+                            quote!{ ((_dyn_imm >> 2) << 12) | ((_dyn_imm & 3) << 6) }
+                        } else {
+                            quote!{ _dyn_imm << 7 }
+                        };
+                        let check = if min == 0 {
+                            quote!{ _dyn_imm > #max }
+                        } else {
+                            quote!{ _dyn_imm < #min || _dyn_imm > #max }
+                        };
+                        encoder.dynamics.push((0, quote!{
+                            {
+                                let _dyn_imm: u32 = #value;
+                                if #check {
+                                    ::dynasmrt::arm::immediate_out_of_range_unsigned_32(_dyn_imm);
+                                }
+                                let _dyn_imm = _dyn_imm & 0x1F;
+                                #encoded
+                            }
+                        }));
+                    }
+                }
+            },
+            Command::ArmRegShift => {
+                let shift = shift_arg(arg);
+                encoder.statics.push((5, u32::from(shift.op.code())));
+                match shift.amount {
+                    ShiftAmount::Register(ref reg) => encoder.encode_register(reg, 8, 0xF),
+                    _ => panic!("Invalid argument processor")
+                }
+            },
+            Command::IndexShift(offset, max) => match arg {
+                FlatArg::Default => (),
+                FlatArg::Shift { shift } => {
+                    let value = match (shift.op, &shift.amount) {
+                        (ShiftOp::LSL, ShiftAmount::Immediate(value)) => value,
+                        _ => return error(shift.span, "Only lsl can be used here")
+                    };
+                    encoder.encode_immediate(value, &Command::URange(0, max), &[(offset, 8, 0)])?;
+                },
+                _ => panic!("Invalid argument processor")
+            },
+            Command::LslExact(amount) => {
+                let shift = match arg {
+                    FlatArg::Shift { shift } => shift,
+                    FlatArg::Default => return rejected(format!("Expected lsl #{}", amount)),
+                    _ => panic!("Invalid argument processor")
+                };
+                let value = match (shift.op, &shift.amount) {
+                    (ShiftOp::LSL, ShiftAmount::Immediate(value)) => static_value(value),
+                    _ => None
+                };
+                if value != Some(i64::from(amount)) {
+                    return error(shift.span, format!("Expected lsl #{}", amount));
+                }
+            },
+            Command::Rotation(offset) => match arg {
+                FlatArg::Default => (),
+                FlatArg::Shift { shift } => {
+                    let value = match shift.amount {
+                        ShiftAmount::Immediate(ref value) => value,
+                        _ => panic!("Invalid argument processor")
+                    };
+                    let span = value.span();
+
+                    match static_value(value) {
+                        Some(v @ (0 | 8 | 16 | 24)) => encoder.statics.push((offset, (v / 8) as u32)),
+                        Some(_) => return error(span, "Rotation has to be 0, 8, 16 or 24"),
+                        None => encoder.dynamics.push((offset, quote!{
+                            {
+                                let _dyn_imm: u32 = #value;
+                                if _dyn_imm & !0x18 != 0 {
+                                    ::dynasmrt::arm::immediate_out_of_range_unsigned_32(_dyn_imm);
+                                }
+                                _dyn_imm >> 3
+                            }
+                        }))
+                    }
+                },
+                _ => panic!("Invalid argument processor")
+            },
+
+            // others
+            Command::LitList(offset, listname) => {
+                let ident = match arg {
+                    FlatArg::Ident { ident } => ident,
+                    _ => panic!("Invalid argument processor")
+                };
+                let list = SPECIAL_IDENT_MAP.get(listname).expect("Invalid literal list");
+                let name = ident.to_string();
+                match list.get(&*name) {
+                    Some(&value) => encoder.statics.push((offset, value)),
+                    None => return error(ident.span(), format!("Unknown literal '{}'", name))
+                }
+            },
+            Command::Offset(relocation) => match arg {
+                FlatArg::JumpTarget { jump } => {
+                    // encode the complete relocation. Always starts at the begin of the instruction, and also relative to that
+                    let size = template.size();
+                    encoder.relocations.push(jump.clone().encode(size, size, &[relocation.to_id()]));
+                },
+                FlatArg::Immediate { value } => {
+                    let span = value.span();
+                    match static_value(value) {
+                        Some(offset) => match encode_branch_offset(relocation, offset) {
+                            Some(Ok(encoded)) => encoder.statics.push((0, encoded)),
+                            Some(Err(e)) => return error(span, e),
+                            None => return error(span, "This instruction requires a label as target")
+                        },
+                        None => return error(span, "Dynamic offsets are not supported, use a dynamic label instead")
+                    }
+                },
+                _ => panic!("Invalid argument processor")
+            },
+
+            Command::Repeat
+            | Command::Next => unreachable!()
+        }
+
+        // figure out how far the cursor has to be advanced.
+        match *command {
+            Command::UImm(_, _)
+            | Command::URange(_, _)
+            | Command::SignMag(_, _, _)
+            | Command::BitRange(_, _, _) => (),
+            _ => cursor += 1
+        }
+    }
+
+    // sanity
+    if cursor != data.args.len() {
+        panic!("Not enough command processors");
+    }
+
+    Ok(encoder.finish(template))
+}
+
+
+/// Collects the fields of an instruction while it's being encoded
+struct Encoder {
+    /// If dynamic values that need a runtime check should be rejected
+    restricted: bool,
+    /// All static bitfields (compile-time constant), as (offset, bitfield)
+    statics: Vec<(u8, u32)>,
+    /// All dynamic bitfields (run-time determined), as (offset, TokenStream)
+    dynamics: Vec<(u8, TokenStream)>,
+    /// Runtime checks that do not encode anything
+    checks: Vec<TokenStream>,
+    /// Any relocations
+    relocations: Vec<Stmt>,
+}
+
+impl Encoder {
+    /// Encode a register in a field of `mask` bits at `offset`.
+    fn encode_register(&mut self, reg: &Register, offset: u8, mask: u8) {
+        match reg {
+            Register::Static(id) => self.statics.push((offset, u32::from(id.code() & mask))),
+            Register::Dynamic(_) => {
+                let expr = register_expr(reg);
+                self.dynamics.push((offset, quote!{
+                    (#expr & #mask) as u32
+                }));
+            }
+        }
+    }
+
+    /// Emit a runtime check that panics if `check` holds for the register (bound to `_dyn_reg`).
+    fn check_register(&mut self, reg: &Register, check: TokenStream) {
+        let expr = register_expr(reg);
+        self.checks.push(quote!{
+            let _dyn_reg: u8 = #expr;
+            if #check {
+                ::dynasmrt::arm::invalid_register(_dyn_reg);
+            }
+        });
+    }
+
+    /// Validate an immediate according to the given command, and encode it using `fields`
+    fn encode_immediate(&mut self, value: &syn::Expr, command: &Command, fields: &[(u8, u8, u8)]) -> Result<(), EncodingError> {
+        let span = value.span();
+
+        if let Some(value) = static_value(value) {
+            let (magnitude, scale) = match *command {
+                Command::UImm(bits, scale) => {
+                    if value < 0 || value > i64::from(bitmask(bits)) {
+                        return error(span, format!("Immediate out of range, expected a value between 0 and {}", bitmask(bits)));
+                    }
+                    (value, scale)
+                },
+                Command::URange(min, max) => {
+                    if value < i64::from(min) || value > i64::from(max) {
+                        return error(span, format!("Immediate out of range, expected a value between {} and {}", min, max));
+                    }
+                    (value, 0)
+                },
+                Command::SignMag(ubit, bits, scale) => {
+                    if value.unsigned_abs() > u64::from(bitmask(bits)) {
+                        return error(span, format!("Immediate out of range, expected a value between -{0} and {0}", bitmask(bits)));
+                    }
+                    if value >= 0 {
+                        self.statics.push((ubit, 1));
+                    }
+                    (value.abs(), scale)
+                },
+                _ => unreachable!()
+            };
+
+            if magnitude & i64::from(bitmask(scale)) != 0 {
+                return error(span, format!("Immediate has to be a multiple of {}", 1u32 << scale));
+            }
+
+            for &(offset, bits, shift) in fields {
+                self.statics.push((offset, (magnitude >> shift) as u32 & bitmask(bits)));
+            }
+            return Ok(());
+        }
+
+        if self.restricted {
+            return rejected("Dynamic immediate in a 16-bit encoding");
+        }
+
+        let mut encodes = Vec::new();
+        for &(offset, bits, shift) in fields {
+            let mask = bitmask(bits);
+            encodes.push(if offset == 0 {
+                quote!{ ((_dyn_imm >> #shift) & #mask) }
+            } else {
+                quote!{ (((_dyn_imm >> #shift) & #mask) << #offset) }
+            });
+        }
+
+        let body = match *command {
+            Command::UImm(bits, scale) => {
+                let range = bitmask(bits);
+                let zeromask = bitmask(scale);
+                let check = if scale == 0 {
+                    quote!{ _dyn_imm > #range }
+                } else {
+                    quote!{ _dyn_imm > #range || _dyn_imm & #zeromask != 0 }
+                };
+                quote!{
+                    let _dyn_imm: u32 = #value;
+                    if #check {
+                        ::dynasmrt::arm::immediate_out_of_range_unsigned_32(_dyn_imm);
+                    }
+                }
+            },
+            Command::URange(min, max) => {
+                let min = u32::from(min);
+                let max = u32::from(max);
+                let check = if min == 0 {
+                    quote!{ _dyn_imm > #max }
+                } else {
+                    quote!{ _dyn_imm < #min || _dyn_imm > #max }
+                };
+                quote!{
+                    let _dyn_imm: u32 = #value;
+                    if #check {
+                        ::dynasmrt::arm::immediate_out_of_range_unsigned_32(_dyn_imm);
+                    }
+                }
+            },
+            Command::SignMag(ubit, bits, scale) => {
+                let range = bitmask(bits);
+                let zeromask = bitmask(scale);
+                let check = if scale == 0 {
+                    quote!{ _dyn_imm.unsigned_abs() > #range }
+                } else {
+                    quote!{ _dyn_imm.unsigned_abs() > #range || _dyn_imm.unsigned_abs() & #zeromask != 0 }
+                };
+                encodes.push(quote!{ (u32::from(_dyn_positive) << #ubit) });
+                quote!{
+                    let _dyn_imm: i32 = #value;
+                    if #check {
+                        ::dynasmrt::arm::immediate_out_of_range_signed_32(_dyn_imm);
+                    }
+                    let _dyn_positive = _dyn_imm >= 0;
+                    let _dyn_imm = _dyn_imm.unsigned_abs();
+                }
+            },
+            _ => unreachable!()
+        };
+
+        self.dynamics.push((0, quote!{
+            {
+                #body
+                #( #encodes )|*
+            }
+        }));
+
+        Ok(())
+    }
+
+    /// Encode a modified immediate, returning the 12-bit field. Dynamic values are encoded at runtime.
+    fn encode_modified_immediate(&mut self, value: &syn::Expr, transform: Transform, thumb: bool) -> Result<u32, EncodingError> {
+        let span = value.span();
+
+        if let Some(value) = static_value(value) {
+            if value < i64::from(i32::MIN) || value > i64::from(u32::MAX) {
+                return error(span, "Immediate out of range, expected a 32-bit value");
+            }
+
+            let value = value as u32;
+            let value = match transform {
+                Transform::Plain => value,
+                Transform::Neg => value.wrapping_neg(),
+                Transform::Inv => !value,
+            };
+
+            let encoded = if thumb {
+                encode_thumb_immediate(value)
+            } else {
+                encode_arm_immediate(value)
+            };
+
+            return match encoded {
+                Some(encoded) => Ok(u32::from(encoded)),
+                None => error(span, "Immediate cannot be encoded as a modified immediate")
+            };
+        }
+
+        if transform != Transform::Plain {
+            return rejected("Dynamic immediates cannot be transformed");
+        }
+
+        if thumb {
+            self.dynamics.push((0, quote!{
+                {
+                    let _dyn_imm: u32 = #value;
+                    let _dyn_imm = match ::dynasmrt::arm::encode_thumb_immediate(_dyn_imm) {
+                        Some(encoded) => u32::from(encoded),
+                        None => ::dynasmrt::arm::immediate_out_of_range_unsigned_32(_dyn_imm)
+                    };
+                    ((_dyn_imm & 0x800) << 15) | ((_dyn_imm & 0x700) << 4) | (_dyn_imm & 0xFF)
+                }
+            }));
+        } else {
+            self.dynamics.push((0, quote!{
+                {
+                    let _dyn_imm: u32 = #value;
+                    match ::dynasmrt::arm::encode_arm_immediate(_dyn_imm) {
+                        Some(encoded) => u32::from(encoded),
+                        None => ::dynasmrt::arm::immediate_out_of_range_unsigned_32(_dyn_imm)
+                    }
+                }
+            }));
+        }
+
+        Ok(0)
+    }
+
+    /// Assemble the template, static and dynamic fields into the final statements.
+    fn finish(self, template: Template) -> Vec<Stmt> {
+        let mut bits = template.bits();
+        for (offset, value) in self.statics {
+            bits |= value << offset;
+        }
+
+        let mut stmts = Vec::new();
+
+        if self.dynamics.is_empty() && self.checks.is_empty() {
+            stmts.push(match template {
+                Template::A32(_) => Stmt::u32(bits),
+                Template::T16(_) => Stmt::u16(bits as u16),
+                Template::T32(_) => Stmt::u32(bits.rotate_left(16)),
+            });
+        } else {
+            let mut expr = quote!{ #bits };
+            for (offset, dynamic) in self.dynamics {
+                expr = if offset == 0 {
+                    quote!{ #expr | (#dynamic) }
+                } else {
+                    quote!{ #expr | ((#dynamic) << #offset) }
+                };
+            }
+
+            let checks = self.checks;
+            let expr = quote!{
+                {
+                    #( #checks )*
+                    #expr
+                }
+            };
+
+            stmts.push(match template {
+                Template::A32(_) => Stmt::ExprUnsigned(delimited(expr), Size::B_4),
+                Template::T16(_) => Stmt::ExprUnsigned(delimited(quote!{ #expr as u16 }), Size::B_2),
+                Template::T32(_) => Stmt::ExprUnsigned(delimited(quote!{ #expr.rotate_left(16) }), Size::B_4),
+            });
+        }
+
+        stmts.extend(self.relocations);
+        stmts
+    }
+}
+
+
+/// Prints register codes, for error messages
+struct RegisterName(u8);
+
+impl std::fmt::Display for RegisterName {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.0 {
+            13 => write!(f, "sp"),
+            14 => write!(f, "lr"),
+            15 => write!(f, "pc"),
+            r => write!(f, "r{}", r)
+        }
+    }
+}
+
+
+/// Gather the BitRange fields following an immediate validation command, until the next Next.
+fn gather_fields(commands: &[Command], mut index: usize) -> Vec<(u8, u8, u8)> {
+    let mut fields = Vec::new();
+    loop {
+        match commands.get(index) {
+            Some(&Command::BitRange(offset, bits, shift)) => fields.push((offset, bits, shift)),
+            Some(Command::Next) => break,
+            Some(_)
+            | None => panic!("Bad encoding data, integer field sequence is not terminated"),
+        }
+        index += 1;
+    }
+    fields
+}
+
+fn register_arg(arg: &FlatArg) -> Result<(Span, &Register), EncodingError> {
+    match arg {
+        FlatArg::Register { span, reg } => Ok((*span, reg)),
+        FlatArg::Index { span, reg, subtract: false } => Ok((*span, reg)),
+        FlatArg::Index { span, subtract: true, .. } => error(*span, "This register cannot be subtracted"),
+        _ => panic!("Invalid argument processor")
+    }
+}
+
+fn register_list_arg(arg: &FlatArg) -> Result<(Span, &[Register]), EncodingError> {
+    match arg {
+        FlatArg::RegList { span, regs } if regs.is_empty() => error(*span, "Register lists cannot be empty"),
+        FlatArg::RegList { span, regs } => Ok((*span, regs)),
+        _ => panic!("Invalid argument processor")
+    }
+}
+
+fn immediate_arg(arg: &FlatArg) -> &syn::Expr {
+    match arg {
+        FlatArg::Immediate { value } => value,
+        _ => panic!("Invalid argument processor")
+    }
+}
+
+fn shift_arg(arg: &FlatArg) -> &ShiftExpr {
+    match arg {
+        FlatArg::Shift { shift } => shift,
+        _ => panic!("Invalid argument processor")
+    }
+}
+
+/// The value of a register as an expression evaluating to u8
+fn register_expr(reg: &Register) -> TokenStream {
+    match reg {
+        Register::Static(id) => {
+            let code = id.code();
+            quote!{ #code }
+        },
+        Register::Dynamic(expr) => {
+            let expr = delimited(expr);
+            quote!{
+                {
+                    let _dyn_reg: u8 = #expr.into();
+                    _dyn_reg
+                }
+            }
+        }
+    }
+}
+
+/// Evaluate an immediate at compile time, if possible
+fn static_value(expr: &syn::Expr) -> Option<i64> {
+    #![allow(unexpected_cfgs)]
+    let value;
+
+    // this allows turning off static checks for testing purposes
+    #[cfg(not(disable_static_checks="1"))]
+    {
+        value = as_signed_number(expr);
+    }
+    #[cfg(disable_static_checks="1")]
+    {
+        value = None;
+    }
+
+    value
+}
+
+
+/// Encode a static branch offset, relative to the start of the instruction. Returns None if the
+/// relocation type does not support static offsets.
+fn encode_branch_offset(relocation: Relocation, offset: i64) -> Option<Result<u32, &'static str>> {
+    let (bias, bits) = match relocation {
+        Relocation::B24 => (8, 26),
+        Relocation::TB11 => (4, 12),
+        Relocation::TB8 => (4, 9),
+        Relocation::TCB6 => (4, 7),
+        Relocation::TB24 => (4, 25),
+        Relocation::TB20 => (4, 21),
+        _ => return None
+    };
+
+    let value = offset - bias;
+    let scale = if relocation == Relocation::B24 { 3 } else { 1 };
+
+    if value & scale != 0 {
+        return Some(Err("Unaligned branch offset"));
+    }
+
+    let in_range = if relocation == Relocation::TCB6 {
+        (0 .. 1 << bits).contains(&value)
+    } else {
+        (-1 << (bits - 1) .. 1 << (bits - 1)).contains(&value)
+    };
+    if !in_range {
+        return Some(Err("Branch offset out of range"));
+    }
+
+    let value = value as u32;
+    Some(Ok(match relocation {
+        Relocation::B24 => (value >> 2) & 0xFF_FFFF,
+        Relocation::TB11 => (value >> 1) & 0x7FF,
+        Relocation::TB8 => (value >> 1) & 0xFF,
+        Relocation::TCB6 => ((value & 0x40) << 3) | ((value & 0x3E) << 2),
+        Relocation::TB24 => {
+            let s = (value >> 24) & 1;
+            let j1 = !((value >> 23) ^ s) & 1;
+            let j2 = !((value >> 22) ^ s) & 1;
+            (s << 26) | (((value >> 12) & 0x3FF) << 16) | (j1 << 13) | (j2 << 11) | ((value >> 1) & 0x7FF)
+        },
+        Relocation::TB20 => {
+            let s = (value >> 20) & 1;
+            let j2 = (value >> 19) & 1;
+            let j1 = (value >> 18) & 1;
+            (s << 26) | (((value >> 12) & 0x3F) << 16) | (j1 << 13) | (j2 << 11) | ((value >> 1) & 0x7FF)
+        },
+        _ => unreachable!()
+    }))
+}
+
+
+/// Encode a value as an A32 modified immediate. Mirrors `dynasmrt::arm::encode_arm_immediate`
+fn encode_arm_immediate(value: u32) -> Option<u16> {
+    (0 .. 16).find_map(|rotate| {
+        let imm8 = value.rotate_left(rotate * 2);
+        if imm8 <= 0xFF {
+            Some(((rotate << 8) | imm8) as u16)
+        } else {
+            None
+        }
+    })
+}
+
+/// Encode a value as a T32 modified immediate. Mirrors `dynasmrt::arm::encode_thumb_immediate`
+fn encode_thumb_immediate(value: u32) -> Option<u16> {
+    let byte = value & 0xFF;
+    let high_byte = (value >> 8) & 0xFF;
+
+    // the replicated byte patterns
+    if value <= 0xFF {
+        return Some(value as u16);
+    } else if value == byte * 0x0001_0001 {
+        return Some(0x100 | byte as u16);
+    } else if value == high_byte * 0x0100_0100 {
+        return Some(0x200 | high_byte as u16);
+    } else if value == byte * 0x0101_0101 {
+        return Some(0x300 | byte as u16);
+    }
+
+    // an 8-bit value with its top bit set, rotated right by 8 to 31 bits
+    (8 .. 32).find_map(|rotation| {
+        let imm8 = value.rotate_left(rotation);
+        if (0x80 ..= 0xFF).contains(&imm8) {
+            Some(((rotation << 7) | (imm8 & 0x7F)) as u16)
+        } else {
+            None
+        }
+    })
+}
+
+/// Spread the 12-bit i:imm3:imm8 field over the T32 instruction
+fn spread_thumb_immediate(encoded: u32) -> u32 {
+    ((encoded & 0x800) << 15) | ((encoded & 0x700) << 4) | (encoded & 0xFF)
+}
@@ -0,0 +1,535 @@
+use super::armdata::{Command, Matcher, Relocation, Opdata, Template};
+
+use std::fmt::Write;
+
+#[cfg(feature = "dynasm_opmap")]
+pub fn create_opmap(thumb: bool) -> String {
+    let mut s = String::new();
+
+    let mut mnemonics: Vec<_> = super::armdata::mnemonics(thumb).cloned().collect();
+    mnemonics.sort();
+    for mnemonic in mnemonics {
+        // get the data for this mnemonic
+        let data = super::armdata::get_mnemonic_data(thumb, mnemonic).unwrap();
+        let formats = data.iter()
+            .map(|x| format_opdata(mnemonic, x))
+            .map(|x| x.replace(">>> ", ""))
+            .collect::<Vec<_>>();
+
+        // push mnemonic name as title
+        write!(s, "### {}\n```insref\n{}\n```\n", mnemonic, formats.join("\n")).unwrap();
+    }
+    s
+}
+
+
+#[cfg(feature = "dynasm_extract")]
+pub fn extract_opmap(thumb: bool) -> String {
+    let mut buf = Vec::new();
+
+    let mut mnemonics: Vec<_> = super::armdata::mnemonics(thumb).cloned().collect();
+    mnemonics.sort();
+
+    for mnemonic in mnemonics {
+        // get the data for this mnemonic
+        let data = super::armdata::get_mnemonic_data(thumb, mnemonic).unwrap();
+
+        buf.extend(
+            data.iter()
+            .map(|x| extract_opdata(mnemonic, x))
+        );
+    }
+
+    buf.join("\n")
+}
+
+
+pub fn format_opdata_list(name: &str, data: &[Opdata]) -> String {
+    let mut forms = Vec::new();
+
+    for data in data {
+        forms.push(format_opdata(name, data));
+    }
+
+    forms.join("\n")
+}
+
+
+pub fn format_opdata(name: &str, data: &Opdata) -> String {
+    let mut buf = format!(">>> {}", name);
+
+    if data.flags.contains(super::armdata::Flags::COND) {
+        buf.push_str("<cond>");
+    }
+    match data.template {
+        Template::T16(_) => buf.push_str(".n"),
+        Template::T32(_) => buf.push_str(".w"),
+        Template::A32(_) => ()
+    }
+
+    let (constraints, names) = match constraints_and_names(data) {
+        Ok(o) => o,
+        Err(e) => panic!("Encountered a faulty op listing for {}: {}", name, e)
+    };
+
+    let mut first = true;
+    let mut end_count = 0;
+    let mut names = &names[..];
+
+    for matcher in data.matchers {
+        if let Matcher::End = matcher {
+            end_count += 1;
+            buf.push_str(" {");
+            continue;
+        }
+
+        if first {
+            buf.push(' ');
+            first = false;
+        } else {
+            buf.push_str(", ");
+        }
+
+        let (arg_names, rest) = names.split_at(matcher.flat_arg_count());
+        names = rest;
+
+        match matcher {
+            Matcher::R => write!(buf, "{}", arg_names[0]).unwrap(),
+            Matcher::Writeback => write!(buf, "{}!", arg_names[0]).unwrap(),
+            Matcher::Index => write!(buf, "{{-}}{}", arg_names[0]).unwrap(),
+            Matcher::Imm => write!(buf, "#{}", arg_names[0]).unwrap(),
+            Matcher::Offset => buf.push_str(&arg_names[0]),
+            Matcher::Lit(s) => buf.push_str(s),
+            Matcher::Ident => buf.push_str(&arg_names[0]),
+            Matcher::Shift => buf.push_str("lsl|lsr|asr|ror #amount|rrx"),
+            Matcher::RegShift => write!(buf, "lsl|lsr|asr|ror {}", arg_names[0]).unwrap(),
+            Matcher::Ror => buf.push_str("ror #rotation"),
+            Matcher::RegList => buf.push_str("{reglist}"),
+            Matcher::RefOffset => write!(buf, "[{} {{, #{}}}]", arg_names[0], arg_names[1]).unwrap(),
+            Matcher::RefPre => write!(buf, "[{}, #{}]!", arg_names[0], arg_names[1]).unwrap(),
+            Matcher::RefBase => write!(buf, "[{}]", arg_names[0]).unwrap(),
+            Matcher::RefIndex => write!(buf, "[{}, {{-}}{}{}]", arg_names[0], arg_names[1], arg_names[2]).unwrap(),
+            Matcher::RefIndexPre => write!(buf, "[{}, {{-}}{}{}]!", arg_names[0], arg_names[1], arg_names[2]).unwrap(),
+            Matcher::End => unreachable!()
+        }
+    }
+
+    for _ in 0 .. end_count {
+        buf.push_str(" }");
+    }
+
+    if let Some(c) = constraints {
+        let mut len = c.len() + buf.len();
+        while len < 100 {
+            buf.push(' ');
+            len += 1;
+        }
+        buf.push_str(&c);
+    }
+
+    buf
+}
+
+fn constraints_and_names(opdata: &Opdata) -> Result<(Option<String>, Vec<String>), &'static str> {
+    let data = group_opdata(opdata)?;
+    let constraints = format_constraints(&data);
+    let names = data.into_iter().map(|a| a.name.unwrap_or_else(|| "?".into())).collect();
+    Ok((constraints, names))
+}
+
+fn group_opdata(opdata: &Opdata) -> Result<Vec<ArgWithCommands>, &'static str> {
+    let args = flatten_matchers(opdata.matchers);
+    let (max_cursor, commands) = group_commands(opdata.commands);
+
+    if args.len() != max_cursor {
+        return Err("arg / command count mismatch");
+    }
+
+    let mut args: Vec<_> = args.into_iter().map(|arg| ArgWithCommands {
+        arg,
+        commands: Vec::new(),
+        name: None
+    }).collect();
+
+    for (command, idx) in commands {
+        args[idx].commands.push(command);
+    }
+
+    // validate the commands - argtypes
+    check_command_sanity(&args)?;
+
+    name_args(&mut args);
+
+    Ok(args)
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum FlatArgTy {
+    Register,
+    Immediate,
+    JumpTarget,
+    Shift,
+    RegList,
+    Ident,
+}
+
+struct ArgWithCommands {
+    pub arg: FlatArgTy,
+    pub commands: Vec<Command>,
+    pub name: Option<String>,
+}
+
+/// Take a matcher array and return a vector of the types of flat arg each should produce
+fn flatten_matchers(matchers: &[Matcher]) -> Vec<FlatArgTy> {
+    let mut args = Vec::new();
+
+    for matcher in matchers {
+        match matcher {
+            Matcher::R
+            | Matcher::Writeback
+            | Matcher::Index
+            | Matcher::RefBase => args.push(FlatArgTy::Register),
+            Matcher::Imm => args.push(FlatArgTy::Immediate),
+            Matcher::Offset => args.push(FlatArgTy::JumpTarget),
+            Matcher::Ident => args.push(FlatArgTy::Ident),
+            Matcher::Shift
+            | Matcher::RegShift
+            | Matcher::Ror => args.push(FlatArgTy::Shift),
+            Matcher::RegList => args.push(FlatArgTy::RegList),
+            Matcher::RefOffset
+            | Matcher::RefPre => {
+                args.push(FlatArgTy::Register);
+                args.push(FlatArgTy::Immediate);
+            },
+            Matcher::RefIndex
+            | Matcher::RefIndexPre => {
+                args.push(FlatArgTy::Register);
+                args.push(FlatArgTy::Register);
+                args.push(FlatArgTy::Shift);
+            },
+            Matcher::Lit(_)
+            | Matcher::End => (),
+        }
+    }
+    args
+}
+
+/// Take a commands slice and calculate the expected amount of args / a vec of command, argidx
+fn group_commands(commands: &[Command]) -> (usize, Vec<(Command, usize)>) {
+    let mut cursor = 0;
+    let mut command_idx = Vec::new();
+
+    for command in commands {
+        match command {
+            Command::Next => {
+                cursor += 1;
+                continue;
+            },
+            Command::Repeat => {
+                cursor -= 1;
+                continue;
+            },
+            _ => ()
+        }
+
+        command_idx.push((command.clone(), cursor));
+        match command {
+            Command::UImm(_, _)
+            | Command::URange(_, _)
+            | Command::SignMag(_, _, _)
+            | Command::BitRange(_, _, _) => (),
+            _ => cursor += 1
+        }
+    }
+
+    (cursor, command_idx)
+}
+
+/// checks if the commands for each arg type make sense
+fn check_command_sanity(args: &[ArgWithCommands]) -> Result<(), &'static str> {
+    for arg in args {
+        if arg.commands.is_empty() {
+            return Err("Arg with no commands")
+        }
+
+        for command in &arg.commands {
+            let check = match command {
+                Command::R(_)
+                | Command::R3(_)
+                | Command::RSplit(_, _)
+                | Command::REven(_)
+                | Command::RNext
+                | Command::Same(_)
+                | Command::RFixed(_)
+                | Command::RIndex(_, _) => arg.arg == FlatArgTy::Register,
+                Command::RList(_)
+                | Command::RListLo(_)
+                | Command::RListSingle(_) => arg.arg == FlatArgTy::RegList,
+                Command::UImm(_, _)
+                    | Command::URange(_, _)
+                | Command::SignMag(_, _, _)
+                | Command::BitRange(_, _, _)
+                | Command::ArmImm(_)
+                | Command::ThumbImm(_)
+                | Command::ThumbOff12
+                | Command::Width(_)
+                | Command::Msb(_) => arg.arg == FlatArgTy::Immediate,
+                Command::ArmShift
+                | Command::ArmRegShift
+                | Command::ThumbShift
+                | Command::IndexShift(_, _)
+                | Command::LslExact(_)
+                | Command::Rotation(_) => arg.arg == FlatArgTy::Shift,
+                Command::LitList(_, _) => arg.arg == FlatArgTy::Ident,
+                Command::Offset(_) => arg.arg == FlatArgTy::JumpTarget,
+                Command::Repeat
+                | Command::Next => unreachable!()
+            };
+
+            if !check {
+                return Err("command / argtype mismatch");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// assign names to the args being used
+fn name_args(args: &mut [ArgWithCommands]) {
+    // no op uses more than 4 registers or 2 unconstrained immediates
+    let reg_name_list = ["rd", "rn", "rm", "ra"];
+    let mut reg_name_idx = 0;
+    let imm_name_list = ["", "1"];
+    let mut imm_name_idx = 0;
+
+    for arg in args.iter_mut() {
+        arg.name = match arg.arg {
+            FlatArgTy::Register => match &arg.commands[0] {
+                Command::RNext => Some(format!("r({}+1)", &reg_name_list[reg_name_idx - 1][1..])),
+                Command::RFixed(13) => Some("sp".to_string()),
+                Command::RFixed(15) => Some("pc".to_string()),
+                _ => {
+                    reg_name_idx += 1;
+                    Some(reg_name_list[reg_name_idx - 1].to_string())
+                }
+            },
+            FlatArgTy::Immediate => match &arg.commands[0] {
+                Command::Width(_)
+                | Command::Msb(_) => Some("width".to_string()),
+                Command::URange(0, 31) if matches!(arg.commands.last(), Some(Command::BitRange(_, _, _))) && imm_name_idx == 0 => {
+                    imm_name_idx += 1;
+                    Some("lsb".to_string())
+                },
+                _ => {
+                    imm_name_idx += 1;
+                    Some(format!("imm{}", imm_name_list[imm_name_idx - 1]))
+                }
+            },
+            FlatArgTy::JumpTarget => Some("<offset>".to_string()),
+            FlatArgTy::Shift => match &arg.commands[0] {
+                Command::ArmRegShift => {
+                    reg_name_idx += 1;
+                    Some(reg_name_list[reg_name_idx - 1].to_string())
+                },
+                Command::ArmShift => Some(" {, lsl|lsr|asr|ror #amount|rrx}".to_string()),
+                Command::IndexShift(_, max) if *max > 0 => Some(" {, lsl #amount}".to_string()),
+                Command::LslExact(amount) => Some(format!(", lsl #{}", amount)),
+                _ => Some(String::new())
+            },
+            FlatArgTy::RegList => Some("{reglist}".to_string()),
+            FlatArgTy::Ident => match &arg.commands[0] {
+                Command::LitList(_, name) => Some(name.trim_end_matches('S').to_lowercase()),
+                _ => unreachable!()
+            },
+        };
+    }
+}
+
+fn format_constraints(args: &[ArgWithCommands]) -> Option<String> {
+    let mut constraints = String::new();
+
+    for arg in args {
+        if let Some(ref name) = arg.name {
+            emit_constraints(name, &arg.commands, args, &mut constraints);
+        }
+    }
+
+    if constraints.is_empty() {
+        None
+    } else {
+        let len = constraints.len();
+        Some(format!(" ({})", &constraints[0 .. len - 2]))
+    }
+}
+
+fn emit_constraints(name: &str, commands: &[Command], args: &[ArgWithCommands], buf: &mut String) {
+    for command in commands {
+        match command {
+            Command::R3(_) => write!(buf, "{} is r0-r7", name),
+            Command::REven(_) => write!(buf, "{} is even", name),
+            Command::Same(index) => write!(buf, "{} is {}", name, args[usize::from(*index)].name.as_deref().unwrap_or("?")),
+            Command::RListLo(0) => write!(buf, "reglist contains r0-r7"),
+            Command::RListLo(extra) => write!(buf, "reglist contains r0-r7, r{}", extra),
+            Command::RListSingle(_) => write!(buf, "reglist contains a single register"),
+
+            Command::UImm(bits, 0) => write!(buf, "#{} <= {}", name, (1u32 << bits) - 1),
+            Command::UImm(bits, scale) => write!(buf, "#{} <= {}, #{} = {} * N", name, (1u32 << bits) - (1u32 << scale), name, 1u32 << scale),
+            Command::URange(min, max) => write!(buf, "{} <= #{} <= {}", min, name, max),
+            Command::SignMag(_, bits, 0) => write!(buf, "-{0} <= #{1} <= {0}", (1u32 << bits) - 1, name),
+            Command::SignMag(_, bits, scale) => write!(buf, "-{0} <= #{1} <= {0}, #{1} = {2} * N", (1u32 << bits) - (1u32 << scale), name, 1u32 << scale),
+            Command::ArmImm(_) => write!(buf, "#{} is a modified immediate", name),
+            Command::ThumbImm(_) => write!(buf, "#{} is a modified immediate", name),
+            Command::ThumbOff12 => write!(buf, "-255 <= #{} <= 4095", name),
+            Command::Width(_)
+            | Command::Msb(_) => write!(buf, "1 <= #{} <= 32 - #lsb", name),
+
+            Command::Offset(Relocation::B24) => write!(buf, "offset is 26 bits, 4-byte aligned"),
+            Command::Offset(Relocation::LDR12)
+            | Command::Offset(Relocation::TLDR12)
+            | Command::Offset(Relocation::TADR12) => write!(buf, "offset is 12 bits, signed"),
+            Command::Offset(Relocation::LDR8) => write!(buf, "offset is 8 bits, signed"),
+            Command::Offset(Relocation::ADR12) => write!(buf, "offset is a signed modified immediate"),
+            Command::Offset(Relocation::TB11) => write!(buf, "offset is 12 bits, 2-byte aligned"),
+            Command::Offset(Relocation::TB8) => write!(buf, "offset is 9 bits, 2-byte aligned"),
+            Command::Offset(Relocation::TCB6) => write!(buf, "offset is 7 bits, unsigned, 2-byte aligned"),
+            Command::Offset(Relocation::TB24) => write!(buf, "offset is 25 bits, 2-byte aligned"),
+            Command::Offset(Relocation::TB20) => write!(buf, "offset is 21 bits, 2-byte aligned"),
+            Command::Offset(Relocation::TLDR8) => write!(buf, "offset is 10 bits, unsigned, 4-byte aligned"),
+
+            _ => continue
+        }.unwrap();
+
+        write!(buf, ", ").unwrap();
+        break;
+    }
+}
+
+
+#[cfg(feature = "dynasm_extract")]
+pub fn extract_opdata(name: &str, data: &Opdata) -> String {
+    let mut buf = format!("\"{}", name);
+
+    if data.flags.contains(super::armdata::Flags::COND) {
+        buf.push_str("<Cond>");
+    }
+    match data.template {
+        Template::T16(_) => buf.push_str(".n"),
+        Template::T32(_) => buf.push_str(".w"),
+        Template::A32(_) => ()
+    }
+
+    let mut first = true;
+    let mut arg_idx = 0;
+
+    let grouped = group_opdata(data).unwrap();
+    let constraints = extract_constraints(&grouped);
+
+    for matcher in data.matchers {
+        if let Matcher::End = matcher {
+            continue;
+        }
+
+        if first {
+            buf.push(' ');
+            first = false;
+        } else {
+            buf.push_str(", ");
+        }
+
+        match matcher {
+            Matcher::R => write!(buf, "<R,{}>", arg_idx).unwrap(),
+            Matcher::Writeback => write!(buf, "<R,{}>!", arg_idx).unwrap(),
+            Matcher::Index => write!(buf, "<Index,{}>", arg_idx).unwrap(),
+            Matcher::Imm => write!(buf, "#<Imm,{}>", arg_idx).unwrap(),
+            Matcher::Offset => write!(buf, "<Off,{}>", arg_idx).unwrap(),
+            Matcher::Lit(s) => buf.push_str(s),
+            Matcher::Ident => write!(buf, "<Ident,{}>", arg_idx).unwrap(),
+            Matcher::Shift
+            | Matcher::RegShift
+            | Matcher::Ror => write!(buf, "<Shift,{}>", arg_idx).unwrap(),
+            Matcher::RegList => write!(buf, "<RegList,{}>", arg_idx).unwrap(),
+            Matcher::RefOffset => write!(buf, "[<R,{}>, #<Imm,{}>]", arg_idx, arg_idx + 1).unwrap(),
+            Matcher::RefPre => write!(buf, "[<R,{}>, #<Imm,{}>]!", arg_idx, arg_idx + 1).unwrap(),
+            Matcher::RefBase => write!(buf, "[<R,{}>]", arg_idx).unwrap(),
+            Matcher::RefIndex => write!(buf, "[<R,{}>, <Index,{}><Shift,{}>]", arg_idx, arg_idx + 1, arg_idx + 2).unwrap(),
+            Matcher::RefIndexPre => write!(buf, "[<R,{}>, <Index,{}><Shift,{}>]!", arg_idx, arg_idx + 1, arg_idx + 2).unwrap(),
+            Matcher::End => unreachable!()
+        }
+
+        arg_idx += matcher.flat_arg_count();
+    }
+
+    write!(buf, "\"\t{{{}}}", constraints.join(", ")).unwrap();
+
+    buf
+}
+
+
+#[cfg(feature = "dynasm_extract")]
+fn extract_constraints(args: &[ArgWithCommands]) -> Vec<String> {
+    use super::armdata::SPECIAL_IDENT_MAP;
+
+    let mut constraints = Vec::new();
+    for (i, arg) in args.iter().enumerate() {
+        for command in &arg.commands {
+            let constraint = match command {
+                Command::R(_)
+                | Command::RSplit(_, _) => "R(0xFFFF)".to_string(),
+                Command::R3(_) => "R(0x00FF)".to_string(),
+                Command::REven(_) => "R(0x1555)".to_string(),
+                Command::RFixed(code) => format!("R({:#06X})", 1u32 << code),
+                Command::RNext => "Next()".to_string(),
+                Command::Same(index) => format!("Same({})", index),
+                Command::RIndex(_, 0) => "Index(0xFFFF, False)".to_string(),
+                Command::RIndex(_, _) => "Index(0xFFFF, True)".to_string(),
+
+                Command::RList(_) => "RegList(0xFFFF)".to_string(),
+                Command::RListLo(0) => "RegList(0x00FF)".to_string(),
+                Command::RListLo(extra) => format!("RegList({:#06X})", 0xFF | (1u32 << extra)),
+                Command::RListSingle(_) => "RegList(0xFFFF, single=True)".to_string(),
+
+                Command::UImm(bits, scale) => format!("Range(0, {}, {})", 1u32 << bits, 1u32 << scale),
+                Command::URange(min, max) => format!("Range({}, {}, 1)", min, u32::from(*max) + 1),
+                Command::SignMag(_, bits, scale) => format!("Range(-{}, {}, {})", (1u32 << bits) - (1u32 << scale), 1u32 << bits, 1u32 << scale),
+                Command::ArmImm(transform) => format!("ArmImm(\"{:?}\")", transform),
+                Command::ThumbImm(transform) => format!("ThumbImm(\"{:?}\")", transform),
+                Command::ThumbOff12 => "Range(-255, 4096, 1)".to_string(),
+                Command::Width(_) => "Width()".to_string(),
+                Command::Msb(_) => "Width()".to_string(),
+
+                Command::ArmShift => "Shift(\"arm\")".to_string(),
+                Command::ThumbShift => "Shift(\"thumb\")".to_string(),
+                Command::ArmRegShift => "RegShift()".to_string(),
+                Command::IndexShift(_, max) => format!("IndexShift({})", max),
+                Command::LslExact(amount) => format!("LslExact({})", amount),
+                Command::Rotation(_) => "Rotation()".to_string(),
+
+                Command::LitList(_, name) => {
+                    let keys: Vec<_> = SPECIAL_IDENT_MAP[name].keys().map(|k| format!("\"{}\"", k)).collect();
+                    format!("List({})", keys.join(", "))
+                },
+
+                Command::Offset(Relocation::B24) => "Range(-33554432, 33554432, 4)".to_string(),
+                Command::Offset(Relocation::TB11) => "Range(-2048, 2048, 2)".to_string(),
+                Command::Offset(Relocation::TB8) => "Range(-256, 256, 2)".to_string(),
+                Command::Offset(Relocation::TCB6) => "Range(0, 128, 2)".to_string(),
+                Command::Offset(Relocation::TB24) => "Range(-16777216, 16777216, 2)".to_string(),
+                Command::Offset(Relocation::TB20) => "Range(-1048576, 1048576, 2)".to_string(),
+                Command::Offset(Relocation::LDR12)
+                | Command::Offset(Relocation::TLDR12)
+                | Command::Offset(Relocation::TADR12) => "Range(-4095, 4096, 4)".to_string(),
+                Command::Offset(Relocation::LDR8) => "Range(-255, 256, 4)".to_string(),
+                Command::Offset(Relocation::ADR12) => "Range(-1020, 1024, 4)".to_string(),
+                Command::Offset(Relocation::TLDR8) => "Range(0, 1024, 4)".to_string(),
+
+                _ => continue
+            };
+            constraints.push(format!("{}: {}", i, constraint));
+
+            break;
+        }
+    }
+    constraints
+}
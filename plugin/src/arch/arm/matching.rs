@@ -0,0 +1,412 @@
+use proc_macro_error2::emit_error;
+
+use super::{Context, ItBlock};
+use super::ast::{ParsedInstruction, RawArg, MatchData, FlatArg, RefOffset, ShiftAmount, ShiftOp, Width};
+use super::armdata::{Opdata, Matcher, Command, Template, Transform, Flags, COND_MAP, get_mnemonic_data};
+use super::compiler::encode_instruction;
+use super::debug::format_opdata_list;
+
+use crate::common::{JumpKind, Stmt};
+use crate::parse_helpers::as_ident;
+
+/// Try finding an appropriate instruction definition that matches the given instruction / arguments.
+/// IT instructions are handled here completely, in which case no match data is returned.
+pub(super) fn match_instruction(ctx: &mut Context, instruction: ParsedInstruction) -> Result<Option<MatchData>, Option<String>> {
+    // sanitize Raw args from parsing for any impossible constructs
+    sanitize_args(&instruction.args)?;
+
+    let thumb = ctx.target.is_thumb();
+    let name = &instruction.name;
+
+    // .w is accepted in ARM code for compatibility, as all instructions are 32 bits wide there
+    if !thumb && instruction.width == Width::Narrow {
+        return Err(Some(format!("'{}': ARM instructions cannot be narrow", name)));
+    }
+
+    // IT instructions only affect the state of the assembler
+    if let Some(pattern) = name.strip_prefix("it") {
+        if pattern.len() <= 3 && pattern.chars().all(|c| c == 't' || c == 'e') {
+            compile_it_instruction(ctx, &instruction, pattern)?;
+            return Ok(None);
+        }
+    }
+
+    // figure out the mnemonic and the condition suffix
+    let (opdata, cond) = decode_mnemonic(thumb, name).ok_or_else(|| Some(format!("Unknown instruction mnemonic '{}'", name)))?;
+
+    // update the IT block state
+    let it_block = ctx.it_block.get();
+    ctx.it_block.set(it_block.and_then(ItBlock::advance));
+
+    if let Some(block) = it_block {
+        let expected = block.current();
+        if cond.unwrap_or(14) != expected {
+            return Err(Some(format!("'{}': instructions in an IT block have to use the condition of the block, '{}'", name, cond_name(expected))));
+        }
+    }
+
+    // filter the encodings based on the condition, IT block state and requested width
+    let mut candidates = Vec::new();
+    let mut rejections = Vec::new();
+
+    for data in opdata {
+        match check_entry(data, thumb, instruction.width, it_block, cond) {
+            Ok(()) => candidates.push(data),
+            Err(reason) => rejections.push((data, reason))
+        }
+    }
+
+    if candidates.is_empty() {
+        // prefer explaining why an encoding of the requested width can't be used
+        let (_, reason) = rejections.iter()
+            .find(|(data, _)| !width_mismatch(data, instruction.width))
+            .or_else(|| rejections.first())
+            .expect("Mnemonic without encodings");
+        return Err(Some(format!("'{}': {}", name, reason)));
+    }
+
+    // the condition to encode. In Thumb code this only applies to conditional branches outside of IT blocks
+    let cond = if thumb && it_block.is_some() {
+        None
+    } else {
+        cond.filter(|&c| c != 14)
+    };
+    let narrow = instruction.width == Width::Narrow;
+
+    // iterate through the supported instruction formats. The first encoding that can encode the
+    // arguments gets picked. If none can, the first format that can encode them with runtime
+    // checks is used. Otherwise the last matching format is used to report the error.
+    let mut lenient = None;
+    let mut fallback = None;
+
+    for data in candidates {
+        if !match_args(&instruction.args, data) {
+            continue;
+        }
+
+        let match_data = MatchData {
+            data,
+            args: flatten_args(&instruction.args, data),
+            cond,
+            narrow
+        };
+
+        if encode_instruction(&match_data, true).is_ok() {
+            return Ok(Some(match_data));
+        }
+
+        if is_substitution(data) {
+            continue;
+        }
+
+        if lenient.is_none() && encode_instruction(&match_data, false).is_ok() {
+            lenient = Some(match_data);
+        } else {
+            fallback = Some(match_data);
+        }
+    }
+
+    if lenient.is_some() {
+        return Ok(lenient);
+    }
+
+    // if an encoding that was filtered out for this context could have encoded the arguments,
+    // explain why it couldn't be used
+    for (data, reason) in rejections {
+        if width_mismatch(data, instruction.width) || !match_args(&instruction.args, data) {
+            continue;
+        }
+
+        let match_data = MatchData {
+            data,
+            args: flatten_args(&instruction.args, data),
+            cond,
+            narrow
+        };
+
+        if encode_instruction(&match_data, false).is_ok() {
+            return Err(Some(format!("'{}': {}", name, reason)));
+        }
+    }
+
+    if fallback.is_some() {
+        return Ok(fallback);
+    }
+
+    Err(Some(format!("'{}': instruction format mismatch, expected one of the following forms:\n{}", name, format_opdata_list(name, opdata))))
+}
+
+/// Splits a mnemonic into the base mnemonic and its condition suffix, if present.
+fn decode_mnemonic(thumb: bool, name: &str) -> Option<(&'static [Opdata], Option<u8>)> {
+    if let Some(data) = get_mnemonic_data(thumb, name) {
+        return Some((data, None));
+    }
+
+    if name.len() > 2 && name.is_char_boundary(name.len() - 2) {
+        let (base, suffix) = name.split_at(name.len() - 2);
+        if let Some(&cond) = COND_MAP.get(suffix) {
+            if let Some(data) = get_mnemonic_data(thumb, base) {
+                return Some((data, Some(cond)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Checks if the width qualifier rules out this encoding
+fn width_mismatch(data: &Opdata, width: Width) -> bool {
+    match data.template {
+        Template::T16(_) => width == Width::Wide,
+        _ => width == Width::Narrow
+    }
+}
+
+/// Returns the canonical name of a condition code
+fn cond_name(cond: u8) -> &'static str {
+    ["eq", "ne", "hs", "lo", "mi", "pl", "vs", "vc", "hi", "ls", "ge", "lt", "gt", "le", "al"][usize::from(cond)]
+}
+
+/// Checks if an encoding can be used given the condition, IT block state and requested width.
+/// Returns the reason why it cannot be used otherwise.
+fn check_entry(data: &Opdata, thumb: bool, width: Width, it_block: Option<ItBlock>, cond: Option<u8>) -> Result<(), &'static str> {
+    let conditional = cond.is_some_and(|c| c != 14);
+
+    match data.template {
+        Template::T16(_) if width == Width::Wide => return Err("this instruction has no 32-bit encoding"),
+        Template::T32(_) if width == Width::Narrow => return Err("this instruction has no 16-bit encoding"),
+        _ => ()
+    }
+
+    if data.flags.contains(Flags::EXPLICIT_N) && width != Width::Narrow {
+        return Err("this instruction has no 32-bit encoding, use '.n' to request the 16-bit encoding");
+    }
+
+    if !thumb {
+        if conditional && !data.flags.contains(Flags::COND) {
+            return Err("this instruction cannot be executed conditionally");
+        }
+        return Ok(());
+    }
+
+    if let Some(block) = it_block {
+        if data.flags.contains(Flags::NO_IT) {
+            return Err("this instruction cannot be used in an IT block");
+        }
+        if data.flags.contains(Flags::LAST_IN_IT) && !block.is_last() {
+            return Err("this instruction has to be the last instruction of an IT block");
+        }
+        if data.flags.intersects(Flags::OUTSIDE_IT | Flags::COND) {
+            return Err("this instruction cannot be used in an IT block");
+        }
+    } else {
+        if data.flags.contains(Flags::INSIDE_IT) {
+            return Err("this instruction can only be used in an IT block");
+        }
+        if conditional != data.flags.contains(Flags::COND) {
+            return Err("conditional execution of this instruction requires an IT block");
+        }
+    }
+
+    Ok(())
+}
+
+/// Encodings that substitute a modified immediate by a negated or inverted equivalent are only used
+/// if they can encode the immediate, so they shouldn't be used to report errors.
+fn is_substitution(data: &Opdata) -> bool {
+    data.commands.iter().any(|command| matches!(
+        command,
+        Command::ArmImm(Transform::Neg | Transform::Inv) | Command::ThumbImm(Transform::Neg | Transform::Inv)
+    ))
+}
+
+/// Handles `it{x{y{z}}} cond`. In Thumb code this emits the IT instruction. In ARM code this
+/// emits nothing, but the conditions of the following instructions are still checked.
+fn compile_it_instruction(ctx: &mut Context, instruction: &ParsedInstruction, pattern: &str) -> Result<(), Option<String>> {
+    if ctx.it_block.get().is_some() {
+        return Err(Some("IT instructions cannot be used in an IT block".into()));
+    }
+
+    if instruction.width == Width::Wide {
+        return Err(Some("'it': this instruction has no 32-bit encoding".into()));
+    }
+
+    let firstcond = match &instruction.args[..] {
+        [RawArg::Immediate { value }] => match as_ident(value).and_then(|i| COND_MAP.get(&*i.to_string())) {
+            Some(&cond) => cond,
+            None => {
+                emit_error!(value, "Unknown condition code");
+                return Err(None);
+            }
+        },
+        _ => return Err(Some(format!("'{}': expected a single condition code", instruction.name)))
+    };
+
+    let mut conds = [firstcond; 4];
+    let mut mask = 0u16;
+
+    for (i, c) in pattern.chars().enumerate() {
+        let then = c == 't';
+        if !then && firstcond == 14 {
+            return Err(Some("IT blocks with condition 'al' cannot contain else instructions".into()));
+        }
+
+        let bit = u16::from(firstcond & 1 == u8::from(then));
+        mask |= bit << (3 - i);
+        conds[i + 1] = if then { firstcond } else { firstcond ^ 1 };
+    }
+    mask |= 1 << (3 - pattern.len());
+
+    ctx.it_block.set(Some(ItBlock::new(conds, pattern.len() as u8 + 1)));
+
+    if ctx.target.is_thumb() {
+        ctx.state.stmts.push(Stmt::u16(0xBF00 | (u16::from(firstcond) << 4) | mask));
+    }
+
+    Ok(())
+}
+
+/// Sanitizes arguments, ensuring that
+/// Extern relocations are not allowed
+fn sanitize_args(args: &[RawArg]) -> Result<(), Option<String>> {
+    for arg in args {
+        if let RawArg::JumpTarget { jump } = arg {
+            if let JumpKind::Bare(_) = jump.kind {
+                emit_error!(jump.span(), "Extern relocations are not allowed in arm");
+                return Err(None);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+
+impl Matcher {
+    /// Returns if this matcher matches the given argument
+    pub fn matches(&self, arg: &RawArg) -> bool {
+        match arg {
+            RawArg::Immediate { value } => match self {
+                Matcher::Imm
+                | Matcher::Offset => true,
+                Matcher::Lit(s) => as_ident(value).is_some_and(|i| i == s),
+                Matcher::Ident => as_ident(value).is_some(),
+                _ => false
+            },
+            RawArg::JumpTarget { .. } => *self == Matcher::Offset,
+            RawArg::Register { .. } => matches!(self, Matcher::R | Matcher::Index),
+            RawArg::Writeback { .. } => *self == Matcher::Writeback,
+            RawArg::NegRegister { .. } => *self == Matcher::Index,
+            RawArg::Shift { shift } => match self {
+                Matcher::Shift => !matches!(shift.amount, ShiftAmount::Register(_)),
+                Matcher::RegShift => matches!(shift.amount, ShiftAmount::Register(_)),
+                Matcher::Ror => shift.op == ShiftOp::ROR && matches!(shift.amount, ShiftAmount::Immediate(_)),
+                _ => false
+            },
+            RawArg::RegList { .. } => *self == Matcher::RegList,
+            RawArg::Reference { offset, bang, .. } => matches!((self, offset, bang),
+                (Matcher::RefOffset, RefOffset::None | RefOffset::Immediate(_), false)
+                | (Matcher::RefPre, RefOffset::Immediate(_), true)
+                | (Matcher::RefBase, RefOffset::None, false)
+                | (Matcher::RefIndex, RefOffset::Index { .. }, false)
+                | (Matcher::RefIndexPre, RefOffset::Index { .. }, true)
+            ),
+        }
+    }
+
+    /// The amount of flat args this matcher produces
+    pub fn flat_arg_count(&self) -> usize {
+        match self {
+            Matcher::Lit(_)
+            | Matcher::End => 0,
+            Matcher::RefOffset
+            | Matcher::RefPre => 2,
+            Matcher::RefIndex
+            | Matcher::RefIndexPre => 3,
+            _ => 1
+        }
+    }
+}
+
+
+/// Check if the parsed instruction arguments match the data matching template
+fn match_args(args: &[RawArg], data: &'static Opdata) -> bool {
+    let mut args = args.iter();
+    let mut optional = false;
+
+    // check if each matcher matches an appropriate arg
+    for matcher in data.matchers {
+        if *matcher == Matcher::End {
+            optional = true;
+            continue;
+        }
+
+        match args.next() {
+            Some(arg) => if !matcher.matches(arg) {
+                return false;
+            },
+            None => return optional
+        }
+    }
+
+    // and return success if there's no more args remaining to match
+    args.next().is_none()
+}
+
+
+/// Lower the raw args to FlatArgs
+fn flatten_args(args: &[RawArg], data: &'static Opdata) -> Vec<FlatArg> {
+    let mut flat = Vec::new();
+    let mut args = args.iter();
+
+    for matcher in data.matchers {
+        if *matcher == Matcher::End {
+            continue;
+        }
+
+        let arg = match args.next() {
+            Some(arg) => arg,
+            None => {
+                // omitted optional args
+                flat.extend((0 .. matcher.flat_arg_count()).map(|_| FlatArg::Default));
+                continue;
+            }
+        };
+
+        match arg.clone() {
+            RawArg::Immediate { value } => match matcher {
+                Matcher::Lit(_) => (),
+                Matcher::Ident => flat.push(FlatArg::Ident { ident: as_ident(&value).unwrap().clone() }),
+                _ => flat.push(FlatArg::Immediate { value })
+            },
+            RawArg::JumpTarget { jump } => flat.push(FlatArg::JumpTarget { jump }),
+            RawArg::Register { span, reg } => match matcher {
+                Matcher::Index => flat.push(FlatArg::Index { span, reg, subtract: false }),
+                _ => flat.push(FlatArg::Register { span, reg })
+            },
+            RawArg::Writeback { span, reg } => flat.push(FlatArg::Register { span, reg }),
+            RawArg::NegRegister { span, reg } => flat.push(FlatArg::Index { span, reg, subtract: true }),
+            RawArg::Shift { shift } => flat.push(FlatArg::Shift { shift }),
+            RawArg::RegList { span, regs } => flat.push(FlatArg::RegList { span, regs }),
+            RawArg::Reference { span, base, offset, .. } => {
+                flat.push(FlatArg::Register { span, reg: base });
+
+                match offset {
+                    RefOffset::None => if *matcher == Matcher::RefOffset {
+                        flat.push(FlatArg::Default);
+                    },
+                    RefOffset::Immediate(value) => flat.push(FlatArg::Immediate { value }),
+                    RefOffset::Index { reg, subtract, shift } => {
+                        flat.push(FlatArg::Index { span, reg, subtract });
+                        flat.push(match shift {
+                            Some(shift) => FlatArg::Shift { shift },
+                            None => FlatArg::Default
+                        });
+                    }
+                }
+            },
+        }
+    }
+
+    flat
+}
@@ -0,0 +1,198 @@
+use std::cell::Cell;
+
+use syn::parse;
+use proc_macro_error2::emit_error;
+
+pub mod armdata;
+pub mod ast;
+pub mod parser;
+pub mod matching;
+pub mod compiler;
+pub mod debug;
+
+use crate::State;
+use crate::arch::{Stmt, Jump, Size};
+use crate::arch::Arch;
+
+use armdata::Relocation;
+
+#[cfg(feature = "dynasm_opmap")]
+pub use debug::create_opmap;
+#[cfg(feature = "dynasm_extract")]
+pub use debug::extract_opmap;
+
+
+/// The instruction set being assembled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArmTarget {
+    /// The ARM (A32) instruction set
+    Arm,
+    /// The Thumb-2 (T32) instruction set
+    Thumb2
+}
+
+impl ArmTarget {
+    pub fn is_thumb(&self) -> bool {
+        *self == ArmTarget::Thumb2
+    }
+}
+
+
+/// The state of an active IT block: the condition of every instruction in it, and how many of
+/// them have already been assembled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItBlock {
+    conds: [u8; 4],
+    len: u8,
+    index: u8,
+}
+
+impl ItBlock {
+    pub fn new(conds: [u8; 4], len: u8) -> ItBlock {
+        ItBlock {
+            conds,
+            len,
+            index: 0
+        }
+    }
+
+    /// The condition of the next instruction
+    pub fn current(&self) -> u8 {
+        self.conds[usize::from(self.index)]
+    }
+
+    /// Is the next instruction the last in the block
+    pub fn is_last(&self) -> bool {
+        self.index + 1 == self.len
+    }
+
+    /// The state after the next instruction, if the block hasn't ended yet
+    pub fn advance(self) -> Option<ItBlock> {
+        if self.is_last() {
+            None
+        } else {
+            Some(ItBlock {
+                index: self.index + 1,
+                ..self
+            })
+        }
+    }
+}
+
+
+struct Context<'a, 'b: 'a>  {
+    pub state: &'a mut State <'b>,
+    pub target: ArmTarget,
+    pub it_block: &'a Cell<Option<ItBlock>>,
+}
+
+
+#[derive(Clone, Debug, Default)]
+pub struct ArchArm {
+    it_block: Cell<Option<ItBlock>>
+}
+
+impl Arch for ArchArm {
+    fn set_features(&mut self, features: &[syn::Ident]) {
+        // ARM has no optional instruction sets that are supported at this moment
+        if let Some(feature) = features.first() {
+            emit_error!(feature, "Unknown arm feature '{}'", feature);
+        }
+    }
+
+    fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
+        handle_static_reloc_inner(stmts, reloc, size);
+    }
+
+    fn default_align(&self) -> u8 {
+        0
+    }
+
+    fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
+        let mut ctx = Context {
+            state,
+            target: ArmTarget::Arm,
+            it_block: &self.it_block
+        };
+
+        compile_instruction_inner(&mut ctx, input)
+    }
+}
+
+
+#[derive(Clone, Debug, Default)]
+pub struct ArchThumb2 {
+    it_block: Cell<Option<ItBlock>>
+}
+
+impl Arch for ArchThumb2 {
+    fn set_features(&mut self, features: &[syn::Ident]) {
+        // Thumb-2 has no optional instruction sets that are supported at this moment
+        if let Some(feature) = features.first() {
+            emit_error!(feature, "Unknown thumb2 feature '{}'", feature);
+        }
+    }
+
+    fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
+        handle_static_reloc_inner(stmts, reloc, size);
+    }
+
+    fn default_align(&self) -> u8 {
+        0
+    }
+
+    fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
+        let mut ctx = Context {
+            state,
+            target: ArmTarget::Thumb2,
+            it_block: &self.it_block
+        };
+
+        compile_instruction_inner(&mut ctx, input)
+    }
+}
+
+
+fn compile_instruction_inner(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<()> {
+    let instruction = parser::parse_instruction(ctx, input)?;
+    let span = instruction.span;
+
+    let match_data = match matching::match_instruction(ctx, instruction) {
+        Err(None) => return Ok(()),
+        Err(Some(e)) => {
+            emit_error!(span, e);
+            return Ok(())
+        }
+        Ok(None) => return Ok(()),
+        Ok(Some(m)) => m
+    };
+
+    match compiler::compile_instruction(ctx, match_data) {
+        Err(None) => return Ok(()),
+        Err(Some(e)) => {
+            emit_error!(span, e);
+            return Ok(())
+        }
+        Ok(()) => ()
+    }
+
+    Ok(())
+}
+
+fn handle_static_reloc_inner(stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
+    let span = reloc.span();
+
+    let relocation = match size {
+        Size::BYTE => Relocation::LITERAL8,
+        Size::B_2 => Relocation::LITERAL16,
+        Size::B_4 => Relocation::LITERAL32,
+        Size::B_8 => Relocation::LITERAL64,
+        _ => {
+            emit_error!(span, "Relocation of unsupported size for the current target architecture");
+            return;
+        }
+    };
+
+    stmts.push(Stmt::Const(0, size));
+    stmts.push(reloc.encode(size.in_bytes(), size.in_bytes(), &[relocation.to_id()]));
+}
@@ -0,0 +1,975 @@
+Ops!(
+
+
+// Data processing
+
+"and" = [
+    // and rd, rn, #imm
+    A32(0x02000000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // and rd, rn, #imm, encoded as bic with the inverted immediate
+    A32(0x03C00000), [R, R, Imm] => [R(12), R(16), ArmImm(Inv)], COND;
+    // and rd, rn, rm {, shift #amount}
+    A32(0x00000000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // and rd, rn, rm, shift rs
+    A32(0x00000010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // and rd, #imm
+    A32(0x02000000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // and rd, #imm, encoded as bic with the inverted immediate
+    A32(0x03C00000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Inv)], COND;
+    // and rd, rm {, shift #amount}
+    A32(0x00000000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"ands" = [
+    // ands rd, rn, #imm
+    A32(0x02100000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // ands rd, rn, #imm, encoded as bics with the inverted immediate
+    A32(0x03D00000), [R, R, Imm] => [R(12), R(16), ArmImm(Inv)], COND;
+    // ands rd, rn, rm {, shift #amount}
+    A32(0x00100000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // ands rd, rn, rm, shift rs
+    A32(0x00100010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // ands rd, #imm
+    A32(0x02100000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // ands rd, #imm, encoded as bics with the inverted immediate
+    A32(0x03D00000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Inv)], COND;
+    // ands rd, rm {, shift #amount}
+    A32(0x00100000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"eor" = [
+    // eor rd, rn, #imm
+    A32(0x02200000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // eor rd, rn, rm {, shift #amount}
+    A32(0x00200000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // eor rd, rn, rm, shift rs
+    A32(0x00200010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // eor rd, #imm
+    A32(0x02200000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // eor rd, rm {, shift #amount}
+    A32(0x00200000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"eors" = [
+    // eors rd, rn, #imm
+    A32(0x02300000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // eors rd, rn, rm {, shift #amount}
+    A32(0x00300000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // eors rd, rn, rm, shift rs
+    A32(0x00300010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // eors rd, #imm
+    A32(0x02300000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // eors rd, rm {, shift #amount}
+    A32(0x00300000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"sub" = [
+    // sub rd, rn, #imm
+    A32(0x02400000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // sub rd, rn, #imm, encoded as add with the negated immediate
+    A32(0x02800000), [R, R, Imm] => [R(12), R(16), ArmImm(Neg)], COND;
+    // sub rd, rn, rm {, shift #amount}
+    A32(0x00400000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // sub rd, rn, rm, shift rs
+    A32(0x00400010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // sub rd, #imm
+    A32(0x02400000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // sub rd, #imm, encoded as add with the negated immediate
+    A32(0x02800000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Neg)], COND;
+    // sub rd, rm {, shift #amount}
+    A32(0x00400000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"subs" = [
+    // subs rd, rn, #imm
+    A32(0x02500000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // subs rd, rn, #imm, encoded as adds with the negated immediate
+    A32(0x02900000), [R, R, Imm] => [R(12), R(16), ArmImm(Neg)], COND;
+    // subs rd, rn, rm {, shift #amount}
+    A32(0x00500000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // subs rd, rn, rm, shift rs
+    A32(0x00500010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // subs rd, #imm
+    A32(0x02500000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // subs rd, #imm, encoded as adds with the negated immediate
+    A32(0x02900000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Neg)], COND;
+    // subs rd, rm {, shift #amount}
+    A32(0x00500000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"rsb" = [
+    // rsb rd, rn, #imm
+    A32(0x02600000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // rsb rd, rn, rm {, shift #amount}
+    A32(0x00600000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // rsb rd, rn, rm, shift rs
+    A32(0x00600010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // rsb rd, #imm
+    A32(0x02600000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // rsb rd, rm {, shift #amount}
+    A32(0x00600000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"rsbs" = [
+    // rsbs rd, rn, #imm
+    A32(0x02700000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // rsbs rd, rn, rm {, shift #amount}
+    A32(0x00700000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // rsbs rd, rn, rm, shift rs
+    A32(0x00700010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // rsbs rd, #imm
+    A32(0x02700000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // rsbs rd, rm {, shift #amount}
+    A32(0x00700000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"add" = [
+    // add rd, rn, #imm
+    A32(0x02800000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // add rd, rn, #imm, encoded as sub with the negated immediate
+    A32(0x02400000), [R, R, Imm] => [R(12), R(16), ArmImm(Neg)], COND;
+    // add rd, rn, rm {, shift #amount}
+    A32(0x00800000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // add rd, rn, rm, shift rs
+    A32(0x00800010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // add rd, #imm
+    A32(0x02800000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // add rd, #imm, encoded as sub with the negated immediate
+    A32(0x02400000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Neg)], COND;
+    // add rd, rm {, shift #amount}
+    A32(0x00800000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"adds" = [
+    // adds rd, rn, #imm
+    A32(0x02900000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // adds rd, rn, #imm, encoded as subs with the negated immediate
+    A32(0x02500000), [R, R, Imm] => [R(12), R(16), ArmImm(Neg)], COND;
+    // adds rd, rn, rm {, shift #amount}
+    A32(0x00900000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // adds rd, rn, rm, shift rs
+    A32(0x00900010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // adds rd, #imm
+    A32(0x02900000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // adds rd, #imm, encoded as subs with the negated immediate
+    A32(0x02500000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Neg)], COND;
+    // adds rd, rm {, shift #amount}
+    A32(0x00900000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"adc" = [
+    // adc rd, rn, #imm
+    A32(0x02A00000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // adc rd, rn, #imm, encoded as sbc with the inverted immediate
+    A32(0x02C00000), [R, R, Imm] => [R(12), R(16), ArmImm(Inv)], COND;
+    // adc rd, rn, rm {, shift #amount}
+    A32(0x00A00000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // adc rd, rn, rm, shift rs
+    A32(0x00A00010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // adc rd, #imm
+    A32(0x02A00000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // adc rd, #imm, encoded as sbc with the inverted immediate
+    A32(0x02C00000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Inv)], COND;
+    // adc rd, rm {, shift #amount}
+    A32(0x00A00000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"adcs" = [
+    // adcs rd, rn, #imm
+    A32(0x02B00000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // adcs rd, rn, #imm, encoded as sbcs with the inverted immediate
+    A32(0x02D00000), [R, R, Imm] => [R(12), R(16), ArmImm(Inv)], COND;
+    // adcs rd, rn, rm {, shift #amount}
+    A32(0x00B00000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // adcs rd, rn, rm, shift rs
+    A32(0x00B00010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // adcs rd, #imm
+    A32(0x02B00000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // adcs rd, #imm, encoded as sbcs with the inverted immediate
+    A32(0x02D00000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Inv)], COND;
+    // adcs rd, rm {, shift #amount}
+    A32(0x00B00000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"sbc" = [
+    // sbc rd, rn, #imm
+    A32(0x02C00000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // sbc rd, rn, #imm, encoded as adc with the inverted immediate
+    A32(0x02A00000), [R, R, Imm] => [R(12), R(16), ArmImm(Inv)], COND;
+    // sbc rd, rn, rm {, shift #amount}
+    A32(0x00C00000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // sbc rd, rn, rm, shift rs
+    A32(0x00C00010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // sbc rd, #imm
+    A32(0x02C00000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // sbc rd, #imm, encoded as adc with the inverted immediate
+    A32(0x02A00000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Inv)], COND;
+    // sbc rd, rm {, shift #amount}
+    A32(0x00C00000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"sbcs" = [
+    // sbcs rd, rn, #imm
+    A32(0x02D00000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // sbcs rd, rn, #imm, encoded as adcs with the inverted immediate
+    A32(0x02B00000), [R, R, Imm] => [R(12), R(16), ArmImm(Inv)], COND;
+    // sbcs rd, rn, rm {, shift #amount}
+    A32(0x00D00000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // sbcs rd, rn, rm, shift rs
+    A32(0x00D00010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // sbcs rd, #imm
+    A32(0x02D00000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // sbcs rd, #imm, encoded as adcs with the inverted immediate
+    A32(0x02B00000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Inv)], COND;
+    // sbcs rd, rm {, shift #amount}
+    A32(0x00D00000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"rsc" = [
+    // rsc rd, rn, #imm
+    A32(0x02E00000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // rsc rd, rn, rm {, shift #amount}
+    A32(0x00E00000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // rsc rd, rn, rm, shift rs
+    A32(0x00E00010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // rsc rd, #imm
+    A32(0x02E00000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // rsc rd, rm {, shift #amount}
+    A32(0x00E00000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"rscs" = [
+    // rscs rd, rn, #imm
+    A32(0x02F00000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // rscs rd, rn, rm {, shift #amount}
+    A32(0x00F00000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // rscs rd, rn, rm, shift rs
+    A32(0x00F00010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // rscs rd, #imm
+    A32(0x02F00000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // rscs rd, rm {, shift #amount}
+    A32(0x00F00000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"orr" = [
+    // orr rd, rn, #imm
+    A32(0x03800000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // orr rd, rn, rm {, shift #amount}
+    A32(0x01800000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // orr rd, rn, rm, shift rs
+    A32(0x01800010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // orr rd, #imm
+    A32(0x03800000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // orr rd, rm {, shift #amount}
+    A32(0x01800000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"orrs" = [
+    // orrs rd, rn, #imm
+    A32(0x03900000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // orrs rd, rn, rm {, shift #amount}
+    A32(0x01900000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // orrs rd, rn, rm, shift rs
+    A32(0x01900010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // orrs rd, #imm
+    A32(0x03900000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // orrs rd, rm {, shift #amount}
+    A32(0x01900000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"bic" = [
+    // bic rd, rn, #imm
+    A32(0x03C00000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // bic rd, rn, #imm, encoded as and with the inverted immediate
+    A32(0x02000000), [R, R, Imm] => [R(12), R(16), ArmImm(Inv)], COND;
+    // bic rd, rn, rm {, shift #amount}
+    A32(0x01C00000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // bic rd, rn, rm, shift rs
+    A32(0x01C00010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // bic rd, #imm
+    A32(0x03C00000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // bic rd, #imm, encoded as and with the inverted immediate
+    A32(0x02000000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Inv)], COND;
+    // bic rd, rm {, shift #amount}
+    A32(0x01C00000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"bics" = [
+    // bics rd, rn, #imm
+    A32(0x03D00000), [R, R, Imm] => [R(12), R(16), ArmImm(Plain)], COND;
+    // bics rd, rn, #imm, encoded as ands with the inverted immediate
+    A32(0x02100000), [R, R, Imm] => [R(12), R(16), ArmImm(Inv)], COND;
+    // bics rd, rn, rm {, shift #amount}
+    A32(0x01D00000), [R, R, R, End, Shift] => [R(12), R(16), R(0), ArmShift], COND;
+    // bics rd, rn, rm, shift rs
+    A32(0x01D00010), [R, R, R, RegShift] => [R(12), R(16), R(0), ArmRegShift], COND;
+    // bics rd, #imm
+    A32(0x03D00000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Plain)], COND;
+    // bics rd, #imm, encoded as ands with the inverted immediate
+    A32(0x02100000), [R, Imm] => [R(12), Repeat, R(16), ArmImm(Inv)], COND;
+    // bics rd, rm {, shift #amount}
+    A32(0x01D00000), [R, R, End, Shift] => [R(12), Repeat, R(16), R(0), ArmShift], COND;
+],
+"tst" = [
+    // tst rn, #imm
+    A32(0x03100000), [R, Imm] => [R(16), ArmImm(Plain)], COND;
+    // tst rn, rm {, shift #amount}
+    A32(0x01100000), [R, R, End, Shift] => [R(16), R(0), ArmShift], COND;
+    // tst rn, rm, shift rs
+    A32(0x01100010), [R, R, RegShift] => [R(16), R(0), ArmRegShift], COND;
+],
+"teq" = [
+    // teq rn, #imm
+    A32(0x03300000), [R, Imm] => [R(16), ArmImm(Plain)], COND;
+    // teq rn, rm {, shift #amount}
+    A32(0x01300000), [R, R, End, Shift] => [R(16), R(0), ArmShift], COND;
+    // teq rn, rm, shift rs
+    A32(0x01300010), [R, R, RegShift] => [R(16), R(0), ArmRegShift], COND;
+],
+"cmp" = [
+    // cmp rn, #imm
+    A32(0x03500000), [R, Imm] => [R(16), ArmImm(Plain)], COND;
+    // cmp rn, #imm, encoded as cmn with the negated immediate
+    A32(0x03700000), [R, Imm] => [R(16), ArmImm(Neg)], COND;
+    // cmp rn, rm {, shift #amount}
+    A32(0x01500000), [R, R, End, Shift] => [R(16), R(0), ArmShift], COND;
+    // cmp rn, rm, shift rs
+    A32(0x01500010), [R, R, RegShift] => [R(16), R(0), ArmRegShift], COND;
+],
+"cmn" = [
+    // cmn rn, #imm
+    A32(0x03700000), [R, Imm] => [R(16), ArmImm(Plain)], COND;
+    // cmn rn, #imm, encoded as cmp with the negated immediate
+    A32(0x03500000), [R, Imm] => [R(16), ArmImm(Neg)], COND;
+    // cmn rn, rm {, shift #amount}
+    A32(0x01700000), [R, R, End, Shift] => [R(16), R(0), ArmShift], COND;
+    // cmn rn, rm, shift rs
+    A32(0x01700010), [R, R, RegShift] => [R(16), R(0), ArmRegShift], COND;
+],
+"mov" = [
+    // mov rd, #imm
+    A32(0x03A00000), [R, Imm] => [R(12), ArmImm(Plain)], COND;
+    // mov rd, #imm, encoded as mvn with the inverted immediate
+    A32(0x03E00000), [R, Imm] => [R(12), ArmImm(Inv)], COND;
+    // mov rd, #imm16, encoded as movw
+    A32(0x03000000), [R, Imm] => [R(12), UImm(16, 0), BitRange(0, 12, 0), BitRange(16, 4, 12), Next], COND;
+    // mov rd, rm {, shift #amount}
+    A32(0x01A00000), [R, R, End, Shift] => [R(12), R(0), ArmShift], COND;
+    // mov rd, rm, shift rs
+    A32(0x01A00010), [R, R, RegShift] => [R(12), R(0), ArmRegShift], COND;
+],
+"movs" = [
+    // movs rd, #imm
+    A32(0x03B00000), [R, Imm] => [R(12), ArmImm(Plain)], COND;
+    // movs rd, #imm, encoded as mvns with the inverted immediate
+    A32(0x03F00000), [R, Imm] => [R(12), ArmImm(Inv)], COND;
+    // movs rd, rm {, shift #amount}
+    A32(0x01B00000), [R, R, End, Shift] => [R(12), R(0), ArmShift], COND;
+    // movs rd, rm, shift rs
+    A32(0x01B00010), [R, R, RegShift] => [R(12), R(0), ArmRegShift], COND;
+],
+"mvn" = [
+    // mvn rd, #imm
+    A32(0x03E00000), [R, Imm] => [R(12), ArmImm(Plain)], COND;
+    // mvn rd, #imm, encoded as mov with the inverted immediate
+    A32(0x03A00000), [R, Imm] => [R(12), ArmImm(Inv)], COND;
+    // mvn rd, rm {, shift #amount}
+    A32(0x01E00000), [R, R, End, Shift] => [R(12), R(0), ArmShift], COND;
+    // mvn rd, rm, shift rs
+    A32(0x01E00010), [R, R, RegShift] => [R(12), R(0), ArmRegShift], COND;
+],
+"mvns" = [
+    // mvns rd, #imm
+    A32(0x03F00000), [R, Imm] => [R(12), ArmImm(Plain)], COND;
+    // mvns rd, #imm, encoded as movs with the inverted immediate
+    A32(0x03B00000), [R, Imm] => [R(12), ArmImm(Inv)], COND;
+    // mvns rd, rm {, shift #amount}
+    A32(0x01F00000), [R, R, End, Shift] => [R(12), R(0), ArmShift], COND;
+    // mvns rd, rm, shift rs
+    A32(0x01F00010), [R, R, RegShift] => [R(12), R(0), ArmRegShift], COND;
+],
+"neg" = [
+    // neg rd, rm, encoded as rsb rd, rm, #0
+    A32(0x02600000), [R, R] => [R(12), R(16)], COND;
+],
+"negs" = [
+    // negs rd, rm, encoded as rsbs rd, rm, #0
+    A32(0x02700000), [R, R] => [R(12), R(16)], COND;
+],
+"movw" = [
+    // movw rd, #imm16
+    A32(0x03000000), [R, Imm] => [R(12), UImm(16, 0), BitRange(0, 12, 0), BitRange(16, 4, 12), Next], COND;
+],
+"movt" = [
+    // movt rd, #imm16
+    A32(0x03400000), [R, Imm] => [R(12), UImm(16, 0), BitRange(0, 12, 0), BitRange(16, 4, 12), Next], COND;
+],
+
+// Shifts
+
+"lsl" = [
+    // lsl rd, rm, #imm
+    A32(0x01A00000), [R, R, Imm] => [R(12), R(0), URange(0, 31), BitRange(7, 5, 0), Next], COND;
+    // lsl rd, rm, rs
+    A32(0x01A00010), [R, R, R] => [R(12), R(0), R(8)], COND;
+],
+"lsls" = [
+    // lsls rd, rm, #imm
+    A32(0x01B00000), [R, R, Imm] => [R(12), R(0), URange(0, 31), BitRange(7, 5, 0), Next], COND;
+    // lsls rd, rm, rs
+    A32(0x01B00010), [R, R, R] => [R(12), R(0), R(8)], COND;
+],
+"lsr" = [
+    // lsr rd, rm, #imm
+    A32(0x01A00020), [R, R, Imm] => [R(12), R(0), URange(1, 32), BitRange(7, 5, 0), Next], COND;
+    // lsr rd, rm, rs
+    A32(0x01A00030), [R, R, R] => [R(12), R(0), R(8)], COND;
+],
+"lsrs" = [
+    // lsrs rd, rm, #imm
+    A32(0x01B00020), [R, R, Imm] => [R(12), R(0), URange(1, 32), BitRange(7, 5, 0), Next], COND;
+    // lsrs rd, rm, rs
+    A32(0x01B00030), [R, R, R] => [R(12), R(0), R(8)], COND;
+],
+"asr" = [
+    // asr rd, rm, #imm
+    A32(0x01A00040), [R, R, Imm] => [R(12), R(0), URange(1, 32), BitRange(7, 5, 0), Next], COND;
+    // asr rd, rm, rs
+    A32(0x01A00050), [R, R, R] => [R(12), R(0), R(8)], COND;
+],
+"asrs" = [
+    // asrs rd, rm, #imm
+    A32(0x01B00040), [R, R, Imm] => [R(12), R(0), URange(1, 32), BitRange(7, 5, 0), Next], COND;
+    // asrs rd, rm, rs
+    A32(0x01B00050), [R, R, R] => [R(12), R(0), R(8)], COND;
+],
+"ror" = [
+    // ror rd, rm, #imm
+    A32(0x01A00060), [R, R, Imm] => [R(12), R(0), URange(1, 31), BitRange(7, 5, 0), Next], COND;
+    // ror rd, rm, rs
+    A32(0x01A00070), [R, R, R] => [R(12), R(0), R(8)], COND;
+],
+"rors" = [
+    // rors rd, rm, #imm
+    A32(0x01B00060), [R, R, Imm] => [R(12), R(0), URange(1, 31), BitRange(7, 5, 0), Next], COND;
+    // rors rd, rm, rs
+    A32(0x01B00070), [R, R, R] => [R(12), R(0), R(8)], COND;
+],
+"rrx" = [
+    // rrx rd, rm
+    A32(0x01A00060), [R, R] => [R(12), R(0)], COND;
+],
+"rrxs" = [
+    // rrxs rd, rm
+    A32(0x01B00060), [R, R] => [R(12), R(0)], COND;
+],
+
+// Multiplication and division
+
+"mul" = [
+    // mul rd, rn, rm
+    A32(0x00000090), [R, R, R] => [R(16), R(0), R(8)], COND;
+    // mul rd, rm
+    A32(0x00000090), [R, R] => [R(16), Repeat, R(0), R(8)], COND;
+],
+"mla" = [
+    // mla rd, rn, rm, ra
+    A32(0x00200090), [R, R, R, R] => [R(16), R(0), R(8), R(12)], COND;
+],
+"umull" = [
+    // umull rdlo, rdhi, rn, rm
+    A32(0x00800090), [R, R, R, R] => [R(12), R(16), R(0), R(8)], COND;
+],
+"umlal" = [
+    // umlal rdlo, rdhi, rn, rm
+    A32(0x00A00090), [R, R, R, R] => [R(12), R(16), R(0), R(8)], COND;
+],
+"smull" = [
+    // smull rdlo, rdhi, rn, rm
+    A32(0x00C00090), [R, R, R, R] => [R(12), R(16), R(0), R(8)], COND;
+],
+"smlal" = [
+    // smlal rdlo, rdhi, rn, rm
+    A32(0x00E00090), [R, R, R, R] => [R(12), R(16), R(0), R(8)], COND;
+],
+"muls" = [
+    // muls rd, rn, rm
+    A32(0x00100090), [R, R, R] => [R(16), R(0), R(8)], COND;
+    // muls rd, rm
+    A32(0x00100090), [R, R] => [R(16), Repeat, R(0), R(8)], COND;
+],
+"mlas" = [
+    // mlas rd, rn, rm, ra
+    A32(0x00300090), [R, R, R, R] => [R(16), R(0), R(8), R(12)], COND;
+],
+"umulls" = [
+    // umulls rdlo, rdhi, rn, rm
+    A32(0x00900090), [R, R, R, R] => [R(12), R(16), R(0), R(8)], COND;
+],
+"umlals" = [
+    // umlals rdlo, rdhi, rn, rm
+    A32(0x00B00090), [R, R, R, R] => [R(12), R(16), R(0), R(8)], COND;
+],
+"smulls" = [
+    // smulls rdlo, rdhi, rn, rm
+    A32(0x00D00090), [R, R, R, R] => [R(12), R(16), R(0), R(8)], COND;
+],
+"smlals" = [
+    // smlals rdlo, rdhi, rn, rm
+    A32(0x00F00090), [R, R, R, R] => [R(12), R(16), R(0), R(8)], COND;
+],
+"mls" = [
+    // mls rd, rn, rm, ra
+    A32(0x00600090), [R, R, R, R] => [R(16), R(0), R(8), R(12)], COND;
+],
+"sdiv" = [
+    // sdiv rd, rn, rm
+    A32(0x0710F010), [R, R, R] => [R(16), R(0), R(8)], COND;
+],
+"udiv" = [
+    // udiv rd, rn, rm
+    A32(0x0730F010), [R, R, R] => [R(16), R(0), R(8)], COND;
+],
+
+// Bit manipulation
+
+"clz" = [
+    // clz rd, rm
+    A32(0x016F0F10), [R, R] => [R(12), R(0)], COND;
+],
+"rbit" = [
+    // rbit rd, rm
+    A32(0x06FF0F30), [R, R] => [R(12), R(0)], COND;
+],
+"rev" = [
+    // rev rd, rm
+    A32(0x06BF0F30), [R, R] => [R(12), R(0)], COND;
+],
+"rev16" = [
+    // rev16 rd, rm
+    A32(0x06BF0FB0), [R, R] => [R(12), R(0)], COND;
+],
+"revsh" = [
+    // revsh rd, rm
+    A32(0x06FF0FB0), [R, R] => [R(12), R(0)], COND;
+],
+"sxtb" = [
+    // sxtb rd, rm {, ror #rotation}
+    A32(0x06AF0070), [R, R, End, Ror] => [R(12), R(0), Rotation(10)], COND;
+],
+"sxth" = [
+    // sxth rd, rm {, ror #rotation}
+    A32(0x06BF0070), [R, R, End, Ror] => [R(12), R(0), Rotation(10)], COND;
+],
+"uxtb" = [
+    // uxtb rd, rm {, ror #rotation}
+    A32(0x06EF0070), [R, R, End, Ror] => [R(12), R(0), Rotation(10)], COND;
+],
+"uxth" = [
+    // uxth rd, rm {, ror #rotation}
+    A32(0x06FF0070), [R, R, End, Ror] => [R(12), R(0), Rotation(10)], COND;
+],
+"sxtab" = [
+    // sxtab rd, rn, rm {, ror #rotation}
+    A32(0x06A00070), [R, R, R, End, Ror] => [R(12), R(16), R(0), Rotation(10)], COND;
+],
+"sxtah" = [
+    // sxtah rd, rn, rm {, ror #rotation}
+    A32(0x06B00070), [R, R, R, End, Ror] => [R(12), R(16), R(0), Rotation(10)], COND;
+],
+"uxtab" = [
+    // uxtab rd, rn, rm {, ror #rotation}
+    A32(0x06E00070), [R, R, R, End, Ror] => [R(12), R(16), R(0), Rotation(10)], COND;
+],
+"uxtah" = [
+    // uxtah rd, rn, rm {, ror #rotation}
+    A32(0x06F00070), [R, R, R, End, Ror] => [R(12), R(16), R(0), Rotation(10)], COND;
+],
+"bfc" = [
+    // bfc rd, #lsb, #width
+    A32(0x07C0001F), [R, Imm, Imm] => [R(12), URange(0, 31), BitRange(7, 5, 0), Next, Msb(16)], COND;
+],
+"bfi" = [
+    // bfi rd, rn, #lsb, #width
+    A32(0x07C00010), [R, R, Imm, Imm] => [R(12), R(0), URange(0, 31), BitRange(7, 5, 0), Next, Msb(16)], COND;
+],
+"sbfx" = [
+    // sbfx rd, rn, #lsb, #width
+    A32(0x07A00050), [R, R, Imm, Imm] => [R(12), R(0), URange(0, 31), BitRange(7, 5, 0), Next, Width(16)], COND;
+],
+"ubfx" = [
+    // ubfx rd, rn, #lsb, #width
+    A32(0x07E00050), [R, R, Imm, Imm] => [R(12), R(0), URange(0, 31), BitRange(7, 5, 0), Next, Width(16)], COND;
+],
+
+// Loads and stores
+
+"ldr" = [
+    // ldr rt, [rn {, #offset}]
+    A32(0x05100000), [R, RefOffset] => [R(12), R(16), SignMag(23, 12, 0), BitRange(0, 12, 0), Next], COND;
+    // ldr rt, [rn, #offset]!
+    A32(0x05300000), [R, RefPre] => [R(12), R(16), SignMag(23, 12, 0), BitRange(0, 12, 0), Next], COND;
+    // ldr rt, [rn], #offset
+    A32(0x04100000), [R, RefBase, Imm] => [R(12), R(16), SignMag(23, 12, 0), BitRange(0, 12, 0), Next], COND;
+    // ldr rt, [rn, +/-rm {, shift #amount}]
+    A32(0x07100000), [R, RefIndex] => [R(12), R(16), RIndex(0, 23), ArmShift], COND;
+    // ldr rt, [rn, +/-rm {, shift #amount}]!
+    A32(0x07300000), [R, RefIndexPre] => [R(12), R(16), RIndex(0, 23), ArmShift], COND;
+    // ldr rt, [rn], +/-rm {, shift #amount}
+    A32(0x06100000), [R, RefBase, Index, End, Shift] => [R(12), R(16), RIndex(0, 23), ArmShift], COND;
+    // ldr rt, label
+    A32(0x051F0000), [R, Offset] => [R(12), Offset(LDR12)], COND;
+],
+"str" = [
+    // str rt, [rn {, #offset}]
+    A32(0x05000000), [R, RefOffset] => [R(12), R(16), SignMag(23, 12, 0), BitRange(0, 12, 0), Next], COND;
+    // str rt, [rn, #offset]!
+    A32(0x05200000), [R, RefPre] => [R(12), R(16), SignMag(23, 12, 0), BitRange(0, 12, 0), Next], COND;
+    // str rt, [rn], #offset
+    A32(0x04000000), [R, RefBase, Imm] => [R(12), R(16), SignMag(23, 12, 0), BitRange(0, 12, 0), Next], COND;
+    // str rt, [rn, +/-rm {, shift #amount}]
+    A32(0x07000000), [R, RefIndex] => [R(12), R(16), RIndex(0, 23), ArmShift], COND;
+    // str rt, [rn, +/-rm {, shift #amount}]!
+    A32(0x07200000), [R, RefIndexPre] => [R(12), R(16), RIndex(0, 23), ArmShift], COND;
+    // str rt, [rn], +/-rm {, shift #amount}
+    A32(0x06000000), [R, RefBase, Index, End, Shift] => [R(12), R(16), RIndex(0, 23), ArmShift], COND;
+],
+"ldrb" = [
+    // ldrb rt, [rn {, #offset}]
+    A32(0x05500000), [R, RefOffset] => [R(12), R(16), SignMag(23, 12, 0), BitRange(0, 12, 0), Next], COND;
+    // ldrb rt, [rn, #offset]!
+    A32(0x05700000), [R, RefPre] => [R(12), R(16), SignMag(23, 12, 0), BitRange(0, 12, 0), Next], COND;
+    // ldrb rt, [rn], #offset
+    A32(0x04500000), [R, RefBase, Imm] => [R(12), R(16), SignMag(23, 12, 0), BitRange(0, 12, 0), Next], COND;
+    // ldrb rt, [rn, +/-rm {, shift #amount}]
+    A32(0x07500000), [R, RefIndex] => [R(12), R(16), RIndex(0, 23), ArmShift], COND;
+    // ldrb rt, [rn, +/-rm {, shift #amount}]!
+    A32(0x07700000), [R, RefIndexPre] => [R(12), R(16), RIndex(0, 23), ArmShift], COND;
+    // ldrb rt, [rn], +/-rm {, shift #amount}
+    A32(0x06500000), [R, RefBase, Index, End, Shift] => [R(12), R(16), RIndex(0, 23), ArmShift], COND;
+    // ldrb rt, label
+    A32(0x055F0000), [R, Offset] => [R(12), Offset(LDR12)], COND;
+],
+"strb" = [
+    // strb rt, [rn {, #offset}]
+    A32(0x05400000), [R, RefOffset] => [R(12), R(16), SignMag(23, 12, 0), BitRange(0, 12, 0), Next], COND;
+    // strb rt, [rn, #offset]!
+    A32(0x05600000), [R, RefPre] => [R(12), R(16), SignMag(23, 12, 0), BitRange(0, 12, 0), Next], COND;
+    // strb rt, [rn], #offset
+    A32(0x04400000), [R, RefBase, Imm] => [R(12), R(16), SignMag(23, 12, 0), BitRange(0, 12, 0), Next], COND;
+    // strb rt, [rn, +/-rm {, shift #amount}]
+    A32(0x07400000), [R, RefIndex] => [R(12), R(16), RIndex(0, 23), ArmShift], COND;
+    // strb rt, [rn, +/-rm {, shift #amount}]!
+    A32(0x07600000), [R, RefIndexPre] => [R(12), R(16), RIndex(0, 23), ArmShift], COND;
+    // strb rt, [rn], +/-rm {, shift #amount}
+    A32(0x06400000), [R, RefBase, Index, End, Shift] => [R(12), R(16), RIndex(0, 23), ArmShift], COND;
+],
+"ldrh" = [
+    // ldrh rt, [rn {, #offset}]
+    A32(0x015000B0), [R, RefOffset] => [R(12), R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // ldrh rt, [rn, #offset]!
+    A32(0x017000B0), [R, RefPre] => [R(12), R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // ldrh rt, [rn], #offset
+    A32(0x005000B0), [R, RefBase, Imm] => [R(12), R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // ldrh rt, [rn, +/-rm]
+    A32(0x011000B0), [R, RefIndex] => [R(12), R(16), RIndex(0, 23), IndexShift(0, 0)], COND;
+    // ldrh rt, [rn, +/-rm]!
+    A32(0x013000B0), [R, RefIndexPre] => [R(12), R(16), RIndex(0, 23), IndexShift(0, 0)], COND;
+    // ldrh rt, [rn], +/-rm
+    A32(0x001000B0), [R, RefBase, Index] => [R(12), R(16), RIndex(0, 23)], COND;
+    // ldrh rt, label
+    A32(0x015F00B0), [R, Offset] => [R(12), Offset(LDR8)], COND;
+],
+"strh" = [
+    // strh rt, [rn {, #offset}]
+    A32(0x014000B0), [R, RefOffset] => [R(12), R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // strh rt, [rn, #offset]!
+    A32(0x016000B0), [R, RefPre] => [R(12), R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // strh rt, [rn], #offset
+    A32(0x004000B0), [R, RefBase, Imm] => [R(12), R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // strh rt, [rn, +/-rm]
+    A32(0x010000B0), [R, RefIndex] => [R(12), R(16), RIndex(0, 23), IndexShift(0, 0)], COND;
+    // strh rt, [rn, +/-rm]!
+    A32(0x012000B0), [R, RefIndexPre] => [R(12), R(16), RIndex(0, 23), IndexShift(0, 0)], COND;
+    // strh rt, [rn], +/-rm
+    A32(0x000000B0), [R, RefBase, Index] => [R(12), R(16), RIndex(0, 23)], COND;
+],
+"ldrsb" = [
+    // ldrsb rt, [rn {, #offset}]
+    A32(0x015000D0), [R, RefOffset] => [R(12), R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // ldrsb rt, [rn, #offset]!
+    A32(0x017000D0), [R, RefPre] => [R(12), R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // ldrsb rt, [rn], #offset
+    A32(0x005000D0), [R, RefBase, Imm] => [R(12), R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // ldrsb rt, [rn, +/-rm]
+    A32(0x011000D0), [R, RefIndex] => [R(12), R(16), RIndex(0, 23), IndexShift(0, 0)], COND;
+    // ldrsb rt, [rn, +/-rm]!
+    A32(0x013000D0), [R, RefIndexPre] => [R(12), R(16), RIndex(0, 23), IndexShift(0, 0)], COND;
+    // ldrsb rt, [rn], +/-rm
+    A32(0x001000D0), [R, RefBase, Index] => [R(12), R(16), RIndex(0, 23)], COND;
+    // ldrsb rt, label
+    A32(0x015F00D0), [R, Offset] => [R(12), Offset(LDR8)], COND;
+],
+"ldrsh" = [
+    // ldrsh rt, [rn {, #offset}]
+    A32(0x015000F0), [R, RefOffset] => [R(12), R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // ldrsh rt, [rn, #offset]!
+    A32(0x017000F0), [R, RefPre] => [R(12), R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // ldrsh rt, [rn], #offset
+    A32(0x005000F0), [R, RefBase, Imm] => [R(12), R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // ldrsh rt, [rn, +/-rm]
+    A32(0x011000F0), [R, RefIndex] => [R(12), R(16), RIndex(0, 23), IndexShift(0, 0)], COND;
+    // ldrsh rt, [rn, +/-rm]!
+    A32(0x013000F0), [R, RefIndexPre] => [R(12), R(16), RIndex(0, 23), IndexShift(0, 0)], COND;
+    // ldrsh rt, [rn], +/-rm
+    A32(0x001000F0), [R, RefBase, Index] => [R(12), R(16), RIndex(0, 23)], COND;
+    // ldrsh rt, label
+    A32(0x015F00F0), [R, Offset] => [R(12), Offset(LDR8)], COND;
+],
+"ldrd" = [
+    // ldrd rt, rt2, [rn {, #offset}]
+    A32(0x014000D0), [R, R, RefOffset] => [REven(12), RNext, R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // ldrd rt, rt2, [rn, #offset]!
+    A32(0x016000D0), [R, R, RefPre] => [REven(12), RNext, R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // ldrd rt, rt2, [rn], #offset
+    A32(0x004000D0), [R, R, RefBase, Imm] => [REven(12), RNext, R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // ldrd rt, rt2, [rn, +/-rm]
+    A32(0x010000D0), [R, R, RefIndex] => [REven(12), RNext, R(16), RIndex(0, 23), IndexShift(0, 0)], COND;
+    // ldrd rt, rt2, [rn, +/-rm]!
+    A32(0x012000D0), [R, R, RefIndexPre] => [REven(12), RNext, R(16), RIndex(0, 23), IndexShift(0, 0)], COND;
+    // ldrd rt, rt2, [rn], +/-rm
+    A32(0x000000D0), [R, R, RefBase, Index] => [REven(12), RNext, R(16), RIndex(0, 23)], COND;
+    // ldrd rt, rt2, label
+    A32(0x014F00D0), [R, R, Offset] => [REven(12), RNext, Offset(LDR8)], COND;
+],
+"strd" = [
+    // strd rt, rt2, [rn {, #offset}]
+    A32(0x014000F0), [R, R, RefOffset] => [REven(12), RNext, R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // strd rt, rt2, [rn, #offset]!
+    A32(0x016000F0), [R, R, RefPre] => [REven(12), RNext, R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // strd rt, rt2, [rn], #offset
+    A32(0x004000F0), [R, R, RefBase, Imm] => [REven(12), RNext, R(16), SignMag(23, 8, 0), BitRange(0, 4, 0), BitRange(8, 4, 4), Next], COND;
+    // strd rt, rt2, [rn, +/-rm]
+    A32(0x010000F0), [R, R, RefIndex] => [REven(12), RNext, R(16), RIndex(0, 23), IndexShift(0, 0)], COND;
+    // strd rt, rt2, [rn, +/-rm]!
+    A32(0x012000F0), [R, R, RefIndexPre] => [REven(12), RNext, R(16), RIndex(0, 23), IndexShift(0, 0)], COND;
+    // strd rt, rt2, [rn], +/-rm
+    A32(0x000000F0), [R, R, RefBase, Index] => [REven(12), RNext, R(16), RIndex(0, 23)], COND;
+],
+"ldrex" = [
+    // ldrex rt, [rn]
+    A32(0x01900F9F), [R, RefBase] => [R(12), R(16)], COND;
+],
+"ldrexb" = [
+    // ldrexb rt, [rn]
+    A32(0x01D00F9F), [R, RefBase] => [R(12), R(16)], COND;
+],
+"ldrexh" = [
+    // ldrexh rt, [rn]
+    A32(0x01F00F9F), [R, RefBase] => [R(12), R(16)], COND;
+],
+"strex" = [
+    // strex rd, rt, [rn]
+    A32(0x01800F90), [R, R, RefBase] => [R(12), R(0), R(16)], COND;
+],
+"strexb" = [
+    // strexb rd, rt, [rn]
+    A32(0x01C00F90), [R, R, RefBase] => [R(12), R(0), R(16)], COND;
+],
+"strexh" = [
+    // strexh rd, rt, [rn]
+    A32(0x01E00F90), [R, R, RefBase] => [R(12), R(0), R(16)], COND;
+],
+"clrex" = [
+    // clrex
+    A32(0xF57FF01F), [] => [];
+],
+
+// Load and store multiple
+
+"ldm" = [
+    // ldm rn, {reglist}
+    A32(0x08900000), [R, RegList] => [R(16), RList(0)], COND;
+    // ldm rn!, {reglist}
+    A32(0x08B00000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"ldmia" = [
+    // ldmia rn, {reglist}
+    A32(0x08900000), [R, RegList] => [R(16), RList(0)], COND;
+    // ldmia rn!, {reglist}
+    A32(0x08B00000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"ldmfd" = [
+    // ldmfd rn, {reglist}
+    A32(0x08900000), [R, RegList] => [R(16), RList(0)], COND;
+    // ldmfd rn!, {reglist}
+    A32(0x08B00000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"ldmib" = [
+    // ldmib rn, {reglist}
+    A32(0x09900000), [R, RegList] => [R(16), RList(0)], COND;
+    // ldmib rn!, {reglist}
+    A32(0x09B00000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"ldmed" = [
+    // ldmed rn, {reglist}
+    A32(0x09900000), [R, RegList] => [R(16), RList(0)], COND;
+    // ldmed rn!, {reglist}
+    A32(0x09B00000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"ldmda" = [
+    // ldmda rn, {reglist}
+    A32(0x08100000), [R, RegList] => [R(16), RList(0)], COND;
+    // ldmda rn!, {reglist}
+    A32(0x08300000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"ldmfa" = [
+    // ldmfa rn, {reglist}
+    A32(0x08100000), [R, RegList] => [R(16), RList(0)], COND;
+    // ldmfa rn!, {reglist}
+    A32(0x08300000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"ldmdb" = [
+    // ldmdb rn, {reglist}
+    A32(0x09100000), [R, RegList] => [R(16), RList(0)], COND;
+    // ldmdb rn!, {reglist}
+    A32(0x09300000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"ldmea" = [
+    // ldmea rn, {reglist}
+    A32(0x09100000), [R, RegList] => [R(16), RList(0)], COND;
+    // ldmea rn!, {reglist}
+    A32(0x09300000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"stm" = [
+    // stm rn, {reglist}
+    A32(0x08800000), [R, RegList] => [R(16), RList(0)], COND;
+    // stm rn!, {reglist}
+    A32(0x08A00000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"stmia" = [
+    // stmia rn, {reglist}
+    A32(0x08800000), [R, RegList] => [R(16), RList(0)], COND;
+    // stmia rn!, {reglist}
+    A32(0x08A00000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"stmea" = [
+    // stmea rn, {reglist}
+    A32(0x08800000), [R, RegList] => [R(16), RList(0)], COND;
+    // stmea rn!, {reglist}
+    A32(0x08A00000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"stmib" = [
+    // stmib rn, {reglist}
+    A32(0x09800000), [R, RegList] => [R(16), RList(0)], COND;
+    // stmib rn!, {reglist}
+    A32(0x09A00000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"stmfa" = [
+    // stmfa rn, {reglist}
+    A32(0x09800000), [R, RegList] => [R(16), RList(0)], COND;
+    // stmfa rn!, {reglist}
+    A32(0x09A00000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"stmda" = [
+    // stmda rn, {reglist}
+    A32(0x08000000), [R, RegList] => [R(16), RList(0)], COND;
+    // stmda rn!, {reglist}
+    A32(0x08200000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"stmed" = [
+    // stmed rn, {reglist}
+    A32(0x08000000), [R, RegList] => [R(16), RList(0)], COND;
+    // stmed rn!, {reglist}
+    A32(0x08200000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"stmdb" = [
+    // stmdb rn, {reglist}
+    A32(0x09000000), [R, RegList] => [R(16), RList(0)], COND;
+    // stmdb rn!, {reglist}
+    A32(0x09200000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"stmfd" = [
+    // stmfd rn, {reglist}
+    A32(0x09000000), [R, RegList] => [R(16), RList(0)], COND;
+    // stmfd rn!, {reglist}
+    A32(0x09200000), [Writeback, RegList] => [R(16), RList(0)], COND;
+],
+"push" = [
+    // push {rt}, encoded as str rt, [sp, #-4]!
+    A32(0x052D0004), [RegList] => [RListSingle(12)], COND;
+    // push {reglist}
+    A32(0x092D0000), [RegList] => [RList(0)], COND;
+],
+"pop" = [
+    // pop {rt}, encoded as ldr rt, [sp], #4
+    A32(0x049D0004), [RegList] => [RListSingle(12)], COND;
+    // pop {reglist}
+    A32(0x08BD0000), [RegList] => [RList(0)], COND;
+],
+
+// Branches
+
+"b" = [
+    // b label
+    A32(0x0A000000), [Offset] => [Offset(B24)], COND;
+],
+"bl" = [
+    // bl label
+    A32(0x0B000000), [Offset] => [Offset(B24)], COND;
+],
+"bx" = [
+    // bx rm
+    A32(0x012FFF10), [R] => [R(0)], COND;
+],
+"blx" = [
+    // blx rm
+    A32(0x012FFF30), [R] => [R(0)], COND;
+],
+"adr" = [
+    // adr rd, label
+    A32(0x020F0000), [R, Offset] => [R(12), Offset(ADR12)], COND;
+],
+
+// Miscellaneous
+
+"nop" = [
+    // nop
+    A32(0x0320F000), [] => [], COND;
+],
+"yield" = [
+    // yield
+    A32(0x0320F001), [] => [], COND;
+],
+"wfe" = [
+    // wfe
+    A32(0x0320F002), [] => [], COND;
+],
+"wfi" = [
+    // wfi
+    A32(0x0320F003), [] => [], COND;
+],
+"sev" = [
+    // sev
+    A32(0x0320F004), [] => [], COND;
+],
+"svc" = [
+    // svc #imm24
+    A32(0x0F000000), [Imm] => [UImm(24, 0), BitRange(0, 24, 0), Next], COND;
+],
+"bkpt" = [
+    // bkpt {#imm16}
+    A32(0xE1200070), [End, Imm] => [UImm(16, 0), BitRange(0, 4, 0), BitRange(8, 12, 4), Next];
+],
+"udf" = [
+    // udf {#imm16}
+    A32(0xE7F000F0), [End, Imm] => [UImm(16, 0), BitRange(0, 4, 0), BitRange(8, 12, 4), Next];
+],
+"dmb" = [
+    // dmb
+    A32(0xF57FF05F), [] => [];
+    // dmb option
+    A32(0xF57FF050), [Ident] => [LitList(0, "BARRIER_OPS")];
+],
+"dsb" = [
+    // dsb
+    A32(0xF57FF04F), [] => [];
+    // dsb option
+    A32(0xF57FF040), [Ident] => [LitList(0, "BARRIER_OPS")];
+],
+"isb" = [
+    // isb
+    A32(0xF57FF06F), [] => [];
+    // isb option
+    A32(0xF57FF060), [Ident] => [LitList(0, "ISB_OPS")];
+],
+"mrs" = [
+    // mrs rd, apsr
+    A32(0x010F0000), [R, Lit("apsr")] => [R(12)], COND;
+],
+"msr" = [
+    // msr apsr_nzcvq, rn
+    A32(0x0128F000), [Lit("apsr_nzcvq"), R] => [R(0)], COND;
+],
+)
@@ -0,0 +1,1137 @@
+Ops!(
+
+
+// Data processing
+
+"and" = [
+    // and rdn, rdn, rm
+    T16(0x4000), [R, R, R] => [R3(0), Same(0), R3(3)], INSIDE_IT;
+    // and rdn, rm, rdn
+    T16(0x4000), [R, R, R] => [R3(0), R3(3), Same(0)], INSIDE_IT;
+    // and rdn, rm
+    T16(0x4000), [R, R] => [R3(0), R3(3)], INSIDE_IT;
+    // and rd, rn, #imm
+    T32(0xF0000000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // and rd, rn, #imm, encoded as bic with the inverted immediate
+    T32(0xF0200000), [R, R, Imm] => [R(8), R(16), ThumbImm(Inv)];
+    // and rd, rn, rm {, shift #amount}
+    T32(0xEA000000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // and rd, #imm
+    T32(0xF0000000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // and rd, #imm, encoded as bic with the inverted immediate
+    T32(0xF0200000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Inv)];
+    // and rd, rm {, shift #amount}
+    T32(0xEA000000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"ands" = [
+    // ands rdn, rdn, rm
+    T16(0x4000), [R, R, R] => [R3(0), Same(0), R3(3)], OUTSIDE_IT;
+    // ands rdn, rm, rdn
+    T16(0x4000), [R, R, R] => [R3(0), R3(3), Same(0)], OUTSIDE_IT;
+    // ands rdn, rm
+    T16(0x4000), [R, R] => [R3(0), R3(3)], OUTSIDE_IT;
+    // ands rd, rn, #imm
+    T32(0xF0100000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // ands rd, rn, #imm, encoded as bics with the inverted immediate
+    T32(0xF0300000), [R, R, Imm] => [R(8), R(16), ThumbImm(Inv)];
+    // ands rd, rn, rm {, shift #amount}
+    T32(0xEA100000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // ands rd, #imm
+    T32(0xF0100000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // ands rd, #imm, encoded as bics with the inverted immediate
+    T32(0xF0300000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Inv)];
+    // ands rd, rm {, shift #amount}
+    T32(0xEA100000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"bic" = [
+    // bic rdn, rdn, rm
+    T16(0x4380), [R, R, R] => [R3(0), Same(0), R3(3)], INSIDE_IT;
+    // bic rdn, rm
+    T16(0x4380), [R, R] => [R3(0), R3(3)], INSIDE_IT;
+    // bic rd, rn, #imm
+    T32(0xF0200000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // bic rd, rn, #imm, encoded as and with the inverted immediate
+    T32(0xF0000000), [R, R, Imm] => [R(8), R(16), ThumbImm(Inv)];
+    // bic rd, rn, rm {, shift #amount}
+    T32(0xEA200000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // bic rd, #imm
+    T32(0xF0200000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // bic rd, #imm, encoded as and with the inverted immediate
+    T32(0xF0000000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Inv)];
+    // bic rd, rm {, shift #amount}
+    T32(0xEA200000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"bics" = [
+    // bics rdn, rdn, rm
+    T16(0x4380), [R, R, R] => [R3(0), Same(0), R3(3)], OUTSIDE_IT;
+    // bics rdn, rm
+    T16(0x4380), [R, R] => [R3(0), R3(3)], OUTSIDE_IT;
+    // bics rd, rn, #imm
+    T32(0xF0300000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // bics rd, rn, #imm, encoded as ands with the inverted immediate
+    T32(0xF0100000), [R, R, Imm] => [R(8), R(16), ThumbImm(Inv)];
+    // bics rd, rn, rm {, shift #amount}
+    T32(0xEA300000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // bics rd, #imm
+    T32(0xF0300000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // bics rd, #imm, encoded as ands with the inverted immediate
+    T32(0xF0100000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Inv)];
+    // bics rd, rm {, shift #amount}
+    T32(0xEA300000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"orr" = [
+    // orr rdn, rdn, rm
+    T16(0x4300), [R, R, R] => [R3(0), Same(0), R3(3)], INSIDE_IT;
+    // orr rdn, rm, rdn
+    T16(0x4300), [R, R, R] => [R3(0), R3(3), Same(0)], INSIDE_IT;
+    // orr rdn, rm
+    T16(0x4300), [R, R] => [R3(0), R3(3)], INSIDE_IT;
+    // orr rd, rn, #imm
+    T32(0xF0400000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // orr rd, rn, #imm, encoded as orn with the inverted immediate
+    T32(0xF0600000), [R, R, Imm] => [R(8), R(16), ThumbImm(Inv)];
+    // orr rd, rn, rm {, shift #amount}
+    T32(0xEA400000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // orr rd, #imm
+    T32(0xF0400000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // orr rd, #imm, encoded as orn with the inverted immediate
+    T32(0xF0600000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Inv)];
+    // orr rd, rm {, shift #amount}
+    T32(0xEA400000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"orrs" = [
+    // orrs rdn, rdn, rm
+    T16(0x4300), [R, R, R] => [R3(0), Same(0), R3(3)], OUTSIDE_IT;
+    // orrs rdn, rm, rdn
+    T16(0x4300), [R, R, R] => [R3(0), R3(3), Same(0)], OUTSIDE_IT;
+    // orrs rdn, rm
+    T16(0x4300), [R, R] => [R3(0), R3(3)], OUTSIDE_IT;
+    // orrs rd, rn, #imm
+    T32(0xF0500000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // orrs rd, rn, #imm, encoded as orns with the inverted immediate
+    T32(0xF0700000), [R, R, Imm] => [R(8), R(16), ThumbImm(Inv)];
+    // orrs rd, rn, rm {, shift #amount}
+    T32(0xEA500000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // orrs rd, #imm
+    T32(0xF0500000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // orrs rd, #imm, encoded as orns with the inverted immediate
+    T32(0xF0700000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Inv)];
+    // orrs rd, rm {, shift #amount}
+    T32(0xEA500000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"orn" = [
+    // orn rd, rn, #imm
+    T32(0xF0600000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // orn rd, rn, #imm, encoded as orr with the inverted immediate
+    T32(0xF0400000), [R, R, Imm] => [R(8), R(16), ThumbImm(Inv)];
+    // orn rd, rn, rm {, shift #amount}
+    T32(0xEA600000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // orn rd, #imm
+    T32(0xF0600000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // orn rd, #imm, encoded as orr with the inverted immediate
+    T32(0xF0400000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Inv)];
+    // orn rd, rm {, shift #amount}
+    T32(0xEA600000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"orns" = [
+    // orns rd, rn, #imm
+    T32(0xF0700000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // orns rd, rn, #imm, encoded as orrs with the inverted immediate
+    T32(0xF0500000), [R, R, Imm] => [R(8), R(16), ThumbImm(Inv)];
+    // orns rd, rn, rm {, shift #amount}
+    T32(0xEA700000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // orns rd, #imm
+    T32(0xF0700000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // orns rd, #imm, encoded as orrs with the inverted immediate
+    T32(0xF0500000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Inv)];
+    // orns rd, rm {, shift #amount}
+    T32(0xEA700000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"eor" = [
+    // eor rdn, rdn, rm
+    T16(0x4040), [R, R, R] => [R3(0), Same(0), R3(3)], INSIDE_IT;
+    // eor rdn, rm, rdn
+    T16(0x4040), [R, R, R] => [R3(0), R3(3), Same(0)], INSIDE_IT;
+    // eor rdn, rm
+    T16(0x4040), [R, R] => [R3(0), R3(3)], INSIDE_IT;
+    // eor rd, rn, #imm
+    T32(0xF0800000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // eor rd, rn, rm {, shift #amount}
+    T32(0xEA800000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // eor rd, #imm
+    T32(0xF0800000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // eor rd, rm {, shift #amount}
+    T32(0xEA800000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"eors" = [
+    // eors rdn, rdn, rm
+    T16(0x4040), [R, R, R] => [R3(0), Same(0), R3(3)], OUTSIDE_IT;
+    // eors rdn, rm, rdn
+    T16(0x4040), [R, R, R] => [R3(0), R3(3), Same(0)], OUTSIDE_IT;
+    // eors rdn, rm
+    T16(0x4040), [R, R] => [R3(0), R3(3)], OUTSIDE_IT;
+    // eors rd, rn, #imm
+    T32(0xF0900000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // eors rd, rn, rm {, shift #amount}
+    T32(0xEA900000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // eors rd, #imm
+    T32(0xF0900000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // eors rd, rm {, shift #amount}
+    T32(0xEA900000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"add" = [
+    // add rd, rn, rm
+    T16(0x1800), [R, R, R] => [R3(0), R3(3), R3(6)], INSIDE_IT;
+    // add rd, rn, #imm3
+    T16(0x1C00), [R, R, Imm] => [R3(0), R3(3), UImm(3, 0), BitRange(6, 3, 0), Next], INSIDE_IT;
+    // add rdn, rdn, #imm8
+    T16(0x3000), [R, R, Imm] => [R3(8), Same(0), UImm(8, 0), BitRange(0, 8, 0), Next], INSIDE_IT;
+    // add rdn, #imm8
+    T16(0x3000), [R, Imm] => [R3(8), UImm(8, 0), BitRange(0, 8, 0), Next], INSIDE_IT;
+    // add sp, sp, #imm7 * 4
+    T16(0xB000), [R, R, Imm] => [RFixed(13), RFixed(13), UImm(9, 2), BitRange(0, 7, 2), Next];
+    // add sp, #imm7 * 4
+    T16(0xB000), [R, Imm] => [RFixed(13), UImm(9, 2), BitRange(0, 7, 2), Next];
+    // add rd, sp, #imm8 * 4
+    T16(0xA800), [R, R, Imm] => [R3(8), RFixed(13), UImm(10, 2), BitRange(0, 8, 2), Next];
+    // add rdn, rdn, rm
+    T16(0x4400), [R, R, R] => [RSplit(0, 7), Same(0), R(3)];
+    // add rdn, rm, rdn
+    T16(0x4400), [R, R, R] => [RSplit(0, 7), R(3), Same(0)];
+    // add rdn, rm
+    T16(0x4400), [R, R] => [RSplit(0, 7), R(3)];
+    // add rd, rn, #imm
+    T32(0xF1000000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // add rd, rn, #imm, encoded as sub with the negated immediate
+    T32(0xF1A00000), [R, R, Imm] => [R(8), R(16), ThumbImm(Neg)];
+    // add rd, rn, #imm12, encoded as addw
+    T32(0xF2000000), [R, R, Imm] => [R(8), R(16), UImm(12, 0), BitRange(0, 8, 0), BitRange(12, 3, 8), BitRange(26, 1, 11), Next];
+    // add rd, rn, rm {, shift #amount}
+    T32(0xEB000000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // add rd, #imm
+    T32(0xF1000000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // add rd, #imm, encoded as sub with the negated immediate
+    T32(0xF1A00000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Neg)];
+    // add rd, #imm12, encoded as addw
+    T32(0xF2000000), [R, Imm] => [R(8), Repeat, R(16), UImm(12, 0), BitRange(0, 8, 0), BitRange(12, 3, 8), BitRange(26, 1, 11), Next];
+    // add rd, rm {, shift #amount}
+    T32(0xEB000000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"adds" = [
+    // adds rd, rn, rm
+    T16(0x1800), [R, R, R] => [R3(0), R3(3), R3(6)], OUTSIDE_IT;
+    // adds rd, rn, #imm3
+    T16(0x1C00), [R, R, Imm] => [R3(0), R3(3), UImm(3, 0), BitRange(6, 3, 0), Next], OUTSIDE_IT;
+    // adds rdn, rdn, #imm8
+    T16(0x3000), [R, R, Imm] => [R3(8), Same(0), UImm(8, 0), BitRange(0, 8, 0), Next], OUTSIDE_IT;
+    // adds rdn, #imm8
+    T16(0x3000), [R, Imm] => [R3(8), UImm(8, 0), BitRange(0, 8, 0), Next], OUTSIDE_IT;
+    // adds rd, rn, #imm
+    T32(0xF1100000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // adds rd, rn, #imm, encoded as subs with the negated immediate
+    T32(0xF1B00000), [R, R, Imm] => [R(8), R(16), ThumbImm(Neg)];
+    // adds rd, rn, rm {, shift #amount}
+    T32(0xEB100000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // adds rd, #imm
+    T32(0xF1100000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // adds rd, #imm, encoded as subs with the negated immediate
+    T32(0xF1B00000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Neg)];
+    // adds rd, rm {, shift #amount}
+    T32(0xEB100000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"adc" = [
+    // adc rdn, rdn, rm
+    T16(0x4140), [R, R, R] => [R3(0), Same(0), R3(3)], INSIDE_IT;
+    // adc rdn, rm, rdn
+    T16(0x4140), [R, R, R] => [R3(0), R3(3), Same(0)], INSIDE_IT;
+    // adc rdn, rm
+    T16(0x4140), [R, R] => [R3(0), R3(3)], INSIDE_IT;
+    // adc rd, rn, #imm
+    T32(0xF1400000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // adc rd, rn, #imm, encoded as sbc with the inverted immediate
+    T32(0xF1600000), [R, R, Imm] => [R(8), R(16), ThumbImm(Inv)];
+    // adc rd, rn, rm {, shift #amount}
+    T32(0xEB400000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // adc rd, #imm
+    T32(0xF1400000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // adc rd, #imm, encoded as sbc with the inverted immediate
+    T32(0xF1600000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Inv)];
+    // adc rd, rm {, shift #amount}
+    T32(0xEB400000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"adcs" = [
+    // adcs rdn, rdn, rm
+    T16(0x4140), [R, R, R] => [R3(0), Same(0), R3(3)], OUTSIDE_IT;
+    // adcs rdn, rm, rdn
+    T16(0x4140), [R, R, R] => [R3(0), R3(3), Same(0)], OUTSIDE_IT;
+    // adcs rdn, rm
+    T16(0x4140), [R, R] => [R3(0), R3(3)], OUTSIDE_IT;
+    // adcs rd, rn, #imm
+    T32(0xF1500000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // adcs rd, rn, #imm, encoded as sbcs with the inverted immediate
+    T32(0xF1700000), [R, R, Imm] => [R(8), R(16), ThumbImm(Inv)];
+    // adcs rd, rn, rm {, shift #amount}
+    T32(0xEB500000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // adcs rd, #imm
+    T32(0xF1500000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // adcs rd, #imm, encoded as sbcs with the inverted immediate
+    T32(0xF1700000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Inv)];
+    // adcs rd, rm {, shift #amount}
+    T32(0xEB500000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"sbc" = [
+    // sbc rdn, rdn, rm
+    T16(0x4180), [R, R, R] => [R3(0), Same(0), R3(3)], INSIDE_IT;
+    // sbc rdn, rm
+    T16(0x4180), [R, R] => [R3(0), R3(3)], INSIDE_IT;
+    // sbc rd, rn, #imm
+    T32(0xF1600000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // sbc rd, rn, #imm, encoded as adc with the inverted immediate
+    T32(0xF1400000), [R, R, Imm] => [R(8), R(16), ThumbImm(Inv)];
+    // sbc rd, rn, rm {, shift #amount}
+    T32(0xEB600000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // sbc rd, #imm
+    T32(0xF1600000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // sbc rd, #imm, encoded as adc with the inverted immediate
+    T32(0xF1400000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Inv)];
+    // sbc rd, rm {, shift #amount}
+    T32(0xEB600000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"sbcs" = [
+    // sbcs rdn, rdn, rm
+    T16(0x4180), [R, R, R] => [R3(0), Same(0), R3(3)], OUTSIDE_IT;
+    // sbcs rdn, rm
+    T16(0x4180), [R, R] => [R3(0), R3(3)], OUTSIDE_IT;
+    // sbcs rd, rn, #imm
+    T32(0xF1700000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // sbcs rd, rn, #imm, encoded as adcs with the inverted immediate
+    T32(0xF1500000), [R, R, Imm] => [R(8), R(16), ThumbImm(Inv)];
+    // sbcs rd, rn, rm {, shift #amount}
+    T32(0xEB700000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // sbcs rd, #imm
+    T32(0xF1700000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // sbcs rd, #imm, encoded as adcs with the inverted immediate
+    T32(0xF1500000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Inv)];
+    // sbcs rd, rm {, shift #amount}
+    T32(0xEB700000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"sub" = [
+    // sub rd, rn, rm
+    T16(0x1A00), [R, R, R] => [R3(0), R3(3), R3(6)], INSIDE_IT;
+    // sub rd, rn, #imm3
+    T16(0x1E00), [R, R, Imm] => [R3(0), R3(3), UImm(3, 0), BitRange(6, 3, 0), Next], INSIDE_IT;
+    // sub rdn, rdn, #imm8
+    T16(0x3800), [R, R, Imm] => [R3(8), Same(0), UImm(8, 0), BitRange(0, 8, 0), Next], INSIDE_IT;
+    // sub rdn, #imm8
+    T16(0x3800), [R, Imm] => [R3(8), UImm(8, 0), BitRange(0, 8, 0), Next], INSIDE_IT;
+    // sub sp, sp, #imm7 * 4
+    T16(0xB080), [R, R, Imm] => [RFixed(13), RFixed(13), UImm(9, 2), BitRange(0, 7, 2), Next];
+    // sub sp, #imm7 * 4
+    T16(0xB080), [R, Imm] => [RFixed(13), UImm(9, 2), BitRange(0, 7, 2), Next];
+    // sub rd, rn, #imm
+    T32(0xF1A00000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // sub rd, rn, #imm, encoded as add with the negated immediate
+    T32(0xF1000000), [R, R, Imm] => [R(8), R(16), ThumbImm(Neg)];
+    // sub rd, rn, #imm12, encoded as subw
+    T32(0xF2A00000), [R, R, Imm] => [R(8), R(16), UImm(12, 0), BitRange(0, 8, 0), BitRange(12, 3, 8), BitRange(26, 1, 11), Next];
+    // sub rd, rn, rm {, shift #amount}
+    T32(0xEBA00000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // sub rd, #imm
+    T32(0xF1A00000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // sub rd, #imm, encoded as add with the negated immediate
+    T32(0xF1000000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Neg)];
+    // sub rd, #imm12, encoded as subw
+    T32(0xF2A00000), [R, Imm] => [R(8), Repeat, R(16), UImm(12, 0), BitRange(0, 8, 0), BitRange(12, 3, 8), BitRange(26, 1, 11), Next];
+    // sub rd, rm {, shift #amount}
+    T32(0xEBA00000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"subs" = [
+    // subs rd, rn, rm
+    T16(0x1A00), [R, R, R] => [R3(0), R3(3), R3(6)], OUTSIDE_IT;
+    // subs rd, rn, #imm3
+    T16(0x1E00), [R, R, Imm] => [R3(0), R3(3), UImm(3, 0), BitRange(6, 3, 0), Next], OUTSIDE_IT;
+    // subs rdn, rdn, #imm8
+    T16(0x3800), [R, R, Imm] => [R3(8), Same(0), UImm(8, 0), BitRange(0, 8, 0), Next], OUTSIDE_IT;
+    // subs rdn, #imm8
+    T16(0x3800), [R, Imm] => [R3(8), UImm(8, 0), BitRange(0, 8, 0), Next], OUTSIDE_IT;
+    // subs rd, rn, #imm
+    T32(0xF1B00000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // subs rd, rn, #imm, encoded as adds with the negated immediate
+    T32(0xF1100000), [R, R, Imm] => [R(8), R(16), ThumbImm(Neg)];
+    // subs rd, rn, rm {, shift #amount}
+    T32(0xEBB00000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // subs rd, #imm
+    T32(0xF1B00000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // subs rd, #imm, encoded as adds with the negated immediate
+    T32(0xF1100000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Neg)];
+    // subs rd, rm {, shift #amount}
+    T32(0xEBB00000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"rsb" = [
+    // rsb rd, rn, #0
+    T16(0x4240), [R, R, Imm] => [R3(0), R3(3), URange(0, 0), Next], INSIDE_IT;
+    // rsb rd, rn, #imm
+    T32(0xF1C00000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // rsb rd, rn, rm {, shift #amount}
+    T32(0xEBC00000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // rsb rd, #imm
+    T32(0xF1C00000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // rsb rd, rm {, shift #amount}
+    T32(0xEBC00000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"rsbs" = [
+    // rsbs rd, rn, #0
+    T16(0x4240), [R, R, Imm] => [R3(0), R3(3), URange(0, 0), Next], OUTSIDE_IT;
+    // rsbs rd, rn, #imm
+    T32(0xF1D00000), [R, R, Imm] => [R(8), R(16), ThumbImm(Plain)];
+    // rsbs rd, rn, rm {, shift #amount}
+    T32(0xEBD00000), [R, R, R, End, Shift] => [R(8), R(16), R(0), ThumbShift];
+    // rsbs rd, #imm
+    T32(0xF1D00000), [R, Imm] => [R(8), Repeat, R(16), ThumbImm(Plain)];
+    // rsbs rd, rm {, shift #amount}
+    T32(0xEBD00000), [R, R, End, Shift] => [R(8), Repeat, R(16), R(0), ThumbShift];
+],
+"addw" = [
+    // addw rd, rn, #imm12
+    T32(0xF2000000), [R, R, Imm] => [R(8), R(16), UImm(12, 0), BitRange(0, 8, 0), BitRange(12, 3, 8), BitRange(26, 1, 11), Next];
+],
+"subw" = [
+    // subw rd, rn, #imm12
+    T32(0xF2A00000), [R, R, Imm] => [R(8), R(16), UImm(12, 0), BitRange(0, 8, 0), BitRange(12, 3, 8), BitRange(26, 1, 11), Next];
+],
+"tst" = [
+    // tst rn, rm
+    T16(0x4200), [R, R] => [R3(0), R3(3)];
+    // tst rn, #imm
+    T32(0xF0100F00), [R, Imm] => [R(16), ThumbImm(Plain)];
+    // tst rn, rm {, shift #amount}
+    T32(0xEA100F00), [R, R, End, Shift] => [R(16), R(0), ThumbShift];
+],
+"teq" = [
+    // teq rn, #imm
+    T32(0xF0900F00), [R, Imm] => [R(16), ThumbImm(Plain)];
+    // teq rn, rm {, shift #amount}
+    T32(0xEA900F00), [R, R, End, Shift] => [R(16), R(0), ThumbShift];
+],
+"cmp" = [
+    // cmp rn, #imm8
+    T16(0x2800), [R, Imm] => [R3(8), UImm(8, 0), BitRange(0, 8, 0), Next];
+    // cmp rn, rm
+    T16(0x4280), [R, R] => [R3(0), R3(3)];
+    // cmp rn, rm
+    T16(0x4500), [R, R] => [RSplit(0, 7), R(3)];
+    // cmp rn, #imm
+    T32(0xF1B00F00), [R, Imm] => [R(16), ThumbImm(Plain)];
+    // cmp rn, #imm, encoded as cmn with the negated immediate
+    T32(0xF1100F00), [R, Imm] => [R(16), ThumbImm(Neg)];
+    // cmp rn, rm {, shift #amount}
+    T32(0xEBB00F00), [R, R, End, Shift] => [R(16), R(0), ThumbShift];
+],
+"cmn" = [
+    // cmn rn, rm
+    T16(0x42C0), [R, R] => [R3(0), R3(3)];
+    // cmn rn, #imm
+    T32(0xF1100F00), [R, Imm] => [R(16), ThumbImm(Plain)];
+    // cmn rn, #imm, encoded as cmp with the negated immediate
+    T32(0xF1B00F00), [R, Imm] => [R(16), ThumbImm(Neg)];
+    // cmn rn, rm {, shift #amount}
+    T32(0xEB100F00), [R, R, End, Shift] => [R(16), R(0), ThumbShift];
+],
+"mov" = [
+    // mov rd, #imm8
+    T16(0x2000), [R, Imm] => [R3(8), UImm(8, 0), BitRange(0, 8, 0), Next], INSIDE_IT;
+    // mov rd, rm
+    T16(0x4600), [R, R] => [RSplit(0, 7), R(3)];
+    // mov rd, #imm
+    T32(0xF04F0000), [R, Imm] => [R(8), ThumbImm(Plain)];
+    // mov rd, #imm, encoded as mvn with the inverted immediate
+    T32(0xF06F0000), [R, Imm] => [R(8), ThumbImm(Inv)];
+    // mov rd, #imm16, encoded as movw
+    T32(0xF2400000), [R, Imm] => [R(8), UImm(16, 0), BitRange(0, 8, 0), BitRange(12, 3, 8), BitRange(26, 1, 11), BitRange(16, 4, 12), Next];
+    // mov rd, rm {, shift #amount}
+    T32(0xEA4F0000), [R, R, End, Shift] => [R(8), R(0), ThumbShift];
+],
+"movs" = [
+    // movs rd, #imm8
+    T16(0x2000), [R, Imm] => [R3(8), UImm(8, 0), BitRange(0, 8, 0), Next], OUTSIDE_IT;
+    // movs rd, rm
+    T16(0x0000), [R, R] => [R3(0), R3(3)], OUTSIDE_IT;
+    // movs rd, #imm
+    T32(0xF05F0000), [R, Imm] => [R(8), ThumbImm(Plain)];
+    // movs rd, #imm, encoded as mvns with the inverted immediate
+    T32(0xF07F0000), [R, Imm] => [R(8), ThumbImm(Inv)];
+    // movs rd, rm {, shift #amount}
+    T32(0xEA5F0000), [R, R, End, Shift] => [R(8), R(0), ThumbShift];
+],
+"mvn" = [
+    // mvn rd, rm
+    T16(0x43C0), [R, R] => [R3(0), R3(3)], INSIDE_IT;
+    // mvn rd, #imm
+    T32(0xF06F0000), [R, Imm] => [R(8), ThumbImm(Plain)];
+    // mvn rd, #imm, encoded as mov with the inverted immediate
+    T32(0xF04F0000), [R, Imm] => [R(8), ThumbImm(Inv)];
+    // mvn rd, rm {, shift #amount}
+    T32(0xEA6F0000), [R, R, End, Shift] => [R(8), R(0), ThumbShift];
+],
+"mvns" = [
+    // mvns rd, rm
+    T16(0x43C0), [R, R] => [R3(0), R3(3)], OUTSIDE_IT;
+    // mvns rd, #imm
+    T32(0xF07F0000), [R, Imm] => [R(8), ThumbImm(Plain)];
+    // mvns rd, #imm, encoded as movs with the inverted immediate
+    T32(0xF05F0000), [R, Imm] => [R(8), ThumbImm(Inv)];
+    // mvns rd, rm {, shift #amount}
+    T32(0xEA7F0000), [R, R, End, Shift] => [R(8), R(0), ThumbShift];
+],
+"neg" = [
+    // neg rd, rm
+    T16(0x4240), [R, R] => [R3(0), R3(3)], INSIDE_IT;
+    // neg rd, rm, encoded as rsb rd, rm, #0
+    T32(0xF1C00000), [R, R] => [R(8), R(16)];
+],
+"negs" = [
+    // negs rd, rm
+    T16(0x4240), [R, R] => [R3(0), R3(3)], OUTSIDE_IT;
+    // negs rd, rm, encoded as rsbs rd, rm, #0
+    T32(0xF1D00000), [R, R] => [R(8), R(16)];
+],
+"movw" = [
+    // movw rd, #imm16
+    T32(0xF2400000), [R, Imm] => [R(8), UImm(16, 0), BitRange(0, 8, 0), BitRange(12, 3, 8), BitRange(26, 1, 11), BitRange(16, 4, 12), Next];
+],
+"movt" = [
+    // movt rd, #imm16
+    T32(0xF2C00000), [R, Imm] => [R(8), UImm(16, 0), BitRange(0, 8, 0), BitRange(12, 3, 8), BitRange(26, 1, 11), BitRange(16, 4, 12), Next];
+],
+
+// Shifts
+
+"lsl" = [
+    // lsl rd, rm, #imm
+    T16(0x0000), [R, R, Imm] => [R3(0), R3(3), URange(0, 31), BitRange(6, 5, 0), Next], INSIDE_IT;
+    // lsl rdn, rdn, rm
+    T16(0x4080), [R, R, R] => [R3(0), Same(0), R3(3)], INSIDE_IT;
+    // lsl rdn, rm
+    T16(0x4080), [R, R] => [R3(0), R3(3)], INSIDE_IT;
+    // lsl rd, rm, #imm
+    T32(0xEA4F0000), [R, R, Imm] => [R(8), R(0), URange(0, 31), BitRange(6, 2, 0), BitRange(12, 3, 2), Next];
+    // lsl rd, rn, rm
+    T32(0xFA00F000), [R, R, R] => [R(8), R(16), R(0)];
+],
+"lsls" = [
+    // lsls rd, rm, #imm
+    T16(0x0000), [R, R, Imm] => [R3(0), R3(3), URange(0, 31), BitRange(6, 5, 0), Next], OUTSIDE_IT;
+    // lsls rdn, rdn, rm
+    T16(0x4080), [R, R, R] => [R3(0), Same(0), R3(3)], OUTSIDE_IT;
+    // lsls rdn, rm
+    T16(0x4080), [R, R] => [R3(0), R3(3)], OUTSIDE_IT;
+    // lsls rd, rm, #imm
+    T32(0xEA5F0000), [R, R, Imm] => [R(8), R(0), URange(0, 31), BitRange(6, 2, 0), BitRange(12, 3, 2), Next];
+    // lsls rd, rn, rm
+    T32(0xFA10F000), [R, R, R] => [R(8), R(16), R(0)];
+],
+"lsr" = [
+    // lsr rd, rm, #imm
+    T16(0x0800), [R, R, Imm] => [R3(0), R3(3), URange(1, 32), BitRange(6, 5, 0), Next], INSIDE_IT;
+    // lsr rdn, rdn, rm
+    T16(0x40C0), [R, R, R] => [R3(0), Same(0), R3(3)], INSIDE_IT;
+    // lsr rdn, rm
+    T16(0x40C0), [R, R] => [R3(0), R3(3)], INSIDE_IT;
+    // lsr rd, rm, #imm
+    T32(0xEA4F0010), [R, R, Imm] => [R(8), R(0), URange(1, 32), BitRange(6, 2, 0), BitRange(12, 3, 2), Next];
+    // lsr rd, rn, rm
+    T32(0xFA20F000), [R, R, R] => [R(8), R(16), R(0)];
+],
+"lsrs" = [
+    // lsrs rd, rm, #imm
+    T16(0x0800), [R, R, Imm] => [R3(0), R3(3), URange(1, 32), BitRange(6, 5, 0), Next], OUTSIDE_IT;
+    // lsrs rdn, rdn, rm
+    T16(0x40C0), [R, R, R] => [R3(0), Same(0), R3(3)], OUTSIDE_IT;
+    // lsrs rdn, rm
+    T16(0x40C0), [R, R] => [R3(0), R3(3)], OUTSIDE_IT;
+    // lsrs rd, rm, #imm
+    T32(0xEA5F0010), [R, R, Imm] => [R(8), R(0), URange(1, 32), BitRange(6, 2, 0), BitRange(12, 3, 2), Next];
+    // lsrs rd, rn, rm
+    T32(0xFA30F000), [R, R, R] => [R(8), R(16), R(0)];
+],
+"asr" = [
+    // asr rd, rm, #imm
+    T16(0x1000), [R, R, Imm] => [R3(0), R3(3), URange(1, 32), BitRange(6, 5, 0), Next], INSIDE_IT;
+    // asr rdn, rdn, rm
+    T16(0x4100), [R, R, R] => [R3(0), Same(0), R3(3)], INSIDE_IT;
+    // asr rdn, rm
+    T16(0x4100), [R, R] => [R3(0), R3(3)], INSIDE_IT;
+    // asr rd, rm, #imm
+    T32(0xEA4F0020), [R, R, Imm] => [R(8), R(0), URange(1, 32), BitRange(6, 2, 0), BitRange(12, 3, 2), Next];
+    // asr rd, rn, rm
+    T32(0xFA40F000), [R, R, R] => [R(8), R(16), R(0)];
+],
+"asrs" = [
+    // asrs rd, rm, #imm
+    T16(0x1000), [R, R, Imm] => [R3(0), R3(3), URange(1, 32), BitRange(6, 5, 0), Next], OUTSIDE_IT;
+    // asrs rdn, rdn, rm
+    T16(0x4100), [R, R, R] => [R3(0), Same(0), R3(3)], OUTSIDE_IT;
+    // asrs rdn, rm
+    T16(0x4100), [R, R] => [R3(0), R3(3)], OUTSIDE_IT;
+    // asrs rd, rm, #imm
+    T32(0xEA5F0020), [R, R, Imm] => [R(8), R(0), URange(1, 32), BitRange(6, 2, 0), BitRange(12, 3, 2), Next];
+    // asrs rd, rn, rm
+    T32(0xFA50F000), [R, R, R] => [R(8), R(16), R(0)];
+],
+"ror" = [
+    // ror rdn, rdn, rm
+    T16(0x41C0), [R, R, R] => [R3(0), Same(0), R3(3)], INSIDE_IT;
+    // ror rdn, rm
+    T16(0x41C0), [R, R] => [R3(0), R3(3)], INSIDE_IT;
+    // ror rd, rm, #imm
+    T32(0xEA4F0030), [R, R, Imm] => [R(8), R(0), URange(1, 31), BitRange(6, 2, 0), BitRange(12, 3, 2), Next];
+    // ror rd, rn, rm
+    T32(0xFA60F000), [R, R, R] => [R(8), R(16), R(0)];
+],
+"rors" = [
+    // rors rdn, rdn, rm
+    T16(0x41C0), [R, R, R] => [R3(0), Same(0), R3(3)], OUTSIDE_IT;
+    // rors rdn, rm
+    T16(0x41C0), [R, R] => [R3(0), R3(3)], OUTSIDE_IT;
+    // rors rd, rm, #imm
+    T32(0xEA5F0030), [R, R, Imm] => [R(8), R(0), URange(1, 31), BitRange(6, 2, 0), BitRange(12, 3, 2), Next];
+    // rors rd, rn, rm
+    T32(0xFA70F000), [R, R, R] => [R(8), R(16), R(0)];
+],
+"rrx" = [
+    // rrx rd, rm
+    T32(0xEA4F0030), [R, R] => [R(8), R(0)];
+],
+"rrxs" = [
+    // rrxs rd, rm
+    T32(0xEA5F0030), [R, R] => [R(8), R(0)];
+],
+
+// Multiplication and division
+
+"muls" = [
+    // muls rdm, rn, rdm
+    T16(0x4340), [R, R, R] => [R3(0), R3(3), Same(0)], OUTSIDE_IT;
+    // muls rdm, rn
+    T16(0x4340), [R, R] => [R3(0), R3(3)], OUTSIDE_IT;
+],
+"mul" = [
+    // mul rdm, rn, rdm
+    T16(0x4340), [R, R, R] => [R3(0), R3(3), Same(0)], INSIDE_IT;
+    // mul rd, rn, rm
+    T32(0xFB00F000), [R, R, R] => [R(8), R(16), R(0)];
+    // mul rdm, rn
+    T32(0xFB00F000), [R, R] => [R(8), Repeat, R(0), R(16)];
+],
+"mla" = [
+    // mla rd, rn, rm, ra
+    T32(0xFB000000), [R, R, R, R] => [R(8), R(16), R(0), R(12)];
+],
+"mls" = [
+    // mls rd, rn, rm, ra
+    T32(0xFB000010), [R, R, R, R] => [R(8), R(16), R(0), R(12)];
+],
+"umull" = [
+    // umull rdlo, rdhi, rn, rm
+    T32(0xFBA00000), [R, R, R, R] => [R(12), R(8), R(16), R(0)];
+],
+"umlal" = [
+    // umlal rdlo, rdhi, rn, rm
+    T32(0xFBE00000), [R, R, R, R] => [R(12), R(8), R(16), R(0)];
+],
+"smull" = [
+    // smull rdlo, rdhi, rn, rm
+    T32(0xFB800000), [R, R, R, R] => [R(12), R(8), R(16), R(0)];
+],
+"smlal" = [
+    // smlal rdlo, rdhi, rn, rm
+    T32(0xFBC00000), [R, R, R, R] => [R(12), R(8), R(16), R(0)];
+],
+"sdiv" = [
+    // sdiv rd, rn, rm
+    T32(0xFB90F0F0), [R, R, R] => [R(8), R(16), R(0)];
+],
+"udiv" = [
+    // udiv rd, rn, rm
+    T32(0xFBB0F0F0), [R, R, R] => [R(8), R(16), R(0)];
+],
+
+// Bit manipulation
+
+"clz" = [
+    // clz rd, rm
+    T32(0xFAB0F080), [R, R] => [R(8), R(16), Repeat, R(0)];
+],
+"rev" = [
+    // rev rd, rm
+    T16(0xBA00), [R, R] => [R3(0), R3(3)];
+    // rev rd, rm
+    T32(0xFA90F080), [R, R] => [R(8), R(16), Repeat, R(0)];
+],
+"rev16" = [
+    // rev16 rd, rm
+    T16(0xBA40), [R, R] => [R3(0), R3(3)];
+    // rev16 rd, rm
+    T32(0xFA90F090), [R, R] => [R(8), R(16), Repeat, R(0)];
+],
+"revsh" = [
+    // revsh rd, rm
+    T16(0xBAC0), [R, R] => [R3(0), R3(3)];
+    // revsh rd, rm
+    T32(0xFA90F0B0), [R, R] => [R(8), R(16), Repeat, R(0)];
+],
+"rbit" = [
+    // rbit rd, rm
+    T32(0xFA90F0A0), [R, R] => [R(8), R(16), Repeat, R(0)];
+],
+"sxth" = [
+    // sxth rd, rm
+    T16(0xB200), [R, R] => [R3(0), R3(3)];
+    // sxth rd, rm {, ror #rotation}
+    T32(0xFA0FF080), [R, R, End, Ror] => [R(8), R(0), Rotation(4)];
+],
+"sxtb" = [
+    // sxtb rd, rm
+    T16(0xB240), [R, R] => [R3(0), R3(3)];
+    // sxtb rd, rm {, ror #rotation}
+    T32(0xFA4FF080), [R, R, End, Ror] => [R(8), R(0), Rotation(4)];
+],
+"uxth" = [
+    // uxth rd, rm
+    T16(0xB280), [R, R] => [R3(0), R3(3)];
+    // uxth rd, rm {, ror #rotation}
+    T32(0xFA1FF080), [R, R, End, Ror] => [R(8), R(0), Rotation(4)];
+],
+"uxtb" = [
+    // uxtb rd, rm
+    T16(0xB2C0), [R, R] => [R3(0), R3(3)];
+    // uxtb rd, rm {, ror #rotation}
+    T32(0xFA5FF080), [R, R, End, Ror] => [R(8), R(0), Rotation(4)];
+],
+"sxtah" = [
+    // sxtah rd, rn, rm {, ror #rotation}
+    T32(0xFA00F080), [R, R, R, End, Ror] => [R(8), R(16), R(0), Rotation(4)];
+],
+"sxtab" = [
+    // sxtab rd, rn, rm {, ror #rotation}
+    T32(0xFA40F080), [R, R, R, End, Ror] => [R(8), R(16), R(0), Rotation(4)];
+],
+"uxtah" = [
+    // uxtah rd, rn, rm {, ror #rotation}
+    T32(0xFA10F080), [R, R, R, End, Ror] => [R(8), R(16), R(0), Rotation(4)];
+],
+"uxtab" = [
+    // uxtab rd, rn, rm {, ror #rotation}
+    T32(0xFA50F080), [R, R, R, End, Ror] => [R(8), R(16), R(0), Rotation(4)];
+],
+"bfc" = [
+    // bfc rd, #lsb, #width
+    T32(0xF36F0000), [R, Imm, Imm] => [R(8), URange(0, 31), BitRange(6, 2, 0), BitRange(12, 3, 2), Next, Msb(0)];
+],
+"bfi" = [
+    // bfi rd, rn, #lsb, #width
+    T32(0xF3600000), [R, R, Imm, Imm] => [R(8), R(16), URange(0, 31), BitRange(6, 2, 0), BitRange(12, 3, 2), Next, Msb(0)];
+],
+"sbfx" = [
+    // sbfx rd, rn, #lsb, #width
+    T32(0xF3400000), [R, R, Imm, Imm] => [R(8), R(16), URange(0, 31), BitRange(6, 2, 0), BitRange(12, 3, 2), Next, Width(0)];
+],
+"ubfx" = [
+    // ubfx rd, rn, #lsb, #width
+    T32(0xF3C00000), [R, R, Imm, Imm] => [R(8), R(16), URange(0, 31), BitRange(6, 2, 0), BitRange(12, 3, 2), Next, Width(0)];
+],
+
+// Loads and stores
+
+"ldr" = [
+    // ldr rt, [rn {, #imm5 * 4}]
+    T16(0x6800), [R, RefOffset] => [R3(0), R3(3), UImm(7, 2), BitRange(6, 5, 2), Next];
+    // ldr rt, [sp {, #imm8 * 4}]
+    T16(0x9800), [R, RefOffset] => [R3(8), RFixed(13), UImm(10, 2), BitRange(0, 8, 2), Next];
+    // ldr rt, [rn {, #offset}]
+    T32(0xF8500000), [R, RefOffset] => [R(12), R(16), ThumbOff12];
+    // ldr rt, [rn, #offset]!
+    T32(0xF8500D00), [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // ldr rt, [rn], #offset
+    T32(0xF8500900), [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // ldr rt, [rn, rm]
+    T16(0x5800), [R, RefIndex] => [R3(0), R3(3), R3(6), IndexShift(0, 0)];
+    // ldr rt, [rn, rm {, lsl #amount}]
+    T32(0xF8500000), [R, RefIndex] => [R(12), R(16), RIndex(0, 0), IndexShift(4, 3)];
+    // ldr rt, label
+    T16(0x4800), [R, Offset] => [R3(8), Offset(TLDR8)], EXPLICIT_N;
+    // ldr rt, label
+    T32(0xF85F0000), [R, Offset] => [R(12), Offset(TLDR12)];
+],
+"str" = [
+    // str rt, [rn {, #imm5 * 4}]
+    T16(0x6000), [R, RefOffset] => [R3(0), R3(3), UImm(7, 2), BitRange(6, 5, 2), Next];
+    // str rt, [sp {, #imm8 * 4}]
+    T16(0x9000), [R, RefOffset] => [R3(8), RFixed(13), UImm(10, 2), BitRange(0, 8, 2), Next];
+    // str rt, [rn {, #offset}]
+    T32(0xF8400000), [R, RefOffset] => [R(12), R(16), ThumbOff12];
+    // str rt, [rn, #offset]!
+    T32(0xF8400D00), [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // str rt, [rn], #offset
+    T32(0xF8400900), [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // str rt, [rn, rm]
+    T16(0x5000), [R, RefIndex] => [R3(0), R3(3), R3(6), IndexShift(0, 0)];
+    // str rt, [rn, rm {, lsl #amount}]
+    T32(0xF8400000), [R, RefIndex] => [R(12), R(16), RIndex(0, 0), IndexShift(4, 3)];
+],
+"ldrb" = [
+    // ldrb rt, [rn {, #imm5}]
+    T16(0x7800), [R, RefOffset] => [R3(0), R3(3), UImm(5, 0), BitRange(6, 5, 0), Next];
+    // ldrb rt, [rn {, #offset}]
+    T32(0xF8100000), [R, RefOffset] => [R(12), R(16), ThumbOff12];
+    // ldrb rt, [rn, #offset]!
+    T32(0xF8100D00), [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // ldrb rt, [rn], #offset
+    T32(0xF8100900), [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // ldrb rt, [rn, rm]
+    T16(0x5C00), [R, RefIndex] => [R3(0), R3(3), R3(6), IndexShift(0, 0)];
+    // ldrb rt, [rn, rm {, lsl #amount}]
+    T32(0xF8100000), [R, RefIndex] => [R(12), R(16), RIndex(0, 0), IndexShift(4, 3)];
+    // ldrb rt, label
+    T32(0xF81F0000), [R, Offset] => [R(12), Offset(TLDR12)];
+],
+"strb" = [
+    // strb rt, [rn {, #imm5}]
+    T16(0x7000), [R, RefOffset] => [R3(0), R3(3), UImm(5, 0), BitRange(6, 5, 0), Next];
+    // strb rt, [rn {, #offset}]
+    T32(0xF8000000), [R, RefOffset] => [R(12), R(16), ThumbOff12];
+    // strb rt, [rn, #offset]!
+    T32(0xF8000D00), [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // strb rt, [rn], #offset
+    T32(0xF8000900), [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // strb rt, [rn, rm]
+    T16(0x5400), [R, RefIndex] => [R3(0), R3(3), R3(6), IndexShift(0, 0)];
+    // strb rt, [rn, rm {, lsl #amount}]
+    T32(0xF8000000), [R, RefIndex] => [R(12), R(16), RIndex(0, 0), IndexShift(4, 3)];
+],
+"ldrh" = [
+    // ldrh rt, [rn {, #imm5 * 2}]
+    T16(0x8800), [R, RefOffset] => [R3(0), R3(3), UImm(6, 1), BitRange(6, 5, 1), Next];
+    // ldrh rt, [rn {, #offset}]
+    T32(0xF8300000), [R, RefOffset] => [R(12), R(16), ThumbOff12];
+    // ldrh rt, [rn, #offset]!
+    T32(0xF8300D00), [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // ldrh rt, [rn], #offset
+    T32(0xF8300900), [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // ldrh rt, [rn, rm]
+    T16(0x5A00), [R, RefIndex] => [R3(0), R3(3), R3(6), IndexShift(0, 0)];
+    // ldrh rt, [rn, rm {, lsl #amount}]
+    T32(0xF8300000), [R, RefIndex] => [R(12), R(16), RIndex(0, 0), IndexShift(4, 3)];
+    // ldrh rt, label
+    T32(0xF83F0000), [R, Offset] => [R(12), Offset(TLDR12)];
+],
+"strh" = [
+    // strh rt, [rn {, #imm5 * 2}]
+    T16(0x8000), [R, RefOffset] => [R3(0), R3(3), UImm(6, 1), BitRange(6, 5, 1), Next];
+    // strh rt, [rn {, #offset}]
+    T32(0xF8200000), [R, RefOffset] => [R(12), R(16), ThumbOff12];
+    // strh rt, [rn, #offset]!
+    T32(0xF8200D00), [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // strh rt, [rn], #offset
+    T32(0xF8200900), [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // strh rt, [rn, rm]
+    T16(0x5200), [R, RefIndex] => [R3(0), R3(3), R3(6), IndexShift(0, 0)];
+    // strh rt, [rn, rm {, lsl #amount}]
+    T32(0xF8200000), [R, RefIndex] => [R(12), R(16), RIndex(0, 0), IndexShift(4, 3)];
+],
+"ldrsb" = [
+    // ldrsb rt, [rn {, #offset}]
+    T32(0xF9100000), [R, RefOffset] => [R(12), R(16), ThumbOff12];
+    // ldrsb rt, [rn, #offset]!
+    T32(0xF9100D00), [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // ldrsb rt, [rn], #offset
+    T32(0xF9100900), [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // ldrsb rt, [rn, rm]
+    T16(0x5600), [R, RefIndex] => [R3(0), R3(3), R3(6), IndexShift(0, 0)];
+    // ldrsb rt, [rn, rm {, lsl #amount}]
+    T32(0xF9100000), [R, RefIndex] => [R(12), R(16), RIndex(0, 0), IndexShift(4, 3)];
+    // ldrsb rt, label
+    T32(0xF91F0000), [R, Offset] => [R(12), Offset(TLDR12)];
+],
+"ldrsh" = [
+    // ldrsh rt, [rn {, #offset}]
+    T32(0xF9300000), [R, RefOffset] => [R(12), R(16), ThumbOff12];
+    // ldrsh rt, [rn, #offset]!
+    T32(0xF9300D00), [R, RefPre] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // ldrsh rt, [rn], #offset
+    T32(0xF9300900), [R, RefBase, Imm] => [R(12), R(16), SignMag(9, 8, 0), BitRange(0, 8, 0), Next];
+    // ldrsh rt, [rn, rm]
+    T16(0x5E00), [R, RefIndex] => [R3(0), R3(3), R3(6), IndexShift(0, 0)];
+    // ldrsh rt, [rn, rm {, lsl #amount}]
+    T32(0xF9300000), [R, RefIndex] => [R(12), R(16), RIndex(0, 0), IndexShift(4, 3)];
+    // ldrsh rt, label
+    T32(0xF93F0000), [R, Offset] => [R(12), Offset(TLDR12)];
+],
+"ldrd" = [
+    // ldrd rt, rt2, [rn {, #offset}]
+    T32(0xE9500000), [R, R, RefOffset] => [R(12), R(8), R(16), SignMag(23, 10, 2), BitRange(0, 8, 2), Next];
+    // ldrd rt, rt2, [rn, #offset]!
+    T32(0xE9700000), [R, R, RefPre] => [R(12), R(8), R(16), SignMag(23, 10, 2), BitRange(0, 8, 2), Next];
+    // ldrd rt, rt2, [rn], #offset
+    T32(0xE8700000), [R, R, RefBase, Imm] => [R(12), R(8), R(16), SignMag(23, 10, 2), BitRange(0, 8, 2), Next];
+],
+"strd" = [
+    // strd rt, rt2, [rn {, #offset}]
+    T32(0xE9400000), [R, R, RefOffset] => [R(12), R(8), R(16), SignMag(23, 10, 2), BitRange(0, 8, 2), Next];
+    // strd rt, rt2, [rn, #offset]!
+    T32(0xE9600000), [R, R, RefPre] => [R(12), R(8), R(16), SignMag(23, 10, 2), BitRange(0, 8, 2), Next];
+    // strd rt, rt2, [rn], #offset
+    T32(0xE8600000), [R, R, RefBase, Imm] => [R(12), R(8), R(16), SignMag(23, 10, 2), BitRange(0, 8, 2), Next];
+],
+"ldrex" = [
+    // ldrex rt, [rn {, #offset}]
+    T32(0xE8500F00), [R, RefOffset] => [R(12), R(16), UImm(10, 2), BitRange(0, 8, 2), Next];
+],
+"ldrexb" = [
+    // ldrexb rt, [rn]
+    T32(0xE8D00F4F), [R, RefBase] => [R(12), R(16)];
+],
+"ldrexh" = [
+    // ldrexh rt, [rn]
+    T32(0xE8D00F5F), [R, RefBase] => [R(12), R(16)];
+],
+"strex" = [
+    // strex rd, rt, [rn {, #offset}]
+    T32(0xE8400000), [R, R, RefOffset] => [R(8), R(12), R(16), UImm(10, 2), BitRange(0, 8, 2), Next];
+],
+"strexb" = [
+    // strexb rd, rt, [rn]
+    T32(0xE8C00F40), [R, R, RefBase] => [R(0), R(12), R(16)];
+],
+"strexh" = [
+    // strexh rd, rt, [rn]
+    T32(0xE8C00F50), [R, R, RefBase] => [R(0), R(12), R(16)];
+],
+"clrex" = [
+    // clrex
+    T32(0xF3BF8F2F), [] => [];
+],
+
+// Load and store multiple
+
+"ldm" = [
+    // ldm rn!, {reglist}
+    T16(0xC800), [Writeback, RegList] => [R3(8), RListLo(0)];
+    // ldm rn, {reglist}
+    T32(0xE8900000), [R, RegList] => [R(16), RList(0)];
+    // ldm rn!, {reglist}
+    T32(0xE8B00000), [Writeback, RegList] => [R(16), RList(0)];
+],
+"ldmia" = [
+    // ldmia rn!, {reglist}
+    T16(0xC800), [Writeback, RegList] => [R3(8), RListLo(0)];
+    // ldmia rn, {reglist}
+    T32(0xE8900000), [R, RegList] => [R(16), RList(0)];
+    // ldmia rn!, {reglist}
+    T32(0xE8B00000), [Writeback, RegList] => [R(16), RList(0)];
+],
+"ldmfd" = [
+    // ldmfd rn!, {reglist}
+    T16(0xC800), [Writeback, RegList] => [R3(8), RListLo(0)];
+    // ldmfd rn, {reglist}
+    T32(0xE8900000), [R, RegList] => [R(16), RList(0)];
+    // ldmfd rn!, {reglist}
+    T32(0xE8B00000), [Writeback, RegList] => [R(16), RList(0)];
+],
+"stm" = [
+    // stm rn!, {reglist}
+    T16(0xC000), [Writeback, RegList] => [R3(8), RListLo(0)];
+    // stm rn, {reglist}
+    T32(0xE8800000), [R, RegList] => [R(16), RList(0)];
+    // stm rn!, {reglist}
+    T32(0xE8A00000), [Writeback, RegList] => [R(16), RList(0)];
+],
+"stmia" = [
+    // stmia rn!, {reglist}
+    T16(0xC000), [Writeback, RegList] => [R3(8), RListLo(0)];
+    // stmia rn, {reglist}
+    T32(0xE8800000), [R, RegList] => [R(16), RList(0)];
+    // stmia rn!, {reglist}
+    T32(0xE8A00000), [Writeback, RegList] => [R(16), RList(0)];
+],
+"stmea" = [
+    // stmea rn!, {reglist}
+    T16(0xC000), [Writeback, RegList] => [R3(8), RListLo(0)];
+    // stmea rn, {reglist}
+    T32(0xE8800000), [R, RegList] => [R(16), RList(0)];
+    // stmea rn!, {reglist}
+    T32(0xE8A00000), [Writeback, RegList] => [R(16), RList(0)];
+],
+"ldmdb" = [
+    // ldmdb rn, {reglist}
+    T32(0xE9100000), [R, RegList] => [R(16), RList(0)];
+    // ldmdb rn!, {reglist}
+    T32(0xE9300000), [Writeback, RegList] => [R(16), RList(0)];
+],
+"ldmea" = [
+    // ldmea rn, {reglist}
+    T32(0xE9100000), [R, RegList] => [R(16), RList(0)];
+    // ldmea rn!, {reglist}
+    T32(0xE9300000), [Writeback, RegList] => [R(16), RList(0)];
+],
+"stmdb" = [
+    // stmdb rn, {reglist}
+    T32(0xE9000000), [R, RegList] => [R(16), RList(0)];
+    // stmdb rn!, {reglist}
+    T32(0xE9200000), [Writeback, RegList] => [R(16), RList(0)];
+],
+"stmfd" = [
+    // stmfd rn, {reglist}
+    T32(0xE9000000), [R, RegList] => [R(16), RList(0)];
+    // stmfd rn!, {reglist}
+    T32(0xE9200000), [Writeback, RegList] => [R(16), RList(0)];
+],
+"push" = [
+    // push {reglist}
+    T16(0xB400), [RegList] => [RListLo(14)];
+    // push {rt}, encoded as str rt, [sp, #-4]!
+    T32(0xF84D0D04), [RegList] => [RListSingle(12)];
+    // push {reglist}
+    T32(0xE92D0000), [RegList] => [RList(0)];
+],
+"pop" = [
+    // pop {reglist}
+    T16(0xBC00), [RegList] => [RListLo(15)];
+    // pop {rt}, encoded as ldr rt, [sp], #4
+    T32(0xF85D0B04), [RegList] => [RListSingle(12)];
+    // pop {reglist}
+    T32(0xE8BD0000), [RegList] => [RList(0)];
+],
+
+// Branches
+
+"b" = [
+    // b<cond> label
+    T16(0xD000), [Offset] => [Offset(TB8)], COND, EXPLICIT_N;
+    // b<cond> label
+    T32(0xF0008000), [Offset] => [Offset(TB20)], COND;
+    // b label
+    T16(0xE000), [Offset] => [Offset(TB11)], LAST_IN_IT, EXPLICIT_N;
+    // b label
+    T32(0xF0009000), [Offset] => [Offset(TB24)], LAST_IN_IT;
+],
+"bl" = [
+    // bl label
+    T32(0xF000D000), [Offset] => [Offset(TB24)], LAST_IN_IT;
+],
+"bx" = [
+    // bx rm
+    T16(0x4700), [R] => [R(3)], LAST_IN_IT;
+],
+"blx" = [
+    // blx rm
+    T16(0x4780), [R] => [R(3)], LAST_IN_IT;
+],
+"cbz" = [
+    // cbz rn, label
+    T16(0xB100), [R, Offset] => [R3(0), Offset(TCB6)], NO_IT;
+],
+"cbnz" = [
+    // cbnz rn, label
+    T16(0xB900), [R, Offset] => [R3(0), Offset(TCB6)], NO_IT;
+],
+"tbb" = [
+    // tbb [rn, rm]
+    T32(0xE8D0F000), [RefIndex] => [R(16), RIndex(0, 0), IndexShift(4, 0)], LAST_IN_IT;
+],
+"tbh" = [
+    // tbh [rn, rm, lsl #1]
+    T32(0xE8D0F010), [RefIndex] => [R(16), RIndex(0, 0), LslExact(1)], LAST_IN_IT;
+],
+"adr" = [
+    // adr rd, label
+    T16(0xA000), [R, Offset] => [R3(8), Offset(TLDR8)], EXPLICIT_N;
+    // adr rd, label
+    T32(0xF20F0000), [R, Offset] => [R(8), Offset(TADR12)];
+],
+
+// Miscellaneous
+
+"nop" = [
+    // nop
+    T16(0xBF00), [] => [];
+    // nop
+    T32(0xF3AF8000), [] => [];
+],
+"yield" = [
+    // yield
+    T16(0xBF10), [] => [];
+    // yield
+    T32(0xF3AF8001), [] => [];
+],
+"wfe" = [
+    // wfe
+    T16(0xBF20), [] => [];
+    // wfe
+    T32(0xF3AF8002), [] => [];
+],
+"wfi" = [
+    // wfi
+    T16(0xBF30), [] => [];
+    // wfi
+    T32(0xF3AF8003), [] => [];
+],
+"sev" = [
+    // sev
+    T16(0xBF40), [] => [];
+    // sev
+    T32(0xF3AF8004), [] => [];
+],
+"svc" = [
+    // svc #imm8
+    T16(0xDF00), [Imm] => [UImm(8, 0), BitRange(0, 8, 0), Next];
+],
+"bkpt" = [
+    // bkpt {#imm8}
+    T16(0xBE00), [End, Imm] => [UImm(8, 0), BitRange(0, 8, 0), Next];
+],
+"udf" = [
+    // udf #imm8
+    T16(0xDE00), [Imm] => [UImm(8, 0), BitRange(0, 8, 0), Next];
+    // udf #imm16
+    T32(0xF7F0A000), [Imm] => [UImm(16, 0), BitRange(0, 12, 0), BitRange(16, 4, 12), Next];
+],
+"cpsid" = [
+    // cpsid i
+    T16(0xB672), [Lit("i")] => [], NO_IT;
+    // cpsid f
+    T16(0xB671), [Lit("f")] => [], NO_IT;
+],
+"cpsie" = [
+    // cpsie i
+    T16(0xB662), [Lit("i")] => [], NO_IT;
+    // cpsie f
+    T16(0xB661), [Lit("f")] => [], NO_IT;
+],
+"dmb" = [
+    // dmb
+    T32(0xF3BF8F5F), [] => [];
+    // dmb option
+    T32(0xF3BF8F50), [Ident] => [LitList(0, "BARRIER_OPS")];
+],
+"dsb" = [
+    // dsb
+    T32(0xF3BF8F4F), [] => [];
+    // dsb option
+    T32(0xF3BF8F40), [Ident] => [LitList(0, "BARRIER_OPS")];
+],
+"isb" = [
+    // isb
+    T32(0xF3BF8F6F), [] => [];
+    // isb option
+    T32(0xF3BF8F60), [Ident] => [LitList(0, "ISB_OPS")];
+],
+"mrs" = [
+    // mrs rd, spec_reg
+    T32(0xF3EF8000), [R, Ident] => [R(8), LitList(0, "SPECIAL_REGS")];
+],
+"msr" = [
+    // msr spec_reg, rn
+    T32(0xF3808800), [Ident, R] => [LitList(0, "SPECIAL_REGS"), R(16)];
+],
+)
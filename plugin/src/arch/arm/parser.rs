@@ -0,0 +1,429 @@
+use std::collections::HashMap;
+
+use syn::{parse, Token};
+use syn::spanned::Spanned;
+use proc_macro2::Span;
+
+use lazy_static::lazy_static;
+
+use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOptExt};
+use crate::serialize;
+
+use super::{Context, ast};
+
+// syntax for a single op: ident ("." ident)? (arg ("," arg)*)? ";"
+pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<ast::ParsedInstruction> {
+    let span = input.cursor().span();
+
+    let name = parse_ident_or_rust_keyword(input)?.to_string();
+
+    // parse an optional width qualifier
+    let width = if input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        let qualifier = parse_ident_or_rust_keyword(input)?;
+
+        match &*qualifier.to_string() {
+            "n" => ast::Width::Narrow,
+            "w" => ast::Width::Wide,
+            _ => return Err(parse::Error::new(qualifier.span(), "Unknown instruction width qualifier, expected 'n' or 'w'"))
+        }
+    } else {
+        ast::Width::Any
+    };
+
+    let mut args = Vec::new();
+
+    // parse 0 or more comma-separated args
+    if !(input.is_empty() || input.peek(Token![;])) {
+        args.push(parse_arg(ctx, input)?);
+
+        while input.peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+
+            args.push(parse_arg(ctx, input)?);
+        }
+    }
+
+    // let span = span.join(input.cursor().span()); // FIXME can't join spans ATM
+
+    Ok(ast::ParsedInstruction {
+        name,
+        width,
+        span,
+        args
+    })
+}
+
+
+/// tries to parse a full arg definition
+fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<ast::RawArg> {
+    let start = input.cursor().span(); // FIXME can't join spans yet
+
+    // a label, identified by a leading < / > / -> / =>
+    if let Some(jump) = input.parse_opt()? {
+        return Ok(ast::RawArg::JumpTarget {
+            jump
+        });
+    }
+
+    // a memory reference
+    if input.peek(syn::token::Bracket) {
+        let span = input.cursor().span();
+        let inner;
+        let _ = syn::bracketed!(inner in input);
+        let inner = &inner;
+
+        let base = parse_reg(ctx, inner)?.ok_or_else(|| inner.error("Expected register"))?;
+
+        let offset = if inner.peek(Token![,]) {
+            let _: Token![,] = inner.parse()?;
+
+            if let Some((reg, subtract)) = parse_index(ctx, inner)? {
+                let shift = if inner.peek(Token![,]) {
+                    let _: Token![,] = inner.parse()?;
+
+                    Some(parse_shift(ctx, inner)?.ok_or_else(|| inner.error("Expected shift"))?)
+                } else {
+                    None
+                };
+
+                ast::RefOffset::Index {
+                    reg,
+                    subtract,
+                    shift
+                }
+            } else {
+                ast::RefOffset::Immediate(parse_immediate(inner)?)
+            }
+        } else {
+            ast::RefOffset::None
+        };
+
+        if !inner.is_empty() {
+            return Err(inner.error("Unexpected tokens in memory reference"));
+        }
+
+        let bang = if input.peek(Token![!]) {
+            let _: Token![!] = input.parse()?;
+            true
+        } else {
+            false
+        };
+
+        return Ok(ast::RawArg::Reference {
+            span,
+            base,
+            offset,
+            bang
+        });
+    }
+
+    // a register list
+    if input.peek(syn::token::Brace) {
+        let span = input.cursor().span();
+        let inner;
+        let _ = syn::braced!(inner in input);
+        let inner = &inner;
+
+        let mut regs = Vec::new();
+
+        loop {
+            let first = parse_reg(ctx, inner)?.ok_or_else(|| inner.error("Expected register"))?;
+
+            // parses reg - reg
+            if inner.peek(Token![-]) {
+                let _: Token![-] = inner.parse()?;
+                let last = parse_reg(ctx, inner)?.ok_or_else(|| inner.error("Expected register"))?;
+
+                match (first, last) {
+                    (ast::Register::Static(first), ast::Register::Static(last)) if first.code() <= last.code() => {
+                        regs.extend((first.code() ..= last.code()).map(|code| ast::Register::Static(REGISTER_CODES[usize::from(code)])));
+                    },
+                    (ast::Register::Static(_), ast::Register::Static(_)) => return Err(parse::Error::new(span, "Invalid register range")),
+                    _ => return Err(parse::Error::new(span, "Register ranges cannot contain dynamic registers")),
+                }
+            } else {
+                regs.push(first);
+            }
+
+            if !inner.peek(Token![,]) {
+                break;
+            }
+            let _: Token![,] = inner.parse()?;
+        }
+
+        if !inner.is_empty() {
+            return Err(inner.error("Unexpected tokens in register list"));
+        }
+
+        return Ok(ast::RawArg::RegList {
+            span,
+            regs
+        });
+    }
+
+    // a shift
+    if let Some(shift) = parse_shift(ctx, input)? {
+        return Ok(ast::RawArg::Shift {
+            shift
+        });
+    }
+
+    // immediate (arm notation)
+    if input.peek(Token![#]) {
+        return Ok(ast::RawArg::Immediate {
+            value: parse_immediate(input)?
+        });
+    }
+
+    // a subtracted register
+    if input.peek(Token![-]) {
+        if let Some((reg, _)) = parse_index(ctx, input)? {
+            return Ok(ast::RawArg::NegRegister {
+                reg,
+                span: start
+            });
+        }
+    }
+
+    // a register, a register with writeback or a type mapped reference (reg => Type[index].attr)
+    if let Some(reg) = parse_reg(ctx, input)? {
+        if input.peek(Token![!]) {
+            let _: Token![!] = input.parse()?;
+            return Ok(ast::RawArg::Writeback {
+                reg,
+                span: start
+            });
+        }
+
+        if input.peek(Token![=>]) {
+            return parse_typemap(ctx, input, reg, start);
+        }
+
+        return Ok(ast::RawArg::Register {
+            reg,
+            span: start
+        })
+    }
+
+    // immediate (relaxed notation)
+    let expr: syn::Expr = input.parse()?;
+
+    Ok(ast::RawArg::Immediate { value: expr })
+}
+
+/// Parses an immediate, with optional leading #
+fn parse_immediate(input: parse::ParseStream) -> parse::Result<syn::Expr> {
+    if input.peek(Token![#]) {
+        let _: Token![#] = input.parse()?;
+    }
+
+    input.parse()
+}
+
+/// Parses an optionally signed index register, `+rm` or `-rm`. Returns if the register is subtracted.
+/// Does not consume anything if no register follows the sign.
+fn parse_index(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<(ast::Register, bool)>> {
+    let subtract = input.peek(Token![-]);
+
+    if subtract || input.peek(Token![+]) {
+        // check if a register follows, otherwise this is just a signed immediate
+        let fork = input.fork();
+        let _: proc_macro2::Punct = fork.parse()?;
+        if parse_reg(ctx, &fork)?.is_none() {
+            return Ok(None);
+        }
+
+        let _: proc_macro2::Punct = input.parse()?;
+    }
+
+    Ok(parse_reg(ctx, input)?.map(|reg| (reg, subtract)))
+}
+
+/// Parses a shift, `lsl #imm`, `lsl rs` or `rrx`
+fn parse_shift(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<ast::ShiftExpr>> {
+    let span = input.cursor().span();
+
+    let op = input.step(|cursor| {
+        if let Some((ident, rest)) = cursor.ident() {
+            let op = match &*ident.to_string() {
+                "LSL" | "lsl" => ast::ShiftOp::LSL,
+                "LSR" | "lsr" => ast::ShiftOp::LSR,
+                "ASR" | "asr" => ast::ShiftOp::ASR,
+                "ROR" | "ror" => ast::ShiftOp::ROR,
+                "RRX" | "rrx" => ast::ShiftOp::RRX,
+                _ => return Err(cursor.error("expected shift"))
+            };
+            return Ok((op, rest));
+        }
+        Err(cursor.error("expected shift"))
+    });
+
+    let op = match op {
+        Ok(op) => op,
+        Err(_) => return Ok(None)
+    };
+
+    let amount = if op == ast::ShiftOp::RRX {
+        ast::ShiftAmount::None
+    } else if let Some(reg) = parse_reg(ctx, input)? {
+        ast::ShiftAmount::Register(reg)
+    } else {
+        ast::ShiftAmount::Immediate(parse_immediate(input)?)
+    };
+
+    Ok(Some(ast::ShiftExpr {
+        span,
+        op,
+        amount
+    }))
+}
+
+/// Parses the remainder of a type mapped reference, `base => Type ("[" index "]")? ("." attr)?`
+/// The index has to be an immediate. It is combined with the offset of the attribute into the
+/// reference offset.
+fn parse_typemap(ctx: &mut Context, input: parse::ParseStream, base: ast::Register, span: Span) -> parse::Result<ast::RawArg> {
+    let _: Token![=>] = input.parse()?;
+
+    let ty: syn::Path = input.parse()?;
+
+    let index = if input.peek(syn::token::Bracket) {
+        let inner;
+        let _ = syn::bracketed!(inner in input);
+        let inner = &inner;
+
+        if parse_reg(ctx, &inner.fork())?.is_some() {
+            return Err(inner.error("ARM type mapped references cannot use an index register"));
+        }
+
+        Some(inner.parse::<syn::Expr>()?)
+    } else {
+        None
+    };
+
+    let attr: Option<syn::Ident> = if input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        Some(input.parse()?)
+    } else {
+        None
+    };
+
+    let offset = match serialize::expr_typemap_disp(span, &ty, index.as_ref(), attr.as_ref()) {
+        Some(disp) => ast::RefOffset::Immediate(serialize::reparse(&disp)?),
+        None => ast::RefOffset::None
+    };
+
+    Ok(ast::RawArg::Reference {
+        span,
+        base,
+        offset,
+        bang: false
+    })
+}
+
+/// Parses a single register, if present
+/// This can be a simple register name (like `r5`)
+/// an alias (any simple name that is registered, like `base`)
+/// or a dynamic register (like `R(expr)`)
+fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<ast::Register>> {
+    // we need to consume an ident, but only if it's one of the many we care about
+    // so use a step parser to figure it out.
+    let name = input.step(|cursor| {
+        if let Some((ident, rest)) = cursor.ident() {
+            let mut ident = ident.to_string();
+
+            // first, parse the dynamic register family
+            if ident == "R" {
+                return Ok((ident, rest));
+            }
+
+            // otherwise, see if this is an alias
+            if let Some(repl) = ctx.state.invocation_context.aliases.get(&ident) {
+                ident = repl.clone();
+            }
+
+            // resolve normal register references
+            if ARM_REGISTERS.contains_key(&*ident) {
+                return Ok((ident, rest));
+            }
+        }
+        Err(cursor.error("expected identifier"))
+    });
+
+    let name = match name {
+        Ok(name) => name,
+        Err(_) => return Ok(None)
+    };
+
+    // we know we have a register reference now, try to resolve it.
+    let register = if let Some(&id) = ARM_REGISTERS.get(&*name) {
+        ast::Register::Static(id)
+    } else {
+        // need to parse the trailing `( expr )`
+        let inner;
+        let _ = syn::parenthesized!(inner in input);
+        let inner = &inner;
+
+        let expr: syn::Expr = inner.parse()?;
+        if !inner.is_empty() {
+            return Err(parse::Error::new(expr.span(), "Too many arguments in register family expression"));
+        }
+
+        ast::Register::Dynamic(expr)
+    };
+
+    Ok(Some(register))
+}
+
+
+/// Register ids in order of their encoding
+static REGISTER_CODES: [ast::RegId; 16] = {
+    use ast::RegId::*;
+    [R0, R1, R2, R3, R4, R5, R6, R7, R8, R9, R10, R11, R12, R13, R14, R15]
+};
+
+lazy_static!{
+    static ref ARM_REGISTERS: HashMap<&'static str, ast::RegId> = {
+        use ast::RegId::*;
+
+        static MAP: &[(&str, ast::RegId)] = &[
+            ("r0" , R0),
+            ("r1" , R1),
+            ("r2" , R2),
+            ("r3" , R3),
+            ("r4" , R4),
+            ("r5" , R5),
+            ("r6" , R6),
+            ("r7" , R7),
+            ("r8" , R8),
+            ("r9" , R9),
+            ("r10", R10),
+            ("r11", R11),
+            ("r12", R12),
+            ("r13", R13),
+            ("r14", R14),
+            ("r15", R15),
+
+            ("a1", R0),
+            ("a2", R1),
+            ("a3", R2),
+            ("a4", R3),
+            ("v1", R4),
+            ("v2", R5),
+            ("v3", R6),
+            ("v4", R7),
+            ("v5", R8),
+            ("v6", R9),
+            ("v7", R10),
+            ("v8", R11),
+            ("sb", R9),
+            ("sl", R10),
+            ("fp", R11),
+            ("ip", R12),
+            ("sp", R13),
+            ("lr", R14),
+            ("pc", R15),
+        ];
+        MAP.iter().cloned().collect()
+    };
+}
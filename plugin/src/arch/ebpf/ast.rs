@@ -0,0 +1,98 @@
+//! eBPF registers are simple. There is a single family of eleven 64-bit registers, r0-r10, where
+//! r10 is the read-only frame pointer. Whether an operation uses the full register or only its lower
+//! 32 bits is purely encoded in the instruction.
+use proc_macro2::Span;
+use crate::common::Jump;
+use super::ebpfdata::Opdata;
+
+use std::fmt;
+
+
+/// A generic register reference. Can be either a static RegId or a dynamic register
+#[derive(Debug, Clone)]
+pub enum Register {
+    Static(RegId),
+    Dynamic(syn::Expr)
+}
+
+/// Unique identifiers for a specific register
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegId {
+    R0 = 0x00, R1 = 0x01, R2 = 0x02, R3 = 0x03,
+    R4 = 0x04, R5 = 0x05, R6 = 0x06, R7 = 0x07,
+    R8 = 0x08, R9 = 0x09, R10= 0x0A,
+}
+
+impl RegId {
+    /// Encode this RegId in a 4-bit value
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+}
+
+impl fmt::Display for RegId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "r{}", self.code())
+    }
+}
+
+
+/// An eBPF parsed instruction.
+/// These are fairly simple. the format is "op" [ arg [ , arg ]* ]
+/// where arg is
+/// * an immediate (arbitrary expression)
+/// * a label (in normal dynasm-rs style)
+/// * a register (one of the above)
+/// * a memory reference `[ reg [ ( + | - ) expr ] ]`
+#[derive(Debug, Clone)]
+pub enum RawArg {
+    // An immediate
+    Immediate {
+        value: syn::Expr
+    },
+    // A label
+    JumpTarget {
+        jump: Jump
+    },
+    // A register
+    Register {
+        span: Span,
+        reg: Register
+    },
+    // A memory reference
+    Reference {
+        span: Span,
+        offset: Option<syn::Expr>,
+        base: Register,
+    },
+}
+
+/// The result of parsing a single instruction
+#[derive(Debug)]
+pub struct ParsedInstruction {
+    pub name: String,
+    pub span: Span,
+    pub args: Vec<RawArg>
+}
+
+#[derive(Debug)]
+pub enum FlatArg {
+    Immediate {
+        value: syn::Expr
+    },
+    JumpTarget {
+        jump: Jump
+    },
+    Register {
+        span: Span,
+        reg: Register
+    },
+    Default
+}
+
+/// The result of finding a match for an instruction
+#[derive(Debug)]
+pub struct MatchData {
+    pub data: &'static Opdata,
+    pub args: Vec<FlatArg>
+}
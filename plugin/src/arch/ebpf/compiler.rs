@@ -0,0 +1,444 @@
+use super::Context;
+use super::ebpfdata::{Template, Command, Relocation};
+use super::ast::{MatchData, FlatArg, Register};
+
+use syn::spanned::Spanned;
+use quote::{quote, quote_spanned};
+use proc_macro2::{TokenStream, Span};
+use proc_macro_error2::emit_error;
+
+use crate::parse_helpers::{as_signed_number, as_unsigned_number};
+use crate::common::{Stmt, Size, delimited, bitmask64};
+
+/// Compile a single instruction. Input is taken from `data`, containing both the arguments
+/// and the encoding template and commands.
+/// Output is written to ctx.state
+/// Errors can be emitted to either the whole instruction span (by returning Err(Some(errormsg)))
+/// or emited specifically using emit_error! and returning Err(None)
+pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<(), Option<String>> {
+    // argument cursor
+    let mut cursor = 0usize;
+
+    // All static bitfields (compile-time constant) will be encoded into this map of (offset, bitfield)
+    let mut statics = Vec::new();
+    // All dynamic bitfields (run-time determined) will be encoded into this map of (offset, TokenStream)
+    let mut dynamics = Vec::new();
+    // Any relocation will be encoded in this list
+    let mut relocations = Vec::new();
+
+    for (i, command) in data.data.commands.iter().enumerate() {
+        // meta commands
+        if let Command::Next = *command {
+            cursor += 1;
+            continue;
+        }
+
+        let arg = data.args.get(cursor).expect("Invalid encoding data, tried to process more arguments than given");
+
+        match *arg {
+            FlatArg::Register { reg: Register::Static(id), .. } => {
+                let offset = match *command {
+                    Command::R(offset) => offset,
+                    _ => panic!("Invalid argument processor")
+                };
+
+                statics.push((offset, u64::from(id.code())));
+            },
+
+            FlatArg::Register { span, reg: Register::Dynamic(ref expr) } => match *command {
+                Command::R(offset) => {
+                    let expr = delimited(expr);
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        {
+                            let _dyn_reg: u8 = #expr.into();
+                            (_dyn_reg & 0xF) as u64
+                        }
+                    }));
+                },
+                _ => panic!("Invalid argument processor")
+            },
+
+            FlatArg::Default => match *command {
+                // Default is only emitted for a Ref where no offset was provided, i.e. it is 0
+                // This practically means we just don't have to encode anything.
+                Command::SImm(_)
+                | Command::BitRange(_, _, _) => (),
+                _ => panic!("Invalid argument processor")
+            },
+
+            FlatArg::Immediate { ref value } => match *command {
+                // integer verification commands
+                Command::UImm(bits) => {
+                    let span = value.span();
+                    let max = bitmask64(bits);
+
+                    let mut imm_encoder = ImmediateEncoder::new(value, false);
+                    imm_encoder.gather_fields(data.data.commands, i + 1, &mut statics);
+
+                    match imm_encoder.static_value {
+                        Some(static_value) => {
+                            static_range_check(static_value, 0, max as i64, span)?;
+                        },
+                        None => {
+                            let max = max as u32;
+                            let check = quote_spanned!{ span =>
+                                _dyn_imm > #max
+                            };
+                            imm_encoder.emit_dynamic(ImmType::Unsigned, Some(check), &mut dynamics);
+                        }
+                    }
+                },
+                Command::SImm(bits) => {
+                    let span = value.span();
+
+                    let mut imm_encoder = ImmediateEncoder::new(value, false);
+                    imm_encoder.gather_fields(data.data.commands, i + 1, &mut statics);
+
+                    signed_immediate(imm_encoder, bits, span, &mut dynamics)?;
+                },
+                Command::Imm32 => {
+                    let span = value.span();
+
+                    let mut imm_encoder = ImmediateEncoder::new(value, false);
+                    imm_encoder.gather_fields(data.data.commands, i + 1, &mut statics);
+
+                    // both signed and unsigned interpretations are allowed for static values.
+                    // dynamic values are always i32, so they need no checks.
+                    match imm_encoder.static_value {
+                        Some(static_value) => {
+                            static_range_check(static_value, i64::from(i32::MIN), i64::from(u32::MAX), span)?;
+                        },
+                        None => {
+                            imm_encoder.emit_dynamic(ImmType::Signed, None, &mut dynamics);
+                        }
+                    }
+                },
+                Command::BigImm => {
+                    let mut imm_encoder = ImmediateEncoder::new(value, true);
+                    imm_encoder.gather_fields(data.data.commands, i + 1, &mut statics);
+
+                    if imm_encoder.static_value.is_none() {
+                        imm_encoder.emit_dynamic(ImmType::Signed64, None, &mut dynamics);
+                    }
+                },
+
+                // integer encoding commands
+                Command::BitRange(_, _, _) => (),
+
+                // offsets can accept immediates too. These are counted in instruction slots,
+                // relative to the next instruction slot.
+                Command::Offset(relocation_type) => {
+                    let span = value.span();
+                    let commands: &'static [Command] = match relocation_type {
+                        Relocation::OFF16 => &[
+                            Command::BitRange(16, 16, 0),
+                            Command::Next
+                        ],
+                        Relocation::IMM32 => &[
+                            Command::BitRange(32, 32, 0),
+                            Command::Next
+                        ],
+                        Relocation::IMM64 => &[
+                            Command::BitRange(32, 32, 0),
+                            Command::BitRange(96, 32, 32),
+                            Command::Next
+                        ],
+                        Relocation::LITERAL8
+                        | Relocation::LITERAL16
+                        | Relocation::LITERAL32
+                        | Relocation::LITERAL64 => panic!("Literal relocation in instruction"),
+                    };
+
+                    let mut imm_encoder = ImmediateEncoder::new(value, false);
+                    imm_encoder.gather_fields(commands, 0, &mut statics);
+
+                    match relocation_type {
+                        Relocation::OFF16 => signed_immediate(imm_encoder, 16, span, &mut dynamics)?,
+                        Relocation::IMM32 => signed_immediate(imm_encoder, 32, span, &mut dynamics)?,
+                        _ => if imm_encoder.static_value.is_none() {
+                            imm_encoder.emit_dynamic(ImmType::Signed64, None, &mut dynamics);
+                        }
+                    }
+                },
+                _ => panic!("Invalid argument processor")
+            },
+
+            FlatArg::JumpTarget { ref jump } => match *command {
+                Command::Offset( relocation ) => {
+                    // encode the complete relocation. The field always covers the complete instruction,
+                    // and the offset is relative to the instruction slot after the first one.
+                    let stmt = jump.clone().encode(relocation.size(), relocation.size() - 8, &[relocation.to_id()]);
+
+                    relocations.push(stmt);
+                },
+                _ => panic!("Invalid argument processor")
+            }
+        }
+
+        // figure out how far the cursor has to be advanced.
+        match *command {
+            Command::UImm(_)
+            | Command::SImm(_)
+            | Command::Imm32
+            | Command::BigImm
+            | Command::BitRange(_, _, _) => (),
+            _ => cursor += 1
+        }
+    }
+
+    // sanity
+    if cursor != data.args.len() {
+        panic!("Not enough command processors");
+    }
+
+    let mut templates = [0u64; 2];
+    let mut exprs = [None, None];
+
+    let count = match data.data.template {
+        Template::Single(val) => {
+            templates[0] = val;
+            1
+        },
+        Template::Double(val1, val2) => {
+            templates[0] = val1;
+            templates[1] = val2;
+            2
+        },
+    };
+
+    // apply all statics to templates
+    for (offset, value) in statics {
+        templates[(offset >> 6) as usize] |= value << (offset & 0x3F);
+    }
+
+    // and process all dynamics
+    for (offset, expr) in dynamics {
+        let index = usize::from(offset >> 6);
+        let offset = offset & 0x3F;
+
+        exprs[index] = match exprs[index].take() {
+            Some(prev_expr) => {
+                Some(if offset == 0 {
+                    quote!{ #prev_expr | #expr }
+                } else {
+                    quote!{ #prev_expr | (#expr << #offset) }
+                })
+            },
+            None => {
+                let bits = templates[index];
+                Some(if offset == 0 {
+                    quote!{ #bits | #expr }
+                } else {
+                    quote!{ #bits | (#expr << #offset) }
+                })
+            }
+        }
+    }
+
+    for i in 0 .. count {
+        if let Some(d) = exprs[i].take() {
+            ctx.state.stmts.push(Stmt::ExprUnsigned(delimited(d), Size::B_8));
+        } else {
+            ctx.state.stmts.push(Stmt::Const(templates[i], Size::B_8));
+        }
+    }
+
+    ctx.state.stmts.extend(relocations);
+
+    Ok(())
+}
+
+
+/// Checks that a (possibly dynamic) immediate is a signed value that fits in `bits` bits
+fn signed_immediate(imm_encoder: ImmediateEncoder, bits: u8, span: Span, dynamics: &mut Vec<(u8, TokenStream)>) -> Result<(), Option<String>> {
+    let min: i64 = (-1) << (bits - 1);
+
+    match imm_encoder.static_value {
+        Some(static_value) => {
+            static_range_check(static_value, min, -1 - min, span)?;
+        },
+        // dynamic values are i32, so 32-bit immediates never need to be checked
+        None if bits == 32 => imm_encoder.emit_dynamic(ImmType::Signed, None, dynamics),
+        None => {
+            let min = min as i32;
+            let range = bitmask64(bits) as u32;
+            let check = quote_spanned!{ span =>
+                _dyn_imm.wrapping_sub(#min) as u32 > #range
+            };
+            imm_encoder.emit_dynamic(ImmType::Signed, Some(check), dynamics);
+        }
+    }
+
+    Ok(())
+}
+
+
+/// The type that dynamic immediates are expected to have
+#[derive(Debug, Clone, Copy)]
+enum ImmType {
+    Unsigned,
+    Signed,
+    Signed64,
+}
+
+/// Handles the encoding of immediates in a somewhat efficient fashion.
+struct ImmediateEncoder<'a> {
+    pub dynamic_value: &'a syn::Expr,
+    pub static_value: Option<i64>,
+    pub encodes: Vec<(u8, TokenStream)>, // encoding_offset, expression
+    pub span: Span
+}
+
+impl<'a> ImmediateEncoder<'a> {
+    /// Creates a new encoder for the given value. If `wide` is set, unsigned literals that
+    /// do not fit in an i64 are also accepted as static values
+    pub fn new(dynamic_value: &'a syn::Expr, wide: bool) -> ImmediateEncoder<'a> {
+        #![allow(unexpected_cfgs)]
+        let static_value;
+
+        // this allows turning off static checks for testing purposes
+        #[cfg(not(disable_static_checks="1"))]
+        {
+            static_value = as_signed_number(dynamic_value).or_else(|| if wide {
+                as_unsigned_number(dynamic_value).map(|v| v as i64)
+            } else {
+                None
+            });
+        }
+        #[cfg(disable_static_checks="1")]
+        {
+            let _ = wide;
+            static_value = None;
+        }
+
+        let span = dynamic_value.span();
+
+        ImmediateEncoder {
+            dynamic_value,
+            static_value,
+            encodes: Vec::new(),
+            span
+        }
+    }
+
+    pub fn gather_fields(&mut self, commands: &[Command], mut index: usize, statics: &mut Vec<(u8, u64)>) {
+        loop {
+            match commands.get(index) {
+                Some(&Command::BitRange(offset, bits, scaling)) => {
+                    let mask = bitmask64(bits);
+
+                    if let Some(v) = self.static_value {
+                        let slice = (v >> scaling) as u64 & mask;
+                        statics.push((offset, slice));
+
+                    } else if scaling == 0 {
+                        self.encodes.push((offset, quote_spanned!{ self.span=>
+                            (_dyn_imm as u64 & #mask)
+                        }));
+                    } else {
+                        self.encodes.push((offset, quote_spanned!{ self.span=>
+                            ((_dyn_imm >> #scaling) as u64 & #mask)
+                        }));
+                    }
+                },
+                Some(Command::Next) => break,
+                Some(_)
+                | None => panic!("Bad encoding data, integer field sequence is not terminated"),
+            }
+            index += 1;
+        }
+    }
+
+    pub fn emit_dynamic(mut self, ty: ImmType, check: Option<TokenStream>, dynamics: &mut Vec<(u8, TokenStream)>) {
+        // assemble encoding chunks
+        let mut exprs = [None, None];
+        let dynamic_value = self.dynamic_value;
+        let span = self.span;
+
+        for (offset, expr) in self.encodes.drain(..) {
+            let index = usize::from(offset >> 6);
+            let offset = offset & 0x3F;
+
+            exprs[index] = match exprs[index].take() {
+                Some(prev_expr) => {
+                    let parenthesized = delimited(prev_expr);
+
+                    Some(if offset == 0 {
+                        let expr = delimited(expr);
+                        quote!{ #parenthesized | #expr }
+                    } else {
+                        quote!{ #parenthesized | (#expr << #offset) }
+                    })
+                },
+                None => {
+                    Some(if offset == 0 {
+                        quote!{ #expr }
+                    } else {
+                        quote!{ #expr << #offset }
+                    })
+                }
+            }
+        }
+
+        let imm_ty = match ty {
+            ImmType::Unsigned => quote_spanned!{ span=> u32 },
+            ImmType::Signed   => quote_spanned!{ span=> i32 },
+            ImmType::Signed64 => quote_spanned!{ span=> i64 },
+        };
+
+        let mut check = check;
+        for (i, expr) in exprs.into_iter().enumerate() {
+            let encodes = if let Some(encodes) = expr { encodes } else {
+                continue
+            };
+            let offset = (i * 64) as u8;
+
+            if let Some(check) = check.take() {
+                let error_expr = match ty {
+                    ImmType::Unsigned => quote_spanned!{ span=>
+                        ::dynasmrt::ebpf::immediate_out_of_range_unsigned_32
+                    },
+                    ImmType::Signed => quote_spanned!{ span=>
+                        ::dynasmrt::ebpf::immediate_out_of_range_signed_32
+                    },
+                    ImmType::Signed64 => unreachable!("64-bit immediates never need to be range checked"),
+                };
+
+                dynamics.push((offset, quote_spanned!{ span=>
+                    {
+                        let _dyn_imm: #imm_ty = #dynamic_value;
+
+                        if #check {
+                            #error_expr(_dyn_imm);
+                        }
+
+                        #encodes
+                    }
+                }));
+
+            } else {
+                dynamics.push((offset, quote_spanned!{ span=>
+                    {
+                        let _dyn_imm: #imm_ty = #dynamic_value;
+                        #encodes
+                    }
+                }));
+            }
+        }
+    }
+}
+
+/// Checks that min <= value <= max
+fn static_range_check(value: i64, min: i64, max: i64, span: Span) -> Result<(), Option<String>> {
+    if value < min {
+        emit_error!(span, "Immediate too low");
+        return Err(None);
+    }
+
+    if value > max {
+        emit_error!(span, "Immediate too high");
+        return Err(None);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+//! this file contains the datastructure specification for the eBPF encoding data
+use lazy_static::lazy_static;
+
+use std::collections::{HashMap, hash_map};
+
+
+/// A template contains the information for the static parts of an instruction encoding, as well
+/// as its length. eBPF instructions are encoded as little-endian 64-bit slots, consisting of an
+/// 8-bit opcode, two 4-bit register fields, a 16-bit offset and a 32-bit immediate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Template {
+    /// A single 64-bit instruction slot
+    Single(u64),
+    /// Two 64-bit instruction slots. Used by the wide immediate loads
+    Double(u64, u64),
+}
+
+
+/// Matchers. These are used to check if a certain argument is compatible with the instruction
+/// being assembled. Multiple matchers can match a single argument
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Matcher {
+    /// A general purpose register
+    R,
+
+    /// An indirect reference with an optional offset. expands args to R, Imm
+    Ref,
+
+    /// An immediate
+    Imm,
+
+    /// A jump offset
+    Offset,
+}
+
+
+/// Encoding commands. They specify how arguments should be checked / encoded.
+///
+/// Unless otherwise stated, the first argument indicates the bottom-most bit of the affected bitfield,
+/// counted from the start of the first instruction slot
+#[derive(Debug, Clone)]
+pub enum Command {
+    // Meta commands
+
+    /// go to the next argument, if not done implicitly
+    Next,
+
+    // register fields
+
+    /// A 4-bit register encoding. The destination register lives at bit 8, the source register at bit 12
+    R(u8),
+
+    // immediate handling, validation fields
+
+    /// validate that the current arg is an unsigned value that fits in .0 bits
+    UImm(u8),
+
+    /// validate that the current arg is a signed value that fits in .0 bits
+    SImm(u8),
+
+    /// validate that the current arg fits in 32 bits, either as a signed or an unsigned value
+    Imm32,
+
+    /// validate that the current arg fits in 64 bits, either as a signed or an unsigned value
+    BigImm,
+
+    // immediate handling, encoding fields.
+
+    /// Encode a slice of bits from a value .0 = offset, .1 = amount of bits, .2 = offset in value
+    BitRange(u8, u8, u8),
+
+    /// some kind of offset for a jump.
+    Offset(Relocation),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relocation {
+    // jumps
+    // 16 bits in the offset field, counted in instruction slots
+    OFF16 = 0,
+    // call, ja32
+    // 32 bits in the immediate field, counted in instruction slots
+    IMM32 = 1,
+    // lddw
+    // 64 bits, split over the immediate fields of both slots, counted in instruction slots
+    IMM64 = 2,
+    // Anything in directives
+    LITERAL8 = 9,
+    LITERAL16 = 10,
+    LITERAL32 = 12,
+    LITERAL64 = 16,
+}
+
+impl Relocation {
+    pub fn to_id(self) -> u8 {
+        self as u8
+    }
+
+    pub fn size(self) -> u8 {
+        match self {
+            Relocation::LITERAL8 => 1,
+            Relocation::LITERAL16 => 2,
+            Relocation::LITERAL32 => 4,
+            Relocation::OFF16
+            | Relocation::IMM32
+            | Relocation::LITERAL64 => 8,
+            Relocation::IMM64 => 16,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct Opdata {
+    /// The base template for the encoding.
+    pub template: Template,
+    /// A set of matchers capable of matching the instruction encoding that this instruction represents.
+    pub matchers: &'static [Matcher],
+    /// A set of commands used to process the matched arguments
+    pub commands: &'static [Command],
+}
+
+macro_rules! SingleOp {
+    ( $template:expr, [ $( $matcher:expr ),* ], [ $( $command:expr ),* ] ) => {
+        {
+            const MATCHERS: &'static [Matcher] = {
+                #[allow(unused_imports)]
+                use self::Matcher::*;
+                &[ $(
+                    $matcher
+                ),* ]
+            };
+            const COMMANDS: &'static [Command] = {
+                #[allow(unused_imports)]
+                use self::Command::*;
+                #[allow(unused_imports)]
+                use self::Relocation::*;
+                &[ $(
+                    $command
+                ),* ]
+            };
+
+            use self::Template::*;
+            Opdata {
+                template: $template,
+                matchers: MATCHERS,
+                commands: COMMANDS,
+            }
+        }
+    }
+}
+
+macro_rules! Ops {
+    ( $( $name:tt = [ $( $template:expr , [ $( $matcher:expr ),* ] => [ $( $command:expr ),* ] ; )+ ] , )* ) => {
+        [ $(
+            (
+                $name,
+                &[ $(
+                    SingleOp!( $template, [ $( $matcher ),* ], [ $( $command ),* ] )
+                ),+ ] as &[_]
+            )
+        ),* ]
+    }
+}
+
+pub fn get_mnemonic_data(name: &str) -> Option<&'static [Opdata]> {
+    OPMAP.get(&name).cloned()
+}
+
+#[allow(dead_code)]
+pub fn mnemonics() -> hash_map::Keys<'static, &'static str, &'static [Opdata]> {
+    OPMAP.keys()
+}
+
+
+lazy_static!{
+    static ref OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        static MAP: &[(&str, &[Opdata])] = &include!("opmap.rs");
+        MAP.iter().cloned().collect()
+    };
+}
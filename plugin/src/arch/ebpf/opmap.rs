@@ -0,0 +1,594 @@
+Ops!(
+
+
+// Arithmetic and logic
+
+"add64" = [
+    // add64 rd, imm
+    Single(0x07), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // add64 rd, rs
+    Single(0x0F), [R, R] => [R(8), R(12)];
+],
+"add32" = [
+    // add32 rd, imm
+    Single(0x04), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // add32 rd, rs
+    Single(0x0C), [R, R] => [R(8), R(12)];
+],
+"sub64" = [
+    // sub64 rd, imm
+    Single(0x17), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // sub64 rd, rs
+    Single(0x1F), [R, R] => [R(8), R(12)];
+],
+"sub32" = [
+    // sub32 rd, imm
+    Single(0x14), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // sub32 rd, rs
+    Single(0x1C), [R, R] => [R(8), R(12)];
+],
+"mul64" = [
+    // mul64 rd, imm
+    Single(0x27), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // mul64 rd, rs
+    Single(0x2F), [R, R] => [R(8), R(12)];
+],
+"mul32" = [
+    // mul32 rd, imm
+    Single(0x24), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // mul32 rd, rs
+    Single(0x2C), [R, R] => [R(8), R(12)];
+],
+"div64" = [
+    // div64 rd, imm
+    Single(0x37), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // div64 rd, rs
+    Single(0x3F), [R, R] => [R(8), R(12)];
+],
+"div32" = [
+    // div32 rd, imm
+    Single(0x34), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // div32 rd, rs
+    Single(0x3C), [R, R] => [R(8), R(12)];
+],
+"or64" = [
+    // or64 rd, imm
+    Single(0x47), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // or64 rd, rs
+    Single(0x4F), [R, R] => [R(8), R(12)];
+],
+"or32" = [
+    // or32 rd, imm
+    Single(0x44), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // or32 rd, rs
+    Single(0x4C), [R, R] => [R(8), R(12)];
+],
+"and64" = [
+    // and64 rd, imm
+    Single(0x57), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // and64 rd, rs
+    Single(0x5F), [R, R] => [R(8), R(12)];
+],
+"and32" = [
+    // and32 rd, imm
+    Single(0x54), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // and32 rd, rs
+    Single(0x5C), [R, R] => [R(8), R(12)];
+],
+"lsh64" = [
+    // lsh64 rd, imm
+    Single(0x67), [R, Imm] => [R(8), UImm(6), BitRange(32, 32, 0), Next];
+    // lsh64 rd, rs
+    Single(0x6F), [R, R] => [R(8), R(12)];
+],
+"lsh32" = [
+    // lsh32 rd, imm
+    Single(0x64), [R, Imm] => [R(8), UImm(5), BitRange(32, 32, 0), Next];
+    // lsh32 rd, rs
+    Single(0x6C), [R, R] => [R(8), R(12)];
+],
+"rsh64" = [
+    // rsh64 rd, imm
+    Single(0x77), [R, Imm] => [R(8), UImm(6), BitRange(32, 32, 0), Next];
+    // rsh64 rd, rs
+    Single(0x7F), [R, R] => [R(8), R(12)];
+],
+"rsh32" = [
+    // rsh32 rd, imm
+    Single(0x74), [R, Imm] => [R(8), UImm(5), BitRange(32, 32, 0), Next];
+    // rsh32 rd, rs
+    Single(0x7C), [R, R] => [R(8), R(12)];
+],
+"mod64" = [
+    // mod64 rd, imm
+    Single(0x97), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // mod64 rd, rs
+    Single(0x9F), [R, R] => [R(8), R(12)];
+],
+"mod32" = [
+    // mod32 rd, imm
+    Single(0x94), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // mod32 rd, rs
+    Single(0x9C), [R, R] => [R(8), R(12)];
+],
+"xor64" = [
+    // xor64 rd, imm
+    Single(0xA7), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // xor64 rd, rs
+    Single(0xAF), [R, R] => [R(8), R(12)];
+],
+"xor32" = [
+    // xor32 rd, imm
+    Single(0xA4), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // xor32 rd, rs
+    Single(0xAC), [R, R] => [R(8), R(12)];
+],
+"mov64" = [
+    // mov64 rd, imm
+    Single(0xB7), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // mov64 rd, rs
+    Single(0xBF), [R, R] => [R(8), R(12)];
+],
+"mov32" = [
+    // mov32 rd, imm
+    Single(0xB4), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // mov32 rd, rs
+    Single(0xBC), [R, R] => [R(8), R(12)];
+],
+"arsh64" = [
+    // arsh64 rd, imm
+    Single(0xC7), [R, Imm] => [R(8), UImm(6), BitRange(32, 32, 0), Next];
+    // arsh64 rd, rs
+    Single(0xCF), [R, R] => [R(8), R(12)];
+],
+"arsh32" = [
+    // arsh32 rd, imm
+    Single(0xC4), [R, Imm] => [R(8), UImm(5), BitRange(32, 32, 0), Next];
+    // arsh32 rd, rs
+    Single(0xCC), [R, R] => [R(8), R(12)];
+],
+"neg64" = [
+    // neg64 rd
+    Single(0x87), [R] => [R(8)];
+],
+"neg32" = [
+    // neg32 rd
+    Single(0x84), [R] => [R(8)];
+],
+"sdiv64" = [
+    // sdiv64 rd, imm
+    Single(0x10037), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // sdiv64 rd, rs
+    Single(0x1003F), [R, R] => [R(8), R(12)];
+],
+"sdiv32" = [
+    // sdiv32 rd, imm
+    Single(0x10034), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // sdiv32 rd, rs
+    Single(0x1003C), [R, R] => [R(8), R(12)];
+],
+"smod64" = [
+    // smod64 rd, imm
+    Single(0x10097), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // smod64 rd, rs
+    Single(0x1009F), [R, R] => [R(8), R(12)];
+],
+"smod32" = [
+    // smod32 rd, imm
+    Single(0x10094), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+    // smod32 rd, rs
+    Single(0x1009C), [R, R] => [R(8), R(12)];
+],
+
+
+// Sign extending moves
+
+"movsxb64" = [
+    // movsxb64 rd, rs
+    Single(0x0800BF), [R, R] => [R(8), R(12)];
+],
+"movsxh64" = [
+    // movsxh64 rd, rs
+    Single(0x1000BF), [R, R] => [R(8), R(12)];
+],
+"movsxw64" = [
+    // movsxw64 rd, rs
+    Single(0x2000BF), [R, R] => [R(8), R(12)];
+],
+"movsxb32" = [
+    // movsxb32 rd, rs
+    Single(0x0800BC), [R, R] => [R(8), R(12)];
+],
+"movsxh32" = [
+    // movsxh32 rd, rs
+    Single(0x1000BC), [R, R] => [R(8), R(12)];
+],
+
+
+// Byte swapping
+
+"le16" = [
+    // le16 rd
+    Single(0x00000010_000000D4), [R] => [R(8)];
+],
+"le32" = [
+    // le32 rd
+    Single(0x00000020_000000D4), [R] => [R(8)];
+],
+"le64" = [
+    // le64 rd
+    Single(0x00000040_000000D4), [R] => [R(8)];
+],
+"be16" = [
+    // be16 rd
+    Single(0x00000010_000000DC), [R] => [R(8)];
+],
+"be32" = [
+    // be32 rd
+    Single(0x00000020_000000DC), [R] => [R(8)];
+],
+"be64" = [
+    // be64 rd
+    Single(0x00000040_000000DC), [R] => [R(8)];
+],
+"bswap16" = [
+    // bswap16 rd
+    Single(0x00000010_000000D7), [R] => [R(8)];
+],
+"bswap32" = [
+    // bswap32 rd
+    Single(0x00000020_000000D7), [R] => [R(8)];
+],
+"bswap64" = [
+    // bswap64 rd
+    Single(0x00000040_000000D7), [R] => [R(8)];
+],
+
+
+// Wide immediate loads
+
+"lddw" = [
+    // lddw rd, imm64
+    Double(0x18, 0x00), [R, Imm] => [R(8), BigImm, BitRange(32, 32, 0), BitRange(96, 32, 32), Next];
+    // lddw rd, label (BPF_PSEUDO_FUNC)
+    Double(0x4018, 0x00), [R, Offset] => [R(8), Offset(IMM64)];
+],
+"ld_map_fd" = [
+    // ld_map_fd rd, fd (BPF_PSEUDO_MAP_FD)
+    Double(0x1018, 0x00), [R, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next];
+],
+"ld_map_value" = [
+    // ld_map_value rd, fd, offset (BPF_PSEUDO_MAP_VALUE)
+    Double(0x2018, 0x00), [R, Imm, Imm] => [R(8), Imm32, BitRange(32, 32, 0), Next, Imm32, BitRange(96, 32, 0), Next];
+],
+
+
+// Loads and stores
+
+"ldxb" = [
+    // ldxb rd, [rs + off]
+    Single(0x71), [R, Ref] => [R(8), R(12), SImm(16), BitRange(16, 16, 0), Next];
+],
+"ldxh" = [
+    // ldxh rd, [rs + off]
+    Single(0x69), [R, Ref] => [R(8), R(12), SImm(16), BitRange(16, 16, 0), Next];
+],
+"ldxw" = [
+    // ldxw rd, [rs + off]
+    Single(0x61), [R, Ref] => [R(8), R(12), SImm(16), BitRange(16, 16, 0), Next];
+],
+"ldxdw" = [
+    // ldxdw rd, [rs + off]
+    Single(0x79), [R, Ref] => [R(8), R(12), SImm(16), BitRange(16, 16, 0), Next];
+],
+"ldxsb" = [
+    // ldxsb rd, [rs + off]
+    Single(0x91), [R, Ref] => [R(8), R(12), SImm(16), BitRange(16, 16, 0), Next];
+],
+"ldxsh" = [
+    // ldxsh rd, [rs + off]
+    Single(0x89), [R, Ref] => [R(8), R(12), SImm(16), BitRange(16, 16, 0), Next];
+],
+"ldxsw" = [
+    // ldxsw rd, [rs + off]
+    Single(0x81), [R, Ref] => [R(8), R(12), SImm(16), BitRange(16, 16, 0), Next];
+],
+"stb" = [
+    // stb [rd + off], imm
+    Single(0x72), [Ref, Imm] => [R(8), SImm(16), BitRange(16, 16, 0), Next, Imm32, BitRange(32, 32, 0), Next];
+],
+"sth" = [
+    // sth [rd + off], imm
+    Single(0x6A), [Ref, Imm] => [R(8), SImm(16), BitRange(16, 16, 0), Next, Imm32, BitRange(32, 32, 0), Next];
+],
+"stw" = [
+    // stw [rd + off], imm
+    Single(0x62), [Ref, Imm] => [R(8), SImm(16), BitRange(16, 16, 0), Next, Imm32, BitRange(32, 32, 0), Next];
+],
+"stdw" = [
+    // stdw [rd + off], imm
+    Single(0x7A), [Ref, Imm] => [R(8), SImm(16), BitRange(16, 16, 0), Next, Imm32, BitRange(32, 32, 0), Next];
+],
+"stxb" = [
+    // stxb [rd + off], rs
+    Single(0x73), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"stxh" = [
+    // stxh [rd + off], rs
+    Single(0x6B), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"stxw" = [
+    // stxw [rd + off], rs
+    Single(0x63), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"stxdw" = [
+    // stxdw [rd + off], rs
+    Single(0x7B), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+
+
+// Atomic operations
+
+"atomic_add64" = [
+    // atomic_add64 [rd + off], rs
+    Single(0xDB), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_add32" = [
+    // atomic_add32 [rd + off], rs
+    Single(0xC3), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_or64" = [
+    // atomic_or64 [rd + off], rs
+    Single(0x00000040_000000DB), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_or32" = [
+    // atomic_or32 [rd + off], rs
+    Single(0x00000040_000000C3), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_and64" = [
+    // atomic_and64 [rd + off], rs
+    Single(0x00000050_000000DB), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_and32" = [
+    // atomic_and32 [rd + off], rs
+    Single(0x00000050_000000C3), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_xor64" = [
+    // atomic_xor64 [rd + off], rs
+    Single(0x000000A0_000000DB), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_xor32" = [
+    // atomic_xor32 [rd + off], rs
+    Single(0x000000A0_000000C3), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_fetch_add64" = [
+    // atomic_fetch_add64 [rd + off], rs
+    Single(0x00000001_000000DB), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_fetch_add32" = [
+    // atomic_fetch_add32 [rd + off], rs
+    Single(0x00000001_000000C3), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_fetch_or64" = [
+    // atomic_fetch_or64 [rd + off], rs
+    Single(0x00000041_000000DB), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_fetch_or32" = [
+    // atomic_fetch_or32 [rd + off], rs
+    Single(0x00000041_000000C3), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_fetch_and64" = [
+    // atomic_fetch_and64 [rd + off], rs
+    Single(0x00000051_000000DB), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_fetch_and32" = [
+    // atomic_fetch_and32 [rd + off], rs
+    Single(0x00000051_000000C3), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_fetch_xor64" = [
+    // atomic_fetch_xor64 [rd + off], rs
+    Single(0x000000A1_000000DB), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_fetch_xor32" = [
+    // atomic_fetch_xor32 [rd + off], rs
+    Single(0x000000A1_000000C3), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_xchg64" = [
+    // atomic_xchg64 [rd + off], rs
+    Single(0x000000E1_000000DB), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_xchg32" = [
+    // atomic_xchg32 [rd + off], rs
+    Single(0x000000E1_000000C3), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_cmpxchg64" = [
+    // atomic_cmpxchg64 [rd + off], rs
+    Single(0x000000F1_000000DB), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+"atomic_cmpxchg32" = [
+    // atomic_cmpxchg32 [rd + off], rs
+    Single(0x000000F1_000000C3), [Ref, R] => [R(8), SImm(16), BitRange(16, 16, 0), Next, R(12)];
+],
+
+
+// Legacy packet access
+
+"ldabsb" = [
+    // ldabsb imm
+    Single(0x30), [Imm] => [Imm32, BitRange(32, 32, 0), Next];
+],
+"ldabsh" = [
+    // ldabsh imm
+    Single(0x28), [Imm] => [Imm32, BitRange(32, 32, 0), Next];
+],
+"ldabsw" = [
+    // ldabsw imm
+    Single(0x20), [Imm] => [Imm32, BitRange(32, 32, 0), Next];
+],
+"ldindb" = [
+    // ldindb rs, imm
+    Single(0x50), [R, Imm] => [R(12), Imm32, BitRange(32, 32, 0), Next];
+],
+"ldindh" = [
+    // ldindh rs, imm
+    Single(0x48), [R, Imm] => [R(12), Imm32, BitRange(32, 32, 0), Next];
+],
+"ldindw" = [
+    // ldindw rs, imm
+    Single(0x40), [R, Imm] => [R(12), Imm32, BitRange(32, 32, 0), Next];
+],
+
+
+// Jumps and calls
+
+"ja" = [
+    // ja offset
+    Single(0x05), [Offset] => [Offset(OFF16)];
+],
+"ja32" = [
+    // ja32 offset
+    Single(0x06), [Offset] => [Offset(IMM32)];
+],
+"jeq" = [
+    // jeq rd, imm, offset
+    Single(0x15), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jeq rd, rs, offset
+    Single(0x1D), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jeq32" = [
+    // jeq32 rd, imm, offset
+    Single(0x16), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jeq32 rd, rs, offset
+    Single(0x1E), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jgt" = [
+    // jgt rd, imm, offset
+    Single(0x25), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jgt rd, rs, offset
+    Single(0x2D), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jgt32" = [
+    // jgt32 rd, imm, offset
+    Single(0x26), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jgt32 rd, rs, offset
+    Single(0x2E), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jge" = [
+    // jge rd, imm, offset
+    Single(0x35), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jge rd, rs, offset
+    Single(0x3D), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jge32" = [
+    // jge32 rd, imm, offset
+    Single(0x36), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jge32 rd, rs, offset
+    Single(0x3E), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jset" = [
+    // jset rd, imm, offset
+    Single(0x45), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jset rd, rs, offset
+    Single(0x4D), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jset32" = [
+    // jset32 rd, imm, offset
+    Single(0x46), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jset32 rd, rs, offset
+    Single(0x4E), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jne" = [
+    // jne rd, imm, offset
+    Single(0x55), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jne rd, rs, offset
+    Single(0x5D), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jne32" = [
+    // jne32 rd, imm, offset
+    Single(0x56), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jne32 rd, rs, offset
+    Single(0x5E), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jsgt" = [
+    // jsgt rd, imm, offset
+    Single(0x65), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jsgt rd, rs, offset
+    Single(0x6D), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jsgt32" = [
+    // jsgt32 rd, imm, offset
+    Single(0x66), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jsgt32 rd, rs, offset
+    Single(0x6E), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jsge" = [
+    // jsge rd, imm, offset
+    Single(0x75), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jsge rd, rs, offset
+    Single(0x7D), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jsge32" = [
+    // jsge32 rd, imm, offset
+    Single(0x76), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jsge32 rd, rs, offset
+    Single(0x7E), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jlt" = [
+    // jlt rd, imm, offset
+    Single(0xA5), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jlt rd, rs, offset
+    Single(0xAD), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jlt32" = [
+    // jlt32 rd, imm, offset
+    Single(0xA6), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jlt32 rd, rs, offset
+    Single(0xAE), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jle" = [
+    // jle rd, imm, offset
+    Single(0xB5), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jle rd, rs, offset
+    Single(0xBD), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jle32" = [
+    // jle32 rd, imm, offset
+    Single(0xB6), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jle32 rd, rs, offset
+    Single(0xBE), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jslt" = [
+    // jslt rd, imm, offset
+    Single(0xC5), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jslt rd, rs, offset
+    Single(0xCD), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jslt32" = [
+    // jslt32 rd, imm, offset
+    Single(0xC6), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jslt32 rd, rs, offset
+    Single(0xCE), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jsle" = [
+    // jsle rd, imm, offset
+    Single(0xD5), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jsle rd, rs, offset
+    Single(0xDD), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"jsle32" = [
+    // jsle32 rd, imm, offset
+    Single(0xD6), [R, Imm, Offset] => [R(8), Imm32, BitRange(32, 32, 0), Next, Offset(OFF16)];
+    // jsle32 rd, rs, offset
+    Single(0xDE), [R, R, Offset] => [R(8), R(12), Offset(OFF16)];
+],
+"call" = [
+    // call helper
+    Single(0x85), [Imm] => [Imm32, BitRange(32, 32, 0), Next];
+    // call label (BPF_PSEUDO_CALL)
+    Single(0x1085), [Offset] => [Offset(IMM32)];
+],
+"exit" = [
+    // exit
+    Single(0x95), [] => [];
+],
+)
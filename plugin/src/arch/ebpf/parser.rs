@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use syn::{parse, Token};
+use proc_macro2::Span;
+
+use lazy_static::lazy_static;
+
+use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOptExt};
+use crate::serialize;
+
+use super::{Context, ast};
+
+// syntax for a single op: ident (arg ("," arg)*)? ";"
+pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<ast::ParsedInstruction> {
+    let span = input.cursor().span();
+
+    let name = parse_ident_or_rust_keyword(input)?.to_string();
+
+    let mut args = Vec::new();
+
+    // parse 0 or more comma-separated args
+    if !(input.is_empty() || input.peek(Token![;])) {
+        args.push(parse_arg(ctx, input)?);
+
+        while input.peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+
+            args.push(parse_arg(ctx, input)?);
+        }
+    }
+
+    // let span = span.join(input.cursor().span()); // FIXME can't join spans ATM
+
+    Ok(ast::ParsedInstruction {
+        name,
+        span,
+        args
+    })
+}
+
+
+/// tries to parse a full arg definition
+fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<ast::RawArg> {
+    let start = input.cursor().span(); // FIXME can't join spans yet
+
+    // a label, identified by a leading < / > / -> / =>
+    if let Some(jump) = input.parse_opt()? {
+        return Ok(ast::RawArg::JumpTarget {
+            jump
+        });
+    }
+
+    // a memory reference. These use the '[' base [ ( '+' | '-' ) offset ] ']' syntax that is
+    // common among eBPF assemblers.
+    if input.peek(syn::token::Bracket) {
+        let span = input.cursor().span();
+        let inner;
+        let _ = syn::bracketed!(inner in input);
+        let inner = &inner;
+
+        let base = parse_reg(ctx, inner)?.ok_or_else(|| inner.error("Expected register"))?;
+
+        let offset = if inner.is_empty() {
+            None
+        } else if inner.peek(Token![+]) {
+            let _: Token![+] = inner.parse()?;
+            Some(inner.parse::<syn::Expr>()?)
+        } else if inner.peek(Token![-]) {
+            // the minus sign is parsed as part of the offset expression, so any operators
+            // following it keep their precedence.
+            Some(inner.parse::<syn::Expr>()?)
+        } else {
+            return Err(inner.error("Expected '+' or '-' followed by an offset"));
+        };
+
+        return Ok(ast::RawArg::Reference {
+            span,
+            base,
+            offset
+        });
+    }
+
+    // a register, or a type mapped reference (reg => Type[index].attr)
+    if let Some(reg) = parse_reg(ctx, input)? {
+        if input.peek(Token![=>]) {
+            return parse_typemap(ctx, input, reg, start);
+        }
+
+        return Ok(ast::RawArg::Register {
+            reg,
+            span: start
+        })
+    }
+
+    // immediate
+    let expr: syn::Expr = input.parse()?;
+
+    Ok(ast::RawArg::Immediate { value: expr })
+}
+
+/// Parses the remainder of a type mapped reference, `base => Type ("[" index "]")? ("." attr)?`
+/// The index has to be an immediate. It is combined with the offset of the attribute into the
+/// reference offset.
+fn parse_typemap(ctx: &mut Context, input: parse::ParseStream, base: ast::Register, span: Span) -> parse::Result<ast::RawArg> {
+    let _: Token![=>] = input.parse()?;
+
+    let ty: syn::Path = input.parse()?;
+
+    let index = if input.peek(syn::token::Bracket) {
+        let inner;
+        let _ = syn::bracketed!(inner in input);
+        let inner = &inner;
+
+        if parse_reg(ctx, &inner.fork())?.is_some() {
+            return Err(inner.error("eBPF type mapped references cannot use an index register"));
+        }
+
+        Some(inner.parse::<syn::Expr>()?)
+    } else {
+        None
+    };
+
+    let attr: Option<syn::Ident> = if input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        Some(input.parse()?)
+    } else {
+        None
+    };
+
+    let offset = match serialize::expr_typemap_disp(span, &ty, index.as_ref(), attr.as_ref()) {
+        Some(disp) => Some(serialize::reparse(&disp)?),
+        None => None
+    };
+
+    Ok(ast::RawArg::Reference {
+        span,
+        base,
+        offset
+    })
+}
+
+/// Parses a single register, if present
+/// This can be a simple register name (like `r5`)
+/// an alias (any simple name that is registered, like `base`)
+/// or a dynamic register (like `R(expr)`)
+fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<ast::Register>> {
+    // we need to consume an ident, but only if it's one of the many we care about
+    // so use a step parser to figure it out.
+    let name = input.step(|cursor| {
+        if let Some((ident, rest)) = cursor.ident() {
+            let mut ident = ident.to_string();
+
+            // first, parse the dynamic register family
+            if ident == "R" {
+                return Ok((ident, rest));
+            }
+
+            // otherwise, see if this is an alias
+            if let Some(repl) = ctx.state.invocation_context.aliases.get(&ident) {
+                ident = repl.clone();
+            }
+
+            // resolve normal register references
+            if EBPF_REGISTERS.contains_key(&*ident) {
+                return Ok((ident, rest));
+            }
+        }
+        Err(cursor.error("expected identifier"))
+    });
+
+    let name = match name {
+        Ok(name) => name,
+        Err(_) => return Ok(None)
+    };
+
+    // we know we have a register reference now, try to resolve it.
+    let register = if let Some(&id) = EBPF_REGISTERS.get(&*name) {
+        ast::Register::Static(id)
+    } else {
+        // need to parse the trailing `( expr )`
+        let inner;
+        let _ = syn::parenthesized!(inner in input);
+        let inner = &inner;
+
+        let expr: syn::Expr = inner.parse()?;
+        if !inner.is_empty() {
+            return Err(inner.error("Too many arguments in register family expression"));
+        }
+
+        ast::Register::Dynamic(expr)
+    };
+
+    Ok(Some(register))
+}
+
+
+lazy_static!{
+    static ref EBPF_REGISTERS: HashMap<&'static str, ast::RegId> = {
+        use ast::RegId::*;
+
+        static MAP: &[(&str, ast::RegId)] = &[
+            ("r0" , R0),
+            ("r1" , R1),
+            ("r2" , R2),
+            ("r3" , R3),
+            ("r4" , R4),
+            ("r5" , R5),
+            ("r6" , R6),
+            ("r7" , R7),
+            ("r8" , R8),
+            ("r9" , R9),
+            ("r10", R10),
+
+            ("fp" , R10),
+        ];
+        MAP.iter().cloned().collect()
+    };
+}
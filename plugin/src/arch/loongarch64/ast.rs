@@ -0,0 +1,171 @@
+//! LoongArch registers are simple. The registers contain no size information, this is purely encoded
+//! in the instruction. We currently have four known register families.
+//! * General purpose registers, either denoted as r0-r31 or by their ABI names
+//! * floating point registers, denoted as f0-f31 or by their ABI names
+//! * floating point condition flags, denoted as fcc0-fcc7
+//! * floating point control and status registers, denoted as fcsr0-fcsr3
+use proc_macro2::Span;
+use crate::common::Jump;
+use super::loongarch64data::Opdata;
+
+use std::fmt;
+
+
+/// A generic register reference. Can be either a static RegId or a dynamic register from a family
+#[derive(Debug, Clone)]
+pub enum Register {
+    Static(RegId),
+    Dynamic(RegFamily, syn::Expr)
+}
+
+/// Unique identifiers for a specific register
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegId {
+    // regular registers
+    R0 = 0x00, R1 = 0x01, R2 = 0x02, R3 = 0x03, // zero, ra, tp, sp
+    R4 = 0x04, R5 = 0x05, R6 = 0x06, R7 = 0x07, // a0, a1, a2, a3
+    R8 = 0x08, R9 = 0x09, R10= 0x0A, R11= 0x0B, // a4, a5, a6, a7
+    R12= 0x0C, R13= 0x0D, R14= 0x0E, R15= 0x0F, // t0, t1, t2, t3
+    R16= 0x10, R17= 0x11, R18= 0x12, R19= 0x13, // t4, t5, t6, t7
+    R20= 0x14, R21= 0x15, R22= 0x16, R23= 0x17, // t8, r21, fp, s0
+    R24= 0x18, R25= 0x19, R26= 0x1A, R27= 0x1B, // s1, s2, s3, s4
+    R28= 0x1C, R29= 0x1D, R30= 0x1E, R31= 0x1F, // s5, s6, s7, s8
+
+    // floating point registers
+    F0 = 0x20, F1 = 0x21, F2 = 0x22, F3 = 0x23,
+    F4 = 0x24, F5 = 0x25, F6 = 0x26, F7 = 0x27,
+    F8 = 0x28, F9 = 0x29, F10= 0x2A, F11= 0x2B,
+    F12= 0x2C, F13= 0x2D, F14= 0x2E, F15= 0x2F,
+    F16= 0x30, F17= 0x31, F18= 0x32, F19= 0x33,
+    F20= 0x34, F21= 0x35, F22= 0x36, F23= 0x37,
+    F24= 0x38, F25= 0x39, F26= 0x3A, F27= 0x3B,
+    F28= 0x3C, F29= 0x3D, F30= 0x3E, F31= 0x3F,
+
+    // floating point condition flags
+    FCC0 = 0x40, FCC1 = 0x41, FCC2 = 0x42, FCC3 = 0x43,
+    FCC4 = 0x44, FCC5 = 0x45, FCC6 = 0x46, FCC7 = 0x47,
+
+    // floating point control and status registers
+    FCSR0 = 0x60, FCSR1 = 0x61, FCSR2 = 0x62, FCSR3 = 0x63,
+}
+
+/// Register families
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegFamily {
+    INTEGER = 0,
+    FP = 1,
+    FCC = 2,
+    FCSR = 3,
+}
+
+impl RegId {
+    /// Encode this RegId in a 5-bit value
+    pub fn code(self) -> u8 {
+        self as u8 & 0x1F
+    }
+
+    /// Returns the family of this Regid
+    pub fn family(self) -> RegFamily {
+        match self as u8 >> 5 {
+            0 => RegFamily::INTEGER,
+            1 => RegFamily::FP,
+            2 => RegFamily::FCC,
+            3 => RegFamily::FCSR,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl fmt::Display for RegId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.family() {
+            RegFamily::INTEGER => write!(f, "r{}", self.code()),
+            RegFamily::FP => write!(f, "f{}", self.code()),
+            RegFamily::FCC => write!(f, "fcc{}", self.code()),
+            RegFamily::FCSR => write!(f, "fcsr{}", self.code()),
+        }
+    }
+}
+
+impl Register {
+    /// Returns the family that this Register is of
+    pub fn family(&self) -> RegFamily {
+        match self {
+            Register::Static(code) => code.family(),
+            Register::Dynamic(family, _) => *family
+        }
+    }
+}
+
+
+/// A LoongArch parsed instruction.
+/// These are fairly simple. the format is "op" [ . "opext" ]* [ arg [ , arg ]* ]
+/// where arg is
+/// * an immediate (arbitrary expression)
+/// * a label (in normal dynasm-rs style)
+/// * a register (one of the above)
+/// * a memory reference `[ intreg [ , expr | intreg | label ] ]`
+#[derive(Debug, Clone)]
+pub enum RawArg {
+    // An immediate
+    Immediate {
+        value: syn::Expr
+    },
+    // A label
+    JumpTarget {
+        jump: Jump
+    },
+    // A register
+    Register {
+        span: Span,
+        reg: Register
+    },
+    // A memory reference
+    Reference {
+        span: Span,
+        offset: Option<syn::Expr>,
+        base: Register,
+    },
+    // A memory reference with an index register
+    IndexedReference {
+        span: Span,
+        base: Register,
+        index: Register,
+    },
+    // A pc-relative reference
+    LabelReference {
+        span: Span,
+        jump: Jump,
+        base: Register
+    },
+}
+
+/// The result of parsing a single instruction
+#[derive(Debug)]
+pub struct ParsedInstruction {
+    pub name: String,
+    pub span: Span,
+    pub args: Vec<RawArg>
+}
+
+#[derive(Debug)]
+pub enum FlatArg {
+    Immediate {
+        value: syn::Expr
+    },
+    JumpTarget {
+        jump: Jump
+    },
+    Register {
+        span: Span,
+        reg: Register
+    },
+    Default
+}
+
+/// The result of finding a match for an instruction
+#[derive(Debug)]
+pub struct MatchData {
+    pub data: &'static Opdata,
+    pub args: Vec<FlatArg>
+}
@@ -0,0 +1,227 @@
+//! this file contains the datastructure specification for the LoongArch encoding data
+use lazy_static::lazy_static;
+
+use std::collections::{HashMap, hash_map};
+
+
+/// A template contains the information for the static parts of an instruction encoding, as well
+/// as its length
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Template {
+    /// A single 32-bit instruction
+    Single(u32),
+    // Two 32-bit instructions. Used for pc-relative address generation
+    Double(u32, u32),
+    // / A long instruction sequence. Used to load big immediates.
+    Many(&'static [u32])
+}
+
+
+/// Matchers. These are used to check if a certain argument is compatible with the instruction
+/// being assembled. Multiple matchers can match a single argument
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Matcher {
+    /// A general purpose register
+    R,
+
+    /// A floating point register
+    F,
+
+    /// A floating point condition flag register
+    Fcc,
+
+    /// A floating point control and status register
+    Fcsr,
+
+    /// An indirect reference to a register
+    Ref,
+
+    /// An indirect reference with offset. expands args to R, Imm
+    RefOffset,
+
+    /// An indirect reference with an index register. expands args to R, R
+    RefIndex,
+
+    /// An pc-relative indirect reference. The register ought to contain an address
+    /// generated by pcaddu12i for the same label
+    RefLabel,
+
+    /// An immediate
+    Imm,
+
+    /// A jump offset
+    Offset,
+}
+
+
+/// Encoding commands. They specify how arguments should be checked / encoded.
+///
+/// Unless otherwise stated, the first argument indicates the bottom-most bit of the affected bitfield
+#[derive(Debug, Clone)]
+pub enum Command {
+    // Meta commands
+
+    /// Repeat the same argument again, as it needs to be encoded twice
+    Repeat,
+
+    /// go to the next argument, if not done implicitly
+    Next,
+
+    // register fields
+
+    /// A normal 5-bit register encoding. Argument specifies
+    R(u8),
+
+    /// A 3-bit condition flag register encoding
+    C(u8),
+
+    // weird fields
+
+    /// A shift amount ranging from 1 to 1 << .1, encoded as the amount minus one in a .1 bits
+    /// field. Used by alsl.
+    ShiftAmount(u8, u8),
+
+    // immediate handling, validation fields
+
+    /// validate that the current arg is an unsigned value that fits in .0 bits, and that the
+    /// lower .1 bits are 0
+    UImm(u8, u8),
+
+    /// validate that the current arg is a signed value that fits in .0 bits, and that the
+    /// lower .1 bits are 0
+    SImm(u8, u8),
+
+    /// Same as SImm, but handles 64-bit values. No scaling support
+    BigImm(u8),
+
+    // immediate handling, encoding fields.
+
+    /// Encode a slice of bits from a value .0 = offset, .1 = amount of bits, .2 = offset in value
+    BitRange(u8, u8, u8),
+
+    /// Encode a slice of bits from a value .0 = offset, .1 = amount of bits, .2 = offset in value,
+    /// but add 1 << (.2 - 1) to value before encoding to round it
+    RBitRange(u8, u8, u8),
+
+    /// some kind of offset for a jump.
+    Offset(Relocation),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relocation {
+    // b, bl
+    // 26 bits, 2-bit scaled
+    B26 = 0,
+    // beq, bne, blt, bge, bltu, bgeu and aliases
+    // 16 bits, 2-bit scaled
+    B16 = 1,
+    // beqz, bnez, bceqz, bcnez
+    // 21 bits, 2-bit scaled
+    B21 = 2,
+    // pcaddu12i
+    // 32 bits, 12-bit scaled
+    HI20 = 3,
+    // loads, stores, addi.w, addi.d
+    // 12 bits, no scaling
+    LO12 = 4,
+    // pc-relative addrgen pseudo instructions
+    // 32 bits, no scaling
+    SPLIT32 = 5,
+    // Anything in directives
+    LITERAL8 = 9,
+    LITERAL16 = 10,
+    LITERAL32 = 12,
+    LITERAL64 = 16,
+}
+
+impl Relocation {
+    pub fn to_id(self) -> u8 {
+        self as u8
+    }
+
+    pub fn size(self) -> u8 {
+        match self {
+            Relocation::LITERAL8 => 1,
+            Relocation::LITERAL16 => 2,
+            Relocation::B26
+            | Relocation::B16
+            | Relocation::B21
+            | Relocation::HI20
+            | Relocation::LO12
+            | Relocation::LITERAL32 => 4,
+            Relocation::SPLIT32
+            | Relocation::LITERAL64 => 8
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct Opdata {
+    /// The base template for the encoding.
+    pub template: Template,
+    /// A set of matchers capable of matching the instruction encoding that this instruction represents.
+    pub matchers: &'static [Matcher],
+    /// A set of commands used to process the matched arguments
+    pub commands: &'static [Command],
+}
+
+macro_rules! SingleOp {
+    ( $template:expr, [ $( $matcher:expr ),* ], [ $( $command:expr ),* ] ) => {
+        {
+            const MATCHERS: &'static [Matcher] = {
+                #[allow(unused_imports)]
+                use self::Matcher::*;
+                &[ $(
+                    $matcher
+                ),* ]
+            };
+            const COMMANDS: &'static [Command] = {
+                #[allow(unused_imports)]
+                use self::Command::*;
+                #[allow(unused_imports)]
+                use self::Relocation::*;
+                &[ $(
+                    $command
+                ),* ]
+            };
+
+            use self::Template::*;
+            Opdata {
+                template: $template,
+                matchers: MATCHERS,
+                commands: COMMANDS,
+            }
+        }
+    }
+}
+
+macro_rules! Ops {
+    ( $( $name:tt = [ $( $template:expr , [ $( $matcher:expr ),* ] => [ $( $command:expr ),* ] ; )+ ] , )* ) => {
+        [ $(
+            (
+                $name,
+                &[ $(
+                    SingleOp!( $template, [ $( $matcher ),* ], [ $( $command ),* ] )
+                ),+ ] as &[_]
+            )
+        ),* ]
+    }
+}
+
+pub fn get_mnemonic_data(name: &str) -> Option<&'static [Opdata]> {
+    OPMAP.get(&name).cloned()
+}
+
+#[allow(dead_code)]
+pub fn mnemonics() -> hash_map::Keys<'static, &'static str, &'static [Opdata]> {
+    OPMAP.keys()
+}
+
+
+lazy_static!{
+    static ref OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        static MAP: &[(&str, &[Opdata])] = &include!("opmap.rs");
+        MAP.iter().cloned().collect()
+    };
+}
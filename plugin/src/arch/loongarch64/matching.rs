@@ -0,0 +1,181 @@
+use proc_macro_error2::emit_error;
+
+use super::Context;
+use super::ast::{ParsedInstruction, RawArg, MatchData, FlatArg, Register, RegFamily};
+use super::loongarch64data::{Opdata, Matcher, get_mnemonic_data};
+use super::debug::format_opdata_list;
+
+use crate::common::JumpKind;
+use crate::parse_helpers::as_signed_number;
+
+/// Try finding an appropriate instruction definition that matches the given instruction / arguments.
+pub(super) fn match_instruction(_ctx: &mut Context, mut instruction: ParsedInstruction) -> Result<MatchData, Option<String>> {
+    // sanitize Raw args from parsing for any impossible constructs
+    sanitize_args(&mut instruction.args)?;
+
+    let opdata = get_mnemonic_data(&instruction.name).ok_or_else(|| Some(format!("Unknown instruction mnemonic '{}'", instruction.name)))?;
+
+    // iterate through the supported instruction formats. If one matches, lower the args to
+    // FlatArgs and return the combined Matchdata
+    for data in opdata {
+        if let Some(mut match_data) = match_args(&instruction.args, data) {
+            flatten_args(instruction.args, &mut match_data);
+
+            return Ok(match_data)
+        }
+    }
+
+    Err(Some(format!("'{}': instruction format mismatch, expected one of the following forms:\n{}", &instruction.name, format_opdata_list(&instruction.name, opdata))))
+}
+
+/// Sanitizes arguments, ensuring that
+/// Extern relocations are not allowed
+/// Base and index registers of memory references are integer registers
+/// Canonicalize `[register, 0]` references as without offset (like `[register]`)
+fn sanitize_args(args: &mut [RawArg]) -> Result<(), Option<String>> {
+    for arg in args {
+        match arg {
+            RawArg::Reference { base, span, offset } => {
+                sanitize_base(base, *span)?;
+
+                if let Some(o) = offset.as_ref() {
+                    if as_signed_number(o) == Some(0) {
+                        *offset = None
+                    }
+                }
+            },
+            RawArg::IndexedReference { base, index, span } => {
+                sanitize_base(base, *span)?;
+                if index.family() != RegFamily::INTEGER {
+                    emit_error!(span, "Index register needs to be a regular (integer) register");
+                    return Err(None);
+                }
+            },
+            RawArg::LabelReference { span, base, jump } => {
+                sanitize_base(base, *span)?;
+
+                if let JumpKind::Bare(_) = jump.kind {
+                    emit_error!(jump.span(), "Extern relocations are not allowed in loongarch64");
+                    return Err(None);
+                }
+            },
+            RawArg::JumpTarget { jump } => {
+                if let JumpKind::Bare(_) = jump.kind {
+                    emit_error!(jump.span(), "Extern relocations are not allowed in loongarch64");
+                    return Err(None);
+                }
+            },
+            _ => ()
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that the base register of a memory reference is an integer register
+fn sanitize_base(base: &Register, span: proc_macro2::Span) -> Result<(), Option<String>> {
+    if base.family() != RegFamily::INTEGER {
+        emit_error!(span, "Base register needs to be a regular (integer) register");
+        return Err(None);
+    }
+    Ok(())
+}
+
+
+impl MatchData {
+    pub fn new(data: &'static Opdata) -> MatchData {
+        MatchData {
+            data,
+            args: Vec::new()
+        }
+    }
+}
+
+
+impl Matcher {
+    /// Returns if this matcher matches the given argument
+    pub fn matches(&self, arg: &RawArg) -> bool {
+        match arg {
+            RawArg::Immediate { .. } => matches!(self, Matcher::Imm | Matcher::Offset),
+            RawArg::JumpTarget { .. } => *self == Matcher::Offset,
+            RawArg::Register { reg, .. } => match self {
+                Matcher::R => reg.family() == RegFamily::INTEGER,
+                Matcher::F => reg.family() == RegFamily::FP,
+                Matcher::Fcc => reg.family() == RegFamily::FCC,
+                Matcher::Fcsr => reg.family() == RegFamily::FCSR,
+                _ => false,
+            },
+            RawArg::Reference { offset, .. } => match self {
+                Matcher::Ref => offset.is_none(),
+                Matcher::RefOffset => true,
+                _ => false,
+            },
+            RawArg::IndexedReference { .. } => *self == Matcher::RefIndex,
+            RawArg::LabelReference { .. } => *self == Matcher::RefLabel,
+        }
+    }
+}
+
+
+/// Check if the parsed instruction arguments match the data matching template
+pub fn match_args(args: &[RawArg], data: &'static Opdata) -> Option<MatchData> {
+    let mut args = args.iter();
+
+    // check if each matcher matches an appropriate arg
+    for matcher in data.matchers {
+        if let Some(arg) = args.next() {
+            if !matcher.matches(arg) {
+                return None;
+            }
+        } else {
+            return None;
+        }
+    }
+
+    // and return success if there's no more args remaining to match
+    if args.next().is_some() {
+        None
+    } else {
+        Some(MatchData::new(data))
+    }
+}
+
+
+/// Populate MatchData with FlatArgs
+fn flatten_args(args: Vec<RawArg>, data: &mut MatchData) {
+    for (arg, matcher) in args.into_iter().zip(data.data.matchers.iter()) {
+        match arg {
+            RawArg::Immediate { value } => {
+                data.args.push(FlatArg::Immediate { value });
+            },
+            RawArg::JumpTarget { jump } => {
+                data.args.push(FlatArg::JumpTarget { jump });
+            },
+            RawArg::Register { span, reg } => {
+                data.args.push(FlatArg::Register { span, reg });
+            },
+            RawArg::Reference { span, offset, base } => match matcher {
+                Matcher::RefOffset => {
+                    data.args.push(FlatArg::Register { span, reg: base });
+                    if let Some(offset) = offset {
+                        data.args.push(FlatArg::Immediate { value: offset });
+                    } else {
+                        data.args.push(FlatArg::Default);
+                    }
+                },
+                Matcher::Ref => {
+                    data.args.push(FlatArg::Register { span, reg: base });
+                },
+                _ => unreachable!("Expected reference")
+            },
+            RawArg::IndexedReference { span, base, index } => {
+                data.args.push(FlatArg::Register { span, reg: base });
+                data.args.push(FlatArg::Register { span, reg: index });
+            },
+            RawArg::LabelReference { span, jump, base } => {
+                data.args.push(FlatArg::Register { span, reg: base });
+                data.args.push(FlatArg::JumpTarget { jump });
+            },
+        }
+    }
+}
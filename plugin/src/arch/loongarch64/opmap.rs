@@ -0,0 +1,1442 @@
+Ops!(
+
+
+// Integer arithmetic and logic
+
+"add.d" = [
+    // add.d rd, rj, rk
+    Single(0x00108000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"add.w" = [
+    // add.w rd, rj, rk
+    Single(0x00100000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"addi.d" = [
+    // addi.d rd, rj, si12
+    Single(0x02C00000), [R, R, Imm] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // addi.d rd, rj, lo12(label)
+    Single(0x02C00000), [R, R, Offset] => [R(0), R(5), Offset(LO12)];
+],
+"addi.w" = [
+    // addi.w rd, rj, si12
+    Single(0x02800000), [R, R, Imm] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // addi.w rd, rj, lo12(label)
+    Single(0x02800000), [R, R, Offset] => [R(0), R(5), Offset(LO12)];
+],
+"addu16i.d" = [
+    // addu16i.d rd, rj, si16 << 16
+    Single(0x10000000), [R, R, Imm] => [R(0), R(5), SImm(32, 16), BitRange(10, 16, 16), Next];
+],
+"alsl.d" = [
+    // alsl.d rd, rj, rk, sa
+    Single(0x002C0000), [R, R, R, Imm] => [R(0), R(5), R(10), ShiftAmount(15, 2)];
+],
+"alsl.w" = [
+    // alsl.w rd, rj, rk, sa
+    Single(0x00040000), [R, R, R, Imm] => [R(0), R(5), R(10), ShiftAmount(15, 2)];
+],
+"alsl.wu" = [
+    // alsl.wu rd, rj, rk, sa
+    Single(0x00060000), [R, R, R, Imm] => [R(0), R(5), R(10), ShiftAmount(15, 2)];
+],
+"and" = [
+    // and rd, rj, rk
+    Single(0x00148000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"andi" = [
+    // andi rd, rj, ui12
+    Single(0x03400000), [R, R, Imm] => [R(0), R(5), UImm(12, 0), BitRange(10, 12, 0), Next];
+],
+"andn" = [
+    // andn rd, rj, rk
+    Single(0x00168000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"asrtgt.d" = [
+    // asrtgt.d rj, rk
+    Single(0x00018000), [R, R] => [R(5), R(10)];
+],
+"asrtle.d" = [
+    // asrtle.d rj, rk
+    Single(0x00010000), [R, R] => [R(5), R(10)];
+],
+"bitrev.4b" = [
+    // bitrev.4b rd, rj
+    Single(0x00004800), [R, R] => [R(0), R(5)];
+],
+"bitrev.8b" = [
+    // bitrev.8b rd, rj
+    Single(0x00004C00), [R, R] => [R(0), R(5)];
+],
+"bitrev.d" = [
+    // bitrev.d rd, rj
+    Single(0x00005400), [R, R] => [R(0), R(5)];
+],
+"bitrev.w" = [
+    // bitrev.w rd, rj
+    Single(0x00005000), [R, R] => [R(0), R(5)];
+],
+"bstrins.d" = [
+    // bstrins.d rd, rj, msb, lsb
+    Single(0x00800000), [R, R, Imm, Imm] => [R(0), R(5), UImm(6, 0), BitRange(16, 6, 0), Next, UImm(6, 0), BitRange(10, 6, 0), Next];
+],
+"bstrins.w" = [
+    // bstrins.w rd, rj, msb, lsb
+    Single(0x00600000), [R, R, Imm, Imm] => [R(0), R(5), UImm(5, 0), BitRange(16, 5, 0), Next, UImm(5, 0), BitRange(10, 5, 0), Next];
+],
+"bstrpick.d" = [
+    // bstrpick.d rd, rj, msb, lsb
+    Single(0x00C00000), [R, R, Imm, Imm] => [R(0), R(5), UImm(6, 0), BitRange(16, 6, 0), Next, UImm(6, 0), BitRange(10, 6, 0), Next];
+],
+"bstrpick.w" = [
+    // bstrpick.w rd, rj, msb, lsb
+    Single(0x00608000), [R, R, Imm, Imm] => [R(0), R(5), UImm(5, 0), BitRange(16, 5, 0), Next, UImm(5, 0), BitRange(10, 5, 0), Next];
+],
+"bytepick.d" = [
+    // bytepick.d rd, rj, rk, sa
+    Single(0x000C0000), [R, R, R, Imm] => [R(0), R(5), R(10), UImm(3, 0), BitRange(15, 3, 0), Next];
+],
+"bytepick.w" = [
+    // bytepick.w rd, rj, rk, sa
+    Single(0x00080000), [R, R, R, Imm] => [R(0), R(5), R(10), UImm(2, 0), BitRange(15, 2, 0), Next];
+],
+"clo.d" = [
+    // clo.d rd, rj
+    Single(0x00002000), [R, R] => [R(0), R(5)];
+],
+"clo.w" = [
+    // clo.w rd, rj
+    Single(0x00001000), [R, R] => [R(0), R(5)];
+],
+"clz.d" = [
+    // clz.d rd, rj
+    Single(0x00002400), [R, R] => [R(0), R(5)];
+],
+"clz.w" = [
+    // clz.w rd, rj
+    Single(0x00001400), [R, R] => [R(0), R(5)];
+],
+"cpucfg" = [
+    // cpucfg rd, rj
+    Single(0x00006C00), [R, R] => [R(0), R(5)];
+],
+"crc.w.b.w" = [
+    // crc.w.b.w rd, rj, rk
+    Single(0x00240000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"crc.w.d.w" = [
+    // crc.w.d.w rd, rj, rk
+    Single(0x00258000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"crc.w.h.w" = [
+    // crc.w.h.w rd, rj, rk
+    Single(0x00248000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"crc.w.w.w" = [
+    // crc.w.w.w rd, rj, rk
+    Single(0x00250000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"crcc.w.b.w" = [
+    // crcc.w.b.w rd, rj, rk
+    Single(0x00260000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"crcc.w.d.w" = [
+    // crcc.w.d.w rd, rj, rk
+    Single(0x00278000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"crcc.w.h.w" = [
+    // crcc.w.h.w rd, rj, rk
+    Single(0x00268000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"crcc.w.w.w" = [
+    // crcc.w.w.w rd, rj, rk
+    Single(0x00270000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"cto.d" = [
+    // cto.d rd, rj
+    Single(0x00002800), [R, R] => [R(0), R(5)];
+],
+"cto.w" = [
+    // cto.w rd, rj
+    Single(0x00001800), [R, R] => [R(0), R(5)];
+],
+"ctz.d" = [
+    // ctz.d rd, rj
+    Single(0x00002C00), [R, R] => [R(0), R(5)];
+],
+"ctz.w" = [
+    // ctz.w rd, rj
+    Single(0x00001C00), [R, R] => [R(0), R(5)];
+],
+"div.d" = [
+    // div.d rd, rj, rk
+    Single(0x00220000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"div.du" = [
+    // div.du rd, rj, rk
+    Single(0x00230000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"div.w" = [
+    // div.w rd, rj, rk
+    Single(0x00200000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"div.wu" = [
+    // div.wu rd, rj, rk
+    Single(0x00210000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"ext.w.b" = [
+    // ext.w.b rd, rj
+    Single(0x00005C00), [R, R] => [R(0), R(5)];
+],
+"ext.w.h" = [
+    // ext.w.h rd, rj
+    Single(0x00005800), [R, R] => [R(0), R(5)];
+],
+"lu12i.w" = [
+    // lu12i.w rd, si20 << 12
+    Single(0x14000000), [R, Imm] => [R(0), SImm(32, 12), BitRange(5, 20, 12), Next];
+],
+"lu32i.d" = [
+    // lu32i.d rd, si20
+    Single(0x16000000), [R, Imm] => [R(0), SImm(20, 0), BitRange(5, 20, 0), Next];
+],
+"lu52i.d" = [
+    // lu52i.d rd, rj, si12
+    Single(0x03000000), [R, R, Imm] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+],
+"maskeqz" = [
+    // maskeqz rd, rj, rk
+    Single(0x00130000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"masknez" = [
+    // masknez rd, rj, rk
+    Single(0x00138000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"mod.d" = [
+    // mod.d rd, rj, rk
+    Single(0x00228000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"mod.du" = [
+    // mod.du rd, rj, rk
+    Single(0x00238000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"mod.w" = [
+    // mod.w rd, rj, rk
+    Single(0x00208000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"mod.wu" = [
+    // mod.wu rd, rj, rk
+    Single(0x00218000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"mul.d" = [
+    // mul.d rd, rj, rk
+    Single(0x001D8000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"mul.w" = [
+    // mul.w rd, rj, rk
+    Single(0x001C0000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"mulh.d" = [
+    // mulh.d rd, rj, rk
+    Single(0x001E0000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"mulh.du" = [
+    // mulh.du rd, rj, rk
+    Single(0x001E8000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"mulh.w" = [
+    // mulh.w rd, rj, rk
+    Single(0x001C8000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"mulh.wu" = [
+    // mulh.wu rd, rj, rk
+    Single(0x001D0000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"mulw.d.w" = [
+    // mulw.d.w rd, rj, rk
+    Single(0x001F0000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"mulw.d.wu" = [
+    // mulw.d.wu rd, rj, rk
+    Single(0x001F8000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"nor" = [
+    // nor rd, rj, rk
+    Single(0x00140000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"or" = [
+    // or rd, rj, rk
+    Single(0x00150000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"ori" = [
+    // ori rd, rj, ui12
+    Single(0x03800000), [R, R, Imm] => [R(0), R(5), UImm(12, 0), BitRange(10, 12, 0), Next];
+],
+"orn" = [
+    // orn rd, rj, rk
+    Single(0x00160000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"pcaddi" = [
+    // pcaddi rd, si20 << 2
+    Single(0x18000000), [R, Imm] => [R(0), SImm(22, 2), BitRange(5, 20, 2), Next];
+],
+"pcaddu12i" = [
+    // pcaddu12i rd, hi20(offset)
+    Single(0x1C000000), [R, Offset] => [R(0), Offset(HI20)];
+],
+"pcaddu18i" = [
+    // pcaddu18i rd, si20
+    Single(0x1E000000), [R, Imm] => [R(0), SImm(20, 0), BitRange(5, 20, 0), Next];
+],
+"pcalau12i" = [
+    // pcalau12i rd, si20 << 12
+    Single(0x1A000000), [R, Imm] => [R(0), SImm(32, 12), BitRange(5, 20, 12), Next];
+],
+"rdtime.d" = [
+    // rdtime.d rd, rj
+    Single(0x00006800), [R, R] => [R(0), R(5)];
+],
+"rdtimeh.w" = [
+    // rdtimeh.w rd, rj
+    Single(0x00006400), [R, R] => [R(0), R(5)];
+],
+"rdtimel.w" = [
+    // rdtimel.w rd, rj
+    Single(0x00006000), [R, R] => [R(0), R(5)];
+],
+"revb.2h" = [
+    // revb.2h rd, rj
+    Single(0x00003000), [R, R] => [R(0), R(5)];
+],
+"revb.2w" = [
+    // revb.2w rd, rj
+    Single(0x00003800), [R, R] => [R(0), R(5)];
+],
+"revb.4h" = [
+    // revb.4h rd, rj
+    Single(0x00003400), [R, R] => [R(0), R(5)];
+],
+"revb.d" = [
+    // revb.d rd, rj
+    Single(0x00003C00), [R, R] => [R(0), R(5)];
+],
+"revh.2w" = [
+    // revh.2w rd, rj
+    Single(0x00004000), [R, R] => [R(0), R(5)];
+],
+"revh.d" = [
+    // revh.d rd, rj
+    Single(0x00004400), [R, R] => [R(0), R(5)];
+],
+"rotr.d" = [
+    // rotr.d rd, rj, rk
+    Single(0x001B8000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"rotr.w" = [
+    // rotr.w rd, rj, rk
+    Single(0x001B0000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"rotri.d" = [
+    // rotri.d rd, rj, ui6
+    Single(0x004D0000), [R, R, Imm] => [R(0), R(5), UImm(6, 0), BitRange(10, 6, 0), Next];
+],
+"rotri.w" = [
+    // rotri.w rd, rj, ui5
+    Single(0x004C8000), [R, R, Imm] => [R(0), R(5), UImm(5, 0), BitRange(10, 5, 0), Next];
+],
+"sll.d" = [
+    // sll.d rd, rj, rk
+    Single(0x00188000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"sll.w" = [
+    // sll.w rd, rj, rk
+    Single(0x00170000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"slli.d" = [
+    // slli.d rd, rj, ui6
+    Single(0x00410000), [R, R, Imm] => [R(0), R(5), UImm(6, 0), BitRange(10, 6, 0), Next];
+],
+"slli.w" = [
+    // slli.w rd, rj, ui5
+    Single(0x00408000), [R, R, Imm] => [R(0), R(5), UImm(5, 0), BitRange(10, 5, 0), Next];
+],
+"slt" = [
+    // slt rd, rj, rk
+    Single(0x00120000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"slti" = [
+    // slti rd, rj, si12
+    Single(0x02000000), [R, R, Imm] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+],
+"sltu" = [
+    // sltu rd, rj, rk
+    Single(0x00128000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"sltui" = [
+    // sltui rd, rj, si12
+    Single(0x02400000), [R, R, Imm] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+],
+"sra.d" = [
+    // sra.d rd, rj, rk
+    Single(0x00198000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"sra.w" = [
+    // sra.w rd, rj, rk
+    Single(0x00180000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"srai.d" = [
+    // srai.d rd, rj, ui6
+    Single(0x00490000), [R, R, Imm] => [R(0), R(5), UImm(6, 0), BitRange(10, 6, 0), Next];
+],
+"srai.w" = [
+    // srai.w rd, rj, ui5
+    Single(0x00488000), [R, R, Imm] => [R(0), R(5), UImm(5, 0), BitRange(10, 5, 0), Next];
+],
+"srl.d" = [
+    // srl.d rd, rj, rk
+    Single(0x00190000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"srl.w" = [
+    // srl.w rd, rj, rk
+    Single(0x00178000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"srli.d" = [
+    // srli.d rd, rj, ui6
+    Single(0x00450000), [R, R, Imm] => [R(0), R(5), UImm(6, 0), BitRange(10, 6, 0), Next];
+],
+"srli.w" = [
+    // srli.w rd, rj, ui5
+    Single(0x00448000), [R, R, Imm] => [R(0), R(5), UImm(5, 0), BitRange(10, 5, 0), Next];
+],
+"sub.d" = [
+    // sub.d rd, rj, rk
+    Single(0x00118000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"sub.w" = [
+    // sub.w rd, rj, rk
+    Single(0x00110000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"xor" = [
+    // xor rd, rj, rk
+    Single(0x00158000), [R, R, R] => [R(0), R(5), R(10)];
+],
+"xori" = [
+    // xori rd, rj, ui12
+    Single(0x03C00000), [R, R, Imm] => [R(0), R(5), UImm(12, 0), BitRange(10, 12, 0), Next];
+],
+
+
+// Loads and stores
+
+"fld.d" = [
+    // fld.d fd, rj, si12
+    Single(0x2B800000), [F, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // fld.d fd, rj, lo12(label)
+    Single(0x2B800000), [F, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"fld.s" = [
+    // fld.s fd, rj, si12
+    Single(0x2B000000), [F, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // fld.s fd, rj, lo12(label)
+    Single(0x2B000000), [F, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"fldx.d" = [
+    // fldx.d fd, rj, rk
+    Single(0x38340000), [F, RefIndex] => [R(0), R(5), R(10)];
+],
+"fldx.s" = [
+    // fldx.s fd, rj, rk
+    Single(0x38300000), [F, RefIndex] => [R(0), R(5), R(10)];
+],
+"fst.d" = [
+    // fst.d fd, rj, si12
+    Single(0x2BC00000), [F, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // fst.d fd, rj, lo12(label)
+    Single(0x2BC00000), [F, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"fst.s" = [
+    // fst.s fd, rj, si12
+    Single(0x2B400000), [F, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // fst.s fd, rj, lo12(label)
+    Single(0x2B400000), [F, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"fstx.d" = [
+    // fstx.d fd, rj, rk
+    Single(0x383C0000), [F, RefIndex] => [R(0), R(5), R(10)];
+],
+"fstx.s" = [
+    // fstx.s fd, rj, rk
+    Single(0x38380000), [F, RefIndex] => [R(0), R(5), R(10)];
+],
+"ld.b" = [
+    // ld.b rd, rj, si12
+    Single(0x28000000), [R, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // ld.b rd, rj, lo12(label)
+    Single(0x28000000), [R, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"ld.bu" = [
+    // ld.bu rd, rj, si12
+    Single(0x2A000000), [R, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // ld.bu rd, rj, lo12(label)
+    Single(0x2A000000), [R, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"ld.d" = [
+    // ld.d rd, rj, si12
+    Single(0x28C00000), [R, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // ld.d rd, rj, lo12(label)
+    Single(0x28C00000), [R, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"ld.h" = [
+    // ld.h rd, rj, si12
+    Single(0x28400000), [R, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // ld.h rd, rj, lo12(label)
+    Single(0x28400000), [R, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"ld.hu" = [
+    // ld.hu rd, rj, si12
+    Single(0x2A400000), [R, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // ld.hu rd, rj, lo12(label)
+    Single(0x2A400000), [R, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"ld.w" = [
+    // ld.w rd, rj, si12
+    Single(0x28800000), [R, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // ld.w rd, rj, lo12(label)
+    Single(0x28800000), [R, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"ld.wu" = [
+    // ld.wu rd, rj, si12
+    Single(0x2A800000), [R, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // ld.wu rd, rj, lo12(label)
+    Single(0x2A800000), [R, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"ldptr.d" = [
+    // ldptr.d rd, rj, si14 << 2
+    Single(0x26000000), [R, RefOffset] => [R(0), R(5), SImm(16, 2), BitRange(10, 14, 2), Next];
+],
+"ldptr.w" = [
+    // ldptr.w rd, rj, si14 << 2
+    Single(0x24000000), [R, RefOffset] => [R(0), R(5), SImm(16, 2), BitRange(10, 14, 2), Next];
+],
+"ldx.b" = [
+    // ldx.b rd, rj, rk
+    Single(0x38000000), [R, RefIndex] => [R(0), R(5), R(10)];
+],
+"ldx.bu" = [
+    // ldx.bu rd, rj, rk
+    Single(0x38200000), [R, RefIndex] => [R(0), R(5), R(10)];
+],
+"ldx.d" = [
+    // ldx.d rd, rj, rk
+    Single(0x380C0000), [R, RefIndex] => [R(0), R(5), R(10)];
+],
+"ldx.h" = [
+    // ldx.h rd, rj, rk
+    Single(0x38040000), [R, RefIndex] => [R(0), R(5), R(10)];
+],
+"ldx.hu" = [
+    // ldx.hu rd, rj, rk
+    Single(0x38240000), [R, RefIndex] => [R(0), R(5), R(10)];
+],
+"ldx.w" = [
+    // ldx.w rd, rj, rk
+    Single(0x38080000), [R, RefIndex] => [R(0), R(5), R(10)];
+],
+"ldx.wu" = [
+    // ldx.wu rd, rj, rk
+    Single(0x38280000), [R, RefIndex] => [R(0), R(5), R(10)];
+],
+"ll.d" = [
+    // ll.d rd, rj, si14 << 2
+    Single(0x22000000), [R, RefOffset] => [R(0), R(5), SImm(16, 2), BitRange(10, 14, 2), Next];
+],
+"ll.w" = [
+    // ll.w rd, rj, si14 << 2
+    Single(0x20000000), [R, RefOffset] => [R(0), R(5), SImm(16, 2), BitRange(10, 14, 2), Next];
+],
+"preld" = [
+    // preld hint, rj, si12
+    Single(0x2AC00000), [Imm, RefOffset] => [UImm(5, 0), BitRange(0, 5, 0), Next, R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+],
+"sc.d" = [
+    // sc.d rd, rj, si14 << 2
+    Single(0x23000000), [R, RefOffset] => [R(0), R(5), SImm(16, 2), BitRange(10, 14, 2), Next];
+],
+"sc.w" = [
+    // sc.w rd, rj, si14 << 2
+    Single(0x21000000), [R, RefOffset] => [R(0), R(5), SImm(16, 2), BitRange(10, 14, 2), Next];
+],
+"st.b" = [
+    // st.b rd, rj, si12
+    Single(0x29000000), [R, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // st.b rd, rj, lo12(label)
+    Single(0x29000000), [R, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"st.d" = [
+    // st.d rd, rj, si12
+    Single(0x29C00000), [R, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // st.d rd, rj, lo12(label)
+    Single(0x29C00000), [R, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"st.h" = [
+    // st.h rd, rj, si12
+    Single(0x29400000), [R, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // st.h rd, rj, lo12(label)
+    Single(0x29400000), [R, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"st.w" = [
+    // st.w rd, rj, si12
+    Single(0x29800000), [R, RefOffset] => [R(0), R(5), SImm(12, 0), BitRange(10, 12, 0), Next];
+    // st.w rd, rj, lo12(label)
+    Single(0x29800000), [R, RefLabel] => [R(0), R(5), Offset(LO12)];
+],
+"stptr.d" = [
+    // stptr.d rd, rj, si14 << 2
+    Single(0x27000000), [R, RefOffset] => [R(0), R(5), SImm(16, 2), BitRange(10, 14, 2), Next];
+],
+"stptr.w" = [
+    // stptr.w rd, rj, si14 << 2
+    Single(0x25000000), [R, RefOffset] => [R(0), R(5), SImm(16, 2), BitRange(10, 14, 2), Next];
+],
+"stx.b" = [
+    // stx.b rd, rj, rk
+    Single(0x38100000), [R, RefIndex] => [R(0), R(5), R(10)];
+],
+"stx.d" = [
+    // stx.d rd, rj, rk
+    Single(0x381C0000), [R, RefIndex] => [R(0), R(5), R(10)];
+],
+"stx.h" = [
+    // stx.h rd, rj, rk
+    Single(0x38140000), [R, RefIndex] => [R(0), R(5), R(10)];
+],
+"stx.w" = [
+    // stx.w rd, rj, rk
+    Single(0x38180000), [R, RefIndex] => [R(0), R(5), R(10)];
+],
+
+
+// Atomics and barriers
+
+"amadd.d" = [
+    // amadd.d rd, rk, rj
+    Single(0x38618000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amadd.w" = [
+    // amadd.w rd, rk, rj
+    Single(0x38610000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amadd_db.d" = [
+    // amadd_db.d rd, rk, rj
+    Single(0x386A8000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amadd_db.w" = [
+    // amadd_db.w rd, rk, rj
+    Single(0x386A0000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amand.d" = [
+    // amand.d rd, rk, rj
+    Single(0x38628000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amand.w" = [
+    // amand.w rd, rk, rj
+    Single(0x38620000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amand_db.d" = [
+    // amand_db.d rd, rk, rj
+    Single(0x386B8000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amand_db.w" = [
+    // amand_db.w rd, rk, rj
+    Single(0x386B0000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammax.d" = [
+    // ammax.d rd, rk, rj
+    Single(0x38658000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammax.du" = [
+    // ammax.du rd, rk, rj
+    Single(0x38678000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammax.w" = [
+    // ammax.w rd, rk, rj
+    Single(0x38650000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammax.wu" = [
+    // ammax.wu rd, rk, rj
+    Single(0x38670000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammax_db.d" = [
+    // ammax_db.d rd, rk, rj
+    Single(0x386E8000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammax_db.du" = [
+    // ammax_db.du rd, rk, rj
+    Single(0x38708000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammax_db.w" = [
+    // ammax_db.w rd, rk, rj
+    Single(0x386E0000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammax_db.wu" = [
+    // ammax_db.wu rd, rk, rj
+    Single(0x38700000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammin.d" = [
+    // ammin.d rd, rk, rj
+    Single(0x38668000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammin.du" = [
+    // ammin.du rd, rk, rj
+    Single(0x38688000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammin.w" = [
+    // ammin.w rd, rk, rj
+    Single(0x38660000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammin.wu" = [
+    // ammin.wu rd, rk, rj
+    Single(0x38680000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammin_db.d" = [
+    // ammin_db.d rd, rk, rj
+    Single(0x386F8000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammin_db.du" = [
+    // ammin_db.du rd, rk, rj
+    Single(0x38718000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammin_db.w" = [
+    // ammin_db.w rd, rk, rj
+    Single(0x386F0000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"ammin_db.wu" = [
+    // ammin_db.wu rd, rk, rj
+    Single(0x38710000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amor.d" = [
+    // amor.d rd, rk, rj
+    Single(0x38638000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amor.w" = [
+    // amor.w rd, rk, rj
+    Single(0x38630000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amor_db.d" = [
+    // amor_db.d rd, rk, rj
+    Single(0x386C8000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amor_db.w" = [
+    // amor_db.w rd, rk, rj
+    Single(0x386C0000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amswap.d" = [
+    // amswap.d rd, rk, rj
+    Single(0x38608000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amswap.w" = [
+    // amswap.w rd, rk, rj
+    Single(0x38600000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amswap_db.d" = [
+    // amswap_db.d rd, rk, rj
+    Single(0x38698000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amswap_db.w" = [
+    // amswap_db.w rd, rk, rj
+    Single(0x38690000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amxor.d" = [
+    // amxor.d rd, rk, rj
+    Single(0x38648000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amxor.w" = [
+    // amxor.w rd, rk, rj
+    Single(0x38640000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amxor_db.d" = [
+    // amxor_db.d rd, rk, rj
+    Single(0x386D8000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"amxor_db.w" = [
+    // amxor_db.w rd, rk, rj
+    Single(0x386D0000), [R, R, Ref] => [R(0), R(10), R(5)];
+],
+"dbar" = [
+    // dbar hint
+    Single(0x38720000), [Imm] => [UImm(15, 0), BitRange(0, 15, 0), Next];
+],
+"ibar" = [
+    // ibar hint
+    Single(0x38728000), [Imm] => [UImm(15, 0), BitRange(0, 15, 0), Next];
+],
+
+
+// Branches and system calls
+
+"b" = [
+    // b offs26
+    Single(0x50000000), [Offset] => [Offset(B26)];
+],
+"bceqz" = [
+    // bceqz cj, offs21
+    Single(0x48000000), [Fcc, Offset] => [C(5), Offset(B21)];
+],
+"bcnez" = [
+    // bcnez cj, offs21
+    Single(0x48000100), [Fcc, Offset] => [C(5), Offset(B21)];
+],
+"beq" = [
+    // beq rj, rd, offs16
+    Single(0x58000000), [R, R, Offset] => [R(5), R(0), Offset(B16)];
+],
+"beqz" = [
+    // beqz rj, offs21
+    Single(0x40000000), [R, Offset] => [R(5), Offset(B21)];
+],
+"bge" = [
+    // bge rj, rd, offs16
+    Single(0x64000000), [R, R, Offset] => [R(5), R(0), Offset(B16)];
+],
+"bgeu" = [
+    // bgeu rj, rd, offs16
+    Single(0x6C000000), [R, R, Offset] => [R(5), R(0), Offset(B16)];
+],
+"bl" = [
+    // bl offs26
+    Single(0x54000000), [Offset] => [Offset(B26)];
+],
+"blt" = [
+    // blt rj, rd, offs16
+    Single(0x60000000), [R, R, Offset] => [R(5), R(0), Offset(B16)];
+],
+"bltu" = [
+    // bltu rj, rd, offs16
+    Single(0x68000000), [R, R, Offset] => [R(5), R(0), Offset(B16)];
+],
+"bne" = [
+    // bne rj, rd, offs16
+    Single(0x5C000000), [R, R, Offset] => [R(5), R(0), Offset(B16)];
+],
+"bnez" = [
+    // bnez rj, offs21
+    Single(0x44000000), [R, Offset] => [R(5), Offset(B21)];
+],
+"break" = [
+    // break code
+    Single(0x002A0000), [Imm] => [UImm(15, 0), BitRange(0, 15, 0), Next];
+],
+"dbcl" = [
+    // dbcl code
+    Single(0x002A8000), [Imm] => [UImm(15, 0), BitRange(0, 15, 0), Next];
+],
+"jirl" = [
+    // jirl rd, rj, offs16
+    Single(0x4C000000), [R, R, Imm] => [R(0), R(5), SImm(18, 2), BitRange(10, 16, 2), Next];
+],
+"syscall" = [
+    // syscall code
+    Single(0x002B0000), [Imm] => [UImm(15, 0), BitRange(0, 15, 0), Next];
+],
+
+
+// Floating point
+
+"fabs.d" = [
+    // fabs.d fd, fj
+    Single(0x01140800), [F, F] => [R(0), R(5)];
+],
+"fabs.s" = [
+    // fabs.s fd, fj
+    Single(0x01140400), [F, F] => [R(0), R(5)];
+],
+"fadd.d" = [
+    // fadd.d fd, fj, fk
+    Single(0x01010000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fadd.s" = [
+    // fadd.s fd, fj, fk
+    Single(0x01008000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fclass.d" = [
+    // fclass.d fd, fj
+    Single(0x01143800), [F, F] => [R(0), R(5)];
+],
+"fclass.s" = [
+    // fclass.s fd, fj
+    Single(0x01143400), [F, F] => [R(0), R(5)];
+],
+"fcmp.caf.d" = [
+    // fcmp.caf.d cd, fj, fk
+    Single(0x0C200000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.caf.s" = [
+    // fcmp.caf.s cd, fj, fk
+    Single(0x0C100000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.ceq.d" = [
+    // fcmp.ceq.d cd, fj, fk
+    Single(0x0C220000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.ceq.s" = [
+    // fcmp.ceq.s cd, fj, fk
+    Single(0x0C120000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cle.d" = [
+    // fcmp.cle.d cd, fj, fk
+    Single(0x0C230000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cle.s" = [
+    // fcmp.cle.s cd, fj, fk
+    Single(0x0C130000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.clt.d" = [
+    // fcmp.clt.d cd, fj, fk
+    Single(0x0C210000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.clt.s" = [
+    // fcmp.clt.s cd, fj, fk
+    Single(0x0C110000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cne.d" = [
+    // fcmp.cne.d cd, fj, fk
+    Single(0x0C280000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cne.s" = [
+    // fcmp.cne.s cd, fj, fk
+    Single(0x0C180000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cor.d" = [
+    // fcmp.cor.d cd, fj, fk
+    Single(0x0C2A0000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cor.s" = [
+    // fcmp.cor.s cd, fj, fk
+    Single(0x0C1A0000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cueq.d" = [
+    // fcmp.cueq.d cd, fj, fk
+    Single(0x0C260000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cueq.s" = [
+    // fcmp.cueq.s cd, fj, fk
+    Single(0x0C160000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cule.d" = [
+    // fcmp.cule.d cd, fj, fk
+    Single(0x0C270000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cule.s" = [
+    // fcmp.cule.s cd, fj, fk
+    Single(0x0C170000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cult.d" = [
+    // fcmp.cult.d cd, fj, fk
+    Single(0x0C250000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cult.s" = [
+    // fcmp.cult.s cd, fj, fk
+    Single(0x0C150000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cun.d" = [
+    // fcmp.cun.d cd, fj, fk
+    Single(0x0C240000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cun.s" = [
+    // fcmp.cun.s cd, fj, fk
+    Single(0x0C140000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cune.d" = [
+    // fcmp.cune.d cd, fj, fk
+    Single(0x0C2C0000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.cune.s" = [
+    // fcmp.cune.s cd, fj, fk
+    Single(0x0C1C0000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.saf.d" = [
+    // fcmp.saf.d cd, fj, fk
+    Single(0x0C208000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.saf.s" = [
+    // fcmp.saf.s cd, fj, fk
+    Single(0x0C108000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.seq.d" = [
+    // fcmp.seq.d cd, fj, fk
+    Single(0x0C228000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.seq.s" = [
+    // fcmp.seq.s cd, fj, fk
+    Single(0x0C128000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sle.d" = [
+    // fcmp.sle.d cd, fj, fk
+    Single(0x0C238000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sle.s" = [
+    // fcmp.sle.s cd, fj, fk
+    Single(0x0C138000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.slt.d" = [
+    // fcmp.slt.d cd, fj, fk
+    Single(0x0C218000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.slt.s" = [
+    // fcmp.slt.s cd, fj, fk
+    Single(0x0C118000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sne.d" = [
+    // fcmp.sne.d cd, fj, fk
+    Single(0x0C288000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sne.s" = [
+    // fcmp.sne.s cd, fj, fk
+    Single(0x0C188000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sor.d" = [
+    // fcmp.sor.d cd, fj, fk
+    Single(0x0C2A8000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sor.s" = [
+    // fcmp.sor.s cd, fj, fk
+    Single(0x0C1A8000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sueq.d" = [
+    // fcmp.sueq.d cd, fj, fk
+    Single(0x0C268000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sueq.s" = [
+    // fcmp.sueq.s cd, fj, fk
+    Single(0x0C168000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sule.d" = [
+    // fcmp.sule.d cd, fj, fk
+    Single(0x0C278000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sule.s" = [
+    // fcmp.sule.s cd, fj, fk
+    Single(0x0C178000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sult.d" = [
+    // fcmp.sult.d cd, fj, fk
+    Single(0x0C258000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sult.s" = [
+    // fcmp.sult.s cd, fj, fk
+    Single(0x0C158000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sun.d" = [
+    // fcmp.sun.d cd, fj, fk
+    Single(0x0C248000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sun.s" = [
+    // fcmp.sun.s cd, fj, fk
+    Single(0x0C148000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sune.d" = [
+    // fcmp.sune.d cd, fj, fk
+    Single(0x0C2C8000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcmp.sune.s" = [
+    // fcmp.sune.s cd, fj, fk
+    Single(0x0C1C8000), [Fcc, F, F] => [C(0), R(5), R(10)];
+],
+"fcopysign.d" = [
+    // fcopysign.d fd, fj, fk
+    Single(0x01130000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fcopysign.s" = [
+    // fcopysign.s fd, fj, fk
+    Single(0x01128000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fcvt.d.s" = [
+    // fcvt.d.s fd, fj
+    Single(0x01192400), [F, F] => [R(0), R(5)];
+],
+"fcvt.s.d" = [
+    // fcvt.s.d fd, fj
+    Single(0x01191800), [F, F] => [R(0), R(5)];
+],
+"fdiv.d" = [
+    // fdiv.d fd, fj, fk
+    Single(0x01070000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fdiv.s" = [
+    // fdiv.s fd, fj, fk
+    Single(0x01068000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"ffint.d.l" = [
+    // ffint.d.l fd, fj
+    Single(0x011D2800), [F, F] => [R(0), R(5)];
+],
+"ffint.d.w" = [
+    // ffint.d.w fd, fj
+    Single(0x011D2000), [F, F] => [R(0), R(5)];
+],
+"ffint.s.l" = [
+    // ffint.s.l fd, fj
+    Single(0x011D1800), [F, F] => [R(0), R(5)];
+],
+"ffint.s.w" = [
+    // ffint.s.w fd, fj
+    Single(0x011D1000), [F, F] => [R(0), R(5)];
+],
+"flogb.d" = [
+    // flogb.d fd, fj
+    Single(0x01142800), [F, F] => [R(0), R(5)];
+],
+"flogb.s" = [
+    // flogb.s fd, fj
+    Single(0x01142400), [F, F] => [R(0), R(5)];
+],
+"fmadd.d" = [
+    // fmadd.d fd, fj, fk, fa
+    Single(0x08200000), [F, F, F, F] => [R(0), R(5), R(10), R(15)];
+],
+"fmadd.s" = [
+    // fmadd.s fd, fj, fk, fa
+    Single(0x08100000), [F, F, F, F] => [R(0), R(5), R(10), R(15)];
+],
+"fmax.d" = [
+    // fmax.d fd, fj, fk
+    Single(0x01090000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fmax.s" = [
+    // fmax.s fd, fj, fk
+    Single(0x01088000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fmaxa.d" = [
+    // fmaxa.d fd, fj, fk
+    Single(0x010D0000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fmaxa.s" = [
+    // fmaxa.s fd, fj, fk
+    Single(0x010C8000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fmin.d" = [
+    // fmin.d fd, fj, fk
+    Single(0x010B0000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fmin.s" = [
+    // fmin.s fd, fj, fk
+    Single(0x010A8000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fmina.d" = [
+    // fmina.d fd, fj, fk
+    Single(0x010F0000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fmina.s" = [
+    // fmina.s fd, fj, fk
+    Single(0x010E8000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fmov.d" = [
+    // fmov.d fd, fj
+    Single(0x01149800), [F, F] => [R(0), R(5)];
+],
+"fmov.s" = [
+    // fmov.s fd, fj
+    Single(0x01149400), [F, F] => [R(0), R(5)];
+],
+"fmsub.d" = [
+    // fmsub.d fd, fj, fk, fa
+    Single(0x08600000), [F, F, F, F] => [R(0), R(5), R(10), R(15)];
+],
+"fmsub.s" = [
+    // fmsub.s fd, fj, fk, fa
+    Single(0x08500000), [F, F, F, F] => [R(0), R(5), R(10), R(15)];
+],
+"fmul.d" = [
+    // fmul.d fd, fj, fk
+    Single(0x01050000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fmul.s" = [
+    // fmul.s fd, fj, fk
+    Single(0x01048000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fneg.d" = [
+    // fneg.d fd, fj
+    Single(0x01141800), [F, F] => [R(0), R(5)];
+],
+"fneg.s" = [
+    // fneg.s fd, fj
+    Single(0x01141400), [F, F] => [R(0), R(5)];
+],
+"fnmadd.d" = [
+    // fnmadd.d fd, fj, fk, fa
+    Single(0x08A00000), [F, F, F, F] => [R(0), R(5), R(10), R(15)];
+],
+"fnmadd.s" = [
+    // fnmadd.s fd, fj, fk, fa
+    Single(0x08900000), [F, F, F, F] => [R(0), R(5), R(10), R(15)];
+],
+"fnmsub.d" = [
+    // fnmsub.d fd, fj, fk, fa
+    Single(0x08E00000), [F, F, F, F] => [R(0), R(5), R(10), R(15)];
+],
+"fnmsub.s" = [
+    // fnmsub.s fd, fj, fk, fa
+    Single(0x08D00000), [F, F, F, F] => [R(0), R(5), R(10), R(15)];
+],
+"frecip.d" = [
+    // frecip.d fd, fj
+    Single(0x01145800), [F, F] => [R(0), R(5)];
+],
+"frecip.s" = [
+    // frecip.s fd, fj
+    Single(0x01145400), [F, F] => [R(0), R(5)];
+],
+"frint.d" = [
+    // frint.d fd, fj
+    Single(0x011E4800), [F, F] => [R(0), R(5)];
+],
+"frint.s" = [
+    // frint.s fd, fj
+    Single(0x011E4400), [F, F] => [R(0), R(5)];
+],
+"frsqrt.d" = [
+    // frsqrt.d fd, fj
+    Single(0x01146800), [F, F] => [R(0), R(5)];
+],
+"frsqrt.s" = [
+    // frsqrt.s fd, fj
+    Single(0x01146400), [F, F] => [R(0), R(5)];
+],
+"fscaleb.d" = [
+    // fscaleb.d fd, fj, fk
+    Single(0x01110000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fscaleb.s" = [
+    // fscaleb.s fd, fj, fk
+    Single(0x01108000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fsel" = [
+    // fsel fd, fj, fk, ca
+    Single(0x0D000000), [F, F, F, Fcc] => [R(0), R(5), R(10), C(15)];
+],
+"fsqrt.d" = [
+    // fsqrt.d fd, fj
+    Single(0x01144800), [F, F] => [R(0), R(5)];
+],
+"fsqrt.s" = [
+    // fsqrt.s fd, fj
+    Single(0x01144400), [F, F] => [R(0), R(5)];
+],
+"fsub.d" = [
+    // fsub.d fd, fj, fk
+    Single(0x01030000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"fsub.s" = [
+    // fsub.s fd, fj, fk
+    Single(0x01028000), [F, F, F] => [R(0), R(5), R(10)];
+],
+"ftint.l.d" = [
+    // ftint.l.d fd, fj
+    Single(0x011B2800), [F, F] => [R(0), R(5)];
+],
+"ftint.l.s" = [
+    // ftint.l.s fd, fj
+    Single(0x011B2400), [F, F] => [R(0), R(5)];
+],
+"ftint.w.d" = [
+    // ftint.w.d fd, fj
+    Single(0x011B0800), [F, F] => [R(0), R(5)];
+],
+"ftint.w.s" = [
+    // ftint.w.s fd, fj
+    Single(0x011B0400), [F, F] => [R(0), R(5)];
+],
+"ftintrm.l.d" = [
+    // ftintrm.l.d fd, fj
+    Single(0x011A2800), [F, F] => [R(0), R(5)];
+],
+"ftintrm.l.s" = [
+    // ftintrm.l.s fd, fj
+    Single(0x011A2400), [F, F] => [R(0), R(5)];
+],
+"ftintrm.w.d" = [
+    // ftintrm.w.d fd, fj
+    Single(0x011A0800), [F, F] => [R(0), R(5)];
+],
+"ftintrm.w.s" = [
+    // ftintrm.w.s fd, fj
+    Single(0x011A0400), [F, F] => [R(0), R(5)];
+],
+"ftintrne.l.d" = [
+    // ftintrne.l.d fd, fj
+    Single(0x011AE800), [F, F] => [R(0), R(5)];
+],
+"ftintrne.l.s" = [
+    // ftintrne.l.s fd, fj
+    Single(0x011AE400), [F, F] => [R(0), R(5)];
+],
+"ftintrne.w.d" = [
+    // ftintrne.w.d fd, fj
+    Single(0x011AC800), [F, F] => [R(0), R(5)];
+],
+"ftintrne.w.s" = [
+    // ftintrne.w.s fd, fj
+    Single(0x011AC400), [F, F] => [R(0), R(5)];
+],
+"ftintrp.l.d" = [
+    // ftintrp.l.d fd, fj
+    Single(0x011A6800), [F, F] => [R(0), R(5)];
+],
+"ftintrp.l.s" = [
+    // ftintrp.l.s fd, fj
+    Single(0x011A6400), [F, F] => [R(0), R(5)];
+],
+"ftintrp.w.d" = [
+    // ftintrp.w.d fd, fj
+    Single(0x011A4800), [F, F] => [R(0), R(5)];
+],
+"ftintrp.w.s" = [
+    // ftintrp.w.s fd, fj
+    Single(0x011A4400), [F, F] => [R(0), R(5)];
+],
+"ftintrz.l.d" = [
+    // ftintrz.l.d fd, fj
+    Single(0x011AA800), [F, F] => [R(0), R(5)];
+],
+"ftintrz.l.s" = [
+    // ftintrz.l.s fd, fj
+    Single(0x011AA400), [F, F] => [R(0), R(5)];
+],
+"ftintrz.w.d" = [
+    // ftintrz.w.d fd, fj
+    Single(0x011A8800), [F, F] => [R(0), R(5)];
+],
+"ftintrz.w.s" = [
+    // ftintrz.w.s fd, fj
+    Single(0x011A8400), [F, F] => [R(0), R(5)];
+],
+"movcf2fr" = [
+    // movcf2fr fd, cj
+    Single(0x0114D400), [F, Fcc] => [R(0), C(5)];
+],
+"movcf2gr" = [
+    // movcf2gr rd, cj
+    Single(0x0114DC00), [R, Fcc] => [R(0), C(5)];
+],
+"movfcsr2gr" = [
+    // movfcsr2gr rd, fcsr
+    Single(0x0114C800), [R, Fcsr] => [R(0), R(5)];
+],
+"movfr2cf" = [
+    // movfr2cf cd, fj
+    Single(0x0114D000), [Fcc, F] => [C(0), R(5)];
+],
+"movfr2gr.d" = [
+    // movfr2gr.d rd, fj
+    Single(0x0114B800), [R, F] => [R(0), R(5)];
+],
+"movfr2gr.s" = [
+    // movfr2gr.s rd, fj
+    Single(0x0114B400), [R, F] => [R(0), R(5)];
+],
+"movfrh2gr.s" = [
+    // movfrh2gr.s rd, fj
+    Single(0x0114BC00), [R, F] => [R(0), R(5)];
+],
+"movgr2cf" = [
+    // movgr2cf cd, rj
+    Single(0x0114D800), [Fcc, R] => [C(0), R(5)];
+],
+"movgr2fcsr" = [
+    // movgr2fcsr fcsr, rj
+    Single(0x0114C000), [Fcsr, R] => [R(0), R(5)];
+],
+"movgr2fr.d" = [
+    // movgr2fr.d fd, rj
+    Single(0x0114A800), [F, R] => [R(0), R(5)];
+],
+"movgr2fr.w" = [
+    // movgr2fr.w fd, rj
+    Single(0x0114A400), [F, R] => [R(0), R(5)];
+],
+"movgr2frh.w" = [
+    // movgr2frh.w fd, rj
+    Single(0x0114AC00), [F, R] => [R(0), R(5)];
+],
+
+
+// Pseudo instructions
+
+"bgez" = [
+    // alias for bge rj, zero, offs16
+    Single(0x64000000), [R, Offset] => [R(5), Offset(B16)];
+],
+"bgt" = [
+    // alias for blt rk, rj, offs16
+    Single(0x60000000), [R, R, Offset] => [R(0), R(5), Offset(B16)];
+],
+"bgtu" = [
+    // alias for bltu rk, rj, offs16
+    Single(0x68000000), [R, R, Offset] => [R(0), R(5), Offset(B16)];
+],
+"bgtz" = [
+    // alias for blt zero, rj, offs16
+    Single(0x60000000), [R, Offset] => [R(0), Offset(B16)];
+],
+"ble" = [
+    // alias for bge rk, rj, offs16
+    Single(0x64000000), [R, R, Offset] => [R(0), R(5), Offset(B16)];
+],
+"bleu" = [
+    // alias for bgeu rk, rj, offs16
+    Single(0x6C000000), [R, R, Offset] => [R(0), R(5), Offset(B16)];
+],
+"blez" = [
+    // alias for bge zero, rj, offs16
+    Single(0x64000000), [R, Offset] => [R(0), Offset(B16)];
+],
+"bltz" = [
+    // alias for blt rj, zero, offs16
+    Single(0x60000000), [R, Offset] => [R(5), Offset(B16)];
+],
+"jr" = [
+    // alias for jirl zero, rj, 0
+    Single(0x4C000000), [R] => [R(5)];
+],
+"la.pcrel" = [
+    // pseudo instruction for pcaddu12i rd, hi20(label); addi.d rd, rd, lo12(label)
+    Double(0x1C000000, 0x02C00000), [R, Offset] => [R(0), Repeat, R(32), Repeat, R(5+32), Offset(SPLIT32)];
+],
+"li.d" = [
+    // pseudo instruction for lu12i.w rd, imm >> 12; ori rd, rd, imm & 0xFFF; lu32i.d rd, imm >> 32; lu52i.d rd, rd, imm >> 52
+    Many(&[0x14000000, 0x03800000, 0x16000000, 0x03000000]), [R, Imm] => [
+        R(0),
+        Repeat, R(32), Repeat, R(5+32),
+        Repeat, R(64),
+        Repeat, R(96), Repeat, R(5+96),
+        BigImm(64),
+        BitRange(5, 20, 12),
+        BitRange(10+32, 12, 0),
+        BitRange(5+64, 20, 32),
+        BitRange(10+96, 12, 52),
+        Next
+    ];
+],
+"li.w" = [
+    // pseudo instruction for lu12i.w rd, imm >> 12; ori rd, rd, imm & 0xFFF
+    Double(0x14000000, 0x03800000), [R, Imm] => [
+        R(0),
+        Repeat, R(32), Repeat, R(5+32),
+        SImm(32, 0),
+        BitRange(5, 20, 12),
+        BitRange(10+32, 12, 0),
+        Next
+    ];
+],
+"move" = [
+    // alias for or rd, rj, zero
+    Single(0x00150000), [R, R] => [R(0), R(5)];
+],
+"nop" = [
+    // alias for andi zero, zero, 0
+    Single(0x03400000), [] => [];
+],
+"ret" = [
+    // alias for jirl zero, ra, 0
+    Single(0x4C000020), [] => [];
+],
+)
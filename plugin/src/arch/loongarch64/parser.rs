@@ -0,0 +1,446 @@
+use std::collections::HashMap;
+
+use syn::{parse, Token};
+use syn::spanned::Spanned;
+use proc_macro2::Span;
+
+
+use lazy_static::lazy_static;
+
+use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOptExt};
+use crate::serialize;
+
+use super::{Context, ast};
+
+// syntax for a single op: ident ("." ident)* (arg ("," arg)*)? ";"
+pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<ast::ParsedInstruction> {
+    let span = input.cursor().span();
+
+    // read the full dot-separated op
+    let mut name = parse_ident_or_rust_keyword(input)?.to_string();
+
+    while input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        name.push('.');
+
+        // element counts like revb.2h are parsed as suffixed integer literals
+        if input.peek(syn::LitInt) {
+            let number: syn::LitInt = input.parse()?;
+            name.push_str(&number.to_string());
+        } else {
+            name.push_str(&parse_ident_or_rust_keyword(input)?.to_string());
+        }
+    }
+
+    let mut args = Vec::new();
+
+    // parse 0 or more comma-separated args
+    if !(input.is_empty() || input.peek(Token![;])) {
+        args.push(parse_arg(ctx, input)?);
+
+        while input.peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+
+            args.push(parse_arg(ctx, input)?);
+        }
+    }
+
+    // let span = span.join(input.cursor().span()); // FIXME can't join spans ATM
+
+    Ok(ast::ParsedInstruction {
+        name,
+        span,
+        args
+    })
+}
+
+
+/// tries to parse a full arg definition
+fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<ast::RawArg> {
+    let start = input.cursor().span(); // FIXME can't join spans yet
+
+    // a label, identified by a leading < / > / -> / =>
+    if let Some(jump) = input.parse_opt()? {
+        return Ok(ast::RawArg::JumpTarget {
+            jump
+        });
+    }
+
+    // a memory reference. LoongArch assembly normally writes these as plain operands, but we use
+    // '[' base [ ',' offset | index | label ] ']' syntax to match the other architectures.
+    if input.peek(syn::token::Bracket) {
+        let span = input.cursor().span();
+        let inner;
+        let _ = syn::bracketed!(inner in input);
+        let inner = &inner;
+
+        let base = parse_reg(ctx, inner)?.ok_or_else(|| inner.error("Expected register"))?;
+
+        if inner.peek(Token![,]) {
+            let _: Token![,] = inner.parse()?;
+
+            if let Some(jump) = inner.parse_opt()? {
+                return Ok(ast::RawArg::LabelReference {
+                    span,
+                    base,
+                    jump
+                });
+
+            } else if let Some(index) = parse_reg(ctx, inner)? {
+                return Ok(ast::RawArg::IndexedReference {
+                    span,
+                    base,
+                    index
+                });
+
+            } else {
+
+                let expr: syn::Expr = inner.parse()?;
+
+                return Ok(ast::RawArg::Reference {
+                    span,
+                    base,
+                    offset: Some(expr)
+                });
+            }
+
+        } else {
+            return Ok(ast::RawArg::Reference {
+                span,
+                base,
+                offset: None
+            });
+        };
+    }
+
+    // a register, or a type mapped reference (reg => Type[index].attr)
+    if let Some(reg) = parse_reg(ctx, input)? {
+        if input.peek(Token![=>]) {
+            return parse_typemap(ctx, input, reg, start);
+        }
+
+        return Ok(ast::RawArg::Register {
+            reg,
+            span: start
+        })
+    }
+
+    // immediate
+    let expr: syn::Expr = input.parse()?;
+
+    Ok(ast::RawArg::Immediate { value: expr })
+}
+
+/// Parses the remainder of a type mapped reference, `base => Type ("[" index "]")? ("." attr)?`
+/// The index has to be an immediate. It is combined with the offset of the attribute into the
+/// reference offset.
+fn parse_typemap(ctx: &mut Context, input: parse::ParseStream, base: ast::Register, span: Span) -> parse::Result<ast::RawArg> {
+    let _: Token![=>] = input.parse()?;
+
+    let ty: syn::Path = input.parse()?;
+
+    let index = if input.peek(syn::token::Bracket) {
+        let inner;
+        let _ = syn::bracketed!(inner in input);
+        let inner = &inner;
+
+        let expr: syn::Expr = inner.parse()?;
+        if parse_reg_from_expression(ctx, &expr)?.is_some() {
+            return Err(parse::Error::new(expr.span(), "LoongArch type mapped references cannot use an index register"));
+        }
+
+        Some(expr)
+    } else {
+        None
+    };
+
+    let attr: Option<syn::Ident> = if input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        Some(input.parse()?)
+    } else {
+        None
+    };
+
+    let offset = match serialize::expr_typemap_disp(span, &ty, index.as_ref(), attr.as_ref()) {
+        Some(disp) => Some(serialize::reparse(&disp)?),
+        None => None
+    };
+
+    Ok(ast::RawArg::Reference {
+        span,
+        base,
+        offset
+    })
+}
+
+/// Checks if the given expression could be a valid LoongArch register reference
+/// This can be a simple register name (like `r5`)
+/// an alias (any simple name that is registered, like `base`)
+/// or a dynamic register (like `R(expr)`)
+fn parse_reg_from_expression(ctx: &mut Context, expr: &syn::Expr) -> parse::Result<Option<ast::Register>> {
+    Ok(match expr {
+        syn::Expr::Call(exprcall) => {
+            let name = match &*exprcall.func {
+                syn::Expr::Path(exprpath) => match exprpath.path.get_ident() {
+                    Some(ident) => ident.to_string(),
+                    None => return Ok(None)
+                }
+                _ => return Ok(None)
+            };
+
+            if exprcall.args.len() != 1 {
+                return Err(parse::Error::new(
+                    expr.span(),
+                    "Too many arguments in register family expression"
+                ));
+            }
+
+            if let Some(&family) = LOONGARCH_FAMILIES.get(&*name) {
+                Some(ast::Register::Dynamic(family, exprcall.args[0].clone()))
+            } else {
+                None
+            }
+        },
+        syn::Expr::Path(exprpath) => {
+            let mut name = match exprpath.path.get_ident() {
+                Some(ident) => ident.to_string(),
+                None => return Ok(None)
+            };
+
+            // fail if it is a family reference without call expression
+            if LOONGARCH_FAMILIES.contains_key(&*name) {
+                return Err(parse::Error::new(
+                    exprpath.path.span(),
+                    "Register family reference without dynamic register id"
+                ));
+            }
+
+            // check if it is an alias
+            if let Some(repl) = ctx.state.invocation_context.aliases.get(&name) {
+                name = repl.clone();
+            }
+
+            // resolve normal register references
+            LOONGARCH_REGISTERS.get(&*name).cloned().map(ast::Register::Static)
+        },
+        _ =>  None
+    })
+}
+
+/// Parses a single register, if present
+/// This can be a simple register name (like `r5`)
+/// an alias (any simple name that is registered, like `base`)
+/// or a dynamic register (like `R(expr)`)
+fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<ast::Register>> {
+    // we need to consume an ident, but only if it's one of the many we care about
+    // so use a step parser to figure it out.
+    let name = input.step(|cursor| {
+        if let Some((ident, rest)) = cursor.ident() {
+            let mut ident = ident.to_string();
+
+            // first, parse known register families
+            if LOONGARCH_FAMILIES.contains_key(&*ident) {
+                return Ok((ident, rest));
+            }
+
+            // otherwise, see if this is an alias
+            if let Some(repl) = ctx.state.invocation_context.aliases.get(&ident) {
+                ident = repl.clone();
+            }
+
+            // resolve normal register references
+            if LOONGARCH_REGISTERS.contains_key(&*ident) {
+                return Ok((ident, rest));
+            }
+        }
+        Err(cursor.error("expected identifier"))
+    });
+
+    let name = match name {
+        Ok(name) => name,
+        Err(_) => return Ok(None)
+    };
+
+    // we know we have a register reference now, try to resolve it.
+    let register = if let Some(&id) = LOONGARCH_REGISTERS.get(&*name) {
+        ast::Register::Static(id)
+    } else if let Some(&family) = LOONGARCH_FAMILIES.get(&*name) {
+        // need to parse the trailing `( expr )`
+        let inner;
+        let _ = syn::parenthesized!(inner in input);
+        let inner = &inner;
+
+        let expr: syn::Expr = inner.parse()?;
+
+        ast::Register::Dynamic(family, expr)
+    } else {
+        unreachable!()
+    };
+
+    Ok(Some(register))
+}
+
+
+lazy_static!{
+    static ref LOONGARCH_REGISTERS: HashMap<&'static str, ast::RegId> = {
+        use ast::RegId::*;
+
+        static MAP: &[(&str, ast::RegId)] = &[
+            ("r0" , R0),
+            ("r1" , R1),
+            ("r2" , R2),
+            ("r3" , R3),
+            ("r4" , R4),
+            ("r5" , R5),
+            ("r6" , R6),
+            ("r7" , R7),
+            ("r8" , R8),
+            ("r9" , R9),
+            ("r10", R10),
+            ("r11", R11),
+            ("r12", R12),
+            ("r13", R13),
+            ("r14", R14),
+            ("r15", R15),
+            ("r16", R16),
+            ("r17", R17),
+            ("r18", R18),
+            ("r19", R19),
+            ("r20", R20),
+            ("r21", R21),
+            ("r22", R22),
+            ("r23", R23),
+            ("r24", R24),
+            ("r25", R25),
+            ("r26", R26),
+            ("r27", R27),
+            ("r28", R28),
+            ("r29", R29),
+            ("r30", R30),
+            ("r31", R31),
+
+            ("zero", R0),
+            ("ra" , R1),
+            ("tp" , R2),
+            ("sp" , R3),
+            ("a0" , R4),
+            ("a1" , R5),
+            ("a2" , R6),
+            ("a3" , R7),
+            ("a4" , R8),
+            ("a5" , R9),
+            ("a6" , R10),
+            ("a7" , R11),
+            ("t0" , R12),
+            ("t1" , R13),
+            ("t2" , R14),
+            ("t3" , R15),
+            ("t4" , R16),
+            ("t5" , R17),
+            ("t6" , R18),
+            ("t7" , R19),
+            ("t8" , R20),
+            ("fp" , R22),
+            ("s9" , R22),
+            ("s0" , R23),
+            ("s1" , R24),
+            ("s2" , R25),
+            ("s3" , R26),
+            ("s4" , R27),
+            ("s5" , R28),
+            ("s6" , R29),
+            ("s7" , R30),
+            ("s8" , R31),
+
+            ("f0" , F0),
+            ("f1" , F1),
+            ("f2" , F2),
+            ("f3" , F3),
+            ("f4" , F4),
+            ("f5" , F5),
+            ("f6" , F6),
+            ("f7" , F7),
+            ("f8" , F8),
+            ("f9" , F9),
+            ("f10", F10),
+            ("f11", F11),
+            ("f12", F12),
+            ("f13", F13),
+            ("f14", F14),
+            ("f15", F15),
+            ("f16", F16),
+            ("f17", F17),
+            ("f18", F18),
+            ("f19", F19),
+            ("f20", F20),
+            ("f21", F21),
+            ("f22", F22),
+            ("f23", F23),
+            ("f24", F24),
+            ("f25", F25),
+            ("f26", F26),
+            ("f27", F27),
+            ("f28", F28),
+            ("f29", F29),
+            ("f30", F30),
+            ("f31", F31),
+
+            ("fa0" , F0),
+            ("fa1" , F1),
+            ("fa2" , F2),
+            ("fa3" , F3),
+            ("fa4" , F4),
+            ("fa5" , F5),
+            ("fa6" , F6),
+            ("fa7" , F7),
+            ("ft0" , F8),
+            ("ft1" , F9),
+            ("ft2", F10),
+            ("ft3", F11),
+            ("ft4", F12),
+            ("ft5", F13),
+            ("ft6", F14),
+            ("ft7", F15),
+            ("ft8", F16),
+            ("ft9", F17),
+            ("ft10", F18),
+            ("ft11", F19),
+            ("ft12", F20),
+            ("ft13", F21),
+            ("ft14", F22),
+            ("ft15", F23),
+            ("fs0", F24),
+            ("fs1", F25),
+            ("fs2", F26),
+            ("fs3", F27),
+            ("fs4", F28),
+            ("fs5", F29),
+            ("fs6", F30),
+            ("fs7", F31),
+
+            ("fcc0", FCC0),
+            ("fcc1", FCC1),
+            ("fcc2", FCC2),
+            ("fcc3", FCC3),
+            ("fcc4", FCC4),
+            ("fcc5", FCC5),
+            ("fcc6", FCC6),
+            ("fcc7", FCC7),
+
+            ("fcsr0", FCSR0),
+            ("fcsr1", FCSR1),
+            ("fcsr2", FCSR2),
+            ("fcsr3", FCSR3),
+        ];
+        MAP.iter().cloned().collect()
+    };
+
+    static ref LOONGARCH_FAMILIES: HashMap<&'static str, ast::RegFamily> = {
+        static MAP: &[(&str, ast::RegFamily)] = &[
+            ("R", ast::RegFamily::INTEGER),
+            ("F", ast::RegFamily::FP),
+            ("FCC", ast::RegFamily::FCC),
+        ];
+        MAP.iter().cloned().collect()
+    };
+}
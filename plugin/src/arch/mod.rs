@@ -9,6 +9,10 @@ use std::fmt::Debug;
 pub mod x64;
 pub mod aarch64;
 pub mod riscv;
+pub mod loongarch64;
+pub mod arm;
+pub mod ebpf;
+pub mod ppc64le;
 
 pub(crate) trait Arch : Debug + Send {
     /// When the .features directive is used for an architecture, this architecture method will be
@@ -59,11 +63,17 @@ pub(crate) fn from_str(s: &str) -> Option<Box<dyn Arch>> {
     match s {
         "x64" => Some(Box::new(x64::Archx64::default())),
         "x86" => Some(Box::new(x64::Archx86::default())),
+        "x86_16" => Some(Box::new(x64::Archx86_16::default())),
         "aarch64" => Some(Box::new(aarch64::ArchAarch64::default())),
         "riscv64i" | "riscv64" => Some(Box::new(riscv::ArchRiscV64I::default())),
         "riscv64e" => Some(Box::new(riscv::ArchRiscV64E::default())),
         "riscv32i" | "riscv32" => Some(Box::new(riscv::ArchRiscV32I::default())),
         "riscv32e" => Some(Box::new(riscv::ArchRiscV32E::default())),
+        "loongarch64" => Some(Box::new(loongarch64::ArchLoongarch64::default())),
+        "arm" => Some(Box::new(arm::ArchArm::default())),
+        "thumb2" => Some(Box::new(arm::ArchThumb2::default())),
+        "ebpf" => Some(Box::new(ebpf::ArchEbpf::default())),
+        "ppc64le" => Some(Box::new(ppc64le::ArchPpc64le::default())),
         "unknown" => Some(Box::new(DummyArch::new())),
         _ => None
     }
@@ -81,11 +91,22 @@ pub const CURRENT_ARCH: &str = "aarch64";
 pub const CURRENT_ARCH: &str = "riscv64i";
 #[cfg(target_arch="riscv32")]
 pub const CURRENT_ARCH: &str = "riscv32i";
+#[cfg(target_arch="loongarch64")]
+pub const CURRENT_ARCH: &str = "loongarch64";
+#[cfg(all(target_arch="arm", not(target_feature="thumb-mode")))]
+pub const CURRENT_ARCH: &str = "arm";
+#[cfg(all(target_arch="arm", target_feature="thumb-mode"))]
+pub const CURRENT_ARCH: &str = "thumb2";
+#[cfg(all(target_arch="powerpc64", target_endian="little"))]
+pub const CURRENT_ARCH: &str = "ppc64le";
 #[cfg(not(any(
     target_arch="x86",
     target_arch="x86_64",
     target_arch="aarch64",
     target_arch="riscv64",
-    target_arch="riscv32"
+    target_arch="riscv32",
+    target_arch="loongarch64",
+    target_arch="arm",
+    all(target_arch="powerpc64", target_endian="little")
 )))]
 pub const CURRENT_ARCH: &str = "unknown";
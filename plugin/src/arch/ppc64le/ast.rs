@@ -0,0 +1,198 @@
+//! PowerPC registers are simple. The registers contain no size information, this is purely encoded
+//! in the instruction. We currently have five known register families.
+//! * General purpose registers, denoted as r0-r31, with sp and rtoc as aliases for r1 and r2
+//! * floating point registers, denoted as f0-f31
+//! * vector registers, denoted as v0-v31
+//! * vector-scalar registers, denoted as vs0-vs63
+//! * condition register fields, denoted as cr0-cr7
+use proc_macro2::Span;
+use crate::common::Jump;
+use super::ppc64ledata::Opdata;
+
+use std::fmt;
+
+
+/// A generic register reference. Can be either a static RegId or a dynamic register from a family
+#[derive(Debug, Clone)]
+pub enum Register {
+    Static(RegId),
+    Dynamic(RegFamily, syn::Expr)
+}
+
+/// Unique identifiers for a specific register
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegId {
+    // general purpose registers
+    R0  = 0x00, R1  = 0x01, R2  = 0x02, R3  = 0x03, // r1 = sp, r2 = rtoc
+    R4  = 0x04, R5  = 0x05, R6  = 0x06, R7  = 0x07,
+    R8  = 0x08, R9  = 0x09, R10 = 0x0A, R11 = 0x0B,
+    R12 = 0x0C, R13 = 0x0D, R14 = 0x0E, R15 = 0x0F,
+    R16 = 0x10, R17 = 0x11, R18 = 0x12, R19 = 0x13,
+    R20 = 0x14, R21 = 0x15, R22 = 0x16, R23 = 0x17,
+    R24 = 0x18, R25 = 0x19, R26 = 0x1A, R27 = 0x1B,
+    R28 = 0x1C, R29 = 0x1D, R30 = 0x1E, R31 = 0x1F,
+
+    // floating point registers
+    F0  = 0x20, F1  = 0x21, F2  = 0x22, F3  = 0x23,
+    F4  = 0x24, F5  = 0x25, F6  = 0x26, F7  = 0x27,
+    F8  = 0x28, F9  = 0x29, F10 = 0x2A, F11 = 0x2B,
+    F12 = 0x2C, F13 = 0x2D, F14 = 0x2E, F15 = 0x2F,
+    F16 = 0x30, F17 = 0x31, F18 = 0x32, F19 = 0x33,
+    F20 = 0x34, F21 = 0x35, F22 = 0x36, F23 = 0x37,
+    F24 = 0x38, F25 = 0x39, F26 = 0x3A, F27 = 0x3B,
+    F28 = 0x3C, F29 = 0x3D, F30 = 0x3E, F31 = 0x3F,
+
+    // vector registers
+    V0  = 0x40, V1  = 0x41, V2  = 0x42, V3  = 0x43,
+    V4  = 0x44, V5  = 0x45, V6  = 0x46, V7  = 0x47,
+    V8  = 0x48, V9  = 0x49, V10 = 0x4A, V11 = 0x4B,
+    V12 = 0x4C, V13 = 0x4D, V14 = 0x4E, V15 = 0x4F,
+    V16 = 0x50, V17 = 0x51, V18 = 0x52, V19 = 0x53,
+    V20 = 0x54, V21 = 0x55, V22 = 0x56, V23 = 0x57,
+    V24 = 0x58, V25 = 0x59, V26 = 0x5A, V27 = 0x5B,
+    V28 = 0x5C, V29 = 0x5D, V30 = 0x5E, V31 = 0x5F,
+
+    // condition register fields
+    CR0 = 0x60, CR1 = 0x61, CR2 = 0x62, CR3 = 0x63,
+    CR4 = 0x64, CR5 = 0x65, CR6 = 0x66, CR7 = 0x67,
+
+    // vector-scalar registers. vs0-vs31 overlap the floating point registers,
+    // vs32-vs63 overlap the vector registers
+    VS0 = 0x80, VS1 = 0x81, VS2 = 0x82, VS3 = 0x83,
+    VS4 = 0x84, VS5 = 0x85, VS6 = 0x86, VS7 = 0x87,
+    VS8 = 0x88, VS9 = 0x89, VS10= 0x8A, VS11= 0x8B,
+    VS12= 0x8C, VS13= 0x8D, VS14= 0x8E, VS15= 0x8F,
+    VS16= 0x90, VS17= 0x91, VS18= 0x92, VS19= 0x93,
+    VS20= 0x94, VS21= 0x95, VS22= 0x96, VS23= 0x97,
+    VS24= 0x98, VS25= 0x99, VS26= 0x9A, VS27= 0x9B,
+    VS28= 0x9C, VS29= 0x9D, VS30= 0x9E, VS31= 0x9F,
+    VS32= 0xA0, VS33= 0xA1, VS34= 0xA2, VS35= 0xA3,
+    VS36= 0xA4, VS37= 0xA5, VS38= 0xA6, VS39= 0xA7,
+    VS40= 0xA8, VS41= 0xA9, VS42= 0xAA, VS43= 0xAB,
+    VS44= 0xAC, VS45= 0xAD, VS46= 0xAE, VS47= 0xAF,
+    VS48= 0xB0, VS49= 0xB1, VS50= 0xB2, VS51= 0xB3,
+    VS52= 0xB4, VS53= 0xB5, VS54= 0xB6, VS55= 0xB7,
+    VS56= 0xB8, VS57= 0xB9, VS58= 0xBA, VS59= 0xBB,
+    VS60= 0xBC, VS61= 0xBD, VS62= 0xBE, VS63= 0xBF,
+}
+
+/// Register families
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegFamily {
+    INTEGER = 0,
+    FP = 1,
+    VECTOR = 2,
+    CR = 3,
+    VSX = 4,
+}
+
+impl RegId {
+    /// Encode this RegId in a 5-bit value, or a 6-bit value for vector-scalar registers
+    pub fn code(self) -> u8 {
+        match self.family() {
+            RegFamily::VSX => self as u8 & 0x3F,
+            _ => self as u8 & 0x1F
+        }
+    }
+
+    /// Returns the family of this Regid
+    pub fn family(self) -> RegFamily {
+        match self as u8 >> 5 {
+            0 => RegFamily::INTEGER,
+            1 => RegFamily::FP,
+            2 => RegFamily::VECTOR,
+            3 => RegFamily::CR,
+            4 | 5 => RegFamily::VSX,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl fmt::Display for RegId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.family() {
+            RegFamily::INTEGER => write!(f, "r{}", self.code()),
+            RegFamily::FP => write!(f, "f{}", self.code()),
+            RegFamily::VECTOR => write!(f, "v{}", self.code()),
+            RegFamily::CR => write!(f, "cr{}", self.code()),
+            RegFamily::VSX => write!(f, "vs{}", self.code()),
+        }
+    }
+}
+
+impl Register {
+    /// Returns the family that this Register is of
+    pub fn family(&self) -> RegFamily {
+        match self {
+            Register::Static(code) => code.family(),
+            Register::Dynamic(family, _) => *family
+        }
+    }
+}
+
+
+/// A PowerPC parsed instruction.
+/// These are fairly simple. the format is "op" [ "." ] [ arg [ , arg ]* ]
+/// where arg is
+/// * an immediate (arbitrary expression)
+/// * a label (in normal dynasm-rs style)
+/// * a register (one of the above)
+/// * a memory reference `[ intreg [ , expr | label ] ]`
+#[derive(Debug, Clone)]
+pub enum RawArg {
+    // An immediate
+    Immediate {
+        value: syn::Expr
+    },
+    // A label
+    JumpTarget {
+        jump: Jump
+    },
+    // A register
+    Register {
+        span: Span,
+        reg: Register
+    },
+    // A memory reference
+    Reference {
+        span: Span,
+        offset: Option<syn::Expr>,
+        base: Register,
+    },
+    // A memory reference with a label as offset
+    LabelReference {
+        span: Span,
+        jump: Jump,
+        base: Register
+    },
+}
+
+/// The result of parsing a single instruction
+#[derive(Debug)]
+pub struct ParsedInstruction {
+    pub name: String,
+    pub span: Span,
+    pub args: Vec<RawArg>
+}
+
+#[derive(Debug)]
+pub enum FlatArg {
+    Immediate {
+        value: syn::Expr
+    },
+    JumpTarget {
+        jump: Jump
+    },
+    Register {
+        span: Span,
+        reg: Register
+    },
+    Default
+}
+
+/// The result of finding a match for an instruction
+#[derive(Debug)]
+pub struct MatchData {
+    pub data: &'static Opdata,
+    pub args: Vec<FlatArg>
+}
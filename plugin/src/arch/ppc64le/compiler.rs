@@ -0,0 +1,472 @@
+use super::Context;
+use super::ppc64ledata::{Template, Command, Relocation};
+use super::ast::{MatchData, FlatArg, Register};
+
+use syn::spanned::Spanned;
+use quote::{quote, quote_spanned};
+use proc_macro2::{TokenStream, Span};
+use proc_macro_error2::emit_error;
+
+use crate::parse_helpers::as_signed_number;
+use crate::common::{Stmt, Size, delimited, bitmask};
+
+/// Compile a single instruction. Input is taken from `data`, containing both the arguments
+/// and the encoding template and commands.
+/// Output is written to ctx.state
+/// Errors can be emitted to either the whole instruction span (by returning Err(Some(errormsg)))
+/// or emited specifically using emit_error! and returning Err(None)
+pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<(), Option<String>> {
+    // argument cursor
+    let mut cursor = 0usize;
+
+    // All static bitfields (compile-time constant) will be encoded into this map of (offset, bitfield)
+    let mut statics = Vec::new();
+    // All dynamic bitfields (run-time determined) will be encoded into this map of (offset, TokenStream)
+    let mut dynamics = Vec::new();
+    // Any relocation will be encoded in this list
+    let mut relocations = Vec::new();
+
+    for (i, command) in data.data.commands.iter().enumerate() {
+        // meta commands
+        match *command {
+
+            Command::Repeat => {
+                cursor -= 1;
+                continue;
+            },
+            Command::Next => {
+                cursor += 1;
+                continue;
+            },
+            _ => ()
+        }
+
+        let arg = data.args.get(cursor).expect("Invalid encoding data, tried to process more arguments than given");
+
+        match *arg {
+            FlatArg::Register { reg: Register::Static(id), .. } => match *command {
+                Command::R(offset)
+                | Command::C(offset) => {
+                    statics.push((offset, u32::from(id.code())));
+                },
+                Command::Vs(offset, high) => {
+                    statics.push((offset, u32::from(id.code() & 0x1F)));
+                    statics.push((high, u32::from(id.code() >> 5)));
+                },
+                _ => panic!("Invalid argument processor")
+            },
+
+            FlatArg::Register { span, reg: Register::Dynamic(_, ref expr) } => match *command {
+                Command::R(offset) => {
+                    let expr = delimited(expr);
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        {
+                            let _dyn_reg: u8 = #expr.into();
+                            (_dyn_reg & 0x1F) as u32
+                        }
+                    }));
+                },
+                Command::C(offset) => {
+                    let expr = delimited(expr);
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        {
+                            let _dyn_reg: u8 = #expr.into();
+                            (_dyn_reg & 0x7) as u32
+                        }
+                    }));
+                },
+                Command::Vs(offset, high) => {
+                    let expr = delimited(expr);
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        {
+                            let _dyn_reg: u8 = #expr.into();
+                            (_dyn_reg & 0x1F) as u32
+                        }
+                    }));
+                    dynamics.push((high, quote_spanned!{ span=>
+                        {
+                            let _dyn_reg: u8 = #expr.into();
+                            ((_dyn_reg >> 5) & 0x1) as u32
+                        }
+                    }));
+                },
+                _ => panic!("Invalid argument processor")
+            },
+
+            FlatArg::Default => match *command {
+                // Default is only emitted for a RefOffset where no offset was provided, i.e. it is 0
+                // This practically means we just don't have to encode anything.
+                Command::UImm(_, _)
+                | Command::SImm(_, _)
+                | Command::BitRange(_, _, _)
+                | Command::Next => (),
+                _ => panic!("Invalid argument processor")
+            },
+
+            FlatArg::Immediate { ref value } => match *command {
+                // integer verification commands
+                Command::UImm(bits, scaling) => {
+                    let span = value.span();
+                    let range: u32 = bitmask(bits);
+
+                    let mut imm_encoder = ImmediateEncoder::new(value);
+                    imm_encoder.gather_fields(data.data.commands, i + 1, &mut statics);
+
+                    match imm_encoder.static_value {
+                        Some(static_value) => {
+                            static_range_check(static_value, 0, range, scaling, span)?;
+                        },
+                        None => {
+                            let check = if scaling == 0 {
+                                quote_spanned!{ span =>
+                                    _dyn_imm > #range
+                                }
+                            } else {
+                                let zeromask: u32 = bitmask(scaling);
+                                quote_spanned!{ span =>
+                                    _dyn_imm > #range || _dyn_imm & #zeromask != 0u32
+                                }
+                            };
+                            imm_encoder.emit_dynamic(false, check, &mut dynamics);
+                        }
+                    }
+                },
+                Command::SImm(bits, scaling) => {
+                    let span = value.span();
+                    let range = bitmask(bits);
+                    let min: i32 = (-1) << (bits - 1);
+
+                    let mut imm_encoder = ImmediateEncoder::new(value);
+                    imm_encoder.gather_fields(data.data.commands, i + 1, &mut statics);
+
+                    match imm_encoder.static_value {
+                        Some(static_value) => {
+                            static_range_check(static_value, min, range, scaling, span)?;
+                        },
+                        None => {
+                            let check = if scaling == 0 {
+                                quote_spanned!{ span =>
+                                    _dyn_imm.wrapping_sub(#min) as u32 > #range
+                                }
+                            } else {
+                                let zeromask = bitmask(scaling) as i32;
+                                quote_spanned!{ span =>
+                                    _dyn_imm.wrapping_sub(#min) as u32 > #range || _dyn_imm & #zeromask != 0i32
+                                }
+                            };
+                            imm_encoder.emit_dynamic(true, check, &mut dynamics);
+                        }
+                    }
+                },
+                // integer encoding commands
+                Command::BitRange(_, _, _)
+                | Command::InvBitRange(_, _, _)
+                | Command::NegBitRange(_, _, _) => (),
+
+                // offsets can accept immediates too
+                Command::Offset(relocation_type) => {
+                    let bits;
+                    let scaling;
+                    let commands: &'static [Command];
+
+                    // equivalent bitrange encodings for offsets
+                    match relocation_type {
+                        // 26 bits, 2-bit scaled
+                        Relocation::B24 => {
+                            bits = 26;
+                            scaling = 2;
+                            commands = &[
+                                Command::BitRange(2, 24, 2),
+                                Command::Next
+                            ];
+                        },
+                        // 16 bits, 2-bit scaled
+                        Relocation::B14 => {
+                            bits = 16;
+                            scaling = 2;
+                            commands = &[
+                                Command::BitRange(2, 14, 2),
+                                Command::Next
+                            ];
+                        },
+                        // these only appear in instruction forms that take a label. Immediates
+                        // are matched by the preceding immediate form of these instructions.
+                        Relocation::HA16
+                        | Relocation::LO16
+                        | Relocation::LO16DS
+                        | Relocation::DXHA16 => panic!("Immediate used as label-only offset"),
+                        Relocation::LITERAL8
+                        | Relocation::LITERAL16
+                        | Relocation::LITERAL32
+                        | Relocation::LITERAL64 => panic!("Literal relocation in instruction"),
+                    }
+
+                    let span = value.span();
+                    let range = bitmask(bits);
+                    let min: i32 = (-1) << (bits - 1);
+
+                    let mut imm_encoder = ImmediateEncoder::new(value);
+                    imm_encoder.gather_fields(commands, 0, &mut statics);
+
+                    match imm_encoder.static_value {
+                        Some(static_value) => {
+                            static_range_check(static_value, min, range, scaling, span)?;
+                        },
+                        None => {
+                            let check = if scaling == 0 {
+                                quote_spanned!{ span =>
+                                    _dyn_imm.wrapping_sub(#min) as u32 > #range
+                                }
+                            } else {
+                                let zeromask = bitmask(scaling) as i32;
+                                quote_spanned!{ span =>
+                                    _dyn_imm.wrapping_sub(#min) as u32 > #range || _dyn_imm & #zeromask != 0i32
+                                }
+                            };
+
+                            imm_encoder.emit_dynamic(true, check, &mut dynamics);
+                        }
+                    }
+                },
+                _ => panic!("Invalid argument processor")
+            },
+
+            FlatArg::JumpTarget { ref jump } => match *command {
+                Command::Offset( relocation ) => {
+                    // encode the complete relocation. Always starts at the begin of the instruction, and
+                    // is relative to that, except for addpcis which is relative to the next instruction.
+                    let ref_offset = match relocation {
+                        Relocation::DXHA16 => 0,
+                        _ => relocation.size()
+                    };
+                    let stmt = jump.clone().encode(relocation.size(), ref_offset, &[relocation.to_id()]);
+
+                    relocations.push(stmt);
+                },
+                _ => panic!("Invalid argument processor")
+            }
+        }
+
+        // figure out how far the cursor has to be advanced.
+        match *command {
+            Command::UImm(_, _)
+            | Command::SImm(_, _)
+            | Command::BitRange(_, _, _)
+            | Command::InvBitRange(_, _, _)
+            | Command::NegBitRange(_, _, _) => (),
+            _ => cursor += 1
+        }
+    }
+
+    // sanity
+    if cursor != data.args.len() {
+        panic!("Not enough command processors");
+    }
+
+    let Template::Single(mut template) = data.data.template;
+    let mut expr = None;
+
+    // apply all statics to the template
+    for (offset, value) in statics {
+        template |= value << offset;
+    }
+
+    // and process all dynamics
+    for (offset, dynamic) in dynamics {
+        expr = match expr.take() {
+            Some(prev_expr) => {
+                Some(if offset == 0 {
+                    quote!{ #prev_expr | #dynamic }
+                } else {
+                    quote!{ #prev_expr | (#dynamic << #offset) }
+                })
+            },
+            None => {
+                Some(if offset == 0 {
+                    quote!{ #template | #dynamic }
+                } else {
+                    quote!{ #template | (#dynamic << #offset) }
+                })
+            }
+        }
+    }
+
+    if let Some(d) = expr {
+        ctx.state.stmts.push(Stmt::ExprUnsigned(delimited(d), Size::B_4));
+    } else {
+        ctx.state.stmts.push(Stmt::Const(u64::from(template), Size::B_4));
+    }
+
+    ctx.state.stmts.extend(relocations);
+
+    Ok(())
+}
+
+
+/// Handles the encoding of immediates in a somewhat efficient fashion.
+struct ImmediateEncoder<'a> {
+    pub dynamic_value: &'a syn::Expr,
+    pub static_value: Option<i64>,
+    pub encodes: Vec<(u8, TokenStream)>, // encoding_offset, expression
+    pub span: Span
+}
+
+impl<'a> ImmediateEncoder<'a> {
+    pub fn new(dynamic_value: &'a syn::Expr) -> ImmediateEncoder<'a> {
+        #![allow(unexpected_cfgs)]
+        let static_value;
+
+        // this allows turning off static checks for testing purposes
+        #[cfg(not(disable_static_checks="1"))]
+        {
+            static_value = as_signed_number(dynamic_value);
+        }
+        #[cfg(disable_static_checks="1")]
+        {
+            static_value = None;
+        }
+
+        let span = dynamic_value.span();
+
+        ImmediateEncoder {
+            dynamic_value,
+            static_value,
+            encodes: Vec::new(),
+            span
+        }
+    }
+
+    pub fn gather_fields(&mut self, commands: &[Command], mut index: usize, statics: &mut Vec<(u8, u32)>) {
+        loop {
+            match commands.get(index) {
+                Some(&Command::BitRange(offset, bits, scaling)) => {
+                    let mask = bitmask(bits);
+
+                    if let Some(v) = self.static_value {
+                        let slice = (v >> scaling) as u32 & mask;
+                        statics.push((offset, slice));
+
+                    } else {
+                        self.encodes.push((offset, quote_spanned!{ self.span=>
+                            (_dyn_imm >> #scaling) as u32 & #mask
+                        }));
+                    }
+                },
+                Some(&Command::InvBitRange(offset, bits, scaling)) => {
+                    let mask = bitmask(bits);
+
+                    if let Some(v) = self.static_value {
+                        let slice = (!v >> scaling) as u32 & mask;
+                        statics.push((offset, slice));
+
+                    } else {
+                        self.encodes.push((offset, quote_spanned!{ self.span=>
+                            (!_dyn_imm >> #scaling) as u32 & #mask
+                        }));
+                    }
+                },
+                Some(&Command::NegBitRange(offset, bits, scaling)) => {
+                    let mask = bitmask(bits);
+
+                    if let Some(v) = self.static_value {
+                        let slice = (v.wrapping_neg() >> scaling) as u32 & mask;
+                        statics.push((offset, slice));
+
+                    } else {
+                        self.encodes.push((offset, quote_spanned!{ self.span=>
+                            (_dyn_imm.wrapping_neg() >> #scaling) as u32 & #mask
+                        }));
+                    }
+                },
+                Some(Command::Next) => break,
+                Some(_)
+                | None => panic!("Bad encoding data, integer field sequence is not terminated"),
+            }
+            index += 1;
+        }
+    }
+
+    pub fn emit_dynamic(mut self, is_signed: bool, check: TokenStream, dynamics: &mut Vec<(u8, TokenStream)>) {
+        // assemble encoding chunks
+        let mut encodes = None;
+        let dynamic_value = self.dynamic_value;
+        let span = self.span;
+
+        for (offset, expr) in self.encodes.drain(..) {
+            encodes = match encodes.take() {
+                Some(prev_expr) => {
+                    let parenthesized = delimited(prev_expr);
+
+                    let expr = delimited(expr);
+
+                    Some(if offset == 0 {
+                        quote!{ #parenthesized | #expr }
+                    } else {
+                        quote!{ #parenthesized | (#expr << #offset) }
+                    })
+                },
+                None => {
+                    // the encoding chunks aren't parenthesized, so a lone chunk doesn't
+                    // trigger unused_parens lints when it ends up as the block result.
+                    Some(if offset == 0 {
+                        quote!{ #expr }
+                    } else {
+                        let expr = delimited(expr);
+                        quote!{ #expr << #offset }
+                    })
+                }
+            }
+        }
+
+        let encodes = match encodes {
+            Some(encodes) => encodes,
+            None => return
+        };
+
+        let (imm_ty, error_expr) = if is_signed {
+            (quote_spanned!{ span=> i32 }, quote_spanned!{ span=> ::dynasmrt::ppc64le::immediate_out_of_range_signed_32 })
+        } else {
+            (quote_spanned!{ span=> u32 }, quote_spanned!{ span=> ::dynasmrt::ppc64le::immediate_out_of_range_unsigned_32 })
+        };
+
+        dynamics.push((0, quote_spanned!{ span=>
+            {
+                let _dyn_imm: #imm_ty = #dynamic_value;
+
+                if #check {
+                    #error_expr(_dyn_imm);
+                }
+
+                #encodes
+            }
+        }));
+    }
+}
+
+/// Checks the following things
+/// value >= min
+/// (value - min) <= range
+/// ((value - min) & bitmask(scale)) == 0
+/// returning (value - min) on success.
+fn static_range_check(value: i64, min: i32, range: u32, scale: u8, span: Span) -> Result<u32, Option<String>> {
+    if value < i64::from(min) {
+        emit_error!(span, "Immediate too low");
+        return Err(None);
+    }
+
+    let biased = value - i64::from(min);
+
+    if biased > i64::from(range) {
+        emit_error!(span, "Immediate too high");
+        return Err(None);
+    }
+
+    let biased = biased as u32;
+
+    if scale != 0 && (biased & bitmask(scale)) != 0 {
+        emit_error!(span, "Unrepresentable immediate");
+        return Err(None);
+    }
+
+    Ok(biased)
+}
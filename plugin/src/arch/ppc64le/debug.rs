@@ -0,0 +1,398 @@
+use super::ppc64ledata::{Command, Matcher, Relocation, Opdata};
+
+use std::fmt::Write;
+
+#[cfg(feature = "dynasm_opmap")]
+pub fn create_opmap() -> String {
+    let mut s = String::new();
+
+    let mut mnemonics: Vec<_> = super::ppc64ledata::mnemonics().cloned().collect();
+    mnemonics.sort();
+    for mnemonic in mnemonics {
+        // get the data for this mnemonic
+        let data = super::ppc64ledata::get_mnemonic_data(mnemonic).unwrap();
+        let formats = data.iter()
+            .map(|x| format_opdata(mnemonic, x))
+            .map(|x| x.replace(">>> ", ""))
+            .collect::<Vec<_>>();
+
+        // push mnemonic name as title
+        write!(s, "### {}\n```insref\n{}\n```\n", mnemonic, formats.join("\n")).unwrap();
+    }
+    s
+}
+
+
+#[cfg(feature = "dynasm_extract")]
+pub fn extract_opmap() -> String {
+    let mut buf = Vec::new();
+
+    let mut mnemonics: Vec<_> = super::ppc64ledata::mnemonics().cloned().collect();
+    mnemonics.sort();
+
+    for mnemonic in mnemonics {
+        // get the data for this mnemonic
+        let data = super::ppc64ledata::get_mnemonic_data(mnemonic).unwrap();
+
+        buf.extend(
+            data.iter()
+            .map(|x| extract_opdata(mnemonic, x))
+        );
+    }
+
+    buf.join("\n")
+}
+
+
+pub fn format_opdata_list(name: &str, data: &[Opdata]) -> String {
+    let mut forms = Vec::new();
+
+    for data in data {
+        forms.push(format_opdata(name, data));
+    }
+
+    forms.join("\n")
+}
+
+
+pub fn format_opdata(name: &str, data: &Opdata) -> String {
+    let mut buf = format!(">>> {}", name);
+
+    let (constraints, names) = match constraints_and_names(data) {
+        Ok(o) => o,
+        Err(e) => panic!("Encountered a faulty op listing for {}: {}", name, e)
+    };
+
+    let mut first = true;
+    let mut names = &names[..];
+
+    for matcher in data.matchers {
+        if first {
+            buf.push(' ');
+            first = false;
+        } else {
+            buf.push_str(", ");
+        }
+
+        let (arg_names, rest) = names.split_at(match matcher {
+            Matcher::RefOffset
+            | Matcher::RefLabel => 2,
+            _ => 1
+        });
+        names = rest;
+
+        match matcher {
+            Matcher::R => write!(buf, "r{}", arg_names[0]).unwrap(),
+            Matcher::F => write!(buf, "f{}", arg_names[0]).unwrap(),
+            Matcher::V => write!(buf, "v{}", arg_names[0]).unwrap(),
+            Matcher::Vs => write!(buf, "vs{}", arg_names[0]).unwrap(),
+            Matcher::Cr => write!(buf, "cr{}", arg_names[0]).unwrap(),
+            Matcher::RefOffset => write!(buf, "[r{}, {}]", arg_names[0], arg_names[1]).unwrap(),
+            Matcher::RefLabel => write!(buf, "[r{}, {}]", arg_names[0], arg_names[1]).unwrap(),
+            Matcher::Offset => buf.push_str(&arg_names[0]),
+            Matcher::Imm => buf.push_str(&arg_names[0]),
+        }
+    }
+
+    if let Some(c) = constraints {
+        let mut len = c.len() + buf.len();
+        while len < 100 {
+            buf.push(' ');
+            len += 1;
+        }
+        buf.push_str(&c);
+    }
+
+    buf
+}
+
+fn constraints_and_names(opdata: &Opdata) -> Result<(Option<String>, Vec<String>), &'static str> {
+    let data = group_opdata(opdata)?;
+    let constraints = format_constraints(&data);
+    let names = data.into_iter().map(|a| a.name.unwrap_or_else(|| "?".into())).collect();
+    Ok((constraints, names))
+}
+
+fn group_opdata(opdata: &Opdata) -> Result<Vec<ArgWithCommands>, &'static str> {
+    let args = flatten_matchers(opdata.matchers);
+    let (max_cursor, commands) = group_commands(opdata.commands);
+
+    if args.len() != max_cursor {
+        return Err("arg / command count mismatch");
+    }
+
+    let mut args: Vec<_> = args.into_iter().map(|arg| ArgWithCommands {
+        arg,
+        commands: Vec::new(),
+        name: None
+    }).collect();
+
+    for (command, idx) in commands {
+        args[idx].commands.push(command);
+    }
+
+    // validate the commands - argtypes
+    check_command_sanity(&args)?;
+
+    name_args(&mut args);
+
+    Ok(args)
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum FlatArgTy {
+    Direct,
+    Immediate,
+    JumpTarget,
+}
+
+struct ArgWithCommands {
+    pub arg: FlatArgTy,
+    pub commands: Vec<Command>,
+    pub name: Option<String>,
+}
+
+/// Take a matcher array and return a vector of the types of flat arg each should produce
+fn flatten_matchers(matchers: &[Matcher]) -> Vec<FlatArgTy> {
+    let mut args = Vec::new();
+
+    for matcher in matchers {
+        match matcher {
+            Matcher::R
+            | Matcher::F
+            | Matcher::V
+            | Matcher::Vs
+            | Matcher::Cr => args.push(FlatArgTy::Direct),
+            Matcher::Imm => args.push(FlatArgTy::Immediate),
+            Matcher::RefOffset => {
+                args.push(FlatArgTy::Direct);
+                args.push(FlatArgTy::Immediate);
+            },
+            Matcher::RefLabel => {
+                args.push(FlatArgTy::Direct);
+                args.push(FlatArgTy::JumpTarget);
+            },
+            Matcher::Offset => args.push(FlatArgTy::JumpTarget),
+        }
+    }
+    args
+}
+
+/// Take a commands slice and calculate the expected amount of args / a vec of command, argidx
+fn group_commands(commands: &[Command]) -> (usize, Vec<(Command, usize)>) {
+    let mut cursor = 0;
+    let mut command_idx = Vec::new();
+
+    for command in commands {
+        match command {
+            Command::Next => {
+                cursor += 1;
+                continue;
+            },
+            Command::Repeat => {
+                cursor -= 1;
+                continue;
+            },
+            _ => ()
+        }
+
+        command_idx.push((command.clone(), cursor));
+        match command {
+            Command::R(_)
+            | Command::Vs(_, _)
+            | Command::C(_)
+            | Command::Offset(_) => cursor += 1,
+            _ => ()
+        }
+    }
+
+    (cursor, command_idx)
+}
+
+/// checks if the commands for each arg type make sense
+fn check_command_sanity(args: &[ArgWithCommands]) -> Result<(), &'static str> {
+    for arg in args {
+        if arg.commands.is_empty() {
+            return Err("Arg with no commands")
+        }
+
+        for command in &arg.commands {
+            let check = match command {
+                Command::R(_)
+                | Command::Vs(_, _)
+                | Command::C(_) => arg.arg == FlatArgTy::Direct,
+                Command::UImm(_, _)
+                | Command::SImm(_, _)
+                | Command::BitRange(_, _, _)
+                | Command::InvBitRange(_, _, _)
+                | Command::NegBitRange(_, _, _) => arg.arg == FlatArgTy::Immediate,
+                Command::Offset(_) => arg.arg == FlatArgTy::JumpTarget,
+                Command::Repeat
+                | Command::Next => unreachable!()
+            };
+
+            if !check {
+                return Err("command / argtype mismatch");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// assign names to the args being used. Registers are named after the instruction field
+/// they are encoded in, following the naming used by the Power ISA documentation.
+fn name_args(args: &mut [ArgWithCommands]) {
+    // no op uses more than 3 unconstrained immediates
+    let imm_name_list = ["", "2", "3"];
+    let mut imm_name_idx = 0;
+
+    for arg in args {
+        match arg.arg {
+            FlatArgTy::Direct => match &arg.commands[0] {
+                Command::R(offset)
+                | Command::Vs(offset, _) => arg.name = Some(match offset {
+                    21 => "t",
+                    16 => "a",
+                    11 => "b",
+                    _ => "c"
+                }.to_string()),
+                Command::C(_) => arg.name = Some("f".to_string()),
+                _ => unreachable!()
+            },
+            FlatArgTy::JumpTarget => match &arg.commands[0] {
+                Command::Offset(_) => arg.name = Some("<offset>".to_string()),
+                _ => unreachable!()
+            },
+            FlatArgTy::Immediate => match &arg.commands[0] {
+                Command::UImm(_, _) => {
+                    arg.name = Some(format!("uimm{}", imm_name_list[imm_name_idx]));
+                    imm_name_idx += 1;
+                },
+                Command::SImm(_, _) => {
+                    arg.name = Some(format!("simm{}", imm_name_list[imm_name_idx]));
+                    imm_name_idx += 1;
+                },
+                _ => unreachable!()
+            }
+        }
+    }
+}
+
+fn format_constraints(args: &[ArgWithCommands]) -> Option<String> {
+    let mut constraints = String::new();
+
+    for arg in args {
+        if let Some(ref name) = arg.name {
+            emit_constraints(name, &arg.commands, &mut constraints);
+        }
+    }
+
+    if constraints.is_empty() {
+        None
+    } else {
+        let len = constraints.len();
+        Some(format!(" ({})", &constraints[0 .. len - 2]))
+    }
+}
+
+fn emit_constraints(name: &str, commands: &[Command], buf: &mut String) {
+    for command in commands {
+        match command {
+            Command::UImm(bits, 0) if *bits > 12 => write!(buf, "{} <= {:#X}", name, (1u32 << bits) - 1),
+            Command::UImm(bits, 0) => write!(buf, "{} <= {}", name, (1u32 << bits) - 1),
+            Command::UImm(bits, scale) => write!(buf, "{} <= {}, {} = {} * N", name, (1u32 << bits) - (1u32 << scale), name, 1u32 << scale),
+            Command::SImm(bits, 0) if *bits > 12 => write!(buf, "-{:#X} <= {} <= {:#X}", (1u32 << (bits - 1)), name, (1u32 << (bits - 1)) - 1),
+            Command::SImm(bits, 0) => write!(buf, "-{} <= {} <= {}", (1u32 << (bits - 1)), name, (1u32 << (bits - 1)) - 1),
+            Command::SImm(bits, scale) if *bits > 12 => write!(buf, "-{:#X} <= {} <= {:#X}, {} = {} * N", 1u32 << (bits - 1), name, (1u32 << (bits - 1)) - (1u32 << scale), name, 1u32 << scale),
+            Command::SImm(bits, scale) => write!(buf, "-{} <= {} <= {}, {} = {} * N", 1u32 << (bits - 1), name, (1u32 << (bits - 1)) - (1u32 << scale), name, 1u32 << scale),
+
+            Command::Offset(Relocation::B24) => write!(buf, "offset is 26 bits, 4-byte aligned"),
+            Command::Offset(Relocation::B14) => write!(buf, "offset is 16 bits, 4-byte aligned"),
+            Command::Offset(Relocation::HA16) => write!(buf, "offset is the 16 highest bits of a 32-bit offset"),
+            Command::Offset(Relocation::LO16) => write!(buf, "offset is the 16 lowest bits of a 32-bit offset"),
+            Command::Offset(Relocation::LO16DS) => write!(buf, "offset is the 16 lowest bits of a 32-bit offset, 4-byte aligned"),
+            Command::Offset(Relocation::DXHA16) => write!(buf, "offset is the 16 highest bits of a 32-bit offset from the next instruction"),
+
+            _ => continue
+        }.unwrap();
+
+        write!(buf, ", ").unwrap();
+        break;
+    }
+}
+
+
+#[cfg(feature = "dynasm_extract")]
+pub fn extract_opdata(name: &str, data: &Opdata) -> String {
+    let mut buf = format!("\"{}", name);
+
+    let mut first = true;
+    let mut arg_idx = 0;
+
+    let grouped = group_opdata(data).unwrap();
+    let constraints = extract_constraints(&grouped);
+
+    for matcher in data.matchers {
+        if first {
+            buf.push(' ');
+            first = false;
+        } else {
+            buf.push_str(", ");
+        }
+
+        match matcher {
+            Matcher::R => write!(buf, "<R,{}>", arg_idx).unwrap(),
+            Matcher::F => write!(buf, "<F,{}>", arg_idx).unwrap(),
+            Matcher::V => write!(buf, "<V,{}>", arg_idx).unwrap(),
+            Matcher::Vs => write!(buf, "<VS,{}>", arg_idx).unwrap(),
+            Matcher::Cr => write!(buf, "<CR,{}>", arg_idx).unwrap(),
+            Matcher::RefOffset => write!(buf, "[<R,{}>, <Imm,{}>]", arg_idx, arg_idx + 1).unwrap(),
+            Matcher::RefLabel => write!(buf, "[<R,{}>, <Off,{}>]", arg_idx, arg_idx + 1).unwrap(),
+            Matcher::Imm => write!(buf, "<Imm,{}>", arg_idx).unwrap(),
+            Matcher::Offset => write!(buf, "<Off,{}>", arg_idx).unwrap(),
+        }
+
+        arg_idx += match matcher {
+            Matcher::RefOffset
+            | Matcher::RefLabel => 2,
+            _ => 1
+        };
+    }
+
+    write!(buf, "\"\t{{{}}}", constraints.join(", ")).unwrap();
+
+    buf
+}
+
+
+#[cfg(feature = "dynasm_extract")]
+fn extract_constraints(args: &[ArgWithCommands]) -> Vec<String> {
+    let mut constraints = Vec::new();
+    for (i, arg) in args.iter().enumerate() {
+        for command in &arg.commands {
+            let constraint = match command {
+                Command::R(_) => "R(0xFFFFFFFF)".to_string(),
+                Command::Vs(_, _) => "R(0xFFFFFFFFFFFFFFFF)".to_string(),
+                Command::C(_) => "R(0x000000FF)".to_string(),
+
+                Command::UImm(bits, scale) => format!("Range(0, {}, {})", 1u32 << bits, 1u32 << scale),
+                Command::SImm(bits, scale) => format!("Range(-{}, {}, {})", 1u32 << (bits - 1), 1u32 << (bits - 1), 1u32 << scale),
+
+                Command::Offset(Relocation::B24) => format!("Range(-{}, {}, {})", 1u32 << 25, 1u32 << 25, 4),
+                Command::Offset(Relocation::B14) => format!("Range(-{}, {}, {})", 1u32 << 15, 1u32 << 15, 4),
+                Command::Offset(Relocation::HA16)
+                | Command::Offset(Relocation::LO16)
+                | Command::Offset(Relocation::DXHA16) => format!("Range(-{}, {}, 1)", 0x8000_8000u32, 0x7FFF_8000u32),
+                Command::Offset(Relocation::LO16DS) => format!("Range(-{}, {}, 4)", 0x8000_8000u32, 0x7FFF_8000u32),
+
+                _ => continue
+            };
+            constraints.push(format!("{}: {}", i, constraint));
+
+            break;
+        }
+    }
+    constraints
+}
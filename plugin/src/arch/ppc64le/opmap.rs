@@ -0,0 +1,3278 @@
+Ops!(
+
+
+// Branches
+
+"b" = [
+    // b label
+    Single(0x48000000), [Offset] => [Offset(B24)];
+],
+"bl" = [
+    // bl label
+    Single(0x48000001), [Offset] => [Offset(B24)];
+],
+"bc" = [
+    // bc u5, u5, label
+    Single(0x40000000), [Imm, Imm, Offset] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), Next, Offset(B14)];
+],
+"bcl" = [
+    // bcl u5, u5, label
+    Single(0x40000001), [Imm, Imm, Offset] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), Next, Offset(B14)];
+],
+"bt" = [
+    // bt u5, label
+    Single(0x41800000), [Imm, Offset] => [UImm(5, 0), BitRange(16, 5, 0), Next, Offset(B14)];
+],
+"bf" = [
+    // bf u5, label
+    Single(0x40800000), [Imm, Offset] => [UImm(5, 0), BitRange(16, 5, 0), Next, Offset(B14)];
+],
+"bdnz" = [
+    // bdnz label
+    Single(0x42000000), [Offset] => [Offset(B14)];
+],
+"bdz" = [
+    // bdz label
+    Single(0x42400000), [Offset] => [Offset(B14)];
+],
+"bdnzl" = [
+    // bdnzl label
+    Single(0x42000001), [Offset] => [Offset(B14)];
+],
+"bdzl" = [
+    // bdzl label
+    Single(0x42400001), [Offset] => [Offset(B14)];
+],
+"bclr" = [
+    // bclr u5, u5
+    Single(0x4C000020), [Imm, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), Next];
+],
+"bclrl" = [
+    // bclrl u5, u5
+    Single(0x4C000021), [Imm, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), Next];
+],
+"bcctr" = [
+    // bcctr u5, u5
+    Single(0x4C000420), [Imm, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), Next];
+],
+"bcctrl" = [
+    // bcctrl u5, u5
+    Single(0x4C000421), [Imm, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), Next];
+],
+"blr" = [
+    // blr
+    Single(0x4E800020), [] => [];
+],
+"blrl" = [
+    // blrl
+    Single(0x4E800021), [] => [];
+],
+"bctr" = [
+    // bctr
+    Single(0x4E800420), [] => [];
+],
+"bctrl" = [
+    // bctrl
+    Single(0x4E800421), [] => [];
+],
+"bdnzlr" = [
+    // bdnzlr
+    Single(0x4E000020), [] => [];
+],
+"bdzlr" = [
+    // bdzlr
+    Single(0x4E400020), [] => [];
+],
+"blt" = [
+    // blt cr, label
+    Single(0x41800000), [Cr, Offset] => [C(18), Offset(B14)];
+    // blt label
+    Single(0x41800000), [Offset] => [Offset(B14)];
+],
+"bltl" = [
+    // bltl cr, label
+    Single(0x41800001), [Cr, Offset] => [C(18), Offset(B14)];
+    // bltl label
+    Single(0x41800001), [Offset] => [Offset(B14)];
+],
+"bltlr" = [
+    // bltlr cr
+    Single(0x4D800020), [Cr] => [C(18)];
+    // bltlr
+    Single(0x4D800020), [] => [];
+],
+"bltlrl" = [
+    // bltlrl cr
+    Single(0x4D800021), [Cr] => [C(18)];
+    // bltlrl
+    Single(0x4D800021), [] => [];
+],
+"bltctr" = [
+    // bltctr cr
+    Single(0x4D800420), [Cr] => [C(18)];
+    // bltctr
+    Single(0x4D800420), [] => [];
+],
+"bltctrl" = [
+    // bltctrl cr
+    Single(0x4D800421), [Cr] => [C(18)];
+    // bltctrl
+    Single(0x4D800421), [] => [];
+],
+"ble" = [
+    // ble cr, label
+    Single(0x40810000), [Cr, Offset] => [C(18), Offset(B14)];
+    // ble label
+    Single(0x40810000), [Offset] => [Offset(B14)];
+],
+"blel" = [
+    // blel cr, label
+    Single(0x40810001), [Cr, Offset] => [C(18), Offset(B14)];
+    // blel label
+    Single(0x40810001), [Offset] => [Offset(B14)];
+],
+"blelr" = [
+    // blelr cr
+    Single(0x4C810020), [Cr] => [C(18)];
+    // blelr
+    Single(0x4C810020), [] => [];
+],
+"blelrl" = [
+    // blelrl cr
+    Single(0x4C810021), [Cr] => [C(18)];
+    // blelrl
+    Single(0x4C810021), [] => [];
+],
+"blectr" = [
+    // blectr cr
+    Single(0x4C810420), [Cr] => [C(18)];
+    // blectr
+    Single(0x4C810420), [] => [];
+],
+"blectrl" = [
+    // blectrl cr
+    Single(0x4C810421), [Cr] => [C(18)];
+    // blectrl
+    Single(0x4C810421), [] => [];
+],
+"beq" = [
+    // beq cr, label
+    Single(0x41820000), [Cr, Offset] => [C(18), Offset(B14)];
+    // beq label
+    Single(0x41820000), [Offset] => [Offset(B14)];
+],
+"beql" = [
+    // beql cr, label
+    Single(0x41820001), [Cr, Offset] => [C(18), Offset(B14)];
+    // beql label
+    Single(0x41820001), [Offset] => [Offset(B14)];
+],
+"beqlr" = [
+    // beqlr cr
+    Single(0x4D820020), [Cr] => [C(18)];
+    // beqlr
+    Single(0x4D820020), [] => [];
+],
+"beqlrl" = [
+    // beqlrl cr
+    Single(0x4D820021), [Cr] => [C(18)];
+    // beqlrl
+    Single(0x4D820021), [] => [];
+],
+"beqctr" = [
+    // beqctr cr
+    Single(0x4D820420), [Cr] => [C(18)];
+    // beqctr
+    Single(0x4D820420), [] => [];
+],
+"beqctrl" = [
+    // beqctrl cr
+    Single(0x4D820421), [Cr] => [C(18)];
+    // beqctrl
+    Single(0x4D820421), [] => [];
+],
+"bge" = [
+    // bge cr, label
+    Single(0x40800000), [Cr, Offset] => [C(18), Offset(B14)];
+    // bge label
+    Single(0x40800000), [Offset] => [Offset(B14)];
+],
+"bgel" = [
+    // bgel cr, label
+    Single(0x40800001), [Cr, Offset] => [C(18), Offset(B14)];
+    // bgel label
+    Single(0x40800001), [Offset] => [Offset(B14)];
+],
+"bgelr" = [
+    // bgelr cr
+    Single(0x4C800020), [Cr] => [C(18)];
+    // bgelr
+    Single(0x4C800020), [] => [];
+],
+"bgelrl" = [
+    // bgelrl cr
+    Single(0x4C800021), [Cr] => [C(18)];
+    // bgelrl
+    Single(0x4C800021), [] => [];
+],
+"bgectr" = [
+    // bgectr cr
+    Single(0x4C800420), [Cr] => [C(18)];
+    // bgectr
+    Single(0x4C800420), [] => [];
+],
+"bgectrl" = [
+    // bgectrl cr
+    Single(0x4C800421), [Cr] => [C(18)];
+    // bgectrl
+    Single(0x4C800421), [] => [];
+],
+"bgt" = [
+    // bgt cr, label
+    Single(0x41810000), [Cr, Offset] => [C(18), Offset(B14)];
+    // bgt label
+    Single(0x41810000), [Offset] => [Offset(B14)];
+],
+"bgtl" = [
+    // bgtl cr, label
+    Single(0x41810001), [Cr, Offset] => [C(18), Offset(B14)];
+    // bgtl label
+    Single(0x41810001), [Offset] => [Offset(B14)];
+],
+"bgtlr" = [
+    // bgtlr cr
+    Single(0x4D810020), [Cr] => [C(18)];
+    // bgtlr
+    Single(0x4D810020), [] => [];
+],
+"bgtlrl" = [
+    // bgtlrl cr
+    Single(0x4D810021), [Cr] => [C(18)];
+    // bgtlrl
+    Single(0x4D810021), [] => [];
+],
+"bgtctr" = [
+    // bgtctr cr
+    Single(0x4D810420), [Cr] => [C(18)];
+    // bgtctr
+    Single(0x4D810420), [] => [];
+],
+"bgtctrl" = [
+    // bgtctrl cr
+    Single(0x4D810421), [Cr] => [C(18)];
+    // bgtctrl
+    Single(0x4D810421), [] => [];
+],
+"bne" = [
+    // bne cr, label
+    Single(0x40820000), [Cr, Offset] => [C(18), Offset(B14)];
+    // bne label
+    Single(0x40820000), [Offset] => [Offset(B14)];
+],
+"bnel" = [
+    // bnel cr, label
+    Single(0x40820001), [Cr, Offset] => [C(18), Offset(B14)];
+    // bnel label
+    Single(0x40820001), [Offset] => [Offset(B14)];
+],
+"bnelr" = [
+    // bnelr cr
+    Single(0x4C820020), [Cr] => [C(18)];
+    // bnelr
+    Single(0x4C820020), [] => [];
+],
+"bnelrl" = [
+    // bnelrl cr
+    Single(0x4C820021), [Cr] => [C(18)];
+    // bnelrl
+    Single(0x4C820021), [] => [];
+],
+"bnectr" = [
+    // bnectr cr
+    Single(0x4C820420), [Cr] => [C(18)];
+    // bnectr
+    Single(0x4C820420), [] => [];
+],
+"bnectrl" = [
+    // bnectrl cr
+    Single(0x4C820421), [Cr] => [C(18)];
+    // bnectrl
+    Single(0x4C820421), [] => [];
+],
+"bso" = [
+    // bso cr, label
+    Single(0x41830000), [Cr, Offset] => [C(18), Offset(B14)];
+    // bso label
+    Single(0x41830000), [Offset] => [Offset(B14)];
+],
+"bsol" = [
+    // bsol cr, label
+    Single(0x41830001), [Cr, Offset] => [C(18), Offset(B14)];
+    // bsol label
+    Single(0x41830001), [Offset] => [Offset(B14)];
+],
+"bsolr" = [
+    // bsolr cr
+    Single(0x4D830020), [Cr] => [C(18)];
+    // bsolr
+    Single(0x4D830020), [] => [];
+],
+"bsolrl" = [
+    // bsolrl cr
+    Single(0x4D830021), [Cr] => [C(18)];
+    // bsolrl
+    Single(0x4D830021), [] => [];
+],
+"bsoctr" = [
+    // bsoctr cr
+    Single(0x4D830420), [Cr] => [C(18)];
+    // bsoctr
+    Single(0x4D830420), [] => [];
+],
+"bsoctrl" = [
+    // bsoctrl cr
+    Single(0x4D830421), [Cr] => [C(18)];
+    // bsoctrl
+    Single(0x4D830421), [] => [];
+],
+"bns" = [
+    // bns cr, label
+    Single(0x40830000), [Cr, Offset] => [C(18), Offset(B14)];
+    // bns label
+    Single(0x40830000), [Offset] => [Offset(B14)];
+],
+"bnsl" = [
+    // bnsl cr, label
+    Single(0x40830001), [Cr, Offset] => [C(18), Offset(B14)];
+    // bnsl label
+    Single(0x40830001), [Offset] => [Offset(B14)];
+],
+"bnslr" = [
+    // bnslr cr
+    Single(0x4C830020), [Cr] => [C(18)];
+    // bnslr
+    Single(0x4C830020), [] => [];
+],
+"bnslrl" = [
+    // bnslrl cr
+    Single(0x4C830021), [Cr] => [C(18)];
+    // bnslrl
+    Single(0x4C830021), [] => [];
+],
+"bnsctr" = [
+    // bnsctr cr
+    Single(0x4C830420), [Cr] => [C(18)];
+    // bnsctr
+    Single(0x4C830420), [] => [];
+],
+"bnsctrl" = [
+    // bnsctrl cr
+    Single(0x4C830421), [Cr] => [C(18)];
+    // bnsctrl
+    Single(0x4C830421), [] => [];
+],
+
+
+// Condition register logical instructions
+
+"crand" = [
+    // crand u5, u5, u5
+    Single(0x4C000202), [Imm, Imm, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), Next, UImm(5, 0), BitRange(11, 5, 0), Next];
+],
+"crnand" = [
+    // crnand u5, u5, u5
+    Single(0x4C0001C2), [Imm, Imm, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), Next, UImm(5, 0), BitRange(11, 5, 0), Next];
+],
+"cror" = [
+    // cror u5, u5, u5
+    Single(0x4C000382), [Imm, Imm, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), Next, UImm(5, 0), BitRange(11, 5, 0), Next];
+],
+"crxor" = [
+    // crxor u5, u5, u5
+    Single(0x4C000182), [Imm, Imm, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), Next, UImm(5, 0), BitRange(11, 5, 0), Next];
+],
+"crnor" = [
+    // crnor u5, u5, u5
+    Single(0x4C000042), [Imm, Imm, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), Next, UImm(5, 0), BitRange(11, 5, 0), Next];
+],
+"creqv" = [
+    // creqv u5, u5, u5
+    Single(0x4C000242), [Imm, Imm, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), Next, UImm(5, 0), BitRange(11, 5, 0), Next];
+],
+"crandc" = [
+    // crandc u5, u5, u5
+    Single(0x4C000102), [Imm, Imm, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), Next, UImm(5, 0), BitRange(11, 5, 0), Next];
+],
+"crorc" = [
+    // crorc u5, u5, u5
+    Single(0x4C000342), [Imm, Imm, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), Next, UImm(5, 0), BitRange(11, 5, 0), Next];
+],
+"crset" = [
+    // crset u5
+    Single(0x4C000242), [Imm] => [UImm(5, 0), BitRange(21, 5, 0), BitRange(16, 5, 0), BitRange(11, 5, 0), Next];
+],
+"crclr" = [
+    // crclr u5
+    Single(0x4C000182), [Imm] => [UImm(5, 0), BitRange(21, 5, 0), BitRange(16, 5, 0), BitRange(11, 5, 0), Next];
+],
+"crmove" = [
+    // crmove u5, u5
+    Single(0x4C000382), [Imm, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), BitRange(11, 5, 0), Next];
+],
+"crnot" = [
+    // crnot u5, u5
+    Single(0x4C000042), [Imm, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, UImm(5, 0), BitRange(16, 5, 0), BitRange(11, 5, 0), Next];
+],
+"mcrf" = [
+    // mcrf cr, cr
+    Single(0x4C000000), [Cr, Cr] => [C(23), C(18)];
+],
+
+
+// System call
+
+"sc" = [
+    // sc
+    Single(0x44000002), [] => [];
+],
+
+
+// Fixed-point loads
+
+"lbz" = [
+    // lbz rt, [ra, d]
+    Single(0x88000000), [R, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // lbz rt, [ra, lo16(label)]
+    Single(0x88000000), [R, RefLabel] => [R(21), R(16), Offset(LO16)];
+],
+"lbzu" = [
+    // lbzu rt, [ra, d]
+    Single(0x8C000000), [R, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"lhz" = [
+    // lhz rt, [ra, d]
+    Single(0xA0000000), [R, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // lhz rt, [ra, lo16(label)]
+    Single(0xA0000000), [R, RefLabel] => [R(21), R(16), Offset(LO16)];
+],
+"lhzu" = [
+    // lhzu rt, [ra, d]
+    Single(0xA4000000), [R, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"lha" = [
+    // lha rt, [ra, d]
+    Single(0xA8000000), [R, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // lha rt, [ra, lo16(label)]
+    Single(0xA8000000), [R, RefLabel] => [R(21), R(16), Offset(LO16)];
+],
+"lhau" = [
+    // lhau rt, [ra, d]
+    Single(0xAC000000), [R, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"lwz" = [
+    // lwz rt, [ra, d]
+    Single(0x80000000), [R, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // lwz rt, [ra, lo16(label)]
+    Single(0x80000000), [R, RefLabel] => [R(21), R(16), Offset(LO16)];
+],
+"lwzu" = [
+    // lwzu rt, [ra, d]
+    Single(0x84000000), [R, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"lwa" = [
+    // lwa rt, [ra, ds]
+    Single(0xE8000002), [R, RefOffset] => [R(21), R(16), SImm(16, 2), BitRange(2, 14, 2), Next];
+    // lwa rt, [ra, lo16(label)]
+    Single(0xE8000002), [R, RefLabel] => [R(21), R(16), Offset(LO16DS)];
+],
+"ld" = [
+    // ld rt, [ra, ds]
+    Single(0xE8000000), [R, RefOffset] => [R(21), R(16), SImm(16, 2), BitRange(2, 14, 2), Next];
+    // ld rt, [ra, lo16(label)]
+    Single(0xE8000000), [R, RefLabel] => [R(21), R(16), Offset(LO16DS)];
+],
+"ldu" = [
+    // ldu rt, [ra, ds]
+    Single(0xE8000001), [R, RefOffset] => [R(21), R(16), SImm(16, 2), BitRange(2, 14, 2), Next];
+],
+"lbzx" = [
+    // lbzx rt, ra, rb
+    Single(0x7C0000AE), [R, R, R] => [R(21), R(16), R(11)];
+],
+"lbzux" = [
+    // lbzux rt, ra, rb
+    Single(0x7C0000EE), [R, R, R] => [R(21), R(16), R(11)];
+],
+"lhzx" = [
+    // lhzx rt, ra, rb
+    Single(0x7C00022E), [R, R, R] => [R(21), R(16), R(11)];
+],
+"lhzux" = [
+    // lhzux rt, ra, rb
+    Single(0x7C00026E), [R, R, R] => [R(21), R(16), R(11)];
+],
+"lhax" = [
+    // lhax rt, ra, rb
+    Single(0x7C0002AE), [R, R, R] => [R(21), R(16), R(11)];
+],
+"lhaux" = [
+    // lhaux rt, ra, rb
+    Single(0x7C0002EE), [R, R, R] => [R(21), R(16), R(11)];
+],
+"lwzx" = [
+    // lwzx rt, ra, rb
+    Single(0x7C00002E), [R, R, R] => [R(21), R(16), R(11)];
+],
+"lwzux" = [
+    // lwzux rt, ra, rb
+    Single(0x7C00006E), [R, R, R] => [R(21), R(16), R(11)];
+],
+"lwax" = [
+    // lwax rt, ra, rb
+    Single(0x7C0002AA), [R, R, R] => [R(21), R(16), R(11)];
+],
+"lwaux" = [
+    // lwaux rt, ra, rb
+    Single(0x7C0002EA), [R, R, R] => [R(21), R(16), R(11)];
+],
+"ldx" = [
+    // ldx rt, ra, rb
+    Single(0x7C00002A), [R, R, R] => [R(21), R(16), R(11)];
+],
+"ldux" = [
+    // ldux rt, ra, rb
+    Single(0x7C00006A), [R, R, R] => [R(21), R(16), R(11)];
+],
+"lhbrx" = [
+    // lhbrx rt, ra, rb
+    Single(0x7C00062C), [R, R, R] => [R(21), R(16), R(11)];
+],
+"lwbrx" = [
+    // lwbrx rt, ra, rb
+    Single(0x7C00042C), [R, R, R] => [R(21), R(16), R(11)];
+],
+"ldbrx" = [
+    // ldbrx rt, ra, rb
+    Single(0x7C000428), [R, R, R] => [R(21), R(16), R(11)];
+],
+
+
+// Fixed-point stores
+
+"stb" = [
+    // stb rt, [ra, d]
+    Single(0x98000000), [R, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // stb rt, [ra, lo16(label)]
+    Single(0x98000000), [R, RefLabel] => [R(21), R(16), Offset(LO16)];
+],
+"stbu" = [
+    // stbu rt, [ra, d]
+    Single(0x9C000000), [R, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"sth" = [
+    // sth rt, [ra, d]
+    Single(0xB0000000), [R, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // sth rt, [ra, lo16(label)]
+    Single(0xB0000000), [R, RefLabel] => [R(21), R(16), Offset(LO16)];
+],
+"sthu" = [
+    // sthu rt, [ra, d]
+    Single(0xB4000000), [R, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"stw" = [
+    // stw rt, [ra, d]
+    Single(0x90000000), [R, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // stw rt, [ra, lo16(label)]
+    Single(0x90000000), [R, RefLabel] => [R(21), R(16), Offset(LO16)];
+],
+"stwu" = [
+    // stwu rt, [ra, d]
+    Single(0x94000000), [R, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"std" = [
+    // std rt, [ra, ds]
+    Single(0xF8000000), [R, RefOffset] => [R(21), R(16), SImm(16, 2), BitRange(2, 14, 2), Next];
+    // std rt, [ra, lo16(label)]
+    Single(0xF8000000), [R, RefLabel] => [R(21), R(16), Offset(LO16DS)];
+],
+"stdu" = [
+    // stdu rt, [ra, ds]
+    Single(0xF8000001), [R, RefOffset] => [R(21), R(16), SImm(16, 2), BitRange(2, 14, 2), Next];
+],
+"stbx" = [
+    // stbx rt, ra, rb
+    Single(0x7C0001AE), [R, R, R] => [R(21), R(16), R(11)];
+],
+"stbux" = [
+    // stbux rt, ra, rb
+    Single(0x7C0001EE), [R, R, R] => [R(21), R(16), R(11)];
+],
+"sthx" = [
+    // sthx rt, ra, rb
+    Single(0x7C00032E), [R, R, R] => [R(21), R(16), R(11)];
+],
+"sthux" = [
+    // sthux rt, ra, rb
+    Single(0x7C00036E), [R, R, R] => [R(21), R(16), R(11)];
+],
+"stwx" = [
+    // stwx rt, ra, rb
+    Single(0x7C00012E), [R, R, R] => [R(21), R(16), R(11)];
+],
+"stwux" = [
+    // stwux rt, ra, rb
+    Single(0x7C00016E), [R, R, R] => [R(21), R(16), R(11)];
+],
+"stdx" = [
+    // stdx rt, ra, rb
+    Single(0x7C00012A), [R, R, R] => [R(21), R(16), R(11)];
+],
+"stdux" = [
+    // stdux rt, ra, rb
+    Single(0x7C00016A), [R, R, R] => [R(21), R(16), R(11)];
+],
+"sthbrx" = [
+    // sthbrx rt, ra, rb
+    Single(0x7C00072C), [R, R, R] => [R(21), R(16), R(11)];
+],
+"stwbrx" = [
+    // stwbrx rt, ra, rb
+    Single(0x7C00052C), [R, R, R] => [R(21), R(16), R(11)];
+],
+"stdbrx" = [
+    // stdbrx rt, ra, rb
+    Single(0x7C000528), [R, R, R] => [R(21), R(16), R(11)];
+],
+
+
+// Load and reserve, store conditional
+
+"lbarx" = [
+    // lbarx rt, ra, rb
+    Single(0x7C000068), [R, R, R] => [R(21), R(16), R(11)];
+],
+"lharx" = [
+    // lharx rt, ra, rb
+    Single(0x7C0000E8), [R, R, R] => [R(21), R(16), R(11)];
+],
+"lwarx" = [
+    // lwarx rt, ra, rb
+    Single(0x7C000028), [R, R, R] => [R(21), R(16), R(11)];
+],
+"ldarx" = [
+    // ldarx rt, ra, rb
+    Single(0x7C0000A8), [R, R, R] => [R(21), R(16), R(11)];
+],
+"stbcx." = [
+    // stbcx. rt, ra, rb
+    Single(0x7C00056D), [R, R, R] => [R(21), R(16), R(11)];
+],
+"sthcx." = [
+    // sthcx. rt, ra, rb
+    Single(0x7C0005AD), [R, R, R] => [R(21), R(16), R(11)];
+],
+"stwcx." = [
+    // stwcx. rt, ra, rb
+    Single(0x7C00012D), [R, R, R] => [R(21), R(16), R(11)];
+],
+"stdcx." = [
+    // stdcx. rt, ra, rb
+    Single(0x7C0001AD), [R, R, R] => [R(21), R(16), R(11)];
+],
+
+
+// Fixed-point arithmetic
+
+"addi" = [
+    // addi rt, ra, s16
+    Single(0x38000000), [R, R, Imm] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // addi rt, ra, lo16(label)
+    Single(0x38000000), [R, R, Offset] => [R(21), R(16), Offset(LO16)];
+],
+"addis" = [
+    // addis rt, ra, s16
+    Single(0x3C000000), [R, R, Imm] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // addis rt, ra, ha16(label)
+    Single(0x3C000000), [R, R, Offset] => [R(21), R(16), Offset(HA16)];
+],
+"addpcis" = [
+    // addpcis rt, s16
+    Single(0x4C000004), [R, Imm] => [R(21), SImm(16, 0), BitRange(16, 5, 1), BitRange(6, 10, 6), BitRange(0, 1, 0), Next];
+    // addpcis rt, ha16(label)
+    Single(0x4C000004), [R, Offset] => [R(21), Offset(DXHA16)];
+],
+"li" = [
+    // li rt, s16
+    Single(0x38000000), [R, Imm] => [R(21), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"lis" = [
+    // lis rt, s16
+    Single(0x3C000000), [R, Imm] => [R(21), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"add" = [
+    // add rt, ra, rb
+    Single(0x7C000214), [R, R, R] => [R(21), R(16), R(11)];
+],
+"add." = [
+    // add. rt, ra, rb
+    Single(0x7C000215), [R, R, R] => [R(21), R(16), R(11)];
+],
+"addo" = [
+    // addo rt, ra, rb
+    Single(0x7C000614), [R, R, R] => [R(21), R(16), R(11)];
+],
+"addo." = [
+    // addo. rt, ra, rb
+    Single(0x7C000615), [R, R, R] => [R(21), R(16), R(11)];
+],
+"subf" = [
+    // subf rt, ra, rb
+    Single(0x7C000050), [R, R, R] => [R(21), R(16), R(11)];
+],
+"subf." = [
+    // subf. rt, ra, rb
+    Single(0x7C000051), [R, R, R] => [R(21), R(16), R(11)];
+],
+"subfo" = [
+    // subfo rt, ra, rb
+    Single(0x7C000450), [R, R, R] => [R(21), R(16), R(11)];
+],
+"subfo." = [
+    // subfo. rt, ra, rb
+    Single(0x7C000451), [R, R, R] => [R(21), R(16), R(11)];
+],
+"sub" = [
+    // sub rt, ra, rb
+    Single(0x7C000050), [R, R, R] => [R(21), R(11), R(16)];
+],
+"sub." = [
+    // sub. rt, ra, rb
+    Single(0x7C000051), [R, R, R] => [R(21), R(11), R(16)];
+],
+"addc" = [
+    // addc rt, ra, rb
+    Single(0x7C000014), [R, R, R] => [R(21), R(16), R(11)];
+],
+"addc." = [
+    // addc. rt, ra, rb
+    Single(0x7C000015), [R, R, R] => [R(21), R(16), R(11)];
+],
+"addco" = [
+    // addco rt, ra, rb
+    Single(0x7C000414), [R, R, R] => [R(21), R(16), R(11)];
+],
+"addco." = [
+    // addco. rt, ra, rb
+    Single(0x7C000415), [R, R, R] => [R(21), R(16), R(11)];
+],
+"subfc" = [
+    // subfc rt, ra, rb
+    Single(0x7C000010), [R, R, R] => [R(21), R(16), R(11)];
+],
+"subfc." = [
+    // subfc. rt, ra, rb
+    Single(0x7C000011), [R, R, R] => [R(21), R(16), R(11)];
+],
+"subfco" = [
+    // subfco rt, ra, rb
+    Single(0x7C000410), [R, R, R] => [R(21), R(16), R(11)];
+],
+"subfco." = [
+    // subfco. rt, ra, rb
+    Single(0x7C000411), [R, R, R] => [R(21), R(16), R(11)];
+],
+"subc" = [
+    // subc rt, ra, rb
+    Single(0x7C000010), [R, R, R] => [R(21), R(11), R(16)];
+],
+"subc." = [
+    // subc. rt, ra, rb
+    Single(0x7C000011), [R, R, R] => [R(21), R(11), R(16)];
+],
+"adde" = [
+    // adde rt, ra, rb
+    Single(0x7C000114), [R, R, R] => [R(21), R(16), R(11)];
+],
+"adde." = [
+    // adde. rt, ra, rb
+    Single(0x7C000115), [R, R, R] => [R(21), R(16), R(11)];
+],
+"addeo" = [
+    // addeo rt, ra, rb
+    Single(0x7C000514), [R, R, R] => [R(21), R(16), R(11)];
+],
+"addeo." = [
+    // addeo. rt, ra, rb
+    Single(0x7C000515), [R, R, R] => [R(21), R(16), R(11)];
+],
+"subfe" = [
+    // subfe rt, ra, rb
+    Single(0x7C000110), [R, R, R] => [R(21), R(16), R(11)];
+],
+"subfe." = [
+    // subfe. rt, ra, rb
+    Single(0x7C000111), [R, R, R] => [R(21), R(16), R(11)];
+],
+"subfeo" = [
+    // subfeo rt, ra, rb
+    Single(0x7C000510), [R, R, R] => [R(21), R(16), R(11)];
+],
+"subfeo." = [
+    // subfeo. rt, ra, rb
+    Single(0x7C000511), [R, R, R] => [R(21), R(16), R(11)];
+],
+"addme" = [
+    // addme rt, ra
+    Single(0x7C0001D4), [R, R] => [R(21), R(16)];
+],
+"addme." = [
+    // addme. rt, ra
+    Single(0x7C0001D5), [R, R] => [R(21), R(16)];
+],
+"addmeo" = [
+    // addmeo rt, ra
+    Single(0x7C0005D4), [R, R] => [R(21), R(16)];
+],
+"addmeo." = [
+    // addmeo. rt, ra
+    Single(0x7C0005D5), [R, R] => [R(21), R(16)];
+],
+"subfme" = [
+    // subfme rt, ra
+    Single(0x7C0001D0), [R, R] => [R(21), R(16)];
+],
+"subfme." = [
+    // subfme. rt, ra
+    Single(0x7C0001D1), [R, R] => [R(21), R(16)];
+],
+"subfmeo" = [
+    // subfmeo rt, ra
+    Single(0x7C0005D0), [R, R] => [R(21), R(16)];
+],
+"subfmeo." = [
+    // subfmeo. rt, ra
+    Single(0x7C0005D1), [R, R] => [R(21), R(16)];
+],
+"addze" = [
+    // addze rt, ra
+    Single(0x7C000194), [R, R] => [R(21), R(16)];
+],
+"addze." = [
+    // addze. rt, ra
+    Single(0x7C000195), [R, R] => [R(21), R(16)];
+],
+"addzeo" = [
+    // addzeo rt, ra
+    Single(0x7C000594), [R, R] => [R(21), R(16)];
+],
+"addzeo." = [
+    // addzeo. rt, ra
+    Single(0x7C000595), [R, R] => [R(21), R(16)];
+],
+"subfze" = [
+    // subfze rt, ra
+    Single(0x7C000190), [R, R] => [R(21), R(16)];
+],
+"subfze." = [
+    // subfze. rt, ra
+    Single(0x7C000191), [R, R] => [R(21), R(16)];
+],
+"subfzeo" = [
+    // subfzeo rt, ra
+    Single(0x7C000590), [R, R] => [R(21), R(16)];
+],
+"subfzeo." = [
+    // subfzeo. rt, ra
+    Single(0x7C000591), [R, R] => [R(21), R(16)];
+],
+"neg" = [
+    // neg rt, ra
+    Single(0x7C0000D0), [R, R] => [R(21), R(16)];
+],
+"neg." = [
+    // neg. rt, ra
+    Single(0x7C0000D1), [R, R] => [R(21), R(16)];
+],
+"nego" = [
+    // nego rt, ra
+    Single(0x7C0004D0), [R, R] => [R(21), R(16)];
+],
+"nego." = [
+    // nego. rt, ra
+    Single(0x7C0004D1), [R, R] => [R(21), R(16)];
+],
+"addic" = [
+    // addic rt, ra, s16
+    Single(0x30000000), [R, R, Imm] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"addic." = [
+    // addic. rt, ra, s16
+    Single(0x34000000), [R, R, Imm] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"subfic" = [
+    // subfic rt, ra, s16
+    Single(0x20000000), [R, R, Imm] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"mulli" = [
+    // mulli rt, ra, s16
+    Single(0x1C000000), [R, R, Imm] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"mullw" = [
+    // mullw rt, ra, rb
+    Single(0x7C0001D6), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mullw." = [
+    // mullw. rt, ra, rb
+    Single(0x7C0001D7), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mullwo" = [
+    // mullwo rt, ra, rb
+    Single(0x7C0005D6), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mullwo." = [
+    // mullwo. rt, ra, rb
+    Single(0x7C0005D7), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mulhw" = [
+    // mulhw rt, ra, rb
+    Single(0x7C000096), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mulhw." = [
+    // mulhw. rt, ra, rb
+    Single(0x7C000097), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mulhwu" = [
+    // mulhwu rt, ra, rb
+    Single(0x7C000016), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mulhwu." = [
+    // mulhwu. rt, ra, rb
+    Single(0x7C000017), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mulld" = [
+    // mulld rt, ra, rb
+    Single(0x7C0001D2), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mulld." = [
+    // mulld. rt, ra, rb
+    Single(0x7C0001D3), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mulldo" = [
+    // mulldo rt, ra, rb
+    Single(0x7C0005D2), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mulldo." = [
+    // mulldo. rt, ra, rb
+    Single(0x7C0005D3), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mulhd" = [
+    // mulhd rt, ra, rb
+    Single(0x7C000092), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mulhd." = [
+    // mulhd. rt, ra, rb
+    Single(0x7C000093), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mulhdu" = [
+    // mulhdu rt, ra, rb
+    Single(0x7C000012), [R, R, R] => [R(21), R(16), R(11)];
+],
+"mulhdu." = [
+    // mulhdu. rt, ra, rb
+    Single(0x7C000013), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divw" = [
+    // divw rt, ra, rb
+    Single(0x7C0003D6), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divw." = [
+    // divw. rt, ra, rb
+    Single(0x7C0003D7), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divwo" = [
+    // divwo rt, ra, rb
+    Single(0x7C0007D6), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divwo." = [
+    // divwo. rt, ra, rb
+    Single(0x7C0007D7), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divwu" = [
+    // divwu rt, ra, rb
+    Single(0x7C000396), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divwu." = [
+    // divwu. rt, ra, rb
+    Single(0x7C000397), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divwuo" = [
+    // divwuo rt, ra, rb
+    Single(0x7C000796), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divwuo." = [
+    // divwuo. rt, ra, rb
+    Single(0x7C000797), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divwe" = [
+    // divwe rt, ra, rb
+    Single(0x7C000356), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divwe." = [
+    // divwe. rt, ra, rb
+    Single(0x7C000357), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divweo" = [
+    // divweo rt, ra, rb
+    Single(0x7C000756), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divweo." = [
+    // divweo. rt, ra, rb
+    Single(0x7C000757), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divweu" = [
+    // divweu rt, ra, rb
+    Single(0x7C000316), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divweu." = [
+    // divweu. rt, ra, rb
+    Single(0x7C000317), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divweuo" = [
+    // divweuo rt, ra, rb
+    Single(0x7C000716), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divweuo." = [
+    // divweuo. rt, ra, rb
+    Single(0x7C000717), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divd" = [
+    // divd rt, ra, rb
+    Single(0x7C0003D2), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divd." = [
+    // divd. rt, ra, rb
+    Single(0x7C0003D3), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divdo" = [
+    // divdo rt, ra, rb
+    Single(0x7C0007D2), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divdo." = [
+    // divdo. rt, ra, rb
+    Single(0x7C0007D3), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divdu" = [
+    // divdu rt, ra, rb
+    Single(0x7C000392), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divdu." = [
+    // divdu. rt, ra, rb
+    Single(0x7C000393), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divduo" = [
+    // divduo rt, ra, rb
+    Single(0x7C000792), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divduo." = [
+    // divduo. rt, ra, rb
+    Single(0x7C000793), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divde" = [
+    // divde rt, ra, rb
+    Single(0x7C000352), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divde." = [
+    // divde. rt, ra, rb
+    Single(0x7C000353), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divdeo" = [
+    // divdeo rt, ra, rb
+    Single(0x7C000752), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divdeo." = [
+    // divdeo. rt, ra, rb
+    Single(0x7C000753), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divdeu" = [
+    // divdeu rt, ra, rb
+    Single(0x7C000312), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divdeu." = [
+    // divdeu. rt, ra, rb
+    Single(0x7C000313), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divdeuo" = [
+    // divdeuo rt, ra, rb
+    Single(0x7C000712), [R, R, R] => [R(21), R(16), R(11)];
+],
+"divdeuo." = [
+    // divdeuo. rt, ra, rb
+    Single(0x7C000713), [R, R, R] => [R(21), R(16), R(11)];
+],
+"modsw" = [
+    // modsw rt, ra, rb
+    Single(0x7C000616), [R, R, R] => [R(21), R(16), R(11)];
+],
+"moduw" = [
+    // moduw rt, ra, rb
+    Single(0x7C000216), [R, R, R] => [R(21), R(16), R(11)];
+],
+"modsd" = [
+    // modsd rt, ra, rb
+    Single(0x7C000612), [R, R, R] => [R(21), R(16), R(11)];
+],
+"modud" = [
+    // modud rt, ra, rb
+    Single(0x7C000212), [R, R, R] => [R(21), R(16), R(11)];
+],
+"maddhd" = [
+    // maddhd rt, ra, rb, rc
+    Single(0x10000030), [R, R, R, R] => [R(21), R(16), R(11), R(6)];
+],
+"maddhdu" = [
+    // maddhdu rt, ra, rb, rc
+    Single(0x10000031), [R, R, R, R] => [R(21), R(16), R(11), R(6)];
+],
+"maddld" = [
+    // maddld rt, ra, rb, rc
+    Single(0x10000033), [R, R, R, R] => [R(21), R(16), R(11), R(6)];
+],
+"darn" = [
+    // darn rt, u2
+    Single(0x7C0005E6), [R, Imm] => [R(21), UImm(2, 0), BitRange(16, 2, 0), Next];
+],
+
+
+// Fixed-point compare and trap
+
+"cmp" = [
+    // cmp cr, u1, rt, ra
+    Single(0x7C000000), [Cr, Imm, R, R] => [C(23), UImm(1, 0), BitRange(21, 1, 0), Next, R(16), R(11)];
+],
+"cmpi" = [
+    // cmpi cr, u1, rt, s16
+    Single(0x2C000000), [Cr, Imm, R, Imm] => [C(23), UImm(1, 0), BitRange(21, 1, 0), Next, R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"cmpl" = [
+    // cmpl cr, u1, rt, ra
+    Single(0x7C000040), [Cr, Imm, R, R] => [C(23), UImm(1, 0), BitRange(21, 1, 0), Next, R(16), R(11)];
+],
+"cmpli" = [
+    // cmpli cr, u1, rt, u16
+    Single(0x28000000), [Cr, Imm, R, Imm] => [C(23), UImm(1, 0), BitRange(21, 1, 0), Next, R(16), UImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"cmpd" = [
+    // cmpd cr, rt, ra
+    Single(0x7C200000), [Cr, R, R] => [C(23), R(16), R(11)];
+    // cmpd rt, ra
+    Single(0x7C200000), [R, R] => [R(16), R(11)];
+],
+"cmpw" = [
+    // cmpw cr, rt, ra
+    Single(0x7C000000), [Cr, R, R] => [C(23), R(16), R(11)];
+    // cmpw rt, ra
+    Single(0x7C000000), [R, R] => [R(16), R(11)];
+],
+"cmpld" = [
+    // cmpld cr, rt, ra
+    Single(0x7C200040), [Cr, R, R] => [C(23), R(16), R(11)];
+    // cmpld rt, ra
+    Single(0x7C200040), [R, R] => [R(16), R(11)];
+],
+"cmplw" = [
+    // cmplw cr, rt, ra
+    Single(0x7C000040), [Cr, R, R] => [C(23), R(16), R(11)];
+    // cmplw rt, ra
+    Single(0x7C000040), [R, R] => [R(16), R(11)];
+],
+"cmpdi" = [
+    // cmpdi cr, rt, s16
+    Single(0x2C200000), [Cr, R, Imm] => [C(23), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // cmpdi rt, s16
+    Single(0x2C200000), [R, Imm] => [R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"cmpwi" = [
+    // cmpwi cr, rt, s16
+    Single(0x2C000000), [Cr, R, Imm] => [C(23), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // cmpwi rt, s16
+    Single(0x2C000000), [R, Imm] => [R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"cmpldi" = [
+    // cmpldi cr, rt, u16
+    Single(0x28200000), [Cr, R, Imm] => [C(23), R(16), UImm(16, 0), BitRange(0, 16, 0), Next];
+    // cmpldi rt, u16
+    Single(0x28200000), [R, Imm] => [R(16), UImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"cmplwi" = [
+    // cmplwi cr, rt, u16
+    Single(0x28000000), [Cr, R, Imm] => [C(23), R(16), UImm(16, 0), BitRange(0, 16, 0), Next];
+    // cmplwi rt, u16
+    Single(0x28000000), [R, Imm] => [R(16), UImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"cmprb" = [
+    // cmprb cr, u1, rt, ra
+    Single(0x7C000180), [Cr, Imm, R, R] => [C(23), UImm(1, 0), BitRange(21, 1, 0), Next, R(16), R(11)];
+],
+"cmpeqb" = [
+    // cmpeqb cr, rt, ra
+    Single(0x7C0001C0), [Cr, R, R] => [C(23), R(16), R(11)];
+],
+"setb" = [
+    // setb rt, cr
+    Single(0x7C000100), [R, Cr] => [R(21), C(18)];
+],
+"isel" = [
+    // isel rt, ra, rb, u5
+    Single(0x7C00001E), [R, R, R, Imm] => [R(21), R(16), R(11), UImm(5, 0), BitRange(6, 5, 0), Next];
+],
+"tw" = [
+    // tw u5, rt, ra
+    Single(0x7C000008), [Imm, R, R] => [UImm(5, 0), BitRange(21, 5, 0), Next, R(16), R(11)];
+],
+"twi" = [
+    // twi u5, rt, s16
+    Single(0x0C000000), [Imm, R, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"td" = [
+    // td u5, rt, ra
+    Single(0x7C000088), [Imm, R, R] => [UImm(5, 0), BitRange(21, 5, 0), Next, R(16), R(11)];
+],
+"tdi" = [
+    // tdi u5, rt, s16
+    Single(0x08000000), [Imm, R, Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next, R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"trap" = [
+    // trap
+    Single(0x7FE00008), [] => [];
+],
+
+
+// Fixed-point logical
+
+"andi." = [
+    // andi. rt, ra, u16
+    Single(0x70000000), [R, R, Imm] => [R(16), R(21), UImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"andis." = [
+    // andis. rt, ra, u16
+    Single(0x74000000), [R, R, Imm] => [R(16), R(21), UImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"ori" = [
+    // ori rt, ra, u16
+    Single(0x60000000), [R, R, Imm] => [R(16), R(21), UImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"oris" = [
+    // oris rt, ra, u16
+    Single(0x64000000), [R, R, Imm] => [R(16), R(21), UImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"xori" = [
+    // xori rt, ra, u16
+    Single(0x68000000), [R, R, Imm] => [R(16), R(21), UImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"xoris" = [
+    // xoris rt, ra, u16
+    Single(0x6C000000), [R, R, Imm] => [R(16), R(21), UImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"and" = [
+    // and rt, ra, rb
+    Single(0x7C000038), [R, R, R] => [R(16), R(21), R(11)];
+],
+"and." = [
+    // and. rt, ra, rb
+    Single(0x7C000039), [R, R, R] => [R(16), R(21), R(11)];
+],
+"or" = [
+    // or rt, ra, rb
+    Single(0x7C000378), [R, R, R] => [R(16), R(21), R(11)];
+],
+"or." = [
+    // or. rt, ra, rb
+    Single(0x7C000379), [R, R, R] => [R(16), R(21), R(11)];
+],
+"xor" = [
+    // xor rt, ra, rb
+    Single(0x7C000278), [R, R, R] => [R(16), R(21), R(11)];
+],
+"xor." = [
+    // xor. rt, ra, rb
+    Single(0x7C000279), [R, R, R] => [R(16), R(21), R(11)];
+],
+"nand" = [
+    // nand rt, ra, rb
+    Single(0x7C0003B8), [R, R, R] => [R(16), R(21), R(11)];
+],
+"nand." = [
+    // nand. rt, ra, rb
+    Single(0x7C0003B9), [R, R, R] => [R(16), R(21), R(11)];
+],
+"nor" = [
+    // nor rt, ra, rb
+    Single(0x7C0000F8), [R, R, R] => [R(16), R(21), R(11)];
+],
+"nor." = [
+    // nor. rt, ra, rb
+    Single(0x7C0000F9), [R, R, R] => [R(16), R(21), R(11)];
+],
+"eqv" = [
+    // eqv rt, ra, rb
+    Single(0x7C000238), [R, R, R] => [R(16), R(21), R(11)];
+],
+"eqv." = [
+    // eqv. rt, ra, rb
+    Single(0x7C000239), [R, R, R] => [R(16), R(21), R(11)];
+],
+"andc" = [
+    // andc rt, ra, rb
+    Single(0x7C000078), [R, R, R] => [R(16), R(21), R(11)];
+],
+"andc." = [
+    // andc. rt, ra, rb
+    Single(0x7C000079), [R, R, R] => [R(16), R(21), R(11)];
+],
+"orc" = [
+    // orc rt, ra, rb
+    Single(0x7C000338), [R, R, R] => [R(16), R(21), R(11)];
+],
+"orc." = [
+    // orc. rt, ra, rb
+    Single(0x7C000339), [R, R, R] => [R(16), R(21), R(11)];
+],
+"mr" = [
+    // mr rt, ra
+    Single(0x7C000378), [R, R] => [R(16), R(11), Repeat, R(21)];
+],
+"mr." = [
+    // mr. rt, ra
+    Single(0x7C000379), [R, R] => [R(16), R(11), Repeat, R(21)];
+],
+"not" = [
+    // not rt, ra
+    Single(0x7C0000F8), [R, R] => [R(16), R(11), Repeat, R(21)];
+],
+"not." = [
+    // not. rt, ra
+    Single(0x7C0000F9), [R, R] => [R(16), R(11), Repeat, R(21)];
+],
+"nop" = [
+    // nop
+    Single(0x60000000), [] => [];
+],
+"extsb" = [
+    // extsb rt, ra
+    Single(0x7C000774), [R, R] => [R(16), R(21)];
+],
+"extsb." = [
+    // extsb. rt, ra
+    Single(0x7C000775), [R, R] => [R(16), R(21)];
+],
+"extsh" = [
+    // extsh rt, ra
+    Single(0x7C000734), [R, R] => [R(16), R(21)];
+],
+"extsh." = [
+    // extsh. rt, ra
+    Single(0x7C000735), [R, R] => [R(16), R(21)];
+],
+"extsw" = [
+    // extsw rt, ra
+    Single(0x7C0007B4), [R, R] => [R(16), R(21)];
+],
+"extsw." = [
+    // extsw. rt, ra
+    Single(0x7C0007B5), [R, R] => [R(16), R(21)];
+],
+"cntlzw" = [
+    // cntlzw rt, ra
+    Single(0x7C000034), [R, R] => [R(16), R(21)];
+],
+"cntlzw." = [
+    // cntlzw. rt, ra
+    Single(0x7C000035), [R, R] => [R(16), R(21)];
+],
+"cntlzd" = [
+    // cntlzd rt, ra
+    Single(0x7C000074), [R, R] => [R(16), R(21)];
+],
+"cntlzd." = [
+    // cntlzd. rt, ra
+    Single(0x7C000075), [R, R] => [R(16), R(21)];
+],
+"cnttzw" = [
+    // cnttzw rt, ra
+    Single(0x7C000434), [R, R] => [R(16), R(21)];
+],
+"cnttzw." = [
+    // cnttzw. rt, ra
+    Single(0x7C000435), [R, R] => [R(16), R(21)];
+],
+"cnttzd" = [
+    // cnttzd rt, ra
+    Single(0x7C000474), [R, R] => [R(16), R(21)];
+],
+"cnttzd." = [
+    // cnttzd. rt, ra
+    Single(0x7C000475), [R, R] => [R(16), R(21)];
+],
+"popcntb" = [
+    // popcntb rt, ra
+    Single(0x7C0000F4), [R, R] => [R(16), R(21)];
+],
+"popcntw" = [
+    // popcntw rt, ra
+    Single(0x7C0002F4), [R, R] => [R(16), R(21)];
+],
+"popcntd" = [
+    // popcntd rt, ra
+    Single(0x7C0003F4), [R, R] => [R(16), R(21)];
+],
+"cmpb" = [
+    // cmpb rt, ra, rb
+    Single(0x7C0003F8), [R, R, R] => [R(16), R(21), R(11)];
+],
+"bpermd" = [
+    // bpermd rt, ra, rb
+    Single(0x7C0001F8), [R, R, R] => [R(16), R(21), R(11)];
+],
+
+
+// Fixed-point rotate and shift
+
+"rlwinm" = [
+    // rlwinm rt, ra, u5, u5, u5
+    Single(0x54000000), [R, R, Imm, Imm, Imm] => [R(16), R(21), UImm(5, 0), BitRange(11, 5, 0), Next, UImm(5, 0), BitRange(6, 5, 0), Next, UImm(5, 0), BitRange(1, 5, 0), Next];
+],
+"rlwinm." = [
+    // rlwinm. rt, ra, u5, u5, u5
+    Single(0x54000001), [R, R, Imm, Imm, Imm] => [R(16), R(21), UImm(5, 0), BitRange(11, 5, 0), Next, UImm(5, 0), BitRange(6, 5, 0), Next, UImm(5, 0), BitRange(1, 5, 0), Next];
+],
+"rlwnm" = [
+    // rlwnm rt, ra, rb, u5, u5
+    Single(0x5C000000), [R, R, R, Imm, Imm] => [R(16), R(21), R(11), UImm(5, 0), BitRange(6, 5, 0), Next, UImm(5, 0), BitRange(1, 5, 0), Next];
+],
+"rlwnm." = [
+    // rlwnm. rt, ra, rb, u5, u5
+    Single(0x5C000001), [R, R, R, Imm, Imm] => [R(16), R(21), R(11), UImm(5, 0), BitRange(6, 5, 0), Next, UImm(5, 0), BitRange(1, 5, 0), Next];
+],
+"rlwimi" = [
+    // rlwimi rt, ra, u5, u5, u5
+    Single(0x50000000), [R, R, Imm, Imm, Imm] => [R(16), R(21), UImm(5, 0), BitRange(11, 5, 0), Next, UImm(5, 0), BitRange(6, 5, 0), Next, UImm(5, 0), BitRange(1, 5, 0), Next];
+],
+"rlwimi." = [
+    // rlwimi. rt, ra, u5, u5, u5
+    Single(0x50000001), [R, R, Imm, Imm, Imm] => [R(16), R(21), UImm(5, 0), BitRange(11, 5, 0), Next, UImm(5, 0), BitRange(6, 5, 0), Next, UImm(5, 0), BitRange(1, 5, 0), Next];
+],
+"rldicl" = [
+    // rldicl rt, ra, u6, u6
+    Single(0x78000000), [R, R, Imm, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), BitRange(1, 1, 5), Next, UImm(6, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), Next];
+],
+"rldicl." = [
+    // rldicl. rt, ra, u6, u6
+    Single(0x78000001), [R, R, Imm, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), BitRange(1, 1, 5), Next, UImm(6, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), Next];
+],
+"rldicr" = [
+    // rldicr rt, ra, u6, u6
+    Single(0x78000004), [R, R, Imm, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), BitRange(1, 1, 5), Next, UImm(6, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), Next];
+],
+"rldicr." = [
+    // rldicr. rt, ra, u6, u6
+    Single(0x78000005), [R, R, Imm, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), BitRange(1, 1, 5), Next, UImm(6, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), Next];
+],
+"rldic" = [
+    // rldic rt, ra, u6, u6
+    Single(0x78000008), [R, R, Imm, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), BitRange(1, 1, 5), Next, UImm(6, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), Next];
+],
+"rldic." = [
+    // rldic. rt, ra, u6, u6
+    Single(0x78000009), [R, R, Imm, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), BitRange(1, 1, 5), Next, UImm(6, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), Next];
+],
+"rldimi" = [
+    // rldimi rt, ra, u6, u6
+    Single(0x7800000C), [R, R, Imm, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), BitRange(1, 1, 5), Next, UImm(6, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), Next];
+],
+"rldimi." = [
+    // rldimi. rt, ra, u6, u6
+    Single(0x7800000D), [R, R, Imm, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), BitRange(1, 1, 5), Next, UImm(6, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), Next];
+],
+"rldcl" = [
+    // rldcl rt, ra, rb, u6
+    Single(0x78000010), [R, R, R, Imm] => [R(16), R(21), R(11), UImm(6, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), Next];
+],
+"rldcl." = [
+    // rldcl. rt, ra, rb, u6
+    Single(0x78000011), [R, R, R, Imm] => [R(16), R(21), R(11), UImm(6, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), Next];
+],
+"rldcr" = [
+    // rldcr rt, ra, rb, u6
+    Single(0x78000012), [R, R, R, Imm] => [R(16), R(21), R(11), UImm(6, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), Next];
+],
+"rldcr." = [
+    // rldcr. rt, ra, rb, u6
+    Single(0x78000013), [R, R, R, Imm] => [R(16), R(21), R(11), UImm(6, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), Next];
+],
+"slw" = [
+    // slw rt, ra, rb
+    Single(0x7C000030), [R, R, R] => [R(16), R(21), R(11)];
+],
+"slw." = [
+    // slw. rt, ra, rb
+    Single(0x7C000031), [R, R, R] => [R(16), R(21), R(11)];
+],
+"srw" = [
+    // srw rt, ra, rb
+    Single(0x7C000430), [R, R, R] => [R(16), R(21), R(11)];
+],
+"srw." = [
+    // srw. rt, ra, rb
+    Single(0x7C000431), [R, R, R] => [R(16), R(21), R(11)];
+],
+"sraw" = [
+    // sraw rt, ra, rb
+    Single(0x7C000630), [R, R, R] => [R(16), R(21), R(11)];
+],
+"sraw." = [
+    // sraw. rt, ra, rb
+    Single(0x7C000631), [R, R, R] => [R(16), R(21), R(11)];
+],
+"srawi" = [
+    // srawi rt, ra, u5
+    Single(0x7C000670), [R, R, Imm] => [R(16), R(21), UImm(5, 0), BitRange(11, 5, 0), Next];
+],
+"srawi." = [
+    // srawi. rt, ra, u5
+    Single(0x7C000671), [R, R, Imm] => [R(16), R(21), UImm(5, 0), BitRange(11, 5, 0), Next];
+],
+"sld" = [
+    // sld rt, ra, rb
+    Single(0x7C000036), [R, R, R] => [R(16), R(21), R(11)];
+],
+"sld." = [
+    // sld. rt, ra, rb
+    Single(0x7C000037), [R, R, R] => [R(16), R(21), R(11)];
+],
+"srd" = [
+    // srd rt, ra, rb
+    Single(0x7C000436), [R, R, R] => [R(16), R(21), R(11)];
+],
+"srd." = [
+    // srd. rt, ra, rb
+    Single(0x7C000437), [R, R, R] => [R(16), R(21), R(11)];
+],
+"srad" = [
+    // srad rt, ra, rb
+    Single(0x7C000634), [R, R, R] => [R(16), R(21), R(11)];
+],
+"srad." = [
+    // srad. rt, ra, rb
+    Single(0x7C000635), [R, R, R] => [R(16), R(21), R(11)];
+],
+"sradi" = [
+    // sradi rt, ra, u6
+    Single(0x7C000674), [R, R, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), BitRange(1, 1, 5), Next];
+],
+"sradi." = [
+    // sradi. rt, ra, u6
+    Single(0x7C000675), [R, R, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), BitRange(1, 1, 5), Next];
+],
+"extswsli" = [
+    // extswsli rt, ra, u6
+    Single(0x7C0006F4), [R, R, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), BitRange(1, 1, 5), Next];
+],
+"extswsli." = [
+    // extswsli. rt, ra, u6
+    Single(0x7C0006F5), [R, R, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), BitRange(1, 1, 5), Next];
+],
+"rotlw" = [
+    // rotlw rt, ra, rb
+    Single(0x5C00003E), [R, R, R] => [R(16), R(21), R(11)];
+],
+"rotlw." = [
+    // rotlw. rt, ra, rb
+    Single(0x5C00003F), [R, R, R] => [R(16), R(21), R(11)];
+],
+"rotlwi" = [
+    // rotlwi rt, ra, u5
+    Single(0x5400003E), [R, R, Imm] => [R(16), R(21), UImm(5, 0), BitRange(11, 5, 0), Next];
+],
+"rotlwi." = [
+    // rotlwi. rt, ra, u5
+    Single(0x5400003F), [R, R, Imm] => [R(16), R(21), UImm(5, 0), BitRange(11, 5, 0), Next];
+],
+"rotld" = [
+    // rotld rt, ra, rb
+    Single(0x78000010), [R, R, R] => [R(16), R(21), R(11)];
+],
+"rotld." = [
+    // rotld. rt, ra, rb
+    Single(0x78000011), [R, R, R] => [R(16), R(21), R(11)];
+],
+"rotldi" = [
+    // rotldi rt, ra, u6
+    Single(0x78000000), [R, R, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), BitRange(1, 1, 5), Next];
+],
+"rotldi." = [
+    // rotldi. rt, ra, u6
+    Single(0x78000001), [R, R, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), BitRange(1, 1, 5), Next];
+],
+"slwi" = [
+    // slwi rt, ra, u5
+    Single(0x54000000), [R, R, Imm] => [R(16), R(21), UImm(5, 0), BitRange(11, 5, 0), InvBitRange(1, 5, 0), Next];
+],
+"slwi." = [
+    // slwi. rt, ra, u5
+    Single(0x54000001), [R, R, Imm] => [R(16), R(21), UImm(5, 0), BitRange(11, 5, 0), InvBitRange(1, 5, 0), Next];
+],
+"srwi" = [
+    // srwi rt, ra, u5
+    Single(0x5400003E), [R, R, Imm] => [R(16), R(21), UImm(5, 0), NegBitRange(11, 5, 0), BitRange(6, 5, 0), Next];
+],
+"srwi." = [
+    // srwi. rt, ra, u5
+    Single(0x5400003F), [R, R, Imm] => [R(16), R(21), UImm(5, 0), NegBitRange(11, 5, 0), BitRange(6, 5, 0), Next];
+],
+"sldi" = [
+    // sldi rt, ra, u6
+    Single(0x78000004), [R, R, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), InvBitRange(6, 5, 0), InvBitRange(5, 1, 5), BitRange(1, 1, 5), Next];
+],
+"sldi." = [
+    // sldi. rt, ra, u6
+    Single(0x78000005), [R, R, Imm] => [R(16), R(21), UImm(6, 0), BitRange(11, 5, 0), InvBitRange(6, 5, 0), InvBitRange(5, 1, 5), BitRange(1, 1, 5), Next];
+],
+"srdi" = [
+    // srdi rt, ra, u6
+    Single(0x78000000), [R, R, Imm] => [R(16), R(21), UImm(6, 0), NegBitRange(11, 5, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), NegBitRange(1, 1, 5), Next];
+],
+"srdi." = [
+    // srdi. rt, ra, u6
+    Single(0x78000001), [R, R, Imm] => [R(16), R(21), UImm(6, 0), NegBitRange(11, 5, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), NegBitRange(1, 1, 5), Next];
+],
+"clrlwi" = [
+    // clrlwi rt, ra, u5
+    Single(0x5400003E), [R, R, Imm] => [R(16), R(21), UImm(5, 0), BitRange(6, 5, 0), Next];
+],
+"clrlwi." = [
+    // clrlwi. rt, ra, u5
+    Single(0x5400003F), [R, R, Imm] => [R(16), R(21), UImm(5, 0), BitRange(6, 5, 0), Next];
+],
+"clrrwi" = [
+    // clrrwi rt, ra, u5
+    Single(0x54000000), [R, R, Imm] => [R(16), R(21), UImm(5, 0), InvBitRange(1, 5, 0), Next];
+],
+"clrrwi." = [
+    // clrrwi. rt, ra, u5
+    Single(0x54000001), [R, R, Imm] => [R(16), R(21), UImm(5, 0), InvBitRange(1, 5, 0), Next];
+],
+"clrldi" = [
+    // clrldi rt, ra, u6
+    Single(0x78000000), [R, R, Imm] => [R(16), R(21), UImm(6, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), Next];
+],
+"clrldi." = [
+    // clrldi. rt, ra, u6
+    Single(0x78000001), [R, R, Imm] => [R(16), R(21), UImm(6, 0), BitRange(6, 5, 0), BitRange(5, 1, 5), Next];
+],
+"clrrdi" = [
+    // clrrdi rt, ra, u6
+    Single(0x78000004), [R, R, Imm] => [R(16), R(21), UImm(6, 0), InvBitRange(6, 5, 0), InvBitRange(5, 1, 5), Next];
+],
+"clrrdi." = [
+    // clrrdi. rt, ra, u6
+    Single(0x78000005), [R, R, Imm] => [R(16), R(21), UImm(6, 0), InvBitRange(6, 5, 0), InvBitRange(5, 1, 5), Next];
+],
+
+
+// Moves to and from special purpose registers
+
+"mfspr" = [
+    // mfspr rt, u10
+    Single(0x7C0002A6), [R, Imm] => [R(21), UImm(10, 0), BitRange(16, 5, 0), BitRange(11, 5, 5), Next];
+],
+"mtspr" = [
+    // mtspr u10, rt
+    Single(0x7C0003A6), [Imm, R] => [UImm(10, 0), BitRange(16, 5, 0), BitRange(11, 5, 5), Next, R(21)];
+],
+"mflr" = [
+    // mflr rt
+    Single(0x7C0802A6), [R] => [R(21)];
+],
+"mtlr" = [
+    // mtlr rt
+    Single(0x7C0803A6), [R] => [R(21)];
+],
+"mfctr" = [
+    // mfctr rt
+    Single(0x7C0902A6), [R] => [R(21)];
+],
+"mtctr" = [
+    // mtctr rt
+    Single(0x7C0903A6), [R] => [R(21)];
+],
+"mfxer" = [
+    // mfxer rt
+    Single(0x7C0102A6), [R] => [R(21)];
+],
+"mtxer" = [
+    // mtxer rt
+    Single(0x7C0103A6), [R] => [R(21)];
+],
+"mfcr" = [
+    // mfcr rt
+    Single(0x7C000026), [R] => [R(21)];
+],
+"mtcr" = [
+    // mtcr rt
+    Single(0x7C0FF120), [R] => [R(21)];
+],
+"mtcrf" = [
+    // mtcrf u8, rt
+    Single(0x7C000120), [Imm, R] => [UImm(8, 0), BitRange(12, 8, 0), Next, R(21)];
+],
+"mcrxrx" = [
+    // mcrxrx cr
+    Single(0x7C000480), [Cr] => [C(23)];
+],
+"mftb" = [
+    // mftb rt
+    Single(0x7C0C42A6), [R] => [R(21)];
+],
+
+
+// Storage control
+
+"sync" = [
+    // sync
+    Single(0x7C0004AC), [] => [];
+    // sync u2
+    Single(0x7C0004AC), [Imm] => [UImm(2, 0), BitRange(21, 2, 0), Next];
+],
+"lwsync" = [
+    // lwsync
+    Single(0x7C2004AC), [] => [];
+],
+"hwsync" = [
+    // hwsync
+    Single(0x7C0004AC), [] => [];
+],
+"isync" = [
+    // isync
+    Single(0x4C00012C), [] => [];
+],
+"eieio" = [
+    // eieio
+    Single(0x7C0006AC), [] => [];
+],
+"dcbt" = [
+    // dcbt rt, ra
+    Single(0x7C00022C), [R, R] => [R(16), R(11)];
+],
+"dcbtst" = [
+    // dcbtst rt, ra
+    Single(0x7C0001EC), [R, R] => [R(16), R(11)];
+],
+"dcbz" = [
+    // dcbz rt, ra
+    Single(0x7C0007EC), [R, R] => [R(16), R(11)];
+],
+"dcbst" = [
+    // dcbst rt, ra
+    Single(0x7C00006C), [R, R] => [R(16), R(11)];
+],
+"dcbf" = [
+    // dcbf rt, ra
+    Single(0x7C0000AC), [R, R] => [R(16), R(11)];
+],
+"icbi" = [
+    // icbi rt, ra
+    Single(0x7C0007AC), [R, R] => [R(16), R(11)];
+],
+
+
+// Floating-point loads and stores
+
+"lfs" = [
+    // lfs ft, [ra, d]
+    Single(0xC0000000), [F, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // lfs ft, [ra, lo16(label)]
+    Single(0xC0000000), [F, RefLabel] => [R(21), R(16), Offset(LO16)];
+],
+"lfsu" = [
+    // lfsu ft, [ra, d]
+    Single(0xC4000000), [F, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"lfd" = [
+    // lfd ft, [ra, d]
+    Single(0xC8000000), [F, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // lfd ft, [ra, lo16(label)]
+    Single(0xC8000000), [F, RefLabel] => [R(21), R(16), Offset(LO16)];
+],
+"lfdu" = [
+    // lfdu ft, [ra, d]
+    Single(0xCC000000), [F, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"stfs" = [
+    // stfs ft, [ra, d]
+    Single(0xD0000000), [F, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // stfs ft, [ra, lo16(label)]
+    Single(0xD0000000), [F, RefLabel] => [R(21), R(16), Offset(LO16)];
+],
+"stfsu" = [
+    // stfsu ft, [ra, d]
+    Single(0xD4000000), [F, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"stfd" = [
+    // stfd ft, [ra, d]
+    Single(0xD8000000), [F, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+    // stfd ft, [ra, lo16(label)]
+    Single(0xD8000000), [F, RefLabel] => [R(21), R(16), Offset(LO16)];
+],
+"stfdu" = [
+    // stfdu ft, [ra, d]
+    Single(0xDC000000), [F, RefOffset] => [R(21), R(16), SImm(16, 0), BitRange(0, 16, 0), Next];
+],
+"lfsx" = [
+    // lfsx ft, rt, ra
+    Single(0x7C00042E), [F, R, R] => [R(21), R(16), R(11)];
+],
+"lfsux" = [
+    // lfsux ft, rt, ra
+    Single(0x7C00046E), [F, R, R] => [R(21), R(16), R(11)];
+],
+"lfdx" = [
+    // lfdx ft, rt, ra
+    Single(0x7C0004AE), [F, R, R] => [R(21), R(16), R(11)];
+],
+"lfdux" = [
+    // lfdux ft, rt, ra
+    Single(0x7C0004EE), [F, R, R] => [R(21), R(16), R(11)];
+],
+"lfiwax" = [
+    // lfiwax ft, rt, ra
+    Single(0x7C0006AE), [F, R, R] => [R(21), R(16), R(11)];
+],
+"lfiwzx" = [
+    // lfiwzx ft, rt, ra
+    Single(0x7C0006EE), [F, R, R] => [R(21), R(16), R(11)];
+],
+"stfsx" = [
+    // stfsx ft, rt, ra
+    Single(0x7C00052E), [F, R, R] => [R(21), R(16), R(11)];
+],
+"stfsux" = [
+    // stfsux ft, rt, ra
+    Single(0x7C00056E), [F, R, R] => [R(21), R(16), R(11)];
+],
+"stfdx" = [
+    // stfdx ft, rt, ra
+    Single(0x7C0005AE), [F, R, R] => [R(21), R(16), R(11)];
+],
+"stfdux" = [
+    // stfdux ft, rt, ra
+    Single(0x7C0005EE), [F, R, R] => [R(21), R(16), R(11)];
+],
+"stfiwx" = [
+    // stfiwx ft, rt, ra
+    Single(0x7C0007AE), [F, R, R] => [R(21), R(16), R(11)];
+],
+
+
+// Floating-point arithmetic
+
+"fmr" = [
+    // fmr ft, fa
+    Single(0xFC000090), [F, F] => [R(21), R(11)];
+],
+"fmr." = [
+    // fmr. ft, fa
+    Single(0xFC000091), [F, F] => [R(21), R(11)];
+],
+"fneg" = [
+    // fneg ft, fa
+    Single(0xFC000050), [F, F] => [R(21), R(11)];
+],
+"fneg." = [
+    // fneg. ft, fa
+    Single(0xFC000051), [F, F] => [R(21), R(11)];
+],
+"fabs" = [
+    // fabs ft, fa
+    Single(0xFC000210), [F, F] => [R(21), R(11)];
+],
+"fabs." = [
+    // fabs. ft, fa
+    Single(0xFC000211), [F, F] => [R(21), R(11)];
+],
+"fnabs" = [
+    // fnabs ft, fa
+    Single(0xFC000110), [F, F] => [R(21), R(11)];
+],
+"fnabs." = [
+    // fnabs. ft, fa
+    Single(0xFC000111), [F, F] => [R(21), R(11)];
+],
+"fcpsgn" = [
+    // fcpsgn ft, fa, fb
+    Single(0xFC000010), [F, F, F] => [R(21), R(16), R(11)];
+],
+"fcpsgn." = [
+    // fcpsgn. ft, fa, fb
+    Single(0xFC000011), [F, F, F] => [R(21), R(16), R(11)];
+],
+"fadd" = [
+    // fadd ft, fa, fb
+    Single(0xFC00002A), [F, F, F] => [R(21), R(16), R(11)];
+],
+"fadd." = [
+    // fadd. ft, fa, fb
+    Single(0xFC00002B), [F, F, F] => [R(21), R(16), R(11)];
+],
+"fadds" = [
+    // fadds ft, fa, fb
+    Single(0xEC00002A), [F, F, F] => [R(21), R(16), R(11)];
+],
+"fadds." = [
+    // fadds. ft, fa, fb
+    Single(0xEC00002B), [F, F, F] => [R(21), R(16), R(11)];
+],
+"fsub" = [
+    // fsub ft, fa, fb
+    Single(0xFC000028), [F, F, F] => [R(21), R(16), R(11)];
+],
+"fsub." = [
+    // fsub. ft, fa, fb
+    Single(0xFC000029), [F, F, F] => [R(21), R(16), R(11)];
+],
+"fsubs" = [
+    // fsubs ft, fa, fb
+    Single(0xEC000028), [F, F, F] => [R(21), R(16), R(11)];
+],
+"fsubs." = [
+    // fsubs. ft, fa, fb
+    Single(0xEC000029), [F, F, F] => [R(21), R(16), R(11)];
+],
+"fmul" = [
+    // fmul ft, fa, fb
+    Single(0xFC000032), [F, F, F] => [R(21), R(16), R(6)];
+],
+"fmul." = [
+    // fmul. ft, fa, fb
+    Single(0xFC000033), [F, F, F] => [R(21), R(16), R(6)];
+],
+"fmuls" = [
+    // fmuls ft, fa, fb
+    Single(0xEC000032), [F, F, F] => [R(21), R(16), R(6)];
+],
+"fmuls." = [
+    // fmuls. ft, fa, fb
+    Single(0xEC000033), [F, F, F] => [R(21), R(16), R(6)];
+],
+"fdiv" = [
+    // fdiv ft, fa, fb
+    Single(0xFC000024), [F, F, F] => [R(21), R(16), R(11)];
+],
+"fdiv." = [
+    // fdiv. ft, fa, fb
+    Single(0xFC000025), [F, F, F] => [R(21), R(16), R(11)];
+],
+"fdivs" = [
+    // fdivs ft, fa, fb
+    Single(0xEC000024), [F, F, F] => [R(21), R(16), R(11)];
+],
+"fdivs." = [
+    // fdivs. ft, fa, fb
+    Single(0xEC000025), [F, F, F] => [R(21), R(16), R(11)];
+],
+"fsqrt" = [
+    // fsqrt ft, fa
+    Single(0xFC00002C), [F, F] => [R(21), R(11)];
+],
+"fsqrt." = [
+    // fsqrt. ft, fa
+    Single(0xFC00002D), [F, F] => [R(21), R(11)];
+],
+"fsqrts" = [
+    // fsqrts ft, fa
+    Single(0xEC00002C), [F, F] => [R(21), R(11)];
+],
+"fsqrts." = [
+    // fsqrts. ft, fa
+    Single(0xEC00002D), [F, F] => [R(21), R(11)];
+],
+"fre" = [
+    // fre ft, fa
+    Single(0xFC000030), [F, F] => [R(21), R(11)];
+],
+"fre." = [
+    // fre. ft, fa
+    Single(0xFC000031), [F, F] => [R(21), R(11)];
+],
+"fres" = [
+    // fres ft, fa
+    Single(0xEC000030), [F, F] => [R(21), R(11)];
+],
+"fres." = [
+    // fres. ft, fa
+    Single(0xEC000031), [F, F] => [R(21), R(11)];
+],
+"frsqrte" = [
+    // frsqrte ft, fa
+    Single(0xFC000034), [F, F] => [R(21), R(11)];
+],
+"frsqrte." = [
+    // frsqrte. ft, fa
+    Single(0xFC000035), [F, F] => [R(21), R(11)];
+],
+"frsqrtes" = [
+    // frsqrtes ft, fa
+    Single(0xEC000034), [F, F] => [R(21), R(11)];
+],
+"frsqrtes." = [
+    // frsqrtes. ft, fa
+    Single(0xEC000035), [F, F] => [R(21), R(11)];
+],
+"ftdiv" = [
+    // ftdiv cr, ft, fa
+    Single(0xFC000100), [Cr, F, F] => [C(23), R(16), R(11)];
+],
+"ftsqrt" = [
+    // ftsqrt cr, ft
+    Single(0xFC000140), [Cr, F] => [C(23), R(11)];
+],
+"fmadd" = [
+    // fmadd ft, fa, fb, fc
+    Single(0xFC00003A), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fmadd." = [
+    // fmadd. ft, fa, fb, fc
+    Single(0xFC00003B), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fmadds" = [
+    // fmadds ft, fa, fb, fc
+    Single(0xEC00003A), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fmadds." = [
+    // fmadds. ft, fa, fb, fc
+    Single(0xEC00003B), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fmsub" = [
+    // fmsub ft, fa, fb, fc
+    Single(0xFC000038), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fmsub." = [
+    // fmsub. ft, fa, fb, fc
+    Single(0xFC000039), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fmsubs" = [
+    // fmsubs ft, fa, fb, fc
+    Single(0xEC000038), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fmsubs." = [
+    // fmsubs. ft, fa, fb, fc
+    Single(0xEC000039), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fnmadd" = [
+    // fnmadd ft, fa, fb, fc
+    Single(0xFC00003E), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fnmadd." = [
+    // fnmadd. ft, fa, fb, fc
+    Single(0xFC00003F), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fnmadds" = [
+    // fnmadds ft, fa, fb, fc
+    Single(0xEC00003E), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fnmadds." = [
+    // fnmadds. ft, fa, fb, fc
+    Single(0xEC00003F), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fnmsub" = [
+    // fnmsub ft, fa, fb, fc
+    Single(0xFC00003C), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fnmsub." = [
+    // fnmsub. ft, fa, fb, fc
+    Single(0xFC00003D), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fnmsubs" = [
+    // fnmsubs ft, fa, fb, fc
+    Single(0xEC00003C), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fnmsubs." = [
+    // fnmsubs. ft, fa, fb, fc
+    Single(0xEC00003D), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fsel" = [
+    // fsel ft, fa, fb, fc
+    Single(0xFC00002E), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"fsel." = [
+    // fsel. ft, fa, fb, fc
+    Single(0xFC00002F), [F, F, F, F] => [R(21), R(16), R(6), R(11)];
+],
+"frsp" = [
+    // frsp ft, fa
+    Single(0xFC000018), [F, F] => [R(21), R(11)];
+],
+"frsp." = [
+    // frsp. ft, fa
+    Single(0xFC000019), [F, F] => [R(21), R(11)];
+],
+"fctid" = [
+    // fctid ft, fa
+    Single(0xFC00065C), [F, F] => [R(21), R(11)];
+],
+"fctid." = [
+    // fctid. ft, fa
+    Single(0xFC00065D), [F, F] => [R(21), R(11)];
+],
+"fctidz" = [
+    // fctidz ft, fa
+    Single(0xFC00065E), [F, F] => [R(21), R(11)];
+],
+"fctidz." = [
+    // fctidz. ft, fa
+    Single(0xFC00065F), [F, F] => [R(21), R(11)];
+],
+"fctidu" = [
+    // fctidu ft, fa
+    Single(0xFC00075C), [F, F] => [R(21), R(11)];
+],
+"fctidu." = [
+    // fctidu. ft, fa
+    Single(0xFC00075D), [F, F] => [R(21), R(11)];
+],
+"fctiduz" = [
+    // fctiduz ft, fa
+    Single(0xFC00075E), [F, F] => [R(21), R(11)];
+],
+"fctiduz." = [
+    // fctiduz. ft, fa
+    Single(0xFC00075F), [F, F] => [R(21), R(11)];
+],
+"fctiw" = [
+    // fctiw ft, fa
+    Single(0xFC00001C), [F, F] => [R(21), R(11)];
+],
+"fctiw." = [
+    // fctiw. ft, fa
+    Single(0xFC00001D), [F, F] => [R(21), R(11)];
+],
+"fctiwz" = [
+    // fctiwz ft, fa
+    Single(0xFC00001E), [F, F] => [R(21), R(11)];
+],
+"fctiwz." = [
+    // fctiwz. ft, fa
+    Single(0xFC00001F), [F, F] => [R(21), R(11)];
+],
+"fctiwu" = [
+    // fctiwu ft, fa
+    Single(0xFC00011C), [F, F] => [R(21), R(11)];
+],
+"fctiwu." = [
+    // fctiwu. ft, fa
+    Single(0xFC00011D), [F, F] => [R(21), R(11)];
+],
+"fctiwuz" = [
+    // fctiwuz ft, fa
+    Single(0xFC00011E), [F, F] => [R(21), R(11)];
+],
+"fctiwuz." = [
+    // fctiwuz. ft, fa
+    Single(0xFC00011F), [F, F] => [R(21), R(11)];
+],
+"fcfid" = [
+    // fcfid ft, fa
+    Single(0xFC00069C), [F, F] => [R(21), R(11)];
+],
+"fcfid." = [
+    // fcfid. ft, fa
+    Single(0xFC00069D), [F, F] => [R(21), R(11)];
+],
+"fcfidu" = [
+    // fcfidu ft, fa
+    Single(0xFC00079C), [F, F] => [R(21), R(11)];
+],
+"fcfidu." = [
+    // fcfidu. ft, fa
+    Single(0xFC00079D), [F, F] => [R(21), R(11)];
+],
+"fcfids" = [
+    // fcfids ft, fa
+    Single(0xEC00069C), [F, F] => [R(21), R(11)];
+],
+"fcfids." = [
+    // fcfids. ft, fa
+    Single(0xEC00069D), [F, F] => [R(21), R(11)];
+],
+"fcfidus" = [
+    // fcfidus ft, fa
+    Single(0xEC00079C), [F, F] => [R(21), R(11)];
+],
+"fcfidus." = [
+    // fcfidus. ft, fa
+    Single(0xEC00079D), [F, F] => [R(21), R(11)];
+],
+"frin" = [
+    // frin ft, fa
+    Single(0xFC000310), [F, F] => [R(21), R(11)];
+],
+"frin." = [
+    // frin. ft, fa
+    Single(0xFC000311), [F, F] => [R(21), R(11)];
+],
+"friz" = [
+    // friz ft, fa
+    Single(0xFC000350), [F, F] => [R(21), R(11)];
+],
+"friz." = [
+    // friz. ft, fa
+    Single(0xFC000351), [F, F] => [R(21), R(11)];
+],
+"frip" = [
+    // frip ft, fa
+    Single(0xFC000390), [F, F] => [R(21), R(11)];
+],
+"frip." = [
+    // frip. ft, fa
+    Single(0xFC000391), [F, F] => [R(21), R(11)];
+],
+"frim" = [
+    // frim ft, fa
+    Single(0xFC0003D0), [F, F] => [R(21), R(11)];
+],
+"frim." = [
+    // frim. ft, fa
+    Single(0xFC0003D1), [F, F] => [R(21), R(11)];
+],
+"fcmpu" = [
+    // fcmpu cr, ft, fa
+    Single(0xFC000000), [Cr, F, F] => [C(23), R(16), R(11)];
+],
+"fcmpo" = [
+    // fcmpo cr, ft, fa
+    Single(0xFC000040), [Cr, F, F] => [C(23), R(16), R(11)];
+],
+"mffs" = [
+    // mffs ft
+    Single(0xFC00048E), [F] => [R(21)];
+],
+"mffs." = [
+    // mffs. ft
+    Single(0xFC00048F), [F] => [R(21)];
+],
+"mffsl" = [
+    // mffsl ft
+    Single(0xFC18048E), [F] => [R(21)];
+],
+"mffsce" = [
+    // mffsce ft
+    Single(0xFC01048E), [F] => [R(21)];
+],
+"mffscdrn" = [
+    // mffscdrn ft, fa
+    Single(0xFC14048E), [F, F] => [R(21), R(11)];
+],
+"mffscrn" = [
+    // mffscrn ft, fa
+    Single(0xFC16048E), [F, F] => [R(21), R(11)];
+],
+"mffscrni" = [
+    // mffscrni ft, u2
+    Single(0xFC17048E), [F, Imm] => [R(21), UImm(2, 0), BitRange(11, 2, 0), Next];
+],
+"mcrfs" = [
+    // mcrfs cr, cr
+    Single(0xFC000080), [Cr, Cr] => [C(23), C(18)];
+],
+"mtfsfi" = [
+    // mtfsfi cr, u4
+    Single(0xFC00010C), [Cr, Imm] => [C(23), UImm(4, 0), BitRange(12, 4, 0), Next];
+],
+"mtfsfi." = [
+    // mtfsfi. cr, u4
+    Single(0xFC00010D), [Cr, Imm] => [C(23), UImm(4, 0), BitRange(12, 4, 0), Next];
+],
+"mtfsf" = [
+    // mtfsf u8, ft
+    Single(0xFC00058E), [Imm, F] => [UImm(8, 0), BitRange(17, 8, 0), Next, R(11)];
+],
+"mtfsf." = [
+    // mtfsf. u8, ft
+    Single(0xFC00058F), [Imm, F] => [UImm(8, 0), BitRange(17, 8, 0), Next, R(11)];
+],
+"mtfsb0" = [
+    // mtfsb0 u5
+    Single(0xFC00008C), [Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next];
+],
+"mtfsb1" = [
+    // mtfsb1 u5
+    Single(0xFC00004C), [Imm] => [UImm(5, 0), BitRange(21, 5, 0), Next];
+],
+
+
+// VSX loads and stores
+
+"lxsdx" = [
+    // lxsdx vst, rt, ra
+    Single(0x7C000498), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxsiwax" = [
+    // lxsiwax vst, rt, ra
+    Single(0x7C000098), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxsiwzx" = [
+    // lxsiwzx vst, rt, ra
+    Single(0x7C000018), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxsspx" = [
+    // lxsspx vst, rt, ra
+    Single(0x7C000418), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxsibzx" = [
+    // lxsibzx vst, rt, ra
+    Single(0x7C00061A), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxsihzx" = [
+    // lxsihzx vst, rt, ra
+    Single(0x7C00065A), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxvd2x" = [
+    // lxvd2x vst, rt, ra
+    Single(0x7C000698), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxvdsx" = [
+    // lxvdsx vst, rt, ra
+    Single(0x7C000298), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxvw4x" = [
+    // lxvw4x vst, rt, ra
+    Single(0x7C000618), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxvh8x" = [
+    // lxvh8x vst, rt, ra
+    Single(0x7C000658), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxvb16x" = [
+    // lxvb16x vst, rt, ra
+    Single(0x7C0006D8), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxvx" = [
+    // lxvx vst, rt, ra
+    Single(0x7C000218), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxvwsx" = [
+    // lxvwsx vst, rt, ra
+    Single(0x7C0002D8), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxvl" = [
+    // lxvl vst, rt, ra
+    Single(0x7C00021A), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxvll" = [
+    // lxvll vst, rt, ra
+    Single(0x7C00025A), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"lxv" = [
+    // lxv vst, [ra, dq]
+    Single(0xF4000001), [Vs, RefOffset] => [Vs(21, 3), R(16), SImm(16, 4), BitRange(4, 12, 4), Next];
+],
+"lxsd" = [
+    // lxsd vt, [ra, ds]
+    Single(0xE4000002), [V, RefOffset] => [R(21), R(16), SImm(16, 2), BitRange(2, 14, 2), Next];
+],
+"lxssp" = [
+    // lxssp vt, [ra, ds]
+    Single(0xE4000003), [V, RefOffset] => [R(21), R(16), SImm(16, 2), BitRange(2, 14, 2), Next];
+],
+"stxsdx" = [
+    // stxsdx vst, rt, ra
+    Single(0x7C000598), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"stxsiwx" = [
+    // stxsiwx vst, rt, ra
+    Single(0x7C000118), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"stxsspx" = [
+    // stxsspx vst, rt, ra
+    Single(0x7C000518), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"stxsibx" = [
+    // stxsibx vst, rt, ra
+    Single(0x7C00071A), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"stxsihx" = [
+    // stxsihx vst, rt, ra
+    Single(0x7C00075A), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"stxvd2x" = [
+    // stxvd2x vst, rt, ra
+    Single(0x7C000798), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"stxvw4x" = [
+    // stxvw4x vst, rt, ra
+    Single(0x7C000718), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"stxvh8x" = [
+    // stxvh8x vst, rt, ra
+    Single(0x7C000758), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"stxvb16x" = [
+    // stxvb16x vst, rt, ra
+    Single(0x7C0007D8), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"stxvx" = [
+    // stxvx vst, rt, ra
+    Single(0x7C000318), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"stxvl" = [
+    // stxvl vst, rt, ra
+    Single(0x7C00031A), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"stxvll" = [
+    // stxvll vst, rt, ra
+    Single(0x7C00035A), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"stxv" = [
+    // stxv vst, [ra, dq]
+    Single(0xF4000005), [Vs, RefOffset] => [Vs(21, 3), R(16), SImm(16, 4), BitRange(4, 12, 4), Next];
+],
+"stxsd" = [
+    // stxsd vt, [ra, ds]
+    Single(0xF4000002), [V, RefOffset] => [R(21), R(16), SImm(16, 2), BitRange(2, 14, 2), Next];
+],
+"stxssp" = [
+    // stxssp vt, [ra, ds]
+    Single(0xF4000003), [V, RefOffset] => [R(21), R(16), SImm(16, 2), BitRange(2, 14, 2), Next];
+],
+
+
+// VSX scalar instructions
+
+"xsadddp" = [
+    // xsadddp vst, vsa, vsb
+    Single(0xF0000100), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xssubdp" = [
+    // xssubdp vst, vsa, vsb
+    Single(0xF0000140), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmuldp" = [
+    // xsmuldp vst, vsa, vsb
+    Single(0xF0000180), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsdivdp" = [
+    // xsdivdp vst, vsa, vsb
+    Single(0xF00001C0), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsaddsp" = [
+    // xsaddsp vst, vsa, vsb
+    Single(0xF0000000), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xssubsp" = [
+    // xssubsp vst, vsa, vsb
+    Single(0xF0000040), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmulsp" = [
+    // xsmulsp vst, vsa, vsb
+    Single(0xF0000080), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsdivsp" = [
+    // xsdivsp vst, vsa, vsb
+    Single(0xF00000C0), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmaxdp" = [
+    // xsmaxdp vst, vsa, vsb
+    Single(0xF0000500), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmindp" = [
+    // xsmindp vst, vsa, vsb
+    Single(0xF0000540), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmaxcdp" = [
+    // xsmaxcdp vst, vsa, vsb
+    Single(0xF0000400), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmincdp" = [
+    // xsmincdp vst, vsa, vsb
+    Single(0xF0000440), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmaxjdp" = [
+    // xsmaxjdp vst, vsa, vsb
+    Single(0xF0000480), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsminjdp" = [
+    // xsminjdp vst, vsa, vsb
+    Single(0xF00004C0), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xscpsgndp" = [
+    // xscpsgndp vst, vsa, vsb
+    Single(0xF0000580), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmaddadp" = [
+    // xsmaddadp vst, vsa, vsb
+    Single(0xF0000108), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmaddmdp" = [
+    // xsmaddmdp vst, vsa, vsb
+    Single(0xF0000148), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmsubadp" = [
+    // xsmsubadp vst, vsa, vsb
+    Single(0xF0000188), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmsubmdp" = [
+    // xsmsubmdp vst, vsa, vsb
+    Single(0xF00001C8), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsnmaddadp" = [
+    // xsnmaddadp vst, vsa, vsb
+    Single(0xF0000508), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsnmaddmdp" = [
+    // xsnmaddmdp vst, vsa, vsb
+    Single(0xF0000548), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsnmsubadp" = [
+    // xsnmsubadp vst, vsa, vsb
+    Single(0xF0000588), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsnmsubmdp" = [
+    // xsnmsubmdp vst, vsa, vsb
+    Single(0xF00005C8), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmaddasp" = [
+    // xsmaddasp vst, vsa, vsb
+    Single(0xF0000008), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmaddmsp" = [
+    // xsmaddmsp vst, vsa, vsb
+    Single(0xF0000048), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmsubasp" = [
+    // xsmsubasp vst, vsa, vsb
+    Single(0xF0000088), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsmsubmsp" = [
+    // xsmsubmsp vst, vsa, vsb
+    Single(0xF00000C8), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsnmaddasp" = [
+    // xsnmaddasp vst, vsa, vsb
+    Single(0xF0000408), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsnmaddmsp" = [
+    // xsnmaddmsp vst, vsa, vsb
+    Single(0xF0000448), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsnmsubasp" = [
+    // xsnmsubasp vst, vsa, vsb
+    Single(0xF0000488), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xsnmsubmsp" = [
+    // xsnmsubmsp vst, vsa, vsb
+    Single(0xF00004C8), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xscmpeqdp" = [
+    // xscmpeqdp vst, vsa, vsb
+    Single(0xF0000018), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xscmpgtdp" = [
+    // xscmpgtdp vst, vsa, vsb
+    Single(0xF0000058), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xscmpgedp" = [
+    // xscmpgedp vst, vsa, vsb
+    Single(0xF0000098), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xssqrtdp" = [
+    // xssqrtdp vst, vsa
+    Single(0xF000012C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xssqrtsp" = [
+    // xssqrtsp vst, vsa
+    Single(0xF000002C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xsredp" = [
+    // xsredp vst, vsa
+    Single(0xF0000168), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xsresp" = [
+    // xsresp vst, vsa
+    Single(0xF0000068), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xsrsqrtedp" = [
+    // xsrsqrtedp vst, vsa
+    Single(0xF0000128), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xsrsqrtesp" = [
+    // xsrsqrtesp vst, vsa
+    Single(0xF0000028), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xsabsdp" = [
+    // xsabsdp vst, vsa
+    Single(0xF0000564), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xsnabsdp" = [
+    // xsnabsdp vst, vsa
+    Single(0xF00005A4), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xsnegdp" = [
+    // xsnegdp vst, vsa
+    Single(0xF00005E4), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscvdpsp" = [
+    // xscvdpsp vst, vsa
+    Single(0xF0000424), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscvdpspn" = [
+    // xscvdpspn vst, vsa
+    Single(0xF000042C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscvspdp" = [
+    // xscvspdp vst, vsa
+    Single(0xF0000524), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscvspdpn" = [
+    // xscvspdpn vst, vsa
+    Single(0xF000052C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscvdpsxds" = [
+    // xscvdpsxds vst, vsa
+    Single(0xF0000560), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscvdpsxws" = [
+    // xscvdpsxws vst, vsa
+    Single(0xF0000160), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscvdpuxds" = [
+    // xscvdpuxds vst, vsa
+    Single(0xF0000520), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscvdpuxws" = [
+    // xscvdpuxws vst, vsa
+    Single(0xF0000120), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscvsxddp" = [
+    // xscvsxddp vst, vsa
+    Single(0xF00005E0), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscvuxddp" = [
+    // xscvuxddp vst, vsa
+    Single(0xF00005A0), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscvsxdsp" = [
+    // xscvsxdsp vst, vsa
+    Single(0xF00004E0), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscvuxdsp" = [
+    // xscvuxdsp vst, vsa
+    Single(0xF00004A0), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscvhpdp" = [
+    // xscvhpdp vst, vsa
+    Single(0xF010056C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscvdphp" = [
+    // xscvdphp vst, vsa
+    Single(0xF011056C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xsrdpi" = [
+    // xsrdpi vst, vsa
+    Single(0xF0000124), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xsrdpic" = [
+    // xsrdpic vst, vsa
+    Single(0xF00001AC), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xsrdpim" = [
+    // xsrdpim vst, vsa
+    Single(0xF00001E4), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xsrdpip" = [
+    // xsrdpip vst, vsa
+    Single(0xF00001A4), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xsrdpiz" = [
+    // xsrdpiz vst, vsa
+    Single(0xF0000164), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xsrsp" = [
+    // xsrsp vst, vsa
+    Single(0xF0000464), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xscmpudp" = [
+    // xscmpudp cr, vst, vsa
+    Single(0xF0000118), [Cr, Vs, Vs] => [C(23), Vs(16, 2), Vs(11, 1)];
+],
+"xscmpodp" = [
+    // xscmpodp cr, vst, vsa
+    Single(0xF0000158), [Cr, Vs, Vs] => [C(23), Vs(16, 2), Vs(11, 1)];
+],
+"xscmpexpdp" = [
+    // xscmpexpdp cr, vst, vsa
+    Single(0xF00001D8), [Cr, Vs, Vs] => [C(23), Vs(16, 2), Vs(11, 1)];
+],
+"xstdivdp" = [
+    // xstdivdp cr, vst, vsa
+    Single(0xF00001E8), [Cr, Vs, Vs] => [C(23), Vs(16, 2), Vs(11, 1)];
+],
+"xstsqrtdp" = [
+    // xstsqrtdp cr, vst
+    Single(0xF00001A8), [Cr, Vs] => [C(23), Vs(11, 1)];
+],
+"xststdcdp" = [
+    // xststdcdp cr, vst, u7
+    Single(0xF00005A8), [Cr, Vs, Imm] => [C(23), Vs(11, 1), UImm(7, 0), BitRange(16, 7, 0), Next];
+],
+"xststdcsp" = [
+    // xststdcsp cr, vst, u7
+    Single(0xF00004A8), [Cr, Vs, Imm] => [C(23), Vs(11, 1), UImm(7, 0), BitRange(16, 7, 0), Next];
+],
+"xsxexpdp" = [
+    // xsxexpdp rt, vst
+    Single(0xF000056C), [R, Vs] => [R(21), Vs(11, 1)];
+],
+"xsxsigdp" = [
+    // xsxsigdp rt, vst
+    Single(0xF001056C), [R, Vs] => [R(21), Vs(11, 1)];
+],
+"xsiexpdp" = [
+    // xsiexpdp vst, rt, ra
+    Single(0xF000072C), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+
+
+// VSX vector instructions
+
+"xvadddp" = [
+    // xvadddp vst, vsa, vsb
+    Single(0xF0000300), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvaddsp" = [
+    // xvaddsp vst, vsa, vsb
+    Single(0xF0000200), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvsubdp" = [
+    // xvsubdp vst, vsa, vsb
+    Single(0xF0000340), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvsubsp" = [
+    // xvsubsp vst, vsa, vsb
+    Single(0xF0000240), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvmuldp" = [
+    // xvmuldp vst, vsa, vsb
+    Single(0xF0000380), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvmulsp" = [
+    // xvmulsp vst, vsa, vsb
+    Single(0xF0000280), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvdivdp" = [
+    // xvdivdp vst, vsa, vsb
+    Single(0xF00003C0), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvdivsp" = [
+    // xvdivsp vst, vsa, vsb
+    Single(0xF00002C0), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvmaxdp" = [
+    // xvmaxdp vst, vsa, vsb
+    Single(0xF0000700), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvmaxsp" = [
+    // xvmaxsp vst, vsa, vsb
+    Single(0xF0000600), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvmindp" = [
+    // xvmindp vst, vsa, vsb
+    Single(0xF0000740), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvminsp" = [
+    // xvminsp vst, vsa, vsb
+    Single(0xF0000640), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvcpsgndp" = [
+    // xvcpsgndp vst, vsa, vsb
+    Single(0xF0000780), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvcpsgnsp" = [
+    // xvcpsgnsp vst, vsa, vsb
+    Single(0xF0000680), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvmaddadp" = [
+    // xvmaddadp vst, vsa, vsb
+    Single(0xF0000308), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvmaddmdp" = [
+    // xvmaddmdp vst, vsa, vsb
+    Single(0xF0000348), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvmaddasp" = [
+    // xvmaddasp vst, vsa, vsb
+    Single(0xF0000208), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvmaddmsp" = [
+    // xvmaddmsp vst, vsa, vsb
+    Single(0xF0000248), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvmsubadp" = [
+    // xvmsubadp vst, vsa, vsb
+    Single(0xF0000388), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvmsubmdp" = [
+    // xvmsubmdp vst, vsa, vsb
+    Single(0xF00003C8), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvmsubasp" = [
+    // xvmsubasp vst, vsa, vsb
+    Single(0xF0000288), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvmsubmsp" = [
+    // xvmsubmsp vst, vsa, vsb
+    Single(0xF00002C8), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvnmaddadp" = [
+    // xvnmaddadp vst, vsa, vsb
+    Single(0xF0000708), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvnmaddmdp" = [
+    // xvnmaddmdp vst, vsa, vsb
+    Single(0xF0000748), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvnmaddasp" = [
+    // xvnmaddasp vst, vsa, vsb
+    Single(0xF0000608), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvnmaddmsp" = [
+    // xvnmaddmsp vst, vsa, vsb
+    Single(0xF0000648), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvnmsubadp" = [
+    // xvnmsubadp vst, vsa, vsb
+    Single(0xF0000788), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvnmsubmdp" = [
+    // xvnmsubmdp vst, vsa, vsb
+    Single(0xF00007C8), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvnmsubasp" = [
+    // xvnmsubasp vst, vsa, vsb
+    Single(0xF0000688), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvnmsubmsp" = [
+    // xvnmsubmsp vst, vsa, vsb
+    Single(0xF00006C8), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xviexpdp" = [
+    // xviexpdp vst, vsa, vsb
+    Single(0xF00007C0), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xviexpsp" = [
+    // xviexpsp vst, vsa, vsb
+    Single(0xF00006C0), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvcmpeqdp" = [
+    // xvcmpeqdp vst, vsa, vsb
+    Single(0xF0000318), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvcmpeqdp." = [
+    // xvcmpeqdp. vst, vsa, vsb
+    Single(0xF0000718), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvcmpgtdp" = [
+    // xvcmpgtdp vst, vsa, vsb
+    Single(0xF0000358), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvcmpgtdp." = [
+    // xvcmpgtdp. vst, vsa, vsb
+    Single(0xF0000758), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvcmpgedp" = [
+    // xvcmpgedp vst, vsa, vsb
+    Single(0xF0000398), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvcmpgedp." = [
+    // xvcmpgedp. vst, vsa, vsb
+    Single(0xF0000798), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvcmpeqsp" = [
+    // xvcmpeqsp vst, vsa, vsb
+    Single(0xF0000218), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvcmpeqsp." = [
+    // xvcmpeqsp. vst, vsa, vsb
+    Single(0xF0000618), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvcmpgtsp" = [
+    // xvcmpgtsp vst, vsa, vsb
+    Single(0xF0000258), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvcmpgtsp." = [
+    // xvcmpgtsp. vst, vsa, vsb
+    Single(0xF0000658), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvcmpgesp" = [
+    // xvcmpgesp vst, vsa, vsb
+    Single(0xF0000298), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvcmpgesp." = [
+    // xvcmpgesp. vst, vsa, vsb
+    Single(0xF0000698), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xvsqrtdp" = [
+    // xvsqrtdp vst, vsa
+    Single(0xF000032C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvsqrtsp" = [
+    // xvsqrtsp vst, vsa
+    Single(0xF000022C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvredp" = [
+    // xvredp vst, vsa
+    Single(0xF0000368), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvresp" = [
+    // xvresp vst, vsa
+    Single(0xF0000268), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvrsqrtedp" = [
+    // xvrsqrtedp vst, vsa
+    Single(0xF0000328), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvrsqrtesp" = [
+    // xvrsqrtesp vst, vsa
+    Single(0xF0000228), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvabsdp" = [
+    // xvabsdp vst, vsa
+    Single(0xF0000764), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvabssp" = [
+    // xvabssp vst, vsa
+    Single(0xF0000664), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvnabsdp" = [
+    // xvnabsdp vst, vsa
+    Single(0xF00007A4), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvnabssp" = [
+    // xvnabssp vst, vsa
+    Single(0xF00006A4), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvnegdp" = [
+    // xvnegdp vst, vsa
+    Single(0xF00007E4), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvnegsp" = [
+    // xvnegsp vst, vsa
+    Single(0xF00006E4), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvdpsp" = [
+    // xvcvdpsp vst, vsa
+    Single(0xF0000624), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvspdp" = [
+    // xvcvspdp vst, vsa
+    Single(0xF0000724), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvdpsxds" = [
+    // xvcvdpsxds vst, vsa
+    Single(0xF0000760), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvdpsxws" = [
+    // xvcvdpsxws vst, vsa
+    Single(0xF0000360), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvdpuxds" = [
+    // xvcvdpuxds vst, vsa
+    Single(0xF0000720), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvdpuxws" = [
+    // xvcvdpuxws vst, vsa
+    Single(0xF0000320), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvspsxds" = [
+    // xvcvspsxds vst, vsa
+    Single(0xF0000660), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvspsxws" = [
+    // xvcvspsxws vst, vsa
+    Single(0xF0000260), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvspuxds" = [
+    // xvcvspuxds vst, vsa
+    Single(0xF0000620), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvspuxws" = [
+    // xvcvspuxws vst, vsa
+    Single(0xF0000220), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvsxddp" = [
+    // xvcvsxddp vst, vsa
+    Single(0xF00007E0), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvsxdsp" = [
+    // xvcvsxdsp vst, vsa
+    Single(0xF00006E0), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvsxwdp" = [
+    // xvcvsxwdp vst, vsa
+    Single(0xF00003E0), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvsxwsp" = [
+    // xvcvsxwsp vst, vsa
+    Single(0xF00002E0), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvuxddp" = [
+    // xvcvuxddp vst, vsa
+    Single(0xF00007A0), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvuxdsp" = [
+    // xvcvuxdsp vst, vsa
+    Single(0xF00006A0), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvuxwdp" = [
+    // xvcvuxwdp vst, vsa
+    Single(0xF00003A0), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvuxwsp" = [
+    // xvcvuxwsp vst, vsa
+    Single(0xF00002A0), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvhpsp" = [
+    // xvcvhpsp vst, vsa
+    Single(0xF018076C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvcvsphp" = [
+    // xvcvsphp vst, vsa
+    Single(0xF019076C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvrdpi" = [
+    // xvrdpi vst, vsa
+    Single(0xF0000324), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvrdpic" = [
+    // xvrdpic vst, vsa
+    Single(0xF00003AC), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvrdpim" = [
+    // xvrdpim vst, vsa
+    Single(0xF00003E4), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvrdpip" = [
+    // xvrdpip vst, vsa
+    Single(0xF00003A4), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvrdpiz" = [
+    // xvrdpiz vst, vsa
+    Single(0xF0000364), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvrspi" = [
+    // xvrspi vst, vsa
+    Single(0xF0000224), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvrspic" = [
+    // xvrspic vst, vsa
+    Single(0xF00002AC), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvrspim" = [
+    // xvrspim vst, vsa
+    Single(0xF00002E4), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvrspip" = [
+    // xvrspip vst, vsa
+    Single(0xF00002A4), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvrspiz" = [
+    // xvrspiz vst, vsa
+    Single(0xF0000264), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvxexpdp" = [
+    // xvxexpdp vst, vsa
+    Single(0xF000076C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvxexpsp" = [
+    // xvxexpsp vst, vsa
+    Single(0xF008076C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvxsigdp" = [
+    // xvxsigdp vst, vsa
+    Single(0xF001076C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvxsigsp" = [
+    // xvxsigsp vst, vsa
+    Single(0xF009076C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xvtdivdp" = [
+    // xvtdivdp cr, vst, vsa
+    Single(0xF00003E8), [Cr, Vs, Vs] => [C(23), Vs(16, 2), Vs(11, 1)];
+],
+"xvtdivsp" = [
+    // xvtdivsp cr, vst, vsa
+    Single(0xF00002E8), [Cr, Vs, Vs] => [C(23), Vs(16, 2), Vs(11, 1)];
+],
+"xvtsqrtdp" = [
+    // xvtsqrtdp cr, vst
+    Single(0xF00003A8), [Cr, Vs] => [C(23), Vs(11, 1)];
+],
+"xvtsqrtsp" = [
+    // xvtsqrtsp cr, vst
+    Single(0xF00002A8), [Cr, Vs] => [C(23), Vs(11, 1)];
+],
+"xvtstdcdp" = [
+    // xvtstdcdp vst, vsa, u7
+    Single(0xF00007A8), [Vs, Vs, Imm] => [Vs(21, 0), Vs(11, 1), UImm(7, 0), BitRange(16, 5, 0), BitRange(6, 1, 6), BitRange(2, 1, 5), Next];
+],
+"xvtstdcsp" = [
+    // xvtstdcsp vst, vsa, u7
+    Single(0xF00006A8), [Vs, Vs, Imm] => [Vs(21, 0), Vs(11, 1), UImm(7, 0), BitRange(16, 5, 0), BitRange(6, 1, 6), BitRange(2, 1, 5), Next];
+],
+
+
+// VSX logical and permute instructions
+
+"xxland" = [
+    // xxland vst, vsa, vsb
+    Single(0xF0000410), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xxlandc" = [
+    // xxlandc vst, vsa, vsb
+    Single(0xF0000450), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xxleqv" = [
+    // xxleqv vst, vsa, vsb
+    Single(0xF00005D0), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xxlnand" = [
+    // xxlnand vst, vsa, vsb
+    Single(0xF0000590), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xxlnor" = [
+    // xxlnor vst, vsa, vsb
+    Single(0xF0000510), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xxlor" = [
+    // xxlor vst, vsa, vsb
+    Single(0xF0000490), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xxlorc" = [
+    // xxlorc vst, vsa, vsb
+    Single(0xF0000550), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xxlxor" = [
+    // xxlxor vst, vsa, vsb
+    Single(0xF00004D0), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xxsel" = [
+    // xxsel vst, vsa, vsb, vsc
+    Single(0xF0000030), [Vs, Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1), Vs(6, 3)];
+],
+"xxmrghw" = [
+    // xxmrghw vst, vsa, vsb
+    Single(0xF0000090), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xxmrglw" = [
+    // xxmrglw vst, vsa, vsb
+    Single(0xF0000190), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xxmrghd" = [
+    // xxmrghd vst, vsa, vsb
+    Single(0xF0000050), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xxmrgld" = [
+    // xxmrgld vst, vsa, vsb
+    Single(0xF0000350), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xxperm" = [
+    // xxperm vst, vsa, vsb
+    Single(0xF00000D0), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xxpermr" = [
+    // xxpermr vst, vsa, vsb
+    Single(0xF00001D0), [Vs, Vs, Vs] => [Vs(21, 0), Vs(16, 2), Vs(11, 1)];
+],
+"xxpermdi" = [
+    // xxpermdi vst, vsa, vsb, u2
+    Single(0xF0000050), [Vs, Vs, Vs, Imm] => [Vs(21, 0), Vs(16, 2), Vs(11, 1), UImm(2, 0), BitRange(8, 2, 0), Next];
+],
+"xxsldwi" = [
+    // xxsldwi vst, vsa, vsb, u2
+    Single(0xF0000010), [Vs, Vs, Vs, Imm] => [Vs(21, 0), Vs(16, 2), Vs(11, 1), UImm(2, 0), BitRange(8, 2, 0), Next];
+],
+"xxspltw" = [
+    // xxspltw vst, vsa, u2
+    Single(0xF0000290), [Vs, Vs, Imm] => [Vs(21, 0), Vs(11, 1), UImm(2, 0), BitRange(16, 2, 0), Next];
+],
+"xxspltd" = [
+    // xxspltd vst, vsa, u1
+    Single(0xF0000050), [Vs, Vs, Imm] => [Vs(21, 0), Vs(11, 1), Repeat, Vs(16, 2), UImm(1, 0), BitRange(9, 1, 0), BitRange(8, 1, 0), Next];
+],
+"xxswapd" = [
+    // xxswapd vst, vsa
+    Single(0xF0000250), [Vs, Vs] => [Vs(21, 0), Vs(11, 1), Repeat, Vs(16, 2)];
+],
+"xxspltib" = [
+    // xxspltib vst, u8
+    Single(0xF00002D0), [Vs, Imm] => [Vs(21, 0), UImm(8, 0), BitRange(11, 8, 0), Next];
+],
+"xxextractuw" = [
+    // xxextractuw vst, vsa, u4
+    Single(0xF0000294), [Vs, Vs, Imm] => [Vs(21, 0), Vs(11, 1), UImm(4, 0), BitRange(16, 4, 0), Next];
+],
+"xxinsertw" = [
+    // xxinsertw vst, vsa, u4
+    Single(0xF00002D4), [Vs, Vs, Imm] => [Vs(21, 0), Vs(11, 1), UImm(4, 0), BitRange(16, 4, 0), Next];
+],
+"xxbrh" = [
+    // xxbrh vst, vsa
+    Single(0xF007076C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xxbrw" = [
+    // xxbrw vst, vsa
+    Single(0xF00F076C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xxbrd" = [
+    // xxbrd vst, vsa
+    Single(0xF017076C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+"xxbrq" = [
+    // xxbrq vst, vsa
+    Single(0xF01F076C), [Vs, Vs] => [Vs(21, 0), Vs(11, 1)];
+],
+
+
+// VSX moves to and from general purpose registers
+
+"mtvsrd" = [
+    // mtvsrd vst, rt
+    Single(0x7C000166), [Vs, R] => [Vs(21, 0), R(16)];
+],
+"mtvsrwa" = [
+    // mtvsrwa vst, rt
+    Single(0x7C0001A6), [Vs, R] => [Vs(21, 0), R(16)];
+],
+"mtvsrwz" = [
+    // mtvsrwz vst, rt
+    Single(0x7C0001E6), [Vs, R] => [Vs(21, 0), R(16)];
+],
+"mtvsrdd" = [
+    // mtvsrdd vst, rt, ra
+    Single(0x7C000366), [Vs, R, R] => [Vs(21, 0), R(16), R(11)];
+],
+"mtvsrws" = [
+    // mtvsrws vst, rt
+    Single(0x7C000326), [Vs, R] => [Vs(21, 0), R(16)];
+],
+"mfvsrd" = [
+    // mfvsrd rt, vst
+    Single(0x7C000066), [R, Vs] => [R(16), Vs(21, 0)];
+],
+"mfvsrwz" = [
+    // mfvsrwz rt, vst
+    Single(0x7C0000E6), [R, Vs] => [R(16), Vs(21, 0)];
+],
+"mfvsrld" = [
+    // mfvsrld rt, vst
+    Single(0x7C000266), [R, Vs] => [R(16), Vs(21, 0)];
+],
+)
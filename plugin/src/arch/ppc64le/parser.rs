@@ -0,0 +1,465 @@
+use std::collections::HashMap;
+
+use syn::{parse, Token};
+use syn::spanned::Spanned;
+use proc_macro2::Span;
+
+
+use lazy_static::lazy_static;
+
+use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOptExt};
+use crate::serialize;
+
+use super::{Context, ast};
+
+// syntax for a single op: ident "."? (arg ("," arg)*)? ";"
+pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<ast::ParsedInstruction> {
+    let span = input.cursor().span();
+
+    // read the op. A trailing dot selects the record form of an instruction
+    let mut name = parse_ident_or_rust_keyword(input)?.to_string();
+
+    if input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        name.push('.');
+    }
+
+    let mut args = Vec::new();
+
+    // parse 0 or more comma-separated args
+    if !(input.is_empty() || input.peek(Token![;])) {
+        args.push(parse_arg(ctx, input)?);
+
+        while input.peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+
+            args.push(parse_arg(ctx, input)?);
+        }
+    }
+
+    // let span = span.join(input.cursor().span()); // FIXME can't join spans ATM
+
+    Ok(ast::ParsedInstruction {
+        name,
+        span,
+        args
+    })
+}
+
+
+/// tries to parse a full arg definition
+fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<ast::RawArg> {
+    let start = input.cursor().span(); // FIXME can't join spans yet
+
+    // a label, identified by a leading < / > / -> / =>
+    if let Some(jump) = input.parse_opt()? {
+        return Ok(ast::RawArg::JumpTarget {
+            jump
+        });
+    }
+
+    // a memory reference. PowerPC assembly normally writes these as `offset(base)`, but we use
+    // '[' base [ ',' offset | label ] ']' syntax to match the other architectures.
+    if input.peek(syn::token::Bracket) {
+        let span = input.cursor().span();
+        let inner;
+        let _ = syn::bracketed!(inner in input);
+        let inner = &inner;
+
+        let base = parse_reg(ctx, inner)?.ok_or_else(|| inner.error("Expected register"))?;
+
+        if inner.peek(Token![,]) {
+            let _: Token![,] = inner.parse()?;
+
+            if let Some(jump) = inner.parse_opt()? {
+                return Ok(ast::RawArg::LabelReference {
+                    span,
+                    base,
+                    jump
+                });
+            }
+
+            if parse_reg(ctx, inner)?.is_some() {
+                return Err(inner.error("PowerPC indexed loads and stores take their base and index registers as plain operands"));
+            }
+
+            let expr: syn::Expr = inner.parse()?;
+
+            return Ok(ast::RawArg::Reference {
+                span,
+                base,
+                offset: Some(expr)
+            });
+
+        } else {
+            return Ok(ast::RawArg::Reference {
+                span,
+                base,
+                offset: None
+            });
+        };
+    }
+
+    // a register, or a type mapped reference (reg => Type[index].attr)
+    if let Some(reg) = parse_reg(ctx, input)? {
+        if input.peek(Token![=>]) {
+            return parse_typemap(ctx, input, reg, start);
+        }
+
+        return Ok(ast::RawArg::Register {
+            reg,
+            span: start
+        })
+    }
+
+    // immediate
+    let expr: syn::Expr = input.parse()?;
+
+    Ok(ast::RawArg::Immediate { value: expr })
+}
+
+/// Parses the remainder of a type mapped reference, `base => Type ("[" index "]")? ("." attr)?`
+/// The index has to be an immediate. It is combined with the offset of the attribute into the
+/// reference offset.
+fn parse_typemap(ctx: &mut Context, input: parse::ParseStream, base: ast::Register, span: Span) -> parse::Result<ast::RawArg> {
+    let _: Token![=>] = input.parse()?;
+
+    let ty: syn::Path = input.parse()?;
+
+    let index = if input.peek(syn::token::Bracket) {
+        let inner;
+        let _ = syn::bracketed!(inner in input);
+        let inner = &inner;
+
+        let expr: syn::Expr = inner.parse()?;
+        if parse_reg_from_expression(ctx, &expr)?.is_some() {
+            return Err(parse::Error::new(expr.span(), "PowerPC type mapped references cannot use an index register"));
+        }
+
+        Some(expr)
+    } else {
+        None
+    };
+
+    let attr: Option<syn::Ident> = if input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        Some(input.parse()?)
+    } else {
+        None
+    };
+
+    let offset = match serialize::expr_typemap_disp(span, &ty, index.as_ref(), attr.as_ref()) {
+        Some(disp) => Some(serialize::reparse(&disp)?),
+        None => None
+    };
+
+    Ok(ast::RawArg::Reference {
+        span,
+        base,
+        offset
+    })
+}
+
+/// Checks if the given expression could be a valid PowerPC register reference
+/// This can be a simple register name (like `r5`)
+/// an alias (any simple name that is registered, like `base`)
+/// or a dynamic register (like `R(expr)`)
+fn parse_reg_from_expression(ctx: &mut Context, expr: &syn::Expr) -> parse::Result<Option<ast::Register>> {
+    Ok(match expr {
+        syn::Expr::Call(exprcall) => {
+            let name = match &*exprcall.func {
+                syn::Expr::Path(exprpath) => match exprpath.path.get_ident() {
+                    Some(ident) => ident.to_string(),
+                    None => return Ok(None)
+                }
+                _ => return Ok(None)
+            };
+
+            if exprcall.args.len() != 1 {
+                return Err(parse::Error::new(
+                    expr.span(),
+                    "Too many arguments in register family expression"
+                ));
+            }
+
+            if let Some(&family) = PPC64LE_FAMILIES.get(&*name) {
+                Some(ast::Register::Dynamic(family, exprcall.args[0].clone()))
+            } else {
+                None
+            }
+        },
+        syn::Expr::Path(exprpath) => {
+            let mut name = match exprpath.path.get_ident() {
+                Some(ident) => ident.to_string(),
+                None => return Ok(None)
+            };
+
+            // fail if it is a family reference without call expression
+            if PPC64LE_FAMILIES.contains_key(&*name) {
+                return Err(parse::Error::new(
+                    exprpath.path.span(),
+                    "Register family reference without dynamic register id"
+                ));
+            }
+
+            // check if it is an alias
+            if let Some(repl) = ctx.state.invocation_context.aliases.get(&name) {
+                name = repl.clone();
+            }
+
+            // resolve normal register references
+            PPC64LE_REGISTERS.get(&*name).cloned().map(ast::Register::Static)
+        },
+        _ =>  None
+    })
+}
+
+/// Parses a single register, if present
+/// This can be a simple register name (like `r5`)
+/// an alias (any simple name that is registered, like `base`)
+/// or a dynamic register (like `R(expr)`)
+fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<ast::Register>> {
+    // we need to consume an ident, but only if it's one of the many we care about
+    // so use a step parser to figure it out.
+    let name = input.step(|cursor| {
+        if let Some((ident, rest)) = cursor.ident() {
+            let mut ident = ident.to_string();
+
+            // first, parse known register families
+            if PPC64LE_FAMILIES.contains_key(&*ident) {
+                return Ok((ident, rest));
+            }
+
+            // otherwise, see if this is an alias
+            if let Some(repl) = ctx.state.invocation_context.aliases.get(&ident) {
+                ident = repl.clone();
+            }
+
+            // resolve normal register references
+            if PPC64LE_REGISTERS.contains_key(&*ident) {
+                return Ok((ident, rest));
+            }
+        }
+        Err(cursor.error("expected identifier"))
+    });
+
+    let name = match name {
+        Ok(name) => name,
+        Err(_) => return Ok(None)
+    };
+
+    // we know we have a register reference now, try to resolve it.
+    let register = if let Some(&id) = PPC64LE_REGISTERS.get(&*name) {
+        ast::Register::Static(id)
+    } else if let Some(&family) = PPC64LE_FAMILIES.get(&*name) {
+        // need to parse the trailing `( expr )`
+        let inner;
+        let _ = syn::parenthesized!(inner in input);
+        let inner = &inner;
+
+        let expr: syn::Expr = inner.parse()?;
+
+        ast::Register::Dynamic(family, expr)
+    } else {
+        unreachable!()
+    };
+
+    Ok(Some(register))
+}
+
+
+lazy_static!{
+    static ref PPC64LE_REGISTERS: HashMap<&'static str, ast::RegId> = {
+        use ast::RegId::*;
+
+        static MAP: &[(&str, ast::RegId)] = &[
+            ("r0" , R0),
+            ("r1" , R1),
+            ("r2" , R2),
+            ("r3" , R3),
+            ("r4" , R4),
+            ("r5" , R5),
+            ("r6" , R6),
+            ("r7" , R7),
+            ("r8" , R8),
+            ("r9" , R9),
+            ("r10", R10),
+            ("r11", R11),
+            ("r12", R12),
+            ("r13", R13),
+            ("r14", R14),
+            ("r15", R15),
+            ("r16", R16),
+            ("r17", R17),
+            ("r18", R18),
+            ("r19", R19),
+            ("r20", R20),
+            ("r21", R21),
+            ("r22", R22),
+            ("r23", R23),
+            ("r24", R24),
+            ("r25", R25),
+            ("r26", R26),
+            ("r27", R27),
+            ("r28", R28),
+            ("r29", R29),
+            ("r30", R30),
+            ("r31", R31),
+
+            ("sp"  , R1),
+            ("rtoc", R2),
+
+            ("f0" , F0),
+            ("f1" , F1),
+            ("f2" , F2),
+            ("f3" , F3),
+            ("f4" , F4),
+            ("f5" , F5),
+            ("f6" , F6),
+            ("f7" , F7),
+            ("f8" , F8),
+            ("f9" , F9),
+            ("f10", F10),
+            ("f11", F11),
+            ("f12", F12),
+            ("f13", F13),
+            ("f14", F14),
+            ("f15", F15),
+            ("f16", F16),
+            ("f17", F17),
+            ("f18", F18),
+            ("f19", F19),
+            ("f20", F20),
+            ("f21", F21),
+            ("f22", F22),
+            ("f23", F23),
+            ("f24", F24),
+            ("f25", F25),
+            ("f26", F26),
+            ("f27", F27),
+            ("f28", F28),
+            ("f29", F29),
+            ("f30", F30),
+            ("f31", F31),
+
+            ("v0" , V0),
+            ("v1" , V1),
+            ("v2" , V2),
+            ("v3" , V3),
+            ("v4" , V4),
+            ("v5" , V5),
+            ("v6" , V6),
+            ("v7" , V7),
+            ("v8" , V8),
+            ("v9" , V9),
+            ("v10", V10),
+            ("v11", V11),
+            ("v12", V12),
+            ("v13", V13),
+            ("v14", V14),
+            ("v15", V15),
+            ("v16", V16),
+            ("v17", V17),
+            ("v18", V18),
+            ("v19", V19),
+            ("v20", V20),
+            ("v21", V21),
+            ("v22", V22),
+            ("v23", V23),
+            ("v24", V24),
+            ("v25", V25),
+            ("v26", V26),
+            ("v27", V27),
+            ("v28", V28),
+            ("v29", V29),
+            ("v30", V30),
+            ("v31", V31),
+
+            ("vs0" , VS0),
+            ("vs1" , VS1),
+            ("vs2" , VS2),
+            ("vs3" , VS3),
+            ("vs4" , VS4),
+            ("vs5" , VS5),
+            ("vs6" , VS6),
+            ("vs7" , VS7),
+            ("vs8" , VS8),
+            ("vs9" , VS9),
+            ("vs10", VS10),
+            ("vs11", VS11),
+            ("vs12", VS12),
+            ("vs13", VS13),
+            ("vs14", VS14),
+            ("vs15", VS15),
+            ("vs16", VS16),
+            ("vs17", VS17),
+            ("vs18", VS18),
+            ("vs19", VS19),
+            ("vs20", VS20),
+            ("vs21", VS21),
+            ("vs22", VS22),
+            ("vs23", VS23),
+            ("vs24", VS24),
+            ("vs25", VS25),
+            ("vs26", VS26),
+            ("vs27", VS27),
+            ("vs28", VS28),
+            ("vs29", VS29),
+            ("vs30", VS30),
+            ("vs31", VS31),
+            ("vs32", VS32),
+            ("vs33", VS33),
+            ("vs34", VS34),
+            ("vs35", VS35),
+            ("vs36", VS36),
+            ("vs37", VS37),
+            ("vs38", VS38),
+            ("vs39", VS39),
+            ("vs40", VS40),
+            ("vs41", VS41),
+            ("vs42", VS42),
+            ("vs43", VS43),
+            ("vs44", VS44),
+            ("vs45", VS45),
+            ("vs46", VS46),
+            ("vs47", VS47),
+            ("vs48", VS48),
+            ("vs49", VS49),
+            ("vs50", VS50),
+            ("vs51", VS51),
+            ("vs52", VS52),
+            ("vs53", VS53),
+            ("vs54", VS54),
+            ("vs55", VS55),
+            ("vs56", VS56),
+            ("vs57", VS57),
+            ("vs58", VS58),
+            ("vs59", VS59),
+            ("vs60", VS60),
+            ("vs61", VS61),
+            ("vs62", VS62),
+            ("vs63", VS63),
+
+            ("cr0", CR0),
+            ("cr1", CR1),
+            ("cr2", CR2),
+            ("cr3", CR3),
+            ("cr4", CR4),
+            ("cr5", CR5),
+            ("cr6", CR6),
+            ("cr7", CR7),
+        ];
+        MAP.iter().cloned().collect()
+    };
+
+    static ref PPC64LE_FAMILIES: HashMap<&'static str, ast::RegFamily> = {
+        static MAP: &[(&str, ast::RegFamily)] = &[
+            ("R", ast::RegFamily::INTEGER),
+            ("F", ast::RegFamily::FP),
+            ("V", ast::RegFamily::VECTOR),
+            ("VS", ast::RegFamily::VSX),
+            ("CR", ast::RegFamily::CR),
+        ];
+        MAP.iter().cloned().collect()
+    };
+}
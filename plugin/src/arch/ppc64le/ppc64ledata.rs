@@ -0,0 +1,220 @@
+//! this file contains the datastructure specification for the PowerPC encoding data
+use lazy_static::lazy_static;
+
+use std::collections::{HashMap, hash_map};
+
+
+/// A template contains the information for the static parts of an instruction encoding, as well
+/// as its length
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Template {
+    /// A single 32-bit instruction
+    Single(u32),
+}
+
+
+/// Matchers. These are used to check if a certain argument is compatible with the instruction
+/// being assembled. Multiple matchers can match a single argument
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Matcher {
+    /// A general purpose register
+    R,
+
+    /// A floating point register
+    F,
+
+    /// A vector (VMX) register
+    V,
+
+    /// A vector-scalar (VSX) register
+    Vs,
+
+    /// A condition register field
+    Cr,
+
+    /// An indirect reference with (optional) offset. expands args to R, Imm
+    RefOffset,
+
+    /// An indirect reference with a label as offset. The low 16 bits of the label's address
+    /// relative to the instruction are encoded. expands args to R, JumpTarget
+    RefLabel,
+
+    /// An immediate
+    Imm,
+
+    /// A jump offset
+    Offset,
+}
+
+
+/// Encoding commands. They specify how arguments should be checked / encoded.
+///
+/// Unless otherwise stated, the first argument indicates the bottom-most bit of the affected bitfield.
+/// Bits are numbered from the least significant bit of the instruction, unlike the Power ISA
+/// documentation.
+#[derive(Debug, Clone)]
+pub enum Command {
+    // Meta commands
+
+    /// Repeat the same argument again, as it needs to be encoded twice
+    Repeat,
+
+    /// go to the next argument, if not done implicitly
+    Next,
+
+    // register fields
+
+    /// A normal 5-bit register encoding. Used for general purpose, floating point and vector registers
+    R(u8),
+
+    /// A 6-bit vector-scalar register encoding. The bottom 5 bits are encoded at .0, the top bit at .1
+    Vs(u8, u8),
+
+    /// A 3-bit condition register field encoding
+    C(u8),
+
+    // immediate handling, validation fields
+
+    /// validate that the current arg is an unsigned value that fits in .0 bits, and that the
+    /// lower .1 bits are 0
+    UImm(u8, u8),
+
+    /// validate that the current arg is a signed value that fits in .0 bits, and that the
+    /// lower .1 bits are 0
+    SImm(u8, u8),
+
+    // immediate handling, encoding fields.
+
+    /// Encode a slice of bits from a value .0 = offset, .1 = amount of bits, .2 = offset in value
+    BitRange(u8, u8, u8),
+
+    /// Same as BitRange, but encodes the bitwise inverse of the value. Used by extended mnemonics
+    /// like sldi that encode a mask end of `63 - n`
+    InvBitRange(u8, u8, u8),
+
+    /// Same as BitRange, but encodes the negated value. Used by extended mnemonics like srdi that
+    /// encode a rotation of `64 - n`
+    NegBitRange(u8, u8, u8),
+
+    /// some kind of offset for a jump.
+    Offset(Relocation),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relocation {
+    // b, bl
+    // 26 bits, 2-bit scaled
+    B24 = 0,
+    // bc and its extended mnemonics
+    // 16 bits, 2-bit scaled
+    B14 = 1,
+    // addis
+    // 32 bits, high half, adjusted for the sign of the low half
+    HA16 = 2,
+    // addi, D-form loads and stores
+    // 32 bits, low half
+    LO16 = 3,
+    // DS-form loads and stores
+    // 32 bits, low half, 2-bit scaled
+    LO16DS = 4,
+    // addpcis
+    // 32 bits, high half, adjusted for the sign of the low half. Relative to the next instruction
+    DXHA16 = 5,
+    // Anything in directives
+    LITERAL8 = 9,
+    LITERAL16 = 10,
+    LITERAL32 = 12,
+    LITERAL64 = 16,
+}
+
+impl Relocation {
+    pub fn to_id(self) -> u8 {
+        self as u8
+    }
+
+    pub fn size(self) -> u8 {
+        match self {
+            Relocation::LITERAL8 => 1,
+            Relocation::LITERAL16 => 2,
+            Relocation::B24
+            | Relocation::B14
+            | Relocation::HA16
+            | Relocation::LO16
+            | Relocation::LO16DS
+            | Relocation::DXHA16
+            | Relocation::LITERAL32 => 4,
+            Relocation::LITERAL64 => 8
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct Opdata {
+    /// The base template for the encoding.
+    pub template: Template,
+    /// A set of matchers capable of matching the instruction encoding that this instruction represents.
+    pub matchers: &'static [Matcher],
+    /// A set of commands used to process the matched arguments
+    pub commands: &'static [Command],
+}
+
+macro_rules! SingleOp {
+    ( $template:expr, [ $( $matcher:expr ),* ], [ $( $command:expr ),* ] ) => {
+        {
+            const MATCHERS: &'static [Matcher] = {
+                #[allow(unused_imports)]
+                use self::Matcher::*;
+                &[ $(
+                    $matcher
+                ),* ]
+            };
+            const COMMANDS: &'static [Command] = {
+                #[allow(unused_imports)]
+                use self::Command::*;
+                #[allow(unused_imports)]
+                use self::Relocation::*;
+                &[ $(
+                    $command
+                ),* ]
+            };
+
+            use self::Template::*;
+            Opdata {
+                template: $template,
+                matchers: MATCHERS,
+                commands: COMMANDS,
+            }
+        }
+    }
+}
+
+macro_rules! Ops {
+    ( $( $name:tt = [ $( $template:expr , [ $( $matcher:expr ),* ] => [ $( $command:expr ),* ] ; )+ ] , )* ) => {
+        [ $(
+            (
+                $name,
+                &[ $(
+                    SingleOp!( $template, [ $( $matcher ),* ], [ $( $command ),* ] )
+                ),+ ] as &[_]
+            )
+        ),* ]
+    }
+}
+
+pub fn get_mnemonic_data(name: &str) -> Option<&'static [Opdata]> {
+    OPMAP.get(&name).cloned()
+}
+
+#[allow(dead_code)]
+pub fn mnemonics() -> hash_map::Keys<'static, &'static str, &'static [Opdata]> {
+    OPMAP.keys()
+}
+
+
+lazy_static!{
+    static ref OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        static MAP: &[(&str, &[Opdata])] = &include!("opmap.rs");
+        MAP.iter().cloned().collect()
+    };
+}
@@ -122,7 +122,7 @@ impl Register {
 }
 
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum RegListCount {
     Static(u8),
     Dynamic(syn::Expr),
@@ -139,10 +139,11 @@ pub enum RegListCount {
 /// * a register (one of the above)
 /// * a memory reference `expr? ( intreg ) `
 /// * a register list {ra [, s0 [- s_n]]}
+/// * a vector mask register `v0.t`
 ///
 /// this last one is somewhat problematic, as just parsing the expr will normally swallow
 /// the register reference as a call expression.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum RawArg {
     // An immediate, or potentially an identifier
     Immediate {
@@ -157,6 +158,11 @@ pub enum RawArg {
         span: Span,
         reg: Register
     },
+    // A register used as vector mask, i.e. v0.t
+    MaskRegister {
+        span: Span,
+        reg: Register
+    },
     // A memory reference
     Reference {
         span: Span,
@@ -1,5 +1,6 @@
 use super::Context;
-use super::riscvdata::{Template, Command, Relocation, ROUNDMODE_MAP, FENCESPEC_MAP, CSR_MAP, FP_IMM_IDENT_MAP, FP_IMM_VALUE_MAP};
+use super::riscvdata::{Template, Command, Relocation, ROUNDMODE_MAP, FENCESPEC_MAP, CSR_MAP, FP_IMM_IDENT_MAP, FP_IMM_VALUE_MAP,
+    ELEMENTWIDTH_MAP, GROUPMULTIPLIER_MAP, TAILPOLICY_MAP, MASKPOLICY_MAP};
 use super::ast::{MatchData, FlatArg, RegListFlat, Register};
 
 use syn::spanned::Spanned;
@@ -116,6 +117,20 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                         code &= 7;
                         offset
                     },
+                    Command::Vno0(offset) => {
+                        if code == 0 {
+                            emit_error!(span, "This register must not be v0, as it is used as mask register");
+                            return Err(None);
+                        }
+                        offset
+                    },
+                    Command::Vgroup(offset, size) => {
+                        if code % size != 0 {
+                            emit_error!(span, "This register group must start at a multiple of {} registers", size);
+                            return Err(None);
+                        }
+                        offset
+                    },
                     _ => panic!("Invalid argument processor")
                 };
 
@@ -229,6 +244,31 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                     },
                     _ => panic!("Invalid encoding data, expected a register before")
                 },
+                Command::Vno0(offset) => {
+                    let expr = delimited(expr);
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        {
+                            let _dyn_reg: u8 = #expr.into();
+                            if _dyn_reg & 0x1F == 0x0 {
+                                ::dynasmrt::riscv::invalid_register(_dyn_reg);
+                            }
+                            (_dyn_reg & 0x1F) as u32
+                        }
+                    }));
+                },
+                Command::Vgroup(offset, size) => {
+                    let invalid_reg_mask: u8 = size - 1;
+                    let expr = delimited(expr);
+                    dynamics.push((offset, quote_spanned!{ span=>
+                        {
+                            let _dyn_reg: u8 = #expr.into();
+                            if _dyn_reg & #invalid_reg_mask != 0 {
+                                ::dynasmrt::riscv::invalid_register(_dyn_reg);
+                            }
+                            (_dyn_reg & 0x1F) as u32
+                        }
+                    }));
+                },
                 _ => panic!("Invalid argument processor")
             },
 
@@ -661,6 +701,42 @@ pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<
                         return Err(None);
                     }
                 },
+                Command::ElementWidth(offset) => {
+                    let name = as_ident(value).expect("bad command data").to_string();
+                    if let Some(&bits) = ELEMENTWIDTH_MAP.get(&&*name) {
+                        statics.push((offset, u32::from(bits)));
+                    } else {
+                        emit_error!(value, "Unknown element width, expected one of e8, e16, e32 or e64");
+                        return Err(None);
+                    }
+                },
+                Command::GroupMultiplier(offset) => {
+                    let name = as_ident(value).expect("bad command data").to_string();
+                    if let Some(&bits) = GROUPMULTIPLIER_MAP.get(&&*name) {
+                        statics.push((offset, u32::from(bits)));
+                    } else {
+                        emit_error!(value, "Unknown register group multiplier, expected one of mf8, mf4, mf2, m1, m2, m4 or m8");
+                        return Err(None);
+                    }
+                },
+                Command::TailPolicy(offset) => {
+                    let name = as_ident(value).expect("bad command data").to_string();
+                    if let Some(&bits) = TAILPOLICY_MAP.get(&&*name) {
+                        statics.push((offset, u32::from(bits)));
+                    } else {
+                        emit_error!(value, "Unknown tail policy, expected ta or tu");
+                        return Err(None);
+                    }
+                },
+                Command::MaskPolicy(offset) => {
+                    let name = as_ident(value).expect("bad command data").to_string();
+                    if let Some(&bits) = MASKPOLICY_MAP.get(&&*name) {
+                        statics.push((offset, u32::from(bits)));
+                    } else {
+                        emit_error!(value, "Unknown mask policy, expected ma or mu");
+                        return Err(None);
+                    }
+                },
                 Command::Csr(offset) => 'csr: {
                     // Csr is a bit special as it allows both immediates and names
                     // because we cannot differentiate from those earlier, we handle that here.
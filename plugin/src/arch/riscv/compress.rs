@@ -0,0 +1,332 @@
+//! Automatic selection of compressed instruction forms.
+//!
+//! When the `compress` pseudo-feature is enabled, plain instructions whose operands are all known
+//! at compile time are rewritten to their `c.` equivalent if the operands fit the (much more
+//! restricted) compressed encoding, just like GNU as does when the C extension is active.
+//! Anything involving dynamic registers, dynamic immediates or labels is left alone, as we
+//! cannot prove that the compressed form would be able to encode it.
+use proc_macro2::Span;
+
+use super::Context;
+use super::ast::{ParsedInstruction, RawArg, Register, RegId, RegFamily};
+use super::riscvdata::{ISAFlags, get_mnemonic_data};
+use super::matching::match_args;
+
+use crate::parse_helpers::as_signed_number;
+
+/// Try to replace `instruction` by an equivalent compressed instruction. If this is not possible,
+/// the original instruction is returned.
+pub(super) fn compress_instruction(ctx: &Context, instruction: ParsedInstruction) -> ParsedInstruction {
+    let (name, args) = match find_compressed(ctx, &instruction) {
+        Some(c) => c,
+        None => return instruction
+    };
+
+    // check that the compressed form is actually available with the current target and features.
+    let opdata = get_mnemonic_data(name).expect("compression rule refers to an unknown mnemonic");
+    let available = opdata.iter().any(|data| {
+        data.isa_flags.contains(if ctx.target.is_64_bit() { ISAFlags::RV64 } else { ISAFlags::RV32 })
+            && data.ext_flags.iter().any(|f| ctx.features.contains(*f))
+            && match_args(&args, data).is_some()
+    });
+
+    if !available {
+        return instruction;
+    }
+
+    ParsedInstruction {
+        name: name.to_string(),
+        span: instruction.span,
+        args
+    }
+}
+
+/// Figure out which compressed instruction (and which arguments) could replace the given
+/// instruction. All register / immediate constraints of the compressed form are checked here.
+fn find_compressed(ctx: &Context, instruction: &ParsedInstruction) -> Option<(&'static str, Vec<RawArg>)> {
+    let args = &instruction.args;
+    let xlen = if ctx.target.is_64_bit() { 64 } else { 32 };
+
+    let pick = |indices: &[usize]| -> Vec<RawArg> {
+        indices.iter().map(|&i| args[i].clone()).collect()
+    };
+
+    let compressed = match (instruction.name.as_str(), args.len()) {
+        ("add", 3) => {
+            let (rd, rs1, rs2) = (xreg(&args[0])?, xreg(&args[1])?, xreg(&args[2])?);
+            if rd == 0 {
+                return None;
+            } else if rs1 == 0 && rs2 != 0 {
+                ("c.mv", pick(&[0, 2]))
+            } else if rs2 == 0 && rs1 != 0 {
+                ("c.mv", pick(&[0, 1]))
+            } else if rd == rs1 && rs2 != 0 {
+                ("c.add", pick(&[0, 2]))
+            } else if rd == rs2 && rs1 != 0 {
+                ("c.add", pick(&[0, 1]))
+            } else {
+                return None;
+            }
+        },
+        ("mv", 2) => {
+            let (rd, rs) = (xreg(&args[0])?, xreg(&args[1])?);
+            if rd == 0 || rs == 0 {
+                return None;
+            }
+            ("c.mv", pick(&[0, 1]))
+        },
+        ("addi", 3) => {
+            let (rd, rs1, imm) = (xreg(&args[0])?, xreg(&args[1])?, imm(&args[2])?);
+            if rd == 0 && rs1 == 0 && imm == 0 {
+                ("c.nop", Vec::new())
+            } else if rd == 2 && rs1 == 2 && imm != 0 && fits_scaled(imm, -512, 511, 16) {
+                ("c.addi16sp", pick(&[0, 2]))
+            } else if is_popular(rd) && rs1 == 2 && imm != 0 && fits_scaled(imm, 0, 1023, 4) {
+                ("c.addi4spn", pick(&[0, 1, 2]))
+            } else if rd != 0 && rs1 == 0 && fits_scaled(imm, -32, 31, 1) {
+                ("c.li", pick(&[0, 2]))
+            } else if rd != 0 && rd == rs1 && imm != 0 && fits_scaled(imm, -32, 31, 1) {
+                ("c.addi", pick(&[0, 2]))
+            } else if rd != 0 && rs1 != 0 && imm == 0 {
+                ("c.mv", pick(&[0, 1]))
+            } else {
+                return None;
+            }
+        },
+        ("li", 2) => {
+            let (rd, imm) = (xreg(&args[0])?, imm(&args[1])?);
+            if rd == 0 || !fits_scaled(imm, -32, 31, 1) {
+                return None;
+            }
+            ("c.li", pick(&[0, 1]))
+        },
+        ("nop", 0) => ("c.nop", Vec::new()),
+        ("ebreak", 0) => ("c.ebreak", Vec::new()),
+        ("addiw", 3) => {
+            let (rd, rs1, imm) = (xreg(&args[0])?, xreg(&args[1])?, imm(&args[2])?);
+            if rd == 0 || rd != rs1 || !fits_scaled(imm, -32, 31, 1) {
+                return None;
+            }
+            ("c.addiw", pick(&[0, 2]))
+        },
+        ("sext.w", 2) => {
+            let (rd, rs1) = (xreg(&args[0])?, xreg(&args[1])?);
+            if rd == 0 || rd != rs1 {
+                return None;
+            }
+            let mut args = pick(&[0]);
+            args.push(zero_immediate(instruction.span));
+            ("c.addiw", args)
+        },
+        ("lui", 2) => {
+            let (rd, imm) = (xreg(&args[0])?, imm(&args[1])?);
+            if rd == 0 || rd == 2 || imm == 0 || !fits_scaled(imm, -0x2_0000, 0x1_FFFF, 0x1000) {
+                return None;
+            }
+            ("c.lui", pick(&[0, 1]))
+        },
+        ("slli", 3) => {
+            let (rd, rs1, imm) = (xreg(&args[0])?, xreg(&args[1])?, imm(&args[2])?);
+            if rd == 0 || rd != rs1 || imm <= 0 || imm >= xlen {
+                return None;
+            }
+            ("c.slli", pick(&[0, 2]))
+        },
+        (name @ ("srli" | "srai"), 3) => {
+            let (rd, rs1, imm) = (xreg(&args[0])?, xreg(&args[1])?, imm(&args[2])?);
+            if !is_popular(rd) || rd != rs1 || imm <= 0 || imm >= xlen {
+                return None;
+            }
+            (if name == "srli" { "c.srli" } else { "c.srai" }, pick(&[0, 2]))
+        },
+        ("andi", 3) => {
+            let (rd, rs1, imm) = (xreg(&args[0])?, xreg(&args[1])?, imm(&args[2])?);
+            if !is_popular(rd) || rd != rs1 {
+                return None;
+            } else if imm == 0xFF {
+                ("c.zext.b", pick(&[0]))
+            } else if fits_scaled(imm, -32, 31, 1) {
+                ("c.andi", pick(&[0, 2]))
+            } else {
+                return None;
+            }
+        },
+        ("xori", 3) => {
+            let (rd, rs1, imm) = (xreg(&args[0])?, xreg(&args[1])?, imm(&args[2])?);
+            if !is_popular(rd) || rd != rs1 || imm != -1 {
+                return None;
+            }
+            ("c.not", pick(&[0]))
+        },
+        // two-operand register-register instructions that overwrite their first source
+        (name @ ("sub" | "subw"), 3) => {
+            let (rd, rs1, rs2) = (xreg(&args[0])?, xreg(&args[1])?, xreg(&args[2])?);
+            if !is_popular(rd) || rd != rs1 || !is_popular(rs2) {
+                return None;
+            }
+            (if name == "sub" { "c.sub" } else { "c.subw" }, pick(&[0, 2]))
+        },
+        // and the commutative ones, which can also overwrite their second source
+        (name @ ("and" | "or" | "xor" | "addw" | "mul"), 3) => {
+            let (rd, rs1, rs2) = (xreg(&args[0])?, xreg(&args[1])?, xreg(&args[2])?);
+            let name = match name {
+                "and" => "c.and",
+                "or" => "c.or",
+                "xor" => "c.xor",
+                "addw" => "c.addw",
+                _ => "c.mul",
+            };
+            if !is_popular(rd) || !is_popular(rs1) || !is_popular(rs2) {
+                return None;
+            } else if rd == rs1 {
+                (name, pick(&[0, 2]))
+            } else if rd == rs2 {
+                (name, pick(&[0, 1]))
+            } else {
+                return None;
+            }
+        },
+        // single-operand instructions that have a compressed form if they operate in-place
+        (name @ ("not" | "zext.b" | "zext.h" | "zext.w" | "sext.b" | "sext.h"), 2) => {
+            let (rd, rs1) = (xreg(&args[0])?, xreg(&args[1])?);
+            if !is_popular(rd) || rd != rs1 {
+                return None;
+            }
+            let name = match name {
+                "not" => "c.not",
+                "zext.b" => "c.zext.b",
+                "zext.h" => "c.zext.h",
+                "zext.w" => "c.zext.w",
+                "sext.b" => "c.sext.b",
+                _ => "c.sext.h",
+            };
+            (name, pick(&[0]))
+        },
+        // loads and stores
+        (name @ ("lw" | "ld" | "flw" | "fld" | "sw" | "sd" | "fsw" | "fsd"), 2) => {
+            let (base, offset) = reference(&args[1])?;
+            let is_float = name.starts_with('f');
+            let is_load = name.trim_start_matches('f').starts_with('l');
+            let reg = if is_float { freg(&args[0])? } else { xreg(&args[0])? };
+            let scale = if name.ends_with('w') { 4 } else { 8 };
+
+            let (sp_name, name) = match name {
+                "lw" => ("c.lwsp", "c.lw"),
+                "ld" => ("c.ldsp", "c.ld"),
+                "flw" => ("c.flwsp", "c.flw"),
+                "fld" => ("c.fldsp", "c.fld"),
+                "sw" => ("c.swsp", "c.sw"),
+                "sd" => ("c.sdsp", "c.sd"),
+                "fsw" => ("c.fswsp", "c.fsw"),
+                _ => ("c.fsdsp", "c.fsd"),
+            };
+
+            // integer loads to x0 are hints instead in the stack pointer relative form
+            if base == 2 && (is_float || !is_load || reg != 0) && fits_scaled(offset, 0, 64 * scale - 1, scale) {
+                (sp_name, pick(&[0, 1]))
+            } else if is_popular(reg) && is_popular(base) && fits_scaled(offset, 0, 32 * scale - 1, scale) {
+                (name, pick(&[0, 1]))
+            } else {
+                return None;
+            }
+        },
+        (name @ ("lbu" | "lhu" | "lh" | "sb" | "sh"), 2) => {
+            let (base, offset) = reference(&args[1])?;
+            let reg = xreg(&args[0])?;
+            let scale = if name.ends_with('b') || name == "lbu" { 1 } else { 2 };
+            if !is_popular(reg) || !is_popular(base) || !fits_scaled(offset, 0, 3, scale) {
+                return None;
+            }
+            let name = match name {
+                "lbu" => "c.lbu",
+                "lhu" => "c.lhu",
+                "lh" => "c.lh",
+                "sb" => "c.sb",
+                _ => "c.sh",
+            };
+            (name, pick(&[0, 1]))
+        },
+        // indirect jumps
+        ("jalr", 3) => {
+            let (rd, rs1, imm) = (xreg(&args[0])?, xreg(&args[1])?, imm(&args[2])?);
+            if rs1 == 0 || imm != 0 {
+                return None;
+            }
+            match rd {
+                0 => ("c.jr", pick(&[1])),
+                1 => ("c.jalr", pick(&[1])),
+                _ => return None
+            }
+        },
+        (name @ ("jalr" | "jr"), 1) => {
+            if xreg(&args[0])? == 0 {
+                return None;
+            }
+            (if name == "jalr" { "c.jalr" } else { "c.jr" }, pick(&[0]))
+        },
+        ("ret", 0) => ("c.jr", vec![RawArg::Register {
+            span: instruction.span,
+            reg: Register::Static(RegId::X1)
+        }]),
+        _ => return None
+    };
+
+    Some(compressed)
+}
+
+/// Returns the register code of a statically known integer register
+fn xreg(arg: &RawArg) -> Option<u8> {
+    match arg {
+        RawArg::Register { reg: Register::Static(id), .. } if id.family() == RegFamily::INTEGER => Some(id.code()),
+        _ => None
+    }
+}
+
+/// Returns the register code of a statically known floating point register
+fn freg(arg: &RawArg) -> Option<u8> {
+    match arg {
+        RawArg::Register { reg: Register::Static(id), .. } if id.family() == RegFamily::FP => Some(id.code()),
+        _ => None
+    }
+}
+
+/// Returns the value of a compile-time constant immediate
+fn imm(arg: &RawArg) -> Option<i64> {
+    match arg {
+        RawArg::Immediate { value } => as_signed_number(value),
+        _ => None
+    }
+}
+
+/// Returns the base register code and offset of a memory reference, if both are statically known
+fn reference(arg: &RawArg) -> Option<(u8, i64)> {
+    match arg {
+        RawArg::Reference { base: Register::Static(id), offset, .. } if id.family() == RegFamily::INTEGER => {
+            let offset = match offset {
+                Some(offset) => as_signed_number(offset)?,
+                None => 0
+            };
+            Some((id.code(), offset))
+        },
+        _ => None
+    }
+}
+
+/// The registers that can be encoded in the 3-bit register fields, x8-x15 / f8-f15
+fn is_popular(code: u8) -> bool {
+    (8 .. 16).contains(&code)
+}
+
+/// Check that min <= value <= max and that value is a multiple of scale
+fn fits_scaled(value: i64, min: i64, max: i64, scale: i64) -> bool {
+    value >= min && value <= max && value % scale == 0
+}
+
+fn zero_immediate(span: Span) -> RawArg {
+    RawArg::Immediate {
+        value: syn::Expr::Lit(syn::ExprLit {
+            attrs: Vec::new(),
+            lit: syn::Lit::Int(syn::LitInt::new("0", span))
+        })
+    }
+}
@@ -84,7 +84,8 @@ pub fn format_opdata(name: &str, data: &Opdata) -> String {
             Matcher::RefOffset
             | Matcher::RefLabel => 2,
             Matcher::Lit(_)
-            | Matcher::Reg(_) => 0,
+            | Matcher::Reg(_)
+            | Matcher::Vm => 0,
             _ => 1
         });
         names = rest;
@@ -92,6 +93,8 @@ pub fn format_opdata(name: &str, data: &Opdata) -> String {
         match matcher {
             Matcher::X => write!(buf, "x{}", arg_names[0]).unwrap(),
             Matcher::F => write!(buf, "f{}", arg_names[0]).unwrap(),
+            Matcher::V => write!(buf, "v{}", arg_names[0]).unwrap(),
+            Matcher::Vm => buf.push_str("v0.t"),
             Matcher::Reg(regid) => write!(buf, "{}", regid).unwrap(),
             Matcher::Xlist => write!(buf, "{{reg_list}}").unwrap(),
             Matcher::Ref => write!(buf, "[x{}]", arg_names[0]).unwrap(),
@@ -179,6 +182,7 @@ fn flatten_matchers(matchers: &[Matcher]) -> Vec<FlatArgTy> {
         match matcher {
             Matcher::X
             | Matcher::F
+            | Matcher::V
             | Matcher::Ref => args.push(FlatArgTy::Direct(false)),
             Matcher::Ident
             |  Matcher::RefSp
@@ -194,7 +198,8 @@ fn flatten_matchers(matchers: &[Matcher]) -> Vec<FlatArgTy> {
             Matcher::Offset => args.push(FlatArgTy::JumpTarget),
             Matcher::Xlist => args.push(FlatArgTy::Reglist),
             Matcher::Lit(_)
-            | Matcher::Reg(_) => ()
+            | Matcher::Reg(_)
+            | Matcher::Vm => ()
         }
     }
     args
@@ -228,10 +233,16 @@ fn group_commands(commands: &[Command]) -> (usize, Vec<(Command, usize)>) {
             | Command::Rpops(_)
             | Command::Rpops2(_)
             | Command::Rlist(_)
+            | Command::Vno0(_)
+            | Command::Vgroup(_, _)
             | Command::RoundingMode(_)
             | Command::FenceSpec(_)
             | Command::Csr(_)
             | Command::FloatingPointImmediate(_)
+            | Command::ElementWidth(_)
+            | Command::GroupMultiplier(_)
+            | Command::TailPolicy(_)
+            | Command::MaskPolicy(_)
             | Command::SPImm(_, _)
             | Command::Offset(_) => cursor += 1,
             _ => ()
@@ -256,12 +267,18 @@ fn check_command_sanity(args: &[ArgWithCommands]) -> Result<(), &'static str> {
                 | Command::Rno02(_)
                 | Command::Rpop(_)
                 | Command::Rpops(_)
-                | Command::Rpops2(_) => arg.arg == FlatArgTy::Direct(false) || arg.arg == FlatArgTy::Direct(true),
+                | Command::Rpops2(_)
+                | Command::Vno0(_)
+                | Command::Vgroup(_, _) => arg.arg == FlatArgTy::Direct(false) || arg.arg == FlatArgTy::Direct(true),
                 Command::Rlist(_) => arg.arg == FlatArgTy::Reglist,
                 Command::RoundingMode(_)
                 | Command::FenceSpec(_)
                 | Command::Csr(_)
                 | Command::FloatingPointImmediate(_)
+                | Command::ElementWidth(_)
+                | Command::GroupMultiplier(_)
+                | Command::TailPolicy(_)
+                | Command::MaskPolicy(_)
                 | Command::SPImm(_, _)
                 | Command::UImm(_, _)
                 | Command::SImm(_, _)
@@ -311,7 +328,9 @@ fn name_args(args: &mut [ArgWithCommands]) {
                 | Command::Rno02(_)
                 | Command::Rpop(_)
                 | Command::Rpops(_)
-                | Command::Rpops2(_) => if is_offset {
+                | Command::Rpops2(_)
+                | Command::Vno0(_)
+                | Command::Vgroup(_, _) => if is_offset {
                     arg.name = Some("b".to_string())
                 } else {
                     arg.name = Some(reg_name_list[reg_name_idx].to_string());
@@ -331,6 +350,10 @@ fn name_args(args: &mut [ArgWithCommands]) {
                 Command::RoundingMode(_) => arg.name = Some("<rounding mode>".to_string()),
                 Command::FenceSpec(_) => arg.name = Some("<fence spec>".to_string()),
                 Command::Csr(_) => arg.name = Some("<csr>".to_string()),
+                Command::ElementWidth(_) => arg.name = Some("<element width>".to_string()),
+                Command::GroupMultiplier(_) => arg.name = Some("<group multiplier>".to_string()),
+                Command::TailPolicy(_) => arg.name = Some("<tail policy>".to_string()),
+                Command::MaskPolicy(_) => arg.name = Some("<mask policy>".to_string()),
                 Command::FloatingPointImmediate(_) => {
                     arg.name = Some(format!("fimm{}", imm_name_list[imm_name_idx]));
                     imm_name_idx += 1;
@@ -385,6 +408,8 @@ fn emit_constraints(name: &str, prev_name: &str, commands: &[Command], buf: &mut
             Command::Rpop(_) => write!(buf, "{} is 8-15", name),
             Command::Rpops(_) => write!(buf, "{} is 8, 9, or 18-23", name),
             Command::Rpops2(_) => write!(buf, "{} is 8, 9, or 18-23, {} != {}", name, name, prev_name),
+            Command::Vno0(_) => write!(buf, "{} cannot be 0", name),
+            Command::Vgroup(_, size) => write!(buf, "{} is a multiple of {}", name, size),
 
             Command::FloatingPointImmediate(_) => write!(buf, "{} is a floating point immediate", name),
             Command::SPImm(_, true) => write!(buf, "{} = -(round_up(reglist_space, 16) + [0|16|32|48])", name),
@@ -471,6 +496,8 @@ pub fn extract_opdata(name: &str, data: &Opdata) -> String {
         match matcher {
             Matcher::X => write!(buf, "<X,{}>", arg_idx).unwrap(),
             Matcher::F => write!(buf, "<F,{}>", arg_idx).unwrap(),
+            Matcher::V => write!(buf, "<V,{}>", arg_idx).unwrap(),
+            Matcher::Vm => write!(buf, "v0.t").unwrap(),
             Matcher::Reg(regid) => write!(buf, "{}", regid.to_string()).unwrap(),
             Matcher::Ref => write!(buf, "[<X,{}>]", arg_idx).unwrap(),
             Matcher::RefOffset => write!(buf, "[<X,{}>, <Imm,{}>]", arg_idx, arg_idx + 1).unwrap(),
@@ -487,7 +514,8 @@ pub fn extract_opdata(name: &str, data: &Opdata) -> String {
             Matcher::RefOffset
             | Matcher::RefLabel => 2,
             Matcher::Lit(_)
-            | Matcher::Reg(_) => 0,
+            | Matcher::Reg(_)
+            | Matcher::Vm => 0,
             _ => 1
         };
     }
@@ -538,11 +566,19 @@ fn extract_constraints(args: &[ArgWithCommands]) -> Vec<String> {
                 Command::Rpops(_) => format!("R(0x00FC0300)"),
                 Command::Rpops2(_) => format!("Rdifferent(0x00FC0300)"),
                 Command::Rlist(_) => format!("RList()"),
+                Command::Vno0(_) => format!("R(0xFFFFFFFE)"),
+                Command::Vgroup(_, 2) => format!("R(0x55555555)"),
+                Command::Vgroup(_, 4) => format!("R(0x11111111)"),
+                Command::Vgroup(_, 8) => format!("R(0x01010101)"),
 
                 Command::RoundingMode(_) => format!("RoundingMode()"),
                 Command::FenceSpec(_) => format!("FenceSpec()"),
                 Command::Csr(_) => format!("Csr()"),
                 Command::FloatingPointImmediate(_) => format!("FloatingPointImmediate()"),
+                Command::ElementWidth(_) => format!("ElementWidth()"),
+                Command::GroupMultiplier(_) => format!("GroupMultiplier()"),
+                Command::TailPolicy(_) => format!("TailPolicy()"),
+                Command::MaskPolicy(_) => format!("MaskPolicy()"),
                 Command::SPImm(_, true) => format!("StackAdjustImmediate(True)"),
                 Command::SPImm(_, false) => format!("StackAdjustImmediate(False)"),
 
@@ -17,6 +17,7 @@ pub(super) fn match_instruction(ctx: &mut Context, mut instruction: ParsedInstru
     let opdata = get_mnemonic_data(&instruction.name).ok_or_else(|| Some(format!("Unknown instruction mnemonic '{}'", instruction.name)))?;
 
     let mut rejected_because_features = false;
+    let mut missing_features = None;
 
     // iterate through the supported instruction formats. If one matches, lower the args to
     // FlatArgs and return the combined Matchdata
@@ -30,6 +31,10 @@ pub(super) fn match_instruction(ctx: &mut Context, mut instruction: ParsedInstru
         }
         if !data.ext_flags.iter().any(|f| ctx.features.contains(*f)) {
             rejected_because_features = true;
+            // remember what extensions the first form that would've matched requires
+            if missing_features.is_none() && match_args(&instruction.args, data).is_some() {
+                missing_features = Some(data.ext_flags);
+            }
             continue;
         }
 
@@ -40,6 +45,11 @@ pub(super) fn match_instruction(ctx: &mut Context, mut instruction: ParsedInstru
         }
     }
 
+    if let Some(ext_flags) = missing_features {
+        let missing: Vec<String> = ext_flags.iter().map(|f| f.difference(ctx.features).to_string()).collect();
+        return Err(Some(format!("'{}': this instruction requires the {} extension, which is not enabled", &instruction.name, missing.join(" or "))));
+    }
+
     let mut error = format!("'{}': instruction format mismatch, expected one of the following forms:\n{}", &instruction.name, format_opdata_list(&instruction.name, opdata, ctx.target));
     if rejected_because_features {
         error.push_str("\nNote: some instruction formats were rejected because of inactive ISA extension sets.");
@@ -53,10 +63,15 @@ pub(super) fn match_instruction(ctx: &mut Context, mut instruction: ParsedInstru
 /// Extern relocations are not allowed
 /// No registers above 15 are used on E-profile RISCV
 /// Canonicalize `0(register)` references as without offset (like `(register)`)
+/// Only v0 is used as a vector mask register
 fn sanitize_args(args: &mut [RawArg], target: &RiscVTarget) -> Result<(), Option<String>> {
     for arg in args {
         match arg {
             RawArg::Register { reg, span } => sanitize_register(reg, *span, target)?,
+            RawArg::MaskRegister { reg, span } if reg.as_id() != Some(RegId::V0) => {
+                emit_error!(span, "Only v0 can be used as a vector mask register");
+                return Err(None);
+            },
             RawArg::Reference { base, span, offset } => {
                 sanitize_register(base, *span, target)?;
                 if base.family() != RegFamily::INTEGER {
@@ -186,9 +201,11 @@ impl Matcher {
             RawArg::Register { reg, .. }=> match self {
                 Matcher::X => reg.family() == RegFamily::INTEGER,
                 Matcher::F => reg.family() == RegFamily::FP,
+                Matcher::V => reg.family() == RegFamily::VECTOR,
                 Matcher::Reg(regid) => reg.as_id() == Some(*regid),
                 _ => false,
             },
+            RawArg::MaskRegister { .. } => *self == Matcher::Vm,
             RawArg::Reference { offset, base, .. } => match self {
                 Matcher::Ref => offset.is_none(),
                 Matcher::RefOffset => true,
@@ -241,6 +258,7 @@ fn flatten_args(args: Vec<RawArg>, data: &mut MatchData) {
                 Matcher::Reg(_) => (),
                 _ => data.args.push(FlatArg::Register { span, reg })
             },
+            RawArg::MaskRegister { .. } => (),
             RawArg::Reference { span, offset, base } => match matcher {
                 Matcher::RefOffset => {
                     data.args.push(FlatArg::Register { span, reg: base });
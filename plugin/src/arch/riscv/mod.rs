@@ -13,6 +13,7 @@ pub mod ast;
 pub mod parser;
 pub mod matching;
 pub mod compiler;
+pub mod compress;
 pub mod debug;
 
 use crate::State;
@@ -62,18 +63,20 @@ impl RiscVTarget {
 struct Context<'a, 'b: 'a>  {
     pub state: &'a mut State <'b>,
     pub target: RiscVTarget,
-    pub features: riscvdata::ExtensionFlags
+    pub features: riscvdata::ExtensionFlags,
+    pub compress: bool
 }
 
 
 #[derive(Clone, Debug, Default)]
 pub struct ArchRiscV64I {
-    features: riscvdata::ExtensionFlags
+    features: riscvdata::ExtensionFlags,
+    compress: bool
 }
 
 impl Arch for ArchRiscV64I {
     fn set_features(&mut self, features: &[syn::Ident]) {
-        self.features = parse_features(features);
+        (self.features, self.compress) = parse_features(features, RiscVTarget::RV64I);
     }
 
     fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
@@ -88,7 +91,8 @@ impl Arch for ArchRiscV64I {
         let mut ctx = Context {
             state,
             target: RiscVTarget::RV64I,
-            features: self.features
+            features: self.features,
+            compress: self.compress
         };
 
         compile_instruction_inner(&mut ctx, input)
@@ -98,12 +102,13 @@ impl Arch for ArchRiscV64I {
 
 #[derive(Clone, Debug, Default)]
 pub struct ArchRiscV64E {
-    features: riscvdata::ExtensionFlags
+    features: riscvdata::ExtensionFlags,
+    compress: bool
 }
 
 impl Arch for ArchRiscV64E {
     fn set_features(&mut self, features: &[syn::Ident]) {
-        self.features = parse_features(features);
+        (self.features, self.compress) = parse_features(features, RiscVTarget::RV64E);
     }
 
     fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
@@ -118,7 +123,8 @@ impl Arch for ArchRiscV64E {
         let mut ctx = Context {
             state,
             target: RiscVTarget::RV64E,
-            features: self.features
+            features: self.features,
+            compress: self.compress
         };
 
         compile_instruction_inner(&mut ctx, input)
@@ -128,12 +134,13 @@ impl Arch for ArchRiscV64E {
 
 #[derive(Clone, Debug, Default)]
 pub struct ArchRiscV32I {
-    features: riscvdata::ExtensionFlags
+    features: riscvdata::ExtensionFlags,
+    compress: bool
 }
 
 impl Arch for ArchRiscV32I {
     fn set_features(&mut self, features: &[syn::Ident]) {
-        self.features = parse_features(features);
+        (self.features, self.compress) = parse_features(features, RiscVTarget::RV32I);
     }
 
     fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
@@ -148,7 +155,8 @@ impl Arch for ArchRiscV32I {
         let mut ctx = Context {
             state,
             target: RiscVTarget::RV32I,
-            features: self.features
+            features: self.features,
+            compress: self.compress
         };
 
         compile_instruction_inner(&mut ctx, input)
@@ -158,12 +166,13 @@ impl Arch for ArchRiscV32I {
 
 #[derive(Clone, Debug, Default)]
 pub struct ArchRiscV32E {
-    features: riscvdata::ExtensionFlags
+    features: riscvdata::ExtensionFlags,
+    compress: bool
 }
 
 impl Arch for ArchRiscV32E {
     fn set_features(&mut self, features: &[syn::Ident]) {
-        self.features = parse_features(features);
+        (self.features, self.compress) = parse_features(features, RiscVTarget::RV32E);
     }
 
     fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
@@ -178,7 +187,8 @@ impl Arch for ArchRiscV32E {
         let mut ctx = Context {
             state,
             target: RiscVTarget::RV32E,
-            features: self.features
+            features: self.features,
+            compress: self.compress
         };
 
         compile_instruction_inner(&mut ctx, input)
@@ -186,7 +196,10 @@ impl Arch for ArchRiscV32E {
 }
 
 fn compile_instruction_inner(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<()> {
-    let instruction = parser::parse_instruction(ctx, input)?;
+    let mut instruction = parser::parse_instruction(ctx, input)?;
+    if ctx.compress {
+        instruction = compress::compress_instruction(ctx, instruction);
+    }
     let span = instruction.span;
 
     let match_data = match matching::match_instruction(ctx, instruction) {
@@ -228,40 +241,81 @@ fn handle_static_reloc_inner(stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
         stmts.push(reloc.encode(size.in_bytes(), size.in_bytes(), &[relocation.to_id()]));
     }
 
-fn parse_features(features: &[syn::Ident]) -> riscvdata::ExtensionFlags {
+/// Parses the list of features, returning the enabled extensions, and if automatic compression
+/// of instructions was requested.
+fn parse_features(features: &[syn::Ident], target: RiscVTarget) -> (riscvdata::ExtensionFlags, bool) {
     // always enable the base extension
     let mut extension_flags = riscvdata::ExtensionFlags::default();
+    let mut compress = false;
 
     for feature in features {
         let mut s = feature.to_string();
         s.make_ascii_lowercase();
         let span = feature.span();
 
+        // the privileged instructions aren't described by an extension name, so they get their own feature
+        if s == "priv" {
+            extension_flags.insert(riscvdata::ExtensionFlags::Ex_Priv);
+            continue;
+        }
+
+        // not an extension, but a request to automatically use compressed instructions when possible
+        if s == "compress" {
+            compress = true;
+            continue;
+        }
+
+        // profiles like rva22u64 expand to their mandatory extensions
+        if let Some(&(_, is_64_bit, extensions)) = riscvdata::PROFILES.iter().find(|p| p.0 == s) {
+            if is_64_bit != target.is_64_bit() {
+                emit_error!(span, "Profile '{}' is not available on {}-bit targets", s, if target.is_64_bit() { 64 } else { 32 });
+                continue;
+            }
+
+            for extension in extensions {
+                extension_flags.insert(parse_extension(extension).expect("profile contains an unknown extension"));
+            }
+            continue;
+        }
 
+        // full ISA strings start with the base ISA, like rv64gc_zba_zbb. That has to match the target.
+        let mut isa = &s[..];
+        if let Some((prefix, rest)) = s.strip_prefix("rv32").map(|r| (false, r)).or_else(|| s.strip_prefix("rv64").map(|r| (true, r))) {
+            if prefix != target.is_64_bit() {
+                emit_error!(span, "ISA string '{}' is not valid on {}-bit targets", s, if target.is_64_bit() { 64 } else { 32 });
+                continue;
+            }
+            isa = rest;
+        } else if s.starts_with("rv") {
+            emit_error!(span, "Unknown risc-v profile or ISA string '{}'", s);
+            continue;
+        }
 
-        // riscv architecture specs are written like this: ABCDEFZlong_Zmore, which needs to be split
-        // into A, B, C, D, E, F, Zlong, Zmore
+        // riscv architecture specs are written like this: ABCDEFZlong_Zmore_Slong, which needs to be split
+        // into A, B, C, D, E, F, Zlong, Zmore, Slong
         let mut split = HashSet::new();
         let mut long_extension = None;
-        for (i, c) in s.char_indices() {
+        for (i, c) in isa.char_indices() {
             match (long_extension, c) {
                 (Some(x), '_') => {
-                    split.insert(&s[x .. i]);
+                    split.insert(&isa[x .. i]);
                     long_extension = None;
                 },
                 (Some(_), c) => (),
-                (None, 'z') => long_extension = Some(i),
+                (None, '_') => (),
+                (None, 'z' | 's' | 'x') => long_extension = Some(i),
                 (None, c) => {
-                    split.insert(&s[i .. i + c.len_utf8()]);
+                    split.insert(&isa[i .. i + c.len_utf8()]);
                 },
             }
         }
         if let Some(x) = long_extension {
-            split.insert(&s[x .. ]);
+            split.insert(&isa[x .. ]);
         }
 
         // expand g to imafdzicsr_zifencei
         if split.remove(&"g") {
+            split.insert("i");
             split.insert("m");
             split.insert("a");
             split.insert("f");
@@ -277,75 +331,93 @@ fn parse_features(features: &[syn::Ident]) -> riscvdata::ExtensionFlags {
             split.insert("zbs");
         }
 
-        // remove Ztso as it doesn't add instructions
-        split.remove(&"ztso");
-
         // many extensions
         for s in split.into_iter() {
-            let flag = match s {
-                "a" => riscvdata::ExtensionFlags::Ex_A,
-                "c" => riscvdata::ExtensionFlags::Ex_C,
-                "d" => riscvdata::ExtensionFlags::Ex_D,
-                "f" => riscvdata::ExtensionFlags::Ex_F,
-                "i" => riscvdata::ExtensionFlags::Ex_I,
-                "m" => riscvdata::ExtensionFlags::Ex_M,
-                "q" => riscvdata::ExtensionFlags::Ex_Q,
-                "zabha" => riscvdata::ExtensionFlags::Ex_Zabha,
-                "zacas" => riscvdata::ExtensionFlags::Ex_Zacas,
-                "zawrs" => riscvdata::ExtensionFlags::Ex_Zawrs,
-                "zba" => riscvdata::ExtensionFlags::Ex_Zba,
-                "zbb" => riscvdata::ExtensionFlags::Ex_Zbb,
-                "zbc" => riscvdata::ExtensionFlags::Ex_Zbc,
-                "zbkb" => riscvdata::ExtensionFlags::Ex_Zbkb,
-                "zbkc" => riscvdata::ExtensionFlags::Ex_Zbkc,
-                "zbkx" => riscvdata::ExtensionFlags::Ex_Zbkx,
-                "zbs" => riscvdata::ExtensionFlags::Ex_Zbs,
-                "zcb" => riscvdata::ExtensionFlags::Ex_Zcb,
-                "zcmop" => riscvdata::ExtensionFlags::Ex_Zcmop,
-                "zcmp" => riscvdata::ExtensionFlags::Ex_Zcmp,
-                "zcmt" => riscvdata::ExtensionFlags::Ex_Zcmt,
-                "zdinx" => riscvdata::ExtensionFlags::Ex_Zdinx,
-                "zfa" => riscvdata::ExtensionFlags::Ex_Zfa,
-                "zfbfmin" => riscvdata::ExtensionFlags::Ex_Zfbfmin,
-                "zfh" => riscvdata::ExtensionFlags::Ex_Zfh,
-                "zfhmin" => riscvdata::ExtensionFlags::Ex_Zfhmin,
-                "zfinx" => riscvdata::ExtensionFlags::Ex_Zfinx,
-                "zhinx" => riscvdata::ExtensionFlags::Ex_Zhinx,
-                "zhinxmin" => riscvdata::ExtensionFlags::Ex_Zhinxmin,
-                "zicbom" => riscvdata::ExtensionFlags::Ex_Zicbom,
-                "zicbop" => riscvdata::ExtensionFlags::Ex_Zicbop,
-                "zicboz" => riscvdata::ExtensionFlags::Ex_Zicboz,
-                "zicfilp" => riscvdata::ExtensionFlags::Ex_Zicfilp,
-                "zicfiss" => riscvdata::ExtensionFlags::Ex_Zicfiss,
-                "zicntr" => riscvdata::ExtensionFlags::Ex_Zicntr,
-                "zicond" => riscvdata::ExtensionFlags::Ex_Zicond,
-                "zicsr" => riscvdata::ExtensionFlags::Ex_Zicsr,
-                "zifencei" => riscvdata::ExtensionFlags::Ex_Zifencei,
-                "zihintntl" => riscvdata::ExtensionFlags::Ex_Zihintntl,
-                "zihintpause" => riscvdata::ExtensionFlags::Ex_Zihintpause,
-                "zimop" => riscvdata::ExtensionFlags::Ex_Zimop,
-                "zk" => riscvdata::ExtensionFlags::Ex_Zk,
-                "zkn" => riscvdata::ExtensionFlags::Ex_Zkn,
-                "zknd" => riscvdata::ExtensionFlags::Ex_Zknd,
-                "zkne" => riscvdata::ExtensionFlags::Ex_Zkne,
-                "zknh" => riscvdata::ExtensionFlags::Ex_Zknh,
-                "zks" => riscvdata::ExtensionFlags::Ex_Zks,
-                "zksed" => riscvdata::ExtensionFlags::Ex_Zksed,
-                "zksh" => riscvdata::ExtensionFlags::Ex_Zksh,
-                x => {
-                    emit_error!(span, "Unknown risc-v extension '{}'", x);
-                    continue;
-                }
-            };
-
-            extension_flags.insert(flag);
+            match parse_extension(s) {
+                Some(flag) => extension_flags.insert(flag),
+                None => emit_error!(span, "Unknown risc-v extension '{}'", s),
+            }
         }
     }
 
-    // TODO
-    // in the future we might want to handle extension dependencies / requirements here
-    // but as of right now the docs are very vague about the exact way this should be handled.
-    // so for now we just allow any combinations and do not add implicit dependencies
+    // add any extensions required by the enabled extensions
+    (extension_flags.with_implied(), compress)
+}
+
+/// Parses the name of a single extension. Returns an empty set for known extensions
+/// that do not add any instructions.
+fn parse_extension(name: &str) -> Option<riscvdata::ExtensionFlags> {
+    let flag = match name {
+        // the embedded base instruction set uses the same instructions
+        "e" => riscvdata::ExtensionFlags::Ex_I,
+        "priv" => riscvdata::ExtensionFlags::Ex_Priv,
+        "a" => riscvdata::ExtensionFlags::Ex_A,
+        "c" => riscvdata::ExtensionFlags::Ex_C,
+        "d" => riscvdata::ExtensionFlags::Ex_D,
+        "f" => riscvdata::ExtensionFlags::Ex_F,
+        "h" => riscvdata::ExtensionFlags::Ex_H,
+        "i" => riscvdata::ExtensionFlags::Ex_I,
+        "m" => riscvdata::ExtensionFlags::Ex_M,
+        "q" => riscvdata::ExtensionFlags::Ex_Q,
+        "svinval" => riscvdata::ExtensionFlags::Ex_Svinval,
+        "v" => riscvdata::ExtensionFlags::Ex_V,
+        "zabha" => riscvdata::ExtensionFlags::Ex_Zabha,
+        "zacas" => riscvdata::ExtensionFlags::Ex_Zacas,
+        "zawrs" => riscvdata::ExtensionFlags::Ex_Zawrs,
+        "zba" => riscvdata::ExtensionFlags::Ex_Zba,
+        "zbb" => riscvdata::ExtensionFlags::Ex_Zbb,
+        "zbc" => riscvdata::ExtensionFlags::Ex_Zbc,
+        "zbkb" => riscvdata::ExtensionFlags::Ex_Zbkb,
+        "zbkc" => riscvdata::ExtensionFlags::Ex_Zbkc,
+        "zbkx" => riscvdata::ExtensionFlags::Ex_Zbkx,
+        "zbs" => riscvdata::ExtensionFlags::Ex_Zbs,
+        "zcb" => riscvdata::ExtensionFlags::Ex_Zcb,
+        "zcmop" => riscvdata::ExtensionFlags::Ex_Zcmop,
+        "zcmp" => riscvdata::ExtensionFlags::Ex_Zcmp,
+        "zcmt" => riscvdata::ExtensionFlags::Ex_Zcmt,
+        "zdinx" => riscvdata::ExtensionFlags::Ex_Zdinx,
+        "zfa" => riscvdata::ExtensionFlags::Ex_Zfa,
+        "zfbfmin" => riscvdata::ExtensionFlags::Ex_Zfbfmin,
+        "zfh" => riscvdata::ExtensionFlags::Ex_Zfh,
+        "zfhmin" => riscvdata::ExtensionFlags::Ex_Zfhmin,
+        "zfinx" => riscvdata::ExtensionFlags::Ex_Zfinx,
+        "zhinx" => riscvdata::ExtensionFlags::Ex_Zhinx,
+        "zhinxmin" => riscvdata::ExtensionFlags::Ex_Zhinxmin,
+        "zicbom" => riscvdata::ExtensionFlags::Ex_Zicbom,
+        "zicbop" => riscvdata::ExtensionFlags::Ex_Zicbop,
+        "zicboz" => riscvdata::ExtensionFlags::Ex_Zicboz,
+        "zicfilp" => riscvdata::ExtensionFlags::Ex_Zicfilp,
+        "zicfiss" => riscvdata::ExtensionFlags::Ex_Zicfiss,
+        "zicntr" => riscvdata::ExtensionFlags::Ex_Zicntr,
+        "zicond" => riscvdata::ExtensionFlags::Ex_Zicond,
+        "zicsr" => riscvdata::ExtensionFlags::Ex_Zicsr,
+        "zifencei" => riscvdata::ExtensionFlags::Ex_Zifencei,
+        "zihintntl" => riscvdata::ExtensionFlags::Ex_Zihintntl,
+        "zihintpause" => riscvdata::ExtensionFlags::Ex_Zihintpause,
+        "zimop" => riscvdata::ExtensionFlags::Ex_Zimop,
+        "zk" => riscvdata::ExtensionFlags::Ex_Zk,
+        "zkn" => riscvdata::ExtensionFlags::Ex_Zkn,
+        "zknd" => riscvdata::ExtensionFlags::Ex_Zknd,
+        "zkne" => riscvdata::ExtensionFlags::Ex_Zkne,
+        "zknh" => riscvdata::ExtensionFlags::Ex_Zknh,
+        "zks" => riscvdata::ExtensionFlags::Ex_Zks,
+        "zksed" => riscvdata::ExtensionFlags::Ex_Zksed,
+        "zksh" => riscvdata::ExtensionFlags::Ex_Zksh,
+        "zvbb" => riscvdata::ExtensionFlags::Ex_Zvbb,
+        "zve32f" => riscvdata::ExtensionFlags::Ex_Zve32f,
+        "zve32x" => riscvdata::ExtensionFlags::Ex_Zve32x,
+        "zve64d" => riscvdata::ExtensionFlags::Ex_Zve64d,
+        "zve64f" => riscvdata::ExtensionFlags::Ex_Zve64f,
+        "zve64x" => riscvdata::ExtensionFlags::Ex_Zve64x,
+        "zvkb" => riscvdata::ExtensionFlags::Ex_Zvkb,
+        "zvkned" => riscvdata::ExtensionFlags::Ex_Zvkned,
+        "zvknha" => riscvdata::ExtensionFlags::Ex_Zvknha,
+        "zvknhb" => riscvdata::ExtensionFlags::Ex_Zvknhb,
+        x if riscvdata::INSTRUCTIONLESS_EXTENSIONS.contains(&x) => riscvdata::ExtensionFlags::empty(),
+        // the minimum vector length extensions zvl32b up to zvl65536b
+        x if x.starts_with("zvl") && x.ends_with('b') && x[3 .. x.len() - 1].parse::<u32>().is_ok() => riscvdata::ExtensionFlags::empty(),
+        _ => return None
+    };
 
-    extension_flags
+    Some(flag)
 }
@@ -1207,6 +1207,77 @@ Ops!(
     Single(0x40507053), RV32 | RV64, [F, F] => [R(7), R(15)], [Ex_F | Ex_Zfa];
 ],
 
+// Extension(s) h
+
+"hfence.gvma" = [
+    // hfence.gvma rs1, rs2 (h)
+    Single(0x62000073), RV32 | RV64, [X, X] => [R(15), R(20)], [Ex_H];
+    // hfence.gvma rs1 (h)
+    Single(0x62000073), RV32 | RV64, [X] => [R(15)], [Ex_H];
+    // hfence.gvma  (h)
+    Single(0x62000073), RV32 | RV64, [] => [], [Ex_H];
+],
+"hfence.vvma" = [
+    // hfence.vvma rs1, rs2 (h)
+    Single(0x22000073), RV32 | RV64, [X, X] => [R(15), R(20)], [Ex_H];
+    // hfence.vvma rs1 (h)
+    Single(0x22000073), RV32 | RV64, [X] => [R(15)], [Ex_H];
+    // hfence.vvma  (h)
+    Single(0x22000073), RV32 | RV64, [] => [], [Ex_H];
+],
+"hlv.b" = [
+    // hlv.b rd, rs1 (h)
+    Single(0x60004073), RV32 | RV64, [X, Ref] => [R(7), R(15)], [Ex_H];
+],
+"hlv.bu" = [
+    // hlv.bu rd, rs1 (h)
+    Single(0x60104073), RV32 | RV64, [X, Ref] => [R(7), R(15)], [Ex_H];
+],
+"hlv.d" = [
+    // hlv.d rd, rs1 (h)
+    Single(0x6C004073),        RV64, [X, Ref] => [R(7), R(15)], [Ex_H];
+],
+"hlv.h" = [
+    // hlv.h rd, rs1 (h)
+    Single(0x64004073), RV32 | RV64, [X, Ref] => [R(7), R(15)], [Ex_H];
+],
+"hlv.hu" = [
+    // hlv.hu rd, rs1 (h)
+    Single(0x64104073), RV32 | RV64, [X, Ref] => [R(7), R(15)], [Ex_H];
+],
+"hlv.w" = [
+    // hlv.w rd, rs1 (h)
+    Single(0x68004073), RV32 | RV64, [X, Ref] => [R(7), R(15)], [Ex_H];
+],
+"hlv.wu" = [
+    // hlv.wu rd, rs1 (h)
+    Single(0x68104073),        RV64, [X, Ref] => [R(7), R(15)], [Ex_H];
+],
+"hlvx.hu" = [
+    // hlvx.hu rd, rs1 (h)
+    Single(0x64304073), RV32 | RV64, [X, Ref] => [R(7), R(15)], [Ex_H];
+],
+"hlvx.wu" = [
+    // hlvx.wu rd, rs1 (h)
+    Single(0x68304073), RV32 | RV64, [X, Ref] => [R(7), R(15)], [Ex_H];
+],
+"hsv.b" = [
+    // hsv.b rs1, rs2 (h)
+    Single(0x62004073), RV32 | RV64, [X, Ref] => [R(20), R(15)], [Ex_H];
+],
+"hsv.d" = [
+    // hsv.d rs1, rs2 (h)
+    Single(0x6E004073),        RV64, [X, Ref] => [R(20), R(15)], [Ex_H];
+],
+"hsv.h" = [
+    // hsv.h rs1, rs2 (h)
+    Single(0x66004073), RV32 | RV64, [X, Ref] => [R(20), R(15)], [Ex_H];
+],
+"hsv.w" = [
+    // hsv.w rs1, rs2 (h)
+    Single(0x6A004073), RV32 | RV64, [X, Ref] => [R(20), R(15)], [Ex_H];
+],
+
 // Extension(s) i
 
 "add" = [
@@ -1766,6 +1837,29 @@ Ops!(
     Single(0x0200603B),        RV64, [X, X, X] => [R(7), R(15), R(20)], [Ex_M];
 ],
 
+// Extension(s) priv
+
+"mret" = [
+    // mret  (priv)
+    Single(0x30200073), RV32 | RV64, [] => [], [Ex_Priv];
+],
+"sfence.vma" = [
+    // sfence.vma rs1, rs2 (priv)
+    Single(0x12000073), RV32 | RV64, [X, X] => [R(15), R(20)], [Ex_Priv];
+    // sfence.vma rs1 (priv)
+    Single(0x12000073), RV32 | RV64, [X] => [R(15)], [Ex_Priv];
+    // sfence.vma  (priv)
+    Single(0x12000073), RV32 | RV64, [] => [], [Ex_Priv];
+],
+"sret" = [
+    // sret  (priv)
+    Single(0x10200073), RV32 | RV64, [] => [], [Ex_Priv];
+],
+"wfi" = [
+    // wfi  (priv)
+    Single(0x10500073), RV32 | RV64, [] => [], [Ex_Priv];
+],
+
 // Extension(s) q
 
 "fabs.q" = [
@@ -2003,6 +2097,3799 @@ Ops!(
     Single(0x46200053), RV32 | RV64, [F, F] => [R(7), R(15)], [Ex_Q | Ex_Zfh, Ex_Q | Ex_Zfhmin];
 ],
 
+// Extension(s) svinval
+
+"sfence.inval.ir" = [
+    // sfence.inval.ir  (svinval)
+    Single(0x18100073), RV32 | RV64, [] => [], [Ex_Svinval];
+],
+"sfence.w.inval" = [
+    // sfence.w.inval  (svinval)
+    Single(0x18000073), RV32 | RV64, [] => [], [Ex_Svinval];
+],
+"sinval.vma" = [
+    // sinval.vma rs1, rs2 (svinval)
+    Single(0x16000073), RV32 | RV64, [X, X] => [R(15), R(20)], [Ex_Svinval];
+    // sinval.vma rs1 (svinval)
+    Single(0x16000073), RV32 | RV64, [X] => [R(15)], [Ex_Svinval];
+    // sinval.vma  (svinval)
+    Single(0x16000073), RV32 | RV64, [] => [], [Ex_Svinval];
+],
+
+// Extension(s) h_svinval
+
+"hinval.gvma" = [
+    // hinval.gvma rs1, rs2 (h_svinval)
+    Single(0x66000073), RV32 | RV64, [X, X] => [R(15), R(20)], [Ex_H | Ex_Svinval];
+    // hinval.gvma rs1 (h_svinval)
+    Single(0x66000073), RV32 | RV64, [X] => [R(15)], [Ex_H | Ex_Svinval];
+    // hinval.gvma  (h_svinval)
+    Single(0x66000073), RV32 | RV64, [] => [], [Ex_H | Ex_Svinval];
+],
+"hinval.vvma" = [
+    // hinval.vvma rs1, rs2 (h_svinval)
+    Single(0x26000073), RV32 | RV64, [X, X] => [R(15), R(20)], [Ex_H | Ex_Svinval];
+    // hinval.vvma rs1 (h_svinval)
+    Single(0x26000073), RV32 | RV64, [X] => [R(15)], [Ex_H | Ex_Svinval];
+    // hinval.vvma  (h_svinval)
+    Single(0x26000073), RV32 | RV64, [] => [], [Ex_H | Ex_Svinval];
+],
+
+// Extension(s) v, zve32f, zve32x, zve64d, zve64f, zve64x
+
+"vaadd.vv" = [
+    // vaadd.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x24002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vaadd.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x26002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vaadd.vx" = [
+    // vaadd.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x24006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vaadd.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x26006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vaaddu.vv" = [
+    // vaaddu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x20002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vaaddu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x22002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vaaddu.vx" = [
+    // vaaddu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x20006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vaaddu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x22006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vadc.vim" = [
+    // vadc.vim vd, vs2, simm5, v0 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x40003057), RV32 | RV64, [V, V, Imm, Reg(RegId::V0)] => [Vno0(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vadc.vvm" = [
+    // vadc.vvm vd, vs2, vs1, v0 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x40000057), RV32 | RV64, [V, V, V, Reg(RegId::V0)] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vadc.vxm" = [
+    // vadc.vxm vd, vs2, rs1, v0 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x40004057), RV32 | RV64, [V, V, X, Reg(RegId::V0)] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vadd.vi" = [
+    // vadd.vi vd, vs2, simm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x00003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vadd.vi vd, vs2, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vadd.vv" = [
+    // vadd.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x00000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vadd.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vadd.vx" = [
+    // vadd.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x00004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vadd.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vand.vi" = [
+    // vand.vi vd, vs2, simm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x24003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vand.vi vd, vs2, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x26003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vand.vv" = [
+    // vand.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x24000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vand.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x26000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vand.vx" = [
+    // vand.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x24004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vand.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x26004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vasub.vv" = [
+    // vasub.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2C002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vasub.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2E002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vasub.vx" = [
+    // vasub.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2C006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vasub.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2E006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vasubu.vv" = [
+    // vasubu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x28002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vasubu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2A002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vasubu.vx" = [
+    // vasubu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x28006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vasubu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2A006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vcompress.vm" = [
+    // vcompress.vm vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x5E002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vcpop.m" = [
+    // vcpop.m rd, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x40082057), RV32 | RV64, [X, V, Vm] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vcpop.m rd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x42082057), RV32 | RV64, [X, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vdiv.vv" = [
+    // vdiv.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x84002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vdiv.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x86002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vdiv.vx" = [
+    // vdiv.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x84006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vdiv.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x86006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vdivu.vv" = [
+    // vdivu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x80002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vdivu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x82002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vdivu.vx" = [
+    // vdivu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x80006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vdivu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x82006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vfirst.m" = [
+    // vfirst.m rd, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4008A057), RV32 | RV64, [X, V, Vm] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vfirst.m rd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4208A057), RV32 | RV64, [X, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vid.v" = [
+    // vid.v vd, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x5008A057), RV32 | RV64, [V, Vm] => [Vno0(7)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vid.v vd (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x5208A057), RV32 | RV64, [V] => [R(7)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"viota.m" = [
+    // viota.m vd, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x50082057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // viota.m vd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x52082057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl1r.v" = [
+    // vl1r.v vd, (rs1) (subformat of rv_v::vl1re8.v) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02800007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl1re16.v" = [
+    // vl1re16.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02805007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl1re32.v" = [
+    // vl1re32.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02806007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl1re8.v" = [
+    // vl1re8.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02800007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl2r.v" = [
+    // vl2r.v vd, (rs1) (subformat of rv_v::vl2re8.v) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x22800007), RV32 | RV64, [V, Ref] => [Vgroup(7, 2), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl2re16.v" = [
+    // vl2re16.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x22805007), RV32 | RV64, [V, Ref] => [Vgroup(7, 2), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl2re32.v" = [
+    // vl2re32.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x22806007), RV32 | RV64, [V, Ref] => [Vgroup(7, 2), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl2re8.v" = [
+    // vl2re8.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x22800007), RV32 | RV64, [V, Ref] => [Vgroup(7, 2), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl4r.v" = [
+    // vl4r.v vd, (rs1) (subformat of rv_v::vl4re8.v) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62800007), RV32 | RV64, [V, Ref] => [Vgroup(7, 4), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl4re16.v" = [
+    // vl4re16.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62805007), RV32 | RV64, [V, Ref] => [Vgroup(7, 4), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl4re32.v" = [
+    // vl4re32.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62806007), RV32 | RV64, [V, Ref] => [Vgroup(7, 4), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl4re8.v" = [
+    // vl4re8.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62800007), RV32 | RV64, [V, Ref] => [Vgroup(7, 4), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl8r.v" = [
+    // vl8r.v vd, (rs1) (subformat of rv_v::vl8re8.v) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE2800007), RV32 | RV64, [V, Ref] => [Vgroup(7, 8), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl8re16.v" = [
+    // vl8re16.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE2805007), RV32 | RV64, [V, Ref] => [Vgroup(7, 8), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl8re32.v" = [
+    // vl8re32.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE2806007), RV32 | RV64, [V, Ref] => [Vgroup(7, 8), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl8re8.v" = [
+    // vl8re8.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE2800007), RV32 | RV64, [V, Ref] => [Vgroup(7, 8), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vle16.v" = [
+    // vle16.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x00005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vle16.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vle16ff.v" = [
+    // vle16ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x01005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vle16ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x03005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vle32.v" = [
+    // vle32.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x00006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vle32.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vle32ff.v" = [
+    // vle32ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x01006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vle32ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x03006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vle8.v" = [
+    // vle8.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x00000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vle8.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vle8ff.v" = [
+    // vle8ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x01000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vle8ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x03000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlm.v" = [
+    // vlm.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02B00007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxei16.v" = [
+    // vloxei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0C005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0E005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxei32.v" = [
+    // vloxei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0C006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0E006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxei8.v" = [
+    // vloxei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0C000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0E000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg2ei16.v" = [
+    // vloxseg2ei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2C005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg2ei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2E005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg2ei32.v" = [
+    // vloxseg2ei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2C006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg2ei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2E006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg2ei8.v" = [
+    // vloxseg2ei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2C000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg2ei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2E000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg3ei16.v" = [
+    // vloxseg3ei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4C005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg3ei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4E005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg3ei32.v" = [
+    // vloxseg3ei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4C006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg3ei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4E006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg3ei8.v" = [
+    // vloxseg3ei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4C000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg3ei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4E000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg4ei16.v" = [
+    // vloxseg4ei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6C005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg4ei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6E005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg4ei32.v" = [
+    // vloxseg4ei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6C006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg4ei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6E006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg4ei8.v" = [
+    // vloxseg4ei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6C000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg4ei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6E000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg5ei16.v" = [
+    // vloxseg5ei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8C005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg5ei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8E005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg5ei32.v" = [
+    // vloxseg5ei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8C006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg5ei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8E006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg5ei8.v" = [
+    // vloxseg5ei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8C000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg5ei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8E000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg6ei16.v" = [
+    // vloxseg6ei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAC005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg6ei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAE005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg6ei32.v" = [
+    // vloxseg6ei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAC006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg6ei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAE006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg6ei8.v" = [
+    // vloxseg6ei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAC000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg6ei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAE000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg7ei16.v" = [
+    // vloxseg7ei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCC005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg7ei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCE005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg7ei32.v" = [
+    // vloxseg7ei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCC006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg7ei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCE006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg7ei8.v" = [
+    // vloxseg7ei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCC000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg7ei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCE000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg8ei16.v" = [
+    // vloxseg8ei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEC005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg8ei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEE005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg8ei32.v" = [
+    // vloxseg8ei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEC006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg8ei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEE006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg8ei8.v" = [
+    // vloxseg8ei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEC000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg8ei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEE000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlse16.v" = [
+    // vlse16.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x08005007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlse16.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0A005007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlse32.v" = [
+    // vlse32.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x08006007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlse32.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0A006007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlse8.v" = [
+    // vlse8.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x08000007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlse8.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0A000007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg2e16.v" = [
+    // vlseg2e16.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x20005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg2e16.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x22005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg2e16ff.v" = [
+    // vlseg2e16ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x21005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg2e16ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x23005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg2e32.v" = [
+    // vlseg2e32.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x20006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg2e32.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x22006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg2e32ff.v" = [
+    // vlseg2e32ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x21006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg2e32ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x23006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg2e8.v" = [
+    // vlseg2e8.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x20000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg2e8.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x22000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg2e8ff.v" = [
+    // vlseg2e8ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x21000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg2e8ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x23000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg3e16.v" = [
+    // vlseg3e16.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x40005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg3e16.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x42005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg3e16ff.v" = [
+    // vlseg3e16ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x41005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg3e16ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x43005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg3e32.v" = [
+    // vlseg3e32.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x40006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg3e32.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x42006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg3e32ff.v" = [
+    // vlseg3e32ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x41006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg3e32ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x43006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg3e8.v" = [
+    // vlseg3e8.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x40000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg3e8.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x42000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg3e8ff.v" = [
+    // vlseg3e8ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x41000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg3e8ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x43000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg4e16.v" = [
+    // vlseg4e16.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x60005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg4e16.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg4e16ff.v" = [
+    // vlseg4e16ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x61005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg4e16ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x63005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg4e32.v" = [
+    // vlseg4e32.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x60006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg4e32.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg4e32ff.v" = [
+    // vlseg4e32ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x61006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg4e32ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x63006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg4e8.v" = [
+    // vlseg4e8.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x60000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg4e8.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg4e8ff.v" = [
+    // vlseg4e8ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x61000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg4e8ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x63000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg5e16.v" = [
+    // vlseg5e16.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x80005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg5e16.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x82005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg5e16ff.v" = [
+    // vlseg5e16ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x81005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg5e16ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x83005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg5e32.v" = [
+    // vlseg5e32.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x80006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg5e32.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x82006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg5e32ff.v" = [
+    // vlseg5e32ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x81006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg5e32ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x83006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg5e8.v" = [
+    // vlseg5e8.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x80000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg5e8.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x82000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg5e8ff.v" = [
+    // vlseg5e8ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x81000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg5e8ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x83000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg6e16.v" = [
+    // vlseg6e16.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA0005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg6e16.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA2005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg6e16ff.v" = [
+    // vlseg6e16ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA1005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg6e16ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA3005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg6e32.v" = [
+    // vlseg6e32.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA0006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg6e32.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA2006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg6e32ff.v" = [
+    // vlseg6e32ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA1006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg6e32ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA3006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg6e8.v" = [
+    // vlseg6e8.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA0000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg6e8.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA2000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg6e8ff.v" = [
+    // vlseg6e8ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA1000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg6e8ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA3000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg7e16.v" = [
+    // vlseg7e16.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC0005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg7e16.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC2005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg7e16ff.v" = [
+    // vlseg7e16ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC1005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg7e16ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC3005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg7e32.v" = [
+    // vlseg7e32.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC0006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg7e32.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC2006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg7e32ff.v" = [
+    // vlseg7e32ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC1006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg7e32ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC3006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg7e8.v" = [
+    // vlseg7e8.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC0000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg7e8.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC2000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg7e8ff.v" = [
+    // vlseg7e8ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC1000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg7e8ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC3000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg8e16.v" = [
+    // vlseg8e16.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE0005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg8e16.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE2005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg8e16ff.v" = [
+    // vlseg8e16ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE1005007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg8e16ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE3005007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg8e32.v" = [
+    // vlseg8e32.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE0006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg8e32.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE2006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg8e32ff.v" = [
+    // vlseg8e32ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE1006007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg8e32ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE3006007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg8e8.v" = [
+    // vlseg8e8.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE0000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg8e8.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE2000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg8e8ff.v" = [
+    // vlseg8e8ff.v vd, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE1000007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg8e8ff.v vd, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE3000007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg2e16.v" = [
+    // vlsseg2e16.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x28005007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg2e16.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2A005007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg2e32.v" = [
+    // vlsseg2e32.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x28006007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg2e32.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2A006007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg2e8.v" = [
+    // vlsseg2e8.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x28000007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg2e8.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2A000007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg3e16.v" = [
+    // vlsseg3e16.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x48005007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg3e16.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4A005007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg3e32.v" = [
+    // vlsseg3e32.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x48006007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg3e32.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4A006007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg3e8.v" = [
+    // vlsseg3e8.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x48000007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg3e8.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4A000007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg4e16.v" = [
+    // vlsseg4e16.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x68005007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg4e16.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6A005007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg4e32.v" = [
+    // vlsseg4e32.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x68006007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg4e32.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6A006007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg4e8.v" = [
+    // vlsseg4e8.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x68000007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg4e8.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6A000007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg5e16.v" = [
+    // vlsseg5e16.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x88005007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg5e16.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8A005007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg5e32.v" = [
+    // vlsseg5e32.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x88006007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg5e32.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8A006007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg5e8.v" = [
+    // vlsseg5e8.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x88000007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg5e8.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8A000007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg6e16.v" = [
+    // vlsseg6e16.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA8005007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg6e16.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAA005007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg6e32.v" = [
+    // vlsseg6e32.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA8006007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg6e32.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAA006007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg6e8.v" = [
+    // vlsseg6e8.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA8000007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg6e8.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAA000007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg7e16.v" = [
+    // vlsseg7e16.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC8005007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg7e16.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCA005007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg7e32.v" = [
+    // vlsseg7e32.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC8006007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg7e32.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCA006007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg7e8.v" = [
+    // vlsseg7e8.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC8000007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg7e8.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCA000007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg8e16.v" = [
+    // vlsseg8e16.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE8005007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg8e16.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEA005007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg8e32.v" = [
+    // vlsseg8e32.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE8006007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg8e32.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEA006007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg8e8.v" = [
+    // vlsseg8e8.v vd, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE8000007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg8e8.v vd, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEA000007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxei16.v" = [
+    // vluxei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x04005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x06005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxei32.v" = [
+    // vluxei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x04006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x06006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxei8.v" = [
+    // vluxei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x04000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x06000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg2ei16.v" = [
+    // vluxseg2ei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x24005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg2ei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x26005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg2ei32.v" = [
+    // vluxseg2ei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x24006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg2ei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x26006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg2ei8.v" = [
+    // vluxseg2ei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x24000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg2ei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x26000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg3ei16.v" = [
+    // vluxseg3ei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x44005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg3ei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x46005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg3ei32.v" = [
+    // vluxseg3ei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x44006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg3ei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x46006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg3ei8.v" = [
+    // vluxseg3ei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x44000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg3ei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x46000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg4ei16.v" = [
+    // vluxseg4ei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x64005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg4ei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x66005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg4ei32.v" = [
+    // vluxseg4ei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x64006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg4ei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x66006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg4ei8.v" = [
+    // vluxseg4ei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x64000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg4ei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x66000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg5ei16.v" = [
+    // vluxseg5ei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x84005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg5ei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x86005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg5ei32.v" = [
+    // vluxseg5ei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x84006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg5ei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x86006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg5ei8.v" = [
+    // vluxseg5ei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x84000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg5ei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x86000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg6ei16.v" = [
+    // vluxseg6ei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA4005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg6ei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA6005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg6ei32.v" = [
+    // vluxseg6ei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA4006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg6ei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA6006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg6ei8.v" = [
+    // vluxseg6ei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA4000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg6ei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA6000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg7ei16.v" = [
+    // vluxseg7ei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC4005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg7ei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC6005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg7ei32.v" = [
+    // vluxseg7ei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC4006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg7ei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC6006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg7ei8.v" = [
+    // vluxseg7ei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC4000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg7ei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC6000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg8ei16.v" = [
+    // vluxseg8ei16.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE4005007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg8ei16.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE6005007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg8ei32.v" = [
+    // vluxseg8ei32.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE4006007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg8ei32.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE6006007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg8ei8.v" = [
+    // vluxseg8ei8.v vd, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE4000007), RV32 | RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg8ei8.v vd, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE6000007), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmacc.vv" = [
+    // vmacc.vv vd, vs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB4002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmacc.vv vd, vs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB6002057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmacc.vx" = [
+    // vmacc.vx vd, rs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB4006057), RV32 | RV64, [V, X, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmacc.vx vd, rs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB6006057), RV32 | RV64, [V, X, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmadc.vi" = [
+    // vmadc.vi vd, vs2, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x46003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmadc.vim" = [
+    // vmadc.vim vd, vs2, simm5, v0 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x44003057), RV32 | RV64, [V, V, Imm, Reg(RegId::V0)] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmadc.vv" = [
+    // vmadc.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x46000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmadc.vvm" = [
+    // vmadc.vvm vd, vs2, vs1, v0 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x44000057), RV32 | RV64, [V, V, V, Reg(RegId::V0)] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmadc.vx" = [
+    // vmadc.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x46004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmadc.vxm" = [
+    // vmadc.vxm vd, vs2, rs1, v0 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x44004057), RV32 | RV64, [V, V, X, Reg(RegId::V0)] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmadd.vv" = [
+    // vmadd.vv vd, vs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA4002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmadd.vv vd, vs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA6002057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmadd.vx" = [
+    // vmadd.vx vd, rs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA4006057), RV32 | RV64, [V, X, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmadd.vx vd, rs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA6006057), RV32 | RV64, [V, X, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmand.mm" = [
+    // vmand.mm vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x66002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmandn.mm" = [
+    // vmandn.mm vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmax.vv" = [
+    // vmax.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x1C000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmax.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x1E000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmax.vx" = [
+    // vmax.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x1C004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmax.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x1E004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmaxu.vv" = [
+    // vmaxu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x18000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmaxu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x1A000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmaxu.vx" = [
+    // vmaxu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x18004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmaxu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x1A004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmclr.m" = [
+    // vmclr.m vd=vs2=vs1 (subformat of rv_v::vmxor.mm) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6E002057), RV32 | RV64, [V] => [R(7), Repeat, R(20), Repeat, R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmerge.vim" = [
+    // vmerge.vim vd, vs2, simm5, v0 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x5C003057), RV32 | RV64, [V, V, Imm, Reg(RegId::V0)] => [Vno0(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmerge.vvm" = [
+    // vmerge.vvm vd, vs2, vs1, v0 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x5C000057), RV32 | RV64, [V, V, V, Reg(RegId::V0)] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmerge.vxm" = [
+    // vmerge.vxm vd, vs2, rs1, v0 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x5C004057), RV32 | RV64, [V, V, X, Reg(RegId::V0)] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmin.vv" = [
+    // vmin.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x14000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmin.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x16000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmin.vx" = [
+    // vmin.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x14004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmin.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x16004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vminu.vv" = [
+    // vminu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x10000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vminu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x12000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vminu.vx" = [
+    // vminu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x10004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vminu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x12004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmmv.m" = [
+    // vmmv.m vd, vs2=vs1 (subformat of rv_v::vmand.mm) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x66002057), RV32 | RV64, [V, V] => [R(7), R(20), Repeat, R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmnand.mm" = [
+    // vmnand.mm vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x76002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmnor.mm" = [
+    // vmnor.mm vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x7A002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmnot.m" = [
+    // vmnot.m vd, vs2=vs1 (subformat of rv_v::vmnand.mm) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x76002057), RV32 | RV64, [V, V] => [R(7), R(20), Repeat, R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmor.mm" = [
+    // vmor.mm vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6A002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmorn.mm" = [
+    // vmorn.mm vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x72002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsbc.vv" = [
+    // vmsbc.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4E000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsbc.vvm" = [
+    // vmsbc.vvm vd, vs2, vs1, v0 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4C000057), RV32 | RV64, [V, V, V, Reg(RegId::V0)] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsbc.vx" = [
+    // vmsbc.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4E004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsbc.vxm" = [
+    // vmsbc.vxm vd, vs2, rs1, v0 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4C004057), RV32 | RV64, [V, V, X, Reg(RegId::V0)] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsbf.m" = [
+    // vmsbf.m vd, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x5000A057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsbf.m vd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x5200A057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmseq.vi" = [
+    // vmseq.vi vd, vs2, simm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x60003057), RV32 | RV64, [V, V, Imm, Vm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmseq.vi vd, vs2, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmseq.vv" = [
+    // vmseq.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x60000057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmseq.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmseq.vx" = [
+    // vmseq.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x60004057), RV32 | RV64, [V, V, X, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmseq.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmset.m" = [
+    // vmset.m vd=vs2=vs1 (subformat of rv_v::vmxnor.mm) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x7E002057), RV32 | RV64, [V] => [R(7), Repeat, R(20), Repeat, R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsge.vv" = [
+    // vmsge.vv vd, vs1, vs2, vm (subformat of rv_v::vmsle.vv) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x74000057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsge.vv vd, vs1, vs2 (subformat of rv_v::vmsle.vv) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x76000057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsgeu.vv" = [
+    // vmsgeu.vv vd, vs1, vs2, vm (subformat of rv_v::vmsleu.vv) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x70000057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsgeu.vv vd, vs1, vs2 (subformat of rv_v::vmsleu.vv) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x72000057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsgt.vi" = [
+    // vmsgt.vi vd, vs2, simm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x7C003057), RV32 | RV64, [V, V, Imm, Vm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsgt.vi vd, vs2, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x7E003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsgt.vv" = [
+    // vmsgt.vv vd, vs1, vs2, vm (subformat of rv_v::vmslt.vv) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6C000057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsgt.vv vd, vs1, vs2 (subformat of rv_v::vmslt.vv) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6E000057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsgt.vx" = [
+    // vmsgt.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x7C004057), RV32 | RV64, [V, V, X, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsgt.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x7E004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsgtu.vi" = [
+    // vmsgtu.vi vd, vs2, simm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x78003057), RV32 | RV64, [V, V, Imm, Vm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsgtu.vi vd, vs2, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x7A003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsgtu.vv" = [
+    // vmsgtu.vv vd, vs1, vs2, vm (subformat of rv_v::vmsltu.vv) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x68000057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsgtu.vv vd, vs1, vs2 (subformat of rv_v::vmsltu.vv) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6A000057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsgtu.vx" = [
+    // vmsgtu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x78004057), RV32 | RV64, [V, V, X, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsgtu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x7A004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsif.m" = [
+    // vmsif.m vd, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x5001A057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsif.m vd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x5201A057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsle.vi" = [
+    // vmsle.vi vd, vs2, simm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x74003057), RV32 | RV64, [V, V, Imm, Vm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsle.vi vd, vs2, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x76003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsle.vv" = [
+    // vmsle.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x74000057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsle.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x76000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsle.vx" = [
+    // vmsle.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x74004057), RV32 | RV64, [V, V, X, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsle.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x76004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsleu.vi" = [
+    // vmsleu.vi vd, vs2, simm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x70003057), RV32 | RV64, [V, V, Imm, Vm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsleu.vi vd, vs2, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x72003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsleu.vv" = [
+    // vmsleu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x70000057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsleu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x72000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsleu.vx" = [
+    // vmsleu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x70004057), RV32 | RV64, [V, V, X, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsleu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x72004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmslt.vv" = [
+    // vmslt.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6C000057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmslt.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6E000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmslt.vx" = [
+    // vmslt.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6C004057), RV32 | RV64, [V, V, X, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmslt.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6E004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsltu.vv" = [
+    // vmsltu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x68000057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsltu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6A000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsltu.vx" = [
+    // vmsltu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x68004057), RV32 | RV64, [V, V, X, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsltu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6A004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsne.vi" = [
+    // vmsne.vi vd, vs2, simm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x64003057), RV32 | RV64, [V, V, Imm, Vm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsne.vi vd, vs2, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x66003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsne.vv" = [
+    // vmsne.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x64000057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsne.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x66000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsne.vx" = [
+    // vmsne.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x64004057), RV32 | RV64, [V, V, X, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsne.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x66004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmsof.m" = [
+    // vmsof.m vd, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x50012057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmsof.m vd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x52012057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmul.vv" = [
+    // vmul.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x94002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmul.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x96002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmul.vx" = [
+    // vmul.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x94006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmul.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x96006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmulh.vv" = [
+    // vmulh.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x9C002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmulh.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x9E002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmulh.vx" = [
+    // vmulh.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x9C006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmulh.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x9E006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmulhsu.vv" = [
+    // vmulhsu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x98002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmulhsu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x9A002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmulhsu.vx" = [
+    // vmulhsu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x98006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmulhsu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x9A006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmulhu.vv" = [
+    // vmulhu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x90002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmulhu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x92002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmulhu.vx" = [
+    // vmulhu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x90006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vmulhu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x92006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmv.s.x" = [
+    // vmv.s.x vd, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x42006057), RV32 | RV64, [V, X] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmv.v.i" = [
+    // vmv.v.i vd, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x5E003057), RV32 | RV64, [V, Imm] => [R(7), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmv.v.v" = [
+    // vmv.v.v vd, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x5E000057), RV32 | RV64, [V, V] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmv.v.x" = [
+    // vmv.v.x vd, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x5E004057), RV32 | RV64, [V, X] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmv.x.s" = [
+    // vmv.x.s rd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x42002057), RV32 | RV64, [X, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmv1r.v" = [
+    // vmv1r.v vd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x9E003057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmv2r.v" = [
+    // vmv2r.v vd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x9E00B057), RV32 | RV64, [V, V] => [Vgroup(7, 2), Vgroup(20, 2)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmv4r.v" = [
+    // vmv4r.v vd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x9E01B057), RV32 | RV64, [V, V] => [Vgroup(7, 4), Vgroup(20, 4)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmv8r.v" = [
+    // vmv8r.v vd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x9E03B057), RV32 | RV64, [V, V] => [Vgroup(7, 8), Vgroup(20, 8)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmxnor.mm" = [
+    // vmxnor.mm vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x7E002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vmxor.mm" = [
+    // vmxor.mm vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6E002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnclip.wi" = [
+    // vnclip.wi vd, vs2, uimm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xBC003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnclip.wi vd, vs2, uimm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xBE003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnclip.wv" = [
+    // vnclip.wv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xBC000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnclip.wv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xBE000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnclip.wx" = [
+    // vnclip.wx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xBC004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnclip.wx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xBE004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnclipu.wi" = [
+    // vnclipu.wi vd, vs2, uimm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB8003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnclipu.wi vd, vs2, uimm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xBA003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnclipu.wv" = [
+    // vnclipu.wv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB8000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnclipu.wv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xBA000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnclipu.wx" = [
+    // vnclipu.wx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB8004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnclipu.wx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xBA004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vncvt.x.x.w" = [
+    // vncvt.x.x.w vd, vs2, vm (subformat of rv_v::vnsrl.wx) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB0004057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vncvt.x.x.w vd, vs2 (subformat of rv_v::vnsrl.wx) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB2004057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vneg.v" = [
+    // vneg.v vd, vs2, vm (subformat of rv_v::vrsub.vx) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0C004057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vneg.v vd, vs2 (subformat of rv_v::vrsub.vx) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0E004057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnmsac.vv" = [
+    // vnmsac.vv vd, vs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xBC002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnmsac.vv vd, vs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xBE002057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnmsac.vx" = [
+    // vnmsac.vx vd, rs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xBC006057), RV32 | RV64, [V, X, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnmsac.vx vd, rs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xBE006057), RV32 | RV64, [V, X, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnmsub.vv" = [
+    // vnmsub.vv vd, vs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAC002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnmsub.vv vd, vs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAE002057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnmsub.vx" = [
+    // vnmsub.vx vd, rs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAC006057), RV32 | RV64, [V, X, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnmsub.vx vd, rs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAE006057), RV32 | RV64, [V, X, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnot.v" = [
+    // vnot.v vd, vs2, vm (subformat of rv_v::vxor.vi) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2C0FB057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnot.v vd, vs2 (subformat of rv_v::vxor.vi) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2E0FB057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnsra.wi" = [
+    // vnsra.wi vd, vs2, uimm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB4003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnsra.wi vd, vs2, uimm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB6003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnsra.wv" = [
+    // vnsra.wv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB4000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnsra.wv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB6000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnsra.wx" = [
+    // vnsra.wx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB4004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnsra.wx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB6004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnsrl.wi" = [
+    // vnsrl.wi vd, vs2, uimm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB0003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnsrl.wi vd, vs2, uimm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB2003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnsrl.wv" = [
+    // vnsrl.wv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB0000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnsrl.wv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB2000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vnsrl.wx" = [
+    // vnsrl.wx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB0004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vnsrl.wx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xB2004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vor.vi" = [
+    // vor.vi vd, vs2, simm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x28003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vor.vi vd, vs2, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2A003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vor.vv" = [
+    // vor.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x28000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vor.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2A000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vor.vx" = [
+    // vor.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x28004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vor.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2A004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vredand.vs" = [
+    // vredand.vs vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x04002057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vredand.vs vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x06002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vredmax.vs" = [
+    // vredmax.vs vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x1C002057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vredmax.vs vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x1E002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vredmaxu.vs" = [
+    // vredmaxu.vs vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x18002057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vredmaxu.vs vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x1A002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vredmin.vs" = [
+    // vredmin.vs vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x14002057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vredmin.vs vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x16002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vredminu.vs" = [
+    // vredminu.vs vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x10002057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vredminu.vs vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x12002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vredor.vs" = [
+    // vredor.vs vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x08002057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vredor.vs vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0A002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vredsum.vs" = [
+    // vredsum.vs vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x00002057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vredsum.vs vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vredxor.vs" = [
+    // vredxor.vs vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0C002057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vredxor.vs vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0E002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vrem.vv" = [
+    // vrem.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8C002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vrem.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8E002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vrem.vx" = [
+    // vrem.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8C006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vrem.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8E006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vremu.vv" = [
+    // vremu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x88002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vremu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8A002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vremu.vx" = [
+    // vremu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x88006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vremu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8A006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vrgather.vi" = [
+    // vrgather.vi vd, vs2, uimm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x30003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vrgather.vi vd, vs2, uimm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x32003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vrgather.vv" = [
+    // vrgather.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x30000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vrgather.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x32000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vrgather.vx" = [
+    // vrgather.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x30004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vrgather.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x32004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vrgatherei16.vv" = [
+    // vrgatherei16.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x38000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vrgatherei16.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x3A000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vrsub.vi" = [
+    // vrsub.vi vd, vs2, simm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0C003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vrsub.vi vd, vs2, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0E003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vrsub.vx" = [
+    // vrsub.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0C004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vrsub.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0E004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vs1r.v" = [
+    // vs1r.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02800027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vs2r.v" = [
+    // vs2r.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x22800027), RV32 | RV64, [V, Ref] => [Vgroup(7, 2), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vs4r.v" = [
+    // vs4r.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62800027), RV32 | RV64, [V, Ref] => [Vgroup(7, 4), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vs8r.v" = [
+    // vs8r.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE2800027), RV32 | RV64, [V, Ref] => [Vgroup(7, 8), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsadd.vi" = [
+    // vsadd.vi vd, vs2, simm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x84003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsadd.vi vd, vs2, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x86003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsadd.vv" = [
+    // vsadd.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x84000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsadd.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x86000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsadd.vx" = [
+    // vsadd.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x84004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsadd.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x86004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsaddu.vi" = [
+    // vsaddu.vi vd, vs2, simm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x80003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsaddu.vi vd, vs2, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x82003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsaddu.vv" = [
+    // vsaddu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x80000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsaddu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x82000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsaddu.vx" = [
+    // vsaddu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x80004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsaddu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x82004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsbc.vvm" = [
+    // vsbc.vvm vd, vs2, vs1, v0 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x48000057), RV32 | RV64, [V, V, V, Reg(RegId::V0)] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsbc.vxm" = [
+    // vsbc.vxm vd, vs2, rs1, v0 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x48004057), RV32 | RV64, [V, V, X, Reg(RegId::V0)] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vse16.v" = [
+    // vse16.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x00005027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vse16.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02005027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vse32.v" = [
+    // vse32.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x00006027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vse32.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02006027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vse8.v" = [
+    // vse8.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x00000027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vse8.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02000027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsetivli" = [
+    // vsetivli rd, uimm5, sew, lmul, ta, ma (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC0007057), RV32 | RV64, [X, Imm, Ident, Ident, Ident, Ident] => [R(7), UImm(5, 0), BitRange(15, 5, 0), Next, ElementWidth(23), GroupMultiplier(20), TailPolicy(26), MaskPolicy(27)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsetivli rd, uimm5, zimm10 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC0007057), RV32 | RV64, [X, Imm, Imm] => [R(7), UImm(5, 0), BitRange(15, 5, 0), Next, UImm(10, 0), BitRange(20, 10, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsetvl" = [
+    // vsetvl rd, rs1, rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x80007057), RV32 | RV64, [X, X, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsetvli" = [
+    // vsetvli rd, rs1, sew, lmul, ta, ma (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x00007057), RV32 | RV64, [X, X, Ident, Ident, Ident, Ident] => [R(7), R(15), ElementWidth(23), GroupMultiplier(20), TailPolicy(26), MaskPolicy(27)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsetvli rd, rs1, zimm11 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x00007057), RV32 | RV64, [X, X, Imm] => [R(7), R(15), UImm(11, 0), BitRange(20, 11, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsext.vf2" = [
+    // vsext.vf2 vd, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4803A057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsext.vf2 vd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4A03A057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsext.vf4" = [
+    // vsext.vf4 vd, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4802A057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsext.vf4 vd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4A02A057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsext.vf8" = [
+    // vsext.vf8 vd, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4801A057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsext.vf8 vd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4A01A057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vslide1down.vx" = [
+    // vslide1down.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x3C006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vslide1down.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x3E006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vslide1up.vx" = [
+    // vslide1up.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x38006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vslide1up.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x3A006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vslidedown.vi" = [
+    // vslidedown.vi vd, vs2, uimm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x3C003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vslidedown.vi vd, vs2, uimm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x3E003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vslidedown.vx" = [
+    // vslidedown.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x3C004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vslidedown.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x3E004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vslideup.vi" = [
+    // vslideup.vi vd, vs2, uimm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x38003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vslideup.vi vd, vs2, uimm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x3A003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vslideup.vx" = [
+    // vslideup.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x38004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vslideup.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x3A004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsll.vi" = [
+    // vsll.vi vd, vs2, uimm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x94003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsll.vi vd, vs2, uimm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x96003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsll.vv" = [
+    // vsll.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x94000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsll.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x96000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsll.vx" = [
+    // vsll.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x94004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsll.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x96004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsm.v" = [
+    // vsm.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x02B00027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsmul.vv" = [
+    // vsmul.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x9C000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsmul.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x9E000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsmul.vx" = [
+    // vsmul.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x9C004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsmul.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x9E004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxei16.v" = [
+    // vsoxei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0C005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0E005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxei32.v" = [
+    // vsoxei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0C006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0E006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxei8.v" = [
+    // vsoxei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0C000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0E000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg2ei16.v" = [
+    // vsoxseg2ei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2C005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg2ei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2E005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg2ei32.v" = [
+    // vsoxseg2ei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2C006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg2ei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2E006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg2ei8.v" = [
+    // vsoxseg2ei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2C000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg2ei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2E000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg3ei16.v" = [
+    // vsoxseg3ei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4C005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg3ei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4E005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg3ei32.v" = [
+    // vsoxseg3ei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4C006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg3ei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4E006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg3ei8.v" = [
+    // vsoxseg3ei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4C000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg3ei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4E000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg4ei16.v" = [
+    // vsoxseg4ei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6C005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg4ei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6E005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg4ei32.v" = [
+    // vsoxseg4ei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6C006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg4ei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6E006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg4ei8.v" = [
+    // vsoxseg4ei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6C000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg4ei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6E000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg5ei16.v" = [
+    // vsoxseg5ei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8C005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg5ei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8E005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg5ei32.v" = [
+    // vsoxseg5ei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8C006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg5ei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8E006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg5ei8.v" = [
+    // vsoxseg5ei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8C000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg5ei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8E000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg6ei16.v" = [
+    // vsoxseg6ei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAC005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg6ei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAE005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg6ei32.v" = [
+    // vsoxseg6ei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAC006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg6ei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAE006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg6ei8.v" = [
+    // vsoxseg6ei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAC000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg6ei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAE000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg7ei16.v" = [
+    // vsoxseg7ei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCC005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg7ei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCE005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg7ei32.v" = [
+    // vsoxseg7ei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCC006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg7ei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCE006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg7ei8.v" = [
+    // vsoxseg7ei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCC000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg7ei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCE000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg8ei16.v" = [
+    // vsoxseg8ei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEC005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg8ei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEE005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg8ei32.v" = [
+    // vsoxseg8ei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEC006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg8ei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEE006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg8ei8.v" = [
+    // vsoxseg8ei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEC000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg8ei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEE000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsra.vi" = [
+    // vsra.vi vd, vs2, uimm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA4003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsra.vi vd, vs2, uimm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA6003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsra.vv" = [
+    // vsra.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA4000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsra.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA6000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsra.vx" = [
+    // vsra.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA4004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsra.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA6004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsrl.vi" = [
+    // vsrl.vi vd, vs2, uimm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA0003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsrl.vi vd, vs2, uimm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA2003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsrl.vv" = [
+    // vsrl.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA0000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsrl.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA2000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsrl.vx" = [
+    // vsrl.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA0004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsrl.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA2004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsse16.v" = [
+    // vsse16.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x08005027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsse16.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0A005027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsse32.v" = [
+    // vsse32.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x08006027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsse32.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0A006027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsse8.v" = [
+    // vsse8.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x08000027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsse8.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0A000027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg2e16.v" = [
+    // vsseg2e16.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x20005027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg2e16.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x22005027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg2e32.v" = [
+    // vsseg2e32.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x20006027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg2e32.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x22006027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg2e8.v" = [
+    // vsseg2e8.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x20000027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg2e8.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x22000027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg3e16.v" = [
+    // vsseg3e16.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x40005027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg3e16.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x42005027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg3e32.v" = [
+    // vsseg3e32.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x40006027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg3e32.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x42006027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg3e8.v" = [
+    // vsseg3e8.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x40000027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg3e8.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x42000027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg4e16.v" = [
+    // vsseg4e16.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x60005027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg4e16.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62005027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg4e32.v" = [
+    // vsseg4e32.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x60006027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg4e32.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62006027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg4e8.v" = [
+    // vsseg4e8.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x60000027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg4e8.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x62000027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg5e16.v" = [
+    // vsseg5e16.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x80005027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg5e16.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x82005027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg5e32.v" = [
+    // vsseg5e32.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x80006027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg5e32.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x82006027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg5e8.v" = [
+    // vsseg5e8.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x80000027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg5e8.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x82000027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg6e16.v" = [
+    // vsseg6e16.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA0005027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg6e16.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA2005027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg6e32.v" = [
+    // vsseg6e32.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA0006027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg6e32.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA2006027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg6e8.v" = [
+    // vsseg6e8.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA0000027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg6e8.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA2000027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg7e16.v" = [
+    // vsseg7e16.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC0005027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg7e16.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC2005027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg7e32.v" = [
+    // vsseg7e32.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC0006027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg7e32.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC2006027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg7e8.v" = [
+    // vsseg7e8.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC0000027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg7e8.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC2000027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg8e16.v" = [
+    // vsseg8e16.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE0005027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg8e16.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE2005027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg8e32.v" = [
+    // vsseg8e32.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE0006027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg8e32.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE2006027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg8e8.v" = [
+    // vsseg8e8.v vs3, (rs1), vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE0000027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg8e8.v vs3, (rs1) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE2000027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssra.vi" = [
+    // vssra.vi vd, vs2, uimm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAC003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssra.vi vd, vs2, uimm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAE003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssra.vv" = [
+    // vssra.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAC000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssra.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAE000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssra.vx" = [
+    // vssra.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAC004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssra.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAE004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssrl.vi" = [
+    // vssrl.vi vd, vs2, uimm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA8003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssrl.vi vd, vs2, uimm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAA003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssrl.vv" = [
+    // vssrl.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA8000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssrl.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAA000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssrl.vx" = [
+    // vssrl.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA8004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssrl.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAA004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg2e16.v" = [
+    // vssseg2e16.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x28005027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg2e16.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2A005027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg2e32.v" = [
+    // vssseg2e32.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x28006027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg2e32.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2A006027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg2e8.v" = [
+    // vssseg2e8.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x28000027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg2e8.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2A000027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg3e16.v" = [
+    // vssseg3e16.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x48005027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg3e16.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4A005027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg3e32.v" = [
+    // vssseg3e32.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x48006027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg3e32.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4A006027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg3e8.v" = [
+    // vssseg3e8.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x48000027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg3e8.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4A000027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg4e16.v" = [
+    // vssseg4e16.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x68005027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg4e16.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6A005027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg4e32.v" = [
+    // vssseg4e32.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x68006027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg4e32.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6A006027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg4e8.v" = [
+    // vssseg4e8.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x68000027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg4e8.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x6A000027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg5e16.v" = [
+    // vssseg5e16.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x88005027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg5e16.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8A005027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg5e32.v" = [
+    // vssseg5e32.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x88006027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg5e32.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8A006027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg5e8.v" = [
+    // vssseg5e8.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x88000027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg5e8.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8A000027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg6e16.v" = [
+    // vssseg6e16.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA8005027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg6e16.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAA005027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg6e32.v" = [
+    // vssseg6e32.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA8006027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg6e32.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAA006027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg6e8.v" = [
+    // vssseg6e8.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA8000027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg6e8.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xAA000027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg7e16.v" = [
+    // vssseg7e16.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC8005027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg7e16.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCA005027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg7e32.v" = [
+    // vssseg7e32.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC8006027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg7e32.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCA006027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg7e8.v" = [
+    // vssseg7e8.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC8000027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg7e8.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCA000027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg8e16.v" = [
+    // vssseg8e16.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE8005027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg8e16.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEA005027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg8e32.v" = [
+    // vssseg8e32.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE8006027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg8e32.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEA006027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg8e8.v" = [
+    // vssseg8e8.v vs3, (rs1), rs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE8000027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg8e8.v vs3, (rs1), rs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEA000027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssub.vv" = [
+    // vssub.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8C000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssub.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8E000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssub.vx" = [
+    // vssub.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8C004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssub.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8E004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssubu.vv" = [
+    // vssubu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x88000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssubu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8A000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssubu.vx" = [
+    // vssubu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x88004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssubu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x8A004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsub.vv" = [
+    // vsub.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x08000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsub.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0A000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsub.vx" = [
+    // vsub.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x08004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsub.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x0A004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxei16.v" = [
+    // vsuxei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x04005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x06005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxei32.v" = [
+    // vsuxei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x04006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x06006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxei8.v" = [
+    // vsuxei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x04000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x06000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg2ei16.v" = [
+    // vsuxseg2ei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x24005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg2ei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x26005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg2ei32.v" = [
+    // vsuxseg2ei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x24006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg2ei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x26006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg2ei8.v" = [
+    // vsuxseg2ei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x24000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg2ei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x26000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg3ei16.v" = [
+    // vsuxseg3ei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x44005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg3ei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x46005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg3ei32.v" = [
+    // vsuxseg3ei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x44006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg3ei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x46006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg3ei8.v" = [
+    // vsuxseg3ei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x44000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg3ei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x46000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg4ei16.v" = [
+    // vsuxseg4ei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x64005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg4ei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x66005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg4ei32.v" = [
+    // vsuxseg4ei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x64006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg4ei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x66006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg4ei8.v" = [
+    // vsuxseg4ei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x64000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg4ei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x66000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg5ei16.v" = [
+    // vsuxseg5ei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x84005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg5ei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x86005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg5ei32.v" = [
+    // vsuxseg5ei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x84006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg5ei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x86006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg5ei8.v" = [
+    // vsuxseg5ei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x84000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg5ei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x86000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg6ei16.v" = [
+    // vsuxseg6ei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA4005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg6ei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA6005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg6ei32.v" = [
+    // vsuxseg6ei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA4006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg6ei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA6006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg6ei8.v" = [
+    // vsuxseg6ei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA4000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg6ei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xA6000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg7ei16.v" = [
+    // vsuxseg7ei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC4005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg7ei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC6005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg7ei32.v" = [
+    // vsuxseg7ei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC4006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg7ei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC6006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg7ei8.v" = [
+    // vsuxseg7ei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC4000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg7ei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC6000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg8ei16.v" = [
+    // vsuxseg8ei16.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE4005027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg8ei16.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE6005027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg8ei32.v" = [
+    // vsuxseg8ei32.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE4006027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg8ei32.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE6006027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg8ei8.v" = [
+    // vsuxseg8ei8.v vs3, (rs1), vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE4000027), RV32 | RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg8ei8.v vs3, (rs1), vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE6000027), RV32 | RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwadd.vv" = [
+    // vwadd.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC4002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwadd.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC6002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwadd.vx" = [
+    // vwadd.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC4006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwadd.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC6006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwadd.wv" = [
+    // vwadd.wv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xD4002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwadd.wv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xD6002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwadd.wx" = [
+    // vwadd.wx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xD4006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwadd.wx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xD6006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwaddu.vv" = [
+    // vwaddu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC0002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwaddu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC2002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwaddu.vx" = [
+    // vwaddu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC0006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwaddu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC2006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwaddu.wv" = [
+    // vwaddu.wv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xD0002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwaddu.wv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xD2002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwaddu.wx" = [
+    // vwaddu.wx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xD0006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwaddu.wx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xD2006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwcvt.x.x.v" = [
+    // vwcvt.x.x.v vd, vs2, vm (subformat of rv_v::vwadd.vx) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC4006057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwcvt.x.x.v vd, vs2 (subformat of rv_v::vwadd.vx) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC6006057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwcvtu.x.x.v" = [
+    // vwcvtu.x.x.v vd, vs2, vm (subformat of rv_v::vwaddu.vx) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC0006057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwcvtu.x.x.v vd, vs2 (subformat of rv_v::vwaddu.vx) (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC2006057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwmacc.vv" = [
+    // vwmacc.vv vd, vs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xF4002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwmacc.vv vd, vs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xF6002057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwmacc.vx" = [
+    // vwmacc.vx vd, rs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xF4006057), RV32 | RV64, [V, X, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwmacc.vx vd, rs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xF6006057), RV32 | RV64, [V, X, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwmaccsu.vv" = [
+    // vwmaccsu.vv vd, vs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xFC002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwmaccsu.vv vd, vs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xFE002057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwmaccsu.vx" = [
+    // vwmaccsu.vx vd, rs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xFC006057), RV32 | RV64, [V, X, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwmaccsu.vx vd, rs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xFE006057), RV32 | RV64, [V, X, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwmaccu.vv" = [
+    // vwmaccu.vv vd, vs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xF0002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwmaccu.vv vd, vs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xF2002057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwmaccu.vx" = [
+    // vwmaccu.vx vd, rs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xF0006057), RV32 | RV64, [V, X, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwmaccu.vx vd, rs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xF2006057), RV32 | RV64, [V, X, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwmaccus.vx" = [
+    // vwmaccus.vx vd, rs1, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xF8006057), RV32 | RV64, [V, X, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwmaccus.vx vd, rs1, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xFA006057), RV32 | RV64, [V, X, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwmul.vv" = [
+    // vwmul.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEC002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwmul.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEE002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwmul.vx" = [
+    // vwmul.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEC006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwmul.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEE006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwmulsu.vv" = [
+    // vwmulsu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE8002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwmulsu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEA002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwmulsu.vx" = [
+    // vwmulsu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE8006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwmulsu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xEA006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwmulu.vv" = [
+    // vwmulu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE0002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwmulu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE2002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwmulu.vx" = [
+    // vwmulu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE0006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwmulu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xE2006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwredsum.vs" = [
+    // vwredsum.vs vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC4000057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwredsum.vs vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC6000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwredsumu.vs" = [
+    // vwredsumu.vs vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC0000057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwredsumu.vs vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC2000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwsub.vv" = [
+    // vwsub.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCC002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwsub.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCE002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwsub.vx" = [
+    // vwsub.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCC006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwsub.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCE006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwsub.wv" = [
+    // vwsub.wv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xDC002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwsub.wv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xDE002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwsub.wx" = [
+    // vwsub.wx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xDC006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwsub.wx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xDE006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwsubu.vv" = [
+    // vwsubu.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC8002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwsubu.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCA002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwsubu.vx" = [
+    // vwsubu.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xC8006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwsubu.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xCA006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwsubu.wv" = [
+    // vwsubu.wv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xD8002057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwsubu.wv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xDA002057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vwsubu.wx" = [
+    // vwsubu.wx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xD8006057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vwsubu.wx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0xDA006057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vxor.vi" = [
+    // vxor.vi vd, vs2, simm5, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2C003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vxor.vi vd, vs2, simm5 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2E003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), SImm(5, 0), BitRange(15, 5, 0), Next], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vxor.vv" = [
+    // vxor.vv vd, vs2, vs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2C000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vxor.vv vd, vs2, vs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2E000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vxor.vx" = [
+    // vxor.vx vd, vs2, rs1, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2C004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vxor.vx vd, vs2, rs1 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x2E004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vzext.vf2" = [
+    // vzext.vf2 vd, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x48032057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vzext.vf2 vd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4A032057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vzext.vf4" = [
+    // vzext.vf4 vd, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x48022057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vzext.vf4 vd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4A022057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vzext.vf8" = [
+    // vzext.vf8 vd, vs2, vm (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x48012057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vzext.vf8 vd, vs2 (v, zve32f, zve32x, zve64d, zve64f, zve64x)
+    Single(0x4A012057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve32x, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+
+// Extension(s) v, zve32f, zve64d, zve64f
+
+"vfabs.v" = [
+    // vfabs.v vd, vs2=vs1, vm (subformat of rv_v::vfsgnjx.vv) (v, zve32f, zve64d, zve64f)
+    Single(0x28001057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20), Repeat, R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfabs.v vd, vs2=vs1 (subformat of rv_v::vfsgnjx.vv) (v, zve32f, zve64d, zve64f)
+    Single(0x2A001057), RV32 | RV64, [V, V] => [R(7), R(20), Repeat, R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfadd.vf" = [
+    // vfadd.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x00005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfadd.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x02005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfadd.vv" = [
+    // vfadd.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x00001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfadd.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x02001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfclass.v" = [
+    // vfclass.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x4C081057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfclass.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4E081057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfcvt.f.x.v" = [
+    // vfcvt.f.x.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48019057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfcvt.f.x.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A019057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfcvt.f.xu.v" = [
+    // vfcvt.f.xu.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48011057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfcvt.f.xu.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A011057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfcvt.rtz.x.f.v" = [
+    // vfcvt.rtz.x.f.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48039057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfcvt.rtz.x.f.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A039057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfcvt.rtz.xu.f.v" = [
+    // vfcvt.rtz.xu.f.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48031057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfcvt.rtz.xu.f.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A031057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfcvt.x.f.v" = [
+    // vfcvt.x.f.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48009057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfcvt.x.f.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A009057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfcvt.xu.f.v" = [
+    // vfcvt.xu.f.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48001057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfcvt.xu.f.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A001057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfdiv.vf" = [
+    // vfdiv.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x80005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfdiv.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x82005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfdiv.vv" = [
+    // vfdiv.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x80001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfdiv.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x82001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmacc.vf" = [
+    // vfmacc.vf vd, rs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xB0005057), RV32 | RV64, [V, F, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfmacc.vf vd, rs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xB2005057), RV32 | RV64, [V, F, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmacc.vv" = [
+    // vfmacc.vv vd, vs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xB0001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfmacc.vv vd, vs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xB2001057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmadd.vf" = [
+    // vfmadd.vf vd, rs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xA0005057), RV32 | RV64, [V, F, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfmadd.vf vd, rs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xA2005057), RV32 | RV64, [V, F, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmadd.vv" = [
+    // vfmadd.vv vd, vs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xA0001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfmadd.vv vd, vs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xA2001057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmax.vf" = [
+    // vfmax.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x18005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfmax.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x1A005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmax.vv" = [
+    // vfmax.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x18001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfmax.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x1A001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmerge.vfm" = [
+    // vfmerge.vfm vd, vs2, rs1, v0 (v, zve32f, zve64d, zve64f)
+    Single(0x5C005057), RV32 | RV64, [V, V, F, Reg(RegId::V0)] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmin.vf" = [
+    // vfmin.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x10005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfmin.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x12005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmin.vv" = [
+    // vfmin.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x10001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfmin.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x12001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmsac.vf" = [
+    // vfmsac.vf vd, rs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xB8005057), RV32 | RV64, [V, F, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfmsac.vf vd, rs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xBA005057), RV32 | RV64, [V, F, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmsac.vv" = [
+    // vfmsac.vv vd, vs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xB8001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfmsac.vv vd, vs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xBA001057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmsub.vf" = [
+    // vfmsub.vf vd, rs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xA8005057), RV32 | RV64, [V, F, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfmsub.vf vd, rs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xAA005057), RV32 | RV64, [V, F, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmsub.vv" = [
+    // vfmsub.vv vd, vs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xA8001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfmsub.vv vd, vs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xAA001057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmul.vf" = [
+    // vfmul.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x90005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfmul.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x92005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmul.vv" = [
+    // vfmul.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x90001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfmul.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x92001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmv.f.s" = [
+    // vfmv.f.s rd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x42001057), RV32 | RV64, [F, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmv.s.f" = [
+    // vfmv.s.f vd, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x42005057), RV32 | RV64, [V, F] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfmv.v.f" = [
+    // vfmv.v.f vd, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x5E005057), RV32 | RV64, [V, F] => [R(7), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfncvt.f.f.w" = [
+    // vfncvt.f.f.w vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x480A1057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfncvt.f.f.w vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A0A1057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfncvt.f.x.w" = [
+    // vfncvt.f.x.w vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48099057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfncvt.f.x.w vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A099057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfncvt.f.xu.w" = [
+    // vfncvt.f.xu.w vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48091057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfncvt.f.xu.w vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A091057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfncvt.rod.f.f.w" = [
+    // vfncvt.rod.f.f.w vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x480A9057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfncvt.rod.f.f.w vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A0A9057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfncvt.rtz.x.f.w" = [
+    // vfncvt.rtz.x.f.w vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x480B9057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfncvt.rtz.x.f.w vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A0B9057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfncvt.rtz.xu.f.w" = [
+    // vfncvt.rtz.xu.f.w vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x480B1057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfncvt.rtz.xu.f.w vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A0B1057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfncvt.x.f.w" = [
+    // vfncvt.x.f.w vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48089057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfncvt.x.f.w vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A089057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfncvt.xu.f.w" = [
+    // vfncvt.xu.f.w vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48081057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfncvt.xu.f.w vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A081057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfneg.v" = [
+    // vfneg.v vd, vs2=vs1, vm (subformat of rv_v::vfsgnjn.vv) (v, zve32f, zve64d, zve64f)
+    Single(0x24001057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20), Repeat, R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfneg.v vd, vs2=vs1 (subformat of rv_v::vfsgnjn.vv) (v, zve32f, zve64d, zve64f)
+    Single(0x26001057), RV32 | RV64, [V, V] => [R(7), R(20), Repeat, R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfnmacc.vf" = [
+    // vfnmacc.vf vd, rs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xB4005057), RV32 | RV64, [V, F, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfnmacc.vf vd, rs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xB6005057), RV32 | RV64, [V, F, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfnmacc.vv" = [
+    // vfnmacc.vv vd, vs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xB4001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfnmacc.vv vd, vs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xB6001057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfnmadd.vf" = [
+    // vfnmadd.vf vd, rs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xA4005057), RV32 | RV64, [V, F, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfnmadd.vf vd, rs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xA6005057), RV32 | RV64, [V, F, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfnmadd.vv" = [
+    // vfnmadd.vv vd, vs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xA4001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfnmadd.vv vd, vs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xA6001057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfnmsac.vf" = [
+    // vfnmsac.vf vd, rs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xBC005057), RV32 | RV64, [V, F, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfnmsac.vf vd, rs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xBE005057), RV32 | RV64, [V, F, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfnmsac.vv" = [
+    // vfnmsac.vv vd, vs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xBC001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfnmsac.vv vd, vs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xBE001057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfnmsub.vf" = [
+    // vfnmsub.vf vd, rs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xAC005057), RV32 | RV64, [V, F, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfnmsub.vf vd, rs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xAE005057), RV32 | RV64, [V, F, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfnmsub.vv" = [
+    // vfnmsub.vv vd, vs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xAC001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfnmsub.vv vd, vs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xAE001057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfrdiv.vf" = [
+    // vfrdiv.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x84005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfrdiv.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x86005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfrec7.v" = [
+    // vfrec7.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x4C029057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfrec7.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4E029057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfredmax.vs" = [
+    // vfredmax.vs vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x1C001057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfredmax.vs vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x1E001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfredmin.vs" = [
+    // vfredmin.vs vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x14001057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfredmin.vs vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x16001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfredosum.vs" = [
+    // vfredosum.vs vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x0C001057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfredosum.vs vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x0E001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfredusum.vs" = [
+    // vfredusum.vs vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x04001057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfredusum.vs vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x06001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfrsqrt7.v" = [
+    // vfrsqrt7.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x4C021057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfrsqrt7.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4E021057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfrsub.vf" = [
+    // vfrsub.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x9C005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfrsub.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x9E005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfsgnj.vf" = [
+    // vfsgnj.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x20005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfsgnj.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x22005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfsgnj.vv" = [
+    // vfsgnj.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x20001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfsgnj.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x22001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfsgnjn.vf" = [
+    // vfsgnjn.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x24005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfsgnjn.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x26005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfsgnjn.vv" = [
+    // vfsgnjn.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x24001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfsgnjn.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x26001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfsgnjx.vf" = [
+    // vfsgnjx.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x28005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfsgnjx.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x2A005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfsgnjx.vv" = [
+    // vfsgnjx.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x28001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfsgnjx.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x2A001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfslide1down.vf" = [
+    // vfslide1down.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x3C005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfslide1down.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x3E005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfslide1up.vf" = [
+    // vfslide1up.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x38005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfslide1up.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x3A005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfsqrt.v" = [
+    // vfsqrt.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x4C001057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfsqrt.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4E001057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfsub.vf" = [
+    // vfsub.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x08005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfsub.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x0A005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfsub.vv" = [
+    // vfsub.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x08001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfsub.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x0A001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwadd.vf" = [
+    // vfwadd.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0xC0005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwadd.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0xC2005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwadd.vv" = [
+    // vfwadd.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0xC0001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwadd.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0xC2001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwadd.wf" = [
+    // vfwadd.wf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0xD0005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwadd.wf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0xD2005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwadd.wv" = [
+    // vfwadd.wv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0xD0001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwadd.wv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0xD2001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwcvt.f.f.v" = [
+    // vfwcvt.f.f.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48061057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwcvt.f.f.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A061057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwcvt.f.x.v" = [
+    // vfwcvt.f.x.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48059057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwcvt.f.x.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A059057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwcvt.f.xu.v" = [
+    // vfwcvt.f.xu.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48051057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwcvt.f.xu.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A051057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwcvt.rtz.x.f.v" = [
+    // vfwcvt.rtz.x.f.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48079057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwcvt.rtz.x.f.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A079057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwcvt.rtz.xu.f.v" = [
+    // vfwcvt.rtz.xu.f.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48071057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwcvt.rtz.xu.f.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A071057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwcvt.x.f.v" = [
+    // vfwcvt.x.f.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48049057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwcvt.x.f.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A049057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwcvt.xu.f.v" = [
+    // vfwcvt.xu.f.v vd, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0x48041057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwcvt.xu.f.v vd, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0x4A041057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwmacc.vf" = [
+    // vfwmacc.vf vd, rs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xF0005057), RV32 | RV64, [V, F, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwmacc.vf vd, rs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xF2005057), RV32 | RV64, [V, F, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwmacc.vv" = [
+    // vfwmacc.vv vd, vs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xF0001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwmacc.vv vd, vs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xF2001057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwmsac.vf" = [
+    // vfwmsac.vf vd, rs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xF8005057), RV32 | RV64, [V, F, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwmsac.vf vd, rs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xFA005057), RV32 | RV64, [V, F, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwmsac.vv" = [
+    // vfwmsac.vv vd, vs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xF8001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwmsac.vv vd, vs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xFA001057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwmul.vf" = [
+    // vfwmul.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0xE0005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwmul.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0xE2005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwmul.vv" = [
+    // vfwmul.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0xE0001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwmul.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0xE2001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwnmacc.vf" = [
+    // vfwnmacc.vf vd, rs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xF4005057), RV32 | RV64, [V, F, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwnmacc.vf vd, rs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xF6005057), RV32 | RV64, [V, F, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwnmacc.vv" = [
+    // vfwnmacc.vv vd, vs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xF4001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwnmacc.vv vd, vs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xF6001057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwnmsac.vf" = [
+    // vfwnmsac.vf vd, rs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xFC005057), RV32 | RV64, [V, F, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwnmsac.vf vd, rs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xFE005057), RV32 | RV64, [V, F, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwnmsac.vv" = [
+    // vfwnmsac.vv vd, vs1, vs2, vm (v, zve32f, zve64d, zve64f)
+    Single(0xFC001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwnmsac.vv vd, vs1, vs2 (v, zve32f, zve64d, zve64f)
+    Single(0xFE001057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwredosum.vs" = [
+    // vfwredosum.vs vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0xCC001057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwredosum.vs vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0xCE001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwredusum.vs" = [
+    // vfwredusum.vs vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0xC4001057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwredusum.vs vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0xC6001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwsub.vf" = [
+    // vfwsub.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0xC8005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwsub.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0xCA005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwsub.vv" = [
+    // vfwsub.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0xC8001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwsub.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0xCA001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwsub.wf" = [
+    // vfwsub.wf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0xD8005057), RV32 | RV64, [V, V, F, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwsub.wf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0xDA005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vfwsub.wv" = [
+    // vfwsub.wv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0xD8001057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vfwsub.wv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0xDA001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vmfeq.vf" = [
+    // vmfeq.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x60005057), RV32 | RV64, [V, V, F, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vmfeq.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x62005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vmfeq.vv" = [
+    // vmfeq.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x60001057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vmfeq.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x62001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vmfge.vf" = [
+    // vmfge.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x7C005057), RV32 | RV64, [V, V, F, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vmfge.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x7E005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vmfge.vv" = [
+    // vmfge.vv vd, vs1, vs2, vm (subformat of rv_v::vmfle.vv) (v, zve32f, zve64d, zve64f)
+    Single(0x64001057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vmfge.vv vd, vs1, vs2 (subformat of rv_v::vmfle.vv) (v, zve32f, zve64d, zve64f)
+    Single(0x66001057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vmfgt.vf" = [
+    // vmfgt.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x74005057), RV32 | RV64, [V, V, F, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vmfgt.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x76005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vmfgt.vv" = [
+    // vmfgt.vv vd, vs1, vs2, vm (subformat of rv_v::vmflt.vv) (v, zve32f, zve64d, zve64f)
+    Single(0x6C001057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vmfgt.vv vd, vs1, vs2 (subformat of rv_v::vmflt.vv) (v, zve32f, zve64d, zve64f)
+    Single(0x6E001057), RV32 | RV64, [V, V, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vmfle.vf" = [
+    // vmfle.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x64005057), RV32 | RV64, [V, V, F, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vmfle.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x66005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vmfle.vv" = [
+    // vmfle.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x64001057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vmfle.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x66001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vmflt.vf" = [
+    // vmflt.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x6C005057), RV32 | RV64, [V, V, F, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vmflt.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x6E005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vmflt.vv" = [
+    // vmflt.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x6C001057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vmflt.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x6E001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vmfne.vf" = [
+    // vmfne.vf vd, vs2, rs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x70005057), RV32 | RV64, [V, V, F, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vmfne.vf vd, vs2, rs1 (v, zve32f, zve64d, zve64f)
+    Single(0x72005057), RV32 | RV64, [V, V, F] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+"vmfne.vv" = [
+    // vmfne.vv vd, vs2, vs1, vm (v, zve32f, zve64d, zve64f)
+    Single(0x70001057), RV32 | RV64, [V, V, V, Vm] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+    // vmfne.vv vd, vs2, vs1 (v, zve32f, zve64d, zve64f)
+    Single(0x72001057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_V, Ex_Zve32f, Ex_Zve64d, Ex_Zve64f];
+],
+
+// Extension(s) v, zve64d, zve64f, zve64x
+
+"vl1re64.v" = [
+    // vl1re64.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x02807007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl2re64.v" = [
+    // vl2re64.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x22807007), RV32 | RV64, [V, Ref] => [Vgroup(7, 2), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl4re64.v" = [
+    // vl4re64.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x62807007), RV32 | RV64, [V, Ref] => [Vgroup(7, 4), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vl8re64.v" = [
+    // vl8re64.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0xE2807007), RV32 | RV64, [V, Ref] => [Vgroup(7, 8), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vle64.v" = [
+    // vle64.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x00007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vle64.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x02007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vle64ff.v" = [
+    // vle64ff.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x01007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vle64ff.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x03007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxei64.v" = [
+    // vloxei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x0C007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x0E007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg2ei64.v" = [
+    // vloxseg2ei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x2C007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg2ei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x2E007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg3ei64.v" = [
+    // vloxseg3ei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x4C007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg3ei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x4E007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg4ei64.v" = [
+    // vloxseg4ei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x6C007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg4ei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x6E007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg5ei64.v" = [
+    // vloxseg5ei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x8C007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg5ei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x8E007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg6ei64.v" = [
+    // vloxseg6ei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xAC007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg6ei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0xAE007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg7ei64.v" = [
+    // vloxseg7ei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xCC007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg7ei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0xCE007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vloxseg8ei64.v" = [
+    // vloxseg8ei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xEC007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vloxseg8ei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0xEE007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlse64.v" = [
+    // vlse64.v vd, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x08007007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlse64.v vd, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0x0A007007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg2e64.v" = [
+    // vlseg2e64.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x20007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg2e64.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x22007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg2e64ff.v" = [
+    // vlseg2e64ff.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x21007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg2e64ff.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x23007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg3e64.v" = [
+    // vlseg3e64.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x40007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg3e64.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x42007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg3e64ff.v" = [
+    // vlseg3e64ff.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x41007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg3e64ff.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x43007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg4e64.v" = [
+    // vlseg4e64.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x60007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg4e64.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x62007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg4e64ff.v" = [
+    // vlseg4e64ff.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x61007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg4e64ff.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x63007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg5e64.v" = [
+    // vlseg5e64.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x80007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg5e64.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x82007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg5e64ff.v" = [
+    // vlseg5e64ff.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x81007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg5e64ff.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x83007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg6e64.v" = [
+    // vlseg6e64.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0xA0007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg6e64.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0xA2007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg6e64ff.v" = [
+    // vlseg6e64ff.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0xA1007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg6e64ff.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0xA3007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg7e64.v" = [
+    // vlseg7e64.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0xC0007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg7e64.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0xC2007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg7e64ff.v" = [
+    // vlseg7e64ff.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0xC1007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg7e64ff.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0xC3007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg8e64.v" = [
+    // vlseg8e64.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0xE0007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg8e64.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0xE2007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlseg8e64ff.v" = [
+    // vlseg8e64ff.v vd, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0xE1007007), RV32 | RV64, [V, Ref, Vm] => [Vno0(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlseg8e64ff.v vd, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0xE3007007), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg2e64.v" = [
+    // vlsseg2e64.v vd, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x28007007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg2e64.v vd, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0x2A007007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg3e64.v" = [
+    // vlsseg3e64.v vd, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x48007007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg3e64.v vd, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0x4A007007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg4e64.v" = [
+    // vlsseg4e64.v vd, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x68007007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg4e64.v vd, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0x6A007007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg5e64.v" = [
+    // vlsseg5e64.v vd, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x88007007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg5e64.v vd, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0x8A007007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg6e64.v" = [
+    // vlsseg6e64.v vd, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xA8007007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg6e64.v vd, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0xAA007007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg7e64.v" = [
+    // vlsseg7e64.v vd, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xC8007007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg7e64.v vd, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0xCA007007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vlsseg8e64.v" = [
+    // vlsseg8e64.v vd, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xE8007007), RV32 | RV64, [V, Ref, X, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vlsseg8e64.v vd, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0xEA007007), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxei64.v" = [
+    // vluxei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x04007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x06007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg2ei64.v" = [
+    // vluxseg2ei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x24007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg2ei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x26007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg3ei64.v" = [
+    // vluxseg3ei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x44007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg3ei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x46007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg4ei64.v" = [
+    // vluxseg4ei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x64007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg4ei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x66007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg5ei64.v" = [
+    // vluxseg5ei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x84007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg5ei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x86007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg6ei64.v" = [
+    // vluxseg6ei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xA4007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg6ei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0xA6007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg7ei64.v" = [
+    // vluxseg7ei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xC4007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg7ei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0xC6007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vluxseg8ei64.v" = [
+    // vluxseg8ei64.v vd, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xE4007007),        RV64, [V, Ref, V, Vm] => [Vno0(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vluxseg8ei64.v vd, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0xE6007007),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vse64.v" = [
+    // vse64.v vs3, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x00007027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vse64.v vs3, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x02007027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxei64.v" = [
+    // vsoxei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x0C007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x0E007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg2ei64.v" = [
+    // vsoxseg2ei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x2C007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg2ei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x2E007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg3ei64.v" = [
+    // vsoxseg3ei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x4C007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg3ei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x4E007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg4ei64.v" = [
+    // vsoxseg4ei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x6C007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg4ei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x6E007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg5ei64.v" = [
+    // vsoxseg5ei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x8C007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg5ei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x8E007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg6ei64.v" = [
+    // vsoxseg6ei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xAC007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg6ei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0xAE007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg7ei64.v" = [
+    // vsoxseg7ei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xCC007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg7ei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0xCE007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsoxseg8ei64.v" = [
+    // vsoxseg8ei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xEC007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsoxseg8ei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0xEE007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsse64.v" = [
+    // vsse64.v vs3, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x08007027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsse64.v vs3, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0x0A007027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg2e64.v" = [
+    // vsseg2e64.v vs3, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x20007027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg2e64.v vs3, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x22007027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg3e64.v" = [
+    // vsseg3e64.v vs3, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x40007027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg3e64.v vs3, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x42007027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg4e64.v" = [
+    // vsseg4e64.v vs3, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x60007027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg4e64.v vs3, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x62007027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg5e64.v" = [
+    // vsseg5e64.v vs3, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0x80007027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg5e64.v vs3, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0x82007027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg6e64.v" = [
+    // vsseg6e64.v vs3, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0xA0007027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg6e64.v vs3, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0xA2007027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg7e64.v" = [
+    // vsseg7e64.v vs3, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0xC0007027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg7e64.v vs3, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0xC2007027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsseg8e64.v" = [
+    // vsseg8e64.v vs3, (rs1), vm (v, zve64d, zve64f, zve64x)
+    Single(0xE0007027), RV32 | RV64, [V, Ref, Vm] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsseg8e64.v vs3, (rs1) (v, zve64d, zve64f, zve64x)
+    Single(0xE2007027), RV32 | RV64, [V, Ref] => [R(7), R(15)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg2e64.v" = [
+    // vssseg2e64.v vs3, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x28007027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg2e64.v vs3, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0x2A007027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg3e64.v" = [
+    // vssseg3e64.v vs3, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x48007027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg3e64.v vs3, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0x4A007027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg4e64.v" = [
+    // vssseg4e64.v vs3, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x68007027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg4e64.v vs3, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0x6A007027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg5e64.v" = [
+    // vssseg5e64.v vs3, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x88007027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg5e64.v vs3, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0x8A007027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg6e64.v" = [
+    // vssseg6e64.v vs3, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xA8007027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg6e64.v vs3, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0xAA007027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg7e64.v" = [
+    // vssseg7e64.v vs3, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xC8007027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg7e64.v vs3, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0xCA007027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vssseg8e64.v" = [
+    // vssseg8e64.v vs3, (rs1), rs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xE8007027), RV32 | RV64, [V, Ref, X, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vssseg8e64.v vs3, (rs1), rs2 (v, zve64d, zve64f, zve64x)
+    Single(0xEA007027), RV32 | RV64, [V, Ref, X] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxei64.v" = [
+    // vsuxei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x04007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x06007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg2ei64.v" = [
+    // vsuxseg2ei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x24007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg2ei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x26007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg3ei64.v" = [
+    // vsuxseg3ei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x44007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg3ei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x46007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg4ei64.v" = [
+    // vsuxseg4ei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x64007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg4ei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x66007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg5ei64.v" = [
+    // vsuxseg5ei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0x84007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg5ei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0x86007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg6ei64.v" = [
+    // vsuxseg6ei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xA4007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg6ei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0xA6007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg7ei64.v" = [
+    // vsuxseg7ei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xC4007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg7ei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0xC6007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+"vsuxseg8ei64.v" = [
+    // vsuxseg8ei64.v vs3, (rs1), vs2, vm (v, zve64d, zve64f, zve64x)
+    Single(0xE4007027),        RV64, [V, Ref, V, Vm] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+    // vsuxseg8ei64.v vs3, (rs1), vs2 (v, zve64d, zve64f, zve64x)
+    Single(0xE6007027),        RV64, [V, Ref, V] => [R(7), R(15), R(20)], [Ex_V, Ex_Zve64d, Ex_Zve64f, Ex_Zve64x];
+],
+
 // Extension(s) zabha
 
 "amoadd.b" = [
@@ -3603,4 +7490,168 @@ Ops!(
     Single(0x10901013), RV32 | RV64, [X, X] => [R(7), R(15)], [Ex_Zks, Ex_Zksh];
 ],
 
+// Extension(s) zvbb
+
+"vbrev.v" = [
+    // vbrev.v vd, vs2, vm (zvbb)
+    Single(0x48052057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_Zvbb];
+    // vbrev.v vd, vs2 (zvbb)
+    Single(0x4A052057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvbb];
+],
+"vclz.v" = [
+    // vclz.v vd, vs2, vm (zvbb)
+    Single(0x48062057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_Zvbb];
+    // vclz.v vd, vs2 (zvbb)
+    Single(0x4A062057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvbb];
+],
+"vcpop.v" = [
+    // vcpop.v vd, vs2, vm (zvbb)
+    Single(0x48072057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_Zvbb];
+    // vcpop.v vd, vs2 (zvbb)
+    Single(0x4A072057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvbb];
+],
+"vctz.v" = [
+    // vctz.v vd, vs2, vm (zvbb)
+    Single(0x4806A057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_Zvbb];
+    // vctz.v vd, vs2 (zvbb)
+    Single(0x4A06A057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvbb];
+],
+"vwsll.vi" = [
+    // vwsll.vi vd, vs2, uimm5, vm (zvbb)
+    Single(0xD4003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_Zvbb];
+    // vwsll.vi vd, vs2, uimm5 (zvbb)
+    Single(0xD6003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_Zvbb];
+],
+"vwsll.vv" = [
+    // vwsll.vv vd, vs2, vs1, vm (zvbb)
+    Single(0xD4000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_Zvbb];
+    // vwsll.vv vd, vs2, vs1 (zvbb)
+    Single(0xD6000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_Zvbb];
+],
+"vwsll.vx" = [
+    // vwsll.vx vd, vs2, rs1, vm (zvbb)
+    Single(0xD4004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_Zvbb];
+    // vwsll.vx vd, vs2, rs1 (zvbb)
+    Single(0xD6004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_Zvbb];
+],
+
+// Extension(s) zvbb, zvkb
+
+"vandn.vv" = [
+    // vandn.vv vd, vs2, vs1, vm (zvbb, zvkb)
+    Single(0x04000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_Zvbb, Ex_Zvkb];
+    // vandn.vv vd, vs2, vs1 (zvbb, zvkb)
+    Single(0x06000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_Zvbb, Ex_Zvkb];
+],
+"vandn.vx" = [
+    // vandn.vx vd, vs2, rs1, vm (zvbb, zvkb)
+    Single(0x04004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_Zvbb, Ex_Zvkb];
+    // vandn.vx vd, vs2, rs1 (zvbb, zvkb)
+    Single(0x06004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_Zvbb, Ex_Zvkb];
+],
+"vbrev8.v" = [
+    // vbrev8.v vd, vs2, vm (zvbb, zvkb)
+    Single(0x48042057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_Zvbb, Ex_Zvkb];
+    // vbrev8.v vd, vs2 (zvbb, zvkb)
+    Single(0x4A042057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvbb, Ex_Zvkb];
+],
+"vrev8.v" = [
+    // vrev8.v vd, vs2, vm (zvbb, zvkb)
+    Single(0x4804A057), RV32 | RV64, [V, V, Vm] => [Vno0(7), R(20)], [Ex_Zvbb, Ex_Zvkb];
+    // vrev8.v vd, vs2 (zvbb, zvkb)
+    Single(0x4A04A057), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvbb, Ex_Zvkb];
+],
+"vrol.vv" = [
+    // vrol.vv vd, vs2, vs1, vm (zvbb, zvkb)
+    Single(0x54000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_Zvbb, Ex_Zvkb];
+    // vrol.vv vd, vs2, vs1 (zvbb, zvkb)
+    Single(0x56000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_Zvbb, Ex_Zvkb];
+],
+"vrol.vx" = [
+    // vrol.vx vd, vs2, rs1, vm (zvbb, zvkb)
+    Single(0x54004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_Zvbb, Ex_Zvkb];
+    // vrol.vx vd, vs2, rs1 (zvbb, zvkb)
+    Single(0x56004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_Zvbb, Ex_Zvkb];
+],
+"vror.vi" = [
+    // vror.vi vd, vs2, uimm6, vm (zvbb, zvkb)
+    Single(0x50003057), RV32 | RV64, [V, V, Imm, Vm] => [Vno0(7), R(20), UImm(6, 0), BitRange(15, 5, 0), BitRange(26, 1, 5), Next], [Ex_Zvbb, Ex_Zvkb];
+    // vror.vi vd, vs2, uimm6 (zvbb, zvkb)
+    Single(0x52003057), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(6, 0), BitRange(15, 5, 0), BitRange(26, 1, 5), Next], [Ex_Zvbb, Ex_Zvkb];
+],
+"vror.vv" = [
+    // vror.vv vd, vs2, vs1, vm (zvbb, zvkb)
+    Single(0x50000057), RV32 | RV64, [V, V, V, Vm] => [Vno0(7), R(20), R(15)], [Ex_Zvbb, Ex_Zvkb];
+    // vror.vv vd, vs2, vs1 (zvbb, zvkb)
+    Single(0x52000057), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_Zvbb, Ex_Zvkb];
+],
+"vror.vx" = [
+    // vror.vx vd, vs2, rs1, vm (zvbb, zvkb)
+    Single(0x50004057), RV32 | RV64, [V, V, X, Vm] => [Vno0(7), R(20), R(15)], [Ex_Zvbb, Ex_Zvkb];
+    // vror.vx vd, vs2, rs1 (zvbb, zvkb)
+    Single(0x52004057), RV32 | RV64, [V, V, X] => [R(7), R(20), R(15)], [Ex_Zvbb, Ex_Zvkb];
+],
+
+// Extension(s) zvkned
+
+"vaesdf.vs" = [
+    // vaesdf.vs vd, vs2 (zvkned)
+    Single(0xA600A077), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvkned];
+],
+"vaesdf.vv" = [
+    // vaesdf.vv vd, vs2 (zvkned)
+    Single(0xA200A077), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvkned];
+],
+"vaesdm.vs" = [
+    // vaesdm.vs vd, vs2 (zvkned)
+    Single(0xA6002077), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvkned];
+],
+"vaesdm.vv" = [
+    // vaesdm.vv vd, vs2 (zvkned)
+    Single(0xA2002077), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvkned];
+],
+"vaesef.vs" = [
+    // vaesef.vs vd, vs2 (zvkned)
+    Single(0xA601A077), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvkned];
+],
+"vaesef.vv" = [
+    // vaesef.vv vd, vs2 (zvkned)
+    Single(0xA201A077), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvkned];
+],
+"vaesem.vs" = [
+    // vaesem.vs vd, vs2 (zvkned)
+    Single(0xA6012077), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvkned];
+],
+"vaesem.vv" = [
+    // vaesem.vv vd, vs2 (zvkned)
+    Single(0xA2012077), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvkned];
+],
+"vaeskf1.vi" = [
+    // vaeskf1.vi vd, vs2, uimm5 (zvkned)
+    Single(0x8A002077), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_Zvkned];
+],
+"vaeskf2.vi" = [
+    // vaeskf2.vi vd, vs2, uimm5 (zvkned)
+    Single(0xAA002077), RV32 | RV64, [V, V, Imm] => [R(7), R(20), UImm(5, 0), BitRange(15, 5, 0), Next], [Ex_Zvkned];
+],
+"vaesz.vs" = [
+    // vaesz.vs vd, vs2 (zvkned)
+    Single(0xA603A077), RV32 | RV64, [V, V] => [R(7), R(20)], [Ex_Zvkned];
+],
+
+// Extension(s) zvknha, zvknhb
+
+"vsha2ch.vv" = [
+    // vsha2ch.vv vd, vs2, vs1 (zvknha, zvknhb)
+    Single(0xBA002077), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_Zvknha, Ex_Zvknhb];
+],
+"vsha2cl.vv" = [
+    // vsha2cl.vv vd, vs2, vs1 (zvknha, zvknhb)
+    Single(0xBE002077), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_Zvknha, Ex_Zvknhb];
+],
+"vsha2ms.vv" = [
+    // vsha2ms.vv vd, vs2, vs1 (zvknha, zvknhb)
+    Single(0xB6002077), RV32 | RV64, [V, V, V] => [R(7), R(20), R(15)], [Ex_Zvknha, Ex_Zvknhb];
+],
+
 )
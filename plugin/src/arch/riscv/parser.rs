@@ -2,11 +2,13 @@ use std::collections::HashMap;
 
 use syn::{parse, Token};
 use syn::spanned::Spanned;
+use proc_macro2::Span;
 
 
 use lazy_static::lazy_static;
 
 use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOptExt, eat_pseudo_keyword};
+use crate::serialize;
 
 use super::{Context, ast};
 
@@ -146,8 +148,25 @@ fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<ast:
         };
     }
 
-    // a register
+    // a register, a vector mask register (reg.t), or a type mapped reference (reg => Type[index].attr)
     if let Some(reg) = parse_reg(ctx, input)? {
+        if input.peek(Token![=>]) {
+            return parse_typemap(ctx, input, reg, start);
+        }
+
+        if input.peek(Token![.]) {
+            let _: Token![.] = input.parse()?;
+            let ident = parse_ident_or_rust_keyword(input)?;
+            if ident != "t" {
+                return Err(parse::Error::new(ident.span(), "Expected a vector mask register like 'v0.t'"));
+            }
+
+            return Ok(ast::RawArg::MaskRegister {
+                reg,
+                span: start
+            })
+        }
+
         return Ok(ast::RawArg::Register {
             reg,
             span: start
@@ -167,6 +186,48 @@ fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<ast:
     Ok(ast::RawArg::Immediate { value: expr })
 }
 
+/// Parses the remainder of a type mapped reference, `base => Type ("[" index "]")? ("." attr)?`
+/// As RISC-V only has base + offset addressing, the index has to be an immediate. It is combined
+/// with the offset of the attribute into the reference offset.
+fn parse_typemap(ctx: &mut Context, input: parse::ParseStream, base: ast::Register, span: Span) -> parse::Result<ast::RawArg> {
+    let _: Token![=>] = input.parse()?;
+
+    let ty: syn::Path = input.parse()?;
+
+    let index = if input.peek(syn::token::Bracket) {
+        let inner;
+        let _ = syn::bracketed!(inner in input);
+        let inner = &inner;
+
+        let expr: syn::Expr = inner.parse()?;
+        if parse_reg_from_expression(ctx, &expr)?.is_some() {
+            return Err(parse::Error::new(expr.span(), "RISC-V memory references cannot use an index register"));
+        }
+
+        Some(expr)
+    } else {
+        None
+    };
+
+    let attr: Option<syn::Ident> = if input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        Some(input.parse()?)
+    } else {
+        None
+    };
+
+    let offset = match serialize::expr_typemap_disp(span, &ty, index.as_ref(), attr.as_ref()) {
+        Some(disp) => Some(serialize::reparse(&disp)?),
+        None => None
+    };
+
+    Ok(ast::RawArg::Reference {
+        span,
+        base,
+        offset
+    })
+}
+
 /// Checks if the given expression could be a valid RISC-V register reference
 /// This can be a simple register name (like `x5`)
 /// an alias (any simple name that is registered, like `base`)
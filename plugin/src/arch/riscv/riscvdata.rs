@@ -34,7 +34,7 @@ bitflags! {
     /// Flags specifying what ISA extensions are required for an instruction
     // debug.rs opmap generation relies on the Ex_ prefixes being fixed,
     // so change that if editing this.
-    #[derive(Debug, Clone, Copy)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct ExtensionFlags: u64 {
         /// A: atomics
         const Ex_A = 0x0000_0000_0000_0001;
@@ -44,96 +44,124 @@ bitflags! {
         const Ex_D = 0x0000_0000_0000_0004;
         /// F: single fp support
         const Ex_F = 0x0000_0000_0000_0008;
+        /// H: hypervisor
+        const Ex_H = 0x0000_0000_0000_0010;
         /// I: integer base instruction set
-        const Ex_I = 0x0000_0000_0000_0010;
+        const Ex_I = 0x0000_0000_0000_0020;
         /// M: multiply/divide
-        const Ex_M = 0x0000_0000_0000_0020;
+        const Ex_M = 0x0000_0000_0000_0040;
+        /// Priv: privileged architecture instructions
+        const Ex_Priv = 0x0000_0000_0000_0080;
         /// Q: quad fp support
-        const Ex_Q = 0x0000_0000_0000_0040;
+        const Ex_Q = 0x0000_0000_0000_0100;
+        /// Svinval: fine-grained address-translation cache invalidation
+        const Ex_Svinval = 0x0000_0000_0000_0200;
+        /// V: vector operations
+        const Ex_V = 0x0000_0000_0000_0400;
         /// Zabha: byte and halfword atomics
-        const Ex_Zabha = 0x0000_0000_0000_0080;
+        const Ex_Zabha = 0x0000_0000_0000_0800;
         /// Zacas: atomic compare and swap
-        const Ex_Zacas = 0x0000_0000_0000_0100;
+        const Ex_Zacas = 0x0000_0000_0000_1000;
         /// Zawrs: wait-on-reservation-set
-        const Ex_Zawrs = 0x0000_0000_0000_0200;
+        const Ex_Zawrs = 0x0000_0000_0000_2000;
         /// Zba: bit manipulation address generation
-        const Ex_Zba = 0x0000_0000_0000_0400;
+        const Ex_Zba = 0x0000_0000_0000_4000;
         /// Zbb: basic bit manipulation
-        const Ex_Zbb = 0x0000_0000_0000_0800;
+        const Ex_Zbb = 0x0000_0000_0000_8000;
         /// Zbc: carry-less multiplication
-        const Ex_Zbc = 0x0000_0000_0000_1000;
+        const Ex_Zbc = 0x0000_0000_0001_0000;
         /// Zbkb: bit manipulation for cryptography
-        const Ex_Zbkb = 0x0000_0000_0000_2000;
+        const Ex_Zbkb = 0x0000_0000_0002_0000;
         /// Zbkc: carry-less multiplication for cryptography
-        const Ex_Zbkc = 0x0000_0000_0000_4000;
+        const Ex_Zbkc = 0x0000_0000_0004_0000;
         /// Zbkx: crossbar permutations
-        const Ex_Zbkx = 0x0000_0000_0000_8000;
+        const Ex_Zbkx = 0x0000_0000_0008_0000;
         /// Zbs: single-bit instructions
-        const Ex_Zbs = 0x0000_0000_0001_0000;
+        const Ex_Zbs = 0x0000_0000_0010_0000;
         /// Zcb: simple code-size saving instructions
-        const Ex_Zcb = 0x0000_0000_0002_0000;
+        const Ex_Zcb = 0x0000_0000_0020_0000;
         /// Zcmop: compressed may-be-operations
-        const Ex_Zcmop = 0x0000_0000_0004_0000;
+        const Ex_Zcmop = 0x0000_0000_0040_0000;
         /// Zcmp: compressed instruction sequences
-        const Ex_Zcmp = 0x0000_0000_0008_0000;
+        const Ex_Zcmp = 0x0000_0000_0080_0000;
         /// Zcmt: compressed table jump instructions
-        const Ex_Zcmt = 0x0000_0000_0010_0000;
+        const Ex_Zcmt = 0x0000_0000_0100_0000;
         /// Zdinx: double floating point in X registers
-        const Ex_Zdinx = 0x0000_0000_0020_0000;
+        const Ex_Zdinx = 0x0000_0000_0200_0000;
         /// Zfa: additional floating point instructions
-        const Ex_Zfa = 0x0000_0000_0040_0000;
+        const Ex_Zfa = 0x0000_0000_0400_0000;
         /// Zfbfmin: Scalar convert to/from BF16
-        const Ex_Zfbfmin = 0x0000_0000_0080_0000;
+        const Ex_Zfbfmin = 0x0000_0000_0800_0000;
         /// Zfh: half-width fp support
-        const Ex_Zfh = 0x0000_0000_0100_0000;
+        const Ex_Zfh = 0x0000_0000_1000_0000;
         /// Zfhmin: half-width fp support, conversion only
-        const Ex_Zfhmin = 0x0000_0000_0200_0000;
+        const Ex_Zfhmin = 0x0000_0000_2000_0000;
         /// Zfinx: floating point in X registers
-        const Ex_Zfinx = 0x0000_0000_0400_0000;
+        const Ex_Zfinx = 0x0000_0000_4000_0000;
         /// Zhinx: half floating point in X registers
-        const Ex_Zhinx = 0x0000_0000_0800_0000;
+        const Ex_Zhinx = 0x0000_0000_8000_0000;
         /// Zhinxmin: half floating point in X registers, conversion only
-        const Ex_Zhinxmin = 0x0000_0000_1000_0000;
+        const Ex_Zhinxmin = 0x0000_0001_0000_0000;
         /// Zicbo: cache block management operations
-        const Ex_Zicbom = 0x0000_0000_2000_0000;
+        const Ex_Zicbom = 0x0000_0002_0000_0000;
         /// Zicbo: cache block prefetch operations
-        const Ex_Zicbop = 0x0000_0000_4000_0000;
+        const Ex_Zicbop = 0x0000_0004_0000_0000;
         /// Zicbo: cache block zero operations
-        const Ex_Zicboz = 0x0000_0000_8000_0000;
+        const Ex_Zicboz = 0x0000_0008_0000_0000;
         /// Zicfilp: control flow integrity landing pad
-        const Ex_Zicfilp = 0x0000_0001_0000_0000;
+        const Ex_Zicfilp = 0x0000_0010_0000_0000;
         /// Zicfiss: Shadow stack
-        const Ex_Zicfiss = 0x0000_0002_0000_0000;
+        const Ex_Zicfiss = 0x0000_0020_0000_0000;
         /// Zicntr: base counters and timers
-        const Ex_Zicntr = 0x0000_0004_0000_0000;
+        const Ex_Zicntr = 0x0000_0040_0000_0000;
         /// Zicond: conditional operations
-        const Ex_Zicond = 0x0000_0008_0000_0000;
+        const Ex_Zicond = 0x0000_0080_0000_0000;
         /// Zicsr: control and status registers
-        const Ex_Zicsr = 0x0000_0010_0000_0000;
+        const Ex_Zicsr = 0x0000_0100_0000_0000;
         /// Zifencei: instruction-fetch fence
-        const Ex_Zifencei = 0x0000_0020_0000_0000;
+        const Ex_Zifencei = 0x0000_0200_0000_0000;
         /// Zihintntl: non-temporal hints
-        const Ex_Zihintntl = 0x0000_0040_0000_0000;
+        const Ex_Zihintntl = 0x0000_0400_0000_0000;
         /// Zihintpause: pause hint
-        const Ex_Zihintpause = 0x0000_0080_0000_0000;
+        const Ex_Zihintpause = 0x0000_0800_0000_0000;
         /// Zimop: may-be-operations
-        const Ex_Zimop = 0x0000_0100_0000_0000;
+        const Ex_Zimop = 0x0000_1000_0000_0000;
         /// Zk: scalar cryptography
-        const Ex_Zk = 0x0000_0200_0000_0000;
+        const Ex_Zk = 0x0000_2000_0000_0000;
         /// Zkn: NIST algorithm suite
-        const Ex_Zkn = 0x0000_0400_0000_0000;
+        const Ex_Zkn = 0x0000_4000_0000_0000;
         /// Zknd: NIST suite: AES decyrption
-        const Ex_Zknd = 0x0000_0800_0000_0000;
+        const Ex_Zknd = 0x0000_8000_0000_0000;
         /// Zkne: NIST suite: AES encryption
-        const Ex_Zkne = 0x0000_1000_0000_0000;
+        const Ex_Zkne = 0x0001_0000_0000_0000;
         /// Zknh: NIST suite: Hash functions
-        const Ex_Zknh = 0x0000_2000_0000_0000;
+        const Ex_Zknh = 0x0002_0000_0000_0000;
         /// Zks: ShangMi algorithm suite
-        const Ex_Zks = 0x0000_4000_0000_0000;
+        const Ex_Zks = 0x0004_0000_0000_0000;
         /// Zksed: ShangMi suite: SM4 block cipher
-        const Ex_Zksed = 0x0000_8000_0000_0000;
+        const Ex_Zksed = 0x0008_0000_0000_0000;
         /// Zksh: ShangMi suite: SM3 hash functions
-        const Ex_Zksh = 0x0001_0000_0000_0000;
+        const Ex_Zksh = 0x0010_0000_0000_0000;
+        /// Zvbb: vector basic bit manipulation
+        const Ex_Zvbb = 0x0020_0000_0000_0000;
+        /// Zve32f: embedded vector, 32-bit elements with single fp support
+        const Ex_Zve32f = 0x0040_0000_0000_0000;
+        /// Zve32x: embedded vector, 32-bit integer elements
+        const Ex_Zve32x = 0x0080_0000_0000_0000;
+        /// Zve64d: embedded vector, 64-bit elements with double fp support
+        const Ex_Zve64d = 0x0100_0000_0000_0000;
+        /// Zve64f: embedded vector, 64-bit elements with single fp support
+        const Ex_Zve64f = 0x0200_0000_0000_0000;
+        /// Zve64x: embedded vector, 64-bit integer elements
+        const Ex_Zve64x = 0x0400_0000_0000_0000;
+        /// Zvkb: vector bit manipulation for cryptography
+        const Ex_Zvkb = 0x0800_0000_0000_0000;
+        /// Zvkned: vector NIST suite: AES block cipher
+        const Ex_Zvkned = 0x1000_0000_0000_0000;
+        /// Zvknha: vector NIST suite: SHA-256 hash functions
+        const Ex_Zvknha = 0x2000_0000_0000_0000;
+        /// Zvknhb: vector NIST suite: SHA-256 and SHA-512 hash functions
+        const Ex_Zvknhb = 0x4000_0000_0000_0000;
     }
 }
 
@@ -153,21 +181,23 @@ impl ExtensionFlags {
 
 impl fmt::Display for ExtensionFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut encountered_z = false;
+        let mut encountered_long = false;
+        let mut first = true;
 
-        // assemble extension sets. only use underscores between Z flags
+        // assemble extension sets. only use underscores around multi-letter flags
         for (flag, bits) in self.iter_names() {
             let flag = &flag[3..];
 
-            if encountered_z {
+            if encountered_long || (flag.len() > 1 && !first) {
                 write!(f, "_")?;
             }
 
             write!(f, "{}", flag)?;
 
-            if flag.starts_with("Z") {
-                encountered_z = true;
+            if flag.len() > 1 {
+                encountered_long = true;
             }
+            first = false;
         }
         Ok(())
     }
@@ -179,6 +209,117 @@ impl Default for ExtensionFlags {
     }
 }
 
+impl ExtensionFlags {
+    /// Adds all extensions that are implied by the extensions in this set
+    pub fn with_implied(mut self) -> ExtensionFlags {
+        loop {
+            let mut next = self;
+            for &(extension, implied) in EXTENSION_IMPLICATIONS {
+                if self.contains(extension) {
+                    next.insert(implied);
+                }
+            }
+
+            if next == self {
+                return self;
+            }
+            self = next;
+        }
+    }
+}
+
+/// Extensions that imply other extensions. Implied extensions that do not define any instructions
+/// are left out.
+const EXTENSION_IMPLICATIONS: &[(ExtensionFlags, ExtensionFlags)] = &[
+    (ExtensionFlags::Ex_D, ExtensionFlags::Ex_F),
+    (ExtensionFlags::Ex_F, ExtensionFlags::Ex_Zicsr),
+    (ExtensionFlags::Ex_H, ExtensionFlags::Ex_Zicsr),
+    (ExtensionFlags::Ex_Q, ExtensionFlags::Ex_D),
+    (ExtensionFlags::Ex_V, ExtensionFlags::Ex_Zve64d),
+    (ExtensionFlags::Ex_Zcmt, ExtensionFlags::Ex_Zicsr),
+    (ExtensionFlags::Ex_Zdinx, ExtensionFlags::Ex_Zfinx),
+    (ExtensionFlags::Ex_Zfa, ExtensionFlags::Ex_F),
+    (ExtensionFlags::Ex_Zfbfmin, ExtensionFlags::Ex_F),
+    (ExtensionFlags::Ex_Zfh, ExtensionFlags::Ex_Zfhmin),
+    (ExtensionFlags::Ex_Zfhmin, ExtensionFlags::Ex_F),
+    (ExtensionFlags::Ex_Zfinx, ExtensionFlags::Ex_Zicsr),
+    (ExtensionFlags::Ex_Zhinx, ExtensionFlags::Ex_Zhinxmin),
+    (ExtensionFlags::Ex_Zhinxmin, ExtensionFlags::Ex_Zfinx),
+    (ExtensionFlags::Ex_Zicfiss, ExtensionFlags::Ex_Zicsr),
+    (ExtensionFlags::Ex_Zicfiss, ExtensionFlags::Ex_Zimop),
+    (ExtensionFlags::Ex_Zicntr, ExtensionFlags::Ex_Zicsr),
+    (ExtensionFlags::Ex_Zk, ExtensionFlags::Ex_Zkn),
+    (ExtensionFlags::Ex_Zkn, ExtensionFlags::Ex_Zbkb),
+    (ExtensionFlags::Ex_Zkn, ExtensionFlags::Ex_Zbkc),
+    (ExtensionFlags::Ex_Zkn, ExtensionFlags::Ex_Zbkx),
+    (ExtensionFlags::Ex_Zkn, ExtensionFlags::Ex_Zknd),
+    (ExtensionFlags::Ex_Zkn, ExtensionFlags::Ex_Zkne),
+    (ExtensionFlags::Ex_Zkn, ExtensionFlags::Ex_Zknh),
+    (ExtensionFlags::Ex_Zks, ExtensionFlags::Ex_Zbkb),
+    (ExtensionFlags::Ex_Zks, ExtensionFlags::Ex_Zbkc),
+    (ExtensionFlags::Ex_Zks, ExtensionFlags::Ex_Zbkx),
+    (ExtensionFlags::Ex_Zks, ExtensionFlags::Ex_Zksed),
+    (ExtensionFlags::Ex_Zks, ExtensionFlags::Ex_Zksh),
+    (ExtensionFlags::Ex_Zvbb, ExtensionFlags::Ex_Zvkb),
+    (ExtensionFlags::Ex_Zve32f, ExtensionFlags::Ex_F),
+    (ExtensionFlags::Ex_Zve32f, ExtensionFlags::Ex_Zve32x),
+    (ExtensionFlags::Ex_Zve32x, ExtensionFlags::Ex_Zicsr),
+    (ExtensionFlags::Ex_Zve64d, ExtensionFlags::Ex_D),
+    (ExtensionFlags::Ex_Zve64d, ExtensionFlags::Ex_Zve64f),
+    (ExtensionFlags::Ex_Zve64f, ExtensionFlags::Ex_Zve32f),
+    (ExtensionFlags::Ex_Zve64f, ExtensionFlags::Ex_Zve64x),
+    (ExtensionFlags::Ex_Zve64x, ExtensionFlags::Ex_Zve32x),
+    (ExtensionFlags::Ex_Zvkb, ExtensionFlags::Ex_Zve32x),
+    (ExtensionFlags::Ex_Zvkned, ExtensionFlags::Ex_Zve32x),
+    (ExtensionFlags::Ex_Zvknha, ExtensionFlags::Ex_Zve32x),
+    (ExtensionFlags::Ex_Zvknhb, ExtensionFlags::Ex_Zve64x),
+];
+
+/// Known extensions that only specify architectural guarantees or CSRs, and do not add any instructions.
+pub static INSTRUCTIONLESS_EXTENSIONS: &[&str] = &[
+    "sha", "shcounterenw", "shgatpa", "shtvala", "shvsatpa", "shvstvala", "shvstvecd",
+    "ss1p11", "ss1p12", "ss1p13", "ssccptr", "sscofpmf", "sscounterenw", "ssnpm", "sstc", "sstvala",
+    "sstvecd", "ssu64xl", "supm", "sv39", "svade", "svbare", "svnapot", "svpbmt",
+    "za128rs", "za64rs", "zic64b", "ziccamoa", "ziccif", "zicclsm", "ziccrse", "zihpm", "zkr", "zkt",
+    "ztso", "zvfhmin", "zvkt",
+];
+
+/// RISC-V profiles, whether they are 64-bit, and their mandatory extensions.
+pub static PROFILES: &[(&str, bool, &[&str])] = &[
+    ("rvi20u32", false, &["i"]),
+    ("rvi20u64", true, &["i"]),
+    ("rva20u64", true, &[
+        "i", "m", "a", "f", "d", "c", "zicsr", "zicntr", "ziccif", "ziccrse", "ziccamoa", "za128rs", "zicclsm",
+    ]),
+    ("rva20s64", true, &[
+        "i", "m", "a", "f", "d", "c", "zicsr", "zicntr", "ziccif", "ziccrse", "ziccamoa", "za128rs", "zicclsm",
+        "priv", "zifencei", "ss1p11", "svbare", "sv39", "svade", "ssccptr", "sstvecd", "sstvala",
+    ]),
+    ("rva22u64", true, &[
+        "i", "m", "a", "f", "d", "c", "zicsr", "zicntr", "ziccif", "ziccrse", "ziccamoa", "za64rs", "zicclsm",
+        "zihpm", "zihintpause", "zba", "zbb", "zbs", "zic64b", "zicbom", "zicbop", "zicboz", "zfhmin", "zkt",
+    ]),
+    ("rva22s64", true, &[
+        "i", "m", "a", "f", "d", "c", "zicsr", "zicntr", "ziccif", "ziccrse", "ziccamoa", "za64rs", "zicclsm",
+        "zihpm", "zihintpause", "zba", "zbb", "zbs", "zic64b", "zicbom", "zicbop", "zicboz", "zfhmin", "zkt",
+        "priv", "zifencei", "ss1p12", "svbare", "sv39", "svade", "ssccptr", "sstvecd", "sstvala", "sscounterenw",
+        "svpbmt", "svinval",
+    ]),
+    ("rva23u64", true, &[
+        "i", "m", "a", "f", "d", "c", "zicsr", "zicntr", "ziccif", "ziccrse", "ziccamoa", "za64rs", "zicclsm",
+        "zihpm", "zihintpause", "zba", "zbb", "zbs", "zic64b", "zicbom", "zicbop", "zicboz", "zfhmin", "zkt",
+        "v", "zvfhmin", "zvbb", "zvkt", "zihintntl", "zicond", "zimop", "zcmop", "zcb", "zfa", "zawrs", "supm",
+    ]),
+    ("rva23s64", true, &[
+        "i", "m", "a", "f", "d", "c", "zicsr", "zicntr", "ziccif", "ziccrse", "ziccamoa", "za64rs", "zicclsm",
+        "zihpm", "zihintpause", "zba", "zbb", "zbs", "zic64b", "zicbom", "zicbop", "zicboz", "zfhmin", "zkt",
+        "v", "zvfhmin", "zvbb", "zvkt", "zihintntl", "zicond", "zimop", "zcmop", "zcb", "zfa", "zawrs", "supm",
+        "priv", "zifencei", "ss1p13", "svbare", "sv39", "svade", "ssccptr", "sstvecd", "sstvala", "sscounterenw",
+        "svpbmt", "svinval", "svnapot", "sstc", "sscofpmf", "ssnpm", "ssu64xl", "h", "sha", "shcounterenw",
+        "shvstvala", "shtvala", "shvstvecd", "shvsatpa", "shgatpa",
+    ]),
+];
+
 
 /// Matchers. These validate the types of arguments passed to an instruction.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -193,7 +334,10 @@ pub enum Matcher {
     Reg(RegId),
 
     /// A vector register
-    // V,
+    V,
+
+    /// The vector mask operand, v0.t
+    Vm,
 
     /// An indirect reference to a register
     Ref,
@@ -278,6 +422,13 @@ pub enum Command {
     /// A register list, as used in cm.pop. values 3-15 encode ra, s0-s11
     Rlist(u8),
 
+    /// A 5-bit vector register encoding that cannot be v0, as v0 is used as mask by the instruction
+    Vno0(u8),
+
+    /// A 5-bit vector register encoding for a group of .1 registers. The register number has
+    /// to be a multiple of .1
+    Vgroup(u8, u8),
+
     // weird fields
 
     /// Rounding mode. 3-bit encoding indicating (RNE, RTZ, RDN, RUP, RMM, _, _, DYN). DYN default
@@ -293,6 +444,18 @@ pub enum Command {
     /// weird floating point immediate instruction
     FloatingPointImmediate(u8),
 
+    /// vtype selected element width. 3-bit field encoding (e8, e16, e32, e64)
+    ElementWidth(u8),
+
+    /// vtype register group multiplier. 3-bit field encoding (m1, m2, m4, m8, _, mf8, mf4, mf2)
+    GroupMultiplier(u8),
+
+    /// vtype tail policy. 1-bit field encoding (tu, ta)
+    TailPolicy(u8),
+
+    /// vtype mask policy. 1-bit field encoding (mu, ma)
+    MaskPolicy(u8),
+
     /// Technically an immediate, but the way we encode this depends on the amount of items
     /// in the previous register list. The boolean indicates if the value should be negated.
     SPImm(u8, bool),
@@ -490,9 +653,13 @@ lazy_static!{
         const Ex_C: u64 = ExtensionFlags::Ex_C.bits();
         const Ex_D: u64 = ExtensionFlags::Ex_D.bits();
         const Ex_F: u64 = ExtensionFlags::Ex_F.bits();
+        const Ex_H: u64 = ExtensionFlags::Ex_H.bits();
         const Ex_I: u64 = ExtensionFlags::Ex_I.bits();
         const Ex_M: u64 = ExtensionFlags::Ex_M.bits();
+        const Ex_Priv: u64 = ExtensionFlags::Ex_Priv.bits();
         const Ex_Q: u64 = ExtensionFlags::Ex_Q.bits();
+        const Ex_Svinval: u64 = ExtensionFlags::Ex_Svinval.bits();
+        const Ex_V: u64 = ExtensionFlags::Ex_V.bits();
         const Ex_Zabha: u64 = ExtensionFlags::Ex_Zabha.bits();
         const Ex_Zacas: u64 = ExtensionFlags::Ex_Zacas.bits();
         const Ex_Zawrs: u64 = ExtensionFlags::Ex_Zawrs.bits();
@@ -535,6 +702,16 @@ lazy_static!{
         const Ex_Zks: u64 = ExtensionFlags::Ex_Zks.bits();
         const Ex_Zksed: u64 = ExtensionFlags::Ex_Zksed.bits();
         const Ex_Zksh: u64 = ExtensionFlags::Ex_Zksh.bits();
+        const Ex_Zvbb: u64 = ExtensionFlags::Ex_Zvbb.bits();
+        const Ex_Zve32f: u64 = ExtensionFlags::Ex_Zve32f.bits();
+        const Ex_Zve32x: u64 = ExtensionFlags::Ex_Zve32x.bits();
+        const Ex_Zve64d: u64 = ExtensionFlags::Ex_Zve64d.bits();
+        const Ex_Zve64f: u64 = ExtensionFlags::Ex_Zve64f.bits();
+        const Ex_Zve64x: u64 = ExtensionFlags::Ex_Zve64x.bits();
+        const Ex_Zvkb: u64 = ExtensionFlags::Ex_Zvkb.bits();
+        const Ex_Zvkned: u64 = ExtensionFlags::Ex_Zvkned.bits();
+        const Ex_Zvknha: u64 = ExtensionFlags::Ex_Zvknha.bits();
+        const Ex_Zvknhb: u64 = ExtensionFlags::Ex_Zvknhb.bits();
 
 
         static MAP: &[(&str, &[Opdata])] = &include!("opmap.rs");
@@ -576,6 +753,49 @@ lazy_static!{
         map
     };
 
+    pub static ref ELEMENTWIDTH_MAP: HashMap<&'static str, u8> = {
+        let mut map = HashMap::new();
+
+        map.insert("e8", 0);
+        map.insert("e16", 1);
+        map.insert("e32", 2);
+        map.insert("e64", 3);
+
+        map
+    };
+
+    pub static ref GROUPMULTIPLIER_MAP: HashMap<&'static str, u8> = {
+        let mut map = HashMap::new();
+
+        map.insert("m1", 0);
+        map.insert("m2", 1);
+        map.insert("m4", 2);
+        map.insert("m8", 3);
+        map.insert("mf8", 5);
+        map.insert("mf4", 6);
+        map.insert("mf2", 7);
+
+        map
+    };
+
+    pub static ref TAILPOLICY_MAP: HashMap<&'static str, u8> = {
+        let mut map = HashMap::new();
+
+        map.insert("tu", 0);
+        map.insert("ta", 1);
+
+        map
+    };
+
+    pub static ref MASKPOLICY_MAP: HashMap<&'static str, u8> = {
+        let mut map = HashMap::new();
+
+        map.insert("mu", 0);
+        map.insert("ma", 1);
+
+        map
+    };
+
     pub static ref CSR_MAP: HashMap<&'static str, u16> = {
         let mut map = HashMap::new();
 
@@ -1039,6 +1259,12 @@ lazy_static!{
         map.insert("mhpmcounter30h", 0xB9E);
         map.insert("mhpmcounter31h", 0xB9F);
 
+        // aliases for CSRs that were renamed in later versions of the privileged spec
+        map.insert("dscratch", 0x7b2);
+        map.insert("sptbr", 0x180);
+        map.insert("sbadaddr", 0x143);
+        map.insert("mbadaddr", 0x343);
+
         map
     };
 
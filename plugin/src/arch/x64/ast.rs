@@ -26,53 +26,69 @@ pub enum RegKind {
 }
 
 // this map identifies the different registers that exist. some of these can be referred to as different sizes
-// but they share the same ID here (think AL/AX/EAX/RAX, XMM/YMM)
+// but they share the same ID here (think AL/AX/EAX/RAX, XMM/YMM/ZMM)
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum RegId {
     // size: 1, 2, 4 or 8 bytes
-    RAX = 0x00, RCX = 0x01, RDX = 0x02, RBX = 0x03,
-    RSP = 0x04, RBP = 0x05, RSI = 0x06, RDI = 0x07,
-    R8  = 0x08, R9  = 0x09, R10 = 0x0A, R11 = 0x0B,
-    R12 = 0x0C, R13 = 0x0D, R14 = 0x0E, R15 = 0x0F,
+    RAX = 0x000, RCX = 0x001, RDX = 0x002, RBX = 0x003,
+    RSP = 0x004, RBP = 0x005, RSI = 0x006, RDI = 0x007,
+    R8  = 0x008, R9  = 0x009, R10 = 0x00A, R11 = 0x00B,
+    R12 = 0x00C, R13 = 0x00D, R14 = 0x00E, R15 = 0x00F,
+    R16 = 0x010, R17 = 0x011, R18 = 0x012, R19 = 0x013,
+    R20 = 0x014, R21 = 0x015, R22 = 0x016, R23 = 0x017,
+    R24 = 0x018, R25 = 0x019, R26 = 0x01A, R27 = 0x01B,
+    R28 = 0x01C, R29 = 0x01D, R30 = 0x01E, R31 = 0x01F,
 
     // size: 4 or 8 bytes
-    RIP = 0x15,
+    RIP = 0x105,
 
     // size: 1 byte
-    AH = 0x24, CH = 0x25, DH = 0x26, BH = 0x27,
+    AH = 0x204, CH = 0x205, DH = 0x206, BH = 0x207,
 
     // size: 10 bytes
-    ST0 = 0x30, ST1 = 0x31, ST2 = 0x32, ST3 = 0x33,
-    ST4 = 0x34, ST5 = 0x35, ST6 = 0x36, ST7 = 0x37,
+    ST0 = 0x300, ST1 = 0x301, ST2 = 0x302, ST3 = 0x303,
+    ST4 = 0x304, ST5 = 0x305, ST6 = 0x306, ST7 = 0x307,
 
     // size: 8 bytes. alternative encoding exists
-    MMX0 = 0x40, MMX1 = 0x41, MMX2 = 0x42, MMX3 = 0x43,
-    MMX4 = 0x44, MMX5 = 0x45, MMX6 = 0x46, MMX7 = 0x47,
-
-    // size: 16 bytes or 32 bytes
-    XMM0  = 0x50, XMM1  = 0x51, XMM2  = 0x52, XMM3  = 0x53,
-    XMM4  = 0x54, XMM5  = 0x55, XMM6  = 0x56, XMM7  = 0x57,
-    XMM8  = 0x58, XMM9  = 0x59, XMM10 = 0x5A, XMM11 = 0x5B,
-    XMM12 = 0x5C, XMM13 = 0x5D, XMM14 = 0x5E, XMM15 = 0x5F,
+    MMX0 = 0x400, MMX1 = 0x401, MMX2 = 0x402, MMX3 = 0x403,
+    MMX4 = 0x404, MMX5 = 0x405, MMX6 = 0x406, MMX7 = 0x407,
+
+    // size: 16, 32 or 64 bytes
+    XMM0  = 0x500, XMM1  = 0x501, XMM2  = 0x502, XMM3  = 0x503,
+    XMM4  = 0x504, XMM5  = 0x505, XMM6  = 0x506, XMM7  = 0x507,
+    XMM8  = 0x508, XMM9  = 0x509, XMM10 = 0x50A, XMM11 = 0x50B,
+    XMM12 = 0x50C, XMM13 = 0x50D, XMM14 = 0x50E, XMM15 = 0x50F,
+    XMM16 = 0x510, XMM17 = 0x511, XMM18 = 0x512, XMM19 = 0x513,
+    XMM20 = 0x514, XMM21 = 0x515, XMM22 = 0x516, XMM23 = 0x517,
+    XMM24 = 0x518, XMM25 = 0x519, XMM26 = 0x51A, XMM27 = 0x51B,
+    XMM28 = 0x51C, XMM29 = 0x51D, XMM30 = 0x51E, XMM31 = 0x51F,
 
     // size: 2 bytes. alternative encoding exists
-    ES = 0x60, CS = 0x61, SS = 0x62, DS = 0x63,
-    FS = 0x64, GS = 0x65,
+    ES = 0x600, CS = 0x601, SS = 0x602, DS = 0x603,
+    FS = 0x604, GS = 0x605,
 
     // size: 4 bytes
-    CR0  = 0x70, CR1  = 0x71, CR2  = 0x72, CR3  = 0x73,
-    CR4  = 0x74, CR5  = 0x75, CR6  = 0x76, CR7  = 0x77,
-    CR8  = 0x78, CR9  = 0x79, CR10 = 0x7A, CR11 = 0x7B,
-    CR12 = 0x7C, CR13 = 0x7D, CR14 = 0x7E, CR15 = 0x7F,
+    CR0  = 0x700, CR1  = 0x701, CR2  = 0x702, CR3  = 0x703,
+    CR4  = 0x704, CR5  = 0x705, CR6  = 0x706, CR7  = 0x707,
+    CR8  = 0x708, CR9  = 0x709, CR10 = 0x70A, CR11 = 0x70B,
+    CR12 = 0x70C, CR13 = 0x70D, CR14 = 0x70E, CR15 = 0x70F,
 
     // size: 4 bytes
-    DR0  = 0x80, DR1  = 0x81, DR2  = 0x82, DR3  = 0x83,
-    DR4  = 0x84, DR5  = 0x85, DR6  = 0x86, DR7  = 0x87,
-    DR8  = 0x88, DR9  = 0x89, DR10 = 0x8A, DR11 = 0x8B,
-    DR12 = 0x8C, DR13 = 0x8D, DR14 = 0x8E, DR15 = 0x8F,
+    DR0  = 0x800, DR1  = 0x801, DR2  = 0x802, DR3  = 0x803,
+    DR4  = 0x804, DR5  = 0x805, DR6  = 0x806, DR7  = 0x807,
+    DR8  = 0x808, DR9  = 0x809, DR10 = 0x80A, DR11 = 0x80B,
+    DR12 = 0x80C, DR13 = 0x80D, DR14 = 0x80E, DR15 = 0x80F,
 
     // size: 16 bytes
-    BND0 = 0x90, BND1 = 0x91, BND2 = 0x92, BND3 = 0x93
+    BND0 = 0x900, BND1 = 0x901, BND2 = 0x902, BND3 = 0x903,
+
+    // size: 8 bytes
+    K0 = 0xA00, K1 = 0xA01, K2 = 0xA02, K3 = 0xA03,
+    K4 = 0xA04, K5 = 0xA05, K6 = 0xA06, K7 = 0xA07,
+
+    // size: 1024 bytes, but as they can only be used as a whole this size is never checked
+    TMM0 = 0xB00, TMM1 = 0xB01, TMM2 = 0xB02, TMM3 = 0xB03,
+    TMM4 = 0xB04, TMM5 = 0xB05, TMM6 = 0xB06, TMM7 = 0xB07
 }
 
 #[derive(Debug, PartialOrd, PartialEq, Ord, Eq, Hash, Clone, Copy)]
@@ -86,7 +102,9 @@ pub enum RegFamily {
     SEGMENT = 6,
     CONTROL = 7,
     DEBUG = 8,
-    BOUND = 9
+    BOUND = 9,
+    MASK = 10,
+    TILE = 11
 }
 
 impl Register {
@@ -135,6 +153,11 @@ impl RegKind {
         }
     }
 
+    // r16-r31 can only be encoded using a REX2 or an extended EVEX prefix
+    pub fn is_apx_extended(&self) -> bool {
+        self.family() == RegFamily::LEGACY && self.code().unwrap_or(0) > 15
+    }
+
     pub fn encode(&self) -> u8 {
         self.code().unwrap_or(0)
     }
@@ -193,11 +216,11 @@ impl PartialEq<RegId> for Option<RegKind> {
 
 impl RegId {
     pub fn code(self) -> u8 {
-        self as u8 & 0xF
+        (self as u16 & 0xFF) as u8
     }
 
     pub fn family(self) -> RegFamily {
-        match self as u8 >> 4 {
+        match self as u16 >> 8 {
             0 => RegFamily::LEGACY,
             1 => RegFamily::RIP,
             2 => RegFamily::HIGHBYTE,
@@ -208,6 +231,8 @@ impl RegId {
             7 => RegFamily::CONTROL,
             8 => RegFamily::DEBUG,
             9 => RegFamily::BOUND,
+            10 => RegFamily::MASK,
+            11 => RegFamily::TILE,
             _ => unreachable!()
         }
     }
@@ -230,6 +255,22 @@ impl RegId {
             13 => RegId::R13,
             14 => RegId::R14,
             15 => RegId::R15,
+            16 => RegId::R16,
+            17 => RegId::R17,
+            18 => RegId::R18,
+            19 => RegId::R19,
+            20 => RegId::R20,
+            21 => RegId::R21,
+            22 => RegId::R22,
+            23 => RegId::R23,
+            24 => RegId::R24,
+            25 => RegId::R25,
+            26 => RegId::R26,
+            27 => RegId::R27,
+            28 => RegId::R28,
+            29 => RegId::R29,
+            30 => RegId::R30,
+            31 => RegId::R31,
             _ => panic!("invalid register code {:?}", id)
         }
     }
@@ -294,18 +335,21 @@ pub enum RawArg {
     Invalid
 }
 
+#[derive(Debug)]
+pub struct MemoryRef {
+    pub span: Span,
+    pub nosplit: bool,
+    pub size: Option<Size>,
+    pub disp_size: Option<Size>,
+    pub base: Option<Register>,
+    pub index: Option<(Register, isize, Option<syn::Expr>)>,
+    pub disp: Option<syn::Expr>
+}
+
 #[derive(Debug)]
 pub enum CleanArg {
     // memory reference
-    Indirect {
-        span: Span,
-        nosplit: bool,
-        size: Option<Size>,
-        disp_size: Option<Size>,
-        base: Option<Register>,
-        index: Option<(Register, isize, Option<syn::Expr>)>,
-        disp: Option<syn::Expr>
-    },
+    Indirect(Box<MemoryRef>),
     // direct register reference, 
     Direct {
         reg: Register
@@ -327,16 +371,20 @@ pub enum CleanArg {
     }
 }
 
+// size info is only kept around for EVEX compressed displacements
+#[derive(Debug)]
+pub struct SizedMemoryRef {
+    pub size: Size,
+    pub disp_size: Option<Size>,
+    pub base: Option<Register>,
+    pub index: Option<(Register, isize, Option<syn::Expr>)>,
+    pub disp: Option<syn::Expr>
+}
+
 #[derive(Debug)]
 pub enum SizedArg {
-    // memory reference. size info is lost here as
-    // it is never actually encoded
-    Indirect {
-        disp_size: Option<Size>,
-        base: Option<Register>,
-        index: Option<(Register, isize, Option<syn::Expr>)>,
-        disp: Option<syn::Expr>
-    },
+    // memory reference
+    Indirect(Box<SizedMemoryRef>),
     // direct register reference, 
     Direct {
         reg: Register
@@ -363,5 +411,40 @@ pub enum SizedArg {
 
 pub struct Instruction {
     pub span: Span,
-    pub idents: Vec<syn::Ident>
+    pub idents: Vec<syn::Ident>,
+    pub decorators: Decorators
+}
+
+/**
+ * AVX-512 operand decorators, and the APX default flags value
+ */
+
+#[derive(Debug, Default)]
+pub struct Decorators {
+    // {k1} on the first operand
+    pub mask: Option<Register>,
+    // {z} on the first operand
+    pub zeroing: bool,
+    // {1toN} on the memory operand
+    pub broadcast: Option<u8>,
+    // {rn-sae}, {rd-sae}, {ru-sae}, {rz-sae} or {sae} as separate operand
+    pub rounding: Option<Rounding>,
+    // {dfv=of, sf, zf, cf} before the operands of a conditional compare
+    pub dfv: Option<u8>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Nearest = 0,
+    Down = 1,
+    Up = 2,
+    Zero = 3,
+    SuppressOnly = 4
+}
+
+impl Decorators {
+    // checks for the absence of AVX-512 decorators. The default flags value is checked separately
+    pub fn is_empty(&self) -> bool {
+        self.mask.is_none() && !self.zeroing && self.broadcast.is_none() && self.rounding.is_none()
+    }
 }
@@ -5,9 +5,10 @@ use proc_macro_error2::emit_error;
 
 use crate::common::{Stmt, Size, Jump, JumpKind, delimited};
 use crate::serialize;
+use crate::parse_helpers::as_signed_number;
 
 use super::{Context, X86Mode};
-use super::ast::{RawArg, CleanArg, SizedArg, Instruction, MemoryRefItem, Register, RegKind, RegFamily, RegId};
+use super::ast::{RawArg, CleanArg, SizedArg, Instruction, MemoryRefItem, MemoryRef, SizedMemoryRef, Register, RegKind, RegFamily, RegId, Decorators, Rounding};
 use super::x64data::get_mnemnonic_data;
 use super::x64data::Flags;
 use super::x64data::Features;
@@ -93,6 +94,7 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
     let mut ops = instruction.idents;
     let op = ops.pop().unwrap();
     let prefixes = ops;
+    let decorators = instruction.decorators;
 
     // Fold RawArgs into CleanArgs
     let mut args = args.into_iter().map(clean_memoryref).collect::<Result<Vec<CleanArg>, _>>()?;
@@ -101,7 +103,8 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
     let addr_size = sanitize_indirects_and_sizes(ctx, &mut args)?;
     let addr_size = addr_size.unwrap_or(match ctx.mode {
         X86Mode::Long => Size::B_8,
-        X86Mode::Protected => Size::B_4
+        X86Mode::Protected => Size::B_4,
+        X86Mode::Real => Size::B_2
     });
 
     // determine if we need an address size override prefix
@@ -110,11 +113,13 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         (X86Mode::Long, Size::B_4) => true,
         (X86Mode::Protected, Size::B_4) => false,
         (X86Mode::Protected, Size::B_2) => true,
+        (X86Mode::Real, Size::B_2) => false,
+        (X86Mode::Real, Size::B_4) => true,
         _ => return Err(Some("Impossible address size".into()))
     };
 
     // find a matching op
-    let data = match_op_format(ctx, &op, &args)?;
+    let data = match_op_format(ctx, &op, &args, &decorators)?;
 
     // determine if the features required for this op are fulfilled
     if !ctx.features.contains(data.features) {
@@ -125,7 +130,17 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
     }
 
     // determine legacy prefixes
-    let (mut pref_mod, pref_seg) = get_legacy_prefixes(data, prefixes)?;
+    let (mut pref_mod, pref_seg, force_rex2) = get_legacy_prefixes(data, prefixes)?;
+
+    // check the usage of decorators, and apply the size of a broadcast memory operand
+    if data.flags.contains(Flags::EVEX_OP) {
+        check_decorators(data, &decorators, &mut args)?;
+    }
+
+    // AMX tile loads and stores use the index register of the SIB byte as stride
+    if data.flags.contains(Flags::SIB_MEM) && !args.iter().any(|a| matches!(a, CleanArg::Indirect(mem) if mem.index.is_some())) {
+        return Err(Some(format!("'{}': This instruction requires a memory reference with an index register", op)));
+    }
 
     // fill in size info from the format string to create the final SizedArg vec
     let (op_size, args) = size_operands(data, args)?;
@@ -133,6 +148,7 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
     let mut pref_size = false;
     let mut rex_w = false;
     let mut vex_l = false;
+    let mut evex_l = false;
 
     // determine if size prefixes are necessary
     if data.flags.intersects(Flags::AUTO_SIZE | Flags::AUTO_NO32 | Flags::AUTO_REXW | Flags::AUTO_VEXL) {
@@ -143,14 +159,19 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
             X86Mode::Protected => if op_size == Size::B_8 {
                 return Err(Some(format!("'{}': Does not support 64 bit operands in 32-bit mode", op)));
             },
+            X86Mode::Real => if op_size == Size::B_8 {
+                return Err(Some(format!("'{}': Does not support 64 bit operands in 16-bit mode", op)));
+            },
             X86Mode::Long => ()
         }
 
         if data.flags.contains(Flags::AUTO_NO32) {
             match (op_size, ctx.mode) {
+                (Size::B_2, X86Mode::Real) => (),
                 (Size::B_2, _) => pref_size = true,
                 (Size::B_8, X86Mode::Long) => (),
                 (Size::B_4, X86Mode::Protected) => (),
+                (Size::B_4, X86Mode::Real) => pref_size = true,
                 (Size::B_4, X86Mode::Long) => return Err(Some(format!("'{}': Does not support 32 bit operands in 64-bit mode", op))),
                 (_, _) => panic!("bad formatting data"),
             }
@@ -159,26 +180,49 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
                 rex_w = true;
             } else if op_size != Size::B_4 {
                 return Err(Some(format!("'{}': Does not support 16-bit operands", op)));
+            } else {
+                pref_size = ctx.mode == X86Mode::Real;
             }
         } else if data.flags.contains(Flags::AUTO_VEXL) {
             if op_size == Size::B_32 {
                 vex_l = true;
+            } else if op_size == Size::B_64 && data.flags.contains(Flags::EVEX_OP) {
+                evex_l = true;
             } else if op_size != Size::B_16 {
                 panic!("bad formatting data");
             }
+
+            // the 128-bit and 256-bit forms of AVX-512 instructions are part of AVX512VL
+            if data.flags.contains(Flags::EVEX_OP) && op_size != Size::B_64 {
+                if !ctx.features.contains(Features::AVX512VL) {
+                    return Err(Some(format!(
+                        "This instruction uses features that are not indicated to be available: {}",
+                        Features::AVX512VL - ctx.features
+                    )));
+                }
+                if decorators.rounding.is_some() {
+                    return Err(Some(format!("'{}': Rounding control is only supported with 512-bit operands", op)));
+                }
+            }
         } else if op_size == Size::B_2 {
-            pref_size = true;
+            pref_size = ctx.mode != X86Mode::Real;
         } else if op_size == Size::B_8 {
             rex_w = true;
-        } else if op_size != Size::B_4 {
+        } else if op_size == Size::B_4 {
+            pref_size = ctx.mode == X86Mode::Real;
+        } else {
             panic!("bad formatting data");
         }
     }
 
     // mandatory prefixes
-    let pref_size = pref_size || data.flags.contains(Flags::WORD_SIZE);
+    let pref_size = pref_size || data.flags.contains(Flags::PREF_66) || data.flags.contains(match ctx.mode {
+        X86Mode::Real => Flags::DWORD_SIZE,
+        _             => Flags::WORD_SIZE
+    });
     let rex_w     = rex_w     || data.flags.contains(Flags::WITH_REXW);
     let vex_l     = vex_l     || data.flags.contains(Flags::WITH_VEXL);
+    let evex_l    = evex_l    || data.flags.contains(Flags::WITH_EVEXL);
     let pref_addr = pref_addr || data.flags.contains(Flags::PREF_67);
 
     if        data.flags.contains(Flags::PREF_F0) { pref_mod = Some(0xF0);
@@ -189,6 +233,9 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
     // check if this combination of args can actually be encoded and whether a rex prefix is necessary
     let need_rex = check_rex(ctx, data, &args, rex_w)?;
 
+    // r16-r31 require a REX2 prefix to be encoded in legacy encoded instructions
+    let need_rex2 = check_rex2(ctx, data, &args, force_rex2)?;
+
     // split args
     let (mut rm, reg, vvvv, ireg, mut args) = extract_args(data, args);
 
@@ -207,8 +254,18 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         None
     };
 
+    // conditional compares encode their source condition code in the EVEX prefix
+    let source_cc = if data.flags.contains(Flags::APX_SCC) {
+        let (&scc, rest) = ops.split_last().expect("bad formatting data");
+        ops = rest;
+        Some((decorators.dfv.unwrap_or(0), scc))
+    } else {
+        None
+    };
+
     // shorthand
     let buffer = &mut ctx.state.stmts;
+    let broadcast = decorators.broadcast.is_some();
 
     // legacy-only prefixes
     if let Some(pref) = pref_seg {
@@ -218,8 +275,8 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         buffer.push(Stmt::u8(0x67));
     }
 
-    // VEX/XOP prefixes embed the operand size prefix / modification prefixes in them.
-    if data.flags.intersects(Flags::VEX_OP | Flags::XOP_OP) {
+    // VEX/XOP/EVEX prefixes embed the operand size prefix / modification prefixes in them.
+    if data.flags.intersects(Flags::VEX_OP | Flags::XOP_OP | Flags::EVEX_OP | Flags::APX_OP) {
         let prefix = if pref_size        { 0b01
         } else if pref_mod == Some(0xF3) { 0b10
         } else if pref_mod == Some(0xF2) { 0b11
@@ -228,7 +285,16 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         // map_sel is stored in the first byte of the opcode
         let (&map_sel, tail) = ops.split_first().expect("bad formatting data");
         ops = tail;
-        compile_vex_xop(ctx.mode, buffer, data, &reg, &rm, map_sel, rex_w, &vvvv, vex_l, prefix);
+        if data.flags.contains(Flags::APX_OP) {
+            let evex = EvexPrefix { map_sel, rex_w, prefix, vector_len: 0 };
+            compile_apx_evex(buffer, data, &reg, &rm, &vvvv, evex, source_cc);
+        } else if data.flags.contains(Flags::EVEX_OP) {
+            let vector_len = if evex_l { 2 } else if vex_l { 1 } else { 0 };
+            let evex = EvexPrefix { map_sel, rex_w, prefix, vector_len };
+            compile_evex(ctx.mode, buffer, &reg, &rm, &vvvv, evex, decorators);
+        } else {
+            compile_vex_xop(ctx.mode, buffer, data, &reg, &rm, map_sel, rex_w, &vvvv, vex_l, prefix);
+        }
     // otherwise, the size/mod prefixes have to be pushed and check if a rex prefix has to be generated.
     } else {
         if let Some(pref) = pref_mod {
@@ -237,11 +303,20 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         if pref_size {
             buffer.push(Stmt::u8(0x66));
         }
-        if need_rex {
+        if need_rex2 {
+            // REX2 replaces the 0x0F escape byte of opcode map 1
+            let map1 = ops[0] == 0x0F;
+            if map1 {
+                ops = &ops[1..];
+            }
+            compile_rex2(buffer, rex_w, map1, &reg, &rm);
+        } else if need_rex {
             // Certain SSE/AVX legacy encoded operations are not available in 32-bit mode
             // as they require a REX.W prefix to be encoded, which is impossible. We catch those cases here
-            if ctx.mode == X86Mode::Protected {
-                return Err(Some(format!("'{}': Does not support 64 bit operand size in 32-bit mode", op)))
+            match ctx.mode {
+                X86Mode::Protected => return Err(Some(format!("'{}': Does not support 64 bit operand size in 32-bit mode", op))),
+                X86Mode::Real => return Err(Some(format!("'{}': Does not support 64 bit operand size in 16-bit mode", op))),
+                X86Mode::Long => ()
             }
             compile_rex(buffer, rex_w, &reg, &rm);
         }
@@ -287,18 +362,35 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
 
         compile_modrm_sib(buffer, MOD_DIRECT, reg_k, rm.kind);
     // Indirect ModRM (+SIB) addressing
-    } else if let Some(SizedArg::Indirect {disp_size, base, index, disp, ..}) = rm {
+    } else if let Some(SizedArg::Indirect(mem)) = rm {
+        let SizedMemoryRef {size, disp_size, base, index, disp} = *mem;
         let reg_k = if let Some(SizedArg::Direct {reg, ..}) = reg {
             reg.kind
         } else {
             RegKind::from_number(data.reg)
         };
 
+        // EVEX encoded instructions scale 8-bit displacements by the size of the memory access.
+        // this only matters if an 8-bit displacement can be encoded at all (i.e. there's a base that isn't RIP)
+        let has_disp8 = base.as_ref().is_some_and(|b| b.kind.family() != RegFamily::RIP);
+        let (disp, disp_size) = if data.flags.contains(Flags::EVEX_OP) && has_disp8 {
+            let scale = if broadcast || data.flags.contains(Flags::T1S_32) {
+                if data.flags.contains(Flags::BCST_64) { 8 } else { 4 }
+            } else if data.flags.contains(Flags::T1S_64) {
+                8
+            } else {
+                size.in_bytes()
+            };
+            compress_displacement(disp, disp_size, scale)?
+        } else {
+            (disp, disp_size)
+        };
+
         // check addressing mode special cases
         let mode_vsib = index.as_ref().map_or(false, |(i, _, _)| i.kind.family() == RegFamily::XMM);
         let mode_16bit = addr_size == Size::B_2;
         let mode_rip_relative = base.as_ref().map_or(false, |b| b.kind.family() == RegFamily::RIP);
-        let mode_rbp_base = base.as_ref().map_or(false, |b| b == &RegId::RBP || b == &RegId::R13 || b.kind.is_dynamic());
+        let mode_rbp_base = base.as_ref().map_or(false, |b| b == &RegId::RBP || b == &RegId::R13 || b == &RegId::R21 || b == &RegId::R29 || b.kind.is_dynamic());
 
         if mode_vsib {
             let (index, scale, scale_expr) = index.unwrap();
@@ -336,21 +428,32 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
 
         } else if mode_16bit {
             // 16-bit mode: the index/base combination has been encoded in the base register.
-            // this register is guaranteed to be present.
-            let base_k = base.unwrap().kind;
-            let mode = match (&disp, disp_size) {
-                (&Some(_), Some(Size::BYTE)) => MOD_DISP8,
-                (&Some(_), _) => MOD_DISP32, // well, technically 16-bit.
-                (&None, _) => if mode_rbp_base {MOD_DISP8} else {MOD_NODISP}
-            };
+            // if no base is present, this is a plain 16-bit displacement which is encoded in the slot of [bp]
+            if let Some(base) = base {
+                // [bp] can only be encoded with a displacement
+                let base_k = base.kind;
+                let mode = match (&disp, disp_size) {
+                    (&Some(_), Some(Size::BYTE)) => MOD_DISP8,
+                    (&Some(_), _) => MOD_DISP32, // well, technically 16-bit.
+                    (&None, _) => if base_k == RegId::from_number(6) {MOD_DISP8} else {MOD_NODISP}
+                };
 
-            // only need a mod.r/m byte for 16-bit addressing
-            compile_modrm_sib(buffer, mode, reg_k, base_k);
+                // only need a mod.r/m byte for 16-bit addressing
+                compile_modrm_sib(buffer, mode, reg_k, base_k);
 
-            if let Some(disp) = disp {
-                buffer.push(Stmt::ExprSigned(delimited(disp), if mode == MOD_DISP8 {Size::BYTE} else {Size::B_2}));
-            } else if mode == MOD_DISP8 {
-                buffer.push(Stmt::u8(0));
+                if let Some(disp) = disp {
+                    buffer.push(Stmt::ExprSigned(delimited(disp), if mode == MOD_DISP8 {Size::BYTE} else {Size::B_2}));
+                } else if mode == MOD_DISP8 {
+                    buffer.push(Stmt::u8(0));
+                }
+            } else {
+                compile_modrm_sib(buffer, MOD_NODISP, reg_k, RegKind::Static(RegId::from_number(6)));
+
+                if let Some(disp) = disp {
+                    buffer.push(Stmt::ExprSigned(delimited(disp), Size::B_2));
+                } else {
+                    buffer.push(Stmt::u16(0));
+                }
             }
 
         } else if mode_rip_relative {
@@ -363,6 +466,7 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
                 } else {
                     buffer.push(Stmt::u32(0))
                 },
+                X86Mode::Real => return Err(Some(format!("'{}': RIP-relative addressing is not supported in 16-bit mode", op))),
                 X86Mode::Protected => {
                     // x86 doesn't actually allow RIP-relative addressing
                     // but we can work around it with relocations
@@ -413,7 +517,7 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
             // no base, no index. only disp. Easy in x86, but in x64 escape, use RBP as base and RSP as index
             } else {
                 match ctx.mode {
-                    X86Mode::Protected => {
+                    X86Mode::Protected | X86Mode::Real => {
                         compile_modrm_sib(buffer, mode, reg_k, RegKind::Static(RegId::RBP));
                     },
                     X86Mode::Long => {
@@ -440,13 +544,21 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         } else {
             RegKind::from_number(data.reg)
         };
+        if ctx.mode == X86Mode::Real {
+            return Err(Some(format!("'{}': Jump target memory references are not supported in 16-bit mode", op)));
+        }
         compile_modrm_sib(buffer, MOD_NODISP, reg_k, RegKind::Static(RegId::RBP));
 
         buffer.push(Stmt::u32(0));
         match ctx.mode {
             X86Mode::Long      => relocations.push((jump, 0, Size::B_4, RelocationKind::Relative)),
-            X86Mode::Protected => relocations.push((jump, 0, Size::B_4, RelocationKind::Absolute))
+            X86Mode::Protected => relocations.push((jump, 0, Size::B_4, RelocationKind::Absolute)),
+            X86Mode::Real      => unreachable!()
         }
+
+    // only a register in modrm.reg, modrm.r/m is fixed
+    } else if let Some(SizedArg::Direct {reg, ..}) = reg {
+        compile_modrm_sib(buffer, MOD_DIRECT, reg.kind, RegKind::from_number(data.reg));
     }
 
     // opcode encoded after the displacement
@@ -517,6 +629,9 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
                 relocations.iter_mut().for_each(|r| r.1 += size.in_bytes());
             },
             SizedArg::JumpTarget {jump, size} => {
+                // near jumps only have a 16-bit displacement in 16-bit mode
+                let size = if ctx.mode == X86Mode::Real && size == Size::B_4 { Size::B_2 } else { size };
+
                 // placeholder
                 buffer.push(Stmt::Const(0, size));
 
@@ -527,7 +642,8 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
                 if let JumpKind::Bare(_) = &jump.kind {
                     match ctx.mode {
                         X86Mode::Protected => relocations.push((jump, 0, size, RelocationKind::Extern)),
-                        X86Mode::Long => return Err(Some("Extern relocations are not supported in x64 mode".to_string()))
+                        X86Mode::Long => return Err(Some("Extern relocations are not supported in x64 mode".to_string())),
+                        X86Mode::Real => return Err(Some("Extern relocations are not supported in x86_16 mode".to_string()))
                     }
                 } else {
                     relocations.push((jump, 0, size, RelocationKind::Relative));
@@ -542,7 +658,7 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         let data = [size.in_bytes(), kind.to_id()];
         let data = match ctx.mode {
             X86Mode::Protected => &data,
-            X86Mode::Long      => &data[..1],
+            X86Mode::Long | X86Mode::Real => &data[..1],
         };
 
         // field offset has been tracked, and ref_offset is 0 as x86 offsets are relative to the end of the instruction
@@ -552,6 +668,110 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
     Ok(())
 }
 
+// Validates the decorators used with an EVEX encoded instruction, and changes the size of a broadcast memory
+// operand to the size of the full vector it expands to.
+fn check_decorators(fmt: &'static Opdata, decorators: &Decorators, args: &mut [CleanArg]) -> Result<(), Option<String>> {
+    if let Some(ref mask) = decorators.mask {
+        if mask.kind == RegId::K0 {
+            return Err(Some("'k0' cannot be used as writemask".to_string()));
+        }
+    } else if fmt.flags.contains(Flags::OPMASK_REQ) {
+        return Err(Some("This instruction requires a writemask".to_string()));
+    } else if decorators.zeroing {
+        return Err(Some("Zeroing-masking requires a writemask".to_string()));
+    }
+
+    if decorators.zeroing && matches!(args.first(), Some(CleanArg::Indirect(_) | CleanArg::IndirectJumpTarget {..})) {
+        return Err(Some("Zeroing-masking cannot be used with a memory destination".to_string()));
+    }
+
+    let has_memory = args.iter().any(|a| matches!(a, CleanArg::Indirect(_) | CleanArg::IndirectJumpTarget {..}));
+    if decorators.rounding.is_some() && has_memory {
+        return Err(Some("Rounding control cannot be used with a memory operand".to_string()));
+    }
+
+    if let Some(count) = decorators.broadcast {
+        for arg in args.iter_mut() {
+            let size = match *arg {
+                CleanArg::Indirect(ref mut mem) => &mut mem.size,
+                CleanArg::IndirectJumpTarget {ref mut size, ..} => size,
+                _ => continue
+            };
+            *size = broadcast_size(fmt, *size, count).map_err(|e| Some(e.to_string()))?;
+        }
+    }
+
+    Ok(())
+}
+
+// Determines the effective size of a memory operand broadcast from a single element to count elements
+fn broadcast_size(fmt: &Opdata, size: Option<Size>, count: u8) -> Result<Option<Size>, &'static str> {
+    let element = if fmt.flags.contains(Flags::BCST_32) {
+        Size::B_4
+    } else if fmt.flags.contains(Flags::BCST_64) {
+        Size::B_8
+    } else {
+        return Err("broadcast not supported");
+    };
+
+    if size.is_some_and(|s| s != element) {
+        return Err("broadcast element size mismatch");
+    }
+
+    match element.in_bytes() as usize * count as usize {
+        8  => Ok(Some(Size::B_8)),
+        16 => Ok(Some(Size::B_16)),
+        32 => Ok(Some(Size::B_32)),
+        64 => Ok(Some(Size::B_64)),
+        _  => Err("invalid broadcast size")
+    }
+}
+
+// EVEX encoded instructions use a compressed 8-bit displacement (disp8*N), which is scaled by the size of
+// the memory access. Compresses constant displacements where possible, and otherwise falls back to a
+// 32-bit displacement
+fn compress_displacement(disp: Option<syn::Expr>, disp_size: Option<Size>, scale: u8) -> Result<(Option<syn::Expr>, Option<Size>), Option<String>> {
+    let disp = match disp {
+        Some(disp) => disp,
+        None => return Ok((None, disp_size))
+    };
+
+    // strip any wrapping Group or Paren nodes due to delimiting
+    let mut inner = &disp;
+    loop {
+        inner = match inner {
+            syn::Expr::Group(syn::ExprGroup { expr, ..}) => expr,
+            syn::Expr::Paren(syn::ExprParen { expr, .. }) => expr,
+            _ => break
+        }
+    }
+
+    let value = match as_signed_number(inner) {
+        Some(value) => value,
+        None => if disp_size == Some(Size::BYTE) {
+            return Err(Some("8-bit displacements of EVEX encoded instructions have to be constant".to_string()));
+        } else {
+            return Ok((Some(disp), disp_size))
+        }
+    };
+
+    let scale = i64::from(scale);
+    if value % scale != 0 || value / scale > 0x7F || value / scale < -0x80 {
+        return Ok((Some(disp), None));
+    }
+
+    let compressed = value / scale;
+    let literal = Literal::u8_unsuffixed(compressed.unsigned_abs() as u8);
+    let span = disp.span();
+    let compressed = if compressed < 0 {
+        quote_spanned!{ span=> -#literal }
+    } else {
+        quote_spanned!{ span=> #literal }
+    };
+    let compressed = serialize::reparse(&delimited(compressed)).expect("Invalid expression generated internally");
+    Ok((Some(compressed), Some(Size::BYTE)))
+}
+
 // Folds RawArgs into CleanArgs by analyzing the different raw memoryref variants
 fn clean_memoryref(arg: RawArg) -> Result<CleanArg, Option<String>> {
     Ok(match arg {
@@ -631,7 +851,7 @@ fn clean_memoryref(arg: RawArg) -> Result<CleanArg, Option<String>> {
             let disp = disp.map(|d| serialize::reparse(&d).expect("Invalid expression generated internally"));
 
             // finalize the memoryref
-            CleanArg::Indirect {
+            CleanArg::Indirect(Box::new(MemoryRef {
                 span,
                 nosplit,
                 size: value_size,
@@ -639,7 +859,7 @@ fn clean_memoryref(arg: RawArg) -> Result<CleanArg, Option<String>> {
                 base,
                 index: index.map(|(r, s)| (r, s, None)),
                 disp,
-            }
+            }))
         },
         RawArg::TypeMappedRaw {span, base_reg, scale, value_size, nosplit, disp_size, scaled_items, attribute} => {
             let base = base_reg;
@@ -713,7 +933,7 @@ fn clean_memoryref(arg: RawArg) -> Result<CleanArg, Option<String>> {
             });
 
             // finalize the memoryref
-            CleanArg::Indirect {
+            CleanArg::Indirect(Box::new(MemoryRef {
                 span,
                 nosplit,
                 size: value_size,
@@ -721,7 +941,7 @@ fn clean_memoryref(arg: RawArg) -> Result<CleanArg, Option<String>> {
                 base: Some(base),
                 index,
                 disp,
-            }
+            }))
         },
     })
 }
@@ -735,7 +955,8 @@ fn sanitize_indirects_and_sizes(ctx: &Context, args: &mut [CleanArg]) -> Result<
 
     for arg in args.iter_mut() {
         match *arg {
-            CleanArg::Indirect {span, nosplit, ref mut disp_size, ref mut base, ref mut index, ref disp, ..} => {
+            CleanArg::Indirect(ref mut mem) => {
+                let MemoryRef {span, nosplit, ref mut disp_size, ref mut base, ref mut index, ref disp, ..} = **mem;
 
                 if encountered_indirect {
                     emit_error!(span, "Multiple memory references in a single instruction")
@@ -882,7 +1103,7 @@ fn sanitize_indirect(ctx: &Context, span: Span, nosplit: bool, base: &mut Option
         RegFamily::LEGACY => match size {
             Size::B_4 => (),
             Size::B_8 => (), // only valid in long mode, but should only be possible in long mode
-            Size::B_2  => if ctx.mode == X86Mode::Protected || vsib_mode {
+            Size::B_2  => if ctx.mode == X86Mode::Long || vsib_mode {
                 emit_error!(span, "16-bit addressing is not supported in this mode");
                 return Err(None);
             },
@@ -1011,8 +1232,9 @@ fn sanitize_indirect(ctx: &Context, span: Span, nosplit: bool, base: &mut Option
         }
     }
 
-    // RSP, R12 or a dynamic register as base without index (add an index so we escape into SIB)
-    if index.is_none() && (*base == RegId::RSP || *base == RegId::R12 || base.as_ref().map_or(false, |r| r.kind.is_dynamic())) {
+    // RSP, R12, R20, R28 or a dynamic register as base without index (add an index so we escape into SIB)
+    if index.is_none() && (*base == RegId::RSP || *base == RegId::R12 || *base == RegId::R20 || *base == RegId::R28 ||
+                           base.as_ref().map_or(false, |r| r.kind.is_dynamic())) {
         *index = Some((Register::new_static(size, RegId::RSP), 1, None));
     }
 
@@ -1020,7 +1242,7 @@ fn sanitize_indirect(ctx: &Context, span: Span, nosplit: bool, base: &mut Option
     Ok(Some(size))
 }
 
-fn match_op_format(ctx: &Context, ident: &syn::Ident, args: &[CleanArg]) -> Result<&'static Opdata, Option<String>> {
+fn match_op_format(ctx: &Context, ident: &syn::Ident, args: &[CleanArg], decorators: &Decorators) -> Result<&'static Opdata, Option<String>> {
     let name = ident.to_string();
     let name = name.as_str();
 
@@ -1031,24 +1253,80 @@ fn match_op_format(ctx: &Context, ident: &syn::Ident, args: &[CleanArg]) -> Resu
         return Err(None);
     };
 
+    // prefer the first matching format whose features are available. If none of them are available,
+    // the first match is returned so the missing features can be reported.
+    let mut first_match = None;
     for format in data {
-        if let Ok(()) = match_format_string(ctx, format, args) {
-            return Ok(format);
+        if let Ok(()) = match_format_string(ctx, format, args, decorators) {
+            if ctx.features.contains(format.features) {
+                return Ok(format);
+            }
+            first_match.get_or_insert(format);
         }
     }
 
+    if let Some(format) = first_match {
+        return Ok(format);
+    }
+
     Err(Some(
         format!("'{}': argument type/size mismatch, expected one of the following forms:\n{}", name, format_opdata_list(name, data))
     ))
 }
 
-fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result<(), &'static str> {
+fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg], decorators: &Decorators) -> Result<(), &'static str> {
     let fmtstr = &fmt.args;
 
-    if ctx.mode != X86Mode::Protected && fmt.flags.intersects(Flags::X86_ONLY) {
+    if ctx.mode == X86Mode::Long && fmt.flags.intersects(Flags::X86_ONLY) {
         return Err("Not available in 32-bit mode");
     }
 
+    if ctx.mode == X86Mode::Protected && fmt.flags.intersects(Flags::APX_OP) {
+        return Err("Only available in 64-bit mode");
+    }
+
+    if ctx.mode == X86Mode::Real && fmt.flags.intersects(Flags::VEX_OP | Flags::XOP_OP | Flags::EVEX_OP | Flags::APX_OP) {
+        return Err("Not available in 16-bit mode");
+    }
+
+    if decorators.dfv.is_some() && !fmt.flags.contains(Flags::APX_SCC) {
+        return Err("default flags value not supported");
+    }
+
+    if fmt.flags.contains(Flags::EVEX_OP) {
+        if decorators.mask.is_some() && !fmt.flags.intersects(Flags::OPMASK | Flags::OPMASK_REQ) {
+            return Err("masking not supported");
+        }
+        if decorators.zeroing && !fmt.flags.contains(Flags::ZEROING) {
+            return Err("zeroing not supported");
+        }
+        if decorators.broadcast.is_some() && !fmt.flags.intersects(Flags::BCST_32 | Flags::BCST_64) {
+            return Err("broadcast not supported");
+        }
+        match decorators.rounding {
+            Some(Rounding::SuppressOnly) if !fmt.flags.contains(Flags::SAE) => {
+                return Err("suppress all exceptions not supported");
+            },
+            Some(Rounding::SuppressOnly) | None => (),
+            Some(_) if !fmt.flags.contains(Flags::EMB_RND) => {
+                return Err("rounding control not supported");
+            },
+            Some(_) => ()
+        }
+    } else {
+        if !decorators.is_empty() {
+            return Err("decorators require an EVEX encoded instruction");
+        }
+        // xmm16-xmm31 can only be encoded using EVEX
+        if args.iter().any(uses_upper_vector_register) {
+            return Err("register requires an EVEX encoded instruction");
+        }
+        // r16-r31 cannot be encoded using VEX or XOP
+        if fmt.flags.intersects(Flags::VEX_OP | Flags::XOP_OP) && args.iter().any(uses_apx_register) {
+            return Err("register requires an EVEX encoded instruction");
+        }
+    }
+
     if fmtstr.len() != args.len() * 2 {
         return Err("argument length mismatch");
     }
@@ -1056,17 +1334,19 @@ fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result
     // o : instruction offset
 
     // m : memory
-    // k : vsib addressing, 32 bit result, size determines xmm or ymm
-    // l : vsib addressing, 64 bit result, size determines xmm or ymm
+    // k : vsib addressing, 32 bit result, size determines xmm, ymm or zmm
+    // l : vsib addressing, 64 bit result, size determines xmm, ymm or zmm
 
     // r : legacy reg
     // f : fp reg
     // x : mmx reg
-    // y : xmm/ymm reg
+    // y : xmm/ymm/zmm reg
     // s : segment reg
     // c : control reg
     // d : debug reg
     // b : bound reg
+    // j : opmask reg
+    // t : tile reg
 
     // v : r and m
     // u : x and m
@@ -1080,7 +1360,7 @@ fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result
     // b, w, d, q, o, h, t match a byte, word (2 bytes), doubleword (4 bytes), quadword (8 bytes), 16 bytes, 32 bytes and 64 bytes
     // p matches a PWORD (10 bytes)
     // f matches an FWORD (6 bytes)
-    // * matches all possible sizes for this operand (w/d for i, w/d/q for r/v, o/h for y/w and everything for m)
+    // * matches all possible sizes for this operand (w/d for i, w/d/q for r/v, o/h (and t for EVEX) for y/w and everything for m)
     // ! matches a lack of size, only useful in combination with m
     // ? matches any size and doesn't participate in the operand size calculation
     let mut args = args.iter();
@@ -1136,25 +1416,36 @@ fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result
                 reg.kind.family() == RegFamily::DEBUG => Some(reg.size()),
             (b'b', CleanArg::Direct{reg, ..}) if
                 reg.kind.family() == RegFamily::BOUND => Some(reg.size()),
+            (b'j', CleanArg::Direct{reg, ..}) if
+                reg.kind.family() == RegFamily::MASK => Some(reg.size()),
+            (b't', CleanArg::Direct{reg, ..}) if
+                reg.kind.family() == RegFamily::TILE => Some(reg.size()),
 
             // memory offsets
-            (b'm',          &CleanArg::Indirect {size, ref index, ..}) |
-            (b'u' ..= b'w', &CleanArg::Indirect {size, ref index, ..}) if
-                index.is_none() || index.as_ref().unwrap().0.kind.family() != RegFamily::XMM => size,
+            (b'm',          CleanArg::Indirect(mem)) |
+            (b'u' ..= b'w', CleanArg::Indirect(mem)) if
+                mem.index.is_none() || mem.index.as_ref().unwrap().0.kind.family() != RegFamily::XMM => mem.size,
 
             (b'm',          &CleanArg::IndirectJumpTarget {size, ..}) |
             (b'u' ..= b'w', &CleanArg::IndirectJumpTarget {size, ..}) => size,
 
             // vsib addressing. as they have two sizes that must be checked they check one of the sizes here
-            (b'k', &CleanArg::Indirect {size, index: Some((ref index, _, _)), ..}) if
-                (size.is_none() || size == Some(Size::B_4)) &&
-                index.kind.family() == RegFamily::XMM => Some(index.size()),
-            (b'l', &CleanArg::Indirect {size, index: Some((ref index, _, _)), ..}) if
-                (size.is_none() ||  size == Some(Size::B_8)) &&
-                index.kind.family() == RegFamily::XMM => Some(index.size()),
+            (b'k', CleanArg::Indirect(mem)) if
+                (mem.size.is_none() || mem.size == Some(Size::B_4)) &&
+                vsib_index(mem).is_some() => vsib_index(mem).map(|index| index.size()),
+            (b'l', CleanArg::Indirect(mem)) if
+                (mem.size.is_none() ||  mem.size == Some(Size::B_8)) &&
+                vsib_index(mem).is_some() => vsib_index(mem).map(|index| index.size()),
             _ => return Err("argument type mismatch")
         };
 
+        // a broadcast memory operand is matched on the size of the vector it is broadcast to
+        let size = match (arg, decorators.broadcast) {
+            (&CleanArg::Indirect(_), Some(count)) |
+            (&CleanArg::IndirectJumpTarget {..}, Some(count)) => broadcast_size(fmt, size, count)?,
+            _ => size
+        };
+
         // if size is none it always matches (and will later be coerced to a more specific type if the match is successful)
         if let Some(size) = size {
             if !match (fsize, code) {
@@ -1177,7 +1468,8 @@ fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result
                 (b'*', b'k') |
                 (b'*', b'l') |
                 (b'*', b'y') |
-                (b'*', b'w') => size == Size::B_16 || size == Size::B_32,
+                (b'*', b'w') => size == Size::B_16 || size == Size::B_32 ||
+                    (size == Size::B_64 && fmt.flags.contains(Flags::EVEX_OP)),
                 (b'*', b'r') |
                 (b'*', b'A' ..= b'P') |
                 (b'*', b'v') => size == Size::B_2 || size == Size::B_4 || size == Size::B_8,
@@ -1201,6 +1493,33 @@ fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result
     Ok(())
 }
 
+// checks if an argument uses one of xmm16-xmm31, which are only available to EVEX encoded instructions
+fn uses_upper_vector_register(arg: &CleanArg) -> bool {
+    match *arg {
+        CleanArg::Direct {ref reg} => reg.kind.family() == RegFamily::XMM && reg.kind.code().unwrap_or(0) > 15,
+        CleanArg::Indirect(ref mem) => vsib_index(mem).is_some_and(|reg| reg.kind.code().unwrap_or(0) > 15),
+        _ => false
+    }
+}
+
+// returns the index register of a memory reference if it uses VSIB addressing
+fn vsib_index(mem: &MemoryRef) -> Option<&Register> {
+    match mem.index {
+        Some((ref reg, _, _)) if reg.kind.family() == RegFamily::XMM => Some(reg),
+        _ => None
+    }
+}
+
+fn uses_apx_register(arg: &CleanArg) -> bool {
+    match *arg {
+        CleanArg::Direct {ref reg} => reg.kind.is_apx_extended(),
+        CleanArg::Indirect(ref mem) =>
+            mem.base.as_ref().is_some_and(|reg| reg.kind.is_apx_extended()) ||
+            mem.index.as_ref().is_some_and(|(reg, _, _)| reg.kind.is_apx_extended()),
+        _ => false
+    }
+}
+
 fn size_operands(fmt: &Opdata, args: Vec<CleanArg>) -> Result<(Option<Size>, Vec<SizedArg>), Option<String>> {
     // sizing operands requires two passes.
     // In the first one, we determine the effective operand size if necessary (if *'s are present)
@@ -1234,14 +1553,10 @@ fn size_operands(fmt: &Opdata, args: Vec<CleanArg>) -> Result<(Option<Size>, Vec
                     op_size = Some(size);
                 }
             }
-            CleanArg::Indirect {mut size, ref index, ..} => {
+            CleanArg::Indirect(ref mem) => {
                 has_arg = true;
                 // VSIB addressing
-                if let Some((ref reg, _, _)) = *index {
-                    if reg.kind.family() == RegFamily::XMM {
-                        size = Some(reg.size());
-                    }
-                }
+                let size = vsib_index(mem).map_or(mem.size, |reg| Some(reg.size()));
 
                 if let Some(size) = size {
                     if op_size.map_or(false, |s| s != size) {
@@ -1288,9 +1603,11 @@ fn size_operands(fmt: &Opdata, args: Vec<CleanArg>) -> Result<(Option<Size>, Vec
             (b'p', _) => Size::B_10,
             (b'o', _) => Size::B_16,
             (b'h', _) => Size::B_32,
+            (b't', _) => Size::B_64,
             (b'*', b'i') => im_size.unwrap(),
             (b'*', _) => op_size.unwrap(),
-            (b'!', _) => Size::BYTE, // will never be used, placeholder
+            (b'!', _) |
+            (b'?', _) => Size::BYTE, // will never be used, placeholder
             _ => unreachable!()
         };
 
@@ -1303,21 +1620,32 @@ fn size_operands(fmt: &Opdata, args: Vec<CleanArg>) -> Result<(Option<Size>, Vec
                 SizedArg::IndirectJumpTarget {jump},
             CleanArg::Immediate {value, ..} =>
                 SizedArg::Immediate {value, size},
-            CleanArg::Indirect {disp_size, base, index, disp, ..} =>
-                SizedArg::Indirect {disp_size, base, index, disp},
+            CleanArg::Indirect(mem) => {
+                let MemoryRef {disp_size, base, index, disp, ..} = *mem;
+                SizedArg::Indirect(Box::new(SizedMemoryRef {size, disp_size, base, index, disp}))
+            },
         });
     }
 
     Ok((op_size, new_args))
 }
 
-fn get_legacy_prefixes(fmt: &'static Opdata, idents: Vec<syn::Ident>) -> Result<(Option<u8>, Option<u8>), Option<String>> {
+fn get_legacy_prefixes(fmt: &'static Opdata, idents: Vec<syn::Ident>) -> Result<(Option<u8>, Option<u8>, bool), Option<String>> {
     let mut group1 = None;
     let mut group2 = None;
+    let mut rex2 = false;
 
     for prefix in idents {
         let name = prefix.to_string();
         let (group, value) = match name.as_str() {
+            // not an actual prefix, this requests encoding with a REX2 prefix, which is validated later
+            "rex2"  => if rex2 {
+                emit_error!(prefix, "Duplicate prefix rex2");
+                return Err(None);
+            } else {
+                rex2 = true;
+                continue;
+            },
             "rep"   => if fmt.flags.contains(Flags::REP) {
                 (&mut group1, 0xF3)
             } else {
@@ -1359,18 +1687,25 @@ fn get_legacy_prefixes(fmt: &'static Opdata, idents: Vec<syn::Ident>) -> Result<
         *group = Some(value);
     }
 
-    Ok((group1, group2))
+    Ok((group1, group2, rex2))
 }
 
 fn check_rex(ctx: &Context, fmt: &'static Opdata, args: &[SizedArg], rex_w: bool) -> Result<bool, Option<String>> {
     // performs checks for not encodable arg combinations
     // output arg indicates if a rex prefix can be encoded
     if ctx.mode == X86Mode::Protected {
-        if rex_w {
+        // VEX/XOP/EVEX prefixes can encode the W bit in 32-bit mode
+        if rex_w && !fmt.flags.intersects(Flags::VEX_OP | Flags::XOP_OP | Flags::EVEX_OP) {
             return Err(Some("Does not support 64 bit operand size in 32-bit mode".to_string()));
         } else {
             return Ok(false);
         }
+    } else if ctx.mode == X86Mode::Real {
+        if rex_w {
+            return Err(Some("Does not support 64 bit operand size in 16-bit mode".to_string()));
+        } else {
+            return Ok(false);
+        }
     }
 
     let mut requires_rex    = rex_w;
@@ -1389,7 +1724,8 @@ fn check_rex(ctx: &Context, fmt: &'static Opdata, args: &[SizedArg], rex_w: bool
                         requires_rex = true;
                     }
                 },
-                SizedArg::Indirect {ref base, ref index, ..} => {
+                SizedArg::Indirect(ref mem) => {
+                    let SizedMemoryRef {ref base, ref index, ..} = **mem;
                     if let Some(ref reg) = *base {
                         requires_rex = requires_rex || reg.kind.is_extended();
                     }
@@ -1409,6 +1745,83 @@ fn check_rex(ctx: &Context, fmt: &'static Opdata, args: &[SizedArg], rex_w: bool
     }
 }
 
+fn check_rex2(ctx: &Context, fmt: &'static Opdata, args: &[SizedArg], force_rex2: bool) -> Result<bool, Option<String>> {
+    // determines if r16-r31 are used, and if a REX2 prefix is necessary and possible to encode them
+    let mut uses_apx_regs = false;
+    let mut uses_high_byte = false;
+
+    for (arg, (c, _)) in args.iter().zip(FormatStringIterator::new(fmt.args)) {
+        // only scan args that are actually encoded
+        if let b'a' ..= b'z' = c {
+            match *arg {
+                SizedArg::Direct {ref reg, ..} => {
+                    uses_apx_regs = uses_apx_regs || reg.kind.is_apx_extended();
+                    uses_high_byte = uses_high_byte || reg.kind.family() == RegFamily::HIGHBYTE;
+                },
+                SizedArg::Indirect(ref mem) => {
+                    let SizedMemoryRef {ref base, ref index, ..} = **mem;
+                    if let Some(ref reg) = *base {
+                        uses_apx_regs = uses_apx_regs || reg.kind.is_apx_extended();
+                    }
+                    if let Some((ref reg, _, _)) = *index {
+                        uses_apx_regs = uses_apx_regs || reg.kind.is_apx_extended();
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+
+    if fmt.flags.contains(Flags::APX_OP) && uses_high_byte {
+        return Err(Some("High byte registers cannot be used with an extended EVEX prefix".to_string()));
+    }
+
+    if !uses_apx_regs && !force_rex2 {
+        return Ok(false);
+    }
+
+    if !ctx.features.contains(Features::APX) {
+        return Err(Some(format!(
+            "This instruction uses features that are not indicated to be available: {}",
+            Features::APX - ctx.features
+        )));
+    }
+
+    if ctx.mode != X86Mode::Long {
+        return Err(Some("The rex2 prefix is only available in 64-bit mode".to_string()));
+    }
+
+    // EVEX prefixes can encode r16-r31 by themselves, VEX/XOP prefixes can't encode them at all
+    if fmt.flags.intersects(Flags::VEX_OP | Flags::XOP_OP | Flags::EVEX_OP | Flags::APX_OP) {
+        if force_rex2 {
+            return Err(Some("Cannot use prefix rex2 on this instruction".to_string()));
+        } else if fmt.flags.intersects(Flags::VEX_OP | Flags::XOP_OP) {
+            return Err(Some("Registers r16-r31 cannot be used with VEX or XOP encoded instructions".to_string()));
+        }
+        return Ok(false);
+    }
+
+    if uses_high_byte {
+        return Err(Some("High byte register combined with a REX2 prefix".to_string()));
+    }
+
+    // REX2 only supports opcode maps 0 and 1, and excludes some opcodes in them
+    let possible = match *fmt.ops {
+        [0x0F, 0x0F, ..] |
+        [0x0F, 0x38, ..] |
+        [0x0F, 0x3A, ..] => false,
+        [0x0F, op, ..] => !matches!(op, 0x30 ..= 0x3F | 0x80 ..= 0x8F),
+        [op, ..] => !matches!(op, 0x70 ..= 0x7F | 0xA0 ..= 0xA3 | 0xE0 ..= 0xE3),
+        [] => false
+    };
+
+    if !possible {
+        return Err(Some("This instruction cannot be encoded using a REX2 prefix".to_string()));
+    }
+
+    Ok(true)
+}
+
 fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>, Option<SizedArg>, Option<SizedArg>, Option<SizedArg>, Vec<SizedArg>) {
     // way operand order works:
 
@@ -1417,9 +1830,10 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
 
     // default argument encoding order is as follows:
     // no encoding flag: m, rm, rvm, rvim
-    // ENC_MR:              mr, rmv, rvmi
+    // ENC_MR:           r, mr, rmv, rvmi
     // ENC_VM:              vm, mvr
     // these can also be chosen based on the location of a memory argument (except for vm)
+    // APX_ND:           the first operand is encoded in vvvv, the others follow the above rules
 
     let mut memarg = None;
     let mut regarg = None;
@@ -1434,7 +1848,7 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
                 memarg = Some(regs.len());
                 regs.push(arg)
             },
-            b'f' | b'x' | b'r' | b'y' | b'b' => regs.push(arg),
+            b'f' | b'x' | b'r' | b'y' | b'b' | b'j' | b't' => regs.push(arg),
             b'c' | b'd' | b's'        => if regarg.is_some() {
                 panic!("multiple segment, debug or control registers in format string");
             } else {
@@ -1457,6 +1871,13 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
     let mut v = None;
     let mut i = None;
 
+    let (len, memarg) = if fmt.flags.contains(Flags::APX_ND) {
+        v = regs.next();
+        (len - 1, memarg.map(|m| m - 1))
+    } else {
+        (len, memarg)
+    };
+
     if let Some(i) = regarg {
         if i == 0 {
             r = regs.next();
@@ -1466,7 +1887,11 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
             r = regs.next();
         }
     } else if len == 1 {
-        m = regs.next();
+        if fmt.flags.contains(Flags::ENC_MR) {
+            r = regs.next();
+        } else {
+            m = regs.next();
+        }
     } else if len == 2 {
         if fmt.flags.contains(Flags::ENC_MR) || memarg == Some(0) {
             m = regs.next();
@@ -1530,7 +1955,8 @@ fn compile_rex(buffer: &mut Vec<Stmt>, rex_w: bool, reg: &Option<SizedArg>, rm:
     if let Some(SizedArg::Direct {ref reg, ..}) = *rm {
         base_k = reg.kind.clone();
     }
-    if let Some(SizedArg::Indirect {ref base, ref index, ..} ) = *rm {
+    if let Some(SizedArg::Indirect(ref mem)) = *rm {
+        let SizedMemoryRef {ref base, ref index, ..} = **mem;
         if let Some(ref base) = *base {
             base_k = base.kind.clone();
         }
@@ -1588,6 +2014,84 @@ fn compile_rex(buffer: &mut Vec<Stmt>, rex_w: bool, reg: &Option<SizedArg>, rm:
     }), Size::BYTE));
 }
 
+fn compile_rex2(buffer: &mut Vec<Stmt>, rex_w: bool, map1: bool, reg: &Option<SizedArg>, rm: &Option<SizedArg>) {
+    let mut reg_k   = RegKind::from_number(0);
+    let mut index_k = RegKind::from_number(0);
+    let mut base_k  = RegKind::from_number(0);
+
+    if let Some(SizedArg::Direct {ref reg, ..}) = *reg {
+        reg_k = reg.kind.clone();
+    }
+    if let Some(SizedArg::Direct {ref reg, ..}) = *rm {
+        base_k = reg.kind.clone();
+    }
+    if let Some(SizedArg::Indirect(ref mem)) = *rm {
+        let SizedMemoryRef {ref base, ref index, ..} = **mem;
+        if let Some(ref base) = *base {
+            base_k = base.kind.clone();
+        }
+        if let Some((ref index, _, _)) = *index {
+            index_k = index.kind.clone();
+        }
+    }
+
+    // M0 R4 X4 B4 W R3 X3 B3
+    let rex2 = (map1             as u8) << 7 |
+               (reg_k.encode()   & 0x10) << 2 |
+               (index_k.encode() & 0x10) << 1 |
+               (base_k.encode()  & 0x10)      |
+               (rex_w            as u8) << 3 |
+               (reg_k.encode()   & 8) >> 1 |
+               (index_k.encode() & 8) >> 2 |
+               (base_k.encode()  & 8) >> 3 ;
+
+    buffer.push(Stmt::u8(0xD5));
+
+    if !reg_k.is_dynamic() && !index_k.is_dynamic() && !base_k.is_dynamic() {
+        buffer.push(Stmt::u8(rex2));
+        return;
+    }
+
+    let mut dyn_regs = Vec::new();
+    let mut dyn_items = Vec::new();
+
+    if let RegKind::Dynamic(_, expr) = reg_k {
+        let expr = delimited(expr);
+        dyn_regs.push(quote_spanned! { expr.span()=>
+            let _dyn_reg: u8 = #expr.into();
+        });
+        dyn_items.push(quote_spanned! { expr.span()=>
+            ((_dyn_reg & 0x10) << 2) | ((_dyn_reg & 8) >> 1)
+        });
+    }
+    if let RegKind::Dynamic(_, expr) = index_k {
+        let expr = delimited(expr);
+        dyn_regs.push(quote_spanned! { expr.span()=>
+            let _dyn_index: u8 = #expr.into();
+        });
+        dyn_items.push(quote_spanned! { expr.span()=>
+            ((_dyn_index & 0x10) << 1) | ((_dyn_index & 8) >> 2)
+        });
+    }
+    if let RegKind::Dynamic(_, expr) = base_k {
+        let expr = delimited(expr);
+        dyn_regs.push(quote_spanned! { expr.span()=>
+            let _dyn_base: u8 = #expr.into();
+        });
+        dyn_items.push(quote_spanned! { expr.span()=>
+            (_dyn_base & 0x10) | ((_dyn_base & 8) >> 3)
+        });
+    }
+
+    let rex2 = Literal::u8_suffixed(rex2);
+    buffer.push(Stmt::ExprUnsigned(delimited(quote! {
+        {
+            #( #dyn_regs )*
+            #rex2 #( | #dyn_items )*
+        }
+    }), Size::BYTE));
+}
+
 fn compile_vex_xop(mode: X86Mode, buffer: &mut Vec<Stmt>, data: &'static Opdata, reg: &Option<SizedArg>,
 rm: &Option<SizedArg>, map_sel: u8, rex_w: bool, vvvv: &Option<SizedArg>, vex_l: bool, prefix: u8) {
     let mut reg_k   = RegKind::from_number(0);
@@ -1603,7 +2107,8 @@ rm: &Option<SizedArg>, map_sel: u8, rex_w: bool, vvvv: &Option<SizedArg>, vex_l:
             if let Some(SizedArg::Direct {ref reg, ..}) = *rm {
                 base_k = reg.kind.clone();
             }
-            if let Some(SizedArg::Indirect {ref base, ref index, ..}) = *rm {
+            if let Some(SizedArg::Indirect(ref mem)) = *rm {
+                let SizedMemoryRef {ref base, ref index, ..} = **mem;
                 if let Some(ref base) = *base {
                     base_k = base.kind.clone();
                 }
@@ -1617,7 +2122,7 @@ rm: &Option<SizedArg>, map_sel: u8, rex_w: bool, vvvv: &Option<SizedArg>, vex_l:
             (!index_k.encode() & 8) << 3 |
             (!base_k.encode()  & 8) << 2
         },
-        X86Mode::Protected => {
+        X86Mode::Protected | X86Mode::Real => {
             (map_sel & 0x1f) | 0xE0
         }
     };
@@ -1733,6 +2238,376 @@ rm: &Option<SizedArg>, map_sel: u8, rex_w: bool, vvvv: &Option<SizedArg>, vex_l:
     }
 }
 
+// the fields of an EVEX prefix that are determined by the instruction, rather than by its operands
+#[derive(Debug, Clone, Copy)]
+struct EvexPrefix {
+    map_sel: u8,
+    rex_w: bool,
+    // the implied legacy prefix, encoded like in VEX
+    prefix: u8,
+    // L'L
+    vector_len: u8,
+}
+
+fn compile_evex(mode: X86Mode, buffer: &mut Vec<Stmt>, reg: &Option<SizedArg>, rm: &Option<SizedArg>,
+vvvv: &Option<SizedArg>, evex: EvexPrefix, decorators: Decorators) {
+    let EvexPrefix { map_sel, rex_w, prefix, vector_len } = evex;
+    let mut reg_k   = RegKind::from_number(0);
+    let mut index_k = RegKind::from_number(0);
+    let mut base_k  = RegKind::from_number(0);
+    let mut vvvv_k  = RegKind::from_number(0);
+    let mut rm_direct = false;
+    let mut vsib = false;
+
+    if let Some(SizedArg::Direct {ref reg, ..}) = *reg {
+        reg_k = reg.kind.clone();
+    }
+    if let Some(SizedArg::Direct {ref reg, ..}) = *rm {
+        base_k = reg.kind.clone();
+        rm_direct = true;
+    }
+    if let Some(SizedArg::Indirect(ref mem)) = *rm {
+        let SizedMemoryRef {ref base, ref index, ..} = **mem;
+        if let Some(ref base) = *base {
+            base_k = base.kind.clone();
+        }
+        if let Some((ref index, _, _)) = *index {
+            index_k = index.kind.clone();
+            vsib = index.kind.family() == RegFamily::XMM;
+        }
+    }
+    if let Some(SizedArg::Direct {ref reg, ..}) = *vvvv {
+        vvvv_k = reg.kind.clone();
+    }
+    let rm_vector = rm_direct && base_k.family() != RegFamily::LEGACY;
+
+    // P0: R X B R' B4 m m m
+    // In protected mode, the R, X, B and R' bits cannot be used. Also, a vector register as r/m uses the X bit
+    // as its fifth bit instead of B4.
+    let byte1 = match mode {
+        X86Mode::Long => {
+            (map_sel             & 0x07)      |
+            (!reg_k.encode()     & 0x08) << 4 |
+            (!reg_k.encode()     & 0x10)      |
+            (!base_k.encode()    & 0x08) << 2 |
+            if rm_vector {
+                (!base_k.encode()  & 0x10) << 2
+            } else {
+                (!index_k.encode() & 0x08) << 3 |
+                (base_k.encode()   & 0x10) >> 1
+            }
+        },
+        X86Mode::Protected | X86Mode::Real => {
+            (map_sel & 0x07) | 0xF0
+        }
+    };
+
+    // P1: W v v v v X4 p p
+    // X4 is the fifth bit of a general purpose index register, and unused otherwise
+    let byte2 = (prefix           & 0x3)      |
+                (rex_w            as u8) << 7 |
+                (!vvvv_k.encode() & 0xF) << 3 |
+                if vsib {
+                    0x04
+                } else {
+                    (!index_k.encode() & 0x10) >> 2
+                };
+
+    // P2: z L' L b V' a a a
+    // The V' bit extends either vvvv or the VSIB index register.
+    let (vector_len, bcst_rc) = match decorators.rounding {
+        Some(Rounding::SuppressOnly) => (0, true),
+        Some(rounding) => (rounding as u8, true),
+        None => (vector_len, decorators.broadcast.is_some())
+    };
+    let v_high = if vsib { &index_k } else { &vvvv_k };
+    let mask_k = decorators.mask.map(|mask| mask.kind).unwrap_or_else(|| RegKind::from_number(0));
+
+    let byte3 = (decorators.zeroing as u8) << 7 |
+                (vector_len     & 0x3)      << 5 |
+                (bcst_rc           as u8)   << 4 |
+                (!v_high.encode() & 0x10)   >> 1 |
+                (mask_k.encode()  & 0x7);
+
+    buffer.push(Stmt::u8(0x62));
+
+    if mode == X86Mode::Long && (reg_k.is_dynamic() || index_k.is_dynamic() || base_k.is_dynamic()) {
+        let mut dyn_regs = Vec::new();
+        let mut dyn_items = Vec::new();
+        let mut high_items = Vec::new();
+
+        if let RegKind::Dynamic(_, ref expr) = reg_k {
+            let expr = delimited(expr);
+            dyn_regs.push(quote_spanned! { expr.span()=>
+                let _dyn_reg: u8 = #expr.into();
+            });
+            dyn_items.push(quote_spanned! { expr.span()=>
+                !(((_dyn_reg & 8) << 4) | (_dyn_reg & 0x10))
+            });
+        }
+        if let RegKind::Dynamic(_, ref expr) = index_k {
+            let expr = delimited(expr);
+            dyn_regs.push(quote_spanned! { expr.span()=>
+                let _dyn_index: u8 = #expr.into();
+            });
+            dyn_items.push(quote_spanned! { expr.span()=>
+                !((_dyn_index & 8) << 3)
+            });
+        }
+        if let RegKind::Dynamic(_, ref expr) = base_k {
+            let expr = delimited(expr);
+            dyn_regs.push(quote_spanned! { expr.span()=>
+                let _dyn_base: u8 = #expr.into();
+            });
+            if rm_vector {
+                dyn_items.push(quote_spanned! { expr.span()=>
+                    !(((_dyn_base & 8) << 2) | ((_dyn_base & 0x10) << 2))
+                });
+            } else {
+                dyn_items.push(quote_spanned! { expr.span()=>
+                    !((_dyn_base & 8) << 2)
+                });
+                high_items.push(quote_spanned! { expr.span()=>
+                    ((_dyn_base & 0x10) >> 1)
+                });
+            }
+        }
+
+        let byte1 = Literal::u8_suffixed(byte1);
+        buffer.push(Stmt::ExprUnsigned(delimited(quote! {
+            {
+                #( #dyn_regs )*
+                (#byte1 #( & #dyn_items )*) #( | #high_items )*
+            }
+        }), Size::BYTE));
+    } else {
+        buffer.push(Stmt::u8(byte1));
+    }
+
+    if vvvv_k.is_dynamic() || (index_k.is_dynamic() && !vsib) {
+        let mut dyn_regs = Vec::new();
+        let mut dyn_items = Vec::new();
+
+        if let RegKind::Dynamic(_, ref expr) = vvvv_k {
+            let expr = delimited(expr);
+            dyn_regs.push(quote_spanned! { expr.span()=>
+                let _dyn_vvvv: u8 = #expr.into();
+            });
+            dyn_items.push(quote_spanned! { expr.span()=>
+                !((_dyn_vvvv & 0xF) << 3)
+            });
+        }
+        if let (RegKind::Dynamic(_, ref expr), false) = (&index_k, vsib) {
+            let expr = delimited(expr);
+            dyn_regs.push(quote_spanned! { expr.span()=>
+                let _dyn_index: u8 = #expr.into();
+            });
+            dyn_items.push(quote_spanned! { expr.span()=>
+                !((_dyn_index & 0x10) >> 2)
+            });
+        }
+
+        let byte2 = Literal::u8_suffixed(byte2);
+        buffer.push(Stmt::ExprUnsigned(delimited(quote! {
+            {
+                #( #dyn_regs )*
+                #byte2 #( & #dyn_items )*
+            }
+        }), Size::BYTE));
+    } else {
+        buffer.push(Stmt::u8(byte2));
+    }
+
+    if v_high.is_dynamic() || mask_k.is_dynamic() {
+        let mut dyn_regs = Vec::new();
+        let mut high_item = None;
+        let mut mask_item = None;
+
+        if let RegKind::Dynamic(_, ref expr) = *v_high {
+            let expr = delimited(expr);
+            dyn_regs.push(quote_spanned! { expr.span()=>
+                let _dyn_vvvv: u8 = #expr.into();
+            });
+            high_item = Some(quote_spanned! { expr.span()=>
+                & !((_dyn_vvvv & 0x10) >> 1)
+            });
+        }
+        if let RegKind::Dynamic(_, ref expr) = mask_k {
+            let expr = delimited(expr);
+            dyn_regs.push(quote_spanned! { expr.span()=>
+                let _dyn_mask: u8 = #expr.into();
+            });
+            mask_item = Some(quote_spanned! { expr.span()=>
+                | (_dyn_mask & 7)
+            });
+        }
+
+        let byte3 = Literal::u8_suffixed(byte3);
+        buffer.push(Stmt::ExprUnsigned(delimited(quote! {
+            {
+                #( #dyn_regs )*
+                (#byte3 #high_item) #mask_item
+            }
+        }), Size::BYTE));
+    } else {
+        buffer.push(Stmt::u8(byte3));
+    }
+}
+
+fn compile_apx_evex(buffer: &mut Vec<Stmt>, data: &'static Opdata, reg: &Option<SizedArg>, rm: &Option<SizedArg>,
+vvvv: &Option<SizedArg>, evex: EvexPrefix, source_cc: Option<(u8, u8)>) {
+    let EvexPrefix { map_sel, rex_w, prefix, .. } = evex;
+    let mut reg_k   = RegKind::from_number(0);
+    let mut index_k = RegKind::from_number(0);
+    let mut base_k  = RegKind::from_number(0);
+    let mut vvvv_k  = RegKind::from_number(0);
+
+    if let Some(SizedArg::Direct {ref reg, ..}) = *reg {
+        reg_k = reg.kind.clone();
+    }
+    if let Some(SizedArg::Direct {ref reg, ..}) = *rm {
+        base_k = reg.kind.clone();
+    }
+    if let Some(SizedArg::Indirect(ref mem)) = *rm {
+        let SizedMemoryRef {ref base, ref index, ..} = **mem;
+        if let Some(ref base) = *base {
+            base_k = base.kind.clone();
+        }
+        if let Some((ref index, _, _)) = *index {
+            index_k = index.kind.clone();
+        }
+    }
+    if let Some(SizedArg::Direct {ref reg, ..}) = *vvvv {
+        vvvv_k = reg.kind.clone();
+    }
+
+    // P0: R X B R4 B4 m m m
+    let byte1 = (map_sel          & 0x07)      |
+                (!reg_k.encode()   & 0x08) << 4 |
+                (!index_k.encode() & 0x08) << 3 |
+                (!base_k.encode()  & 0x08) << 2 |
+                (!reg_k.encode()   & 0x10)      |
+                (base_k.encode()   & 0x10) >> 1;
+
+    // P1: W v v v v X4 p p
+    // conditional compares store their default flags value (OF SF ZF CF) in vvvv
+    let vvvv_bits = if let Some((dfv, _)) = source_cc {
+        dfv & 0xF
+    } else {
+        !vvvv_k.encode() & 0xF
+    };
+    let byte2 = (prefix             & 0x3)      |
+                (rex_w              as u8) << 7 |
+                vvvv_bits                  << 3 |
+                (!index_k.encode()  & 0x10) >> 2;
+
+    // P2: 0 0 0 ND V4 NF 0 0
+    // conditional compares store their source condition code in the lower 4 bits instead
+    let byte3 = if let Some((_, scc)) = source_cc {
+        scc & 0xF
+    } else {
+        (data.flags.contains(Flags::APX_ND) as u8) << 4 |
+        (!vvvv_k.encode()         & 0x10)    >> 1 |
+        (data.flags.contains(Flags::APX_NF) as u8) << 2
+    };
+
+    buffer.push(Stmt::u8(0x62));
+
+    if reg_k.is_dynamic() || index_k.is_dynamic() || base_k.is_dynamic() {
+        let mut dyn_regs = Vec::new();
+        let mut dyn_items = Vec::new();
+        let mut high_items = Vec::new();
+
+        if let RegKind::Dynamic(_, ref expr) = reg_k {
+            let expr = delimited(expr);
+            dyn_regs.push(quote_spanned! { expr.span()=>
+                let _dyn_reg: u8 = #expr.into();
+            });
+            dyn_items.push(quote_spanned! { expr.span()=>
+                !(((_dyn_reg & 8) << 4) | (_dyn_reg & 0x10))
+            });
+        }
+        if let RegKind::Dynamic(_, ref expr) = index_k {
+            let expr = delimited(expr);
+            dyn_regs.push(quote_spanned! { expr.span()=>
+                let _dyn_index: u8 = #expr.into();
+            });
+            dyn_items.push(quote_spanned! { expr.span()=>
+                !((_dyn_index & 8) << 3)
+            });
+        }
+        if let RegKind::Dynamic(_, ref expr) = base_k {
+            let expr = delimited(expr);
+            dyn_regs.push(quote_spanned! { expr.span()=>
+                let _dyn_base: u8 = #expr.into();
+            });
+            dyn_items.push(quote_spanned! { expr.span()=>
+                !((_dyn_base & 8) << 2)
+            });
+            high_items.push(quote_spanned! { expr.span()=>
+                ((_dyn_base & 0x10) >> 1)
+            });
+        }
+
+        let byte1 = Literal::u8_suffixed(byte1);
+        buffer.push(Stmt::ExprUnsigned(delimited(quote! {
+            {
+                #( #dyn_regs )*
+                (#byte1 #( & #dyn_items )*) #( | #high_items )*
+            }
+        }), Size::BYTE));
+    } else {
+        buffer.push(Stmt::u8(byte1));
+    }
+
+    if vvvv_k.is_dynamic() || index_k.is_dynamic() {
+        let mut dyn_regs = Vec::new();
+        let mut dyn_items = Vec::new();
+
+        if let RegKind::Dynamic(_, ref expr) = vvvv_k {
+            let expr = delimited(expr);
+            dyn_regs.push(quote_spanned! { expr.span()=>
+                let _dyn_vvvv: u8 = #expr.into();
+            });
+            dyn_items.push(quote_spanned! { expr.span()=>
+                !((_dyn_vvvv & 0xF) << 3)
+            });
+        }
+        if let RegKind::Dynamic(_, ref expr) = index_k {
+            let expr = delimited(expr);
+            dyn_regs.push(quote_spanned! { expr.span()=>
+                let _dyn_index: u8 = #expr.into();
+            });
+            dyn_items.push(quote_spanned! { expr.span()=>
+                !((_dyn_index & 0x10) >> 2)
+            });
+        }
+
+        let byte2 = Literal::u8_suffixed(byte2);
+        buffer.push(Stmt::ExprUnsigned(delimited(quote! {
+            {
+                #( #dyn_regs )*
+                #byte2 #( & #dyn_items )*
+            }
+        }), Size::BYTE));
+    } else {
+        buffer.push(Stmt::u8(byte2));
+    }
+
+    if let RegKind::Dynamic(_, ref expr) = vvvv_k {
+        let byte3 = Literal::u8_suffixed(byte3);
+        let expr = delimited(expr);
+        buffer.push(Stmt::ExprUnsigned(delimited(quote! {
+            {
+                let _dyn_vvvv: u8 = #expr.into();
+                #byte3 & !((_dyn_vvvv & 0x10) >> 1)
+            }
+        }), Size::BYTE));
+    } else {
+        buffer.push(Stmt::u8(byte3));
+    }
+}
+
 fn compile_modrm_sib(buffer: &mut Vec<Stmt>, mode: u8, reg1: RegKind, reg2: RegKind) {
     let byte = mode                << 6 |
               (reg1.encode()  & 7) << 3 |
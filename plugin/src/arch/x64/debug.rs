@@ -15,6 +15,7 @@ pub fn format_opdata(name: &str, data: &Opdata) -> Vec<String> {
     let opsizes = if data.flags.contains(Flags::AUTO_SIZE) {&b"qwd"[..]}
              else if data.flags.contains(Flags::AUTO_NO32) {&b"qw"[..]}
              else if data.flags.contains(Flags::AUTO_REXW) {&b"qd"[..]}
+             else if data.flags.contains(Flags::AUTO_VEXL) && data.flags.contains(Flags::EVEX_OP) {&b"tho"[..]}
              else if data.flags.contains(Flags::AUTO_VEXL) {&b"ho"[..]}
              else if name == "monitorx"                    {&b"qwd"[..]}
              else                                          {&b"!"[..]};
@@ -24,7 +25,11 @@ pub fn format_opdata(name: &str, data: &Opdata) -> Vec<String> {
         let mut buf = String::new();
         buf.push_str(">>> ");
         buf.push_str(name);
+        if data.flags.contains(Flags::APX_SCC) {
+            buf.push_str(" {dfv=}");
+        }
         let mut first = true;
+        let mut first_arg = true;
         for (ty, size) in FormatStringIterator::new(data.args) {
             if first {
                 buf.push(' ');
@@ -32,7 +37,32 @@ pub fn format_opdata(name: &str, data: &Opdata) -> Vec<String> {
             } else {
                 buf.push_str(", ");
             }
-            buf.push_str(&format_arg(ty, size, opsize))
+            if ty == b'm' && data.flags.contains(Flags::SIB_MEM) {
+                buf.push_str("sibmem");
+            } else {
+                buf.push_str(&format_arg(ty, size, opsize));
+            }
+
+            // AVX-512 decorators
+            if first_arg && data.flags.intersects(Flags::OPMASK | Flags::OPMASK_REQ) {
+                buf.push_str(" {k}");
+                if data.flags.contains(Flags::ZEROING) {
+                    buf.push_str("{z}");
+                }
+            }
+            first_arg = false;
+            if let b'm' | b'w' = ty {
+                if data.flags.contains(Flags::BCST_32) {
+                    buf.push_str("/m32bcst");
+                } else if data.flags.contains(Flags::BCST_64) {
+                    buf.push_str("/m64bcst");
+                }
+            }
+        }
+        if data.flags.contains(Flags::EMB_RND) {
+            buf.push_str(", {er}");
+        } else if data.flags.contains(Flags::SAE) {
+            buf.push_str(", {sae}");
         }
         if data.flags.contains(Flags::X86_ONLY) {
             for _ in buf.len() .. 45 {
@@ -88,14 +118,16 @@ fn format_arg(ty: u8, mut size: u8, opsize: u8) -> Cow<'static, str> {
         b'r' => format!("reg{}",      format_size(size)).into(),
         b'f' => "st".into(),
         b'x' => "mm".into(),
-        b'y' => (if size == b'h' {"ymm"} else {"xmm"}).into(),
+        b'y' => (if size == b't' {"zmm"} else if size == b'h' {"ymm"} else {"xmm"}).into(),
         b's' => "segreg".into(),
         b'c' => "creg".into(),
         b'd' => "dreg".into(),
         b'b' => "bndreg".into(),
+        b'j' => "k".into(),
+        b't' => "tmm".into(),
         b'v' => format!("reg/mem{}", format_size(size)).into(),
         b'u' => format!("mm/mem{}", format_size(size)).into(),
-        b'w' => format!("{}mm/mem{}", if size == b'h' {"y"} else {"x"}, format_size(size)).into(),
+        b'w' => format!("{}mm/mem{}", if size == b't' {"z"} else if size == b'h' {"y"} else {"x"}, format_size(size)).into(),
         b'A'..=b'P' => {
             let i = ty as usize - 'A' as usize;
             match size {
@@ -27,6 +27,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 2, AUTO_SIZE ;
     b"r*r*"       , [0x11              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x13              ], X, AUTO_SIZE;
+    b"rbvbrb"     , [0x04, 0x10        ], X, APX_OP | APX_ND | ENC_MR, APX;
+    b"rbrbvb"     , [0x04, 0x12        ], X, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0x80        ], 2, APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 2, AUTO_SIZE | EXACT_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 2, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*r*"     , [0x04, 0x11        ], X, AUTO_SIZE | APX_OP | APX_ND | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x13        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "adcx" = [
     b"rqvq"       , [0x0F, 0x38, 0xF6  ], X, WITH_REXW | PREF_66;
@@ -46,6 +53,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 0, AUTO_SIZE ;
     b"r*r*"       , [0x01              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x03              ], X, AUTO_SIZE;
+    b"rbvbrb"     , [0x04, 0x00        ], X, APX_OP | APX_ND | ENC_MR, APX;
+    b"rbrbvb"     , [0x04, 0x02        ], X, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0x80        ], 0, APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 0, AUTO_SIZE | EXACT_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 0, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*r*"     , [0x04, 0x01        ], X, AUTO_SIZE | APX_OP | APX_ND | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x03        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "addpd" = [
     b"yowo"       , [0x0F, 0x58        ], X, PREF_66, SSE2;
@@ -103,6 +117,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 4, AUTO_SIZE ;
     b"r*r*"       , [0x21              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x23              ], X, AUTO_SIZE;
+    b"rbvbrb"     , [0x04, 0x20        ], X, APX_OP | APX_ND | ENC_MR, APX;
+    b"rbrbvb"     , [0x04, 0x22        ], X, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0x80        ], 4, APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 4, AUTO_SIZE | EXACT_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 4, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*r*"     , [0x04, 0x21        ], X, AUTO_SIZE | APX_OP | APX_ND | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x23        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "andn" = [
     b"r*r*v*"     , [0x02, 0xF2        ], X, VEX_OP | AUTO_REXW, BMI1;
@@ -240,7 +261,7 @@ Ops!(
     b""           , [0x98              ], X, WORD_SIZE;
 ]
 "cdq" = [
-    b""           , [0x99              ], X;
+    b""           , [0x99              ], X, DWORD_SIZE;
 ]
 "cdqe" = [
     b""           , [0x98              ], X, WITH_REXW;
@@ -257,15 +278,24 @@ Ops!(
 "clflush" = [
     b"mb"         , [0x0F, 0xAE        ], 7, DEFAULT, SSE2;
 ]
+"clflushopt" = [
+    b"mb"         , [0x0F, 0xAE        ], 7, PREF_66, CLFLUSHOPT;
+]
 "clgi" = [
     b""           , [0x0F, 0x01, 0xDD  ], X, DEFAULT, VMX | AMD;
 ]
 "cli" = [
     b""           , [0xFA              ], X;
 ]
+"clrssbsy" = [
+    b"mq"         , [0x0F, 0xAE        ], 6, PREF_F3, CET;
+]
 "clts" = [
     b""           , [0x0F, 0x06        ], X;
 ]
+"clwb" = [
+    b"mb"         , [0x0F, 0xAE        ], 6, PREF_66, CLWB;
+]
 "clzero" = [
     b""           , [0x0F, 0x01, 0xFC  ], X, DEFAULT, AMD;
 ]
@@ -392,7 +422,7 @@ Ops!(
     b""           , [0xA6              ], X, REPE;
 ]
 "cmpsd" = [
-    b""           , [0xA7              ], X, REPE;
+    b""           , [0xA7              ], X, REPE | DWORD_SIZE;
     b"yowoib"     , [0x0F, 0xC2        ], X, PREF_F2, SSE2;
 ]
 "cmpsq" = [
@@ -541,7 +571,7 @@ Ops!(
     b""           , [0x99              ], X, WORD_SIZE;
 ]
 "cwde" = [
-    b""           , [0x98              ], X;
+    b""           , [0x98              ], X, DWORD_SIZE;
 ]
 "daa" = [
     b""           , [0x27              ], X, X86_ONLY;
@@ -553,8 +583,10 @@ Ops!(
     b"mb"         , [0xFE              ], 1, LOCK;
     b"rb"         , [0xFE              ], 1;
     b"m*"         , [0xFF              ], 1, AUTO_SIZE | LOCK;
-    b"r*"         , [0x48              ], 0, X86_ONLY | SHORT_ARG;
+    b"r*"         , [0x48              ], 0, X86_ONLY | AUTO_SIZE | SHORT_ARG;
     b"r*"         , [0xFF              ], 1, AUTO_SIZE ;
+    b"rbvb"       , [0x04, 0xFE        ], 1, APX_OP | APX_ND, APX;
+    b"r*v*"       , [0x04, 0xFF        ], 1, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "div" = [
     b"vb"         , [0xF6              ], 6;
@@ -588,6 +620,18 @@ Ops!(
 "emms" = [
     b""           , [0x0F, 0x77        ], X, DEFAULT, MMX;
 ]
+"endbr32" = [
+    b""           , [0x0F, 0x1E, 0xFB  ], X, PREF_F3, CET;
+]
+"endbr64" = [
+    b""           , [0x0F, 0x1E, 0xFA  ], X, PREF_F3, CET;
+]
+"enqcmd" = [
+    b"rqm!"       , [0x0F, 0x38, 0xF8  ], X, PREF_F2, ENQCMD;
+]
+"enqcmds" = [
+    b"rqm!"       , [0x0F, 0x38, 0xF8  ], X, PREF_F3, ENQCMD;
+]
 "enter" = [
     b"iwib"       , [0xC8              ], X;
 ]
@@ -1027,6 +1071,15 @@ Ops!(
 "getsec" = [
     b""           , [0x0F, 0x37        ], X;
 ]
+"gf2p8affineinvqb" = [
+    b"yowoib"     , [0x0F, 0x3A, 0xCF  ], X, PREF_66, GFNI;
+]
+"gf2p8affineqb" = [
+    b"yowoib"     , [0x0F, 0x3A, 0xCE  ], X, PREF_66, GFNI;
+]
+"gf2p8mulb" = [
+    b"yowo"       , [0x0F, 0x38, 0xCF  ], X, PREF_66, GFNI;
+]
 "haddpd" = [
     b"yowo"       , [0x0F, 0x7C        ], X, PREF_66, SSE3;
 ]
@@ -1053,14 +1106,22 @@ Ops!(
     b"mb"         , [0xFE              ], 0, LOCK;
     b"rb"         , [0xFE              ], 0;
     b"m*"         , [0xFF              ], 0, AUTO_SIZE | LOCK;
-    b"r*"         , [0x40              ], 0, X86_ONLY | SHORT_ARG;
+    b"r*"         , [0x40              ], 0, X86_ONLY | AUTO_SIZE | SHORT_ARG;
     b"r*"         , [0xFF              ], 0, AUTO_SIZE ;
+    b"rbvb"       , [0x04, 0xFE        ], 0, APX_OP | APX_ND, APX;
+    b"r*v*"       , [0x04, 0xFF        ], 0, AUTO_SIZE | APX_OP | APX_ND, APX;
+]
+"incsspd" = [
+    b"rd"         , [0x0F, 0xAE        ], 5, PREF_F3, CET;
+]
+"incsspq" = [
+    b"rq"         , [0x0F, 0xAE        ], 5, WITH_REXW | PREF_F3, CET;
 ]
 "insb" = [
     b""           , [0x6C              ], X, REP;
 ]
 "insd" = [
-    b""           , [0x6D              ], X, REP;
+    b""           , [0x6D              ], X, REP | DWORD_SIZE;
 ]
 "insertps" = [
     b"yomdib"     , [0x0F, 0x3A, 0x21  ], X, PREF_66, SSE41;
@@ -1114,7 +1175,7 @@ Ops!(
     b""           , [0xCF              ], X;
 ]
 "iretd" = [
-    b""           , [0xCF              ], X;
+    b""           , [0xCF              ], X, DWORD_SIZE;
 ]
 "iretq" = [
     b""           , [0xCF              ], X, WITH_REXW;
@@ -1128,6 +1189,175 @@ Ops!(
 "jrcxz" = [
     b"ob"         , [0xE3              ], X;
 ]
+"kaddb" = [
+    b"jqjqjq"     , [0x01, 0x4A        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX512DQ;
+]
+"kaddd" = [
+    b"jqjqjq"     , [0x01, 0x4A        ], X, VEX_OP | WITH_VEXL | WITH_REXW | PREF_66, AVX512BW;
+]
+"kaddq" = [
+    b"jqjqjq"     , [0x01, 0x4A        ], X, VEX_OP | WITH_VEXL | WITH_REXW, AVX512BW;
+]
+"kaddw" = [
+    b"jqjqjq"     , [0x01, 0x4A        ], X, VEX_OP | WITH_VEXL, AVX512DQ;
+]
+"kandb" = [
+    b"jqjqjq"     , [0x01, 0x41        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX512DQ;
+]
+"kandd" = [
+    b"jqjqjq"     , [0x01, 0x41        ], X, VEX_OP | WITH_VEXL | WITH_REXW | PREF_66, AVX512BW;
+]
+"kandnb" = [
+    b"jqjqjq"     , [0x01, 0x42        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX512DQ;
+]
+"kandnd" = [
+    b"jqjqjq"     , [0x01, 0x42        ], X, VEX_OP | WITH_VEXL | WITH_REXW | PREF_66, AVX512BW;
+]
+"kandnq" = [
+    b"jqjqjq"     , [0x01, 0x42        ], X, VEX_OP | WITH_VEXL | WITH_REXW, AVX512BW;
+]
+"kandnw" = [
+    b"jqjqjq"     , [0x01, 0x42        ], X, VEX_OP | WITH_VEXL, AVX512F;
+]
+"kandq" = [
+    b"jqjqjq"     , [0x01, 0x41        ], X, VEX_OP | WITH_VEXL | WITH_REXW, AVX512BW;
+]
+"kandw" = [
+    b"jqjqjq"     , [0x01, 0x41        ], X, VEX_OP | WITH_VEXL, AVX512F;
+]
+"kmovb" = [
+    b"jqjq"       , [0x01, 0x90        ], X, VEX_OP | PREF_66, AVX512DQ;
+    b"jqmb"       , [0x01, 0x90        ], X, VEX_OP | PREF_66, AVX512DQ;
+    b"mbjq"       , [0x01, 0x91        ], X, VEX_OP | PREF_66, AVX512DQ;
+    b"jqrd"       , [0x01, 0x92        ], X, VEX_OP | PREF_66, AVX512DQ;
+    b"rdjq"       , [0x01, 0x93        ], X, VEX_OP | PREF_66, AVX512DQ;
+]
+"kmovd" = [
+    b"jqjq"       , [0x01, 0x90        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+    b"jqmd"       , [0x01, 0x90        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+    b"mdjq"       , [0x01, 0x91        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+    b"jqrd"       , [0x01, 0x92        ], X, VEX_OP | PREF_F2, AVX512BW;
+    b"rdjq"       , [0x01, 0x93        ], X, VEX_OP | PREF_F2, AVX512BW;
+]
+"kmovq" = [
+    b"jqjq"       , [0x01, 0x90        ], X, VEX_OP | WITH_REXW, AVX512BW;
+    b"jqmq"       , [0x01, 0x90        ], X, VEX_OP | WITH_REXW, AVX512BW;
+    b"mqjq"       , [0x01, 0x91        ], X, VEX_OP | WITH_REXW, AVX512BW;
+    b"jqrq"       , [0x01, 0x92        ], X, VEX_OP | WITH_REXW | PREF_F2, AVX512BW;
+    b"rqjq"       , [0x01, 0x93        ], X, VEX_OP | WITH_REXW | PREF_F2, AVX512BW;
+]
+"kmovw" = [
+    b"jqjq"       , [0x01, 0x90        ], X, VEX_OP, AVX512F;
+    b"jqmw"       , [0x01, 0x90        ], X, VEX_OP, AVX512F;
+    b"mwjq"       , [0x01, 0x91        ], X, VEX_OP, AVX512F;
+    b"jqrd"       , [0x01, 0x92        ], X, VEX_OP, AVX512F;
+    b"rdjq"       , [0x01, 0x93        ], X, VEX_OP, AVX512F;
+]
+"knotb" = [
+    b"jqjq"       , [0x01, 0x44        ], X, VEX_OP | PREF_66, AVX512DQ;
+]
+"knotd" = [
+    b"jqjq"       , [0x01, 0x44        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+]
+"knotq" = [
+    b"jqjq"       , [0x01, 0x44        ], X, VEX_OP | WITH_REXW, AVX512BW;
+]
+"knotw" = [
+    b"jqjq"       , [0x01, 0x44        ], X, VEX_OP, AVX512F;
+]
+"korb" = [
+    b"jqjqjq"     , [0x01, 0x45        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX512DQ;
+]
+"kord" = [
+    b"jqjqjq"     , [0x01, 0x45        ], X, VEX_OP | WITH_VEXL | WITH_REXW | PREF_66, AVX512BW;
+]
+"korq" = [
+    b"jqjqjq"     , [0x01, 0x45        ], X, VEX_OP | WITH_VEXL | WITH_REXW, AVX512BW;
+]
+"kortestb" = [
+    b"jqjq"       , [0x01, 0x98        ], X, VEX_OP | PREF_66, AVX512DQ;
+]
+"kortestd" = [
+    b"jqjq"       , [0x01, 0x98        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+]
+"kortestq" = [
+    b"jqjq"       , [0x01, 0x98        ], X, VEX_OP | WITH_REXW, AVX512BW;
+]
+"kortestw" = [
+    b"jqjq"       , [0x01, 0x98        ], X, VEX_OP, AVX512F;
+]
+"korw" = [
+    b"jqjqjq"     , [0x01, 0x45        ], X, VEX_OP | WITH_VEXL, AVX512F;
+]
+"kshiftlb" = [
+    b"jqjqib"     , [0x03, 0x32        ], X, VEX_OP | PREF_66, AVX512DQ;
+]
+"kshiftld" = [
+    b"jqjqib"     , [0x03, 0x33        ], X, VEX_OP | PREF_66, AVX512BW;
+]
+"kshiftlq" = [
+    b"jqjqib"     , [0x03, 0x33        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+]
+"kshiftlw" = [
+    b"jqjqib"     , [0x03, 0x32        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512F;
+]
+"kshiftrb" = [
+    b"jqjqib"     , [0x03, 0x30        ], X, VEX_OP | PREF_66, AVX512DQ;
+]
+"kshiftrd" = [
+    b"jqjqib"     , [0x03, 0x31        ], X, VEX_OP | PREF_66, AVX512BW;
+]
+"kshiftrq" = [
+    b"jqjqib"     , [0x03, 0x31        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+]
+"kshiftrw" = [
+    b"jqjqib"     , [0x03, 0x30        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512F;
+]
+"ktestb" = [
+    b"jqjq"       , [0x01, 0x99        ], X, VEX_OP | PREF_66, AVX512DQ;
+]
+"ktestd" = [
+    b"jqjq"       , [0x01, 0x99        ], X, VEX_OP | WITH_REXW | PREF_66, AVX512BW;
+]
+"ktestq" = [
+    b"jqjq"       , [0x01, 0x99        ], X, VEX_OP | WITH_REXW, AVX512BW;
+]
+"ktestw" = [
+    b"jqjq"       , [0x01, 0x99        ], X, VEX_OP, AVX512DQ;
+]
+"kunpckbw" = [
+    b"jqjqjq"     , [0x01, 0x4B        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX512F;
+]
+"kunpckdq" = [
+    b"jqjqjq"     , [0x01, 0x4B        ], X, VEX_OP | WITH_VEXL | WITH_REXW, AVX512BW;
+]
+"kunpckwd" = [
+    b"jqjqjq"     , [0x01, 0x4B        ], X, VEX_OP | WITH_VEXL, AVX512BW;
+]
+"kxnorb" = [
+    b"jqjqjq"     , [0x01, 0x46        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX512DQ;
+]
+"kxnord" = [
+    b"jqjqjq"     , [0x01, 0x46        ], X, VEX_OP | WITH_VEXL | WITH_REXW | PREF_66, AVX512BW;
+]
+"kxnorq" = [
+    b"jqjqjq"     , [0x01, 0x46        ], X, VEX_OP | WITH_VEXL | WITH_REXW, AVX512BW;
+]
+"kxnorw" = [
+    b"jqjqjq"     , [0x01, 0x46        ], X, VEX_OP | WITH_VEXL, AVX512F;
+]
+"kxorb" = [
+    b"jqjqjq"     , [0x01, 0x47        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX512DQ;
+]
+"kxord" = [
+    b"jqjqjq"     , [0x01, 0x47        ], X, VEX_OP | WITH_VEXL | WITH_REXW | PREF_66, AVX512BW;
+]
+"kxorq" = [
+    b"jqjqjq"     , [0x01, 0x47        ], X, VEX_OP | WITH_VEXL | WITH_REXW, AVX512BW;
+]
+"kxorw" = [
+    b"jqjqjq"     , [0x01, 0x47        ], X, VEX_OP | WITH_VEXL, AVX512F;
+]
 "lahf" = [
     b""           , [0x9F              ], X;
 ]
@@ -1144,6 +1374,9 @@ Ops!(
 "lds" = [
     b"r*m!"       , [0xC5              ], X, AUTO_SIZE | X86_ONLY;
 ]
+"ldtilecfg" = [
+    b"m!"         , [0x02, 0x49        ], 0, VEX_OP, AMX_TILE;
+]
 "lea" = [
     b"r*m!"       , [0x8D              ], X, AUTO_SIZE;
 ]
@@ -1183,7 +1416,7 @@ Ops!(
     b""           , [0xAC              ], X, REP;
 ]
 "lodsd" = [
-    b""           , [0xAD              ], X, REP;
+    b""           , [0xAD              ], X, REP | DWORD_SIZE;
 ]
 "lodsq" = [
     b""           , [0xAD              ], X, WITH_REXW | REP;
@@ -1395,7 +1628,7 @@ Ops!(
     b""           , [0xA4              ], X, REP;
 ]
 "movsd" = [
-    b""           , [0xA5              ], X, REP;
+    b""           , [0xA5              ], X, REP | DWORD_SIZE;
     b"mqyo"       , [0x0F, 0x11        ], X, ENC_MR | PREF_F2, SSE2;
     b"yomq"       , [0x0F, 0x10        ], X, PREF_F2, SSE2;
     b"yoyo"       , [0x0F, 0x10        ], X, PREF_F2, SSE2;
@@ -1482,6 +1715,8 @@ Ops!(
     b"rb"         , [0xF6              ], 3;
     b"m*"         , [0xF7              ], 3, AUTO_SIZE | LOCK;
     b"r*"         , [0xF7              ], 3, AUTO_SIZE ;
+    b"rbvb"       , [0x04, 0xF6        ], 3, APX_OP | APX_ND, APX;
+    b"r*v*"       , [0x04, 0xF7        ], 3, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "nop" = [
     b""           , [0x90              ], X;
@@ -1492,6 +1727,8 @@ Ops!(
     b"rb"         , [0xF6              ], 2;
     b"m*"         , [0xF7              ], 2, AUTO_SIZE | LOCK;
     b"r*"         , [0xF7              ], 2, AUTO_SIZE ;
+    b"rbvb"       , [0x04, 0xF6        ], 2, APX_OP | APX_ND, APX;
+    b"r*v*"       , [0x04, 0xF7        ], 2, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "or" = [
     b"Abib"       , [0x0C              ], X;
@@ -1508,6 +1745,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 1, AUTO_SIZE ;
     b"r*r*"       , [0x09              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x0B              ], X, AUTO_SIZE;
+    b"rbvbrb"     , [0x04, 0x08        ], X, APX_OP | APX_ND | ENC_MR, APX;
+    b"rbrbvb"     , [0x04, 0x0A        ], X, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0x80        ], 1, APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 1, AUTO_SIZE | EXACT_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 1, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*r*"     , [0x04, 0x09        ], X, AUTO_SIZE | APX_OP | APX_ND | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x0B        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "orpd" = [
     b"yowo"       , [0x0F, 0x56        ], X, PREF_66, SSE2;
@@ -1519,7 +1763,7 @@ Ops!(
     b""           , [0x6E              ], X, REP;
 ]
 "outsd" = [
-    b""           , [0x6F              ], X, REP;
+    b""           , [0x6F              ], X, REP | DWORD_SIZE;
 ]
 "outsw" = [
     b""           , [0x6F              ], X, WORD_SIZE | REP;
@@ -2017,11 +2261,17 @@ Ops!(
     b"r*"         , [0x58              ], X, AUTO_NO32 | SHORT_ARG;
     b"v*"         , [0x8F              ], 0, AUTO_NO32;
 ]
+"pop2" = [
+    b"rqrq"       , [0x04, 0x8F        ], 0, APX_OP | APX_ND, APX;
+]
+"pop2p" = [
+    b"rqrq"       , [0x04, 0x8F        ], 0, WITH_REXW | APX_OP | APX_ND, APX;
+]
 "popa" = [
     b""           , [0x61              ], X, X86_ONLY | WORD_SIZE;
 ]
 "popad" = [
-    b""           , [0x61              ], X, X86_ONLY;
+    b""           , [0x61              ], X, X86_ONLY | DWORD_SIZE;
 ]
 "popcnt" = [
     b"r*v*"       , [0x0F, 0xB8        ], X, AUTO_SIZE | PREF_F3;
@@ -2231,15 +2481,21 @@ Ops!(
     b"Vw"         , [0x0F, 0xA8        ], X;
     b"ib"         , [0x6A              ], X, EXACT_SIZE;
     b"iw"         , [0x68              ], X, EXACT_SIZE | WORD_SIZE;
-    b"id"         , [0x68              ], X;
+    b"id"         , [0x68              ], X, DWORD_SIZE;
     b"r*"         , [0x50              ], X, AUTO_NO32 | SHORT_ARG;
     b"v*"         , [0xFF              ], 6, AUTO_NO32;
 ]
+"push2" = [
+    b"rqrq"       , [0x04, 0xFF        ], 6, APX_OP | APX_ND, APX;
+]
+"push2p" = [
+    b"rqrq"       , [0x04, 0xFF        ], 6, WITH_REXW | APX_OP | APX_ND, APX;
+]
 "pusha" = [
     b""           , [0x60              ], X, X86_ONLY | WORD_SIZE;
 ]
 "pushad" = [
-    b""           , [0x60              ], X, X86_ONLY;
+    b""           , [0x60              ], X, X86_ONLY | DWORD_SIZE;
 ]
 "pushf" = [
     b""           , [0x9C              ], X;
@@ -2259,6 +2515,10 @@ Ops!(
     b"vbib"       , [0xC0              ], 2;
     b"v*Bb"       , [0xD3              ], 2, AUTO_SIZE;
     b"v*ib"       , [0xC1              ], 2, AUTO_SIZE;
+    b"rbvbBb"     , [0x04, 0xD2        ], 2, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0xC0        ], 2, APX_OP | APX_ND, APX;
+    b"r*v*Bb"     , [0x04, 0xD3        ], 2, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0xC1        ], 2, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "rcpps" = [
     b"yowo"       , [0x0F, 0x53        ], X, DEFAULT, SSE;
@@ -2272,6 +2532,10 @@ Ops!(
     b"vbib"       , [0xC0              ], 3;
     b"v*Bb"       , [0xD3              ], 3, AUTO_SIZE;
     b"v*ib"       , [0xC1              ], 3, AUTO_SIZE;
+    b"rbvbBb"     , [0x04, 0xD2        ], 3, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0xC0        ], 3, APX_OP | APX_ND, APX;
+    b"r*v*Bb"     , [0x04, 0xD3        ], 3, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0xC1        ], 3, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "rdfsbase" = [
     b"rd"         , [0x0F, 0xAE        ], 0, PREF_F3;
@@ -2308,6 +2572,12 @@ Ops!(
 "rdshr" = [
     b"vd"         , [0x0F, 0x36        ], 0, DEFAULT, CYRIX;
 ]
+"rdsspd" = [
+    b"rd"         , [0x0F, 0x1E        ], 1, PREF_F3, CET;
+]
+"rdsspq" = [
+    b"rq"         , [0x0F, 0x1E        ], 1, WITH_REXW | PREF_F3, CET;
+]
 "rdtsc" = [
     b""           , [0x0F, 0x31        ], X;
 ]
@@ -2331,12 +2601,20 @@ Ops!(
     b"vbib"       , [0xC0              ], 0;
     b"v*Bb"       , [0xD3              ], 0, AUTO_SIZE;
     b"v*ib"       , [0xC1              ], 0, AUTO_SIZE;
+    b"rbvbBb"     , [0x04, 0xD2        ], 0, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0xC0        ], 0, APX_OP | APX_ND, APX;
+    b"r*v*Bb"     , [0x04, 0xD3        ], 0, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0xC1        ], 0, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "ror" = [
     b"vbBb"       , [0xD2              ], 1;
     b"vbib"       , [0xC0              ], 1;
     b"v*Bb"       , [0xD3              ], 1, AUTO_SIZE;
     b"v*ib"       , [0xC1              ], 1, AUTO_SIZE;
+    b"rbvbBb"     , [0x04, 0xD2        ], 1, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0xC0        ], 1, APX_OP | APX_ND, APX;
+    b"r*v*Bb"     , [0x04, 0xD3        ], 1, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0xC1        ], 1, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "rorx" = [
     b"r*v*ib"     , [0x03, 0xF0        ], X, VEX_OP | AUTO_REXW | PREF_F2, BMI2;
@@ -2373,6 +2651,9 @@ Ops!(
     b"yomd"       , [0x0F, 0x52        ], X, PREF_F3, SSE;
     b"yoyo"       , [0x0F, 0x52        ], X, PREF_F3, SSE;
 ]
+"rstorssp" = [
+    b"mq"         , [0x0F, 0x01        ], 5, PREF_F3, CET;
+]
 "rsts" = [
     b"mp"         , [0x0F, 0x7D        ], 0, EXACT_SIZE, CYRIX;
 ]
@@ -2384,16 +2665,27 @@ Ops!(
     b"vbib"       , [0xC0              ], 4;
     b"v*Bb"       , [0xD3              ], 4, AUTO_SIZE;
     b"v*ib"       , [0xC1              ], 4, AUTO_SIZE;
+    b"rbvbBb"     , [0x04, 0xD2        ], 4, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0xC0        ], 4, APX_OP | APX_ND, APX;
+    b"r*v*Bb"     , [0x04, 0xD3        ], 4, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0xC1        ], 4, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "sar" = [
     b"vbBb"       , [0xD2              ], 7;
     b"vbib"       , [0xC0              ], 7;
     b"v*Bb"       , [0xD3              ], 7, AUTO_SIZE;
     b"v*ib"       , [0xC1              ], 7, AUTO_SIZE;
+    b"rbvbBb"     , [0x04, 0xD2        ], 7, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0xC0        ], 7, APX_OP | APX_ND, APX;
+    b"r*v*Bb"     , [0x04, 0xD3        ], 7, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0xC1        ], 7, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "sarx" = [
     b"r*v*r*"     , [0x02, 0xF7        ], X, VEX_OP | AUTO_REXW | ENC_MR | PREF_F3, BMI2;
 ]
+"saveprevssp" = [
+    b""           , [0x0F, 0x01, 0xEA  ], X, PREF_F3, CET;
+]
 "sbb" = [
     b"Abib"       , [0x1C              ], X;
     b"mbib"       , [0x80              ], 3, LOCK;
@@ -2409,12 +2701,19 @@ Ops!(
     b"r*i*"       , [0x81              ], 3, AUTO_SIZE ;
     b"r*r*"       , [0x19              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x1B              ], X, AUTO_SIZE;
+    b"rbvbrb"     , [0x04, 0x18        ], X, APX_OP | APX_ND | ENC_MR, APX;
+    b"rbrbvb"     , [0x04, 0x1A        ], X, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0x80        ], 3, APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 3, AUTO_SIZE | EXACT_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 3, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*r*"     , [0x04, 0x19        ], X, AUTO_SIZE | APX_OP | APX_ND | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x1B        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "scasb" = [
     b""           , [0xAE              ], X, REPE;
 ]
 "scasd" = [
-    b""           , [0xAF              ], X, REPE;
+    b""           , [0xAF              ], X, REPE | DWORD_SIZE;
 ]
 "scasq" = [
     b""           , [0xAF              ], X, REPE | WITH_REXW;
@@ -2422,6 +2721,12 @@ Ops!(
 "scasw" = [
     b""           , [0xAF              ], X, REPE | WORD_SIZE;
 ]
+"serialize" = [
+    b""           , [0x0F, 0x01, 0xE8  ], X, DEFAULT, SERIALIZE;
+]
+"setssbsy" = [
+    b""           , [0x0F, 0x01, 0xE8  ], X, PREF_F3, CET;
+]
 "sfence" = [
     b""           , [0x0F, 0xAE, 0xF8  ], X, DEFAULT, AMD;
 ]
@@ -2454,6 +2759,10 @@ Ops!(
     b"vbib"       , [0xC0              ], 4;
     b"v*Bb"       , [0xD3              ], 4, AUTO_SIZE;
     b"v*ib"       , [0xC1              ], 4, AUTO_SIZE;
+    b"rbvbBb"     , [0x04, 0xD2        ], 4, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0xC0        ], 4, APX_OP | APX_ND, APX;
+    b"r*v*Bb"     , [0x04, 0xD3        ], 4, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0xC1        ], 4, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "shld" = [
     b"v*r*Bb"     , [0x0F, 0xA5        ], X, AUTO_SIZE | ENC_MR;
@@ -2467,6 +2776,10 @@ Ops!(
     b"vbib"       , [0xC0              ], 5;
     b"v*Bb"       , [0xD3              ], 5, AUTO_SIZE;
     b"v*ib"       , [0xC1              ], 5, AUTO_SIZE;
+    b"rbvbBb"     , [0x04, 0xD2        ], 5, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0xC0        ], 5, APX_OP | APX_ND, APX;
+    b"r*v*Bb"     , [0x04, 0xD3        ], 5, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0xC1        ], 5, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "shrd" = [
     b"v*r*Bb"     , [0x0F, 0xAD        ], X, AUTO_SIZE | ENC_MR;
@@ -2537,7 +2850,7 @@ Ops!(
     b""           , [0xAA              ], X, REP;
 ]
 "stosd" = [
-    b""           , [0xAB              ], X, REP;
+    b""           , [0xAB              ], X, REP | DWORD_SIZE;
 ]
 "stosq" = [
     b""           , [0xAB              ], X, WITH_REXW | REP;
@@ -2549,6 +2862,9 @@ Ops!(
     b"m!"         , [0x0F, 0x00        ], 1;
     b"r*"         , [0x0F, 0x00        ], 1, AUTO_SIZE;
 ]
+"sttilecfg" = [
+    b"m!"         , [0x02, 0x49        ], 0, VEX_OP | PREF_66, AMX_TILE;
+]
 "sub" = [
     b"Abib"       , [0x2C              ], X;
     b"mbib"       , [0x80              ], 5, LOCK;
@@ -2564,6 +2880,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 5, AUTO_SIZE ;
     b"r*r*"       , [0x29              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x2B              ], X, AUTO_SIZE;
+    b"rbvbrb"     , [0x04, 0x28        ], X, APX_OP | APX_ND | ENC_MR, APX;
+    b"rbrbvb"     , [0x04, 0x2A        ], X, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0x80        ], 5, APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 5, AUTO_SIZE | EXACT_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 5, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*r*"     , [0x04, 0x29        ], X, AUTO_SIZE | APX_OP | APX_ND | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x2B        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "subpd" = [
     b"yowo"       , [0x0F, 0x5C        ], X, PREF_66, SSE2;
@@ -2606,6 +2929,21 @@ Ops!(
 "t1mskc" = [
     b"r*v*"       , [0x09, 0x01        ], 7, XOP_OP | AUTO_REXW | ENC_VM, TBM;
 ]
+"tdpbf16ps" = [
+    b"t?t?t?"     , [0x02, 0x5C        ], X, VEX_OP | ENC_MR | PREF_F3, AMX_BF16;
+]
+"tdpbssd" = [
+    b"t?t?t?"     , [0x02, 0x5E        ], X, VEX_OP | ENC_MR | PREF_F2, AMX_INT8;
+]
+"tdpbsud" = [
+    b"t?t?t?"     , [0x02, 0x5E        ], X, VEX_OP | ENC_MR | PREF_F3, AMX_INT8;
+]
+"tdpbusd" = [
+    b"t?t?t?"     , [0x02, 0x5E        ], X, VEX_OP | ENC_MR | PREF_66, AMX_INT8;
+]
+"tdpbuud" = [
+    b"t?t?t?"     , [0x02, 0x5E        ], X, VEX_OP | ENC_MR, AMX_INT8;
+]
 "test" = [
     b"Abib"       , [0xA8              ], X;
     b"rbmb"       , [0x84              ], X;
@@ -2616,6 +2954,24 @@ Ops!(
     b"v*i*"       , [0xF7              ], 0, AUTO_SIZE;
     b"v*r*"       , [0x85              ], X, AUTO_SIZE | ENC_MR;
 ]
+"tileloadd" = [
+    b"t?m!"       , [0x02, 0x4B        ], X, VEX_OP | PREF_F2 | SIB_MEM, AMX_TILE;
+]
+"tileloaddt1" = [
+    b"t?m!"       , [0x02, 0x4B        ], X, VEX_OP | PREF_66 | SIB_MEM, AMX_TILE;
+]
+"tilerelease" = [
+    b""           , [0x02, 0x49, 0xC0  ], X, VEX_OP, AMX_TILE;
+]
+"tilestored" = [
+    b"m!t?"       , [0x02, 0x4B        ], X, VEX_OP | PREF_F3 | SIB_MEM, AMX_TILE;
+]
+"tilezero" = [
+    b"t?"         , [0x02, 0x49        ], 0, VEX_OP | ENC_MR | PREF_F2, AMX_TILE;
+]
+"tpause" = [
+    b"rd"         , [0x0F, 0xAE        ], 6, PREF_66, WAITPKG;
+]
 "tzcnt" = [
     b"r*v*"       , [0x0F, 0xBC        ], X, AUTO_SIZE | PREF_F3, BMI1;
 ]
@@ -2639,6 +2995,12 @@ Ops!(
 "ud2a" = [
     b""           , [0x0F, 0x0B        ], X;
 ]
+"umonitor" = [
+    b"rq"         , [0x0F, 0xAE        ], 6, PREF_F3, WAITPKG;
+]
+"umwait" = [
+    b"rd"         , [0x0F, 0xAE        ], 6, PREF_F2, WAITPKG;
+]
 "unpckhpd" = [
     b"yowo"       , [0x0F, 0x15        ], X, PREF_66, SSE2;
 ]
@@ -2653,17 +3015,23 @@ Ops!(
 ]
 "vaddpd" = [
     b"y*y*w*"     , [0x01, 0x58        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x58        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vaddps" = [
     b"y*y*w*"     , [0x01, 0x58        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x58        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vaddsd" = [
     b"yoyomq"     , [0x01, 0x58        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x58        ], X, VEX_OP | PREF_F2, AVX;
+    b"yoyomq"     , [0x01, 0x58        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x58        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vaddss" = [
     b"yoyomd"     , [0x01, 0x58        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x58        ], X, VEX_OP | PREF_F3, AVX;
+    b"yoyomd"     , [0x01, 0x58        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x58        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vaddsubpd" = [
     b"y*y*w*"     , [0x01, 0xD0        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -2689,17 +3057,33 @@ Ops!(
 "vaeskeygenassist" = [
     b"yowoib"     , [0x03, 0xDF        ], X, VEX_OP | PREF_66, AVX;
 ]
+"valignd" = [
+    b"y*y*w*ib"   , [0x03, 0x03        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"valignq" = [
+    b"y*y*w*ib"   , [0x03, 0x03        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
 "vandnpd" = [
     b"y*y*w*"     , [0x01, 0x55        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x55        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512DQ;
 ]
 "vandnps" = [
     b"y*y*w*"     , [0x01, 0x55        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x55        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32, AVX512DQ;
 ]
 "vandpd" = [
     b"y*y*w*"     , [0x01, 0x54        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x54        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512DQ;
 ]
 "vandps" = [
     b"y*y*w*"     , [0x01, 0x54        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x54        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32, AVX512DQ;
+]
+"vblendmpd" = [
+    b"y*y*w*"     , [0x02, 0x65        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vblendmps" = [
+    b"y*y*w*"     , [0x02, 0x65        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vblendpd" = [
     b"y*y*w*ib"   , [0x03, 0x0D        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
@@ -2716,772 +3100,1272 @@ Ops!(
 "vbroadcastf128" = [
     b"yhmo"       , [0x02, 0x1A        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX;
 ]
+"vbroadcastf32x2" = [
+    b"yhyo"       , [0x02, 0x19        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512DQ | AVX512VL;
+    b"ytyo"       , [0x02, 0x19        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+    b"yhmq"       , [0x02, 0x19        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512DQ | AVX512VL;
+    b"ytmq"       , [0x02, 0x19        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vbroadcastf32x4" = [
+    b"yhmo"       , [0x02, 0x1A        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytmo"       , [0x02, 0x1A        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vbroadcastf32x8" = [
+    b"ytmh"       , [0x02, 0x1B        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vbroadcastf64x2" = [
+    b"yhmo"       , [0x02, 0x1A        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512DQ | AVX512VL;
+    b"ytmo"       , [0x02, 0x1A        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vbroadcastf64x4" = [
+    b"ytmh"       , [0x02, 0x1B        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
 "vbroadcasti128" = [
     b"yhmo"       , [0x02, 0x5A        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX2;
 ]
+"vbroadcasti32x2" = [
+    b"y*yo"       , [0x02, 0x59        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+    b"y*mq"       , [0x02, 0x59        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vbroadcasti32x4" = [
+    b"yhmo"       , [0x02, 0x5A        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytmo"       , [0x02, 0x5A        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vbroadcasti32x8" = [
+    b"ytmh"       , [0x02, 0x5B        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vbroadcasti64x2" = [
+    b"yhmo"       , [0x02, 0x5A        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512DQ | AVX512VL;
+    b"ytmo"       , [0x02, 0x5A        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vbroadcasti64x4" = [
+    b"ytmh"       , [0x02, 0x5B        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
 "vbroadcastsd" = [
     b"yhmq"       , [0x02, 0x19        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX;
     b"yhyo"       , [0x02, 0x19        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX;
+    b"yhmq"       , [0x02, 0x19        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhyo"       , [0x02, 0x19        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytyo"       , [0x02, 0x19        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"ytmq"       , [0x02, 0x19        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vbroadcastss" = [
     b"y*md"       , [0x02, 0x18        ], X, VEX_OP | PREF_66, AVX;
     b"y*yo"       , [0x02, 0x18        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*md"       , [0x02, 0x18        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"y*yo"       , [0x02, 0x18        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vcmpeq_ospd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x10  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x10  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x10  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpeq_osps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x10  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x10  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpeq_ossd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x10  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x10  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x10  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x10  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpeq_osss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x10  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x10  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x10  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x10  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpeq_uqpd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x08  ], X, WITH_VEXL | VEX_OP | IMM_OP | PREF_66, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x08  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x08  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpeq_uqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x08  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x08  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpeq_uqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x08  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x08  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x08  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x08  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpeq_uqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x08  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x08  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x08  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x08  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpeq_uspd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x18  ], X, WITH_VEXL | VEX_OP | IMM_OP | PREF_66, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x18  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x18  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpeq_usps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x18  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x18  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpeq_ussd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x18  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x18  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x18  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x18  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpeq_usss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x18  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x18  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x18  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x18  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpeqpd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x00  ], X, VEX_OP | AUTO_VEXL | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x00  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpeqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x00  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x00  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpeqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x00  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x00  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x00  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x00  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpeqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x00  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x00  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x00  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x00  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpfalse_oqpd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0B  ], X, VEX_OP | AUTO_VEXL | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0B  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpfalse_oqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0B  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0B  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpfalse_oqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0B  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0B  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x0B  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0B  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpfalse_oqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0B  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0B  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0B  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x0B  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpfalse_ospd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x1B  ], X, VEX_OP | AUTO_VEXL | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x1B  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpfalse_osps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x1B  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x1B  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpfalse_ossd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x1B  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x1B  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x1B  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x1B  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpfalse_osss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x1B  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x1B  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x1B  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x1B  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpfalsepd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x0B  ], X, WITH_VEXL | VEX_OP | PREF_66 | IMM_OP, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x0B  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0B  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpfalseps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0B  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0B  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpfalsesd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0B  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0B  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x0B  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0B  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpfalsess" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0B  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0B  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0B  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x0B  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpge_oqpd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x1D  ], X, WITH_VEXL | VEX_OP | PREF_66 | IMM_OP, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x1D  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x1D  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpge_oqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x1D  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x1D  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpge_oqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x1D  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x1D  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x1D  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x1D  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpge_oqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x1D  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x1D  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x1D  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x1D  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpge_ospd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0D  ], X, VEX_OP | AUTO_VEXL | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0D  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpge_osps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0D  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0D  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpge_ossd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0D  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0D  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x0D  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0D  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpge_osss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0D  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0D  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0D  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x0D  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpgepd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0D  ], X, VEX_OP | AUTO_VEXL | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0D  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpgeps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0D  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0D  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpgesd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0D  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0D  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x0D  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0D  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpgess" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0D  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0D  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0D  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x0D  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpgt_oqpd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x1E  ], X, VEX_OP | AUTO_VEXL | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x1E  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpgt_oqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x1E  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x1E  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpgt_oqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x1E  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x1E  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x1E  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x1E  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpgt_oqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x1E  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x1E  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x1E  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x1E  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpgt_ospd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0E  ], X, VEX_OP | AUTO_VEXL | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0E  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpgt_osps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0E  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0E  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpgt_ossd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0E  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0E  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x0E  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0E  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpgt_osss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0E  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0E  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0E  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x0E  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpgtpd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x0E  ], X, WITH_VEXL | VEX_OP | PREF_66 | IMM_OP, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x0E  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0E  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpgtps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0E  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0E  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpgtsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0E  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0E  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x0E  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0E  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpgtss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0E  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0E  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0E  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x0E  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmple_oqpd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x12  ], X, WITH_VEXL | VEX_OP | IMM_OP | PREF_66, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x12  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x12  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmple_oqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x12  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x12  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmple_oqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x12  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x12  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x12  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x12  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmple_oqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x12  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x12  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x12  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x12  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmple_ospd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x02  ], X, VEX_OP | AUTO_VEXL | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x02  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmple_osps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x02  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x02  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmple_ossd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x02  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x02  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x02  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x02  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmple_osss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x02  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x02  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x02  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x02  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmplepd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x02  ], X, WITH_VEXL | VEX_OP | IMM_OP | PREF_66, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x02  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x02  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpleps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x02  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x02  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmplesd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x02  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x02  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x02  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x02  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpless" = [
     b"yoyomq"     , [0x01, 0xC2, 0x02  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x02  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x02  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x02  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmplt_oqpd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x11  ], X, VEX_OP | AUTO_VEXL | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x11  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmplt_oqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x11  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x11  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmplt_oqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x11  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x11  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x11  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x11  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmplt_oqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x11  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x11  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x11  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x11  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmplt_ospd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x01  ], X, WITH_VEXL | VEX_OP | IMM_OP | PREF_66, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x01  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x01  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmplt_osps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x01  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x01  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmplt_ossd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x01  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x01  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x01  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x01  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmplt_osss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x01  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x01  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x01  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x01  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpltpd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x01  ], X, WITH_VEXL | VEX_OP | IMM_OP | PREF_66, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x01  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x01  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpltps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x01  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x01  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpltsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x01  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x01  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x01  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x01  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpltss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x01  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x01  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x01  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x01  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpneq_oqpd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x0C  ], X, WITH_VEXL | VEX_OP | IMM_OP | PREF_66, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x0C  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0C  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpneq_oqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0C  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0C  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpneq_oqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0C  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0C  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x0C  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0C  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpneq_oqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0C  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0C  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0C  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x0C  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpneq_ospd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x1C  ], X, WITH_VEXL | VEX_OP | PREF_66 | IMM_OP, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x1C  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x1C  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpneq_osps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x1C  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x1C  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpneq_ossd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x1C  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x1C  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x1C  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x1C  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpneq_osss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x1C  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x1C  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x1C  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x1C  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpneq_uqpd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x04  ], X, VEX_OP | AUTO_VEXL | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x04  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpneq_uqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x04  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x04  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpneq_uqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x04  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x04  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x04  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x04  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpneq_uqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x04  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x04  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x04  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x04  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpneq_uspd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x14  ], X, WITH_VEXL | VEX_OP | IMM_OP | PREF_66, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x14  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x14  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpneq_usps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x14  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x14  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpneq_ussd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x14  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x14  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x14  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x14  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpneq_usss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x14  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x14  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x14  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x14  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpneqpd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x04  ], X, WITH_VEXL | VEX_OP | PREF_66 | IMM_OP, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x04  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x04  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpneqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x04  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x04  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpneqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x04  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x04  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x04  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x04  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpneqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x04  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x04  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x04  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x04  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpnge_uqpd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x19  ], X, VEX_OP | AUTO_VEXL | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x19  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpnge_uqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x19  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x19  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpnge_uqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x19  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x19  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x19  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x19  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpnge_uqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x19  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x19  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x19  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x19  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpnge_uspd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x09  ], X, VEX_OP | AUTO_VEXL | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x09  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpnge_usps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x09  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x09  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpnge_ussd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x09  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x09  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x09  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x09  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpnge_usss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x09  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x09  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x09  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x09  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpngepd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x09  ], X, WITH_VEXL | VEX_OP | PREF_66 | IMM_OP, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x09  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x09  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpngeps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x09  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x09  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpngesd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x09  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x09  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x09  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x09  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpngess" = [
     b"yoyomq"     , [0x01, 0xC2, 0x09  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x09  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x09  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x09  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpngt_uqpd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x1A  ], X, VEX_OP | AUTO_VEXL | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x1A  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpngt_uqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x1A  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x1A  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpngt_uqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x1A  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x1A  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x1A  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x1A  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpngt_uqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x1A  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x1A  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x1A  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x1A  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpngt_uspd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0A  ], X, VEX_OP | AUTO_VEXL | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0A  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpngt_usps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0A  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0A  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpngt_ussd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0A  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0A  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x0A  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0A  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpngt_usss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0A  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0A  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0A  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x0A  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpngtpd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0A  ], X, VEX_OP | AUTO_VEXL | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0A  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpngtps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0A  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0A  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpngtsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0A  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0A  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x0A  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0A  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpngtss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0A  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0A  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0A  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x0A  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpnle_uqpd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x16  ], X, VEX_OP | AUTO_VEXL | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x16  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpnle_uqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x16  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x16  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpnle_uqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x16  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x16  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x16  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x16  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpnle_uqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x16  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x16  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x16  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x16  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpnle_uspd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x06  ], X, WITH_VEXL | VEX_OP | IMM_OP | PREF_66, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x06  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x06  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpnle_usps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x06  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x06  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpnle_ussd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x06  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x06  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x06  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x06  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpnle_usss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x06  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x06  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x06  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x06  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpnlepd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x06  ], X, VEX_OP | AUTO_VEXL | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x06  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpnleps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x06  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x06  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpnlesd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x06  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x06  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x06  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x06  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpnless" = [
     b"yoyomq"     , [0x01, 0xC2, 0x06  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x06  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x06  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x06  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpnlt_uqpd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x15  ], X, WITH_VEXL | VEX_OP | IMM_OP | PREF_66, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x15  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x15  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpnlt_uqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x15  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x15  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpnlt_uqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x15  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x15  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x15  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x15  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpnlt_uqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x15  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x15  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x15  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x15  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpnlt_uspd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x05  ], X, VEX_OP | AUTO_VEXL | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x05  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpnlt_usps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x05  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x05  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpnlt_ussd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x05  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x05  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x05  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x05  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpnlt_usss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x05  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x05  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x05  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x05  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpnltpd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x05  ], X, WITH_VEXL | VEX_OP | PREF_66 | IMM_OP, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x05  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x05  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpnltps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x05  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x05  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpnltsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x05  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x05  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x05  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x05  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpnltss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x05  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x05  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x05  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x05  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpord_qpd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x07  ], X, WITH_VEXL | VEX_OP | PREF_66 | IMM_OP, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x07  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x07  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpord_qps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x07  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x07  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpord_qsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x07  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x07  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x07  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x07  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpord_qss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x07  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x07  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x07  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x07  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpord_spd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x17  ], X, WITH_VEXL | VEX_OP | PREF_66 | IMM_OP, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x17  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x17  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpord_sps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x17  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x17  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpord_ssd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x17  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x17  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x17  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x17  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpord_sss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x17  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x17  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x17  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x17  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpordpd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x07  ], X, WITH_VEXL | VEX_OP | IMM_OP | PREF_66, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x07  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x07  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpordps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x07  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x07  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpordsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x07  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x07  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x07  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x07  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpordss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x07  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x07  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x07  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x07  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmppd" = [
-    b"y*y*w*ib"   , [0x01, 0xC2        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
+    b"y*y*w*ib"   , [0x01, 0xC2        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"jqy*w*ib"   , [0x01, 0xC2        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpps" = [
-    b"y*y*w*ib"   , [0x01, 0xC2        ], X, VEX_OP | AUTO_VEXL | ENC_MR, AVX;
+    b"y*y*w*ib"   , [0x01, 0xC2        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"jqy*w*ib"   , [0x01, 0xC2        ], X, EVEX_OP | AUTO_VEXL | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpsd" = [
     b"yoyomqib"   , [0x01, 0xC2        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyoib"   , [0x01, 0xC2        ], X, VEX_OP | PREF_F2, AVX;
+    b"jqyomqib"   , [0x01, 0xC2        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK, AVX512F;
+    b"jqyoyoib"   , [0x01, 0xC2        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | SAE, AVX512F;
 ]
 "vcmpss" = [
     b"yoyomqib"   , [0x01, 0xC2        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyoib"   , [0x01, 0xC2        ], X, VEX_OP | PREF_F3, AVX;
+    b"jqyoyoib"   , [0x01, 0xC2        ], X, EVEX_OP | PREF_F3 | OPMASK | SAE, AVX512F;
+    b"jqyomdib"   , [0x01, 0xC2        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F;
 ]
 "vcmptrue_uqpd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x0F  ], X, WITH_VEXL | VEX_OP | IMM_OP | PREF_66, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x0F  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0F  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmptrue_uqps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0F  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0F  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmptrue_uqsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0F  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0F  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x0F  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0F  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmptrue_uqss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0F  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0F  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0F  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x0F  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmptrue_uspd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x1F  ], X, VEX_OP | AUTO_VEXL | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x1F  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmptrue_usps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x1F  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x1F  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmptrue_ussd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x1F  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x1F  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x1F  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x1F  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmptrue_usss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x1F  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x1F  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x1F  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x1F  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmptruepd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0F  ], X, VEX_OP | AUTO_VEXL | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0F  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmptrueps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x0F  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x0F  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmptruesd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0F  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0F  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x0F  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0F  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmptruess" = [
     b"yoyomq"     , [0x01, 0xC2, 0x0F  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x0F  ], X, VEX_OP | PREF_F3 | IMM_OP, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x0F  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x0F  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpunord_qpd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x03  ], X, VEX_OP | AUTO_VEXL | PREF_66 | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x03  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpunord_qps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x03  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x03  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpunord_qsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x03  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x03  ], X, VEX_OP | IMM_OP | PREF_F2, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x03  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x03  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpunord_qss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x03  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x03  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x03  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x03  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpunord_spd" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x13  ], X, VEX_OP | AUTO_VEXL | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x13  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpunord_sps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x13  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x13  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpunord_ssd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x13  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x13  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x13  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x13  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpunord_sss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x13  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x13  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x13  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x13  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcmpunordpd" = [
     b"yhyhwh"     , [0x01, 0xC2, 0x03  ], X, WITH_VEXL | VEX_OP | PREF_66 | IMM_OP, AVX;
     b"yoyowo"     , [0x01, 0xC2, 0x03  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x03  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64 | SAE, AVX512F;
 ]
 "vcmpunordps" = [
     b"y*y*w*"     , [0x01, 0xC2, 0x03  ], X, VEX_OP | AUTO_VEXL | IMM_OP, AVX;
+    b"jqy*w*"     , [0x01, 0xC2, 0x03  ], X, EVEX_OP | AUTO_VEXL | IMM_OP | OPMASK | BCST_32 | SAE, AVX512F;
 ]
 "vcmpunordsd" = [
     b"yoyomq"     , [0x01, 0xC2, 0x03  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x03  ], X, VEX_OP | PREF_F2 | IMM_OP, AVX;
+    b"jqyomq"     , [0x01, 0xC2, 0x03  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK, AVX512F;
+    b"jqyoyo"     , [0x01, 0xC2, 0x03  ], X, EVEX_OP | WITH_REXW | PREF_F2 | IMM_OP | OPMASK | SAE, AVX512F;
 ]
 "vcmpunordss" = [
     b"yoyomq"     , [0x01, 0xC2, 0x03  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0xC2, 0x03  ], X, VEX_OP | IMM_OP | PREF_F3, AVX;
+    b"jqyoyo"     , [0x01, 0xC2, 0x03  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK | SAE, AVX512F;
+    b"jqyomd"     , [0x01, 0xC2, 0x03  ], X, EVEX_OP | PREF_F3 | IMM_OP | OPMASK, AVX512F;
 ]
 "vcomisd" = [
     b"yomq"       , [0x01, 0x2F        ], X, VEX_OP | PREF_66, AVX;
     b"yoyo"       , [0x01, 0x2F        ], X, VEX_OP | PREF_66, AVX;
+    b"yomq"       , [0x01, 0x2F        ], X, EVEX_OP | WITH_REXW | PREF_66, AVX512F;
+    b"yoyo"       , [0x01, 0x2F        ], X, EVEX_OP | WITH_REXW | PREF_66 | SAE, AVX512F;
 ]
 "vcomiss" = [
     b"yomd"       , [0x01, 0x2F        ], X, VEX_OP, AVX;
     b"yoyo"       , [0x01, 0x2F        ], X, VEX_OP, AVX;
+    b"yomd"       , [0x01, 0x2F        ], X, EVEX_OP, AVX512F;
+    b"yoyo"       , [0x01, 0x2F        ], X, EVEX_OP | SAE, AVX512F;
+]
+"vcompresspd" = [
+    b"w*y*"       , [0x02, 0x8A        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | T1S_64, AVX512F;
+]
+"vcompressps" = [
+    b"w*y*"       , [0x02, 0x8A        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | T1S_32, AVX512F;
 ]
 "vcvtdq2pd" = [
     b"yomq"       , [0x01, 0xE6        ], X, VEX_OP | PREF_F3, AVX;
     b"y*wo"       , [0x01, 0xE6        ], X, VEX_OP | AUTO_VEXL | PREF_F3, AVX;
+    b"yomq"       , [0x01, 0xE6        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING | BCST_32, AVX512F | AVX512VL;
+    b"yhwo"       , [0x01, 0xE6        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK | ZEROING | BCST_32, AVX512F | AVX512VL;
+    b"yoyo"       , [0x01, 0xE6        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytwh"       , [0x01, 0xE6        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vcvtdq2ps" = [
     b"y*w*"       , [0x01, 0x5B        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*w*"       , [0x01, 0x5B        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vcvtpd2dq" = [
     b"yom*"       , [0x01, 0xE6        ], X, VEX_OP | AUTO_VEXL | PREF_F2, AVX;
     b"yoy*"       , [0x01, 0xE6        ], X, VEX_OP | AUTO_VEXL | PREF_F2, AVX;
+    b"yowo"       , [0x01, 0xE6        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"yowh"       , [0x01, 0xE6        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_F2 | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"yhwt"       , [0x01, 0xE6        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_F2 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vcvtpd2ps" = [
     b"yom*"       , [0x01, 0x5A        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
     b"yoy*"       , [0x01, 0x5A        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yowo"       , [0x01, 0x5A        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"yowh"       , [0x01, 0x5A        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"yhwt"       , [0x01, 0x5A        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
+]
+"vcvtpd2qq" = [
+    b"y*w*"       , [0x01, 0x7B        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512DQ;
+]
+"vcvtpd2udq" = [
+    b"yowo"       , [0x01, 0x79        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"yowh"       , [0x01, 0x79        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"yhwt"       , [0x01, 0x79        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
+]
+"vcvtpd2uqq" = [
+    b"y*w*"       , [0x01, 0x79        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512DQ;
 ]
 "vcvtph2ps" = [
     b"yomq"       , [0x02, 0x13        ], X, VEX_OP | PREF_66, AVX;
     b"y*wo"       , [0x02, 0x13        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomq"       , [0x02, 0x13        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhwo"       , [0x02, 0x13        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyo"       , [0x02, 0x13        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytwh"       , [0x02, 0x13        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING | SAE, AVX512F;
 ]
 "vcvtps2dq" = [
     b"y*w*"       , [0x01, 0x5B        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*w*"       , [0x01, 0x5B        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vcvtps2pd" = [
     b"yomq"       , [0x01, 0x5A        ], X, VEX_OP, AVX;
     b"y*wo"       , [0x01, 0x5A        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"yomq"       , [0x01, 0x5A        ], X, EVEX_OP | OPMASK | ZEROING | BCST_32, AVX512F | AVX512VL;
+    b"yhwo"       , [0x01, 0x5A        ], X, EVEX_OP | WITH_VEXL | OPMASK | ZEROING | BCST_32, AVX512F | AVX512VL;
+    b"yoyo"       , [0x01, 0x5A        ], X, EVEX_OP | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytwh"       , [0x01, 0x5A        ], X, EVEX_OP | WITH_EVEXL | OPMASK | ZEROING | BCST_32 | SAE, AVX512F;
 ]
 "vcvtps2ph" = [
     b"mqyoib"     , [0x03, 0x1D        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
     b"woy*ib"     , [0x03, 0x1D        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
+    b"mqyoib"     , [0x03, 0x1D        ], X, EVEX_OP | PREF_66 | OPMASK, AVX512F | AVX512VL;
+    b"yoyoib"     , [0x03, 0x1D        ], X, EVEX_OP | ENC_MR | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"woyhib"     , [0x03, 0x1D        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"whytib"     , [0x03, 0x1D        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING | SAE, AVX512F;
+]
+"vcvtps2qq" = [
+    b"yoyo"       , [0x01, 0x7B        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512DQ | AVX512VL;
+    b"yomq"       , [0x01, 0x7B        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512DQ | AVX512VL;
+    b"yhwo"       , [0x01, 0x7B        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512DQ | AVX512VL;
+    b"ytwh"       , [0x01, 0x7B        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512DQ;
+]
+"vcvtps2udq" = [
+    b"y*w*"       , [0x01, 0x79        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
+]
+"vcvtps2uqq" = [
+    b"yoyo"       , [0x01, 0x79        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512DQ | AVX512VL;
+    b"yomq"       , [0x01, 0x79        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512DQ | AVX512VL;
+    b"yhwo"       , [0x01, 0x79        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512DQ | AVX512VL;
+    b"ytwh"       , [0x01, 0x79        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512DQ;
+]
+"vcvtqq2pd" = [
+    b"y*w*"       , [0x01, 0xE6        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_F3 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512DQ;
+]
+"vcvtqq2ps" = [
+    b"yowo"       , [0x01, 0x5B        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | BCST_64, AVX512DQ | AVX512VL;
+    b"yowh"       , [0x01, 0x5B        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | OPMASK | ZEROING | BCST_64, AVX512DQ | AVX512VL;
+    b"yhwt"       , [0x01, 0x5B        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512DQ;
 ]
 "vcvtsd2si" = [
     b"r*mq"       , [0x01, 0x2D        ], X, VEX_OP | AUTO_REXW | PREF_F2, AVX;
     b"r*yo"       , [0x01, 0x2D        ], X, VEX_OP | AUTO_REXW | PREF_F2, AVX;
+    b"rdyo"       , [0x01, 0x2D        ], X, EVEX_OP | PREF_F2 | EMB_RND, AVX512F;
+    b"rdmq"       , [0x01, 0x2D        ], X, EVEX_OP | PREF_F2, AVX512F;
+    b"rqyo"       , [0x01, 0x2D        ], X, EVEX_OP | WITH_REXW | PREF_F2 | EMB_RND, AVX512F;
+    b"rqmq"       , [0x01, 0x2D        ], X, EVEX_OP | WITH_REXW | PREF_F2, AVX512F;
 ]
 "vcvtsd2ss" = [
     b"yoyomq"     , [0x01, 0x5A        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x5A        ], X, VEX_OP | PREF_F2, AVX;
+    b"yoyomq"     , [0x01, 0x5A        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x5A        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING | EMB_RND, AVX512F;
+]
+"vcvtsd2usi" = [
+    b"rdyo"       , [0x01, 0x79        ], X, EVEX_OP | PREF_F2 | EMB_RND, AVX512F;
+    b"rdmq"       , [0x01, 0x79        ], X, EVEX_OP | PREF_F2, AVX512F;
+    b"rqyo"       , [0x01, 0x79        ], X, EVEX_OP | WITH_REXW | PREF_F2 | EMB_RND, AVX512F;
+    b"rqmq"       , [0x01, 0x79        ], X, EVEX_OP | WITH_REXW | PREF_F2, AVX512F;
 ]
 "vcvtsi2sd" = [
     b"yoyov*"     , [0x01, 0x2A        ], X, VEX_OP | AUTO_REXW | PREF_F2, AVX;
+    b"yoyovd"     , [0x01, 0x2A        ], X, EVEX_OP | PREF_F2, AVX512F;
+    b"yoyovq"     , [0x01, 0x2A        ], X, EVEX_OP | WITH_REXW | PREF_F2 | EMB_RND, AVX512F;
 ]
 "vcvtsi2ss" = [
     b"yoyov*"     , [0x01, 0x2A        ], X, VEX_OP | AUTO_REXW | PREF_F3, AVX;
+    b"yoyovd"     , [0x01, 0x2A        ], X, EVEX_OP | PREF_F3 | EMB_RND, AVX512F;
+    b"yoyovq"     , [0x01, 0x2A        ], X, EVEX_OP | WITH_REXW | PREF_F3 | EMB_RND, AVX512F;
 ]
 "vcvtss2sd" = [
     b"yoyomd"     , [0x01, 0x5A        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x5A        ], X, VEX_OP | PREF_F3, AVX;
+    b"yoyomd"     , [0x01, 0x5A        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x5A        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING | SAE, AVX512F;
 ]
 "vcvtss2si" = [
     b"r*md"       , [0x01, 0x2D        ], X, VEX_OP | AUTO_REXW | PREF_F3, AVX;
     b"r*yo"       , [0x01, 0x2D        ], X, VEX_OP | AUTO_REXW | PREF_F3, AVX;
+    b"rdyo"       , [0x01, 0x2D        ], X, EVEX_OP | PREF_F3 | EMB_RND, AVX512F;
+    b"rdmd"       , [0x01, 0x2D        ], X, EVEX_OP | PREF_F3, AVX512F;
+    b"rqyo"       , [0x01, 0x2D        ], X, EVEX_OP | WITH_REXW | PREF_F3 | EMB_RND, AVX512F;
+    b"rqmd"       , [0x01, 0x2D        ], X, EVEX_OP | WITH_REXW | PREF_F3, AVX512F;
+]
+"vcvtss2usi" = [
+    b"rdyo"       , [0x01, 0x79        ], X, EVEX_OP | PREF_F3 | EMB_RND, AVX512F;
+    b"rdmd"       , [0x01, 0x79        ], X, EVEX_OP | PREF_F3, AVX512F;
+    b"rqyo"       , [0x01, 0x79        ], X, EVEX_OP | WITH_REXW | PREF_F3 | EMB_RND, AVX512F;
+    b"rqmd"       , [0x01, 0x79        ], X, EVEX_OP | WITH_REXW | PREF_F3, AVX512F;
 ]
 "vcvttpd2dq" = [
     b"yom*"       , [0x01, 0xE6        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
     b"yoy*"       , [0x01, 0xE6        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yowo"       , [0x01, 0xE6        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"yowh"       , [0x01, 0xE6        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"yhwt"       , [0x01, 0xE6        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | SAE, AVX512F;
+]
+"vcvttpd2qq" = [
+    b"y*w*"       , [0x01, 0x7A        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | SAE, AVX512DQ;
+]
+"vcvttpd2udq" = [
+    b"yowo"       , [0x01, 0x78        ], X, EVEX_OP | WITH_REXW | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"yowh"       , [0x01, 0x78        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"yhwt"       , [0x01, 0x78        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | OPMASK | ZEROING | BCST_64 | SAE, AVX512F;
+]
+"vcvttpd2uqq" = [
+    b"y*w*"       , [0x01, 0x78        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | SAE, AVX512DQ;
 ]
 "vcvttps2dq" = [
     b"y*w*"       , [0x01, 0x5B        ], X, VEX_OP | AUTO_VEXL | PREF_F3, AVX;
+    b"y*w*"       , [0x01, 0x5B        ], X, EVEX_OP | AUTO_VEXL | PREF_F3 | OPMASK | ZEROING | BCST_32 | SAE, AVX512F;
+]
+"vcvttps2qq" = [
+    b"yoyo"       , [0x01, 0x7A        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512DQ | AVX512VL;
+    b"yomq"       , [0x01, 0x7A        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512DQ | AVX512VL;
+    b"yhwo"       , [0x01, 0x7A        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512DQ | AVX512VL;
+    b"ytwh"       , [0x01, 0x7A        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | SAE, AVX512DQ;
+]
+"vcvttps2udq" = [
+    b"y*w*"       , [0x01, 0x78        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32 | SAE, AVX512F;
+]
+"vcvttps2uqq" = [
+    b"yoyo"       , [0x01, 0x78        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512DQ | AVX512VL;
+    b"yomq"       , [0x01, 0x78        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512DQ | AVX512VL;
+    b"yhwo"       , [0x01, 0x78        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512DQ | AVX512VL;
+    b"ytwh"       , [0x01, 0x78        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | SAE, AVX512DQ;
 ]
 "vcvttsd2si" = [
     b"r*mq"       , [0x01, 0x2C        ], X, VEX_OP | AUTO_REXW | PREF_F2, AVX;
     b"r*yo"       , [0x01, 0x2C        ], X, VEX_OP | AUTO_REXW | PREF_F2, AVX;
+    b"rdyo"       , [0x01, 0x2C        ], X, EVEX_OP | PREF_F2 | SAE, AVX512F;
+    b"rdmq"       , [0x01, 0x2C        ], X, EVEX_OP | PREF_F2, AVX512F;
+    b"rqyo"       , [0x01, 0x2C        ], X, EVEX_OP | WITH_REXW | PREF_F2 | SAE, AVX512F;
+    b"rqmq"       , [0x01, 0x2C        ], X, EVEX_OP | WITH_REXW | PREF_F2, AVX512F;
+]
+"vcvttsd2usi" = [
+    b"rdyo"       , [0x01, 0x78        ], X, EVEX_OP | PREF_F2 | SAE, AVX512F;
+    b"rdmq"       , [0x01, 0x78        ], X, EVEX_OP | PREF_F2, AVX512F;
+    b"rqyo"       , [0x01, 0x78        ], X, EVEX_OP | WITH_REXW | PREF_F2 | SAE, AVX512F;
+    b"rqmq"       , [0x01, 0x78        ], X, EVEX_OP | WITH_REXW | PREF_F2, AVX512F;
 ]
 "vcvttss2si" = [
     b"r*md"       , [0x01, 0x2C        ], X, VEX_OP | AUTO_REXW | PREF_F3, AVX;
     b"r*yo"       , [0x01, 0x2C        ], X, VEX_OP | AUTO_REXW | PREF_F3, AVX;
+    b"rdyo"       , [0x01, 0x2C        ], X, EVEX_OP | PREF_F3 | SAE, AVX512F;
+    b"rdmd"       , [0x01, 0x2C        ], X, EVEX_OP | PREF_F3, AVX512F;
+    b"rqyo"       , [0x01, 0x2C        ], X, EVEX_OP | WITH_REXW | PREF_F3 | SAE, AVX512F;
+    b"rqmd"       , [0x01, 0x2C        ], X, EVEX_OP | WITH_REXW | PREF_F3, AVX512F;
+]
+"vcvttss2usi" = [
+    b"rdyo"       , [0x01, 0x78        ], X, EVEX_OP | PREF_F3 | SAE, AVX512F;
+    b"rdmd"       , [0x01, 0x78        ], X, EVEX_OP | PREF_F3, AVX512F;
+    b"rqyo"       , [0x01, 0x78        ], X, EVEX_OP | WITH_REXW | PREF_F3 | SAE, AVX512F;
+    b"rqmd"       , [0x01, 0x78        ], X, EVEX_OP | WITH_REXW | PREF_F3, AVX512F;
+]
+"vcvtudq2pd" = [
+    b"yoyo"       , [0x01, 0x7A        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yomq"       , [0x01, 0x7A        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING | BCST_32, AVX512F | AVX512VL;
+    b"yhwo"       , [0x01, 0x7A        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK | ZEROING | BCST_32, AVX512F | AVX512VL;
+    b"ytwh"       , [0x01, 0x7A        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vcvtudq2ps" = [
+    b"y*w*"       , [0x01, 0x7A        ], X, EVEX_OP | AUTO_VEXL | PREF_F2 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
+]
+"vcvtuqq2pd" = [
+    b"y*w*"       , [0x01, 0x7A        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_F3 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512DQ;
+]
+"vcvtuqq2ps" = [
+    b"yowo"       , [0x01, 0x7A        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING | BCST_64, AVX512DQ | AVX512VL;
+    b"yowh"       , [0x01, 0x7A        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_F2 | OPMASK | ZEROING | BCST_64, AVX512DQ | AVX512VL;
+    b"yhwt"       , [0x01, 0x7A        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_F2 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512DQ;
+]
+"vcvtusi2sd" = [
+    b"yoyovd"     , [0x01, 0x7B        ], X, EVEX_OP | PREF_F2, AVX512F;
+    b"yoyovq"     , [0x01, 0x7B        ], X, EVEX_OP | WITH_REXW | PREF_F2 | EMB_RND, AVX512F;
+]
+"vcvtusi2ss" = [
+    b"yoyovd"     , [0x01, 0x7B        ], X, EVEX_OP | PREF_F3 | EMB_RND, AVX512F;
+    b"yoyovq"     , [0x01, 0x7B        ], X, EVEX_OP | WITH_REXW | PREF_F3 | EMB_RND, AVX512F;
+]
+"vdbpsadbw" = [
+    b"y*y*w*ib"   , [0x03, 0x42        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vdivpd" = [
     b"y*y*w*"     , [0x01, 0x5E        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x5E        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vdivps" = [
     b"y*y*w*"     , [0x01, 0x5E        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x5E        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vdivsd" = [
     b"yoyomq"     , [0x01, 0x5E        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x5E        ], X, VEX_OP | PREF_F2, AVX;
+    b"yoyomq"     , [0x01, 0x5E        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x5E        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vdivss" = [
     b"yoyomd"     , [0x01, 0x5E        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x5E        ], X, VEX_OP | PREF_F3, AVX;
+    b"yoyomd"     , [0x01, 0x5E        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x5E        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vdppd" = [
     b"yoyowoib"   , [0x03, 0x41        ], X, VEX_OP | PREF_66, AVX;
@@ -3497,14 +4381,69 @@ Ops!(
     b"m!"         , [0x0F, 0x00        ], 5;
     b"rw"         , [0x0F, 0x00        ], 5;
 ]
+"vexp2pd" = [
+    b"ytwt"       , [0x02, 0xC8        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | SAE, AVX512ER;
+]
+"vexp2ps" = [
+    b"ytwt"       , [0x02, 0xC8        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | SAE, AVX512ER;
+]
+"vexpandpd" = [
+    b"y*w*"       , [0x02, 0x88        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | T1S_64, AVX512F;
+]
+"vexpandps" = [
+    b"y*w*"       , [0x02, 0x88        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | T1S_32, AVX512F;
+]
 "vextractf128" = [
     b"woyhib"     , [0x03, 0x19        ], X, WITH_VEXL | VEX_OP | ENC_MR | PREF_66, AVX;
 ]
+"vextractf32x4" = [
+    b"woyhib"     , [0x03, 0x19        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"woytib"     , [0x03, 0x19        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vextractf32x8" = [
+    b"whytib"     , [0x03, 0x1B        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vextractf64x2" = [
+    b"woyhib"     , [0x03, 0x19        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512DQ | AVX512VL;
+    b"woytib"     , [0x03, 0x19        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vextractf64x4" = [
+    b"whytib"     , [0x03, 0x1B        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
 "vextracti128" = [
     b"woyhib"     , [0x03, 0x39        ], X, WITH_VEXL | VEX_OP | ENC_MR | PREF_66, AVX2;
 ]
+"vextracti32x4" = [
+    b"woyhib"     , [0x03, 0x39        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"woytib"     , [0x03, 0x39        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vextracti32x8" = [
+    b"whytib"     , [0x03, 0x3B        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vextracti64x2" = [
+    b"woyhib"     , [0x03, 0x39        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512DQ | AVX512VL;
+    b"woytib"     , [0x03, 0x39        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vextracti64x4" = [
+    b"whytib"     , [0x03, 0x3B        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
 "vextractps" = [
     b"vdyoib"     , [0x03, 0x17        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
+    b"vdyoib"     , [0x03, 0x17        ], X, EVEX_OP | PREF_66, AVX512F;
+]
+"vfixupimmpd" = [
+    b"y*y*w*ib"   , [0x03, 0x54        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | SAE, AVX512F;
+]
+"vfixupimmps" = [
+    b"y*y*w*ib"   , [0x03, 0x54        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | SAE, AVX512F;
+]
+"vfixupimmsd" = [
+    b"yoyoyoib"   , [0x03, 0x55        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | SAE, AVX512F;
+    b"yoyomqib"   , [0x03, 0x55        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vfixupimmss" = [
+    b"yoyoyoib"   , [0x03, 0x55        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | SAE, AVX512F;
+    b"yoyomdib"   , [0x03, 0x55        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vfmadd123pd" = [
     b"y*y*w*"     , [0x02, 0xA8        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
@@ -3522,45 +4461,63 @@ Ops!(
 ]
 "vfmadd132pd" = [
     b"y*y*w*"     , [0x02, 0x98        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0x98        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfmadd132ps" = [
     b"y*y*w*"     , [0x02, 0x98        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0x98        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfmadd132sd" = [
     b"yoyomq"     , [0x02, 0x99        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0x99        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
+    b"yoyomq"     , [0x02, 0x99        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0x99        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfmadd132ss" = [
     b"yoyomd"     , [0x02, 0x99        ], X, VEX_OP | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0x99        ], X, VEX_OP | PREF_66, FMA;
+    b"yoyomd"     , [0x02, 0x99        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0x99        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfmadd213pd" = [
     b"y*y*w*"     , [0x02, 0xA8        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xA8        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfmadd213ps" = [
     b"y*y*w*"     , [0x02, 0xA8        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xA8        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfmadd213sd" = [
     b"yoyomq"     , [0x02, 0xA9        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xA9        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
+    b"yoyomq"     , [0x02, 0xA9        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xA9        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfmadd213ss" = [
     b"yoyomd"     , [0x02, 0xA9        ], X, VEX_OP | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xA9        ], X, VEX_OP | PREF_66, FMA;
+    b"yoyomd"     , [0x02, 0xA9        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xA9        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfmadd231pd" = [
     b"y*y*w*"     , [0x02, 0xB8        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xB8        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfmadd231ps" = [
     b"y*y*w*"     , [0x02, 0xB8        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xB8        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfmadd231sd" = [
     b"yoyomq"     , [0x02, 0xB9        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xB9        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
+    b"yoyomq"     , [0x02, 0xB9        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xB9        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfmadd231ss" = [
     b"yoyomd"     , [0x02, 0xB9        ], X, VEX_OP | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xB9        ], X, VEX_OP | PREF_66, FMA;
+    b"yoyomd"     , [0x02, 0xB9        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xB9        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfmadd312pd" = [
     b"y*y*w*"     , [0x02, 0x98        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
@@ -3616,21 +4573,27 @@ Ops!(
 ]
 "vfmaddsub132pd" = [
     b"y*y*w*"     , [0x02, 0x96        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0x96        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfmaddsub132ps" = [
     b"y*y*w*"     , [0x02, 0x96        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0x96        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfmaddsub213pd" = [
     b"y*y*w*"     , [0x02, 0xA6        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xA6        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfmaddsub213ps" = [
     b"y*y*w*"     , [0x02, 0xA6        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xA6        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfmaddsub231pd" = [
     b"y*y*w*"     , [0x02, 0xB6        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xB6        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfmaddsub231ps" = [
     b"y*y*w*"     , [0x02, 0xB6        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xB6        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfmaddsub312pd" = [
     b"y*y*w*"     , [0x02, 0x96        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
@@ -3668,45 +4631,63 @@ Ops!(
 ]
 "vfmsub132pd" = [
     b"y*y*w*"     , [0x02, 0x9A        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0x9A        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfmsub132ps" = [
     b"y*y*w*"     , [0x02, 0x9A        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0x9A        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfmsub132sd" = [
     b"yoyomq"     , [0x02, 0x9B        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0x9B        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
+    b"yoyomq"     , [0x02, 0x9B        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0x9B        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfmsub132ss" = [
     b"yoyomd"     , [0x02, 0x9B        ], X, VEX_OP | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0x9B        ], X, VEX_OP | PREF_66, FMA;
+    b"yoyomd"     , [0x02, 0x9B        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0x9B        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfmsub213pd" = [
     b"y*y*w*"     , [0x02, 0xAA        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xAA        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfmsub213ps" = [
     b"y*y*w*"     , [0x02, 0xAA        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xAA        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfmsub213sd" = [
     b"yoyomq"     , [0x02, 0xAB        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xAB        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
+    b"yoyomq"     , [0x02, 0xAB        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xAB        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfmsub213ss" = [
     b"yoyomd"     , [0x02, 0xAB        ], X, VEX_OP | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xAB        ], X, VEX_OP | PREF_66, FMA;
+    b"yoyomd"     , [0x02, 0xAB        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xAB        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfmsub231pd" = [
     b"y*y*w*"     , [0x02, 0xBA        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xBA        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfmsub231ps" = [
     b"y*y*w*"     , [0x02, 0xBA        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xBA        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfmsub231sd" = [
     b"yoyomq"     , [0x02, 0xBB        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xBB        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
+    b"yoyomq"     , [0x02, 0xBB        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xBB        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfmsub231ss" = [
     b"yoyomd"     , [0x02, 0xBB        ], X, VEX_OP | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xBB        ], X, VEX_OP | PREF_66, FMA;
+    b"yoyomd"     , [0x02, 0xBB        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xBB        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfmsub312pd" = [
     b"y*y*w*"     , [0x02, 0x9A        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
@@ -3744,21 +4725,27 @@ Ops!(
 ]
 "vfmsubadd132pd" = [
     b"y*y*w*"     , [0x02, 0x97        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0x97        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfmsubadd132ps" = [
     b"y*y*w*"     , [0x02, 0x97        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0x97        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfmsubadd213pd" = [
     b"y*y*w*"     , [0x02, 0xA7        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xA7        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfmsubadd213ps" = [
     b"y*y*w*"     , [0x02, 0xA7        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xA7        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfmsubadd231pd" = [
     b"y*y*w*"     , [0x02, 0xB7        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xB7        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfmsubadd231ps" = [
     b"y*y*w*"     , [0x02, 0xB7        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xB7        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfmsubadd312pd" = [
     b"y*y*w*"     , [0x02, 0x97        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
@@ -3814,45 +4801,63 @@ Ops!(
 ]
 "vfnmadd132pd" = [
     b"y*y*w*"     , [0x02, 0x9C        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0x9C        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfnmadd132ps" = [
     b"y*y*w*"     , [0x02, 0x9C        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0x9C        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfnmadd132sd" = [
     b"yoyomq"     , [0x02, 0x9D        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0x9D        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
+    b"yoyomq"     , [0x02, 0x9D        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0x9D        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfnmadd132ss" = [
     b"yoyomd"     , [0x02, 0x9D        ], X, VEX_OP | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0x9D        ], X, VEX_OP | PREF_66, FMA;
+    b"yoyomd"     , [0x02, 0x9D        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0x9D        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfnmadd213pd" = [
     b"y*y*w*"     , [0x02, 0xAC        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xAC        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfnmadd213ps" = [
     b"y*y*w*"     , [0x02, 0xAC        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xAC        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfnmadd213sd" = [
     b"yoyomq"     , [0x02, 0xAD        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xAD        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
+    b"yoyomq"     , [0x02, 0xAD        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xAD        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfnmadd213ss" = [
     b"yoyomd"     , [0x02, 0xAD        ], X, VEX_OP | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xAD        ], X, VEX_OP | PREF_66, FMA;
+    b"yoyomd"     , [0x02, 0xAD        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xAD        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfnmadd231pd" = [
     b"y*y*w*"     , [0x02, 0xBC        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xBC        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfnmadd231ps" = [
     b"y*y*w*"     , [0x02, 0xBC        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xBC        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfnmadd231sd" = [
     b"yoyomq"     , [0x02, 0xBD        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xBD        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
+    b"yoyomq"     , [0x02, 0xBD        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xBD        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfnmadd231ss" = [
     b"yoyomd"     , [0x02, 0xBD        ], X, VEX_OP | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xBD        ], X, VEX_OP | PREF_66, FMA;
+    b"yoyomd"     , [0x02, 0xBD        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xBD        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfnmadd312pd" = [
     b"y*y*w*"     , [0x02, 0x9C        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
@@ -3916,45 +4921,63 @@ Ops!(
 ]
 "vfnmsub132pd" = [
     b"y*y*w*"     , [0x02, 0x9E        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0x9E        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfnmsub132ps" = [
     b"y*y*w*"     , [0x02, 0x9E        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0x9E        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfnmsub132sd" = [
     b"yoyomq"     , [0x02, 0x9F        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0x9F        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
+    b"yoyomq"     , [0x02, 0x9F        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0x9F        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfnmsub132ss" = [
     b"yoyomd"     , [0x02, 0x9F        ], X, VEX_OP | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0x9F        ], X, VEX_OP | PREF_66, FMA;
+    b"yoyomd"     , [0x02, 0x9F        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0x9F        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfnmsub213pd" = [
     b"y*y*w*"     , [0x02, 0xAE        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xAE        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfnmsub213ps" = [
     b"y*y*w*"     , [0x02, 0xAE        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xAE        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfnmsub213sd" = [
     b"yoyomq"     , [0x02, 0xAF        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xAF        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
+    b"yoyomq"     , [0x02, 0xAF        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xAF        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfnmsub213ss" = [
     b"yoyomd"     , [0x02, 0xAF        ], X, VEX_OP | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xAF        ], X, VEX_OP | PREF_66, FMA;
+    b"yoyomd"     , [0x02, 0xAF        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xAF        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfnmsub231pd" = [
     b"y*y*w*"     , [0x02, 0xBE        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xBE        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vfnmsub231ps" = [
     b"y*y*w*"     , [0x02, 0xBE        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
+    b"y*y*w*"     , [0x02, 0xBE        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vfnmsub231sd" = [
     b"yoyomq"     , [0x02, 0xBF        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xBF        ], X, VEX_OP | WITH_REXW | PREF_66, FMA;
+    b"yoyomq"     , [0x02, 0xBF        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xBF        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfnmsub231ss" = [
     b"yoyomd"     , [0x02, 0xBF        ], X, VEX_OP | PREF_66, FMA;
     b"yoyoyo"     , [0x02, 0xBF        ], X, VEX_OP | PREF_66, FMA;
+    b"yoyomd"     , [0x02, 0xBF        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x02, 0xBF        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vfnmsub312pd" = [
     b"y*y*w*"     , [0x02, 0x9E        ], X, VEX_OP | AUTO_VEXL | PREF_66, FMA;
@@ -4002,6 +5025,20 @@ Ops!(
     b"yoyoyomd"   , [0x03, 0x7E        ], X, VEX_OP | WITH_REXW | PREF_66, SSE5 | AMD;
     b"yoyoyoyo"   , [0x03, 0x7E        ], X, VEX_OP | WITH_REXW | PREF_66, SSE5 | AMD;
 ]
+"vfpclasspd" = [
+    b"jqw*ib"     , [0x03, 0x66        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | BCST_64, AVX512DQ;
+]
+"vfpclassps" = [
+    b"jqw*ib"     , [0x03, 0x66        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | BCST_32, AVX512DQ;
+]
+"vfpclasssd" = [
+    b"jqyoib"     , [0x03, 0x67        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK, AVX512DQ;
+    b"jqmqib"     , [0x03, 0x67        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK, AVX512DQ;
+]
+"vfpclassss" = [
+    b"jqyoib"     , [0x03, 0x67        ], X, EVEX_OP | PREF_66 | OPMASK, AVX512DQ;
+    b"jqmdib"     , [0x03, 0x67        ], X, EVEX_OP | PREF_66 | OPMASK, AVX512DQ;
+]
 "vfrczpd" = [
     b"y*w*"       , [0x09, 0x81        ], X, XOP_OP | AUTO_VEXL, SSE5 | AMD;
 ]
@@ -4018,15 +5055,87 @@ Ops!(
 ]
 "vgatherdpd" = [
     b"y*loy*"     , [0x02, 0x92        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
+    b"yolo"       , [0x02, 0x92        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"yhlo"       , [0x02, 0x92        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"ytlh"       , [0x02, 0x92        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F;
 ]
 "vgatherdps" = [
     b"y*k*y*"     , [0x02, 0x92        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
+    b"y*k*"       , [0x02, 0x92        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK_REQ, AVX512F;
+]
+"vgatherpf0dpd" = [
+    b"lh"         , [0x02, 0xC6        ], 1, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vgatherpf0dps" = [
+    b"kt"         , [0x02, 0xC6        ], 1, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vgatherpf0qpd" = [
+    b"lt"         , [0x02, 0xC7        ], 1, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vgatherpf0qps" = [
+    b"kt"         , [0x02, 0xC7        ], 1, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vgatherpf1dpd" = [
+    b"lh"         , [0x02, 0xC6        ], 2, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vgatherpf1dps" = [
+    b"kt"         , [0x02, 0xC6        ], 2, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vgatherpf1qpd" = [
+    b"lt"         , [0x02, 0xC7        ], 2, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vgatherpf1qps" = [
+    b"kt"         , [0x02, 0xC7        ], 2, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK_REQ, AVX512PF;
 ]
 "vgatherqpd" = [
     b"y*l*y*"     , [0x02, 0x93        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
+    b"y*l*"       , [0x02, 0x93        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F;
 ]
 "vgatherqps" = [
     b"yok*yo"     , [0x02, 0x93        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
+    b"yoko"       , [0x02, 0x93        ], X, EVEX_OP | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"yokh"       , [0x02, 0x93        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"yhkt"       , [0x02, 0x93        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK_REQ, AVX512F;
+]
+"vgetexppd" = [
+    b"y*w*"       , [0x02, 0x42        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | SAE, AVX512F;
+]
+"vgetexpps" = [
+    b"y*w*"       , [0x02, 0x42        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | SAE, AVX512F;
+]
+"vgetexpsd" = [
+    b"yoyoyo"     , [0x02, 0x43        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | SAE, AVX512F;
+    b"yoyomq"     , [0x02, 0x43        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vgetexpss" = [
+    b"yoyoyo"     , [0x02, 0x43        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | SAE, AVX512F;
+    b"yoyomd"     , [0x02, 0x43        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vgetmantpd" = [
+    b"y*w*ib"     , [0x03, 0x26        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | SAE, AVX512F;
+]
+"vgetmantps" = [
+    b"y*w*ib"     , [0x03, 0x26        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | SAE, AVX512F;
+]
+"vgetmantsd" = [
+    b"yoyoyoib"   , [0x03, 0x27        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | SAE, AVX512F;
+    b"yoyomqib"   , [0x03, 0x27        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vgetmantss" = [
+    b"yoyoyoib"   , [0x03, 0x27        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | SAE, AVX512F;
+    b"yoyomdib"   , [0x03, 0x27        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vgf2p8affineinvqb" = [
+    b"y*y*w*ib"   , [0x03, 0xCF        ], X, VEX_OP | AUTO_VEXL | WITH_REXW | PREF_66, GFNI | AVX;
+    b"y*y*w*ib"   , [0x03, 0xCF        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, GFNI | AVX512F;
+]
+"vgf2p8affineqb" = [
+    b"y*y*w*ib"   , [0x03, 0xCE        ], X, VEX_OP | AUTO_VEXL | WITH_REXW | PREF_66, GFNI | AVX;
+    b"y*y*w*ib"   , [0x03, 0xCE        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, GFNI | AVX512F;
+]
+"vgf2p8mulb" = [
+    b"y*y*w*"     , [0x02, 0xCF        ], X, VEX_OP | AUTO_VEXL | PREF_66, GFNI | AVX;
+    b"y*y*w*"     , [0x02, 0xCF        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, GFNI | AVX512F;
 ]
 "vhaddpd" = [
     b"y*y*w*"     , [0x01, 0x7C        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -4043,12 +5152,42 @@ Ops!(
 "vinsertf128" = [
     b"yhyhwoib"   , [0x03, 0x18        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX;
 ]
+"vinsertf32x4" = [
+    b"yhyhwoib"   , [0x03, 0x18        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytytwoib"   , [0x03, 0x18        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vinsertf32x8" = [
+    b"ytytwhib"   , [0x03, 0x1A        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vinsertf64x2" = [
+    b"yhyhwoib"   , [0x03, 0x18        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512DQ | AVX512VL;
+    b"ytytwoib"   , [0x03, 0x18        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vinsertf64x4" = [
+    b"ytytwhib"   , [0x03, 0x1A        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
 "vinserti128" = [
     b"yhyhwoib"   , [0x03, 0x38        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX2;
 ]
+"vinserti32x4" = [
+    b"yhyhwoib"   , [0x03, 0x38        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytytwoib"   , [0x03, 0x38        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vinserti32x8" = [
+    b"ytytwhib"   , [0x03, 0x3A        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vinserti64x2" = [
+    b"yhyhwoib"   , [0x03, 0x38        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512DQ | AVX512VL;
+    b"ytytwoib"   , [0x03, 0x38        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vinserti64x4" = [
+    b"ytytwhib"   , [0x03, 0x3A        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
 "vinsertps" = [
     b"yoyomdib"   , [0x03, 0x21        ], X, VEX_OP | PREF_66, AVX;
     b"yoyoyoib"   , [0x03, 0x21        ], X, VEX_OP | PREF_66, AVX;
+    b"yoyomdib"   , [0x03, 0x21        ], X, EVEX_OP | PREF_66, AVX512F;
+    b"yoyoyoib"   , [0x03, 0x21        ], X, EVEX_OP | PREF_66, AVX512F;
 ]
 "vlddqu" = [
     b"y*m*"       , [0x01, 0xF0        ], X, VEX_OP | AUTO_VEXL | PREF_F2, AVX;
@@ -4072,17 +5211,23 @@ Ops!(
 ]
 "vmaxpd" = [
     b"y*y*w*"     , [0x01, 0x5F        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x5F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | SAE, AVX512F;
 ]
 "vmaxps" = [
     b"y*y*w*"     , [0x01, 0x5F        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x5F        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32 | SAE, AVX512F;
 ]
 "vmaxsd" = [
     b"yoyomq"     , [0x01, 0x5F        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x5F        ], X, VEX_OP | PREF_F2, AVX;
+    b"yoyomq"     , [0x01, 0x5F        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x5F        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING | SAE, AVX512F;
 ]
 "vmaxss" = [
     b"yoyomd"     , [0x01, 0x5F        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x5F        ], X, VEX_OP | PREF_F3, AVX;
+    b"yoyomd"     , [0x01, 0x5F        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x5F        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING | SAE, AVX512F;
 ]
 "vmcall" = [
     b""           , [0x0F, 0x01, 0xC1  ], X, DEFAULT, VMX;
@@ -4095,17 +5240,23 @@ Ops!(
 ]
 "vminpd" = [
     b"y*y*w*"     , [0x01, 0x5D        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x5D        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | SAE, AVX512F;
 ]
 "vminps" = [
     b"y*y*w*"     , [0x01, 0x5D        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x5D        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32 | SAE, AVX512F;
 ]
 "vminsd" = [
     b"yoyomq"     , [0x01, 0x5D        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x5D        ], X, VEX_OP | PREF_F2, AVX;
+    b"yoyomq"     , [0x01, 0x5D        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x5D        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING | SAE, AVX512F;
 ]
 "vminss" = [
     b"yoyomd"     , [0x01, 0x5D        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x5D        ], X, VEX_OP | PREF_F3, AVX;
+    b"yoyomd"     , [0x01, 0x5D        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x5D        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING | SAE, AVX512F;
 ]
 "vmlaunch" = [
     b""           , [0x0F, 0x01, 0xC2  ], X, DEFAULT, VMX;
@@ -4120,51 +5271,95 @@ Ops!(
     b"y*w*"       , [0x01, 0x28        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
     b"whyh"       , [0x01, 0x29        ], X, VEX_OP | WITH_VEXL | ENC_MR | PREF_66, AVX;
     b"woyo"       , [0x01, 0x29        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
+    b"y*w*"       , [0x01, 0x28        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"w*y*"       , [0x01, 0x29        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vmovaps" = [
     b"y*w*"       , [0x01, 0x28        ], X, VEX_OP | AUTO_VEXL, AVX;
     b"whyh"       , [0x01, 0x29        ], X, VEX_OP | WITH_VEXL | ENC_MR, AVX;
     b"woyo"       , [0x01, 0x29        ], X, VEX_OP | ENC_MR, AVX;
+    b"y*w*"       , [0x01, 0x28        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING, AVX512F;
+    b"w*y*"       , [0x01, 0x29        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING, AVX512F;
 ]
 "vmovd" = [
     b"yovd"       , [0x01, 0x6E        ], X, VEX_OP | PREF_66, AVX;
     b"vdyo"       , [0x01, 0x7E        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
+    b"yovd"       , [0x01, 0x6E        ], X, EVEX_OP | PREF_66, AVX512F;
+    b"vdyo"       , [0x01, 0x7E        ], X, EVEX_OP | PREF_66, AVX512F;
 ]
 "vmovddup" = [
     b"y*w*"       , [0x01, 0x12        ], X, VEX_OP | AUTO_VEXL | PREF_F2, AVX;
     b"yomq"       , [0x01, 0x12        ], X, VEX_OP | PREF_F2, AVX;
+    b"yomq"       , [0x01, 0x12        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyo"       , [0x01, 0x12        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhwh"       , [0x01, 0x12        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytwt"       , [0x01, 0x12        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512F;
 ]
 "vmovdqa" = [
     b"y*w*"       , [0x01, 0x6F        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
     b"whyh"       , [0x01, 0x7F        ], X, VEX_OP | WITH_VEXL | ENC_MR | PREF_66, AVX;
     b"woyo"       , [0x01, 0x7F        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
 ]
+"vmovdqa32" = [
+    b"y*w*"       , [0x01, 0x6F        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"w*y*"       , [0x01, 0x7F        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vmovdqa64" = [
+    b"y*w*"       , [0x01, 0x6F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"w*y*"       , [0x01, 0x7F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
 "vmovdqu" = [
     b"y*w*"       , [0x01, 0x6F        ], X, VEX_OP | AUTO_VEXL | PREF_F3, AVX;
     b"whyh"       , [0x01, 0x7F        ], X, VEX_OP | WITH_VEXL | ENC_MR | PREF_F3, AVX;
     b"woyo"       , [0x01, 0x7F        ], X, VEX_OP | ENC_MR | PREF_F3, AVX;
 ]
+"vmovdqu16" = [
+    b"y*w*"       , [0x01, 0x6F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512BW;
+    b"w*y*"       , [0x01, 0x7F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512BW;
+]
+"vmovdqu32" = [
+    b"y*w*"       , [0x01, 0x6F        ], X, EVEX_OP | AUTO_VEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
+    b"w*y*"       , [0x01, 0x7F        ], X, EVEX_OP | AUTO_VEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
+]
+"vmovdqu64" = [
+    b"y*w*"       , [0x01, 0x6F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_F3 | OPMASK | ZEROING, AVX512F;
+    b"w*y*"       , [0x01, 0x7F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_F3 | OPMASK | ZEROING, AVX512F;
+]
+"vmovdqu8" = [
+    b"y*w*"       , [0x01, 0x6F        ], X, EVEX_OP | AUTO_VEXL | PREF_F2 | OPMASK | ZEROING, AVX512BW;
+    b"w*y*"       , [0x01, 0x7F        ], X, EVEX_OP | AUTO_VEXL | PREF_F2 | OPMASK | ZEROING, AVX512BW;
+]
 "vmovhlps" = [
     b"yoyoyo"     , [0x01, 0x12        ], X, VEX_OP, AVX;
+    b"yoyoyo"     , [0x01, 0x12        ], X, EVEX_OP, AVX512F;
 ]
 "vmovhpd" = [
     b"mqyo"       , [0x01, 0x17        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
     b"yoyomq"     , [0x01, 0x16        ], X, VEX_OP | PREF_66, AVX;
+    b"mqyo"       , [0x01, 0x17        ], X, EVEX_OP | WITH_REXW | PREF_66, AVX512F;
+    b"yoyomq"     , [0x01, 0x16        ], X, EVEX_OP | WITH_REXW | PREF_66, AVX512F;
 ]
 "vmovhps" = [
     b"mqyo"       , [0x01, 0x17        ], X, VEX_OP | ENC_MR, AVX;
     b"yoyomq"     , [0x01, 0x16        ], X, VEX_OP, AVX;
+    b"mqyo"       , [0x01, 0x17        ], X, EVEX_OP, AVX512F;
+    b"yoyomq"     , [0x01, 0x16        ], X, EVEX_OP, AVX512F;
 ]
 "vmovlhps" = [
     b"yoyoyo"     , [0x01, 0x16        ], X, VEX_OP, AVX;
+    b"yoyoyo"     , [0x01, 0x16        ], X, EVEX_OP, AVX512F;
 ]
 "vmovlpd" = [
     b"mqyo"       , [0x01, 0x13        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
     b"yoyomq"     , [0x01, 0x12        ], X, VEX_OP | PREF_66, AVX;
+    b"mqyo"       , [0x01, 0x13        ], X, EVEX_OP | WITH_REXW | PREF_66, AVX512F;
+    b"yoyomq"     , [0x01, 0x12        ], X, EVEX_OP | WITH_REXW | PREF_66, AVX512F;
 ]
 "vmovlps" = [
     b"mqyo"       , [0x01, 0x13        ], X, VEX_OP | ENC_MR, AVX;
     b"yoyomq"     , [0x01, 0x12        ], X, VEX_OP, AVX;
+    b"mqyo"       , [0x01, 0x13        ], X, EVEX_OP, AVX512F;
+    b"yoyomq"     , [0x01, 0x12        ], X, EVEX_OP, AVX512F;
 ]
 "vmovmskpd" = [
     b"r*y*"       , [0x01, 0x50        ], X, VEX_OP | PREF_66, AVX;
@@ -4174,15 +5369,19 @@ Ops!(
 ]
 "vmovntdq" = [
     b"m*y*"       , [0x01, 0xE7        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
+    b"m*y*"       , [0x01, 0xE7        ], X, EVEX_OP | AUTO_VEXL | PREF_66, AVX512F;
 ]
 "vmovntdqa" = [
     b"y*m*"       , [0x02, 0x2A        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*m*"       , [0x02, 0x2A        ], X, EVEX_OP | AUTO_VEXL | PREF_66, AVX512F;
 ]
 "vmovntpd" = [
     b"m*y*"       , [0x01, 0x2B        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
+    b"m*y*"       , [0x01, 0x2B        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66, AVX512F;
 ]
 "vmovntps" = [
     b"m*y*"       , [0x01, 0x2B        ], X, VEX_OP | AUTO_VEXL | ENC_MR, AVX;
+    b"m*y*"       , [0x01, 0x2B        ], X, EVEX_OP | AUTO_VEXL, AVX512F;
 ]
 "vmovntqq" = [
     b"mhyh"       , [0x01, 0xE7        ], X, WITH_VEXL | VEX_OP | ENC_MR | PREF_66, AVX;
@@ -4194,6 +5393,11 @@ Ops!(
     b"yoyo"       , [0x01, 0x7E        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyo"       , [0x01, 0xD6        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
     b"vqyo"       , [0x01, 0x7E        ], X, WITH_REXW | VEX_OP | ENC_MR | PREF_66, AVX;
+    b"mqyo"       , [0x01, 0xD6        ], X, EVEX_OP | WITH_REXW | PREF_66, AVX512F;
+    b"yomq"       , [0x01, 0x7E        ], X, EVEX_OP | WITH_REXW | PREF_F3, AVX512F;
+    b"yovq"       , [0x01, 0x6E        ], X, EVEX_OP | WITH_REXW | PREF_66, AVX512F;
+    b"yoyo"       , [0x01, 0x7E        ], X, EVEX_OP | WITH_REXW | PREF_F3, AVX512F;
+    b"vqyo"       , [0x01, 0x7E        ], X, EVEX_OP | WITH_REXW | PREF_66, AVX512F;
 ]
 "vmovqqa" = [
     b"yhwh"       , [0x01, 0x6F        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX;
@@ -4208,28 +5412,40 @@ Ops!(
     b"yomq"       , [0x01, 0x10        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x10        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x11        ], X, VEX_OP | ENC_VM | PREF_F2, AVX;
+    b"mqyo"       , [0x01, 0x11        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK, AVX512F;
+    b"yomq"       , [0x01, 0x10        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x10        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512F;
 ]
 "vmovshdup" = [
     b"y*w*"       , [0x01, 0x16        ], X, VEX_OP | AUTO_VEXL | PREF_F3, AVX;
+    b"y*w*"       , [0x01, 0x16        ], X, EVEX_OP | AUTO_VEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
 ]
 "vmovsldup" = [
     b"y*w*"       , [0x01, 0x12        ], X, VEX_OP | AUTO_VEXL | PREF_F3, AVX;
+    b"y*w*"       , [0x01, 0x12        ], X, EVEX_OP | AUTO_VEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
 ]
 "vmovss" = [
     b"mdyo"       , [0x01, 0x11        ], X, VEX_OP | ENC_MR | PREF_F3, AVX;
     b"yomd"       , [0x01, 0x10        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x10        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x11        ], X, VEX_OP | ENC_VM | PREF_F3, AVX;
+    b"mdyo"       , [0x01, 0x11        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F;
+    b"yomd"       , [0x01, 0x10        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x10        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING, AVX512F;
 ]
 "vmovupd" = [
     b"y*w*"       , [0x01, 0x10        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
     b"whyh"       , [0x01, 0x11        ], X, VEX_OP | WITH_VEXL | ENC_MR | PREF_66, AVX;
     b"woyo"       , [0x01, 0x11        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
+    b"y*w*"       , [0x01, 0x10        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"w*y*"       , [0x01, 0x11        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vmovups" = [
     b"y*w*"       , [0x01, 0x10        ], X, VEX_OP | AUTO_VEXL, AVX;
     b"whyh"       , [0x01, 0x11        ], X, VEX_OP | WITH_VEXL | ENC_MR, AVX;
     b"woyo"       , [0x01, 0x11        ], X, VEX_OP | ENC_MR, AVX;
+    b"y*w*"       , [0x01, 0x10        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING, AVX512F;
+    b"w*y*"       , [0x01, 0x11        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING, AVX512F;
 ]
 "vmpsadbw" = [
     b"y*y*w*ib"   , [0x03, 0x42        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
@@ -4254,17 +5470,23 @@ Ops!(
 ]
 "vmulpd" = [
     b"y*y*w*"     , [0x01, 0x59        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x59        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vmulps" = [
     b"y*y*w*"     , [0x01, 0x59        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x59        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vmulsd" = [
     b"yoyomq"     , [0x01, 0x59        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x59        ], X, VEX_OP | PREF_F2, AVX;
+    b"yoyomq"     , [0x01, 0x59        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x59        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vmulss" = [
     b"yoyomd"     , [0x01, 0x59        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x59        ], X, VEX_OP | PREF_F3, AVX;
+    b"yoyomd"     , [0x01, 0x59        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x59        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vmwrite" = [
     b"rqvq"       , [0x0F, 0x79        ], X, DEFAULT, VMX;
@@ -4277,73 +5499,120 @@ Ops!(
 ]
 "vorpd" = [
     b"y*y*w*"     , [0x01, 0x56        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x56        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512DQ;
 ]
 "vorps" = [
     b"y*y*w*"     , [0x01, 0x56        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x56        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32, AVX512DQ;
 ]
 "vpabsb" = [
     b"y*w*"       , [0x02, 0x1C        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*w*"       , [0x02, 0x1C        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpabsd" = [
     b"y*w*"       , [0x02, 0x1E        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*w*"       , [0x02, 0x1E        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpabsq" = [
+    b"y*w*"       , [0x02, 0x1F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpabsw" = [
     b"y*w*"       , [0x02, 0x1D        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*w*"       , [0x02, 0x1D        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpackssdw" = [
     b"y*y*w*"     , [0x01, 0x6B        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x6B        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512BW;
 ]
 "vpacksswb" = [
     b"y*y*w*"     , [0x01, 0x63        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x63        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpackusdw" = [
     b"y*y*w*"     , [0x02, 0x2B        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x2B        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512BW;
 ]
 "vpackuswb" = [
     b"y*y*w*"     , [0x01, 0x67        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x67        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpaddb" = [
     b"y*y*w*"     , [0x01, 0xFC        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xFC        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpaddd" = [
     b"y*y*w*"     , [0x01, 0xFE        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xFE        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vpaddq" = [
     b"y*y*w*"     , [0x01, 0xD4        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xD4        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpaddsb" = [
     b"y*y*w*"     , [0x01, 0xEC        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xEC        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpaddsw" = [
     b"y*y*w*"     , [0x01, 0xED        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xED        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpaddusb" = [
     b"y*y*w*"     , [0x01, 0xDC        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xDC        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpaddusw" = [
     b"y*y*w*"     , [0x01, 0xDD        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xDD        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpaddw" = [
     b"y*y*w*"     , [0x01, 0xFD        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xFD        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpalignr" = [
     b"y*y*w*ib"   , [0x03, 0x0F        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
+    b"y*y*w*ib"   , [0x03, 0x0F        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpand" = [
     b"y*y*w*"     , [0x01, 0xDB        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
+"vpandd" = [
+    b"y*y*w*"     , [0x01, 0xDB        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
 "vpandn" = [
     b"y*y*w*"     , [0x01, 0xDF        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
+"vpandnd" = [
+    b"y*y*w*"     , [0x01, 0xDF        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpandnq" = [
+    b"y*y*w*"     , [0x01, 0xDF        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vpandq" = [
+    b"y*y*w*"     , [0x01, 0xDB        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
 "vpavgb" = [
     b"y*y*w*"     , [0x01, 0xE0        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xE0        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpavgw" = [
     b"y*y*w*"     , [0x01, 0xE3        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xE3        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpblendd" = [
     b"y*y*w*ib"   , [0x03, 0x02        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
 ]
+"vpblendmb" = [
+    b"y*y*w*"     , [0x02, 0x66        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
+]
+"vpblendmd" = [
+    b"y*y*w*"     , [0x02, 0x64        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpblendmq" = [
+    b"y*y*w*"     , [0x02, 0x64        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vpblendmw" = [
+    b"y*y*w*"     , [0x02, 0x66        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512BW;
+]
 "vpblendvb" = [
     b"y*y*w*y*"   , [0x03, 0x4C        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
@@ -4353,19 +5622,37 @@ Ops!(
 "vpbroadcastb" = [
     b"y*mb"       , [0x02, 0x78        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
     b"y*yo"       , [0x02, 0x78        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
+    b"y*mb"       , [0x02, 0x78        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
+    b"y*yo"       , [0x02, 0x78        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
+    b"y*rd"       , [0x02, 0x7A        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpbroadcastd" = [
     b"y*md"       , [0x02, 0x58        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
     b"y*yo"       , [0x02, 0x58        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
+    b"y*md"       , [0x02, 0x58        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"y*yo"       , [0x02, 0x58        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"y*rd"       , [0x02, 0x7C        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vpbroadcastmb2q" = [
+    b"y*jq"       , [0x02, 0x2A        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_F3, AVX512CD;
+]
+"vpbroadcastmw2d" = [
+    b"y*jq"       , [0x02, 0x3A        ], X, EVEX_OP | AUTO_VEXL | PREF_F3, AVX512CD;
 ]
 "vpbroadcastq" = [
     b"yhmq"       , [0x02, 0x59        ], X, VEX_OP | WITH_VEXL | PREF_66, AVX2;
     b"yomq"       , [0x02, 0x59        ], X, VEX_OP | PREF_66, AVX2;
     b"y*yo"       , [0x02, 0x59        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
+    b"y*mq"       , [0x02, 0x59        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"y*yo"       , [0x02, 0x59        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"y*rq"       , [0x02, 0x7C        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vpbroadcastw" = [
     b"y*mw"       , [0x02, 0x79        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
     b"y*yo"       , [0x02, 0x79        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
+    b"y*mw"       , [0x02, 0x79        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
+    b"y*yo"       , [0x02, 0x79        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
+    b"y*rd"       , [0x02, 0x7B        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpclmulhqhqdq" = [
     b"yoyowo"     , [0x03, 0x44, 0x11  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
@@ -4386,17 +5673,39 @@ Ops!(
     b"y*y*w*y*"   , [0x08, 0xA2        ], X, XOP_OP | AUTO_VEXL, SSE5 | AMD;
     b"y*y*y*w*"   , [0x08, 0xA2        ], X, XOP_OP | AUTO_VEXL, AMD | SSE5;
 ]
+"vpcmpb" = [
+    b"jqy*w*ib"   , [0x03, 0x3F        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK, AVX512BW;
+]
+"vpcmpd" = [
+    b"jqy*w*ib"   , [0x03, 0x1F        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | BCST_32, AVX512F;
+]
 "vpcmpeqb" = [
     b"y*y*w*"     , [0x01, 0x74        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0x74        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK, AVX512BW;
 ]
 "vpcmpeqd" = [
     b"y*y*w*"     , [0x01, 0x76        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0x76        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | BCST_32, AVX512F;
 ]
 "vpcmpeqq" = [
     b"y*y*w*"     , [0x02, 0x29        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"jqy*w*"     , [0x02, 0x29        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | BCST_64, AVX512F;
+]
+"vpcmpequb" = [
+    b"jqy*w*"     , [0x03, 0x3E, 0x00  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpequd" = [
+    b"jqy*w*"     , [0x03, 0x1E, 0x00  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK | BCST_32, AVX512F;
+]
+"vpcmpequq" = [
+    b"jqy*w*"     , [0x03, 0x1E, 0x00  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64, AVX512F;
+]
+"vpcmpequw" = [
+    b"jqy*w*"     , [0x03, 0x3E, 0x00  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK, AVX512BW;
 ]
 "vpcmpeqw" = [
     b"y*y*w*"     , [0x01, 0x75        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0x75        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK, AVX512BW;
 ]
 "vpcmpestri" = [
     b"yowoib"     , [0x03, 0x61        ], X, VEX_OP | PREF_66, AVX;
@@ -4406,15 +5715,19 @@ Ops!(
 ]
 "vpcmpgtb" = [
     b"y*y*w*"     , [0x01, 0x64        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0x64        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK, AVX512BW;
 ]
 "vpcmpgtd" = [
     b"y*y*w*"     , [0x01, 0x66        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0x66        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | BCST_32, AVX512F;
 ]
 "vpcmpgtq" = [
     b"y*y*w*"     , [0x02, 0x37        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"jqy*w*"     , [0x02, 0x37        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | BCST_64, AVX512F;
 ]
 "vpcmpgtw" = [
     b"y*y*w*"     , [0x01, 0x65        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"jqy*w*"     , [0x01, 0x65        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK, AVX512BW;
 ]
 "vpcmpistri" = [
     b"yowoib"     , [0x03, 0x63        ], X, VEX_OP | PREF_66, AVX;
@@ -4422,12 +5735,156 @@ Ops!(
 "vpcmpistrm" = [
     b"yowoib"     , [0x03, 0x62        ], X, VEX_OP | PREF_66, AVX;
 ]
+"vpcmpleb" = [
+    b"jqy*w*"     , [0x03, 0x3F, 0x02  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpled" = [
+    b"jqy*w*"     , [0x03, 0x1F, 0x02  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK | BCST_32, AVX512F;
+]
+"vpcmpleq" = [
+    b"jqy*w*"     , [0x03, 0x1F, 0x02  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64, AVX512F;
+]
+"vpcmpleub" = [
+    b"jqy*w*"     , [0x03, 0x3E, 0x02  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpleud" = [
+    b"jqy*w*"     , [0x03, 0x1E, 0x02  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK | BCST_32, AVX512F;
+]
+"vpcmpleuq" = [
+    b"jqy*w*"     , [0x03, 0x1E, 0x02  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64, AVX512F;
+]
+"vpcmpleuw" = [
+    b"jqy*w*"     , [0x03, 0x3E, 0x02  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmplew" = [
+    b"jqy*w*"     , [0x03, 0x3F, 0x02  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpltb" = [
+    b"jqy*w*"     , [0x03, 0x3F, 0x01  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpltd" = [
+    b"jqy*w*"     , [0x03, 0x1F, 0x01  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK | BCST_32, AVX512F;
+]
+"vpcmpltq" = [
+    b"jqy*w*"     , [0x03, 0x1F, 0x01  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64, AVX512F;
+]
+"vpcmpltub" = [
+    b"jqy*w*"     , [0x03, 0x3E, 0x01  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpltud" = [
+    b"jqy*w*"     , [0x03, 0x1E, 0x01  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK | BCST_32, AVX512F;
+]
+"vpcmpltuq" = [
+    b"jqy*w*"     , [0x03, 0x1E, 0x01  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64, AVX512F;
+]
+"vpcmpltuw" = [
+    b"jqy*w*"     , [0x03, 0x3E, 0x01  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpltw" = [
+    b"jqy*w*"     , [0x03, 0x3F, 0x01  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpneqb" = [
+    b"jqy*w*"     , [0x03, 0x3F, 0x04  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpneqd" = [
+    b"jqy*w*"     , [0x03, 0x1F, 0x04  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK | BCST_32, AVX512F;
+]
+"vpcmpneqq" = [
+    b"jqy*w*"     , [0x03, 0x1F, 0x04  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64, AVX512F;
+]
+"vpcmpnequb" = [
+    b"jqy*w*"     , [0x03, 0x3E, 0x04  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpnequd" = [
+    b"jqy*w*"     , [0x03, 0x1E, 0x04  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK | BCST_32, AVX512F;
+]
+"vpcmpnequq" = [
+    b"jqy*w*"     , [0x03, 0x1E, 0x04  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64, AVX512F;
+]
+"vpcmpnequw" = [
+    b"jqy*w*"     , [0x03, 0x3E, 0x04  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpneqw" = [
+    b"jqy*w*"     , [0x03, 0x3F, 0x04  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpnleb" = [
+    b"jqy*w*"     , [0x03, 0x3F, 0x06  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpnled" = [
+    b"jqy*w*"     , [0x03, 0x1F, 0x06  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK | BCST_32, AVX512F;
+]
+"vpcmpnleq" = [
+    b"jqy*w*"     , [0x03, 0x1F, 0x06  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64, AVX512F;
+]
+"vpcmpnleub" = [
+    b"jqy*w*"     , [0x03, 0x3E, 0x06  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpnleud" = [
+    b"jqy*w*"     , [0x03, 0x1E, 0x06  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK | BCST_32, AVX512F;
+]
+"vpcmpnleuq" = [
+    b"jqy*w*"     , [0x03, 0x1E, 0x06  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64, AVX512F;
+]
+"vpcmpnleuw" = [
+    b"jqy*w*"     , [0x03, 0x3E, 0x06  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpnlew" = [
+    b"jqy*w*"     , [0x03, 0x3F, 0x06  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpnltb" = [
+    b"jqy*w*"     , [0x03, 0x3F, 0x05  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpnltd" = [
+    b"jqy*w*"     , [0x03, 0x1F, 0x05  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK | BCST_32, AVX512F;
+]
+"vpcmpnltq" = [
+    b"jqy*w*"     , [0x03, 0x1F, 0x05  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64, AVX512F;
+]
+"vpcmpnltub" = [
+    b"jqy*w*"     , [0x03, 0x3E, 0x05  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpnltud" = [
+    b"jqy*w*"     , [0x03, 0x1E, 0x05  ], X, EVEX_OP | AUTO_VEXL | PREF_66 | IMM_OP | OPMASK | BCST_32, AVX512F;
+]
+"vpcmpnltuq" = [
+    b"jqy*w*"     , [0x03, 0x1E, 0x05  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK | BCST_64, AVX512F;
+]
+"vpcmpnltuw" = [
+    b"jqy*w*"     , [0x03, 0x3E, 0x05  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpnltw" = [
+    b"jqy*w*"     , [0x03, 0x3F, 0x05  ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | IMM_OP | OPMASK, AVX512BW;
+]
+"vpcmpq" = [
+    b"jqy*w*ib"   , [0x03, 0x1F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | BCST_64, AVX512F;
+]
+"vpcmpub" = [
+    b"jqy*w*ib"   , [0x03, 0x3E        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK, AVX512BW;
+]
+"vpcmpud" = [
+    b"jqy*w*ib"   , [0x03, 0x1E        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | BCST_32, AVX512F;
+]
+"vpcmpuq" = [
+    b"jqy*w*ib"   , [0x03, 0x1E        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | BCST_64, AVX512F;
+]
+"vpcmpuw" = [
+    b"jqy*w*ib"   , [0x03, 0x3E        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK, AVX512BW;
+]
+"vpcmpw" = [
+    b"jqy*w*ib"   , [0x03, 0x3F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK, AVX512BW;
+]
 "vpcomb" = [
     b"yoyowoib"   , [0x08, 0xCC        ], X, XOP_OP, AMD | SSE5;
 ]
 "vpcomd" = [
     b"yoyowoib"   , [0x08, 0xCE        ], X, XOP_OP, AMD | SSE5;
 ]
+"vpcompressd" = [
+    b"w*y*"       , [0x02, 0x8B        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | T1S_32, AVX512F;
+]
+"vpcompressq" = [
+    b"w*y*"       , [0x02, 0x8B        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | T1S_64, AVX512F;
+]
 "vpcomq" = [
     b"yoyowoib"   , [0x08, 0xCF        ], X, XOP_OP, SSE5 | AMD;
 ]
@@ -4446,43 +5903,129 @@ Ops!(
 "vpcomw" = [
     b"yoyowoib"   , [0x08, 0xCD        ], X, XOP_OP, AMD | SSE5;
 ]
+"vpconflictd" = [
+    b"y*w*"       , [0x02, 0xC4        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512CD;
+]
+"vpconflictq" = [
+    b"y*w*"       , [0x02, 0xC4        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512CD;
+]
+"vpdpbusd" = [
+    b"y*y*w*"     , [0x02, 0x50        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVXVNNI;
+]
+"vpdpbusds" = [
+    b"y*y*w*"     , [0x02, 0x51        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVXVNNI;
+]
+"vpdpwssd" = [
+    b"y*y*w*"     , [0x02, 0x52        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVXVNNI;
+]
+"vpdpwssds" = [
+    b"y*y*w*"     , [0x02, 0x53        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVXVNNI;
+]
 "vperm2f128" = [
     b"yhyhwhib"   , [0x03, 0x06        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX;
 ]
 "vperm2i128" = [
     b"yhyhwhib"   , [0x03, 0x46        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX2;
 ]
+"vpermb" = [
+    b"y*y*w*"     , [0x02, 0x8D        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512VBMI;
+]
 "vpermd" = [
     b"yhyhwh"     , [0x02, 0x36        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX2;
+    b"yhyhwh"     , [0x02, 0x36        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F | AVX512VL;
+    b"ytytwt"     , [0x02, 0x36        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpermi2b" = [
+    b"y*y*w*"     , [0x02, 0x75        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512VBMI;
+]
+"vpermi2d" = [
+    b"y*y*w*"     , [0x02, 0x76        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpermi2pd" = [
+    b"y*y*w*"     , [0x02, 0x77        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vpermi2ps" = [
+    b"y*y*w*"     , [0x02, 0x77        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpermi2q" = [
+    b"y*y*w*"     , [0x02, 0x76        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vpermi2w" = [
+    b"y*y*w*"     , [0x02, 0x75        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpermilpd" = [
     b"y*y*w*"     , [0x02, 0x0D        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
     b"y*w*ib"     , [0x03, 0x05        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x0D        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+    b"y*w*ib"     , [0x03, 0x05        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpermilps" = [
     b"y*y*w*"     , [0x02, 0x0C        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
     b"y*w*ib"     , [0x03, 0x04        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x0C        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+    b"y*w*ib"     , [0x03, 0x04        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vpermpd" = [
     b"yhwhib"     , [0x03, 0x01        ], X, WITH_VEXL | WITH_REXW | VEX_OP | PREF_66, AVX2;
+    b"yhwhib"     , [0x03, 0x01        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"yhyhwh"     , [0x02, 0x16        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"ytytwt"     , [0x02, 0x16        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+    b"ytwtib"     , [0x03, 0x01        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpermps" = [
     b"yhyhwh"     , [0x02, 0x16        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX2;
+    b"yhyhwh"     , [0x02, 0x16        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F | AVX512VL;
+    b"ytytwt"     , [0x02, 0x16        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vpermq" = [
     b"yhwhib"     , [0x03, 0x00        ], X, WITH_VEXL | WITH_REXW | VEX_OP | PREF_66, AVX2;
+    b"yhwhib"     , [0x03, 0x00        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"yhyhwh"     , [0x02, 0x36        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"ytytwt"     , [0x02, 0x36        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+    b"ytwtib"     , [0x03, 0x00        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vpermt2b" = [
+    b"y*y*w*"     , [0x02, 0x7D        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512VBMI;
+]
+"vpermt2d" = [
+    b"y*y*w*"     , [0x02, 0x7E        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpermt2pd" = [
+    b"y*y*w*"     , [0x02, 0x7F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vpermt2ps" = [
+    b"y*y*w*"     , [0x02, 0x7F        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpermt2q" = [
+    b"y*y*w*"     , [0x02, 0x7E        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vpermt2w" = [
+    b"y*y*w*"     , [0x02, 0x7D        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512BW;
+]
+"vpermw" = [
+    b"y*y*w*"     , [0x02, 0x8D        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512BW;
+]
+"vpexpandd" = [
+    b"y*w*"       , [0x02, 0x89        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | T1S_32, AVX512F;
+]
+"vpexpandq" = [
+    b"y*w*"       , [0x02, 0x89        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | T1S_64, AVX512F;
 ]
 "vpextrb" = [
     b"mbyoib"     , [0x03, 0x14        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
     b"rdyoib"     , [0x03, 0x14        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
     b"rqyoib"     , [0x03, 0x14        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
+    b"mbyoib"     , [0x03, 0x14        ], X, EVEX_OP | PREF_66, AVX512BW;
+    b"rdyoib"     , [0x03, 0x14        ], X, EVEX_OP | ENC_MR | PREF_66, AVX512BW;
 ]
 "vpextrd" = [
     b"rqyoib"     , [0x03, 0x16        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
     b"vdyoib"     , [0x03, 0x16        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
+    b"vdyoib"     , [0x03, 0x16        ], X, EVEX_OP | PREF_66, AVX512DQ;
 ]
 "vpextrq" = [
     b"vqyoib"     , [0x03, 0x16        ], X, WITH_REXW | VEX_OP | ENC_MR | PREF_66, AVX;
+    b"vqyoib"     , [0x03, 0x16        ], X, EVEX_OP | WITH_REXW | PREF_66, AVX512DQ;
 ]
 "vpextrw" = [
     b"mwyoib"     , [0x03, 0x15        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
@@ -4490,18 +6033,28 @@ Ops!(
     b"rdyoib"     , [0x03, 0x15        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
     b"rqyoib"     , [0x01, 0xC5        ], X, VEX_OP | PREF_66, AVX;
     b"rqyoib"     , [0x03, 0x15        ], X, VEX_OP | ENC_MR | PREF_66, AVX;
+    b"mwyoib"     , [0x03, 0x15        ], X, EVEX_OP | PREF_66, AVX512BW;
+    b"rdyoib"     , [0x01, 0xC5        ], X, EVEX_OP | PREF_66, AVX512BW;
 ]
 "vpgatherdd" = [
     b"y*k*y*"     , [0x02, 0x90        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
+    b"y*k*"       , [0x02, 0x90        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK_REQ, AVX512F;
 ]
 "vpgatherdq" = [
     b"y*loy*"     , [0x02, 0x90        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
+    b"yolo"       , [0x02, 0x90        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"yhlo"       , [0x02, 0x90        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"ytlh"       , [0x02, 0x90        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F;
 ]
 "vpgatherqd" = [
     b"yok*yo"     , [0x02, 0x91        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
+    b"yoko"       , [0x02, 0x91        ], X, EVEX_OP | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"yokh"       , [0x02, 0x91        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"yhkt"       , [0x02, 0x91        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK_REQ, AVX512F;
 ]
 "vpgatherqq" = [
     b"y*l*y*"     , [0x02, 0x91        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
+    b"y*l*"       , [0x02, 0x91        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F;
 ]
 "vphaddbd" = [
     b"yowo"       , [0x09, 0xC2        ], X, XOP_OP, SSE5 | AMD;
@@ -4572,16 +6125,28 @@ Ops!(
 "vpinsrb" = [
     b"yoyordib"   , [0x03, 0x20        ], X, VEX_OP | PREF_66, AVX;
     b"yoyovbib"   , [0x03, 0x20        ], X, VEX_OP | PREF_66, AVX;
+    b"yoyordib"   , [0x03, 0x20        ], X, EVEX_OP | PREF_66, AVX512BW;
+    b"yoyombib"   , [0x03, 0x20        ], X, EVEX_OP | PREF_66, AVX512BW;
 ]
 "vpinsrd" = [
     b"yoyovdib"   , [0x03, 0x22        ], X, VEX_OP | PREF_66, AVX;
+    b"yoyovdib"   , [0x03, 0x22        ], X, EVEX_OP | PREF_66, AVX512DQ;
 ]
 "vpinsrq" = [
     b"yoyovqib"   , [0x03, 0x22        ], X, VEX_OP | WITH_REXW | PREF_66, AVX;
+    b"yoyovqib"   , [0x03, 0x22        ], X, EVEX_OP | WITH_REXW | PREF_66, AVX512DQ;
 ]
 "vpinsrw" = [
     b"yoyordib"   , [0x01, 0xC4        ], X, VEX_OP | PREF_66, AVX;
     b"yoyovwib"   , [0x01, 0xC4        ], X, VEX_OP | PREF_66, AVX;
+    b"yoyordib"   , [0x01, 0xC4        ], X, EVEX_OP | PREF_66, AVX512BW;
+    b"yoyomwib"   , [0x01, 0xC4        ], X, EVEX_OP | PREF_66, AVX512BW;
+]
+"vplzcntd" = [
+    b"y*w*"       , [0x02, 0x44        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512CD;
+]
+"vplzcntq" = [
+    b"y*w*"       , [0x02, 0x44        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512CD;
 ]
 "vpmacsdd" = [
     b"yoyowoyo"   , [0x08, 0x9E        ], X, XOP_OP, AMD | SSE5;
@@ -4619,11 +6184,19 @@ Ops!(
 "vpmadcswd" = [
     b"yoyowoyo"   , [0x08, 0xB6        ], X, XOP_OP, AMD | SSE5;
 ]
+"vpmadd52huq" = [
+    b"y*y*w*"     , [0x02, 0xB5        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512IFMA;
+]
+"vpmadd52luq" = [
+    b"y*y*w*"     , [0x02, 0xB4        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512IFMA;
+]
 "vpmaddubsw" = [
     b"y*y*w*"     , [0x02, 0x04        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x04        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpmaddwd" = [
     b"y*y*w*"     , [0x01, 0xF5        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xF5        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpmaskmovd" = [
     b"m*y*y*"     , [0x02, 0x8E        ], X, VEX_OP | AUTO_VEXL | ENC_VM | PREF_66, AVX2;
@@ -4635,119 +6208,376 @@ Ops!(
 ]
 "vpmaxsb" = [
     b"y*y*w*"     , [0x02, 0x3C        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x3C        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpmaxsd" = [
     b"y*y*w*"     , [0x02, 0x3D        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x3D        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpmaxsq" = [
+    b"y*y*w*"     , [0x02, 0x3D        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpmaxsw" = [
     b"y*y*w*"     , [0x01, 0xEE        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xEE        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpmaxub" = [
     b"y*y*w*"     , [0x01, 0xDE        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xDE        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpmaxud" = [
     b"y*y*w*"     , [0x02, 0x3F        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x3F        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpmaxuq" = [
+    b"y*y*w*"     , [0x02, 0x3F        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpmaxuw" = [
     b"y*y*w*"     , [0x02, 0x3E        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x3E        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpminsb" = [
     b"y*y*w*"     , [0x02, 0x38        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x38        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpminsd" = [
     b"y*y*w*"     , [0x02, 0x39        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x39        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpminsq" = [
+    b"y*y*w*"     , [0x02, 0x39        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpminsw" = [
     b"y*y*w*"     , [0x01, 0xEA        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xEA        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpminub" = [
     b"y*y*w*"     , [0x01, 0xDA        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xDA        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpminud" = [
     b"y*y*w*"     , [0x02, 0x3B        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x3B        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpminuq" = [
+    b"y*y*w*"     , [0x02, 0x3B        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpminuw" = [
     b"y*y*w*"     , [0x02, 0x3A        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x3A        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
+]
+"vpmovb2m" = [
+    b"jqy*"       , [0x02, 0x29        ], X, EVEX_OP | AUTO_VEXL | PREF_F3, AVX512BW;
+]
+"vpmovd2m" = [
+    b"jqy*"       , [0x02, 0x39        ], X, EVEX_OP | AUTO_VEXL | PREF_F3, AVX512DQ;
+]
+"vpmovdb" = [
+    b"yoyo"       , [0x02, 0x31        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyh"       , [0x02, 0x31        ], X, EVEX_OP | WITH_VEXL | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"mdyo"       , [0x02, 0x31        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"mqyh"       , [0x02, 0x31        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"woyt"       , [0x02, 0x31        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
+]
+"vpmovdw" = [
+    b"yoyo"       , [0x02, 0x33        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"mqyo"       , [0x02, 0x33        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"woyh"       , [0x02, 0x33        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"whyt"       , [0x02, 0x33        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
+]
+"vpmovm2b" = [
+    b"y*jq"       , [0x02, 0x28        ], X, EVEX_OP | AUTO_VEXL | PREF_F3, AVX512BW;
+]
+"vpmovm2d" = [
+    b"y*jq"       , [0x02, 0x38        ], X, EVEX_OP | AUTO_VEXL | PREF_F3, AVX512DQ;
+]
+"vpmovm2q" = [
+    b"y*jq"       , [0x02, 0x38        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_F3, AVX512DQ;
+]
+"vpmovm2w" = [
+    b"y*jq"       , [0x02, 0x28        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_F3, AVX512BW;
 ]
 "vpmovmskb" = [
     b"r*y*"       , [0x01, 0xD7        ], X, VEX_OP | PREF_66, AVX;
 ]
+"vpmovq2m" = [
+    b"jqy*"       , [0x02, 0x39        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_F3, AVX512DQ;
+]
+"vpmovqb" = [
+    b"yoy*"       , [0x02, 0x32        ], X, EVEX_OP | AUTO_VEXL | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F;
+    b"mwyo"       , [0x02, 0x32        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"mdyh"       , [0x02, 0x32        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"mqyt"       , [0x02, 0x32        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK, AVX512F;
+]
+"vpmovqd" = [
+    b"yoyo"       , [0x02, 0x35        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"mqyo"       , [0x02, 0x35        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"woyh"       , [0x02, 0x35        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"whyt"       , [0x02, 0x35        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
+]
+"vpmovqw" = [
+    b"yoyo"       , [0x02, 0x34        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyh"       , [0x02, 0x34        ], X, EVEX_OP | WITH_VEXL | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"mdyo"       , [0x02, 0x34        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"mqyh"       , [0x02, 0x34        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"woyt"       , [0x02, 0x34        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
+]
+"vpmovsdb" = [
+    b"yoyo"       , [0x02, 0x21        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyh"       , [0x02, 0x21        ], X, EVEX_OP | WITH_VEXL | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"mdyo"       , [0x02, 0x21        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"mqyh"       , [0x02, 0x21        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"woyt"       , [0x02, 0x21        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
+]
+"vpmovsdw" = [
+    b"yoyo"       , [0x02, 0x23        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"mqyo"       , [0x02, 0x23        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"woyh"       , [0x02, 0x23        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"whyt"       , [0x02, 0x23        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
+]
+"vpmovsqb" = [
+    b"yoy*"       , [0x02, 0x22        ], X, EVEX_OP | AUTO_VEXL | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F;
+    b"mwyo"       , [0x02, 0x22        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"mdyh"       , [0x02, 0x22        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"mqyt"       , [0x02, 0x22        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK, AVX512F;
+]
+"vpmovsqd" = [
+    b"yoyo"       , [0x02, 0x25        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"mqyo"       , [0x02, 0x25        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"woyh"       , [0x02, 0x25        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"whyt"       , [0x02, 0x25        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
+]
+"vpmovsqw" = [
+    b"yoyo"       , [0x02, 0x24        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyh"       , [0x02, 0x24        ], X, EVEX_OP | WITH_VEXL | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"mdyo"       , [0x02, 0x24        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"mqyh"       , [0x02, 0x24        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"woyt"       , [0x02, 0x24        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
+]
+"vpmovswb" = [
+    b"yoyo"       , [0x02, 0x20        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512BW | AVX512VL;
+    b"mqyo"       , [0x02, 0x20        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512BW | AVX512VL;
+    b"woyh"       , [0x02, 0x20        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK | ZEROING, AVX512BW | AVX512VL;
+    b"whyt"       , [0x02, 0x20        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512BW;
+]
 "vpmovsxbd" = [
     b"yomd"       , [0x02, 0x21        ], X, VEX_OP | PREF_66, AVX;
     b"y*yo"       , [0x02, 0x21        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomd"       , [0x02, 0x21        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyo"       , [0x02, 0x21        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhyo"       , [0x02, 0x21        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhmq"       , [0x02, 0x21        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytwo"       , [0x02, 0x21        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vpmovsxbq" = [
     b"y*mw"       , [0x02, 0x22        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
     b"y*yo"       , [0x02, 0x22        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomw"       , [0x02, 0x22        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"y*yo"       , [0x02, 0x22        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yhmd"       , [0x02, 0x22        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytmq"       , [0x02, 0x22        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vpmovsxbw" = [
     b"yomq"       , [0x02, 0x20        ], X, VEX_OP | PREF_66, AVX;
     b"y*wo"       , [0x02, 0x20        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomq"       , [0x02, 0x20        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512BW | AVX512VL;
+    b"yhwo"       , [0x02, 0x20        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW | AVX512VL;
+    b"yoyo"       , [0x02, 0x20        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512BW | AVX512VL;
+    b"ytwh"       , [0x02, 0x20        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpmovsxdq" = [
     b"yomq"       , [0x02, 0x25        ], X, VEX_OP | PREF_66, AVX;
     b"y*wo"       , [0x02, 0x25        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomq"       , [0x02, 0x25        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhwo"       , [0x02, 0x25        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyo"       , [0x02, 0x25        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytwh"       , [0x02, 0x25        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vpmovsxwd" = [
     b"yomq"       , [0x02, 0x23        ], X, VEX_OP | PREF_66, AVX;
     b"y*wo"       , [0x02, 0x23        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomq"       , [0x02, 0x23        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhwo"       , [0x02, 0x23        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyo"       , [0x02, 0x23        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytwh"       , [0x02, 0x23        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vpmovsxwq" = [
     b"yomd"       , [0x02, 0x24        ], X, VEX_OP | PREF_66, AVX;
     b"y*yo"       , [0x02, 0x24        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomd"       , [0x02, 0x24        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyo"       , [0x02, 0x24        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhyo"       , [0x02, 0x24        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhmq"       , [0x02, 0x24        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytwo"       , [0x02, 0x24        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vpmovusdb" = [
+    b"yoyo"       , [0x02, 0x11        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyh"       , [0x02, 0x11        ], X, EVEX_OP | WITH_VEXL | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"mdyo"       , [0x02, 0x11        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"mqyh"       , [0x02, 0x11        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"woyt"       , [0x02, 0x11        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
+]
+"vpmovusdw" = [
+    b"yoyo"       , [0x02, 0x13        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"mqyo"       , [0x02, 0x13        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"woyh"       , [0x02, 0x13        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"whyt"       , [0x02, 0x13        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
+]
+"vpmovusqb" = [
+    b"yoy*"       , [0x02, 0x12        ], X, EVEX_OP | AUTO_VEXL | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F;
+    b"mwyo"       , [0x02, 0x12        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"mdyh"       , [0x02, 0x12        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"mqyt"       , [0x02, 0x12        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK, AVX512F;
+]
+"vpmovusqd" = [
+    b"yoyo"       , [0x02, 0x15        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"mqyo"       , [0x02, 0x15        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"woyh"       , [0x02, 0x15        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"whyt"       , [0x02, 0x15        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
+]
+"vpmovusqw" = [
+    b"yoyo"       , [0x02, 0x14        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyh"       , [0x02, 0x14        ], X, EVEX_OP | WITH_VEXL | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"mdyo"       , [0x02, 0x14        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"mqyh"       , [0x02, 0x14        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK, AVX512F | AVX512VL;
+    b"woyt"       , [0x02, 0x14        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512F;
+]
+"vpmovuswb" = [
+    b"yoyo"       , [0x02, 0x10        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512BW | AVX512VL;
+    b"mqyo"       , [0x02, 0x10        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512BW | AVX512VL;
+    b"woyh"       , [0x02, 0x10        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK | ZEROING, AVX512BW | AVX512VL;
+    b"whyt"       , [0x02, 0x10        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512BW;
+]
+"vpmovw2m" = [
+    b"jqy*"       , [0x02, 0x29        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_F3, AVX512BW;
+]
+"vpmovwb" = [
+    b"yoyo"       , [0x02, 0x30        ], X, EVEX_OP | ENC_MR | PREF_F3 | OPMASK | ZEROING, AVX512BW | AVX512VL;
+    b"mqyo"       , [0x02, 0x30        ], X, EVEX_OP | PREF_F3 | OPMASK, AVX512BW | AVX512VL;
+    b"woyh"       , [0x02, 0x30        ], X, EVEX_OP | WITH_VEXL | PREF_F3 | OPMASK | ZEROING, AVX512BW | AVX512VL;
+    b"whyt"       , [0x02, 0x30        ], X, EVEX_OP | WITH_EVEXL | PREF_F3 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpmovzxbd" = [
     b"yomd"       , [0x02, 0x31        ], X, VEX_OP | PREF_66, AVX;
     b"y*yo"       , [0x02, 0x31        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomd"       , [0x02, 0x31        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyo"       , [0x02, 0x31        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhyo"       , [0x02, 0x31        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhmq"       , [0x02, 0x31        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytwo"       , [0x02, 0x31        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vpmovzxbq" = [
     b"y*mw"       , [0x02, 0x32        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
     b"y*yo"       , [0x02, 0x32        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomw"       , [0x02, 0x32        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"y*yo"       , [0x02, 0x32        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yhmd"       , [0x02, 0x32        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytmq"       , [0x02, 0x32        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vpmovzxbw" = [
     b"yomq"       , [0x02, 0x30        ], X, VEX_OP | PREF_66, AVX;
     b"y*wo"       , [0x02, 0x30        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomq"       , [0x02, 0x30        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512BW | AVX512VL;
+    b"yhwo"       , [0x02, 0x30        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW | AVX512VL;
+    b"yoyo"       , [0x02, 0x30        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512BW | AVX512VL;
+    b"ytwh"       , [0x02, 0x30        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpmovzxdq" = [
     b"yomq"       , [0x02, 0x35        ], X, VEX_OP | PREF_66, AVX;
     b"y*wo"       , [0x02, 0x35        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomq"       , [0x02, 0x35        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhwo"       , [0x02, 0x35        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyo"       , [0x02, 0x35        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytwh"       , [0x02, 0x35        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vpmovzxwd" = [
     b"yomq"       , [0x02, 0x33        ], X, VEX_OP | PREF_66, AVX;
     b"y*wo"       , [0x02, 0x33        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomq"       , [0x02, 0x33        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhwo"       , [0x02, 0x33        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyo"       , [0x02, 0x33        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytwh"       , [0x02, 0x33        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vpmovzxwq" = [
     b"yomd"       , [0x02, 0x34        ], X, VEX_OP | PREF_66, AVX;
     b"y*yo"       , [0x02, 0x34        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"yomd"       , [0x02, 0x34        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yoyo"       , [0x02, 0x34        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhyo"       , [0x02, 0x34        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"yhmq"       , [0x02, 0x34        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F | AVX512VL;
+    b"ytwo"       , [0x02, 0x34        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vpmuldq" = [
     b"y*y*w*"     , [0x02, 0x28        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x28        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpmulhrsw" = [
     b"y*y*w*"     , [0x02, 0x0B        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x0B        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpmulhuw" = [
     b"y*y*w*"     , [0x01, 0xE4        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xE4        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpmulhw" = [
     b"y*y*w*"     , [0x01, 0xE5        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xE5        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpmulld" = [
     b"y*y*w*"     , [0x02, 0x40        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x40        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpmullq" = [
+    b"y*y*w*"     , [0x02, 0x40        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512DQ;
 ]
 "vpmullw" = [
     b"y*y*w*"     , [0x01, 0xD5        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xD5        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
+]
+"vpmultishiftqb" = [
+    b"y*y*w*"     , [0x02, 0x83        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512VBMI;
 ]
 "vpmuludq" = [
     b"y*y*w*"     , [0x01, 0xF4        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xF4        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpor" = [
     b"y*y*w*"     , [0x01, 0xEB        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
+"vpord" = [
+    b"y*y*w*"     , [0x01, 0xEB        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vporq" = [
+    b"y*y*w*"     , [0x01, 0xEB        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
 "vpperm" = [
     b"yoyowoyo"   , [0x08, 0xA3        ], X, XOP_OP, AMD | SSE5;
     b"yoyoyowo"   , [0x08, 0xA3        ], X, WITH_REXW | XOP_OP, SSE5 | AMD;
 ]
+"vprold" = [
+    b"y*w*ib"     , [0x01, 0x72        ], 1, EVEX_OP | AUTO_VEXL | ENC_VM | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vprolq" = [
+    b"y*w*ib"     , [0x01, 0x72        ], 1, EVEX_OP | AUTO_VEXL | WITH_REXW | ENC_VM | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vprolvd" = [
+    b"y*y*w*"     , [0x02, 0x15        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vprolvq" = [
+    b"y*y*w*"     , [0x02, 0x15        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vprord" = [
+    b"y*w*ib"     , [0x01, 0x72        ], 0, EVEX_OP | AUTO_VEXL | ENC_VM | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vprorq" = [
+    b"y*w*ib"     , [0x01, 0x72        ], 0, EVEX_OP | AUTO_VEXL | WITH_REXW | ENC_VM | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vprorvd" = [
+    b"y*y*w*"     , [0x02, 0x14        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vprorvq" = [
+    b"y*y*w*"     , [0x02, 0x14        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
 "vprotb" = [
     b"yowoib"     , [0x08, 0xC0        ], X, XOP_OP, SSE5 | AMD;
     b"yowoyo"     , [0x09, 0x90        ], X, XOP_OP | ENC_MR, AMD | SSE5;
@@ -4770,6 +6600,23 @@ Ops!(
 ]
 "vpsadbw" = [
     b"y*y*w*"     , [0x01, 0xF6        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xF6        ], X, EVEX_OP | AUTO_VEXL | PREF_66, AVX512BW;
+]
+"vpscatterdd" = [
+    b"k*y*"       , [0x02, 0xA0        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK_REQ, AVX512F;
+]
+"vpscatterdq" = [
+    b"loyo"       , [0x02, 0xA0        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"loyh"       , [0x02, 0xA0        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"lhyt"       , [0x02, 0xA0        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F;
+]
+"vpscatterqd" = [
+    b"koyo"       , [0x02, 0xA1        ], X, EVEX_OP | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"khyo"       , [0x02, 0xA1        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"ktyh"       , [0x02, 0xA1        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK_REQ, AVX512F;
+]
+"vpscatterqq" = [
+    b"l*y*"       , [0x02, 0xA1        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F;
 ]
 "vpshab" = [
     b"yowoyo"     , [0x09, 0x98        ], X, XOP_OP | ENC_MR, AMD | SSE5;
@@ -4805,15 +6652,19 @@ Ops!(
 ]
 "vpshufb" = [
     b"y*y*w*"     , [0x02, 0x00        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x02, 0x00        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpshufd" = [
     b"y*w*ib"     , [0x01, 0x70        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*w*ib"     , [0x01, 0x70        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vpshufhw" = [
     b"y*w*ib"     , [0x01, 0x70        ], X, VEX_OP | AUTO_VEXL | PREF_F3, AVX;
+    b"y*w*ib"     , [0x01, 0x70        ], X, EVEX_OP | AUTO_VEXL | PREF_F3 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpshuflw" = [
     b"y*w*ib"     , [0x01, 0x70        ], X, VEX_OP | AUTO_VEXL | PREF_F2, AVX;
+    b"y*w*ib"     , [0x01, 0x70        ], X, EVEX_OP | AUTO_VEXL | PREF_F2 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpsignb" = [
     b"y*y*w*"     , [0x02, 0x08        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -4827,110 +6678,243 @@ Ops!(
 "vpslld" = [
     b"y*y*ib"     , [0x01, 0x72        ], 6, VEX_OP | AUTO_VEXL | ENC_VM | PREF_66, AVX;
     b"y*y*wo"     , [0x01, 0xF2        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*wo"     , [0x01, 0xF2        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"y*w*ib"     , [0x01, 0x72        ], 6, EVEX_OP | AUTO_VEXL | ENC_VM | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vpslldq" = [
     b"y*y*ib"     , [0x01, 0x73        ], 7, VEX_OP | AUTO_VEXL | ENC_VM | PREF_66, AVX;
+    b"y*w*ib"     , [0x01, 0x73        ], 7, EVEX_OP | AUTO_VEXL | ENC_VM | PREF_66, AVX512BW;
 ]
 "vpsllq" = [
     b"y*y*ib"     , [0x01, 0x73        ], 6, VEX_OP | AUTO_VEXL | ENC_VM | PREF_66, AVX;
     b"y*y*wo"     , [0x01, 0xF3        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*wo"     , [0x01, 0xF3        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"y*w*ib"     , [0x01, 0x73        ], 6, EVEX_OP | AUTO_VEXL | WITH_REXW | ENC_VM | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpsllvd" = [
     b"y*y*w*"     , [0x02, 0x47        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
+    b"y*y*w*"     , [0x02, 0x47        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vpsllvq" = [
     b"y*y*w*"     , [0x02, 0x47        ], X, VEX_OP | AUTO_VEXL | WITH_REXW | PREF_66, AVX2;
+    b"y*y*w*"     , [0x02, 0x47        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vpsllvw" = [
+    b"y*y*w*"     , [0x02, 0x12        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpsllw" = [
     b"y*y*ib"     , [0x01, 0x71        ], 6, VEX_OP | AUTO_VEXL | ENC_VM | PREF_66, AVX;
     b"y*y*wo"     , [0x01, 0xF1        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*wo"     , [0x01, 0xF1        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
+    b"y*w*ib"     , [0x01, 0x71        ], 6, EVEX_OP | AUTO_VEXL | ENC_VM | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpsrad" = [
     b"y*y*ib"     , [0x01, 0x72        ], 4, VEX_OP | AUTO_VEXL | ENC_VM | PREF_66, AVX;
     b"y*y*wo"     , [0x01, 0xE2        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*wo"     , [0x01, 0xE2        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"y*w*ib"     , [0x01, 0x72        ], 4, EVEX_OP | AUTO_VEXL | ENC_VM | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpsraq" = [
+    b"y*w*ib"     , [0x01, 0x72        ], 4, EVEX_OP | AUTO_VEXL | WITH_REXW | ENC_VM | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+    b"y*y*wo"     , [0x01, 0xE2        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
 ]
 "vpsravd" = [
     b"y*y*w*"     , [0x02, 0x46        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
+    b"y*y*w*"     , [0x02, 0x46        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpsravq" = [
+    b"y*y*w*"     , [0x02, 0x46        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vpsravw" = [
+    b"y*y*w*"     , [0x02, 0x11        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpsraw" = [
     b"y*y*ib"     , [0x01, 0x71        ], 4, VEX_OP | AUTO_VEXL | ENC_VM | PREF_66, AVX;
     b"y*y*wo"     , [0x01, 0xE1        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*wo"     , [0x01, 0xE1        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
+    b"y*w*ib"     , [0x01, 0x71        ], 4, EVEX_OP | AUTO_VEXL | ENC_VM | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpsrld" = [
     b"y*y*ib"     , [0x01, 0x72        ], 2, VEX_OP | AUTO_VEXL | ENC_VM | PREF_66, AVX;
     b"y*y*wo"     , [0x01, 0xD2        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*wo"     , [0x01, 0xD2        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"y*w*ib"     , [0x01, 0x72        ], 2, EVEX_OP | AUTO_VEXL | ENC_VM | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vpsrldq" = [
     b"y*y*ib"     , [0x01, 0x73        ], 3, VEX_OP | AUTO_VEXL | ENC_VM | PREF_66, AVX;
+    b"y*w*ib"     , [0x01, 0x73        ], 3, EVEX_OP | AUTO_VEXL | ENC_VM | PREF_66, AVX512BW;
 ]
 "vpsrlq" = [
     b"y*y*ib"     , [0x01, 0x73        ], 2, VEX_OP | ENC_VM | PREF_66, AVX;
     b"y*y*wo"     , [0x01, 0xD3        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*wo"     , [0x01, 0xD3        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"y*w*ib"     , [0x01, 0x73        ], 2, EVEX_OP | AUTO_VEXL | WITH_REXW | ENC_VM | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpsrlvd" = [
     b"y*y*w*"     , [0x02, 0x45        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX2;
+    b"y*y*w*"     , [0x02, 0x45        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vpsrlvq" = [
     b"y*y*w*"     , [0x02, 0x45        ], X, VEX_OP | AUTO_VEXL | WITH_REXW | PREF_66, AVX2;
+    b"y*y*w*"     , [0x02, 0x45        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vpsrlvw" = [
+    b"y*y*w*"     , [0x02, 0x10        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpsrlw" = [
     b"y*y*ib"     , [0x01, 0x71        ], 2, VEX_OP | AUTO_VEXL | ENC_VM | PREF_66, AVX;
     b"y*y*wo"     , [0x01, 0xD1        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*wo"     , [0x01, 0xD1        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
+    b"y*w*ib"     , [0x01, 0x71        ], 2, EVEX_OP | AUTO_VEXL | ENC_VM | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpsubb" = [
     b"y*y*w*"     , [0x01, 0xF8        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xF8        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpsubd" = [
     b"y*y*w*"     , [0x01, 0xFA        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xFA        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vpsubq" = [
     b"y*y*w*"     , [0x01, 0xFB        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xFB        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpsubsb" = [
     b"y*y*w*"     , [0x01, 0xE8        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xE8        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpsubsw" = [
     b"y*y*w*"     , [0x01, 0xE9        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xE9        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpsubusb" = [
     b"y*y*w*"     , [0x01, 0xD8        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xD8        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpsubusw" = [
     b"y*y*w*"     , [0x01, 0xD9        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xD9        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpsubw" = [
     b"y*y*w*"     , [0x01, 0xF9        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0xF9        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
+]
+"vpternlogd" = [
+    b"y*y*w*ib"   , [0x03, 0x25        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpternlogq" = [
+    b"y*y*w*ib"   , [0x03, 0x25        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vptest" = [
     b"y*w*"       , [0x02, 0x17        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
+"vptestmb" = [
+    b"jqy*w*"     , [0x02, 0x26        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK, AVX512BW;
+]
+"vptestmd" = [
+    b"jqy*w*"     , [0x02, 0x27        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | BCST_32, AVX512F;
+]
+"vptestmq" = [
+    b"jqy*w*"     , [0x02, 0x27        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | BCST_64, AVX512F;
+]
+"vptestmw" = [
+    b"jqy*w*"     , [0x02, 0x26        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK, AVX512BW;
+]
+"vptestnmb" = [
+    b"jqy*w*"     , [0x02, 0x26        ], X, EVEX_OP | AUTO_VEXL | PREF_F3 | OPMASK, AVX512BW;
+]
+"vptestnmd" = [
+    b"jqy*w*"     , [0x02, 0x27        ], X, EVEX_OP | AUTO_VEXL | PREF_F3 | OPMASK | BCST_32, AVX512F;
+]
+"vptestnmq" = [
+    b"jqy*w*"     , [0x02, 0x27        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_F3 | OPMASK | BCST_64, AVX512F;
+]
+"vptestnmw" = [
+    b"jqy*w*"     , [0x02, 0x26        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_F3 | OPMASK, AVX512BW;
+]
 "vpunpckhbw" = [
     b"y*y*w*"     , [0x01, 0x68        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x68        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpunpckhdq" = [
     b"y*y*w*"     , [0x01, 0x6A        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x6A        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vpunpckhqdq" = [
     b"y*y*w*"     , [0x01, 0x6D        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x6D        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpunpckhwd" = [
     b"y*y*w*"     , [0x01, 0x69        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x69        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpunpcklbw" = [
     b"y*y*w*"     , [0x01, 0x60        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x60        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpunpckldq" = [
     b"y*y*w*"     , [0x01, 0x62        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x62        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vpunpcklqdq" = [
     b"y*y*w*"     , [0x01, 0x6C        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x6C        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vpunpcklwd" = [
     b"y*y*w*"     , [0x01, 0x61        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x61        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING, AVX512BW;
 ]
 "vpxor" = [
     b"y*y*w*"     , [0x01, 0xEF        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
+"vpxord" = [
+    b"y*y*w*"     , [0x01, 0xEF        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vpxorq" = [
+    b"y*y*w*"     , [0x01, 0xEF        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vrangepd" = [
+    b"y*y*w*ib"   , [0x03, 0x50        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | SAE, AVX512DQ;
+]
+"vrangeps" = [
+    b"y*y*w*ib"   , [0x03, 0x50        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | SAE, AVX512DQ;
+]
+"vrangesd" = [
+    b"yoyoyoib"   , [0x03, 0x51        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | SAE, AVX512DQ;
+    b"yoyomqib"   , [0x03, 0x51        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vrangess" = [
+    b"yoyoyoib"   , [0x03, 0x51        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | SAE, AVX512DQ;
+    b"yoyomdib"   , [0x03, 0x51        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vrcp14pd" = [
+    b"y*w*"       , [0x02, 0x4C        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vrcp14ps" = [
+    b"y*w*"       , [0x02, 0x4C        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vrcp14sd" = [
+    b"yoyoyo"     , [0x02, 0x4D        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyomq"     , [0x02, 0x4D        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vrcp14ss" = [
+    b"yoyoyo"     , [0x02, 0x4D        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyomd"     , [0x02, 0x4D        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vrcp28pd" = [
+    b"ytwt"       , [0x02, 0xCA        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | SAE, AVX512ER;
+]
+"vrcp28ps" = [
+    b"ytwt"       , [0x02, 0xCA        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | SAE, AVX512ER;
+]
+"vrcp28sd" = [
+    b"yoyoyo"     , [0x02, 0xCB        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | SAE, AVX512ER;
+    b"yoyomq"     , [0x02, 0xCB        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512ER;
+]
+"vrcp28ss" = [
+    b"yoyoyo"     , [0x02, 0xCB        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | SAE, AVX512ER;
+    b"yoyomd"     , [0x02, 0xCB        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512ER;
+]
 "vrcpps" = [
     b"y*w*"       , [0x01, 0x53        ], X, VEX_OP | AUTO_VEXL, AVX;
 ]
@@ -4938,6 +6922,34 @@ Ops!(
     b"yoyomd"     , [0x01, 0x53        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x53        ], X, VEX_OP | PREF_F3, AVX;
 ]
+"vreducepd" = [
+    b"y*w*ib"     , [0x03, 0x56        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | SAE, AVX512DQ;
+]
+"vreduceps" = [
+    b"y*w*ib"     , [0x03, 0x56        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | SAE, AVX512DQ;
+]
+"vreducesd" = [
+    b"yoyoyoib"   , [0x03, 0x57        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | SAE, AVX512DQ;
+    b"yoyomqib"   , [0x03, 0x57        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vreducess" = [
+    b"yoyoyoib"   , [0x03, 0x57        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | SAE, AVX512DQ;
+    b"yoyomdib"   , [0x03, 0x57        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512DQ;
+]
+"vrndscalepd" = [
+    b"y*w*ib"     , [0x03, 0x09        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | SAE, AVX512F;
+]
+"vrndscaleps" = [
+    b"y*w*ib"     , [0x03, 0x08        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | SAE, AVX512F;
+]
+"vrndscalesd" = [
+    b"yoyoyoib"   , [0x03, 0x0B        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | SAE, AVX512F;
+    b"yoyomqib"   , [0x03, 0x0B        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vrndscaless" = [
+    b"yoyoyoib"   , [0x03, 0x0A        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | SAE, AVX512F;
+    b"yoyomdib"   , [0x03, 0x0A        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
 "vroundpd" = [
     b"y*w*ib"     , [0x03, 0x09        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
@@ -4952,6 +6964,34 @@ Ops!(
     b"yoyomdib"   , [0x03, 0x0A        ], X, VEX_OP | PREF_66, AVX;
     b"yoyoyoib"   , [0x03, 0x0A        ], X, VEX_OP | PREF_66, AVX;
 ]
+"vrsqrt14pd" = [
+    b"y*w*"       , [0x02, 0x4E        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vrsqrt14ps" = [
+    b"y*w*"       , [0x02, 0x4E        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vrsqrt14sd" = [
+    b"yoyoyo"     , [0x02, 0x4F        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyomq"     , [0x02, 0x4F        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vrsqrt14ss" = [
+    b"yoyoyo"     , [0x02, 0x4F        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+    b"yoyomd"     , [0x02, 0x4F        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vrsqrt28pd" = [
+    b"ytwt"       , [0x02, 0xCC        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | SAE, AVX512ER;
+]
+"vrsqrt28ps" = [
+    b"ytwt"       , [0x02, 0xCC        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | SAE, AVX512ER;
+]
+"vrsqrt28sd" = [
+    b"yoyoyo"     , [0x02, 0xCD        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | SAE, AVX512ER;
+    b"yoyomq"     , [0x02, 0xCD        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512ER;
+]
+"vrsqrt28ss" = [
+    b"yoyoyo"     , [0x02, 0xCD        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | SAE, AVX512ER;
+    b"yoyomd"     , [0x02, 0xCD        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512ER;
+]
 "vrsqrtps" = [
     b"y*w*"       , [0x01, 0x52        ], X, VEX_OP | AUTO_VEXL, AVX;
 ]
@@ -4959,42 +6999,126 @@ Ops!(
     b"yoyomd"     , [0x01, 0x52        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x52        ], X, VEX_OP | PREF_F3, AVX;
 ]
+"vscalefpd" = [
+    b"y*y*w*"     , [0x02, 0x2C        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
+]
+"vscalefps" = [
+    b"y*y*w*"     , [0x02, 0x2C        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
+]
+"vscalefsd" = [
+    b"yoyoyo"     , [0x02, 0x2D        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
+    b"yoyomq"     , [0x02, 0x2D        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vscalefss" = [
+    b"yoyoyo"     , [0x02, 0x2D        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING | EMB_RND, AVX512F;
+    b"yoyomd"     , [0x02, 0x2D        ], X, EVEX_OP | PREF_66 | OPMASK | ZEROING, AVX512F;
+]
+"vscatterdpd" = [
+    b"loyo"       , [0x02, 0xA2        ], X, EVEX_OP | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"loyh"       , [0x02, 0xA2        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"lhyt"       , [0x02, 0xA2        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F;
+]
+"vscatterdps" = [
+    b"k*y*"       , [0x02, 0xA2        ], X, EVEX_OP | AUTO_VEXL | PREF_66 | OPMASK_REQ, AVX512F;
+]
+"vscatterpf0dpd" = [
+    b"lh"         , [0x02, 0xC6        ], 5, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vscatterpf0dps" = [
+    b"kt"         , [0x02, 0xC6        ], 5, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vscatterpf0qpd" = [
+    b"lt"         , [0x02, 0xC7        ], 5, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vscatterpf0qps" = [
+    b"kt"         , [0x02, 0xC7        ], 5, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vscatterpf1dpd" = [
+    b"lh"         , [0x02, 0xC6        ], 6, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vscatterpf1dps" = [
+    b"kt"         , [0x02, 0xC6        ], 6, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vscatterpf1qpd" = [
+    b"lt"         , [0x02, 0xC7        ], 6, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vscatterpf1qps" = [
+    b"kt"         , [0x02, 0xC7        ], 6, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK_REQ, AVX512PF;
+]
+"vscatterqpd" = [
+    b"l*y*"       , [0x02, 0xA3        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK_REQ, AVX512F;
+]
+"vscatterqps" = [
+    b"koyo"       , [0x02, 0xA3        ], X, EVEX_OP | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"khyo"       , [0x02, 0xA3        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK_REQ, AVX512F | AVX512VL;
+    b"ktyh"       , [0x02, 0xA3        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK_REQ, AVX512F;
+]
+"vshuff32x4" = [
+    b"yhyhwhib"   , [0x03, 0x23        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F | AVX512VL;
+    b"ytytwtib"   , [0x03, 0x23        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vshuff64x2" = [
+    b"yhyhwhib"   , [0x03, 0x23        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"ytytwtib"   , [0x03, 0x23        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
+"vshufi32x4" = [
+    b"yhyhwhib"   , [0x03, 0x43        ], X, EVEX_OP | WITH_VEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F | AVX512VL;
+    b"ytytwtib"   , [0x03, 0x43        ], X, EVEX_OP | WITH_EVEXL | PREF_66 | OPMASK | ZEROING | BCST_32, AVX512F;
+]
+"vshufi64x2" = [
+    b"yhyhwhib"   , [0x03, 0x43        ], X, EVEX_OP | WITH_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F | AVX512VL;
+    b"ytytwtib"   , [0x03, 0x43        ], X, EVEX_OP | WITH_EVEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
+]
 "vshufpd" = [
     b"y*y*w*ib"   , [0x01, 0xC6        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX;
+    b"y*y*w*ib"   , [0x01, 0xC6        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vshufps" = [
     b"y*y*w*ib"   , [0x01, 0xC6        ], X, VEX_OP | AUTO_VEXL | ENC_MR, AVX;
+    b"y*y*w*ib"   , [0x01, 0xC6        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vsqrtpd" = [
     b"y*w*"       , [0x01, 0x51        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*w*"       , [0x01, 0x51        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vsqrtps" = [
     b"y*w*"       , [0x01, 0x51        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*w*"       , [0x01, 0x51        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vsqrtsd" = [
     b"yoyomq"     , [0x01, 0x51        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x51        ], X, VEX_OP | PREF_F2, AVX;
+    b"yoyomq"     , [0x01, 0x51        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x51        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vsqrtss" = [
     b"yoyomd"     , [0x01, 0x51        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x51        ], X, VEX_OP | PREF_F3, AVX;
+    b"yoyomd"     , [0x01, 0x51        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x51        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vstmxcsr" = [
     b"md"         , [0x01, 0xAE        ], 3, VEX_OP, AVX;
 ]
 "vsubpd" = [
     b"y*y*w*"     , [0x01, 0x5C        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x5C        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64 | EMB_RND, AVX512F;
 ]
 "vsubps" = [
     b"y*y*w*"     , [0x01, 0x5C        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x5C        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32 | EMB_RND, AVX512F;
 ]
 "vsubsd" = [
     b"yoyomq"     , [0x01, 0x5C        ], X, VEX_OP | PREF_F2, AVX;
     b"yoyoyo"     , [0x01, 0x5C        ], X, VEX_OP | PREF_F2, AVX;
+    b"yoyomq"     , [0x01, 0x5C        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x5C        ], X, EVEX_OP | WITH_REXW | PREF_F2 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vsubss" = [
     b"yoyomd"     , [0x01, 0x5C        ], X, VEX_OP | PREF_F3, AVX;
     b"yoyoyo"     , [0x01, 0x5C        ], X, VEX_OP | PREF_F3, AVX;
+    b"yoyomd"     , [0x01, 0x5C        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING, AVX512F;
+    b"yoyoyo"     , [0x01, 0x5C        ], X, EVEX_OP | PREF_F3 | OPMASK | ZEROING | EMB_RND, AVX512F;
 ]
 "vtestpd" = [
     b"y*w*"       , [0x02, 0x0F        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
@@ -5005,28 +7129,38 @@ Ops!(
 "vucomisd" = [
     b"yomq"       , [0x01, 0x2E        ], X, VEX_OP | PREF_66, AVX;
     b"yoyo"       , [0x01, 0x2E        ], X, VEX_OP | PREF_66, AVX;
+    b"yomq"       , [0x01, 0x2E        ], X, EVEX_OP | WITH_REXW | PREF_66, AVX512F;
+    b"yoyo"       , [0x01, 0x2E        ], X, EVEX_OP | WITH_REXW | PREF_66 | SAE, AVX512F;
 ]
 "vucomiss" = [
     b"yomd"       , [0x01, 0x2E        ], X, VEX_OP, AVX;
     b"yoyo"       , [0x01, 0x2E        ], X, VEX_OP, AVX;
+    b"yomd"       , [0x01, 0x2E        ], X, EVEX_OP, AVX512F;
+    b"yoyo"       , [0x01, 0x2E        ], X, EVEX_OP | SAE, AVX512F;
 ]
 "vunpckhpd" = [
     b"y*y*w*"     , [0x01, 0x15        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x15        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vunpckhps" = [
     b"y*y*w*"     , [0x01, 0x15        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x15        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vunpcklpd" = [
     b"y*y*w*"     , [0x01, 0x14        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x14        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512F;
 ]
 "vunpcklps" = [
     b"y*y*w*"     , [0x01, 0x14        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x14        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32, AVX512F;
 ]
 "vxorpd" = [
     b"y*y*w*"     , [0x01, 0x57        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
+    b"y*y*w*"     , [0x01, 0x57        ], X, EVEX_OP | AUTO_VEXL | WITH_REXW | PREF_66 | OPMASK | ZEROING | BCST_64, AVX512DQ;
 ]
 "vxorps" = [
     b"y*y*w*"     , [0x01, 0x57        ], X, VEX_OP | AUTO_VEXL, AVX;
+    b"y*y*w*"     , [0x01, 0x57        ], X, EVEX_OP | AUTO_VEXL | OPMASK | ZEROING | BCST_32, AVX512DQ;
 ]
 "vzeroall" = [
     b""           , [0x01, 0x77        ], X, WITH_VEXL | VEX_OP, AVX;
@@ -5054,6 +7188,18 @@ Ops!(
 "wrshr" = [
     b"vd"         , [0x0F, 0x37        ], 0, DEFAULT, CYRIX;
 ]
+"wrssd" = [
+    b"mdrd"       , [0x0F, 0x38, 0xF6  ], X, ENC_MR, CET;
+]
+"wrssq" = [
+    b"mqrq"       , [0x0F, 0x38, 0xF6  ], X, WITH_REXW | ENC_MR, CET;
+]
+"wrussd" = [
+    b"mdrd"       , [0x0F, 0x38, 0xF5  ], X, ENC_MR | PREF_66, CET;
+]
+"wrussq" = [
+    b"mqrq"       , [0x0F, 0x38, 0xF5  ], X, WITH_REXW | ENC_MR | PREF_66, CET;
+]
 "xabort" = [
     b"ib"         , [0xC6, 0xF8        ], X, DEFAULT, RTM;
 ]
@@ -5120,6 +7266,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 6, AUTO_SIZE ;
     b"r*r*"       , [0x31              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x33              ], X, AUTO_SIZE;
+    b"rbvbrb"     , [0x04, 0x30        ], X, APX_OP | APX_ND | ENC_MR, APX;
+    b"rbrbvb"     , [0x04, 0x32        ], X, APX_OP | APX_ND, APX;
+    b"rbvbib"     , [0x04, 0x80        ], 6, APX_OP | APX_ND, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 6, AUTO_SIZE | EXACT_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 6, AUTO_SIZE | APX_OP | APX_ND, APX;
+    b"r*v*r*"     , [0x04, 0x31        ], X, AUTO_SIZE | APX_OP | APX_ND | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x33        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "xorpd" = [
     b"yowo"       , [0x0F, 0x57        ], X, PREF_66, SSE2;
@@ -5169,30 +7322,30 @@ Ops!(
 
 "call"  = [
     b"iwiw"       , [0x9A              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"idiw"       , [0x9A              ], X, X86_ONLY;
-    b"mf"         , [0xFF              ], 3, X86_ONLY | EXACT_SIZE;
+    b"idiw"       , [0x9A              ], X, X86_ONLY | DWORD_SIZE;
+    b"mf"         , [0xFF              ], 3, X86_ONLY | EXACT_SIZE | DWORD_SIZE;
     b"od"         , [0xE8              ], X;
     b"v*"         , [0xFF              ], 2, AUTO_NO32;
 ]
 "callf" = [
     b"iwiw"       , [0x9A              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"idiw"       , [0x9A              ], X, X86_ONLY;
+    b"idiw"       , [0x9A              ], X, X86_ONLY | DWORD_SIZE;
     b"md"         , [0xFF              ], 3, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"mf"         , [0xFF              ], 3, X86_ONLY;
+    b"mf"         , [0xFF              ], 3, X86_ONLY | DWORD_SIZE;
 ]
 "jmp"   = [
-    b"iwiw"       , [0x9A              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"idiw"       , [0xEA              ], X, X86_ONLY;
-    b"mf"         , [0xFF              ], 5, X86_ONLY | EXACT_SIZE;
+    b"iwiw"       , [0xEA              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
+    b"idiw"       , [0xEA              ], X, X86_ONLY | DWORD_SIZE;
+    b"mf"         , [0xFF              ], 5, X86_ONLY | EXACT_SIZE | DWORD_SIZE;
     b"ob"         , [0xEB              ], X, EXACT_SIZE;
     b"od"         , [0xE9              ], X;
     b"v*"         , [0xFF              ], 4, AUTO_NO32 ;
 ]
 "jmpf" = [
-    b"iwiw"       , [0x9A              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"idiw"       , [0xEA              ], X, X86_ONLY;
+    b"iwiw"       , [0xEA              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
+    b"idiw"       , [0xEA              ], X, X86_ONLY | DWORD_SIZE;
     b"md"         , [0xFF              ], 5, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"mf"         , [0xFF              ], 5, X86_ONLY;
+    b"mf"         , [0xFF              ], 5, X86_ONLY | DWORD_SIZE;
 ]
 "mov"   = [
     b"v*r*"       , [0x89              ], X, AUTO_SIZE;
@@ -5201,11 +7354,11 @@ Ops!(
     b"rbvb"       , [0x8A              ], X;
     b"r*sw"       , [0x8C              ], X, AUTO_SIZE;
     b"mwsw"       , [0x8C              ], X;
-    b"swmw"       , [0x8C              ], X;
-    b"swrw"       , [0x8C              ], X;
+    b"swmw"       , [0x8E              ], X;
+    b"swrw"       , [0x8E              ], X;
     b"rbib"       , [0xB0              ], X,             SHORT_ARG;
     b"rwiw"       , [0xB8              ], X, WORD_SIZE | SHORT_ARG;
-    b"rdid"       , [0xB8              ], X,             SHORT_ARG;
+    b"rdid"       , [0xB8              ], X, DWORD_SIZE | SHORT_ARG;
     b"v*i*"       , [0xC7              ], 0, AUTO_SIZE;
     b"vbib"       , [0xC6              ], 0;
     b"rqiq"       , [0xB8              ], X, WITH_REXW | SHORT_ARG;
@@ -5355,93 +7508,527 @@ Ops!(
 
 "cmovo"    = [
     b"r*v*",     [0x0F, 0x40      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x40        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovno"   = [
     b"r*v*",     [0x0F, 0x41      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x41        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovb"    = [
     b"r*v*",     [0x0F, 0x42      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x42        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovc"    = [
     b"r*v*",     [0x0F, 0x42      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x42        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovnae"  = [
     b"r*v*",     [0x0F, 0x42      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x42        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovnb"      = [
     b"r*v*",     [0x0F, 0x43      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x43        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovnc"      = [
     b"r*v*",     [0x0F, 0x43      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x43        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovae"      = [
     b"r*v*",     [0x0F, 0x43      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x43        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovz"       = [
     b"r*v*",     [0x0F, 0x44      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x44        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmove"       = [
     b"r*v*",     [0x0F, 0x44      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x44        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovnz"      = [
     b"r*v*",     [0x0F, 0x45      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x45        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovne"      = [
     b"r*v*",     [0x0F, 0x45      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x45        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovbe"      = [
     b"r*v*",     [0x0F, 0x46      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x46        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovna"      = [
     b"r*v*",     [0x0F, 0x46      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x46        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovnbe"     = [
     b"r*v*",     [0x0F, 0x47      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x47        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmova"       = [
     b"r*v*",     [0x0F, 0x47      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x47        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovs"       = [
     b"r*v*",     [0x0F, 0x48      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x48        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovns"      = [
     b"r*v*",     [0x0F, 0x49      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x49        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovp"       = [
     b"r*v*",     [0x0F, 0x4A      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x4A        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovpe"      = [
     b"r*v*",     [0x0F, 0x4A      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x4A        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovnp"      = [
     b"r*v*",     [0x0F, 0x4B      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x4B        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovpo"      = [
     b"r*v*",     [0x0F, 0x4B      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x4B        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovl"       = [
     b"r*v*",     [0x0F, 0x4C      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x4C        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovnge"     = [
     b"r*v*",     [0x0F, 0x4C      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x4C        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovnl"      = [
     b"r*v*",     [0x0F, 0x4D      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x4D        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovge"      = [
     b"r*v*",     [0x0F, 0x4D      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x4D        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovle"      = [
     b"r*v*",     [0x0F, 0x4E      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x4E        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovng"      = [
     b"r*v*",     [0x0F, 0x4E      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x4E        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovnle"     = [
     b"r*v*",     [0x0F, 0x4F      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x4F        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 "cmovg"       = [
     b"r*v*",     [0x0F, 0x4F      ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0x4F        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
+]
+
+"ccmpo"      = [
+    b"rbvb"       , [0x04, 0x3A, 0x00  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x00  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x00  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x00  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x00  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x00  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x00  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpno"     = [
+    b"rbvb"       , [0x04, 0x3A, 0x01  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x01  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x01  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x01  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x01  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x01  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x01  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpb"      = [
+    b"rbvb"       , [0x04, 0x3A, 0x02  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x02  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x02  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x02  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x02  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x02  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x02  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpc"      = [
+    b"rbvb"       , [0x04, 0x3A, 0x02  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x02  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x02  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x02  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x02  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x02  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x02  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpnae"    = [
+    b"rbvb"       , [0x04, 0x3A, 0x02  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x02  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x02  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x02  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x02  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x02  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x02  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpnb"     = [
+    b"rbvb"       , [0x04, 0x3A, 0x03  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x03  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x03  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x03  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x03  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x03  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x03  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpnc"     = [
+    b"rbvb"       , [0x04, 0x3A, 0x03  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x03  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x03  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x03  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x03  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x03  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x03  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpae"     = [
+    b"rbvb"       , [0x04, 0x3A, 0x03  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x03  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x03  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x03  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x03  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x03  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x03  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpz"      = [
+    b"rbvb"       , [0x04, 0x3A, 0x04  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x04  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x04  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x04  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x04  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x04  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x04  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpe"      = [
+    b"rbvb"       , [0x04, 0x3A, 0x04  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x04  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x04  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x04  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x04  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x04  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x04  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpnz"     = [
+    b"rbvb"       , [0x04, 0x3A, 0x05  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x05  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x05  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x05  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x05  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x05  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x05  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpne"     = [
+    b"rbvb"       , [0x04, 0x3A, 0x05  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x05  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x05  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x05  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x05  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x05  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x05  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpbe"     = [
+    b"rbvb"       , [0x04, 0x3A, 0x06  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x06  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x06  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x06  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x06  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x06  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x06  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpna"     = [
+    b"rbvb"       , [0x04, 0x3A, 0x06  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x06  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x06  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x06  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x06  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x06  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x06  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpnbe"    = [
+    b"rbvb"       , [0x04, 0x3A, 0x07  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x07  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x07  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x07  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x07  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x07  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x07  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpa"      = [
+    b"rbvb"       , [0x04, 0x3A, 0x07  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x07  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x07  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x07  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x07  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x07  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x07  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmps"      = [
+    b"rbvb"       , [0x04, 0x3A, 0x08  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x08  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x08  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x08  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x08  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x08  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x08  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpns"     = [
+    b"rbvb"       , [0x04, 0x3A, 0x09  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x09  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x09  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x09  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x09  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x09  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x09  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpt"      = [
+    b"rbvb"       , [0x04, 0x3A, 0x0A  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x0A  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x0A  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x0A  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x0A  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x0A  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x0A  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpf"      = [
+    b"rbvb"       , [0x04, 0x3A, 0x0B  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x0B  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x0B  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x0B  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x0B  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x0B  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x0B  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpl"      = [
+    b"rbvb"       , [0x04, 0x3A, 0x0C  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x0C  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x0C  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x0C  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x0C  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x0C  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x0C  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpnge"    = [
+    b"rbvb"       , [0x04, 0x3A, 0x0C  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x0C  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x0C  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x0C  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x0C  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x0C  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x0C  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpnl"     = [
+    b"rbvb"       , [0x04, 0x3A, 0x0D  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x0D  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x0D  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x0D  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x0D  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x0D  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x0D  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpge"     = [
+    b"rbvb"       , [0x04, 0x3A, 0x0D  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x0D  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x0D  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x0D  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x0D  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x0D  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x0D  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmple"     = [
+    b"rbvb"       , [0x04, 0x3A, 0x0E  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x0E  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x0E  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x0E  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x0E  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x0E  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x0E  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpng"     = [
+    b"rbvb"       , [0x04, 0x3A, 0x0E  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x0E  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x0E  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x0E  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x0E  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x0E  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x0E  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpnle"    = [
+    b"rbvb"       , [0x04, 0x3A, 0x0F  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x0F  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x0F  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x0F  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x0F  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x0F  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x0F  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+"ccmpg"      = [
+    b"rbvb"       , [0x04, 0x3A, 0x0F  ], X, APX_OP | APX_SCC, APX;
+    b"vbib"       , [0x04, 0x80, 0x0F  ], 7, APX_OP | APX_SCC, APX;
+    b"vbrb"       , [0x04, 0x38, 0x0F  ], X, APX_OP | APX_SCC | ENC_MR, APX;
+    b"r*v*"       , [0x04, 0x3B, 0x0F  ], X, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*i*"       , [0x04, 0x81, 0x0F  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*ib"       , [0x04, 0x83, 0x0F  ], 7, AUTO_SIZE | APX_OP | APX_SCC, APX;
+    b"v*r*"       , [0x04, 0x39, 0x0F  ], X, AUTO_SIZE | APX_OP | APX_SCC | ENC_MR, APX;
+]
+
+"cfcmovo"    = [
+    b"r*v*"       , [0x04, 0x40        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x40        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x40        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovno"   = [
+    b"r*v*"       , [0x04, 0x41        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x41        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x41        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovb"    = [
+    b"r*v*"       , [0x04, 0x42        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x42        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x42        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovc"    = [
+    b"r*v*"       , [0x04, 0x42        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x42        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x42        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovnae"  = [
+    b"r*v*"       , [0x04, 0x42        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x42        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x42        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovnb"   = [
+    b"r*v*"       , [0x04, 0x43        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x43        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x43        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovnc"   = [
+    b"r*v*"       , [0x04, 0x43        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x43        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x43        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovae"   = [
+    b"r*v*"       , [0x04, 0x43        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x43        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x43        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovz"    = [
+    b"r*v*"       , [0x04, 0x44        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x44        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x44        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmove"    = [
+    b"r*v*"       , [0x04, 0x44        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x44        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x44        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovnz"   = [
+    b"r*v*"       , [0x04, 0x45        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x45        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x45        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovne"   = [
+    b"r*v*"       , [0x04, 0x45        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x45        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x45        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovbe"   = [
+    b"r*v*"       , [0x04, 0x46        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x46        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x46        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovna"   = [
+    b"r*v*"       , [0x04, 0x46        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x46        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x46        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovnbe"  = [
+    b"r*v*"       , [0x04, 0x47        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x47        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x47        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmova"    = [
+    b"r*v*"       , [0x04, 0x47        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x47        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x47        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovs"    = [
+    b"r*v*"       , [0x04, 0x48        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x48        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x48        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovns"   = [
+    b"r*v*"       , [0x04, 0x49        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x49        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x49        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovp"    = [
+    b"r*v*"       , [0x04, 0x4A        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x4A        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x4A        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovpe"   = [
+    b"r*v*"       , [0x04, 0x4A        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x4A        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x4A        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovnp"   = [
+    b"r*v*"       , [0x04, 0x4B        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x4B        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x4B        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovpo"   = [
+    b"r*v*"       , [0x04, 0x4B        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x4B        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x4B        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovl"    = [
+    b"r*v*"       , [0x04, 0x4C        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x4C        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x4C        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovnge"  = [
+    b"r*v*"       , [0x04, 0x4C        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x4C        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x4C        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovnl"   = [
+    b"r*v*"       , [0x04, 0x4D        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x4D        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x4D        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovge"   = [
+    b"r*v*"       , [0x04, 0x4D        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x4D        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x4D        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovle"   = [
+    b"r*v*"       , [0x04, 0x4E        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x4E        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x4E        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovng"   = [
+    b"r*v*"       , [0x04, 0x4E        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x4E        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x4E        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovnle"  = [
+    b"r*v*"       , [0x04, 0x4F        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x4F        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x4F        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
+]
+"cfcmovg"    = [
+    b"r*v*"       , [0x04, 0x4F        ], X, AUTO_SIZE | APX_OP | APX_NF, APX;
+    b"m*r*"       , [0x04, 0x4F        ], X, AUTO_SIZE | APX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x4F        ], X, AUTO_SIZE | APX_OP | APX_ND | APX_NF, APX;
 ]
 
 "seto"        = [
@@ -5539,23 +8126,24 @@ Ops!(
 "in"    = [
     b"Abib"       , [0xE4            ], X;
     b"Awib"       , [0xE5            ], X, WORD_SIZE;
-    b"Adib"       , [0xE5            ], X;
+    b"Adib"       , [0xE5            ], X, DWORD_SIZE;
     b"AbCw"       , [0xEC            ], X;
     b"AwCw"       , [0xED            ], X, WORD_SIZE;
-    b"AdCw"       , [0xED            ], X;
+    b"AdCw"       , [0xED            ], X, DWORD_SIZE;
 ]
 
 "out"   = [
     b"ibAb"       , [0xE6            ], X;
-    b"ibAw"       , [0xE7            ], X;
-    b"ibAd"       , [0xE7            ], X;
+    b"ibAw"       , [0xE7            ], X, WORD_SIZE;
+    b"ibAd"       , [0xE7            ], X, DWORD_SIZE;
     b"CwAb"       , [0xEE            ], X;
     b"CwAw"       , [0xEF            ], X, WORD_SIZE;
-    b"CwAd"       , [0xEF            ], X;
+    b"CwAd"       , [0xEF            ], X, DWORD_SIZE;
 ]
 
 "crc32"  = [
-    b"r*vb"       , [0x0F, 0x38, 0xF0], X, AUTO_REXW | PREF_F2 | EXACT_SIZE; // unique size encoding scheme
+    b"rdvb"       , [0x0F, 0x38, 0xF0], X, PREF_F2 | EXACT_SIZE; // unique size encoding scheme
+    b"rqvb"       , [0x0F, 0x38, 0xF0], X, WITH_REXW | PREF_F2 | EXACT_SIZE;
     b"rdvw"       , [0x0F, 0x38, 0xF1], X, WORD_SIZE | PREF_F2 | EXACT_SIZE;
     b"r*v*"       , [0x0F, 0x38, 0xF1], X, AUTO_REXW | PREF_F2 | EXACT_SIZE;
 ]
@@ -5566,6 +8154,7 @@ Ops!(
     b"r*v*"       , [0x0F, 0xAF      ], X, AUTO_SIZE;
     b"r*v*ib"     , [0x6B            ], X, AUTO_SIZE | EXACT_SIZE;
     b"r*v*i*"     , [0x69            ], X, AUTO_SIZE;
+    b"r*r*v*"     , [0x04, 0xAF        ], X, AUTO_SIZE | APX_OP | APX_ND, APX;
 ]
 
 )
@@ -1,6 +1,6 @@
 use syn::{parse, Token};
 use syn::spanned::Spanned;
-use proc_macro2::Span;
+use proc_macro2::{Span, Delimiter};
 use proc_macro_error2::emit_error;
 
 use lazy_static::lazy_static;
@@ -9,7 +9,7 @@ use crate::common::Size;
 use crate::parse_helpers::{eat_pseudo_keyword, parse_ident_or_rust_keyword, as_ident, ParseOptExt};
 
 use super::{Context, X86Mode};
-use super::ast::{Instruction, RawArg, Register, RegId, RegFamily, MemoryRefItem};
+use super::ast::{Instruction, RawArg, Register, RegId, RegFamily, MemoryRefItem, Decorators, Rounding};
 
 use std::collections::HashMap;
 
@@ -18,7 +18,9 @@ use std::collections::HashMap;
  */
 
 // parses a full instruction
-// syntax for a single op: PREFIX* ident (SIZE? expr ("," SIZE? expr)*)? ";"
+// syntax for a single op: PREFIX* ident DFV? (operand ("," operand)*)? ";"
+// where operand is either SIZE? expr DECORATOR* or a rounding control operand like {rn-sae}
+// and DFV is the default flags value of a conditional compare, like {dfv=of, cf}
 pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<(Instruction, Vec<RawArg>)> {
     let span = input.cursor().span();
 
@@ -32,16 +34,20 @@ pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) ->
     }
     ops.push(op);
 
-    // parse (sizehint? expr),*
+    // parse (sizehint? expr decorator*),*
     let mut args = Vec::new();
+    let mut decorators = Decorators {
+        dfv: eat_default_flags_value(input)?,
+        ..Decorators::default()
+    };
 
     if !(input.is_empty() || input.peek(Token![;])) {
-        args.push(parse_arg(ctx, input)?);
+        parse_operand(ctx, input, &mut args, &mut decorators)?;
 
         while input.peek(Token![,]) {
             let _: Token![,] = input.parse()?;
 
-            args.push(parse_arg(ctx, input)?);
+            parse_operand(ctx, input, &mut args, &mut decorators)?;
         }
     }
 
@@ -50,19 +56,173 @@ pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) ->
     Ok((
         Instruction {
             idents: ops,
-            span
+            span,
+            decorators
         },
         args
     ))
 }
 
+/// parses either a rounding control operand or a full arg definition followed by any decorators
+fn parse_operand(ctx: &mut Context, input: parse::ParseStream, args: &mut Vec<RawArg>, decorators: &mut Decorators) -> parse::Result<()> {
+    // rounding control operands don't result in an arg
+    let span = input.cursor().span();
+    if let Some(rounding) = eat_rounding_control(input) {
+        if decorators.rounding.is_some() {
+            emit_error!(span, "Duplicate rounding control operand");
+        }
+        decorators.rounding = Some(rounding);
+        return Ok(());
+    }
+
+    let arg = parse_arg(ctx, input)?;
+
+    // {k}, {z} and {1toN} decorators
+    while input.peek(syn::token::Brace) {
+        let span = input.cursor().span();
+        let inner;
+        let _ = syn::braced!(inner in input);
+        let inner = &inner;
+
+        if eat_pseudo_keyword(inner, "z") {
+            if !args.is_empty() {
+                emit_error!(span, "Zeroing can only be applied to the first operand");
+            } else if decorators.zeroing {
+                emit_error!(span, "Duplicate zeroing decorator");
+            }
+            decorators.zeroing = true;
+
+        } else if inner.peek(syn::LitInt) {
+            let lit: syn::LitInt = inner.parse()?;
+            let count = lit.suffix().strip_prefix("to").and_then(|n| n.parse::<u8>().ok());
+            let count = match count {
+                Some(count @ (2 | 4 | 8 | 16)) if lit.base10_digits() == "1" => count,
+                _ => {
+                    emit_error!(span, "Invalid broadcast decorator, expected {1to2}, {1to4}, {1to8} or {1to16}");
+                    continue;
+                }
+            };
+
+            match arg {
+                RawArg::IndirectRaw {..} |
+                RawArg::TypeMappedRaw {..} |
+                RawArg::IndirectJumpTarget {..} => (),
+                _ => emit_error!(span, "Broadcasts can only be applied to memory operands")
+            }
+            if decorators.broadcast.is_some() {
+                emit_error!(span, "Duplicate broadcast decorator");
+            }
+            decorators.broadcast = Some(count);
+
+        } else {
+            let expr: syn::Expr = inner.parse()?;
+            match parse_reg(ctx, &expr) {
+                Some((span, reg)) if reg.kind.family() == RegFamily::MASK => {
+                    if !args.is_empty() {
+                        emit_error!(span, "Masking can only be applied to the first operand");
+                    } else if decorators.mask.is_some() {
+                        emit_error!(span, "Duplicate masking decorator");
+                    }
+                    decorators.mask = Some(reg);
+                },
+                _ => emit_error!(expr, "Expected an opmask register, {z} or {1toN}")
+            }
+        }
+
+        if !inner.is_empty() {
+            emit_error!(span, "Unexpected tokens in decorator");
+        }
+    }
+
+    args.push(arg);
+    Ok(())
+}
+
+/// if a rounding control operand ({rn-sae}, {rd-sae}, {ru-sae}, {rz-sae} or {sae}) is present
+/// in the parse stream, returns the indicated rounding mode
+fn eat_rounding_control(input: parse::ParseStream) -> Option<Rounding> {
+    input.step(|cursor| {
+        if let Some((inner, _, rest)) = cursor.group(Delimiter::Brace) {
+            if let Some((ident, inner)) = inner.ident() {
+                let rounding = match ident.to_string().as_str() {
+                    "sae" => if inner.eof() {
+                        return Ok((Rounding::SuppressOnly, rest));
+                    } else {
+                        None
+                    },
+                    "rn" => Some(Rounding::Nearest),
+                    "rd" => Some(Rounding::Down),
+                    "ru" => Some(Rounding::Up),
+                    "rz" => Some(Rounding::Zero),
+                    _ => None
+                };
+
+                if let Some(rounding) = rounding {
+                    if let Some((punct, inner)) = inner.punct() {
+                        if let Some((ident, inner)) = inner.ident() {
+                            if punct.as_char() == '-' && ident == "sae" && inner.eof() {
+                                return Ok((rounding, rest));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Err(cursor.error("expected rounding control"))
+    }).ok()
+}
+
+/// if a default flags value ({dfv=of, sf, zf, cf}) is present in the parse stream,
+/// returns the indicated flags in OF SF ZF CF bit order
+fn eat_default_flags_value(input: parse::ParseStream) -> parse::Result<Option<u8>> {
+    if !input.peek(syn::token::Brace) {
+        return Ok(None);
+    }
+
+    // only consume the braces if they contain a dfv specification
+    let fork = input.fork();
+    let inner;
+    let _ = syn::braced!(inner in fork);
+    if !eat_pseudo_keyword(&inner, "dfv") {
+        return Ok(None);
+    }
+
+    let inner;
+    let _ = syn::braced!(inner in input);
+    let inner = &inner;
+    let _ = eat_pseudo_keyword(inner, "dfv");
+    let _: Token![=] = inner.parse()?;
+
+    let mut dfv = 0;
+    let flags = inner.parse_terminated(parse_ident_or_rust_keyword, Token![,])?;
+    for flag in flags {
+        let bit = match flag.to_string().as_str() {
+            "of" => 8,
+            "sf" => 4,
+            "zf" => 2,
+            "cf" => 1,
+            _ => {
+                emit_error!(flag, "Unknown flag '{}', expected of, sf, zf or cf", flag);
+                continue;
+            }
+        };
+        if dfv & bit != 0 {
+            emit_error!(flag, "Duplicate flag '{}'", flag);
+        }
+        dfv |= bit;
+    }
+
+    Ok(Some(dfv))
+}
+
 /// checks if the given ident is a valid x86 prefix
 fn is_prefix(ident: &syn::Ident) -> bool {
-    const PREFIXES: [&str; 12] = [
+    const PREFIXES: [&str; 13] = [
         "lock",
         "rep", "repe", "repz",
         "repne", "repnz",
-        "ss", "cs", "ds", "es", "fs", "gs"
+        "ss", "cs", "ds", "es", "fs", "gs",
+        "rex2"
     ];
 
     PREFIXES.contains(&ident.to_string().as_str())
@@ -96,7 +256,8 @@ fn eat_size_hint(ctx: &Context, input: parse::ParseStream) -> Option<Size> {
     ];
 
     let sizes = match ctx.mode {
-        X86Mode::Protected => &X86_SIZES,
+        X86Mode::Protected |
+        X86Mode::Real      => &X86_SIZES,
         X86Mode::Long      => &X64_SIZES
     };
     for &(kw, size) in sizes {
@@ -156,7 +317,8 @@ fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawA
     }
 
     // it's a normal (register/immediate/typemapped) operand
-    let arg: syn::Expr = input.parse()?;
+    // struct literals are not parsed here as they'd conflict with decorators, i.e. zmm0 {k1}
+    let arg = syn::Expr::parse_without_eager_brace(input)?;
 
     // typemapped: expr => type [expr] . ident
     if input.peek(Token![=>]) {
@@ -238,7 +400,8 @@ fn parse_reg(ctx: &Context, expr: &syn::Expr) -> Option<(Span, Register)> {
 
         let (reg, size) = match ctx.mode {
             X86Mode::Long      => X64_REGISTERS.get(&name).cloned(),
-            X86Mode::Protected => X86_REGISTERS.get(&name).cloned()
+            X86Mode::Protected |
+            X86Mode::Real      => X86_REGISTERS.get(&name).cloned()
         }?;
 
         Some((
@@ -262,7 +425,8 @@ fn parse_reg(ctx: &Context, expr: &syn::Expr) -> Option<(Span, Register)> {
         let name = name.as_str();
         let (size, family) = match ctx.mode {
             X86Mode::Long      => X64_FAMILIES.get(&name).cloned(),
-            X86Mode::Protected => X86_FAMILIES.get(&name).cloned()
+            X86Mode::Protected |
+            X86Mode::Real      => X86_FAMILIES.get(&name).cloned()
         }?;
 
         Some((
@@ -361,6 +525,22 @@ lazy_static!{
             ("r13", (R13, B_8)),
             ("r14", (R14, B_8)),
             ("r15", (R15, B_8)),
+            ("r16", (R16, B_8)),
+            ("r17", (R17, B_8)),
+            ("r18", (R18, B_8)),
+            ("r19", (R19, B_8)),
+            ("r20", (R20, B_8)),
+            ("r21", (R21, B_8)),
+            ("r22", (R22, B_8)),
+            ("r23", (R23, B_8)),
+            ("r24", (R24, B_8)),
+            ("r25", (R25, B_8)),
+            ("r26", (R26, B_8)),
+            ("r27", (R27, B_8)),
+            ("r28", (R28, B_8)),
+            ("r29", (R29, B_8)),
+            ("r30", (R30, B_8)),
+            ("r31", (R31, B_8)),
 
             ("eax" , (RAX, B_4)),
             ("r0d" , (RAX, B_4)),
@@ -386,6 +566,22 @@ lazy_static!{
             ("r13d", (R13, B_4)),
             ("r14d", (R14, B_4)),
             ("r15d", (R15, B_4)),
+            ("r16d", (R16, B_4)),
+            ("r17d", (R17, B_4)),
+            ("r18d", (R18, B_4)),
+            ("r19d", (R19, B_4)),
+            ("r20d", (R20, B_4)),
+            ("r21d", (R21, B_4)),
+            ("r22d", (R22, B_4)),
+            ("r23d", (R23, B_4)),
+            ("r24d", (R24, B_4)),
+            ("r25d", (R25, B_4)),
+            ("r26d", (R26, B_4)),
+            ("r27d", (R27, B_4)),
+            ("r28d", (R28, B_4)),
+            ("r29d", (R29, B_4)),
+            ("r30d", (R30, B_4)),
+            ("r31d", (R31, B_4)),
 
             ("ax"  , (RAX, B_2)),
             ("r0w" , (RAX, B_2)),
@@ -411,6 +607,22 @@ lazy_static!{
             ("r13w", (R13, B_2)),
             ("r14w", (R14, B_2)),
             ("r15w", (R15, B_2)),
+            ("r16w", (R16, B_2)),
+            ("r17w", (R17, B_2)),
+            ("r18w", (R18, B_2)),
+            ("r19w", (R19, B_2)),
+            ("r20w", (R20, B_2)),
+            ("r21w", (R21, B_2)),
+            ("r22w", (R22, B_2)),
+            ("r23w", (R23, B_2)),
+            ("r24w", (R24, B_2)),
+            ("r25w", (R25, B_2)),
+            ("r26w", (R26, B_2)),
+            ("r27w", (R27, B_2)),
+            ("r28w", (R28, B_2)),
+            ("r29w", (R29, B_2)),
+            ("r30w", (R30, B_2)),
+            ("r31w", (R31, B_2)),
 
             ("al"  , (RAX, BYTE)),
             ("r0b" , (RAX, BYTE)),
@@ -436,6 +648,22 @@ lazy_static!{
             ("r13b", (R13, BYTE)),
             ("r14b", (R14, BYTE)),
             ("r15b", (R15, BYTE)),
+            ("r16b", (R16, BYTE)),
+            ("r17b", (R17, BYTE)),
+            ("r18b", (R18, BYTE)),
+            ("r19b", (R19, BYTE)),
+            ("r20b", (R20, BYTE)),
+            ("r21b", (R21, BYTE)),
+            ("r22b", (R22, BYTE)),
+            ("r23b", (R23, BYTE)),
+            ("r24b", (R24, BYTE)),
+            ("r25b", (R25, BYTE)),
+            ("r26b", (R26, BYTE)),
+            ("r27b", (R27, BYTE)),
+            ("r28b", (R28, BYTE)),
+            ("r29b", (R29, BYTE)),
+            ("r30b", (R30, BYTE)),
+            ("r31b", (R31, BYTE)),
 
             ("rip", (RIP, B_8)),
             ("eip", (RIP, B_4)),
@@ -479,6 +707,22 @@ lazy_static!{
             ("xmm13", (XMM13, B_16)),
             ("xmm14", (XMM14, B_16)),
             ("xmm15", (XMM15, B_16)),
+            ("xmm16", (XMM16, B_16)),
+            ("xmm17", (XMM17, B_16)),
+            ("xmm18", (XMM18, B_16)),
+            ("xmm19", (XMM19, B_16)),
+            ("xmm20", (XMM20, B_16)),
+            ("xmm21", (XMM21, B_16)),
+            ("xmm22", (XMM22, B_16)),
+            ("xmm23", (XMM23, B_16)),
+            ("xmm24", (XMM24, B_16)),
+            ("xmm25", (XMM25, B_16)),
+            ("xmm26", (XMM26, B_16)),
+            ("xmm27", (XMM27, B_16)),
+            ("xmm28", (XMM28, B_16)),
+            ("xmm29", (XMM29, B_16)),
+            ("xmm30", (XMM30, B_16)),
+            ("xmm31", (XMM31, B_16)),
 
             ("ymm0" , (XMM0 , B_32)),
             ("ymm1" , (XMM1 , B_32)),
@@ -496,6 +740,55 @@ lazy_static!{
             ("ymm13", (XMM13, B_32)),
             ("ymm14", (XMM14, B_32)),
             ("ymm15", (XMM15, B_32)),
+            ("ymm16", (XMM16, B_32)),
+            ("ymm17", (XMM17, B_32)),
+            ("ymm18", (XMM18, B_32)),
+            ("ymm19", (XMM19, B_32)),
+            ("ymm20", (XMM20, B_32)),
+            ("ymm21", (XMM21, B_32)),
+            ("ymm22", (XMM22, B_32)),
+            ("ymm23", (XMM23, B_32)),
+            ("ymm24", (XMM24, B_32)),
+            ("ymm25", (XMM25, B_32)),
+            ("ymm26", (XMM26, B_32)),
+            ("ymm27", (XMM27, B_32)),
+            ("ymm28", (XMM28, B_32)),
+            ("ymm29", (XMM29, B_32)),
+            ("ymm30", (XMM30, B_32)),
+            ("ymm31", (XMM31, B_32)),
+
+            ("zmm0" , (XMM0 , B_64)),
+            ("zmm1" , (XMM1 , B_64)),
+            ("zmm2" , (XMM2 , B_64)),
+            ("zmm3" , (XMM3 , B_64)),
+            ("zmm4" , (XMM4 , B_64)),
+            ("zmm5" , (XMM5 , B_64)),
+            ("zmm6" , (XMM6 , B_64)),
+            ("zmm7" , (XMM7 , B_64)),
+            ("zmm8" , (XMM8 , B_64)),
+            ("zmm9" , (XMM9 , B_64)),
+            ("zmm10", (XMM10, B_64)),
+            ("zmm11", (XMM11, B_64)),
+            ("zmm12", (XMM12, B_64)),
+            ("zmm13", (XMM13, B_64)),
+            ("zmm14", (XMM14, B_64)),
+            ("zmm15", (XMM15, B_64)),
+            ("zmm16", (XMM16, B_64)),
+            ("zmm17", (XMM17, B_64)),
+            ("zmm18", (XMM18, B_64)),
+            ("zmm19", (XMM19, B_64)),
+            ("zmm20", (XMM20, B_64)),
+            ("zmm21", (XMM21, B_64)),
+            ("zmm22", (XMM22, B_64)),
+            ("zmm23", (XMM23, B_64)),
+            ("zmm24", (XMM24, B_64)),
+            ("zmm25", (XMM25, B_64)),
+            ("zmm26", (XMM26, B_64)),
+            ("zmm27", (XMM27, B_64)),
+            ("zmm28", (XMM28, B_64)),
+            ("zmm29", (XMM29, B_64)),
+            ("zmm30", (XMM30, B_64)),
+            ("zmm31", (XMM31, B_64)),
 
             ("es", (ES, B_2)),
             ("cs", (CS, B_2)),
@@ -542,6 +835,24 @@ lazy_static!{
             ("bnd1", (BND1, B_16)),
             ("bnd2", (BND2, B_16)),
             ("bnd3", (BND3, B_16)),
+
+            ("k0", (K0, B_8)),
+            ("k1", (K1, B_8)),
+            ("k2", (K2, B_8)),
+            ("k3", (K3, B_8)),
+            ("k4", (K4, B_8)),
+            ("k5", (K5, B_8)),
+            ("k6", (K6, B_8)),
+            ("k7", (K7, B_8)),
+
+            ("tmm0", (TMM0, B_64)),
+            ("tmm1", (TMM1, B_64)),
+            ("tmm2", (TMM2, B_64)),
+            ("tmm3", (TMM3, B_64)),
+            ("tmm4", (TMM4, B_64)),
+            ("tmm5", (TMM5, B_64)),
+            ("tmm6", (TMM6, B_64)),
+            ("tmm7", (TMM7, B_64)),
         ];
         MAP.iter().cloned().collect()
     };
@@ -616,6 +927,15 @@ lazy_static!{
             ("ymm6", (XMM6, B_32)),
             ("ymm7", (XMM7, B_32)),
 
+            ("zmm0", (XMM0, B_64)),
+            ("zmm1", (XMM1, B_64)),
+            ("zmm2", (XMM2, B_64)),
+            ("zmm3", (XMM3, B_64)),
+            ("zmm4", (XMM4, B_64)),
+            ("zmm5", (XMM5, B_64)),
+            ("zmm6", (XMM6, B_64)),
+            ("zmm7", (XMM7, B_64)),
+
             ("es", (ES, B_2)),
             ("cs", (CS, B_2)),
             ("ss", (SS, B_2)),
@@ -645,6 +965,15 @@ lazy_static!{
             ("bnd1", (BND1, B_16)),
             ("bnd2", (BND2, B_16)),
             ("bnd3", (BND3, B_16)),
+
+            ("k0", (K0, B_8)),
+            ("k1", (K1, B_8)),
+            ("k2", (K2, B_8)),
+            ("k3", (K3, B_8)),
+            ("k4", (K4, B_8)),
+            ("k5", (K5, B_8)),
+            ("k6", (K6, B_8)),
+            ("k7", (K7, B_8)),
         ];
         MAP.iter().cloned().collect()
     };
@@ -660,10 +989,13 @@ lazy_static!{
             ("Rm", (Size::B_8, RegFamily::MMX)),
             ("Rx", (Size::B_16, RegFamily::XMM)),
             ("Ry", (Size::B_32, RegFamily::XMM)),
+            ("Rz", (Size::B_64, RegFamily::XMM)),
             ("Rs", (Size::B_2,  RegFamily::SEGMENT)),
             ("RC", (Size::B_8, RegFamily::CONTROL)),
             ("RD", (Size::B_8, RegFamily::DEBUG)),
             ("RB", (Size::B_16, RegFamily::BOUND)),
+            ("Rk", (Size::B_8, RegFamily::MASK)),
+            ("Rt", (Size::B_64, RegFamily::TILE)),
         ];
         MAP.iter().cloned().collect()
     };
@@ -678,10 +1010,12 @@ lazy_static!{
             ("Rm",(Size::B_8, RegFamily::MMX)),
             ("Rx",(Size::B_16, RegFamily::XMM)),
             ("Ry",(Size::B_32, RegFamily::XMM)),
+            ("Rz",(Size::B_64, RegFamily::XMM)),
             ("Rs",(Size::B_2,  RegFamily::SEGMENT)),
             ("RC",(Size::B_4, RegFamily::CONTROL)),
             ("RD",(Size::B_4, RegFamily::DEBUG)),
             ("RB",(Size::B_16, RegFamily::BOUND)),
+            ("Rk",(Size::B_8, RegFamily::MASK)),
         ];
         MAP.iter().cloned().collect()
     };
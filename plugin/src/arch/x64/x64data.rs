@@ -37,7 +37,7 @@ pub fn get_mnemnonic_data(name: &str) -> Option<&'static [Opdata]> {
 
 bitflags! {
     #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-    pub struct Flags: u32 {
+    pub struct Flags: u64 {
         const DEFAULT   = 0x0000_0000; // this instruction has default encoding
         const VEX_OP    = 0x0000_0001; // this instruction requires a VEX prefix to be encoded
         const XOP_OP    = 0x0000_0002; // this instruction requires a XOP prefix to be encoded
@@ -48,12 +48,12 @@ bitflags! {
         const AUTO_NO32 = 0x0000_0010; // 16 bit -> OPSIZE , 32-bit -> None(x86), 64-bit -> None(x64)
         const AUTO_REXW = 0x0000_0020; // 16 bit -> illegal, 32-bit -> None     , 64-bit -> REX.W/VEX.W/XOP.W
         const AUTO_VEXL = 0x0000_0040; // 128bit -> None   , 256bit -> VEX.L
-        const WORD_SIZE = 0x0000_0080; // implies opsize prefix
+        const WORD_SIZE = 0x0000_0080; // implies 16-bit operand size (opsize prefix outside of 16-bit mode)
         const WITH_REXW = 0x0000_0100; // implies REX.W/VEX.W/XOP.W
         const WITH_VEXL = 0x0000_0200; // implies VEX.L/XOP.L
         const EXACT_SIZE= 0x0000_0400; // operands with unknown sizes cannot be assumed to match
 
-        const PREF_66   = WORD_SIZE;   // mandatory prefix (same as WORD_SIZE)
+        const PREF_66   = 0x0080_0000_0000; // mandatory prefix (opsize prefix regardless of the mode)
         const PREF_67   = 0x0000_0800; // mandatory prefix (same as SMALL_ADDRESS)
         const PREF_F0   = 0x0000_1000; // mandatory prefix (same as LOCK)
         const PREF_F2   = 0x0000_2000; // mandatory prefix (REPNE)
@@ -68,21 +68,44 @@ bitflags! {
         const ENC_VM    = 0x0010_0000; // select alternate arg encoding
         const ENC_MIB   = 0x0020_0000; // A special encoding using the SIB to specify an immediate and two registers
         const X86_ONLY  = 0x0040_0000; // instructions available in protected mode, but not long mode
+
+        const EVEX_OP   = 0x0080_0000; // this instruction requires an EVEX prefix to be encoded
+        const WITH_EVEXL= 0x0100_0000; // implies EVEX.L'L = 2 (512-bit vectors)
+        const OPMASK    = 0x0200_0000; // an opmask register can be applied to the first operand
+        const OPMASK_REQ= 0x0400_0000; // an opmask register must be applied to the first operand
+        const ZEROING   = 0x0800_0000; // zeroing-masking is allowed
+        const BCST_32   = 0x1000_0000; // the memory operand can be broadcast from a 32-bit element
+        const BCST_64   = 0x2000_0000; // the memory operand can be broadcast from a 64-bit element
+        const EMB_RND   = 0x4000_0000; // embedded rounding control is allowed
+        const SAE       = 0x8000_0000; // suppress all exceptions is allowed
+
+        // compressed displacement scales with the element size instead of the memory operand size
+        const T1S_32    = 0x0001_0000_0000;
+        const T1S_64    = 0x0002_0000_0000;
+
+        const SIB_MEM   = 0x0004_0000_0000; // the memory operand has to be encoded using a SIB byte with an index register
+
+        const APX_OP    = 0x0008_0000_0000; // this instruction requires an extended EVEX prefix in map 4 (APX promoted legacy instruction)
+        const APX_ND    = 0x0010_0000_0000; // sets EVEX.ND, the first operand is a new data destination encoded in vvvv
+        const APX_NF    = 0x0020_0000_0000; // sets EVEX.NF
+        const APX_SCC   = 0x0040_0000_0000; // conditional compare, the last opcode byte is the source condition code
+
+        const DWORD_SIZE= 0x0100_0000_0000; // implies 32-bit operand size (opsize prefix in 16-bit mode)
     }
 }
 
 impl Flags {
-    const fn make(bits: u32) -> Flags {
+    const fn make(bits: u64) -> Flags {
         Flags::from_bits_truncate(bits)
     }
 }
 
 bitflags! {
     #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-    pub struct Features: u32 {
+    pub struct Features: u64 {
         const X64_IMPLICIT = 0x0000_0000;
         const FPU          = 0x0000_0001;
-          const MMX          = 0x0000_0002;
+        const MMX          = 0x0000_0002;
         const TDNOW        = 0x0000_0004;
         const SSE          = 0x0000_0008;
         const SSE2         = 0x0000_0010;
@@ -107,11 +130,32 @@ bitflags! {
         const CYRIX        = 0x0080_0000;
         const AMD          = 0x0100_0000;
         const DIRECTSTORES = 0x0200_0000;
+        const AVX512F      = 0x0400_0000;
+        const AVX512CD     = 0x0800_0000;
+        const AVX512ER     = 0x1000_0000;
+        const AVX512PF     = 0x2000_0000;
+        const AVX512BW     = 0x4000_0000;
+        const AVX512DQ     = 0x8000_0000;
+        const AVX512VL     = 0x0001_0000_0000;
+        const AVX512IFMA   = 0x0002_0000_0000;
+        const AVX512VBMI   = 0x0004_0000_0000;
+        const AMX_TILE     = 0x0008_0000_0000;
+        const AMX_INT8     = 0x0010_0000_0000;
+        const AMX_BF16     = 0x0020_0000_0000;
+        const APX          = 0x0040_0000_0000;
+        const CET          = 0x0080_0000_0000;
+        const GFNI         = 0x0100_0000_0000;
+        const AVXVNNI      = 0x0200_0000_0000;
+        const CLFLUSHOPT   = 0x0400_0000_0000;
+        const CLWB         = 0x0800_0000_0000;
+        const SERIALIZE    = 0x1000_0000_0000;
+        const WAITPKG      = 0x2000_0000_0000;
+        const ENQCMD       = 0x4000_0000_0000;
     }
 }
 
 impl Features {
-    const fn make(bits: u32) -> Features {
+    const fn make(bits: u64) -> Features {
         Features::from_bits_truncate(bits)
     }
 
@@ -143,6 +187,27 @@ impl Features {
             "cyrix" => Some(Features::CYRIX),
             "amd"   => Some(Features::AMD),
             "directstores"   => Some(Features::DIRECTSTORES),
+            "avx512f"    => Some(Features::AVX512F),
+            "avx512cd"   => Some(Features::AVX512CD),
+            "avx512er"   => Some(Features::AVX512ER),
+            "avx512pf"   => Some(Features::AVX512PF),
+            "avx512bw"   => Some(Features::AVX512BW),
+            "avx512dq"   => Some(Features::AVX512DQ),
+            "avx512vl"   => Some(Features::AVX512VL),
+            "avx512ifma" => Some(Features::AVX512IFMA),
+            "avx512vbmi" => Some(Features::AVX512VBMI),
+            "amx_tile"   => Some(Features::AMX_TILE),
+            "amx_int8"   => Some(Features::AMX_INT8),
+            "amx_bf16"   => Some(Features::AMX_BF16),
+            "apx"        => Some(Features::APX),
+            "cet"        => Some(Features::CET),
+            "gfni"       => Some(Features::GFNI),
+            "avxvnni"    => Some(Features::AVXVNNI),
+            "clflushopt" => Some(Features::CLFLUSHOPT),
+            "clwb"       => Some(Features::CLWB),
+            "serialize"  => Some(Features::SERIALIZE),
+            "waitpkg"    => Some(Features::WAITPKG),
+            "enqcmd"     => Some(Features::ENQCMD),
             _ => None
         }
     }
@@ -177,6 +242,27 @@ impl Display for Features {
         if self.contains(Features::CYRIX) { keys.push("cyrix"); }
         if self.contains(Features::AMD)   { keys.push("amd"); }
         if self.contains(Features::DIRECTSTORES)   { keys.push("directstores"); }
+        if self.contains(Features::AVX512F)    { keys.push("avx512f"); }
+        if self.contains(Features::AVX512CD)   { keys.push("avx512cd"); }
+        if self.contains(Features::AVX512ER)   { keys.push("avx512er"); }
+        if self.contains(Features::AVX512PF)   { keys.push("avx512pf"); }
+        if self.contains(Features::AVX512BW)   { keys.push("avx512bw"); }
+        if self.contains(Features::AVX512DQ)   { keys.push("avx512dq"); }
+        if self.contains(Features::AVX512VL)   { keys.push("avx512vl"); }
+        if self.contains(Features::AVX512IFMA) { keys.push("avx512ifma"); }
+        if self.contains(Features::AVX512VBMI) { keys.push("avx512vbmi"); }
+        if self.contains(Features::AMX_TILE)   { keys.push("amx_tile"); }
+        if self.contains(Features::AMX_INT8)   { keys.push("amx_int8"); }
+        if self.contains(Features::AMX_BF16)   { keys.push("amx_bf16"); }
+        if self.contains(Features::APX)        { keys.push("apx"); }
+        if self.contains(Features::CET)        { keys.push("cet"); }
+        if self.contains(Features::GFNI)       { keys.push("gfni"); }
+        if self.contains(Features::AVXVNNI)    { keys.push("avxvnni"); }
+        if self.contains(Features::CLFLUSHOPT) { keys.push("clflushopt"); }
+        if self.contains(Features::CLWB)       { keys.push("clwb"); }
+        if self.contains(Features::SERIALIZE)  { keys.push("serialize"); }
+        if self.contains(Features::WAITPKG)    { keys.push("waitpkg"); }
+        if self.contains(Features::ENQCMD)     { keys.push("enqcmd"); }
         for (i, k) in keys.into_iter().enumerate() {
             if i != 0 {
                 f.write_str(", ")?;
@@ -193,60 +279,98 @@ pub fn mnemnonics() -> hash_map::Keys<'static, &'static str, &'static [Opdata]>
 }
 
 // workaround until bitflags can be used in const
-const DEFAULT    : u32 = Flags::DEFAULT.bits();
-const VEX_OP     : u32 = Flags::VEX_OP.bits();
-const XOP_OP     : u32 = Flags::XOP_OP.bits();
-const IMM_OP     : u32 = Flags::IMM_OP.bits();
-const SHORT_ARG  : u32 = Flags::SHORT_ARG.bits();
-const AUTO_SIZE  : u32 = Flags::AUTO_SIZE.bits();
-const AUTO_NO32  : u32 = Flags::AUTO_NO32.bits();
-const AUTO_REXW  : u32 = Flags::AUTO_REXW.bits();
-const AUTO_VEXL  : u32 = Flags::AUTO_VEXL.bits();
-const WORD_SIZE  : u32 = Flags::WORD_SIZE.bits();
-const WITH_REXW  : u32 = Flags::WITH_REXW.bits();
-const WITH_VEXL  : u32 = Flags::WITH_VEXL.bits();
-const EXACT_SIZE : u32 = Flags::EXACT_SIZE.bits();
-const PREF_66    : u32 = Flags::PREF_66.bits();
-const PREF_67    : u32 = Flags::PREF_67.bits();
-const PREF_F0    : u32 = Flags::PREF_F0.bits();
-const PREF_F2    : u32 = Flags::PREF_F2.bits();
-const PREF_F3    : u32 = Flags::PREF_F3.bits();
-const LOCK       : u32 = Flags::LOCK.bits();
-const REP        : u32 = Flags::REP.bits();
-const REPE       : u32 = Flags::REPE.bits();
-const ENC_MR     : u32 = Flags::ENC_MR.bits();
-const ENC_VM     : u32 = Flags::ENC_VM.bits();
-const ENC_MIB    : u32 = Flags::ENC_MIB.bits();
-const X86_ONLY   : u32 = Flags::X86_ONLY.bits();
+const DEFAULT    : u64 = Flags::DEFAULT.bits();
+const VEX_OP     : u64 = Flags::VEX_OP.bits();
+const XOP_OP     : u64 = Flags::XOP_OP.bits();
+const IMM_OP     : u64 = Flags::IMM_OP.bits();
+const SHORT_ARG  : u64 = Flags::SHORT_ARG.bits();
+const AUTO_SIZE  : u64 = Flags::AUTO_SIZE.bits();
+const AUTO_NO32  : u64 = Flags::AUTO_NO32.bits();
+const AUTO_REXW  : u64 = Flags::AUTO_REXW.bits();
+const AUTO_VEXL  : u64 = Flags::AUTO_VEXL.bits();
+const WORD_SIZE  : u64 = Flags::WORD_SIZE.bits();
+const WITH_REXW  : u64 = Flags::WITH_REXW.bits();
+const WITH_VEXL  : u64 = Flags::WITH_VEXL.bits();
+const EXACT_SIZE : u64 = Flags::EXACT_SIZE.bits();
+const PREF_66    : u64 = Flags::PREF_66.bits();
+const PREF_67    : u64 = Flags::PREF_67.bits();
+const PREF_F0    : u64 = Flags::PREF_F0.bits();
+const PREF_F2    : u64 = Flags::PREF_F2.bits();
+const PREF_F3    : u64 = Flags::PREF_F3.bits();
+const LOCK       : u64 = Flags::LOCK.bits();
+const REP        : u64 = Flags::REP.bits();
+const REPE       : u64 = Flags::REPE.bits();
+const ENC_MR     : u64 = Flags::ENC_MR.bits();
+const ENC_VM     : u64 = Flags::ENC_VM.bits();
+const ENC_MIB    : u64 = Flags::ENC_MIB.bits();
+const X86_ONLY   : u64 = Flags::X86_ONLY.bits();
+const EVEX_OP    : u64 = Flags::EVEX_OP.bits();
+const WITH_EVEXL : u64 = Flags::WITH_EVEXL.bits();
+const OPMASK     : u64 = Flags::OPMASK.bits();
+const OPMASK_REQ : u64 = Flags::OPMASK_REQ.bits();
+const ZEROING    : u64 = Flags::ZEROING.bits();
+const BCST_32    : u64 = Flags::BCST_32.bits();
+const BCST_64    : u64 = Flags::BCST_64.bits();
+const EMB_RND    : u64 = Flags::EMB_RND.bits();
+const SAE        : u64 = Flags::SAE.bits();
+const T1S_32     : u64 = Flags::T1S_32.bits();
+const T1S_64     : u64 = Flags::T1S_64.bits();
+const SIB_MEM    : u64 = Flags::SIB_MEM.bits();
+const APX_OP     : u64 = Flags::APX_OP.bits();
+const APX_ND     : u64 = Flags::APX_ND.bits();
+const APX_NF     : u64 = Flags::APX_NF.bits();
+const APX_SCC    : u64 = Flags::APX_SCC.bits();
+const DWORD_SIZE : u64 = Flags::DWORD_SIZE.bits();
 
 #[allow(dead_code)]
-const X64_IMPLICIT : u32 = Features::X64_IMPLICIT.bits();
-const FPU          : u32 = Features::FPU.bits();
-const MMX          : u32 = Features::MMX.bits();
-const TDNOW        : u32 = Features::TDNOW.bits();
-const SSE          : u32 = Features::SSE.bits();
-const SSE2         : u32 = Features::SSE2.bits();
-const SSE3         : u32 = Features::SSE3.bits();
-const VMX          : u32 = Features::VMX.bits();
-const SSSE3        : u32 = Features::SSSE3.bits();
-const SSE4A        : u32 = Features::SSE4A.bits();
-const SSE41        : u32 = Features::SSE41.bits();
-const SSE42        : u32 = Features::SSE42.bits();
-const SSE5         : u32 = Features::SSE5.bits();
-const AVX          : u32 = Features::AVX.bits();
-const AVX2         : u32 = Features::AVX2.bits();
-const FMA          : u32 = Features::FMA.bits();
-const BMI1         : u32 = Features::BMI1.bits();
-const BMI2         : u32 = Features::BMI2.bits();
-const TBM          : u32 = Features::TBM.bits();
-const RTM          : u32 = Features::RTM.bits();
-const INVPCID      : u32 = Features::INVPCID.bits();
-const MPX          : u32 = Features::MPX.bits();
-const SHA          : u32 = Features::SHA.bits();
-const PREFETCHWT1  : u32 = Features::PREFETCHWT1.bits();
-const CYRIX        : u32 = Features::CYRIX.bits();
-const AMD          : u32 = Features::AMD.bits();
-const DIRECTSTORES : u32 = Features::DIRECTSTORES.bits();
+const X64_IMPLICIT : u64 = Features::X64_IMPLICIT.bits();
+const FPU          : u64 = Features::FPU.bits();
+const MMX          : u64 = Features::MMX.bits();
+const TDNOW        : u64 = Features::TDNOW.bits();
+const SSE          : u64 = Features::SSE.bits();
+const SSE2         : u64 = Features::SSE2.bits();
+const SSE3         : u64 = Features::SSE3.bits();
+const VMX          : u64 = Features::VMX.bits();
+const SSSE3        : u64 = Features::SSSE3.bits();
+const SSE4A        : u64 = Features::SSE4A.bits();
+const SSE41        : u64 = Features::SSE41.bits();
+const SSE42        : u64 = Features::SSE42.bits();
+const SSE5         : u64 = Features::SSE5.bits();
+const AVX          : u64 = Features::AVX.bits();
+const AVX2         : u64 = Features::AVX2.bits();
+const FMA          : u64 = Features::FMA.bits();
+const BMI1         : u64 = Features::BMI1.bits();
+const BMI2         : u64 = Features::BMI2.bits();
+const TBM          : u64 = Features::TBM.bits();
+const RTM          : u64 = Features::RTM.bits();
+const INVPCID      : u64 = Features::INVPCID.bits();
+const MPX          : u64 = Features::MPX.bits();
+const SHA          : u64 = Features::SHA.bits();
+const PREFETCHWT1  : u64 = Features::PREFETCHWT1.bits();
+const CYRIX        : u64 = Features::CYRIX.bits();
+const AMD          : u64 = Features::AMD.bits();
+const DIRECTSTORES : u64 = Features::DIRECTSTORES.bits();
+const AVX512F      : u64 = Features::AVX512F.bits();
+const AVX512CD     : u64 = Features::AVX512CD.bits();
+const AVX512ER     : u64 = Features::AVX512ER.bits();
+const AVX512PF     : u64 = Features::AVX512PF.bits();
+const AVX512BW     : u64 = Features::AVX512BW.bits();
+const AVX512DQ     : u64 = Features::AVX512DQ.bits();
+const AVX512VL     : u64 = Features::AVX512VL.bits();
+const AVX512IFMA   : u64 = Features::AVX512IFMA.bits();
+const AVX512VBMI   : u64 = Features::AVX512VBMI.bits();
+const AMX_TILE     : u64 = Features::AMX_TILE.bits();
+const AMX_INT8     : u64 = Features::AMX_INT8.bits();
+const AMX_BF16     : u64 = Features::AMX_BF16.bits();
+const APX          : u64 = Features::APX.bits();
+const CET          : u64 = Features::CET.bits();
+const GFNI         : u64 = Features::GFNI.bits();
+const AVXVNNI      : u64 = Features::AVXVNNI.bits();
+const CLFLUSHOPT   : u64 = Features::CLFLUSHOPT.bits();
+const CLWB         : u64 = Features::CLWB.bits();
+const SERIALIZE    : u64 = Features::SERIALIZE.bits();
+const WAITPKG      : u64 = Features::WAITPKG.bits();
+const ENQCMD       : u64 = Features::ENQCMD.bits();
 
 
 lazy_static! {
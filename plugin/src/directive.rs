@@ -10,9 +10,9 @@ use proc_macro_error2::emit_error;
 use crate::common::{Stmt, Size, delimited};
 use crate::arch;
 use crate::DynasmContext;
-use crate::parse_helpers::ParseOptExt;
+use crate::parse_helpers::{ParseOptExt, parse_ident_or_rust_keyword};
 
-pub(crate) fn evaluate_directive(invocation_context: &mut DynasmContext, stmts: &mut Vec<Stmt>, input: parse::ParseStream) -> parse::Result<()> {
+pub(crate) fn evaluate_directive(invocation_context: &mut DynasmContext, target: &TokenTree, stmts: &mut Vec<Stmt>, input: parse::ParseStream) -> parse::Result<()> {
     let directive: syn::Ident = input.parse()?;
 
     match directive.to_string().as_str() {
@@ -30,12 +30,13 @@ pub(crate) fn evaluate_directive(invocation_context: &mut DynasmContext, stmts:
         "feature" => {
             // ; .feature ident ("," ident) *
             let mut features = Vec::new();
-            let ident: syn::Ident = input.parse()?;
+            // features can be rust keywords, like riscv's priv
+            let ident = parse_ident_or_rust_keyword(input)?;
             features.push(ident);
 
             while input.peek(Token![,]) {
                 let _: Token![,] = input.parse()?;
-                let ident: syn::Ident = input.parse()?;
+                let ident = parse_ident_or_rust_keyword(input)?;
                 features.push(ident);
             }
 
@@ -77,6 +78,13 @@ pub(crate) fn evaluate_directive(invocation_context: &mut DynasmContext, stmts:
 
             stmts.push(Stmt::Align(delimited(value), with));
         },
+        "ltorg" => {
+            // ; .ltorg
+            // emits the literal pool managed by the assembler
+            stmts.push(Stmt::Stmt(quote_spanned!{ directive.span()=>
+                #target.pool_flush()
+            }));
+        },
         "alias" => {
             // ; .alias ident, ident
             // consider changing this to ; .alias ident = ident next breaking change
@@ -154,13 +154,14 @@ impl parse::Parse for Dynasm {
             if input.peek(Token![.]) {
                 let _: Token![.] = input.parse()?;
 
-                directive::evaluate_directive(invocation_context, &mut stmts, input)?;
+                directive::evaluate_directive(invocation_context, &target, &mut stmts, input)?;
             } else {
                 // anything else is an assembly instruction which should be in current_arch
 
                 let mut state = State {
                     stmts: &mut stmts,
                     invocation_context: &*invocation_context,
+                    target: &target,
                 };
                 invocation_context.current_arch.compile_instruction(&mut state, input)?;
             }
@@ -187,9 +188,14 @@ pub fn dynasm_opmap(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream
     s.push_str("% Instruction Reference\n\n");
 
     s.push_str(&match opmap.arch.as_str() {
-        "x64" | "x86" => arch::x64::create_opmap(),
+        "x64" | "x86" | "x86_16" => arch::x64::create_opmap(),
         "aarch64" => arch::aarch64::create_opmap(),
         "riscv" => arch::riscv::create_opmap(),
+        "loongarch64" => arch::loongarch64::create_opmap(),
+        "arm" => arch::arm::create_opmap(false),
+        "thumb2" => arch::arm::create_opmap(true),
+        "ebpf" => arch::ebpf::create_opmap(),
+        "ppc64le" => arch::ppc64le::create_opmap(),
         x => panic!("Unknown architecture {}", x)
     });
 
@@ -209,9 +215,14 @@ pub fn dynasm_extract(tokens: proc_macro::TokenStream) -> proc_macro::TokenStrea
     let opmap = parse_macro_input!(tokens as DynasmOpmap);
 
     let s = match opmap.arch.as_str() {
-        "x64" | "x86" => "UNIMPLEMENTED".into(),
+        "x64" | "x86" | "x86_16" => "UNIMPLEMENTED".into(),
         "aarch64" => arch::aarch64::extract_opmap(),
         "riscv" => arch::riscv::extract_opmap(),
+        "loongarch64" => arch::loongarch64::extract_opmap(),
+        "arm" => arch::arm::extract_opmap(false),
+        "thumb2" => arch::arm::extract_opmap(true),
+        "ebpf" => arch::ebpf::extract_opmap(),
+        "ppc64le" => arch::ppc64le::extract_opmap(),
         x => panic!("Unknown architecture {}", x)
     };
 
@@ -245,6 +256,7 @@ impl parse::Parse for DynasmOpmap {
 struct State<'a> {
     pub stmts: &'a mut Vec<common::Stmt>,
     pub invocation_context: &'a DynasmContext,
+    pub target: &'a TokenTree,
 }
 
 // File local data implementation.
@@ -1,6 +1,6 @@
 use syn::parse;
 use syn::spanned::Spanned;
-use proc_macro2::{Span, TokenStream, TokenTree, Literal};
+use proc_macro2::{Span, TokenStream, TokenTree, Literal, Group, Delimiter};
 use quote::{quote, quote_spanned, ToTokens};
 
 use byteorder::{ByteOrder, LittleEndian};
@@ -278,6 +278,24 @@ pub fn expr_size_of(path: &syn::Path) -> TokenTree {
     })
 }
 
+// returns ((offset_of!(path, attr) as i32) + (size_of::<path>() as i32) * index) as _
+// this is the displacement of a type mapped reference on architectures that encode it as a
+// plain immediate offset, which has to fit whatever integer type that architecture uses.
+pub fn expr_typemap_disp(span: Span, path: &syn::Path, index: Option<&syn::Expr>, attr: Option<&syn::Ident>) -> Option<TokenTree> {
+    let scaled = index.map(|index| expr_size_of_scale(path, &delimited(index), Size::B_4));
+    let attr = attr.map(|attr| expr_offset_of(path, attr, Size::B_4));
+
+    let disp = expr_add_many(span, attr.into_iter().chain(scaled))?;
+
+    // the parenthesis are needed for precedence wherever this gets interpolated, but they should
+    // not carry the user's span, or rustc will lint them as unnecessary when it gets assigned.
+    let mut group = Group::new(Delimiter::Parenthesis, quote_spanned! { span=>
+        #disp as _
+    });
+    group.set_span(Span::mixed_site());
+    Some(TokenTree::Group(group))
+}
+
 // Reparses a tokentree into an expression
 pub fn reparse(tt: &TokenTree) -> parse::Result<syn::Expr> {
     syn::parse2(tt.into_token_stream())
@@ -41,8 +41,12 @@ pub enum Aarch64Relocation {
     ADRP,
     // tbnz, tbz: 14 bits, dword aligned
     TBZ,
+    // adrp followed by add/ldr/str: split 33 bit relative to the page of the adrp, low 12 bits scaled by 1 << scale
+    ADRPPAIR(u8, RelocationKind),
     // Anything in directives
     Plain(RelocationSize),
+    // Label addresses in literal pools
+    Absolute(RelocationSize),
 }
 
 impl Aarch64Relocation {
@@ -53,10 +57,40 @@ impl Aarch64Relocation {
             Self::ADR => 0x9F00_001F,
             Self::ADRP => 0x9F00_001F,
             Self::TBZ => 0xFFF8_001F,
-            Self::Plain(_) => 0
+            Self::ADRPPAIR(_, _) | Self::Plain(_) | Self::Absolute(_) => 0
         }
     }
 
+    fn write_pair(scale: u8, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        let value = i64::try_from(value).map_err(|_| ImpossibleRelocation { } )?;
+        let low = value & 0xFFF;
+        if low & ((1 << scale) - 1) != 0 || !fits_signed_bitfield(value >> 12, 21) {
+            return Err(ImpossibleRelocation { } );
+        }
+
+        // adrp gets the page offset, the following instruction the offset within the page
+        let page = (value >> 12) as u32;
+        let adrp = LittleEndian::read_u32(&buf[..4]) & 0x9F00_001F;
+        LittleEndian::write_u32(&mut buf[..4], adrp | (((page >> 2) & 0x7FFFF) << 5) | ((page & 3) << 29));
+
+        let offset = (low >> scale) as u32;
+        let lo12 = LittleEndian::read_u32(&buf[4..]) & 0xFFC0_03FF;
+        LittleEndian::write_u32(&mut buf[4..], lo12 | (offset << 10));
+        Ok(())
+    }
+
+    fn read_pair(scale: u8, buf: &[u8]) -> isize {
+        let adrp = LittleEndian::read_u32(&buf[..4]);
+        let page = (((adrp >> 5) & 0x7FFFF) << 2) | ((adrp >> 29) & 3);
+        // sign extend the 21-bit page offset
+        let page = i64::from(((page << 11) as i32) >> 11);
+
+        let lo12 = LittleEndian::read_u32(&buf[4..]);
+        let low = i64::from((lo12 >> 10) & 0xFFF) << scale;
+
+        ((page << 12) + low) as isize
+    }
+
     fn encode(&self, value: isize) -> Result<u32, ImpossibleRelocation> {
         let value = i64::try_from(value).map_err(|_| ImpossibleRelocation { } )?;
         Ok(match self {
@@ -98,7 +132,7 @@ impl Aarch64Relocation {
                 let value = (value >> 2) as u32;
                 (value & 0x3FFF) << 5
             },
-            Self::Plain(_) => return Err(ImpossibleRelocation { } )
+            Self::ADRPPAIR(_, _) | Self::Plain(_) | Self::Absolute(_) => return Err(ImpossibleRelocation { } )
         })
     }
 }
@@ -112,22 +146,35 @@ impl Relocation for Aarch64Relocation {
             2 => Self::ADR,
             3 => Self::ADRP,
             4 => Self::TBZ,
+            x @ 16 ..= 31 => Self::ADRPPAIR(x & 7, if x & 8 == 0 { RelocationKind::Relative } else { RelocationKind::RelToAbs }),
             x  => Self::Plain(RelocationSize::from_encoding(x - 4))
         }
     }
     fn from_size(size: RelocationSize) -> Self {
         Self::Plain(size)
     }
+    fn absolute_from_size(size: RelocationSize) -> Option<Self> {
+        Some(Self::Absolute(size))
+    }
+    fn ref_alignment(&self) -> usize {
+        match self {
+            Self::ADRPPAIR(_, _) => 4096,
+            _ => 1
+        }
+    }
     fn size(&self) -> usize {
         match self {
-            Self::Plain(s) => s.size(),
+            Self::Plain(s) | Self::Absolute(s) => s.size(),
+            Self::ADRPPAIR(_, _) => RelocationSize::QWord.size(),
             _ => RelocationSize::DWord.size(),
         }
     }
     fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
-        if let Self::Plain(s) = self {
-            return s.write_value(buf, value);
-        };
+        match self {
+            Self::Plain(s) | Self::Absolute(s) => return s.write_value(buf, value),
+            Self::ADRPPAIR(scale, _) => return Self::write_pair(*scale, buf, value),
+            _ => ()
+        }
 
         let mask = self.op_mask();
         let template = LittleEndian::read_u32(buf) & mask;
@@ -138,9 +185,11 @@ impl Relocation for Aarch64Relocation {
         Ok(())
     }
     fn read_value(&self, buf: &[u8]) -> isize {
-        if let Self::Plain(s) = self {
-            return s.read_value(buf);
-        };
+        match self {
+            Self::Plain(s) | Self::Absolute(s) => return s.read_value(buf),
+            Self::ADRPPAIR(scale, _) => return Self::read_pair(*scale, buf),
+            _ => ()
+        }
 
         let mask = !self.op_mask();
         let value = LittleEndian::read_u32(buf);
@@ -162,7 +211,7 @@ impl Relocation for Aarch64Relocation {
             Self::TBZ => u64::from(
                 (value & mask) >> 5
             ) << 2,
-            Self::Plain(_) => unreachable!()
+            Self::ADRPPAIR(_, _) | Self::Plain(_) | Self::Absolute(_) => unreachable!()
         };
 
         // Sign extend.
@@ -172,7 +221,7 @@ impl Relocation for Aarch64Relocation {
             Self::ADR => 21,
             Self::ADRP => 33,
             Self::TBZ => 14,
-            Self::Plain(_) => unreachable!()
+            Self::ADRPPAIR(_, _) | Self::Plain(_) | Self::Absolute(_) => unreachable!()
         };
         let offset = 1u64 << (bits - 1);
         let value: u64 = (unpacked ^ offset).wrapping_sub(offset);
@@ -180,7 +229,11 @@ impl Relocation for Aarch64Relocation {
         value as i64 as isize
     }
     fn kind(&self) -> RelocationKind {
-        RelocationKind::Relative
+        match self {
+            Self::Absolute(_) => RelocationKind::AbsToRel,
+            Self::ADRPPAIR(_, kind) => *kind,
+            _ => RelocationKind::Relative
+        }
     }
     fn page_size() -> usize {
         4096
@@ -272,6 +325,71 @@ pub fn encode_logical_immediate_64bit(value: u64) -> Option<u16> {
     Some(((n as u16) << 12) | ((immr as u16) << 6) | (imms as u16))
 }
 
+/// Helper function for the `mov.imm.short` pseudo-instruction. Returns the shortest sequence of
+/// `movz`, `movn`, `movk` and `orr` instructions loading `value` into the 64-bit register `rd`.
+pub fn encode_mov_immediate_64bit(rd: u8, value: u64) -> impl Iterator<Item=u8> {
+    encode_mov_immediate(rd, value, true)
+}
+
+/// Helper function for the `mov.imm.short` pseudo-instruction. Returns the shortest sequence of
+/// `movz`, `movn`, `movk` and `orr` instructions loading `value` into the 32-bit register `rd`.
+pub fn encode_mov_immediate_32bit(rd: u8, value: u32) -> impl Iterator<Item=u8> {
+    encode_mov_immediate(rd, u64::from(value), false)
+}
+
+fn encode_mov_immediate(rd: u8, value: u64, wide: bool) -> impl Iterator<Item=u8> {
+    let rd = u32::from(rd & 0x1F);
+    let sf = if wide { 0x8000_0000 } else { 0 };
+    let count = if wide { 4 } else { 2 };
+
+    let mut instructions = [0u32; 4];
+    let mut len = 0;
+
+    let chunks = (0 .. count).map(|i| (value >> (16 * i)) as u16);
+    let zeros = chunks.clone().filter(|&c| c == 0).count();
+    let ones = chunks.clone().filter(|&c| c == 0xFFFF).count();
+
+    // orr can only be used if it saves instructions, and cannot target the zero register
+    let logical = if rd == 31 || zeros.max(ones) + 1 >= count {
+        None
+    } else if wide {
+        encode_logical_immediate_64bit(value)
+    } else {
+        encode_logical_immediate_32bit(value as u32)
+    };
+
+    if let Some(logical) = logical {
+        instructions[0] = sf | 0x3200_0000 | (u32::from(logical) << 10) | (31 << 5) | rd;
+        len = 1;
+    } else {
+        // start with either movn or movz, depending on which saves the most movk's
+        let (inverted, skip) = if ones > zeros { (true, 0xFFFF) } else { (false, 0) };
+
+        for (i, chunk) in chunks.enumerate() {
+            if chunk == skip {
+                continue;
+            }
+
+            let (opcode, imm) = match (len, inverted) {
+                (0, true) => (0x1280_0000, !chunk),
+                (0, false) => (0x5280_0000, chunk),
+                _ => (0x7280_0000, chunk)
+            };
+            instructions[len] = sf | opcode | ((i as u32) << 21) | (u32::from(imm) << 5) | rd;
+            len += 1;
+        }
+
+        // the value consists entirely of zeros or ones
+        if len == 0 {
+            let opcode = if inverted { 0x1280_0000 } else { 0x5280_0000 };
+            instructions[0] = sf | opcode | rd;
+            len = 1;
+        }
+    }
+
+    instructions.into_iter().take(len).flat_map(u32::to_le_bytes)
+}
+
 /// Helper function for validating that a given value can be encoded as a floating point immediate
 pub fn encode_floating_point_immediate(value: f32) -> Option<u8> {
     // floating point ARM immediates are encoded as
@@ -336,6 +454,32 @@ pub enum RV {
 }
 reg_impls!(RV);
 
+/// Scalable SVE vector registers.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RZ {
+    Z0 = 0x00, Z1 = 0x01, Z2 = 0x02, Z3 = 0x03,
+    Z4 = 0x04, Z5 = 0x05, Z6 = 0x06, Z7 = 0x07,
+    Z8 = 0x08, Z9 = 0x09, Z10= 0x0A, Z11= 0x0B,
+    Z12= 0x0C, Z13= 0x0D, Z14= 0x0E, Z15= 0x0F,
+    Z16= 0x10, Z17= 0x11, Z18= 0x12, Z19= 0x13,
+    Z20= 0x14, Z21= 0x15, Z22= 0x16, Z23= 0x17,
+    Z24= 0x18, Z25= 0x19, Z26= 0x1A, Z27= 0x1B,
+    Z28= 0x1C, Z29= 0x1D, Z30= 0x1E, Z31= 0x1F,
+}
+reg_impls!(RZ);
+
+/// SVE predicate registers. Note that governing predicates of most instructions can only use P0-P7.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RP {
+    P0 = 0x00, P1 = 0x01, P2 = 0x02, P3 = 0x03,
+    P4 = 0x04, P5 = 0x05, P6 = 0x06, P7 = 0x07,
+    P8 = 0x08, P9 = 0x09, P10= 0x0A, P11= 0x0B,
+    P12= 0x0C, P13= 0x0D, P14= 0x0E, P15= 0x0F,
+}
+reg_impls!(RP);
+
 #[cfg(test)]
 mod tests {
     use super::RX::*;
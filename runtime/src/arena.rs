@@ -0,0 +1,453 @@
+//! This module implements a `CodeArena`, which assembles functions into individually freeable regions
+//! of executable memory.
+
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, RwLock};
+use std::io;
+
+use crate::{AssemblyOffset, DynasmError, TargetKind, VecAssembler, ExecutableBuffer};
+use crate::components::{MemoryManager, ManagedRelocs};
+use crate::relocations::Relocation;
+use crate::cache_control;
+
+/// The alignment of every region in a `CodeArena`
+pub const REGION_ALIGNMENT: usize = 16;
+
+/// An arena of executable memory that code can be inserted into and removed from on a per-function basis.
+///
+/// Functions are assembled using a `VecAssembler` obtained from `CodeArena::assembler`, and then moved into
+/// the arena with `CodeArena::insert`. This returns a `CodeHandle` that refers to the region of executable
+/// memory the function was placed in. This region is freed when the last clone of its handle is dropped,
+/// after which the arena reuses it for new functions.
+///
+/// The arena reserves its address space up front, and its memory is dual-mapped. Inserting code therefore
+/// never moves or changes the protection of memory that might be executing. Any thread that executes code from
+/// the arena should hold a clone of its `CodeHandle` while it does so, to ensure the region isn't reused.
+/// This is currently only supported on Linux and Android.
+#[derive(Debug)]
+pub struct CodeArena<R: Relocation> {
+    memory: MemoryManager,
+    // free regions in the committed part of the arena, mapping their offset to their size
+    free: BTreeMap<usize, usize>,
+    // regions whose handles have all been dropped, but that haven't been added to the free list yet
+    released: Arc<Mutex<Vec<(usize, usize)>>>,
+    phantom: PhantomData<R>,
+}
+
+/// A handle to a function that has been inserted into a `CodeArena`. The region of memory containing the function
+/// is kept alive until the last clone of this handle is dropped.
+#[derive(Debug, Clone)]
+pub struct CodeHandle {
+    region: Arc<Region>
+}
+
+#[derive(Debug)]
+struct Region {
+    offset: usize,
+    size: usize,
+    addr: usize,
+    released: Arc<Mutex<Vec<(usize, usize)>>>,
+    // keeps the executable memory mapped while this region exists
+    _execbuffer: Arc<RwLock<ExecutableBuffer>>,
+}
+
+impl<R: Relocation> CodeArena<R> {
+    /// Create a new, empty code arena that reserves `size` bytes of address space, rounded up to a multiple of `page_size`.
+    pub fn new(size: usize) -> io::Result<Self> {
+        let memory_size =
+            size
+                .checked_next_multiple_of(R::page_size())
+                .ok_or(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "Reservation is too large",
+                ))?;
+
+        Ok(Self {
+            memory: MemoryManager::new_reserved_dual_mapped(memory_size)?,
+            free: BTreeMap::new(),
+            released: Arc::new(Mutex::new(Vec::new())),
+            phantom: PhantomData
+        })
+    }
+
+    /// Create a `VecAssembler` to assemble a function with, for insertion into this arena.
+    /// Its base address is set to where the function would end up if no regions are reused, relocations
+    /// that depend on this address are adjusted when the function is placed elsewhere.
+    pub fn assembler(&self) -> VecAssembler<R> {
+        VecAssembler::new_managed(self.memory.execbuffer_addr() + self.memory.committed())
+    }
+
+    /// Returns the amount of bytes that are currently available in freed regions.
+    pub fn free_size(&mut self) -> usize {
+        self.reclaim();
+        self.free.values().sum()
+    }
+
+    /// Returns the amount of bytes that can still be added to the arena without reusing freed regions.
+    pub fn unused_size(&self) -> usize {
+        self.memory.size() - self.memory.committed()
+    }
+
+    /// Insert the code assembled by `ops` into the arena, returning a handle to it. This reuses a freed region if
+    /// a large enough one is available. Offsets obtained from `ops` can be used with the returned handle.
+    /// If no region is available and the arena has run out of address space, this returns `DynasmError::BufferFull`.
+    ///
+    /// Code assembled by a `VecAssembler` that wasn't created by `CodeArena::assembler` can only be placed at its
+    /// base address, as the relocations that would have to be adjusted to move it are unknown. Code containing
+    /// page-relative relocations is only moved by multiples of their page size.
+    pub fn insert(&mut self, mut ops: VecAssembler<R>) -> Result<CodeHandle, DynasmError> {
+        ops.commit()?;
+
+        let baseaddr = ops.baseaddr;
+        let managed = ops.managed.as_ref();
+        let mut code = ops.ops;
+
+        // pad the code to the alignment of the regions
+        let size = code.len().max(1).next_multiple_of(REGION_ALIGNMENT);
+        code.resize(size, 0);
+
+        self.reclaim();
+        let execbuffer_addr = self.memory.execbuffer_addr();
+
+        // page-relative relocations can only be moved by multiples of their page size,
+        // so the code has to be placed at an offset that matches its base address modulo that size.
+        let alignment = managed.iter()
+            .flat_map(|managed| managed.iter())
+            .map(|reloc| reloc.relocation.ref_alignment())
+            .fold(1, usize::max);
+        let phase = baseaddr.wrapping_sub(execbuffer_addr) % alignment;
+
+        // try to reuse a previously freed region
+        if let Some(offset) = self.take_free(size, alignment, phase) {
+            let mut relocated = code.clone();
+            if adjust_managed(&mut relocated, managed, (execbuffer_addr + offset).wrapping_sub(baseaddr) as isize).is_ok() {
+                self.memory.modify(|buffer| {
+                    let region = &mut buffer[offset .. offset + size];
+                    region.copy_from_slice(&relocated);
+                    cache_control::synchronize_icache(region);
+                });
+
+                return Ok(self.handle(offset, size));
+            }
+
+            // the code couldn't be moved there. Give the region back and append it instead.
+            self.add_free(offset, size);
+        }
+
+        // otherwise append it. Any gap that is left to align it is added to the free list.
+        let end = self.memory.committed();
+        let offset = end + phase.wrapping_sub(end) % alignment;
+        adjust_managed(&mut code, managed, (execbuffer_addr + offset).wrapping_sub(baseaddr) as isize)?;
+        code.splice(0 .. 0, std::iter::repeat(0).take(offset - end));
+
+        // the memory is fixed, so this never has to move any code.
+        self.memory.try_commit(&mut code, |_, _, _| ())?;
+        if offset != end {
+            self.add_free(end, offset - end);
+        }
+
+        Ok(self.handle(offset, size))
+    }
+
+    // create a handle to a region of memory
+    fn handle(&self, offset: usize, size: usize) -> CodeHandle {
+        CodeHandle {
+            region: Arc::new(Region {
+                offset,
+                size,
+                addr: self.memory.execbuffer_addr() + offset,
+                released: self.released.clone(),
+                _execbuffer: self.memory.reader()
+            })
+        }
+    }
+
+    // moves any released regions to the free list
+    fn reclaim(&mut self) {
+        let released = std::mem::take(&mut *self.released.lock().unwrap());
+        for (offset, size) in released {
+            self.add_free(offset, size);
+        }
+    }
+
+    // adds a region to the free list, merging it with adjacent free regions
+    fn add_free(&mut self, mut offset: usize, mut size: usize) {
+        if let Some((&prev_offset, &prev_size)) = self.free.range(.. offset).next_back() {
+            if prev_offset + prev_size == offset {
+                self.free.remove(&prev_offset);
+                offset = prev_offset;
+                size += prev_size;
+            }
+        }
+
+        if let Some(next_size) = self.free.remove(&(offset + size)) {
+            size += next_size;
+        }
+
+        self.free.insert(offset, size);
+    }
+
+    // takes a region of `size` bytes from the free list that starts at an offset equal to `phase` modulo `alignment`,
+    // returning its offset
+    fn take_free(&mut self, size: usize, alignment: usize, phase: usize) -> Option<usize> {
+        let (free_offset, free_size, offset) = self.free.iter().find_map(|(&free_offset, &free_size)| {
+            let offset = free_offset + phase.wrapping_sub(free_offset) % alignment;
+            (offset + size <= free_offset + free_size).then_some((free_offset, free_size, offset))
+        })?;
+
+        self.free.remove(&free_offset);
+        if offset > free_offset {
+            self.free.insert(free_offset, offset - free_offset);
+        }
+        if free_offset + free_size > offset + size {
+            self.free.insert(offset + size, free_offset + free_size - offset - size);
+        }
+
+        Some(offset)
+    }
+}
+
+// adjusts the managed relocations in `code` for it being moved by `change` bytes. If these are unknown, the code cannot be moved.
+fn adjust_managed<R: Relocation>(code: &mut [u8], managed: Option<&ManagedRelocs<R>>, change: isize) -> Result<(), DynasmError> {
+    if change == 0 {
+        return Ok(());
+    }
+
+    let managed = managed.ok_or(DynasmError::ImpossibleRelocation(TargetKind::Managed))?;
+
+    for reloc in managed.iter() {
+        let buf = &mut code[reloc.range(0)];
+        if reloc.adjust(buf, change).is_err() {
+            return Err(DynasmError::ImpossibleRelocation(TargetKind::Managed));
+        }
+    }
+
+    Ok(())
+}
+
+impl CodeHandle {
+    /// Obtain a pointer into the function from an offset into the `VecAssembler` it was assembled with.
+    /// This pointer stays valid as long as this handle, or any of its clones, exists.
+    ///
+    /// On architectures that require it, `cache_control::prepare_for_execution` should be called
+    /// before code is executed on a thread that didn't insert it.
+    pub fn ptr(&self, offset: AssemblyOffset) -> *const u8 {
+        assert!(offset.0 < self.region.size, "Offset is outside of the function");
+        (self.region.addr + offset.0) as *const u8
+    }
+
+    /// Returns the size of the region of memory that the function occupies.
+    pub fn size(&self) -> usize {
+        self.region.size
+    }
+}
+
+impl Drop for Region {
+    fn drop(&mut self) {
+        if let Ok(mut released) = self.released.lock() {
+            released.push((self.offset, self.size));
+        }
+    }
+}
+
+
+#[cfg(test)]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod tests {
+    use crate::*;
+    use crate::relocations::{RelocationSize, RelocationKind};
+
+    #[test]
+    fn test_arena_reuse() {
+        let mut arena = CodeArena::<x64::X64Relocation>::new(0x10000).unwrap();
+
+        let mut ops = arena.assembler();
+        ops.extend(&[0x90; 40]);
+        let first = arena.insert(ops).unwrap();
+        assert_eq!(first.size(), 48);
+
+        let mut ops = arena.assembler();
+        ops.extend(&[0xCC; 16]);
+        let second = arena.insert(ops).unwrap();
+        assert_eq!(second.ptr(AssemblyOffset(0)) as usize, first.ptr(AssemblyOffset(0)) as usize + 48);
+
+        // the region is only freed once all handles are gone
+        let clone = first.clone();
+        drop(first);
+        assert_eq!(arena.free_size(), 0);
+        drop(clone);
+        assert_eq!(arena.free_size(), 48);
+
+        // smaller functions reuse the freed region
+        let mut ops = arena.assembler();
+        ops.extend(&[0x90; 20]);
+        let third = arena.insert(ops).unwrap();
+        assert_eq!(third.ptr(AssemblyOffset(0)) as usize, second.ptr(AssemblyOffset(0)) as usize - 48);
+        assert_eq!(arena.free_size(), 16);
+
+        // and freed regions get merged
+        drop(third);
+        drop(second);
+        assert_eq!(arena.free_size(), 64);
+        assert_eq!(arena.unused_size(), 0x10000 - 64);
+    }
+
+    #[test]
+    fn test_arena_exhausted() {
+        let mut arena = CodeArena::<x64::X64Relocation>::new(0x1000).unwrap();
+
+        let mut ops = arena.assembler();
+        ops.extend(&[0x90; 0x800]);
+        let first = arena.insert(ops).unwrap();
+        let addr = first.ptr(AssemblyOffset(0)) as usize;
+
+        let mut ops = arena.assembler();
+        ops.extend(&[0x90; 0x900]);
+        assert_eq!(arena.insert(ops).unwrap_err(), DynasmError::BufferFull);
+        assert_eq!(arena.unused_size(), 0x800);
+
+        // freeing a region only makes room for functions that fit in it
+        drop(first);
+        let mut ops = arena.assembler();
+        ops.extend(&[0x90; 0x900]);
+        assert_eq!(arena.insert(ops).unwrap_err(), DynasmError::BufferFull);
+
+        let mut ops = arena.assembler();
+        ops.extend(&[0x90; 0x700]);
+        let second = arena.insert(ops).unwrap();
+        assert_eq!(second.ptr(AssemblyOffset(0)) as usize, addr);
+    }
+
+    #[test]
+    fn test_arena_relocations() {
+        let mut arena = CodeArena::<aarch64::Aarch64Relocation>::new(0x10000).unwrap();
+
+        let mut ops = arena.assembler();
+        ops.extend(&[0; 16]);
+        let filler = arena.insert(ops).unwrap();
+        let start = filler.ptr(AssemblyOffset(0)) as usize;
+
+        // an absolute reference to a label, which has to be adjusted when the function doesn't end up at the base address
+        let mut ops = arena.assembler();
+        let label = ops.new_dynamic_label();
+        ops.extend(&[0; 8]);
+        ops.dynamic_label(label);
+        ops.extend(&[0; 8]);
+        ops.dynamic_relocation(label, 0, 8, 8, aarch64::Aarch64Relocation::Absolute(RelocationSize::QWord));
+        assert_eq!(ops.baseaddr, start + 16);
+        drop(filler);
+
+        let func = arena.insert(ops).unwrap();
+        let ptr = func.ptr(AssemblyOffset(0));
+        assert_eq!(ptr as usize, start);
+
+        let value = unsafe { std::ptr::read_unaligned(ptr.add(8) as *const usize) };
+        assert_eq!(value, start + 8);
+
+        // code from an assembler that doesn't track these relocations cannot be moved
+        drop(func);
+        let mut ops = VecAssembler::<aarch64::Aarch64Relocation>::new(start + 0x100);
+        ops.extend(&[0; 16]);
+        assert_eq!(arena.insert(ops).unwrap_err(), DynasmError::ImpossibleRelocation(TargetKind::Managed));
+    }
+
+    // returns the address calculated by the adrp and add instructions at `ptr`
+    fn adrp_add_target(ptr: *const u8) -> usize {
+        let adrp = unsafe { std::ptr::read_unaligned(ptr as *const u32) };
+        let add = unsafe { std::ptr::read_unaligned(ptr.add(4) as *const u32) };
+
+        let pages = ((((adrp >> 5) & 0x7FFFF) << 2 | (adrp >> 29) & 3) << 11) as i32 >> 11;
+        let page = (ptr as usize & !0xFFF).wrapping_add((pages as isize * 0x1000) as usize);
+        page + ((add >> 10) & 0xFFF) as usize
+    }
+
+    #[test]
+    fn test_arena_page_relative() {
+        let mut arena = CodeArena::<aarch64::Aarch64Relocation>::new(0x10000).unwrap();
+
+        let mut ops = arena.assembler();
+        ops.extend(&[0; 16]);
+        let filler = arena.insert(ops).unwrap();
+        let start = filler.ptr(AssemblyOffset(0)) as usize;
+
+        // adrl x0, label ; ret ; label:
+        let mut ops = arena.assembler();
+        let label = ops.new_dynamic_label();
+        ops.push_u32(0x9000_0000);
+        ops.push_u32(0x9100_0000);
+        ops.dynamic_relocation(label, 0, 8, 8, aarch64::Aarch64Relocation::ADRPPAIR(0, RelocationKind::Relative));
+        ops.push_u32(0xD65F_03C0);
+        ops.dynamic_label(label);
+        assert_eq!(ops.baseaddr, start + 16);
+        drop(filler);
+
+        // moving it back by 16 bytes could change the page of the adrp, so the freed region isn't reused
+        let func = arena.insert(ops).unwrap();
+        let ptr = func.ptr(AssemblyOffset(0));
+        assert_eq!(ptr as usize, start + 16);
+        assert_eq!(adrp_add_target(ptr), start + 28);
+        assert_eq!(arena.free_size(), 16);
+
+        // but it can be moved by whole pages
+        let mut ops = arena.assembler();
+        let label = ops.new_dynamic_label();
+        ops.push_u32(0x9000_0000);
+        ops.push_u32(0x9100_0000);
+        ops.dynamic_relocation(label, 0, 8, 8, aarch64::Aarch64Relocation::ADRPPAIR(0, RelocationKind::Relative));
+        ops.push_u32(0xD65F_03C0);
+        ops.dynamic_label(label);
+
+        let mut filler = arena.assembler();
+        filler.extend(&[0; 0x1000]);
+        let _filler = arena.insert(filler).unwrap();
+
+        let func = arena.insert(ops).unwrap();
+        let ptr = func.ptr(AssemblyOffset(0));
+        assert_eq!(ptr as usize, start + 0x1020);
+        assert_eq!(adrp_add_target(ptr), start + 0x102C);
+
+        // when appending it, the gap that is needed to keep its position in the page is freed
+        let mut ops = arena.assembler();
+        let label = ops.new_dynamic_label();
+        ops.push_u32(0x9000_0000);
+        ops.push_u32(0x9100_0000);
+        ops.dynamic_relocation(label, 0, 8, 8, aarch64::Aarch64Relocation::ADRPPAIR(0, RelocationKind::Relative));
+        ops.push_u32(0xD65F_03C0);
+        ops.dynamic_label(label);
+
+        let mut filler = arena.assembler();
+        filler.extend(&[0; 0x20]);
+        let _filler = arena.insert(filler).unwrap();
+
+        let func = arena.insert(ops).unwrap();
+        let ptr = func.ptr(AssemblyOffset(0));
+        assert_eq!(ptr as usize, start + 0x2030);
+        assert_eq!(adrp_add_target(ptr), start + 0x203C);
+        assert_eq!(arena.free_size(), 16 + 0xFE0);
+    }
+
+    #[test]
+    #[cfg(target_arch="x86_64")]
+    fn test_arena_execute() {
+        let mut arena = CodeArena::<x64::X64Relocation>::new(0x10000).unwrap();
+
+        // mov eax, 0x1234 ; ret
+        let mut ops = arena.assembler();
+        ops.extend(&[0xB8, 0x34, 0x12, 0x00, 0x00, 0xC3]);
+        let first = arena.insert(ops).unwrap();
+
+        let f: extern "C" fn() -> u32 = unsafe { std::mem::transmute(first.ptr(AssemblyOffset(0))) };
+        assert_eq!(f(), 0x1234);
+        drop(first);
+
+        // mov eax, 0x5678 ; ret
+        let mut ops = arena.assembler();
+        ops.extend(&[0xB8, 0x78, 0x56, 0x00, 0x00, 0xC3]);
+        let second = arena.insert(ops).unwrap();
+
+        let g: extern "C" fn() -> u32 = unsafe { std::mem::transmute(second.ptr(AssemblyOffset(0))) };
+        assert_eq!(g as usize, f as usize);
+        assert_eq!(g(), 0x5678);
+    }
+}
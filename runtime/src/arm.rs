@@ -0,0 +1,485 @@
+//! Runtime support for the 32-bit ARM architecture assembling targets.
+//!
+//! This module supports both the ARM (A32) and Thumb-2 (T32) instruction sets. A32 code consists of
+//! fixed-width 32-bit instructions, while Thumb-2 code mixes 16-bit and 32-bit instructions. 32-bit
+//! Thumb instructions are stored as two little-endian halfwords, with the first halfword containing
+//! the opcode. Relocations range from 8-bit offsets (Thumb `cbz`) to 25-bit offsets (`b` and `bl`).
+//!
+//! The core relocation behaviour for these architectures is provided by the [`ArmRelocation`] type.
+//!
+//! Next to that, this module contains the following:
+//!
+//! ## Type aliases
+//!
+//! Several specialized type aliases of the generic [`Assembler`] are provided as these are by far the most common usecase.
+//!
+//! ## Enums
+//!
+//! There is an enumeration of the general purpose registers of the ARM architecture. As there are no separate families,
+//! `sp`, `lr` and `pc` are simply `R13`, `R14` and `R15`.
+//! This enum implements the [`Register`] trait and its discriminant values match their numeric encoding in dynamic register literals.
+//!
+//! ## Functions
+//!
+//! The ARM and Thumb-2 instruction sets allow encoding a special "modified immediate" type for data processing instructions.
+//! The encoding implementations for these immediate types have been exposed to assist the user in correctly using these instructions.
+//! They will return `Some(encoding)` only if the given value can be encoded losslessly in that immediate type.
+//!
+//! Next to that, this module contains handlers for error conditions in the case where a dynamically encoded immediate is out of range.
+//! These panic with a friendly error message if any of these conditions happen at runtime.
+
+use crate::Register;
+use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation, fits_signed_bitfield};
+use byteorder::{ByteOrder, LittleEndian};
+use std::convert::TryFrom;
+
+/// Relocation implementation for the ARM and Thumb-2 architectures.
+///
+/// All offsets handed to this relocation are relative to the start of the instruction. The bias
+/// of the PC as read by the processor (8 bytes in ARM state, 4 bytes in Thumb state) is handled here.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub enum ArmRelocation {
+    // A32 b, bl: 26 bits, word aligned
+    B24,
+    // A32 ldr, ldrb, str, strb (literal): sign and 12-bit magnitude
+    LDR12,
+    // A32 ldrh, ldrsb, ldrsh, ldrd (literal): sign and 8-bit magnitude
+    LDR8,
+    // A32 adr: add or sub from pc with a modified immediate
+    ADR12,
+    // T16 b: 12 bits, halfword aligned
+    TB11,
+    // T16 b<cond>: 9 bits, halfword aligned
+    TB8,
+    // T16 cbz, cbnz: 7 bits unsigned, halfword aligned
+    TCB6,
+    // T32 b, bl: 25 bits, halfword aligned
+    TB24,
+    // T32 b<cond>: 21 bits, halfword aligned
+    TB20,
+    // T16 ldr (literal), adr: 10 bits unsigned, word aligned, relative to the aligned pc
+    TLDR8,
+    // T32 ldr (literal) and friends: sign and 12-bit magnitude, relative to the aligned pc
+    TLDR12,
+    // T32 adr: addw or subw from the aligned pc
+    TADR12,
+    // Anything in directives
+    Plain(RelocationSize),
+}
+
+impl ArmRelocation {
+    /// Bits of the instruction that are not part of the relocation
+    fn op_mask(&self) -> u32 {
+        match self {
+            Self::B24 => 0xFF00_0000,
+            Self::LDR12 => 0xFF7F_F000,
+            Self::LDR8 => 0xFF7F_F0F0,
+            Self::ADR12 => 0xFE1F_F000,
+            Self::TB11 => 0xF800,
+            Self::TB8 => 0xFF00,
+            Self::TCB6 => 0xFD07,
+            Self::TB24 => 0xF800_D000,
+            Self::TB20 => 0xFBC0_D000,
+            Self::TLDR8 => 0xFF00,
+            Self::TLDR12 => 0xFF7F_F000,
+            Self::TADR12 => 0xFB5F_8F00,
+            Self::Plain(_) => 0
+        }
+    }
+
+    /// The offset between the start of the instruction and the value of PC as read by it.
+    fn pc_bias(&self) -> i64 {
+        match self {
+            Self::B24
+            | Self::LDR12
+            | Self::LDR8
+            | Self::ADR12 => 8,
+            Self::Plain(_) => 0,
+            _ => 4
+        }
+    }
+
+    /// Returns if this relocation patches a 16-bit Thumb instruction
+    fn is_narrow(&self) -> bool {
+        matches!(self, Self::TB11 | Self::TB8 | Self::TCB6 | Self::TLDR8)
+    }
+
+    /// Returns if this relocation patches a 32-bit Thumb instruction
+    fn is_wide_thumb(&self) -> bool {
+        matches!(self, Self::TB24 | Self::TB20 | Self::TLDR12 | Self::TADR12)
+    }
+
+    fn encode(&self, value: i64) -> Result<u32, ImpossibleRelocation> {
+        let value = value - self.pc_bias();
+        let magnitude = value.unsigned_abs();
+        let positive = value >= 0;
+
+        Ok(match self {
+            Self::B24 => {
+                if value & 3 != 0 || !fits_signed_bitfield(value >> 2, 24) {
+                    return Err(ImpossibleRelocation { } );
+                }
+                (value >> 2) as u32 & 0xFF_FFFF
+            },
+            Self::LDR12 | Self::TLDR12 => {
+                if magnitude > 0xFFF {
+                    return Err(ImpossibleRelocation { } );
+                }
+                (u32::from(positive) << 23) | magnitude as u32
+            },
+            Self::LDR8 => {
+                if magnitude > 0xFF {
+                    return Err(ImpossibleRelocation { } );
+                }
+                let magnitude = magnitude as u32;
+                (u32::from(positive) << 23) | ((magnitude & 0xF0) << 4) | (magnitude & 0xF)
+            },
+            Self::ADR12 => {
+                let imm = u32::try_from(magnitude).ok()
+                    .and_then(encode_arm_immediate)
+                    .ok_or(ImpossibleRelocation { } )?;
+                // add (0b0100) or sub (0b0010) as data processing opcode
+                let opcode = if positive { 0x0080_0000 } else { 0x0040_0000 };
+                opcode | u32::from(imm)
+            },
+            Self::TB11 => {
+                if value & 1 != 0 || !fits_signed_bitfield(value >> 1, 11) {
+                    return Err(ImpossibleRelocation { } );
+                }
+                (value >> 1) as u32 & 0x7FF
+            },
+            Self::TB8 => {
+                if value & 1 != 0 || !fits_signed_bitfield(value >> 1, 8) {
+                    return Err(ImpossibleRelocation { } );
+                }
+                (value >> 1) as u32 & 0xFF
+            },
+            Self::TCB6 => {
+                if value & 1 != 0 || !(0 ..= 126).contains(&value) {
+                    return Err(ImpossibleRelocation { } );
+                }
+                let value = value as u32;
+                ((value & 0x40) << 3) | ((value & 0x3E) << 2)
+            },
+            Self::TB24 => {
+                if value & 1 != 0 || !fits_signed_bitfield(value >> 1, 24) {
+                    return Err(ImpossibleRelocation { } );
+                }
+                let value = value as u32;
+                let s = (value >> 24) & 1;
+                let j1 = !((value >> 23) ^ s) & 1;
+                let j2 = !((value >> 22) ^ s) & 1;
+                (s << 26) | (((value >> 12) & 0x3FF) << 16) | (j1 << 13) | (j2 << 11) | ((value >> 1) & 0x7FF)
+            },
+            Self::TB20 => {
+                if value & 1 != 0 || !fits_signed_bitfield(value >> 1, 20) {
+                    return Err(ImpossibleRelocation { } );
+                }
+                let value = value as u32;
+                let s = (value >> 20) & 1;
+                let j2 = (value >> 19) & 1;
+                let j1 = (value >> 18) & 1;
+                (s << 26) | (((value >> 12) & 0x3F) << 16) | (j1 << 13) | (j2 << 11) | ((value >> 1) & 0x7FF)
+            },
+            Self::TLDR8 => {
+                if value & 3 != 0 || !(0 ..= 1020).contains(&value) {
+                    return Err(ImpossibleRelocation { } );
+                }
+                (value >> 2) as u32
+            },
+            Self::TADR12 => {
+                if magnitude > 0xFFF {
+                    return Err(ImpossibleRelocation { } );
+                }
+                let magnitude = magnitude as u32;
+                // addw (0b00000) or subw (0b01010) as opcode
+                let opcode = if positive { 0 } else { 0x00A0_0000 };
+                opcode | ((magnitude & 0x800) << 15) | ((magnitude & 0x700) << 4) | (magnitude & 0xFF)
+            },
+            Self::Plain(_) => return Err(ImpossibleRelocation { } )
+        })
+    }
+
+    fn decode(&self, instr: u32) -> i64 {
+        let sign = |positive: u32, magnitude: u32| if positive != 0 {
+            i64::from(magnitude)
+        } else {
+            -i64::from(magnitude)
+        };
+
+        let value = match self {
+            Self::B24 => i64::from(((instr << 8) as i32) >> 6),
+            Self::LDR12 | Self::TLDR12 => sign(instr & 0x0080_0000, instr & 0xFFF),
+            Self::LDR8 => sign(instr & 0x0080_0000, ((instr >> 4) & 0xF0) | (instr & 0xF)),
+            Self::ADR12 => {
+                let rotation = (instr >> 7) & 0x1E;
+                let magnitude = (instr & 0xFF).rotate_right(rotation);
+                sign(instr & 0x0080_0000, magnitude)
+            },
+            Self::TB11 => i64::from(((instr << 21) as i32) >> 20),
+            Self::TB8 => i64::from(((instr << 24) as i32) >> 23),
+            Self::TCB6 => i64::from(((instr >> 3) & 0x40) | ((instr >> 2) & 0x3E)),
+            Self::TB24 => {
+                let s = (instr >> 26) & 1;
+                let i1 = !((instr >> 13) ^ s) & 1;
+                let i2 = !((instr >> 11) ^ s) & 1;
+                let unpacked = (s << 24) | (i1 << 23) | (i2 << 22) | (((instr >> 16) & 0x3FF) << 12) | ((instr & 0x7FF) << 1);
+                i64::from(((unpacked << 7) as i32) >> 7)
+            },
+            Self::TB20 => {
+                let unpacked = (((instr >> 26) & 1) << 20)
+                    | (((instr >> 11) & 1) << 19)
+                    | (((instr >> 13) & 1) << 18)
+                    | (((instr >> 16) & 0x3F) << 12)
+                    | ((instr & 0x7FF) << 1);
+                i64::from(((unpacked << 11) as i32) >> 11)
+            },
+            Self::TLDR8 => i64::from((instr & 0xFF) << 2),
+            Self::TADR12 => {
+                let magnitude = ((instr >> 15) & 0x800) | ((instr >> 4) & 0x700) | (instr & 0xFF);
+                sign(u32::from(instr & 0x00A0_0000 == 0), magnitude)
+            },
+            Self::Plain(_) => unreachable!()
+        };
+
+        value + self.pc_bias()
+    }
+
+    fn read_instruction(&self, buf: &[u8]) -> u32 {
+        if self.is_narrow() {
+            u32::from(LittleEndian::read_u16(buf))
+        } else if self.is_wide_thumb() {
+            (u32::from(LittleEndian::read_u16(&buf[..2])) << 16) | u32::from(LittleEndian::read_u16(&buf[2..]))
+        } else {
+            LittleEndian::read_u32(buf)
+        }
+    }
+
+    fn write_instruction(&self, buf: &mut [u8], instr: u32) {
+        if self.is_narrow() {
+            LittleEndian::write_u16(buf, instr as u16);
+        } else if self.is_wide_thumb() {
+            LittleEndian::write_u16(&mut buf[..2], (instr >> 16) as u16);
+            LittleEndian::write_u16(&mut buf[2..], instr as u16);
+        } else {
+            LittleEndian::write_u32(buf, instr);
+        }
+    }
+}
+
+impl Relocation for ArmRelocation {
+    type Encoding = (u8,);
+    fn from_encoding(encoding: Self::Encoding) -> Self {
+        match encoding.0 {
+            0 => Self::B24,
+            1 => Self::LDR12,
+            2 => Self::LDR8,
+            3 => Self::ADR12,
+            4 => Self::TB11,
+            5 => Self::TB8,
+            6 => Self::TCB6,
+            7 => Self::TB24,
+            8 => Self::TB20,
+            9 => Self::TLDR8,
+            10 => Self::TLDR12,
+            11 => Self::TADR12,
+            x  => Self::Plain(RelocationSize::from_encoding(x - 16))
+        }
+    }
+    fn from_size(size: RelocationSize) -> Self {
+        Self::Plain(size)
+    }
+    fn ref_alignment(&self) -> usize {
+        // thumb literal loads and adr are relative to the word-aligned pc
+        match self {
+            Self::TLDR8
+            | Self::TLDR12
+            | Self::TADR12 => 4,
+            _ => 1
+        }
+    }
+    fn size(&self) -> usize {
+        match self {
+            Self::Plain(s) => s.size(),
+            Self::TB11
+            | Self::TB8
+            | Self::TCB6
+            | Self::TLDR8 => RelocationSize::Word.size(),
+            _ => RelocationSize::DWord.size(),
+        }
+    }
+    fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        if let Self::Plain(s) = self {
+            return s.write_value(buf, value);
+        }
+
+        let value = i64::try_from(value).map_err(|_| ImpossibleRelocation { } )?;
+        let packed = self.encode(value)?;
+
+        let template = self.read_instruction(buf) & self.op_mask();
+        self.write_instruction(buf, template | packed);
+        Ok(())
+    }
+    fn read_value(&self, buf: &[u8]) -> isize {
+        if let Self::Plain(s) = self {
+            return s.read_value(buf);
+        }
+
+        self.decode(self.read_instruction(buf)) as isize
+    }
+    fn kind(&self) -> RelocationKind {
+        RelocationKind::Relative
+    }
+    fn page_size() -> usize {
+        4096
+    }
+}
+
+/// An ARM / Thumb-2 Assembler. This is aliased here for backwards compatability.
+pub type Assembler = crate::Assembler<ArmRelocation>;
+/// An ARM / Thumb-2 AssemblyModifier. This is aliased here for backwards compatability.
+pub type AssemblyModifier<'a> = crate::Modifier<'a, ArmRelocation>;
+/// An ARM / Thumb-2 UncommittedModifier. This is aliased here for backwards compatability.
+pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
+
+
+// these should explicitly never be inlined, as this is the slow path.
+// that's also why these aren't made generic.
+
+/// Handler for `u32` out-of-range ARM immediates.
+#[inline(never)]
+pub fn immediate_out_of_range_unsigned_32(immediate: u32) -> ! {
+    panic!("Cannot assemble this ARM instruction. Immediate {immediate} is out of range.")
+}
+
+/// Handler for `i32` out-of-range ARM immediates.
+#[inline(never)]
+pub fn immediate_out_of_range_signed_32(immediate: i32) -> ! {
+    panic!("Cannot assemble this ARM instruction. Immediate {immediate} is out of range.")
+}
+
+/// Handler for ARM registers that cannot be encoded in the chosen instruction encoding.
+#[inline(never)]
+pub fn invalid_register(register: u8) -> ! {
+    panic!("Cannot assemble this ARM instruction. Register r{register} cannot be encoded.")
+}
+
+
+/// Helper function for validating that a given value can be encoded as an A32 modified immediate.
+/// On success, returns the 12-bit `rotate:imm8` field, where the value equals `imm8` rotated right
+/// by twice `rotate`.
+pub fn encode_arm_immediate(value: u32) -> Option<u16> {
+    (0 .. 16).find_map(|rotate| {
+        let imm8 = value.rotate_left(rotate * 2);
+        if imm8 <= 0xFF {
+            Some(((rotate << 8) | imm8) as u16)
+        } else {
+            None
+        }
+    })
+}
+
+/// Helper function for validating that a given value can be encoded as a T32 modified immediate.
+/// On success, returns the 12-bit `i:imm3:imm8` field.
+pub fn encode_thumb_immediate(value: u32) -> Option<u16> {
+    let byte = value & 0xFF;
+    let high_byte = (value >> 8) & 0xFF;
+
+    // the replicated byte patterns
+    if value <= 0xFF {
+        return Some(value as u16);
+    } else if value == byte * 0x0001_0001 {
+        return Some(0x100 | byte as u16);
+    } else if value == high_byte * 0x0100_0100 {
+        return Some(0x200 | high_byte as u16);
+    } else if value == byte * 0x0101_0101 {
+        return Some(0x300 | byte as u16);
+    }
+
+    // an 8-bit value with its top bit set, rotated right by 8 to 31 bits
+    (8 .. 32).find_map(|rotation| {
+        let imm8 = value.rotate_left(rotation);
+        if (0x80 ..= 0xFF).contains(&imm8) {
+            Some(((rotation << 7) | (imm8 & 0x7F)) as u16)
+        } else {
+            None
+        }
+    })
+}
+
+
+/// 4-byte general purpose registers. R13 is the stack pointer, R14 the link register and R15 the program counter.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RR {
+    R0 = 0x00, R1 = 0x01, R2 = 0x02, R3 = 0x03,
+    R4 = 0x04, R5 = 0x05, R6 = 0x06, R7 = 0x07,
+    R8 = 0x08, R9 = 0x09, R10= 0x0A, R11= 0x0B,
+    R12= 0x0C, R13= 0x0D, R14= 0x0E, R15= 0x0F,
+}
+reg_impls!(RR);
+
+
+#[cfg(test)]
+mod tests {
+    use super::RR::*;
+    use super::{encode_arm_immediate, encode_thumb_immediate, ArmRelocation};
+    use crate::Register;
+    use crate::relocations::Relocation;
+
+    #[test]
+    fn reg_code() {
+        assert_eq!(R13.code(), 13);
+    }
+
+    #[test]
+    fn reg_code_from() {
+        assert_eq!(u8::from(R11), 0x0B);
+    }
+
+    #[test]
+    fn arm_immediates() {
+        assert_eq!(encode_arm_immediate(0xFF), Some(0x0FF));
+        assert_eq!(encode_arm_immediate(0xFF00_0000), Some(0x4FF));
+        assert_eq!(encode_arm_immediate(0xF000_000F), Some(0x2FF));
+        assert_eq!(encode_arm_immediate(0x101), None);
+    }
+
+    #[test]
+    fn thumb_immediates() {
+        assert_eq!(encode_thumb_immediate(0xAB), Some(0x0AB));
+        assert_eq!(encode_thumb_immediate(0x00AB_00AB), Some(0x1AB));
+        assert_eq!(encode_thumb_immediate(0xAB00_AB00), Some(0x2AB));
+        assert_eq!(encode_thumb_immediate(0xABAB_ABAB), Some(0x3AB));
+        assert_eq!(encode_thumb_immediate(0x8000_0000), Some(0x400));
+        assert_eq!(encode_thumb_immediate(0x0000_0100), Some(0xF80));
+        assert_eq!(encode_thumb_immediate(0x0000_0101), None);
+    }
+
+    #[test]
+    fn relocation_roundtrip() {
+        let cases: &[(ArmRelocation, &[u8], &[isize])] = &[
+            (ArmRelocation::B24, &[0, 0, 0, 0xEA], &[-0x0200_0000 + 8, -4, 0, 8, 0x0200_0004]),
+            (ArmRelocation::LDR12, &[0, 0, 0x9F, 0xE5], &[-4087, 0, 8, 4103]),
+            (ArmRelocation::LDR8, &[0xB0, 0, 0xDF, 0xE1], &[-247, 8, 263]),
+            (ArmRelocation::ADR12, &[0, 0, 0x8F, 0xE2], &[-0x3F8, 8, 0x408]),
+            (ArmRelocation::TB11, &[0, 0xE0], &[-2044, 0, 2050]),
+            (ArmRelocation::TB8, &[0, 0xD0], &[-252, 0, 258]),
+            (ArmRelocation::TCB6, &[0, 0xB1], &[4, 66, 130]),
+            (ArmRelocation::TB24, &[0, 0xF0, 0, 0xD0], &[-0x0100_0000 + 4, -4, 0, 0x0100_0002]),
+            (ArmRelocation::TB20, &[0, 0xF0, 0, 0x80], &[-0x0010_0000 + 4, -4, 0, 0x0010_0002]),
+            (ArmRelocation::TLDR8, &[0, 0x48], &[4, 1024]),
+            (ArmRelocation::TLDR12, &[0x5F, 0xF8, 0, 0], &[-4091, 4, 4099]),
+            (ArmRelocation::TADR12, &[0x0F, 0xF2, 0, 0], &[-4091, 4, 4099]),
+        ];
+
+        for (relocation, template, values) in cases {
+            for &value in values.iter() {
+                let mut buf = template.to_vec();
+                relocation.write_value(&mut buf, value).unwrap();
+                assert_eq!(relocation.read_value(&buf), value, "{:?} {}", relocation, value);
+            }
+        }
+    }
+}
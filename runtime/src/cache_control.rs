@@ -17,6 +17,10 @@ pub fn prepare_for_execution(slice: &[u8]) {
     {
         riscv::enforce_ordering_dcache_icache(slice, true);
     }
+    #[cfg(target_arch="loongarch64")]
+    {
+        loongarch64::prepare_for_execution()
+    }
 }
 
 /// This function should be called after modification of any data that could've been loaded into the
@@ -41,6 +45,10 @@ pub fn synchronize_icache(slice: &[u8]) {
             sys_icache_invalidate(slice.as_ptr() as *const std::ffi::c_void, slice.len());
         }
     }
+    #[cfg(all(unix, target_arch="arm"))]
+    {
+        arm::synchronize_icache(slice);
+    }
 }
 
 #[cfg(target_arch="aarch64")]
@@ -205,3 +213,42 @@ mod riscv {
         assert!(rv == 0, "riscv_flush_icache failed, returned {rv}");
     }
 }
+
+#[cfg(target_arch="loongarch64")]
+mod loongarch64 {
+    use std::arch::asm;
+
+    /// LoongArch keeps the instruction and data caches of a core coherent, but instruction fetch
+    /// can still see stale data until an instruction fetch barrier is executed on the executing core.
+    #[inline(always)]
+    pub fn prepare_for_execution() {
+        // safety: this is just a barrier.
+        unsafe {
+            asm!(
+                "ibar 0",
+                options(nostack, preserves_flags)
+            );
+        }
+    }
+}
+
+#[cfg(all(unix, target_arch="arm"))]
+mod arm {
+    // On 32-bit ARM, cache maintenance instructions are not available to user mode code.
+    // Instead, the OS offers a syscall that cleans the dcache and invalidates the icache for
+    // a range of addresses, which is wrapped by the `__clear_cache` compiler builtin.
+    use std::ffi::c_char;
+
+    extern "C" {
+        fn __clear_cache(start: *mut c_char, end: *mut c_char);
+    }
+
+    pub fn synchronize_icache(slice: &[u8]) {
+        let range = slice.as_ptr_range();
+
+        // safety: this only affects the cache state of the given range
+        unsafe {
+            __clear_cache(range.start as *mut c_char, range.end as *mut c_char);
+        }
+    }
+}
@@ -77,11 +77,17 @@ impl StaticLabel {
     }
 }
 
-/// This struct implements a protection-swapping assembling buffer
+/// This struct implements a protection-swapping assembling buffer. Alternatively, it can manage
+/// a dual-mapped buffer, where new code is written through a separate writable mapping of the
+/// same memory so the protection of the executable memory never has to change.
 #[derive(Debug)]
 pub struct MemoryManager {
     // buffer where the end result is copied into
     execbuffer: Arc<RwLock<ExecutableBuffer>>,
+    // writable mapping of the memory of execbuffer, if it is dual-mapped
+    writebuffer: Option<MutableBuffer>,
+    // if set, the buffer is not allowed to move to a different address to grow
+    fixed: bool,
 
     // size of the allocated mmap (so we don't have to go through RwLock to get it)
     execbuffer_size: usize,
@@ -100,12 +106,96 @@ impl MemoryManager {
 
         Ok(MemoryManager {
             execbuffer: Arc::new(RwLock::new(execbuffer)),
+            writebuffer: None,
+            fixed: false,
             execbuffer_size: initial_mmap_size,
             asmoffset: 0,
             execbuffer_addr
         })
     }
 
+    /// Create a new memory manager that reserves `reserved_size` bytes of address space up front.
+    /// The managed memory never moves, so the address of committed code never changes.
+    /// Committing more than `reserved_size` bytes makes `commit` panic, and `try_commit` return an error.
+    pub fn new_reserved(reserved_size: usize) -> io::Result<Self> {
+        let execbuffer = ExecutableBuffer::new_reserved(reserved_size)?;
+        let execbuffer_addr = execbuffer.as_ptr() as usize;
+
+        Ok(MemoryManager {
+            execbuffer: Arc::new(RwLock::new(execbuffer)),
+            writebuffer: None,
+            fixed: true,
+            execbuffer_size: reserved_size,
+            asmoffset: 0,
+            execbuffer_addr
+        })
+    }
+
+    /// Create a new memory manager with a fixed-size buffer of `size` bytes, that lies entirely within `distance` bytes of `hint`.
+    /// Like `new_reserved`, this buffer can never grow.
+    pub fn new_near(size: usize, hint: usize, distance: usize) -> io::Result<Self> {
+        let execbuffer = ExecutableBuffer::new_near(size, hint, distance)?;
+        let execbuffer_addr = execbuffer.as_ptr() as usize;
+
+        Ok(MemoryManager {
+            execbuffer: Arc::new(RwLock::new(execbuffer)),
+            writebuffer: None,
+            fixed: true,
+            execbuffer_size: size,
+            asmoffset: 0,
+            execbuffer_addr
+        })
+    }
+
+    /// Create a new dual-mapped memory manager, with `initial_mmap_size` data allocated.
+    /// Committing code into this manager never changes the protection of the executable memory,
+    /// and therefore doesn't block any readers unless the buffer has to grow. Modifying committed
+    /// code still waits for readers to release their locks.
+    pub fn new_dual_mapped(initial_mmap_size: usize) -> io::Result<Self> {
+        // safety: committing only writes past the length of the executable buffer, and modifying
+        // takes the write lock, so nothing that can be borrowed through it is ever written to.
+        let (execbuffer, writebuffer) = unsafe { ExecutableBuffer::new_dual_mapped(initial_mmap_size)? };
+        let execbuffer_addr = execbuffer.as_ptr() as usize;
+
+        Ok(MemoryManager {
+            execbuffer: Arc::new(RwLock::new(execbuffer)),
+            writebuffer: Some(writebuffer),
+            fixed: false,
+            execbuffer_size: initial_mmap_size,
+            asmoffset: 0,
+            execbuffer_addr
+        })
+    }
+
+    /// Create a new dual-mapped memory manager that reserves `reserved_size` bytes of address space up front.
+    /// This combines the properties of `new_reserved` and `new_dual_mapped`: the managed memory never moves,
+    /// and code can be committed without blocking readers. Committing more than `reserved_size` bytes
+    /// makes `commit` panic, and `try_commit` return an error.
+    pub fn new_reserved_dual_mapped(reserved_size: usize) -> io::Result<Self> {
+        // safety: see new_dual_mapped
+        let (execbuffer, writebuffer) = unsafe { ExecutableBuffer::new_dual_mapped(reserved_size)? };
+        let execbuffer_addr = execbuffer.as_ptr() as usize;
+
+        Ok(MemoryManager {
+            execbuffer: Arc::new(RwLock::new(execbuffer)),
+            writebuffer: Some(writebuffer),
+            fixed: true,
+            execbuffer_size: reserved_size,
+            asmoffset: 0,
+            execbuffer_addr
+        })
+    }
+
+    /// Returns the amount of bytes that can be committed to the manager without it having to grow
+    pub fn size(&self) -> usize {
+        self.execbuffer_size
+    }
+
+    /// Returns if the managed memory is dual-mapped
+    pub fn is_dual_mapped(&self) -> bool {
+        self.writebuffer.is_some()
+    }
+
     /// Returns the amount of bytes already committed to the manager
     pub fn committed(&self) -> usize {
         self.asmoffset
@@ -117,39 +207,91 @@ impl MemoryManager {
     }
 
     /// Commits the data from `new` into the managed memory, calling `f` when the buffer is moved to fix anything
-    /// that relies on the address of the buffer
+    /// that relies on the address of the buffer. Panics if the buffer cannot grow and the data does not fit in it,
+    /// `try_commit` can be used to handle that case instead.
     pub fn commit<F>(&mut self, new: &mut Vec<u8>, f: F) where F: FnOnce(&mut [u8], usize, usize) {
+        if self.try_commit(new, f).is_err() {
+            panic!("Cannot commit {} bytes into a fixed buffer of {} bytes", self.asmoffset + new.len(), self.execbuffer_size);
+        }
+    }
+
+    /// Commits the data from `new` into the managed memory, calling `f` when the buffer is moved to fix anything
+    /// that relies on the address of the buffer. If the buffer cannot grow and the data does not fit in it,
+    /// this returns `DynasmError::BufferFull` and leaves `new` untouched.
+    pub fn try_commit<F>(&mut self, new: &mut Vec<u8>, f: F) -> Result<(), DynasmError> where F: FnOnce(&mut [u8], usize, usize) {
         let old_asmoffset = self.asmoffset;
         let new_asmoffset = self.asmoffset + new.len();
 
         if old_asmoffset >= new_asmoffset {
-            return;
+            return Ok(());
         }
 
         // see if we need to request a new buffer
         if new_asmoffset > self.execbuffer_size {
+            if self.fixed {
+                return Err(DynasmError::BufferFull);
+            }
+
             while self.execbuffer_size <= new_asmoffset {
                 self.execbuffer_size *= 2;
             }
 
-            // create a larger writable buffer
-            let mut new_buffer = MutableBuffer::new(self.execbuffer_size).expect("Could not allocate a larger buffer");
-            new_buffer.set_len(new_asmoffset);
+            if let Some(writebuffer) = &mut self.writebuffer {
+                // create a larger dual-mapped buffer. safety: see new_dual_mapped
+                let (new_execbuffer, mut new_buffer) = unsafe { ExecutableBuffer::new_dual_mapped(self.execbuffer_size) }.expect("Could not allocate a larger buffer");
+                new_buffer.set_len(new_asmoffset);
+
+                // copy over the data
+                new_buffer[.. old_asmoffset].copy_from_slice(writebuffer);
+                new_buffer[old_asmoffset..].copy_from_slice(new);
+                let new_buffer_addr = new_execbuffer.as_ptr() as usize;
+
+                // allow modifications to be made
+                f(&mut new_buffer, self.execbuffer_addr, new_buffer_addr);
+
+                // resynchronize the entire buffer. The caches of the processors we support are
+                // physically tagged, so this can be done through the writable mapping.
+                cache_control::synchronize_icache(&new_buffer);
+
+                // swap the buffers
+                new_execbuffer.set_len(new_asmoffset);
+                self.execbuffer_addr = new_buffer_addr;
+                *writebuffer = new_buffer;
+                *self.execbuffer.write().unwrap() = new_execbuffer;
+
+            } else {
+                // create a larger writable buffer
+                let mut new_buffer = MutableBuffer::new(self.execbuffer_size).expect("Could not allocate a larger buffer");
+                new_buffer.set_len(new_asmoffset);
+
+                // copy over the data
+                new_buffer[.. old_asmoffset].copy_from_slice(&self.execbuffer.read().unwrap());
+                new_buffer[old_asmoffset..].copy_from_slice(new);
+                let new_buffer_addr = new_buffer.as_ptr() as usize;
+
+                // allow modifications to be made
+                f(&mut new_buffer, self.execbuffer_addr, new_buffer_addr);
 
-            // copy over the data
-            new_buffer[.. old_asmoffset].copy_from_slice(&self.execbuffer.read().unwrap());
-            new_buffer[old_asmoffset..].copy_from_slice(new);
-            let new_buffer_addr = new_buffer.as_ptr() as usize;
+                // resynchronize the entire buffer
+                cache_control::synchronize_icache(&new_buffer);
 
-            // allow modifications to be made
-            f(&mut new_buffer, self.execbuffer_addr, new_buffer_addr);
+                // swap the buffers
+                self.execbuffer_addr = new_buffer_addr;
+                *self.execbuffer.write().unwrap() = new_buffer.make_exec().expect("Could not swap buffer protection modes")
+            }
+
+        } else if let Some(writebuffer) = &mut self.writebuffer {
 
-            // resynchronize the entire buffer
-            cache_control::synchronize_icache(&new_buffer);
+            // copy in the new data through the writable mapping
+            writebuffer.set_len(new_asmoffset);
+            writebuffer[old_asmoffset..].copy_from_slice(new);
 
-            // swap the buffers
-            self.execbuffer_addr = new_buffer_addr;
-            *self.execbuffer.write().unwrap() = new_buffer.make_exec().expect("Could not swap buffer protection modes")
+            // ensure that no old data remains in the icache of what we just updated
+            cache_control::synchronize_icache(&writebuffer[old_asmoffset .. ]);
+
+            // and make it available to any readers. They can only see the part of the buffer
+            // that was previously committed, so they never observe the data being written.
+            self.execbuffer.read().unwrap().set_len(new_asmoffset);
 
         } else {
 
@@ -158,7 +300,8 @@ impl MemoryManager {
             let buffer = mem::replace(&mut *lock, ExecutableBuffer::default());
             let mut buffer = buffer.make_mut().expect("Could not swap buffer protection modes");
 
-            // update buffer and length
+            // update buffer and length, committing memory for it if the buffer is a reservation
+            buffer.commit(new_asmoffset).expect("Could not commit memory");
             buffer.set_len(new_asmoffset);
             buffer[old_asmoffset..].copy_from_slice(new);
 
@@ -172,6 +315,7 @@ impl MemoryManager {
 
         new.clear();
         self.asmoffset = new_asmoffset;
+        Ok(())
     }
 
     /// Borrow the internal memory buffer mutably
@@ -179,6 +323,32 @@ impl MemoryManager {
         self.execbuffer.write().unwrap()
     }
 
+    /// Calls `f` with mutable access to the committed part of the managed memory. This waits until
+    /// no readers hold a lock on the memory. Unless the memory is dual-mapped, no code can be executed
+    /// while this happens as the memory is remapped as writable. For dual-mapped memory, the caller is
+    /// responsible for not modifying code that is being executed without holding a lock.
+    pub fn modify<F, O>(&mut self, f: F) -> O where F: FnOnce(&mut [u8]) -> O {
+        if let Some(writebuffer) = &mut self.writebuffer {
+            // readers can borrow the committed code through the executable mapping, so they
+            // have to be locked out while it is written to through the writable one.
+            let _lock = self.execbuffer.write().unwrap();
+            return f(writebuffer);
+        }
+
+        // swap out a buffer from base
+        let mut lock = self.write();
+        let buffer = mem::take(&mut *lock);
+        let mut buffer = buffer.make_mut().expect("Could not swap buffer protection modes");
+
+        let output = f(&mut buffer);
+
+        // repack the buffer
+        let buffer = buffer.make_exec().expect("Could not swap buffer protection modes");
+        *lock = buffer;
+
+        output
+    }
+
     /// Finalizes the currently committed part of the buffer.
     pub fn finalize(self) -> Result<ExecutableBuffer, Self> {
         match Arc::try_unwrap(self.execbuffer) {
@@ -340,9 +510,13 @@ impl<R: Relocation> PatchLoc<R> {
 
     /// Returns the actual value that should be inserted at the relocation site.
     pub fn value(&self, target: usize, buf_addr: usize) -> isize {
+        // page-relative relocations are calculated relative to the start of the page containing the reference address
+        let reference = self.location.0 - self.ref_offset as usize;
+        let reference = reference.wrapping_sub(buf_addr.wrapping_add(reference) % self.relocation.ref_alignment());
+
         (match self.relocation.kind() {
-            RelocationKind::Relative => target.wrapping_sub(self.location.0 - self.ref_offset as usize),
-            RelocationKind::RelToAbs => target.wrapping_sub(self.location.0 - self.ref_offset as usize + buf_addr),
+            RelocationKind::Relative => target.wrapping_sub(reference),
+            RelocationKind::RelToAbs => target.wrapping_sub(reference.wrapping_add(buf_addr)),
             RelocationKind::AbsToRel => target + buf_addr
         }) as isize + self.target_offset
     }
@@ -358,7 +532,13 @@ impl<R: Relocation> PatchLoc<R> {
     /// Patch `buffer` so that this relocation will still point to the right location due to a change in the address of the containing buffer.
     /// `buffer` is a subsection of a larger buffer, located at offset `buf_offset` in this larger buffer.
     /// `adjustment` is `new_buf_addr - old_buf_addr`.
+    /// Page-relative relocations can only be adjusted if `adjustment` is a multiple of their page size, as
+    /// the page their reference address lies in would otherwise change as well.
     pub fn adjust(&self, buffer: &mut [u8], adjustment: isize) -> Result<(), ImpossibleRelocation> {
+        if adjustment.rem_euclid(self.relocation.ref_alignment() as isize) != 0 {
+            return Err(ImpossibleRelocation { });
+        }
+
         let value = match self.relocation.kind() {
             RelocationKind::Relative => return Ok(()),
             RelocationKind::RelToAbs => self.relocation.read_value(buffer).wrapping_sub(adjustment),
@@ -368,9 +548,10 @@ impl<R: Relocation> PatchLoc<R> {
     }
 
     /// Returns if this patch requires adjustment when the address of the buffer it resides in is altered.
+    /// This includes relative page-relative relocations, as they cannot be moved by arbitrary amounts.
     pub fn needs_adjustment(&self) -> bool {
         match self.relocation.kind() {
-            RelocationKind::Relative => false,
+            RelocationKind::Relative => self.relocation.ref_alignment() > 1,
             RelocationKind::RelToAbs
             | RelocationKind::AbsToRel => true,
         }
@@ -619,6 +800,157 @@ impl LitPool {
     }
 }
 
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum PendingPoolEntry {
+    Value(u64),
+    Global(&'static str, isize),
+    Dynamic(DynamicLabel, isize),
+}
+
+/// A literal pool that is owned by an assembler, as used by the `DynasmLitPoolApi` trait. Entries are placed in a
+/// pending pool, and references to them are made through dynamic labels which get defined when the pool is emitted.
+/// Identical entries are only stored once. Values are always aligned to their size.
+#[derive(Clone, Debug, Default)]
+pub struct PendingLitPool {
+    entries: Vec<(RelocationSize, PendingPoolEntry, DynamicLabel)>,
+    first_use: Option<usize>,
+    jump: Option<(fn(usize) -> u32, usize)>,
+}
+
+impl PendingLitPool {
+    // the order in which entries are emitted. largest first to minimize padding.
+    const SIZES: [RelocationSize; 4] = [RelocationSize::QWord, RelocationSize::DWord, RelocationSize::Word, RelocationSize::Byte];
+
+    /// Create a new, empty pending literal pool
+    pub fn new() -> Self {
+        PendingLitPool {
+            entries: Vec::new(),
+            first_use: None,
+            jump: None,
+        }
+    }
+
+    fn entry(&mut self, labels: &mut LabelRegistry, size: RelocationSize, entry: PendingPoolEntry, location: usize) -> DynamicLabel {
+        if let Some((_, _, label)) = self.entries.iter().find(|(s, e, _)| *s == size && *e == entry) {
+            return *label;
+        }
+
+        let label = labels.new_dynamic_label();
+        self.entries.push((size, entry, label));
+        self.first_use.get_or_insert(location);
+        label
+    }
+
+    /// Place `value` in the pool, returning the label of its entry. `location` is the offset
+    /// the entry is referenced from, which is used to determine when the pool has to be emitted.
+    pub fn push_value(&mut self, labels: &mut LabelRegistry, value: u64, size: RelocationSize, location: usize) -> DynamicLabel {
+        self.entry(labels, size, PendingPoolEntry::Value(value), location)
+    }
+
+    /// Place the address of global label `name` in the pool, returning the label of its entry.
+    pub fn push_global(&mut self, labels: &mut LabelRegistry, name: &'static str, target_offset: isize, size: RelocationSize, location: usize) -> DynamicLabel {
+        self.entry(labels, size, PendingPoolEntry::Global(name, target_offset), location)
+    }
+
+    /// Place the address of dynamic label `id` in the pool, returning the label of its entry.
+    pub fn push_dynamic(&mut self, labels: &mut LabelRegistry, id: DynamicLabel, target_offset: isize, size: RelocationSize, location: usize) -> DynamicLabel {
+        self.entry(labels, size, PendingPoolEntry::Dynamic(id, target_offset), location)
+    }
+
+    /// Set the function that encodes the 4-byte instruction used to jump over the pool when it is emitted in the
+    /// middle of the instruction stream. It is called with the distance to jump ahead from the start of the instruction.
+    /// `range` is the maximum distance between the first reference to the pool and the end of the pool.
+    pub fn set_jump(&mut self, jump: fn(usize) -> u32, range: usize) {
+        self.jump = Some((jump, range));
+    }
+
+    /// Returns true if the pool contains no pending entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the amount of bytes the pool would take up if it were emitted at `offset`
+    pub fn len(&self, offset: usize) -> usize {
+        let mut end = offset;
+        for size in Self::SIZES {
+            for _ in self.entries.iter().filter(|(s, _, _)| *s == size) {
+                end = end.next_multiple_of(size as usize) + size as usize;
+            }
+        }
+        end - offset
+    }
+
+    /// Returns true if the pool would end more than `range` bytes after the first reference to it, if it were emitted at `offset`
+    pub fn is_due(&self, offset: usize, range: usize) -> bool {
+        match self.first_use {
+            Some(first_use) => offset + self.len(offset) > first_use + range,
+            None => false
+        }
+    }
+
+    /// Emit the pending entries into the specified assembler, leaving the pool empty.
+    pub fn emit<D: DynasmLabelApi>(&mut self, assembler: &mut D) {
+        let entries = mem::take(&mut self.entries);
+        self.first_use = None;
+
+        for size in Self::SIZES {
+            for (_, entry, label) in entries.iter().filter(|(s, _, _)| *s == size) {
+                assembler.align(size as usize, 0);
+                assembler.dynamic_label(*label);
+                match *entry {
+                    PendingPoolEntry::Value(value) => match size {
+                        RelocationSize::Byte => assembler.push(value as u8),
+                        RelocationSize::Word => assembler.push_u16(value as u16),
+                        RelocationSize::DWord => assembler.push_u32(value as u32),
+                        RelocationSize::QWord => assembler.push_u64(value),
+                    },
+                    PendingPoolEntry::Global(name, target_offset) => {
+                        LitPool::pad_sized(size, assembler);
+                        assembler.global_relocation(name, target_offset, size as u8, size as u8, Self::absolute(size, assembler));
+                    },
+                    PendingPoolEntry::Dynamic(id, target_offset) => {
+                        LitPool::pad_sized(size, assembler);
+                        assembler.dynamic_relocation(id, target_offset, size as u8, size as u8, Self::absolute(size, assembler));
+                    },
+                }
+            }
+        }
+    }
+
+    /// Emit the pending entries into the specified assembler, preceded by a jump over them if one was set.
+    /// Does nothing if the pool is empty.
+    pub fn emit_behind_jump<D: DynasmLabelApi>(&mut self, assembler: &mut D) {
+        if self.entries.is_empty() {
+            return;
+        }
+
+        if let Some((jump, _)) = self.jump {
+            let len = self.len(assembler.offset().0 + 4);
+            assembler.push_u32(jump(4 + len));
+        }
+        self.emit(assembler);
+    }
+
+    /// Emit the pending entries into the specified assembler behind a jump, if a jump was set and the pool has used
+    /// up half of its range. Assemblers call this at labels and alignment directives, so code that is assembled
+    /// after the last reference to the pool does not silently push it out of range.
+    pub fn emit_if_due<D: DynasmLabelApi>(&mut self, assembler: &mut D) {
+        if let Some((_, range)) = self.jump {
+            if self.is_due(assembler.offset().0 + 4, range / 2) {
+                self.emit_behind_jump(assembler);
+            }
+        }
+    }
+
+    fn absolute<D: DynasmLabelApi>(size: RelocationSize, assembler: &D) -> D::Relocation {
+        match D::Relocation::absolute_from_size(size) {
+            Some(relocation) => relocation,
+            None => assembler.runtime_error("Label addresses in literal pools are not supported by this architecture")
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -639,11 +971,281 @@ mod tests {
         test_litpool::<x86::X86Relocation>();
     }
 
+    #[test]
+    fn test_litpool_x86_16() {
+        test_litpool::<x86_16::X86_16Relocation>();
+    }
+
     #[test]
     fn test_litpool_aarch64() {
         test_litpool::<aarch64::Aarch64Relocation>();
     }
 
+    #[test]
+    fn test_adjust_page_relative() {
+        use super::PatchLoc;
+        use aarch64::Aarch64Relocation;
+        use relocations::RelocationKind;
+
+        // adrp x0, 0 ; add x0, x0, 0
+        let code = [0x00, 0x00, 0x00, 0x90, 0x00, 0x00, 0x00, 0x91];
+
+        let loc = PatchLoc::new(AssemblyOffset(8), 0, 8, 8, Aarch64Relocation::ADRPPAIR(0, RelocationKind::RelToAbs));
+        assert!(loc.needs_adjustment());
+
+        let mut buf = code;
+        loc.patch(&mut buf, 0x1_0FF8, 0x2_0004).unwrap();
+
+        // moving the buffer by whole pages keeps the same target
+        let mut moved = buf;
+        loc.adjust(&mut moved, 0x1000).unwrap();
+        let mut expected = code;
+        loc.patch(&mut expected, 0x1_1FF8, 0x2_0004).unwrap();
+        assert_eq!(moved, expected);
+
+        // but moving it by anything else can change the page of the adrp
+        assert!(loc.adjust(&mut buf, 0x10).is_err());
+
+        // which is also true for relocations targeting the same buffer
+        let loc = PatchLoc::new(AssemblyOffset(8), 0, 8, 8, Aarch64Relocation::ADRPPAIR(0, RelocationKind::Relative));
+        assert!(loc.needs_adjustment());
+        assert!(loc.adjust(&mut buf, 0x1000).is_ok());
+        assert!(loc.adjust(&mut buf, 0x10).is_err());
+    }
+
+    #[test]
+    fn test_pending_litpool() {
+        let mut ops = VecAssembler::<aarch64::Aarch64Relocation>::new(0x1000);
+        let mut pool = components::PendingLitPool::new();
+
+        assert!(pool.is_empty());
+        assert!(!pool.is_due(0x10_0000, 0x1000));
+
+        let a = pool.push_value(ops.labels_mut(), 0x1234, RelocationSize::DWord, 0);
+        let b = pool.push_value(ops.labels_mut(), 0x5678, RelocationSize::QWord, 8);
+        assert_eq!(pool.push_value(ops.labels_mut(), 0x1234, RelocationSize::DWord, 16), a);
+        assert_ne!(pool.push_value(ops.labels_mut(), 0x1234, RelocationSize::QWord, 16), a);
+        let c = pool.push_global(ops.labels_mut(), "global1", 4, RelocationSize::QWord, 24);
+
+        assert_eq!(pool.len(0), 28);
+        assert_eq!(pool.len(4), 32);
+        assert!(!pool.is_due(4, 36));
+        assert!(pool.is_due(4, 35));
+
+        ops.push_u32(0);
+        ops.global_label("global1");
+        pool.emit(&mut ops);
+        assert!(pool.is_empty());
+
+        assert_eq!(ops.labels().resolve_dynamic(b), Ok(AssemblyOffset(8)));
+        assert_eq!(ops.labels().resolve_dynamic(c), Ok(AssemblyOffset(24)));
+        assert_eq!(ops.labels().resolve_dynamic(a), Ok(AssemblyOffset(32)));
+
+        let buf = ops.finalize().unwrap();
+        assert_eq!(&*buf, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x34, 0x12, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x08, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x34, 0x12, 0x00, 0x00u8,
+        ] as &[u8]);
+    }
+
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn test_dual_mapped_assembler() {
+        let mut ops = x64::Assembler::new_dual_mapped().unwrap();
+        let reader = ops.reader();
+
+        // mov eax, 0x1234 ; ret
+        let start = ops.offset();
+        ops.extend(&[0xB8, 0x34, 0x12, 0x00, 0x00, 0xC3]);
+        ops.commit().unwrap();
+
+        {
+            // committing code doesn't need to wait for readers
+            let guard = reader.lock();
+            let addr = guard.as_ptr() as usize;
+
+            ops.extend(&[0x90, 0x90]);
+            ops.commit().unwrap();
+            assert_eq!(&guard[..], &[0xB8, 0x34, 0x12, 0x00, 0x00, 0xC3, 0x90, 0x90]);
+
+            // but altering it does
+            drop(guard);
+            ops.alter(|modifier| {
+                modifier.goto(AssemblyOffset(1));
+                modifier.push(0x78);
+                modifier.push(0x56);
+            }).unwrap();
+
+            // the changes are visible after locking again, without moving the code
+            let guard = reader.lock();
+            assert_eq!(guard.as_ptr() as usize, addr);
+            assert_eq!(&guard[..], &[0xB8, 0x78, 0x56, 0x00, 0x00, 0xC3, 0x90, 0x90]);
+
+            #[cfg(target_arch="x86_64")]
+            {
+                let f: extern "C" fn() -> u32 = unsafe { std::mem::transmute(guard.ptr(start)) };
+                assert_eq!(f(), 0x5678);
+            }
+        }
+
+        // growing the buffer moves the code
+        let old_addr = reader.lock().as_ptr() as usize;
+        ops.extend(&[0xCCu8; 0x2000]);
+        ops.commit().unwrap();
+
+        let guard = reader.lock();
+        assert_ne!(guard.as_ptr() as usize, old_addr);
+        assert_eq!(guard.len(), 0x2008);
+        assert_eq!(&guard[..8], &[0xB8, 0x78, 0x56, 0x00, 0x00, 0xC3, 0x90, 0x90]);
+        drop(guard);
+        drop(reader);
+
+        let buf = ops.finalize().unwrap();
+        assert_eq!(buf.len(), 0x2008);
+        assert_eq!(buf[0x2007], 0xCC);
+    }
+
+    #[test]
+    fn test_reserved_assembler() {
+        let mut ops = aarch64::Assembler::with_reservation(1 << 30).unwrap();
+        let reader = ops.reader();
+
+        let label = ops.new_dynamic_label();
+        ops.dynamic_label(label);
+        ops.extend(&[0x90; 0x100]);
+        ops.commit().unwrap();
+        let addr = reader.lock().as_ptr() as usize;
+
+        // an absolute reference, which would need to be adjusted if the buffer moved
+        ops.extend(&[0; 8]);
+        ops.dynamic_relocation(label, 0, 8, 8, aarch64::Aarch64Relocation::Absolute(RelocationSize::QWord));
+        ops.extend(&[0xCC; 0x3_0000]);
+        ops.commit().unwrap();
+
+        let guard = reader.lock();
+        assert_eq!(guard.as_ptr() as usize, addr);
+        assert_eq!(guard.len(), 0x3_0108);
+        assert_eq!(&guard[0x100..0x108], &addr.to_le_bytes());
+        drop(guard);
+        drop(reader);
+
+        // only the committed part of the reservation is accessible
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        {
+            assert_eq!(protection_of(addr + 0x3_0000), "r-xp");
+            assert_eq!(protection_of(addr + 0x4_0000), "---p");
+        }
+
+        let buf = ops.finalize().unwrap();
+        assert_eq!(buf.as_ptr() as usize, addr);
+    }
+
+    // returns the protection of the mapping containing `addr`, as listed in /proc/self/maps
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn protection_of(addr: usize) -> String {
+        let maps = std::fs::read_to_string("/proc/self/maps").unwrap();
+        for line in maps.lines() {
+            let mut fields = line.split_whitespace();
+            let (start, end) = fields.next().unwrap().split_once('-').unwrap();
+            let (start, end) = (usize::from_str_radix(start, 16).unwrap(), usize::from_str_radix(end, 16).unwrap());
+            if (start..end).contains(&addr) {
+                return fields.next().unwrap().to_string();
+            }
+        }
+        panic!("Address {addr:#x} is not mapped");
+    }
+
+    #[test]
+    fn test_reserved_assembler_exhausted() {
+        let mut ops = x64::Assembler::with_reservation(0x1000).unwrap();
+        ops.extend(&[0x90; 0xF00]);
+        ops.commit().unwrap();
+
+        ops.extend(&[0xCC; 0x101]);
+        assert_eq!(ops.commit(), Err(DynasmError::BufferFull));
+
+        // the code that didn't fit stays uncommitted, and the committed code is still usable
+        assert_eq!(ops.offset(), AssemblyOffset(0x1001));
+        assert_eq!(ops.commit(), Err(DynasmError::BufferFull));
+        let reader = ops.reader();
+        let guard = reader.lock();
+        assert_eq!(guard.len(), 0xF00);
+        assert_eq!(guard[0xEFF], 0x90);
+    }
+
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn test_near_assembler() {
+        let hint = test_near_assembler as fn() as usize;
+        let mut ops = aarch64::Assembler::new_near(hint, 0x1_0000).unwrap();
+        ops.extend(&[0xCC; 0x100]);
+        ops.commit().unwrap();
+
+        let addr = ops.reader().lock().as_ptr() as usize;
+        assert!(addr.abs_diff(hint) < 0x8000_0000);
+        assert!((addr + 0x1_0000).abs_diff(hint) < 0x8000_0000);
+
+        ops.extend(&[0xCC; 0x8000]);
+        let buf = ops.finalize().unwrap();
+        assert_eq!(buf.as_ptr() as usize, addr);
+        assert_eq!(buf.len(), 0x8100);
+    }
+
+    #[test]
+    #[cfg(all(target_arch="x86_64", any(target_os = "linux", target_os = "android")))]
+    fn test_near_assembler_call() {
+        extern "C" fn callee() -> u32 {
+            0x1234
+        }
+
+        let target = callee as extern "C" fn() -> u32 as usize;
+        let mut ops = x64::Assembler::new_near(target, 0x1000).unwrap();
+        let start = ops.offset();
+
+        // the buffer doesn't move, so the displacement to the callee can be calculated up front
+        let addr = ops.reader().lock().as_ptr() as usize;
+        let disp = i32::try_from(target.wrapping_sub(addr + start.0 + 6) as isize).unwrap();
+
+        // push rax ; call callee ; pop rcx ; ret
+        ops.extend(&[0x50, 0xE8]);
+        ops.extend(&disp.to_le_bytes());
+        ops.extend(&[0x59, 0xC3]);
+
+        let buf = ops.finalize().unwrap();
+        let f: extern "C" fn() -> u32 = unsafe { std::mem::transmute(buf.ptr(start)) };
+        assert_eq!(f(), 0x1234);
+    }
+
+    #[test]
+    #[cfg(all(target_arch="x86_64", any(target_os = "linux", target_os = "android")))]
+    fn test_near_assembler_call_at_end() {
+        extern "C" fn callee() -> u32 {
+            0x5678
+        }
+
+        let target = callee as extern "C" fn() -> u32 as usize;
+        let mut ops = x64::Assembler::new_near(target, 0x1000).unwrap();
+        let addr = ops.reader().lock().as_ptr() as usize;
+
+        // fill the buffer up to the last 8 bytes
+        ops.extend(&[0xCC; 0x1000 - 8]);
+        let start = ops.offset();
+        let disp = i32::try_from(target.wrapping_sub(addr + start.0 + 6) as isize).unwrap();
+
+        // push rax ; call callee ; pop rcx ; ret
+        ops.extend(&[0x50, 0xE8]);
+        ops.extend(&disp.to_le_bytes());
+        ops.extend(&[0x59, 0xC3]);
+
+        let buf = ops.finalize().unwrap();
+        assert_eq!(buf.len(), 0x1000);
+        let f: extern "C" fn() -> u32 = unsafe { std::mem::transmute(buf.ptr(start)) };
+        assert_eq!(f(), 0x5678);
+    }
+
     fn test_litpool<R: Relocation + Debug>() {
         let mut ops = Assembler::<R>::new().unwrap();
         let dynamic1 = ops.new_dynamic_label();
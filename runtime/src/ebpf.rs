@@ -0,0 +1,188 @@
+//! Runtime support for the eBPF assembling target.
+//!
+//! The eBPF instruction set features fixed 64-bit width instruction slots, with the exception of the
+//! wide immediate loads (`lddw`) that occupy two slots. All relocations are counted in instruction
+//! slots, relative to the slot following the first slot of the instruction. Jumps use the 16-bit
+//! offset field of the instruction, calls and `ja32` the 32-bit immediate field, and `lddw` splits
+//! its 64-bit immediate over the immediate fields of both of its slots.
+//!
+//! The core relocation behaviour for this architecture is provided by the [`EbpfRelocation`] type.
+//!
+//! eBPF code is not executed directly by the host processor, but handed to a kernel or a virtual
+//! machine. Therefore this module doesn't provide an executable `Assembler` type alias. Instead,
+//! programs are assembled into a byte buffer using a [`VecAssembler`](crate::VecAssembler)`<EbpfRelocation>`.
+//!
+//! Next to that, this module contains the following:
+//!
+//! ## Enums
+//!
+//! There is an enumeration of the eBPF general purpose registers.
+//! This enum implements the [`Register`] trait and its discriminant values match their numeric encoding in dynamic register literals.
+//!
+//! ## Functions
+//!
+//! This module contains handlers for error conditions in the case where a dynamically encoded immediate is out of range.
+//! These panic with a friendly error message if any of these conditions happen at runtime.
+
+use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation, fits_signed_bitfield};
+use byteorder::{ByteOrder, LittleEndian};
+use std::convert::TryFrom;
+use crate::Register;
+
+/// Relocation implementation for the eBPF architecture.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub enum EbpfRelocation {
+    // jumps
+    // 16 bits in the offset field, counted in instruction slots
+    OFF16,
+    // call, ja32
+    // 32 bits in the immediate field, counted in instruction slots
+    IMM32,
+    // lddw
+    // 64 bits, split over the immediate fields of both slots, counted in instruction slots
+    IMM64,
+    // Anything in directives
+    Plain(RelocationSize),
+}
+
+impl Relocation for EbpfRelocation {
+    type Encoding = (u8,);
+    fn from_encoding(encoding: Self::Encoding) -> Self {
+        match encoding.0 {
+            0 => Self::OFF16,
+            1 => Self::IMM32,
+            2 => Self::IMM64,
+            x  => Self::Plain(RelocationSize::from_encoding(x - 8))
+        }
+    }
+    fn from_size(size: RelocationSize) -> Self {
+        Self::Plain(size)
+    }
+    fn size(&self) -> usize {
+        match self {
+            Self::OFF16
+            | Self::IMM32 => 8,
+            Self::IMM64 => 16,
+            Self::Plain(s) => s.size(),
+        }
+    }
+    fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        let value = i64::try_from(value).map_err(|_| ImpossibleRelocation { } )?;
+
+        // instruction relocations are counted in instruction slots
+        let slots = match self {
+            Self::Plain(s) => return s.write_value(buf, value as isize),
+            _ => {
+                if value % 8 != 0 {
+                    return Err(ImpossibleRelocation { } );
+                }
+                value / 8
+            }
+        };
+
+        match self {
+            Self::OFF16 => {
+                if !fits_signed_bitfield(slots, 16) {
+                    return Err(ImpossibleRelocation { } );
+                }
+                LittleEndian::write_i16(&mut buf[2..4], slots as i16);
+            },
+            Self::IMM32 => {
+                if !fits_signed_bitfield(slots, 32) {
+                    return Err(ImpossibleRelocation { } );
+                }
+                LittleEndian::write_i32(&mut buf[4..8], slots as i32);
+            },
+            Self::IMM64 => {
+                LittleEndian::write_u32(&mut buf[4..8], slots as u32);
+                LittleEndian::write_u32(&mut buf[12..16], (slots >> 32) as u32);
+            },
+            Self::Plain(_) => unreachable!(),
+        }
+
+        Ok(())
+    }
+    fn read_value(&self, buf: &[u8]) -> isize {
+        let slots = match self {
+            Self::Plain(s) => return s.read_value(buf),
+            Self::OFF16 => i64::from(LittleEndian::read_i16(&buf[2..4])),
+            Self::IMM32 => i64::from(LittleEndian::read_i32(&buf[4..8])),
+            Self::IMM64 => {
+                let low = LittleEndian::read_u32(&buf[4..8]);
+                let high = LittleEndian::read_u32(&buf[12..16]);
+                ((u64::from(high) << 32) | u64::from(low)) as i64
+            },
+        };
+
+        (slots * 8) as isize
+    }
+    fn kind(&self) -> RelocationKind {
+        RelocationKind::Relative
+    }
+    fn page_size() -> usize {
+        4096
+    }
+}
+
+// these should explicitly never be inlined, as this is the slow path.
+// that's also why these aren't made generic.
+
+/// Handler for `u32` out-of-range eBPF immediates.
+#[inline(never)]
+pub fn immediate_out_of_range_unsigned_32(immediate: u32) -> ! {
+    panic!("Cannot assemble this eBPF instruction. Immediate {immediate} is out of range.")
+}
+
+/// Handler for `i32` out-of-range eBPF immediates.
+#[inline(never)]
+pub fn immediate_out_of_range_signed_32(immediate: i32) -> ! {
+    panic!("Cannot assemble this eBPF instruction. Immediate {immediate} is out of range.")
+}
+
+
+/// 8-byte general purpose registers, where R10 is the read-only frame pointer
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RR {
+    R0 = 0x00, R1 = 0x01, R2 = 0x02, R3 = 0x03,
+    R4 = 0x04, R5 = 0x05, R6 = 0x06, R7 = 0x07,
+    R8 = 0x08, R9 = 0x09, R10= 0x0A,
+}
+reg_impls!(RR);
+
+
+#[cfg(test)]
+mod tests {
+    use super::RR::*;
+    use super::EbpfRelocation;
+    use crate::Register;
+    use crate::relocations::Relocation;
+
+    #[test]
+    fn reg_code() {
+        assert_eq!(R3.code(), 3);
+    }
+
+    #[test]
+    fn reg_code_from() {
+        assert_eq!(u8::from(R10), 0x0A);
+    }
+
+    #[test]
+    fn imm64_roundtrip() {
+        let mut buf = [0u8; 16];
+        EbpfRelocation::IMM64.write_value(&mut buf, -0x40).unwrap();
+        assert_eq!(buf[4..8], [0xF8, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(buf[12..16], [0xFF, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(EbpfRelocation::IMM64.read_value(&buf), -0x40);
+    }
+
+    #[test]
+    fn off16_range() {
+        let mut buf = [0u8; 8];
+        assert!(EbpfRelocation::OFF16.write_value(&mut buf, 0x7FFF * 8).is_ok());
+        assert!(EbpfRelocation::OFF16.write_value(&mut buf, 0x8000 * 8).is_err());
+        assert!(EbpfRelocation::OFF16.write_value(&mut buf, 4).is_err());
+    }
+}
@@ -8,6 +8,7 @@ pub mod mmap;
 pub mod components;
 pub mod relocations;
 pub mod cache_control;
+pub mod arena;
 
 /// Helper to implement common traits on register enums.
 macro_rules! reg_impls {
@@ -28,14 +29,20 @@ macro_rules! reg_impls {
 
 pub mod x64;
 pub mod x86;
+pub mod x86_16;
 pub mod aarch64;
 pub mod riscv;
+pub mod loongarch64;
+pub mod arm;
+pub mod ebpf;
+pub mod ppc64le;
 
 pub use crate::mmap::ExecutableBuffer;
+pub use crate::arena::{CodeArena, CodeHandle};
 pub use dynasm::{dynasm, dynasm_backwards};
 
-use crate::components::{MemoryManager, LabelRegistry, RelocRegistry, ManagedRelocs, PatchLoc, StaticLabel};
-use crate::relocations::Relocation;
+use crate::components::{MemoryManager, LabelRegistry, RelocRegistry, ManagedRelocs, PatchLoc, StaticLabel, PendingLitPool};
+use crate::relocations::{Relocation, RelocationSize};
 
 use std::hash::Hash;
 use std::sync::{Arc, RwLock, RwLockReadGuard};
@@ -90,6 +97,9 @@ impl Executor {
     /// is alive, it can be used to read and execute from the `ExecutableBuffer`.
     /// Any pointers created to the `ExecutableBuffer` should no longer be used when
     /// the guard is dropped.
+    ///
+    /// For assemblers created with `Assembler::new_dual_mapped`, holding the guard does not stop
+    /// the assembler from committing new code into the buffer, past the part that was already visible.
     #[inline]
     pub fn lock(&self) -> RwLockReadGuard<'_, ExecutableBuffer> {
         let guard = self.execbuffer.read().unwrap();
@@ -151,6 +161,7 @@ impl fmt::Display for TargetKind {
 
 /// The various error types generated by dynasm functions.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum DynasmError {
     /// A check (like `Modifier::check` or `Modifier::check_exact`) that failed
     CheckFailed,
@@ -160,6 +171,8 @@ pub enum DynasmError {
     UnknownLabel(LabelKind),
     /// The user tried to declare a relocation too far away from the label it targets
     ImpossibleRelocation(TargetKind),
+    /// The committed code does not fit in an executable buffer that cannot grow
+    BufferFull,
 }
 
 impl fmt::Display for DynasmError {
@@ -169,6 +182,7 @@ impl fmt::Display for DynasmError {
             DynasmError::DuplicateLabel(l) => write!(f, "Duplicate label defined: '{}'", l),
             DynasmError::UnknownLabel(l) => write!(f, "Unknown label: '{}'", l),
             DynasmError::ImpossibleRelocation(s) => write!(f, "Impossible relocation: '{}'", s),
+            DynasmError::BufferFull => write!(f, "The code does not fit in the executable buffer"),
         }
     }
 }
@@ -180,6 +194,7 @@ impl error::Error for DynasmError {
             DynasmError::DuplicateLabel(_) => "Duplicate label defined",
             DynasmError::UnknownLabel(_) => "Unknown label",
             DynasmError::ImpossibleRelocation(_) => "Impossible relocation",
+            DynasmError::BufferFull => "Executable buffer is full",
         }
     }
 }
@@ -286,6 +301,49 @@ pub trait DynasmLabelApi : DynasmApi {
 }
 
 
+/// This trait extends DynasmLabelApi with a literal pool that is managed by the assembler, as used by
+/// pseudo-instructions like aarch64's `ldr x0, =value`. Entries are collected in a pending pool, which is
+/// emitted into the instruction stream when it is flushed.
+pub trait DynasmLitPoolApi : DynasmLabelApi {
+    /// Place `value` in the pending literal pool, and record a relocation spot referencing it.
+    fn pool_u32(&mut self, value: u32, field_offset: u8, ref_offset: u8, kind: <Self::Relocation as Relocation>::Encoding);
+    /// Place `value` in the pending literal pool, and record a relocation spot referencing it.
+    fn pool_u64(&mut self, value: u64, field_offset: u8, ref_offset: u8, kind: <Self::Relocation as Relocation>::Encoding);
+    /// Place the address of a global label in the pending literal pool, and record a relocation spot referencing it.
+    fn pool_global_reloc(&mut self, name: &'static str, target_offset: isize, size: RelocationSize, field_offset: u8, ref_offset: u8, kind: <Self::Relocation as Relocation>::Encoding);
+    /// Place the address of a dynamic label in the pending literal pool, and record a relocation spot referencing it.
+    fn pool_dynamic_reloc(&mut self, id: DynamicLabel, target_offset: isize, size: RelocationSize, field_offset: u8, ref_offset: u8, kind: <Self::Relocation as Relocation>::Encoding);
+    /// Emit the pending literal pool at the current location.
+    fn pool_flush(&mut self);
+    /// Returns true if the pending literal pool would end more than `range` bytes after the first reference to it,
+    /// if it were emitted at `offset`.
+    fn pool_is_due(&self, offset: AssemblyOffset, range: usize) -> bool;
+    /// Returns the amount of bytes the pending literal pool would take up if it were emitted at `offset`.
+    fn pool_len(&self, offset: AssemblyOffset) -> usize;
+
+    /// Set the function that encodes the 4-byte instruction used to jump over the pending literal pool when it is
+    /// emitted in the middle of the instruction stream. It is called with the distance to jump ahead.
+    /// `range` is the maximum distance between the first reference to the pool and the end of the pool.
+    fn pool_set_jump(&mut self, jump: fn(usize) -> u32, range: usize);
+
+    /// Emit the pending literal pool if it could otherwise end up out of `range` bytes of its first reference
+    /// after `reserve` more bytes are assembled. As this happens in the middle of the instruction stream,
+    /// the pool is preceded by the instruction encoded by `jump`, which should jump the given amount of bytes ahead.
+    /// Any entries that are still pending when the assembler commits are emitted behind this jump as well, just like
+    /// they are at labels and alignment directives once half of `range` has been used up.
+    fn pool_check(&mut self, range: usize, reserve: usize, jump: fn(usize) -> u32) where Self: Sized {
+        self.pool_set_jump(jump, range);
+
+        let offset = self.offset().0 + 4;
+        if self.pool_is_due(AssemblyOffset(offset + reserve), range) {
+            let len = self.pool_len(AssemblyOffset(offset));
+            self.push_u32(jump(4 + len));
+            self.pool_flush();
+        }
+    }
+}
+
+
 /// An assembler that is purely a `Vec<u8>`. It doesn't support labels or architecture-specific directives,
 /// but can be used to easily inspect generated code. It is intended to be used in testcases.
 #[derive(Debug, Clone)]
@@ -351,6 +409,9 @@ pub struct VecAssembler<R: Relocation> {
     baseaddr: usize,
     labels: LabelRegistry,
     relocs: RelocRegistry<R>,
+    // relocations that have to be adjusted if the code is moved, if these are tracked
+    managed: Option<ManagedRelocs<R>>,
+    pool: PendingLitPool,
     error: Option<DynasmError>,
 }
 
@@ -362,6 +423,8 @@ impl<R: Relocation> VecAssembler<R> {
             baseaddr,
             labels: LabelRegistry::new(),
             relocs: RelocRegistry::new(),
+            managed: None,
+            pool: PendingLitPool::new(),
             error: None
         }
     }
@@ -383,10 +446,21 @@ impl<R: Relocation> VecAssembler<R> {
             baseaddr,
             labels: LabelRegistry::with_capacity(local_labels, global_labels, dynamic_labels),
             relocs: RelocRegistry::with_capacity(static_references, dynamic_references),
+            managed: None,
+            pool: PendingLitPool::new(),
             error: None
         }
     }
 
+    /// Creates a new VecAssembler, with the specified base address, that keeps track of the resolved relocations
+    /// that have to be adjusted when the assembled code is moved to a different address.
+    pub(crate) fn new_managed(baseaddr: usize) -> VecAssembler<R> {
+        VecAssembler {
+            managed: Some(ManagedRelocs::new()),
+            ..VecAssembler::new(baseaddr)
+        }
+    }
+
     /// Reserve capacity for at least `additional` instruction bytes to be inserted
     pub fn reserve_ops(&mut self, additional: usize) {
         self.ops.reserve(additional);
@@ -405,6 +479,11 @@ impl<R: Relocation> VecAssembler<R> {
             return Err(e);
         }
 
+        // Emit any pending literal pool entries so their references can be resolved
+        let mut pool = mem::take(&mut self.pool);
+        pool.emit_behind_jump(self);
+        self.pool = pool;
+
         // Resolve statics
         for (loc, label) in self.relocs.take_statics() {
             let target = self.labels.resolve_static(&label)?;
@@ -418,6 +497,11 @@ impl<R: Relocation> VecAssembler<R> {
                     }
                 ));
             }
+            if let Some(managed) = &mut self.managed {
+                if loc.needs_adjustment() {
+                    managed.add(loc)
+                }
+            }
         }
 
         // Resolve dynamics
@@ -427,6 +511,11 @@ impl<R: Relocation> VecAssembler<R> {
             if loc.patch(buf, self.baseaddr, target.0).is_err() {
                 return Err(DynasmError::ImpossibleRelocation(TargetKind::Dynamic(id)));
             }
+            if let Some(managed) = &mut self.managed {
+                if loc.needs_adjustment() {
+                    managed.add(loc)
+                }
+            }
         }
 
         Ok(())
@@ -459,6 +548,9 @@ impl<R: Relocation> VecAssembler<R> {
     pub fn take(&mut self) -> Result<Vec<u8>, DynasmError> {
         self.commit()?;
         self.labels.clear();
+        if let Some(managed) = &mut self.managed {
+            *managed = ManagedRelocs::new();
+        }
         Ok(std::mem::take(&mut self.ops))
     }
 
@@ -466,8 +558,18 @@ impl<R: Relocation> VecAssembler<R> {
     pub fn drain<'a>(&'a mut self) -> Result<impl Iterator<Item=u8> + 'a, DynasmError> {
         self.commit()?;
         self.labels.clear();
+        if let Some(managed) = &mut self.managed {
+            *managed = ManagedRelocs::new();
+        }
         Ok(self.ops.drain(..))
     }
+
+    /// Emits the pending literal pool if it is getting out of range. Called at labels and alignment directives.
+    fn pool_boundary(&mut self) {
+        let mut pool = mem::take(&mut self.pool);
+        pool.emit_if_due(self);
+        self.pool = pool;
+    }
 }
 
 impl<R: Relocation> Extend<u8> for VecAssembler<R> {
@@ -490,6 +592,8 @@ impl<R: Relocation> DynasmApi for VecAssembler<R> {
         self.ops.push(byte);
     }
     fn align(&mut self, alignment: usize, with: u8) {
+        self.pool_boundary();
+
         let offset = self.offset().0 % alignment;
         if offset != 0 {
             for _ in offset .. alignment {
@@ -503,16 +607,19 @@ impl<R: Relocation> DynasmLabelApi for VecAssembler<R> {
     type Relocation = R;
 
     fn local_label(&mut self, name: &'static str) {
+        self.pool_boundary();
         let offset = self.offset();
         self.labels.define_local(name, offset);
     }
     fn global_label( &mut self, name: &'static str) {
+        self.pool_boundary();
         let offset = self.offset();
         if let Err(e) = self.labels.define_global(name, offset) {
             self.error = Some(e)
         }
     }
     fn dynamic_label(&mut self, id: DynamicLabel) {
+        self.pool_boundary();
         let offset = self.offset();
         if let Err(e) = self.labels.define_dynamic(id, offset) {
             self.error = Some(e)
@@ -552,10 +659,50 @@ impl<R: Relocation> DynasmLabelApi for VecAssembler<R> {
         let buf = &mut self.ops[loc.range(0)];
         if loc.patch(buf, self.baseaddr, target).is_err() {
             self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Extern(target)))
+        } else if let Some(managed) = &mut self.managed {
+            if loc.needs_adjustment() {
+                managed.add(loc)
+            }
         }
     }
 }
 
+impl<R: Relocation> DynasmLitPoolApi for VecAssembler<R> {
+    fn pool_u32(&mut self, value: u32, field_offset: u8, ref_offset: u8, kind: R::Encoding) {
+        let location = self.offset();
+        let label = self.pool.push_value(&mut self.labels, u64::from(value), RelocationSize::DWord, location.0 - ref_offset as usize);
+        self.dynamic_reloc(label, 0, field_offset, ref_offset, kind);
+    }
+    fn pool_u64(&mut self, value: u64, field_offset: u8, ref_offset: u8, kind: R::Encoding) {
+        let location = self.offset();
+        let label = self.pool.push_value(&mut self.labels, value, RelocationSize::QWord, location.0 - ref_offset as usize);
+        self.dynamic_reloc(label, 0, field_offset, ref_offset, kind);
+    }
+    fn pool_global_reloc(&mut self, name: &'static str, target_offset: isize, size: RelocationSize, field_offset: u8, ref_offset: u8, kind: R::Encoding) {
+        let location = self.offset();
+        let label = self.pool.push_global(&mut self.labels, name, target_offset, size, location.0 - ref_offset as usize);
+        self.dynamic_reloc(label, 0, field_offset, ref_offset, kind);
+    }
+    fn pool_dynamic_reloc(&mut self, id: DynamicLabel, target_offset: isize, size: RelocationSize, field_offset: u8, ref_offset: u8, kind: R::Encoding) {
+        let location = self.offset();
+        let label = self.pool.push_dynamic(&mut self.labels, id, target_offset, size, location.0 - ref_offset as usize);
+        self.dynamic_reloc(label, 0, field_offset, ref_offset, kind);
+    }
+    fn pool_flush(&mut self) {
+        let mut pool = mem::take(&mut self.pool);
+        pool.emit(self);
+        self.pool = pool;
+    }
+    fn pool_set_jump(&mut self, jump: fn(usize) -> u32, range: usize) {
+        self.pool.set_jump(jump, range);
+    }
+    fn pool_is_due(&self, offset: AssemblyOffset, range: usize) -> bool {
+        self.pool.is_due(offset.0, range)
+    }
+    fn pool_len(&self, offset: AssemblyOffset) -> usize {
+        self.pool.len(offset.0)
+    }
+}
 
 /// A full assembler implementation. Supports labels, all types of relocations,
 /// incremental compilation and multithreaded execution with simultaneous compilation.
@@ -567,6 +714,7 @@ pub struct Assembler<R: Relocation> {
     labels: LabelRegistry,
     relocs: RelocRegistry<R>,
     managed: ManagedRelocs<R>,
+    pool: Box<PendingLitPool>,
     error: Option<DynasmError>,
 }
 
@@ -579,6 +727,7 @@ impl<R: Relocation> Assembler<R> {
             labels: LabelRegistry::new(),
             relocs: RelocRegistry::new(),
             managed: ManagedRelocs::new(),
+            pool: Box::new(PendingLitPool::new()),
             error: None
         })
     }
@@ -601,10 +750,96 @@ impl<R: Relocation> Assembler<R> {
             labels: LabelRegistry::new(),
             relocs: RelocRegistry::new(),
             managed: ManagedRelocs::new(),
+            pool: Box::new(PendingLitPool::new()),
             error: None,
         })
     }
 
+    /// Create a new, empty assembler that reserves `size` bytes of address space for its executable buffer
+    /// up front, rounded up to a multiple of `page_size`. Memory is only committed, and made accessible,
+    /// as code is added to the buffer.
+    ///
+    /// As the executable buffer of this assembler never moves, pointers into it stay valid when more code is
+    /// committed, and managed relocations never need to be adjusted. Committing more than the reserved
+    /// amount of code returns `DynasmError::BufferFull`, and leaves the code uncommitted.
+    pub fn with_reservation(size: usize) -> io::Result<Self> {
+        let memory_size =
+            size
+                .checked_next_multiple_of(R::page_size())
+                .ok_or(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "Reservation is too large",
+                ))?;
+
+        Ok(Self {
+            ops: Vec::new(),
+            memory: MemoryManager::new_reserved(memory_size)?,
+            labels: LabelRegistry::new(),
+            relocs: RelocRegistry::new(),
+            managed: ManagedRelocs::new(),
+            pool: Box::new(PendingLitPool::new()),
+            error: None
+        })
+    }
+
+    /// Create a new, empty assembler whose executable buffer of `capacity` bytes, rounded up to a multiple of `page_size`,
+    /// lies entirely within 2 GiB of the address `addr`. This ensures that 32-bit relative relocations from the buffer
+    /// to `addr` can always be resolved, like x64 `call` displacements or aarch64 `adrp` references into the host binary.
+    /// This is currently only supported on Linux and Android.
+    ///
+    /// Like with `with_reservation`, memory is only committed as code is added to the buffer, and the buffer never moves.
+    /// Committing more than `capacity` bytes of code returns `DynasmError::BufferFull`.
+    pub fn new_near(addr: usize, capacity: usize) -> io::Result<Self> {
+        let memory_size =
+            capacity
+                .checked_next_multiple_of(R::page_size())
+                .ok_or(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "Capacity is too large",
+                ))?;
+
+        // the whole buffer has to lie within this distance, so that references from its far end still reach `addr`.
+        let distance =
+            (i32::MAX as usize)
+                .checked_sub(memory_size)
+                .ok_or(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "Capacity is too large",
+                ))?;
+
+        Ok(Self {
+            ops: Vec::new(),
+            memory: MemoryManager::new_near(memory_size, addr, distance)?,
+            labels: LabelRegistry::new(),
+            relocs: RelocRegistry::new(),
+            managed: ManagedRelocs::new(),
+            pool: Box::new(PendingLitPool::new()),
+            error: None
+        })
+    }
+
+    /// Create a new, empty assembler that uses dual-mapped memory, with initial allocation size `page_size`.
+    /// The executable memory of this assembler is mapped a second time as writable memory, which is used
+    /// to commit and alter code. This means that, unless the buffer has to grow, `commit` does not have to
+    /// wait for `Executor` instances to release their locks, as new code is only ever appended after what they
+    /// can see. `alter` still waits for them, but doesn't change the protection of the memory. No memory is
+    /// mapped as both writable and executable at the same time. This is currently only supported on Linux and Android.
+    ///
+    /// As `alter` doesn't make the memory inaccessible, threads that execute code without holding a lock
+    /// from an `Executor` are not stopped while it is modified. The user is responsible for ensuring that
+    /// no code is modified while it is being executed in this way.
+    pub fn new_dual_mapped() -> io::Result<Self> {
+        Ok(Self {
+            ops: Vec::new(),
+            memory: MemoryManager::new_dual_mapped(R::page_size())?,
+            labels: LabelRegistry::new(),
+            relocs: RelocRegistry::new(),
+            managed: ManagedRelocs::new(),
+            pool: Box::new(PendingLitPool::new()),
+            error: None
+        })
+    }
+
     /// Create a new dynamic label ID
     pub fn new_dynamic_label(&mut self) -> DynamicLabel {
         self.labels.new_dynamic_label()
@@ -618,54 +853,60 @@ impl<R: Relocation> Assembler<R> {
     }
 
     /// Use a `Modifier` to alter committed code directly. While this is happening
-    /// no code can be executed as the relevant pages are remapped as writable.
+    /// no code can be executed as the relevant pages are remapped as writable,
+    /// unless this assembler was created with `new_dual_mapped`. In both cases this
+    /// waits until no `Executor` holds a lock on the code.
     /// This API supports defining new labels/relocations, and overwriting previously defined relocations.
     pub fn alter<F, O>(&mut self, f: F) -> Result<O, DynasmError>
     where F: FnOnce(&mut Modifier<R>) -> O {
         self.commit()?;
 
-        // swap out a buffer from base
-        let mut lock = self.memory.write();
-        let buffer = mem::replace(&mut *lock, ExecutableBuffer::default());
-        let mut buffer = buffer.make_mut().expect("Could not swap buffer protection modes");
+        let buffer_addr = self.memory.execbuffer_addr();
+        let labels = &mut self.labels;
+        let relocs = &mut self.relocs;
+        let managed = &mut self.managed;
 
-        // construct the modifier
-        let mut modifier = Modifier {
-            asmoffset: 0,
-            previous_asmoffset: 0,
-            buffer: &mut buffer,
+        self.memory.modify(|buffer| {
+            // construct the modifier
+            let mut modifier = Modifier {
+                asmoffset: 0,
+                previous_asmoffset: 0,
+                buffer,
+                buffer_addr,
 
-            labels: &mut self.labels,
-            relocs: &mut self.relocs,
-            old_managed: &mut self.managed,
-            new_managed: ManagedRelocs::new(),
-
-            error: None
-        };
+                labels,
+                relocs,
+                old_managed: managed,
+                new_managed: ManagedRelocs::new(),
 
-        // execute the user code
-        let output = f(&mut modifier);
+                error: None
+            };
 
-        // flush any changes made by the user code to the buffer
-        modifier.encode_relocs()?;
+            // execute the user code
+            let output = f(&mut modifier);
 
-        // repack the buffer
-        let buffer = buffer.make_exec().expect("Could not swap buffer protection modes");
-        *lock = buffer;
+            // flush any changes made by the user code to the buffer
+            modifier.encode_relocs()?;
 
-        // call it a day
-        Ok(output)
+            // call it a day
+            Ok(output)
+        })
     }
 
     /// Commit code, flushing the temporary internal assembling buffer to the mapped executable memory.
     /// This makes assembled code available for execution.
     pub fn commit(&mut self) -> Result<(), DynasmError> {
+        // Emit any pending literal pool entries so their references can be resolved
+        let mut pool = mem::take(&mut *self.pool);
+        pool.emit_behind_jump(self);
+        *self.pool = pool;
+
         self.encode_relocs()?;
 
         let managed = &self.managed;
         let error = &mut self.error;
 
-        self.memory.commit(&mut self.ops, |buffer, old_addr, new_addr| {
+        self.memory.try_commit(&mut self.ops, |buffer, old_addr, new_addr| {
             let change = new_addr.wrapping_sub(old_addr) as isize;
 
             for reloc in managed.iter() {
@@ -677,7 +918,7 @@ impl<R: Relocation> Assembler<R> {
                 // we don't need to inform the cache here that we changed something
                 // as the entire allocation is new.
             }
-        });
+        })?;
 
         if let Some(e) = self.error.take() {
             return Err(e);
@@ -761,6 +1002,13 @@ impl<R: Relocation> Assembler<R> {
 
         Ok(())
     }
+
+    /// Emits the pending literal pool if it is getting out of range. Called at labels and alignment directives.
+    fn pool_boundary(&mut self) {
+        let mut pool = mem::take(&mut *self.pool);
+        pool.emit_if_due(self);
+        *self.pool = pool;
+    }
 }
 
 impl<R: Relocation> Extend<u8> for Assembler<R> {
@@ -785,6 +1033,8 @@ impl<R: Relocation> DynasmApi for Assembler<R> {
     }
 
     fn align(&mut self, alignment: usize, with: u8) {
+        self.pool_boundary();
+
         let misalign = self.offset().0 % alignment;
         if misalign != 0 {
             for _ in misalign .. alignment {
@@ -798,16 +1048,19 @@ impl<R: Relocation> DynasmLabelApi for Assembler<R> {
     type Relocation = R;
 
     fn local_label(&mut self, name: &'static str) {
+        self.pool_boundary();
         let offset = self.offset();
         self.labels.define_local(name, offset);
     }
     fn global_label( &mut self, name: &'static str) {
+        self.pool_boundary();
         let offset = self.offset();
         if let Err(e) = self.labels.define_global(name, offset) {
             self.error = Some(e)
         }
     }
     fn dynamic_label(&mut self, id: DynamicLabel) {
+        self.pool_boundary();
         let offset = self.offset();
         if let Err(e) = self.labels.define_dynamic(id, offset) {
             self.error = Some(e)
@@ -853,6 +1106,42 @@ impl<R: Relocation> DynasmLabelApi for Assembler<R> {
     }
 }
 
+impl<R: Relocation> DynasmLitPoolApi for Assembler<R> {
+    fn pool_u32(&mut self, value: u32, field_offset: u8, ref_offset: u8, kind: R::Encoding) {
+        let location = self.offset();
+        let label = self.pool.push_value(&mut self.labels, u64::from(value), RelocationSize::DWord, location.0 - ref_offset as usize);
+        self.dynamic_reloc(label, 0, field_offset, ref_offset, kind);
+    }
+    fn pool_u64(&mut self, value: u64, field_offset: u8, ref_offset: u8, kind: R::Encoding) {
+        let location = self.offset();
+        let label = self.pool.push_value(&mut self.labels, value, RelocationSize::QWord, location.0 - ref_offset as usize);
+        self.dynamic_reloc(label, 0, field_offset, ref_offset, kind);
+    }
+    fn pool_global_reloc(&mut self, name: &'static str, target_offset: isize, size: RelocationSize, field_offset: u8, ref_offset: u8, kind: R::Encoding) {
+        let location = self.offset();
+        let label = self.pool.push_global(&mut self.labels, name, target_offset, size, location.0 - ref_offset as usize);
+        self.dynamic_reloc(label, 0, field_offset, ref_offset, kind);
+    }
+    fn pool_dynamic_reloc(&mut self, id: DynamicLabel, target_offset: isize, size: RelocationSize, field_offset: u8, ref_offset: u8, kind: R::Encoding) {
+        let location = self.offset();
+        let label = self.pool.push_dynamic(&mut self.labels, id, target_offset, size, location.0 - ref_offset as usize);
+        self.dynamic_reloc(label, 0, field_offset, ref_offset, kind);
+    }
+    fn pool_flush(&mut self) {
+        let mut pool = mem::take(&mut *self.pool);
+        pool.emit(self);
+        *self.pool = pool;
+    }
+    fn pool_set_jump(&mut self, jump: fn(usize) -> u32, range: usize) {
+        self.pool.set_jump(jump, range);
+    }
+    fn pool_is_due(&self, offset: AssemblyOffset, range: usize) -> bool {
+        self.pool.is_due(offset.0, range)
+    }
+    fn pool_len(&self, offset: AssemblyOffset) -> usize {
+        self.pool.len(offset.0)
+    }
+}
 
 /// Allows modification of already committed assembly code. Contains an internal cursor
 /// into the emitted assembly, initialized to the start, that can be moved around either with the
@@ -862,6 +1151,8 @@ pub struct Modifier<'a, R: Relocation> {
     asmoffset: usize,
     previous_asmoffset: usize,
     buffer: &'a mut [u8],
+    // the address the buffer is executed at, which differs from its own address when it is dual-mapped
+    buffer_addr: usize,
 
     labels: &'a mut LabelRegistry,
     relocs: &'a mut RelocRegistry<R>,
@@ -905,7 +1196,7 @@ impl<'a, R: Relocation> Modifier<'a, R> {
 
     // encode uncommited relocations. also, invalidate the icache
     fn encode_relocs(&mut self) -> Result<(), DynasmError> {
-        let buf_addr = self.buffer.as_ptr() as usize;
+        let buf_addr = self.buffer_addr;
 
         // resync the caches of anything we modified before
         cache_control::synchronize_icache(&self.buffer[self.previous_asmoffset .. self.asmoffset]);
@@ -1050,7 +1341,7 @@ impl<'a, R: Relocation> DynasmLabelApi for Modifier<'a, R> {
     fn bare_relocation(&mut self, target: usize, field_offset: u8, ref_offset: u8, kind: R) {
         let location = self.offset();
         let loc = PatchLoc::new(location, 0, field_offset, ref_offset, kind);
-            let buf_addr = self.buffer.as_ptr() as usize;
+        let buf_addr = self.buffer_addr;
         let buf = &mut self.buffer[loc.range(0)];
         if loc.patch(buf, buf_addr, target).is_err() {
             self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Extern(target)));
@@ -0,0 +1,347 @@
+//! Runtime support for the 64-bit LoongArch architecture assembling target.
+//!
+//! The LoongArch instruction set features fixed 32-bit width instructions. It features relocations
+//! up to 26 bits in size in a single instruction, or 32 bits in size using a `pcaddu12i` instruction
+//! paired with an instruction that supplies the lower 12 bits.
+//!
+//! The core relocation behaviour for this architecture is provided by the [`Loongarch64Relocation`] type.
+//!
+//! Next to that, this module contains the following:
+//!
+//! ## Type aliases
+//!
+//! Several specialized type aliases of the generic [`Assembler`] are provided as these are by far the most common usecase.
+//!
+//! ## Enums
+//!
+//! There are enumerations of every LoongArch register family.
+//! These enums implement the [`Register`] trait and their discriminant values match their numeric encoding in dynamic register literals.
+//!
+//! *Note: The presence of some registers listed here is purely what is encodable. Check the relevant architecture documentation to find what is architecturally valid.*
+//!
+//! ## Functions
+//!
+//! This module contains handlers for error conditions in the case where a dynamically selected register is invalid, or a dynamically encoded immediate is out of range.
+//! These panic with a friendly error message if any of these conditions happen at runtime.
+
+use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation, fits_signed_bitfield};
+use byteorder::{ByteOrder, LittleEndian};
+use std::convert::TryFrom;
+use crate::Register;
+
+/// Relocation implementation for the LoongArch64 architecture.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub enum Loongarch64Relocation {
+    // b, bl
+    // 26 bits, 2-bit scaled
+    B26,
+    // beq, bne, blt, bge, bltu, bgeu
+    // 16 bits, 2-bit scaled
+    B16,
+    // beqz, bnez, bceqz, bcnez
+    // 21 bits, 2-bit scaled
+    B21,
+    // pcaddu12i
+    // 32 bits, 12-bit scaled
+    HI20,
+    // loads, stores, addi.w, addi.d
+    // 12 bits, no scaling
+    LO12,
+    // pc-relative address generation pseudo instructions, pcaddu12i + addi.d
+    // 32 bits, no scaling
+    SPLIT32,
+    // Anything in directives
+    Plain(RelocationSize),
+}
+
+impl Loongarch64Relocation {
+    fn bitsize(&self) -> (u8, u8) {
+        match self {
+            Self::B26 => (28, 2),
+            Self::B16 => (18, 2),
+            Self::B21 => (23, 2),
+
+            Self::HI20 => (32, 0),
+            Self::LO12 => (32, 0),
+
+            Self::SPLIT32 => (32, 0),
+            Self::Plain(s) => ((s.size() * 8) as u8, 0)
+        }
+    }
+}
+
+impl Relocation for Loongarch64Relocation {
+    type Encoding = (u8,);
+    fn from_encoding(encoding: Self::Encoding) -> Self {
+        match encoding.0 {
+            0 => Self::B26,
+            1 => Self::B16,
+            2 => Self::B21,
+            3 => Self::HI20,
+            4 => Self::LO12,
+            5 => Self::SPLIT32,
+            x  => Self::Plain(RelocationSize::from_encoding(x - 8))
+        }
+    }
+    fn from_size(size: RelocationSize) -> Self {
+        Self::Plain(size)
+    }
+    fn size(&self) -> usize {
+        match self {
+            Self::B26
+            | Self::B16
+            | Self::B21
+            | Self::HI20
+            | Self::LO12 => 4,
+            Self::SPLIT32 => 8,
+            Self::Plain(s) => s.size(),
+        }
+    }
+    fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        // determine if the value fits
+        let value = i64::try_from(value).map_err(|_| ImpossibleRelocation { } )?;
+
+        let (bits, scaling) = self.bitsize();
+        let mask = (1i64 << scaling) - 1;
+        // just like on RISC-V, the lower 12 bits are sign extended before being added
+        // to the upper 20 bits, so pcaddu12i-based offsets range from -0x8000_0800 to 0x7FFF_F7FF.
+        match self {
+            Self::HI20
+            | Self::LO12
+            | Self::SPLIT32 => {
+                if !(-0x8000_0800..=0x7FFF_F7FF).contains(&value) {
+                    return Err(ImpossibleRelocation { } );
+                }
+            },
+            _ => {
+                if !fits_signed_bitfield(value, bits) || (value & mask) != 0 {
+                    return Err(ImpossibleRelocation { } );
+                }
+            }
+        }
+
+        // we never encode any bit above the 31st so cast now
+        let val_cast = value as u32;
+
+        match self {
+            Self::Plain(s) => s.write_value(buf, value as isize)?,
+            Self::B26 => {
+                let mut instr = LittleEndian::read_u32(buf);
+                instr &= 0xFC00_0000;
+
+                instr |= ((val_cast >> 2) & 0xFFFF) << 10;
+                instr |= (val_cast >> 18) & 0x3FF;
+
+                LittleEndian::write_u32(buf, instr);
+            },
+            Self::B16 => {
+                let mut instr = LittleEndian::read_u32(buf);
+                instr &= 0xFC00_03FF;
+
+                instr |= ((val_cast >> 2) & 0xFFFF) << 10;
+
+                LittleEndian::write_u32(buf, instr);
+            },
+            Self::B21 => {
+                let mut instr = LittleEndian::read_u32(buf);
+                instr &= 0xFC00_03E0;
+
+                instr |= ((val_cast >> 2) & 0xFFFF) << 10;
+                instr |= (val_cast >> 18) & 0x1F;
+
+                LittleEndian::write_u32(buf, instr);
+            },
+            Self::HI20 => {
+                let mut instr = LittleEndian::read_u32(buf);
+                instr &= 0xFE00_001F;
+
+                let val_round: u32 = val_cast.wrapping_add(0x800);
+                instr |= (val_round >> 12) << 5;
+
+                LittleEndian::write_u32(buf, instr);
+            },
+            Self::LO12 => {
+                let mut instr = LittleEndian::read_u32(buf);
+                instr &= 0xFFC0_03FF;
+
+                instr |= (val_cast & 0xFFF) << 10;
+
+                LittleEndian::write_u32(buf, instr);
+            },
+            Self::SPLIT32 => {
+                let mut instr1 = LittleEndian::read_u32(&buf[..4]);
+                let mut instr2 = LittleEndian::read_u32(&buf[4..]);
+                instr1 &= 0xFE00_001F;
+                instr2 &= 0xFFC0_03FF;
+
+                let val_round: u32 = val_cast.wrapping_add(0x800);
+                instr1 |= (val_round >> 12) << 5;
+                instr2 |= (val_cast & 0xFFF) << 10;
+
+                LittleEndian::write_u32(&mut buf[..4], instr1);
+                LittleEndian::write_u32(&mut buf[4..], instr2);
+            },
+        };
+
+        Ok(())
+    }
+    fn read_value(&self, buf: &[u8]) -> isize {
+        let bits;
+        let mut unpacked;
+
+        match self {
+            Self::Plain(s) => {
+                return s.read_value(buf)
+            },
+            Self::B26 => {
+                bits = 28;
+                let instr = LittleEndian::read_u32(buf);
+
+                unpacked = ((instr >> 10) & 0xFFFF) << 2;
+                unpacked |= (instr & 0x3FF) << 18;
+            },
+            Self::B16 => {
+                bits = 18;
+                let instr = LittleEndian::read_u32(buf);
+
+                unpacked = ((instr >> 10) & 0xFFFF) << 2;
+            },
+            Self::B21 => {
+                bits = 23;
+                let instr = LittleEndian::read_u32(buf);
+
+                unpacked = ((instr >> 10) & 0xFFFF) << 2;
+                unpacked |= (instr & 0x1F) << 18;
+            },
+            Self::HI20 => {
+                bits = 32;
+                let instr = LittleEndian::read_u32(buf);
+
+                unpacked = ((instr >> 5) & 0xFFFFF) << 12;
+                // as on RISC-V, the lower bits of the value aren't known here. As only relative
+                // relocations are supported, this value is never adjusted so that's fine.
+            },
+            Self::LO12 => {
+                bits = 12;
+                let instr = LittleEndian::read_u32(buf);
+
+                unpacked = (instr >> 10) & 0xFFF;
+            },
+            Self::SPLIT32 => {
+                bits = 32;
+                let instr1 = LittleEndian::read_u32(&buf[..4]);
+                let instr2 = LittleEndian::read_u32(&buf[4..]);
+
+                unpacked = ((instr1 >> 5) & 0xFFFFF) << 12;
+                let mut lower: u32 = (instr2 >> 10) & 0xFFF;
+
+                // sign extend the lower part and then add them
+                lower = (lower ^ 0x800).wrapping_sub(0x800);
+                unpacked = unpacked.wrapping_add(lower)
+            },
+        }
+
+        // sign extension
+        let offset = 1u64 << (bits - 1);
+        let value: u64 = (unpacked as u64 ^ offset).wrapping_sub(offset);
+
+        value as i64 as isize
+    }
+    fn kind(&self) -> RelocationKind {
+        RelocationKind::Relative
+    }
+    fn page_size() -> usize {
+        4096
+    }
+}
+
+/// A LoongArch64 Assembler. This is aliased here for backwards compatability.
+pub type Assembler = crate::Assembler<Loongarch64Relocation>;
+/// A LoongArch64 AssemblyModifier. This is aliased here for backwards compatability.
+pub type AssemblyModifier<'a> = crate::Modifier<'a, Loongarch64Relocation>;
+/// A LoongArch64 UncommittedModifier. This is aliased here for backwards compatability.
+pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
+
+// these should explicitly never be inlined, as this is the slow path.
+// that's also why these aren't made generic.
+
+/// Handler for `u32` out-of-range loongarch64 immediates.
+#[inline(never)]
+pub fn immediate_out_of_range_unsigned_32(immediate: u32) -> ! {
+    panic!("Cannot assemble this LoongArch instruction. Immediate {immediate} is out of range.")
+}
+
+/// Handler for `i32` out-of-range loongarch64 immediates.
+#[inline(never)]
+pub fn immediate_out_of_range_signed_32(immediate: i32) -> ! {
+    panic!("Cannot assemble this LoongArch instruction. Immediate {immediate} is out of range.")
+}
+/// Handler for `u64` out-of-range loongarch64 immediates.
+#[inline(never)]
+pub fn immediate_out_of_range_unsigned_64(immediate: u64) -> ! {
+    panic!("Cannot assemble this LoongArch instruction. Immediate {immediate} is out of range.")
+}
+
+/// Handler for `i64` out-of-range loongarch64 immediates.
+#[inline(never)]
+pub fn immediate_out_of_range_signed_64(immediate: i64) -> ! {
+    panic!("Cannot assemble this LoongArch instruction. Immediate {immediate} is out of range.")
+}
+
+
+/// 8-byte general purpose registers, where R0 is the zero register
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RR {
+    R0 = 0x00, R1 = 0x01, R2 = 0x02, R3 = 0x03,
+    R4 = 0x04, R5 = 0x05, R6 = 0x06, R7 = 0x07,
+    R8 = 0x08, R9 = 0x09, R10= 0x0A, R11= 0x0B,
+    R12= 0x0C, R13= 0x0D, R14= 0x0E, R15= 0x0F,
+    R16= 0x10, R17= 0x11, R18= 0x12, R19= 0x13,
+    R20= 0x14, R21= 0x15, R22= 0x16, R23= 0x17,
+    R24= 0x18, R25= 0x19, R26= 0x1A, R27= 0x1B,
+    R28= 0x1C, R29= 0x1D, R30= 0x1E, R31= 0x1F,
+}
+reg_impls!(RR);
+
+/// 4 or 8-byte floating point registers
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RF {
+    F0 = 0x00, F1 = 0x01, F2 = 0x02, F3 = 0x03,
+    F4 = 0x04, F5 = 0x05, F6 = 0x06, F7 = 0x07,
+    F8 = 0x08, F9 = 0x09, F10= 0x0A, F11= 0x0B,
+    F12= 0x0C, F13= 0x0D, F14= 0x0E, F15= 0x0F,
+    F16= 0x10, F17= 0x11, F18= 0x12, F19= 0x13,
+    F20= 0x14, F21= 0x15, F22= 0x16, F23= 0x17,
+    F24= 0x18, F25= 0x19, F26= 0x1A, F27= 0x1B,
+    F28= 0x1C, F29= 0x1D, F30= 0x1E, F31= 0x1F,
+}
+reg_impls!(RF);
+
+/// Floating point condition flag registers, as set by `fcmp` and used by `bceqz`, `bcnez` and `fsel`
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RFCC {
+    FCC0 = 0x00, FCC1 = 0x01, FCC2 = 0x02, FCC3 = 0x03,
+    FCC4 = 0x04, FCC5 = 0x05, FCC6 = 0x06, FCC7 = 0x07,
+}
+reg_impls!(RFCC);
+
+
+#[cfg(test)]
+mod tests {
+    use super::RR::*;
+    use crate::Register;
+
+    #[test]
+    fn reg_code() {
+        assert_eq!(R3.code(), 3);
+    }
+
+    #[test]
+    fn reg_code_from() {
+        assert_eq!(u8::from(R24), 0x18);
+    }
+}
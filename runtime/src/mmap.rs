@@ -2,20 +2,25 @@
 // Unfortunately Memmap itself doesn't support a cheap zero-length variant
 
 use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::fs::File;
 use std::io;
 
-use memmap2::{Mmap, MmapMut};
+use memmap2::{Mmap, MmapMut, MmapOptions};
 
 use crate::AssemblyOffset;
 
+use self::fixed::FixedMap;
+
 /// A structure holding a buffer of executable memory. It also derefs to a `&[u8]`.
 /// This structure does not allocate when its size is 0.
 #[derive(Debug, Default)]
 pub struct ExecutableBuffer {
-    // length of the buffer that has actually been written to
-    length: usize,
+    // length of the buffer that has actually been written to. This is atomic so it can be
+    // updated while the buffer is shared with executing threads when it is dual-mapped.
+    length: AtomicUsize,
     // backing buffer
-    buffer: Option<Mmap>
+    buffer: Option<ExecMap>
 }
 
 /// ExecutableBuffer equivalent that holds a buffer of mutable memory instead of executable memory. It also derefs to a `&mut [u8]`.
@@ -25,7 +30,22 @@ pub struct MutableBuffer {
     // length of the buffer that has actually been written to
     length: usize,
     // backing buffer
-    buffer: Option<MmapMut>
+    buffer: Option<MutMap>
+}
+
+// backing memory of an `ExecutableBuffer`. Normally this is managed by memmap2, but buffers that reserve
+// their address space up front, possibly at a specific address, are mapped by us.
+#[derive(Debug)]
+enum ExecMap {
+    Mmap(Mmap),
+    Fixed(FixedMap)
+}
+
+// backing memory of a `MutableBuffer`
+#[derive(Debug)]
+enum MutMap {
+    Mmap(MmapMut),
+    Fixed(FixedMap)
 }
 
 impl ExecutableBuffer {
@@ -51,18 +71,98 @@ impl ExecutableBuffer {
         let buffer = if size == 0 {
             None
         } else {
-            Some(MmapMut::map_anon(size)?.make_exec()?)
+            Some(ExecMap::Mmap(MmapMut::map_anon(size)?.make_exec()?))
         };
 
         Ok(ExecutableBuffer {
-            length: 0,
+            length: AtomicUsize::new(0),
+            buffer
+        })
+    }
+
+    /// Create a new executable buffer of size `size`, located such that the entire buffer is at most `distance` bytes away
+    /// from the address `hint`. It will start with an initialized length of 0.
+    ///
+    /// This is useful when the code in the buffer needs to reference code or data at `hint` with a relative relocation of limited
+    /// range, like the 32-bit displacement of x64 calls. The free address ranges closest to `hint` are found using `/proc/self/maps`,
+    /// after which the buffer is mapped at a fixed address. As with `new_reserved`, memory is only committed for the parts of the
+    /// buffer that are written to. This is currently only supported on Linux and Android.
+    pub fn new_near(size: usize, hint: usize, distance: usize) -> io::Result<ExecutableBuffer> {
+        let buffer = if size == 0 {
+            None
+        } else {
+            Some(ExecMap::Fixed(FixedMap::map_near(size, hint, distance)?))
+        };
+
+        Ok(ExecutableBuffer {
+            length: AtomicUsize::new(0),
             buffer
         })
     }
 
+    /// Create a new executable buffer that reserves `size` bytes of address space up front.
+    /// The reservation is not accessible at first. Memory is only committed, and its protection
+    /// changed, for the parts of the reservation that are written to. It will start with an initialized length of 0.
+    pub fn new_reserved(size: usize) -> io::Result<ExecutableBuffer> {
+        let buffer = if size == 0 {
+            None
+        } else {
+            Some(ExecMap::Fixed(FixedMap::reserve(size)?))
+        };
+
+        Ok(ExecutableBuffer {
+            length: AtomicUsize::new(0),
+            buffer
+        })
+    }
+
+    /// Create a new executable buffer of size `size`, together with a mutable buffer that maps
+    /// the same memory at a different address. Both will start with an initialized length of 0.
+    ///
+    /// Data written into the mutable buffer immediately becomes visible in the executable buffer,
+    /// without ever changing the protection of either mapping. The length of the executable buffer
+    /// is then updated by the assembler while it is being read from. This is currently only
+    /// supported on Linux and Android, where the memory is backed by a `memfd`.
+    ///
+    /// # Safety
+    ///
+    /// As both buffers refer to the same memory, the caller must ensure that no bytes are written through
+    /// the mutable buffer while they are borrowed through the executable buffer. Only the bytes within the
+    /// length of the executable buffer can be borrowed through it.
+    pub unsafe fn new_dual_mapped(size: usize) -> io::Result<(ExecutableBuffer, MutableBuffer)> {
+        if size == 0 {
+            return Ok((ExecutableBuffer::default(), MutableBuffer::default()));
+        }
+
+        let file = anonymous_file(size)?;
+
+        // safety: the file is private to us, so nobody else can truncate it while it is mapped.
+        let execbuffer = unsafe { MmapOptions::new().len(size).map_exec(&file)? };
+        let mutbuffer = unsafe { MmapOptions::new().len(size).map_mut(&file)? };
+
+        Ok((
+            ExecutableBuffer {
+                length: AtomicUsize::new(0),
+                buffer: Some(ExecMap::Mmap(execbuffer))
+            },
+            MutableBuffer {
+                length: 0,
+                buffer: Some(MutMap::Mmap(mutbuffer))
+            }
+        ))
+    }
+
     /// Query the backing size of this executable buffer
     pub fn size(&self) -> usize {
-        self.buffer.as_ref().map(|b| b.len()).unwrap_or(0)
+        self.buffer.as_ref().map(|b| b.size()).unwrap_or(0)
+    }
+
+    /// Set the length of the usable part of this executable buffer. Unlike `MutableBuffer::set_len`
+    /// this can be done while the buffer is shared, which is used to make code written through a
+    /// dual-mapped `MutableBuffer` available. The length should not be set larger than the
+    /// allocated size, otherwise methods can panic.
+    pub(crate) fn set_len(&self, length: usize) {
+        self.length.store(length, Ordering::Release);
     }
 
     /// Change this executable buffer into a mutable buffer.
@@ -74,7 +174,7 @@ impl ExecutableBuffer {
         };
 
         Ok(MutableBuffer {
-            length: self.length,
+            length: self.length.into_inner(),
             buffer
         })
     }
@@ -87,7 +187,7 @@ impl MutableBuffer {
         let buffer = if size == 0 {
             None
         } else {
-            Some(MmapMut::map_anon(size)?)
+            Some(MutMap::Mmap(MmapMut::map_anon(size)?))
         };
 
         Ok(MutableBuffer {
@@ -98,7 +198,7 @@ impl MutableBuffer {
 
     /// Query the backing size of this mutable buffer
     pub fn size(&self) -> usize {
-        self.buffer.as_ref().map(|b| b.len()).unwrap_or(0)
+        self.buffer.as_ref().map(|b| b.size()).unwrap_or(0)
     }
 
     /// Set the length of the usable part of this mutable buffer. The length
@@ -107,6 +207,15 @@ impl MutableBuffer {
         self.length = length
     }
 
+    /// Make sure that memory is committed for the first `length` bytes of this buffer, if it was created
+    /// from a reservation. This has to happen before its length is set beyond what was committed before.
+    pub(crate) fn commit(&mut self, length: usize) -> io::Result<()> {
+        match &mut self.buffer {
+            Some(MutMap::Fixed(map)) => map.commit(length),
+            _ => Ok(())
+        }
+    }
+
     /// Change this mutable buffer into an executable buffer.
     pub fn make_exec(self) -> io::Result<ExecutableBuffer> {
         let buffer = if let Some(map) = self.buffer {
@@ -116,17 +225,440 @@ impl MutableBuffer {
         };
 
         Ok(ExecutableBuffer {
-            length: self.length,
+            length: AtomicUsize::new(self.length),
             buffer
         })
     }
 }
 
+/// Creates an anonymous file of `size` bytes, that only lives in memory.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn anonymous_file(size: usize) -> io::Result<File> {
+    use std::os::unix::io::FromRawFd;
+
+    // safety: the name is a valid nul-terminated string
+    let fd = unsafe { libc::memfd_create(c"dynasmrt".as_ptr(), libc::MFD_CLOEXEC) };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    // safety: we just created this file descriptor, so we own it
+    let file = unsafe { File::from_raw_fd(fd) };
+    file.set_len(size as u64)?;
+    Ok(file)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn anonymous_file(_size: usize) -> io::Result<File> {
+    Err(io::Error::new(io::ErrorKind::Unsupported, "Dual-mapped buffers are only supported on Linux and Android"))
+}
+
+impl ExecMap {
+    fn make_mut(self) -> io::Result<MutMap> {
+        match self {
+            ExecMap::Mmap(map) => Ok(MutMap::Mmap(map.make_mut()?)),
+            ExecMap::Fixed(map) => {
+                map.make_mut()?;
+                Ok(MutMap::Fixed(map))
+            }
+        }
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            ExecMap::Mmap(map) => map.len(),
+            ExecMap::Fixed(map) => map.size()
+        }
+    }
+}
+
+impl MutMap {
+    fn make_exec(self) -> io::Result<ExecMap> {
+        match self {
+            MutMap::Mmap(map) => Ok(ExecMap::Mmap(map.make_exec()?)),
+            MutMap::Fixed(map) => {
+                map.make_exec()?;
+                Ok(ExecMap::Fixed(map))
+            }
+        }
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            MutMap::Mmap(map) => map.len(),
+            MutMap::Fixed(map) => map.size()
+        }
+    }
+}
+
+impl Deref for ExecMap {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        match self {
+            ExecMap::Mmap(map) => map,
+            ExecMap::Fixed(map) => map
+        }
+    }
+}
+
+impl Deref for MutMap {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        match self {
+            MutMap::Mmap(map) => map,
+            MutMap::Fixed(map) => map
+        }
+    }
+}
+
+impl DerefMut for MutMap {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        match self {
+            MutMap::Mmap(map) => map,
+            MutMap::Fixed(map) => map
+        }
+    }
+}
+
+mod fixed {
+    use std::ops::{Deref, DerefMut};
+    use std::{io, slice};
+
+    use super::sys;
+
+    /// An anonymous memory mapping that is managed by us. The address space of the whole mapping is reserved
+    /// up front, but only a prefix of it is backed by memory and accessible. The rest is inaccessible until it
+    /// is committed.
+    #[derive(Debug)]
+    pub struct FixedMap {
+        ptr: *mut u8,
+        len: usize,
+        // length of the accessible prefix of the mapping, a multiple of the page size
+        committed: usize
+    }
+
+    // safety: like the mappings of memmap2, the memory is owned by this struct.
+    unsafe impl Send for FixedMap {}
+    unsafe impl Sync for FixedMap {}
+
+    impl FixedMap {
+        /// Reserves `len` bytes of address space anywhere in memory. Nothing is accessible until it is committed.
+        pub fn reserve(len: usize) -> io::Result<FixedMap> {
+            let len = len.checked_next_multiple_of(sys::page_size()).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Size is too large"))?;
+            let ptr = sys::reserve(len)?;
+
+            Ok(FixedMap {
+                ptr,
+                len,
+                committed: 0
+            })
+        }
+
+        /// Reserves `len` bytes of address space, such that the whole mapping is at most `distance` bytes away from `hint`.
+        /// Tries the free address ranges closest to `hint` first. Nothing is accessible until it is committed.
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        pub fn map_near(len: usize, hint: usize, distance: usize) -> io::Result<FixedMap> {
+            let page_size = sys::page_size();
+            let len = len.checked_next_multiple_of(page_size).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Size is too large"))?;
+
+            let lower = hint.saturating_sub(distance);
+            let upper = hint.saturating_add(distance);
+
+            // find the gaps between all current mappings that overlap with the allowed range
+            let maps = std::fs::read_to_string("/proc/self/maps")?;
+            let mut candidates = Vec::new();
+            let mut gap_start = page_size;
+            for line in maps.lines() {
+                let range = line.split_whitespace().next().and_then(|range| range.split_once('-'));
+                let (start, end) = match range {
+                    Some((start, end)) => match (usize::from_str_radix(start, 16), usize::from_str_radix(end, 16)) {
+                        (Ok(start), Ok(end)) => (start, end),
+                        _ => continue
+                    },
+                    None => continue
+                };
+
+                candidates.extend(place_in_gap(gap_start, start, len, page_size, hint, lower, upper));
+                gap_start = gap_start.max(end);
+            }
+            candidates.extend(place_in_gap(gap_start, usize::MAX, len, page_size, hint, lower, upper));
+
+            candidates.sort_by_key(|&addr| addr.abs_diff(hint));
+
+            // other threads might be mapping memory as well, so just try them in order.
+            for addr in candidates {
+                // safety: MAP_FIXED_NOREPLACE ensures we never replace existing mappings.
+                let ptr = unsafe {
+                    libc::mmap(
+                        addr as *mut libc::c_void,
+                        len,
+                        libc::PROT_NONE,
+                        libc::MAP_PRIVATE | libc::MAP_ANONYMOUS | libc::MAP_FIXED_NOREPLACE,
+                        -1,
+                        0
+                    )
+                };
+
+                if ptr == libc::MAP_FAILED {
+                    continue;
+                }
+
+                // kernels before 4.17 treat MAP_FIXED_NOREPLACE as a hint, and might put the mapping elsewhere
+                if ptr as usize != addr {
+                    // safety: we just mapped this
+                    unsafe { libc::munmap(ptr, len) };
+                    continue;
+                }
+
+                return Ok(FixedMap {
+                    ptr: ptr as *mut u8,
+                    len,
+                    committed: 0
+                });
+            }
+
+            Err(io::Error::new(io::ErrorKind::OutOfMemory, "Could not find free memory near the requested address"))
+        }
+
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        pub fn map_near(_len: usize, _hint: usize, _distance: usize) -> io::Result<FixedMap> {
+            Err(io::Error::new(io::ErrorKind::Unsupported, "Allocating memory near an address is only supported on Linux and Android"))
+        }
+
+        /// Returns the size of the whole mapping
+        pub fn size(&self) -> usize {
+            self.len
+        }
+
+        /// Commits memory for the first `len` bytes of the mapping. This has to happen while the mapping is writable.
+        pub fn commit(&mut self, len: usize) -> io::Result<()> {
+            if len <= self.committed {
+                return Ok(());
+            }
+
+            let len = len.next_multiple_of(sys::page_size()).min(self.len);
+
+            // safety: this range lies within our reservation
+            unsafe { sys::commit(self.ptr.add(self.committed), len - self.committed)? };
+            self.committed = len;
+            Ok(())
+        }
+
+        /// Makes the committed part of the mapping readable and writable
+        pub fn make_mut(&self) -> io::Result<()> {
+            self.protect(false)
+        }
+
+        /// Makes the committed part of the mapping readable and executable
+        pub fn make_exec(&self) -> io::Result<()> {
+            self.protect(true)
+        }
+
+        fn protect(&self, exec: bool) -> io::Result<()> {
+            if self.committed == 0 {
+                return Ok(());
+            }
+
+            // safety: this range lies within our reservation
+            unsafe { sys::protect(self.ptr, self.committed, exec) }
+        }
+    }
+
+    // returns the address closest to `hint` where `len` bytes fit in the gap between `start` and `end`,
+    // as well as between `lower` and `upper`.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn place_in_gap(start: usize, end: usize, len: usize, page_size: usize, hint: usize, lower: usize, upper: usize) -> Option<usize> {
+        let start = start.max(lower).checked_next_multiple_of(page_size)?;
+        let end = end.min(upper) / page_size * page_size;
+
+        if end < start || end - start < len {
+            return None;
+        }
+
+        Some((hint / page_size * page_size).clamp(start, end - len))
+    }
+
+    impl Deref for FixedMap {
+        type Target = [u8];
+        fn deref(&self) -> &[u8] {
+            // safety: the committed part of the mapping is always readable
+            unsafe { slice::from_raw_parts(self.ptr, self.committed) }
+        }
+    }
+
+    impl DerefMut for FixedMap {
+        fn deref_mut(&mut self) -> &mut [u8] {
+            // safety: this is only used through `MutableBuffer`, when the mapping is writable
+            unsafe { slice::from_raw_parts_mut(self.ptr, self.committed) }
+        }
+    }
+
+    impl Drop for FixedMap {
+        fn drop(&mut self) {
+            // safety: we own this mapping
+            unsafe { sys::release(self.ptr, self.len) };
+        }
+    }
+}
+
+// platform specific functions to reserve address space and commit memory in it
+#[cfg(unix)]
+mod sys {
+    use std::io;
+
+    pub fn page_size() -> usize {
+        // safety: sysconf has no preconditions
+        unsafe { libc::sysconf(libc::_SC_PAGESIZE) as usize }
+    }
+
+    pub fn reserve(len: usize) -> io::Result<*mut u8> {
+        // safety: we don't ask for a specific address, so this cannot replace existing mappings.
+        let ptr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                len,
+                libc::PROT_NONE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                -1,
+                0
+            )
+        };
+
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(ptr as *mut u8)
+    }
+
+    // memory in anonymous mappings is allocated on first use, so committing only makes it accessible
+    pub unsafe fn commit(ptr: *mut u8, len: usize) -> io::Result<()> {
+        protect(ptr, len, false)
+    }
+
+    pub unsafe fn protect(ptr: *mut u8, len: usize, exec: bool) -> io::Result<()> {
+        let prot = if exec {
+            libc::PROT_READ | libc::PROT_EXEC
+        } else {
+            libc::PROT_READ | libc::PROT_WRITE
+        };
+
+        if libc::mprotect(ptr as *mut libc::c_void, len, prot) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    pub unsafe fn release(ptr: *mut u8, len: usize) {
+        libc::munmap(ptr as *mut libc::c_void, len);
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use std::ffi::c_void;
+    use std::{io, mem, ptr};
+
+    const MEM_COMMIT: u32 = 0x1000;
+    const MEM_RESERVE: u32 = 0x2000;
+    const MEM_RELEASE: u32 = 0x8000;
+    const PAGE_NOACCESS: u32 = 0x01;
+    const PAGE_READWRITE: u32 = 0x04;
+    const PAGE_EXECUTE_READ: u32 = 0x20;
+
+    #[repr(C)]
+    struct SystemInfo {
+        processor_architecture: u16,
+        reserved: u16,
+        page_size: u32,
+        minimum_application_address: *mut c_void,
+        maximum_application_address: *mut c_void,
+        active_processor_mask: usize,
+        number_of_processors: u32,
+        processor_type: u32,
+        allocation_granularity: u32,
+        processor_level: u16,
+        processor_revision: u16,
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn VirtualAlloc(address: *mut c_void, size: usize, allocation_type: u32, protect: u32) -> *mut c_void;
+        fn VirtualProtect(address: *mut c_void, size: usize, new_protect: u32, old_protect: *mut u32) -> i32;
+        fn VirtualFree(address: *mut c_void, size: usize, free_type: u32) -> i32;
+        fn GetSystemInfo(system_info: *mut SystemInfo);
+    }
+
+    pub fn page_size() -> usize {
+        // safety: GetSystemInfo fills in the whole struct
+        unsafe {
+            let mut info: SystemInfo = mem::zeroed();
+            GetSystemInfo(&mut info);
+            info.page_size as usize
+        }
+    }
+
+    pub fn reserve(len: usize) -> io::Result<*mut u8> {
+        // safety: we don't ask for a specific address, so this cannot affect existing allocations.
+        let ptr = unsafe { VirtualAlloc(ptr::null_mut(), len, MEM_RESERVE, PAGE_NOACCESS) };
+        if ptr.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(ptr as *mut u8)
+    }
+
+    pub unsafe fn commit(ptr: *mut u8, len: usize) -> io::Result<()> {
+        if VirtualAlloc(ptr as *mut c_void, len, MEM_COMMIT, PAGE_READWRITE).is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    pub unsafe fn protect(ptr: *mut u8, len: usize, exec: bool) -> io::Result<()> {
+        let prot = if exec { PAGE_EXECUTE_READ } else { PAGE_READWRITE };
+        let mut old = 0;
+        if VirtualProtect(ptr as *mut c_void, len, prot, &mut old) == 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    pub unsafe fn release(ptr: *mut u8, _len: usize) {
+        VirtualFree(ptr as *mut c_void, 0, MEM_RELEASE);
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod sys {
+    use std::io;
+
+    fn unsupported() -> io::Error {
+        io::Error::new(io::ErrorKind::Unsupported, "Reserving address space is not supported on this platform")
+    }
+
+    pub fn page_size() -> usize {
+        4096
+    }
+
+    pub fn reserve(_len: usize) -> io::Result<*mut u8> {
+        Err(unsupported())
+    }
+
+    pub unsafe fn commit(_ptr: *mut u8, _len: usize) -> io::Result<()> {
+        Err(unsupported())
+    }
+
+    pub unsafe fn protect(_ptr: *mut u8, _len: usize, _exec: bool) -> io::Result<()> {
+        Err(unsupported())
+    }
+
+    pub unsafe fn release(_ptr: *mut u8, _len: usize) {}
+}
+
 impl Deref for ExecutableBuffer {
     type Target = [u8];
     fn deref(&self) -> &[u8] {
         if let Some(map) = &self.buffer {
-            &map[..self.length]
+            &map[..self.length.load(Ordering::Acquire)]
         } else {
             &[]
         }
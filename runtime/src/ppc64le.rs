@@ -0,0 +1,345 @@
+//! Runtime support for the 64-bit little-endian PowerPC architecture assembling target.
+//!
+//! The PowerPC instruction set features fixed 32-bit width instructions. It features relocations
+//! up to 26 bits in size in a single instruction, or 32 bits in size using an `addis` or `addpcis`
+//! instruction paired with an instruction that supplies the lower 16 bits.
+//!
+//! The core relocation behaviour for this architecture is provided by the [`Ppc64leRelocation`] type.
+//!
+//! Next to that, this module contains the following:
+//!
+//! ## Type aliases
+//!
+//! Several specialized type aliases of the generic [`Assembler`] are provided as these are by far the most common usecase.
+//!
+//! ## Enums
+//!
+//! There are enumerations of every PowerPC register family.
+//! These enums implement the [`Register`] trait and their discriminant values match their numeric encoding in dynamic register literals.
+//!
+//! ## Functions
+//!
+//! This module contains handlers for error conditions in the case where a dynamically encoded immediate is out of range.
+//! These panic with a friendly error message if any of these conditions happen at runtime.
+
+use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation, fits_signed_bitfield};
+use byteorder::{ByteOrder, LittleEndian};
+use std::convert::TryFrom;
+use crate::Register;
+
+/// Relocation implementation for the little-endian PowerPC64 architecture.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub enum Ppc64leRelocation {
+    // b, bl
+    // 26 bits, 2-bit scaled
+    B24,
+    // bc and its extended mnemonics
+    // 16 bits, 2-bit scaled
+    B14,
+    // addis
+    // the high 16 bits of a 32-bit offset, adjusted for the sign of the low 16 bits
+    HA16,
+    // addi, D-form loads and stores
+    // the low 16 bits of a 32-bit offset
+    LO16,
+    // DS-form loads and stores
+    // the low 16 bits of a 32-bit offset, 2-bit scaled
+    LO16DS,
+    // addpcis
+    // like HA16, but split over the d0, d1 and d2 fields of the instruction
+    DXHA16,
+    // Anything in directives
+    Plain(RelocationSize),
+}
+
+impl Relocation for Ppc64leRelocation {
+    type Encoding = (u8,);
+    fn from_encoding(encoding: Self::Encoding) -> Self {
+        match encoding.0 {
+            0 => Self::B24,
+            1 => Self::B14,
+            2 => Self::HA16,
+            3 => Self::LO16,
+            4 => Self::LO16DS,
+            5 => Self::DXHA16,
+            x  => Self::Plain(RelocationSize::from_encoding(x - 8))
+        }
+    }
+    fn from_size(size: RelocationSize) -> Self {
+        Self::Plain(size)
+    }
+    fn size(&self) -> usize {
+        match self {
+            Self::B24
+            | Self::B14
+            | Self::HA16
+            | Self::LO16
+            | Self::LO16DS
+            | Self::DXHA16 => 4,
+            Self::Plain(s) => s.size(),
+        }
+    }
+    fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        // determine if the value fits
+        let value = i64::try_from(value).map_err(|_| ImpossibleRelocation { } )?;
+
+        match self {
+            Self::Plain(s) => return s.write_value(buf, value as isize),
+            Self::B24 => {
+                if !fits_signed_bitfield(value, 26) || (value & 3) != 0 {
+                    return Err(ImpossibleRelocation { } );
+                }
+            },
+            Self::B14 => {
+                if !fits_signed_bitfield(value, 16) || (value & 3) != 0 {
+                    return Err(ImpossibleRelocation { } );
+                }
+            },
+            // the lower 16 bits are sign extended before being added to the upper 16 bits,
+            // so these offsets range from -0x8000_8000 to 0x7FFF_7FFF.
+            Self::HA16
+            | Self::LO16
+            | Self::LO16DS
+            | Self::DXHA16 => {
+                if !(-0x8000_8000..=0x7FFF_7FFF).contains(&value) {
+                    return Err(ImpossibleRelocation { } );
+                }
+                if let Self::LO16DS = self {
+                    if (value & 3) != 0 {
+                        return Err(ImpossibleRelocation { } );
+                    }
+                }
+            }
+        }
+
+        // we never encode any bit above the 31st so cast now
+        let val_cast = value as u32;
+        let val_high = val_cast.wrapping_add(0x8000) >> 16;
+
+        let mut instr = LittleEndian::read_u32(buf);
+
+        match self {
+            Self::B24 => {
+                instr &= 0xFC00_0003;
+                instr |= val_cast & 0x03FF_FFFC;
+            },
+            Self::B14 => {
+                instr &= 0xFFFF_0003;
+                instr |= val_cast & 0xFFFC;
+            },
+            Self::HA16 => {
+                instr &= 0xFFFF_0000;
+                instr |= val_high;
+            },
+            Self::LO16 => {
+                instr &= 0xFFFF_0000;
+                instr |= val_cast & 0xFFFF;
+            },
+            Self::LO16DS => {
+                instr &= 0xFFFF_0003;
+                instr |= val_cast & 0xFFFC;
+            },
+            Self::DXHA16 => {
+                instr &= 0xFFE0_003E;
+                instr |= (val_high & 0xFFC0) | ((val_high & 0x3E) << 15) | (val_high & 0x1);
+            },
+            Self::Plain(_) => unreachable!(),
+        }
+
+        LittleEndian::write_u32(buf, instr);
+
+        Ok(())
+    }
+    fn read_value(&self, buf: &[u8]) -> isize {
+        let bits;
+        let unpacked;
+
+        let instr = match self {
+            Self::Plain(s) => {
+                return s.read_value(buf)
+            },
+            _ => LittleEndian::read_u32(buf)
+        };
+
+        match self {
+            Self::B24 => {
+                bits = 26;
+                unpacked = instr & 0x03FF_FFFC;
+            },
+            Self::B14 => {
+                bits = 16;
+                unpacked = instr & 0xFFFC;
+            },
+            Self::HA16 => {
+                bits = 32;
+                // as on RISC-V, the lower bits of the value aren't known here. As only relative
+                // relocations are supported, this value is never adjusted so that's fine.
+                unpacked = (instr & 0xFFFF) << 16;
+            },
+            Self::LO16 => {
+                bits = 16;
+                unpacked = instr & 0xFFFF;
+            },
+            Self::LO16DS => {
+                bits = 16;
+                unpacked = instr & 0xFFFC;
+            },
+            Self::DXHA16 => {
+                bits = 32;
+                unpacked = ((instr & 0xFFC0) | ((instr >> 15) & 0x3E) | (instr & 0x1)) << 16;
+            },
+            Self::Plain(_) => unreachable!(),
+        }
+
+        // sign extension
+        let offset = 1u64 << (bits - 1);
+        let value: u64 = (u64::from(unpacked) ^ offset).wrapping_sub(offset);
+
+        value as i64 as isize
+    }
+    fn kind(&self) -> RelocationKind {
+        RelocationKind::Relative
+    }
+    fn page_size() -> usize {
+        // linux on POWER generally uses 64KiB pages
+        0x10000
+    }
+}
+
+/// A PowerPC64 Assembler. This is aliased here for backwards compatability.
+pub type Assembler = crate::Assembler<Ppc64leRelocation>;
+/// A PowerPC64 AssemblyModifier. This is aliased here for backwards compatability.
+pub type AssemblyModifier<'a> = crate::Modifier<'a, Ppc64leRelocation>;
+/// A PowerPC64 UncommittedModifier. This is aliased here for backwards compatability.
+pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
+
+// these should explicitly never be inlined, as this is the slow path.
+// that's also why these aren't made generic.
+
+/// Handler for `u32` out-of-range ppc64le immediates.
+#[inline(never)]
+pub fn immediate_out_of_range_unsigned_32(immediate: u32) -> ! {
+    panic!("Cannot assemble this PowerPC instruction. Immediate {immediate} is out of range.")
+}
+
+/// Handler for `i32` out-of-range ppc64le immediates.
+#[inline(never)]
+pub fn immediate_out_of_range_signed_32(immediate: i32) -> ! {
+    panic!("Cannot assemble this PowerPC instruction. Immediate {immediate} is out of range.")
+}
+
+
+/// 8-byte general purpose registers. R1 is the stack pointer and R2 the TOC pointer.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RR {
+    R0 = 0x00, R1 = 0x01, R2 = 0x02, R3 = 0x03,
+    R4 = 0x04, R5 = 0x05, R6 = 0x06, R7 = 0x07,
+    R8 = 0x08, R9 = 0x09, R10= 0x0A, R11= 0x0B,
+    R12= 0x0C, R13= 0x0D, R14= 0x0E, R15= 0x0F,
+    R16= 0x10, R17= 0x11, R18= 0x12, R19= 0x13,
+    R20= 0x14, R21= 0x15, R22= 0x16, R23= 0x17,
+    R24= 0x18, R25= 0x19, R26= 0x1A, R27= 0x1B,
+    R28= 0x1C, R29= 0x1D, R30= 0x1E, R31= 0x1F,
+}
+reg_impls!(RR);
+
+/// 8-byte floating point registers
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RF {
+    F0 = 0x00, F1 = 0x01, F2 = 0x02, F3 = 0x03,
+    F4 = 0x04, F5 = 0x05, F6 = 0x06, F7 = 0x07,
+    F8 = 0x08, F9 = 0x09, F10= 0x0A, F11= 0x0B,
+    F12= 0x0C, F13= 0x0D, F14= 0x0E, F15= 0x0F,
+    F16= 0x10, F17= 0x11, F18= 0x12, F19= 0x13,
+    F20= 0x14, F21= 0x15, F22= 0x16, F23= 0x17,
+    F24= 0x18, F25= 0x19, F26= 0x1A, F27= 0x1B,
+    F28= 0x1C, F29= 0x1D, F30= 0x1E, F31= 0x1F,
+}
+reg_impls!(RF);
+
+/// 16-byte vector (VMX) registers
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RV {
+    V0 = 0x00, V1 = 0x01, V2 = 0x02, V3 = 0x03,
+    V4 = 0x04, V5 = 0x05, V6 = 0x06, V7 = 0x07,
+    V8 = 0x08, V9 = 0x09, V10= 0x0A, V11= 0x0B,
+    V12= 0x0C, V13= 0x0D, V14= 0x0E, V15= 0x0F,
+    V16= 0x10, V17= 0x11, V18= 0x12, V19= 0x13,
+    V20= 0x14, V21= 0x15, V22= 0x16, V23= 0x17,
+    V24= 0x18, V25= 0x19, V26= 0x1A, V27= 0x1B,
+    V28= 0x1C, V29= 0x1D, V30= 0x1E, V31= 0x1F,
+}
+reg_impls!(RV);
+
+/// 16-byte vector-scalar (VSX) registers. VS0-VS31 overlap the floating point registers,
+/// VS32-VS63 overlap the vector registers
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RVS {
+    VS0 = 0x00, VS1 = 0x01, VS2 = 0x02, VS3 = 0x03,
+    VS4 = 0x04, VS5 = 0x05, VS6 = 0x06, VS7 = 0x07,
+    VS8 = 0x08, VS9 = 0x09, VS10= 0x0A, VS11= 0x0B,
+    VS12= 0x0C, VS13= 0x0D, VS14= 0x0E, VS15= 0x0F,
+    VS16= 0x10, VS17= 0x11, VS18= 0x12, VS19= 0x13,
+    VS20= 0x14, VS21= 0x15, VS22= 0x16, VS23= 0x17,
+    VS24= 0x18, VS25= 0x19, VS26= 0x1A, VS27= 0x1B,
+    VS28= 0x1C, VS29= 0x1D, VS30= 0x1E, VS31= 0x1F,
+    VS32= 0x20, VS33= 0x21, VS34= 0x22, VS35= 0x23,
+    VS36= 0x24, VS37= 0x25, VS38= 0x26, VS39= 0x27,
+    VS40= 0x28, VS41= 0x29, VS42= 0x2A, VS43= 0x2B,
+    VS44= 0x2C, VS45= 0x2D, VS46= 0x2E, VS47= 0x2F,
+    VS48= 0x30, VS49= 0x31, VS50= 0x32, VS51= 0x33,
+    VS52= 0x34, VS53= 0x35, VS54= 0x36, VS55= 0x37,
+    VS56= 0x38, VS57= 0x39, VS58= 0x3A, VS59= 0x3B,
+    VS60= 0x3C, VS61= 0x3D, VS62= 0x3E, VS63= 0x3F,
+}
+reg_impls!(RVS);
+
+/// Condition register fields, as set by compares and record form instructions and used by conditional branches
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RCR {
+    CR0 = 0x00, CR1 = 0x01, CR2 = 0x02, CR3 = 0x03,
+    CR4 = 0x04, CR5 = 0x05, CR6 = 0x06, CR7 = 0x07,
+}
+reg_impls!(RCR);
+
+
+#[cfg(test)]
+mod tests {
+    use super::RR::*;
+    use super::RVS::*;
+    use super::Ppc64leRelocation;
+    use crate::Register;
+    use crate::relocations::Relocation;
+
+    #[test]
+    fn reg_code() {
+        assert_eq!(R3.code(), 3);
+    }
+
+    #[test]
+    fn reg_code_from() {
+        assert_eq!(u8::from(VS63), 0x3F);
+    }
+
+    #[test]
+    fn dxha16_roundtrip() {
+        let mut buf = 0x4C60_0004u32.to_le_bytes();
+        Ppc64leRelocation::DXHA16.write_value(&mut buf, 0x1234_8000).unwrap();
+        assert_eq!(u32::from_le_bytes(buf), 0x4C7A_1205);
+        assert_eq!(Ppc64leRelocation::DXHA16.read_value(&buf), 0x1235_0000);
+    }
+
+    #[test]
+    fn b14_range() {
+        let mut buf = [0u8; 4];
+        assert!(Ppc64leRelocation::B14.write_value(&mut buf, 0x7FFC).is_ok());
+        assert!(Ppc64leRelocation::B14.write_value(&mut buf, 0x8000).is_err());
+        assert!(Ppc64leRelocation::B14.write_value(&mut buf, 2).is_err());
+    }
+}
@@ -19,6 +19,18 @@ pub trait Relocation {
     fn from_encoding(encoding: Self::Encoding) -> Self;
     /// construct this relocation from a simple size. This is used to implement relocations in directives and literal pools.
     fn from_size(size: RelocationSize) -> Self;
+    /// construct an absolute relocation of a simple size, which resolves to the address of its target.
+    /// This is used to place label addresses in assembler-managed literal pools. Returns None if the
+    /// architecture does not support this.
+    fn absolute_from_size(size: RelocationSize) -> Option<Self> where Self: Sized {
+        let _ = size;
+        None
+    }
+    /// The alignment that the address this relocation is calculated relative to is rounded down to.
+    /// Page-relative relocations, like a pair of aarch64 `adrp` and `add` instructions, return their page size here.
+    fn ref_alignment(&self) -> usize {
+        1
+    }
     /// The size of the slice of bytes affected by this relocation
     fn size(&self) -> usize;
     /// Write a value into a buffer of size `self.size()` in the format of this relocation.
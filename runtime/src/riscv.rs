@@ -444,6 +444,21 @@ pub enum RF {
 }
 reg_impls!(RF);
 
+/// Vector registers, of which the size is implementation defined. V0 doubles as the mask register
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RV {
+    V0 = 0x00, V1 = 0x01, V2 = 0x02, V3 = 0x03,
+    V4 = 0x04, V5 = 0x05, V6 = 0x06, V7 = 0x07,
+    V8 = 0x08, V9 = 0x09, V10= 0x0A, V11= 0x0B,
+    V12= 0x0C, V13= 0x0D, V14= 0x0E, V15= 0x0F,
+    V16= 0x10, V17= 0x11, V18= 0x12, V19= 0x13,
+    V20= 0x14, V21= 0x15, V22= 0x16, V23= 0x17,
+    V24= 0x18, V25= 0x19, V26= 0x1A, V27= 0x1B,
+    V28= 0x1C, V29= 0x1D, V30= 0x1E, V31= 0x1F,
+}
+reg_impls!(RV);
+
 
 #[cfg(test)]
 mod tests {
@@ -71,24 +71,36 @@ pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
 /// 1, 2, 4 or 8-byte general purpose "quad-word" registers.
 ///
 /// RIP does not appear here as it cannot be addressed dynamically.
+///
+/// R16 to R31 require APX and can only be used with REX2 or EVEX encoded instructions.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Rq {
-    RAX = 0x0, RCX = 0x1, RDX = 0x2, RBX = 0x3,
-    RSP = 0x4, RBP = 0x5, RSI = 0x6, RDI = 0x7,
-    R8  = 0x8, R9  = 0x9, R10 = 0xA, R11 = 0xB,
-    R12 = 0xC, R13 = 0xD, R14 = 0xE, R15 = 0xF,
+    RAX = 0x00, RCX = 0x01, RDX = 0x02, RBX = 0x03,
+    RSP = 0x04, RBP = 0x05, RSI = 0x06, RDI = 0x07,
+    R8  = 0x08, R9  = 0x09, R10 = 0x0A, R11 = 0x0B,
+    R12 = 0x0C, R13 = 0x0D, R14 = 0x0E, R15 = 0x0F,
+    R16 = 0x10, R17 = 0x11, R18 = 0x12, R19 = 0x13,
+    R20 = 0x14, R21 = 0x15, R22 = 0x16, R23 = 0x17,
+    R24 = 0x18, R25 = 0x19, R26 = 0x1A, R27 = 0x1B,
+    R28 = 0x1C, R29 = 0x1D, R30 = 0x1E, R31 = 0x1F,
 }
 reg_impls!(Rq);
 
-/// 16 or 32-byte SSE registers.
+/// 16, 32 or 64-byte SSE registers.
+///
+/// XMM16 to XMM31 can only be used with EVEX encoded instructions.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Rx {
-    XMM0  = 0x0, XMM1  = 0x1, XMM2  = 0x2, XMM3  = 0x3,
-    XMM4  = 0x4, XMM5  = 0x5, XMM6  = 0x6, XMM7  = 0x7,
-    XMM8  = 0x8, XMM9  = 0x9, XMM10 = 0xA, XMM11 = 0xB,
-    XMM12 = 0xC, XMM13 = 0xD, XMM14 = 0xE, XMM15 = 0xF,
+    XMM0  = 0x00, XMM1  = 0x01, XMM2  = 0x02, XMM3  = 0x03,
+    XMM4  = 0x04, XMM5  = 0x05, XMM6  = 0x06, XMM7  = 0x07,
+    XMM8  = 0x08, XMM9  = 0x09, XMM10 = 0x0A, XMM11 = 0x0B,
+    XMM12 = 0x0C, XMM13 = 0x0D, XMM14 = 0x0E, XMM15 = 0x0F,
+    XMM16 = 0x10, XMM17 = 0x11, XMM18 = 0x12, XMM19 = 0x13,
+    XMM20 = 0x14, XMM21 = 0x15, XMM22 = 0x16, XMM23 = 0x17,
+    XMM24 = 0x18, XMM25 = 0x19, XMM26 = 0x1A, XMM27 = 0x1B,
+    XMM28 = 0x1C, XMM29 = 0x1D, XMM30 = 0x1E, XMM31 = 0x1F,
 }
 reg_impls!(Rx);
 
@@ -103,8 +115,17 @@ pub enum RC {
 }
 reg_impls!(RC);
 
+/// 1024-byte AMX tile registers.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rt {
+    TMM0 = 0x0, TMM1 = 0x1, TMM2 = 0x2, TMM3 = 0x3,
+    TMM4 = 0x4, TMM5 = 0x5, TMM6 = 0x6, TMM7 = 0x7,
+}
+reg_impls!(Rt);
+
 // The other register families are the same as 32-bit X86. (Although access size for Debug regs is 8-byte)
-pub use crate::x86::{Rh, Rf, Rm, Rs, RD, RB};
+pub use crate::x86::{Rh, Rf, Rm, Rs, RD, RB, Rk};
 
 #[cfg(test)]
 mod tests {
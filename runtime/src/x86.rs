@@ -107,7 +107,7 @@ pub enum Rm {
 }
 reg_impls!(Rm);
 
-/// 16 or 32-byte SSE registers.
+/// 16, 32 or 64-byte SSE registers.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Rx {
@@ -153,6 +153,15 @@ pub enum RB {
 }
 reg_impls!(RB);
 
+/// 8-byte AVX-512 opmask registers.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rk {
+    K0 = 0x0, K1 = 0x1, K2 = 0x2, K3 = 0x3,
+    K4 = 0x4, K5 = 0x5, K6 = 0x6, K7 = 0x7,
+}
+reg_impls!(Rk);
+
 #[cfg(test)]
 mod tests {
     use super::Rd::*;
@@ -0,0 +1,66 @@
+//! Runtime support for the x86_16 architecture assembling target.
+//!
+//! The x86_16 target assembles code for the 16-bit real mode of x86 processors. It features variable-length
+//! instructions and relative relocations up to 16 bits in size.
+//!
+//! The core relocation behaviour for this architecture is provided by the [`X86_16Relocation`] type.
+//!
+//! Next to that, this module contains the following:
+//!
+//! ## Type aliases
+//!
+//! Several specialized type aliases of the generic [`Assembler`] are provided as these are by far the most common usecase.
+//!
+//! ## Enums
+//!
+//! The register enums of this architecture are re-exported from the x86 architecture.
+//! Note that even though 32-bit registers can be used in 16-bit mode, the base registers used in 16-bit memory references
+//! are restricted to `bx`, `bp`, `si` and `di`.
+
+use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation};
+
+
+/// Relocation implementation for the x86_16 architecture.
+#[derive(Debug, Clone)]
+pub struct X86_16Relocation {
+    size: RelocationSize,
+}
+
+impl Relocation for X86_16Relocation {
+    type Encoding = (u8,);
+    fn from_encoding(encoding: Self::Encoding) -> Self {
+        Self {
+            size: RelocationSize::from_encoding(encoding.0),
+        }
+    }
+    fn from_size(size: RelocationSize) -> Self {
+        Self {
+            size,
+        }
+    }
+    fn size(&self) -> usize {
+        self.size.size()
+    }
+    fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        self.size.write_value(buf, value)
+    }
+    fn read_value(&self, buf: &[u8]) -> isize {
+        self.size.read_value(buf)
+    }
+    fn kind(&self) -> RelocationKind {
+        RelocationKind::Relative
+    }
+    fn page_size() -> usize {
+        4096
+    }
+}
+
+/// An x86_16 Assembler. This is aliased here for convenience.
+pub type Assembler = crate::Assembler<X86_16Relocation>;
+/// An x86_16 AssemblyModifier. This is aliased here for convenience.
+pub type AssemblyModifier<'a> = crate::Modifier<'a, X86_16Relocation>;
+/// An x86_16 UncommittedModifier. This is aliased here for convenience.
+pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
+
+
+pub use crate::x86::{Rd, Rh, Rf, Rm, Rx, Rs, RC, RD, RB};
@@ -0,0 +1,6 @@
+#![allow(unused_imports)]
+
+use dynasmrt::dynasm;
+use dynasmrt::DynasmApi;
+
+include!("gen_aarch64/aarch64_bti_mte_mops_tests.rs.gen");
@@ -3,7 +3,7 @@
 extern crate dynasmrt;
 
 use dynasmrt::{dynasm, MutPointer};
-use dynasmrt::{DynasmApi, DynasmLabelApi};
+use dynasmrt::{DynasmApi, DynasmLabelApi, DynasmLitPoolApi};
 use dynasmrt::components::LitPool;
 
 // aliases, and dynasm! in item position
@@ -154,3 +154,407 @@ fn opaque_register_type() {
         ; mov X(rs), X(12)
     );
 }
+
+#[test]
+fn type_mapped_references() {
+    #[repr(C)]
+    struct Test {
+        foo: u64,
+        bar: u32,
+        baz: u32,
+    }
+
+    let index = 3;
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch aarch64
+        ; ldr x1, [x0, 0]
+        ; ldr w1, [x0, 12]
+        ; str w1, [x0, 40]
+        ; ldr x1, [x0, 48]
+        ; ldr x1, [x0]
+        ; ldr x1, [x0, x2, lsl 3]
+        ; ldr x1, [x0, w2, sxtw 3]
+    );
+    let expected = ops.finalize();
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch aarch64
+        ; ldr x1, x0 => Test.foo
+        ; ldr w1, x0 => Test.baz
+        ; str w1, x0 => Test[2].bar
+        ; ldr x1, x0 => Test[index]
+        ; ldr x1, x0 => Test
+        ; ldr x1, x0 => u64[x2]
+        ; ldr x1, x0 => u64[w2]
+    );
+    let buf = ops.finalize();
+
+    assert_eq!(buf, expected, "type mapped references");
+}
+
+#[test]
+#[should_panic]
+fn type_mapped_reference_misaligned() {
+    #[repr(C)]
+    struct Test {
+        foo: u32,
+        bar: u32,
+    }
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch aarch64
+        ; ldr x1, x0 => Test.bar
+    );
+}
+
+#[test]
+fn type_mapped_reference_index_sizes() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch aarch64
+        ; ldrb w1, [x0, x2, lsl 0]
+        ; ldrh w1, [x0, w2, sxtw 1]
+        ; ldr w1, [x0, x2, lsl 2]
+        ; ldr q1, [x0, x2, lsl 4]
+    );
+    let expected = ops.finalize();
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch aarch64
+        ; ldrb w1, x0 => u8[x2]
+        ; ldrh w1, x0 => u16[w2]
+        ; ldr w1, x0 => u32[x2]
+        ; ldr q1, x0 => u128[x2]
+    );
+    let buf = ops.finalize();
+
+    assert_eq!(buf, expected, "type mapped index registers");
+}
+
+#[test]
+fn mov_immediate_constant() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch aarch64
+        ; movz x0, 0
+        ; movz x0, 0x1234, lsl 32
+        ; movn x0, 0
+        ; movn x0, 0xEDCB, lsl 16
+        ; orr x0, xzr, 0x5555_5555_5555_5555
+        ; movz x0, 0x5678
+        ; movk x0, 0x1234, lsl 48
+        ; movz x0, 0xDEF0
+        ; movk x0, 0x9ABC, lsl 16
+        ; movk x0, 0x5678, lsl 32
+        ; movk x0, 0x1234, lsl 48
+        ; movz xzr, 0xFFFF
+        ; movk xzr, 0xFFFF, lsl 16
+        ; movz w1, 0xFFFF, lsl 16
+        ; movn w1, 0
+        ; orr w1, wzr, 0x00FF_00FF
+        ; movz w1, 0x5678
+        ; movk w1, 0x1234, lsl 16
+    );
+    let expected = ops.finalize();
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch aarch64
+        ; mov.imm x0, 0
+        ; mov.imm x0, 0x1234_0000_0000
+        ; mov.imm x0, -1
+        ; mov.imm x0, 0xFFFF_FFFF_1234_FFFF
+        ; mov.imm x0, 0x5555_5555_5555_5555
+        ; mov.imm x0, 0x1234_0000_0000_5678
+        ; mov.imm x0, 0x1234_5678_9ABC_DEF0
+        ; mov.imm xzr, 0xFFFF_FFFF
+        ; mov.imm w1, 0xFFFF_0000
+        ; mov.imm w1, -1
+        ; mov.imm w1, 0x00FF_00FF
+        ; mov.imm w1, 0x1234_5678
+    );
+    let buf = ops.finalize();
+
+    assert_eq!(buf, expected, "mov.imm with constant immediates");
+}
+
+#[test]
+fn mov_immediate_dynamic() {
+    let values = [0u64, 0x1234_0000_0000, 0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF_1234_FFFF, 0x5555_5555_5555_5555, 0x1234_0000_0000_5678, 0x1234_5678_9ABC_DEF0];
+    let reg = 3u8;
+
+    // fixed-length sequences
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    for &value in &values {
+        dynasm!(ops
+            ; .arch aarch64
+            ; movz X(reg), (value & 0xFFFF) as u32
+            ; movk X(reg), ((value >> 16) & 0xFFFF) as u32, lsl 16
+            ; movk X(reg), ((value >> 32) & 0xFFFF) as u32, lsl 32
+            ; movk X(reg), ((value >> 48) & 0xFFFF) as u32, lsl 48
+            ; movz w5, (value & 0xFFFF) as u32
+            ; movk w5, ((value >> 16) & 0xFFFF) as u32, lsl 16
+        );
+    }
+    let expected = ops.finalize();
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    for &value in &values {
+        dynasm!(ops
+            ; .arch aarch64
+            ; mov.imm X(reg), value
+            ; mov.imm w5, value as u32
+        );
+    }
+    let buf = ops.finalize();
+
+    assert_eq!(buf, expected, "mov.imm with dynamic immediates");
+
+    // size-varying sequences
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch aarch64
+        ; mov.imm x3, 0
+        ; mov.imm x3, 0x1234_0000_0000
+        ; mov.imm x3, -1
+        ; mov.imm x3, 0xFFFF_FFFF_1234_FFFF
+        ; mov.imm x3, 0x5555_5555_5555_5555
+        ; mov.imm x3, 0x1234_0000_0000_5678
+        ; mov.imm x3, 0x1234_5678_9ABC_DEF0
+        ; mov.imm w5, 0
+        ; mov.imm w5, 0x5555_5555
+        ; mov.imm w5, -1
+        ; mov.imm w5, 0x1234_FFFF
+        ; mov.imm w5, 0x9ABC_DEF0
+    );
+    let expected = ops.finalize();
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    for &value in &values {
+        dynasm!(ops
+            ; .arch aarch64
+            ; mov.imm.short X(reg), value
+        );
+    }
+    for &value in &[0u32, 0x5555_5555, 0xFFFF_FFFF, 0x1234_FFFF, 0x9ABC_DEF0] {
+        dynasm!(ops
+            ; .arch aarch64
+            ; mov.imm.short w5, value
+        );
+    }
+    let buf = ops.finalize();
+
+    assert_eq!(buf, expected, "mov.imm.short with dynamic immediates");
+}
+
+#[test]
+fn literal_pool() {
+    let mut ops = dynasmrt::VecAssembler::<dynasmrt::aarch64::Aarch64Relocation>::new(0x10000);
+    dynasm!(ops
+        ; .arch aarch64
+        ; ldr x0, >value
+        ; ldr w1, >word
+        ; ldr x2, >value
+        ; ldr x3, >start
+        ; ldr x4, >end
+        ; ret
+        ; value:
+        ; .u64 0x1234_5678_9ABC_DEF0
+        ; start:
+        ; .u64 0x10000
+        ; end:
+        ; .u64 0x10034
+        ; word:
+        ; .u32 0xDEAD_BEEF
+    );
+    let expected = ops.finalize().unwrap();
+
+    let mut ops = dynasmrt::VecAssembler::<dynasmrt::aarch64::Aarch64Relocation>::new(0x10000);
+    let end = ops.new_dynamic_label();
+    dynasm!(ops
+        ; .arch aarch64
+        ; ->start:
+        ; ldr x0, =0x1234_5678_9ABC_DEF0u64
+        ; ldr w1, =0xDEAD_BEEFu32
+        ; ldr x2, =0x1234_5678_9ABC_DEF0u64
+        ; ldr x3, =->start
+        ; ldr X(4), ==>end
+        ; ret
+        ; .ltorg
+        ; =>end
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(buf, expected, "ldr from a literal pool");
+}
+
+#[test]
+fn literal_pool_automatic_flush() {
+    let mut ops = dynasmrt::VecAssembler::<dynasmrt::aarch64::Aarch64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch aarch64
+        ; ldr x0, =1
+    );
+    while ops.offset().0 < 0xF_FFF0 {
+        dynasm!(ops
+            ; .arch aarch64
+            ; nop
+        );
+    }
+    dynasm!(ops
+        ; .arch aarch64
+        ; ldr x1, =2
+        ; ret
+        ; .ltorg
+    );
+    let buf = ops.finalize().unwrap();
+
+    // a branch over the pool, padding, and the first entry
+    assert_eq!(&buf[0xF_FFF0 .. 0x10_0000], &[
+        0x04, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], "automatically emitted literal pool");
+    assert_eq!(&buf[0x10_0008 ..], &[2, 0, 0, 0, 0, 0, 0, 0], "literal pool emitted by .ltorg");
+}
+
+#[test]
+fn literal_pool_flushed_on_commit() {
+    let mut ops = dynasmrt::VecAssembler::<dynasmrt::aarch64::Aarch64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch aarch64
+        ; ldr x0, =1
+    );
+    ops.commit().unwrap();
+    dynasm!(ops
+        ; .arch aarch64
+        ; ret
+    );
+    let buf = ops.finalize().unwrap();
+
+    let words: Vec<u32> = buf.chunks(4).map(|c| u32::from_le_bytes(c.try_into().unwrap())).collect();
+    assert_eq!(words, [
+        0x5800_0040, // ldr x0, 8
+        0x1400_0003, // b 12
+        0x0000_0001, 0x0000_0000,
+        0xD65F_03C0,
+    ]);
+}
+
+#[test]
+fn literal_pool_out_of_range() {
+    let mut ops = dynasmrt::VecAssembler::<dynasmrt::aarch64::Aarch64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch aarch64
+        ; ldr x0, =1
+    );
+    ops.extend(std::iter::repeat(0).take(0x10_0000));
+    assert!(matches!(ops.commit(), Err(dynasmrt::DynasmError::ImpossibleRelocation(_))), "raw data pushed the pool out of range");
+}
+
+#[test]
+fn literal_pool_flushed_at_label() {
+    let mut ops = dynasmrt::VecAssembler::<dynasmrt::aarch64::Aarch64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch aarch64
+        ; ldr x0, =1
+    );
+    while ops.offset().0 < 0x8_0000 {
+        dynasm!(ops
+            ; .arch aarch64
+            ; nop
+        );
+    }
+    dynasm!(ops
+        ; .arch aarch64
+        ; ->after:
+    );
+    ops.extend(std::iter::repeat(0).take(0x10_0000));
+    let buf = ops.finalize().unwrap();
+
+    // a branch over the pool, padding, and the entry, all before the label
+    assert_eq!(&buf[0x8_0000 .. 0x8_0010], &[
+        0x04, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], "literal pool emitted at a label");
+    assert_eq!(buf.len(), 0x18_0010);
+}
+
+#[test]
+fn adrp_pair() {
+    let mut ops = dynasmrt::VecAssembler::<dynasmrt::aarch64::Aarch64Relocation>::new(0x1_0FF8);
+    dynasm!(ops
+        ; .arch aarch64
+        ; adrl x0, >data
+        ; ldr W(1), [>data]
+        ; ldrh w2, [>data]
+        ; ret
+        ; data:
+        ; .u32 0x1234_5678
+        ; adrl x3, extern 0x1234_5678usize
+    );
+    let buf = ops.finalize().unwrap();
+
+    let words: Vec<u32> = buf.chunks(4).map(|c| u32::from_le_bytes(c.try_into().unwrap())).collect();
+    assert_eq!(words, [
+        0xB000_0000, 0x9100_5000, // adrp x0, 0x1_1000; add x0, x0, 0x14
+        0x9000_0001, 0xB940_1421, // adrp x1, 0x1_1000; ldr w1, [x1, 0x14]
+        0x9000_0002, 0x7940_2842, // adrp x2, 0x1_1000; ldrh w2, [x2, 0x14]
+        0xD65F_03C0,
+        0x1234_5678,
+        0x9009_19A3, 0x9119_E063, // adrp x3, 0x1234_5000; add x3, x3, 0x678
+    ], "adrp pairs");
+}
+
+#[test]
+fn adrp_pair_extern_page_rounding() {
+    // the adrp is at the end of its page, so the page difference is larger than the distance in pages
+    let mut ops = dynasmrt::VecAssembler::<dynasmrt::aarch64::Aarch64Relocation>::new(0x1_0FFC);
+    dynasm!(ops
+        ; .arch aarch64
+        ; ldr w0, [extern 0x2_0004usize]
+        ; adrl x1, extern 0x2_0004usize
+    );
+    let buf = ops.finalize().unwrap();
+
+    let words: Vec<u32> = buf.chunks(4).map(|c| u32::from_le_bytes(c.try_into().unwrap())).collect();
+    assert_eq!(words, [
+        0x9000_0080, 0xB940_0400, // adrp x0, 0x2_0000; ldr w0, [x0, 0x4]
+        0xF000_0061, 0x9100_1021, // adrp x1, 0x2_0000; add x1, x1, 0x4
+    ], "extern adrp pairs");
+}
+
+#[test]
+fn adrp_pair_extern_managed() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch aarch64
+        ; nop
+    );
+    ops.commit().unwrap();
+
+    // an absolute address in range of the executable buffer
+    let target = ops.reader().lock().ptr(dynasmrt::AssemblyOffset(0)) as usize + 0x1234_5678;
+
+    dynasm!(ops
+        ; .arch aarch64
+        ; ldr x0, [extern target]
+        ; ret
+    );
+    ops.commit().unwrap();
+    // grow the buffer, so it gets moved
+    ops.extend(std::iter::repeat(0).take(0x10_0000));
+    let buf = ops.finalize().unwrap();
+
+    let adrp = u32::from_le_bytes(buf[4 .. 8].try_into().unwrap());
+    let ldr = u32::from_le_bytes(buf[8 .. 12].try_into().unwrap());
+    let page = (((((adrp >> 5) & 0x7FFFF) << 2) | ((adrp >> 29) & 3)) << 11) as i32 >> 11;
+    let address = (buf.ptr(dynasmrt::AssemblyOffset(4)) as usize & !0xFFF)
+        .wrapping_add((page as isize * 0x1000) as usize)
+        + (((ldr >> 10) & 0xFFF) << 3) as usize;
+    assert_eq!(address, target, "extern adrp pair relocation after moving the buffer");
+}
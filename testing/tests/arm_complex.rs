@@ -0,0 +1,278 @@
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+
+use dynasmrt::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+use dynasmrt::arm::ArmRelocation;
+
+// aliases, and dynasm! in item position
+macro_rules! my_dynasm {
+    ($ops:ident $($t:tt)*) => {
+        dynasm!($ops
+            ; .arch arm
+            $($t)*
+        )
+    }
+}
+
+fn words(buf: &[u8]) -> Vec<u32> {
+    buf.chunks(4).map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]])).collect()
+}
+
+#[test]
+fn complex() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    let c = 4u32;
+    let offset = -16i32;
+    let reg = 3u8;
+
+    // interesting testcases
+    my_dynasm!(ops
+        ; test:
+        // no args
+        ; nop
+        ; bx lr
+        // bare immediate
+        ; svc 0
+        // expression
+        ; svc c
+        // registers
+        ; add r1, r2, r3
+        ; add R(1), R(2), R(reg)
+        ; add fp, sp, ip
+        // condition codes and flag setting
+        ; addeq r0, r0, r1
+        ; addsne r0, r0, r1
+        ; movshi r0, #1
+        // shifts
+        ; add r0, r1, r2, lsl #4
+        ; add r0, r1, r2, lsr r3
+        ; add r0, r1, r2, rrx
+        ; mov r0, r1, ror #8
+        ; lsl r0, r1, #3
+        // modified immediates
+        ; add r0, r1, #0xFF00
+        ; add r0, r1, #-4
+        ; and r0, r1, #0xFFFF_FF00
+        ; mov r0, #0x1234
+        ; mov r0, c
+        // memory references
+        ; ldr r0, [r1]
+        ; ldr r0, [r1, #4]
+        ; ldr r0, [r1, offset]
+        ; ldr r0, [r1, #4]!
+        ; ldr r0, [r1], #4
+        ; ldr r0, [r1, r2]
+        ; ldr r0, [r1, -r2, lsl #2]
+        ; ldr r0, [r1], r2
+        ; ldrh r0, [r1, #-6]
+        ; ldrd r2, r3, [r1, #16]
+        ; ldrex r0, [r1]
+        ; strex r0, r2, [r1]
+        // register lists
+        ; push {r4, lr}
+        ; pop {r4-r11, pc}
+        ; ldm r0!, {r1, r2, R(reg)}
+        ; stmdb sp!, {r4}
+        // bitfields and others
+        ; ubfx r0, r1, #4, #8
+        ; bfi r0, r1, #8, #4
+        ; uxtb r0, r1, ror #8
+        ; dmb ish
+        ; mrs r0, apsr
+        // all the branches
+        ; b <test
+        ; bl <test
+        ; bgt <test
+        ; blx r0
+        ; adr r0, <test
+        ; ldr r0, <test
+        ; ldrd r2, r3, <test
+    );
+
+    let buf = ops.finalize().unwrap();
+
+    println!("Generated assembly:");
+    for i in buf.iter() {
+        print!("{:02x }", i);
+    }
+    println!("");
+}
+
+#[test]
+fn encodings() {
+    let mut ops = dynasmrt::VecAssembler::<ArmRelocation>::new(0);
+    my_dynasm!(ops
+        ; add r0, r1, #0xFF00_0000
+        ; add r0, r1, #-4
+        ; and r0, r1, #0xFFFF_FF00
+        ; mov r0, #0x1234
+        ; mov r0, #-1
+        ; cmp r0, #-1
+        ; ldr r0, [r1, #-8]
+        ; ldr r0, [r1, #8]!
+        ; ldr r0, [r1], #-8
+        ; ldr r0, [r1, -r2, lsl #2]
+        ; ldrh r0, [r1, #-6]
+        ; ldrd r2, r3, [r1, #16]
+        ; lsl r0, r1, #3
+        ; ror r0, r1, r2
+        ; mov r0, r1, asr #2
+        ; ubfx r0, r1, #4, #8
+        ; bfi r0, r1, #8, #4
+        ; push {r4, lr}
+        ; pop {r4, pc}
+        ; push {r0}
+        ; addeq r0, r0, r1, lsl #4
+        ; movsne r0, #1
+        ; dmb ish
+        ; svc 0x12_3456
+        ; mrs r0, apsr
+        ; ldm r0!, {r1, r2, r3}
+        ; stmdb sp!, {r4-r11}
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(words(&buf), [
+        0xE281_04FF, 0xE241_0004, 0xE3C1_00FF, 0xE301_0234,
+        0xE3E0_0000, 0xE370_0001, 0xE511_0008, 0xE5B1_0008,
+        0xE411_0008, 0xE711_0102, 0xE151_00B6, 0xE1C1_21D0,
+        0xE1A0_0181, 0xE1A0_0271, 0xE1A0_0141, 0xE7E7_0251,
+        0xE7CB_0411, 0xE92D_4010, 0xE8BD_8010, 0xE52D_0004,
+        0x0080_0201, 0x13B0_0001, 0xF57F_F05B, 0xEF12_3456,
+        0xE10F_0000, 0xE8B0_000E, 0xE92D_0FF0,
+    ]);
+}
+
+#[test]
+fn it_blocks() {
+    // IT instructions are accepted in ARM code, but they only check the condition codes used
+    let mut ops = dynasmrt::VecAssembler::<ArmRelocation>::new(0);
+    my_dynasm!(ops
+        ; ite eq
+        ; moveq r0, r1
+        ; movne r0, r2
+        ; add r0, r0, r1
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(words(&buf), [0x01A0_0001, 0x11A0_0002, 0xE080_0001]);
+}
+
+#[test]
+fn relocations() {
+    let mut ops = dynasmrt::VecAssembler::<ArmRelocation>::new(0);
+    my_dynasm!(ops
+        ; start:
+        ; b >end
+        ; bl >end
+        ; beq <start
+        ; ldr r0, >end
+        ; ldrh r4, <start
+        ; adr r1, >end
+        ; adr r2, <start
+        ; ldrd r2, r3, <start
+        ; .align 0x400
+        ; end:
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(&words(&buf)[.. 8], &[
+        0xEA00_00FE, // b +0x400
+        0xEB00_00FD, // bl +0x3FC
+        0x0AFF_FFFC, // beq -8
+        0xE59F_03EC, // ldr r0, +0x3F4
+        0xE15F_41B8, // ldrh r4, -0x10
+        0xE28F_1FF9, // adr r1, +0x3EC
+        0xE24F_2020, // adr r2, -0x18
+        0xE14F_22D4, // ldrd r2, r3, -0x1C
+    ]);
+}
+
+#[test]
+fn immediates() {
+    let imm = 0xFF00_0000u32;
+    let offset = -8i32;
+    let amount = 3u32;
+    let lsb = 4u32;
+    let width = 8u32;
+
+    let mut ops = dynasmrt::VecAssembler::<ArmRelocation>::new(0);
+    my_dynasm!(ops
+        ; add r0, r1, #0xFF00_0000
+        ; add r0, r1, #imm
+        ; ldr r0, [r1, #-8]
+        ; ldr r0, [r1, #offset]
+        ; lsl r0, r1, #3
+        ; lsl r0, r1, #amount
+        ; ubfx r0, r1, #4, #8
+        ; ubfx r0, r1, #lsb, #width
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(words(&buf), [
+        0xE281_04FF, 0xE281_04FF,
+        0xE511_0008, 0xE511_0008,
+        0xE1A0_0181, 0xE1A0_0181,
+        0xE7E7_0251, 0xE7E7_0251,
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn dynamic_modified_immediate_out_of_range() {
+    let imm = 0x1234u32;
+
+    let mut ops = dynasmrt::VecAssembler::<ArmRelocation>::new(0);
+    my_dynasm!(ops
+        ; add r0, r1, #imm
+    );
+}
+
+#[test]
+#[should_panic]
+fn dynamic_register_out_of_range() {
+    let reg = 14u8;
+
+    let mut ops = dynasmrt::VecAssembler::<ArmRelocation>::new(0);
+    my_dynasm!(ops
+        ; ldrd R(reg), r15, [r0]
+    );
+}
+
+#[test]
+fn type_mapped_references() {
+    #[repr(C)]
+    struct Test {
+        foo: u32,
+        bar: u16,
+        baz: u16,
+    }
+
+    let index = 3;
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    my_dynasm!(ops
+        ; ldr r1, [r0, #0]
+        ; ldrh r1, [r0, #6]
+        ; strh r1, [r0, #20]
+        ; ldr r1, [r0, #24]
+        ; ldr r1, [r0]
+        ; ldrh r1, [r0, #-2]
+    );
+    let expected = ops.finalize();
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    my_dynasm!(ops
+        ; ldr r1, r0 => Test.foo
+        ; ldrh r1, r0 => Test.baz
+        ; strh r1, r0 => Test[2].bar
+        ; ldr r1, r0 => Test[index]
+        ; ldr r1, r0 => Test
+        ; ldrh r1, r0 => Test[-1].baz
+    );
+    let buf = ops.finalize();
+
+    assert_eq!(buf, expected, "type mapped references");
+}
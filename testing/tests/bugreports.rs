@@ -120,6 +120,75 @@ fn bugreport_6() {
 }
 
 
+// mov into a segment register was encoded as a move from the segment register
+#[test]
+fn bugreport_7() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+       ; .arch x64
+       ; mov ds, ax
+       ; mov fs, WORD [rax]
+       ; mov ax, ds
+    );
+
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("0x{:02X}", *x)).collect();
+    let hex: String = hex.join(", ");
+    assert_eq!(hex, "0x8E, 0xD8, 0x8E, 0x20, 0x66, 0x8C, 0xD8", "bugreport_7");
+}
+
+// 16-bit immediate far jumps were encoded as far calls
+#[test]
+fn bugreport_8() {
+    let mut ops = dynasmrt::x86::Assembler::new().unwrap();
+    dynasm!(ops
+       ; .arch x86
+       ; jmpf WORD 0x1234, WORD 0x5678
+       ; jmp WORD 0x1234, WORD 0x5678
+       ; callf WORD 0x1234, WORD 0x5678
+    );
+
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("0x{:02X}", *x)).collect();
+    let hex: String = hex.join(", ");
+    assert_eq!(hex, "0x66, 0xEA, 0x34, 0x12, 0x78, 0x56, 0x66, 0xEA, 0x34, 0x12, 0x78, 0x56, 0x66, 0x9A, 0x34, 0x12, 0x78, 0x56", "bugreport_8");
+}
+
+// missing operand size prefixes on the short forms of inc/dec and on out imm8, ax
+#[test]
+fn bugreport_9() {
+    let mut ops = dynasmrt::x86::Assembler::new().unwrap();
+    dynasm!(ops
+       ; .arch x86
+       ; inc ax
+       ; dec cx
+       ; inc eax
+       ; out 0x12, ax
+       ; out 0x12, eax
+    );
+
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("0x{:02X}", *x)).collect();
+    let hex: String = hex.join(", ");
+    assert_eq!(hex, "0x66, 0x40, 0x66, 0x49, 0x40, 0x66, 0xE7, 0x12, 0xE7, 0x12", "bugreport_9");
+}
+
+// the byte source forms of crc32 are split by destination size, which should not change their encodings
+#[test]
+fn bugreport_10() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+       ; .arch x64
+       ; crc32 eax, bl
+       ; crc32 rax, BYTE [rax]
+    );
+
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("0x{:02X}", *x)).collect();
+    let hex: String = hex.join(", ");
+    assert_eq!(hex, "0xF2, 0x0F, 0x38, 0xF0, 0xC3, 0xF2, 0x48, 0x0F, 0x38, 0xF0, 0x00", "bugreport_10");
+}
+
 #[test]
 fn rustc_does_not_properly_respect_macro_expr_grouping_for_precedence() {
     // the issue here is that code for emitting dynamic registers ends up emitting code like this
@@ -99,6 +99,122 @@ fn test_x64_features() {
     );
 }
 
+#[test]
+fn test_aarch64_features() {
+    dynasm!(()
+        ; .arch aarch64
+        ; .feature aes
+        ; .feature bti
+        ; .feature crc
+        ; .feature dotprod
+        ; .feature fcma
+        ; .feature flagm
+        ; .feature fp16
+        ; .feature fp16fml
+        ; .feature jscvt
+        ; .feature lor
+        ; .feature lse
+        ; .feature mops
+        ; .feature mte
+        ; .feature pauth
+        ; .feature predres
+        ; .feature ras
+        ; .feature rcpc
+        ; .feature rcpc2
+        ; .feature rdm
+        ; .feature sb
+        ; .feature sha2
+        ; .feature sha3
+        ; .feature sm4
+        ; .feature sme
+        ; .feature sme_f64f64
+        ; .feature sme_i16i64
+        ; .feature spe
+        ; .feature sve
+        ; .feature sve2
+        ; .feature trf
+        // multiple
+        ; .feature lse, crc
+        // case insensitive
+        ; .feature LSE
+    );
+}
+
+#[test]
+fn test_aarch64_feature_gating() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch aarch64
+        ; .feature none
+        ; add x0, x1, x2
+        ; fadd v0.s4, v1.s4, v2.s4
+        ; .feature lse, crc
+        ; ldadd w0, w1, [x2]
+        ; crc32b w0, w1, w2
+        ; .feature sve2
+        ; add z0.s, p0/m, z0.s, z1.s
+        ; fadd z0.h, z1.h, z2.h
+    );
+
+    let buf = ops.finalize();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "20, 00, 02, 8B, 20, D4, 22, 4E, 41, 00, 20, B8, 20, 40, C2, 1A, 20, 00, 80, 04, 20, 00, 42, 65", "aarch64 feature gating");
+}
+
+#[test]
+fn test_riscv_features() {
+    dynasm!(()
+        ; .arch riscv64
+        // isa strings
+        ; .feature rv64gc_zba_zbb
+        ; .feature RV64IMAFDCV_Zicsr_Zifencei
+        ; .feature IMA_Svinval_Zvl128b
+        // profiles
+        ; .feature rvi20u64
+        ; .feature rva20u64
+        ; .feature rva22u64
+        ; .feature rva23u64
+        ; .feature rva23s64
+        // privileged instructions
+        ; .feature priv
+        // automatic compression
+        ; .feature gc, compress
+        ; .arch riscv32
+        ; .feature rv32ec
+        ; .feature rvi20u32
+    );
+}
+
+#[test]
+fn test_riscv_feature_implications() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch riscv64
+        ; .feature rv64gc_zba_zbb
+        ; sh1add x10, x11, x12
+        ; andn x10, x11, x12
+        ; c.addi x10, 1
+        // d implies f
+        ; .feature d
+        ; fadd.s f10, f11, f12
+        // zk implies zkn, which implies zknd
+        ; .feature zk
+        ; aes64ds x10, x11, x12
+        // the profile includes v and zicond, and v implies zicsr
+        ; .feature rva23u64
+        ; vadd.vv v1, v2, v3
+        ; czero.eqz x10, x11, x12
+        ; .feature v
+        ; csrr x10, vl
+    );
+
+    let buf = ops.finalize();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "33, A5, C5, 20, 33, F5, C5, 40, 05, 05, 53, F5, C5, 00, 33, 85, C5, 3A, D7, 80, 21, 02, 33, D5, C5, 0E, 73, 25, 00, C2", "riscv feature implications");
+}
+
 #[test]
 fn test_aliases() {
     let mut ops = dynasmrt::SimpleAssembler::new();
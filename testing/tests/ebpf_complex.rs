@@ -0,0 +1,266 @@
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+
+use dynasmrt::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+use dynasmrt::ebpf::EbpfRelocation;
+
+// aliases, and dynasm! in item position
+macro_rules! my_dynasm {
+    ($ops:ident $($t:tt)*) => {
+        dynasm!($ops
+            ; .arch ebpf
+            $($t)*
+        )
+    }
+}
+
+fn slots(buf: &[u8]) -> Vec<u64> {
+    buf.chunks(8).map(|c| u64::from_le_bytes(c.try_into().unwrap())).collect()
+}
+
+#[test]
+fn complex() {
+    let mut ops = dynasmrt::VecAssembler::<EbpfRelocation>::new(0);
+    let c = 4i32;
+    let offset = -16i32;
+    let reg = 3u8;
+
+    // interesting testcases
+    my_dynasm!(ops
+        ; test:
+        // no args
+        ; exit
+        // bare immediate
+        ; call 1
+        // expression
+        ; ldabsw c
+        // registers
+        ; add64 r1, r2
+        ; add64 R(1), R(2)
+        ; mov64 r1, fp
+        ; add32 R(reg), 7
+        // memory references
+        ; ldxdw r0, [r1]
+        ; ldxdw r0, [r1 + 0]
+        ; ldxdw r0, [r1 + 512]
+        ; ldxdw r0, [r1 - 8]
+        ; ldxdw r0, [fp + offset]
+        ; stxw [r10 - 4], r1
+        ; stw [r10 - 4], 0x1234
+        ; atomic_fetch_add64 [r1 + 8], r2
+        // all the branches
+        ; ja <test
+        ; ja32 <test
+        ; jeq r1, 0, <test
+        ; jsgt32 r1, r2, <test
+        ; call <test
+        ; lddw r1, <test
+        // wide loads
+        ; lddw r1, 0x1234_5678_9ABC_DEF0
+        ; ld_map_fd r1, 3
+        ; ld_map_value r1, 3, 16
+    );
+
+    let buf = ops.finalize().unwrap();
+
+    println!("Generated assembly:");
+    for i in buf.iter() {
+        print!("{:02x }", i);
+    }
+    println!("");
+}
+
+#[test]
+fn relocations() {
+    let mut ops = dynasmrt::VecAssembler::<EbpfRelocation>::new(0);
+    my_dynasm!(ops
+        ; start:
+        ; ja >end
+        ; jeq r1, 5, <start
+        ; jne r1, r2, >end
+        ; call >func
+        ; ja32 <start
+        ; lddw r1, >func
+        ; exit
+        ; end:
+        ; func:
+        ; exit
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(slots(&buf), [
+        0x0000_0000_0007_0005, // ja +7
+        0x0000_0005_FFFE_0115, // jeq r1, 5, -2
+        0x0000_0000_0005_215D, // jne r1, r2, +5
+        0x0000_0004_0000_1085, // call +4
+        0xFFFF_FFFB_0000_0006, // ja32 -5
+        0x0000_0002_0000_4118, // lddw r1, +2
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0095, // exit
+        0x0000_0000_0000_0095, // exit
+    ]);
+}
+
+#[test]
+fn immediates() {
+    let imm = -1i32;
+    let shift = 63u32;
+    let value = 0x1234_5678_9ABC_DEF0i64;
+    let offset = -4i32;
+    let helper = 12i32;
+
+    let mut ops = dynasmrt::VecAssembler::<EbpfRelocation>::new(0);
+    my_dynasm!(ops
+        ; mov64 r1, -1
+        ; mov64 r1, imm
+        ; mov32 r1, 0xFFFF_FFFF
+        ; lsh64 r2, 63
+        ; lsh64 r2, shift
+        ; lddw r3, 0x1234_5678_9ABC_DEF0
+        ; lddw r3, value
+        ; ldxw r4, [r10 - 4]
+        ; ldxw r4, [fp + offset]
+        ; ja -1
+        ; call 12
+        ; call helper
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(slots(&buf), [
+        0xFFFF_FFFF_0000_01B7, 0xFFFF_FFFF_0000_01B7,
+        0xFFFF_FFFF_0000_01B4,
+        0x0000_003F_0000_0267, 0x0000_003F_0000_0267,
+        0x9ABC_DEF0_0000_0318, 0x1234_5678_0000_0000,
+        0x9ABC_DEF0_0000_0318, 0x1234_5678_0000_0000,
+        0x0000_0000_FFFC_A461, 0x0000_0000_FFFC_A461,
+        0x0000_0000_FFFF_0005,
+        0x0000_000C_0000_0085, 0x0000_000C_0000_0085,
+    ]);
+}
+
+// instructions that llvm-mc 14 cannot assemble, and therefore aren't covered by the generated tests.
+#[test]
+fn extended_instructions() {
+    let mut ops = dynasmrt::VecAssembler::<EbpfRelocation>::new(0);
+    my_dynasm!(ops
+        // store immediate
+        ; stb [r1 + 2], 0x12
+        ; sth [r1 - 2], 0x1234
+        ; stw [r10 - 8], 0x1234
+        ; stdw [r10 - 16], -1
+        // signed division and modulo
+        ; sdiv64 r1, r2
+        ; smod32 r1, 7
+        ; mod64 r1, r2
+        ; mod32 r1, 7
+        // sign extension
+        ; movsxb64 r1, r2
+        ; movsxw64 r1, r2
+        ; movsxh32 r3, r4
+        ; ldxsb r1, [r2 + 4]
+        ; ldxsw r1, [r2 - 2]
+        // unconditional byte swap
+        ; bswap64 r5
+        // jset
+        ; jset r1, 1, 2
+        ; jset32 r1, r2, -1
+        // long jumps
+        ; ja32 100
+        // atomics
+        ; atomic_fetch_add32 [r1 + 8], r2
+        ; atomic_xchg64 [r1 + 8], r2
+        ; atomic_cmpxchg64 [r1 + 8], r2
+        // legacy packet access with an offset
+        ; ldindh r2, 14
+        // map loads
+        ; ld_map_fd r1, 3
+        ; ld_map_value r2, 3, 16
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(slots(&buf), [
+        0x0000_0012_0002_0172,
+        0x0000_1234_FFFE_016A,
+        0x0000_1234_FFF8_0A62,
+        0xFFFF_FFFF_FFF0_0A7A,
+        0x0000_0000_0001_213F,
+        0x0000_0007_0001_0194,
+        0x0000_0000_0000_219F,
+        0x0000_0007_0000_0194,
+        0x0000_0000_0008_21BF,
+        0x0000_0000_0020_21BF,
+        0x0000_0000_0010_43BC,
+        0x0000_0000_0004_2191,
+        0x0000_0000_FFFE_2181,
+        0x0000_0040_0000_05D7,
+        0x0000_0001_0002_0145,
+        0x0000_0000_FFFF_214E,
+        0x0000_0064_0000_0006,
+        0x0000_0001_0008_21C3,
+        0x0000_00E1_0008_21DB,
+        0x0000_00F1_0008_21DB,
+        0x0000_000E_0000_2048,
+        0x0000_0003_0000_1118, 0x0000_0000_0000_0000,
+        0x0000_0003_0000_2218, 0x0000_0010_0000_0000,
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn dynamic_shift_amount_out_of_range() {
+    let shift = 64u32;
+
+    let mut ops = dynasmrt::VecAssembler::<EbpfRelocation>::new(0);
+    my_dynasm!(ops
+        ; lsh64 r1, shift
+    );
+}
+
+#[test]
+#[should_panic]
+fn dynamic_offset_out_of_range() {
+    let offset = 0x8000i32;
+
+    let mut ops = dynasmrt::VecAssembler::<EbpfRelocation>::new(0);
+    my_dynasm!(ops
+        ; ldxdw r1, [r2 + offset]
+    );
+}
+
+#[test]
+fn type_mapped_references() {
+    #[repr(C)]
+    struct Test {
+        foo: u64,
+        bar: u32,
+        baz: u32,
+    }
+
+    let index = 3;
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    my_dynasm!(ops
+        ; ldxdw r1, [r2 + 0]
+        ; ldxw r1, [r2 + 12]
+        ; stxw [r2 + 40], r1
+        ; ldxdw r1, [r2 + 48]
+        ; ldxdw r1, [r2]
+        ; ldxw r1, [r2 - 4]
+    );
+    let expected = ops.finalize();
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    my_dynasm!(ops
+        ; ldxdw r1, r2 => Test.foo
+        ; ldxw r1, r2 => Test.baz
+        ; stxw r2 => Test[2].bar, r1
+        ; ldxdw r1, r2 => Test[index]
+        ; ldxdw r1, r2 => Test
+        ; ldxw r1, r2 => Test[-1].baz
+    );
+    let buf = ops.finalize();
+
+    assert_eq!(buf, expected, "type mapped references");
+}
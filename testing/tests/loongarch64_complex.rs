@@ -0,0 +1,197 @@
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+
+use dynasmrt::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+use dynasmrt::loongarch64::Loongarch64Relocation;
+
+// aliases, and dynasm! in item position
+macro_rules! my_dynasm {
+    ($ops:ident $($t:tt)*) => {
+        dynasm!($ops
+            ; .arch loongarch64
+            $($t)*
+        )
+    }
+}
+
+fn words(buf: &[u8]) -> Vec<u32> {
+    buf.chunks(4).map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]])).collect()
+}
+
+#[test]
+fn complex() {
+    let mut ops = dynasmrt::loongarch64::Assembler::new().unwrap();
+    let c = 4u32;
+    let offset = -16i32;
+    let flag = 2u8;
+
+    // interesting testcases
+    my_dynasm!(ops
+        ; test:
+        // no args
+        ; nop
+        ; ret
+        // bare immediate
+        ; dbar 0
+        // expression
+        ; syscall c
+        // registers
+        ; add.d r1, r2, r3
+        ; add.d R(1), R(2), R(3)
+        ; add.d ra, sp, zero
+        ; fadd.d f1, f2, f3
+        ; fadd.d F(1), F(2), F(3)
+        ; fcmp.clt.s fcc1, f2, f3
+        ; fsel f1, f2, f3, FCC(flag)
+        ; movgr2fcsr fcsr0, a0
+        // weird fields
+        ; alsl.d a0, a1, a2, 4
+        ; revb.2h a0, a1
+        ; bstrpick.d a0, a1, 63, 32
+        // memory references
+        ; ld.d a0, [a1]
+        ; ld.d a0, [a1, 0]
+        ; ld.d a0, [a1, 512]
+        ; ld.d a0, [a1, offset]
+        ; ldx.d a0, [a1, a2]
+        ; ll.w a0, [a1, 4]
+        ; amswap.w a0, a1, [a2]
+        ; preld 0, [a1, 64]
+        // all the branches
+        ; b <test
+        ; bl <test
+        ; beq a0, a1, <test
+        ; bgt a0, a1, <test
+        ; beqz a0, <test
+        ; bgez a0, <test
+        ; bceqz fcc0, <test
+        ; jirl ra, a0, 0
+        ; pcaddu12i t0, <test
+        ; addi.d t0, t0, <test + 4
+        ; ld.d t1, [t0, <test + 8]
+        ; la.pcrel t0, <test
+        // load immediates
+        ; li.w a0, 0x7FFF_FFFF
+        ; li.d a0, 0x7FFF_FFFF_FFFF_FFFF
+    );
+
+    let buf = ops.finalize().unwrap();
+
+    println!("Generated assembly:");
+    for i in buf.iter() {
+        print!("{:02x }", i);
+    }
+    println!("");
+}
+
+#[test]
+fn relocations() {
+    let mut ops = dynasmrt::VecAssembler::<Loongarch64Relocation>::new(0);
+    my_dynasm!(ops
+        ; start:
+        ; b >end
+        ; bl >end
+        ; beq a0, a1, <start
+        ; beqz a0, >end
+        ; bceqz fcc1, >end
+        ; la.pcrel t0, >end
+        ; pcaddu12i t1, >end
+        ; ld.d a2, [t1, >end + 4]
+        ; addi.d t2, t1, >end + 8
+        ; .align 0x4000
+        ; end:
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(&words(&buf)[.. 11], &[
+        0x5040_0000, // b +0x4000
+        0x543F_FC00, // bl +0x3FFC
+        0x5BFF_F885, // beq a0, a1, -8
+        0x403F_F480, // beqz a0, +0x3FF4
+        0x483F_F020, // bceqz fcc1, +0x3FF0
+        0x1C00_008C, // pcaddu12i t0, 4
+        0x02FF_B18C, // addi.d t0, t0, -0x14
+        0x1C00_008D, // pcaddu12i t1, 4
+        0x28FF_91A6, // ld.d a2, t1, -0x1C
+        0x02FF_91AE, // addi.d t2, t1, -0x1C
+        0x0000_0000,
+    ]);
+}
+
+#[test]
+fn immediates() {
+    let offset = -0x10_0000i32;
+    let amount = 3u32;
+    let value = 0x1234_5678_9ABC_DEF0i64;
+
+    let mut ops = dynasmrt::VecAssembler::<Loongarch64Relocation>::new(0);
+    my_dynasm!(ops
+        ; b -0x10_0000
+        ; b offset
+        ; li.w a0, 0x1234_5678
+        ; li.w a0, -1
+        ; li.d a0, 0x1234_5678_9ABC_DEF0
+        ; li.d a0, value
+        ; alsl.d a0, a1, a2, 3
+        ; alsl.d a0, a1, a2, amount
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(words(&buf), [
+        0x5000_03FC, 0x5000_03FC,
+        0x1424_68A4, 0x0399_E084,
+        0x15FF_FFE4, 0x03BF_FC84,
+        0x1535_79A4, 0x03BB_C084, 0x168A_CF04, 0x0304_8C84,
+        0x1535_79A4, 0x03BB_C084, 0x168A_CF04, 0x0304_8C84,
+        0x002D_18A4, 0x002D_18A4,
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn dynamic_shift_amount_out_of_range() {
+    let amount = 0u32;
+
+    let mut ops = dynasmrt::VecAssembler::<Loongarch64Relocation>::new(0);
+    my_dynasm!(ops
+        ; alsl.d a0, a1, a2, amount
+    );
+}
+
+#[test]
+fn type_mapped_references() {
+    #[repr(C)]
+    struct Test {
+        foo: u64,
+        bar: u32,
+        baz: u32,
+    }
+
+    let index = 3;
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    my_dynasm!(ops
+        ; ld.d a1, [a0, 0]
+        ; ld.w a1, [a0, 12]
+        ; st.w a1, [a0, 40]
+        ; ld.d a1, [a0, 48]
+        ; ld.d a1, [a0]
+        ; fld.s f1, [a0, -4]
+    );
+    let expected = ops.finalize();
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    my_dynasm!(ops
+        ; ld.d a1, a0 => Test.foo
+        ; ld.w a1, a0 => Test.baz
+        ; st.w a1, a0 => Test[2].bar
+        ; ld.d a1, a0 => Test[index]
+        ; ld.d a1, a0 => Test
+        ; fld.s f1, a0 => Test[-1].baz
+    );
+    let buf = ops.finalize();
+
+    assert_eq!(buf, expected, "type mapped references");
+}
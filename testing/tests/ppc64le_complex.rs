@@ -0,0 +1,253 @@
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+
+use dynasmrt::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+use dynasmrt::ppc64le::Ppc64leRelocation;
+
+// aliases, and dynasm! in item position
+macro_rules! my_dynasm {
+    ($ops:ident $($t:tt)*) => {
+        dynasm!($ops
+            ; .arch ppc64le
+            $($t)*
+        )
+    }
+}
+
+fn words(buf: &[u8]) -> Vec<u32> {
+    buf.chunks(4).map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]])).collect()
+}
+
+#[test]
+fn complex() {
+    let mut ops = dynasmrt::ppc64le::Assembler::new().unwrap();
+    let c = 4u32;
+    let offset = -16i32;
+    let field = 2u8;
+
+    // interesting testcases
+    my_dynasm!(ops
+        ; test:
+        // no args
+        ; nop
+        ; blr
+        ; sync
+        // bare immediate
+        ; sync 1
+        // expression
+        ; mtspr c, r3
+        // registers
+        ; add r1, r2, r3
+        ; add R(1), R(2), R(3)
+        ; add sp, rtoc, r0
+        ; fadd f1, f2, f3
+        ; fadd F(1), F(2), F(3)
+        ; xsadddp vs1, vs34, VS(63)
+        ; lxsd v2, [r3, 8]
+        ; cmpd cr7, r3, r4
+        ; cmpd CR(field), r3, r4
+        ; cmpd r3, r4
+        // record forms
+        ; add. r3, r4, r5
+        ; addo. r3, r4, r5
+        ; andi. r3, r4, 0xFF
+        ; stwcx. r3, r4, r5
+        ; xvcmpeqdp. vs0, vs1, vs2
+        // extended mnemonics
+        ; mr r3, r4
+        ; not r3, r4
+        ; li r3, -1
+        ; sldi r3, r4, 3
+        ; srdi r3, r4, 3
+        ; clrldi r3, r4, 32
+        ; mflr r0
+        ; mtctr r12
+        // memory references
+        ; ld r3, [r4]
+        ; ld r3, [r4, 0]
+        ; ld r3, [r4, 512]
+        ; ld r3, [sp, offset]
+        ; stdu sp, [sp, -64]
+        ; ldx r3, r4, r5
+        ; lxv vs40, [r3, 32]
+        // all the branches
+        ; b <test
+        ; bl <test
+        ; bc 12, 2, <test
+        ; beq <test
+        ; bne cr1, <test
+        ; bdnz <test
+        ; beqlr cr7
+        ; bctrl
+        ; addpcis r3, <test
+        ; addi r3, r3, <test
+        ; ld r4, [r3, <test + 4]
+        ; addis r5, r5, <test
+        ; addi r5, r5, <test + 4
+    );
+
+    let buf = ops.finalize().unwrap();
+
+    println!("Generated assembly:");
+    for i in buf.iter() {
+        print!("{:02x }", i);
+    }
+    println!("");
+}
+
+#[test]
+fn relocations() {
+    let mut ops = dynasmrt::VecAssembler::<Ppc64leRelocation>::new(0);
+    my_dynasm!(ops
+        ; start:
+        ; b >end
+        ; bl <start
+        ; beq cr1, >end
+        ; bdnz <start
+        // addpcis is relative to the next instruction, so it pairs with the following instruction
+        ; addpcis r3, >data
+        ; addi r3, r3, >data
+        ; addpcis r4, >data
+        ; ld r4, [r4, >data]
+        // addis is relative to itself, so the following instruction has to compensate
+        ; addpcis r5, 0
+        ; addis r5, r5, >data
+        ; addi r5, r5, >data + 4
+        ; end:
+        ; blr
+        ; data:
+        ; .u64 0x1234_5678_9ABC_DEF0
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(words(&buf), [
+        0x4800_002C, // b .+44
+        0x4BFF_FFFD, // bl .-4
+        0x4186_0024, // beq 1, .+36
+        0x4200_FFF4, // bdnz .-12
+        0x4C60_0004, // addpcis 3, 0
+        0x3863_001C, // addi 3, 3, 28
+        0x4C80_0004, // addpcis 4, 0
+        0xE884_0014, // ld 4, 20(4)
+        0x4CA0_0004, // addpcis 5, 0
+        0x3CA5_0000, // addis 5, 5, 0
+        0x38A5_000C, // addi 5, 5, 12
+        0x4E80_0020, // blr
+        0x9ABC_DEF0, 0x1234_5678,
+    ]);
+}
+
+#[test]
+fn immediates() {
+    let imm = -1i32;
+    let upper = 0x1234i32;
+    let shift = 13u32;
+    let right = 3u32;
+    let vs = 33u8;
+    let cr = 6u8;
+    let offset = -16i32;
+    let vector_offset = -32i32;
+    let pc_offset = -2i32;
+
+    let mut ops = dynasmrt::VecAssembler::<Ppc64leRelocation>::new(0);
+    my_dynasm!(ops
+        ; li r3, -1
+        ; li r3, imm
+        ; lis r4, upper
+        ; sldi r5, r6, 13
+        ; sldi r5, r6, shift
+        ; srdi r5, r6, 13
+        ; srdi r5, r6, shift
+        ; srwi r7, r8, 3
+        ; srwi r7, r8, right
+        ; clrrwi r7, r8, 3
+        ; clrrwi r7, r8, right
+        ; xsadddp vs33, vs2, vs63
+        ; xsadddp VS(vs), VS(2), VS(63)
+        ; cmpdi cr6, r3, -12
+        ; cmpdi CR(cr), r3, -12
+        ; std r3, [sp, -16]
+        ; std r3, [sp, offset]
+        ; lxv vs40, [r3, -32]
+        ; lxv vs40, [r3, vector_offset]
+        ; addpcis r3, -2
+        ; addpcis r3, pc_offset
+        ; beq -16
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(words(&buf), [
+        0x3860_FFFF, 0x3860_FFFF,
+        0x3C80_1234,
+        0x78C5_6CA4, 0x78C5_6CA4,
+        0x78C5_9B42, 0x78C5_9B42,
+        0x5507_E8FE, 0x5507_E8FE,
+        0x5507_0038, 0x5507_0038,
+        0xF022_F903, 0xF022_F903,
+        0x2F23_FFF4, 0x2F23_FFF4,
+        0xF861_FFF0, 0xF861_FFF0,
+        0xF503_FFE9, 0xF503_FFE9,
+        0x4C7F_FFC4, 0x4C7F_FFC4,
+        0x4182_FFF0,
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn dynamic_shift_amount_out_of_range() {
+    let shift = 64u32;
+
+    let mut ops = dynasmrt::VecAssembler::<Ppc64leRelocation>::new(0);
+    my_dynasm!(ops
+        ; sldi r3, r4, shift
+    );
+}
+
+#[test]
+#[should_panic]
+fn dynamic_offset_misaligned() {
+    let offset = 6i32;
+
+    let mut ops = dynasmrt::VecAssembler::<Ppc64leRelocation>::new(0);
+    my_dynasm!(ops
+        ; ld r3, [r4, offset]
+    );
+}
+
+#[test]
+fn type_mapped_references() {
+    #[repr(C)]
+    struct Test {
+        foo: u64,
+        bar: u32,
+        baz: u32,
+    }
+
+    let index = 3;
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    my_dynasm!(ops
+        ; ld r3, [r4, 0]
+        ; lwz r3, [r4, 12]
+        ; stw r3, [r4, 40]
+        ; ld r3, [r4, 48]
+        ; ld r3, [r4]
+        ; lwz r3, [r4, -4]
+    );
+    let expected = ops.finalize();
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    my_dynasm!(ops
+        ; ld r3, r4 => Test.foo
+        ; lwz r3, r4 => Test.baz
+        ; stw r3, r4 => Test[2].bar
+        ; ld r3, r4 => Test[index]
+        ; ld r3, r4 => Test
+        ; lwz r3, r4 => Test[-1].baz
+    );
+    let buf = ops.finalize();
+
+    assert_eq!(buf, expected, "type mapped references");
+}
@@ -94,3 +94,39 @@ fn complex() {
     }
     println!("");
 }
+
+#[test]
+fn type_mapped_references() {
+    #[repr(C)]
+    struct Test {
+        foo: u64,
+        bar: u32,
+        baz: u32,
+    }
+
+    let index = 3;
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    my_dynasm!(ops
+        ; ld a1, [a0, 0]
+        ; lw a1, [a0, 12]
+        ; sw a1, [a0, 40]
+        ; ld a1, [a0, 48]
+        ; ld a1, [a0]
+        ; flw f1, [a0, -4]
+    );
+    let expected = ops.finalize();
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    my_dynasm!(ops
+        ; ld a1, a0 => Test.foo
+        ; lw a1, a0 => Test.baz
+        ; sw a1, a0 => Test[2].bar
+        ; ld a1, a0 => Test[index]
+        ; ld a1, a0 => Test
+        ; flw f1, a0 => Test[-1].baz
+    );
+    let buf = ops.finalize();
+
+    assert_eq!(buf, expected, "type mapped references");
+}
@@ -74,6 +74,35 @@ fn csrs() {
     assert!(are_chunks_equal(&buf, 4), "csrs");
 }
 
+#[test]
+fn vtype() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch riscv64
+        ; .feature GV
+        ; vsetvli x10, x11, e32, m4, ta, ma
+        ; vsetvli x10, x11, 0xD2
+        ; vsetvli x10, x11, i(0xD2)
+        ; vsetvli X(10), X(11), e32, m4, ta, ma
+    );
+    let buf = ops.finalize();
+    assert!(are_chunks_equal(&buf, 4), "vtype");
+}
+
+#[test]
+fn vector_uimm6() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch riscv64
+        ; .feature GVZvbb
+        ; vror.vi v4, v8, 45, v0.t
+        ; vror.vi v4, v8, i(45), v0.t
+        ; vror.vi V(4), V(8), i(45), v0.t
+    );
+    let buf = ops.finalize();
+    assert!(are_chunks_equal(&buf, 4), "vector_uimm6");
+}
+
 #[test]
 fn uimm() {
     let mut ops = dynasmrt::SimpleAssembler::new();
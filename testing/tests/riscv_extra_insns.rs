@@ -479,3 +479,289 @@ fn pc_rel_store_32() {
     assert_eq!(hex, "AABBCCDDEEFF0011223344556677889997000000238810FE17010000231421FE9701000023A031FE97020000879282FD17030000272863FC9703000027B473FC17040000274084FC17080000230C18FA231C38FA232C58FA271C88FA272C98FA273CA8FA274CB8FA", "pc-relative stores");
 }
 
+
+// the vector crypto extensions aren't supported by the available assemblers yet
+
+#[test]
+fn zvkned() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch riscv64
+        ; .feature GVZvkned
+        ; vaesdf.vv v4, v8
+        ; vaesdm.vs v5, v9
+        ; vaesef.vv v6, v10
+        ; vaesem.vs v7, v11
+        ; vaesz.vs v8, v12
+        ; vaeskf1.vi v9, v13, 10
+        ; vaeskf2.vi v10, v14, 3
+    );
+    let buf = ops.finalize();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join("");
+    assert_eq!(hex, "77A280A2F72290A677A3A1A2F723B1A677A4C3A6F724D58A77A5E1AA", "zvkned tests");
+}
+
+#[test]
+fn zvknh() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch riscv32
+        ; .feature GVZvknha
+        ; vsha2ms.vv v4, v8, v12
+        ; vsha2ch.vv v5, v9, v13
+        ; vsha2cl.vv v6, v10, v14
+    );
+    let buf = ops.finalize();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join("");
+    assert_eq!(hex, "772286B6F7A296BA7723A7BE", "zvknh tests");
+}
+
+#[test]
+fn zvbb() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch riscv64
+        ; .feature GVZvbb
+        ; vror.vi v4, v8, 63
+        ; vror.vi v5, v9, 7, v0.t
+        ; vandn.vv v1, v2, v3
+        ; vrol.vx v4, v5, x6, v0.t
+        ; vwsll.vi v8, v12, 31
+        ; vclz.v v2, v3
+        ; vcpop.v v4, v5, v0.t
+    );
+    let buf = ops.finalize();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join("");
+    assert_eq!(hex, "57B28F56D7B29350D78021065742535457B4CFD65721364A57225748", "zvbb tests");
+}
+
+// privileged instructions
+
+#[test]
+fn priv_insns() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch riscv64
+        ; .feature priv
+        ; sret
+        ; mret
+        ; wfi
+        ; sfence.vma
+        ; sfence.vma x10
+        ; sfence.vma x10, x11
+    );
+    let buf = ops.finalize();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join("");
+    assert_eq!(hex, "73002010730020307300501073000012730005127300B512", "priv tests");
+}
+
+#[test]
+fn h_insns() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch riscv64
+        ; .feature IH
+        ; hfence.vvma x10, x11
+        ; hfence.gvma
+        ; hlv.b x10, [x11]
+        ; hlv.hu x5, [x6]
+        ; hlvx.wu x12, [x13]
+        ; hlv.d x8, [x9]
+        ; hsv.w x10, [x11]
+        ; hsv.d x5, [sp]
+    );
+    let buf = ops.finalize();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join("");
+    assert_eq!(hex, "7300B5227300006273C50560F342136473C6366873C4046C73C0A56A7340516E", "h tests");
+}
+
+#[test]
+fn svinval_insns() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch riscv32
+        ; .feature IH_Svinval
+        ; sinval.vma x10, x11
+        ; sfence.w.inval
+        ; sfence.inval.ir
+        ; hinval.vvma x5
+        ; hinval.gvma x6, x7
+    );
+    let buf = ops.finalize();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join("");
+    assert_eq!(hex, "7300B51673000018730010187380022673007366", "svinval tests");
+}
+
+#[test]
+fn priv_csrs() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch riscv64
+        ; .feature IZicsr
+        ; csrr x10, mstatus
+        ; csrw satp, x11
+        ; csrr x5, hgatp
+        ; csrr x6, mbadaddr
+    );
+    let buf = ops.finalize();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join("");
+    assert_eq!(hex, "7325003073900518F322006873233034", "privileged csr tests");
+}
+
+#[test]
+fn compress_64() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch riscv64
+        ; .feature gc, compress
+        ; add a0, a0, a1
+        ; add a0, a1, a0
+        ; add a0, zero, a1
+        ; mv s1, t0
+        ; addi sp, sp, -64
+        ; addi s0, sp, 16
+        ; addi a0, zero, -5
+        ; addi t0, t0, 7
+        ; addi t0, t1, 0
+        ; addi zero, zero, 0
+        ; li a5, 31
+        ; addiw a0, a0, -1
+        ; sext.w a1, a1
+        ; lui t0, 0x1F000
+        ; slli t0, t0, 40
+        ; srli a0, a0, 3
+        ; srai a1, a1, 63
+        ; andi a2, a2, -8
+        ; sub a3, a3, a4
+        ; subw a3, a3, a4
+        ; and a0, a1, a0
+        ; or a0, a0, a1
+        ; xor a5, a5, a4
+        ; addw s0, s1, s0
+        ; lw a0, [a1, 4]
+        ; ld a0, [a1, 248]
+        ; sw a0, [a1, 124]
+        ; sd a2, [a3, 0]
+        ; lw ra, [sp, 12]
+        ; ld ra, [sp, 504]
+        ; sw zero, [sp, 252]
+        ; sd s11, [sp, 8]
+        ; fld fa0, [a0, 16]
+        ; fsd f8, [sp, 8]
+        ; fld ft0, [sp]
+        ; jalr zero, t0, 0
+        ; jalr ra, t1, 0
+        ; jalr t2
+        ; jr t3
+        ; ret
+        ; nop
+        ; ebreak
+        // these cannot be compressed
+        ; addi a0, a1, 1
+        ; add a0, a1, a2
+        ; lw a0, [a1, 128]
+        ; lw a0, [a1, 2]
+        ; ld a0, [sp, 12]
+        ; slli t0, t1, 3
+        ; lui sp, 0x1000
+        ; srli t0, t0, 3
+        // this one can again
+        ; addi sp, sp, 8
+    );
+    let buf = ops.finalize();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join("");
+    assert_eq!(hex, "2E952E952E859684397100086D559D029A820100FD477D358125FD62A2120D81FD95619A998E999E6D8D4D8DB98F259CC841E87DE8DD90E2B240FE7082DF6EE4082922A40220828202938293028E828001000290138515003385C50003A5050803A525000335C100931233003711000093D232002101", "compress tests");
+}
+
+#[test]
+fn compress_32() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch riscv32
+        ; .feature ifc, compress
+        ; slli t0, t0, 31
+        ; srai a0, a0, 31
+        ; flw fa0, [a1, 4]
+        ; fsw f9, [sp, 124]
+        ; lw s0, [sp, 0]
+        ; addi s1, sp, 1020
+        ; lui a0, -0x2_0000
+    );
+    let buf = ops.finalize();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join("");
+    assert_eq!(hex, "FE027D85C861A6FE0244E41F0175", "compress tests");
+}
+
+#[test]
+fn compress_zcb() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch riscv64
+        ; .feature gc_zba_zbb_zcb, compress
+        ; lbu a0, [a1, 3]
+        ; lhu a0, [a1, 2]
+        ; lh a0, [a1, 0]
+        ; sb a0, [a1, 1]
+        ; sh a0, [a1, 2]
+        ; andi s0, s0, 0xFF
+        ; zext.b s0, s0
+        ; zext.h s0, s0
+        ; zext.w s0, s0
+        ; sext.b s0, s0
+        ; sext.h s0, s0
+        ; xori s1, s1, -1
+        ; not s1, s1
+        ; mul a0, a1, a0
+        ; .feature gc_zba_zbb_zcb
+        ; c.lbu a0, [a1, 3]
+        ; c.lhu a0, [a1, 2]
+        ; c.lh a0, [a1, 0]
+        ; c.sb a0, [a1, 1]
+        ; c.sh a0, [a1, 2]
+        ; c.zext.b s0
+        ; c.zext.b s0
+        ; c.zext.h s0
+        ; c.zext.w s0
+        ; c.sext.b s0
+        ; c.sext.h s0
+        ; c.not s1
+        ; c.not s1
+        ; c.mul a0, a1
+    );
+    let buf = ops.finalize();
+    assert_eq!(buf.len(), 56, "compress tests");
+    assert_eq!(buf[.. 28], buf[28 ..], "compress tests");
+}
+
+#[test]
+fn compress_dynamic() {
+    let reg = 11;
+    let imm = 1;
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch riscv64
+        ; .feature gc, compress
+        ; add a0, a0, X(reg)
+        ; addi a0, a0, imm
+        ; lw a0, [a1, imm * 4]
+        // no compression without the feature
+        ; .feature gc
+        ; add a0, a0, a1
+        // or when the c extension is not enabled
+        ; .feature g, compress
+        ; add a0, a0, a1
+    );
+    let buf = ops.finalize();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{:02X}", *x)).collect();
+    let hex = hex.join("");
+    assert_eq!(hex, "3305B5001305150003A545003305B5003305B500", "compress tests");
+}
@@ -0,0 +1,334 @@
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+
+use dynasmrt::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+use dynasmrt::arm::ArmRelocation;
+
+// aliases, and dynasm! in item position
+macro_rules! my_dynasm {
+    ($ops:ident $($t:tt)*) => {
+        dynasm!($ops
+            ; .arch thumb2
+            $($t)*
+        )
+    }
+}
+
+fn halfwords(buf: &[u8]) -> Vec<u16> {
+    buf.chunks(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect()
+}
+
+#[test]
+fn complex() {
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    let c = 4u32;
+    let offset = -16i32;
+    let reg = 3u8;
+
+    // interesting testcases
+    my_dynasm!(ops
+        ; test:
+        // no args
+        ; nop
+        ; nop.w
+        ; bx lr
+        // bare immediate
+        ; svc 0
+        // expression
+        ; svc c
+        // registers
+        ; add r1, r2, r3
+        ; add R(1), R(2), R(reg)
+        ; add.w r8, r9, r10
+        ; mov fp, sp
+        // flag setting
+        ; adds r0, r0, r1
+        ; adds.w r8, r8, #1
+        // shifts
+        ; add r0, r1, r2, lsl #4
+        ; mov r0, r1, ror #8
+        ; lsl r0, r1, #3
+        ; lsl.w r0, r1, r2
+        // modified immediates
+        ; add r0, r1, #0xFF00
+        ; add r0, r1, #0x00FF_00FF
+        ; and r0, r1, #0xFFFF_FF00
+        ; mov r0, c
+        ; movw r0, #0x1234
+        // memory references
+        ; ldr r0, [r1]
+        ; ldr r0, [r1, #4]
+        ; ldr r0, [r1, offset]
+        ; ldr r0, [r1, #4]!
+        ; ldr r0, [r1], #4
+        ; ldr r0, [r1, r2]
+        ; ldr r0, [r1, r2, lsl #2]
+        ; ldrd r2, r3, [r1, #16]
+        ; ldrex r0, [r1]
+        ; strex r0, r2, [r1]
+        // register lists
+        ; push {r4, lr}
+        ; pop {r4-r11, pc}
+        ; ldm r0!, {r1, r2, R(reg)}
+        // IT blocks
+        ; ite eq
+        ; moveq r0, r1
+        ; movne r0, r2
+        // bitfields and others
+        ; ubfx r0, r1, #4, #8
+        ; bfi r0, r1, #8, #4
+        ; uxtb r0, r1, ror #8
+        ; dmb ish
+        ; mrs r0, apsr
+        // all the branches
+        ; b <test
+        ; bl <test
+        ; bgt <test
+        ; blx r0
+        ; cbz r0, >next
+        ; cbnz r0, >next
+        ; nop
+        ; next:
+        ; adr r0, <test
+        ; ldr r0, <test
+    );
+
+    let buf = ops.finalize().unwrap();
+
+    println!("Generated assembly:");
+    for i in buf.iter() {
+        print!("{:02x }", i);
+    }
+    println!("");
+}
+
+#[test]
+fn encodings() {
+    let mut ops = dynasmrt::VecAssembler::<ArmRelocation>::new(0);
+    my_dynasm!(ops
+        ; add.w r0, r1, #0x00FF_00FF
+        ; add.w r0, r1, #0xAB00_AB00
+        ; movw r0, #0x1234
+        ; ldr.w r0, [r1, #0x123]
+        ; lsl.w r0, r1, #3
+        ; ubfx r0, r1, #4, #8
+        ; add.w r8, r9, r10, lsl #2
+        ; ldr.w r0, [r1, r2, lsl #2]
+        ; push.w {r4-r11, lr}
+        ; pop.w {r4, pc}
+        ; sub sp, sp, #16
+        ; add r0, sp, #8
+        ; movs r0, #1
+        ; adds r1, r2, #3
+        ; ldr r0, [sp, #8]
+        ; str r1, [r2, #4]
+        ; bx lr
+        ; svc 5
+        ; dmb ish
+        ; nop
+        ; ldr r0, [r1, #-8]
+        ; str r0, [r1, #-8]!
+        ; ldr r0, [r1], #-8
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(halfwords(&buf), [
+        0xF101, 0x10FF, // add.w r0, r1, #0x00FF00FF
+        0xF101, 0x20AB, // add.w r0, r1, #0xAB00AB00
+        0xF241, 0x2034, // movw r0, #0x1234
+        0xF8D1, 0x0123, // ldr.w r0, [r1, #0x123]
+        0xEA4F, 0x00C1, // lsl.w r0, r1, #3
+        0xF3C1, 0x1007, // ubfx r0, r1, #4, #8
+        0xEB09, 0x088A, // add.w r8, r9, r10, lsl #2
+        0xF851, 0x0022, // ldr.w r0, [r1, r2, lsl #2]
+        0xE92D, 0x4FF0, // push.w {r4-r11, lr}
+        0xE8BD, 0x8010, // pop.w {r4, pc}
+        0xB084,         // sub sp, sp, #16
+        0xA802,         // add r0, sp, #8
+        0x2001,         // movs r0, #1
+        0x1CD1,         // adds r1, r2, #3
+        0x9802,         // ldr r0, [sp, #8]
+        0x6051,         // str r1, [r2, #4]
+        0x4770,         // bx lr
+        0xDF05,         // svc 5
+        0xF3BF, 0x8F5B, // dmb ish
+        0xBF00,         // nop
+        0xF851, 0x0C08, // ldr r0, [r1, #-8]
+        0xF841, 0x0D08, // str r0, [r1, #-8]!
+        0xF851, 0x0908, // ldr r0, [r1], #-8
+    ]);
+}
+
+#[test]
+fn it_blocks() {
+    let mut ops = dynasmrt::VecAssembler::<ArmRelocation>::new(0);
+    my_dynasm!(ops
+        ; it eq
+        ; moveq r0, r1
+        ; ite ne
+        ; addne r0, r0, #1
+        ; subeq r1, r1, #2
+        ; itte lt
+        ; movlt r2, #5
+        ; addlt.w r9, r9, #0x100
+        ; movge r3, r4
+        ; ittet hi
+        ; andhi r0, r1
+        ; ldrhi r2, [r3, #4]
+        ; strls r2, [r3, #8]
+        ; bxhi lr
+        ; it cs
+        ; addcs r8, r8, r1
+        // outside of an IT block, 16-bit data processing instructions set the flags
+        ; adds r0, r0, r1
+        ; adds.w r0, r0, r1
+        ; add r0, r0, r1
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(halfwords(&buf), [
+        0xBF08, 0x4608,
+        0xBF14, 0x1C40, 0x1E89,
+        0xBFBA, 0x2205, 0xF509, 0x7980, 0x4623,
+        0xBF85, 0x4008, 0x685A, 0x609A, 0x4770,
+        0xBF28, 0x4488,
+        0x1840, 0xEB10, 0x0001, 0x4408,
+    ]);
+}
+
+#[test]
+fn relocations() {
+    let mut ops = dynasmrt::VecAssembler::<ArmRelocation>::new(0);
+    my_dynasm!(ops
+        ; start:
+        ; b.n >end
+        ; b.w >end
+        ; bl >end
+        ; beq.n <start
+        ; bne.w >end
+        ; cbz r0, >end2
+        ; cbnz r3, >end2
+        ; ldr.n r0, >end
+        ; ldr.w r1, >end
+        ; ldr.w r1, <start
+        ; ldrb.w r2, >end
+        ; adr.n r3, >end
+        ; adr.w r4, >end
+        ; adr.w r5, <start
+        ; nop
+        ; end2:
+        ; nop
+        ; .align 0x100
+        ; end:
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(&halfwords(&buf)[.. 24], &[
+        0xE07E,         // b.n +0x100
+        0xF000, 0xB87D, // b.w +0xFE
+        0xF000, 0xF87B, // bl +0xFA
+        0xD0F9,         // beq.n -0xA
+        0xF040, 0x8078, // bne.w +0xF4
+        0xB168,         // cbz r0, +0x1E
+        0xB963,         // cbnz r3, +0x1C
+        0x483A,         // ldr.n r0, +0xEA
+        0xF8DF, 0x10E8, // ldr.w r1, +0xE8
+        0xF85F, 0x101C, // ldr.w r1, -0x18
+        0xF89F, 0x20E0, // ldrb.w r2, +0xE0
+        0xA337,         // adr.n r3, +0xDC
+        0xF20F, 0x04D8, // adr.w r4, +0xDA
+        0xF2AF, 0x052C, // adr.w r5, -0x28
+        0xBF00,         // nop
+        0xBF00,         // nop
+    ]);
+}
+
+#[test]
+fn immediates() {
+    let imm = 0x00FF_00FFu32;
+    let offset = -8i32;
+    let amount = 3u32;
+    let lsb = 4u32;
+    let width = 8u32;
+
+    let mut ops = dynasmrt::VecAssembler::<ArmRelocation>::new(0);
+    my_dynasm!(ops
+        ; add.w r0, r1, #0x00FF_00FF
+        ; add.w r0, r1, #imm
+        ; ldr r0, [r1, #-8]
+        ; ldr r0, [r1, #offset]
+        ; lsl.w r0, r1, #3
+        ; lsl.w r0, r1, #amount
+        ; ubfx r0, r1, #4, #8
+        ; ubfx r0, r1, #lsb, #width
+    );
+    let buf = ops.finalize().unwrap();
+
+    assert_eq!(halfwords(&buf), [
+        0xF101, 0x10FF, 0xF101, 0x10FF,
+        0xF851, 0x0C08, 0xF851, 0x0C08,
+        0xEA4F, 0x00C1, 0xEA4F, 0x00C1,
+        0xF3C1, 0x1007, 0xF3C1, 0x1007,
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn dynamic_modified_immediate_out_of_range() {
+    let imm = 0x1234u32;
+
+    let mut ops = dynasmrt::VecAssembler::<ArmRelocation>::new(0);
+    my_dynasm!(ops
+        ; add.w r0, r1, #imm
+    );
+}
+
+#[test]
+#[should_panic]
+fn dynamic_low_register_out_of_range() {
+    let reg = 8u8;
+
+    let mut ops = dynasmrt::VecAssembler::<ArmRelocation>::new(0);
+    my_dynasm!(ops
+        ; adds.n R(reg), r1, r2
+    );
+}
+
+#[test]
+fn type_mapped_references() {
+    #[repr(C)]
+    struct Test {
+        foo: u32,
+        bar: u16,
+        baz: u16,
+    }
+
+    let index = 3;
+
+    // type mapped references use a dynamic offset, so they never pick the 16-bit encodings
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    my_dynasm!(ops
+        ; ldr.w r1, [r0, #0]
+        ; ldrh.w r1, [r0, #6]
+        ; strh.w r1, [r0, #20]
+        ; ldr.w r1, [r0, #24]
+        ; ldr r1, [r0]
+        ; ldrh r1, [r0, #-2]
+    );
+    let expected = ops.finalize();
+
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    my_dynasm!(ops
+        ; ldr r1, r0 => Test.foo
+        ; ldrh r1, r0 => Test.baz
+        ; strh r1, r0 => Test[2].bar
+        ; ldr r1, r0 => Test[index]
+        ; ldr r1, r0 => Test
+        ; ldrh r1, r0 => Test[-1].baz
+    );
+    let buf = ops.finalize();
+
+    assert_eq!(buf, expected, "type mapped references");
+}
@@ -0,0 +1,27 @@
+#![allow(unused_imports)]
+
+use dynasmrt::dynasm;
+use dynasmrt::DynasmApi;
+
+include!("gen_x64/apx.rs.gen");
+
+// dynamic r16-r31 registers, using the rex2 prefix for legacy instructions
+#[test]
+fn apx_dynamic_registers() {
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch x64
+        ; rex2 add Rq(16), Rq(1)
+        ; rex2 imul Rq(16), Rq(17)
+        ; rex2 mov Rq(31), QWORD [Rq(20) + Rq(21) * 8]
+        ; rex2 push Rq(31)
+        ; add Rq(16), Rq(17), Rq(18)
+        ; push2 Rq(16), Rq(31)
+        ; ccmpz {dfv=of, cf} Rq(16), Rq(25)
+        ; vaddps zmm0, zmm1, [Rq(16) + Rq(17) * 4]
+    );
+    let buf = ops.finalize();
+    let hex: Vec<String> = buf.iter().map(|x| format!("0x{:02X}", *x)).collect();
+    let hex: String = hex.join(", ");
+    assert_eq!(hex, "0xD5, 0x18, 0x01, 0xC8, 0xD5, 0xD8, 0xAF, 0xC1, 0xD5, 0x7C, 0x8B, 0x7C, 0xEC, 0x00, 0xD5, 0x11, 0x57, 0x62, 0xEC, 0xFC, 0x10, 0x01, 0xD1, 0x62, 0xDC, 0x7C, 0x10, 0xFF, 0xF7, 0x62, 0xCC, 0xCC, 0x04, 0x3B, 0xC1, 0x62, 0xF9, 0x70, 0x48, 0x58, 0x44, 0x88, 0x00", "apx_dynamic_registers");
+}
@@ -0,0 +1,74 @@
+#![allow(unused_imports)]
+
+use dynasmrt::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi};
+
+
+#[test]
+fn test_local_jumps() {
+    let mut ops = dynasmrt::VecAssembler::<dynasmrt::x86_16::X86_16Relocation>::new(0);
+
+    dynasm!(ops
+        ; .arch x86_16
+        ; jmp BYTE >foo
+        ; inc ax
+        ; foo:
+        ; dec ax
+        ; jmp BYTE <foo
+
+        ; jmp >bar
+        ; inc ax
+        ; bar:
+        ; dec ax
+        ; jmp <bar
+
+        ; jz >baz
+        ; call >baz
+        ; baz:
+        ; call <baz
+    );
+
+    let output = ops.finalize().unwrap();
+
+    for i in &output {
+        print!("\\x{:02x}", i);
+    }
+    println!("");
+
+    let expected: &[u8] = b"\
+\xeb\x01\x40\x48\xeb\xfd\xe9\x01\x00\x40\x48\xe9\xfc\xff\x0f\x84\
+\x03\x00\xe8\x00\x00\xe8\xfd\xff";
+    assert!(&output == expected);
+}
+
+
+#[test]
+fn test_dynamic_jumps() {
+    let mut ops = dynasmrt::VecAssembler::<dynasmrt::x86_16::X86_16Relocation>::new(0x7C00);
+    let start = ops.new_dynamic_label();
+    let end = ops.new_dynamic_label();
+
+    dynasm!(ops
+        ; .arch x86_16
+        ;=>start
+        ; cli
+        ; xor ax, ax
+        ; mov ds, ax
+        ; jmp =>end
+        ; int 0x10
+        ;=>end
+        ; hlt
+        ; jmp =>start
+    );
+
+    let output = ops.finalize().unwrap();
+
+    for i in &output {
+        print!("\\x{:02x}", i);
+    }
+    println!("");
+
+    let expected: &[u8] = b"\
+\xfa\x31\xc0\x8e\xd8\xe9\x02\x00\xcd\x10\xf4\xe9\xf2\xff";
+    assert!(&output == expected);
+}
@@ -255,7 +255,23 @@ def merge_matchers(ops):
         group[:] = [op for op in newgroup if op.matcher not in discards]
 
 
-def emit_opmap(ops, f):
+def load_extension_tags(path):
+    # the XML data doesn't tell which extension is required for an instruction, so these
+    # are maintained in the opmap itself. Read them back so they survive regeneration.
+    tags = {}
+    mnemonic = None
+    with open(path, "r", encoding="utf-8") as f:
+        for line in f:
+            m = re.match(r'^"([^"]+)" = \[', line)
+            if m:
+                mnemonic = m.group(1)
+                continue
+            m = re.match(r'^    0b([01_]+) = \[(.*)\] => \[(.*)\], \[(.*)\];', line)
+            if m:
+                tags[(mnemonic, m.group(1), m.group(2), m.group(3))] = m.group(4)
+    return tags
+
+def emit_opmap(ops, f, tags):
     for m, group in ops:
         f.write("\"{}\" = [\n".format(m.lower()))
 
@@ -270,7 +286,8 @@ def emit_opmap(ops, f):
             bits = op.bits.replace("x", "0").replace("N", "0").replace("Z", "0").replace("z", "0")
             bits = bits[0:8] + "_" + bits[8:16] + "_" + bits[16:24] + "_" + bits[24:32]
 
-            f.write("    0b{} = [{}] => [{}];\n".format(bits, op.matcher, op.processor))
+            ext = tags.get((m.lower(), bits, op.matcher, op.processor), "Ex_BASE")
+            f.write("    0b{} = [{}] => [{}], [{}];\n".format(bits, op.matcher, op.processor, ext))
 
 
         f.write("]\n")
@@ -483,9 +500,11 @@ def main():
 
     #emit the actual opmap
 
+    tags = load_extension_tags("../plugin/src/arch/aarch64/opmap.rs")
+
     with open("../plugin/src/arch/aarch64/opmap.rs", "w", encoding="utf-8") as f:
         f.write("// This file was generated bo tools/aarch64_gen_opmap.py\nOps!(\n\n")
-        emit_opmap(ops, f)
+        emit_opmap(ops, f, tags)
         f.write("\n);\n")
 
 if __name__ == '__main__':
@@ -0,0 +1,100 @@
+#!/usr/bin/python3
+
+# this file takes the testcase listing generated by arm_gen_tests.py
+# and compiles them using `llvm-mc`, then using `llvm-objcopy` to extract the generated machine code.
+
+import subprocess
+import binascii
+import argparse
+from pathlib import Path
+
+def read_test_strings(f):
+    buf = []
+    for line in f:
+        if line:
+            dynasm, gas = line.split("\t")
+            buf.append((dynasm.strip(), gas.strip()))
+
+    return buf
+
+def compile_with_llvm_mc(asmstring, thumb, prefix):
+    with open("test.s", "w", encoding="utf-8") as f:
+        f.write(".syntax unified\n")
+        if thumb:
+            f.write(".thumb\n")
+        f.write(asmstring)
+        f.write("\n")
+
+    if thumb:
+        target = ["-triple=thumbv7em-none-eabi"]
+    else:
+        target = ["-triple=armv7-none-eabi", "-mattr=+hwdiv-arm"]
+    subprocess.run([f"llvm-mc{prefix}", *target, "-filetype=obj", "test.s", "-o", "test.o"], check=True, capture_output=True)
+    subprocess.run([f"llvm-objcopy{prefix}", "-O", "binary", "--only-section=.text", "test.o", "test.bin"], check=True)
+
+    with open("test.bin", "rb") as f:
+        data = f.read()
+
+    return data
+
+def check_width(gas, binary):
+    # llvm-mc silently widens instructions with a .n qualifier if they have no 16-bit encoding
+    mnemonic = gas.split(" ")[0]
+    if mnemonic.endswith(".n") and len(binary) != 2:
+        return False
+    if mnemonic.endswith(".w") and len(binary) != 4:
+        return False
+    return True
+
+def write_result(buf, f):
+    for dynasm, gas, binary in buf:
+        binary = binascii.hexlify(binary).decode("utf-8")
+        f.write(f"{dynasm}\t{gas}\t{binary}\n")
+
+def main():
+    parser = argparse.ArgumentParser("arm_compile_tests",  description="compile ARM / Thumb-2 testcases using `llvm-mc` to use as reference output")
+    parser.add_argument("--thumb", help="assemble the testcases as thumb code", action="store_true")
+    parser.add_argument("--suffix", help="version suffix of the llvm executables, i.e. -14", default="")
+
+    parser.add_argument("input_file", help="The output of arm_gen_tests.py", type=Path)
+    parser.add_argument("output_file", help="The file with compiled test case data", type=Path)
+
+    args = parser.parse_args()
+
+
+    with args.input_file.open("r", encoding="utf-8") as f:
+        test_strings = read_test_strings(f)
+
+    buf = []
+    for dynasm, gas in test_strings:
+        try:
+            binary = compile_with_llvm_mc(gas, args.thumb, args.suffix)
+            buf.append((dynasm, gas, binary))
+            continue
+        except subprocess.CalledProcessError as e:
+            error = e
+
+        # llvm-mc rejects the .w qualifier on instructions that only have a 32-bit encoding
+        mnemonic, _, rest = gas.partition(" ")
+        if mnemonic.endswith(".w"):
+            try:
+                binary = compile_with_llvm_mc(f"{mnemonic[:-2]} {rest}", args.thumb, args.suffix)
+                buf.append((dynasm, gas, binary))
+                continue
+            except subprocess.CalledProcessError as e:
+                error = e
+
+        print(f"Error at {gas}:\n{error.stderr.decode('utf-8')}")
+
+    filtered = []
+    for dynasm, gas, binary in buf:
+        if check_width(gas, binary):
+            filtered.append((dynasm, gas, binary))
+        else:
+            print(f"Error at {gas}: encoded with a different width")
+
+    with args.output_file.open("w", encoding="utf-8") as f:
+        write_result(filtered, f)
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,413 @@
+#!/usr/bin/python3
+
+# this file takes the opmap exported by docs/insref/export.rs and generates a big list of test cases
+# with it that can be compared with another assembler.
+# syntax is arm_gen_tests.py [--thumb] input_file test_multiplier output_file
+
+import ast
+import random
+import re
+from pathlib import Path
+import argparse
+
+CONDITIONS = ["eq", "ne", "cs", "cc", "mi", "pl", "vs", "vc", "hi", "ls", "ge", "lt", "gt", "le"]
+
+# only branches can have their offsets tested without labels
+BRANCHES = {"b", "bl", "cbz", "cbnz"}
+
+def main():
+    parser = argparse.ArgumentParser("arm_gen_tests",  description="generate ARM / Thumb-2 testcases from the exported dynasm-rs opmap definitions")
+    parser.add_argument("--thumb", help="the opmap is a thumb2 opmap", action="store_true")
+    parser.add_argument("opmap_export_file", type=Path)
+    parser.add_argument("test_multiplier", type=int)
+    parser.add_argument("result_file", type=Path)
+    args = parser.parse_args()
+
+    with args.opmap_export_file.open("r", encoding="utf-8") as f:
+        templates = read_opdata_file(f, args.thumb)
+
+    tests = []
+
+    for template in templates:
+        mnemonic = template.template.split()[0].split(".")[0].replace("<Cond>", "")
+        if "Off" in template.template and mnemonic not in BRANCHES:
+            print(f"skipping {template.template} because it requires a label")
+            continue
+
+        for _ in range(args.test_multiplier):
+            tests.append(template.create_entry())
+
+    with args.result_file.open("w", encoding="utf-8") as f:
+        for dynasm, gas in tests:
+            f.write(f"{dynasm}\t{gas}\n")
+
+class OpTemplate:
+    def __init__(self, template, constraints, thumb):
+        self.template = template
+        self.constraints = constraints
+        self.thumb = thumb
+
+        self.args = parse_template(template)
+
+        # arguments that other constraints refer to have to be static
+        self.static = set()
+        # 16-bit encodings are selected based on the registers used, which cannot be done for
+        # dynamic registers. So only use static registers here.
+        if template.split()[0].endswith(".n"):
+            self.static.update(constraints.keys())
+        for i, constraint in constraints.items():
+            if isinstance(constraint, Same):
+                self.static.add(constraint.index)
+            elif isinstance(constraint, Next):
+                self.static.add(i - 1)
+                self.static.add(i)
+
+    def create_entry(self):
+        history = History()
+
+        for (arg, i) in self.args:
+            constraint = self.constraints[i]
+            value = constraint.create_value(history)
+            history.values.append(value)
+
+        for (arg, i), value in zip(self.args, history.values):
+            allow_dynamic = i not in self.static and self.constraints[i].allow_dynamic
+            history.emitted.append(arg.emit_dynasm(value, allow_dynamic, self.thumb))
+            history.gas.append(arg.emit_gas(value, self.thumb))
+
+        template = self.template
+        if "<Cond>" in template:
+            if self.thumb:
+                cond = random.choice(CONDITIONS)
+            else:
+                cond = random.choice(CONDITIONS + ["", "al"])
+            template = template.replace("<Cond>", cond)
+
+        dynasm_string = SUBSTITUTION_RE.sub(lambda m: substitute(m, history.emitted), template)
+        gas_string = SUBSTITUTION_RE.sub(lambda m: substitute(m, history.gas), template)
+
+        return dynasm_string, gas_string
+
+class History:
+    def __init__(self):
+        self.values = []
+        self.emitted = []
+        self.gas = []
+
+def read_opdata_file(f, thumb):
+    templates = []
+    context = dict(
+        R=R,
+        Same=Same,
+        Next=Next,
+        Index=Index,
+        RegList=RegList,
+        Range=Range,
+        ArmImm=ArmImm,
+        ThumbImm=ThumbImm,
+        Width=Width,
+        Shift=Shift,
+        RegShift=RegShift,
+        IndexShift=IndexShift,
+        LslExact=LslExact,
+        Rotation=Rotation,
+        List=List,
+    )
+
+    for line in f:
+        template, constraints = line.split("\t")
+
+        template = ast.literal_eval(template)
+        constraints = eval(constraints, context)
+
+        templates.append(OpTemplate(template, constraints, thumb))
+
+    return templates
+
+# matches the argument placeholders. shifts are optional, so they carry their leading comma with them
+SUBSTITUTION_RE = re.compile(r"(, )?<([a-zA-Z]+),([0-9]+)>")
+def substitute(m, emitted):
+    prefix, argty, argidx = m.groups()
+    if argty == "Shift" or prefix is None:
+        return emitted[int(argidx)]
+    return prefix + emitted[int(argidx)]
+
+def parse_template(template):
+    matches = []
+    for _, argty, argidx in SUBSTITUTION_RE.findall(template):
+        if argty == "Imm":
+            arg = Immediate()
+        elif argty == "Off":
+            arg = Offset()
+        elif argty == "R":
+            arg = Register()
+        elif argty == "Index":
+            arg = Register()
+        elif argty == "Ident":
+            arg = Ident()
+        elif argty == "Shift":
+            arg = ShiftArg()
+        elif argty == "RegList":
+            arg = RegListArg()
+        else:
+            raise NotImplementedError(argty)
+        matches.append((arg, int(argidx)))
+    return matches
+
+# Implementation details Constraints
+
+def pick_register(mask):
+    options = [i for i in range(16) if mask & (1 << i)]
+    if len(options) > 1:
+        # avoid sp and pc, most encodings don't allow them.
+        options = [i for i in options if i not in (13, 15)]
+    return random.choice(options)
+
+class Constraint:
+    allow_dynamic = False
+
+    def __init__(self):
+        pass
+
+    def create_value(self, history):
+        raise NotImplementedError()
+
+class R(Constraint):
+    """A constraint that allows a certain range of registers, as specified by a mask"""
+
+    def __init__(self, mask):
+        self.mask = mask
+        self.allow_dynamic = mask in (0xFFFF, 0x00FF)
+
+    def create_value(self, history):
+        return pick_register(self.mask)
+
+class Same(Constraint):
+    """The register has to be the same as a previous argument"""
+
+    def __init__(self, index):
+        self.index = index
+
+    def create_value(self, history):
+        return history.values[self.index]
+
+class Next(Constraint):
+    """The register has to follow the previous register"""
+
+    def create_value(self, history):
+        return history.values[-1] + 1
+
+class Index(Constraint):
+    """An index register, which is optionally subtracted"""
+
+    def __init__(self, mask, negatable):
+        self.mask = mask
+        self.negatable = negatable
+
+    def create_value(self, history):
+        return ("-" if self.negatable and random.choice((True, False)) else "", pick_register(self.mask))
+
+class RegList(Constraint):
+    """A list of registers, as specified by a mask"""
+
+    def __init__(self, mask, single=False):
+        self.mask = mask
+        self.single = single
+
+    def create_value(self, history):
+        # sp can't be in a list, and pc only in the lists that can contain it explicitly.
+        options = [i for i in range(16) if self.mask & (1 << i) and i != 13 and (i != 15 or self.mask != 0xFFFF)]
+        if self.single:
+            return [random.choice(options)]
+        # thumb-2 register lists need at least two registers
+        count = random.randint(2 if self.mask == 0xFFFF else 1, len(options))
+        return sorted(random.sample(options, count))
+
+class Range(Constraint):
+    """A constraint that allows any value in the specified range"""
+
+    def __init__(self, min, max, scale):
+        self.min = min
+        self.max = max
+        self.scale = scale
+
+    def create_value(self, history):
+        return random.randrange(self.min, self.max, self.scale)
+
+def rotate_right(value, amount):
+    amount %= 32
+    return ((value >> amount) | (value << (32 - amount))) & 0xFFFFFFFF
+
+def transform(value, kind):
+    if kind == "Neg":
+        # negated values have to fit in an i32
+        return -value if value <= 0x80000000 else value
+    elif kind == "Inv":
+        return ~value & 0xFFFFFFFF
+    return value
+
+class ArmImm(Constraint):
+    """An A32 modified immediate"""
+
+    def __init__(self, kind):
+        self.kind = kind
+
+    def create_value(self, history):
+        value = rotate_right(random.randrange(1, 256), random.randrange(0, 32, 2))
+        return transform(value, self.kind)
+
+class ThumbImm(Constraint):
+    """A T32 modified immediate"""
+
+    def __init__(self, kind):
+        self.kind = kind
+
+    def create_value(self, history):
+        byte = random.randrange(1, 256)
+        form = random.randrange(5)
+        if form == 0:
+            value = byte
+        elif form == 1:
+            value = byte | (byte << 16)
+        elif form == 2:
+            value = (byte << 8) | (byte << 24)
+        elif form == 3:
+            value = byte * 0x01010101
+        else:
+            value = rotate_right(byte | 0x80, random.randrange(8, 32))
+        return transform(value, self.kind)
+
+class Width(Constraint):
+    """A bitfield width, that cannot exceed the register given the previous lsb"""
+
+    def create_value(self, history):
+        return random.randint(1, 32 - history.values[-1])
+
+class List(Constraint):
+    """A constraint that allows a certain amount of options"""
+
+    def __init__(self, *args):
+        self.options = args
+
+    def create_value(self, history):
+        return random.choice(self.options)
+
+class Shift(Constraint):
+    """An optional shift by an immediate"""
+
+    def __init__(self, kind):
+        self.kind = kind
+
+    def create_value(self, history):
+        op = random.choice(("", "lsl", "lsr", "asr", "ror", "rrx"))
+        if op in ("", "rrx"):
+            return op
+        elif op in ("lsl", "ror"):
+            return f"{op} #{random.randint(1, 31)}"
+        else:
+            return f"{op} #{random.randint(1, 32)}"
+
+class RegShift(Constraint):
+    """A shift by a register"""
+
+    def create_value(self, history):
+        return (random.choice(("lsl", "lsr", "asr", "ror")), pick_register(0xFFFF))
+
+class IndexShift(Constraint):
+    """An optional left shift, by at most the given amount"""
+
+    def __init__(self, max):
+        self.max = max
+
+    def create_value(self, history):
+        amount = random.randint(0, self.max)
+        return f"lsl #{amount}" if amount else ""
+
+class LslExact(Constraint):
+    """A left shift by an exact amount"""
+
+    def __init__(self, amount):
+        self.amount = amount
+
+    def create_value(self, history):
+        return f"lsl #{self.amount}"
+
+class Rotation(Constraint):
+    """An optional rotation by a multiple of 8"""
+
+    def create_value(self, history):
+        amount = random.choice((0, 8, 16, 24))
+        return f"ror #{amount}" if amount else ""
+
+# Implementation details Args
+
+class Arg:
+    def __init__(self):
+        pass
+
+    def emit_gas(self, value, thumb):
+        raise NotImplementedError()
+
+    def emit_dynasm(self, value, allow_dynamic, thumb):
+        raise NotImplementedError()
+
+class Register(Arg):
+    def emit_gas(self, value, thumb):
+        if isinstance(value, tuple):
+            sign, value = value
+            return f"{sign}r{value}"
+        return f"r{value}"
+
+    def emit_dynasm(self, value, allow_dynamic, thumb):
+        sign = ""
+        if isinstance(value, tuple):
+            sign, value = value
+        # randomly choose dynamic vs static notation
+        if allow_dynamic and not sign and random.choice((True, False)):
+            return f"R({value})"
+        return f"{sign}r{value}"
+
+class ShiftArg(Arg):
+    def emit_gas(self, value, thumb):
+        if isinstance(value, tuple):
+            op, reg = value
+            return f", {op} r{reg}"
+        return f", {value}" if value else ""
+
+    def emit_dynasm(self, value, allow_dynamic, thumb):
+        return self.emit_gas(value, thumb)
+
+class RegListArg(Arg):
+    def emit_gas(self, value, thumb):
+        return "{" + ", ".join(f"r{i}" for i in value) + "}"
+
+    def emit_dynasm(self, value, allow_dynamic, thumb):
+        return self.emit_gas(value, thumb)
+
+class Immediate(Arg):
+    def emit_gas(self, value, thumb):
+        return f"{value}"
+
+    def emit_dynasm(self, value, allow_dynamic, thumb):
+        return f"{value}"
+
+class Offset(Arg):
+    # offsets are generated relative to the pc, which is what the assembler expects. dynasm-rs
+    # offsets are relative to the start of the instruction instead.
+    def emit_gas(self, value, thumb):
+        return f"#{value}"
+
+    def emit_dynasm(self, value, allow_dynamic, thumb):
+        bias = 4 if thumb else 8
+        return f"{value + bias}"
+
+class Ident(Arg):
+    def emit_gas(self, value, thumb):
+        return value
+
+    def emit_dynasm(self, value, allow_dynamic, thumb):
+        return value
+
+
+if __name__ == '__main__':
+    main()
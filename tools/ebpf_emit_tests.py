@@ -0,0 +1,68 @@
+#!/usr/bin/python3
+
+# this file takes the compiled testcase listing emitted by `ebpf_compile_tests.py` and
+# compiles this to a set of testcase files for dynasm-rs
+
+import argparse
+from pathlib import Path
+
+BLACKLIST = set()
+
+def read_input_file(f):
+    buf = []
+    for line in f:
+        dynasm, gas, bytes = line.split("\t")
+        buf.append((dynasm.strip(), gas.strip(), bytes.strip()))
+    return buf
+
+def chunks(l, n):
+    for i in range(0, len(l), n):
+        yield l[i:i+n]
+
+def main():
+    parser = argparse.ArgumentParser("ebpf_emit_tests",  description="compile dynasm-rs eBPF testcase files.")
+
+    parser.add_argument("input_file", help="The output of ebpf_compile_tests.py", type=Path)
+    parser.add_argument("output_folder", help="The folder in which to deposit the test files", type=Path)
+
+    args = parser.parse_args()
+
+    with args.input_file.open("r", encoding="utf-8") as f:
+        data = read_input_file(f)
+
+    arch = "ebpf"
+    tests = [emit_test_case(arch, i, dynasm, gas, bytes) for i, (dynasm, gas, bytes) in enumerate(data)]
+
+    if not args.output_folder.exists():
+        args.output_folder.mkdir()
+
+    for i, chunk in enumerate(chunks(tests, 800)):
+        with (args.output_folder / f"{arch}_tests_{i}.rs.gen").open("w", encoding="utf-8") as f:
+            for test in chunk:
+                f.write(test)
+
+def emit_test_case(arch, i, dynasm, gas, bytes):
+    name = dynasm.split(' ', 1)[0]
+    if name in BLACKLIST:
+        return ""
+    bytes = ", ".join(chunks(bytes, 2)).upper()
+    error = dynasm.replace("{", "{{").replace("}", "}}")
+    return f"""
+#[test]
+fn {name.lower()}_{i}() {{
+    let mut ops = dynasmrt::SimpleAssembler::new();
+    dynasm!(ops
+        ; .arch {arch}
+        ; {dynasm}
+    );
+    let buf = ops.finalize();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{{:02X}}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "{bytes}", "{error}");
+}}
+"""
+
+
+
+if __name__ == "__main__":
+    main()
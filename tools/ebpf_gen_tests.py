@@ -0,0 +1,254 @@
+#!/usr/bin/python3
+
+# this file takes the opmap exported by docs/insref/export.rs and generates a big list of test cases
+# with it that can be compared with another assembler.
+# syntax is ebpf_gen_tests.py input_file test_multiplier output_file
+#
+# eBPF assemblers don't share a mnemonic syntax, llvm-mc uses a C-like syntax instead. Therefore
+# every mnemonic is translated to this syntax using the tables below.
+
+import ast
+import random
+import re
+from pathlib import Path
+import argparse
+
+ALU_OPS = {
+    "add": "+=", "sub": "-=", "mul": "*=", "div": "/=", "or": "|=", "and": "&=",
+    "lsh": "<<=", "rsh": ">>=", "xor": "^=", "mov": "=", "arsh": "s>>=",
+}
+
+JUMP_OPS = {
+    "jeq": "==", "jgt": ">", "jge": ">=", "jne": "!=", "jsgt": "s>", "jsge": "s>=",
+    "jlt": "<", "jle": "<=", "jslt": "s<", "jsle": "s<=",
+}
+
+SIZES = {"b": "u8", "h": "u16", "w": "u32", "dw": "u64"}
+
+ATOMIC_OPS = {"add": "+=", "or": "|=", "and": "&=", "xor": "^="}
+
+def llvm_template(mnemonic):
+    """Returns the llvm-mc syntax for a mnemonic, with {0}, {1}, ... as argument placeholders.
+    Registers are emitted by their number. Returns None if llvm-mc cannot assemble the mnemonic."""
+
+    m = re.fullmatch(r"([a-z]+)(32|64)", mnemonic)
+    if m and m.group(1) in ALU_OPS:
+        reg = "w" if m.group(2) == "32" else "r"
+        return f"{reg}{{0}} {ALU_OPS[m.group(1)]} {{1:{reg}}}"
+    if m and m.group(1) == "neg":
+        reg = "w" if m.group(2) == "32" else "r"
+        return f"{reg}{{0}} = -{reg}{{0}}"
+
+    m = re.fullmatch(r"(le|be)(16|32|64)", mnemonic)
+    if m:
+        return f"r{{0}} = {mnemonic} r{{0}}"
+
+    if mnemonic == "lddw":
+        return "r{0} = {1} ll"
+
+    m = re.fullmatch(r"ldx(b|h|w|dw)", mnemonic)
+    if m:
+        return f"r{{0}} = *({SIZES[m.group(1)]} *)(r{{1}} {{2:off}})"
+
+    m = re.fullmatch(r"stx(b|h|w|dw)", mnemonic)
+    if m:
+        reg = "r" if m.group(1) == "dw" else "w"
+        return f"*({SIZES[m.group(1)]} *)(r{{0}} {{1:off}}) = {reg}{{2}}"
+
+    m = re.fullmatch(r"atomic_(add|or|and|xor)(32|64)", mnemonic)
+    if m:
+        size, reg = ("u32", "w") if m.group(2) == "32" else ("u64", "r")
+        return f"lock *({size} *)(r{{0}} {{1:off}}) {ATOMIC_OPS[m.group(1)]} {reg}{{2}}"
+
+    m = re.fullmatch(r"ldabs(b|h|w)", mnemonic)
+    if m:
+        return f"r0 = *({SIZES[m.group(1)]} *)skb[{{0}}]"
+
+    m = re.fullmatch(r"ldind(b|h|w)", mnemonic)
+    if m:
+        return f"r0 = *({SIZES[m.group(1)]} *)skb[r{{0}}]"
+
+    if mnemonic == "ja":
+        return "goto {0:off}"
+
+    m = re.fullmatch(r"([a-z]+?)(32)?", mnemonic)
+    if m and m.group(1) in JUMP_OPS:
+        reg = "w" if m.group(2) else "r"
+        return f"if {reg}{{0}} {JUMP_OPS[m.group(1)]} {{1:{reg}}} goto {{2:off}}"
+
+    if mnemonic == "call":
+        return "call {0}"
+
+    if mnemonic == "exit":
+        return "exit"
+
+    return None
+
+def main():
+    parser = argparse.ArgumentParser("ebpf_gen_tests",  description="generate eBPF testcases from the exported dynasm-rs opmap definitions")
+    parser.add_argument("opmap_export_file", type=Path)
+    parser.add_argument("test_multiplier", type=int)
+    parser.add_argument("result_file", type=Path)
+    args = parser.parse_args()
+
+    with args.opmap_export_file.open("r", encoding="utf-8") as f:
+        templates = read_opdata_file(f)
+
+    tests = []
+
+    for template in templates:
+        if template.llvm is None:
+            print(f"skipping {template.template} because llvm-mc cannot assemble it")
+            continue
+
+        # only jumps can have their offsets tested without labels
+        if "Off" in template.template and "goto" not in template.llvm:
+            print(f"skipping {template.template} because it requires a label")
+            continue
+
+        for _ in range(args.test_multiplier):
+            tests.append(template.create_entry())
+
+    with args.result_file.open("w", encoding="utf-8") as f:
+        for dynasm, llvm in tests:
+            f.write(f"{dynasm}\t{llvm}\n")
+
+class OpTemplate:
+    def __init__(self, template, constraints):
+        self.template = template
+        self.constraints = constraints
+        self.mnemonic = template.split()[0]
+        self.llvm = llvm_template(self.mnemonic)
+
+        self.args = parse_template(template)
+
+    def create_entry(self):
+        values = []
+        emitted = []
+
+        for (arg, i) in self.args:
+            value = self.constraints[i].create_value(self.mnemonic)
+            values.append(value)
+            emitted.append(arg.emit_dynasm(value))
+
+        dynasm_string = SUBSTITUTION_RE.sub(lambda m: substitute(m, values, emitted), self.template)
+        llvm_string = self.llvm.format(*[LlvmValue(v) for v in values])
+
+        return dynasm_string, llvm_string
+
+class LlvmValue:
+    """Formats argument values in llvm-mc syntax. The format spec selects how the value is used:
+    `off` emits a signed offset, `r` or `w` emit either a register or an immediate."""
+
+    def __init__(self, value):
+        self.value = value
+
+    def __format__(self, spec):
+        if spec == "off":
+            return f"- {-self.value}" if self.value < 0 else f"+ {self.value}"
+        elif isinstance(self.value, Reg):
+            return f"{spec}{self.value.num}"
+        else:
+            return f"{self.value}"
+
+def read_opdata_file(f):
+    templates = []
+    context = dict(
+        R=R,
+        Range=Range,
+    )
+
+    for line in f:
+        template, constraints = line.split("\t")
+
+        template = ast.literal_eval(template)
+        constraints = eval(constraints, context)
+
+        templates.append(OpTemplate(template, constraints))
+
+    return templates
+
+# matches the argument placeholders. memory reference offsets carry their sign with them
+SUBSTITUTION_RE = re.compile(r"(\+ )?<([a-zA-Z]+),([0-9]+)>")
+def substitute(m, values, emitted):
+    prefix, argty, argidx = m.groups()
+    value = values[int(argidx)]
+    if prefix is None:
+        return emitted[int(argidx)]
+    elif value < 0:
+        return f"- {-value}"
+    return f"+ {value}"
+
+def parse_template(template):
+    matches = []
+    for _, argty, argidx in SUBSTITUTION_RE.findall(template):
+        if argty == "Imm":
+            arg = Immediate()
+        elif argty == "Off":
+            arg = Immediate()
+        elif argty == "R":
+            arg = Register()
+        else:
+            raise NotImplementedError(argty)
+        matches.append((arg, int(argidx)))
+    return matches
+
+# Implementation details Constraints
+
+class Reg:
+    def __init__(self, num):
+        self.num = num
+
+class Constraint:
+    def __init__(self):
+        pass
+
+    def create_value(self, mnemonic):
+        raise NotImplementedError()
+
+class R(Constraint):
+    """A constraint that allows a certain range of registers, as specified by a mask"""
+
+    def __init__(self, mask):
+        self.mask = mask
+
+    def create_value(self, mnemonic):
+        return Reg(random.choice([i for i in range(16) if self.mask & (1 << i)]))
+
+class Range(Constraint):
+    """A constraint that allows any value in the specified range"""
+
+    def __init__(self, min, max, scale):
+        self.min = min
+        self.max = max
+        self.scale = scale
+
+    def create_value(self, mnemonic):
+        # llvm-mc only assembles packet accesses through an index register without offset
+        if mnemonic.startswith("ldind"):
+            return 0
+        return random.randrange(self.min, self.max, self.scale)
+
+# Implementation details Args
+
+class Arg:
+    def __init__(self):
+        pass
+
+    def emit_dynasm(self, value):
+        raise NotImplementedError()
+
+class Register(Arg):
+    def emit_dynasm(self, value):
+        # randomly choose dynamic vs static notation
+        if random.choice((True, False)):
+            return f"R({value.num})"
+        return f"r{value.num}"
+
+class Immediate(Arg):
+    def emit_dynasm(self, value):
+        return f"{value}"
+
+
+if __name__ == '__main__':
+    main()
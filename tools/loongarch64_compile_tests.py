@@ -0,0 +1,67 @@
+#!/usr/bin/python3
+
+# this file takes the testcase listing generated by loongarch64_gen_tests.py
+# and compiles them using a loongarch64 `as`, then using `objcopy` to extract the generated machine code.
+# On other hosts, a cross toolchain (like binutils-loongarch64-linux-gnu) can be used by passing its prefix.
+
+import subprocess
+import binascii
+import argparse
+from pathlib import Path
+
+def read_test_strings(f):
+    buf = []
+    for line in f:
+        if line:
+            dynasm, gas = line.split("\t")
+            buf.append((dynasm.strip(), gas.strip()))
+
+    return buf
+
+def compile_with_as(asmstring, prefix):
+    with open("test.s", "w", encoding="utf-8") as f:
+        f.write(".global _start\n")
+        f.write("_start:\n")
+        f.write(asmstring)
+        f.write("\n")
+
+    subprocess.run([f"{prefix}as", "-mno-relax", "test.s", "-o", "test.o"], check=True, capture_output=True)
+    subprocess.run([f"{prefix}ld", "test.o", "-o", "test.elf"], check=True)
+    subprocess.run([f"{prefix}objcopy", "-O", "binary", "test.elf", "test.bin"], check=True)
+
+    with open("test.bin", "rb") as f:
+        data = f.read()
+
+    return data
+
+def write_result(buf, f):
+    for dynasm, gas, binary in buf:
+        binary = binascii.hexlify(binary).decode("utf-8")
+        f.write(f"{dynasm}\t{gas}\t{binary}\n")
+
+def main():
+    parser = argparse.ArgumentParser("loongarch64_compile_tests",  description="compile loongarch64 testcases using `as` to use as reference output")
+    parser.add_argument("--prefix", help="prefix of the binutils executables, i.e. loongarch64-linux-gnu-", default="")
+
+    parser.add_argument("input_file", help="The output of loongarch64_gen_tests.py", type=Path)
+    parser.add_argument("output_file", help="The file with compiled test case data", type=Path)
+
+    args = parser.parse_args()
+
+
+    with args.input_file.open("r", encoding="utf-8") as f:
+        test_strings = read_test_strings(f)
+
+    buf = []
+    for dynasm, gas in test_strings:
+        try:
+            binary = compile_with_as(gas, args.prefix)
+            buf.append((dynasm, gas, binary))
+        except subprocess.CalledProcessError as e:
+            print(f"Error at {gas}:\n{e.stderr}")
+
+    with args.output_file.open("w", encoding="utf-8") as f:
+        write_result(buf, f)
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,228 @@
+#!/usr/bin/python3
+
+# this file takes the opmap exported by docs/insref/export.rs and generates a big list of test cases
+# with it that can be compared with another assembler.
+# syntax is loongarch64_gen_tests.py input_file test_multiplier output_file
+
+import ast
+import random
+import re
+from pathlib import Path
+import argparse
+
+BLACKLIST = {
+    # gas expands these into different instruction sequences depending on the immediate
+    "li.w",
+    "li.d",
+    # gas resolves these against symbols, while we only accept dynasm labels here
+    "la.pcrel",
+}
+
+# dynasm-rs expects the resulting value for these instructions, while gas expects the immediate field
+UPPER_IMMEDIATES = {
+    "lu12i.w": 12,
+    "pcalau12i": 12,
+    "addu16i.d": 16,
+    "pcaddi": 2,
+}
+
+def main():
+    parser = argparse.ArgumentParser("loongarch64_gen_tests",  description="generate loongarch64 testcases from the exported dynasm-rs opmap definitions")
+    parser.add_argument("opmap_export_file", type=Path)
+    parser.add_argument("test_multiplier", type=int)
+    parser.add_argument("result_file", type=Path)
+    args = parser.parse_args()
+
+    with args.opmap_export_file.open("r", encoding="utf-8") as f:
+        templates = read_opdata_file(f)
+
+    tests = []
+
+    for template in templates:
+        # filter out the pc-relative label forms of pcaddu12i, addi and memory references,
+        # as these take the low / high part of a label offset instead of the plain offset
+        if template.template.startswith(("pcaddu12i ", "addi.w ", "addi.d ")) and "Off" in template.template:
+            print(f"skipping {template.template} because it is exempted")
+            continue
+        if "[" in template.template and "Off" in template.template:
+            print(f"skipping {template.template} because it is exempted")
+            continue
+
+        if template.template.split()[0] in BLACKLIST:
+            print(f"skipping {template.template} because it is blacklisted")
+            continue
+
+        for _ in range(args.test_multiplier):
+            tests.append(template.create_entry())
+
+    with args.result_file.open("w", encoding="utf-8") as f:
+        for dynasm, gas in tests:
+            f.write(f"{dynasm}\t{gas}\n")
+
+FIX_GAS_MEMREF = re.compile(r"\[ *([^,\]]+?) *(?:, *([^\]]+?) *)?\]")
+class OpTemplate:
+    def __init__(self, template, constraints):
+        self.template = template
+        self.constraints = constraints
+
+        self.args = parse_template(template)
+
+    def create_entry(self):
+        history = History()
+
+        for (arg, i) in self.args:
+            constraint = self.constraints[i]
+            value = constraint.create_value(history)
+            if isinstance(arg, Register):
+                value = arg.restrict(value)
+
+            history.values.append(value)
+
+        # gas rejects bitfield instructions where the msb is below the lsb
+        if self.template.startswith(("bstrins.", "bstrpick.")) and history.values[2] < history.values[3]:
+            history.values[2], history.values[3] = history.values[3], history.values[2]
+
+        shift = UPPER_IMMEDIATES.get(self.template.split()[0], 0)
+        for (arg, i), value in zip(self.args, history.values):
+            history.emitted.append(arg.emit_dynasm(value))
+            if isinstance(arg, Immediate):
+                history.gas.append(arg.emit_gas(value >> shift))
+            else:
+                history.gas.append(arg.emit_gas(value))
+
+        dynasm_string = SUBSTITUTION_RE.sub(lambda m: history.emitted[int(m.group(2))], self.template)
+        gas_string = SUBSTITUTION_RE.sub(lambda m: history.gas[int(m.group(2))], self.template)
+
+        # gas doesn't use brackets for memory references, the base register is just another operand
+        gas_string = FIX_GAS_MEMREF.sub(lambda m: f"{m.group(1)}, {m.group(2)}" if m.group(2) else m.group(1), gas_string)
+
+        return dynasm_string, gas_string
+
+class History:
+    def __init__(self):
+        self.values = []
+        self.emitted = []
+        self.gas = []
+
+def read_opdata_file(f):
+    templates = []
+    context = dict(
+        R=R,
+        Range=Range,
+    )
+
+    for line in f:
+        template, constraints = line.split("\t")
+
+        template = ast.literal_eval(template)
+        constraints = eval(constraints, context)
+
+        templates.append(OpTemplate(template, constraints))
+
+    return templates
+
+SUBSTITUTION_RE = re.compile(r"<([a-zA-Z]+),([0-9]+)>")
+def parse_template(template):
+    matches = []
+    for argty, argidx in SUBSTITUTION_RE.findall(template):
+        if argty == "Imm":
+            arg = Immediate()
+        elif argty == "Off":
+            arg = Offset()
+        elif argty in ("R", "F", "FCC", "FCSR"):
+            arg = Register(argty)
+        else:
+            raise NotImplementedError(argty)
+        matches.append((arg, int(argidx)))
+    return matches
+
+# Implementation details Constraints
+
+class Constraint:
+    def __init__(self):
+        pass
+
+    def create_value(self, history):
+        raise NotImplementedError()
+
+class Range(Constraint):
+    """A constraint that allows any value in the specified range"""
+
+    def __init__(self, min, max, scale):
+        self.min = min
+        self.max = max
+        self.scale = scale
+
+    def create_value(self, history):
+        return random.randrange(self.min, self.max, self.scale)
+
+class R(Constraint):
+    """A constraint that allows a certain range of registers, as specified by a mask"""
+
+    def __init__(self, mask):
+        self.mask = mask
+
+    def create_value(self, history):
+        options = []
+        for i in range(32):
+            if self.mask & (1 << i) != 0:
+                options.append(i)
+
+        return random.choice(options)
+
+# Implementation details Args
+
+class Arg:
+    def __init__(self):
+        pass
+
+    def emit_gas(self, value):
+        raise NotImplementedError()
+
+    def emit_dynasm(self, value):
+        raise NotImplementedError()
+
+class Register(Arg):
+    PREFIXES = {
+        "R": "r",
+        "F": "f",
+        "FCC": "fcc",
+        "FCSR": "fcsr",
+    }
+
+    def __init__(self, family):
+        self.family = family
+
+    def restrict(self, value):
+        # only four control and status registers exist
+        if self.family == "FCSR":
+            return value & 3
+        return value
+
+    def emit_gas(self, value):
+        return f"${self.PREFIXES[self.family]}{value}"
+
+    def emit_dynasm(self, value):
+        # randomly choose dynamic vs static notation. control and status registers can't be dynamic.
+        if random.choice((True, False)) or self.family == "FCSR":
+            return f"{self.PREFIXES[self.family]}{value}"
+        else:
+            return f"{self.family}({value})"
+
+class Immediate(Arg):
+    def emit_gas(self, value):
+        return f"{value}"
+
+    def emit_dynasm(self, value):
+        return f"{value}"
+
+class Offset(Immediate):
+    def emit_gas(self, value):
+        if value >= 0:
+            return ".+" + super().emit_gas(value)
+        else:
+            return "." + super().emit_gas(value)
+
+
+if __name__ == '__main__':
+    main()
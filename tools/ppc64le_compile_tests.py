@@ -0,0 +1,63 @@
+#!/usr/bin/python3
+
+# this file takes the testcase listing generated by ppc64le_gen_tests.py
+# and compiles them using `llvm-mc`, then using `llvm-objcopy` to extract the generated machine code.
+
+import subprocess
+import binascii
+import argparse
+from pathlib import Path
+
+def read_test_strings(f):
+    buf = []
+    for line in f:
+        if line:
+            dynasm, gas = line.split("\t")
+            buf.append((dynasm.strip(), gas.strip()))
+
+    return buf
+
+def compile_with_llvm_mc(asmstring, prefix):
+    with open("test.s", "w", encoding="utf-8") as f:
+        f.write(asmstring)
+        f.write("\n")
+
+    subprocess.run([f"llvm-mc{prefix}", "-triple=powerpc64le", "-mcpu=pwr9", "-filetype=obj", "test.s", "-o", "test.o"], check=True, capture_output=True)
+    subprocess.run([f"llvm-objcopy{prefix}", "-O", "binary", "--only-section=.text", "test.o", "test.bin"], check=True)
+
+    with open("test.bin", "rb") as f:
+        data = f.read()
+
+    return data
+
+def write_result(buf, f):
+    for dynasm, gas, binary in buf:
+        binary = binascii.hexlify(binary).decode("utf-8")
+        f.write(f"{dynasm}\t{gas}\t{binary}\n")
+
+def main():
+    parser = argparse.ArgumentParser("ppc64le_compile_tests",  description="compile ppc64le testcases using `llvm-mc` to use as reference output")
+    parser.add_argument("--suffix", help="version suffix of the llvm executables, i.e. -14", default="")
+
+    parser.add_argument("input_file", help="The output of ppc64le_gen_tests.py", type=Path)
+    parser.add_argument("output_file", help="The file with compiled test case data", type=Path)
+
+    args = parser.parse_args()
+
+
+    with args.input_file.open("r", encoding="utf-8") as f:
+        test_strings = read_test_strings(f)
+
+    buf = []
+    for dynasm, gas in test_strings:
+        try:
+            binary = compile_with_llvm_mc(gas, args.suffix)
+            buf.append((dynasm, gas, binary))
+        except subprocess.CalledProcessError as e:
+            print(f"Error at {gas}:\n{e.stderr.decode('utf-8')}")
+
+    with args.output_file.open("w", encoding="utf-8") as f:
+        write_result(buf, f)
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,1019 @@
+#!/usr/bin/python3
+
+# this file generates the opmap.rs file for the ppc64le assembler.
+# syntax is ppc64le_gen_opmap.py [--suffix -14] output_file
+#
+# The PowerPC instruction set has no machine-readable specification that is easy to consume.
+# Therefore the instruction listing below is written by hand, only specifying the operand kinds
+# of every instruction form. The actual encoding information is derived from `llvm-mc`: every
+# instruction form is assembled once with all operands set to zero to determine the template, and
+# then once for every bit of every operand to find out where and how that bit is encoded.
+
+import argparse
+import re
+import subprocess
+import tempfile
+from collections import OrderedDict
+from pathlib import Path
+
+# operand kinds
+# R, F, V, VS, CR: registers of the respective family
+# D, DS, DQ: a memory reference [ra, offset], offset being 16 bits, or scaled by 4 or 16
+# DL, DSL: a memory reference with a label as offset, [ra, label]
+# uN, sN: an unsigned or signed immediate of N bits
+# B24, B14: a branch target
+# HA, LO, PCHA: a label used in addis, addi and addpcis
+
+CONDITIONS = ["lt", "le", "eq", "ge", "gt", "ne", "so", "ns"]
+
+INSTRUCTIONS = """
+# Branches
+b                   B24
+bl                  B24
+bc                  u5 u5 B14
+bcl                 u5 u5 B14
+bt                  u5 B14
+bf                  u5 B14
+bdnz                B14
+bdz                 B14
+bdnzl               B14
+bdzl                B14
+bclr                u5 u5
+bclrl               u5 u5
+bcctr               u5 u5
+bcctrl              u5 u5
+blr
+blrl
+bctr
+bctrl
+bdnzlr
+bdzlr
+{conditional_branches}
+
+# Condition register logical instructions
+crand               u5 u5 u5
+crnand              u5 u5 u5
+cror                u5 u5 u5
+crxor               u5 u5 u5
+crnor               u5 u5 u5
+creqv               u5 u5 u5
+crandc              u5 u5 u5
+crorc               u5 u5 u5
+crset               u5
+crclr               u5
+crmove              u5 u5
+crnot               u5 u5
+mcrf                CR CR
+
+# System call
+sc
+
+# Fixed-point loads
+lbz                 R D
+lbz                 R DL
+lbzu                R D
+lhz                 R D
+lhz                 R DL
+lhzu                R D
+lha                 R D
+lha                 R DL
+lhau                R D
+lwz                 R D
+lwz                 R DL
+lwzu                R D
+lwa                 R DS
+lwa                 R DSL
+ld                  R DS
+ld                  R DSL
+ldu                 R DS
+lbzx                R R R
+lbzux               R R R
+lhzx                R R R
+lhzux               R R R
+lhax                R R R
+lhaux               R R R
+lwzx                R R R
+lwzux               R R R
+lwax                R R R
+lwaux               R R R
+ldx                 R R R
+ldux                R R R
+lhbrx               R R R
+lwbrx               R R R
+ldbrx               R R R
+
+# Fixed-point stores
+stb                 R D
+stb                 R DL
+stbu                R D
+sth                 R D
+sth                 R DL
+sthu                R D
+stw                 R D
+stw                 R DL
+stwu                R D
+std                 R DS
+std                 R DSL
+stdu                R DS
+stbx                R R R
+stbux               R R R
+sthx                R R R
+sthux               R R R
+stwx                R R R
+stwux               R R R
+stdx                R R R
+stdux               R R R
+sthbrx              R R R
+stwbrx              R R R
+stdbrx              R R R
+
+# Load and reserve, store conditional
+lbarx               R R R
+lharx               R R R
+lwarx               R R R
+ldarx               R R R
+stbcx.              R R R
+sthcx.              R R R
+stwcx.              R R R
+stdcx.              R R R
+
+# Fixed-point arithmetic
+addi                R R s16
+addi                R R LO
+addis               R R s16
+addis               R R HA
+addpcis             R s16
+addpcis             R PCHA
+li                  R s16
+lis                 R s16
+add[o][.]           R R R
+subf[o][.]          R R R
+sub[.]              R R R
+addc[o][.]          R R R
+subfc[o][.]         R R R
+subc[.]             R R R
+adde[o][.]          R R R
+subfe[o][.]         R R R
+addme[o][.]         R R
+subfme[o][.]        R R
+addze[o][.]         R R
+subfze[o][.]        R R
+neg[o][.]           R R
+addic               R R s16
+addic.              R R s16
+subfic              R R s16
+mulli               R R s16
+mullw[o][.]         R R R
+mulhw[.]            R R R
+mulhwu[.]           R R R
+mulld[o][.]         R R R
+mulhd[.]            R R R
+mulhdu[.]           R R R
+divw[o][.]          R R R
+divwu[o][.]         R R R
+divwe[o][.]         R R R
+divweu[o][.]        R R R
+divd[o][.]          R R R
+divdu[o][.]         R R R
+divde[o][.]         R R R
+divdeu[o][.]        R R R
+modsw               R R R
+moduw               R R R
+modsd               R R R
+modud               R R R
+maddhd              R R R R
+maddhdu             R R R R
+maddld              R R R R
+darn                R u2
+
+# Fixed-point compare and trap
+cmp                 CR u1 R R
+cmpi                CR u1 R s16
+cmpl                CR u1 R R
+cmpli               CR u1 R u16
+cmpd                CR R R
+cmpd                R R
+cmpw                CR R R
+cmpw                R R
+cmpld               CR R R
+cmpld               R R
+cmplw               CR R R
+cmplw               R R
+cmpdi               CR R s16
+cmpdi               R s16
+cmpwi               CR R s16
+cmpwi               R s16
+cmpldi              CR R u16
+cmpldi              R u16
+cmplwi              CR R u16
+cmplwi              R u16
+cmprb               CR u1 R R
+cmpeqb              CR R R
+setb                R CR
+isel                R R R u5
+tw                  u5 R R
+twi                 u5 R s16
+td                  u5 R R
+tdi                 u5 R s16
+trap
+
+# Fixed-point logical
+andi.               R R u16
+andis.              R R u16
+ori                 R R u16
+oris                R R u16
+xori                R R u16
+xoris               R R u16
+and[.]              R R R
+or[.]               R R R
+xor[.]              R R R
+nand[.]             R R R
+nor[.]              R R R
+eqv[.]              R R R
+andc[.]             R R R
+orc[.]              R R R
+mr[.]               R R
+not[.]              R R
+nop
+extsb[.]            R R
+extsh[.]            R R
+extsw[.]            R R
+cntlzw[.]           R R
+cntlzd[.]           R R
+cnttzw[.]           R R
+cnttzd[.]           R R
+popcntb             R R
+popcntw             R R
+popcntd             R R
+cmpb                R R R
+bpermd              R R R
+
+# Fixed-point rotate and shift
+rlwinm[.]           R R u5 u5 u5
+rlwnm[.]            R R R u5 u5
+rlwimi[.]           R R u5 u5 u5
+rldicl[.]           R R u6 u6
+rldicr[.]           R R u6 u6
+rldic[.]            R R u6 u6
+rldimi[.]           R R u6 u6
+rldcl[.]            R R R u6
+rldcr[.]            R R R u6
+slw[.]              R R R
+srw[.]              R R R
+sraw[.]             R R R
+srawi[.]            R R u5
+sld[.]              R R R
+srd[.]              R R R
+srad[.]             R R R
+sradi[.]            R R u6
+extswsli[.]         R R u6
+rotlw[.]            R R R
+rotlwi[.]           R R u5
+rotld[.]            R R R
+rotldi[.]           R R u6
+slwi[.]             R R u5
+srwi[.]             R R u5
+sldi[.]             R R u6
+srdi[.]             R R u6
+clrlwi[.]           R R u5
+clrrwi[.]           R R u5
+clrldi[.]           R R u6
+clrrdi[.]           R R u6
+
+# Moves to and from special purpose registers
+mfspr               R u10
+mtspr               u10 R
+mflr                R
+mtlr                R
+mfctr               R
+mtctr               R
+mfxer               R
+mtxer               R
+mfcr                R
+mtcr                R
+mtcrf               u8 R
+mcrxrx              CR
+mftb                R
+
+# Storage control
+sync
+sync                u2
+lwsync
+hwsync
+isync
+eieio
+dcbt                R R
+dcbtst              R R
+dcbz                R R
+dcbst               R R
+dcbf                R R
+icbi                R R
+
+# Floating-point loads and stores
+lfs                 F D
+lfs                 F DL
+lfsu                F D
+lfd                 F D
+lfd                 F DL
+lfdu                F D
+stfs                F D
+stfs                F DL
+stfsu               F D
+stfd                F D
+stfd                F DL
+stfdu               F D
+lfsx                F R R
+lfsux               F R R
+lfdx                F R R
+lfdux               F R R
+lfiwax              F R R
+lfiwzx              F R R
+stfsx               F R R
+stfsux              F R R
+stfdx               F R R
+stfdux              F R R
+stfiwx              F R R
+
+# Floating-point arithmetic
+fmr[.]              F F
+fneg[.]             F F
+fabs[.]             F F
+fnabs[.]            F F
+fcpsgn[.]           F F F
+fadd[.]             F F F
+fadds[.]            F F F
+fsub[.]             F F F
+fsubs[.]            F F F
+fmul[.]             F F F
+fmuls[.]            F F F
+fdiv[.]             F F F
+fdivs[.]            F F F
+fsqrt[.]            F F
+fsqrts[.]           F F
+fre[.]              F F
+fres[.]             F F
+frsqrte[.]          F F
+frsqrtes[.]         F F
+ftdiv               CR F F
+ftsqrt              CR F
+fmadd[.]            F F F F
+fmadds[.]           F F F F
+fmsub[.]            F F F F
+fmsubs[.]           F F F F
+fnmadd[.]           F F F F
+fnmadds[.]          F F F F
+fnmsub[.]           F F F F
+fnmsubs[.]          F F F F
+fsel[.]             F F F F
+frsp[.]             F F
+fctid[.]            F F
+fctidz[.]           F F
+fctidu[.]           F F
+fctiduz[.]          F F
+fctiw[.]            F F
+fctiwz[.]           F F
+fctiwu[.]           F F
+fctiwuz[.]          F F
+fcfid[.]            F F
+fcfidu[.]           F F
+fcfids[.]           F F
+fcfidus[.]          F F
+frin[.]             F F
+friz[.]             F F
+frip[.]             F F
+frim[.]             F F
+fcmpu               CR F F
+fcmpo               CR F F
+mffs[.]             F
+mffsl               F
+mffsce              F
+mffscdrn            F F
+mffscrn             F F
+mffscrni            F u2
+mcrfs               CR CR
+mtfsfi[.]           CR u4
+mtfsf[.]            u8 F
+mtfsb0              u5
+mtfsb1              u5
+
+# VSX loads and stores
+lxsdx               VS R R
+lxsiwax             VS R R
+lxsiwzx             VS R R
+lxsspx              VS R R
+lxsibzx             VS R R
+lxsihzx             VS R R
+lxvd2x              VS R R
+lxvdsx              VS R R
+lxvw4x              VS R R
+lxvh8x              VS R R
+lxvb16x             VS R R
+lxvx                VS R R
+lxvwsx              VS R R
+lxvl                VS R R
+lxvll               VS R R
+lxv                 VS DQ
+lxsd                V DS
+lxssp               V DS
+stxsdx              VS R R
+stxsiwx             VS R R
+stxsspx             VS R R
+stxsibx             VS R R
+stxsihx             VS R R
+stxvd2x             VS R R
+stxvw4x             VS R R
+stxvh8x             VS R R
+stxvb16x            VS R R
+stxvx               VS R R
+stxvl               VS R R
+stxvll              VS R R
+stxv                VS DQ
+stxsd               V DS
+stxssp              V DS
+
+# VSX scalar instructions
+xsadddp             VS VS VS
+xssubdp             VS VS VS
+xsmuldp             VS VS VS
+xsdivdp             VS VS VS
+xsaddsp             VS VS VS
+xssubsp             VS VS VS
+xsmulsp             VS VS VS
+xsdivsp             VS VS VS
+xsmaxdp             VS VS VS
+xsmindp             VS VS VS
+xsmaxcdp            VS VS VS
+xsmincdp            VS VS VS
+xsmaxjdp            VS VS VS
+xsminjdp            VS VS VS
+xscpsgndp           VS VS VS
+xsmaddadp           VS VS VS
+xsmaddmdp           VS VS VS
+xsmsubadp           VS VS VS
+xsmsubmdp           VS VS VS
+xsnmaddadp          VS VS VS
+xsnmaddmdp          VS VS VS
+xsnmsubadp          VS VS VS
+xsnmsubmdp          VS VS VS
+xsmaddasp           VS VS VS
+xsmaddmsp           VS VS VS
+xsmsubasp           VS VS VS
+xsmsubmsp           VS VS VS
+xsnmaddasp          VS VS VS
+xsnmaddmsp          VS VS VS
+xsnmsubasp          VS VS VS
+xsnmsubmsp          VS VS VS
+xscmpeqdp           VS VS VS
+xscmpgtdp           VS VS VS
+xscmpgedp           VS VS VS
+xssqrtdp            VS VS
+xssqrtsp            VS VS
+xsredp              VS VS
+xsresp              VS VS
+xsrsqrtedp          VS VS
+xsrsqrtesp          VS VS
+xsabsdp             VS VS
+xsnabsdp            VS VS
+xsnegdp             VS VS
+xscvdpsp            VS VS
+xscvdpspn           VS VS
+xscvspdp            VS VS
+xscvspdpn           VS VS
+xscvdpsxds          VS VS
+xscvdpsxws          VS VS
+xscvdpuxds          VS VS
+xscvdpuxws          VS VS
+xscvsxddp           VS VS
+xscvuxddp           VS VS
+xscvsxdsp           VS VS
+xscvuxdsp           VS VS
+xscvhpdp            VS VS
+xscvdphp            VS VS
+xsrdpi              VS VS
+xsrdpic             VS VS
+xsrdpim             VS VS
+xsrdpip             VS VS
+xsrdpiz             VS VS
+xsrsp               VS VS
+xscmpudp            CR VS VS
+xscmpodp            CR VS VS
+xscmpexpdp          CR VS VS
+xstdivdp            CR VS VS
+xstsqrtdp           CR VS
+xststdcdp           CR VS u7
+xststdcsp           CR VS u7
+xsxexpdp            R VS
+xsxsigdp            R VS
+xsiexpdp            VS R R
+
+# VSX vector instructions
+xvadddp             VS VS VS
+xvaddsp             VS VS VS
+xvsubdp             VS VS VS
+xvsubsp             VS VS VS
+xvmuldp             VS VS VS
+xvmulsp             VS VS VS
+xvdivdp             VS VS VS
+xvdivsp             VS VS VS
+xvmaxdp             VS VS VS
+xvmaxsp             VS VS VS
+xvmindp             VS VS VS
+xvminsp             VS VS VS
+xvcpsgndp           VS VS VS
+xvcpsgnsp           VS VS VS
+xvmaddadp           VS VS VS
+xvmaddmdp           VS VS VS
+xvmaddasp           VS VS VS
+xvmaddmsp           VS VS VS
+xvmsubadp           VS VS VS
+xvmsubmdp           VS VS VS
+xvmsubasp           VS VS VS
+xvmsubmsp           VS VS VS
+xvnmaddadp          VS VS VS
+xvnmaddmdp          VS VS VS
+xvnmaddasp          VS VS VS
+xvnmaddmsp          VS VS VS
+xvnmsubadp          VS VS VS
+xvnmsubmdp          VS VS VS
+xvnmsubasp          VS VS VS
+xvnmsubmsp          VS VS VS
+xviexpdp            VS VS VS
+xviexpsp            VS VS VS
+xvcmpeqdp[.]        VS VS VS
+xvcmpgtdp[.]        VS VS VS
+xvcmpgedp[.]        VS VS VS
+xvcmpeqsp[.]        VS VS VS
+xvcmpgtsp[.]        VS VS VS
+xvcmpgesp[.]        VS VS VS
+xvsqrtdp            VS VS
+xvsqrtsp            VS VS
+xvredp              VS VS
+xvresp              VS VS
+xvrsqrtedp          VS VS
+xvrsqrtesp          VS VS
+xvabsdp             VS VS
+xvabssp             VS VS
+xvnabsdp            VS VS
+xvnabssp            VS VS
+xvnegdp             VS VS
+xvnegsp             VS VS
+xvcvdpsp            VS VS
+xvcvspdp            VS VS
+xvcvdpsxds          VS VS
+xvcvdpsxws          VS VS
+xvcvdpuxds          VS VS
+xvcvdpuxws          VS VS
+xvcvspsxds          VS VS
+xvcvspsxws          VS VS
+xvcvspuxds          VS VS
+xvcvspuxws          VS VS
+xvcvsxddp           VS VS
+xvcvsxdsp           VS VS
+xvcvsxwdp           VS VS
+xvcvsxwsp           VS VS
+xvcvuxddp           VS VS
+xvcvuxdsp           VS VS
+xvcvuxwdp           VS VS
+xvcvuxwsp           VS VS
+xvcvhpsp            VS VS
+xvcvsphp            VS VS
+xvrdpi              VS VS
+xvrdpic             VS VS
+xvrdpim             VS VS
+xvrdpip             VS VS
+xvrdpiz             VS VS
+xvrspi              VS VS
+xvrspic             VS VS
+xvrspim             VS VS
+xvrspip             VS VS
+xvrspiz             VS VS
+xvxexpdp            VS VS
+xvxexpsp            VS VS
+xvxsigdp            VS VS
+xvxsigsp            VS VS
+xvtdivdp            CR VS VS
+xvtdivsp            CR VS VS
+xvtsqrtdp           CR VS
+xvtsqrtsp           CR VS
+xvtstdcdp           VS VS u7
+xvtstdcsp           VS VS u7
+
+# VSX logical and permute instructions
+xxland              VS VS VS
+xxlandc             VS VS VS
+xxleqv              VS VS VS
+xxlnand             VS VS VS
+xxlnor              VS VS VS
+xxlor               VS VS VS
+xxlorc              VS VS VS
+xxlxor              VS VS VS
+xxsel               VS VS VS VS
+xxmrghw             VS VS VS
+xxmrglw             VS VS VS
+xxmrghd             VS VS VS
+xxmrgld             VS VS VS
+xxperm              VS VS VS
+xxpermr             VS VS VS
+xxpermdi            VS VS VS u2
+xxsldwi             VS VS VS u2
+xxspltw             VS VS u2
+xxspltd             VS VS u1
+xxswapd             VS VS
+xxspltib            VS u8
+xxextractuw         VS VS u4
+xxinsertw           VS VS u4
+xxbrh               VS VS
+xxbrw               VS VS
+xxbrd               VS VS
+xxbrq               VS VS
+
+# VSX moves to and from general purpose registers
+mtvsrd              VS R
+mtvsrwa             VS R
+mtvsrwz             VS R
+mtvsrdd             VS R R
+mtvsrws             VS R
+mfvsrd              R VS
+mfvsrwz             R VS
+mfvsrld             R VS
+"""
+
+# some extended mnemonics do not encode their operands as a plain bit pattern, but encode a
+# negated value. These are probed using the base instruction instead, with a list of how every
+# base operand is derived: the index of an extended mnemonic operand, that index prefixed with
+# - for a negated operand, or = followed by a constant.
+NEGATED = {
+    "srwi": ("rlwinm", ["0", "1", "-2", "2", "=31"]),
+    "srdi": ("rldicl", ["0", "1", "-2", "2"]),
+}
+
+
+def conditional_branches():
+    lines = []
+    for cond in CONDITIONS:
+        for suffix, target in (("", "B14"), ("l", "B14"), ("lr", ""), ("lrl", ""), ("ctr", ""), ("ctrl", "")):
+            lines.append(f"b{cond}{suffix} CR {target}".strip())
+            lines.append(f"b{cond}{suffix} {target}".strip())
+    return "\n".join(lines)
+
+
+def parse_table():
+    forms = []
+    section = None
+    for line in INSTRUCTIONS.replace("{conditional_branches}", conditional_branches()).splitlines():
+        line = line.strip()
+        if not line:
+            continue
+        if line.startswith("#"):
+            section = line[1:].strip()
+            continue
+
+        mnemonic, *operands = line.split()
+        for name in expand_suffixes(mnemonic):
+            forms.append((section, name, operands))
+    return forms
+
+
+def expand_suffixes(mnemonic):
+    names = [""]
+    for part in re.findall(r"\[[^\]]*\]|[^\[]+", mnemonic):
+        if part.startswith("["):
+            names = [n + s for n in names for s in ("", part[1:-1])]
+        else:
+            names = [n + part for n in names]
+    return names
+
+
+def operand_bits(kind):
+    """returns a list of (bit index, probe value) tuples for every bit of an operand"""
+    if kind in ("R", "F", "V"):
+        return [(i, 1 << i) for i in range(5)]
+    elif kind == "VS":
+        return [(i, 1 << i) for i in range(6)]
+    elif kind == "CR":
+        return [(i, 1 << i) for i in range(3)]
+    elif kind[0] == "u":
+        return [(i, 1 << i) for i in range(int(kind[1:]))]
+    elif kind[0] == "s":
+        bits = int(kind[1:])
+        return [(i, 1 << i if i != bits - 1 else -(1 << i)) for i in range(bits)]
+    raise NotImplementedError(kind)
+
+
+def memory_scale(kind):
+    return {"D": 0, "DS": 2, "DQ": 4}[kind]
+
+
+def flatten_operands(operands):
+    """memory references consist of two operands, a base register and an offset. returns a list
+    of (kind, probeable) tuples for every flat operand"""
+    flat = []
+    for op in operands:
+        if op in ("D", "DS", "DQ"):
+            flat.append("R")
+            flat.append(f"m{memory_scale(op)}")
+        elif op in ("DL", "DSL"):
+            flat.append("R")
+            flat.append("label")
+        elif op in ("B24", "B14", "HA", "LO", "PCHA"):
+            flat.append("label")
+        else:
+            flat.append(op)
+    return flat
+
+
+def probe_bits(kind):
+    if kind == "label":
+        return []
+    if kind[0] == "m":
+        scale = int(kind[1:])
+        return [(i, 1 << i if i != 15 else -(1 << i)) for i in range(scale, 16)]
+    return operand_bits(kind)
+
+
+def format_llvm(mnemonic, operands, values):
+    values = iter(values)
+    args = []
+    for op in operands:
+        if op in ("D", "DS", "DQ", "DL", "DSL"):
+            base = next(values)
+            offset = next(values)
+            args.append(f"{offset}({base})")
+        else:
+            args.append(f"{next(values)}")
+    if args:
+        return f"{mnemonic} {', '.join(args)}"
+    return mnemonic
+
+
+def assemble_all(lines, suffix):
+    """assembles a list of single-instruction lines with llvm-mc. Returns a list of encodings,
+    or None for lines that failed to assemble"""
+    with tempfile.TemporaryDirectory() as d:
+        path = Path(d) / "probe.s"
+        path.write_text("\n".join(lines) + "\n", encoding="utf-8")
+        result = subprocess.run([f"llvm-mc{suffix}", "-triple=powerpc64le", "-mcpu=pwr9", "-show-encoding", str(path)], capture_output=True, encoding="utf-8")
+
+    failed = set()
+    for m in re.finditer(r"probe\.s:(\d+):\d+: error", result.stderr):
+        failed.add(int(m.group(1)) - 1)
+
+    encodings = iter(re.findall(r"encoding: \[(0x[0-9a-f]{2}),(0x[0-9a-f]{2}),(0x[0-9a-f]{2}),(0x[0-9a-f]{2})\]", result.stdout))
+    results = []
+    for i in range(len(lines)):
+        if i in failed:
+            results.append(None)
+        else:
+            b = next(encodings)
+            results.append(int(b[0], 16) | int(b[1], 16) << 8 | int(b[2], 16) << 16 | int(b[3], 16) << 24)
+    return results
+
+
+class Form:
+    def __init__(self, section, mnemonic, operands):
+        self.section = section
+        self.mnemonic = mnemonic
+        self.operands = operands
+        self.flat = flatten_operands(operands)
+        self.template = None
+        # for every flat operand, a list of (value bit, instruction bit, polarity)
+        self.fields = [[] for _ in self.flat]
+        self.error = None
+
+    def probes(self):
+        """returns the llvm line of the template, and a list of (flat operand index, value bit,
+        negated, llvm line) probes"""
+        name = self.mnemonic.rstrip(".")
+        if name not in NEGATED:
+            template = format_llvm(self.mnemonic, self.operands, [0] * len(self.flat))
+            probes = []
+            for i, kind in enumerate(self.flat):
+                for bit, value in probe_bits(kind):
+                    values = [0] * len(self.flat)
+                    values[i] = value
+                    probes.append((i, bit, False, format_llvm(self.mnemonic, self.operands, values)))
+            return template, probes
+
+        base, mapping = NEGATED[name]
+        base = base + self.mnemonic[len(name):]
+        kinds = ["u5" if m.startswith("=") else self.flat[abs(int(m))] for m in mapping]
+        zeroes = [int(m[1:]) if m.startswith("=") else 0 for m in mapping]
+        template = format_llvm(base, kinds, zeroes)
+        probes = []
+        for j, m in enumerate(mapping):
+            if m.startswith("="):
+                continue
+            i = abs(int(m))
+            for bit, value in probe_bits(self.flat[i]):
+                values = list(zeroes)
+                values[j] = value
+                probes.append((i, bit, m.startswith("-"), format_llvm(base, kinds, values)))
+        return template, probes
+
+    def process(self, template, results):
+        if template is None:
+            self.error = "could not assemble template"
+            return
+        self.template = template
+
+        for (operand, bit, negated), encoding in results:
+            if encoding is None:
+                self.error = f"could not assemble probe for operand {operand} bit {bit}"
+                return
+            diff = encoding ^ template
+            if diff == 0:
+                self.error = f"operand {operand} bit {bit} is not encoded"
+                return
+            for pos in range(32):
+                if diff & (1 << pos):
+                    if negated:
+                        polarity = "neg"
+                    else:
+                        polarity = bool(template & (1 << pos))
+                    self.fields[operand].append((bit, pos, polarity))
+
+        # inverted fields are encoded as the inverse of the operand, and are all ones in the
+        # template. Clear them as the encoding is or-ed into the template.
+        for fields in self.fields:
+            for bit, pos, polarity in fields:
+                if polarity is True:
+                    self.template &= ~(1 << pos)
+
+    def matchers(self):
+        matchers = []
+        for op in self.operands:
+            if op in ("R", "F", "V", "VS", "CR"):
+                matchers.append({"VS": "Vs", "CR": "Cr"}.get(op, op))
+            elif op in ("D", "DS", "DQ"):
+                matchers.append("RefOffset")
+            elif op in ("DL", "DSL"):
+                matchers.append("RefLabel")
+            elif op in ("B24", "B14", "HA", "LO", "PCHA"):
+                matchers.append("Offset")
+            else:
+                matchers.append("Imm")
+        return matchers
+
+    def commands(self):
+        commands = []
+        ops = iter(self.operands)
+        flat = iter(enumerate(self.flat))
+        for op in ops:
+            if op in ("D", "DS", "DQ"):
+                commands.extend(self.register_commands(next(flat)[0], "R"))
+                i, kind = next(flat)
+                commands.extend(self.immediate_commands(i, True, 16, memory_scale(op)))
+            elif op in ("DL", "DSL"):
+                commands.extend(self.register_commands(next(flat)[0], "R"))
+                next(flat)
+                commands.append("Offset(LO16)" if op == "DL" else "Offset(LO16DS)")
+            elif op in ("B24", "B14", "HA", "LO", "PCHA"):
+                next(flat)
+                commands.append({
+                    "B24": "Offset(B24)", "B14": "Offset(B14)", "HA": "Offset(HA16)",
+                    "LO": "Offset(LO16)", "PCHA": "Offset(DXHA16)"
+                }[op])
+            elif op in ("R", "F", "V", "VS", "CR"):
+                commands.extend(self.register_commands(next(flat)[0], op))
+            else:
+                i, kind = next(flat)
+                commands.extend(self.immediate_commands(i, kind[0] == "s", int(kind[1:]), 0))
+        return commands
+
+    def register_commands(self, i, kind):
+        fields = sorted(self.fields[i], key=lambda f: f[1])
+        # group the bits by the field they belong to. Repeated registers are encoded in multiple fields
+        runs = group_runs(fields)
+        commands = []
+        if kind == "VS":
+            low = [r for r in runs if r[0] == 0]
+            high = [r for r in runs if r[0] == 5]
+            # VSX register numbers are split in a 5-bit field and a separate upper bit
+            parts = []
+            for l in low:
+                if l[2] == 5:
+                    h = high.pop(0)
+                    parts.append(f"Vs({l[1]}, {h[1]})")
+                elif l[2] == 6:
+                    parts.append(f"Vs({l[1]}, {l[1] + 5})")
+                else:
+                    raise ValueError(f"weird VSX register encoding {runs}")
+        else:
+            bits = {"R": 5, "F": 5, "V": 5, "CR": 3}[kind]
+            parts = []
+            for r in runs:
+                if r[0] != 0 or r[2] != bits or r[3]:
+                    raise ValueError(f"weird register encoding {runs}")
+                parts.append(f"R({r[1]})" if kind != "CR" else f"C({r[1]})")
+        for j, p in enumerate(parts):
+            if j != 0:
+                commands.append("Repeat")
+            commands.append(p)
+        return commands
+
+    def immediate_commands(self, i, signed, bits, scale):
+        fields = sorted(self.fields[i], key=lambda f: f[1])
+        runs = group_runs(fields)
+        commands = [f"SImm({bits}, {scale})" if signed else f"UImm({bits}, {scale})"]
+        for value_bit, pos, count, inverted in sorted(runs, key=lambda r: -r[1]):
+            kind = {False: "BitRange", True: "InvBitRange", "neg": "NegBitRange"}[inverted]
+            commands.append(f"{kind}({pos}, {count}, {value_bit})")
+        commands.append("Next")
+        return commands
+
+
+def group_runs(fields):
+    """groups (value bit, instruction bit, inverted) tuples into runs of
+    (first value bit, first instruction bit, length, inverted)"""
+    runs = []
+    for bit, pos, inverted in sorted(fields, key=lambda f: f[1]):
+        if runs:
+            vb, p, c, inv = runs[-1]
+            if p + c == pos and vb + c == bit and inv == inverted:
+                runs[-1] = (vb, p, c + 1, inv)
+                continue
+        runs.append((bit, pos, 1, inverted))
+    return runs
+
+
+def format_comment(form):
+    names = {"R": "r", "F": "f", "V": "v", "VS": "vs", "CR": "cr"}
+    args = []
+    regs = {}
+    for op in form.operands:
+        if op in names:
+            idx = regs.get(op, 0)
+            regs[op] = idx + 1
+            args.append(f"{names[op]}{'tabcd'[idx] if op != 'CR' else ''}")
+        elif op in ("D", "DS", "DQ"):
+            args.append(f"[ra, {op.lower()}]")
+        elif op in ("DL", "DSL"):
+            args.append("[ra, lo16(label)]")
+        elif op == "HA":
+            args.append("ha16(label)")
+        elif op == "LO":
+            args.append("lo16(label)")
+        elif op == "PCHA":
+            args.append("ha16(label)")
+        elif op in ("B24", "B14"):
+            args.append("label")
+        else:
+            args.append(op)
+    return f"{form.mnemonic} {', '.join(args)}".strip()
+
+
+def main():
+    parser = argparse.ArgumentParser("ppc64le_gen_opmap", description="generate the ppc64le opmap using llvm-mc as encoding reference")
+    parser.add_argument("--suffix", help="version suffix of the llvm executables, i.e. -14", default="")
+    parser.add_argument("output_file", type=Path)
+    args = parser.parse_args()
+
+    forms = [Form(*f) for f in parse_table()]
+
+    lines = []
+    index = []
+    for fi, form in enumerate(forms):
+        template, probes = form.probes()
+        lines.append(template)
+        index.append((fi, None))
+        for operand, bit, negated, line in probes:
+            lines.append(line)
+            index.append((fi, (operand, bit, negated)))
+
+    results = assemble_all(lines, args.suffix)
+
+    per_form = [[None, []] for _ in forms]
+    for (fi, probe), result in zip(index, results):
+        if probe is None:
+            per_form[fi][0] = result
+        else:
+            per_form[fi][1].append((probe, result))
+
+    for form, (template, probes) in zip(forms, per_form):
+        form.process(template, probes)
+
+    # and write out the result
+    mnemonics = OrderedDict()
+    for form in forms:
+        if form.error:
+            print(f"skipping {form.mnemonic} {' '.join(form.operands)}: {form.error}")
+            continue
+        mnemonics.setdefault(form.mnemonic, []).append(form)
+
+    with args.output_file.open("w", encoding="utf-8") as f:
+        f.write("Ops!(\n")
+        section = None
+        for mnemonic, forms in mnemonics.items():
+            if forms[0].section != section:
+                section = forms[0].section
+                f.write(f"\n\n// {section}\n\n")
+            f.write(f"\"{mnemonic}\" = [\n")
+            for form in forms:
+                f.write(f"    // {format_comment(form)}\n")
+                f.write(f"    Single(0x{form.template:08X}), [{', '.join(form.matchers())}] => [{', '.join(form.commands())}];\n")
+            f.write("],\n")
+        f.write(")\n")
+
+
+if __name__ == "__main__":
+    main()
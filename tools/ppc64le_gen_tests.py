@@ -0,0 +1,201 @@
+#!/usr/bin/python3
+
+# this file takes the opmap exported by docs/insref/export.rs and generates a big list of test cases
+# with it that can be compared with another assembler.
+# syntax is ppc64le_gen_tests.py input_file test_multiplier output_file
+#
+# The generated reference assembly uses the syntax of llvm-mc. Registers are written as plain
+# numbers and memory references as offset(base).
+
+import ast
+import random
+import re
+from pathlib import Path
+import argparse
+
+# instructions that take the high or low half of a label offset instead of the plain offset.
+# these can only be tested using labels.
+LABEL_HALVES = {"addi", "addis", "addpcis"}
+
+# dynasm-rs writes memory references as [base, offset], llvm-mc as offset(base)
+FIX_LLVM_MEMREF = re.compile(r"\[ *([^,\]]+?) *(?:, *([^\]]+?) *)?\]")
+
+def main():
+    parser = argparse.ArgumentParser("ppc64le_gen_tests",  description="generate ppc64le testcases from the exported dynasm-rs opmap definitions")
+    parser.add_argument("opmap_export_file", type=Path)
+    parser.add_argument("test_multiplier", type=int)
+    parser.add_argument("result_file", type=Path)
+    args = parser.parse_args()
+
+    with args.opmap_export_file.open("r", encoding="utf-8") as f:
+        templates = read_opdata_file(f)
+
+    tests = []
+
+    for template in templates:
+        # filter out the label forms of addis, addi, addpcis and memory references,
+        # as these take the low / high part of a label offset instead of the plain offset
+        if template.template.split()[0] in LABEL_HALVES and "Off" in template.template:
+            print(f"skipping {template.template} because it is exempted")
+            continue
+        if "[" in template.template and "Off" in template.template:
+            print(f"skipping {template.template} because it is exempted")
+            continue
+
+        for _ in range(args.test_multiplier):
+            tests.append(template.create_entry())
+
+    with args.result_file.open("w", encoding="utf-8") as f:
+        for dynasm, llvm in tests:
+            f.write(f"{dynasm}\t{llvm}\n")
+
+class OpTemplate:
+    def __init__(self, template, constraints):
+        self.template = template
+        self.constraints = constraints
+
+        self.args = parse_template(template)
+
+    def create_entry(self):
+        history = History()
+
+        for (arg, i) in self.args:
+            constraint = self.constraints[i]
+            value = constraint.create_value(history)
+            history.values.append(value)
+
+        for (arg, i), value in zip(self.args, history.values):
+            history.emitted.append(arg.emit_dynasm(value))
+            history.llvm.append(arg.emit_llvm(value))
+
+        dynasm_string = SUBSTITUTION_RE.sub(lambda m: history.emitted[int(m.group(2))], self.template)
+        llvm_string = SUBSTITUTION_RE.sub(lambda m: history.llvm[int(m.group(2))], self.template)
+
+        llvm_string = FIX_LLVM_MEMREF.sub(lambda m: f"{m.group(2) or 0}({m.group(1)})", llvm_string)
+
+        return dynasm_string, llvm_string
+
+class History:
+    def __init__(self):
+        self.values = []
+        self.emitted = []
+        self.llvm = []
+
+def read_opdata_file(f):
+    templates = []
+    context = dict(
+        R=R,
+        Range=Range,
+    )
+
+    for line in f:
+        template, constraints = line.split("\t")
+
+        template = ast.literal_eval(template)
+        constraints = eval(constraints, context)
+
+        templates.append(OpTemplate(template, constraints))
+
+    return templates
+
+SUBSTITUTION_RE = re.compile(r"<([a-zA-Z]+),([0-9]+)>")
+def parse_template(template):
+    matches = []
+    for argty, argidx in SUBSTITUTION_RE.findall(template):
+        if argty == "Imm":
+            arg = Immediate()
+        elif argty == "Off":
+            arg = Offset()
+        elif argty in ("R", "F", "V", "VS", "CR"):
+            arg = Register(argty)
+        else:
+            raise NotImplementedError(argty)
+        matches.append((arg, int(argidx)))
+    return matches
+
+# Implementation details Constraints
+
+class Constraint:
+    def __init__(self):
+        pass
+
+    def create_value(self, history):
+        raise NotImplementedError()
+
+class Range(Constraint):
+    """A constraint that allows any value in the specified range"""
+
+    def __init__(self, min, max, scale):
+        self.min = min
+        self.max = max
+        self.scale = scale
+
+    def create_value(self, history):
+        return random.randrange(self.min, self.max, self.scale)
+
+class R(Constraint):
+    """A constraint that allows a certain range of registers, as specified by a mask"""
+
+    def __init__(self, mask):
+        self.mask = mask
+
+    def create_value(self, history):
+        options = []
+        for i in range(64):
+            if self.mask & (1 << i) != 0:
+                options.append(i)
+
+        return random.choice(options)
+
+# Implementation details Args
+
+class Arg:
+    def __init__(self):
+        pass
+
+    def emit_llvm(self, value):
+        raise NotImplementedError()
+
+    def emit_dynasm(self, value):
+        raise NotImplementedError()
+
+class Register(Arg):
+    PREFIXES = {
+        "R": "r",
+        "F": "f",
+        "V": "v",
+        "VS": "vs",
+        "CR": "cr",
+    }
+
+    def __init__(self, family):
+        self.family = family
+
+    def emit_llvm(self, value):
+        # llvm-mc accepts plain register numbers, the kind of register follows from the instruction
+        return f"{value}"
+
+    def emit_dynasm(self, value):
+        # randomly choose dynamic vs static notation.
+        if random.choice((True, False)):
+            return f"{self.PREFIXES[self.family]}{value}"
+        else:
+            return f"{self.family}({value})"
+
+class Immediate(Arg):
+    def emit_llvm(self, value):
+        return f"{value}"
+
+    def emit_dynasm(self, value):
+        return f"{value}"
+
+class Offset(Immediate):
+    def emit_llvm(self, value):
+        if value >= 0:
+            return ".+" + super().emit_llvm(value)
+        else:
+            return "." + super().emit_llvm(value)
+
+
+if __name__ == '__main__':
+    main()
@@ -529,7 +529,8 @@ def main():
     rv32_extensions = archive.get_available_extensions("rv32")
     rv64_extensions = archive.get_available_extensions("rv64")
 
-    # right now we're not dealing with the vector or privileged instruction sets
+    # right now we're not dealing with the privileged instruction sets.
+    # the vector instruction sets are maintained by hand in opmap.rs, as they need masking and vtype handling
     unused_extensions = ("v", "s", "h", "zv", "aliases")
 
     rv32_extensions = [i for i in rv32_extensions if not i.startswith(unused_extensions)]
@@ -165,7 +165,11 @@ def read_opdata_file(f):
         FenceSpec=FenceSpec,
         Csr=Csr,
         FloatingPointImmediate=FloatingPointImmediate,
-        StackAdjustImmediate=StackAdjustImmediate
+        StackAdjustImmediate=StackAdjustImmediate,
+        ElementWidth=ElementWidth,
+        GroupMultiplier=GroupMultiplier,
+        TailPolicy=TailPolicy,
+        MaskPolicy=MaskPolicy
     )
 
     for line in f:
@@ -190,7 +194,7 @@ def parse_template(template):
             arg = Ident()
         elif argty == "Off":
             arg = Offset()
-        elif argty in "XF":
+        elif argty in "XFV":
             arg = Register(argty)
         elif argty == "RegList":
             arg = RegList()
@@ -307,6 +311,30 @@ class FloatingPointImmediate(Constraint):
             "32768.0", "65536.0", "inf", "nan"
         ))
 
+class ElementWidth(Constraint):
+    """A constraint that produces values for a vector element width"""
+
+    def create_value(self, history, arch):
+        return random.choice(("e8", "e16", "e32", "e64"))
+
+class GroupMultiplier(Constraint):
+    """A constraint that produces values for a vector register group multiplier"""
+
+    def create_value(self, history, arch):
+        return random.choice(("m1", "m2", "m4", "m8", "mf8", "mf4", "mf2"))
+
+class TailPolicy(Constraint):
+    """A constraint that produces values for a vector tail policy"""
+
+    def create_value(self, history, arch):
+        return random.choice(("tu", "ta"))
+
+class MaskPolicy(Constraint):
+    """A constraint that produces values for a vector mask policy"""
+
+    def create_value(self, history, arch):
+        return random.choice(("mu", "ma"))
+
 class StackAdjustImmediate(Constraint):
 
     def __init__(self, negated):
@@ -354,6 +382,8 @@ class Register(Arg):
             return f"x{value}"
         elif self.family == "F":
             return f"f{value}"
+        elif self.family == "V":
+            return f"v{value}"
         else:
             raise NotImplementedError(self.family)
 